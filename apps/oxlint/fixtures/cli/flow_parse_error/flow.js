@@ -0,0 +1,4 @@
+// @flow
+import type { Node } from 'react';
+
+declare module.exports: Node;
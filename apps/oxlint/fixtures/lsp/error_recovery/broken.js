@@ -0,0 +1,8 @@
+debugger;
+
+function f() {
+  const a = ;
+  debugger;
+}
+
+f();
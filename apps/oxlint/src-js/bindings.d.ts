@@ -51,6 +51,16 @@ export declare function applyFixes(sourceText: string, fixesJson: string): strin
  */
 export declare function getBufferOffset(buffer: Uint8Array): number
 
+/**
+ * Get type information of the file being linted, serialized to JSON.
+ *
+ * `id` is the ID which `lintFile` received. Type information is only serialized when a rule
+ * first asks for it, and can only be got once, so JS side must cache it.
+ *
+ * Returns `null` if type information for `id` does not exist.
+ */
+export declare function getTypeInfo(id: number): string | null
+
 /** JS callback to create a workspace. */
 export type JsCreateWorkspaceCb =
   ((arg: string) => Promise<undefined>)
@@ -61,7 +71,7 @@ export type JsDestroyWorkspaceCb =
 
 /** JS callback to lint a file. */
 export type JsLintFileCb =
-  ((arg0: string, arg1: number, arg2: Uint8Array | undefined | null, arg3: Array<number>, arg4: Array<number>, arg5: string, arg6: string, arg7?: string | undefined | null) => string | null)
+  ((arg0: string, arg1: number, arg2: Uint8Array | undefined | null, arg3: Array<number>, arg4: Array<number>, arg5: string, arg6: string, arg7?: number | undefined | null, arg8?: string | undefined | null) => string | null)
 
 /** JS callback to load JavaScript config files. */
 export type JsLoadJsConfigsCb =
@@ -590,10 +590,11 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { Severity, applyFixes, getBufferOffset, lint, parseRawSync, rawTransferSupported } = nativeBinding
+const { Severity, applyFixes, getBufferOffset, getTypeInfo, lint, parseRawSync, rawTransferSupported } = nativeBinding
 export { Severity }
 export { applyFixes }
 export { getBufferOffset }
+export { getTypeInfo }
 export { lint }
 export { parseRawSync }
 export { rawTransferSupported }
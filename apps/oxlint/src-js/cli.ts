@@ -70,6 +70,7 @@ function setupRuleConfigsWrapper(optionsJSON: string): string | null {
  * @param optionsIds - IDs of options to use for rules on this file, in same order as `ruleIds`
  * @param settingsJSON - Settings for file, as JSON
  * @param globalsJSON - Globals for file, as JSON
+ * @param typeInfoId - ID to get type information for file with (`null` unless type-aware linting is enabled)
  * @param workspaceUri - Workspace URI (`null` in CLI mode, `string` in LSP mode)
  * @returns Diagnostics or error serialized to JSON string
  */
@@ -81,11 +82,13 @@ function lintFileWrapper(
   optionsIds: number[],
   settingsJSON: string,
   globalsJSON: string,
+  typeInfoId: number | null | undefined,
   workspaceUri: string | null | undefined,
 ): string | null {
   // Type generated by NAPI-RS for `lint` is inaccurate.
   // `Option::None` on Rust side becomes `null` on JS side, not `undefined`.
   debugAssertIsNotUndefined(buffer, "`buffer` should not be `undefined`");
+  debugAssertIsNotUndefined(typeInfoId, "`typeInfoId` should not be `undefined`");
   debugAssertIsNotUndefined(workspaceUri, "`workspaceUri` should not be `undefined`");
 
   // `lintFileWrapper` is never called without `loadPluginWrapper` being called first,
@@ -99,6 +102,7 @@ function lintFileWrapper(
     optionsIds,
     settingsJSON,
     globalsJSON,
+    typeInfoId,
     workspaceUri,
   );
 }
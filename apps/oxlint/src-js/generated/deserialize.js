@@ -190,6 +190,12 @@ function deserializeExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -377,6 +383,12 @@ function deserializeArrayExpressionElement(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -420,6 +432,8 @@ function deserializeObjectPropertyKind(pos) {
       return deserializeBoxObjectProperty(pos + 8);
     case 1:
       return deserializeBoxSpreadElement(pos + 8);
+    case 2:
+      return deserializeBoxErrorNode(pos + 8);
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for ObjectPropertyKind`);
   }
@@ -532,6 +546,12 @@ function deserializePropertyKey(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -862,6 +882,12 @@ function deserializeArgument(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -1415,6 +1441,8 @@ function deserializeStatement(pos) {
       return deserializeBoxWhileStatement(pos + 8);
     case 17:
       return deserializeBoxWithStatement(pos + 8);
+    case 18:
+      return deserializeBoxErrorNode(pos + 8);
     case 32:
       return deserializeBoxVariableDeclaration(pos + 8);
     case 33:
@@ -1433,6 +1461,8 @@ function deserializeStatement(pos) {
       return deserializeBoxTSGlobalDeclaration(pos + 8);
     case 40:
       return deserializeBoxTSImportEqualsDeclaration(pos + 8);
+    case 41:
+      return deserializeBoxFlowOpaqueTypeDeclaration(pos + 8);
     case 64:
       return deserializeBoxImportDeclaration(pos + 8);
     case 65:
@@ -1507,6 +1537,8 @@ function deserializeDeclaration(pos) {
       return deserializeBoxTSGlobalDeclaration(pos + 8);
     case 40:
       return deserializeBoxTSImportEqualsDeclaration(pos + 8);
+    case 41:
+      return deserializeBoxFlowOpaqueTypeDeclaration(pos + 8);
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for Declaration`);
   }
@@ -1780,6 +1812,12 @@ function deserializeForStatementInit(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2582,6 +2620,8 @@ function deserializeClassElement(pos) {
       return deserializeBoxAccessorProperty(pos + 8);
     case 4:
       return deserializeBoxTSIndexSignature(pos + 8);
+    case 5:
+      return deserializeBoxErrorNode(pos + 8);
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for ClassElement`);
   }
@@ -3094,6 +3134,12 @@ function deserializeExportDefaultDeclarationKind(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -3144,6 +3190,50 @@ function deserializeV8IntrinsicExpression(pos) {
   return node;
 }
 
+function deserializePipelineExpression(pos) {
+  let start,
+    end,
+    previousParent = parent,
+    node = (parent = {
+      __proto__: NodeProto,
+      type: "PipelineExpression",
+      head: null,
+      body: null,
+      start: (start = deserializeI32(pos)),
+      end: (end = deserializeI32(pos + 4)),
+      range: [start, end],
+      parent,
+    });
+  node.head = deserializeExpression(pos + 16);
+  node.body = deserializeExpression(pos + 32);
+  parent = previousParent;
+  return node;
+}
+
+function deserializeTopicReference(pos) {
+  let start, end;
+  return {
+    __proto__: NodeProto,
+    type: "TopicReference",
+    start: (start = deserializeI32(pos)),
+    end: (end = deserializeI32(pos + 4)),
+    range: [start, end],
+    parent,
+  };
+}
+
+function deserializeErrorNode(pos) {
+  let start, end;
+  return {
+    __proto__: NodeProto,
+    type: "ErrorNode",
+    start: (start = deserializeI32(pos)),
+    end: (end = deserializeI32(pos + 4)),
+    range: [start, end],
+    parent,
+  };
+}
+
 function deserializeBooleanLiteral(pos) {
   let value = deserializeBool(pos + 12),
     start = deserializeI32(pos),
@@ -3611,6 +3701,12 @@ function deserializeJSXExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -5592,6 +5688,8 @@ function deserializeImportOrExportKind(pos) {
       return "value";
     case 1:
       return "type";
+    case 2:
+      return "typeof";
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for ImportOrExportKind`);
   }
@@ -5647,6 +5745,51 @@ function deserializeJSDocUnknownType(pos) {
   };
 }
 
+function deserializeFlowTypeCastExpression(pos) {
+  let start,
+    end,
+    previousParent = parent,
+    node = (parent = {
+      __proto__: NodeProto,
+      type: "FlowTypeCastExpression",
+      expression: null,
+      typeAnnotation: null,
+      start: (start = deserializeI32(pos)),
+      end: (end = deserializeI32(pos + 4)),
+      range: [start, end],
+      parent,
+    });
+  node.expression = deserializeExpression(pos + 16);
+  node.typeAnnotation = deserializeBoxTSTypeAnnotation(pos + 32);
+  parent = previousParent;
+  return node;
+}
+
+function deserializeFlowOpaqueTypeDeclaration(pos) {
+  let start,
+    end,
+    previousParent = parent,
+    node = (parent = {
+      __proto__: NodeProto,
+      type: "FlowOpaqueTypeDeclaration",
+      id: null,
+      typeParameters: null,
+      supertype: null,
+      impltype: null,
+      declare: deserializeBool(pos + 88),
+      start: (start = deserializeI32(pos)),
+      end: (end = deserializeI32(pos + 4)),
+      range: [start, end],
+      parent,
+    });
+  node.id = deserializeBindingIdentifier(pos + 16);
+  node.typeParameters = deserializeOptionBoxTSTypeParameterDeclaration(pos + 48);
+  node.supertype = deserializeOptionTSType(pos + 56);
+  node.impltype = deserializeOptionTSType(pos + 72);
+  parent = previousParent;
+  return node;
+}
+
 function deserializeModuleKind(pos) {
   switch (uint8[pos]) {
     case 0:
@@ -6010,6 +6153,18 @@ function deserializeBoxV8IntrinsicExpression(pos) {
   return deserializeV8IntrinsicExpression(int32[pos >> 2]);
 }
 
+function deserializeBoxFlowTypeCastExpression(pos) {
+  return deserializeFlowTypeCastExpression(int32[pos >> 2]);
+}
+
+function deserializeBoxPipelineExpression(pos) {
+  return deserializePipelineExpression(int32[pos >> 2]);
+}
+
+function deserializeBoxTopicReference(pos) {
+  return deserializeTopicReference(int32[pos >> 2]);
+}
+
 function deserializeVecArrayExpressionElement(pos) {
   let arr = [],
     pos32 = pos >> 2;
@@ -6046,6 +6201,10 @@ function deserializeBoxObjectProperty(pos) {
   return deserializeObjectProperty(int32[pos >> 2]);
 }
 
+function deserializeBoxErrorNode(pos) {
+  return deserializeErrorNode(int32[pos >> 2]);
+}
+
 function deserializeBool(pos) {
   return uint8[pos] === 1;
 }
@@ -6282,6 +6441,10 @@ function deserializeBoxTSImportEqualsDeclaration(pos) {
   return deserializeTSImportEqualsDeclaration(int32[pos >> 2]);
 }
 
+function deserializeBoxFlowOpaqueTypeDeclaration(pos) {
+  return deserializeFlowOpaqueTypeDeclaration(int32[pos >> 2]);
+}
+
 function deserializeVecVariableDeclarator(pos) {
   let arr = [],
     pos32 = pos >> 2;
@@ -51,6 +51,7 @@ export default freeze({
   // Leaf nodes
   DebuggerStatement: $EMPTY,
   EmptyStatement: $EMPTY,
+  ErrorNode: $EMPTY,
   Literal: $EMPTY,
   PrivateIdentifier: $EMPTY,
   Super: $EMPTY,
@@ -5,196 +5,197 @@
 export const NODE_TYPE_IDS_MAP = /* @__PURE__ */ new Map([
   ["DebuggerStatement", 0],
   ["EmptyStatement", 1],
-  ["Literal", 2],
-  ["PrivateIdentifier", 3],
-  ["Super", 4],
-  ["TemplateElement", 5],
-  ["ThisExpression", 6],
-  ["JSXClosingFragment", 7],
-  ["JSXEmptyExpression", 8],
-  ["JSXIdentifier", 9],
-  ["JSXOpeningFragment", 10],
-  ["JSXText", 11],
-  ["TSAnyKeyword", 12],
-  ["TSBigIntKeyword", 13],
-  ["TSBooleanKeyword", 14],
-  ["TSIntrinsicKeyword", 15],
-  ["TSJSDocUnknownType", 16],
-  ["TSNeverKeyword", 17],
-  ["TSNullKeyword", 18],
-  ["TSNumberKeyword", 19],
-  ["TSObjectKeyword", 20],
-  ["TSStringKeyword", 21],
-  ["TSSymbolKeyword", 22],
-  ["TSThisType", 23],
-  ["TSUndefinedKeyword", 24],
-  ["TSUnknownKeyword", 25],
-  ["TSVoidKeyword", 26],
-  ["AccessorProperty", 27],
-  ["ArrayExpression", 28],
-  ["ArrayPattern", 29],
-  ["ArrowFunctionExpression", 30],
-  ["AssignmentExpression", 31],
-  ["AssignmentPattern", 32],
-  ["AwaitExpression", 33],
-  ["BinaryExpression", 34],
-  ["BlockStatement", 35],
-  ["BreakStatement", 36],
-  ["CallExpression", 37],
-  ["CatchClause", 38],
-  ["ChainExpression", 39],
-  ["ClassBody", 40],
-  ["ClassDeclaration", 41],
-  ["ClassExpression", 42],
-  ["ConditionalExpression", 43],
-  ["ContinueStatement", 44],
-  ["Decorator", 45],
-  ["DoWhileStatement", 46],
-  ["ExportAllDeclaration", 47],
-  ["ExportDefaultDeclaration", 48],
-  ["ExportNamedDeclaration", 49],
-  ["ExportSpecifier", 50],
-  ["ExpressionStatement", 51],
-  ["ForInStatement", 52],
-  ["ForOfStatement", 53],
-  ["ForStatement", 54],
-  ["FunctionDeclaration", 55],
-  ["FunctionExpression", 56],
-  ["Identifier", 57],
-  ["IfStatement", 58],
-  ["ImportAttribute", 59],
-  ["ImportDeclaration", 60],
-  ["ImportDefaultSpecifier", 61],
-  ["ImportExpression", 62],
-  ["ImportNamespaceSpecifier", 63],
-  ["ImportSpecifier", 64],
-  ["LabeledStatement", 65],
-  ["LogicalExpression", 66],
-  ["MemberExpression", 67],
-  ["MetaProperty", 68],
-  ["MethodDefinition", 69],
-  ["NewExpression", 70],
-  ["ObjectExpression", 71],
-  ["ObjectPattern", 72],
-  ["ParenthesizedExpression", 73],
-  ["Program", 74],
-  ["Property", 75],
-  ["PropertyDefinition", 76],
-  ["RestElement", 77],
-  ["ReturnStatement", 78],
-  ["SequenceExpression", 79],
-  ["SpreadElement", 80],
-  ["StaticBlock", 81],
-  ["SwitchCase", 82],
-  ["SwitchStatement", 83],
-  ["TaggedTemplateExpression", 84],
-  ["TemplateLiteral", 85],
-  ["ThrowStatement", 86],
-  ["TryStatement", 87],
-  ["UnaryExpression", 88],
-  ["UpdateExpression", 89],
-  ["V8IntrinsicExpression", 90],
-  ["VariableDeclaration", 91],
-  ["VariableDeclarator", 92],
-  ["WhileStatement", 93],
-  ["WithStatement", 94],
-  ["YieldExpression", 95],
-  ["JSXAttribute", 96],
-  ["JSXClosingElement", 97],
-  ["JSXElement", 98],
-  ["JSXExpressionContainer", 99],
-  ["JSXFragment", 100],
-  ["JSXMemberExpression", 101],
-  ["JSXNamespacedName", 102],
-  ["JSXOpeningElement", 103],
-  ["JSXSpreadAttribute", 104],
-  ["JSXSpreadChild", 105],
-  ["TSAbstractAccessorProperty", 106],
-  ["TSAbstractMethodDefinition", 107],
-  ["TSAbstractPropertyDefinition", 108],
-  ["TSArrayType", 109],
-  ["TSAsExpression", 110],
-  ["TSCallSignatureDeclaration", 111],
-  ["TSClassImplements", 112],
-  ["TSConditionalType", 113],
-  ["TSConstructSignatureDeclaration", 114],
-  ["TSConstructorType", 115],
-  ["TSDeclareFunction", 116],
-  ["TSEmptyBodyFunctionExpression", 117],
-  ["TSEnumBody", 118],
-  ["TSEnumDeclaration", 119],
-  ["TSEnumMember", 120],
-  ["TSExportAssignment", 121],
-  ["TSExternalModuleReference", 122],
-  ["TSFunctionType", 123],
-  ["TSImportEqualsDeclaration", 124],
-  ["TSImportType", 125],
-  ["TSIndexSignature", 126],
-  ["TSIndexedAccessType", 127],
-  ["TSInferType", 128],
-  ["TSInstantiationExpression", 129],
-  ["TSInterfaceBody", 130],
-  ["TSInterfaceDeclaration", 131],
-  ["TSInterfaceHeritage", 132],
-  ["TSIntersectionType", 133],
-  ["TSJSDocNonNullableType", 134],
-  ["TSJSDocNullableType", 135],
-  ["TSLiteralType", 136],
-  ["TSMappedType", 137],
-  ["TSMethodSignature", 138],
-  ["TSModuleBlock", 139],
-  ["TSModuleDeclaration", 140],
-  ["TSNamedTupleMember", 141],
-  ["TSNamespaceExportDeclaration", 142],
-  ["TSNonNullExpression", 143],
-  ["TSOptionalType", 144],
-  ["TSParameterProperty", 145],
-  ["TSParenthesizedType", 146],
-  ["TSPropertySignature", 147],
-  ["TSQualifiedName", 148],
-  ["TSRestType", 149],
-  ["TSSatisfiesExpression", 150],
-  ["TSTemplateLiteralType", 151],
-  ["TSTupleType", 152],
-  ["TSTypeAliasDeclaration", 153],
-  ["TSTypeAnnotation", 154],
-  ["TSTypeAssertion", 155],
-  ["TSTypeLiteral", 156],
-  ["TSTypeOperator", 157],
-  ["TSTypeParameter", 158],
-  ["TSTypeParameterDeclaration", 159],
-  ["TSTypeParameterInstantiation", 160],
-  ["TSTypePredicate", 161],
-  ["TSTypeQuery", 162],
-  ["TSTypeReference", 163],
-  ["TSUnionType", 164],
-  ["onCodePathStart", 165],
-  ["onCodePathEnd", 166],
-  ["onCodePathSegmentStart", 167],
-  ["onCodePathSegmentEnd", 168],
-  ["onUnreachableCodePathSegmentStart", 169],
-  ["onUnreachableCodePathSegmentEnd", 170],
-  ["onCodePathSegmentLoop", 171],
+  ["ErrorNode", 2],
+  ["Literal", 3],
+  ["PrivateIdentifier", 4],
+  ["Super", 5],
+  ["TemplateElement", 6],
+  ["ThisExpression", 7],
+  ["JSXClosingFragment", 8],
+  ["JSXEmptyExpression", 9],
+  ["JSXIdentifier", 10],
+  ["JSXOpeningFragment", 11],
+  ["JSXText", 12],
+  ["TSAnyKeyword", 13],
+  ["TSBigIntKeyword", 14],
+  ["TSBooleanKeyword", 15],
+  ["TSIntrinsicKeyword", 16],
+  ["TSJSDocUnknownType", 17],
+  ["TSNeverKeyword", 18],
+  ["TSNullKeyword", 19],
+  ["TSNumberKeyword", 20],
+  ["TSObjectKeyword", 21],
+  ["TSStringKeyword", 22],
+  ["TSSymbolKeyword", 23],
+  ["TSThisType", 24],
+  ["TSUndefinedKeyword", 25],
+  ["TSUnknownKeyword", 26],
+  ["TSVoidKeyword", 27],
+  ["AccessorProperty", 28],
+  ["ArrayExpression", 29],
+  ["ArrayPattern", 30],
+  ["ArrowFunctionExpression", 31],
+  ["AssignmentExpression", 32],
+  ["AssignmentPattern", 33],
+  ["AwaitExpression", 34],
+  ["BinaryExpression", 35],
+  ["BlockStatement", 36],
+  ["BreakStatement", 37],
+  ["CallExpression", 38],
+  ["CatchClause", 39],
+  ["ChainExpression", 40],
+  ["ClassBody", 41],
+  ["ClassDeclaration", 42],
+  ["ClassExpression", 43],
+  ["ConditionalExpression", 44],
+  ["ContinueStatement", 45],
+  ["Decorator", 46],
+  ["DoWhileStatement", 47],
+  ["ExportAllDeclaration", 48],
+  ["ExportDefaultDeclaration", 49],
+  ["ExportNamedDeclaration", 50],
+  ["ExportSpecifier", 51],
+  ["ExpressionStatement", 52],
+  ["ForInStatement", 53],
+  ["ForOfStatement", 54],
+  ["ForStatement", 55],
+  ["FunctionDeclaration", 56],
+  ["FunctionExpression", 57],
+  ["Identifier", 58],
+  ["IfStatement", 59],
+  ["ImportAttribute", 60],
+  ["ImportDeclaration", 61],
+  ["ImportDefaultSpecifier", 62],
+  ["ImportExpression", 63],
+  ["ImportNamespaceSpecifier", 64],
+  ["ImportSpecifier", 65],
+  ["LabeledStatement", 66],
+  ["LogicalExpression", 67],
+  ["MemberExpression", 68],
+  ["MetaProperty", 69],
+  ["MethodDefinition", 70],
+  ["NewExpression", 71],
+  ["ObjectExpression", 72],
+  ["ObjectPattern", 73],
+  ["ParenthesizedExpression", 74],
+  ["Program", 75],
+  ["Property", 76],
+  ["PropertyDefinition", 77],
+  ["RestElement", 78],
+  ["ReturnStatement", 79],
+  ["SequenceExpression", 80],
+  ["SpreadElement", 81],
+  ["StaticBlock", 82],
+  ["SwitchCase", 83],
+  ["SwitchStatement", 84],
+  ["TaggedTemplateExpression", 85],
+  ["TemplateLiteral", 86],
+  ["ThrowStatement", 87],
+  ["TryStatement", 88],
+  ["UnaryExpression", 89],
+  ["UpdateExpression", 90],
+  ["V8IntrinsicExpression", 91],
+  ["VariableDeclaration", 92],
+  ["VariableDeclarator", 93],
+  ["WhileStatement", 94],
+  ["WithStatement", 95],
+  ["YieldExpression", 96],
+  ["JSXAttribute", 97],
+  ["JSXClosingElement", 98],
+  ["JSXElement", 99],
+  ["JSXExpressionContainer", 100],
+  ["JSXFragment", 101],
+  ["JSXMemberExpression", 102],
+  ["JSXNamespacedName", 103],
+  ["JSXOpeningElement", 104],
+  ["JSXSpreadAttribute", 105],
+  ["JSXSpreadChild", 106],
+  ["TSAbstractAccessorProperty", 107],
+  ["TSAbstractMethodDefinition", 108],
+  ["TSAbstractPropertyDefinition", 109],
+  ["TSArrayType", 110],
+  ["TSAsExpression", 111],
+  ["TSCallSignatureDeclaration", 112],
+  ["TSClassImplements", 113],
+  ["TSConditionalType", 114],
+  ["TSConstructSignatureDeclaration", 115],
+  ["TSConstructorType", 116],
+  ["TSDeclareFunction", 117],
+  ["TSEmptyBodyFunctionExpression", 118],
+  ["TSEnumBody", 119],
+  ["TSEnumDeclaration", 120],
+  ["TSEnumMember", 121],
+  ["TSExportAssignment", 122],
+  ["TSExternalModuleReference", 123],
+  ["TSFunctionType", 124],
+  ["TSImportEqualsDeclaration", 125],
+  ["TSImportType", 126],
+  ["TSIndexSignature", 127],
+  ["TSIndexedAccessType", 128],
+  ["TSInferType", 129],
+  ["TSInstantiationExpression", 130],
+  ["TSInterfaceBody", 131],
+  ["TSInterfaceDeclaration", 132],
+  ["TSInterfaceHeritage", 133],
+  ["TSIntersectionType", 134],
+  ["TSJSDocNonNullableType", 135],
+  ["TSJSDocNullableType", 136],
+  ["TSLiteralType", 137],
+  ["TSMappedType", 138],
+  ["TSMethodSignature", 139],
+  ["TSModuleBlock", 140],
+  ["TSModuleDeclaration", 141],
+  ["TSNamedTupleMember", 142],
+  ["TSNamespaceExportDeclaration", 143],
+  ["TSNonNullExpression", 144],
+  ["TSOptionalType", 145],
+  ["TSParameterProperty", 146],
+  ["TSParenthesizedType", 147],
+  ["TSPropertySignature", 148],
+  ["TSQualifiedName", 149],
+  ["TSRestType", 150],
+  ["TSSatisfiesExpression", 151],
+  ["TSTemplateLiteralType", 152],
+  ["TSTupleType", 153],
+  ["TSTypeAliasDeclaration", 154],
+  ["TSTypeAnnotation", 155],
+  ["TSTypeAssertion", 156],
+  ["TSTypeLiteral", 157],
+  ["TSTypeOperator", 158],
+  ["TSTypeParameter", 159],
+  ["TSTypeParameterDeclaration", 160],
+  ["TSTypeParameterInstantiation", 161],
+  ["TSTypePredicate", 162],
+  ["TSTypeQuery", 163],
+  ["TSTypeReference", 164],
+  ["TSUnionType", 165],
+  ["onCodePathStart", 166],
+  ["onCodePathEnd", 167],
+  ["onCodePathSegmentStart", 168],
+  ["onCodePathSegmentEnd", 169],
+  ["onUnreachableCodePathSegmentStart", 170],
+  ["onUnreachableCodePathSegmentEnd", 171],
+  ["onCodePathSegmentLoop", 172],
 ]);
 
 /** Count of all node types (both leaf and non-leaf nodes) */
-export const NODE_TYPES_COUNT = 165;
+export const NODE_TYPES_COUNT = 166;
 
 /** Count of leaf node types */
-export const LEAF_NODE_TYPES_COUNT = 27;
+export const LEAF_NODE_TYPES_COUNT = 28;
 
 /** Total count of node types and CFG events */
-export const TYPE_IDS_COUNT = 172;
+export const TYPE_IDS_COUNT = 173;
 
 /** Type IDs which match `:statement` selector class */
 export const STATEMENT_NODE_TYPE_IDS = [
-  0, 1, 35, 36, 41, 44, 46, 47, 48, 49, 51, 52, 53, 54, 55, 58, 60, 65, 78, 83, 86, 87, 91, 93, 94,
-  111, 114, 119, 124, 131, 140, 142, 153, 159,
+  0, 1, 36, 37, 42, 45, 47, 48, 49, 50, 52, 53, 54, 55, 56, 59, 61, 66, 79, 84, 87, 88, 92, 94, 95,
+  112, 115, 120, 125, 132, 141, 143, 154, 160,
 ];
 
 /** Type IDs which match `:declaration` selector class */
 export const DECLARATION_NODE_TYPE_IDS = [
-  41, 47, 48, 49, 55, 60, 91, 111, 114, 119, 124, 131, 140, 142, 153, 159,
+  42, 48, 49, 50, 56, 61, 92, 112, 115, 120, 125, 132, 141, 143, 154, 160,
 ];
 
 /**
@@ -202,8 +203,8 @@ export const DECLARATION_NODE_TYPE_IDS = [
  * Only *may* match because `Identifier` nodes only match this class if their parent is not a `MetaProperty`.
  */
 export const PATTERN_NODE_TYPE_IDS = [
-  2, 6, 8, 28, 29, 30, 31, 32, 33, 34, 37, 39, 42, 43, 56, 57, 62, 66, 67, 68, 70, 71, 72, 73, 79,
-  84, 85, 88, 89, 90, 95, 101, 110, 117, 129, 143, 150, 156,
+  3, 7, 9, 29, 30, 31, 32, 33, 34, 35, 38, 40, 43, 44, 57, 58, 63, 67, 68, 69, 71, 72, 73, 74, 80,
+  85, 86, 89, 90, 91, 96, 102, 111, 118, 130, 144, 151, 157,
 ];
 
 /**
@@ -211,9 +212,9 @@ export const PATTERN_NODE_TYPE_IDS = [
  * Only *may* match because `Identifier` nodes only match this class if their parent is not a `MetaProperty`.
  */
 export const EXPRESSION_NODE_TYPE_IDS = [
-  2, 6, 8, 28, 30, 31, 33, 34, 37, 39, 42, 43, 56, 57, 62, 66, 67, 68, 70, 71, 73, 79, 84, 85, 88,
-  89, 90, 95, 101, 110, 117, 129, 143, 150, 156,
+  3, 7, 9, 29, 31, 32, 34, 35, 38, 40, 43, 44, 57, 58, 63, 67, 68, 69, 71, 72, 74, 80, 85, 86, 89,
+  90, 91, 96, 102, 111, 118, 130, 144, 151, 157,
 ];
 
 /** Type IDs which match `:function` selector class */
-export const FUNCTION_NODE_TYPE_IDS = [30, 55, 56];
+export const FUNCTION_NODE_TYPE_IDS = [31, 56, 57];
@@ -57,6 +57,9 @@ export type Expression =
   | TSNonNullExpression
   | TSInstantiationExpression
   | V8IntrinsicExpression
+  | FlowTypeCastExpression
+  | PipelineExpression
+  | TopicReference
   | MemberExpression;
 
 export interface IdentifierName extends Span {
@@ -114,7 +117,7 @@ export interface ObjectExpression extends Span {
   parent: Node;
 }
 
-export type ObjectPropertyKind = ObjectProperty | SpreadElement;
+export type ObjectPropertyKind = ObjectProperty | SpreadElement | ErrorNode;
 
 export interface ObjectProperty extends Span {
   type: "Property";
@@ -409,6 +412,7 @@ export type Statement =
   | TryStatement
   | WhileStatement
   | WithStatement
+  | ErrorNode
   | Declaration
   | ModuleDeclaration;
 
@@ -440,7 +444,8 @@ export type Declaration =
   | TSEnumDeclaration
   | TSModuleDeclaration
   | TSGlobalDeclaration
-  | TSImportEqualsDeclaration;
+  | TSImportEqualsDeclaration
+  | FlowOpaqueTypeDeclaration;
 
 export interface VariableDeclaration extends Span {
   type: "VariableDeclaration";
@@ -748,7 +753,8 @@ export type ClassElement =
   | MethodDefinition
   | PropertyDefinition
   | AccessorProperty
-  | TSIndexSignature;
+  | TSIndexSignature
+  | ErrorNode;
 
 export interface MethodDefinition extends Span {
   type: MethodDefinitionType;
@@ -925,6 +931,23 @@ export interface V8IntrinsicExpression extends Span {
   parent: Node;
 }
 
+export interface PipelineExpression extends Span {
+  type: "PipelineExpression";
+  head: Expression;
+  body: Expression;
+  parent: Node;
+}
+
+export interface TopicReference extends Span {
+  type: "TopicReference";
+  parent: Node;
+}
+
+export interface ErrorNode extends Span {
+  type: "ErrorNode";
+  parent: Node;
+}
+
 export interface BooleanLiteral extends Span {
   type: "Literal";
   value: boolean;
@@ -1656,7 +1679,7 @@ export interface TSInstantiationExpression extends Span {
   parent: Node;
 }
 
-export type ImportOrExportKind = "value" | "type";
+export type ImportOrExportKind = "value" | "type" | "typeof";
 
 export interface JSDocNullableType extends Span {
   type: "TSJSDocNullableType";
@@ -1677,6 +1700,23 @@ export interface JSDocUnknownType extends Span {
   parent: Node;
 }
 
+export interface FlowTypeCastExpression extends Span {
+  type: "FlowTypeCastExpression";
+  expression: Expression;
+  typeAnnotation: TSTypeAnnotation;
+  parent: Node;
+}
+
+export interface FlowOpaqueTypeDeclaration extends Span {
+  type: "FlowOpaqueTypeDeclaration";
+  id: BindingIdentifier;
+  typeParameters: TSTypeParameterDeclaration | null;
+  supertype: TSType | null;
+  impltype: TSType | null;
+  declare: boolean;
+  parent: Node;
+}
+
 export type ModuleKind = "script" | "module" | "commonjs";
 
 export type AssignmentOperator =
@@ -1816,6 +1856,9 @@ export type Node =
   | ExportAllDeclaration
   | ExportSpecifier
   | V8IntrinsicExpression
+  | PipelineExpression
+  | TopicReference
+  | ErrorNode
   | BooleanLiteral
   | NullLiteral
   | NumericLiteral
@@ -1910,4 +1953,6 @@ export type Node =
   | JSDocNullableType
   | JSDocNonNullableType
   | JSDocUnknownType
+  | FlowTypeCastExpression
+  | FlowOpaqueTypeDeclaration
   | ParamPattern;
@@ -14,6 +14,8 @@ interface StrictVisitorObject {
   "DebuggerStatement:exit"?: (node: ESTree.DebuggerStatement) => void;
   EmptyStatement?: (node: ESTree.EmptyStatement) => void;
   "EmptyStatement:exit"?: (node: ESTree.EmptyStatement) => void;
+  ErrorNode?: (node: ESTree.ErrorNode) => void;
+  "ErrorNode:exit"?: (node: ESTree.ErrorNode) => void;
   Literal?: (
     node:
       | ESTree.BooleanLiteral
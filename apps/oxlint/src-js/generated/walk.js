@@ -30,6 +30,9 @@ function walkNode(node, visitors) {
       case "EmptyStatement":
         walkEmptyStatement(node, visitors);
         break;
+      case "ErrorNode":
+        walkErrorNode(node, visitors);
+        break;
       case "Literal":
         walkLiteral(node, visitors);
         break;
@@ -532,133 +535,138 @@ function walkEmptyStatement(node, visitors) {
   visit !== null && visit(node);
 }
 
-function walkLiteral(node, visitors) {
+function walkErrorNode(node, visitors) {
   let visit = visitors[2];
   visit !== null && visit(node);
 }
 
-function walkPrivateIdentifier(node, visitors) {
+function walkLiteral(node, visitors) {
   let visit = visitors[3];
   visit !== null && visit(node);
 }
 
-function walkSuper(node, visitors) {
+function walkPrivateIdentifier(node, visitors) {
   let visit = visitors[4];
   visit !== null && visit(node);
 }
 
-function walkTemplateElement(node, visitors) {
+function walkSuper(node, visitors) {
   let visit = visitors[5];
   visit !== null && visit(node);
 }
 
-function walkThisExpression(node, visitors) {
+function walkTemplateElement(node, visitors) {
   let visit = visitors[6];
   visit !== null && visit(node);
 }
 
-function walkJSXClosingFragment(node, visitors) {
+function walkThisExpression(node, visitors) {
   let visit = visitors[7];
   visit !== null && visit(node);
 }
 
-function walkJSXEmptyExpression(node, visitors) {
+function walkJSXClosingFragment(node, visitors) {
   let visit = visitors[8];
   visit !== null && visit(node);
 }
 
-function walkJSXIdentifier(node, visitors) {
+function walkJSXEmptyExpression(node, visitors) {
   let visit = visitors[9];
   visit !== null && visit(node);
 }
 
-function walkJSXOpeningFragment(node, visitors) {
+function walkJSXIdentifier(node, visitors) {
   let visit = visitors[10];
   visit !== null && visit(node);
 }
 
-function walkJSXText(node, visitors) {
+function walkJSXOpeningFragment(node, visitors) {
   let visit = visitors[11];
   visit !== null && visit(node);
 }
 
-function walkTSAnyKeyword(node, visitors) {
+function walkJSXText(node, visitors) {
   let visit = visitors[12];
   visit !== null && visit(node);
 }
 
-function walkTSBigIntKeyword(node, visitors) {
+function walkTSAnyKeyword(node, visitors) {
   let visit = visitors[13];
   visit !== null && visit(node);
 }
 
-function walkTSBooleanKeyword(node, visitors) {
+function walkTSBigIntKeyword(node, visitors) {
   let visit = visitors[14];
   visit !== null && visit(node);
 }
 
-function walkTSIntrinsicKeyword(node, visitors) {
+function walkTSBooleanKeyword(node, visitors) {
   let visit = visitors[15];
   visit !== null && visit(node);
 }
 
-function walkTSJSDocUnknownType(node, visitors) {
+function walkTSIntrinsicKeyword(node, visitors) {
   let visit = visitors[16];
   visit !== null && visit(node);
 }
 
-function walkTSNeverKeyword(node, visitors) {
+function walkTSJSDocUnknownType(node, visitors) {
   let visit = visitors[17];
   visit !== null && visit(node);
 }
 
-function walkTSNullKeyword(node, visitors) {
+function walkTSNeverKeyword(node, visitors) {
   let visit = visitors[18];
   visit !== null && visit(node);
 }
 
-function walkTSNumberKeyword(node, visitors) {
+function walkTSNullKeyword(node, visitors) {
   let visit = visitors[19];
   visit !== null && visit(node);
 }
 
-function walkTSObjectKeyword(node, visitors) {
+function walkTSNumberKeyword(node, visitors) {
   let visit = visitors[20];
   visit !== null && visit(node);
 }
 
-function walkTSStringKeyword(node, visitors) {
+function walkTSObjectKeyword(node, visitors) {
   let visit = visitors[21];
   visit !== null && visit(node);
 }
 
-function walkTSSymbolKeyword(node, visitors) {
+function walkTSStringKeyword(node, visitors) {
   let visit = visitors[22];
   visit !== null && visit(node);
 }
 
-function walkTSThisType(node, visitors) {
+function walkTSSymbolKeyword(node, visitors) {
   let visit = visitors[23];
   visit !== null && visit(node);
 }
 
-function walkTSUndefinedKeyword(node, visitors) {
+function walkTSThisType(node, visitors) {
   let visit = visitors[24];
   visit !== null && visit(node);
 }
 
-function walkTSUnknownKeyword(node, visitors) {
+function walkTSUndefinedKeyword(node, visitors) {
   let visit = visitors[25];
   visit !== null && visit(node);
 }
 
-function walkTSVoidKeyword(node, visitors) {
+function walkTSUnknownKeyword(node, visitors) {
   let visit = visitors[26];
   visit !== null && visit(node);
 }
 
+function walkTSVoidKeyword(node, visitors) {
+  let visit = visitors[27];
+  visit !== null && visit(node);
+}
+
 function walkAccessorProperty(node, visitors) {
-  let enterExit = visitors[27],
+  let enterExit = visitors[28],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -677,7 +685,7 @@ function walkAccessorProperty(node, visitors) {
 }
 
 function walkArrayExpression(node, visitors) {
-  let enterExit = visitors[28],
+  let enterExit = visitors[29],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -693,7 +701,7 @@ function walkArrayExpression(node, visitors) {
 }
 
 function walkArrayPattern(node, visitors) {
-  let enterExit = visitors[29],
+  let enterExit = visitors[30],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -711,7 +719,7 @@ function walkArrayPattern(node, visitors) {
 }
 
 function walkArrowFunctionExpression(node, visitors) {
-  let enterExit = visitors[30],
+  let enterExit = visitors[31],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -730,7 +738,7 @@ function walkArrowFunctionExpression(node, visitors) {
 }
 
 function walkAssignmentExpression(node, visitors) {
-  let enterExit = visitors[31],
+  let enterExit = visitors[32],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -747,7 +755,7 @@ function walkAssignmentExpression(node, visitors) {
 }
 
 function walkAssignmentPattern(node, visitors) {
-  let enterExit = visitors[32],
+  let enterExit = visitors[33],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -766,7 +774,7 @@ function walkAssignmentPattern(node, visitors) {
 }
 
 function walkAwaitExpression(node, visitors) {
-  let enterExit = visitors[33],
+  let enterExit = visitors[34],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -782,7 +790,7 @@ function walkAwaitExpression(node, visitors) {
 }
 
 function walkBinaryExpression(node, visitors) {
-  let enterExit = visitors[34],
+  let enterExit = visitors[35],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -799,7 +807,7 @@ function walkBinaryExpression(node, visitors) {
 }
 
 function walkBlockStatement(node, visitors) {
-  let enterExit = visitors[35],
+  let enterExit = visitors[36],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -815,7 +823,7 @@ function walkBlockStatement(node, visitors) {
 }
 
 function walkBreakStatement(node, visitors) {
-  let enterExit = visitors[36],
+  let enterExit = visitors[37],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -831,7 +839,7 @@ function walkBreakStatement(node, visitors) {
 }
 
 function walkCallExpression(node, visitors) {
-  let enterExit = visitors[37],
+  let enterExit = visitors[38],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -849,7 +857,7 @@ function walkCallExpression(node, visitors) {
 }
 
 function walkCatchClause(node, visitors) {
-  let enterExit = visitors[38],
+  let enterExit = visitors[39],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -866,7 +874,7 @@ function walkCatchClause(node, visitors) {
 }
 
 function walkChainExpression(node, visitors) {
-  let enterExit = visitors[39],
+  let enterExit = visitors[40],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -882,7 +890,7 @@ function walkChainExpression(node, visitors) {
 }
 
 function walkClassBody(node, visitors) {
-  let enterExit = visitors[40],
+  let enterExit = visitors[41],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -898,7 +906,7 @@ function walkClassBody(node, visitors) {
 }
 
 function walkClassDeclaration(node, visitors) {
-  let enterExit = visitors[41],
+  let enterExit = visitors[42],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -920,7 +928,7 @@ function walkClassDeclaration(node, visitors) {
 }
 
 function walkClassExpression(node, visitors) {
-  let enterExit = visitors[42],
+  let enterExit = visitors[43],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -942,7 +950,7 @@ function walkClassExpression(node, visitors) {
 }
 
 function walkConditionalExpression(node, visitors) {
-  let enterExit = visitors[43],
+  let enterExit = visitors[44],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -960,7 +968,7 @@ function walkConditionalExpression(node, visitors) {
 }
 
 function walkContinueStatement(node, visitors) {
-  let enterExit = visitors[44],
+  let enterExit = visitors[45],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -976,7 +984,7 @@ function walkContinueStatement(node, visitors) {
 }
 
 function walkDecorator(node, visitors) {
-  let enterExit = visitors[45],
+  let enterExit = visitors[46],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -992,7 +1000,7 @@ function walkDecorator(node, visitors) {
 }
 
 function walkDoWhileStatement(node, visitors) {
-  let enterExit = visitors[46],
+  let enterExit = visitors[47],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1009,7 +1017,7 @@ function walkDoWhileStatement(node, visitors) {
 }
 
 function walkExportAllDeclaration(node, visitors) {
-  let enterExit = visitors[47],
+  let enterExit = visitors[48],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1027,7 +1035,7 @@ function walkExportAllDeclaration(node, visitors) {
 }
 
 function walkExportDefaultDeclaration(node, visitors) {
-  let enterExit = visitors[48],
+  let enterExit = visitors[49],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1043,7 +1051,7 @@ function walkExportDefaultDeclaration(node, visitors) {
 }
 
 function walkExportNamedDeclaration(node, visitors) {
-  let enterExit = visitors[49],
+  let enterExit = visitors[50],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1062,7 +1070,7 @@ function walkExportNamedDeclaration(node, visitors) {
 }
 
 function walkExportSpecifier(node, visitors) {
-  let enterExit = visitors[50],
+  let enterExit = visitors[51],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1079,7 +1087,7 @@ function walkExportSpecifier(node, visitors) {
 }
 
 function walkExpressionStatement(node, visitors) {
-  let enterExit = visitors[51],
+  let enterExit = visitors[52],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1095,7 +1103,7 @@ function walkExpressionStatement(node, visitors) {
 }
 
 function walkForInStatement(node, visitors) {
-  let enterExit = visitors[52],
+  let enterExit = visitors[53],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1113,7 +1121,7 @@ function walkForInStatement(node, visitors) {
 }
 
 function walkForOfStatement(node, visitors) {
-  let enterExit = visitors[53],
+  let enterExit = visitors[54],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1131,7 +1139,7 @@ function walkForOfStatement(node, visitors) {
 }
 
 function walkForStatement(node, visitors) {
-  let enterExit = visitors[54],
+  let enterExit = visitors[55],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1150,7 +1158,7 @@ function walkForStatement(node, visitors) {
 }
 
 function walkFunctionDeclaration(node, visitors) {
-  let enterExit = visitors[55],
+  let enterExit = visitors[56],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1170,7 +1178,7 @@ function walkFunctionDeclaration(node, visitors) {
 }
 
 function walkFunctionExpression(node, visitors) {
-  let enterExit = visitors[56],
+  let enterExit = visitors[57],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1190,7 +1198,7 @@ function walkFunctionExpression(node, visitors) {
 }
 
 function walkIdentifier(node, visitors) {
-  let enterExit = visitors[57],
+  let enterExit = visitors[58],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1207,7 +1215,7 @@ function walkIdentifier(node, visitors) {
 }
 
 function walkIfStatement(node, visitors) {
-  let enterExit = visitors[58],
+  let enterExit = visitors[59],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1225,7 +1233,7 @@ function walkIfStatement(node, visitors) {
 }
 
 function walkImportAttribute(node, visitors) {
-  let enterExit = visitors[59],
+  let enterExit = visitors[60],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1242,7 +1250,7 @@ function walkImportAttribute(node, visitors) {
 }
 
 function walkImportDeclaration(node, visitors) {
-  let enterExit = visitors[60],
+  let enterExit = visitors[61],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1260,7 +1268,7 @@ function walkImportDeclaration(node, visitors) {
 }
 
 function walkImportDefaultSpecifier(node, visitors) {
-  let enterExit = visitors[61],
+  let enterExit = visitors[62],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1276,7 +1284,7 @@ function walkImportDefaultSpecifier(node, visitors) {
 }
 
 function walkImportExpression(node, visitors) {
-  let enterExit = visitors[62],
+  let enterExit = visitors[63],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1293,7 +1301,7 @@ function walkImportExpression(node, visitors) {
 }
 
 function walkImportNamespaceSpecifier(node, visitors) {
-  let enterExit = visitors[63],
+  let enterExit = visitors[64],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1309,7 +1317,7 @@ function walkImportNamespaceSpecifier(node, visitors) {
 }
 
 function walkImportSpecifier(node, visitors) {
-  let enterExit = visitors[64],
+  let enterExit = visitors[65],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1326,7 +1334,7 @@ function walkImportSpecifier(node, visitors) {
 }
 
 function walkLabeledStatement(node, visitors) {
-  let enterExit = visitors[65],
+  let enterExit = visitors[66],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1343,7 +1351,7 @@ function walkLabeledStatement(node, visitors) {
 }
 
 function walkLogicalExpression(node, visitors) {
-  let enterExit = visitors[66],
+  let enterExit = visitors[67],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1360,7 +1368,7 @@ function walkLogicalExpression(node, visitors) {
 }
 
 function walkMemberExpression(node, visitors) {
-  let enterExit = visitors[67],
+  let enterExit = visitors[68],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1377,7 +1385,7 @@ function walkMemberExpression(node, visitors) {
 }
 
 function walkMetaProperty(node, visitors) {
-  let enterExit = visitors[68],
+  let enterExit = visitors[69],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1394,7 +1402,7 @@ function walkMetaProperty(node, visitors) {
 }
 
 function walkMethodDefinition(node, visitors) {
-  let enterExit = visitors[69],
+  let enterExit = visitors[70],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1412,7 +1420,7 @@ function walkMethodDefinition(node, visitors) {
 }
 
 function walkNewExpression(node, visitors) {
-  let enterExit = visitors[70],
+  let enterExit = visitors[71],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1430,7 +1438,7 @@ function walkNewExpression(node, visitors) {
 }
 
 function walkObjectExpression(node, visitors) {
-  let enterExit = visitors[71],
+  let enterExit = visitors[72],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1446,7 +1454,7 @@ function walkObjectExpression(node, visitors) {
 }
 
 function walkObjectPattern(node, visitors) {
-  let enterExit = visitors[72],
+  let enterExit = visitors[73],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1464,7 +1472,7 @@ function walkObjectPattern(node, visitors) {
 }
 
 function walkParenthesizedExpression(node, visitors) {
-  let enterExit = visitors[73],
+  let enterExit = visitors[74],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1480,7 +1488,7 @@ function walkParenthesizedExpression(node, visitors) {
 }
 
 function walkProgram(node, visitors) {
-  let enterExit = visitors[74],
+  let enterExit = visitors[75],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1496,7 +1504,7 @@ function walkProgram(node, visitors) {
 }
 
 function walkProperty(node, visitors) {
-  let enterExit = visitors[75],
+  let enterExit = visitors[76],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1513,7 +1521,7 @@ function walkProperty(node, visitors) {
 }
 
 function walkPropertyDefinition(node, visitors) {
-  let enterExit = visitors[76],
+  let enterExit = visitors[77],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1532,7 +1540,7 @@ function walkPropertyDefinition(node, visitors) {
 }
 
 function walkRestElement(node, visitors) {
-  let enterExit = visitors[77],
+  let enterExit = visitors[78],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1550,7 +1558,7 @@ function walkRestElement(node, visitors) {
 }
 
 function walkReturnStatement(node, visitors) {
-  let enterExit = visitors[78],
+  let enterExit = visitors[79],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1566,7 +1574,7 @@ function walkReturnStatement(node, visitors) {
 }
 
 function walkSequenceExpression(node, visitors) {
-  let enterExit = visitors[79],
+  let enterExit = visitors[80],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1582,7 +1590,7 @@ function walkSequenceExpression(node, visitors) {
 }
 
 function walkSpreadElement(node, visitors) {
-  let enterExit = visitors[80],
+  let enterExit = visitors[81],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1598,7 +1606,7 @@ function walkSpreadElement(node, visitors) {
 }
 
 function walkStaticBlock(node, visitors) {
-  let enterExit = visitors[81],
+  let enterExit = visitors[82],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1614,7 +1622,7 @@ function walkStaticBlock(node, visitors) {
 }
 
 function walkSwitchCase(node, visitors) {
-  let enterExit = visitors[82],
+  let enterExit = visitors[83],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1631,7 +1639,7 @@ function walkSwitchCase(node, visitors) {
 }
 
 function walkSwitchStatement(node, visitors) {
-  let enterExit = visitors[83],
+  let enterExit = visitors[84],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1648,7 +1656,7 @@ function walkSwitchStatement(node, visitors) {
 }
 
 function walkTaggedTemplateExpression(node, visitors) {
-  let enterExit = visitors[84],
+  let enterExit = visitors[85],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1666,7 +1674,7 @@ function walkTaggedTemplateExpression(node, visitors) {
 }
 
 function walkTemplateLiteral(node, visitors) {
-  let enterExit = visitors[85],
+  let enterExit = visitors[86],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1683,7 +1691,7 @@ function walkTemplateLiteral(node, visitors) {
 }
 
 function walkThrowStatement(node, visitors) {
-  let enterExit = visitors[86],
+  let enterExit = visitors[87],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1699,7 +1707,7 @@ function walkThrowStatement(node, visitors) {
 }
 
 function walkTryStatement(node, visitors) {
-  let enterExit = visitors[87],
+  let enterExit = visitors[88],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1717,7 +1725,7 @@ function walkTryStatement(node, visitors) {
 }
 
 function walkUnaryExpression(node, visitors) {
-  let enterExit = visitors[88],
+  let enterExit = visitors[89],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1733,7 +1741,7 @@ function walkUnaryExpression(node, visitors) {
 }
 
 function walkUpdateExpression(node, visitors) {
-  let enterExit = visitors[89],
+  let enterExit = visitors[90],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1749,7 +1757,7 @@ function walkUpdateExpression(node, visitors) {
 }
 
 function walkV8IntrinsicExpression(node, visitors) {
-  let enterExit = visitors[90],
+  let enterExit = visitors[91],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1766,7 +1774,7 @@ function walkV8IntrinsicExpression(node, visitors) {
 }
 
 function walkVariableDeclaration(node, visitors) {
-  let enterExit = visitors[91],
+  let enterExit = visitors[92],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1782,7 +1790,7 @@ function walkVariableDeclaration(node, visitors) {
 }
 
 function walkVariableDeclarator(node, visitors) {
-  let enterExit = visitors[92],
+  let enterExit = visitors[93],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1799,7 +1807,7 @@ function walkVariableDeclarator(node, visitors) {
 }
 
 function walkWhileStatement(node, visitors) {
-  let enterExit = visitors[93],
+  let enterExit = visitors[94],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1816,7 +1824,7 @@ function walkWhileStatement(node, visitors) {
 }
 
 function walkWithStatement(node, visitors) {
-  let enterExit = visitors[94],
+  let enterExit = visitors[95],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1833,7 +1841,7 @@ function walkWithStatement(node, visitors) {
 }
 
 function walkYieldExpression(node, visitors) {
-  let enterExit = visitors[95],
+  let enterExit = visitors[96],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1849,7 +1857,7 @@ function walkYieldExpression(node, visitors) {
 }
 
 function walkJSXAttribute(node, visitors) {
-  let enterExit = visitors[96],
+  let enterExit = visitors[97],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1866,7 +1874,7 @@ function walkJSXAttribute(node, visitors) {
 }
 
 function walkJSXClosingElement(node, visitors) {
-  let enterExit = visitors[97],
+  let enterExit = visitors[98],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1882,7 +1890,7 @@ function walkJSXClosingElement(node, visitors) {
 }
 
 function walkJSXElement(node, visitors) {
-  let enterExit = visitors[98],
+  let enterExit = visitors[99],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1900,7 +1908,7 @@ function walkJSXElement(node, visitors) {
 }
 
 function walkJSXExpressionContainer(node, visitors) {
-  let enterExit = visitors[99],
+  let enterExit = visitors[100],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1916,7 +1924,7 @@ function walkJSXExpressionContainer(node, visitors) {
 }
 
 function walkJSXFragment(node, visitors) {
-  let enterExit = visitors[100],
+  let enterExit = visitors[101],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1934,7 +1942,7 @@ function walkJSXFragment(node, visitors) {
 }
 
 function walkJSXMemberExpression(node, visitors) {
-  let enterExit = visitors[101],
+  let enterExit = visitors[102],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1951,7 +1959,7 @@ function walkJSXMemberExpression(node, visitors) {
 }
 
 function walkJSXNamespacedName(node, visitors) {
-  let enterExit = visitors[102],
+  let enterExit = visitors[103],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1968,7 +1976,7 @@ function walkJSXNamespacedName(node, visitors) {
 }
 
 function walkJSXOpeningElement(node, visitors) {
-  let enterExit = visitors[103],
+  let enterExit = visitors[104],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1986,7 +1994,7 @@ function walkJSXOpeningElement(node, visitors) {
 }
 
 function walkJSXSpreadAttribute(node, visitors) {
-  let enterExit = visitors[104],
+  let enterExit = visitors[105],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2002,7 +2010,7 @@ function walkJSXSpreadAttribute(node, visitors) {
 }
 
 function walkJSXSpreadChild(node, visitors) {
-  let enterExit = visitors[105],
+  let enterExit = visitors[106],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2018,7 +2026,7 @@ function walkJSXSpreadChild(node, visitors) {
 }
 
 function walkTSAbstractAccessorProperty(node, visitors) {
-  let enterExit = visitors[106],
+  let enterExit = visitors[107],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2036,7 +2044,7 @@ function walkTSAbstractAccessorProperty(node, visitors) {
 }
 
 function walkTSAbstractMethodDefinition(node, visitors) {
-  let enterExit = visitors[107],
+  let enterExit = visitors[108],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2053,7 +2061,7 @@ function walkTSAbstractMethodDefinition(node, visitors) {
 }
 
 function walkTSAbstractPropertyDefinition(node, visitors) {
-  let enterExit = visitors[108],
+  let enterExit = visitors[109],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2071,7 +2079,7 @@ function walkTSAbstractPropertyDefinition(node, visitors) {
 }
 
 function walkTSArrayType(node, visitors) {
-  let enterExit = visitors[109],
+  let enterExit = visitors[110],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2087,7 +2095,7 @@ function walkTSArrayType(node, visitors) {
 }
 
 function walkTSAsExpression(node, visitors) {
-  let enterExit = visitors[110],
+  let enterExit = visitors[111],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2104,7 +2112,7 @@ function walkTSAsExpression(node, visitors) {
 }
 
 function walkTSCallSignatureDeclaration(node, visitors) {
-  let enterExit = visitors[111],
+  let enterExit = visitors[112],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2122,7 +2130,7 @@ function walkTSCallSignatureDeclaration(node, visitors) {
 }
 
 function walkTSClassImplements(node, visitors) {
-  let enterExit = visitors[112],
+  let enterExit = visitors[113],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2139,7 +2147,7 @@ function walkTSClassImplements(node, visitors) {
 }
 
 function walkTSConditionalType(node, visitors) {
-  let enterExit = visitors[113],
+  let enterExit = visitors[114],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2158,7 +2166,7 @@ function walkTSConditionalType(node, visitors) {
 }
 
 function walkTSConstructSignatureDeclaration(node, visitors) {
-  let enterExit = visitors[114],
+  let enterExit = visitors[115],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2176,7 +2184,7 @@ function walkTSConstructSignatureDeclaration(node, visitors) {
 }
 
 function walkTSConstructorType(node, visitors) {
-  let enterExit = visitors[115],
+  let enterExit = visitors[116],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2194,7 +2202,7 @@ function walkTSConstructorType(node, visitors) {
 }
 
 function walkTSDeclareFunction(node, visitors) {
-  let enterExit = visitors[116],
+  let enterExit = visitors[117],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2214,7 +2222,7 @@ function walkTSDeclareFunction(node, visitors) {
 }
 
 function walkTSEmptyBodyFunctionExpression(node, visitors) {
-  let enterExit = visitors[117],
+  let enterExit = visitors[118],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2233,7 +2241,7 @@ function walkTSEmptyBodyFunctionExpression(node, visitors) {
 }
 
 function walkTSEnumBody(node, visitors) {
-  let enterExit = visitors[118],
+  let enterExit = visitors[119],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2249,7 +2257,7 @@ function walkTSEnumBody(node, visitors) {
 }
 
 function walkTSEnumDeclaration(node, visitors) {
-  let enterExit = visitors[119],
+  let enterExit = visitors[120],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2266,7 +2274,7 @@ function walkTSEnumDeclaration(node, visitors) {
 }
 
 function walkTSEnumMember(node, visitors) {
-  let enterExit = visitors[120],
+  let enterExit = visitors[121],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2283,7 +2291,7 @@ function walkTSEnumMember(node, visitors) {
 }
 
 function walkTSExportAssignment(node, visitors) {
-  let enterExit = visitors[121],
+  let enterExit = visitors[122],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2299,7 +2307,7 @@ function walkTSExportAssignment(node, visitors) {
 }
 
 function walkTSExternalModuleReference(node, visitors) {
-  let enterExit = visitors[122],
+  let enterExit = visitors[123],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2315,7 +2323,7 @@ function walkTSExternalModuleReference(node, visitors) {
 }
 
 function walkTSFunctionType(node, visitors) {
-  let enterExit = visitors[123],
+  let enterExit = visitors[124],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2333,7 +2341,7 @@ function walkTSFunctionType(node, visitors) {
 }
 
 function walkTSImportEqualsDeclaration(node, visitors) {
-  let enterExit = visitors[124],
+  let enterExit = visitors[125],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2350,7 +2358,7 @@ function walkTSImportEqualsDeclaration(node, visitors) {
 }
 
 function walkTSImportType(node, visitors) {
-  let enterExit = visitors[125],
+  let enterExit = visitors[126],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2369,7 +2377,7 @@ function walkTSImportType(node, visitors) {
 }
 
 function walkTSIndexSignature(node, visitors) {
-  let enterExit = visitors[126],
+  let enterExit = visitors[127],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2386,7 +2394,7 @@ function walkTSIndexSignature(node, visitors) {
 }
 
 function walkTSIndexedAccessType(node, visitors) {
-  let enterExit = visitors[127],
+  let enterExit = visitors[128],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2403,7 +2411,7 @@ function walkTSIndexedAccessType(node, visitors) {
 }
 
 function walkTSInferType(node, visitors) {
-  let enterExit = visitors[128],
+  let enterExit = visitors[129],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2419,7 +2427,7 @@ function walkTSInferType(node, visitors) {
 }
 
 function walkTSInstantiationExpression(node, visitors) {
-  let enterExit = visitors[129],
+  let enterExit = visitors[130],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2436,7 +2444,7 @@ function walkTSInstantiationExpression(node, visitors) {
 }
 
 function walkTSInterfaceBody(node, visitors) {
-  let enterExit = visitors[130],
+  let enterExit = visitors[131],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2452,7 +2460,7 @@ function walkTSInterfaceBody(node, visitors) {
 }
 
 function walkTSInterfaceDeclaration(node, visitors) {
-  let enterExit = visitors[131],
+  let enterExit = visitors[132],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2471,7 +2479,7 @@ function walkTSInterfaceDeclaration(node, visitors) {
 }
 
 function walkTSInterfaceHeritage(node, visitors) {
-  let enterExit = visitors[132],
+  let enterExit = visitors[133],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2488,7 +2496,7 @@ function walkTSInterfaceHeritage(node, visitors) {
 }
 
 function walkTSIntersectionType(node, visitors) {
-  let enterExit = visitors[133],
+  let enterExit = visitors[134],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2504,7 +2512,7 @@ function walkTSIntersectionType(node, visitors) {
 }
 
 function walkTSJSDocNonNullableType(node, visitors) {
-  let enterExit = visitors[134],
+  let enterExit = visitors[135],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2520,7 +2528,7 @@ function walkTSJSDocNonNullableType(node, visitors) {
 }
 
 function walkTSJSDocNullableType(node, visitors) {
-  let enterExit = visitors[135],
+  let enterExit = visitors[136],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2536,7 +2544,7 @@ function walkTSJSDocNullableType(node, visitors) {
 }
 
 function walkTSLiteralType(node, visitors) {
-  let enterExit = visitors[136],
+  let enterExit = visitors[137],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2552,7 +2560,7 @@ function walkTSLiteralType(node, visitors) {
 }
 
 function walkTSMappedType(node, visitors) {
-  let enterExit = visitors[137],
+  let enterExit = visitors[138],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2571,7 +2579,7 @@ function walkTSMappedType(node, visitors) {
 }
 
 function walkTSMethodSignature(node, visitors) {
-  let enterExit = visitors[138],
+  let enterExit = visitors[139],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2590,7 +2598,7 @@ function walkTSMethodSignature(node, visitors) {
 }
 
 function walkTSModuleBlock(node, visitors) {
-  let enterExit = visitors[139],
+  let enterExit = visitors[140],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2606,7 +2614,7 @@ function walkTSModuleBlock(node, visitors) {
 }
 
 function walkTSModuleDeclaration(node, visitors) {
-  let enterExit = visitors[140],
+  let enterExit = visitors[141],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2623,7 +2631,7 @@ function walkTSModuleDeclaration(node, visitors) {
 }
 
 function walkTSNamedTupleMember(node, visitors) {
-  let enterExit = visitors[141],
+  let enterExit = visitors[142],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2640,7 +2648,7 @@ function walkTSNamedTupleMember(node, visitors) {
 }
 
 function walkTSNamespaceExportDeclaration(node, visitors) {
-  let enterExit = visitors[142],
+  let enterExit = visitors[143],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2656,7 +2664,7 @@ function walkTSNamespaceExportDeclaration(node, visitors) {
 }
 
 function walkTSNonNullExpression(node, visitors) {
-  let enterExit = visitors[143],
+  let enterExit = visitors[144],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2672,7 +2680,7 @@ function walkTSNonNullExpression(node, visitors) {
 }
 
 function walkTSOptionalType(node, visitors) {
-  let enterExit = visitors[144],
+  let enterExit = visitors[145],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2688,7 +2696,7 @@ function walkTSOptionalType(node, visitors) {
 }
 
 function walkTSParameterProperty(node, visitors) {
-  let enterExit = visitors[145],
+  let enterExit = visitors[146],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2705,7 +2713,7 @@ function walkTSParameterProperty(node, visitors) {
 }
 
 function walkTSParenthesizedType(node, visitors) {
-  let enterExit = visitors[146],
+  let enterExit = visitors[147],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2721,7 +2729,7 @@ function walkTSParenthesizedType(node, visitors) {
 }
 
 function walkTSPropertySignature(node, visitors) {
-  let enterExit = visitors[147],
+  let enterExit = visitors[148],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2738,7 +2746,7 @@ function walkTSPropertySignature(node, visitors) {
 }
 
 function walkTSQualifiedName(node, visitors) {
-  let enterExit = visitors[148],
+  let enterExit = visitors[149],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2755,7 +2763,7 @@ function walkTSQualifiedName(node, visitors) {
 }
 
 function walkTSRestType(node, visitors) {
-  let enterExit = visitors[149],
+  let enterExit = visitors[150],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2771,7 +2779,7 @@ function walkTSRestType(node, visitors) {
 }
 
 function walkTSSatisfiesExpression(node, visitors) {
-  let enterExit = visitors[150],
+  let enterExit = visitors[151],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2788,7 +2796,7 @@ function walkTSSatisfiesExpression(node, visitors) {
 }
 
 function walkTSTemplateLiteralType(node, visitors) {
-  let enterExit = visitors[151],
+  let enterExit = visitors[152],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2805,7 +2813,7 @@ function walkTSTemplateLiteralType(node, visitors) {
 }
 
 function walkTSTupleType(node, visitors) {
-  let enterExit = visitors[152],
+  let enterExit = visitors[153],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2821,7 +2829,7 @@ function walkTSTupleType(node, visitors) {
 }
 
 function walkTSTypeAliasDeclaration(node, visitors) {
-  let enterExit = visitors[153],
+  let enterExit = visitors[154],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2839,7 +2847,7 @@ function walkTSTypeAliasDeclaration(node, visitors) {
 }
 
 function walkTSTypeAnnotation(node, visitors) {
-  let enterExit = visitors[154],
+  let enterExit = visitors[155],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2855,7 +2863,7 @@ function walkTSTypeAnnotation(node, visitors) {
 }
 
 function walkTSTypeAssertion(node, visitors) {
-  let enterExit = visitors[155],
+  let enterExit = visitors[156],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2872,7 +2880,7 @@ function walkTSTypeAssertion(node, visitors) {
 }
 
 function walkTSTypeLiteral(node, visitors) {
-  let enterExit = visitors[156],
+  let enterExit = visitors[157],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2888,7 +2896,7 @@ function walkTSTypeLiteral(node, visitors) {
 }
 
 function walkTSTypeOperator(node, visitors) {
-  let enterExit = visitors[157],
+  let enterExit = visitors[158],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2904,7 +2912,7 @@ function walkTSTypeOperator(node, visitors) {
 }
 
 function walkTSTypeParameter(node, visitors) {
-  let enterExit = visitors[158],
+  let enterExit = visitors[159],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2922,7 +2930,7 @@ function walkTSTypeParameter(node, visitors) {
 }
 
 function walkTSTypeParameterDeclaration(node, visitors) {
-  let enterExit = visitors[159],
+  let enterExit = visitors[160],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2938,7 +2946,7 @@ function walkTSTypeParameterDeclaration(node, visitors) {
 }
 
 function walkTSTypeParameterInstantiation(node, visitors) {
-  let enterExit = visitors[160],
+  let enterExit = visitors[161],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2954,7 +2962,7 @@ function walkTSTypeParameterInstantiation(node, visitors) {
 }
 
 function walkTSTypePredicate(node, visitors) {
-  let enterExit = visitors[161],
+  let enterExit = visitors[162],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2971,7 +2979,7 @@ function walkTSTypePredicate(node, visitors) {
 }
 
 function walkTSTypeQuery(node, visitors) {
-  let enterExit = visitors[162],
+  let enterExit = visitors[163],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2988,7 +2996,7 @@ function walkTSTypeQuery(node, visitors) {
 }
 
 function walkTSTypeReference(node, visitors) {
-  let enterExit = visitors[163],
+  let enterExit = visitors[164],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -3005,7 +3013,7 @@ function walkTSTypeReference(node, visitors) {
 }
 
 function walkTSUnionType(node, visitors) {
-  let enterExit = visitors[164],
+  let enterExit = visitors[165],
     exit = null,
     enter;
   if (enterExit !== null) {
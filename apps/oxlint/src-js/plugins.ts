@@ -24,6 +24,7 @@ export type {
 } from "./plugins/scope.ts";
 export type { Settings } from "./plugins/settings.ts";
 export type { SourceCode } from "./plugins/source_code.ts";
+export type { TypeInfo } from "./plugins/type_info.ts";
 export type {
   Token,
   BooleanToken,
@@ -9,6 +9,7 @@ import { HAS_BOM_FLAG_POS } from "../generated/constants.ts";
 import { typeAssertIs, debugAssert, debugAssertIsNonNull } from "../utils/asserts.ts";
 import { getErrorMessage } from "../utils/utils.ts";
 import { setGlobalsForFile, resetGlobals } from "./globals.ts";
+import { setTypeInfoForFile, resetTypeInfo } from "./type_info.ts";
 import { resetWeakMaps } from "./weak_map.ts";
 import { switchWorkspace } from "./workspace.ts";
 import {
@@ -51,6 +52,7 @@ const OPTIONS_DESCRIPTOR: PropertyDescriptor = { value: null };
  * @param optionsIds - IDs of options to use for rules on this file, in same order as `ruleIds`
  * @param settingsJSON - Settings for this file, as JSON string
  * @param globalsJSON - Globals for this file, as JSON string
+ * @param typeInfoId - ID to get type information for this file with (`null` unless type-aware linting is enabled)
  * @param workspaceUri - Workspace URI (`null` in CLI, string in LSP)
  * @returns Diagnostics or error serialized to JSON string
  */
@@ -62,6 +64,7 @@ export function lintFile(
   optionsIds: number[],
   settingsJSON: string,
   globalsJSON: string,
+  typeInfoId: number | null,
   workspaceUri: string | null,
 ): string | null {
   try {
@@ -73,6 +76,7 @@ export function lintFile(
       optionsIds,
       settingsJSON,
       globalsJSON,
+      typeInfoId,
       workspaceUri,
     );
 
@@ -108,6 +112,7 @@ export function lintFile(
  * @param optionsIds - IDs of options to use for rules on this file, in same order as `ruleIds`
  * @param settingsJSON - Settings for this file, as JSON string
  * @param globalsJSON - Globals for this file, as JSON string
+ * @param typeInfoId - ID to get type information for this file with (`null` unless type-aware linting is enabled)
  * @param workspaceUri - Workspace URI (`null` in CLI, string in LSP)
  * @throws {Error} If any parameters are invalid
  * @throws {*} If any rule throws
@@ -120,6 +125,7 @@ export function lintFileImpl(
   optionsIds: number[],
   settingsJSON: string,
   globalsJSON: string,
+  typeInfoId: number | null,
   workspaceUri: string | null,
 ) {
   // If new buffer, add it to `buffers` array. Otherwise, get existing buffer from array.
@@ -186,9 +192,10 @@ export function lintFileImpl(
   const hasBOM = buffer[HAS_BOM_FLAG_POS] === 1;
   setupSourceForFile(buffer, hasBOM);
 
-  // Pass settings and globals JSON to modules that handle them
+  // Pass settings, globals, and type information ID to modules that handle them
   setSettingsForFile(settingsJSON);
   setGlobalsForFile(globalsJSON);
+  setTypeInfoForFile(typeInfoId);
 
   // Get visitors for this file from all rules
   for (let i = 0, len = ruleIds.length; i < len; i++) {
@@ -311,6 +318,7 @@ export function resetFile() {
   resetSourceAndAst();
   resetSettings();
   resetGlobals();
+  resetTypeInfo();
   resetWeakMaps();
 }
 
@@ -22,6 +22,7 @@ import * as scopeMethods from "./scope.ts";
 import { resetTokens } from "./tokens.ts";
 import * as tokenMethods from "./tokens_methods.ts";
 import { getTokensAndComments, resetTokensAndComments } from "./tokens_and_comments.ts";
+import * as typeInfoMethods from "./type_info.ts";
 import { debugAssertIsNonNull } from "../utils/asserts.ts";
 
 import type { Program } from "../generated/types.d.ts";
@@ -229,9 +230,14 @@ export const SOURCE_CODE = Object.freeze({
   /**
    * Parser services for the file.
    *
-   * Oxlint does not offer any parser services.
+   * When type-aware linting is enabled, offers type information inferred by Oxlint's type checker.
+   * This is not typescript-eslint's API: there is no `program`, so rules which require type information
+   * via `ESLintUtils.getParserServices(context)` still throw typescript-eslint's usual error.
    */
-  parserServices: Object.freeze({} as Record<string, unknown>),
+  parserServices: Object.freeze({
+    getTypeAtLocation: typeInfoMethods.getTypeAtLocation,
+    getSymbolDeclarations: typeInfoMethods.getSymbolDeclarations,
+  }),
 
   /**
    * Source text as array of lines, split according to specification's definition of line breaks.
@@ -0,0 +1,198 @@
+/*
+ * Type information for `sourceCode.parserServices`.
+ */
+
+import { getTypeInfo } from "../bindings.js";
+import visitorKeys from "../generated/keys.ts";
+import { ast, initAst } from "./source_code.ts";
+import { debugAssert, debugAssertIsNonNull } from "../utils/asserts.ts";
+
+import type { Node } from "./types.ts";
+import type { Node as ESTreeNode } from "../generated/types.d.ts";
+
+/**
+ * Type of an expression, as inferred by Oxlint's type checker.
+ */
+export interface TypeInfo {
+  /**
+   * The type, formatted as TypeScript prints it e.g. `Promise<number>`.
+   */
+  text: string;
+  /**
+   * TypeScript's `ts.TypeFlags` for the type.
+   * `boolean` is only `Boolean`, although TypeScript represents it as the union `true | false`.
+   */
+  flags: number;
+  /**
+   * `true` if values of the type may be promises, or other objects with a `then` method.
+   */
+  isPromiseLike: boolean;
+}
+
+/**
+ * Type information for the file being linted, as sent from Rust.
+ *
+ * `expressions` and `declarations` are sorted by `start`, then `end`.
+ */
+interface TypeInfoData {
+  types: TypeInfo[];
+  // `[start, end, typeIndex]`, where `typeIndex` is the index of the expression's type in `types`
+  expressions: [number, number, number][];
+  // `[start, end, declarations]`, where `declarations` is the `[start, end]` of each declaration
+  declarations: [number, number, [number, number][]][];
+}
+
+// Type information for current file.
+// `typeInfoId` is set before linting a file by `setTypeInfoForFile`.
+// It is `null` if type-aware linting is not enabled.
+// `typeInfo` is fetched from Rust with `typeInfoId` lazily upon first access,
+// so type information is only serialized for files where a rule uses it.
+let typeInfoId: number | null = null;
+let typeInfo: TypeInfoData | null = null;
+
+/**
+ * Set type information for the file.
+ *
+ * @param typeInfoIdInput - ID to get type information for the file with, or `null` if type-aware linting is not enabled
+ */
+export function setTypeInfoForFile(typeInfoIdInput: number | null): undefined {
+  typeInfoId = typeInfoIdInput;
+}
+
+/**
+ * Get type information from Rust, and deserialize it from JSON.
+ *
+ * @throws {Error} If type-aware linting is not enabled
+ */
+function initTypeInfo(): void {
+  if (typeInfoId === null) {
+    throw new Error(
+      "Type information is not available. Enable type-aware linting with `--type-aware` " +
+        "or `options.typeAware` in config.",
+    );
+  }
+
+  // Rust only sends type information once, so it's cached in `typeInfo` until `resetTypeInfo` is called
+  const typeInfoJSON = getTypeInfo(typeInfoId);
+  debugAssertIsNonNull(typeInfoJSON, "Type information should only be requested once per file");
+
+  typeInfo = JSON.parse(typeInfoJSON);
+  debugAssertIsNonNull(typeInfo);
+  debugAssert(Array.isArray(typeInfo.types), "`types` should be an array");
+
+  for (const type of typeInfo.types) {
+    Object.freeze(type);
+  }
+}
+
+/**
+ * Reset type information.
+ */
+export function resetTypeInfo(): undefined {
+  typeInfoId = null;
+  typeInfo = null;
+}
+
+/**
+ * Get the type of an expression.
+ *
+ * @param node - AST node
+ * @returns Type of the node, or `null` if `node` is not an expression
+ * @throws {Error} If type-aware linting is not enabled
+ */
+export function getTypeAtLocation(node: Node): Readonly<TypeInfo> | null {
+  if (typeInfo === null) initTypeInfo();
+  debugAssertIsNonNull(typeInfo);
+
+  const entry = findEntry(typeInfo.expressions, node.range[0], node.range[1]);
+  return entry === null ? null : typeInfo.types[entry[2]];
+}
+
+/**
+ * Get the nodes which declare the variable, function, class etc. which an identifier refers to.
+ *
+ * e.g. the `VariableDeclarator` for `x` in `let x = 1; x;`.
+ *
+ * @param node - `Identifier` node
+ * @returns Declaration nodes, or empty array if the identifier does not refer to a declaration in this file
+ * @throws {Error} If type-aware linting is not enabled
+ */
+export function getSymbolDeclarations(node: Node): ESTreeNode[] {
+  if (typeInfo === null) initTypeInfo();
+  debugAssertIsNonNull(typeInfo);
+
+  const entry = findEntry(typeInfo.declarations, node.range[0], node.range[1]);
+  if (entry === null) return [];
+
+  if (ast === null) initAst();
+  debugAssertIsNonNull(ast);
+
+  const declarations: ESTreeNode[] = [];
+  for (const [start, end] of entry[2]) {
+    const declaration = findNode(ast, start, end);
+    if (declaration !== null) declarations.push(declaration);
+  }
+  return declarations;
+}
+
+/**
+ * Binary search for the entry with the given `start` and `end`.
+ *
+ * @param entries - Entries sorted by `start`, then `end`
+ * @param start - Start offset
+ * @param end - End offset
+ * @returns Entry, or `null` if not found
+ */
+function findEntry<Entry extends [number, number, unknown]>(
+  entries: Entry[],
+  start: number,
+  end: number,
+): Entry | null {
+  let low = 0,
+    high = entries.length;
+  while (low < high) {
+    const mid = (low + high) >>> 1;
+    const entry = entries[mid];
+    if (entry[0] < start || (entry[0] === start && entry[1] < end)) {
+      low = mid + 1;
+    } else {
+      high = mid;
+    }
+  }
+
+  if (low === entries.length) return null;
+  const entry = entries[low];
+  return entry[0] === start && entry[1] === end ? entry : null;
+}
+
+/**
+ * Find the outermost node with the given `start` and `end`, searching from `node` downwards.
+ *
+ * @param node - Node containing `start` and `end`
+ * @param start - Start offset
+ * @param end - End offset
+ * @returns Node, or `null` if there is no node with exactly this range
+ */
+function findNode(node: ESTreeNode, start: number, end: number): ESTreeNode | null {
+  if (node.start === start && node.end === end) return node;
+
+  const keys = (visitorKeys as Record<string, readonly string[]>)[node.type];
+  for (let keyIndex = 0, keysLen = keys.length; keyIndex < keysLen; keyIndex++) {
+    const child = (node as unknown as Record<string, ESTreeNode | ESTreeNode[] | null>)[
+      keys[keyIndex]
+    ];
+
+    if (Array.isArray(child)) {
+      for (let arrIndex = 0, arrLen = child.length; arrIndex < arrLen; arrIndex++) {
+        const entry = child[arrIndex];
+        if (entry !== null && entry.start <= start && end <= entry.end) {
+          return findNode(entry, start, end);
+        }
+      }
+    } else if (child !== null && child.start <= start && end <= child.end) {
+      return findNode(child, start, end);
+    }
+  }
+
+  return null;
+}
@@ -14,7 +14,7 @@ use oxc_allocator::{Allocator, free_fixed_size_allocator};
 use oxc_linter::{
     ExternalLinter, ExternalLinterCreateWorkspaceCb, ExternalLinterDestroyWorkspaceCb,
     ExternalLinterLintFileCb, ExternalLinterLoadPluginCb, ExternalLinterSetupRuleConfigsCb,
-    LintFileResult, LoadPluginResult,
+    LintFileResult, LoadPluginResult, TypeInfo,
 };
 
 use crate::{
@@ -25,6 +25,8 @@ use crate::{
     },
 };
 
+use super::type_info::{register_type_info, unregister_type_info};
+
 /// Wrap JS callbacks as normal Rust functions, and create [`ExternalLinter`].
 pub fn create_external_linter(
     load_plugin: JsLoadPluginCb,
@@ -161,6 +163,7 @@ fn wrap_lint_file(cb: JsLintFileCb) -> ExternalLinterLintFileCb {
               options_ids: Vec<u32>,
               settings_json: String,
               globals_json: String,
+              type_info: Option<TypeInfo>,
               workspace_uri: Option<String>,
               allocator: &Allocator| {
             let (tx, rx) = channel();
@@ -173,6 +176,9 @@ fn wrap_lint_file(cb: JsLintFileCb) -> ExternalLinterLintFileCb {
             // TODO: Close this soundness hole with type-level guarantees.
             let (buffer_id, buffer) = unsafe { get_buffer(allocator) };
 
+            // Type information is only serialized if JS side asks for it with `getTypeInfo`
+            let type_info_id = type_info.map(register_type_info);
+
             // Send data to JS
             let status = cb.call_with_return_value(
                 FnArgs::from((
@@ -183,6 +189,7 @@ fn wrap_lint_file(cb: JsLintFileCb) -> ExternalLinterLintFileCb {
                     options_ids,
                     settings_json,
                     globals_json,
+                    type_info_id,
                     workspace_uri,
                 )),
                 ThreadsafeFunctionCallMode::NonBlocking,
@@ -196,7 +203,7 @@ fn wrap_lint_file(cb: JsLintFileCb) -> ExternalLinterLintFileCb {
                 },
             );
 
-            if status == Status::Ok {
+            let result = if status == Status::Ok {
                 match rx.recv() {
                     // `lintFile` returns `null` if no diagnostics reported, and no error occurred
                     Ok(Ok(None)) => Ok(Vec::new()),
@@ -223,7 +230,14 @@ fn wrap_lint_file(cb: JsLintFileCb) -> ExternalLinterLintFileCb {
                 }
             } else {
                 Err(format!("Failed to schedule `lintFile` callback: {status:?}"))
+            };
+
+            // Free type information if no rule asked for it
+            if let Some(type_info_id) = type_info_id {
+                unregister_type_info(type_info_id);
             }
+
+            result
         },
     ))
 }
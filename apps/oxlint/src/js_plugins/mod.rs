@@ -6,4 +6,6 @@ pub mod fix;
 #[cfg(all(target_pointer_width = "64", target_endian = "little"))]
 pub mod parse;
 
+pub mod type_info;
+
 pub use external_linter::create_external_linter;
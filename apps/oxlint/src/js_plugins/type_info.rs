@@ -0,0 +1,47 @@
+use std::sync::{
+    LazyLock, Mutex,
+    atomic::{AtomicU32, Ordering},
+};
+
+use napi::{Error, Result};
+use napi_derive::napi;
+use rustc_hash::FxHashMap;
+
+use oxc_linter::TypeInfo;
+
+/// Type information of the files which are being linted, keyed by the ID passed to JS side.
+///
+/// Several linting threads may be waiting for `lintFile` at once, so the type information of each
+/// file needs its own ID.
+static TYPE_INFOS: LazyLock<Mutex<FxHashMap<u32, TypeInfo>>> = LazyLock::new(Mutex::default);
+
+static NEXT_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Store type information of a file until JS side asks for it with [`get_type_info`],
+/// and return its ID.
+pub fn register_type_info(type_info: TypeInfo) -> u32 {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    TYPE_INFOS.lock().unwrap().insert(id, type_info);
+    id
+}
+
+/// Remove type information of a file, once linting it has finished.
+pub fn unregister_type_info(id: u32) {
+    TYPE_INFOS.lock().unwrap().remove(&id);
+}
+
+/// Get type information of the file being linted, serialized to JSON.
+///
+/// `id` is the ID which `lintFile` received. Type information is only serialized when a rule
+/// first asks for it, and can only be got once, so JS side must cache it.
+///
+/// Returns `null` if type information for `id` does not exist.
+#[napi]
+#[allow(dead_code, clippy::allow_attributes)]
+pub fn get_type_info(id: u32) -> Result<Option<String>> {
+    let Some(type_info) = TYPE_INFOS.lock().unwrap().remove(&id) else { return Ok(None) };
+    type_info
+        .to_json()
+        .map(Some)
+        .map_err(|err| Error::from_reason(format!("Error serializing type information.\n{err}")))
+}
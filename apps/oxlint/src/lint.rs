@@ -806,6 +806,12 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn report_flow_parse_error() {
+        let args = &["fixtures/cli/flow_parse_error/flow.js"];
+        Tester::new().test_and_snapshot(args);
+    }
+
     #[test]
     // https://github.com/oxc-project/oxc/issues/9023
     fn ignore_file_current_dir() {
@@ -1055,6 +1061,36 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn lint_glimmer_file() {
+        // Imports used only in templates are not unused, and the template placeholders are
+        // neither sparse arrays nor unused expressions
+        let args = &[
+            "-D",
+            "no-unused-vars",
+            "-D",
+            "no-sparse-arrays",
+            "-D",
+            "no-unused-expressions",
+            "fixtures/cli/glimmer/component.gjs",
+        ];
+        Tester::new().test_and_snapshot(args);
+    }
+
+    #[test]
+    fn lint_glimmer_typescript_file() {
+        let args = &[
+            "-D",
+            "no-unused-vars",
+            "-D",
+            "no-sparse-arrays",
+            "-D",
+            "no-unused-expressions",
+            "fixtures/cli/glimmer/component.gts",
+        ];
+        Tester::new().test_and_snapshot(args);
+    }
+
     #[test]
     fn lint_svelte_file() {
         let args = &["fixtures/cli/svelte/debugger.svelte"];
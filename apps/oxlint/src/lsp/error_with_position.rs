@@ -213,13 +213,17 @@ pub fn message_to_lsp_diagnostic(
         });
     }
 
-    add_ignore_fixes(
-        &mut fixed_content,
-        &message.error.code,
-        error_offset,
-        section_offset,
-        source_text,
-    );
+    // Only the diagnostics of lint rules can be disabled with comments, not syntax errors,
+    // which have TypeScript error codes
+    if message.rule.is_some() {
+        add_ignore_fixes(
+            &mut fixed_content,
+            &message.error.code,
+            error_offset,
+            section_offset,
+            source_text,
+        );
+    }
 
     let code_action = if fixed_content.is_empty() {
         None
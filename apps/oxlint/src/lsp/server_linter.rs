@@ -195,8 +195,10 @@ impl ServerLinterBuilder {
 
         let linter = Linter::new(lint_options, config_store, external_linter.cloned())
             .with_workspace_uri(Some(root_uri.as_str()));
-        let mut lint_service_options =
-            LintServiceOptions::new(root_path.clone()).with_cross_module(use_cross_module);
+        // Files are linted while they are being edited, so keep linting through syntax errors
+        let mut lint_service_options = LintServiceOptions::new(root_path.clone())
+            .with_cross_module(use_cross_module)
+            .with_error_recovery(true);
 
         if let Some(ts_path) = options.ts_config_path.as_ref() {
             let ts_path = Path::new(ts_path).to_path_buf();
@@ -1337,6 +1339,12 @@ mod test {
             .test_and_snapshot_multiple_file(&["debugger.ts", "invalid.vue"]);
     }
 
+    #[test]
+    fn test_error_recovery() {
+        Tester::new("fixtures/lsp/error_recovery", json!({}))
+            .test_and_snapshot_single_file("broken.js");
+    }
+
     #[test]
     fn test_cross_module_debugger() {
         Tester::new("fixtures/lsp/cross_module", json!({}))
@@ -3,7 +3,7 @@ use rustc_hash::FxHashMap;
 use serde::Serialize;
 
 use oxc_diagnostics::{
-    Error, Severity,
+    Error, OxcDiagnostic, Severity,
     reporter::{DiagnosticReporter, DiagnosticResult, Info, InfoPosition},
 };
 use oxc_linter::rules::{RULES, RuleEnum};
@@ -243,7 +243,11 @@ impl SarifBuilder {
         });
         let location = self.location_from_info(&info);
 
-        if let Some(rule_id) = info.rule_id {
+        // Syntax errors carry TypeScript error codes instead of lint rule names.
+        let is_syntax_error = OxcDiagnostic::from_error(diagnostic)
+            .is_some_and(|diagnostic| diagnostic.code.is_typescript());
+        let rule_id = info.rule_id.filter(|_| !is_syntax_error);
+        if let Some(rule_id) = rule_id {
             let rule_index = self.get_rule_index(&rule_id);
             self.push_result(rule_id, rule_index, level, message, location);
         } else if location.is_some() {
@@ -548,6 +552,21 @@ mod test {
         assert!(run.get("invocations").is_none());
     }
 
+    #[test]
+    fn syntax_error_with_typescript_code_uses_synthetic_rule() {
+        let error = OxcDiagnostic::error("Expected `;` but found `:`")
+            .with_error_code("TS", "1005")
+            .with_label(Span::new(0, 1))
+            .with_source_code(NamedSource::new("parser-error.js", ":"));
+
+        let output = render(vec![error]);
+        let run = &output["runs"][0];
+
+        assert_eq!(run["results"][0]["ruleId"], "OXL0001");
+        assert_eq!(run["results"][0]["ruleIndex"], 0);
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "OXL0001");
+    }
+
     #[test]
     fn diagnostic_without_artifact_uses_configuration_notification() {
         let error: Error = OxcDiagnostic::error("Failed to parse configuration").into();
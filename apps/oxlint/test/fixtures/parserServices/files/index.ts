@@ -0,0 +1,10 @@
+async function fetchCount(): Promise<number> {
+  return 1;
+}
+
+let count = 1;
+
+count;
+fetchCount;
+fetchCount();
+"a";
@@ -0,0 +1,31 @@
+import type { Plugin } from "#oxlint/plugins";
+
+const plugin: Plugin = {
+  meta: {
+    name: "types-plugin",
+  },
+  rules: {
+    types: {
+      create(context) {
+        const { parserServices } = context.sourceCode;
+        return {
+          ExpressionStatement(node) {
+            const { expression } = node;
+            const type = parserServices.getTypeAtLocation(expression);
+            const declarations = parserServices
+              .getSymbolDeclarations(expression)
+              .map((declaration) => declaration.type);
+            context.report({
+              message:
+                `type: ${type?.text}, flags: ${type?.flags}, isPromiseLike: ${type?.isPromiseLike}, ` +
+                `declarations: [${declarations.join(", ")}]`,
+              node: expression,
+            });
+          },
+        };
+      },
+    },
+  },
+};
+
+export default plugin;
@@ -0,0 +1,90 @@
+//! [Flow](https://flow.org) Definitions
+//!
+//! Flow syntax which has the same shape as TypeScript's (type annotations, generics, type aliases,
+//! interfaces, `declare` statements, `import type`) is represented by the TypeScript nodes in
+//! [`ts`](super::ts). This module only contains nodes for syntax which TypeScript does not have.
+//!
+//! These nodes are only produced when parsing a [`SourceType`](oxc_span::SourceType) with
+//! [`Language::Flow`](oxc_span::Language::Flow).
+//!
+//! ## References
+//! - [Flow documentation](https://flow.org/en/docs/)
+//! - [Babel AST spec](https://github.com/babel/babel/blob/v7.26.2/packages/babel-parser/ast/spec.md)
+
+// NB: `#[span]`, `#[scope(...)]`,`#[visit(...)]` and `#[generate_derive(...)]` do NOT do anything to the code.
+// They are purely markers for codegen used in `tasks/ast_tools`. See docs in that crate.
+// Read [`macro@oxc_ast_macros::ast`] for more information.
+
+use std::cell::Cell;
+
+use oxc_allocator::{Box, CloneIn, Dummy, TakeIn, UnstableAddress};
+use oxc_ast_macros::ast;
+use oxc_estree::ESTree;
+use oxc_span::{ContentEq, GetSpan, GetSpanMut, Span};
+use oxc_syntax::{node::NodeId, scope::ScopeId};
+
+use super::{js::*, ts::*};
+
+/// Flow Type Cast Expression
+///
+/// ## Example
+/// ```js
+/// //          _____ expression
+/// const foo = (bar: string);
+/// //                ^^^^^^ type_annotation
+/// ```
+///
+/// ## Reference
+/// * [Flow - Type Casting Expressions](https://flow.org/en/docs/types/casting/)
+#[ast(visit)]
+#[derive(Debug)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+pub struct FlowTypeCastExpression<'a> {
+    /// Unique identifier for this AST node.
+    pub node_id: Cell<NodeId>,
+    /// Node location in source code, excluding the enclosing parentheses.
+    pub span: Span,
+    /// The expression being cast.
+    pub expression: Expression<'a>,
+    /// The type the expression is cast to.
+    pub type_annotation: Box<'a, TSTypeAnnotation<'a>>,
+}
+
+/// Flow Opaque Type Alias Declaration
+///
+/// ## Example
+/// ```js
+/// //          __ id
+/// opaque type ID: string = string;
+/// //              ^^^^^^ supertype
+/// //                       ^^^^^^ impltype
+///
+/// declare opaque type Token: string;
+/// //                               ^ no impltype
+/// ```
+///
+/// ## Reference
+/// * [Flow - Opaque Type Aliases](https://flow.org/en/docs/types/opaque-types/)
+#[ast(visit)]
+#[scope]
+#[derive(Debug)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+pub struct FlowOpaqueTypeDeclaration<'a> {
+    /// Unique identifier for this AST node.
+    pub node_id: Cell<NodeId>,
+    /// Node location in source code.
+    pub span: Span,
+    /// Opaque type's identifier, e.g. `ID` in `opaque type ID = string`.
+    pub id: BindingIdentifier<'a>,
+    /// Type parameters, e.g. `<T>` in `opaque type Box<T> = Array<T>`.
+    #[scope(enter_before)]
+    pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+    /// Type which the opaque type is visible as outside of its defining file.
+    pub supertype: Option<TSType<'a>>,
+    /// Underlying type. [`None`] in `declare opaque type` declarations.
+    pub impltype: Option<TSType<'a>>,
+    /// Was this declared with `declare opaque type`?
+    pub declare: bool,
+    /// Scope of the type parameters.
+    pub scope_id: Cell<Option<ScopeId>>,
+}
@@ -162,6 +162,12 @@ pub enum Expression<'a> {
     TSInstantiationExpression(Box<'a, TSInstantiationExpression<'a>>) = 38,
     /// See [`V8IntrinsicExpression`] for AST node details.
     V8IntrinsicExpression(Box<'a, V8IntrinsicExpression<'a>>) = 39,
+    /// See [`FlowTypeCastExpression`] for AST node details.
+    FlowTypeCastExpression(Box<'a, FlowTypeCastExpression<'a>>) = 40,
+    /// See [`PipelineExpression`] for AST node details.
+    PipelineExpression(Box<'a, PipelineExpression<'a>>) = 41,
+    /// See [`TopicReference`] for AST node details.
+    TopicReference(Box<'a, TopicReference>) = 42,
 
     // `MemberExpression` variants added here by `#[ast]` macro
     INHERIT(MemberExpression<'a>),
@@ -340,6 +346,10 @@ pub enum ObjectPropertyKind<'a> {
     ObjectProperty(Box<'a, ObjectProperty<'a>>) = 0,
     /// `...{ a: 1 }` in `const obj = { ...{ a: 1 } };`
     SpreadProperty(Box<'a, SpreadElement<'a>>) = 1,
+    /// Property which could not be parsed.
+    ///
+    /// Only produced by the parser when error recovery is enabled.
+    ErrorNode(Box<'a, ErrorNode>) = 2,
 }
 
 /// `a: 1` in `const obj = { a: 1 };`
@@ -1023,6 +1033,10 @@ pub enum Statement<'a> {
     TryStatement(Box<'a, TryStatement<'a>>) = 15,
     WhileStatement(Box<'a, WhileStatement<'a>>) = 16,
     WithStatement(Box<'a, WithStatement<'a>>) = 17,
+    /// Source text which could not be parsed.
+    ///
+    /// Only produced by the parser when error recovery is enabled.
+    ErrorNode(Box<'a, ErrorNode>) = 18,
 
     // `Declaration` and `ModuleDeclaration` variants added here by `#[ast]` macro
     INHERIT(Declaration<'a>),
@@ -1087,6 +1101,8 @@ pub enum Declaration<'a> {
     TSModuleDeclaration(Box<'a, TSModuleDeclaration<'a>>) = 38,
     TSGlobalDeclaration(Box<'a, TSGlobalDeclaration<'a>>) = 39,
     TSImportEqualsDeclaration(Box<'a, TSImportEqualsDeclaration<'a>>) = 40,
+
+    FlowOpaqueTypeDeclaration(Box<'a, FlowOpaqueTypeDeclaration<'a>>) = 41,
 }
 
 /// `let a;` in `let a; a = 1;`
@@ -2056,6 +2072,10 @@ pub enum ClassElement<'a> {
     /// }
     /// ```
     TSIndexSignature(Box<'a, TSIndexSignature<'a>>) = 4,
+    /// Class member which could not be parsed.
+    ///
+    /// Only produced by the parser when error recovery is enabled.
+    ErrorNode(Box<'a, ErrorNode>) = 5,
 }
 
 #[ast(visit)]
@@ -2662,3 +2682,59 @@ pub struct V8IntrinsicExpression<'a> {
     pub name: IdentifierName<'a>,
     pub arguments: Vec<'a, Argument<'a>>,
 }
+
+/// Hack-style pipeline expression, e.g. `value |> f(%)`.
+///
+/// `body` is evaluated with every [`TopicReference`] in it (outside of nested pipeline bodies)
+/// referring to the value of `head`.
+///
+/// The parser only produces this node when `ParseOptions::allow_pipeline_operator` is enabled.
+///
+/// ## Example
+/// ```js
+/// //          ____ head
+/// const foo = bar |> baz(%, 1);
+/// //                 ^^^^^^^^^ body
+/// ```
+///
+/// ## Reference
+/// * [TC39 proposal](https://github.com/tc39/proposal-pipeline-operator)
+#[ast(visit)]
+#[derive(Debug)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+pub struct PipelineExpression<'a> {
+    pub node_id: Cell<NodeId>,
+    pub span: Span,
+    pub head: Expression<'a>,
+    pub body: Expression<'a>,
+}
+
+/// Topic reference `%` in the body of a [`PipelineExpression`].
+#[ast(visit)]
+#[derive(Debug)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+pub struct TopicReference {
+    pub node_id: Cell<NodeId>,
+    pub span: Span,
+}
+
+/// Placeholder for a region of source text which could not be parsed.
+///
+/// The parser only produces this node when `ParseOptions::error_recovery` is enabled.
+/// Instead of aborting on the first unrecoverable syntax error, the parser skips ahead to the
+/// next statement, class member or object property, and records the skipped source text as an
+/// `ErrorNode`. The syntax error itself is reported in the parser's diagnostics.
+///
+/// ## Example
+/// ```ts
+/// let a = 1;
+/// let b = ;   // ErrorNode
+/// let c = 3;
+/// ```
+#[ast(visit)]
+#[derive(Debug)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+pub struct ErrorNode {
+    pub node_id: Cell<NodeId>,
+    pub span: Span,
+}
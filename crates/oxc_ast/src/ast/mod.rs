@@ -181,12 +181,14 @@ pub use oxc_syntax::{
 };
 
 pub(crate) mod comment;
+pub(crate) mod flow;
 pub(crate) mod js;
 pub(crate) mod jsx;
 pub(crate) mod literal;
 pub(crate) mod ts;
 
 pub use comment::*;
+pub use flow::*;
 pub use js::*;
 pub use jsx::*;
 pub use literal::*;
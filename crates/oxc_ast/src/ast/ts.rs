@@ -1751,6 +1751,8 @@ pub enum ImportOrExportKind {
     Value = 0,
     /// `import type { foo } from './foo'`;
     Type = 1,
+    /// `import typeof foo from './foo'` (Flow only)
+    Typeof = 2,
 }
 
 // [`JSDoc`](https://github.com/microsoft/TypeScript/blob/54a554d8af2657630307cbfa8a3e4f3946e36507/src/compiler/types.ts#L393)
@@ -205,7 +205,8 @@ impl<'a> Expression<'a> {
     /// Returns the expression inside of this one, if applicable, and takes ownership of it.
     /// For example, if the expression is a [`ParenthesizedExpression`], it will return the
     /// expression inside the parentheses. Or if this is part of a TypeScript expression
-    /// like `as`, `satisfies`, or `!`, then it will return the expression that is being type asserted.
+    /// like `as`, `satisfies`, or `!`, or a Flow type cast `(x: T)`, then it will return the
+    /// expression that is being type asserted.
     ///
     /// For getting a reference to the expression inside, use [`Expression::get_inner_expression`].
     #[must_use]
@@ -219,6 +220,7 @@ impl<'a> Expression<'a> {
                 Expression::TSInstantiationExpression(e) => e.unbox().expression,
                 Expression::TSNonNullExpression(e) => e.unbox().expression,
                 Expression::TSTypeAssertion(e) => e.unbox().expression,
+                Expression::FlowTypeCastExpression(e) => e.unbox().expression,
                 _ => break,
             };
         }
@@ -228,7 +230,8 @@ impl<'a> Expression<'a> {
     /// Gets the expression inside of this one, if applicable, and returns a reference to it.
     /// For example, if the expression is a [`ParenthesizedExpression`], it will return the
     /// expression inside the parentheses. Or if this is part of a TypeScript expression
-    /// like `as`, `satisfies`, or `!`, then it will return the expression that is being type asserted.
+    /// like `as`, `satisfies`, or `!`, or a Flow type cast `(x: T)`, then it will return the
+    /// expression that is being type asserted.
     ///
     /// For taking ownership of the expression inside, use [`Expression::into_inner_expression`].
     /// For getting a mutable reference to the expression inside, use [`Expression::get_inner_expression_mut`].
@@ -242,6 +245,7 @@ impl<'a> Expression<'a> {
                 Expression::TSInstantiationExpression(e) => &e.expression,
                 Expression::TSNonNullExpression(e) => &e.expression,
                 Expression::TSTypeAssertion(e) => &e.expression,
+                Expression::FlowTypeCastExpression(e) => &e.expression,
                 _ => break,
             };
         }
@@ -251,7 +255,8 @@ impl<'a> Expression<'a> {
     /// Gets the expression inside of this one, if applicable, and returns a mutable reference to it.
     /// For example, if the expression is a [`ParenthesizedExpression`], it will return the
     /// expression inside the parentheses. Or if this is part of a TypeScript expression
-    /// like `as`, `satisfies`, or `!`, then it will return the expression that is being type asserted.
+    /// like `as`, `satisfies`, or `!`, or a Flow type cast `(x: T)`, then it will return the
+    /// expression that is being type asserted.
     ///
     /// For taking ownership of the expression inside, use [`Expression::into_inner_expression`].
     /// For getting an immutable reference to the expression inside, use [`Expression::get_inner_expression`].
@@ -265,6 +270,7 @@ impl<'a> Expression<'a> {
                 Expression::TSInstantiationExpression(e) => &mut e.expression,
                 Expression::TSNonNullExpression(e) => &mut e.expression,
                 Expression::TSTypeAssertion(e) => &mut e.expression,
+                Expression::FlowTypeCastExpression(e) => &mut e.expression,
                 _ => break,
             };
         }
@@ -447,7 +453,7 @@ impl<'a> ObjectPropertyKind<'a> {
     pub fn as_property(&self) -> Option<&ObjectProperty<'a>> {
         match self {
             Self::ObjectProperty(prop) => Some(prop),
-            Self::SpreadProperty(_) => None,
+            Self::SpreadProperty(_) | Self::ErrorNode(_) => None,
         }
     }
 }
@@ -1123,6 +1129,7 @@ impl<'a> Declaration<'a> {
             Declaration::TSInterfaceDeclaration(decl) => Some(&decl.id),
             Declaration::TSEnumDeclaration(decl) => Some(&decl.id),
             Declaration::TSImportEqualsDeclaration(decl) => Some(&decl.id),
+            Declaration::FlowOpaqueTypeDeclaration(decl) => Some(&decl.id),
             Declaration::TSModuleDeclaration(decl) => {
                 if let TSModuleDeclarationName::Identifier(ident) = &decl.id {
                     Some(ident)
@@ -1145,13 +1152,20 @@ impl<'a> Declaration<'a> {
             Declaration::TSModuleDeclaration(decl) => decl.declare,
             Declaration::TSGlobalDeclaration(decl) => decl.declare,
             Declaration::TSInterfaceDeclaration(decl) => decl.declare,
+            Declaration::FlowOpaqueTypeDeclaration(decl) => decl.declare,
             Declaration::TSImportEqualsDeclaration(_) => false,
         }
     }
 
-    /// Returns `true` if this declaration is a TypeScript type or interface declaration.
+    /// Returns `true` if this declaration is a TypeScript type or interface declaration,
+    /// or a Flow opaque type declaration.
     pub fn is_type(&self) -> bool {
-        matches!(self, Self::TSTypeAliasDeclaration(_) | Self::TSInterfaceDeclaration(_))
+        matches!(
+            self,
+            Self::TSTypeAliasDeclaration(_)
+                | Self::TSInterfaceDeclaration(_)
+                | Self::FlowOpaqueTypeDeclaration(_)
+        )
     }
 }
 
@@ -1247,6 +1261,13 @@ impl SwitchCase<'_> {
 }
 
 impl<'a> BindingPattern<'a> {
+    /// `true` for the placeholder pattern of an unnamed Flow function type parameter.
+    ///
+    /// See [`ArrayPattern::is_unnamed_flow_parameter`].
+    pub fn is_unnamed_flow_parameter(&self) -> bool {
+        matches!(self, Self::ArrayPattern(array) if array.is_unnamed_flow_parameter())
+    }
+
     /// Returns the name of the bound identifier in this binding pattern, if it has one, or `None` otherwise.
     ///
     /// ## Example
@@ -1443,6 +1464,12 @@ impl ArrayPattern<'_> {
     pub fn len(&self) -> usize {
         self.elements.len() + usize::from(self.rest.is_some())
     }
+
+    /// `true` for the placeholder pattern of an unnamed Flow function type parameter,
+    /// e.g. `string` in `(string) => void`.
+    pub fn is_unnamed_flow_parameter(&self) -> bool {
+        self.span.is_empty() && self.is_empty()
+    }
 }
 
 impl<'a> Function<'a> {
@@ -1660,7 +1687,7 @@ impl<'a> ClassElement<'a> {
     /// ```
     pub fn r#static(&self) -> bool {
         match self {
-            Self::TSIndexSignature(_) | Self::StaticBlock(_) => false,
+            Self::TSIndexSignature(_) | Self::StaticBlock(_) | Self::ErrorNode(_) => false,
             Self::MethodDefinition(def) => def.r#static,
             Self::PropertyDefinition(def) => def.r#static,
             Self::AccessorProperty(def) => def.r#static,
@@ -1679,7 +1706,7 @@ impl<'a> ClassElement<'a> {
     /// ```
     pub fn computed(&self) -> bool {
         match self {
-            Self::TSIndexSignature(_) | Self::StaticBlock(_) => false,
+            Self::TSIndexSignature(_) | Self::StaticBlock(_) | Self::ErrorNode(_) => false,
             Self::MethodDefinition(def) => def.computed,
             Self::PropertyDefinition(def) => def.computed,
             Self::AccessorProperty(def) => def.computed,
@@ -1689,7 +1716,10 @@ impl<'a> ClassElement<'a> {
     /// Returns the [accessibility][`TSAccessibility`] of this [`ClassElement`], if any is indicated.
     pub fn accessibility(&self) -> Option<TSAccessibility> {
         match self {
-            Self::StaticBlock(_) | Self::TSIndexSignature(_) | Self::AccessorProperty(_) => None,
+            Self::StaticBlock(_)
+            | Self::TSIndexSignature(_)
+            | Self::AccessorProperty(_)
+            | Self::ErrorNode(_) => None,
             Self::MethodDefinition(def) => def.accessibility,
             Self::PropertyDefinition(def) => def.accessibility,
         }
@@ -1702,7 +1732,8 @@ impl<'a> ClassElement<'a> {
             Self::TSIndexSignature(_)
             | Self::StaticBlock(_)
             | Self::PropertyDefinition(_)
-            | Self::AccessorProperty(_) => None,
+            | Self::AccessorProperty(_)
+            | Self::ErrorNode(_) => None,
             Self::MethodDefinition(def) => Some(def.kind),
         }
     }
@@ -1712,7 +1743,7 @@ impl<'a> ClassElement<'a> {
     /// This is either the name of the method, property name, or accessor name.
     pub fn property_key(&self) -> Option<&PropertyKey<'a>> {
         match self {
-            Self::TSIndexSignature(_) | Self::StaticBlock(_) => None,
+            Self::TSIndexSignature(_) | Self::StaticBlock(_) | Self::ErrorNode(_) => None,
             Self::MethodDefinition(def) => Some(&def.key),
             Self::PropertyDefinition(def) => Some(&def.key),
             Self::AccessorProperty(def) => Some(&def.key),
@@ -1723,7 +1754,7 @@ impl<'a> ClassElement<'a> {
     /// computed members that use literals.
     pub fn static_name(&self) -> Option<Cow<'a, str>> {
         match self {
-            Self::TSIndexSignature(_) | Self::StaticBlock(_) => None,
+            Self::TSIndexSignature(_) | Self::StaticBlock(_) | Self::ErrorNode(_) => None,
             Self::MethodDefinition(def) => def.key.static_name(),
             Self::PropertyDefinition(def) => def.key.static_name(),
             Self::AccessorProperty(def) => def.key.static_name(),
@@ -1742,7 +1773,8 @@ impl<'a> ClassElement<'a> {
             Self::PropertyDefinition(_)
             | Self::StaticBlock(_)
             | Self::AccessorProperty(_)
-            | Self::TSIndexSignature(_) => false,
+            | Self::TSIndexSignature(_)
+            | Self::ErrorNode(_) => false,
             Self::MethodDefinition(method) => method.value.body.is_none(),
         }
     }
@@ -1757,7 +1789,7 @@ impl<'a> ClassElement<'a> {
                 property.r#type == PropertyDefinitionType::TSAbstractPropertyDefinition
             }
             Self::AccessorProperty(property) => property.r#type.is_abstract(),
-            Self::StaticBlock(_) => false,
+            Self::StaticBlock(_) | Self::ErrorNode(_) => false,
         }
     }
 
@@ -1767,7 +1799,7 @@ impl<'a> ClassElement<'a> {
             Self::MethodDefinition(method) => !method.decorators.is_empty(),
             Self::PropertyDefinition(property) => !property.decorators.is_empty(),
             Self::AccessorProperty(property) => !property.decorators.is_empty(),
-            Self::StaticBlock(_) | Self::TSIndexSignature(_) => false,
+            Self::StaticBlock(_) | Self::TSIndexSignature(_) | Self::ErrorNode(_) => false,
         }
     }
 
@@ -1784,7 +1816,7 @@ impl<'a> ClassElement<'a> {
             Self::MethodDefinition(method) => method.r#type.is_abstract(),
             Self::AccessorProperty(accessor) => accessor.r#type.is_abstract(),
             Self::PropertyDefinition(property) => property.r#type.is_abstract(),
-            Self::StaticBlock(_) | Self::TSIndexSignature(_) => false,
+            Self::StaticBlock(_) | Self::TSIndexSignature(_) | Self::ErrorNode(_) => false,
         }
     }
 }
@@ -313,9 +313,14 @@ impl ImportOrExportKind {
         matches!(self, Self::Value)
     }
 
-    /// Returns `true` if this is an `import type` or `export type` statement.
+    /// Returns `true` if this is an `import type`, `export type` or `import typeof` statement.
     pub fn is_type(self) -> bool {
-        matches!(self, Self::Type)
+        matches!(self, Self::Type | Self::Typeof)
+    }
+
+    /// Returns `true` if this is a Flow `import typeof` statement.
+    pub fn is_typeof(self) -> bool {
+        matches!(self, Self::Typeof)
     }
 }
 
@@ -35,6 +35,7 @@ impl<'a> AstKind<'a> {
         || matches!(self, Self::TSEnumDeclaration(_) | Self::TSModuleDeclaration(_) | Self::TSGlobalDeclaration(_)
             | Self::VariableDeclaration(_) | Self::TSInterfaceDeclaration(_)
             | Self::TSTypeAliasDeclaration(_) | Self::TSImportEqualsDeclaration(_) | Self::PropertyDefinition(_)
+            | Self::FlowOpaqueTypeDeclaration(_)
         ) || self.is_module_declaration()
     }
 
@@ -284,6 +285,9 @@ impl<'a> AstKind<'a> {
             Expression::TSNonNullExpression(e) => Self::TSNonNullExpression(e),
             Expression::TSInstantiationExpression(e) => Self::TSInstantiationExpression(e),
             Expression::V8IntrinsicExpression(e) => Self::V8IntrinsicExpression(e),
+            Expression::FlowTypeCastExpression(e) => Self::FlowTypeCastExpression(e),
+            Expression::PipelineExpression(e) => Self::PipelineExpression(e),
+            Expression::TopicReference(e) => Self::TopicReference(e),
         }
     }
 
@@ -595,6 +599,11 @@ impl AstKind<'_> {
             Self::TSInterfaceBody(_) => "TSInterfaceBody".into(),
             Self::TSIndexSignature(_) => "TSIndexSignature".into(),
             Self::V8IntrinsicExpression(_) => "V8IntrinsicExpression".into(),
+            Self::PipelineExpression(_) => "PipelineExpression".into(),
+            Self::TopicReference(_) => "TopicReference".into(),
+            Self::FlowTypeCastExpression(_) => "FlowTypeCastExpression".into(),
+            Self::FlowOpaqueTypeDeclaration(_) => "FlowOpaqueTypeDeclaration".into(),
+            Self::ErrorNode(_) => "ErrorNode".into(),
 
             Self::JSDocNullableType(_) => "JSDocNullableType".into(),
             Self::JSDocNonNullableType(_) => "JSDocNonNullableType".into(),
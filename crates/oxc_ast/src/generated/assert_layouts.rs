@@ -954,6 +954,26 @@ const _: () = {
     assert!(offset_of!(V8IntrinsicExpression, name) == 16);
     assert!(offset_of!(V8IntrinsicExpression, arguments) == 48);
 
+    // Padding: 4 bytes
+    assert!(size_of::<PipelineExpression>() == 48);
+    assert!(align_of::<PipelineExpression>() == 8);
+    assert!(offset_of!(PipelineExpression, span) == 0);
+    assert!(offset_of!(PipelineExpression, node_id) == 8);
+    assert!(offset_of!(PipelineExpression, head) == 16);
+    assert!(offset_of!(PipelineExpression, body) == 32);
+
+    // Padding: 4 bytes
+    assert!(size_of::<TopicReference>() == 16);
+    assert!(align_of::<TopicReference>() == 8);
+    assert!(offset_of!(TopicReference, span) == 0);
+    assert!(offset_of!(TopicReference, node_id) == 8);
+
+    // Padding: 4 bytes
+    assert!(size_of::<ErrorNode>() == 16);
+    assert!(align_of::<ErrorNode>() == 8);
+    assert!(offset_of!(ErrorNode, span) == 0);
+    assert!(offset_of!(ErrorNode, node_id) == 8);
+
     // Padding: 3 bytes
     assert!(size_of::<BooleanLiteral>() == 16);
     assert!(align_of::<BooleanLiteral>() == 8);
@@ -1794,6 +1814,26 @@ const _: () = {
     assert!(offset_of!(JSDocUnknownType, span) == 0);
     assert!(offset_of!(JSDocUnknownType, node_id) == 8);
 
+    // Padding: 4 bytes
+    assert!(size_of::<FlowTypeCastExpression>() == 40);
+    assert!(align_of::<FlowTypeCastExpression>() == 8);
+    assert!(offset_of!(FlowTypeCastExpression, span) == 0);
+    assert!(offset_of!(FlowTypeCastExpression, node_id) == 8);
+    assert!(offset_of!(FlowTypeCastExpression, expression) == 16);
+    assert!(offset_of!(FlowTypeCastExpression, type_annotation) == 32);
+
+    // Padding: 7 bytes
+    assert!(size_of::<FlowOpaqueTypeDeclaration>() == 96);
+    assert!(align_of::<FlowOpaqueTypeDeclaration>() == 8);
+    assert!(offset_of!(FlowOpaqueTypeDeclaration, span) == 0);
+    assert!(offset_of!(FlowOpaqueTypeDeclaration, node_id) == 8);
+    assert!(offset_of!(FlowOpaqueTypeDeclaration, scope_id) == 12);
+    assert!(offset_of!(FlowOpaqueTypeDeclaration, id) == 16);
+    assert!(offset_of!(FlowOpaqueTypeDeclaration, type_parameters) == 48);
+    assert!(offset_of!(FlowOpaqueTypeDeclaration, supertype) == 56);
+    assert!(offset_of!(FlowOpaqueTypeDeclaration, impltype) == 72);
+    assert!(offset_of!(FlowOpaqueTypeDeclaration, declare) == 88);
+
     assert!(size_of::<CommentKind>() == 1);
     assert!(align_of::<CommentKind>() == 1);
 
@@ -2765,6 +2805,26 @@ const _: () = if cfg!(target_family = "wasm") || align_of::<u64>() == 8 {
     assert!(offset_of!(V8IntrinsicExpression, name) == 12);
     assert!(offset_of!(V8IntrinsicExpression, arguments) == 36);
 
+    // Padding: 0 bytes
+    assert!(size_of::<PipelineExpression>() == 28);
+    assert!(align_of::<PipelineExpression>() == 4);
+    assert!(offset_of!(PipelineExpression, span) == 0);
+    assert!(offset_of!(PipelineExpression, node_id) == 8);
+    assert!(offset_of!(PipelineExpression, head) == 12);
+    assert!(offset_of!(PipelineExpression, body) == 20);
+
+    // Padding: 0 bytes
+    assert!(size_of::<TopicReference>() == 12);
+    assert!(align_of::<TopicReference>() == 4);
+    assert!(offset_of!(TopicReference, span) == 0);
+    assert!(offset_of!(TopicReference, node_id) == 8);
+
+    // Padding: 0 bytes
+    assert!(size_of::<ErrorNode>() == 12);
+    assert!(align_of::<ErrorNode>() == 4);
+    assert!(offset_of!(ErrorNode, span) == 0);
+    assert!(offset_of!(ErrorNode, node_id) == 8);
+
     // Padding: 3 bytes
     assert!(size_of::<BooleanLiteral>() == 16);
     assert!(align_of::<BooleanLiteral>() == 4);
@@ -3605,6 +3665,26 @@ const _: () = if cfg!(target_family = "wasm") || align_of::<u64>() == 8 {
     assert!(offset_of!(JSDocUnknownType, span) == 0);
     assert!(offset_of!(JSDocUnknownType, node_id) == 8);
 
+    // Padding: 0 bytes
+    assert!(size_of::<FlowTypeCastExpression>() == 24);
+    assert!(align_of::<FlowTypeCastExpression>() == 4);
+    assert!(offset_of!(FlowTypeCastExpression, span) == 0);
+    assert!(offset_of!(FlowTypeCastExpression, node_id) == 8);
+    assert!(offset_of!(FlowTypeCastExpression, expression) == 12);
+    assert!(offset_of!(FlowTypeCastExpression, type_annotation) == 20);
+
+    // Padding: 3 bytes
+    assert!(size_of::<FlowOpaqueTypeDeclaration>() == 68);
+    assert!(align_of::<FlowOpaqueTypeDeclaration>() == 4);
+    assert!(offset_of!(FlowOpaqueTypeDeclaration, span) == 0);
+    assert!(offset_of!(FlowOpaqueTypeDeclaration, node_id) == 8);
+    assert!(offset_of!(FlowOpaqueTypeDeclaration, scope_id) == 12);
+    assert!(offset_of!(FlowOpaqueTypeDeclaration, id) == 16);
+    assert!(offset_of!(FlowOpaqueTypeDeclaration, type_parameters) == 44);
+    assert!(offset_of!(FlowOpaqueTypeDeclaration, supertype) == 48);
+    assert!(offset_of!(FlowOpaqueTypeDeclaration, impltype) == 56);
+    assert!(offset_of!(FlowOpaqueTypeDeclaration, declare) == 64);
+
     assert!(size_of::<CommentKind>() == 1);
     assert!(align_of::<CommentKind>() == 1);
 
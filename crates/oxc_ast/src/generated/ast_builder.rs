@@ -1359,6 +1359,69 @@ impl<'a> AstBuilder<'a> {
         Expression::V8IntrinsicExpression(self.alloc_v8_intrinsic_expression(span, name, arguments))
     }
 
+    /// Build an [`Expression::FlowTypeCastExpression`].
+    ///
+    /// This node contains a [`FlowTypeCastExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code, excluding the enclosing parentheses.
+    /// * `expression`: The expression being cast.
+    /// * `type_annotation`: The type the expression is cast to.
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn expression_flow_type_cast<T1>(
+        self,
+        span: Span,
+        expression: Expression<'a>,
+        type_annotation: T1,
+    ) -> Expression<'a>
+    where
+        T1: IntoIn<'a, ArenaBox<'a, TSTypeAnnotation<'a>>>,
+    {
+        Expression::FlowTypeCastExpression(self.alloc_flow_type_cast_expression(
+            span,
+            expression,
+            type_annotation,
+        ))
+    }
+
+    /// Build an [`Expression::PipelineExpression`].
+    ///
+    /// This node contains a [`PipelineExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `head`
+    /// * `body`
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn expression_pipeline(
+        self,
+        span: Span,
+        head: Expression<'a>,
+        body: Expression<'a>,
+    ) -> Expression<'a> {
+        Expression::PipelineExpression(self.alloc_pipeline_expression(span, head, body))
+    }
+
+    /// Build an [`Expression::TopicReference`].
+    ///
+    /// This node contains a [`TopicReference`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn expression_topic_reference(self, span: Span) -> Expression<'a> {
+        Expression::TopicReference(self.alloc_topic_reference(span))
+    }
+
     /// Build an [`IdentifierName`].
     ///
     /// If you want the built node to be allocated in the memory arena,
@@ -1843,6 +1906,20 @@ impl<'a> AstBuilder<'a> {
         ObjectPropertyKind::SpreadProperty(self.alloc_spread_element(span, argument))
     }
 
+    /// Build an [`ObjectPropertyKind::ErrorNode`].
+    ///
+    /// This node contains an [`ErrorNode`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn object_property_kind_error_node(self, span: Span) -> ObjectPropertyKind<'a> {
+        ObjectPropertyKind::ErrorNode(self.alloc_error_node(span))
+    }
+
     /// Build an [`ObjectProperty`].
     ///
     /// If you want the built node to be allocated in the memory arena,
@@ -4360,6 +4437,20 @@ impl<'a> AstBuilder<'a> {
         )
     }
 
+    /// Build a [`Statement::ErrorNode`].
+    ///
+    /// This node contains an [`ErrorNode`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn statement_error_node(self, span: Span) -> Statement<'a> {
+        Statement::ErrorNode(self.alloc_error_node(span))
+    }
+
     /// Build a [`Directive`].
     ///
     /// ## Parameters
@@ -5067,6 +5158,85 @@ impl<'a> AstBuilder<'a> {
         ))
     }
 
+    /// Build a [`Declaration::FlowOpaqueTypeDeclaration`].
+    ///
+    /// This node contains a [`FlowOpaqueTypeDeclaration`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code.
+    /// * `id`: Opaque type's identifier, e.g. `ID` in `opaque type ID = string`.
+    /// * `type_parameters`: Type parameters, e.g. `<T>` in `opaque type Box<T> = Array<T>`.
+    /// * `supertype`: Type which the opaque type is visible as outside of its defining file.
+    /// * `impltype`: Underlying type. [`None`] in `declare opaque type` declarations.
+    /// * `declare`: Was this declared with `declare opaque type`?
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn declaration_flow_opaque_type<T1>(
+        self,
+        span: Span,
+        id: BindingIdentifier<'a>,
+        type_parameters: T1,
+        supertype: Option<TSType<'a>>,
+        impltype: Option<TSType<'a>>,
+        declare: bool,
+    ) -> Declaration<'a>
+    where
+        T1: IntoIn<'a, Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>>,
+    {
+        Declaration::FlowOpaqueTypeDeclaration(self.alloc_flow_opaque_type_declaration(
+            span,
+            id,
+            type_parameters,
+            supertype,
+            impltype,
+            declare,
+        ))
+    }
+
+    /// Build a [`Declaration::FlowOpaqueTypeDeclaration`] with `scope_id`.
+    ///
+    /// This node contains a [`FlowOpaqueTypeDeclaration`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code.
+    /// * `id`: Opaque type's identifier, e.g. `ID` in `opaque type ID = string`.
+    /// * `type_parameters`: Type parameters, e.g. `<T>` in `opaque type Box<T> = Array<T>`.
+    /// * `supertype`: Type which the opaque type is visible as outside of its defining file.
+    /// * `impltype`: Underlying type. [`None`] in `declare opaque type` declarations.
+    /// * `declare`: Was this declared with `declare opaque type`?
+    /// * `scope_id`: Scope of the type parameters.
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn declaration_flow_opaque_type_with_scope_id<T1>(
+        self,
+        span: Span,
+        id: BindingIdentifier<'a>,
+        type_parameters: T1,
+        supertype: Option<TSType<'a>>,
+        impltype: Option<TSType<'a>>,
+        declare: bool,
+        scope_id: ScopeId,
+    ) -> Declaration<'a>
+    where
+        T1: IntoIn<'a, Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>>,
+    {
+        Declaration::FlowOpaqueTypeDeclaration(
+            self.alloc_flow_opaque_type_declaration_with_scope_id(
+                span,
+                id,
+                type_parameters,
+                supertype,
+                impltype,
+                declare,
+                scope_id,
+            ),
+        )
+    }
+
     /// Build a [`VariableDeclaration`].
     ///
     /// If you want the built node to be allocated in the memory arena,
@@ -8007,6 +8177,20 @@ impl<'a> AstBuilder<'a> {
         ))
     }
 
+    /// Build a [`ClassElement::ErrorNode`].
+    ///
+    /// This node contains an [`ErrorNode`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn class_element_error_node(self, span: Span) -> ClassElement<'a> {
+        ClassElement::ErrorNode(self.alloc_error_node(span))
+    }
+
     /// Build a [`MethodDefinition`].
     ///
     /// If you want the built node to be allocated in the memory arena,
@@ -9786,6 +9970,110 @@ impl<'a> AstBuilder<'a> {
         ArenaBox::new_in(self.v8_intrinsic_expression(span, name, arguments), &self)
     }
 
+    /// Build a [`PipelineExpression`].
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`AstBuilder::alloc_pipeline_expression`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `head`
+    /// * `body`
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn pipeline_expression(
+        self,
+        span: Span,
+        head: Expression<'a>,
+        body: Expression<'a>,
+    ) -> PipelineExpression<'a> {
+        PipelineExpression { node_id: Default::default(), span, head, body }
+    }
+
+    /// Build a [`PipelineExpression`], and store it in the memory arena.
+    ///
+    /// Returns a [`Box`](ArenaBox) containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`AstBuilder::pipeline_expression`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `head`
+    /// * `body`
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn alloc_pipeline_expression(
+        self,
+        span: Span,
+        head: Expression<'a>,
+        body: Expression<'a>,
+    ) -> ArenaBox<'a, PipelineExpression<'a>> {
+        ArenaBox::new_in(self.pipeline_expression(span, head, body), &self)
+    }
+
+    /// Build a [`TopicReference`].
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`AstBuilder::alloc_topic_reference`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn topic_reference(self, span: Span) -> TopicReference {
+        TopicReference { node_id: Default::default(), span }
+    }
+
+    /// Build a [`TopicReference`], and store it in the memory arena.
+    ///
+    /// Returns a [`Box`](ArenaBox) containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`AstBuilder::topic_reference`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn alloc_topic_reference(self, span: Span) -> ArenaBox<'a, TopicReference> {
+        ArenaBox::new_in(self.topic_reference(span), &self)
+    }
+
+    /// Build an [`ErrorNode`].
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`AstBuilder::alloc_error_node`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn error_node(self, span: Span) -> ErrorNode {
+        ErrorNode { node_id: Default::default(), span }
+    }
+
+    /// Build an [`ErrorNode`], and store it in the memory arena.
+    ///
+    /// Returns a [`Box`](ArenaBox) containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`AstBuilder::error_node`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn alloc_error_node(self, span: Span) -> ArenaBox<'a, ErrorNode> {
+        ArenaBox::new_in(self.error_node(span), &self)
+    }
+
     /// Build a [`BooleanLiteral`].
     ///
     /// If you want the built node to be allocated in the memory arena,
@@ -17519,4 +17807,226 @@ impl<'a> AstBuilder<'a> {
     pub fn alloc_js_doc_unknown_type(self, span: Span) -> ArenaBox<'a, JSDocUnknownType> {
         ArenaBox::new_in(self.js_doc_unknown_type(span), &self)
     }
+
+    /// Build a [`FlowTypeCastExpression`].
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`AstBuilder::alloc_flow_type_cast_expression`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code, excluding the enclosing parentheses.
+    /// * `expression`: The expression being cast.
+    /// * `type_annotation`: The type the expression is cast to.
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn flow_type_cast_expression<T1>(
+        self,
+        span: Span,
+        expression: Expression<'a>,
+        type_annotation: T1,
+    ) -> FlowTypeCastExpression<'a>
+    where
+        T1: IntoIn<'a, ArenaBox<'a, TSTypeAnnotation<'a>>>,
+    {
+        FlowTypeCastExpression {
+            node_id: Default::default(),
+            span,
+            expression,
+            type_annotation: type_annotation.into_in(self.allocator()),
+        }
+    }
+
+    /// Build a [`FlowTypeCastExpression`], and store it in the memory arena.
+    ///
+    /// Returns a [`Box`](ArenaBox) containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`AstBuilder::flow_type_cast_expression`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code, excluding the enclosing parentheses.
+    /// * `expression`: The expression being cast.
+    /// * `type_annotation`: The type the expression is cast to.
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn alloc_flow_type_cast_expression<T1>(
+        self,
+        span: Span,
+        expression: Expression<'a>,
+        type_annotation: T1,
+    ) -> ArenaBox<'a, FlowTypeCastExpression<'a>>
+    where
+        T1: IntoIn<'a, ArenaBox<'a, TSTypeAnnotation<'a>>>,
+    {
+        ArenaBox::new_in(self.flow_type_cast_expression(span, expression, type_annotation), &self)
+    }
+
+    /// Build a [`FlowOpaqueTypeDeclaration`].
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`AstBuilder::alloc_flow_opaque_type_declaration`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code.
+    /// * `id`: Opaque type's identifier, e.g. `ID` in `opaque type ID = string`.
+    /// * `type_parameters`: Type parameters, e.g. `<T>` in `opaque type Box<T> = Array<T>`.
+    /// * `supertype`: Type which the opaque type is visible as outside of its defining file.
+    /// * `impltype`: Underlying type. [`None`] in `declare opaque type` declarations.
+    /// * `declare`: Was this declared with `declare opaque type`?
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn flow_opaque_type_declaration<T1>(
+        self,
+        span: Span,
+        id: BindingIdentifier<'a>,
+        type_parameters: T1,
+        supertype: Option<TSType<'a>>,
+        impltype: Option<TSType<'a>>,
+        declare: bool,
+    ) -> FlowOpaqueTypeDeclaration<'a>
+    where
+        T1: IntoIn<'a, Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>>,
+    {
+        FlowOpaqueTypeDeclaration {
+            node_id: Default::default(),
+            span,
+            id,
+            type_parameters: type_parameters.into_in(self.allocator()),
+            supertype,
+            impltype,
+            declare,
+            scope_id: Default::default(),
+        }
+    }
+
+    /// Build a [`FlowOpaqueTypeDeclaration`], and store it in the memory arena.
+    ///
+    /// Returns a [`Box`](ArenaBox) containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`AstBuilder::flow_opaque_type_declaration`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code.
+    /// * `id`: Opaque type's identifier, e.g. `ID` in `opaque type ID = string`.
+    /// * `type_parameters`: Type parameters, e.g. `<T>` in `opaque type Box<T> = Array<T>`.
+    /// * `supertype`: Type which the opaque type is visible as outside of its defining file.
+    /// * `impltype`: Underlying type. [`None`] in `declare opaque type` declarations.
+    /// * `declare`: Was this declared with `declare opaque type`?
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn alloc_flow_opaque_type_declaration<T1>(
+        self,
+        span: Span,
+        id: BindingIdentifier<'a>,
+        type_parameters: T1,
+        supertype: Option<TSType<'a>>,
+        impltype: Option<TSType<'a>>,
+        declare: bool,
+    ) -> ArenaBox<'a, FlowOpaqueTypeDeclaration<'a>>
+    where
+        T1: IntoIn<'a, Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>>,
+    {
+        ArenaBox::new_in(
+            self.flow_opaque_type_declaration(
+                span,
+                id,
+                type_parameters,
+                supertype,
+                impltype,
+                declare,
+            ),
+            &self,
+        )
+    }
+
+    /// Build a [`FlowOpaqueTypeDeclaration`] with `scope_id`.
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`AstBuilder::alloc_flow_opaque_type_declaration_with_scope_id`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code.
+    /// * `id`: Opaque type's identifier, e.g. `ID` in `opaque type ID = string`.
+    /// * `type_parameters`: Type parameters, e.g. `<T>` in `opaque type Box<T> = Array<T>`.
+    /// * `supertype`: Type which the opaque type is visible as outside of its defining file.
+    /// * `impltype`: Underlying type. [`None`] in `declare opaque type` declarations.
+    /// * `declare`: Was this declared with `declare opaque type`?
+    /// * `scope_id`: Scope of the type parameters.
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn flow_opaque_type_declaration_with_scope_id<T1>(
+        self,
+        span: Span,
+        id: BindingIdentifier<'a>,
+        type_parameters: T1,
+        supertype: Option<TSType<'a>>,
+        impltype: Option<TSType<'a>>,
+        declare: bool,
+        scope_id: ScopeId,
+    ) -> FlowOpaqueTypeDeclaration<'a>
+    where
+        T1: IntoIn<'a, Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>>,
+    {
+        FlowOpaqueTypeDeclaration {
+            node_id: Default::default(),
+            span,
+            id,
+            type_parameters: type_parameters.into_in(self.allocator()),
+            supertype,
+            impltype,
+            declare,
+            scope_id: Cell::new(Some(scope_id)),
+        }
+    }
+
+    /// Build a [`FlowOpaqueTypeDeclaration`] with `scope_id`, and store it in the memory arena.
+    ///
+    /// Returns a [`Box`](ArenaBox) containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`AstBuilder::flow_opaque_type_declaration_with_scope_id`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code.
+    /// * `id`: Opaque type's identifier, e.g. `ID` in `opaque type ID = string`.
+    /// * `type_parameters`: Type parameters, e.g. `<T>` in `opaque type Box<T> = Array<T>`.
+    /// * `supertype`: Type which the opaque type is visible as outside of its defining file.
+    /// * `impltype`: Underlying type. [`None`] in `declare opaque type` declarations.
+    /// * `declare`: Was this declared with `declare opaque type`?
+    /// * `scope_id`: Scope of the type parameters.
+    #[deprecated(
+        note = "Migrate to new `AstBuilder` interface. See https://github.com/oxc-project/oxc/issues/23043"
+    )]
+    #[inline]
+    pub fn alloc_flow_opaque_type_declaration_with_scope_id<T1>(
+        self,
+        span: Span,
+        id: BindingIdentifier<'a>,
+        type_parameters: T1,
+        supertype: Option<TSType<'a>>,
+        impltype: Option<TSType<'a>>,
+        declare: bool,
+        scope_id: ScopeId,
+    ) -> ArenaBox<'a, FlowOpaqueTypeDeclaration<'a>>
+    where
+        T1: IntoIn<'a, Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>>,
+    {
+        ArenaBox::new_in(
+            self.flow_opaque_type_declaration_with_scope_id(
+                span,
+                id,
+                type_parameters,
+                supertype,
+                impltype,
+                declare,
+                scope_id,
+            ),
+            &self,
+        )
+    }
 }
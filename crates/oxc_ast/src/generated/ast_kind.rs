@@ -10,7 +10,7 @@ use oxc_syntax::node::NodeId;
 use crate::ast::*;
 
 /// The largest integer value that can be mapped to an `AstType`/`AstKind` enum variant.
-pub const AST_TYPE_MAX: u8 = 187;
+pub const AST_TYPE_MAX: u8 = 192;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u8)]
@@ -109,100 +109,105 @@ pub enum AstType {
     ExportAllDeclaration = 91,
     ExportSpecifier = 92,
     V8IntrinsicExpression = 93,
-    BooleanLiteral = 94,
-    NullLiteral = 95,
-    NumericLiteral = 96,
-    StringLiteral = 97,
-    BigIntLiteral = 98,
-    RegExpLiteral = 99,
-    JSXElement = 100,
-    JSXOpeningElement = 101,
-    JSXClosingElement = 102,
-    JSXFragment = 103,
-    JSXOpeningFragment = 104,
-    JSXClosingFragment = 105,
-    JSXNamespacedName = 106,
-    JSXMemberExpression = 107,
-    JSXExpressionContainer = 108,
-    JSXEmptyExpression = 109,
-    JSXAttribute = 110,
-    JSXSpreadAttribute = 111,
-    JSXIdentifier = 112,
-    JSXSpreadChild = 113,
-    JSXText = 114,
-    TSThisParameter = 115,
-    TSEnumDeclaration = 116,
-    TSEnumBody = 117,
-    TSEnumMember = 118,
-    TSTypeAnnotation = 119,
-    TSLiteralType = 120,
-    TSConditionalType = 121,
-    TSUnionType = 122,
-    TSIntersectionType = 123,
-    TSParenthesizedType = 124,
-    TSTypeOperator = 125,
-    TSArrayType = 126,
-    TSIndexedAccessType = 127,
-    TSTupleType = 128,
-    TSNamedTupleMember = 129,
-    TSOptionalType = 130,
-    TSRestType = 131,
-    TSAnyKeyword = 132,
-    TSStringKeyword = 133,
-    TSBooleanKeyword = 134,
-    TSNumberKeyword = 135,
-    TSNeverKeyword = 136,
-    TSIntrinsicKeyword = 137,
-    TSUnknownKeyword = 138,
-    TSNullKeyword = 139,
-    TSUndefinedKeyword = 140,
-    TSVoidKeyword = 141,
-    TSSymbolKeyword = 142,
-    TSThisType = 143,
-    TSObjectKeyword = 144,
-    TSBigIntKeyword = 145,
-    TSTypeReference = 146,
-    TSQualifiedName = 147,
-    TSTypeParameterInstantiation = 148,
-    TSTypeParameter = 149,
-    TSTypeParameterDeclaration = 150,
-    TSTypeAliasDeclaration = 151,
-    TSClassImplements = 152,
-    TSInterfaceDeclaration = 153,
-    TSInterfaceBody = 154,
-    TSPropertySignature = 155,
-    TSIndexSignature = 156,
-    TSCallSignatureDeclaration = 157,
-    TSMethodSignature = 158,
-    TSConstructSignatureDeclaration = 159,
-    TSIndexSignatureName = 160,
-    TSInterfaceHeritage = 161,
-    TSTypePredicate = 162,
-    TSModuleDeclaration = 163,
-    TSGlobalDeclaration = 164,
-    TSModuleBlock = 165,
-    TSTypeLiteral = 166,
-    TSInferType = 167,
-    TSTypeQuery = 168,
-    TSImportType = 169,
-    TSImportTypeQualifiedName = 170,
-    TSFunctionType = 171,
-    TSConstructorType = 172,
-    TSMappedType = 173,
-    TSTemplateLiteralType = 174,
-    TSAsExpression = 175,
-    TSSatisfiesExpression = 176,
-    TSTypeAssertion = 177,
-    TSImportEqualsDeclaration = 178,
-    TSExternalModuleReference = 179,
-    TSNonNullExpression = 180,
-    Decorator = 181,
-    TSExportAssignment = 182,
-    TSNamespaceExportDeclaration = 183,
-    TSInstantiationExpression = 184,
-    JSDocNullableType = 185,
-    JSDocNonNullableType = 186,
-    JSDocUnknownType = 187,
+    PipelineExpression = 94,
+    TopicReference = 95,
+    ErrorNode = 96,
+    BooleanLiteral = 97,
+    NullLiteral = 98,
+    NumericLiteral = 99,
+    StringLiteral = 100,
+    BigIntLiteral = 101,
+    RegExpLiteral = 102,
+    JSXElement = 103,
+    JSXOpeningElement = 104,
+    JSXClosingElement = 105,
+    JSXFragment = 106,
+    JSXOpeningFragment = 107,
+    JSXClosingFragment = 108,
+    JSXNamespacedName = 109,
+    JSXMemberExpression = 110,
+    JSXExpressionContainer = 111,
+    JSXEmptyExpression = 112,
+    JSXAttribute = 113,
+    JSXSpreadAttribute = 114,
+    JSXIdentifier = 115,
+    JSXSpreadChild = 116,
+    JSXText = 117,
+    TSThisParameter = 118,
+    TSEnumDeclaration = 119,
+    TSEnumBody = 120,
+    TSEnumMember = 121,
+    TSTypeAnnotation = 122,
+    TSLiteralType = 123,
+    TSConditionalType = 124,
+    TSUnionType = 125,
+    TSIntersectionType = 126,
+    TSParenthesizedType = 127,
+    TSTypeOperator = 128,
+    TSArrayType = 129,
+    TSIndexedAccessType = 130,
+    TSTupleType = 131,
+    TSNamedTupleMember = 132,
+    TSOptionalType = 133,
+    TSRestType = 134,
+    TSAnyKeyword = 135,
+    TSStringKeyword = 136,
+    TSBooleanKeyword = 137,
+    TSNumberKeyword = 138,
+    TSNeverKeyword = 139,
+    TSIntrinsicKeyword = 140,
+    TSUnknownKeyword = 141,
+    TSNullKeyword = 142,
+    TSUndefinedKeyword = 143,
+    TSVoidKeyword = 144,
+    TSSymbolKeyword = 145,
+    TSThisType = 146,
+    TSObjectKeyword = 147,
+    TSBigIntKeyword = 148,
+    TSTypeReference = 149,
+    TSQualifiedName = 150,
+    TSTypeParameterInstantiation = 151,
+    TSTypeParameter = 152,
+    TSTypeParameterDeclaration = 153,
+    TSTypeAliasDeclaration = 154,
+    TSClassImplements = 155,
+    TSInterfaceDeclaration = 156,
+    TSInterfaceBody = 157,
+    TSPropertySignature = 158,
+    TSIndexSignature = 159,
+    TSCallSignatureDeclaration = 160,
+    TSMethodSignature = 161,
+    TSConstructSignatureDeclaration = 162,
+    TSIndexSignatureName = 163,
+    TSInterfaceHeritage = 164,
+    TSTypePredicate = 165,
+    TSModuleDeclaration = 166,
+    TSGlobalDeclaration = 167,
+    TSModuleBlock = 168,
+    TSTypeLiteral = 169,
+    TSInferType = 170,
+    TSTypeQuery = 171,
+    TSImportType = 172,
+    TSImportTypeQualifiedName = 173,
+    TSFunctionType = 174,
+    TSConstructorType = 175,
+    TSMappedType = 176,
+    TSTemplateLiteralType = 177,
+    TSAsExpression = 178,
+    TSSatisfiesExpression = 179,
+    TSTypeAssertion = 180,
+    TSImportEqualsDeclaration = 181,
+    TSExternalModuleReference = 182,
+    TSNonNullExpression = 183,
+    Decorator = 184,
+    TSExportAssignment = 185,
+    TSNamespaceExportDeclaration = 186,
+    TSInstantiationExpression = 187,
+    JSDocNullableType = 188,
+    JSDocNonNullableType = 189,
+    JSDocUnknownType = 190,
+    FlowTypeCastExpression = 191,
+    FlowOpaqueTypeDeclaration = 192,
 }
 
 /// Untyped AST Node Kind
@@ -312,6 +317,9 @@ pub enum AstKind<'a> {
     ExportAllDeclaration(&'a ExportAllDeclaration<'a>) = AstType::ExportAllDeclaration as u8,
     ExportSpecifier(&'a ExportSpecifier<'a>) = AstType::ExportSpecifier as u8,
     V8IntrinsicExpression(&'a V8IntrinsicExpression<'a>) = AstType::V8IntrinsicExpression as u8,
+    PipelineExpression(&'a PipelineExpression<'a>) = AstType::PipelineExpression as u8,
+    TopicReference(&'a TopicReference) = AstType::TopicReference as u8,
+    ErrorNode(&'a ErrorNode) = AstType::ErrorNode as u8,
     BooleanLiteral(&'a BooleanLiteral) = AstType::BooleanLiteral as u8,
     NullLiteral(&'a NullLiteral) = AstType::NullLiteral as u8,
     NumericLiteral(&'a NumericLiteral<'a>) = AstType::NumericLiteral as u8,
@@ -415,6 +423,9 @@ pub enum AstKind<'a> {
     JSDocNullableType(&'a JSDocNullableType<'a>) = AstType::JSDocNullableType as u8,
     JSDocNonNullableType(&'a JSDocNonNullableType<'a>) = AstType::JSDocNonNullableType as u8,
     JSDocUnknownType(&'a JSDocUnknownType) = AstType::JSDocUnknownType as u8,
+    FlowTypeCastExpression(&'a FlowTypeCastExpression<'a>) = AstType::FlowTypeCastExpression as u8,
+    FlowOpaqueTypeDeclaration(&'a FlowOpaqueTypeDeclaration<'a>) =
+        AstType::FlowOpaqueTypeDeclaration as u8,
 }
 
 impl AstKind<'_> {
@@ -527,6 +538,9 @@ impl AstKind<'_> {
             Self::ExportAllDeclaration(it) => it.node_id(),
             Self::ExportSpecifier(it) => it.node_id(),
             Self::V8IntrinsicExpression(it) => it.node_id(),
+            Self::PipelineExpression(it) => it.node_id(),
+            Self::TopicReference(it) => it.node_id(),
+            Self::ErrorNode(it) => it.node_id(),
             Self::BooleanLiteral(it) => it.node_id(),
             Self::NullLiteral(it) => it.node_id(),
             Self::NumericLiteral(it) => it.node_id(),
@@ -621,6 +635,8 @@ impl AstKind<'_> {
             Self::JSDocNullableType(it) => it.node_id(),
             Self::JSDocNonNullableType(it) => it.node_id(),
             Self::JSDocUnknownType(it) => it.node_id(),
+            Self::FlowTypeCastExpression(it) => it.node_id(),
+            Self::FlowOpaqueTypeDeclaration(it) => it.node_id(),
         }
     }
 
@@ -723,6 +739,9 @@ impl AstKind<'_> {
             Self::ExportAllDeclaration(it) => it.set_node_id(node_id),
             Self::ExportSpecifier(it) => it.set_node_id(node_id),
             Self::V8IntrinsicExpression(it) => it.set_node_id(node_id),
+            Self::PipelineExpression(it) => it.set_node_id(node_id),
+            Self::TopicReference(it) => it.set_node_id(node_id),
+            Self::ErrorNode(it) => it.set_node_id(node_id),
             Self::BooleanLiteral(it) => it.set_node_id(node_id),
             Self::NullLiteral(it) => it.set_node_id(node_id),
             Self::NumericLiteral(it) => it.set_node_id(node_id),
@@ -817,6 +836,8 @@ impl AstKind<'_> {
             Self::JSDocNullableType(it) => it.set_node_id(node_id),
             Self::JSDocNonNullableType(it) => it.set_node_id(node_id),
             Self::JSDocUnknownType(it) => it.set_node_id(node_id),
+            Self::FlowTypeCastExpression(it) => it.set_node_id(node_id),
+            Self::FlowOpaqueTypeDeclaration(it) => it.set_node_id(node_id),
         }
     }
 }
@@ -921,6 +942,9 @@ impl GetSpan for AstKind<'_> {
             Self::ExportAllDeclaration(it) => it.span(),
             Self::ExportSpecifier(it) => it.span(),
             Self::V8IntrinsicExpression(it) => it.span(),
+            Self::PipelineExpression(it) => it.span(),
+            Self::TopicReference(it) => it.span(),
+            Self::ErrorNode(it) => it.span(),
             Self::BooleanLiteral(it) => it.span(),
             Self::NullLiteral(it) => it.span(),
             Self::NumericLiteral(it) => it.span(),
@@ -1015,6 +1039,8 @@ impl GetSpan for AstKind<'_> {
             Self::JSDocNullableType(it) => it.span(),
             Self::JSDocNonNullableType(it) => it.span(),
             Self::JSDocUnknownType(it) => it.span(),
+            Self::FlowTypeCastExpression(it) => it.span(),
+            Self::FlowOpaqueTypeDeclaration(it) => it.span(),
         }
     }
 }
@@ -1120,6 +1146,9 @@ impl GetAddress for AstKind<'_> {
             Self::ExportAllDeclaration(it) => it.unstable_address(),
             Self::ExportSpecifier(it) => it.unstable_address(),
             Self::V8IntrinsicExpression(it) => it.unstable_address(),
+            Self::PipelineExpression(it) => it.unstable_address(),
+            Self::TopicReference(it) => it.unstable_address(),
+            Self::ErrorNode(it) => it.unstable_address(),
             Self::BooleanLiteral(it) => it.unstable_address(),
             Self::NullLiteral(it) => it.unstable_address(),
             Self::NumericLiteral(it) => it.unstable_address(),
@@ -1214,6 +1243,8 @@ impl GetAddress for AstKind<'_> {
             Self::JSDocNullableType(it) => it.unstable_address(),
             Self::JSDocNonNullableType(it) => it.unstable_address(),
             Self::JSDocUnknownType(it) => it.unstable_address(),
+            Self::FlowTypeCastExpression(it) => it.unstable_address(),
+            Self::FlowOpaqueTypeDeclaration(it) => it.unstable_address(),
         }
     }
 }
@@ -1693,6 +1724,21 @@ impl<'a> AstKind<'a> {
         if let Self::V8IntrinsicExpression(v) = self { Some(v) } else { None }
     }
 
+    #[inline]
+    pub fn as_pipeline_expression(self) -> Option<&'a PipelineExpression<'a>> {
+        if let Self::PipelineExpression(v) = self { Some(v) } else { None }
+    }
+
+    #[inline]
+    pub fn as_topic_reference(self) -> Option<&'a TopicReference> {
+        if let Self::TopicReference(v) = self { Some(v) } else { None }
+    }
+
+    #[inline]
+    pub fn as_error_node(self) -> Option<&'a ErrorNode> {
+        if let Self::ErrorNode(v) = self { Some(v) } else { None }
+    }
+
     #[inline]
     pub fn as_boolean_literal(self) -> Option<&'a BooleanLiteral> {
         if let Self::BooleanLiteral(v) = self { Some(v) } else { None }
@@ -2168,4 +2214,14 @@ impl<'a> AstKind<'a> {
     pub fn as_js_doc_unknown_type(self) -> Option<&'a JSDocUnknownType> {
         if let Self::JSDocUnknownType(v) = self { Some(v) } else { None }
     }
+
+    #[inline]
+    pub fn as_flow_type_cast_expression(self) -> Option<&'a FlowTypeCastExpression<'a>> {
+        if let Self::FlowTypeCastExpression(v) = self { Some(v) } else { None }
+    }
+
+    #[inline]
+    pub fn as_flow_opaque_type_declaration(self) -> Option<&'a FlowOpaqueTypeDeclaration<'a>> {
+        if let Self::FlowOpaqueTypeDeclaration(v) = self { Some(v) } else { None }
+    }
 }
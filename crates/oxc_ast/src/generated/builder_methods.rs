@@ -1254,6 +1254,61 @@ impl<'a> Expression<'a> {
         Self::V8IntrinsicExpression(V8IntrinsicExpression::boxed(span, name, arguments, builder))
     }
 
+    /// Build an [`Expression::FlowTypeCastExpression`].
+    ///
+    /// This node contains a [`FlowTypeCastExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code, excluding the enclosing parentheses.
+    /// * `expression`: The expression being cast.
+    /// * `type_annotation`: The type the expression is cast to.
+    #[inline]
+    pub fn new_flow_type_cast_expression<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        expression: Expression<'a>,
+        type_annotation: T1,
+        builder: &B,
+    ) -> Self
+    where
+        T1: IntoIn<'a, ArenaBox<'a, TSTypeAnnotation<'a>>>,
+    {
+        Self::FlowTypeCastExpression(FlowTypeCastExpression::boxed(
+            span,
+            expression,
+            type_annotation,
+            builder,
+        ))
+    }
+
+    /// Build an [`Expression::PipelineExpression`].
+    ///
+    /// This node contains a [`PipelineExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `head`
+    /// * `body`
+    #[inline]
+    pub fn new_pipeline_expression<B: GetAstBuilder<'a>>(
+        span: Span,
+        head: Expression<'a>,
+        body: Expression<'a>,
+        builder: &B,
+    ) -> Self {
+        Self::PipelineExpression(PipelineExpression::boxed(span, head, body, builder))
+    }
+
+    /// Build an [`Expression::TopicReference`].
+    ///
+    /// This node contains a [`TopicReference`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn new_topic_reference<B: GetAstBuilder<'a>>(span: Span, builder: &B) -> Self {
+        Self::TopicReference(TopicReference::boxed(span, builder))
+    }
+
     /// Build an [`Expression::ComputedMemberExpression`].
     ///
     /// This node contains a [`ComputedMemberExpression`] that will be stored in the memory arena.
@@ -2813,6 +2868,61 @@ impl<'a> ArrayExpressionElement<'a> {
         Self::V8IntrinsicExpression(V8IntrinsicExpression::boxed(span, name, arguments, builder))
     }
 
+    /// Build an [`ArrayExpressionElement::FlowTypeCastExpression`].
+    ///
+    /// This node contains a [`FlowTypeCastExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code, excluding the enclosing parentheses.
+    /// * `expression`: The expression being cast.
+    /// * `type_annotation`: The type the expression is cast to.
+    #[inline]
+    pub fn new_flow_type_cast_expression<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        expression: Expression<'a>,
+        type_annotation: T1,
+        builder: &B,
+    ) -> Self
+    where
+        T1: IntoIn<'a, ArenaBox<'a, TSTypeAnnotation<'a>>>,
+    {
+        Self::FlowTypeCastExpression(FlowTypeCastExpression::boxed(
+            span,
+            expression,
+            type_annotation,
+            builder,
+        ))
+    }
+
+    /// Build an [`ArrayExpressionElement::PipelineExpression`].
+    ///
+    /// This node contains a [`PipelineExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `head`
+    /// * `body`
+    #[inline]
+    pub fn new_pipeline_expression<B: GetAstBuilder<'a>>(
+        span: Span,
+        head: Expression<'a>,
+        body: Expression<'a>,
+        builder: &B,
+    ) -> Self {
+        Self::PipelineExpression(PipelineExpression::boxed(span, head, body, builder))
+    }
+
+    /// Build an [`ArrayExpressionElement::TopicReference`].
+    ///
+    /// This node contains a [`TopicReference`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn new_topic_reference<B: GetAstBuilder<'a>>(span: Span, builder: &B) -> Self {
+        Self::TopicReference(TopicReference::boxed(span, builder))
+    }
+
     /// Build an [`ArrayExpressionElement::ComputedMemberExpression`].
     ///
     /// This node contains a [`ComputedMemberExpression`] that will be stored in the memory arena.
@@ -2988,6 +3098,17 @@ impl<'a> ObjectPropertyKind<'a> {
     ) -> Self {
         Self::SpreadProperty(SpreadElement::boxed(span, argument, builder))
     }
+
+    /// Build an [`ObjectPropertyKind::ErrorNode`].
+    ///
+    /// This node contains an [`ErrorNode`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn new_error_node<B: GetAstBuilder<'a>>(span: Span, builder: &B) -> Self {
+        Self::ErrorNode(ErrorNode::boxed(span, builder))
+    }
 }
 
 impl<'a> ObjectProperty<'a> {
@@ -4261,6 +4382,61 @@ impl<'a> PropertyKey<'a> {
         Self::V8IntrinsicExpression(V8IntrinsicExpression::boxed(span, name, arguments, builder))
     }
 
+    /// Build a [`PropertyKey::FlowTypeCastExpression`].
+    ///
+    /// This node contains a [`FlowTypeCastExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code, excluding the enclosing parentheses.
+    /// * `expression`: The expression being cast.
+    /// * `type_annotation`: The type the expression is cast to.
+    #[inline]
+    pub fn new_flow_type_cast_expression<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        expression: Expression<'a>,
+        type_annotation: T1,
+        builder: &B,
+    ) -> Self
+    where
+        T1: IntoIn<'a, ArenaBox<'a, TSTypeAnnotation<'a>>>,
+    {
+        Self::FlowTypeCastExpression(FlowTypeCastExpression::boxed(
+            span,
+            expression,
+            type_annotation,
+            builder,
+        ))
+    }
+
+    /// Build a [`PropertyKey::PipelineExpression`].
+    ///
+    /// This node contains a [`PipelineExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `head`
+    /// * `body`
+    #[inline]
+    pub fn new_pipeline_expression<B: GetAstBuilder<'a>>(
+        span: Span,
+        head: Expression<'a>,
+        body: Expression<'a>,
+        builder: &B,
+    ) -> Self {
+        Self::PipelineExpression(PipelineExpression::boxed(span, head, body, builder))
+    }
+
+    /// Build a [`PropertyKey::TopicReference`].
+    ///
+    /// This node contains a [`TopicReference`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn new_topic_reference<B: GetAstBuilder<'a>>(span: Span, builder: &B) -> Self {
+        Self::TopicReference(TopicReference::boxed(span, builder))
+    }
+
     /// Build a [`PropertyKey::ComputedMemberExpression`].
     ///
     /// This node contains a [`ComputedMemberExpression`] that will be stored in the memory arena.
@@ -6209,6 +6385,61 @@ impl<'a> Argument<'a> {
         Self::V8IntrinsicExpression(V8IntrinsicExpression::boxed(span, name, arguments, builder))
     }
 
+    /// Build an [`Argument::FlowTypeCastExpression`].
+    ///
+    /// This node contains a [`FlowTypeCastExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code, excluding the enclosing parentheses.
+    /// * `expression`: The expression being cast.
+    /// * `type_annotation`: The type the expression is cast to.
+    #[inline]
+    pub fn new_flow_type_cast_expression<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        expression: Expression<'a>,
+        type_annotation: T1,
+        builder: &B,
+    ) -> Self
+    where
+        T1: IntoIn<'a, ArenaBox<'a, TSTypeAnnotation<'a>>>,
+    {
+        Self::FlowTypeCastExpression(FlowTypeCastExpression::boxed(
+            span,
+            expression,
+            type_annotation,
+            builder,
+        ))
+    }
+
+    /// Build an [`Argument::PipelineExpression`].
+    ///
+    /// This node contains a [`PipelineExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `head`
+    /// * `body`
+    #[inline]
+    pub fn new_pipeline_expression<B: GetAstBuilder<'a>>(
+        span: Span,
+        head: Expression<'a>,
+        body: Expression<'a>,
+        builder: &B,
+    ) -> Self {
+        Self::PipelineExpression(PipelineExpression::boxed(span, head, body, builder))
+    }
+
+    /// Build an [`Argument::TopicReference`].
+    ///
+    /// This node contains a [`TopicReference`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn new_topic_reference<B: GetAstBuilder<'a>>(span: Span, builder: &B) -> Self {
+        Self::TopicReference(TopicReference::boxed(span, builder))
+    }
+
     /// Build an [`Argument::ComputedMemberExpression`].
     ///
     /// This node contains a [`ComputedMemberExpression`] that will be stored in the memory arena.
@@ -8402,6 +8633,17 @@ impl<'a> Statement<'a> {
         ))
     }
 
+    /// Build a [`Statement::ErrorNode`].
+    ///
+    /// This node contains an [`ErrorNode`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn new_error_node<B: GetAstBuilder<'a>>(span: Span, builder: &B) -> Self {
+        Self::ErrorNode(ErrorNode::boxed(span, builder))
+    }
+
     /// Build a [`Statement::VariableDeclaration`].
     ///
     /// This node contains a [`VariableDeclaration`] that will be stored in the memory arena.
@@ -8954,6 +9196,79 @@ impl<'a> Statement<'a> {
         ))
     }
 
+    /// Build a [`Statement::FlowOpaqueTypeDeclaration`].
+    ///
+    /// This node contains a [`FlowOpaqueTypeDeclaration`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code.
+    /// * `id`: Opaque type's identifier, e.g. `ID` in `opaque type ID = string`.
+    /// * `type_parameters`: Type parameters, e.g. `<T>` in `opaque type Box<T> = Array<T>`.
+    /// * `supertype`: Type which the opaque type is visible as outside of its defining file.
+    /// * `impltype`: Underlying type. [`None`] in `declare opaque type` declarations.
+    /// * `declare`: Was this declared with `declare opaque type`?
+    #[inline]
+    pub fn new_flow_opaque_type_declaration<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        id: BindingIdentifier<'a>,
+        type_parameters: T1,
+        supertype: Option<TSType<'a>>,
+        impltype: Option<TSType<'a>>,
+        declare: bool,
+        builder: &B,
+    ) -> Self
+    where
+        T1: IntoIn<'a, Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>>,
+    {
+        Self::FlowOpaqueTypeDeclaration(FlowOpaqueTypeDeclaration::boxed(
+            span,
+            id,
+            type_parameters,
+            supertype,
+            impltype,
+            declare,
+            builder,
+        ))
+    }
+
+    /// Build a [`Statement::FlowOpaqueTypeDeclaration`] with `scope_id`.
+    ///
+    /// This node contains a [`FlowOpaqueTypeDeclaration`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code.
+    /// * `id`: Opaque type's identifier, e.g. `ID` in `opaque type ID = string`.
+    /// * `type_parameters`: Type parameters, e.g. `<T>` in `opaque type Box<T> = Array<T>`.
+    /// * `supertype`: Type which the opaque type is visible as outside of its defining file.
+    /// * `impltype`: Underlying type. [`None`] in `declare opaque type` declarations.
+    /// * `declare`: Was this declared with `declare opaque type`?
+    /// * `scope_id`: Scope of the type parameters.
+    #[inline]
+    pub fn new_flow_opaque_type_declaration_with_scope_id<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        id: BindingIdentifier<'a>,
+        type_parameters: T1,
+        supertype: Option<TSType<'a>>,
+        impltype: Option<TSType<'a>>,
+        declare: bool,
+        scope_id: ScopeId,
+        builder: &B,
+    ) -> Self
+    where
+        T1: IntoIn<'a, Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>>,
+    {
+        Self::FlowOpaqueTypeDeclaration(FlowOpaqueTypeDeclaration::boxed_with_scope_id(
+            span,
+            id,
+            type_parameters,
+            supertype,
+            impltype,
+            declare,
+            scope_id,
+            builder,
+        ))
+    }
+
     /// Build a [`Statement::ImportDeclaration`].
     ///
     /// This node contains an [`ImportDeclaration`] that will be stored in the memory arena.
@@ -9786,6 +10101,79 @@ impl<'a> Declaration<'a> {
             builder,
         ))
     }
+
+    /// Build a [`Declaration::FlowOpaqueTypeDeclaration`].
+    ///
+    /// This node contains a [`FlowOpaqueTypeDeclaration`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code.
+    /// * `id`: Opaque type's identifier, e.g. `ID` in `opaque type ID = string`.
+    /// * `type_parameters`: Type parameters, e.g. `<T>` in `opaque type Box<T> = Array<T>`.
+    /// * `supertype`: Type which the opaque type is visible as outside of its defining file.
+    /// * `impltype`: Underlying type. [`None`] in `declare opaque type` declarations.
+    /// * `declare`: Was this declared with `declare opaque type`?
+    #[inline]
+    pub fn new_flow_opaque_type_declaration<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        id: BindingIdentifier<'a>,
+        type_parameters: T1,
+        supertype: Option<TSType<'a>>,
+        impltype: Option<TSType<'a>>,
+        declare: bool,
+        builder: &B,
+    ) -> Self
+    where
+        T1: IntoIn<'a, Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>>,
+    {
+        Self::FlowOpaqueTypeDeclaration(FlowOpaqueTypeDeclaration::boxed(
+            span,
+            id,
+            type_parameters,
+            supertype,
+            impltype,
+            declare,
+            builder,
+        ))
+    }
+
+    /// Build a [`Declaration::FlowOpaqueTypeDeclaration`] with `scope_id`.
+    ///
+    /// This node contains a [`FlowOpaqueTypeDeclaration`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code.
+    /// * `id`: Opaque type's identifier, e.g. `ID` in `opaque type ID = string`.
+    /// * `type_parameters`: Type parameters, e.g. `<T>` in `opaque type Box<T> = Array<T>`.
+    /// * `supertype`: Type which the opaque type is visible as outside of its defining file.
+    /// * `impltype`: Underlying type. [`None`] in `declare opaque type` declarations.
+    /// * `declare`: Was this declared with `declare opaque type`?
+    /// * `scope_id`: Scope of the type parameters.
+    #[inline]
+    pub fn new_flow_opaque_type_declaration_with_scope_id<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        id: BindingIdentifier<'a>,
+        type_parameters: T1,
+        supertype: Option<TSType<'a>>,
+        impltype: Option<TSType<'a>>,
+        declare: bool,
+        scope_id: ScopeId,
+        builder: &B,
+    ) -> Self
+    where
+        T1: IntoIn<'a, Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>>,
+    {
+        Self::FlowOpaqueTypeDeclaration(FlowOpaqueTypeDeclaration::boxed_with_scope_id(
+            span,
+            id,
+            type_parameters,
+            supertype,
+            impltype,
+            declare,
+            scope_id,
+            builder,
+        ))
+    }
 }
 
 impl<'a> VariableDeclaration<'a> {
@@ -11371,9 +11759,64 @@ impl<'a> ForStatementInit<'a> {
         Self::V8IntrinsicExpression(V8IntrinsicExpression::boxed(span, name, arguments, builder))
     }
 
-    /// Build a [`ForStatementInit::ComputedMemberExpression`].
+    /// Build a [`ForStatementInit::FlowTypeCastExpression`].
     ///
-    /// This node contains a [`ComputedMemberExpression`] that will be stored in the memory arena.
+    /// This node contains a [`FlowTypeCastExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code, excluding the enclosing parentheses.
+    /// * `expression`: The expression being cast.
+    /// * `type_annotation`: The type the expression is cast to.
+    #[inline]
+    pub fn new_flow_type_cast_expression<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        expression: Expression<'a>,
+        type_annotation: T1,
+        builder: &B,
+    ) -> Self
+    where
+        T1: IntoIn<'a, ArenaBox<'a, TSTypeAnnotation<'a>>>,
+    {
+        Self::FlowTypeCastExpression(FlowTypeCastExpression::boxed(
+            span,
+            expression,
+            type_annotation,
+            builder,
+        ))
+    }
+
+    /// Build a [`ForStatementInit::PipelineExpression`].
+    ///
+    /// This node contains a [`PipelineExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `head`
+    /// * `body`
+    #[inline]
+    pub fn new_pipeline_expression<B: GetAstBuilder<'a>>(
+        span: Span,
+        head: Expression<'a>,
+        body: Expression<'a>,
+        builder: &B,
+    ) -> Self {
+        Self::PipelineExpression(PipelineExpression::boxed(span, head, body, builder))
+    }
+
+    /// Build a [`ForStatementInit::TopicReference`].
+    ///
+    /// This node contains a [`TopicReference`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn new_topic_reference<B: GetAstBuilder<'a>>(span: Span, builder: &B) -> Self {
+        Self::TopicReference(TopicReference::boxed(span, builder))
+    }
+
+    /// Build a [`ForStatementInit::ComputedMemberExpression`].
+    ///
+    /// This node contains a [`ComputedMemberExpression`] that will be stored in the memory arena.
     ///
     /// ## Parameters
     /// * `span`: The [`Span`] covering this node
@@ -14052,6 +14495,17 @@ impl<'a> ClassElement<'a> {
             builder,
         ))
     }
+
+    /// Build a [`ClassElement::ErrorNode`].
+    ///
+    /// This node contains an [`ErrorNode`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn new_error_node<B: GetAstBuilder<'a>>(span: Span, builder: &B) -> Self {
+        Self::ErrorNode(ErrorNode::boxed(span, builder))
+    }
 }
 
 impl<'a> MethodDefinition<'a> {
@@ -16753,6 +17207,61 @@ impl<'a> ExportDefaultDeclarationKind<'a> {
         Self::V8IntrinsicExpression(V8IntrinsicExpression::boxed(span, name, arguments, builder))
     }
 
+    /// Build an [`ExportDefaultDeclarationKind::FlowTypeCastExpression`].
+    ///
+    /// This node contains a [`FlowTypeCastExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code, excluding the enclosing parentheses.
+    /// * `expression`: The expression being cast.
+    /// * `type_annotation`: The type the expression is cast to.
+    #[inline]
+    pub fn new_flow_type_cast_expression<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        expression: Expression<'a>,
+        type_annotation: T1,
+        builder: &B,
+    ) -> Self
+    where
+        T1: IntoIn<'a, ArenaBox<'a, TSTypeAnnotation<'a>>>,
+    {
+        Self::FlowTypeCastExpression(FlowTypeCastExpression::boxed(
+            span,
+            expression,
+            type_annotation,
+            builder,
+        ))
+    }
+
+    /// Build an [`ExportDefaultDeclarationKind::PipelineExpression`].
+    ///
+    /// This node contains a [`PipelineExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `head`
+    /// * `body`
+    #[inline]
+    pub fn new_pipeline_expression<B: GetAstBuilder<'a>>(
+        span: Span,
+        head: Expression<'a>,
+        body: Expression<'a>,
+        builder: &B,
+    ) -> Self {
+        Self::PipelineExpression(PipelineExpression::boxed(span, head, body, builder))
+    }
+
+    /// Build an [`ExportDefaultDeclarationKind::TopicReference`].
+    ///
+    /// This node contains a [`TopicReference`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn new_topic_reference<B: GetAstBuilder<'a>>(span: Span, builder: &B) -> Self {
+        Self::TopicReference(TopicReference::boxed(span, builder))
+    }
+
     /// Build an [`ExportDefaultDeclarationKind::ComputedMemberExpression`].
     ///
     /// This node contains a [`ComputedMemberExpression`] that will be stored in the memory arena.
@@ -16963,6 +17472,101 @@ impl<'a> V8IntrinsicExpression<'a> {
     }
 }
 
+impl<'a> PipelineExpression<'a> {
+    /// Build a [`PipelineExpression`].
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`PipelineExpression::boxed`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `head`
+    /// * `body`
+    #[inline]
+    pub fn new<B: GetAstBuilder<'a>>(
+        span: Span,
+        head: Expression<'a>,
+        body: Expression<'a>,
+        builder: &B,
+    ) -> Self {
+        let builder = builder.builder();
+        PipelineExpression { node_id: Cell::new(builder.node_id()), span, head, body }
+    }
+
+    /// Build a [`PipelineExpression`], and store it in the memory arena.
+    ///
+    /// Returns a [`Box`](ArenaBox) containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`PipelineExpression::new`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `head`
+    /// * `body`
+    #[inline]
+    pub fn boxed<B: GetAstBuilder<'a>>(
+        span: Span,
+        head: Expression<'a>,
+        body: Expression<'a>,
+        builder: &B,
+    ) -> ArenaBox<'a, Self> {
+        ArenaBox::new_in(Self::new(span, head, body, builder), builder.builder())
+    }
+}
+
+impl TopicReference {
+    /// Build a [`TopicReference`].
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`TopicReference::boxed`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn new<'a, B: GetAstBuilder<'a>>(span: Span, builder: &B) -> Self {
+        let builder = builder.builder();
+        TopicReference { node_id: Cell::new(builder.node_id()), span }
+    }
+
+    /// Build a [`TopicReference`], and store it in the memory arena.
+    ///
+    /// Returns a [`Box`](ArenaBox) containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`TopicReference::new`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn boxed<'a, B: GetAstBuilder<'a>>(span: Span, builder: &B) -> ArenaBox<'a, Self> {
+        ArenaBox::new_in(Self::new(span, builder), builder.builder())
+    }
+}
+
+impl ErrorNode {
+    /// Build an [`ErrorNode`].
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`ErrorNode::boxed`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn new<'a, B: GetAstBuilder<'a>>(span: Span, builder: &B) -> Self {
+        let builder = builder.builder();
+        ErrorNode { node_id: Cell::new(builder.node_id()), span }
+    }
+
+    /// Build an [`ErrorNode`], and store it in the memory arena.
+    ///
+    /// Returns a [`Box`](ArenaBox) containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`ErrorNode::new`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn boxed<'a, B: GetAstBuilder<'a>>(span: Span, builder: &B) -> ArenaBox<'a, Self> {
+        ArenaBox::new_in(Self::new(span, builder), builder.builder())
+    }
+}
+
 impl BooleanLiteral {
     /// Build a [`BooleanLiteral`].
     ///
@@ -18989,6 +19593,61 @@ impl<'a> JSXExpression<'a> {
         Self::V8IntrinsicExpression(V8IntrinsicExpression::boxed(span, name, arguments, builder))
     }
 
+    /// Build a [`JSXExpression::FlowTypeCastExpression`].
+    ///
+    /// This node contains a [`FlowTypeCastExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code, excluding the enclosing parentheses.
+    /// * `expression`: The expression being cast.
+    /// * `type_annotation`: The type the expression is cast to.
+    #[inline]
+    pub fn new_flow_type_cast_expression<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        expression: Expression<'a>,
+        type_annotation: T1,
+        builder: &B,
+    ) -> Self
+    where
+        T1: IntoIn<'a, ArenaBox<'a, TSTypeAnnotation<'a>>>,
+    {
+        Self::FlowTypeCastExpression(FlowTypeCastExpression::boxed(
+            span,
+            expression,
+            type_annotation,
+            builder,
+        ))
+    }
+
+    /// Build a [`JSXExpression::PipelineExpression`].
+    ///
+    /// This node contains a [`PipelineExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    /// * `head`
+    /// * `body`
+    #[inline]
+    pub fn new_pipeline_expression<B: GetAstBuilder<'a>>(
+        span: Span,
+        head: Expression<'a>,
+        body: Expression<'a>,
+        builder: &B,
+    ) -> Self {
+        Self::PipelineExpression(PipelineExpression::boxed(span, head, body, builder))
+    }
+
+    /// Build a [`JSXExpression::TopicReference`].
+    ///
+    /// This node contains a [`TopicReference`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn new_topic_reference<B: GetAstBuilder<'a>>(span: Span, builder: &B) -> Self {
+        Self::TopicReference(TopicReference::boxed(span, builder))
+    }
+
     /// Build a [`JSXExpression::ComputedMemberExpression`].
     ///
     /// This node contains a [`ComputedMemberExpression`] that will be stored in the memory arena.
@@ -26247,3 +26906,208 @@ impl JSDocUnknownType {
         ArenaBox::new_in(Self::new(span, builder), builder.builder())
     }
 }
+
+impl<'a> FlowTypeCastExpression<'a> {
+    /// Build a [`FlowTypeCastExpression`].
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`FlowTypeCastExpression::boxed`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code, excluding the enclosing parentheses.
+    /// * `expression`: The expression being cast.
+    /// * `type_annotation`: The type the expression is cast to.
+    #[inline]
+    pub fn new<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        expression: Expression<'a>,
+        type_annotation: T1,
+        builder: &B,
+    ) -> Self
+    where
+        T1: IntoIn<'a, ArenaBox<'a, TSTypeAnnotation<'a>>>,
+    {
+        let builder = builder.builder();
+        FlowTypeCastExpression {
+            node_id: Cell::new(builder.node_id()),
+            span,
+            expression,
+            type_annotation: type_annotation.into_in(builder.allocator()),
+        }
+    }
+
+    /// Build a [`FlowTypeCastExpression`], and store it in the memory arena.
+    ///
+    /// Returns a [`Box`](ArenaBox) containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`FlowTypeCastExpression::new`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code, excluding the enclosing parentheses.
+    /// * `expression`: The expression being cast.
+    /// * `type_annotation`: The type the expression is cast to.
+    #[inline]
+    pub fn boxed<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        expression: Expression<'a>,
+        type_annotation: T1,
+        builder: &B,
+    ) -> ArenaBox<'a, Self>
+    where
+        T1: IntoIn<'a, ArenaBox<'a, TSTypeAnnotation<'a>>>,
+    {
+        ArenaBox::new_in(Self::new(span, expression, type_annotation, builder), builder.builder())
+    }
+}
+
+impl<'a> FlowOpaqueTypeDeclaration<'a> {
+    /// Build a [`FlowOpaqueTypeDeclaration`].
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`FlowOpaqueTypeDeclaration::boxed`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code.
+    /// * `id`: Opaque type's identifier, e.g. `ID` in `opaque type ID = string`.
+    /// * `type_parameters`: Type parameters, e.g. `<T>` in `opaque type Box<T> = Array<T>`.
+    /// * `supertype`: Type which the opaque type is visible as outside of its defining file.
+    /// * `impltype`: Underlying type. [`None`] in `declare opaque type` declarations.
+    /// * `declare`: Was this declared with `declare opaque type`?
+    #[inline]
+    pub fn new<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        id: BindingIdentifier<'a>,
+        type_parameters: T1,
+        supertype: Option<TSType<'a>>,
+        impltype: Option<TSType<'a>>,
+        declare: bool,
+        builder: &B,
+    ) -> Self
+    where
+        T1: IntoIn<'a, Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>>,
+    {
+        let builder = builder.builder();
+        FlowOpaqueTypeDeclaration {
+            node_id: Cell::new(builder.node_id()),
+            span,
+            id,
+            type_parameters: type_parameters.into_in(builder.allocator()),
+            supertype,
+            impltype,
+            declare,
+            scope_id: Default::default(),
+        }
+    }
+
+    /// Build a [`FlowOpaqueTypeDeclaration`], and store it in the memory arena.
+    ///
+    /// Returns a [`Box`](ArenaBox) containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`FlowOpaqueTypeDeclaration::new`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code.
+    /// * `id`: Opaque type's identifier, e.g. `ID` in `opaque type ID = string`.
+    /// * `type_parameters`: Type parameters, e.g. `<T>` in `opaque type Box<T> = Array<T>`.
+    /// * `supertype`: Type which the opaque type is visible as outside of its defining file.
+    /// * `impltype`: Underlying type. [`None`] in `declare opaque type` declarations.
+    /// * `declare`: Was this declared with `declare opaque type`?
+    #[inline]
+    pub fn boxed<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        id: BindingIdentifier<'a>,
+        type_parameters: T1,
+        supertype: Option<TSType<'a>>,
+        impltype: Option<TSType<'a>>,
+        declare: bool,
+        builder: &B,
+    ) -> ArenaBox<'a, Self>
+    where
+        T1: IntoIn<'a, Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>>,
+    {
+        ArenaBox::new_in(
+            Self::new(span, id, type_parameters, supertype, impltype, declare, builder),
+            builder.builder(),
+        )
+    }
+
+    /// Build a [`FlowOpaqueTypeDeclaration`] with `scope_id`.
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`FlowOpaqueTypeDeclaration::boxed_with_scope_id`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code.
+    /// * `id`: Opaque type's identifier, e.g. `ID` in `opaque type ID = string`.
+    /// * `type_parameters`: Type parameters, e.g. `<T>` in `opaque type Box<T> = Array<T>`.
+    /// * `supertype`: Type which the opaque type is visible as outside of its defining file.
+    /// * `impltype`: Underlying type. [`None`] in `declare opaque type` declarations.
+    /// * `declare`: Was this declared with `declare opaque type`?
+    /// * `scope_id`: Scope of the type parameters.
+    #[inline]
+    pub fn new_with_scope_id<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        id: BindingIdentifier<'a>,
+        type_parameters: T1,
+        supertype: Option<TSType<'a>>,
+        impltype: Option<TSType<'a>>,
+        declare: bool,
+        scope_id: ScopeId,
+        builder: &B,
+    ) -> Self
+    where
+        T1: IntoIn<'a, Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>>,
+    {
+        let builder = builder.builder();
+        FlowOpaqueTypeDeclaration {
+            node_id: Cell::new(builder.node_id()),
+            span,
+            id,
+            type_parameters: type_parameters.into_in(builder.allocator()),
+            supertype,
+            impltype,
+            declare,
+            scope_id: Cell::new(Some(scope_id)),
+        }
+    }
+
+    /// Build a [`FlowOpaqueTypeDeclaration`] with `scope_id`, and store it in the memory arena.
+    ///
+    /// Returns a [`Box`](ArenaBox) containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`FlowOpaqueTypeDeclaration::new_with_scope_id`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: Node location in source code.
+    /// * `id`: Opaque type's identifier, e.g. `ID` in `opaque type ID = string`.
+    /// * `type_parameters`: Type parameters, e.g. `<T>` in `opaque type Box<T> = Array<T>`.
+    /// * `supertype`: Type which the opaque type is visible as outside of its defining file.
+    /// * `impltype`: Underlying type. [`None`] in `declare opaque type` declarations.
+    /// * `declare`: Was this declared with `declare opaque type`?
+    /// * `scope_id`: Scope of the type parameters.
+    #[inline]
+    pub fn boxed_with_scope_id<B: GetAstBuilder<'a>, T1>(
+        span: Span,
+        id: BindingIdentifier<'a>,
+        type_parameters: T1,
+        supertype: Option<TSType<'a>>,
+        impltype: Option<TSType<'a>>,
+        declare: bool,
+        scope_id: ScopeId,
+        builder: &B,
+    ) -> ArenaBox<'a, Self>
+    where
+        T1: IntoIn<'a, Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>>,
+    {
+        ArenaBox::new_in(
+            Self::new_with_scope_id(
+                span,
+                id,
+                type_parameters,
+                supertype,
+                impltype,
+                declare,
+                scope_id,
+                builder,
+            ),
+            builder.builder(),
+        )
+    }
+}
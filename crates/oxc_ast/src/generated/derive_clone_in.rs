@@ -8,6 +8,7 @@ use std::cell::Cell;
 use oxc_allocator::{Allocator, CloneIn};
 
 use crate::ast::comment::*;
+use crate::ast::flow::*;
 use crate::ast::js::*;
 use crate::ast::jsx::*;
 use crate::ast::literal::*;
@@ -150,6 +151,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for Expression<'_> {
             Self::V8IntrinsicExpression(it) => {
                 Expression::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::FlowTypeCastExpression(it) => {
+                Expression::FlowTypeCastExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                Expression::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                Expression::TopicReference(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 Expression::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -284,6 +294,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for Expression<'_> {
             Self::V8IntrinsicExpression(it) => Expression::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::FlowTypeCastExpression(it) => Expression::FlowTypeCastExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::PipelineExpression(it) => {
+                Expression::PipelineExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                Expression::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => Expression::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -546,6 +565,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for ArrayExpressionElement<'_> {
             Self::V8IntrinsicExpression(it) => {
                 ArrayExpressionElement::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::FlowTypeCastExpression(it) => {
+                ArrayExpressionElement::FlowTypeCastExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                ArrayExpressionElement::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                ArrayExpressionElement::TopicReference(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 ArrayExpressionElement::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -688,6 +716,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for ArrayExpressionElement<'_> {
             Self::V8IntrinsicExpression(it) => ArrayExpressionElement::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::FlowTypeCastExpression(it) => ArrayExpressionElement::FlowTypeCastExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::PipelineExpression(it) => ArrayExpressionElement::PipelineExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::TopicReference(it) => ArrayExpressionElement::TopicReference(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
             Self::ComputedMemberExpression(it) => ArrayExpressionElement::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -747,6 +784,7 @@ impl<'new_alloc> CloneIn<'new_alloc> for ObjectPropertyKind<'_> {
             Self::SpreadProperty(it) => {
                 ObjectPropertyKind::SpreadProperty(CloneIn::clone_in(it, allocator))
             }
+            Self::ErrorNode(it) => ObjectPropertyKind::ErrorNode(CloneIn::clone_in(it, allocator)),
         }
     }
 
@@ -758,6 +796,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for ObjectPropertyKind<'_> {
             Self::SpreadProperty(it) => ObjectPropertyKind::SpreadProperty(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::ErrorNode(it) => {
+                ObjectPropertyKind::ErrorNode(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
         }
     }
 }
@@ -903,6 +944,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for PropertyKey<'_> {
             Self::V8IntrinsicExpression(it) => {
                 PropertyKey::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::FlowTypeCastExpression(it) => {
+                PropertyKey::FlowTypeCastExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                PropertyKey::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                PropertyKey::TopicReference(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 PropertyKey::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -1043,6 +1093,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for PropertyKey<'_> {
             Self::V8IntrinsicExpression(it) => PropertyKey::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::FlowTypeCastExpression(it) => PropertyKey::FlowTypeCastExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::PipelineExpression(it) => {
+                PropertyKey::PipelineExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                PropertyKey::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => PropertyKey::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -1454,6 +1513,13 @@ impl<'new_alloc> CloneIn<'new_alloc> for Argument<'_> {
             Self::V8IntrinsicExpression(it) => {
                 Argument::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::FlowTypeCastExpression(it) => {
+                Argument::FlowTypeCastExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                Argument::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => Argument::TopicReference(CloneIn::clone_in(it, allocator)),
             Self::ComputedMemberExpression(it) => {
                 Argument::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -1589,6 +1655,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for Argument<'_> {
             Self::V8IntrinsicExpression(it) => {
                 Argument::V8IntrinsicExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
             }
+            Self::FlowTypeCastExpression(it) => {
+                Argument::FlowTypeCastExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                Argument::PipelineExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                Argument::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => Argument::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -2376,6 +2451,7 @@ impl<'new_alloc> CloneIn<'new_alloc> for Statement<'_> {
             Self::TryStatement(it) => Statement::TryStatement(CloneIn::clone_in(it, allocator)),
             Self::WhileStatement(it) => Statement::WhileStatement(CloneIn::clone_in(it, allocator)),
             Self::WithStatement(it) => Statement::WithStatement(CloneIn::clone_in(it, allocator)),
+            Self::ErrorNode(it) => Statement::ErrorNode(CloneIn::clone_in(it, allocator)),
             Self::VariableDeclaration(it) => {
                 Statement::VariableDeclaration(CloneIn::clone_in(it, allocator))
             }
@@ -2403,6 +2479,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for Statement<'_> {
             Self::TSImportEqualsDeclaration(it) => {
                 Statement::TSImportEqualsDeclaration(CloneIn::clone_in(it, allocator))
             }
+            Self::FlowOpaqueTypeDeclaration(it) => {
+                Statement::FlowOpaqueTypeDeclaration(CloneIn::clone_in(it, allocator))
+            }
             Self::ImportDeclaration(it) => {
                 Statement::ImportDeclaration(CloneIn::clone_in(it, allocator))
             }
@@ -2480,6 +2559,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for Statement<'_> {
             Self::WithStatement(it) => {
                 Statement::WithStatement(CloneIn::clone_in_with_semantic_ids(it, allocator))
             }
+            Self::ErrorNode(it) => {
+                Statement::ErrorNode(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::VariableDeclaration(it) => {
                 Statement::VariableDeclaration(CloneIn::clone_in_with_semantic_ids(it, allocator))
             }
@@ -2507,6 +2589,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for Statement<'_> {
             Self::TSImportEqualsDeclaration(it) => Statement::TSImportEqualsDeclaration(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::FlowOpaqueTypeDeclaration(it) => Statement::FlowOpaqueTypeDeclaration(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
             Self::ImportDeclaration(it) => {
                 Statement::ImportDeclaration(CloneIn::clone_in_with_semantic_ids(it, allocator))
             }
@@ -2625,6 +2710,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for Declaration<'_> {
             Self::TSImportEqualsDeclaration(it) => {
                 Declaration::TSImportEqualsDeclaration(CloneIn::clone_in(it, allocator))
             }
+            Self::FlowOpaqueTypeDeclaration(it) => {
+                Declaration::FlowOpaqueTypeDeclaration(CloneIn::clone_in(it, allocator))
+            }
         }
     }
 
@@ -2657,6 +2745,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for Declaration<'_> {
             Self::TSImportEqualsDeclaration(it) => Declaration::TSImportEqualsDeclaration(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::FlowOpaqueTypeDeclaration(it) => Declaration::FlowOpaqueTypeDeclaration(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
         }
     }
 }
@@ -2983,6 +3074,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for ForStatementInit<'_> {
             Self::V8IntrinsicExpression(it) => {
                 ForStatementInit::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::FlowTypeCastExpression(it) => {
+                ForStatementInit::FlowTypeCastExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                ForStatementInit::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                ForStatementInit::TopicReference(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 ForStatementInit::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -3120,6 +3220,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for ForStatementInit<'_> {
             Self::V8IntrinsicExpression(it) => ForStatementInit::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::FlowTypeCastExpression(it) => ForStatementInit::FlowTypeCastExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::PipelineExpression(it) => ForStatementInit::PipelineExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::TopicReference(it) => {
+                ForStatementInit::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => ForStatementInit::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -4006,6 +4115,7 @@ impl<'new_alloc> CloneIn<'new_alloc> for ClassElement<'_> {
             Self::TSIndexSignature(it) => {
                 ClassElement::TSIndexSignature(CloneIn::clone_in(it, allocator))
             }
+            Self::ErrorNode(it) => ClassElement::ErrorNode(CloneIn::clone_in(it, allocator)),
         }
     }
 
@@ -4026,6 +4136,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for ClassElement<'_> {
             Self::TSIndexSignature(it) => {
                 ClassElement::TSIndexSignature(CloneIn::clone_in_with_semantic_ids(it, allocator))
             }
+            Self::ErrorNode(it) => {
+                ClassElement::ErrorNode(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
         }
     }
 }
@@ -4792,6 +4905,17 @@ impl<'new_alloc> CloneIn<'new_alloc> for ExportDefaultDeclarationKind<'_> {
             Self::V8IntrinsicExpression(it) => ExportDefaultDeclarationKind::V8IntrinsicExpression(
                 CloneIn::clone_in(it, allocator),
             ),
+            Self::FlowTypeCastExpression(it) => {
+                ExportDefaultDeclarationKind::FlowTypeCastExpression(CloneIn::clone_in(
+                    it, allocator,
+                ))
+            }
+            Self::PipelineExpression(it) => {
+                ExportDefaultDeclarationKind::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                ExportDefaultDeclarationKind::TopicReference(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 ExportDefaultDeclarationKind::ComputedMemberExpression(CloneIn::clone_in(
                     it, allocator,
@@ -4951,6 +5075,17 @@ impl<'new_alloc> CloneIn<'new_alloc> for ExportDefaultDeclarationKind<'_> {
             Self::V8IntrinsicExpression(it) => ExportDefaultDeclarationKind::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::FlowTypeCastExpression(it) => {
+                ExportDefaultDeclarationKind::FlowTypeCastExpression(
+                    CloneIn::clone_in_with_semantic_ids(it, allocator),
+                )
+            }
+            Self::PipelineExpression(it) => ExportDefaultDeclarationKind::PipelineExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::TopicReference(it) => ExportDefaultDeclarationKind::TopicReference(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
             Self::ComputedMemberExpression(it) => {
                 ExportDefaultDeclarationKind::ComputedMemberExpression(
                     CloneIn::clone_in_with_semantic_ids(it, allocator),
@@ -5024,6 +5159,61 @@ impl<'new_alloc> CloneIn<'new_alloc> for V8IntrinsicExpression<'_> {
     }
 }
 
+impl<'new_alloc> CloneIn<'new_alloc> for PipelineExpression<'_> {
+    type Cloned = PipelineExpression<'new_alloc>;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        PipelineExpression {
+            node_id: Default::default(),
+            span: CloneIn::clone_in(&self.span, allocator),
+            head: CloneIn::clone_in(&self.head, allocator),
+            body: CloneIn::clone_in(&self.body, allocator),
+        }
+    }
+
+    fn clone_in_with_semantic_ids(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        PipelineExpression {
+            node_id: CloneIn::clone_in_with_semantic_ids(&self.node_id, allocator),
+            span: CloneIn::clone_in_with_semantic_ids(&self.span, allocator),
+            head: CloneIn::clone_in_with_semantic_ids(&self.head, allocator),
+            body: CloneIn::clone_in_with_semantic_ids(&self.body, allocator),
+        }
+    }
+}
+
+impl<'new_alloc> CloneIn<'new_alloc> for TopicReference {
+    type Cloned = TopicReference;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        TopicReference {
+            node_id: Default::default(),
+            span: CloneIn::clone_in(&self.span, allocator),
+        }
+    }
+
+    fn clone_in_with_semantic_ids(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        TopicReference {
+            node_id: CloneIn::clone_in_with_semantic_ids(&self.node_id, allocator),
+            span: CloneIn::clone_in_with_semantic_ids(&self.span, allocator),
+        }
+    }
+}
+
+impl<'new_alloc> CloneIn<'new_alloc> for ErrorNode {
+    type Cloned = ErrorNode;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        ErrorNode { node_id: Default::default(), span: CloneIn::clone_in(&self.span, allocator) }
+    }
+
+    fn clone_in_with_semantic_ids(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        ErrorNode {
+            node_id: CloneIn::clone_in_with_semantic_ids(&self.node_id, allocator),
+            span: CloneIn::clone_in_with_semantic_ids(&self.span, allocator),
+        }
+    }
+}
+
 impl<'new_alloc> CloneIn<'new_alloc> for BooleanLiteral {
     type Cloned = BooleanLiteral;
 
@@ -5577,6 +5767,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for JSXExpression<'_> {
             Self::V8IntrinsicExpression(it) => {
                 JSXExpression::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::FlowTypeCastExpression(it) => {
+                JSXExpression::FlowTypeCastExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                JSXExpression::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                JSXExpression::TopicReference(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 JSXExpression::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -5714,6 +5913,15 @@ impl<'new_alloc> CloneIn<'new_alloc> for JSXExpression<'_> {
             Self::V8IntrinsicExpression(it) => JSXExpression::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::FlowTypeCastExpression(it) => JSXExpression::FlowTypeCastExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::PipelineExpression(it) => JSXExpression::PipelineExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::TopicReference(it) => {
+                JSXExpression::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => JSXExpression::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -8412,6 +8620,58 @@ impl<'new_alloc> CloneIn<'new_alloc> for JSDocUnknownType {
     }
 }
 
+impl<'new_alloc> CloneIn<'new_alloc> for FlowTypeCastExpression<'_> {
+    type Cloned = FlowTypeCastExpression<'new_alloc>;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        FlowTypeCastExpression {
+            node_id: Default::default(),
+            span: CloneIn::clone_in(&self.span, allocator),
+            expression: CloneIn::clone_in(&self.expression, allocator),
+            type_annotation: CloneIn::clone_in(&self.type_annotation, allocator),
+        }
+    }
+
+    fn clone_in_with_semantic_ids(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        FlowTypeCastExpression {
+            node_id: CloneIn::clone_in_with_semantic_ids(&self.node_id, allocator),
+            span: CloneIn::clone_in_with_semantic_ids(&self.span, allocator),
+            expression: CloneIn::clone_in_with_semantic_ids(&self.expression, allocator),
+            type_annotation: CloneIn::clone_in_with_semantic_ids(&self.type_annotation, allocator),
+        }
+    }
+}
+
+impl<'new_alloc> CloneIn<'new_alloc> for FlowOpaqueTypeDeclaration<'_> {
+    type Cloned = FlowOpaqueTypeDeclaration<'new_alloc>;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        FlowOpaqueTypeDeclaration {
+            node_id: Default::default(),
+            span: CloneIn::clone_in(&self.span, allocator),
+            id: CloneIn::clone_in(&self.id, allocator),
+            type_parameters: CloneIn::clone_in(&self.type_parameters, allocator),
+            supertype: CloneIn::clone_in(&self.supertype, allocator),
+            impltype: CloneIn::clone_in(&self.impltype, allocator),
+            declare: CloneIn::clone_in(&self.declare, allocator),
+            scope_id: Default::default(),
+        }
+    }
+
+    fn clone_in_with_semantic_ids(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        FlowOpaqueTypeDeclaration {
+            node_id: CloneIn::clone_in_with_semantic_ids(&self.node_id, allocator),
+            span: CloneIn::clone_in_with_semantic_ids(&self.span, allocator),
+            id: CloneIn::clone_in_with_semantic_ids(&self.id, allocator),
+            type_parameters: CloneIn::clone_in_with_semantic_ids(&self.type_parameters, allocator),
+            supertype: CloneIn::clone_in_with_semantic_ids(&self.supertype, allocator),
+            impltype: CloneIn::clone_in_with_semantic_ids(&self.impltype, allocator),
+            declare: CloneIn::clone_in_with_semantic_ids(&self.declare, allocator),
+            scope_id: CloneIn::clone_in_with_semantic_ids(&self.scope_id, allocator),
+        }
+    }
+}
+
 impl<'new_alloc> CloneIn<'new_alloc> for CommentKind {
     type Cloned = CommentKind;
 
@@ -6,6 +6,7 @@
 use oxc_span::ContentEq;
 
 use crate::ast::comment::*;
+use crate::ast::flow::*;
 use crate::ast::js::*;
 use crate::ast::jsx::*;
 use crate::ast::literal::*;
@@ -67,6 +68,9 @@ impl ContentEq for Expression<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::FlowTypeCastExpression(a), Self::FlowTypeCastExpression(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -162,6 +166,9 @@ impl ContentEq for ArrayExpressionElement<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::FlowTypeCastExpression(a), Self::FlowTypeCastExpression(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -189,6 +196,7 @@ impl ContentEq for ObjectPropertyKind<'_> {
         match (self, other) {
             (Self::ObjectProperty(a), Self::ObjectProperty(b)) => a.content_eq(b),
             (Self::SpreadProperty(a), Self::SpreadProperty(b)) => a.content_eq(b),
+            (Self::ErrorNode(a), Self::ErrorNode(b)) => a.content_eq(b),
             _ => false,
         }
     }
@@ -254,6 +262,9 @@ impl ContentEq for PropertyKey<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::FlowTypeCastExpression(a), Self::FlowTypeCastExpression(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -417,6 +428,9 @@ impl ContentEq for Argument<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::FlowTypeCastExpression(a), Self::FlowTypeCastExpression(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -682,6 +696,7 @@ impl ContentEq for Statement<'_> {
             (Self::TryStatement(a), Self::TryStatement(b)) => a.content_eq(b),
             (Self::WhileStatement(a), Self::WhileStatement(b)) => a.content_eq(b),
             (Self::WithStatement(a), Self::WithStatement(b)) => a.content_eq(b),
+            (Self::ErrorNode(a), Self::ErrorNode(b)) => a.content_eq(b),
             (Self::VariableDeclaration(a), Self::VariableDeclaration(b)) => a.content_eq(b),
             (Self::FunctionDeclaration(a), Self::FunctionDeclaration(b)) => a.content_eq(b),
             (Self::ClassDeclaration(a), Self::ClassDeclaration(b)) => a.content_eq(b),
@@ -693,6 +708,9 @@ impl ContentEq for Statement<'_> {
             (Self::TSImportEqualsDeclaration(a), Self::TSImportEqualsDeclaration(b)) => {
                 a.content_eq(b)
             }
+            (Self::FlowOpaqueTypeDeclaration(a), Self::FlowOpaqueTypeDeclaration(b)) => {
+                a.content_eq(b)
+            }
             (Self::ImportDeclaration(a), Self::ImportDeclaration(b)) => a.content_eq(b),
             (Self::ExportAllDeclaration(a), Self::ExportAllDeclaration(b)) => a.content_eq(b),
             (Self::ExportDefaultDeclaration(a), Self::ExportDefaultDeclaration(b)) => {
@@ -741,6 +759,9 @@ impl ContentEq for Declaration<'_> {
             (Self::TSImportEqualsDeclaration(a), Self::TSImportEqualsDeclaration(b)) => {
                 a.content_eq(b)
             }
+            (Self::FlowOpaqueTypeDeclaration(a), Self::FlowOpaqueTypeDeclaration(b)) => {
+                a.content_eq(b)
+            }
             _ => false,
         }
     }
@@ -861,6 +882,9 @@ impl ContentEq for ForStatementInit<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::FlowTypeCastExpression(a), Self::FlowTypeCastExpression(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -1159,6 +1183,7 @@ impl ContentEq for ClassElement<'_> {
             (Self::PropertyDefinition(a), Self::PropertyDefinition(b)) => a.content_eq(b),
             (Self::AccessorProperty(a), Self::AccessorProperty(b)) => a.content_eq(b),
             (Self::TSIndexSignature(a), Self::TSIndexSignature(b)) => a.content_eq(b),
+            (Self::ErrorNode(a), Self::ErrorNode(b)) => a.content_eq(b),
             _ => false,
         }
     }
@@ -1436,6 +1461,9 @@ impl ContentEq for ExportDefaultDeclarationKind<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::FlowTypeCastExpression(a), Self::FlowTypeCastExpression(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -1464,6 +1492,25 @@ impl ContentEq for V8IntrinsicExpression<'_> {
     }
 }
 
+impl ContentEq for PipelineExpression<'_> {
+    fn content_eq(&self, other: &Self) -> bool {
+        ContentEq::content_eq(&self.head, &other.head)
+            && ContentEq::content_eq(&self.body, &other.body)
+    }
+}
+
+impl ContentEq for TopicReference {
+    fn content_eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl ContentEq for ErrorNode {
+    fn content_eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
 impl ContentEq for BooleanLiteral {
     fn content_eq(&self, other: &Self) -> bool {
         ContentEq::content_eq(&self.value, &other.value)
@@ -1648,6 +1695,9 @@ impl ContentEq for JSXExpression<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::FlowTypeCastExpression(a), Self::FlowTypeCastExpression(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -2503,6 +2553,23 @@ impl ContentEq for JSDocUnknownType {
     }
 }
 
+impl ContentEq for FlowTypeCastExpression<'_> {
+    fn content_eq(&self, other: &Self) -> bool {
+        ContentEq::content_eq(&self.expression, &other.expression)
+            && ContentEq::content_eq(&self.type_annotation, &other.type_annotation)
+    }
+}
+
+impl ContentEq for FlowOpaqueTypeDeclaration<'_> {
+    fn content_eq(&self, other: &Self) -> bool {
+        ContentEq::content_eq(&self.id, &other.id)
+            && ContentEq::content_eq(&self.type_parameters, &other.type_parameters)
+            && ContentEq::content_eq(&self.supertype, &other.supertype)
+            && ContentEq::content_eq(&self.impltype, &other.impltype)
+            && ContentEq::content_eq(&self.declare, &other.declare)
+    }
+}
+
 impl ContentEq for CommentKind {
     fn content_eq(&self, other: &Self) -> bool {
         self == other
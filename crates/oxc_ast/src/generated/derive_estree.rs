@@ -8,6 +8,7 @@ use oxc_estree::{
 };
 
 use crate::ast::comment::*;
+use crate::ast::flow::*;
 use crate::ast::js::*;
 use crate::ast::jsx::*;
 use crate::ast::literal::*;
@@ -62,6 +63,9 @@ impl ESTree for Expression<'_> {
             Self::TSNonNullExpression(it) => it.serialize(serializer),
             Self::TSInstantiationExpression(it) => it.serialize(serializer),
             Self::V8IntrinsicExpression(it) => it.serialize(serializer),
+            Self::FlowTypeCastExpression(it) => it.serialize(serializer),
+            Self::PipelineExpression(it) => it.serialize(serializer),
+            Self::TopicReference(it) => it.serialize(serializer),
             Self::ComputedMemberExpression(it) => it.serialize(serializer),
             Self::StaticMemberExpression(it) => it.serialize(serializer),
             Self::PrivateFieldExpression(it) => it.serialize(serializer),
@@ -185,6 +189,9 @@ impl ESTree for ArrayExpressionElement<'_> {
             Self::TSNonNullExpression(it) => it.serialize(serializer),
             Self::TSInstantiationExpression(it) => it.serialize(serializer),
             Self::V8IntrinsicExpression(it) => it.serialize(serializer),
+            Self::FlowTypeCastExpression(it) => it.serialize(serializer),
+            Self::PipelineExpression(it) => it.serialize(serializer),
+            Self::TopicReference(it) => it.serialize(serializer),
             Self::ComputedMemberExpression(it) => it.serialize(serializer),
             Self::StaticMemberExpression(it) => it.serialize(serializer),
             Self::PrivateFieldExpression(it) => it.serialize(serializer),
@@ -213,6 +220,7 @@ impl ESTree for ObjectPropertyKind<'_> {
         match self {
             Self::ObjectProperty(it) => it.serialize(serializer),
             Self::SpreadProperty(it) => it.serialize(serializer),
+            Self::ErrorNode(it) => it.serialize(serializer),
         }
     }
 }
@@ -278,6 +286,9 @@ impl ESTree for PropertyKey<'_> {
             Self::TSNonNullExpression(it) => it.serialize(serializer),
             Self::TSInstantiationExpression(it) => it.serialize(serializer),
             Self::V8IntrinsicExpression(it) => it.serialize(serializer),
+            Self::FlowTypeCastExpression(it) => it.serialize(serializer),
+            Self::PipelineExpression(it) => it.serialize(serializer),
+            Self::TopicReference(it) => it.serialize(serializer),
             Self::ComputedMemberExpression(it) => it.serialize(serializer),
             Self::StaticMemberExpression(it) => it.serialize(serializer),
             Self::PrivateFieldExpression(it) => it.serialize(serializer),
@@ -472,6 +483,9 @@ impl ESTree for Argument<'_> {
             Self::TSNonNullExpression(it) => it.serialize(serializer),
             Self::TSInstantiationExpression(it) => it.serialize(serializer),
             Self::V8IntrinsicExpression(it) => it.serialize(serializer),
+            Self::FlowTypeCastExpression(it) => it.serialize(serializer),
+            Self::PipelineExpression(it) => it.serialize(serializer),
+            Self::TopicReference(it) => it.serialize(serializer),
             Self::ComputedMemberExpression(it) => it.serialize(serializer),
             Self::StaticMemberExpression(it) => it.serialize(serializer),
             Self::PrivateFieldExpression(it) => it.serialize(serializer),
@@ -798,6 +812,7 @@ impl ESTree for Statement<'_> {
             Self::TryStatement(it) => it.serialize(serializer),
             Self::WhileStatement(it) => it.serialize(serializer),
             Self::WithStatement(it) => it.serialize(serializer),
+            Self::ErrorNode(it) => it.serialize(serializer),
             Self::VariableDeclaration(it) => it.serialize(serializer),
             Self::FunctionDeclaration(it) => it.serialize(serializer),
             Self::ClassDeclaration(it) => it.serialize(serializer),
@@ -807,6 +822,7 @@ impl ESTree for Statement<'_> {
             Self::TSModuleDeclaration(it) => it.serialize(serializer),
             Self::TSGlobalDeclaration(it) => it.serialize(serializer),
             Self::TSImportEqualsDeclaration(it) => it.serialize(serializer),
+            Self::FlowOpaqueTypeDeclaration(it) => it.serialize(serializer),
             Self::ImportDeclaration(it) => it.serialize(serializer),
             Self::ExportAllDeclaration(it) => it.serialize(serializer),
             Self::ExportDefaultDeclaration(it) => it.serialize(serializer),
@@ -860,6 +876,7 @@ impl ESTree for Declaration<'_> {
             Self::TSModuleDeclaration(it) => it.serialize(serializer),
             Self::TSGlobalDeclaration(it) => it.serialize(serializer),
             Self::TSImportEqualsDeclaration(it) => it.serialize(serializer),
+            Self::FlowOpaqueTypeDeclaration(it) => it.serialize(serializer),
         }
     }
 }
@@ -1014,6 +1031,9 @@ impl ESTree for ForStatementInit<'_> {
             Self::TSNonNullExpression(it) => it.serialize(serializer),
             Self::TSInstantiationExpression(it) => it.serialize(serializer),
             Self::V8IntrinsicExpression(it) => it.serialize(serializer),
+            Self::FlowTypeCastExpression(it) => it.serialize(serializer),
+            Self::PipelineExpression(it) => it.serialize(serializer),
+            Self::TopicReference(it) => it.serialize(serializer),
             Self::ComputedMemberExpression(it) => it.serialize(serializer),
             Self::StaticMemberExpression(it) => it.serialize(serializer),
             Self::PrivateFieldExpression(it) => it.serialize(serializer),
@@ -1410,6 +1430,7 @@ impl ESTree for ClassElement<'_> {
             Self::PropertyDefinition(it) => it.serialize(serializer),
             Self::AccessorProperty(it) => it.serialize(serializer),
             Self::TSIndexSignature(it) => it.serialize(serializer),
+            Self::ErrorNode(it) => it.serialize(serializer),
         }
     }
 }
@@ -1764,6 +1785,9 @@ impl ESTree for ExportDefaultDeclarationKind<'_> {
             Self::TSNonNullExpression(it) => it.serialize(serializer),
             Self::TSInstantiationExpression(it) => it.serialize(serializer),
             Self::V8IntrinsicExpression(it) => it.serialize(serializer),
+            Self::FlowTypeCastExpression(it) => it.serialize(serializer),
+            Self::PipelineExpression(it) => it.serialize(serializer),
+            Self::TopicReference(it) => it.serialize(serializer),
             Self::ComputedMemberExpression(it) => it.serialize(serializer),
             Self::StaticMemberExpression(it) => it.serialize(serializer),
             Self::PrivateFieldExpression(it) => it.serialize(serializer),
@@ -1792,6 +1816,35 @@ impl ESTree for V8IntrinsicExpression<'_> {
     }
 }
 
+impl ESTree for PipelineExpression<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("type", &JsonSafeString("PipelineExpression"));
+        state.serialize_field("head", &self.head);
+        state.serialize_field("body", &self.body);
+        state.serialize_span(self.span);
+        state.end();
+    }
+}
+
+impl ESTree for TopicReference {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("type", &JsonSafeString("TopicReference"));
+        state.serialize_span(self.span);
+        state.end();
+    }
+}
+
+impl ESTree for ErrorNode {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("type", &JsonSafeString("ErrorNode"));
+        state.serialize_span(self.span);
+        state.end();
+    }
+}
+
 impl ESTree for BooleanLiteral {
     fn serialize<S: Serializer>(&self, serializer: S) {
         let mut state = serializer.serialize_struct();
@@ -2062,6 +2115,9 @@ impl ESTree for JSXExpression<'_> {
             Self::TSNonNullExpression(it) => it.serialize(serializer),
             Self::TSInstantiationExpression(it) => it.serialize(serializer),
             Self::V8IntrinsicExpression(it) => it.serialize(serializer),
+            Self::FlowTypeCastExpression(it) => it.serialize(serializer),
+            Self::PipelineExpression(it) => it.serialize(serializer),
+            Self::TopicReference(it) => it.serialize(serializer),
             Self::ComputedMemberExpression(it) => it.serialize(serializer),
             Self::StaticMemberExpression(it) => it.serialize(serializer),
             Self::PrivateFieldExpression(it) => it.serialize(serializer),
@@ -3187,6 +3243,7 @@ impl ESTree for ImportOrExportKind {
         match self {
             Self::Value => JsonSafeString("value").serialize(serializer),
             Self::Type => JsonSafeString("type").serialize(serializer),
+            Self::Typeof => JsonSafeString("typeof").serialize(serializer),
         }
     }
 }
@@ -3222,6 +3279,31 @@ impl ESTree for JSDocUnknownType {
     }
 }
 
+impl ESTree for FlowTypeCastExpression<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("type", &JsonSafeString("FlowTypeCastExpression"));
+        state.serialize_field("expression", &self.expression);
+        state.serialize_field("typeAnnotation", &self.type_annotation);
+        state.serialize_span(self.span);
+        state.end();
+    }
+}
+
+impl ESTree for FlowOpaqueTypeDeclaration<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("type", &JsonSafeString("FlowOpaqueTypeDeclaration"));
+        state.serialize_field("id", &self.id);
+        state.serialize_field("typeParameters", &self.type_parameters);
+        state.serialize_field("supertype", &self.supertype);
+        state.serialize_field("impltype", &self.impltype);
+        state.serialize_field("declare", &self.declare);
+        state.serialize_span(self.span);
+        state.end();
+    }
+}
+
 impl ESTree for CommentKind {
     fn serialize<S: Serializer>(&self, serializer: S) {
         match self {
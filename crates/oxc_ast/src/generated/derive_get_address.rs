@@ -54,6 +54,9 @@ impl GetAddress for Expression<'_> {
             Self::TSNonNullExpression(it) => GetAddress::address(it),
             Self::TSInstantiationExpression(it) => GetAddress::address(it),
             Self::V8IntrinsicExpression(it) => GetAddress::address(it),
+            Self::FlowTypeCastExpression(it) => GetAddress::address(it),
+            Self::PipelineExpression(it) => GetAddress::address(it),
+            Self::TopicReference(it) => GetAddress::address(it),
             Self::ComputedMemberExpression(it) => GetAddress::address(it),
             Self::StaticMemberExpression(it) => GetAddress::address(it),
             Self::PrivateFieldExpression(it) => GetAddress::address(it),
@@ -68,6 +71,7 @@ impl GetAddress for ObjectPropertyKind<'_> {
         match self {
             Self::ObjectProperty(it) => GetAddress::address(it),
             Self::SpreadProperty(it) => GetAddress::address(it),
+            Self::ErrorNode(it) => GetAddress::address(it),
         }
     }
 }
@@ -119,6 +123,9 @@ impl GetAddress for PropertyKey<'_> {
             Self::TSNonNullExpression(it) => GetAddress::address(it),
             Self::TSInstantiationExpression(it) => GetAddress::address(it),
             Self::V8IntrinsicExpression(it) => GetAddress::address(it),
+            Self::FlowTypeCastExpression(it) => GetAddress::address(it),
+            Self::PipelineExpression(it) => GetAddress::address(it),
+            Self::TopicReference(it) => GetAddress::address(it),
             Self::ComputedMemberExpression(it) => GetAddress::address(it),
             Self::StaticMemberExpression(it) => GetAddress::address(it),
             Self::PrivateFieldExpression(it) => GetAddress::address(it),
@@ -184,6 +191,9 @@ impl GetAddress for Argument<'_> {
             Self::TSNonNullExpression(it) => GetAddress::address(it),
             Self::TSInstantiationExpression(it) => GetAddress::address(it),
             Self::V8IntrinsicExpression(it) => GetAddress::address(it),
+            Self::FlowTypeCastExpression(it) => GetAddress::address(it),
+            Self::PipelineExpression(it) => GetAddress::address(it),
+            Self::TopicReference(it) => GetAddress::address(it),
             Self::ComputedMemberExpression(it) => GetAddress::address(it),
             Self::StaticMemberExpression(it) => GetAddress::address(it),
             Self::PrivateFieldExpression(it) => GetAddress::address(it),
@@ -306,6 +316,7 @@ impl GetAddress for Statement<'_> {
             Self::TryStatement(it) => GetAddress::address(it),
             Self::WhileStatement(it) => GetAddress::address(it),
             Self::WithStatement(it) => GetAddress::address(it),
+            Self::ErrorNode(it) => GetAddress::address(it),
             Self::VariableDeclaration(it) => GetAddress::address(it),
             Self::FunctionDeclaration(it) => GetAddress::address(it),
             Self::ClassDeclaration(it) => GetAddress::address(it),
@@ -315,6 +326,7 @@ impl GetAddress for Statement<'_> {
             Self::TSModuleDeclaration(it) => GetAddress::address(it),
             Self::TSGlobalDeclaration(it) => GetAddress::address(it),
             Self::TSImportEqualsDeclaration(it) => GetAddress::address(it),
+            Self::FlowOpaqueTypeDeclaration(it) => GetAddress::address(it),
             Self::ImportDeclaration(it) => GetAddress::address(it),
             Self::ExportAllDeclaration(it) => GetAddress::address(it),
             Self::ExportDefaultDeclaration(it) => GetAddress::address(it),
@@ -339,6 +351,7 @@ impl GetAddress for Declaration<'_> {
             Self::TSModuleDeclaration(it) => GetAddress::address(it),
             Self::TSGlobalDeclaration(it) => GetAddress::address(it),
             Self::TSImportEqualsDeclaration(it) => GetAddress::address(it),
+            Self::FlowOpaqueTypeDeclaration(it) => GetAddress::address(it),
         }
     }
 }
@@ -389,6 +402,9 @@ impl GetAddress for ForStatementInit<'_> {
             Self::TSNonNullExpression(it) => GetAddress::address(it),
             Self::TSInstantiationExpression(it) => GetAddress::address(it),
             Self::V8IntrinsicExpression(it) => GetAddress::address(it),
+            Self::FlowTypeCastExpression(it) => GetAddress::address(it),
+            Self::PipelineExpression(it) => GetAddress::address(it),
+            Self::TopicReference(it) => GetAddress::address(it),
             Self::ComputedMemberExpression(it) => GetAddress::address(it),
             Self::StaticMemberExpression(it) => GetAddress::address(it),
             Self::PrivateFieldExpression(it) => GetAddress::address(it),
@@ -439,6 +455,7 @@ impl GetAddress for ClassElement<'_> {
             Self::PropertyDefinition(it) => GetAddress::address(it),
             Self::AccessorProperty(it) => GetAddress::address(it),
             Self::TSIndexSignature(it) => GetAddress::address(it),
+            Self::ErrorNode(it) => GetAddress::address(it),
         }
     }
 }
@@ -518,6 +535,9 @@ impl GetAddress for ExportDefaultDeclarationKind<'_> {
             Self::TSNonNullExpression(it) => GetAddress::address(it),
             Self::TSInstantiationExpression(it) => GetAddress::address(it),
             Self::V8IntrinsicExpression(it) => GetAddress::address(it),
+            Self::FlowTypeCastExpression(it) => GetAddress::address(it),
+            Self::PipelineExpression(it) => GetAddress::address(it),
+            Self::TopicReference(it) => GetAddress::address(it),
             Self::ComputedMemberExpression(it) => GetAddress::address(it),
             Self::StaticMemberExpression(it) => GetAddress::address(it),
             Self::PrivateFieldExpression(it) => GetAddress::address(it),
@@ -5,6 +5,7 @@
 
 use oxc_span::{GetSpan, Span};
 
+use crate::ast::flow::*;
 use crate::ast::js::*;
 use crate::ast::jsx::*;
 use crate::ast::literal::*;
@@ -60,6 +61,9 @@ impl GetSpan for Expression<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::FlowTypeCastExpression(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -154,6 +158,9 @@ impl GetSpan for ArrayExpressionElement<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::FlowTypeCastExpression(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -180,6 +187,7 @@ impl GetSpan for ObjectPropertyKind<'_> {
         match self {
             Self::ObjectProperty(it) => GetSpan::span(&**it),
             Self::SpreadProperty(it) => GetSpan::span(&**it),
+            Self::ErrorNode(it) => GetSpan::span(&**it),
         }
     }
 }
@@ -236,6 +244,9 @@ impl GetSpan for PropertyKey<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::FlowTypeCastExpression(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -367,6 +378,9 @@ impl GetSpan for Argument<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::FlowTypeCastExpression(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -601,6 +615,7 @@ impl GetSpan for Statement<'_> {
             Self::TryStatement(it) => GetSpan::span(&**it),
             Self::WhileStatement(it) => GetSpan::span(&**it),
             Self::WithStatement(it) => GetSpan::span(&**it),
+            Self::ErrorNode(it) => GetSpan::span(&**it),
             Self::VariableDeclaration(it) => GetSpan::span(&**it),
             Self::FunctionDeclaration(it) => GetSpan::span(&**it),
             Self::ClassDeclaration(it) => GetSpan::span(&**it),
@@ -610,6 +625,7 @@ impl GetSpan for Statement<'_> {
             Self::TSModuleDeclaration(it) => GetSpan::span(&**it),
             Self::TSGlobalDeclaration(it) => GetSpan::span(&**it),
             Self::TSImportEqualsDeclaration(it) => GetSpan::span(&**it),
+            Self::FlowOpaqueTypeDeclaration(it) => GetSpan::span(&**it),
             Self::ImportDeclaration(it) => GetSpan::span(&**it),
             Self::ExportAllDeclaration(it) => GetSpan::span(&**it),
             Self::ExportDefaultDeclaration(it) => GetSpan::span(&**it),
@@ -653,6 +669,7 @@ impl GetSpan for Declaration<'_> {
             Self::TSModuleDeclaration(it) => GetSpan::span(&**it),
             Self::TSGlobalDeclaration(it) => GetSpan::span(&**it),
             Self::TSImportEqualsDeclaration(it) => GetSpan::span(&**it),
+            Self::FlowOpaqueTypeDeclaration(it) => GetSpan::span(&**it),
         }
     }
 }
@@ -757,6 +774,9 @@ impl GetSpan for ForStatementInit<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::FlowTypeCastExpression(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -997,6 +1017,7 @@ impl GetSpan for ClassElement<'_> {
             Self::PropertyDefinition(it) => GetSpan::span(&**it),
             Self::AccessorProperty(it) => GetSpan::span(&**it),
             Self::TSIndexSignature(it) => GetSpan::span(&**it),
+            Self::ErrorNode(it) => GetSpan::span(&**it),
         }
     }
 }
@@ -1191,6 +1212,9 @@ impl GetSpan for ExportDefaultDeclarationKind<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::FlowTypeCastExpression(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -1215,6 +1239,27 @@ impl GetSpan for V8IntrinsicExpression<'_> {
     }
 }
 
+impl GetSpan for PipelineExpression<'_> {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl GetSpan for TopicReference {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl GetSpan for ErrorNode {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
 impl GetSpan for BooleanLiteral {
     #[inline]
     fn span(&self) -> Span {
@@ -1386,6 +1431,9 @@ impl GetSpan for JSXExpression<'_> {
             Self::TSNonNullExpression(it) => GetSpan::span(&**it),
             Self::TSInstantiationExpression(it) => GetSpan::span(&**it),
             Self::V8IntrinsicExpression(it) => GetSpan::span(&**it),
+            Self::FlowTypeCastExpression(it) => GetSpan::span(&**it),
+            Self::PipelineExpression(it) => GetSpan::span(&**it),
+            Self::TopicReference(it) => GetSpan::span(&**it),
             Self::ComputedMemberExpression(it) => GetSpan::span(&**it),
             Self::StaticMemberExpression(it) => GetSpan::span(&**it),
             Self::PrivateFieldExpression(it) => GetSpan::span(&**it),
@@ -2179,3 +2227,17 @@ impl GetSpan for JSDocUnknownType {
         self.span
     }
 }
+
+impl GetSpan for FlowTypeCastExpression<'_> {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl GetSpan for FlowOpaqueTypeDeclaration<'_> {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
+}
@@ -5,6 +5,7 @@
 
 use oxc_span::{GetSpanMut, Span};
 
+use crate::ast::flow::*;
 use crate::ast::js::*;
 use crate::ast::jsx::*;
 use crate::ast::literal::*;
@@ -60,6 +61,9 @@ impl GetSpanMut for Expression<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::FlowTypeCastExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -154,6 +158,9 @@ impl GetSpanMut for ArrayExpressionElement<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::FlowTypeCastExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -180,6 +187,7 @@ impl GetSpanMut for ObjectPropertyKind<'_> {
         match self {
             Self::ObjectProperty(it) => GetSpanMut::span_mut(&mut **it),
             Self::SpreadProperty(it) => GetSpanMut::span_mut(&mut **it),
+            Self::ErrorNode(it) => GetSpanMut::span_mut(&mut **it),
         }
     }
 }
@@ -236,6 +244,9 @@ impl GetSpanMut for PropertyKey<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::FlowTypeCastExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -367,6 +378,9 @@ impl GetSpanMut for Argument<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::FlowTypeCastExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -601,6 +615,7 @@ impl GetSpanMut for Statement<'_> {
             Self::TryStatement(it) => GetSpanMut::span_mut(&mut **it),
             Self::WhileStatement(it) => GetSpanMut::span_mut(&mut **it),
             Self::WithStatement(it) => GetSpanMut::span_mut(&mut **it),
+            Self::ErrorNode(it) => GetSpanMut::span_mut(&mut **it),
             Self::VariableDeclaration(it) => GetSpanMut::span_mut(&mut **it),
             Self::FunctionDeclaration(it) => GetSpanMut::span_mut(&mut **it),
             Self::ClassDeclaration(it) => GetSpanMut::span_mut(&mut **it),
@@ -610,6 +625,7 @@ impl GetSpanMut for Statement<'_> {
             Self::TSModuleDeclaration(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSGlobalDeclaration(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSImportEqualsDeclaration(it) => GetSpanMut::span_mut(&mut **it),
+            Self::FlowOpaqueTypeDeclaration(it) => GetSpanMut::span_mut(&mut **it),
             Self::ImportDeclaration(it) => GetSpanMut::span_mut(&mut **it),
             Self::ExportAllDeclaration(it) => GetSpanMut::span_mut(&mut **it),
             Self::ExportDefaultDeclaration(it) => GetSpanMut::span_mut(&mut **it),
@@ -653,6 +669,7 @@ impl GetSpanMut for Declaration<'_> {
             Self::TSModuleDeclaration(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSGlobalDeclaration(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSImportEqualsDeclaration(it) => GetSpanMut::span_mut(&mut **it),
+            Self::FlowOpaqueTypeDeclaration(it) => GetSpanMut::span_mut(&mut **it),
         }
     }
 }
@@ -757,6 +774,9 @@ impl GetSpanMut for ForStatementInit<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::FlowTypeCastExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -997,6 +1017,7 @@ impl GetSpanMut for ClassElement<'_> {
             Self::PropertyDefinition(it) => GetSpanMut::span_mut(&mut **it),
             Self::AccessorProperty(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSIndexSignature(it) => GetSpanMut::span_mut(&mut **it),
+            Self::ErrorNode(it) => GetSpanMut::span_mut(&mut **it),
         }
     }
 }
@@ -1191,6 +1212,9 @@ impl GetSpanMut for ExportDefaultDeclarationKind<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::FlowTypeCastExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -1215,6 +1239,27 @@ impl GetSpanMut for V8IntrinsicExpression<'_> {
     }
 }
 
+impl GetSpanMut for PipelineExpression<'_> {
+    #[inline]
+    fn span_mut(&mut self) -> &mut Span {
+        &mut self.span
+    }
+}
+
+impl GetSpanMut for TopicReference {
+    #[inline]
+    fn span_mut(&mut self) -> &mut Span {
+        &mut self.span
+    }
+}
+
+impl GetSpanMut for ErrorNode {
+    #[inline]
+    fn span_mut(&mut self) -> &mut Span {
+        &mut self.span
+    }
+}
+
 impl GetSpanMut for BooleanLiteral {
     #[inline]
     fn span_mut(&mut self) -> &mut Span {
@@ -1386,6 +1431,9 @@ impl GetSpanMut for JSXExpression<'_> {
             Self::TSNonNullExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::TSInstantiationExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::V8IntrinsicExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::FlowTypeCastExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::PipelineExpression(it) => GetSpanMut::span_mut(&mut **it),
+            Self::TopicReference(it) => GetSpanMut::span_mut(&mut **it),
             Self::ComputedMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::StaticMemberExpression(it) => GetSpanMut::span_mut(&mut **it),
             Self::PrivateFieldExpression(it) => GetSpanMut::span_mut(&mut **it),
@@ -2179,3 +2227,17 @@ impl GetSpanMut for JSDocUnknownType {
         &mut self.span
     }
 }
+
+impl GetSpanMut for FlowTypeCastExpression<'_> {
+    #[inline]
+    fn span_mut(&mut self) -> &mut Span {
+        &mut self.span
+    }
+}
+
+impl GetSpanMut for FlowOpaqueTypeDeclaration<'_> {
+    #[inline]
+    fn span_mut(&mut self) -> &mut Span {
+        &mut self.span
+    }
+}
@@ -5,6 +5,7 @@
 
 use oxc_allocator::TakeIn;
 
+use crate::ast::flow::*;
 use crate::ast::js::*;
 use crate::ast::jsx::*;
 use crate::ast::literal::*;
@@ -246,6 +247,12 @@ impl<'a> TakeIn<'a> for ModuleExportName<'a> {}
 
 impl<'a> TakeIn<'a> for V8IntrinsicExpression<'a> {}
 
+impl<'a> TakeIn<'a> for PipelineExpression<'a> {}
+
+impl<'a> TakeIn<'a> for TopicReference {}
+
+impl<'a> TakeIn<'a> for ErrorNode {}
+
 impl<'a> TakeIn<'a> for BooleanLiteral {}
 
 impl<'a> TakeIn<'a> for NullLiteral {}
@@ -475,3 +482,7 @@ impl<'a> TakeIn<'a> for JSDocNullableType<'a> {}
 impl<'a> TakeIn<'a> for JSDocNonNullableType<'a> {}
 
 impl<'a> TakeIn<'a> for JSDocUnknownType {}
+
+impl<'a> TakeIn<'a> for FlowTypeCastExpression<'a> {}
+
+impl<'a> TakeIn<'a> for FlowOpaqueTypeDeclaration<'a> {}
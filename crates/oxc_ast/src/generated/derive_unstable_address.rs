@@ -3,6 +3,7 @@
 
 use oxc_allocator::UnstableAddress;
 
+use crate::ast::flow::*;
 use crate::ast::js::*;
 use crate::ast::jsx::*;
 use crate::ast::literal::*;
@@ -196,6 +197,12 @@ impl UnstableAddress for ExportSpecifier<'_> {}
 
 impl UnstableAddress for V8IntrinsicExpression<'_> {}
 
+impl UnstableAddress for PipelineExpression<'_> {}
+
+impl UnstableAddress for TopicReference {}
+
+impl UnstableAddress for ErrorNode {}
+
 impl UnstableAddress for BooleanLiteral {}
 
 impl UnstableAddress for NullLiteral {}
@@ -383,3 +390,7 @@ impl UnstableAddress for JSDocNullableType<'_> {}
 impl UnstableAddress for JSDocNonNullableType<'_> {}
 
 impl UnstableAddress for JSDocUnknownType {}
+
+impl UnstableAddress for FlowTypeCastExpression<'_> {}
+
+impl UnstableAddress for FlowOpaqueTypeDeclaration<'_> {}
@@ -1749,6 +1749,54 @@ impl V8IntrinsicExpression<'_> {
     }
 }
 
+impl PipelineExpression<'_> {
+    /// Get [`NodeId`] of [`PipelineExpression`].
+    ///
+    /// Only use this method on a post-semantic AST where [`NodeId`]s are always defined.
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.node_id.get()
+    }
+
+    /// Set [`NodeId`] of [`PipelineExpression`].
+    #[inline]
+    pub fn set_node_id(&self, node_id: NodeId) {
+        self.node_id.set(node_id);
+    }
+}
+
+impl TopicReference {
+    /// Get [`NodeId`] of [`TopicReference`].
+    ///
+    /// Only use this method on a post-semantic AST where [`NodeId`]s are always defined.
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.node_id.get()
+    }
+
+    /// Set [`NodeId`] of [`TopicReference`].
+    #[inline]
+    pub fn set_node_id(&self, node_id: NodeId) {
+        self.node_id.set(node_id);
+    }
+}
+
+impl ErrorNode {
+    /// Get [`NodeId`] of [`ErrorNode`].
+    ///
+    /// Only use this method on a post-semantic AST where [`NodeId`]s are always defined.
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.node_id.get()
+    }
+
+    /// Set [`NodeId`] of [`ErrorNode`].
+    #[inline]
+    pub fn set_node_id(&self, node_id: NodeId) {
+        self.node_id.set(node_id);
+    }
+}
+
 impl BooleanLiteral {
     /// Get [`NodeId`] of [`BooleanLiteral`].
     ///
@@ -3457,6 +3505,55 @@ impl JSDocUnknownType {
     }
 }
 
+impl FlowTypeCastExpression<'_> {
+    /// Get [`NodeId`] of [`FlowTypeCastExpression`].
+    ///
+    /// Only use this method on a post-semantic AST where [`NodeId`]s are always defined.
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.node_id.get()
+    }
+
+    /// Set [`NodeId`] of [`FlowTypeCastExpression`].
+    #[inline]
+    pub fn set_node_id(&self, node_id: NodeId) {
+        self.node_id.set(node_id);
+    }
+}
+
+impl FlowOpaqueTypeDeclaration<'_> {
+    /// Get [`NodeId`] of [`FlowOpaqueTypeDeclaration`].
+    ///
+    /// Only use this method on a post-semantic AST where [`NodeId`]s are always defined.
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.node_id.get()
+    }
+
+    /// Set [`NodeId`] of [`FlowOpaqueTypeDeclaration`].
+    #[inline]
+    pub fn set_node_id(&self, node_id: NodeId) {
+        self.node_id.set(node_id);
+    }
+
+    /// Get [`ScopeId`] of [`FlowOpaqueTypeDeclaration`].
+    ///
+    /// Only use this method on a post-semantic AST where [`ScopeId`]s are always defined.
+    ///
+    /// # Panics
+    /// Panics if `scope_id` is [`None`].
+    #[inline]
+    pub fn scope_id(&self) -> ScopeId {
+        self.scope_id.get().unwrap()
+    }
+
+    /// Set [`ScopeId`] of [`FlowOpaqueTypeDeclaration`].
+    #[inline]
+    pub fn set_scope_id(&self, scope_id: ScopeId) {
+        self.scope_id.set(Some(scope_id));
+    }
+}
+
 impl Expression<'_> {
     /// Get [`NodeId`] of [`Expression`].
     // `#[inline(always)]` because this should boil down to a single instruction.
@@ -3503,6 +3600,9 @@ impl Expression<'_> {
             Self::TSNonNullExpression(it) => it.node_id(),
             Self::TSInstantiationExpression(it) => it.node_id(),
             Self::V8IntrinsicExpression(it) => it.node_id(),
+            Self::FlowTypeCastExpression(it) => it.node_id(),
+            Self::PipelineExpression(it) => it.node_id(),
+            Self::TopicReference(it) => it.node_id(),
             Self::ComputedMemberExpression(it) => it.node_id(),
             Self::StaticMemberExpression(it) => it.node_id(),
             Self::PrivateFieldExpression(it) => it.node_id(),
@@ -3558,6 +3658,9 @@ impl ArrayExpressionElement<'_> {
             Self::TSNonNullExpression(it) => it.node_id(),
             Self::TSInstantiationExpression(it) => it.node_id(),
             Self::V8IntrinsicExpression(it) => it.node_id(),
+            Self::FlowTypeCastExpression(it) => it.node_id(),
+            Self::PipelineExpression(it) => it.node_id(),
+            Self::TopicReference(it) => it.node_id(),
             Self::ComputedMemberExpression(it) => it.node_id(),
             Self::StaticMemberExpression(it) => it.node_id(),
             Self::PrivateFieldExpression(it) => it.node_id(),
@@ -3573,6 +3676,7 @@ impl ObjectPropertyKind<'_> {
         match self {
             Self::ObjectProperty(it) => it.node_id(),
             Self::SpreadProperty(it) => it.node_id(),
+            Self::ErrorNode(it) => it.node_id(),
         }
     }
 }
@@ -3625,6 +3729,9 @@ impl PropertyKey<'_> {
             Self::TSNonNullExpression(it) => it.node_id(),
             Self::TSInstantiationExpression(it) => it.node_id(),
             Self::V8IntrinsicExpression(it) => it.node_id(),
+            Self::FlowTypeCastExpression(it) => it.node_id(),
+            Self::PipelineExpression(it) => it.node_id(),
+            Self::TopicReference(it) => it.node_id(),
             Self::ComputedMemberExpression(it) => it.node_id(),
             Self::StaticMemberExpression(it) => it.node_id(),
             Self::PrivateFieldExpression(it) => it.node_id(),
@@ -3692,6 +3799,9 @@ impl Argument<'_> {
             Self::TSNonNullExpression(it) => it.node_id(),
             Self::TSInstantiationExpression(it) => it.node_id(),
             Self::V8IntrinsicExpression(it) => it.node_id(),
+            Self::FlowTypeCastExpression(it) => it.node_id(),
+            Self::PipelineExpression(it) => it.node_id(),
+            Self::TopicReference(it) => it.node_id(),
             Self::ComputedMemberExpression(it) => it.node_id(),
             Self::StaticMemberExpression(it) => it.node_id(),
             Self::PrivateFieldExpression(it) => it.node_id(),
@@ -3821,6 +3931,7 @@ impl Statement<'_> {
             Self::TryStatement(it) => it.node_id(),
             Self::WhileStatement(it) => it.node_id(),
             Self::WithStatement(it) => it.node_id(),
+            Self::ErrorNode(it) => it.node_id(),
             Self::VariableDeclaration(it) => it.node_id(),
             Self::FunctionDeclaration(it) => it.node_id(),
             Self::ClassDeclaration(it) => it.node_id(),
@@ -3830,6 +3941,7 @@ impl Statement<'_> {
             Self::TSModuleDeclaration(it) => it.node_id(),
             Self::TSGlobalDeclaration(it) => it.node_id(),
             Self::TSImportEqualsDeclaration(it) => it.node_id(),
+            Self::FlowOpaqueTypeDeclaration(it) => it.node_id(),
             Self::ImportDeclaration(it) => it.node_id(),
             Self::ExportAllDeclaration(it) => it.node_id(),
             Self::ExportDefaultDeclaration(it) => it.node_id(),
@@ -3855,6 +3967,7 @@ impl Declaration<'_> {
             Self::TSModuleDeclaration(it) => it.node_id(),
             Self::TSGlobalDeclaration(it) => it.node_id(),
             Self::TSImportEqualsDeclaration(it) => it.node_id(),
+            Self::FlowOpaqueTypeDeclaration(it) => it.node_id(),
         }
     }
 }
@@ -3906,6 +4019,9 @@ impl ForStatementInit<'_> {
             Self::TSNonNullExpression(it) => it.node_id(),
             Self::TSInstantiationExpression(it) => it.node_id(),
             Self::V8IntrinsicExpression(it) => it.node_id(),
+            Self::FlowTypeCastExpression(it) => it.node_id(),
+            Self::PipelineExpression(it) => it.node_id(),
+            Self::TopicReference(it) => it.node_id(),
             Self::ComputedMemberExpression(it) => it.node_id(),
             Self::StaticMemberExpression(it) => it.node_id(),
             Self::PrivateFieldExpression(it) => it.node_id(),
@@ -3959,6 +4075,7 @@ impl ClassElement<'_> {
             Self::PropertyDefinition(it) => it.node_id(),
             Self::AccessorProperty(it) => it.node_id(),
             Self::TSIndexSignature(it) => it.node_id(),
+            Self::ErrorNode(it) => it.node_id(),
         }
     }
 }
@@ -4053,6 +4170,9 @@ impl ExportDefaultDeclarationKind<'_> {
             Self::TSNonNullExpression(it) => it.node_id(),
             Self::TSInstantiationExpression(it) => it.node_id(),
             Self::V8IntrinsicExpression(it) => it.node_id(),
+            Self::FlowTypeCastExpression(it) => it.node_id(),
+            Self::PipelineExpression(it) => it.node_id(),
+            Self::TopicReference(it) => it.node_id(),
             Self::ComputedMemberExpression(it) => it.node_id(),
             Self::StaticMemberExpression(it) => it.node_id(),
             Self::PrivateFieldExpression(it) => it.node_id(),
@@ -4148,6 +4268,9 @@ impl JSXExpression<'_> {
             Self::TSNonNullExpression(it) => it.node_id(),
             Self::TSInstantiationExpression(it) => it.node_id(),
             Self::V8IntrinsicExpression(it) => it.node_id(),
+            Self::FlowTypeCastExpression(it) => it.node_id(),
+            Self::PipelineExpression(it) => it.node_id(),
+            Self::TopicReference(it) => it.node_id(),
             Self::ComputedMemberExpression(it) => it.node_id(),
             Self::StaticMemberExpression(it) => it.node_id(),
             Self::PrivateFieldExpression(it) => it.node_id(),
@@ -185,6 +185,9 @@ impl<'a> ArrayExpressionElement<'a> {
                 | Self::TSNonNullExpression(_)
                 | Self::TSInstantiationExpression(_)
                 | Self::V8IntrinsicExpression(_)
+                | Self::FlowTypeCastExpression(_)
+                | Self::PipelineExpression(_)
+                | Self::TopicReference(_)
                 | Self::ComputedMemberExpression(_)
                 | Self::StaticMemberExpression(_)
                 | Self::PrivateFieldExpression(_)
@@ -340,6 +343,11 @@ impl<'a> TryFrom<ArrayExpressionElement<'a>> for Expression<'a> {
             ArrayExpressionElement::V8IntrinsicExpression(o) => {
                 Ok(Expression::V8IntrinsicExpression(o))
             }
+            ArrayExpressionElement::FlowTypeCastExpression(o) => {
+                Ok(Expression::FlowTypeCastExpression(o))
+            }
+            ArrayExpressionElement::PipelineExpression(o) => Ok(Expression::PipelineExpression(o)),
+            ArrayExpressionElement::TopicReference(o) => Ok(Expression::TopicReference(o)),
             ArrayExpressionElement::ComputedMemberExpression(o) => {
                 Ok(Expression::ComputedMemberExpression(o))
             }
@@ -415,6 +423,11 @@ impl<'a> From<Expression<'a>> for ArrayExpressionElement<'a> {
             Expression::V8IntrinsicExpression(o) => {
                 ArrayExpressionElement::V8IntrinsicExpression(o)
             }
+            Expression::FlowTypeCastExpression(o) => {
+                ArrayExpressionElement::FlowTypeCastExpression(o)
+            }
+            Expression::PipelineExpression(o) => ArrayExpressionElement::PipelineExpression(o),
+            Expression::TopicReference(o) => ArrayExpressionElement::TopicReference(o),
             Expression::ComputedMemberExpression(o) => {
                 ArrayExpressionElement::ComputedMemberExpression(o)
             }
@@ -609,6 +622,9 @@ impl<'a> PropertyKey<'a> {
                 | Self::TSNonNullExpression(_)
                 | Self::TSInstantiationExpression(_)
                 | Self::V8IntrinsicExpression(_)
+                | Self::FlowTypeCastExpression(_)
+                | Self::PipelineExpression(_)
+                | Self::TopicReference(_)
                 | Self::ComputedMemberExpression(_)
                 | Self::StaticMemberExpression(_)
                 | Self::PrivateFieldExpression(_)
@@ -746,6 +762,9 @@ impl<'a> TryFrom<PropertyKey<'a>> for Expression<'a> {
                 Ok(Expression::TSInstantiationExpression(o))
             }
             PropertyKey::V8IntrinsicExpression(o) => Ok(Expression::V8IntrinsicExpression(o)),
+            PropertyKey::FlowTypeCastExpression(o) => Ok(Expression::FlowTypeCastExpression(o)),
+            PropertyKey::PipelineExpression(o) => Ok(Expression::PipelineExpression(o)),
+            PropertyKey::TopicReference(o) => Ok(Expression::TopicReference(o)),
             PropertyKey::ComputedMemberExpression(o) => Ok(Expression::ComputedMemberExpression(o)),
             PropertyKey::StaticMemberExpression(o) => Ok(Expression::StaticMemberExpression(o)),
             PropertyKey::PrivateFieldExpression(o) => Ok(Expression::PrivateFieldExpression(o)),
@@ -801,6 +820,9 @@ impl<'a> From<Expression<'a>> for PropertyKey<'a> {
             Expression::TSNonNullExpression(o) => PropertyKey::TSNonNullExpression(o),
             Expression::TSInstantiationExpression(o) => PropertyKey::TSInstantiationExpression(o),
             Expression::V8IntrinsicExpression(o) => PropertyKey::V8IntrinsicExpression(o),
+            Expression::FlowTypeCastExpression(o) => PropertyKey::FlowTypeCastExpression(o),
+            Expression::PipelineExpression(o) => PropertyKey::PipelineExpression(o),
+            Expression::TopicReference(o) => PropertyKey::TopicReference(o),
             Expression::ComputedMemberExpression(o) => PropertyKey::ComputedMemberExpression(o),
             Expression::StaticMemberExpression(o) => PropertyKey::StaticMemberExpression(o),
             Expression::PrivateFieldExpression(o) => PropertyKey::PrivateFieldExpression(o),
@@ -985,6 +1007,9 @@ impl<'a> Argument<'a> {
                 | Self::TSNonNullExpression(_)
                 | Self::TSInstantiationExpression(_)
                 | Self::V8IntrinsicExpression(_)
+                | Self::FlowTypeCastExpression(_)
+                | Self::PipelineExpression(_)
+                | Self::TopicReference(_)
                 | Self::ComputedMemberExpression(_)
                 | Self::StaticMemberExpression(_)
                 | Self::PrivateFieldExpression(_)
@@ -1120,6 +1145,9 @@ impl<'a> TryFrom<Argument<'a>> for Expression<'a> {
             Argument::TSNonNullExpression(o) => Ok(Expression::TSNonNullExpression(o)),
             Argument::TSInstantiationExpression(o) => Ok(Expression::TSInstantiationExpression(o)),
             Argument::V8IntrinsicExpression(o) => Ok(Expression::V8IntrinsicExpression(o)),
+            Argument::FlowTypeCastExpression(o) => Ok(Expression::FlowTypeCastExpression(o)),
+            Argument::PipelineExpression(o) => Ok(Expression::PipelineExpression(o)),
+            Argument::TopicReference(o) => Ok(Expression::TopicReference(o)),
             Argument::ComputedMemberExpression(o) => Ok(Expression::ComputedMemberExpression(o)),
             Argument::StaticMemberExpression(o) => Ok(Expression::StaticMemberExpression(o)),
             Argument::PrivateFieldExpression(o) => Ok(Expression::PrivateFieldExpression(o)),
@@ -1175,6 +1203,9 @@ impl<'a> From<Expression<'a>> for Argument<'a> {
             Expression::TSNonNullExpression(o) => Argument::TSNonNullExpression(o),
             Expression::TSInstantiationExpression(o) => Argument::TSInstantiationExpression(o),
             Expression::V8IntrinsicExpression(o) => Argument::V8IntrinsicExpression(o),
+            Expression::FlowTypeCastExpression(o) => Argument::FlowTypeCastExpression(o),
+            Expression::PipelineExpression(o) => Argument::PipelineExpression(o),
+            Expression::TopicReference(o) => Argument::TopicReference(o),
             Expression::ComputedMemberExpression(o) => Argument::ComputedMemberExpression(o),
             Expression::StaticMemberExpression(o) => Argument::StaticMemberExpression(o),
             Expression::PrivateFieldExpression(o) => Argument::PrivateFieldExpression(o),
@@ -2620,6 +2651,7 @@ impl<'a> Statement<'a> {
                 | Self::TSModuleDeclaration(_)
                 | Self::TSGlobalDeclaration(_)
                 | Self::TSImportEqualsDeclaration(_)
+                | Self::FlowOpaqueTypeDeclaration(_)
         )
     }
 
@@ -2723,6 +2755,9 @@ impl<'a> TryFrom<Statement<'a>> for Declaration<'a> {
             Statement::TSImportEqualsDeclaration(o) => {
                 Ok(Declaration::TSImportEqualsDeclaration(o))
             }
+            Statement::FlowOpaqueTypeDeclaration(o) => {
+                Ok(Declaration::FlowOpaqueTypeDeclaration(o))
+            }
             _ => Err(()),
         }
     }
@@ -2744,6 +2779,7 @@ impl<'a> From<Declaration<'a>> for Statement<'a> {
             Declaration::TSModuleDeclaration(o) => Statement::TSModuleDeclaration(o),
             Declaration::TSGlobalDeclaration(o) => Statement::TSGlobalDeclaration(o),
             Declaration::TSImportEqualsDeclaration(o) => Statement::TSImportEqualsDeclaration(o),
+            Declaration::FlowOpaqueTypeDeclaration(o) => Statement::FlowOpaqueTypeDeclaration(o),
         }
     }
 }
@@ -2936,6 +2972,9 @@ impl<'a> ForStatementInit<'a> {
                 | Self::TSNonNullExpression(_)
                 | Self::TSInstantiationExpression(_)
                 | Self::V8IntrinsicExpression(_)
+                | Self::FlowTypeCastExpression(_)
+                | Self::PipelineExpression(_)
+                | Self::TopicReference(_)
                 | Self::ComputedMemberExpression(_)
                 | Self::StaticMemberExpression(_)
                 | Self::PrivateFieldExpression(_)
@@ -3079,6 +3118,11 @@ impl<'a> TryFrom<ForStatementInit<'a>> for Expression<'a> {
                 Ok(Expression::TSInstantiationExpression(o))
             }
             ForStatementInit::V8IntrinsicExpression(o) => Ok(Expression::V8IntrinsicExpression(o)),
+            ForStatementInit::FlowTypeCastExpression(o) => {
+                Ok(Expression::FlowTypeCastExpression(o))
+            }
+            ForStatementInit::PipelineExpression(o) => Ok(Expression::PipelineExpression(o)),
+            ForStatementInit::TopicReference(o) => Ok(Expression::TopicReference(o)),
             ForStatementInit::ComputedMemberExpression(o) => {
                 Ok(Expression::ComputedMemberExpression(o))
             }
@@ -3144,6 +3188,9 @@ impl<'a> From<Expression<'a>> for ForStatementInit<'a> {
                 ForStatementInit::TSInstantiationExpression(o)
             }
             Expression::V8IntrinsicExpression(o) => ForStatementInit::V8IntrinsicExpression(o),
+            Expression::FlowTypeCastExpression(o) => ForStatementInit::FlowTypeCastExpression(o),
+            Expression::PipelineExpression(o) => ForStatementInit::PipelineExpression(o),
+            Expression::TopicReference(o) => ForStatementInit::TopicReference(o),
             Expression::ComputedMemberExpression(o) => {
                 ForStatementInit::ComputedMemberExpression(o)
             }
@@ -3929,6 +3976,9 @@ impl<'a> ExportDefaultDeclarationKind<'a> {
                 | Self::TSNonNullExpression(_)
                 | Self::TSInstantiationExpression(_)
                 | Self::V8IntrinsicExpression(_)
+                | Self::FlowTypeCastExpression(_)
+                | Self::PipelineExpression(_)
+                | Self::TopicReference(_)
                 | Self::ComputedMemberExpression(_)
                 | Self::StaticMemberExpression(_)
                 | Self::PrivateFieldExpression(_)
@@ -4098,6 +4148,13 @@ impl<'a> TryFrom<ExportDefaultDeclarationKind<'a>> for Expression<'a> {
             ExportDefaultDeclarationKind::V8IntrinsicExpression(o) => {
                 Ok(Expression::V8IntrinsicExpression(o))
             }
+            ExportDefaultDeclarationKind::FlowTypeCastExpression(o) => {
+                Ok(Expression::FlowTypeCastExpression(o))
+            }
+            ExportDefaultDeclarationKind::PipelineExpression(o) => {
+                Ok(Expression::PipelineExpression(o))
+            }
+            ExportDefaultDeclarationKind::TopicReference(o) => Ok(Expression::TopicReference(o)),
             ExportDefaultDeclarationKind::ComputedMemberExpression(o) => {
                 Ok(Expression::ComputedMemberExpression(o))
             }
@@ -4183,6 +4240,13 @@ impl<'a> From<Expression<'a>> for ExportDefaultDeclarationKind<'a> {
             Expression::V8IntrinsicExpression(o) => {
                 ExportDefaultDeclarationKind::V8IntrinsicExpression(o)
             }
+            Expression::FlowTypeCastExpression(o) => {
+                ExportDefaultDeclarationKind::FlowTypeCastExpression(o)
+            }
+            Expression::PipelineExpression(o) => {
+                ExportDefaultDeclarationKind::PipelineExpression(o)
+            }
+            Expression::TopicReference(o) => ExportDefaultDeclarationKind::TopicReference(o),
             Expression::ComputedMemberExpression(o) => {
                 ExportDefaultDeclarationKind::ComputedMemberExpression(o)
             }
@@ -4377,6 +4441,9 @@ impl<'a> JSXExpression<'a> {
                 | Self::TSNonNullExpression(_)
                 | Self::TSInstantiationExpression(_)
                 | Self::V8IntrinsicExpression(_)
+                | Self::FlowTypeCastExpression(_)
+                | Self::PipelineExpression(_)
+                | Self::TopicReference(_)
                 | Self::ComputedMemberExpression(_)
                 | Self::StaticMemberExpression(_)
                 | Self::PrivateFieldExpression(_)
@@ -4516,6 +4583,9 @@ impl<'a> TryFrom<JSXExpression<'a>> for Expression<'a> {
                 Ok(Expression::TSInstantiationExpression(o))
             }
             JSXExpression::V8IntrinsicExpression(o) => Ok(Expression::V8IntrinsicExpression(o)),
+            JSXExpression::FlowTypeCastExpression(o) => Ok(Expression::FlowTypeCastExpression(o)),
+            JSXExpression::PipelineExpression(o) => Ok(Expression::PipelineExpression(o)),
+            JSXExpression::TopicReference(o) => Ok(Expression::TopicReference(o)),
             JSXExpression::ComputedMemberExpression(o) => {
                 Ok(Expression::ComputedMemberExpression(o))
             }
@@ -4573,6 +4643,9 @@ impl<'a> From<Expression<'a>> for JSXExpression<'a> {
             Expression::TSNonNullExpression(o) => JSXExpression::TSNonNullExpression(o),
             Expression::TSInstantiationExpression(o) => JSXExpression::TSInstantiationExpression(o),
             Expression::V8IntrinsicExpression(o) => JSXExpression::V8IntrinsicExpression(o),
+            Expression::FlowTypeCastExpression(o) => JSXExpression::FlowTypeCastExpression(o),
+            Expression::PipelineExpression(o) => JSXExpression::PipelineExpression(o),
+            Expression::TopicReference(o) => JSXExpression::TopicReference(o),
             Expression::ComputedMemberExpression(o) => JSXExpression::ComputedMemberExpression(o),
             Expression::StaticMemberExpression(o) => JSXExpression::StaticMemberExpression(o),
             Expression::PrivateFieldExpression(o) => JSXExpression::PrivateFieldExpression(o),
@@ -5103,6 +5176,9 @@ macro_rules! match_expression {
             | $ty::TSNonNullExpression(_)
             | $ty::TSInstantiationExpression(_)
             | $ty::V8IntrinsicExpression(_)
+            | $ty::FlowTypeCastExpression(_)
+            | $ty::PipelineExpression(_)
+            | $ty::TopicReference(_)
             | $ty::ComputedMemberExpression(_)
             | $ty::StaticMemberExpression(_)
             | $ty::PrivateFieldExpression(_)
@@ -5181,6 +5257,7 @@ macro_rules! match_declaration {
             | $ty::TSModuleDeclaration(_)
             | $ty::TSGlobalDeclaration(_)
             | $ty::TSImportEqualsDeclaration(_)
+            | $ty::FlowOpaqueTypeDeclaration(_)
     };
 }
 pub use match_declaration;
@@ -4,9 +4,9 @@ use oxc_syntax::precedence::{GetPrecedence, Precedence};
 use crate::ast::{
     AssignmentExpression, AwaitExpression, BinaryExpression, CallExpression, ChainExpression,
     ComputedMemberExpression, ConditionalExpression, Expression, ImportExpression,
-    LogicalExpression, MemberExpression, NewExpression, PrivateFieldExpression, SequenceExpression,
-    StaticMemberExpression, TSTypeAssertion, UnaryExpression, UpdateExpression, YieldExpression,
-    match_member_expression,
+    LogicalExpression, MemberExpression, NewExpression, PipelineExpression, PrivateFieldExpression,
+    SequenceExpression, StaticMemberExpression, TSTypeAssertion, UnaryExpression, UpdateExpression,
+    YieldExpression, match_member_expression,
 };
 
 impl GetPrecedence for Expression<'_> {
@@ -16,6 +16,7 @@ impl GetPrecedence for Expression<'_> {
             Self::AssignmentExpression(expr) => expr.precedence(),
             Self::YieldExpression(expr) => expr.precedence(),
             Self::ConditionalExpression(expr) => expr.precedence(),
+            Self::PipelineExpression(expr) => expr.precedence(),
             Self::LogicalExpression(expr) => expr.precedence(),
             Self::BinaryExpression(expr) => expr.precedence(),
             Self::UnaryExpression(expr) => expr.precedence(),
@@ -53,6 +54,12 @@ impl GetPrecedence for AssignmentExpression<'_> {
     }
 }
 
+impl GetPrecedence for PipelineExpression<'_> {
+    fn precedence(&self) -> Precedence {
+        Precedence::Assign
+    }
+}
+
 impl GetPrecedence for LogicalExpression<'_> {
     fn precedence(&self) -> Precedence {
         self.operator.precedence()
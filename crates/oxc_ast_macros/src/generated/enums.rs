@@ -417,6 +417,27 @@ pub static INHERITED_ENUMS: [InheritedEnum; 9] = [
                 is_boxed: true,
                 discriminant: 39,
             },
+            EnumVariant {
+                name: "FlowTypeCastExpression",
+                inner_name: "FlowTypeCastExpression",
+                inner_has_lifetime: true,
+                is_boxed: true,
+                discriminant: 40,
+            },
+            EnumVariant {
+                name: "PipelineExpression",
+                inner_name: "PipelineExpression",
+                inner_has_lifetime: true,
+                is_boxed: true,
+                discriminant: 41,
+            },
+            EnumVariant {
+                name: "TopicReference",
+                inner_name: "TopicReference",
+                inner_has_lifetime: false,
+                is_boxed: true,
+                discriminant: 42,
+            },
             EnumVariant {
                 name: "ComputedMemberExpression",
                 inner_name: "ComputedMemberExpression",
@@ -692,6 +713,13 @@ pub static INHERITED_ENUMS: [InheritedEnum; 9] = [
                 is_boxed: true,
                 discriminant: 40,
             },
+            EnumVariant {
+                name: "FlowOpaqueTypeDeclaration",
+                inner_name: "FlowOpaqueTypeDeclaration",
+                inner_has_lifetime: true,
+                is_boxed: true,
+                discriminant: 41,
+            },
         ],
     },
     // `ModuleDeclaration`
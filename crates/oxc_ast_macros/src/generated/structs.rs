@@ -8,118 +8,114 @@ use crate::ast::StructDetails;
 pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
     key: 16287231350648472473,
     disps: &[
+        (0, 0),
+        (0, 8),
+        (0, 8),
         (0, 1),
-        (0, 3),
-        (0, 26),
-        (0, 19),
-        (0, 1),
-        (0, 6),
-        (0, 6),
-        (0, 32),
-        (0, 3),
         (0, 0),
-        (0, 2),
-        (0, 43),
+        (0, 12),
         (0, 29),
-        (0, 21),
-        (0, 0),
-        (0, 0),
+        (0, 22),
         (0, 0),
-        (0, 14),
-        (0, 21),
-        (0, 86),
-        (0, 2),
-        (0, 23),
-        (0, 0),
-        (0, 0),
-        (0, 2),
-        (0, 12),
+        (0, 1),
+        (0, 13),
         (0, 0),
+        (0, 56),
+        (0, 67),
         (0, 7),
-        (0, 4),
         (0, 1),
-        (0, 0),
-        (0, 20),
-        (0, 0),
-        (0, 0),
-        (0, 54),
         (0, 1),
-        (0, 19),
-        (0, 138),
-        (0, 20),
-        (0, 162),
         (0, 1),
-        (0, 0),
         (0, 3),
+        (0, 55),
+        (0, 9),
+        (0, 10),
+        (0, 15),
+        (0, 42),
+        (0, 76),
+        (0, 66),
         (0, 7),
-        (0, 20),
-        (0, 16),
-        (0, 0),
-        (0, 26),
-        (0, 28),
+        (0, 125),
+        (0, 6),
         (0, 0),
-        (0, 68),
-        (0, 1),
-        (0, 233),
-        (0, 38),
+        (0, 79),
         (0, 0),
-        (0, 118),
-        (0, 15),
-        (0, 77),
+        (0, 86),
+        (0, 13),
+        (0, 2),
         (0, 1),
-        (0, 26),
-        (0, 70),
-        (0, 111),
-        (0, 161),
+        (0, 32),
+        (0, 138),
+        (0, 123),
+        (0, 17),
+        (0, 20),
+        (0, 82),
+        (0, 3),
+        (0, 22),
         (0, 6),
-        (0, 141),
-        (0, 4),
-        (1, 0),
-        (0, 14),
+        (0, 1),
         (0, 35),
-        (0, 14),
-        (0, 17),
+        (0, 8),
+        (0, 89),
+        (0, 2),
+        (0, 1),
         (0, 0),
-        (0, 80),
-        (1, 28),
-        (0, 41),
+        (0, 5),
+        (0, 23),
+        (0, 11),
+        (0, 86),
+        (0, 98),
+        (0, 14),
+        (0, 5),
+        (0, 1),
+        (0, 5),
+        (0, 6),
+        (0, 60),
+        (0, 180),
+        (0, 2),
         (0, 0),
+        (1, 152),
+        (0, 33),
         (0, 14),
         (0, 4),
-        (0, 1),
+        (0, 11),
+        (0, 39),
+        (0, 40),
+        (0, 110),
+        (0, 6),
+        (0, 48),
+        (0, 98),
+        (0, 65),
+        (0, 111),
+        (0, 68),
     ],
     entries: &[
         (
-            "LabelIdentifier",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
-        ),
-        (
-            "TSQualifiedName",
+            "FlowOpaqueTypeDeclaration",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 3, 4, 5, 6, 7, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSNullKeyword",
+            "TSIntrinsicKeyword",
             StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "IndexedReference",
-            StructDetails { field_order: None, is_node: false, is_transparent: false },
+            "DebuggerStatement",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
-        ("I32Dummy", StructDetails { field_order: None, is_node: false, is_transparent: true }),
         (
-            "ImportExpression",
+            "TSEnumDeclaration",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 2]),
+                field_order: Some(&[1, 0, 4, 5, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "AssignmentTargetPropertyIdentifier",
+            "PipelineExpression",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
@@ -127,82 +123,105 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "IfStatement",
+            "TSTypeAliasDeclaration",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3, 4]),
+                field_order: Some(&[1, 0, 3, 4, 5, 6, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "SwitchStatement",
+            "FlowTypeCastExpression",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 2]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSTypeParameter",
+            "LabeledStatement",
             StructDetails {
-                field_order: Some(&[1, 0, 5, 6, 7, 2, 3, 4]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
-        ("Comment", StructDetails { field_order: None, is_node: false, is_transparent: false }),
         (
-            "DebuggerStatement",
+            "TSNullKeyword",
             StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "JSDocNullableType",
+            "RegExpPattern",
+            StructDetails { field_order: None, is_node: false, is_transparent: false },
+        ),
+        (
+            "BinaryExpression",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 2]),
+                field_order: Some(&[1, 0, 3, 2, 4]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "ImportSpecifier",
+            "ArrowFunctionExpression",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 2]),
+                field_order: Some(&[1, 0, 7, 8, 3, 4, 5, 6, 2, 9, 10]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "BindingRestElement",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+            "ThisExpression",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "StaticBlock",
+            "EcmaScriptModule",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 2]),
-                is_node: true,
+                field_order: Some(&[4, 0, 1, 2, 3]),
+                is_node: false,
                 is_transparent: false,
             },
         ),
         (
-            "LogicalExpression",
+            "TSAsExpression",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 2, 4]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "AssignmentPattern",
+            "FixedSizeAllocatorMetadata",
+            StructDetails { field_order: None, is_node: false, is_transparent: false },
+        ),
+        (
+            "ExpressionStatement",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "ClassString",
+            StructDetails { field_order: Some(&[0, 2, 1]), is_node: false, is_transparent: false },
+        ),
+        (
+            "TSFunctionType",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 3, 4, 5, 6, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "PropertyDefinition",
+            "TSThisType",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
+        ),
+        (
+            "TSOptionalType",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "TSInterfaceHeritage",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 6, 7, 8, 9, 3, 4, 5, 10, 11, 12, 13, 14]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
@@ -212,27 +231,23 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             StructDetails { field_order: Some(&[0, 2, 1]), is_node: false, is_transparent: false },
         ),
         (
-            "AccessorProperty",
+            "TSImportType",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 6, 7, 8, 9, 3, 4, 5, 10, 11]),
+                field_order: Some(&[1, 0, 2, 3, 4, 5]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "Program",
+            "ObjectAssignmentTarget",
             StructDetails {
-                field_order: Some(&[1, 0, 8, 3, 4, 5, 6, 7, 2]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "ErrorLabel",
-            StructDetails { field_order: Some(&[1, 0]), is_node: false, is_transparent: false },
-        ),
-        (
-            "ImportAttribute",
+            "AssignmentPattern",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
@@ -240,79 +255,81 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "TSInterfaceBody",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+            "UnicodePropertyEscape",
+            StructDetails {
+                field_order: Some(&[0, 3, 4, 1, 2]),
+                is_node: false,
+                is_transparent: false,
+            },
         ),
         (
-            "JSXSpreadAttribute",
+            "Decorator",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "TemplateElementValue",
-            StructDetails { field_order: None, is_node: false, is_transparent: false },
+            "TemplateLiteral",
+            StructDetails {
+                field_order: Some(&[1, 0, 2, 3]),
+                is_node: true,
+                is_transparent: false,
+            },
         ),
         (
-            "TSTypeAliasDeclaration",
+            "JSXNamespacedName",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 5, 6, 2]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "Elision",
+            "TSNumberKeyword",
             StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "ObjectProperty",
+            "TSSymbolKeyword",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
+        ),
+        (
+            "FormalParameters",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 6, 7, 3, 4, 5]),
+                field_order: Some(&[1, 0, 2, 3, 4]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSTypeLiteral",
+            "ClassBody",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "TSVoidKeyword",
-            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
-        ),
-        (
-            "NewExpression",
+            "YieldExpression",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 5, 2]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
+        ("RegExpFlags", StructDetails { field_order: None, is_node: false, is_transparent: true }),
+        ("Pattern", StructDetails { field_order: None, is_node: false, is_transparent: false }),
         (
-            "TSEnumDeclaration",
+            "StaticMemberExpression",
             StructDetails {
-                field_order: Some(&[1, 0, 4, 5, 2, 3]),
+                field_order: Some(&[1, 0, 3, 4, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "ExportDefaultDeclaration",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
-        ),
-        (
-            "RegExpPattern",
-            StructDetails { field_order: None, is_node: false, is_transparent: false },
-        ),
-        (
-            "CatchParameter",
+            "TSMethodSignature",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 3, 8, 9, 10, 4, 5, 6, 7, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "StaticMemberExpression",
+            "SwitchStatement",
             StructDetails {
                 field_order: Some(&[1, 0, 3, 4, 2]),
                 is_node: true,
@@ -320,33 +337,35 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "Hashbang",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
-        ),
-        ("ScopeId", StructDetails { field_order: None, is_node: false, is_transparent: true }),
-        (
-            "TryStatement",
+            "AssignmentTargetPropertyIdentifier",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3, 4]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSUndefinedKeyword",
-            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
+            "TemplateElementValue",
+            StructDetails { field_order: None, is_node: false, is_transparent: false },
         ),
-        ("IgnoreGroup", StructDetails { field_order: None, is_node: false, is_transparent: false }),
         (
-            "TSIndexedAccessType",
+            "TSIntersectionType",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "JSXExpressionContainer",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "AssignmentTargetPropertyProperty",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 3, 4, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "YieldExpression",
+            "TSClassImplements",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
@@ -354,15 +373,23 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "V8IntrinsicExpression",
+            "TSInterfaceBody",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "BindingProperty",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 4, 5, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TaggedTemplateExpression",
+            "LabelIdentifier",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "ConditionalExpression",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3, 4]),
                 is_node: true,
@@ -370,15 +397,19 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "IdentifierReference",
+            "TSImportTypeQualifiedName",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 2]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "JSXNamespacedName",
+            "TSTupleType",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "RegExpLiteral",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
@@ -386,15 +417,15 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "FormalParameters",
+            "VariableDeclaration",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3, 4]),
+                field_order: Some(&[1, 0, 2, 4, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "JSXAttribute",
+            "JSXMemberExpression",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
@@ -402,144 +433,134 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "AwaitExpression",
+            "TSParenthesizedType",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "VariableDeclarator",
+            "Program",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 4, 5, 6, 3]),
+                field_order: Some(&[1, 0, 8, 3, 4, 5, 6, 7, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "CharacterClassRange",
+            "IndexedReference",
             StructDetails { field_order: None, is_node: false, is_transparent: false },
         ),
         (
-            "CapturingGroup",
-            StructDetails { field_order: None, is_node: false, is_transparent: false },
+            "TaggedTemplateExpression",
+            StructDetails {
+                field_order: Some(&[1, 0, 2, 3, 4]),
+                is_node: true,
+                is_transparent: false,
+            },
         ),
         (
-            "TSTupleType",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+            "FunctionBody",
+            StructDetails {
+                field_order: Some(&[1, 0, 2, 3]),
+                is_node: true,
+                is_transparent: false,
+            },
         ),
         (
-            "Span",
-            StructDetails { field_order: Some(&[1, 2, 0]), is_node: false, is_transparent: false },
+            "TSInstantiationExpression",
+            StructDetails {
+                field_order: Some(&[1, 0, 2, 3]),
+                is_node: true,
+                is_transparent: false,
+            },
         ),
         (
-            "ContinueStatement",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+            "ErrorNode",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "SwitchCase",
+            "StringLiteral",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 3, 4, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
+        ("ReferenceId", StructDetails { field_order: None, is_node: false, is_transparent: true }),
         (
-            "TSExternalModuleReference",
+            "TSTypeParameterDeclaration",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "SpreadElement",
+            "BreakStatement",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "TSTypeAnnotation",
+            "IdentifierName",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "Function",
+            "JSDocNonNullableType",
             StructDetails {
-                field_order: Some(&[1, 0, 9, 3, 10, 11, 12, 4, 5, 6, 7, 8, 2, 13, 14]),
+                field_order: Some(&[1, 0, 3, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
+        ("I32Dummy", StructDetails { field_order: None, is_node: false, is_transparent: true }),
         (
-            "UpdateExpression",
+            "JSXText",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3, 4]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
-        ("Alternative", StructDetails { field_order: None, is_node: false, is_transparent: false }),
-        (
-            "JSXExpressionContainer",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
-        ),
+        ("SourceType", StructDetails { field_order: None, is_node: false, is_transparent: false }),
         (
-            "AssignmentExpression",
+            "TSTypeParameter",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3, 4]),
+                field_order: Some(&[1, 0, 5, 6, 7, 2, 3, 4]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "StaticImport",
-            StructDetails { field_order: None, is_node: false, is_transparent: false },
-        ),
-        (
-            "JSXSpreadChild",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
-        ),
-        (
-            "BigIntLiteral",
+            "ImportAttribute",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 2]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "MethodDefinition",
+            "TSQualifiedName",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 6, 7, 8, 3, 4, 5, 9, 10, 11]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSTypeParameterDeclaration",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
-        ),
-        (
-            "Error",
+            "TSTypeOperator",
             StructDetails {
-                field_order: Some(&[4, 0, 1, 2, 3]),
-                is_node: false,
+                field_order: Some(&[1, 0, 2, 3]),
+                is_node: true,
                 is_transparent: false,
             },
         ),
-        (
-            "JSXClosingFragment",
-            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
-        ),
-        (
-            "ClassStringDisjunction",
-            StructDetails { field_order: Some(&[0, 2, 1]), is_node: false, is_transparent: false },
-        ),
         (
             "StaticExport",
             StructDetails { field_order: None, is_node: false, is_transparent: false },
         ),
         (
-            "ForStatement",
+            "AccessorProperty",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 5, 6, 2]),
+                field_order: Some(&[1, 0, 2, 6, 7, 8, 9, 3, 4, 5, 10, 11]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TemplateLiteral",
+            "UnaryExpression",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
@@ -547,39 +568,51 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "TSImportType",
+            "ArrayExpression",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "TSTypeAssertion",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3, 4, 5]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSBooleanKeyword",
-            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
+            "BigIntLiteral",
+            StructDetails {
+                field_order: Some(&[1, 0, 3, 4, 2]),
+                is_node: true,
+                is_transparent: false,
+            },
         ),
         (
-            "TSStringKeyword",
-            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
+            "ForInStatement",
+            StructDetails {
+                field_order: Some(&[1, 0, 3, 4, 5, 2]),
+                is_node: true,
+                is_transparent: false,
+            },
         ),
         (
-            "ImportDeclaration",
+            "PrivateInExpression",
             StructDetails {
-                field_order: Some(&[1, 0, 4, 5, 2, 6, 3]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "VariableDeclaration",
+            "ExportNamedDeclaration",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 4, 3]),
+                field_order: Some(&[1, 0, 3, 4, 5, 2, 6]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSInterfaceHeritage",
+            "CatchParameter",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
@@ -587,68 +620,51 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "AssignmentTargetRest",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
-        ),
-        ("Pattern", StructDetails { field_order: None, is_node: false, is_transparent: false }),
-        (
-            "TSThisType",
+            "TSBigIntKeyword",
             StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "SequenceExpression",
+            "TSTypeParameterInstantiation",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "TSNumberKeyword",
-            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
-        ),
-        (
-            "TSAsExpression",
+            "TSEnumBody",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 3, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "ArrowFunctionExpression",
-            StructDetails {
-                field_order: Some(&[1, 0, 7, 8, 3, 4, 5, 6, 2, 9, 10]),
-                is_node: true,
-                is_transparent: false,
-            },
+            "AssignmentTargetRest",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "PrivateFieldExpression",
+            "TSTemplateLiteralType",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 2]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "ObjectPattern",
-            StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
-                is_node: true,
-                is_transparent: false,
-            },
+            "TSTypeLiteral",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "TSOptionalType",
+            "JSXSpreadChild",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "ConditionalExpression",
-            StructDetails {
-                field_order: Some(&[1, 0, 2, 3, 4]),
-                is_node: true,
-                is_transparent: false,
-            },
+            "ContinueStatement",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "WithStatement",
+            "SequenceExpression",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "ImportSpecifier",
             StructDetails {
                 field_order: Some(&[1, 0, 3, 4, 2]),
                 is_node: true,
@@ -656,7 +672,7 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "JSXMemberExpression",
+            "WhileStatement",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
@@ -664,35 +680,36 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "TSUnknownKeyword",
+            "TSStringKeyword",
             StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "TSParenthesizedType",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+            "TSVoidKeyword",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "Directive",
+            "ComputedMemberExpression",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 3, 4, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
+        ("Modifier", StructDetails { field_order: None, is_node: false, is_transparent: true }),
         (
-            "NameSpan",
-            StructDetails { field_order: Some(&[1, 0]), is_node: false, is_transparent: false },
+            "ImportDefaultSpecifier",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "TSThisParameter",
+            "JSXElement",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 2, 3, 4]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSGlobalDeclaration",
+            "NewExpression",
             StructDetails {
                 field_order: Some(&[1, 0, 3, 4, 5, 2]),
                 is_node: true,
@@ -700,19 +717,27 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "TSNamespaceExportDeclaration",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
-        ),
-        (
-            "CharacterClass",
+            "ForOfStatement",
             StructDetails {
-                field_order: Some(&[0, 2, 3, 4, 1]),
-                is_node: false,
+                field_order: Some(&[1, 0, 6, 3, 4, 5, 2]),
+                is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSEnumBody",
+            "ReturnStatement",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "ChainExpression",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "CommentNewlines",
+            StructDetails { field_order: None, is_node: false, is_transparent: true },
+        ),
+        (
+            "JSDocNullableType",
             StructDetails {
                 field_order: Some(&[1, 0, 3, 2]),
                 is_node: true,
@@ -720,154 +745,157 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "LookAroundAssertion",
-            StructDetails { field_order: Some(&[0, 2, 1]), is_node: false, is_transparent: false },
+            "TSUndefinedKeyword",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "TSTypeReference",
+            "TSModuleDeclaration",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 3, 4, 5, 6, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "ThrowStatement",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
-        ),
-        (
-            "ExportNamedDeclaration",
+            "TSPropertySignature",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 5, 2, 6]),
+                field_order: Some(&[1, 0, 2, 3, 4, 5, 6]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
-        ("Modifiers", StructDetails { field_order: None, is_node: false, is_transparent: false }),
-        (
-            "DynamicImport",
-            StructDetails { field_order: None, is_node: false, is_transparent: false },
-        ),
         (
-            "UnaryExpression",
+            "CatchClause",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 3, 4, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "BoundaryAssertion",
-            StructDetails { field_order: None, is_node: false, is_transparent: false },
+            "ErrorLabel",
+            StructDetails { field_order: Some(&[1, 0]), is_node: false, is_transparent: false },
         ),
+        ("ScopeId", StructDetails { field_order: None, is_node: false, is_transparent: true }),
         (
-            "TSBigIntKeyword",
+            "JSXOpeningFragment",
             StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "StringLiteral",
+            "NumericLiteral",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 2]),
+                field_order: Some(&[1, 0, 4, 3, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "Quantifier",
+            "TemplateElement",
             StructDetails {
-                field_order: Some(&[0, 1, 2, 4, 3]),
-                is_node: false,
+                field_order: Some(&[1, 0, 4, 2, 3]),
+                is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "ImportNamespaceSpecifier",
+            "TSTypeReference",
+            StructDetails {
+                field_order: Some(&[1, 0, 2, 3]),
+                is_node: true,
+                is_transparent: false,
+            },
+        ),
+        (
+            "RawTransferData",
+            StructDetails { field_order: None, is_node: false, is_transparent: false },
+        ),
+        (
+            "JSXSpreadAttribute",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
-        ("Disjunction", StructDetails { field_order: None, is_node: false, is_transparent: false }),
         (
-            "TSTemplateLiteralType",
+            "LogicalExpression",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 3, 2, 4]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "PrivateInExpression",
+            "TSTypeQuery",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
-        ("Modifier", StructDetails { field_order: None, is_node: false, is_transparent: true }),
         (
-            "ExpressionStatement",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+            "TSAnyKeyword",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "NumericLiteral",
+            "BlockStatement",
             StructDetails {
-                field_order: Some(&[1, 0, 4, 3, 2]),
+                field_order: Some(&[1, 0, 3, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSCallSignatureDeclaration",
+            "DoWhileStatement",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 5, 6, 2]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSMethodSignature",
+            "Quantifier",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 8, 9, 10, 4, 5, 6, 7, 2]),
-                is_node: true,
+                field_order: Some(&[0, 1, 2, 4, 3]),
+                is_node: false,
                 is_transparent: false,
             },
         ),
         (
-            "NullLiteral",
+            "TSObjectKeyword",
             StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "BreakStatement",
+            "TSNonNullExpression",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "ParenthesizedExpression",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+            "Elision",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "ComputedMemberExpression",
+            "TSConstructSignatureDeclaration",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 2]),
+                field_order: Some(&[1, 0, 3, 4, 5, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSIndexSignature",
+            "TSCallSignatureDeclaration",
             StructDetails {
-                field_order: Some(&[1, 0, 4, 5, 2, 3]),
+                field_order: Some(&[1, 0, 3, 4, 5, 6, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "ObjectAssignmentTarget",
+            "ExportSpecifier",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 3, 4, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
+        ("NonMaxU32", StructDetails { field_order: None, is_node: false, is_transparent: true }),
         (
-            "TSImportTypeQualifiedName",
+            "WithClause",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
@@ -875,144 +903,153 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "Decorator",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
-        ),
-        (
-            "JSDocNonNullableType",
+            "RawTransferMetadata",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 2]),
-                is_node: true,
+                field_order: Some(&[0, 3, 4, 5, 1, 2]),
+                is_node: false,
                 is_transparent: false,
             },
         ),
         (
-            "TSObjectKeyword",
-            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
-        ),
-        (
-            "TSNamedTupleMember",
+            "TSConstructorType",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 2]),
+                field_order: Some(&[1, 0, 6, 3, 4, 5, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "AssignmentTargetWithDefault",
+            "MethodDefinition",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 2, 6, 7, 8, 3, 4, 5, 9, 10, 11]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSClassImplements",
+            "TryStatement",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 2, 3, 4]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "ImportDefaultSpecifier",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+            "NamedReference",
+            StructDetails { field_order: None, is_node: false, is_transparent: false },
         ),
         (
-            "JSXOpeningFragment",
+            "JSXEmptyExpression",
             StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
+        ("NodeId", StructDetails { field_order: None, is_node: false, is_transparent: true }),
+        ("Modifiers", StructDetails { field_order: None, is_node: false, is_transparent: false }),
         (
-            "ThisExpression",
-            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
+            "Hashbang",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "CatchClause",
+            "TSThisParameter",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 2]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "CallExpression",
-            StructDetails {
-                field_order: Some(&[1, 0, 4, 5, 6, 2, 3]),
-                is_node: true,
-                is_transparent: false,
-            },
+            "Span",
+            StructDetails { field_order: Some(&[1, 2, 0]), is_node: false, is_transparent: false },
         ),
         (
-            "TSSymbolKeyword",
+            "NullLiteral",
             StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "LabeledStatement",
+            "TSInferType",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "VariableDeclarator",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 2, 4, 5, 6, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "ReturnStatement",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+            "LookAroundAssertion",
+            StructDetails { field_order: Some(&[0, 2, 1]), is_node: false, is_transparent: false },
         ),
         (
-            "TemplateElement",
+            "JSXAttribute",
             StructDetails {
-                field_order: Some(&[1, 0, 4, 2, 3]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "ClassBody",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+            "V8IntrinsicExpression",
+            StructDetails {
+                field_order: Some(&[1, 0, 2, 3]),
+                is_node: true,
+                is_transparent: false,
+            },
         ),
         (
-            "BindingIdentifier",
+            "TSIndexSignature",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 2]),
+                field_order: Some(&[1, 0, 4, 5, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "WhileStatement",
+            "PropertyDefinition",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 2, 6, 7, 8, 9, 3, 4, 5, 10, 11, 12, 13, 14]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSFunctionType",
+            "TSExportAssignment",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "TSIndexSignatureName",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 5, 6, 2]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "EcmaScriptModule",
+            "TSTypeAnnotation",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "ImportDeclaration",
             StructDetails {
-                field_order: Some(&[4, 0, 1, 2, 3]),
-                is_node: false,
+                field_order: Some(&[1, 0, 4, 5, 2, 6, 3]),
+                is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "DoWhileStatement",
+            "ExportDefaultDeclaration",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "PrivateFieldExpression",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 3, 4, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
-        ("SourceType", StructDetails { field_order: None, is_node: false, is_transparent: false }),
         (
-            "ArrayPattern",
+            "Directive",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
@@ -1020,26 +1057,39 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "TSConstructorType",
+            "SpreadElement",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "IfStatement",
             StructDetails {
-                field_order: Some(&[1, 0, 6, 3, 4, 5, 2]),
+                field_order: Some(&[1, 0, 2, 3, 4]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "ExportSpecifier",
+            "Error",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 2]),
-                is_node: true,
+                field_order: Some(&[4, 0, 1, 2, 3]),
+                is_node: false,
                 is_transparent: false,
             },
         ),
         (
-            "UnicodePropertyEscape",
+            "Super",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
+        ),
+        (
+            "TSLiteralType",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        ("IgnoreGroup", StructDetails { field_order: None, is_node: false, is_transparent: false }),
+        (
+            "TSNamedTupleMember",
             StructDetails {
-                field_order: Some(&[0, 3, 4, 1, 2]),
-                is_node: false,
+                field_order: Some(&[1, 0, 3, 4, 2]),
+                is_node: true,
                 is_transparent: false,
             },
         ),
@@ -1048,150 +1098,147 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             StructDetails { field_order: None, is_node: false, is_transparent: false },
         ),
         (
-            "EmptyStatement",
-            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
+            "CharacterClassRange",
+            StructDetails { field_order: None, is_node: false, is_transparent: false },
         ),
-        ("ReferenceId", StructDetails { field_order: None, is_node: false, is_transparent: true }),
         (
-            "JSXOpeningElement",
+            "TSImportEqualsDeclaration",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3, 4]),
+                field_order: Some(&[1, 0, 3, 4, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
+        ("SymbolId", StructDetails { field_order: None, is_node: false, is_transparent: true }),
         (
-            "TSTypeOperator",
+            "ArrayAssignmentTarget",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
+        ("Dot", StructDetails { field_order: None, is_node: false, is_transparent: true }),
+        ("RegExp", StructDetails { field_order: None, is_node: false, is_transparent: false }),
         (
-            "JSXFragment",
+            "BooleanLiteral",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "IdentifierReference",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3, 4]),
+                field_order: Some(&[1, 0, 3, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "Super",
-            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
-        ),
-        (
-            "TSArrayType",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+            "ImportExpression",
+            StructDetails {
+                field_order: Some(&[1, 0, 3, 4, 2]),
+                is_node: true,
+                is_transparent: false,
+            },
         ),
         (
-            "ObjectExpression",
+            "PrivateIdentifier",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "BooleanLiteral",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+            "BoundaryAssertion",
+            StructDetails { field_order: None, is_node: false, is_transparent: false },
         ),
         (
-            "TSTypeQuery",
+            "BindingIdentifier",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 3, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSImportEqualsDeclaration",
+            "JSXFragment",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 2]),
+                field_order: Some(&[1, 0, 2, 3, 4]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSModuleDeclaration",
+            "MetaProperty",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 5, 6, 2]),
+                field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "BinaryExpression",
-            StructDetails {
-                field_order: Some(&[1, 0, 3, 2, 4]),
-                is_node: true,
-                is_transparent: false,
-            },
+            "ClassStringDisjunction",
+            StructDetails { field_order: Some(&[0, 2, 1]), is_node: false, is_transparent: false },
         ),
-        ("RegExp", StructDetails { field_order: None, is_node: false, is_transparent: false }),
         (
-            "RawTransferMetadata2",
+            "TopicReference",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
+        ),
+        (
+            "CharacterClass",
             StructDetails {
-                field_order: Some(&[0, 3, 4, 5, 1, 2]),
+                field_order: Some(&[0, 2, 3, 4, 1]),
                 is_node: false,
                 is_transparent: false,
             },
         ),
         (
-            "PrivateIdentifier",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+            "EmptyStatement",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "TSTypeParameterInstantiation",
+            "TSUnionType",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "WithClause",
-            StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
-                is_node: true,
-                is_transparent: false,
-            },
+            "StaticImport",
+            StructDetails { field_order: None, is_node: false, is_transparent: false },
         ),
         (
-            "RawTransferData",
-            StructDetails { field_order: None, is_node: false, is_transparent: false },
+            "TSNeverKeyword",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "ArrayAssignmentTarget",
+            "UpdateExpression",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 2, 3, 4]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
+        ("Comment", StructDetails { field_order: None, is_node: false, is_transparent: false }),
         (
-            "TSIndexSignatureName",
+            "TSTypePredicate",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 3, 2, 4]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSNeverKeyword",
-            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
-        ),
-        ("NonMaxU32", StructDetails { field_order: None, is_node: false, is_transparent: true }),
-        (
-            "JSXElement",
+            "TSInterfaceDeclaration",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3, 4]),
+                field_order: Some(&[1, 0, 3, 4, 5, 6, 7, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "ForOfStatement",
+            "StaticBlock",
             StructDetails {
-                field_order: Some(&[1, 0, 6, 3, 4, 5, 2]),
+                field_order: Some(&[1, 0, 3, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSTypeAssertion",
+            "TSEnumMember",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
@@ -1199,32 +1246,37 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "TSConstructSignatureDeclaration",
+            "WithStatement",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 5, 2]),
+                field_order: Some(&[1, 0, 3, 4, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
+        ("ImportEntry", StructDetails { field_order: None, is_node: false, is_transparent: false }),
         (
-            "TSSatisfiesExpression",
+            "RawTransferMetadata2",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
-                is_node: true,
+                field_order: Some(&[0, 3, 4, 5, 1, 2]),
+                is_node: false,
                 is_transparent: false,
             },
         ),
-        ("Dot", StructDetails { field_order: None, is_node: false, is_transparent: true }),
         (
-            "TSEnumMember",
+            "JSXIdentifier",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "TSModuleBlock",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
+        ("Disjunction", StructDetails { field_order: None, is_node: false, is_transparent: false }),
         (
-            "FunctionBody",
+            "TSIndexedAccessType",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
@@ -1232,77 +1284,88 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "ExportAllDeclaration",
-            StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 5, 2]),
-                is_node: true,
-                is_transparent: false,
-            },
+            "NameSpan",
+            StructDetails { field_order: Some(&[1, 0]), is_node: false, is_transparent: false },
         ),
         (
-            "ClassString",
-            StructDetails { field_order: Some(&[0, 2, 1]), is_node: false, is_transparent: false },
+            "AwaitExpression",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
+        ("Alternative", StructDetails { field_order: None, is_node: false, is_transparent: false }),
         (
-            "TSInferType",
+            "TSArrayType",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "AssignmentTargetPropertyProperty",
-            StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 2]),
-                is_node: true,
-                is_transparent: false,
-            },
+            "JSDocUnknownType",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
         ),
         (
-            "FormalParameter",
+            "ObjectExpression",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "Function",
             StructDetails {
-                field_order: Some(&[1, 0, 6, 7, 8, 9, 2, 3, 4, 5]),
+                field_order: Some(&[1, 0, 9, 3, 10, 11, 12, 4, 5, 6, 7, 8, 2, 13, 14]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSLiteralType",
+            "TSNamespaceExportDeclaration",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "TSRestType",
+            "ParenthesizedExpression",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "ArrayExpression",
+            "DynamicImport",
+            StructDetails { field_order: None, is_node: false, is_transparent: false },
+        ),
+        (
+            "TSExternalModuleReference",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "Class",
+            "TSBooleanKeyword",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
+        ),
+        (
+            "FormalParameterRest",
             StructDetails {
-                field_order: Some(&[1, 0, 10, 3, 4, 5, 6, 7, 8, 9, 11, 12, 2]),
+                field_order: Some(&[1, 0, 2, 3, 4]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
-        ("NodeId", StructDetails { field_order: None, is_node: false, is_transparent: true }),
-        ("RegExpFlags", StructDetails { field_order: None, is_node: false, is_transparent: true }),
         (
-            "TSPropertySignature",
+            "BindingRestElement",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "CapturingGroup",
+            StructDetails { field_order: None, is_node: false, is_transparent: false },
+        ),
+        (
+            "ExportAllDeclaration",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3, 4, 5, 6]),
+                field_order: Some(&[1, 0, 3, 4, 5, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "BlockStatement",
+            "TSGlobalDeclaration",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 2]),
+                field_order: Some(&[1, 0, 3, 4, 5, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "RegExpLiteral",
+            "TSSatisfiesExpression",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
@@ -1310,44 +1373,31 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "TSUnionType",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
-        ),
-        (
-            "RawTransferMetadata",
+            "TSMappedType",
             StructDetails {
-                field_order: Some(&[0, 3, 4, 5, 1, 2]),
-                is_node: false,
+                field_order: Some(&[1, 0, 3, 4, 5, 6, 7, 8, 2]),
+                is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "IdentifierName",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
-        ),
-        (
-            "TSInterfaceDeclaration",
+            "Class",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 5, 6, 7, 2]),
+                field_order: Some(&[1, 0, 10, 3, 4, 5, 6, 7, 8, 9, 11, 12, 2]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
-        ("ImportEntry", StructDetails { field_order: None, is_node: false, is_transparent: false }),
-        (
-            "TSIntersectionType",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
-        ),
         (
-            "TSMappedType",
+            "ExportEntry",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 5, 6, 7, 8, 2]),
-                is_node: true,
+                field_order: Some(&[1, 0, 2, 3, 4, 5, 6]),
+                is_node: false,
                 is_transparent: false,
             },
         ),
         (
-            "FormalParameterRest",
+            "JSXOpeningElement",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3, 4]),
                 is_node: true,
@@ -1355,11 +1405,7 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "NamedReference",
-            StructDetails { field_order: None, is_node: false, is_transparent: false },
-        ),
-        (
-            "TSInstantiationExpression",
+            "SwitchCase",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
@@ -1367,48 +1413,39 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "TSNonNullExpression",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+            "ArrayPattern",
+            StructDetails {
+                field_order: Some(&[1, 0, 2, 3]),
+                is_node: true,
+                is_transparent: false,
+            },
         ),
         (
-            "JSXClosingElement",
+            "TSRestType",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
-        ("SymbolId", StructDetails { field_order: None, is_node: false, is_transparent: true }),
-        (
-            "JSXEmptyExpression",
-            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
-        ),
-        (
-            "CommentNewlines",
-            StructDetails { field_order: None, is_node: false, is_transparent: true },
-        ),
         (
-            "ExportEntry",
+            "ForStatement",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3, 4, 5, 6]),
-                is_node: false,
+                field_order: Some(&[1, 0, 3, 4, 5, 6, 2]),
+                is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "FixedSizeAllocatorMetadata",
-            StructDetails { field_order: None, is_node: false, is_transparent: false },
-        ),
-        (
-            "ForInStatement",
+            "ObjectProperty",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 5, 2]),
+                field_order: Some(&[1, 0, 2, 6, 7, 3, 4, 5]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "ChainExpression",
+            "ImportNamespaceSpecifier",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "TSModuleBlock",
+            "AssignmentTargetWithDefault",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
@@ -1416,51 +1453,39 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "JSDocUnknownType",
-            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
-        ),
-        (
-            "JSXIdentifier",
-            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
-        ),
-        (
-            "BindingProperty",
+            "AssignmentExpression",
             StructDetails {
-                field_order: Some(&[1, 0, 4, 5, 2, 3]),
+                field_order: Some(&[1, 0, 2, 3, 4]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSAnyKeyword",
-            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
-        ),
-        (
-            "JSXText",
+            "FormalParameter",
             StructDetails {
-                field_order: Some(&[1, 0, 2, 3]),
+                field_order: Some(&[1, 0, 6, 7, 8, 9, 2, 3, 4, 5]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSExportAssignment",
+            "ThrowStatement",
             StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
         ),
         (
-            "TSConditionalType",
+            "JSXClosingFragment",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
+        ),
+        (
+            "CallExpression",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 4, 5, 6, 2]),
+                field_order: Some(&[1, 0, 4, 5, 6, 2, 3]),
                 is_node: true,
                 is_transparent: false,
             },
         ),
         (
-            "TSIntrinsicKeyword",
-            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
-        ),
-        (
-            "MetaProperty",
+            "ObjectPattern",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 3]),
                 is_node: true,
@@ -1468,9 +1493,17 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
             },
         ),
         (
-            "TSTypePredicate",
+            "JSXClosingElement",
+            StructDetails { field_order: Some(&[1, 0, 2]), is_node: true, is_transparent: false },
+        ),
+        (
+            "TSUnknownKeyword",
+            StructDetails { field_order: Some(&[1, 0]), is_node: true, is_transparent: false },
+        ),
+        (
+            "TSConditionalType",
             StructDetails {
-                field_order: Some(&[1, 0, 3, 2, 4]),
+                field_order: Some(&[1, 0, 3, 4, 5, 6, 2]),
                 is_node: true,
                 is_transparent: false,
             },
@@ -563,6 +563,24 @@ impl<'a> VisitMut<'a> for Utf8ToUtf16Converter<'_> {
         self.convert_offset(&mut it.span.end);
     }
 
+    fn visit_pipeline_expression(&mut self, it: &mut PipelineExpression<'a>) {
+        self.convert_offset(&mut it.span.start);
+        walk_mut::walk_pipeline_expression(self, it);
+        self.convert_offset(&mut it.span.end);
+    }
+
+    fn visit_topic_reference(&mut self, it: &mut TopicReference) {
+        self.convert_offset(&mut it.span.start);
+        walk_mut::walk_topic_reference(self, it);
+        self.convert_offset(&mut it.span.end);
+    }
+
+    fn visit_error_node(&mut self, it: &mut ErrorNode) {
+        self.convert_offset(&mut it.span.start);
+        walk_mut::walk_error_node(self, it);
+        self.convert_offset(&mut it.span.end);
+    }
+
     fn visit_boolean_literal(&mut self, it: &mut BooleanLiteral) {
         self.convert_offset(&mut it.span.start);
         walk_mut::walk_boolean_literal(self, it);
@@ -1127,4 +1145,16 @@ impl<'a> VisitMut<'a> for Utf8ToUtf16Converter<'_> {
         walk_mut::walk_js_doc_unknown_type(self, it);
         self.convert_offset(&mut it.span.end);
     }
+
+    fn visit_flow_type_cast_expression(&mut self, it: &mut FlowTypeCastExpression<'a>) {
+        self.convert_offset(&mut it.span.start);
+        walk_mut::walk_flow_type_cast_expression(self, it);
+        self.convert_offset(&mut it.span.end);
+    }
+
+    fn visit_flow_opaque_type_declaration(&mut self, it: &mut FlowOpaqueTypeDeclaration<'a>) {
+        self.convert_offset(&mut it.span.start);
+        walk_mut::walk_flow_opaque_type_declaration(self, it);
+        self.convert_offset(&mut it.span.end);
+    }
 }
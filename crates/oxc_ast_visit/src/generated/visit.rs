@@ -631,6 +631,21 @@ pub trait Visit<'a>: Sized {
         walk_v8_intrinsic_expression(self, it);
     }
 
+    #[inline]
+    fn visit_pipeline_expression(&mut self, it: &PipelineExpression<'a>) {
+        walk_pipeline_expression(self, it);
+    }
+
+    #[inline]
+    fn visit_topic_reference(&mut self, it: &TopicReference) {
+        walk_topic_reference(self, it);
+    }
+
+    #[inline]
+    fn visit_error_node(&mut self, it: &ErrorNode) {
+        walk_error_node(self, it);
+    }
+
     #[inline]
     fn visit_boolean_literal(&mut self, it: &BooleanLiteral) {
         walk_boolean_literal(self, it);
@@ -1199,6 +1214,16 @@ pub trait Visit<'a>: Sized {
         walk_js_doc_unknown_type(self, it);
     }
 
+    #[inline]
+    fn visit_flow_type_cast_expression(&mut self, it: &FlowTypeCastExpression<'a>) {
+        walk_flow_type_cast_expression(self, it);
+    }
+
+    #[inline]
+    fn visit_flow_opaque_type_declaration(&mut self, it: &FlowOpaqueTypeDeclaration<'a>) {
+        walk_flow_opaque_type_declaration(self, it);
+    }
+
     #[inline]
     fn visit_span(&mut self, it: &Span) {
         walk_span(self, it);
@@ -1428,6 +1453,9 @@ pub mod walk {
                 visitor.visit_ts_instantiation_expression(it)
             }
             Expression::V8IntrinsicExpression(it) => visitor.visit_v8_intrinsic_expression(it),
+            Expression::FlowTypeCastExpression(it) => visitor.visit_flow_type_cast_expression(it),
+            Expression::PipelineExpression(it) => visitor.visit_pipeline_expression(it),
+            Expression::TopicReference(it) => visitor.visit_topic_reference(it),
             match_member_expression!(Expression) => {
                 visitor.visit_member_expression(it.to_member_expression())
             }
@@ -1527,6 +1555,7 @@ pub mod walk {
         match it {
             ObjectPropertyKind::ObjectProperty(it) => visitor.visit_object_property(it),
             ObjectPropertyKind::SpreadProperty(it) => visitor.visit_spread_element(it),
+            ObjectPropertyKind::ErrorNode(it) => visitor.visit_error_node(it),
         }
     }
 
@@ -2023,6 +2052,7 @@ pub mod walk {
             Statement::TryStatement(it) => visitor.visit_try_statement(it),
             Statement::WhileStatement(it) => visitor.visit_while_statement(it),
             Statement::WithStatement(it) => visitor.visit_with_statement(it),
+            Statement::ErrorNode(it) => visitor.visit_error_node(it),
             match_declaration!(Statement) => visitor.visit_declaration(it.to_declaration()),
             match_module_declaration!(Statement) => {
                 visitor.visit_module_declaration(it.to_module_declaration())
@@ -2075,6 +2105,9 @@ pub mod walk {
             Declaration::TSImportEqualsDeclaration(it) => {
                 visitor.visit_ts_import_equals_declaration(it)
             }
+            Declaration::FlowOpaqueTypeDeclaration(it) => {
+                visitor.visit_flow_opaque_type_declaration(it)
+            }
         }
     }
 
@@ -2595,7 +2628,6 @@ pub mod walk {
         visitor.leave_node(kind);
     }
 
-    #[inline]
     pub fn walk_class_element<'a, V: Visit<'a>>(visitor: &mut V, it: &ClassElement<'a>) {
         // No `AstKind` for this type
         match it {
@@ -2604,6 +2636,7 @@ pub mod walk {
             ClassElement::PropertyDefinition(it) => visitor.visit_property_definition(it),
             ClassElement::AccessorProperty(it) => visitor.visit_accessor_property(it),
             ClassElement::TSIndexSignature(it) => visitor.visit_ts_index_signature(it),
+            ClassElement::ErrorNode(it) => visitor.visit_error_node(it),
         }
     }
 
@@ -2912,6 +2945,35 @@ pub mod walk {
         visitor.leave_node(kind);
     }
 
+    #[inline]
+    pub fn walk_pipeline_expression<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &PipelineExpression<'a>,
+    ) {
+        let kind = AstKind::PipelineExpression(visitor.alloc(it));
+        visitor.enter_node(kind);
+        visitor.visit_span(&it.span);
+        visitor.visit_expression(&it.head);
+        visitor.visit_expression(&it.body);
+        visitor.leave_node(kind);
+    }
+
+    #[inline]
+    pub fn walk_topic_reference<'a, V: Visit<'a>>(visitor: &mut V, it: &TopicReference) {
+        let kind = AstKind::TopicReference(visitor.alloc(it));
+        visitor.enter_node(kind);
+        visitor.visit_span(&it.span);
+        visitor.leave_node(kind);
+    }
+
+    #[inline]
+    pub fn walk_error_node<'a, V: Visit<'a>>(visitor: &mut V, it: &ErrorNode) {
+        let kind = AstKind::ErrorNode(visitor.alloc(it));
+        visitor.enter_node(kind);
+        visitor.visit_span(&it.span);
+        visitor.leave_node(kind);
+    }
+
     #[inline]
     pub fn walk_boolean_literal<'a, V: Visit<'a>>(visitor: &mut V, it: &BooleanLiteral) {
         let kind = AstKind::BooleanLiteral(visitor.alloc(it));
@@ -4238,6 +4300,42 @@ pub mod walk {
         visitor.leave_node(kind);
     }
 
+    #[inline]
+    pub fn walk_flow_type_cast_expression<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &FlowTypeCastExpression<'a>,
+    ) {
+        let kind = AstKind::FlowTypeCastExpression(visitor.alloc(it));
+        visitor.enter_node(kind);
+        visitor.visit_span(&it.span);
+        visitor.visit_expression(&it.expression);
+        visitor.visit_ts_type_annotation(&it.type_annotation);
+        visitor.leave_node(kind);
+    }
+
+    #[inline]
+    pub fn walk_flow_opaque_type_declaration<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &FlowOpaqueTypeDeclaration<'a>,
+    ) {
+        let kind = AstKind::FlowOpaqueTypeDeclaration(visitor.alloc(it));
+        visitor.enter_node(kind);
+        visitor.visit_span(&it.span);
+        visitor.visit_binding_identifier(&it.id);
+        visitor.enter_scope(ScopeFlags::empty(), &it.scope_id);
+        if let Some(type_parameters) = &it.type_parameters {
+            visitor.visit_ts_type_parameter_declaration(type_parameters);
+        }
+        if let Some(supertype) = &it.supertype {
+            visitor.visit_ts_type(supertype);
+        }
+        if let Some(impltype) = &it.impltype {
+            visitor.visit_ts_type(impltype);
+        }
+        visitor.leave_scope();
+        visitor.leave_node(kind);
+    }
+
     #[inline]
     pub fn walk_span<'a, V: Visit<'a>>(visitor: &mut V, it: &Span) {
         // No `AstKind` for this type
@@ -623,6 +623,21 @@ pub trait VisitMut<'a>: Sized {
         walk_v8_intrinsic_expression(self, it);
     }
 
+    #[inline]
+    fn visit_pipeline_expression(&mut self, it: &mut PipelineExpression<'a>) {
+        walk_pipeline_expression(self, it);
+    }
+
+    #[inline]
+    fn visit_topic_reference(&mut self, it: &mut TopicReference) {
+        walk_topic_reference(self, it);
+    }
+
+    #[inline]
+    fn visit_error_node(&mut self, it: &mut ErrorNode) {
+        walk_error_node(self, it);
+    }
+
     #[inline]
     fn visit_boolean_literal(&mut self, it: &mut BooleanLiteral) {
         walk_boolean_literal(self, it);
@@ -1191,6 +1206,16 @@ pub trait VisitMut<'a>: Sized {
         walk_js_doc_unknown_type(self, it);
     }
 
+    #[inline]
+    fn visit_flow_type_cast_expression(&mut self, it: &mut FlowTypeCastExpression<'a>) {
+        walk_flow_type_cast_expression(self, it);
+    }
+
+    #[inline]
+    fn visit_flow_opaque_type_declaration(&mut self, it: &mut FlowOpaqueTypeDeclaration<'a>) {
+        walk_flow_opaque_type_declaration(self, it);
+    }
+
     #[inline]
     fn visit_span(&mut self, it: &mut Span) {
         walk_span(self, it);
@@ -1423,6 +1448,9 @@ pub mod walk_mut {
                 visitor.visit_ts_instantiation_expression(it)
             }
             Expression::V8IntrinsicExpression(it) => visitor.visit_v8_intrinsic_expression(it),
+            Expression::FlowTypeCastExpression(it) => visitor.visit_flow_type_cast_expression(it),
+            Expression::PipelineExpression(it) => visitor.visit_pipeline_expression(it),
+            Expression::TopicReference(it) => visitor.visit_topic_reference(it),
             match_member_expression!(Expression) => {
                 visitor.visit_member_expression(it.to_member_expression_mut())
             }
@@ -1534,6 +1562,7 @@ pub mod walk_mut {
         match it {
             ObjectPropertyKind::ObjectProperty(it) => visitor.visit_object_property(it),
             ObjectPropertyKind::SpreadProperty(it) => visitor.visit_spread_element(it),
+            ObjectPropertyKind::ErrorNode(it) => visitor.visit_error_node(it),
         }
     }
 
@@ -2060,6 +2089,7 @@ pub mod walk_mut {
             Statement::TryStatement(it) => visitor.visit_try_statement(it),
             Statement::WhileStatement(it) => visitor.visit_while_statement(it),
             Statement::WithStatement(it) => visitor.visit_with_statement(it),
+            Statement::ErrorNode(it) => visitor.visit_error_node(it),
             match_declaration!(Statement) => visitor.visit_declaration(it.to_declaration_mut()),
             match_module_declaration!(Statement) => {
                 visitor.visit_module_declaration(it.to_module_declaration_mut())
@@ -2112,6 +2142,9 @@ pub mod walk_mut {
             Declaration::TSImportEqualsDeclaration(it) => {
                 visitor.visit_ts_import_equals_declaration(it)
             }
+            Declaration::FlowOpaqueTypeDeclaration(it) => {
+                visitor.visit_flow_opaque_type_declaration(it)
+            }
         }
     }
 
@@ -2681,7 +2714,6 @@ pub mod walk_mut {
         visitor.leave_node(kind);
     }
 
-    #[inline]
     pub fn walk_class_element<'a, V: VisitMut<'a>>(visitor: &mut V, it: &mut ClassElement<'a>) {
         // No `AstType` for this type
         match it {
@@ -2690,6 +2722,7 @@ pub mod walk_mut {
             ClassElement::PropertyDefinition(it) => visitor.visit_property_definition(it),
             ClassElement::AccessorProperty(it) => visitor.visit_accessor_property(it),
             ClassElement::TSIndexSignature(it) => visitor.visit_ts_index_signature(it),
+            ClassElement::ErrorNode(it) => visitor.visit_error_node(it),
         }
     }
 
@@ -3028,6 +3061,35 @@ pub mod walk_mut {
         visitor.leave_node(kind);
     }
 
+    #[inline]
+    pub fn walk_pipeline_expression<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut PipelineExpression<'a>,
+    ) {
+        let kind = AstType::PipelineExpression;
+        visitor.enter_node(kind);
+        visitor.visit_span(&mut it.span);
+        visitor.visit_expression(&mut it.head);
+        visitor.visit_expression(&mut it.body);
+        visitor.leave_node(kind);
+    }
+
+    #[inline]
+    pub fn walk_topic_reference<'a, V: VisitMut<'a>>(visitor: &mut V, it: &mut TopicReference) {
+        let kind = AstType::TopicReference;
+        visitor.enter_node(kind);
+        visitor.visit_span(&mut it.span);
+        visitor.leave_node(kind);
+    }
+
+    #[inline]
+    pub fn walk_error_node<'a, V: VisitMut<'a>>(visitor: &mut V, it: &mut ErrorNode) {
+        let kind = AstType::ErrorNode;
+        visitor.enter_node(kind);
+        visitor.visit_span(&mut it.span);
+        visitor.leave_node(kind);
+    }
+
     #[inline]
     pub fn walk_boolean_literal<'a, V: VisitMut<'a>>(visitor: &mut V, it: &mut BooleanLiteral) {
         let kind = AstType::BooleanLiteral;
@@ -4465,6 +4527,42 @@ pub mod walk_mut {
         visitor.leave_node(kind);
     }
 
+    #[inline]
+    pub fn walk_flow_type_cast_expression<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut FlowTypeCastExpression<'a>,
+    ) {
+        let kind = AstType::FlowTypeCastExpression;
+        visitor.enter_node(kind);
+        visitor.visit_span(&mut it.span);
+        visitor.visit_expression(&mut it.expression);
+        visitor.visit_ts_type_annotation(&mut it.type_annotation);
+        visitor.leave_node(kind);
+    }
+
+    #[inline]
+    pub fn walk_flow_opaque_type_declaration<'a, V: VisitMut<'a>>(
+        visitor: &mut V,
+        it: &mut FlowOpaqueTypeDeclaration<'a>,
+    ) {
+        let kind = AstType::FlowOpaqueTypeDeclaration;
+        visitor.enter_node(kind);
+        visitor.visit_span(&mut it.span);
+        visitor.visit_binding_identifier(&mut it.id);
+        visitor.enter_scope(ScopeFlags::empty(), &it.scope_id);
+        if let Some(type_parameters) = &mut it.type_parameters {
+            visitor.visit_ts_type_parameter_declaration(type_parameters);
+        }
+        if let Some(supertype) = &mut it.supertype {
+            visitor.visit_ts_type(supertype);
+        }
+        if let Some(impltype) = &mut it.impltype {
+            visitor.visit_ts_type(impltype);
+        }
+        visitor.leave_scope();
+        visitor.leave_node(kind);
+    }
+
     #[inline]
     pub fn walk_span<'a, V: VisitMut<'a>>(visitor: &mut V, it: &mut Span) {
         // No `AstType` for this type
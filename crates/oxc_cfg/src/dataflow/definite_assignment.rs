@@ -0,0 +1,74 @@
+use rustc_hash::FxHashSet;
+
+use oxc_syntax::symbol::SymbolId;
+
+use super::{Access, Analysis, BlockAccesses, DataflowResults, Direction};
+use crate::BlockNodeId;
+
+/// Which symbols are written on every path to each point of the program.
+///
+/// The state is the set of definitely assigned symbols, or `None` for blocks that control never
+/// reaches from the entry. That includes the bodies of nested functions, so reads of captured
+/// variables are always considered assigned.
+pub struct DefiniteAssignment<'b> {
+    accesses: &'b BlockAccesses,
+}
+
+impl<'b> DefiniteAssignment<'b> {
+    pub fn new(accesses: &'b BlockAccesses) -> Self {
+        Self { accesses }
+    }
+
+    /// Whether `symbol_id` is definitely assigned before the `index`th access of `block`.
+    pub fn is_assigned_before(
+        &self,
+        results: &DataflowResults<Option<FxHashSet<SymbolId>>>,
+        block: BlockNodeId,
+        index: usize,
+        symbol_id: SymbolId,
+    ) -> bool {
+        let Some(assigned) = results.entry(block) else {
+            return true;
+        };
+        assigned.contains(&symbol_id)
+            || self.accesses.get(block)[..index]
+                .iter()
+                .any(|access| access.is_write() && access.symbol_id == symbol_id)
+    }
+
+    fn apply(access: &Access, state: &mut FxHashSet<SymbolId>) {
+        if access.is_write() {
+            state.insert(access.symbol_id);
+        }
+    }
+}
+
+impl Analysis for DefiniteAssignment<'_> {
+    type Domain = Option<FxHashSet<SymbolId>>;
+
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn bottom(&self) -> Self::Domain {
+        None
+    }
+
+    fn boundary(&self) -> Self::Domain {
+        Some(FxHashSet::default())
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) {
+        match (state.as_mut(), other) {
+            (_, None) => {}
+            (None, Some(other)) => *state = Some(other.clone()),
+            (Some(state), Some(other)) => state.retain(|symbol_id| other.contains(symbol_id)),
+        }
+    }
+
+    fn transfer(&self, block: BlockNodeId, state: &mut Self::Domain) {
+        if let Some(state) = state {
+            for access in self.accesses.get(block) {
+                Self::apply(access, state);
+            }
+        }
+    }
+}
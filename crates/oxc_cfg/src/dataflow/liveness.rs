@@ -0,0 +1,62 @@
+use rustc_hash::FxHashSet;
+
+use oxc_syntax::symbol::SymbolId;
+
+use super::{Access, Analysis, BlockAccesses, DataflowResults, Direction};
+use crate::BlockNodeId;
+
+/// Which symbols may be read before being written again.
+///
+/// The state is the set of symbols that are live at a point. A write to a symbol that is not
+/// live right after it is a dead store.
+pub struct Liveness<'b> {
+    accesses: &'b BlockAccesses,
+}
+
+impl<'b> Liveness<'b> {
+    pub fn new(accesses: &'b BlockAccesses) -> Self {
+        Self { accesses }
+    }
+
+    /// Symbols that are live right after the `index`th access of `block`.
+    pub fn live_after(
+        &self,
+        results: &DataflowResults<FxHashSet<SymbolId>>,
+        block: BlockNodeId,
+        index: usize,
+    ) -> FxHashSet<SymbolId> {
+        let mut state = results.exit(block).clone();
+        for access in self.accesses.get(block)[index + 1..].iter().rev() {
+            Self::apply(access, &mut state);
+        }
+        state
+    }
+
+    fn apply(access: &Access, state: &mut FxHashSet<SymbolId>) {
+        if access.is_write() {
+            state.remove(&access.symbol_id);
+        } else {
+            state.insert(access.symbol_id);
+        }
+    }
+}
+
+impl Analysis for Liveness<'_> {
+    type Domain = FxHashSet<SymbolId>;
+
+    const DIRECTION: Direction = Direction::Backward;
+
+    fn bottom(&self) -> Self::Domain {
+        FxHashSet::default()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) {
+        state.extend(other.iter().copied());
+    }
+
+    fn transfer(&self, block: BlockNodeId, state: &mut Self::Domain) {
+        for access in self.accesses.get(block).iter().rev() {
+            Self::apply(access, state);
+        }
+    }
+}
@@ -0,0 +1,241 @@
+//! Dataflow analysis over a [`ControlFlowGraph`].
+//!
+//! [`solve`] runs an [`Analysis`] to a fixed point using a worklist over basic blocks, and
+//! records the state on entry to and exit from every block.
+//!
+//! The built-in analyses ([`ReachingDefinitions`], [`Liveness`] and [`DefiniteAssignment`])
+//! operate on the reads and writes of symbols in each basic block, which are recorded in
+//! [`BlockAccesses`]. `oxc_semantic` can produce these for a program with
+//! `Semantic::block_accesses`.
+
+mod definite_assignment;
+mod liveness;
+mod reaching_definitions;
+
+use std::collections::VecDeque;
+
+use petgraph::{Direction as EdgeDirection, visit::EdgeRef};
+use rustc_hash::FxHashMap;
+
+use oxc_syntax::{node::NodeId, symbol::SymbolId};
+
+use crate::{BlockNodeId, ControlFlowGraph, EdgeType};
+
+pub use definite_assignment::DefiniteAssignment;
+pub use liveness::Liveness;
+pub use reaching_definitions::ReachingDefinitions;
+
+/// The direction in which state flows through the graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// From a block to its successors, e.g. reaching definitions.
+    Forward,
+    /// From a block to its predecessors, e.g. liveness.
+    Backward,
+}
+
+/// A dataflow problem solvable with [`solve`].
+///
+/// The domain must form a lattice of finite height under [`Analysis::join`], and
+/// [`Analysis::transfer`] must be monotone, otherwise the solver may not terminate.
+pub trait Analysis {
+    type Domain: Clone + PartialEq;
+
+    const DIRECTION: Direction;
+
+    /// The initial state of every block, which is also the identity of [`Analysis::join`].
+    fn bottom(&self) -> Self::Domain;
+
+    /// The state flowing into the entry block of a forward analysis, or out of every block
+    /// without successors in a backward analysis.
+    fn boundary(&self) -> Self::Domain {
+        self.bottom()
+    }
+
+    /// Merges `other` into `state` where control flow paths meet.
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain);
+
+    /// Applies the effect of `block` to `state`, in the direction of the analysis.
+    fn transfer(&self, block: BlockNodeId, state: &mut Self::Domain);
+
+    /// The state flowing along the edge from `source` to `target`, given the state flowing into
+    /// the edge, or `None` if the edge does not change it.
+    ///
+    /// `source` and `target` are in program order regardless of the direction of the analysis.
+    /// This is how e.g. the outcome of a condition is applied to the branch it leads to.
+    fn transfer_edge(
+        &self,
+        _source: BlockNodeId,
+        _target: BlockNodeId,
+        _edge: &EdgeType,
+        _state: &Self::Domain,
+    ) -> Option<Self::Domain> {
+        None
+    }
+
+    /// Whether state flows along an edge of the given type.
+    ///
+    /// By default, state does not flow into nested functions or along unreachable edges.
+    fn follows_edge(&self, edge: &EdgeType) -> bool {
+        !matches!(edge, EdgeType::NewFunction | EdgeType::Unreachable)
+    }
+}
+
+/// The fixed point computed by [`solve`].
+#[derive(Debug, Clone)]
+pub struct DataflowResults<D> {
+    entry: Vec<D>,
+    exit: Vec<D>,
+}
+
+impl<D> DataflowResults<D> {
+    /// State before the first instruction of `block`, in program order.
+    pub fn entry(&self, block: BlockNodeId) -> &D {
+        &self.entry[block.index()]
+    }
+
+    /// State after the last instruction of `block`, in program order.
+    pub fn exit(&self, block: BlockNodeId) -> &D {
+        &self.exit[block.index()]
+    }
+}
+
+/// Solves `analysis` over `cfg`.
+///
+/// For a forward analysis, [`Analysis::boundary`] flows into `entry`. Blocks that are not
+/// reachable from `entry`, such as the bodies of nested functions, keep [`Analysis::bottom`]
+/// as their incoming state.
+pub fn solve<A: Analysis>(
+    cfg: &ControlFlowGraph,
+    analysis: &A,
+    entry: BlockNodeId,
+) -> DataflowResults<A::Domain> {
+    let graph = &cfg.graph;
+    let (incoming, outgoing) = match A::DIRECTION {
+        Direction::Forward => (EdgeDirection::Incoming, EdgeDirection::Outgoing),
+        Direction::Backward => (EdgeDirection::Outgoing, EdgeDirection::Incoming),
+    };
+    let is_boundary = |block: BlockNodeId| match A::DIRECTION {
+        Direction::Forward => block == entry,
+        Direction::Backward => !graph
+            .edges_directed(block, EdgeDirection::Outgoing)
+            .any(|edge| analysis.follows_edge(edge.weight())),
+    };
+
+    let len = graph.node_count();
+    // State flowing into and out of each block, in the direction of the analysis.
+    let mut state_in = vec![analysis.bottom(); len];
+    let mut state_out = vec![analysis.bottom(); len];
+
+    let mut worklist: VecDeque<BlockNodeId> = match A::DIRECTION {
+        Direction::Forward => graph.node_indices().collect(),
+        Direction::Backward => graph.node_indices().rev().collect(),
+    };
+    let mut queued = vec![true; len];
+
+    while let Some(block) = worklist.pop_front() {
+        queued[block.index()] = false;
+
+        let mut state = if is_boundary(block) { analysis.boundary() } else { analysis.bottom() };
+        for edge in graph.edges_directed(block, incoming) {
+            if analysis.follows_edge(edge.weight()) {
+                let other =
+                    if incoming == EdgeDirection::Incoming { edge.source() } else { edge.target() };
+                let other_state = &state_out[other.index()];
+                match analysis.transfer_edge(
+                    edge.source(),
+                    edge.target(),
+                    edge.weight(),
+                    other_state,
+                ) {
+                    Some(edge_state) => analysis.join(&mut state, &edge_state),
+                    None => analysis.join(&mut state, other_state),
+                }
+            }
+        }
+        state_in[block.index()] = state.clone();
+
+        analysis.transfer(block, &mut state);
+        if state == state_out[block.index()] {
+            continue;
+        }
+        state_out[block.index()] = state;
+
+        for edge in graph.edges_directed(block, outgoing) {
+            if !analysis.follows_edge(edge.weight()) {
+                continue;
+            }
+            let next =
+                if outgoing == EdgeDirection::Outgoing { edge.target() } else { edge.source() };
+            if !queued[next.index()] {
+                queued[next.index()] = true;
+                worklist.push_back(next);
+            }
+        }
+    }
+
+    match A::DIRECTION {
+        Direction::Forward => DataflowResults { entry: state_in, exit: state_out },
+        Direction::Backward => DataflowResults { entry: state_out, exit: state_in },
+    }
+}
+
+/// Whether an [`Access`] reads or writes its symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AccessKind {
+    Read,
+    Write,
+}
+
+/// A read or write of a symbol.
+///
+/// A compound assignment such as `x += 1` is recorded as a read followed by a write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Access {
+    pub symbol_id: SymbolId,
+    /// The identifier reference, or the declaration for an initialized binding.
+    pub node_id: NodeId,
+    pub kind: AccessKind,
+}
+
+impl Access {
+    pub fn is_read(&self) -> bool {
+        self.kind == AccessKind::Read
+    }
+
+    pub fn is_write(&self) -> bool {
+        self.kind == AccessKind::Write
+    }
+}
+
+/// The accesses of each basic block, in evaluation order.
+#[derive(Debug, Default, Clone)]
+pub struct BlockAccesses {
+    blocks: FxHashMap<BlockNodeId, Vec<Access>>,
+}
+
+impl BlockAccesses {
+    /// Appends an access to the end of `block`.
+    pub fn push(&mut self, block: BlockNodeId, access: Access) {
+        self.blocks.entry(block).or_default().push(access);
+    }
+
+    /// The accesses of `block`, in evaluation order.
+    pub fn get(&self, block: BlockNodeId) -> &[Access] {
+        self.blocks.get(&block).map_or(&[], Vec::as_slice)
+    }
+
+    /// Finds the block and position of the access of `node_id`.
+    pub fn find(&self, node_id: NodeId, kind: AccessKind) -> Option<(BlockNodeId, usize)> {
+        self.blocks.iter().find_map(|(block, accesses)| {
+            accesses
+                .iter()
+                .position(|access| access.node_id == node_id && access.kind == kind)
+                .map(|index| (*block, index))
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (BlockNodeId, &[Access])> + '_ {
+        self.blocks.iter().map(|(block, accesses)| (*block, accesses.as_slice()))
+    }
+}
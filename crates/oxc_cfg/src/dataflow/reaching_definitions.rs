@@ -0,0 +1,58 @@
+use rustc_hash::FxHashSet;
+
+use super::{Access, Analysis, BlockAccesses, DataflowResults, Direction};
+use crate::BlockNodeId;
+
+/// Which writes may reach each point of the program without being overwritten.
+///
+/// The state is the set of writes that reach a point.
+pub struct ReachingDefinitions<'b> {
+    accesses: &'b BlockAccesses,
+}
+
+impl<'b> ReachingDefinitions<'b> {
+    pub fn new(accesses: &'b BlockAccesses) -> Self {
+        Self { accesses }
+    }
+
+    /// Writes that reach the `index`th access of `block`.
+    pub fn definitions_before(
+        &self,
+        results: &DataflowResults<FxHashSet<Access>>,
+        block: BlockNodeId,
+        index: usize,
+    ) -> FxHashSet<Access> {
+        let mut state = results.entry(block).clone();
+        for access in &self.accesses.get(block)[..index] {
+            Self::apply(access, &mut state);
+        }
+        state
+    }
+
+    fn apply(access: &Access, state: &mut FxHashSet<Access>) {
+        if access.is_write() {
+            state.retain(|definition| definition.symbol_id != access.symbol_id);
+            state.insert(*access);
+        }
+    }
+}
+
+impl Analysis for ReachingDefinitions<'_> {
+    type Domain = FxHashSet<Access>;
+
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn bottom(&self) -> Self::Domain {
+        FxHashSet::default()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) {
+        state.extend(other.iter().copied());
+    }
+
+    fn transfer(&self, block: BlockNodeId, state: &mut Self::Domain) {
+        for access in self.accesses.get(block) {
+            Self::apply(access, state);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 mod block;
 mod builder;
+pub mod dataflow;
 pub mod dot;
 pub mod visit;
 
@@ -157,6 +157,12 @@ impl Gen for Statement<'_> {
             Self::ExportAllDeclaration(decl) => decl.print(p, ctx),
             Self::WithStatement(stmt) => stmt.print(p, ctx),
             Self::DebuggerStatement(stmt) => stmt.print(p, ctx),
+            Self::ErrorNode(node) => {
+                p.print_comments_at(node.span.start);
+                p.print_indent();
+                node.print(p, ctx);
+                p.print_soft_newline();
+            }
             // TypeScript-specific (less common)
             Self::TSModuleDeclaration(decl) => {
                 p.print_comments_at(decl.span.start);
@@ -190,6 +196,12 @@ impl Gen for Statement<'_> {
             }
             Self::TSExportAssignment(decl) => decl.print(p, ctx),
             Self::TSNamespaceExportDeclaration(decl) => decl.print(p, ctx),
+            Self::FlowOpaqueTypeDeclaration(decl) => {
+                p.print_indent();
+                p.print_comments_at(decl.span.start);
+                decl.print(p, ctx);
+                p.print_semicolon_after_statement();
+            }
             Self::TSImportEqualsDeclaration(decl) => {
                 p.print_indent();
                 p.print_comments_at(decl.span.start);
@@ -703,6 +715,16 @@ impl Gen for DebuggerStatement {
     }
 }
 
+impl Gen for ErrorNode {
+    /// Source text which failed to parse is printed verbatim, if the source text is available.
+    fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
+        p.add_source_mapping(self.span);
+        if let Some(source_text) = p.source_text {
+            p.print_str(self.span.source_text(source_text));
+        }
+    }
+}
+
 impl Gen for VariableDeclaration<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         p.add_source_mapping(self.span);
@@ -850,6 +872,13 @@ impl Gen for FormalParameter<'_> {
             p.print_str("readonly");
             p.print_soft_space();
         }
+        // Unnamed Flow function type parameter, e.g. `string` in `(string) => void`
+        if self.pattern.is_unnamed_flow_parameter()
+            && let Some(type_annotation) = &self.type_annotation
+        {
+            type_annotation.print(p, ctx);
+            return;
+        }
         self.pattern.print(p, ctx);
         if self.optional {
             p.print_ascii_byte(b'?');
@@ -872,6 +901,13 @@ impl Gen for FormalParameterRest<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         p.add_source_mapping(self.span);
         p.print_decorators(&self.decorators, ctx);
+        if self.rest.argument.is_unnamed_flow_parameter()
+            && let Some(type_annotation) = &self.type_annotation
+        {
+            p.print_str("...");
+            type_annotation.print(p, ctx);
+            return;
+        }
         self.rest.print(p, ctx);
         if let Some(type_annotation) = &self.type_annotation {
             p.print_colon();
@@ -901,7 +937,9 @@ impl Gen for ImportDeclaration<'_> {
         p.add_source_mapping(self.span);
         p.print_space_before_identifier();
         p.print_str("import");
-        if self.import_kind.is_type() {
+        if self.import_kind.is_typeof() {
+            p.print_str(" typeof");
+        } else if self.import_kind.is_type() {
             p.print_str(" type");
         }
         if let Some(phase) = self.phase {
@@ -976,7 +1014,9 @@ impl Gen for ImportDeclaration<'_> {
                             p.print_soft_space();
                         }
 
-                        if spec.import_kind.is_type() {
+                        if spec.import_kind.is_typeof() {
+                            p.print_str("typeof ");
+                        } else if spec.import_kind.is_type() {
                             p.print_str("type ");
                         }
 
@@ -1074,11 +1114,13 @@ impl Gen for ExportNamedDeclaration<'_> {
                 Declaration::TSInterfaceDeclaration(decl) => decl.print(p, ctx),
                 Declaration::TSEnumDeclaration(decl) => decl.print(p, ctx),
                 Declaration::TSImportEqualsDeclaration(decl) => decl.print(p, ctx),
+                Declaration::FlowOpaqueTypeDeclaration(decl) => decl.print(p, ctx),
             }
             if matches!(
                 decl,
                 Declaration::VariableDeclaration(_)
                     | Declaration::TSTypeAliasDeclaration(_)
+                    | Declaration::FlowOpaqueTypeDeclaration(_)
                     | Declaration::TSImportEqualsDeclaration(_)
             ) {
                 p.print_semicolon_after_statement();
@@ -1339,6 +1381,11 @@ impl GenExpr for Expression<'_> {
             Self::TSInstantiationExpression(e) => e.print_expr(p, precedence, ctx),
             // V8 intrinsics (rare)
             Self::V8IntrinsicExpression(e) => e.print_expr(p, precedence, ctx),
+            // Flow
+            Self::FlowTypeCastExpression(e) => e.print(p, ctx),
+            // Proposals
+            Self::PipelineExpression(e) => e.print_expr(p, precedence, ctx),
+            Self::TopicReference(e) => e.print(p, ctx),
         }
     }
 }
@@ -1694,6 +1741,7 @@ impl Gen for ObjectPropertyKind<'_> {
         match self {
             Self::ObjectProperty(prop) => prop.print(p, ctx),
             Self::SpreadProperty(elem) => elem.print(p, ctx),
+            Self::ErrorNode(node) => node.print(p, ctx),
         }
     }
 }
@@ -2013,6 +2061,37 @@ impl GenExpr for ConditionalExpression<'_> {
     }
 }
 
+impl GenExpr for PipelineExpression<'_> {
+    fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
+        let mut ctx = ctx;
+        let wrap = precedence >= self.precedence();
+        if wrap {
+            ctx &= Context::FORBID_IN.not();
+        }
+        p.wrap(wrap, |p| {
+            self.head.print_expr(p, Precedence::Conditional, ctx & Context::FORBID_IN);
+            p.print_soft_space();
+            p.print_str("|>");
+            p.print_soft_space();
+            // A nested pipeline is the right-hand side of a chain `a |> b |> c`.
+            // Arrow functions, assignments, conditionals and `yield` must be parenthesized.
+            let body_precedence = if matches!(self.body, Expression::PipelineExpression(_)) {
+                Precedence::Yield
+            } else {
+                Precedence::Conditional
+            };
+            self.body.print_expr(p, body_precedence, ctx & Context::FORBID_IN);
+        });
+    }
+}
+
+impl Gen for TopicReference {
+    fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
+        p.add_source_mapping(self.span);
+        p.print_ascii_byte(b'%');
+    }
+}
+
 /// `true` if `expr` is a `pife`-marked arrow or function expression — its
 /// own `gen_expr` adds a `(` wrap and prints leading comments inside it.
 fn is_pife_arrow_or_function(expr: &Expression<'_>) -> bool {
@@ -2574,6 +2653,10 @@ impl Gen for ClassElement<'_> {
                 elem.print(p, ctx);
                 p.print_semicolon_after_statement();
             }
+            Self::ErrorNode(node) => {
+                node.print(p, ctx);
+                p.print_soft_newline();
+            }
         }
     }
 }
@@ -3907,9 +3990,12 @@ impl Gen for TSIndexSignature<'_> {
                 p.print_ascii_byte(b',');
                 p.print_soft_space();
             }
-            p.print_str(parameter.name.as_str());
-            p.print_colon();
-            p.print_soft_space();
+            // Flow indexers may have no name, e.g. `{ [string]: number }`
+            if !parameter.name.is_empty() {
+                p.print_str(parameter.name.as_str());
+                p.print_colon();
+                p.print_soft_space();
+            }
             parameter.type_annotation.print(p, ctx);
         }
         p.print_ascii_byte(b']');
@@ -4075,6 +4161,31 @@ impl Gen for TSTypeAliasDeclaration<'_> {
     }
 }
 
+impl Gen for FlowOpaqueTypeDeclaration<'_> {
+    fn r#gen(&self, p: &mut Codegen, ctx: Context) {
+        if self.declare {
+            p.print_str("declare ");
+        }
+        p.print_str("opaque type");
+        p.print_space_before_identifier();
+        self.id.print(p, ctx);
+        if let Some(type_parameters) = &self.type_parameters {
+            type_parameters.print(p, ctx);
+        }
+        if let Some(supertype) = &self.supertype {
+            p.print_colon();
+            p.print_soft_space();
+            supertype.print(p, ctx);
+        }
+        if let Some(impltype) = &self.impltype {
+            p.print_soft_space();
+            p.print_ascii_byte(b'=');
+            p.print_soft_space();
+            impltype.print(p, ctx);
+        }
+    }
+}
+
 impl Gen for TSInterfaceDeclaration<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         p.print_space_before_identifier();
@@ -4224,6 +4335,18 @@ impl Gen for TSModuleReference<'_> {
     }
 }
 
+impl Gen for FlowTypeCastExpression<'_> {
+    fn r#gen(&self, p: &mut Codegen, ctx: Context) {
+        p.add_source_mapping(self.span);
+        p.print_ascii_byte(b'(');
+        self.expression.print_expr(p, Precedence::Comma, Context::empty());
+        p.print_colon();
+        p.print_soft_space();
+        self.type_annotation.print(p, ctx);
+        p.print_ascii_byte(b')');
+    }
+}
+
 impl GenExpr for V8IntrinsicExpression<'_> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
         let is_statement = p.start_of_stmt == p.code_len();
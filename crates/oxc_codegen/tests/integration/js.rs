@@ -796,6 +796,32 @@ fn v8_intrinsics() {
     );
 }
 
+#[test]
+fn pipeline_operator() {
+    let parse_opts = oxc_parser::ParseOptions {
+        allow_pipeline_operator: true,
+        preserve_parens: false,
+        ..oxc_parser::ParseOptions::default()
+    };
+
+    let cases = [
+        ("a |> f(%) |> % + 1", "a |> f(%) |> % + 1;\n"),
+        ("(a |> f(%)) |> g(%)", "(a |> f(%)) |> g(%);\n"),
+        ("a |> (% ? b : c)", "a |> (% ? b : c);\n"),
+        ("a |> (() => %)", "a |> (() => %);\n"),
+        ("a |> (x = %)", "a |> (x = %);\n"),
+        ("(a ? b : c) |> %", "(a ? b : c) |> %;\n"),
+        ("a ?? b |> %", "a ?? b |> %;\n"),
+        ("(a |> %) ? b : c", "(a |> %) ? b : c;\n"),
+        ("c ? d : a |> %", "c ? d : a |> %;\n"),
+        ("(a |> %) + 1", "(a |> %) + 1;\n"),
+        ("x = a |> % % 2", "x = a |> % % 2;\n"),
+    ];
+    for (source, expected) in cases {
+        test_with_parse_options(source, expected, parse_opts);
+    }
+}
+
 #[test]
 fn indentation() {
     // Test default - tabs with width 1
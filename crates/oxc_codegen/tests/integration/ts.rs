@@ -495,3 +495,18 @@ fn ts_cast_assignment_target_is_parenthesized() {
     test_idempotency("(foo as Bar) = baz");
     test_idempotency("(foo satisfies Bar) = baz");
 }
+
+#[test]
+fn flow_unnamed_parameters_and_indexers() {
+    let flow = |src: &str| {
+        test_options_with_source_type(
+            src,
+            src,
+            SourceType::jsx().with_flow(true),
+            default_options(),
+        );
+    };
+    flow("type F = (string, ...Array<number>) => void;\n");
+    flow("type M = {\n\t[string]: number;\n\t[k: string]: number;\n};\n");
+    flow("declare function f(string, x: number): void;\n");
+}
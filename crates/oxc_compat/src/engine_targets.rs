@@ -76,6 +76,11 @@ impl EngineTargets {
     /// `false` if the feature IS supported (can be used natively).
     pub fn has_feature(&self, feature: ESFeature) -> bool {
         let feature_engine_targets = &features()[&feature];
+        // Internet Explorer only appears in the compat data of the few features it supports,
+        // so any other feature is not supported.
+        if self.contains_key(&Engine::Ie) && !feature_engine_targets.contains_key(&Engine::Ie) {
+            return true;
+        }
         for (engine, feature_version) in feature_engine_targets.iter() {
             if let Some(target_version) = self.get(engine) {
                 if *engine == Engine::Es {
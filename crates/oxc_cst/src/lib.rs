@@ -0,0 +1,167 @@
+//! Lossless concrete syntax tree.
+//!
+//! The AST discards the exact layout of source text: whitespace, comments, semicolons and
+//! redundant parentheses are not represented by nodes. [`SyntaxTree`] is an optional layer over
+//! the AST and the token stream of a parse which keeps all of it, so that tools such as codemods
+//! can edit part of a file while preserving the rest byte-for-byte.
+//!
+//! ```ignore
+//! let ret = Parser::new(&allocator, source_text, source_type)
+//!     .with_config(TokensParserConfig)
+//!     .parse();
+//! let program = allocator.alloc(ret.program);
+//! let tree = SyntaxTree::new(program, ret.tokens);
+//! assert_eq!(tree.to_string(), source_text);
+//! ```
+
+mod tree;
+mod trivia;
+
+pub use tree::{Children, ChildrenWithTokens, SyntaxElement, SyntaxNode, SyntaxToken, SyntaxTree};
+pub use trivia::{Trivia, TriviaKind};
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{AstType, ast::CommentKind};
+    use oxc_parser::{Kind, ParseOptions, Parser, config::TokensParserConfig};
+    use oxc_span::{SourceType, Span};
+
+    use crate::{SyntaxElement, SyntaxTree, TriviaKind};
+
+    fn build<'a>(allocator: &'a Allocator, source_text: &'a str) -> SyntaxTree<'a> {
+        build_with(allocator, source_text, SourceType::tsx(), ParseOptions::default())
+    }
+
+    fn build_with<'a>(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+        options: ParseOptions,
+    ) -> SyntaxTree<'a> {
+        let ret = Parser::new(allocator, source_text, source_type)
+            .with_options(options)
+            .with_config(TokensParserConfig)
+            .parse();
+        let program = allocator.alloc(ret.program);
+        SyntaxTree::new(program, ret.tokens)
+    }
+
+    #[test]
+    fn lossless() {
+        let sources = [
+            "",
+            "   \n// only a comment\n",
+            "#!/usr/bin/env node\n'use strict';\nlet a = 1 ;  // trailing\n\n/* leading */ a++\n",
+            "const x = ((1 + 2)) * 3;\r\nfoo(x,\u{2028}y,);\u{a0}\n",
+            "const t = `a${b}c${ `d` }`;\nconst r = /[/]+/g;\n",
+            "const el = <div className=\"a\">\n  text {/* comment */}\n  <br />\n</div>;\n",
+            "type A<T> = T extends string ? { a: T }[] : never;\nenum E { A = 1, }\n",
+            "@dec export class C<T> { #x = 1; static { this.y = 2 } declare z?: T }\n",
+        ];
+        for source_text in sources {
+            let allocator = Allocator::default();
+            let tree = build(&allocator, source_text);
+            assert_eq!(tree.to_string(), source_text);
+        }
+
+        // Syntax errors
+        let allocator = Allocator::default();
+        let options = ParseOptions { error_recovery: true, ..ParseOptions::default() };
+        let source_text = "let a = ;\nfoo bar baz\nlet b = [1, 2";
+        let tree = build_with(&allocator, source_text, SourceType::mjs(), options);
+        assert_eq!(tree.to_string(), source_text);
+    }
+
+    #[test]
+    fn trivia() {
+        let allocator = Allocator::default();
+        let source_text = "#!node\n/** doc */\nlet a = 1; // one\n\n  // two\nlet b;\n// end\n";
+        let tree = build(&allocator, source_text);
+        let tokens = tree.tokens().collect::<Vec<_>>();
+        let trivia_kinds =
+            |trivia: &[crate::Trivia]| trivia.iter().map(|t| t.kind).collect::<Vec<_>>();
+
+        let let_a = tokens[0];
+        assert_eq!(let_a.text(), "let");
+        assert_eq!(
+            trivia_kinds(let_a.leading_trivia()),
+            [
+                TriviaKind::Hashbang,
+                TriviaKind::LineTerminator,
+                TriviaKind::Comment(CommentKind::SingleLineBlock),
+                TriviaKind::LineTerminator,
+            ]
+        );
+        let semicolon = tokens[4];
+        assert_eq!(semicolon.kind(), Kind::Semicolon);
+        assert_eq!(
+            trivia_kinds(semicolon.trailing_trivia()),
+            [TriviaKind::Whitespace, TriviaKind::Comment(CommentKind::Line)]
+        );
+        let let_b = tokens[5];
+        assert_eq!(
+            trivia_kinds(let_b.leading_trivia()),
+            [
+                TriviaKind::LineTerminator,
+                TriviaKind::LineTerminator,
+                TriviaKind::Whitespace,
+                TriviaKind::Comment(CommentKind::Line),
+                TriviaKind::LineTerminator,
+            ]
+        );
+        assert_eq!(
+            trivia_kinds(tree.end_trivia()),
+            [
+                TriviaKind::LineTerminator,
+                TriviaKind::Comment(CommentKind::Line),
+                TriviaKind::LineTerminator,
+            ]
+        );
+        assert_eq!(tree.end_trivia()[1].span.source_text(source_text), "// end");
+    }
+
+    #[test]
+    fn nodes() {
+        let allocator = Allocator::default();
+        let source_text = "foo((a), b);";
+        let tree = build(&allocator, source_text);
+
+        let stmt = tree.root().children().next().unwrap();
+        assert_eq!(stmt.kind().ty(), AstType::ExpressionStatement);
+        let elements = stmt
+            .children_with_tokens()
+            .map(|element| match element {
+                SyntaxElement::Node(node) => format!("{:?}", node.kind().ty()),
+                SyntaxElement::Token(token) => token.text().to_string(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(elements, ["CallExpression", ";"]);
+
+        // Commas and the parentheses around arguments belong to the call expression
+        let call = stmt.children().next().unwrap();
+        let elements = call
+            .children_with_tokens()
+            .map(|element| match element {
+                SyntaxElement::Node(node) => node.text().to_string(),
+                SyntaxElement::Token(token) => token.text().to_string(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(elements, ["foo", "(", "(a)", ",", "b", ")"]);
+
+        let a = tree.token_at_offset(5).unwrap();
+        assert_eq!(a.text(), "a");
+        assert_eq!(a.parent().kind().ty(), AstType::IdentifierReference);
+        assert_eq!(a.prev_token().unwrap().parent().kind().ty(), AstType::ParenthesizedExpression);
+        assert_eq!(
+            a.next_token().unwrap().next_token().unwrap().parent().kind().ty(),
+            AstType::CallExpression
+        );
+        assert!(tree.token_at_offset(8).is_none());
+
+        let node = tree.covering_node(Span::new(4, 10));
+        assert_eq!(node.kind().ty(), AstType::CallExpression);
+        assert_eq!(node.parent().unwrap().kind().ty(), AstType::ExpressionStatement);
+        assert_eq!(tree.root().full_span(), Span::new(0, 12));
+    }
+}
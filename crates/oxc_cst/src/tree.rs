@@ -0,0 +1,441 @@
+use std::{fmt, iter::FusedIterator, ops::Range};
+
+use oxc_allocator::ArenaVec;
+use oxc_ast::{AstKind, ast::Program};
+use oxc_ast_visit::Visit;
+use oxc_parser::{Kind, Token};
+use oxc_span::{GetSpan, Span};
+
+use crate::trivia::{Trivia, TriviaCollector};
+
+/// Lossless syntax tree.
+///
+/// Groups the tokens of a parse under the AST nodes which own them, and attaches the trivia
+/// between tokens (whitespace, line terminators and comments) to the tokens either side.
+///
+/// Each token has leading and trailing trivia. Trailing trivia is everything after the token up
+/// to, but not including, the next line terminator. Leading trivia is everything before the
+/// token which is not the trailing trivia of the previous token. Trivia after the last token
+/// which is not its trailing trivia is [`SyntaxTree::end_trivia`].
+///
+/// Every token belongs to the innermost AST node whose span contains it. Tokens which have no
+/// node of their own, such as `;`, `,` and the parentheses around an expression when
+/// [`ParseOptions::preserve_parens`](oxc_parser::ParseOptions::preserve_parens) is disabled,
+/// belong to the enclosing node.
+///
+/// Printing a [`SyntaxTree`] with [`fmt::Display`] reproduces the source text exactly.
+pub struct SyntaxTree<'a> {
+    source_text: &'a str,
+    tokens: ArenaVec<'a, Token>,
+    trivia: Vec<Trivia>,
+    /// Index into `trivia` of the leading and trailing trivia of each token.
+    /// Has an extra entry at the end, whose leading trivia is [`SyntaxTree::end_trivia`].
+    token_trivia: Vec<TokenTrivia>,
+    /// Index into `nodes` of the node which owns each token.
+    token_owners: Vec<u32>,
+    /// All AST nodes in pre-order, so the descendants of a node follow it.
+    nodes: Vec<NodeData<'a>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TokenTrivia {
+    leading: u32,
+    trailing: u32,
+}
+
+#[derive(Debug, Clone)]
+struct NodeData<'a> {
+    kind: AstKind<'a>,
+    parent: Option<u32>,
+    /// Index into `nodes` after the last descendant of this node.
+    subtree_end: u32,
+    /// Tokens within the span of this node, including those of its descendants.
+    tokens: Range<u32>,
+}
+
+impl<'a> SyntaxTree<'a> {
+    /// Build a [`SyntaxTree`] from `program` and the `tokens` collected while parsing it.
+    ///
+    /// `tokens` must be the tokens returned by the parser for `program`, which are only collected
+    /// when the parser is configured with a token-collecting
+    /// [`ParserConfig`](oxc_parser::config::ParserConfig).
+    ///
+    /// # Panics
+    /// Panics if the source text is longer than `u32::MAX` bytes.
+    pub fn new(program: &'a Program<'a>, tokens: ArenaVec<'a, Token>) -> Self {
+        let source_text = program.source_text;
+        let mut collector = TriviaCollector::new(
+            source_text,
+            &program.comments,
+            program.hashbang.as_ref().map(GetSpan::span),
+        );
+        let mut token_trivia = Vec::with_capacity(tokens.len() + 1);
+        let mut prev_end = 0;
+        for token in tokens.iter().map(Token::span).chain([Span::empty(to_u32(source_text.len()))])
+        {
+            let gap_start = collector.trivia.len();
+            let line_start = collector.collect(prev_end, token.start);
+            // Trivia before the first token has no previous token to trail
+            let leading = if token_trivia.is_empty() { gap_start } else { line_start };
+            token_trivia.push(TokenTrivia {
+                leading: to_u32(leading),
+                trailing: to_u32(collector.trivia.len()),
+            });
+            prev_end = token.end;
+        }
+        let trivia = collector.trivia;
+
+        let mut builder = NodeBuilder { tokens: &tokens, nodes: vec![], parents: vec![] };
+        builder.visit_program(program);
+        let nodes = builder.nodes;
+
+        let mut tree =
+            Self { source_text, tokens, trivia, token_trivia, token_owners: vec![], nodes };
+        tree.token_owners = tree.compute_token_owners();
+        tree
+    }
+
+    fn compute_token_owners(&self) -> Vec<u32> {
+        let mut owners = vec![0; self.tokens.len()];
+        for node in self.nodes() {
+            for element in node.children_with_tokens() {
+                if let SyntaxElement::Token(token) = element {
+                    owners[token.index as usize] = node.index;
+                }
+            }
+        }
+        owners
+    }
+
+    /// Source text which the tree was built from.
+    pub fn source_text(&self) -> &'a str {
+        self.source_text
+    }
+
+    /// The root node, which is always the [`Program`].
+    pub fn root(&self) -> SyntaxNode<'_, 'a> {
+        SyntaxNode { tree: self, index: 0 }
+    }
+
+    /// All nodes, in pre-order.
+    pub fn nodes(&self) -> impl ExactSizeIterator<Item = SyntaxNode<'_, 'a>> {
+        (0..to_u32(self.nodes.len())).map(|index| SyntaxNode { tree: self, index })
+    }
+
+    /// All tokens, in source order.
+    pub fn tokens(&self) -> impl ExactSizeIterator<Item = SyntaxToken<'_, 'a>> {
+        (0..to_u32(self.tokens.len())).map(|index| SyntaxToken { tree: self, index })
+    }
+
+    /// Trivia after the trailing trivia of the last token, up to the end of the file.
+    ///
+    /// If the file contains no tokens, this is all of the trivia in the file.
+    pub fn end_trivia(&self) -> &[Trivia] {
+        let end = self.token_trivia[self.tokens.len()];
+        &self.trivia[end.leading as usize..end.trailing as usize]
+    }
+
+    /// Find the innermost node whose span contains `span`.
+    pub fn covering_node(&self, span: Span) -> SyntaxNode<'_, 'a> {
+        let mut node = self.root();
+        'outer: loop {
+            for child in node.children() {
+                let child_span = child.span();
+                if child_span.start <= span.start && span.end <= child_span.end {
+                    node = child;
+                    continue 'outer;
+                }
+            }
+            return node;
+        }
+    }
+
+    /// Find the token which contains `offset`, if `offset` is not in trivia.
+    pub fn token_at_offset(&self, offset: u32) -> Option<SyntaxToken<'_, 'a>> {
+        let index = self.tokens.partition_point(|token| token.end() <= offset);
+        let token = self.tokens.get(index)?;
+        (token.start() <= offset).then_some(SyntaxToken { tree: self, index: to_u32(index) })
+    }
+
+    fn trivia_range(&self, range: Range<u32>) -> &[Trivia] {
+        &self.trivia[range.start as usize..range.end as usize]
+    }
+}
+
+impl fmt::Display for SyntaxTree<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_trivia = |f: &mut fmt::Formatter<'_>, trivia: &[Trivia]| {
+            trivia
+                .iter()
+                .try_for_each(|trivia| f.write_str(trivia.span.source_text(self.source_text)))
+        };
+        for token in self.tokens() {
+            write_trivia(f, token.leading_trivia())?;
+            f.write_str(token.text())?;
+            write_trivia(f, token.trailing_trivia())?;
+        }
+        write_trivia(f, self.end_trivia())
+    }
+}
+
+impl fmt::Debug for SyntaxTree<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_node(
+            f: &mut fmt::Formatter<'_>,
+            node: SyntaxNode<'_, '_>,
+            depth: usize,
+        ) -> fmt::Result {
+            writeln!(
+                f,
+                "{:indent$}{:?}@{:?}",
+                "",
+                node.kind().ty(),
+                node.span(),
+                indent = depth * 2
+            )?;
+            for element in node.children_with_tokens() {
+                match element {
+                    SyntaxElement::Node(child) => write_node(f, child, depth + 1)?,
+                    SyntaxElement::Token(token) => writeln!(
+                        f,
+                        "{:indent$}{:?}@{:?} {:?}",
+                        "",
+                        token.kind(),
+                        token.span(),
+                        token.text(),
+                        indent = (depth + 1) * 2
+                    )?,
+                }
+            }
+            Ok(())
+        }
+        write_node(f, self.root(), 0)
+    }
+}
+
+/// An AST node in a [`SyntaxTree`].
+#[derive(Clone, Copy)]
+pub struct SyntaxNode<'t, 'a> {
+    tree: &'t SyntaxTree<'a>,
+    index: u32,
+}
+
+impl<'t, 'a> SyntaxNode<'t, 'a> {
+    fn data(self) -> &'t NodeData<'a> {
+        &self.tree.nodes[self.index as usize]
+    }
+
+    /// The AST node.
+    pub fn kind(self) -> AstKind<'a> {
+        self.data().kind
+    }
+
+    /// Span of the node, excluding the trivia around it.
+    pub fn span(self) -> Span {
+        self.kind().span()
+    }
+
+    /// Span of the node, including the leading trivia of its first token and the trailing
+    /// trivia of its last token.
+    pub fn full_span(self) -> Span {
+        let mut tokens = self.tokens();
+        let (Some(first), Some(last)) = (tokens.next(), tokens.next_back()) else {
+            return self.span();
+        };
+        let start = first.leading_trivia().first().map_or(first.span().start, |t| t.span.start);
+        let end = last.trailing_trivia().last().map_or(last.span().end, |t| t.span.end);
+        Span::new(start.min(self.span().start), end.max(self.span().end))
+    }
+
+    /// Source text of the node, excluding the trivia around it.
+    pub fn text(self) -> &'a str {
+        self.span().source_text(self.tree.source_text)
+    }
+
+    pub fn parent(self) -> Option<Self> {
+        self.data().parent.map(|index| Self { tree: self.tree, index })
+    }
+
+    /// Child nodes, in source order.
+    pub fn children(self) -> Children<'t, 'a> {
+        Children { tree: self.tree, next: self.index + 1, end: self.data().subtree_end }
+    }
+
+    /// All tokens within the node, including those owned by its descendants.
+    pub fn tokens(
+        self,
+    ) -> impl DoubleEndedIterator<Item = SyntaxToken<'t, 'a>> + ExactSizeIterator {
+        let tree = self.tree;
+        self.data().tokens.clone().map(move |index| SyntaxToken { tree, index })
+    }
+
+    /// Child nodes and the tokens owned by this node, interleaved in source order.
+    pub fn children_with_tokens(self) -> ChildrenWithTokens<'t, 'a> {
+        ChildrenWithTokens {
+            tree: self.tree,
+            next_token: self.data().tokens.start,
+            end_token: self.data().tokens.end,
+            children: self.children(),
+            next_child: None,
+        }
+    }
+}
+
+impl fmt::Debug for SyntaxNode<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}@{:?}", self.kind().ty(), self.span())
+    }
+}
+
+/// A token in a [`SyntaxTree`].
+#[derive(Clone, Copy)]
+pub struct SyntaxToken<'t, 'a> {
+    tree: &'t SyntaxTree<'a>,
+    index: u32,
+}
+
+impl<'t, 'a> SyntaxToken<'t, 'a> {
+    fn token(self) -> Token {
+        self.tree.tokens[self.index as usize]
+    }
+
+    pub fn kind(self) -> Kind {
+        self.token().kind()
+    }
+
+    /// Span of the token, excluding trivia.
+    pub fn span(self) -> Span {
+        self.token().span()
+    }
+
+    /// Source text of the token, excluding trivia.
+    pub fn text(self) -> &'a str {
+        self.span().source_text(self.tree.source_text)
+    }
+
+    /// Trivia before the token, which is not trailing trivia of the previous token.
+    pub fn leading_trivia(self) -> &'t [Trivia] {
+        let trivia = self.tree.token_trivia[self.index as usize];
+        self.tree.trivia_range(trivia.leading..trivia.trailing)
+    }
+
+    /// Trivia after the token, up to the next line terminator.
+    pub fn trailing_trivia(self) -> &'t [Trivia] {
+        let trivia = self.tree.token_trivia[self.index as usize];
+        let next = self.tree.token_trivia[self.index as usize + 1];
+        self.tree.trivia_range(trivia.trailing..next.leading)
+    }
+
+    /// The innermost node which contains the token.
+    pub fn parent(self) -> SyntaxNode<'t, 'a> {
+        SyntaxNode { tree: self.tree, index: self.tree.token_owners[self.index as usize] }
+    }
+
+    pub fn prev_token(self) -> Option<Self> {
+        self.index.checked_sub(1).map(|index| Self { tree: self.tree, index })
+    }
+
+    pub fn next_token(self) -> Option<Self> {
+        let index = self.index + 1;
+        ((index as usize) < self.tree.tokens.len()).then_some(Self { tree: self.tree, index })
+    }
+}
+
+impl fmt::Debug for SyntaxToken<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}@{:?} {:?}", self.kind(), self.span(), self.text())
+    }
+}
+
+/// A child of a [`SyntaxNode`].
+#[derive(Debug, Clone, Copy)]
+pub enum SyntaxElement<'t, 'a> {
+    Node(SyntaxNode<'t, 'a>),
+    Token(SyntaxToken<'t, 'a>),
+}
+
+/// Iterator over the child nodes of a [`SyntaxNode`]. See [`SyntaxNode::children`].
+pub struct Children<'t, 'a> {
+    tree: &'t SyntaxTree<'a>,
+    next: u32,
+    end: u32,
+}
+
+impl<'t, 'a> Iterator for Children<'t, 'a> {
+    type Item = SyntaxNode<'t, 'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.end {
+            return None;
+        }
+        let node = SyntaxNode { tree: self.tree, index: self.next };
+        self.next = node.data().subtree_end;
+        Some(node)
+    }
+}
+
+impl FusedIterator for Children<'_, '_> {}
+
+/// Iterator over the child nodes and tokens of a [`SyntaxNode`].
+/// See [`SyntaxNode::children_with_tokens`].
+pub struct ChildrenWithTokens<'t, 'a> {
+    tree: &'t SyntaxTree<'a>,
+    next_token: u32,
+    end_token: u32,
+    children: Children<'t, 'a>,
+    next_child: Option<SyntaxNode<'t, 'a>>,
+}
+
+impl<'t, 'a> Iterator for ChildrenWithTokens<'t, 'a> {
+    type Item = SyntaxElement<'t, 'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_child.is_none() {
+            self.next_child = self.children.next();
+        }
+        let child_tokens_start =
+            self.next_child.map_or(self.end_token, |child| child.data().tokens.start);
+        if self.next_token < child_tokens_start.min(self.end_token) {
+            let token = SyntaxToken { tree: self.tree, index: self.next_token };
+            self.next_token += 1;
+            return Some(SyntaxElement::Token(token));
+        }
+        let child = self.next_child.take()?;
+        self.next_token = self.next_token.max(child.data().tokens.end);
+        Some(SyntaxElement::Node(child))
+    }
+}
+
+impl FusedIterator for ChildrenWithTokens<'_, '_> {}
+
+/// Records AST nodes in pre-order, with the range of tokens in each.
+struct NodeBuilder<'b, 'a> {
+    tokens: &'b [Token],
+    nodes: Vec<NodeData<'a>>,
+    parents: Vec<u32>,
+}
+
+impl<'a> Visit<'a> for NodeBuilder<'_, 'a> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let span = kind.span();
+        let start = self.tokens.partition_point(|token| token.start() < span.start);
+        let end = start + self.tokens[start..].partition_point(|token| token.start() < span.end);
+        let index = to_u32(self.nodes.len());
+        self.nodes.push(NodeData {
+            kind,
+            parent: self.parents.last().copied(),
+            subtree_end: index + 1,
+            tokens: to_u32(start)..to_u32(end),
+        });
+        self.parents.push(index);
+    }
+
+    fn leave_node(&mut self, _kind: AstKind<'a>) {
+        let index = self.parents.pop().unwrap();
+        self.nodes[index as usize].subtree_end = to_u32(self.nodes.len());
+    }
+}
+
+fn to_u32(n: usize) -> u32 {
+    u32::try_from(n).expect("source text is too long")
+}
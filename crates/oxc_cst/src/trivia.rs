@@ -0,0 +1,103 @@
+//! Trivia: the source text between tokens.
+
+use oxc_ast::ast::{Comment, CommentKind};
+use oxc_span::Span;
+use oxc_syntax::{identifier::is_white_space, line_terminator::is_line_terminator};
+
+/// A piece of source text which is not part of any token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    /// Span of the trivia in the source text.
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriviaKind {
+    /// A run of whitespace on a single line.
+    Whitespace,
+    /// A single line terminator. `\r\n` is one line terminator.
+    LineTerminator,
+    /// A comment, with its delimiters.
+    Comment(CommentKind),
+    /// `#!` comment at the start of the file.
+    Hashbang,
+    /// Text which the lexer skipped over, e.g. after a syntax error.
+    Skipped,
+}
+
+impl TriviaKind {
+    /// `true` for comments, including the hashbang.
+    pub fn is_comment(self) -> bool {
+        matches!(self, Self::Comment(_) | Self::Hashbang)
+    }
+}
+
+/// Splits the text between tokens into [`Trivia`].
+pub struct TriviaCollector<'s, 'c> {
+    source_text: &'s str,
+    /// Comments not yet reached, in source order.
+    comments: &'c [Comment],
+    hashbang: Option<Span>,
+    pub trivia: Vec<Trivia>,
+}
+
+impl<'s, 'c> TriviaCollector<'s, 'c> {
+    pub fn new(source_text: &'s str, comments: &'c [Comment], hashbang: Option<Span>) -> Self {
+        Self { source_text, comments, hashbang, trivia: vec![] }
+    }
+
+    /// Collect trivia from `start` up to `end`.
+    ///
+    /// Returns the index of the first trivia after a line terminator, which is where the trailing
+    /// trivia of the previous token ends. If there is no line terminator, returns the index after
+    /// the last trivia.
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn collect(&mut self, mut start: u32, end: u32) -> usize {
+        let mut line_start = None;
+        while start < end {
+            let kind;
+            let len;
+            if let Some((comment, rest)) =
+                self.comments.split_first().filter(|(comment, _)| comment.span.start == start)
+            {
+                self.comments = rest;
+                kind = TriviaKind::Comment(comment.kind);
+                len = comment.span.size();
+            } else if let Some(hashbang) = self.hashbang.filter(|span| span.start == start) {
+                kind = TriviaKind::Hashbang;
+                len = hashbang.size();
+            } else {
+                let text = &self.source_text[start as usize..end as usize];
+                let c = text.chars().next().unwrap();
+                if is_line_terminator(c) {
+                    kind = TriviaKind::LineTerminator;
+                    len = if text.starts_with("\r\n") { 2 } else { c.len_utf8() as u32 };
+                } else if is_white_space(c) {
+                    kind = TriviaKind::Whitespace;
+                    len = text.find(|c| !is_white_space(c)).unwrap_or(text.len()) as u32;
+                } else {
+                    kind = TriviaKind::Skipped;
+                    let next_comment = self
+                        .comments
+                        .first()
+                        .map(|comment| comment.span.start)
+                        .filter(|&comment_start| comment_start > start)
+                        .unwrap_or(end);
+                    len = text
+                        .find(|c| is_white_space(c) || is_line_terminator(c))
+                        .map_or(end, |len| start + len as u32)
+                        .min(next_comment)
+                        - start;
+                }
+            }
+            let span = Span::sized(start, len);
+            self.trivia.push(Trivia { kind, span });
+            if kind == TriviaKind::LineTerminator && line_start.is_none() {
+                line_start = Some(self.trivia.len() - 1);
+            }
+            start = span.end;
+        }
+        line_start.unwrap_or(self.trivia.len())
+    }
+}
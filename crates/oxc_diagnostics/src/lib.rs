@@ -200,6 +200,11 @@ impl OxcCode {
     pub fn is_some(&self) -> bool {
         self.scope.is_some() || self.number.is_some()
     }
+
+    /// `true` if this is a TypeScript error code, e.g. `TS(1005)`.
+    pub fn is_typescript(&self) -> bool {
+        self.scope.as_deref() == Some("TS")
+    }
 }
 
 impl Display for OxcCode {
@@ -232,6 +237,58 @@ impl Display for OxcDiagnostic {
 
 impl std::error::Error for OxcDiagnostic {}
 
+/// An [`OxcDiagnostic`] with the source code its labels point into.
+///
+/// Unlike [`Error::with_source_code`], the diagnostic can be recovered from the [`Error`].
+struct WithSourceCode {
+    diagnostic: OxcDiagnostic,
+    source_code: Box<dyn SourceCode + Send + Sync>,
+}
+
+impl fmt::Debug for WithSourceCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.diagnostic, f)
+    }
+}
+
+impl Display for WithSourceCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.diagnostic, f)
+    }
+}
+
+impl std::error::Error for WithSourceCode {}
+
+impl Diagnostic for WithSourceCode {
+    fn help(&self) -> Option<Cow<'_, str>> {
+        self.diagnostic.help()
+    }
+
+    fn note(&self) -> Option<Cow<'_, str>> {
+        self.diagnostic.note()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn labels(&self) -> Labels {
+        self.diagnostic.labels()
+    }
+
+    fn code(&self) -> Option<Cow<'_, str>> {
+        self.diagnostic.code()
+    }
+
+    fn url(&self) -> Option<Cow<'_, str>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&*self.source_code)
+    }
+}
+
 impl Diagnostic for OxcDiagnostic {
     /// The secondary help message.
     fn help(&self) -> Option<Cow<'_, str>> {
@@ -457,7 +514,13 @@ impl OxcDiagnostic {
     ///
     /// You should use a [`NamedSource`] if you have a file name as well as the source code.
     pub fn with_source_code<T: SourceCode + Send + Sync + 'static>(self, code: T) -> Error {
-        Error::from(self).with_source_code(code)
+        Error::from(WithSourceCode { diagnostic: self, source_code: Box::new(code) })
+    }
+
+    /// Get the [`OxcDiagnostic`] an [`Error`] was created from with
+    /// [`with_source_code`](OxcDiagnostic::with_source_code), if any.
+    pub fn from_error(error: &Error) -> Option<&Self> {
+        error.downcast_ref::<WithSourceCode>().map(|error| &error.diagnostic)
     }
 
     /// Consumes the diagnostic and returns the inner owned data.
@@ -182,6 +182,7 @@ impl<'a> IsLiteralValue<'a, '_> for ObjectPropertyKind<'a> {
                 Expression::ObjectExpression(expr) => expr.is_literal_value(include_functions, ctx),
                 _ => false,
             },
+            Self::ErrorNode(_) => false,
         }
     }
 }
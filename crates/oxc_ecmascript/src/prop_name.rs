@@ -13,7 +13,7 @@ impl PropName for ObjectPropertyKind<'_> {
     fn prop_name(&self) -> Option<(&str, Span)> {
         match self {
             ObjectPropertyKind::ObjectProperty(prop) => prop.prop_name(),
-            ObjectPropertyKind::SpreadProperty(_) => None,
+            ObjectPropertyKind::SpreadProperty(_) | ObjectPropertyKind::ErrorNode(_) => None,
         }
     }
 }
@@ -309,6 +309,7 @@ impl<'a> MayHaveSideEffects<'a> for ObjectPropertyKind<'a> {
                                 ObjectPropertyKind::SpreadProperty(e) => {
                                     e.argument.may_have_side_effects(ctx)
                                 }
+                                ObjectPropertyKind::ErrorNode(_) => true,
                             })
                         }
                         Expression::StringLiteral(_) => false,
@@ -317,6 +318,7 @@ impl<'a> MayHaveSideEffects<'a> for ObjectPropertyKind<'a> {
                     }
                 }
             }
+            ObjectPropertyKind::ErrorNode(_) => true,
         }
     }
 }
@@ -387,6 +389,7 @@ impl<'a> MayHaveSideEffects<'a> for ClassElement<'a> {
                     || e.value.as_ref().is_some_and(|init| init.may_have_side_effects(ctx))
             }
             ClassElement::TSIndexSignature(_) => false,
+            ClassElement::ErrorNode(_) => true,
         }
     }
 }
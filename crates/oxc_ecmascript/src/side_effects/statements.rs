@@ -27,7 +27,8 @@ impl<'a> MayHaveSideEffects<'a> for Statement<'a> {
             | Statement::ForStatement(_)
             | Statement::ThrowStatement(_)
             | Statement::WithStatement(_)
-            | Statement::DebuggerStatement(_) => true,
+            | Statement::DebuggerStatement(_)
+            | Statement::ErrorNode(_) => true,
             #[expect(clippy::match_same_arms)]
             match_module_declaration!(Statement) => true,
         }
@@ -95,7 +96,8 @@ impl<'a> MayHaveSideEffects<'a> for Declaration<'a> {
             | Declaration::TSModuleDeclaration(_)
             | Declaration::TSGlobalDeclaration(_)
             | Declaration::TSInterfaceDeclaration(_)
-            | Declaration::TSTypeAliasDeclaration(_) => unreachable!(),
+            | Declaration::TSTypeAliasDeclaration(_)
+            | Declaration::FlowOpaqueTypeDeclaration(_) => unreachable!(),
         }
     }
 }
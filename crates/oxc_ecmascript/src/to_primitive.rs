@@ -106,5 +106,6 @@ pub fn maybe_object_with_to_primitive_related_properties_overridden(
             | Expression::TemplateLiteral(_) => false,
             _ => true,
         },
+        ObjectPropertyKind::ErrorNode(_) => true,
     })
 }
@@ -83,6 +83,7 @@ impl<'a, C: Context> Visit<'a> for Visitor<C> {
                     ObjectPropertyKind::SpreadProperty(spread) => {
                         self.visit_spread_element(spread);
                     }
+                    ObjectPropertyKind::ErrorNode(_) => {}
                 }
             }
         }
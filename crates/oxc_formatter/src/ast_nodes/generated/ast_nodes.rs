@@ -120,6 +120,9 @@ pub enum AstNodes<'a> {
     ExportAllDeclaration(&'a AstNode<'a, ExportAllDeclaration<'a>>),
     ExportSpecifier(&'a AstNode<'a, ExportSpecifier<'a>>),
     V8IntrinsicExpression(&'a AstNode<'a, V8IntrinsicExpression<'a>>),
+    PipelineExpression(&'a AstNode<'a, PipelineExpression<'a>>),
+    TopicReference(&'a AstNode<'a, TopicReference>),
+    ErrorNode(&'a AstNode<'a, ErrorNode>),
     BooleanLiteral(&'a AstNode<'a, BooleanLiteral>),
     NullLiteral(&'a AstNode<'a, NullLiteral>),
     NumericLiteral(&'a AstNode<'a, NumericLiteral<'a>>),
@@ -214,6 +217,8 @@ pub enum AstNodes<'a> {
     JSDocNullableType(&'a AstNode<'a, JSDocNullableType<'a>>),
     JSDocNonNullableType(&'a AstNode<'a, JSDocNonNullableType<'a>>),
     JSDocUnknownType(&'a AstNode<'a, JSDocUnknownType>),
+    FlowTypeCastExpression(&'a AstNode<'a, FlowTypeCastExpression<'a>>),
+    FlowOpaqueTypeDeclaration(&'a AstNode<'a, FlowOpaqueTypeDeclaration<'a>>),
 }
 impl AstNodes<'_> {
     /// Returns the span of this AST node.
@@ -319,6 +324,9 @@ impl AstNodes<'_> {
             Self::ExportAllDeclaration(n) => n.span(),
             Self::ExportSpecifier(n) => n.span(),
             Self::V8IntrinsicExpression(n) => n.span(),
+            Self::PipelineExpression(n) => n.span(),
+            Self::TopicReference(n) => n.span(),
+            Self::ErrorNode(n) => n.span(),
             Self::BooleanLiteral(n) => n.span(),
             Self::NullLiteral(n) => n.span(),
             Self::NumericLiteral(n) => n.span(),
@@ -413,6 +421,8 @@ impl AstNodes<'_> {
             Self::JSDocNullableType(n) => n.span(),
             Self::JSDocNonNullableType(n) => n.span(),
             Self::JSDocUnknownType(n) => n.span(),
+            Self::FlowTypeCastExpression(n) => n.span(),
+            Self::FlowOpaqueTypeDeclaration(n) => n.span(),
         }
     }
     /// Returns the parent of this AST node.
@@ -518,6 +528,9 @@ impl AstNodes<'_> {
             Self::ExportAllDeclaration(n) => n.parent(),
             Self::ExportSpecifier(n) => n.parent(),
             Self::V8IntrinsicExpression(n) => n.parent(),
+            Self::PipelineExpression(n) => n.parent(),
+            Self::TopicReference(n) => n.parent(),
+            Self::ErrorNode(n) => n.parent(),
             Self::BooleanLiteral(n) => n.parent(),
             Self::NullLiteral(n) => n.parent(),
             Self::NumericLiteral(n) => n.parent(),
@@ -612,6 +625,8 @@ impl AstNodes<'_> {
             Self::JSDocNullableType(n) => n.parent(),
             Self::JSDocNonNullableType(n) => n.parent(),
             Self::JSDocUnknownType(n) => n.parent(),
+            Self::FlowTypeCastExpression(n) => n.parent(),
+            Self::FlowOpaqueTypeDeclaration(n) => n.parent(),
         }
     }
     #[inline]
@@ -712,6 +727,9 @@ impl AstNodes<'_> {
             Self::ExportAllDeclaration(_) => "ExportAllDeclaration",
             Self::ExportSpecifier(_) => "ExportSpecifier",
             Self::V8IntrinsicExpression(_) => "V8IntrinsicExpression",
+            Self::PipelineExpression(_) => "PipelineExpression",
+            Self::TopicReference(_) => "TopicReference",
+            Self::ErrorNode(_) => "ErrorNode",
             Self::BooleanLiteral(_) => "BooleanLiteral",
             Self::NullLiteral(_) => "NullLiteral",
             Self::NumericLiteral(_) => "NumericLiteral",
@@ -806,6 +824,8 @@ impl AstNodes<'_> {
             Self::JSDocNullableType(_) => "JSDocNullableType",
             Self::JSDocNonNullableType(_) => "JSDocNonNullableType",
             Self::JSDocUnknownType(_) => "JSDocUnknownType",
+            Self::FlowTypeCastExpression(_) => "FlowTypeCastExpression",
+            Self::FlowOpaqueTypeDeclaration(_) => "FlowOpaqueTypeDeclaration",
         }
     }
 }
@@ -1202,6 +1222,30 @@ impl<'a> AstNode<'a, Expression<'a>> {
                     following_span_start: self.following_span_start,
                 }))
             }
+            Expression::FlowTypeCastExpression(s) => {
+                AstNodes::FlowTypeCastExpression(self.allocator.alloc(AstNode {
+                    inner: s.as_ref(),
+                    parent,
+                    allocator: self.allocator,
+                    following_span_start: self.following_span_start,
+                }))
+            }
+            Expression::PipelineExpression(s) => {
+                AstNodes::PipelineExpression(self.allocator.alloc(AstNode {
+                    inner: s.as_ref(),
+                    parent,
+                    allocator: self.allocator,
+                    following_span_start: self.following_span_start,
+                }))
+            }
+            Expression::TopicReference(s) => {
+                AstNodes::TopicReference(self.allocator.alloc(AstNode {
+                    inner: s.as_ref(),
+                    parent,
+                    allocator: self.allocator,
+                    following_span_start: self.following_span_start,
+                }))
+            }
             it @ match_member_expression!(Expression) => {
                 return self
                     .allocator
@@ -1446,6 +1490,14 @@ impl<'a> AstNode<'a, ObjectPropertyKind<'a>> {
                     following_span_start: self.following_span_start,
                 }))
             }
+            ObjectPropertyKind::ErrorNode(s) => {
+                AstNodes::ErrorNode(self.allocator.alloc(AstNode {
+                    inner: s.as_ref(),
+                    parent,
+                    allocator: self.allocator,
+                    following_span_start: self.following_span_start,
+                }))
+            }
         };
         self.allocator.alloc(node)
     }
@@ -3071,6 +3123,12 @@ impl<'a> AstNode<'a, Statement<'a>> {
                 allocator: self.allocator,
                 following_span_start: self.following_span_start,
             })),
+            Statement::ErrorNode(s) => AstNodes::ErrorNode(self.allocator.alloc(AstNode {
+                inner: s.as_ref(),
+                parent,
+                allocator: self.allocator,
+                following_span_start: self.following_span_start,
+            })),
             it @ match_declaration!(Statement) => {
                 return self
                     .allocator
@@ -3253,6 +3311,14 @@ impl<'a> AstNode<'a, Declaration<'a>> {
                     following_span_start: self.following_span_start,
                 }))
             }
+            Declaration::FlowOpaqueTypeDeclaration(s) => {
+                AstNodes::FlowOpaqueTypeDeclaration(self.allocator.alloc(AstNode {
+                    inner: s.as_ref(),
+                    parent,
+                    allocator: self.allocator,
+                    following_span_start: self.following_span_start,
+                }))
+            }
         };
         self.allocator.alloc(node)
     }
@@ -5192,6 +5258,12 @@ impl<'a> AstNode<'a, ClassElement<'a>> {
                     following_span_start: self.following_span_start,
                 }))
             }
+            ClassElement::ErrorNode(s) => AstNodes::ErrorNode(self.allocator.alloc(AstNode {
+                inner: s.as_ref(),
+                parent,
+                allocator: self.allocator,
+                following_span_start: self.following_span_start,
+            })),
         };
         self.allocator.alloc(node)
     }
@@ -6292,6 +6364,76 @@ impl<'a> AstNode<'a, V8IntrinsicExpression<'a>> {
     }
 }
 
+impl<'a> AstNode<'a, PipelineExpression<'a>> {
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.inner.node_id()
+    }
+
+    #[inline]
+    pub fn head(&self) -> &AstNode<'a, Expression<'a>> {
+        let following_span_start = self.inner.body.span().start;
+        self.allocator.alloc(AstNode {
+            inner: &self.inner.head,
+            allocator: self.allocator,
+            parent: AstNodes::PipelineExpression(transmute_self(self)),
+            following_span_start,
+        })
+    }
+
+    #[inline]
+    pub fn body(&self) -> &AstNode<'a, Expression<'a>> {
+        let following_span_start = self.following_span_start;
+        self.allocator.alloc(AstNode {
+            inner: &self.inner.body,
+            allocator: self.allocator,
+            parent: AstNodes::PipelineExpression(transmute_self(self)),
+            following_span_start,
+        })
+    }
+
+    pub fn format_leading_comments(&self, f: &mut JsFormatter<'_, 'a>) {
+        format_leading_comments(self.span()).fmt(f);
+    }
+
+    pub fn format_trailing_comments(&self, f: &mut JsFormatter<'_, 'a>) {
+        format_trailing_comments(self.parent.span(), self.inner.span(), self.following_span_start)
+            .fmt(f);
+    }
+}
+
+impl<'a> AstNode<'a, TopicReference> {
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.inner.node_id()
+    }
+
+    pub fn format_leading_comments(&self, f: &mut JsFormatter<'_, 'a>) {
+        format_leading_comments(self.span()).fmt(f);
+    }
+
+    pub fn format_trailing_comments(&self, f: &mut JsFormatter<'_, 'a>) {
+        format_trailing_comments(self.parent.span(), self.inner.span(), self.following_span_start)
+            .fmt(f);
+    }
+}
+
+impl<'a> AstNode<'a, ErrorNode> {
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.inner.node_id()
+    }
+
+    pub fn format_leading_comments(&self, f: &mut JsFormatter<'_, 'a>) {
+        format_leading_comments(self.span()).fmt(f);
+    }
+
+    pub fn format_trailing_comments(&self, f: &mut JsFormatter<'_, 'a>) {
+        format_trailing_comments(self.parent.span(), self.inner.span(), self.following_span_start)
+            .fmt(f);
+    }
+}
+
 impl<'a> AstNode<'a, BooleanLiteral> {
     #[inline]
     pub fn node_id(&self) -> NodeId {
@@ -10594,3 +10736,125 @@ impl<'a> AstNode<'a, JSDocUnknownType> {
             .fmt(f);
     }
 }
+
+impl<'a> AstNode<'a, FlowTypeCastExpression<'a>> {
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.inner.node_id()
+    }
+
+    #[inline]
+    pub fn expression(&self) -> &AstNode<'a, Expression<'a>> {
+        let following_span_start = self.inner.type_annotation.span().start;
+        self.allocator.alloc(AstNode {
+            inner: &self.inner.expression,
+            allocator: self.allocator,
+            parent: AstNodes::FlowTypeCastExpression(transmute_self(self)),
+            following_span_start,
+        })
+    }
+
+    #[inline]
+    pub fn type_annotation(&self) -> &AstNode<'a, TSTypeAnnotation<'a>> {
+        let following_span_start = self.following_span_start;
+        self.allocator.alloc(AstNode {
+            inner: self.inner.type_annotation.as_ref(),
+            allocator: self.allocator,
+            parent: AstNodes::FlowTypeCastExpression(transmute_self(self)),
+            following_span_start,
+        })
+    }
+
+    pub fn format_leading_comments(&self, f: &mut JsFormatter<'_, 'a>) {
+        format_leading_comments(self.span()).fmt(f);
+    }
+
+    pub fn format_trailing_comments(&self, f: &mut JsFormatter<'_, 'a>) {
+        format_trailing_comments(self.parent.span(), self.inner.span(), self.following_span_start)
+            .fmt(f);
+    }
+}
+
+impl<'a> AstNode<'a, FlowOpaqueTypeDeclaration<'a>> {
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.inner.node_id()
+    }
+
+    #[inline]
+    pub fn id(&self) -> &AstNode<'a, BindingIdentifier<'a>> {
+        let following_span_start = self
+            .inner
+            .type_parameters
+            .as_deref()
+            .map(|n| n.span().start)
+            .or_else(|| self.inner.supertype.as_ref().map(|n| n.span().start))
+            .or_else(|| self.inner.impltype.as_ref().map(|n| n.span().start))
+            .unwrap_or(0);
+        self.allocator.alloc(AstNode {
+            inner: &self.inner.id,
+            allocator: self.allocator,
+            parent: AstNodes::FlowOpaqueTypeDeclaration(transmute_self(self)),
+            following_span_start,
+        })
+    }
+
+    #[inline]
+    pub fn type_parameters(&self) -> Option<&AstNode<'a, TSTypeParameterDeclaration<'a>>> {
+        let following_span_start = self
+            .inner
+            .supertype
+            .as_ref()
+            .map(|n| n.span().start)
+            .or_else(|| self.inner.impltype.as_ref().map(|n| n.span().start))
+            .unwrap_or(0);
+        self.allocator
+            .alloc(self.inner.type_parameters.as_ref().map(|inner| AstNode {
+                inner: inner.as_ref(),
+                allocator: self.allocator,
+                parent: AstNodes::FlowOpaqueTypeDeclaration(transmute_self(self)),
+                following_span_start,
+            }))
+            .as_ref()
+    }
+
+    #[inline]
+    pub fn supertype(&self) -> Option<&AstNode<'a, TSType<'a>>> {
+        let following_span_start = self.inner.impltype.as_ref().map_or(0, |n| n.span().start);
+        self.allocator
+            .alloc(self.inner.supertype.as_ref().map(|inner| AstNode {
+                inner,
+                allocator: self.allocator,
+                parent: AstNodes::FlowOpaqueTypeDeclaration(transmute_self(self)),
+                following_span_start,
+            }))
+            .as_ref()
+    }
+
+    #[inline]
+    pub fn impltype(&self) -> Option<&AstNode<'a, TSType<'a>>> {
+        let following_span_start = 0;
+        self.allocator
+            .alloc(self.inner.impltype.as_ref().map(|inner| AstNode {
+                inner,
+                allocator: self.allocator,
+                parent: AstNodes::FlowOpaqueTypeDeclaration(transmute_self(self)),
+                following_span_start,
+            }))
+            .as_ref()
+    }
+
+    #[inline]
+    pub fn declare(&self) -> bool {
+        self.inner.declare
+    }
+
+    pub fn format_leading_comments(&self, f: &mut JsFormatter<'_, 'a>) {
+        format_leading_comments(self.span()).fmt(f);
+    }
+
+    pub fn format_trailing_comments(&self, f: &mut JsFormatter<'_, 'a>) {
+        format_trailing_comments(self.parent.span(), self.inner.span(), self.following_span_start)
+            .fmt(f);
+    }
+}
@@ -209,6 +209,8 @@ pub fn parse_for_format<'a>(
         parse_regular_expression: false, // the formatter doesn't need regexes parsed
         allow_return_outside_function: true, // accept all syntax the formatter may be handed
         allow_v8_intrinsics: true,
+        allow_pipeline_operator: false, // parentheses around pipe bodies are not preserved
+        error_recovery: false,
         preserve_parens: false, // MUST be false: the formatter panics otherwise
     };
     Parser::new(allocator, source_text, source_type).with_options(options).parse()
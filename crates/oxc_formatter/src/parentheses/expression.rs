@@ -838,6 +838,50 @@ impl NeedsParentheses<'_> for AstNode<'_, V8IntrinsicExpression<'_>> {
     }
 }
 
+impl NeedsParentheses<'_> for AstNode<'_, FlowTypeCastExpression<'_>> {
+    #[inline]
+    fn needs_parentheses(&self, _f: &JsFormatter<'_, '_>) -> bool {
+        // The parentheses are part of the type cast syntax and are always printed.
+        false
+    }
+}
+
+impl NeedsParentheses<'_> for AstNode<'_, PipelineExpression<'_>> {
+    fn needs_parentheses(&self, f: &JsFormatter<'_, '_>) -> bool {
+        if f.comments().is_type_cast_node(self) {
+            return false;
+        }
+
+        let parent = self.parent();
+        if matches!(
+            parent,
+            AstNodes::UnaryExpression(_)
+                | AstNodes::AwaitExpression(_)
+                | AstNodes::TSTypeAssertion(_)
+                | AstNodes::TSAsExpression(_)
+                | AstNodes::TSSatisfiesExpression(_)
+                | AstNodes::SpreadElement(_)
+                | AstNodes::JSXSpreadAttribute(_)
+                | AstNodes::LogicalExpression(_)
+                | AstNodes::BinaryExpression(_)
+        ) {
+            return true;
+        }
+        match parent {
+            AstNodes::ConditionalExpression(e) => e.test.span() == self.span(),
+            AstNodes::PipelineExpression(e) => e.head.span() == self.span(),
+            _ => update_or_lower_expression_needs_parens(self.span(), parent),
+        }
+    }
+}
+
+impl NeedsParentheses<'_> for AstNode<'_, TopicReference> {
+    #[inline]
+    fn needs_parentheses(&self, _f: &JsFormatter<'_, '_>) -> bool {
+        false
+    }
+}
+
 impl NeedsParentheses<'_> for AstNode<'_, JSXMemberExpression<'_>> {
     #[inline]
     fn needs_parentheses(&self, _f: &JsFormatter<'_, '_>) -> bool {
@@ -19,7 +19,9 @@ use super::FormatWrite;
 
 impl<'a> Format<'a, JsFormatContext<'a>> for ImportOrExportKind {
     fn fmt(&self, f: &mut JsFormatter<'_, 'a>) {
-        if self.is_type() {
+        if self.is_typeof() {
+            write!(f, ["typeof", space()]);
+        } else if self.is_type() {
             write!(f, ["type", space()]);
         }
     }
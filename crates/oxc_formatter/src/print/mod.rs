@@ -986,6 +986,43 @@ impl<'a> FormatWrite<'a> for AstNode<'a, V8IntrinsicExpression<'a>> {
     }
 }
 
+impl<'a> FormatWrite<'a> for AstNode<'a, PipelineExpression<'a>> {
+    fn write(&self, f: &mut JsFormatter<'_, 'a>) {
+        write!(f, [self.head(), space(), "|>", space()]);
+        let body = self.body();
+        if matches!(
+            body.as_ref(),
+            Expression::ArrowFunctionExpression(_)
+                | Expression::AssignmentExpression(_)
+                | Expression::ConditionalExpression(_)
+                | Expression::YieldExpression(_)
+        ) {
+            write!(f, ["(", body, ")"]);
+        } else {
+            write!(f, body);
+        }
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, TopicReference> {
+    fn write(&self, f: &mut JsFormatter<'_, 'a>) {
+        write!(f, "%");
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, FlowTypeCastExpression<'a>> {
+    fn write(&self, f: &mut JsFormatter<'_, 'a>) {
+        write!(f, ["(", self.expression(), self.type_annotation(), ")"]);
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, ErrorNode> {
+    fn write(&self, f: &mut JsFormatter<'_, 'a>) {
+        // Source text which failed to parse is printed verbatim
+        FormatSuppressedNode(self.span).fmt(f);
+    }
+}
+
 impl<'a> FormatWrite<'a> for AstNode<'a, BooleanLiteral> {
     fn write(&self, f: &mut JsFormatter<'_, 'a>) {
         write!(f, if self.value() { "true" } else { "false" });
@@ -1387,6 +1424,22 @@ impl<'a> FormatWrite<'a> for AstNode<'a, TSTypeAliasDeclaration<'a>> {
     }
 }
 
+impl<'a> FormatWrite<'a> for AstNode<'a, FlowOpaqueTypeDeclaration<'a>> {
+    fn write(&self, f: &mut JsFormatter<'_, 'a>) {
+        if self.declare() {
+            write!(f, ["declare", space()]);
+        }
+        write!(f, ["opaque", space(), "type", space(), self.id(), self.type_parameters()]);
+        if let Some(supertype) = self.supertype() {
+            write!(f, [":", space(), supertype]);
+        }
+        if let Some(impltype) = self.impltype() {
+            write!(f, [space(), "=", space(), impltype]);
+        }
+        write!(f, OptionalSemicolon);
+    }
+}
+
 impl<'a> FormatWrite<'a> for AstNode<'a, TSInterfaceDeclaration<'a>> {
     fn write(&self, f: &mut JsFormatter<'_, 'a>) {
         let id = self.id();
@@ -78,6 +78,9 @@ impl<'a> Format<'a, JsonFormatContext<'a>> for FmtJsonObject<'a, '_> {
                     ObjectPropertyKind::SpreadProperty(spread) => {
                         write!(f, FormatInvalidJson(spread.span));
                     }
+                    ObjectPropertyKind::ErrorNode(node) => {
+                        write!(f, FormatInvalidJson(node.span));
+                    }
                 }
             });
 
@@ -156,6 +156,9 @@ fn write_object<'a>(object: &ObjectExpression<'a>, f: &mut JsonFormatter<'_, 'a>
         ObjectPropertyKind::SpreadProperty(spread) => {
             write!(f, FormatInvalidJson(spread.span));
         }
+        ObjectPropertyKind::ErrorNode(node) => {
+            write!(f, FormatInvalidJson(node.span));
+        }
     });
     write!(f, "}");
 }
@@ -462,7 +462,7 @@ impl<'a> IsolatedDeclarations<'a> {
         let mut is_function_overloads = false;
         for element in &decl.body.body {
             match element {
-                ClassElement::StaticBlock(_) => {}
+                ClassElement::StaticBlock(_) | ClassElement::ErrorNode(_) => {}
                 ClassElement::MethodDefinition(method) => {
                     if self.has_internal_annotation(method.span) {
                         continue;
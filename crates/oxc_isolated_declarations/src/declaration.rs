@@ -235,6 +235,8 @@ impl<'a> IsolatedDeclarations<'a> {
                     None
                 }
             }
+            // Flow syntax has no `.d.ts` representation
+            Declaration::FlowOpaqueTypeDeclaration(_) => None,
         }
     }
 
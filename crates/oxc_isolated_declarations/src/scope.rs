@@ -195,6 +195,9 @@ impl<'a> Visit<'a> for ScopeTree<'a> {
             Declaration::TSInterfaceDeclaration(decl) => {
                 self.add_binding(decl.id.name.into(), KindFlags::Type);
             }
+            Declaration::FlowOpaqueTypeDeclaration(decl) => {
+                self.add_binding(decl.id.name.into(), KindFlags::Type);
+            }
             Declaration::TSEnumDeclaration(decl) => {
                 self.add_binding(decl.id.name.into(), KindFlags::All);
             }
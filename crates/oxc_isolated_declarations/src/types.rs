@@ -234,6 +234,7 @@ impl<'a> IsolatedDeclarations<'a> {
                     self.error(object_with_spread_assignments(spread.span));
                     None
                 }
+                ObjectPropertyKind::ErrorNode(_) => None,
             }),
             self,
         );
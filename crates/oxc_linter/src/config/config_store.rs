@@ -1,4 +1,5 @@
 use std::{
+    iter,
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -351,6 +352,21 @@ impl ConfigStore {
         Some(builtin_rules.len() + external_rules.len())
     }
 
+    /// Whether any enabled rule, in any config or override, is a type-aware rule which cannot run
+    /// in-process and needs tsgolint.
+    pub fn needs_tsgolint(&self) -> bool {
+        iter::once(&self.base).chain(self.nested_configs.values()).any(|config| {
+            let base_rules = config.base.rules.iter();
+            let override_rules = config
+                .overrides
+                .iter()
+                .flat_map(|override_config| &override_config.rules.builtin_rules);
+            base_rules.chain(override_rules).any(|(rule, severity)| {
+                severity.is_warn_deny() && rule.is_tsgolint_rule() && !rule.is_native_type_aware()
+            })
+        })
+    }
+
     pub fn rules(&self) -> &Arc<[(RuleEnum, AllowWarnDeny)]> {
         &self.base.base.rules
     }
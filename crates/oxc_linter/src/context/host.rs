@@ -1,6 +1,6 @@
 use std::{
     borrow::Cow,
-    cell::{Cell, RefCell},
+    cell::{Cell, OnceCell, RefCell},
     ffi::OsStr,
     path::Path,
     rc::Rc,
@@ -12,6 +12,7 @@ use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_parser::Token;
 use oxc_semantic::Semantic;
 use oxc_span::{SourceType, Span};
+use oxc_type_checker::{ExpressionTypes, TypeChecker};
 
 use crate::{
     AllowWarnDeny, FrameworkFlags,
@@ -46,6 +47,8 @@ pub struct ContextSubHost<'a> {
     pub(super) parser_tokens: ArenaBox<'a, [Token]>,
     /// The source text offset of the sub host
     pub(super) source_text_offset: u32,
+    /// Types of the expressions, inferred by the type checker when a rule first asks for one.
+    expression_types: OnceCell<ExpressionTypes>,
 }
 
 impl<'a> ContextSubHost<'a> {
@@ -79,6 +82,7 @@ impl<'a> ContextSubHost<'a> {
             disable_directives,
             framework_options: options.framework_options,
             parser_tokens: options.parser_tokens,
+            expression_types: OnceCell::new(),
         }
     }
 
@@ -103,6 +107,13 @@ impl<'a> ContextSubHost<'a> {
     pub fn framework_options(&self) -> FrameworkOptions {
         self.framework_options
     }
+
+    /// Types of the expressions of the program, see [`ExpressionTypes`].
+    pub fn expression_types(&self) -> &ExpressionTypes {
+        self.expression_types.get_or_init(|| {
+            TypeChecker::new().expression_types(self.semantic.nodes().program(), &self.semantic)
+        })
+    }
 }
 
 #[non_exhaustive]
@@ -212,6 +223,13 @@ impl<'a> ContextHost<'a> {
         &self.sub_hosts[self.current_sub_host_index.get()]
     }
 
+    /// Take the [`ExpressionTypes`] of the current script block, inferring them if no rule has.
+    pub(crate) fn take_expression_types(&mut self) -> ExpressionTypes {
+        let sub_host = self.current_sub_host_mut();
+        sub_host.expression_types();
+        sub_host.expression_types.take().unwrap()
+    }
+
     /// Get mutable reference to the current [`ContextSubHost`]
     fn current_sub_host_mut(&mut self) -> &mut ContextSubHost<'a> {
         &mut self.sub_hosts[self.current_sub_host_index.get()]
@@ -4,11 +4,12 @@ use std::{borrow::Cow, ffi::OsStr, ops::Deref, path::Path, rc::Rc};
 
 use javascript_globals::{GLOBALS, GLOBALS_BUILTIN, GLOBALS_ES2026};
 
-use oxc_ast::ast::IdentifierReference;
+use oxc_ast::ast::{AssignmentTarget, Expression, IdentifierReference};
 use oxc_cfg::ControlFlowGraph;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_semantic::Semantic;
 use oxc_span::Span;
+use oxc_type_checker::{TypeId, Types};
 
 #[cfg(debug_assertions)]
 use crate::rule::RuleFixMeta;
@@ -121,6 +122,37 @@ impl<'a> LintContext<'a> {
         unsafe { self.parent.semantic().cfg().unwrap_unchecked() }
     }
 
+    /// The type of `expr` inferred by `oxc_type_checker`, whose id is valid in
+    /// [`LintContext::types`].
+    ///
+    /// This is what type-aware rules which run in-process instead of in tsgolint use. Types which
+    /// the checker cannot represent yet, such as generics and classes, are `any`, so `any` means
+    /// that the type is not known.
+    pub fn expression_type(&self, expr: &Expression) -> TypeId {
+        self.parent.current_sub_host().expression_types().get(expr).unwrap_or(TypeId::ANY)
+    }
+
+    /// The type of the variable `target` assigns to, like [`LintContext::expression_type`].
+    pub fn assignment_target_type(&self, target: &AssignmentTarget) -> TypeId {
+        let expression_types = self.parent.current_sub_host().expression_types();
+        expression_types.assignment_target(target).unwrap_or(TypeId::ANY)
+    }
+
+    /// The types which [`LintContext::expression_type`] refers to.
+    pub fn types(&self) -> &Types {
+        self.parent.current_sub_host().expression_types().types()
+    }
+
+    /// Whether `expr` may be a promise, or another object with a `then` method.
+    pub fn is_promise_like(&self, expr: &Expression) -> bool {
+        self.types().is_promise_like(self.expression_type(expr))
+    }
+
+    /// Whether the type of `expr` is `any`, which includes types that are not known.
+    pub fn is_any(&self, expr: &Expression) -> bool {
+        self.expression_type(expr) == TypeId::ANY
+    }
+
     /// List of all disable directives in the file being linted.
     #[inline]
     pub fn disable_directives(&self) -> &DisableDirectives {
@@ -1,10 +1,13 @@
 use std::{fmt::Debug, sync::Arc};
 
+use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize, Serializer, ser::SerializeMap};
 
 use oxc_allocator::Allocator;
+use oxc_ast::AstKind;
 use oxc_ast_visit::utf8_to_utf16::Utf8ToUtf16;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
+use oxc_type_checker::{TypeId, Types};
 
 use crate::{
     config::{OxlintEnv, OxlintGlobals},
@@ -53,6 +56,9 @@ pub type ExternalLinterLintFileCb = Arc<
                 String,
                 // Globals JSON
                 String,
+                // Type information, serialized with `TypeInfo::to_json` if a JS rule asks for it.
+                // `None` unless type-aware linting is enabled.
+                Option<TypeInfo>,
                 // Workspace URI (e.g. `file:///path/to/workspace`).
                 // `None` in CLI mode (single workspace), `Some` in LSP mode.
                 Option<String>,
@@ -301,3 +307,136 @@ impl Serialize for EnabledEnvs<'_> {
         map.end()
     }
 }
+
+/// Type information of a file, to send to JS plugins when type-aware linting is enabled.
+///
+/// Collected while `Semantic` still exists, but the types are only formatted and serialized
+/// by [`TypeInfo::to_json`] when a JS rule first asks for them, as most files are linted
+/// without any rule doing so.
+pub struct TypeInfo {
+    types: Types,
+    expressions: Vec<(u32, u32, TypeId)>,
+    declarations: Vec<(u32, u32, Vec<SpanRange>)>,
+}
+
+/// `(start, end)` of a span.
+type SpanRange = (u32, u32);
+
+/// Struct for serializing [`TypeInfo`].
+///
+/// Serializes as
+/// `{ "types": [{ "text": "Promise<number>", "flags": 524288, "isPromiseLike": true }], "expressions": [[0, 3, 0]], "declarations": [[0, 1, [[6, 20]]]] }`.
+/// `expressions` maps the `[start, end]` span of each expression to its index in `types`.
+/// `declarations` maps the span of each identifier which refers to a symbol to the spans of the
+/// nodes declaring that symbol. Both are sorted by span, as JS side looks spans up with binary search.
+#[derive(Serialize)]
+struct TypeInfoJson<'t> {
+    types: Vec<TypeDescription>,
+    expressions: Vec<(u32, u32, u32)>,
+    declarations: &'t [(u32, u32, Vec<SpanRange>)],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TypeDescription {
+    text: String,
+    flags: u32,
+    is_promise_like: bool,
+}
+
+impl TypeInfo {
+    /// Collect type information of the current script block.
+    /// Takes its [`ExpressionTypes`], so no rule may ask for them afterwards.
+    ///
+    /// [`ExpressionTypes`]: oxc_type_checker::ExpressionTypes
+    pub fn new(ctx_host: &mut ContextHost<'_>) -> Self {
+        let expression_types = ctx_host.take_expression_types();
+
+        let semantic = ctx_host.semantic();
+        let nodes = semantic.nodes();
+        let scoping = semantic.scoping();
+
+        let mut expressions = expression_types
+            .iter()
+            .map(|(node_id, type_id)| {
+                let span = nodes.get_node(node_id).span();
+                (span.start, span.end, type_id)
+            })
+            .collect::<Vec<_>>();
+        expressions.sort_unstable();
+        expressions.dedup_by_key(|&mut (start, end, _)| (start, end));
+
+        let mut declarations = nodes
+            .iter()
+            .filter_map(|node| {
+                let (span, symbol_id) = match node.kind() {
+                    AstKind::IdentifierReference(ident) => {
+                        (ident.span, scoping.get_reference(ident.reference_id()).symbol_id()?)
+                    }
+                    AstKind::BindingIdentifier(ident) => (ident.span, ident.symbol_id.get()?),
+                    _ => return None,
+                };
+                let declarations = scoping
+                    .symbol_declarations(symbol_id)
+                    .map(|node_id| {
+                        let span = nodes.get_node(node_id).span();
+                        (span.start, span.end)
+                    })
+                    .collect();
+                Some((span.start, span.end, declarations))
+            })
+            .collect::<Vec<_>>();
+        declarations.sort_unstable_by_key(|&(start, end, _)| (start, end));
+
+        Self { types: expression_types.into_types(), expressions, declarations }
+    }
+
+    /// Format the types and serialize type information to JSON, to send to JS side.
+    ///
+    /// # Errors
+    /// If serialization fails.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        let types = &self.types;
+        let mut descriptions = vec![];
+        let mut indices = FxHashMap::default();
+        let expressions = self
+            .expressions
+            .iter()
+            .map(|&(start, end, type_id)| {
+                let index = *indices.entry(type_id).or_insert_with(|| {
+                    descriptions.push(TypeDescription {
+                        text: types.display(type_id),
+                        flags: types.typescript_flags(type_id),
+                        is_promise_like: types.is_promise_like(type_id),
+                    });
+                    descriptions.len() - 1
+                });
+                #[expect(clippy::cast_possible_truncation)]
+                (start, end, index as u32)
+            })
+            .collect();
+        serde_json::to_string(&TypeInfoJson {
+            types: descriptions,
+            expressions,
+            declarations: &self.declarations,
+        })
+    }
+
+    /// Convert all spans to UTF-16, to match the spans of the AST on JS side.
+    pub fn convert_spans(&mut self, span_converter: &Utf8ToUtf16) {
+        let Some(mut converter) = span_converter.converter() else { return };
+        let mut convert = |start: &mut u32, end: &mut u32| {
+            converter.convert_offset(start);
+            converter.convert_offset(end);
+        };
+        for (start, end, _) in &mut self.expressions {
+            convert(start, end);
+        }
+        for (start, end, declarations) in &mut self.declarations {
+            convert(start, end);
+            for (start, end) in declarations {
+                convert(start, end);
+            }
+        }
+    }
+}
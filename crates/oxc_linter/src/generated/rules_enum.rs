@@ -19324,6 +19324,858 @@ impl RuleEnum {
             Self::VueValidNextTick(_) => VueValidNextTick::IS_TSGOLINT_RULE,
         }
     }
+    pub fn is_native_type_aware(&self) -> bool {
+        match self {
+            Self::ImportConsistentTypeSpecifierStyle(rule) => rule.is_native_type_aware(),
+            Self::ImportDefault(rule) => rule.is_native_type_aware(),
+            Self::ImportExport(rule) => rule.is_native_type_aware(),
+            Self::ImportExportsLast(rule) => rule.is_native_type_aware(),
+            Self::ImportExtensions(rule) => rule.is_native_type_aware(),
+            Self::ImportFirst(rule) => rule.is_native_type_aware(),
+            Self::ImportGroupExports(rule) => rule.is_native_type_aware(),
+            Self::ImportMaxDependencies(rule) => rule.is_native_type_aware(),
+            Self::ImportNamed(rule) => rule.is_native_type_aware(),
+            Self::ImportNamespace(rule) => rule.is_native_type_aware(),
+            Self::ImportNewlineAfterImport(rule) => rule.is_native_type_aware(),
+            Self::ImportNoAbsolutePath(rule) => rule.is_native_type_aware(),
+            Self::ImportNoAmd(rule) => rule.is_native_type_aware(),
+            Self::ImportNoAnonymousDefaultExport(rule) => rule.is_native_type_aware(),
+            Self::ImportNoCommonjs(rule) => rule.is_native_type_aware(),
+            Self::ImportNoCycle(rule) => rule.is_native_type_aware(),
+            Self::ImportNoDefaultExport(rule) => rule.is_native_type_aware(),
+            Self::ImportNoDuplicates(rule) => rule.is_native_type_aware(),
+            Self::ImportNoDynamicRequire(rule) => rule.is_native_type_aware(),
+            Self::ImportNoEmptyNamedBlocks(rule) => rule.is_native_type_aware(),
+            Self::ImportNoMutableExports(rule) => rule.is_native_type_aware(),
+            Self::ImportNoNamedAsDefault(rule) => rule.is_native_type_aware(),
+            Self::ImportNoNamedAsDefaultMember(rule) => rule.is_native_type_aware(),
+            Self::ImportNoNamedDefault(rule) => rule.is_native_type_aware(),
+            Self::ImportNoNamedExport(rule) => rule.is_native_type_aware(),
+            Self::ImportNoNamespace(rule) => rule.is_native_type_aware(),
+            Self::ImportNoNodejsModules(rule) => rule.is_native_type_aware(),
+            Self::ImportNoRelativeParentImports(rule) => rule.is_native_type_aware(),
+            Self::ImportNoSelfImport(rule) => rule.is_native_type_aware(),
+            Self::ImportNoUnassignedImport(rule) => rule.is_native_type_aware(),
+            Self::ImportNoWebpackLoaderSyntax(rule) => rule.is_native_type_aware(),
+            Self::ImportPreferDefaultExport(rule) => rule.is_native_type_aware(),
+            Self::ImportUnambiguous(rule) => rule.is_native_type_aware(),
+            Self::EslintAccessorPairs(rule) => rule.is_native_type_aware(),
+            Self::EslintArrayCallbackReturn(rule) => rule.is_native_type_aware(),
+            Self::EslintArrowBodyStyle(rule) => rule.is_native_type_aware(),
+            Self::EslintBlockScopedVar(rule) => rule.is_native_type_aware(),
+            Self::EslintCapitalizedComments(rule) => rule.is_native_type_aware(),
+            Self::EslintClassMethodsUseThis(rule) => rule.is_native_type_aware(),
+            Self::EslintComplexity(rule) => rule.is_native_type_aware(),
+            Self::EslintConstructorSuper(rule) => rule.is_native_type_aware(),
+            Self::EslintCurly(rule) => rule.is_native_type_aware(),
+            Self::EslintDefaultCase(rule) => rule.is_native_type_aware(),
+            Self::EslintDefaultCaseLast(rule) => rule.is_native_type_aware(),
+            Self::EslintDefaultParamLast(rule) => rule.is_native_type_aware(),
+            Self::EslintEqeqeq(rule) => rule.is_native_type_aware(),
+            Self::EslintForDirection(rule) => rule.is_native_type_aware(),
+            Self::EslintFuncNameMatching(rule) => rule.is_native_type_aware(),
+            Self::EslintFuncNames(rule) => rule.is_native_type_aware(),
+            Self::EslintFuncStyle(rule) => rule.is_native_type_aware(),
+            Self::EslintGetterReturn(rule) => rule.is_native_type_aware(),
+            Self::EslintGroupedAccessorPairs(rule) => rule.is_native_type_aware(),
+            Self::EslintGuardForIn(rule) => rule.is_native_type_aware(),
+            Self::EslintIdLength(rule) => rule.is_native_type_aware(),
+            Self::EslintIdMatch(rule) => rule.is_native_type_aware(),
+            Self::EslintInitDeclarations(rule) => rule.is_native_type_aware(),
+            Self::EslintLogicalAssignmentOperators(rule) => rule.is_native_type_aware(),
+            Self::EslintMaxClassesPerFile(rule) => rule.is_native_type_aware(),
+            Self::EslintMaxDepth(rule) => rule.is_native_type_aware(),
+            Self::EslintMaxLines(rule) => rule.is_native_type_aware(),
+            Self::EslintMaxLinesPerFunction(rule) => rule.is_native_type_aware(),
+            Self::EslintMaxNestedCallbacks(rule) => rule.is_native_type_aware(),
+            Self::EslintMaxParams(rule) => rule.is_native_type_aware(),
+            Self::EslintMaxStatements(rule) => rule.is_native_type_aware(),
+            Self::EslintNewCap(rule) => rule.is_native_type_aware(),
+            Self::EslintNoAlert(rule) => rule.is_native_type_aware(),
+            Self::EslintNoArrayConstructor(rule) => rule.is_native_type_aware(),
+            Self::EslintNoAsyncPromiseExecutor(rule) => rule.is_native_type_aware(),
+            Self::EslintNoAwaitInLoop(rule) => rule.is_native_type_aware(),
+            Self::EslintNoBitwise(rule) => rule.is_native_type_aware(),
+            Self::EslintNoCaller(rule) => rule.is_native_type_aware(),
+            Self::EslintNoCaseDeclarations(rule) => rule.is_native_type_aware(),
+            Self::EslintNoClassAssign(rule) => rule.is_native_type_aware(),
+            Self::EslintNoCompareNegZero(rule) => rule.is_native_type_aware(),
+            Self::EslintNoCondAssign(rule) => rule.is_native_type_aware(),
+            Self::EslintNoConsole(rule) => rule.is_native_type_aware(),
+            Self::EslintNoConstAssign(rule) => rule.is_native_type_aware(),
+            Self::EslintNoConstantBinaryExpression(rule) => rule.is_native_type_aware(),
+            Self::EslintNoConstantCondition(rule) => rule.is_native_type_aware(),
+            Self::EslintNoConstructorReturn(rule) => rule.is_native_type_aware(),
+            Self::EslintNoContinue(rule) => rule.is_native_type_aware(),
+            Self::EslintNoControlRegex(rule) => rule.is_native_type_aware(),
+            Self::EslintNoDebugger(rule) => rule.is_native_type_aware(),
+            Self::EslintNoDeleteVar(rule) => rule.is_native_type_aware(),
+            Self::EslintNoDivRegex(rule) => rule.is_native_type_aware(),
+            Self::EslintNoDupeClassMembers(rule) => rule.is_native_type_aware(),
+            Self::EslintNoDupeElseIf(rule) => rule.is_native_type_aware(),
+            Self::EslintNoDupeKeys(rule) => rule.is_native_type_aware(),
+            Self::EslintNoDuplicateCase(rule) => rule.is_native_type_aware(),
+            Self::EslintNoDuplicateImports(rule) => rule.is_native_type_aware(),
+            Self::EslintNoElseReturn(rule) => rule.is_native_type_aware(),
+            Self::EslintNoEmpty(rule) => rule.is_native_type_aware(),
+            Self::EslintNoEmptyCharacterClass(rule) => rule.is_native_type_aware(),
+            Self::EslintNoEmptyFunction(rule) => rule.is_native_type_aware(),
+            Self::EslintNoEmptyPattern(rule) => rule.is_native_type_aware(),
+            Self::EslintNoEmptyStaticBlock(rule) => rule.is_native_type_aware(),
+            Self::EslintNoEqNull(rule) => rule.is_native_type_aware(),
+            Self::EslintNoEval(rule) => rule.is_native_type_aware(),
+            Self::EslintNoExAssign(rule) => rule.is_native_type_aware(),
+            Self::EslintNoExtendNative(rule) => rule.is_native_type_aware(),
+            Self::EslintNoExtraBind(rule) => rule.is_native_type_aware(),
+            Self::EslintNoExtraBooleanCast(rule) => rule.is_native_type_aware(),
+            Self::EslintNoExtraLabel(rule) => rule.is_native_type_aware(),
+            Self::EslintNoFallthrough(rule) => rule.is_native_type_aware(),
+            Self::EslintNoFuncAssign(rule) => rule.is_native_type_aware(),
+            Self::EslintNoGlobalAssign(rule) => rule.is_native_type_aware(),
+            Self::EslintNoImplicitCoercion(rule) => rule.is_native_type_aware(),
+            Self::EslintNoImplicitGlobals(rule) => rule.is_native_type_aware(),
+            Self::EslintNoImpliedEval(rule) => rule.is_native_type_aware(),
+            Self::EslintNoImportAssign(rule) => rule.is_native_type_aware(),
+            Self::EslintNoInlineComments(rule) => rule.is_native_type_aware(),
+            Self::EslintNoInnerDeclarations(rule) => rule.is_native_type_aware(),
+            Self::EslintNoInvalidRegexp(rule) => rule.is_native_type_aware(),
+            Self::EslintNoIrregularWhitespace(rule) => rule.is_native_type_aware(),
+            Self::EslintNoIterator(rule) => rule.is_native_type_aware(),
+            Self::EslintNoLabelVar(rule) => rule.is_native_type_aware(),
+            Self::EslintNoLabels(rule) => rule.is_native_type_aware(),
+            Self::EslintNoLoneBlocks(rule) => rule.is_native_type_aware(),
+            Self::EslintNoLonelyIf(rule) => rule.is_native_type_aware(),
+            Self::EslintNoLoopFunc(rule) => rule.is_native_type_aware(),
+            Self::EslintNoLossOfPrecision(rule) => rule.is_native_type_aware(),
+            Self::EslintNoMagicNumbers(rule) => rule.is_native_type_aware(),
+            Self::EslintNoMisleadingCharacterClass(rule) => rule.is_native_type_aware(),
+            Self::EslintNoMultiAssign(rule) => rule.is_native_type_aware(),
+            Self::EslintNoMultiStr(rule) => rule.is_native_type_aware(),
+            Self::EslintNoNegatedCondition(rule) => rule.is_native_type_aware(),
+            Self::EslintNoNestedTernary(rule) => rule.is_native_type_aware(),
+            Self::EslintNoNew(rule) => rule.is_native_type_aware(),
+            Self::EslintNoNewFunc(rule) => rule.is_native_type_aware(),
+            Self::EslintNoNewNativeNonconstructor(rule) => rule.is_native_type_aware(),
+            Self::EslintNoNewWrappers(rule) => rule.is_native_type_aware(),
+            Self::EslintNoNonoctalDecimalEscape(rule) => rule.is_native_type_aware(),
+            Self::EslintNoObjCalls(rule) => rule.is_native_type_aware(),
+            Self::EslintNoObjectConstructor(rule) => rule.is_native_type_aware(),
+            Self::EslintNoParamReassign(rule) => rule.is_native_type_aware(),
+            Self::EslintNoPlusplus(rule) => rule.is_native_type_aware(),
+            Self::EslintNoPromiseExecutorReturn(rule) => rule.is_native_type_aware(),
+            Self::EslintNoProto(rule) => rule.is_native_type_aware(),
+            Self::EslintNoPrototypeBuiltins(rule) => rule.is_native_type_aware(),
+            Self::EslintNoRedeclare(rule) => rule.is_native_type_aware(),
+            Self::EslintNoRegexSpaces(rule) => rule.is_native_type_aware(),
+            Self::EslintNoRestrictedExports(rule) => rule.is_native_type_aware(),
+            Self::EslintNoRestrictedGlobals(rule) => rule.is_native_type_aware(),
+            Self::EslintNoRestrictedImports(rule) => rule.is_native_type_aware(),
+            Self::EslintNoRestrictedProperties(rule) => rule.is_native_type_aware(),
+            Self::EslintNoReturnAssign(rule) => rule.is_native_type_aware(),
+            Self::EslintNoScriptUrl(rule) => rule.is_native_type_aware(),
+            Self::EslintNoSelfAssign(rule) => rule.is_native_type_aware(),
+            Self::EslintNoSelfCompare(rule) => rule.is_native_type_aware(),
+            Self::EslintNoSequences(rule) => rule.is_native_type_aware(),
+            Self::EslintNoSetterReturn(rule) => rule.is_native_type_aware(),
+            Self::EslintNoShadow(rule) => rule.is_native_type_aware(),
+            Self::EslintNoShadowRestrictedNames(rule) => rule.is_native_type_aware(),
+            Self::EslintNoSparseArrays(rule) => rule.is_native_type_aware(),
+            Self::EslintNoTemplateCurlyInString(rule) => rule.is_native_type_aware(),
+            Self::EslintNoTernary(rule) => rule.is_native_type_aware(),
+            Self::EslintNoThisBeforeSuper(rule) => rule.is_native_type_aware(),
+            Self::EslintNoThrowLiteral(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUnassignedVars(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUndef(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUndefined(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUnderscoreDangle(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUnexpectedMultiline(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUnmodifiedLoopCondition(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUnneededTernary(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUnreachable(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUnsafeFinally(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUnsafeNegation(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUnsafeOptionalChaining(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUnusedExpressions(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUnusedLabels(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUnusedPrivateClassMembers(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUnusedVars(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUseBeforeDefine(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUselessAssignment(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUselessBackreference(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUselessCall(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUselessCatch(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUselessComputedKey(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUselessConcat(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUselessConstructor(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUselessEscape(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUselessRename(rule) => rule.is_native_type_aware(),
+            Self::EslintNoUselessReturn(rule) => rule.is_native_type_aware(),
+            Self::EslintNoVar(rule) => rule.is_native_type_aware(),
+            Self::EslintNoVoid(rule) => rule.is_native_type_aware(),
+            Self::EslintNoWarningComments(rule) => rule.is_native_type_aware(),
+            Self::EslintNoWith(rule) => rule.is_native_type_aware(),
+            Self::EslintObjectShorthand(rule) => rule.is_native_type_aware(),
+            Self::EslintOperatorAssignment(rule) => rule.is_native_type_aware(),
+            Self::EslintPreferArrowCallback(rule) => rule.is_native_type_aware(),
+            Self::EslintPreferConst(rule) => rule.is_native_type_aware(),
+            Self::EslintPreferDestructuring(rule) => rule.is_native_type_aware(),
+            Self::EslintPreferExponentiationOperator(rule) => rule.is_native_type_aware(),
+            Self::EslintPreferNamedCaptureGroup(rule) => rule.is_native_type_aware(),
+            Self::EslintPreferNumericLiterals(rule) => rule.is_native_type_aware(),
+            Self::EslintPreferObjectHasOwn(rule) => rule.is_native_type_aware(),
+            Self::EslintPreferObjectSpread(rule) => rule.is_native_type_aware(),
+            Self::EslintPreferPromiseRejectErrors(rule) => rule.is_native_type_aware(),
+            Self::EslintPreferRegexLiterals(rule) => rule.is_native_type_aware(),
+            Self::EslintPreferRestParams(rule) => rule.is_native_type_aware(),
+            Self::EslintPreferSpread(rule) => rule.is_native_type_aware(),
+            Self::EslintPreferTemplate(rule) => rule.is_native_type_aware(),
+            Self::EslintPreserveCaughtError(rule) => rule.is_native_type_aware(),
+            Self::EslintRadix(rule) => rule.is_native_type_aware(),
+            Self::EslintRequireAwait(rule) => rule.is_native_type_aware(),
+            Self::EslintRequireUnicodeRegexp(rule) => rule.is_native_type_aware(),
+            Self::EslintRequireYield(rule) => rule.is_native_type_aware(),
+            Self::EslintSortImports(rule) => rule.is_native_type_aware(),
+            Self::EslintSortKeys(rule) => rule.is_native_type_aware(),
+            Self::EslintSortVars(rule) => rule.is_native_type_aware(),
+            Self::EslintSymbolDescription(rule) => rule.is_native_type_aware(),
+            Self::EslintUnicodeBom(rule) => rule.is_native_type_aware(),
+            Self::EslintUseIsnan(rule) => rule.is_native_type_aware(),
+            Self::EslintValidTypeof(rule) => rule.is_native_type_aware(),
+            Self::EslintVarsOnTop(rule) => rule.is_native_type_aware(),
+            Self::EslintYoda(rule) => rule.is_native_type_aware(),
+            Self::TypescriptAdjacentOverloadSignatures(rule) => rule.is_native_type_aware(),
+            Self::TypescriptArrayType(rule) => rule.is_native_type_aware(),
+            Self::TypescriptAwaitThenable(rule) => rule.is_native_type_aware(),
+            Self::TypescriptBanTsComment(rule) => rule.is_native_type_aware(),
+            Self::TypescriptBanTslintComment(rule) => rule.is_native_type_aware(),
+            Self::TypescriptBanTypes(rule) => rule.is_native_type_aware(),
+            Self::TypescriptClassLiteralPropertyStyle(rule) => rule.is_native_type_aware(),
+            Self::TypescriptConsistentGenericConstructors(rule) => rule.is_native_type_aware(),
+            Self::TypescriptConsistentIndexedObjectStyle(rule) => rule.is_native_type_aware(),
+            Self::TypescriptConsistentReturn(rule) => rule.is_native_type_aware(),
+            Self::TypescriptConsistentTypeAssertions(rule) => rule.is_native_type_aware(),
+            Self::TypescriptConsistentTypeDefinitions(rule) => rule.is_native_type_aware(),
+            Self::TypescriptConsistentTypeExports(rule) => rule.is_native_type_aware(),
+            Self::TypescriptConsistentTypeImports(rule) => rule.is_native_type_aware(),
+            Self::TypescriptDotNotation(rule) => rule.is_native_type_aware(),
+            Self::TypescriptExplicitFunctionReturnType(rule) => rule.is_native_type_aware(),
+            Self::TypescriptExplicitMemberAccessibility(rule) => rule.is_native_type_aware(),
+            Self::TypescriptExplicitModuleBoundaryTypes(rule) => rule.is_native_type_aware(),
+            Self::TypescriptMethodSignatureStyle(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoArrayDelete(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoBaseToString(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoConfusingNonNullAssertion(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoConfusingVoidExpression(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoDeprecated(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoDuplicateEnumValues(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoDuplicateTypeConstituents(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoDynamicDelete(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoEmptyInterface(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoEmptyObjectType(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoExplicitAny(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoExtraNonNullAssertion(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoExtraneousClass(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoFloatingPromises(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoForInArray(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoImpliedEval(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoImportTypeSideEffects(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoInferrableTypes(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoInvalidVoidType(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoMeaninglessVoidOperator(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoMisusedNew(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoMisusedPromises(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoMisusedSpread(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoMixedEnums(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoNamespace(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoNonNullAssertedNullishCoalescing(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoNonNullAssertedOptionalChain(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoNonNullAssertion(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoRedundantTypeConstituents(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoRequireImports(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoRestrictedTypes(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoThisAlias(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnnecessaryBooleanLiteralCompare(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnnecessaryCondition(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnnecessaryParameterPropertyAssignment(rule) => {
+                rule.is_native_type_aware()
+            }
+            Self::TypescriptNoUnnecessaryQualifier(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnnecessaryTemplateExpression(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnnecessaryTypeArguments(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnnecessaryTypeAssertion(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnnecessaryTypeConstraint(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnnecessaryTypeConversion(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnnecessaryTypeParameters(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnsafeArgument(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnsafeAssignment(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnsafeCall(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnsafeDeclarationMerging(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnsafeEnumComparison(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnsafeFunctionType(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnsafeMemberAccess(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnsafeReturn(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnsafeTypeAssertion(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUnsafeUnaryMinus(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUselessDefaultAssignment(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoUselessEmptyExport(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoVarRequires(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNoWrapperObjectTypes(rule) => rule.is_native_type_aware(),
+            Self::TypescriptNonNullableTypeAssertionStyle(rule) => rule.is_native_type_aware(),
+            Self::TypescriptOnlyThrowError(rule) => rule.is_native_type_aware(),
+            Self::TypescriptParameterProperties(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferAsConst(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferEnumInitializers(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferFind(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferForOf(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferFunctionType(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferIncludes(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferLiteralEnumMember(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferNamespaceKeyword(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferNullishCoalescing(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferOptionalChain(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferPromiseRejectErrors(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferReadonly(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferReadonlyParameterTypes(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferReduceTypeParameter(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferRegexpExec(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferReturnThisType(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferStringStartsEndsWith(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPreferTsExpectError(rule) => rule.is_native_type_aware(),
+            Self::TypescriptPromiseFunctionAsync(rule) => rule.is_native_type_aware(),
+            Self::TypescriptRelatedGetterSetterPairs(rule) => rule.is_native_type_aware(),
+            Self::TypescriptRequireArraySortCompare(rule) => rule.is_native_type_aware(),
+            Self::TypescriptRequireAwait(rule) => rule.is_native_type_aware(),
+            Self::TypescriptRestrictPlusOperands(rule) => rule.is_native_type_aware(),
+            Self::TypescriptRestrictTemplateExpressions(rule) => rule.is_native_type_aware(),
+            Self::TypescriptReturnAwait(rule) => rule.is_native_type_aware(),
+            Self::TypescriptStrictBooleanExpressions(rule) => rule.is_native_type_aware(),
+            Self::TypescriptStrictVoidReturn(rule) => rule.is_native_type_aware(),
+            Self::TypescriptSwitchExhaustivenessCheck(rule) => rule.is_native_type_aware(),
+            Self::TypescriptTripleSlashReference(rule) => rule.is_native_type_aware(),
+            Self::TypescriptUnboundMethod(rule) => rule.is_native_type_aware(),
+            Self::TypescriptUnifiedSignatures(rule) => rule.is_native_type_aware(),
+            Self::TypescriptUseUnknownInCatchCallbackVariable(rule) => rule.is_native_type_aware(),
+            Self::JestConsistentTestIt(rule) => rule.is_native_type_aware(),
+            Self::JestExpectExpect(rule) => rule.is_native_type_aware(),
+            Self::JestMaxExpects(rule) => rule.is_native_type_aware(),
+            Self::JestMaxNestedDescribe(rule) => rule.is_native_type_aware(),
+            Self::JestNoAliasMethods(rule) => rule.is_native_type_aware(),
+            Self::JestNoCommentedOutTests(rule) => rule.is_native_type_aware(),
+            Self::JestNoConditionalExpect(rule) => rule.is_native_type_aware(),
+            Self::JestNoConditionalInTest(rule) => rule.is_native_type_aware(),
+            Self::JestNoConfusingSetTimeout(rule) => rule.is_native_type_aware(),
+            Self::JestNoDeprecatedFunctions(rule) => rule.is_native_type_aware(),
+            Self::JestNoDisabledTests(rule) => rule.is_native_type_aware(),
+            Self::JestNoDoneCallback(rule) => rule.is_native_type_aware(),
+            Self::JestNoDuplicateHooks(rule) => rule.is_native_type_aware(),
+            Self::JestNoExport(rule) => rule.is_native_type_aware(),
+            Self::JestNoFocusedTests(rule) => rule.is_native_type_aware(),
+            Self::JestNoHooks(rule) => rule.is_native_type_aware(),
+            Self::JestNoIdenticalTitle(rule) => rule.is_native_type_aware(),
+            Self::JestNoInterpolationInSnapshots(rule) => rule.is_native_type_aware(),
+            Self::JestNoJasmineGlobals(rule) => rule.is_native_type_aware(),
+            Self::JestNoLargeSnapshots(rule) => rule.is_native_type_aware(),
+            Self::JestNoMocksImport(rule) => rule.is_native_type_aware(),
+            Self::JestNoRestrictedJestMethods(rule) => rule.is_native_type_aware(),
+            Self::JestNoRestrictedMatchers(rule) => rule.is_native_type_aware(),
+            Self::JestNoStandaloneExpect(rule) => rule.is_native_type_aware(),
+            Self::JestNoTestPrefixes(rule) => rule.is_native_type_aware(),
+            Self::JestNoTestReturnStatement(rule) => rule.is_native_type_aware(),
+            Self::JestNoUnneededAsyncExpectFunction(rule) => rule.is_native_type_aware(),
+            Self::JestNoUntypedMockFactory(rule) => rule.is_native_type_aware(),
+            Self::JestPaddingAroundAfterAllBlocks(rule) => rule.is_native_type_aware(),
+            Self::JestPaddingAroundTestBlocks(rule) => rule.is_native_type_aware(),
+            Self::JestPreferCalledWith(rule) => rule.is_native_type_aware(),
+            Self::JestPreferComparisonMatcher(rule) => rule.is_native_type_aware(),
+            Self::JestPreferEach(rule) => rule.is_native_type_aware(),
+            Self::JestPreferEndingWithAnExpect(rule) => rule.is_native_type_aware(),
+            Self::JestPreferEqualityMatcher(rule) => rule.is_native_type_aware(),
+            Self::JestPreferExpectAssertions(rule) => rule.is_native_type_aware(),
+            Self::JestPreferExpectResolves(rule) => rule.is_native_type_aware(),
+            Self::JestPreferHooksInOrder(rule) => rule.is_native_type_aware(),
+            Self::JestPreferHooksOnTop(rule) => rule.is_native_type_aware(),
+            Self::JestPreferImportingJestGlobals(rule) => rule.is_native_type_aware(),
+            Self::JestPreferJestMocked(rule) => rule.is_native_type_aware(),
+            Self::JestPreferLowercaseTitle(rule) => rule.is_native_type_aware(),
+            Self::JestPreferMockPromiseShorthand(rule) => rule.is_native_type_aware(),
+            Self::JestPreferMockReturnShorthand(rule) => rule.is_native_type_aware(),
+            Self::JestPreferSnapshotHint(rule) => rule.is_native_type_aware(),
+            Self::JestPreferSpyOn(rule) => rule.is_native_type_aware(),
+            Self::JestPreferStrictEqual(rule) => rule.is_native_type_aware(),
+            Self::JestPreferToBe(rule) => rule.is_native_type_aware(),
+            Self::JestPreferToContain(rule) => rule.is_native_type_aware(),
+            Self::JestPreferToHaveBeenCalled(rule) => rule.is_native_type_aware(),
+            Self::JestPreferToHaveBeenCalledTimes(rule) => rule.is_native_type_aware(),
+            Self::JestPreferToHaveLength(rule) => rule.is_native_type_aware(),
+            Self::JestPreferTodo(rule) => rule.is_native_type_aware(),
+            Self::JestRequireHook(rule) => rule.is_native_type_aware(),
+            Self::JestRequireToThrowMessage(rule) => rule.is_native_type_aware(),
+            Self::JestRequireTopLevelDescribe(rule) => rule.is_native_type_aware(),
+            Self::JestValidDescribeCallback(rule) => rule.is_native_type_aware(),
+            Self::JestValidExpect(rule) => rule.is_native_type_aware(),
+            Self::JestValidExpectInPromise(rule) => rule.is_native_type_aware(),
+            Self::JestValidTitle(rule) => rule.is_native_type_aware(),
+            Self::ReactButtonHasType(rule) => rule.is_native_type_aware(),
+            Self::ReactCheckedRequiresOnchangeOrReadonly(rule) => rule.is_native_type_aware(),
+            Self::ReactDisplayName(rule) => rule.is_native_type_aware(),
+            Self::ReactExhaustiveDeps(rule) => rule.is_native_type_aware(),
+            Self::ReactForbidComponentProps(rule) => rule.is_native_type_aware(),
+            Self::ReactForbidDomProps(rule) => rule.is_native_type_aware(),
+            Self::ReactForbidElements(rule) => rule.is_native_type_aware(),
+            Self::ReactForwardRefUsesRef(rule) => rule.is_native_type_aware(),
+            Self::ReactHookUseState(rule) => rule.is_native_type_aware(),
+            Self::ReactIframeMissingSandbox(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxBooleanValue(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxCurlyBracePresence(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxFilenameExtension(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxFragments(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxHandlerNames(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxKey(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxMaxDepth(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxNoCommentTextnodes(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxNoConstructedContextValues(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxNoDuplicateProps(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxNoLiterals(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxNoScriptUrl(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxNoTargetBlank(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxNoUndef(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxNoUselessFragment(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxPascalCase(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxPropsNoSpreadMulti(rule) => rule.is_native_type_aware(),
+            Self::ReactJsxPropsNoSpreading(rule) => rule.is_native_type_aware(),
+            Self::ReactNoArrayIndexKey(rule) => rule.is_native_type_aware(),
+            Self::ReactNoChildrenProp(rule) => rule.is_native_type_aware(),
+            Self::ReactNoCloneElement(rule) => rule.is_native_type_aware(),
+            Self::ReactNoDanger(rule) => rule.is_native_type_aware(),
+            Self::ReactNoDangerWithChildren(rule) => rule.is_native_type_aware(),
+            Self::ReactNoDidMountSetState(rule) => rule.is_native_type_aware(),
+            Self::ReactNoDidUpdateSetState(rule) => rule.is_native_type_aware(),
+            Self::ReactNoDirectMutationState(rule) => rule.is_native_type_aware(),
+            Self::ReactNoFindDomNode(rule) => rule.is_native_type_aware(),
+            Self::ReactNoIsMounted(rule) => rule.is_native_type_aware(),
+            Self::ReactNoMultiComp(rule) => rule.is_native_type_aware(),
+            Self::ReactNoNamespace(rule) => rule.is_native_type_aware(),
+            Self::ReactNoObjectTypeAsDefaultProp(rule) => rule.is_native_type_aware(),
+            Self::ReactNoReactChildren(rule) => rule.is_native_type_aware(),
+            Self::ReactNoRedundantShouldComponentUpdate(rule) => rule.is_native_type_aware(),
+            Self::ReactNoRenderReturnValue(rule) => rule.is_native_type_aware(),
+            Self::ReactNoSetState(rule) => rule.is_native_type_aware(),
+            Self::ReactNoStringRefs(rule) => rule.is_native_type_aware(),
+            Self::ReactNoThisInSfc(rule) => rule.is_native_type_aware(),
+            Self::ReactNoUnescapedEntities(rule) => rule.is_native_type_aware(),
+            Self::ReactNoUnknownProperty(rule) => rule.is_native_type_aware(),
+            Self::ReactNoUnsafe(rule) => rule.is_native_type_aware(),
+            Self::ReactNoUnstableNestedComponents(rule) => rule.is_native_type_aware(),
+            Self::ReactNoWillUpdateSetState(rule) => rule.is_native_type_aware(),
+            Self::ReactOnlyExportComponents(rule) => rule.is_native_type_aware(),
+            Self::ReactPreferEs6Class(rule) => rule.is_native_type_aware(),
+            Self::ReactPreferFunctionComponent(rule) => rule.is_native_type_aware(),
+            Self::ReactReactCompiler(rule) => rule.is_native_type_aware(),
+            Self::ReactReactInJsxScope(rule) => rule.is_native_type_aware(),
+            Self::ReactRequireRenderReturn(rule) => rule.is_native_type_aware(),
+            Self::ReactRulesOfHooks(rule) => rule.is_native_type_aware(),
+            Self::ReactSelfClosingComp(rule) => rule.is_native_type_aware(),
+            Self::ReactStateInConstructor(rule) => rule.is_native_type_aware(),
+            Self::ReactStylePropObject(rule) => rule.is_native_type_aware(),
+            Self::ReactVoidDomElementsNoChildren(rule) => rule.is_native_type_aware(),
+            Self::ReactPerfJsxNoJsxAsProp(rule) => rule.is_native_type_aware(),
+            Self::ReactPerfJsxNoNewArrayAsProp(rule) => rule.is_native_type_aware(),
+            Self::ReactPerfJsxNoNewFunctionAsProp(rule) => rule.is_native_type_aware(),
+            Self::ReactPerfJsxNoNewObjectAsProp(rule) => rule.is_native_type_aware(),
+            Self::UnicornCatchErrorName(rule) => rule.is_native_type_aware(),
+            Self::UnicornConsistentAssert(rule) => rule.is_native_type_aware(),
+            Self::UnicornConsistentDateClone(rule) => rule.is_native_type_aware(),
+            Self::UnicornConsistentEmptyArraySpread(rule) => rule.is_native_type_aware(),
+            Self::UnicornConsistentExistenceIndexCheck(rule) => rule.is_native_type_aware(),
+            Self::UnicornConsistentFunctionScoping(rule) => rule.is_native_type_aware(),
+            Self::UnicornConsistentTemplateLiteralEscape(rule) => rule.is_native_type_aware(),
+            Self::UnicornCustomErrorDefinition(rule) => rule.is_native_type_aware(),
+            Self::UnicornEmptyBraceSpaces(rule) => rule.is_native_type_aware(),
+            Self::UnicornErrorMessage(rule) => rule.is_native_type_aware(),
+            Self::UnicornEscapeCase(rule) => rule.is_native_type_aware(),
+            Self::UnicornExplicitLengthCheck(rule) => rule.is_native_type_aware(),
+            Self::UnicornFilenameCase(rule) => rule.is_native_type_aware(),
+            Self::UnicornImportStyle(rule) => rule.is_native_type_aware(),
+            Self::UnicornMaxNestedCalls(rule) => rule.is_native_type_aware(),
+            Self::UnicornNewForBuiltins(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoAbusiveEslintDisable(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoAccessorRecursion(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoAnonymousDefaultExport(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoArrayCallbackReference(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoArrayFillWithReferenceType(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoArrayForEach(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoArrayMethodThisArgument(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoArrayReduce(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoArrayReverse(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoArraySort(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoAwaitExpressionMember(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoAwaitInPromiseMethods(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoConsoleSpaces(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoDocumentCookie(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoEmptyFile(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoHexEscape(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoImmediateMutation(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoInstanceofArray(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoInstanceofBuiltins(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoInvalidFetchOptions(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoInvalidRemoveEventListener(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoLengthAsSliceEnd(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoLonelyIf(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoMagicArrayFlatDepth(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoNegatedCondition(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoNegationInEqualityCheck(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoNestedTernary(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoNewArray(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoNewBuffer(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoNull(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoObjectAsDefaultParameter(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoProcessExit(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoSinglePromiseInPromiseMethods(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoStaticOnlyClass(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoThenable(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoThisAssignment(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoTypeofUndefined(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoUnnecessaryArrayFlatDepth(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoUnnecessaryArraySpliceCount(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoUnnecessaryAwait(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoUnnecessarySliceEnd(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoUnreadableArrayDestructuring(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoUnreadableIife(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoUselessCollectionArgument(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoUselessErrorCaptureStackTrace(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoUselessFallbackInSpread(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoUselessIteratorToArray(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoUselessLengthCheck(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoUselessPromiseResolveReject(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoUselessSpread(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoUselessSwitchCase(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoUselessUndefined(rule) => rule.is_native_type_aware(),
+            Self::UnicornNoZeroFractions(rule) => rule.is_native_type_aware(),
+            Self::UnicornNumberLiteralCase(rule) => rule.is_native_type_aware(),
+            Self::UnicornNumericSeparatorsStyle(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferAddEventListener(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferArrayFind(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferArrayFlat(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferArrayFlatMap(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferArrayIndexOf(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferArraySome(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferAt(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferBigintLiterals(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferBlobReadingMethods(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferClassFields(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferClasslistToggle(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferCodePoint(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferDateNow(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferDefaultParameters(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferDomNodeAppend(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferDomNodeDataset(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferDomNodeRemove(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferDomNodeTextContent(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferEventTarget(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferExportFrom(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferGlobalThis(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferImportMetaProperties(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferIncludes(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferKeyboardEventKey(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferLogicalOperatorOverTernary(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferMathMinMax(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferMathTrunc(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferModernDomApis(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferModernMathApis(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferModule(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferNativeCoercionFunctions(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferNegativeIndex(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferNodeProtocol(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferNumberCoercion(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferNumberProperties(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferObjectFromEntries(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferOptionalCatchBinding(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferPrototypeMethods(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferQuerySelector(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferReflectApply(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferRegexpTest(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferResponseStaticJson(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferSetHas(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferSetSize(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferSingleCall(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferSpread(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferStringRaw(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferStringReplaceAll(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferStringSlice(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferStringStartsEndsWith(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferStringTrimStartEnd(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferStructuredClone(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferTernary(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferTopLevelAwait(rule) => rule.is_native_type_aware(),
+            Self::UnicornPreferTypeError(rule) => rule.is_native_type_aware(),
+            Self::UnicornRelativeUrlStyle(rule) => rule.is_native_type_aware(),
+            Self::UnicornRequireArrayJoinSeparator(rule) => rule.is_native_type_aware(),
+            Self::UnicornRequireModuleAttributes(rule) => rule.is_native_type_aware(),
+            Self::UnicornRequireModuleSpecifiers(rule) => rule.is_native_type_aware(),
+            Self::UnicornRequireNumberToFixedDigitsArgument(rule) => rule.is_native_type_aware(),
+            Self::UnicornRequirePostMessageTargetOrigin(rule) => rule.is_native_type_aware(),
+            Self::UnicornSwitchCaseBraces(rule) => rule.is_native_type_aware(),
+            Self::UnicornSwitchCaseBreakPosition(rule) => rule.is_native_type_aware(),
+            Self::UnicornTextEncodingIdentifierCase(rule) => rule.is_native_type_aware(),
+            Self::UnicornThrowNewError(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YAltText(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YAnchorAmbiguousText(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YAnchorHasContent(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YAnchorIsValid(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YAriaActivedescendantHasTabindex(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YAriaProps(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YAriaProptypes(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YAriaRole(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YAriaUnsupportedElements(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YAutocompleteValid(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YClickEventsHaveKeyEvents(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YControlHasAssociatedLabel(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YHeadingHasContent(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YHtmlHasLang(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YIframeHasTitle(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YImgRedundantAlt(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YInteractiveSupportsFocus(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YLabelHasAssociatedControl(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YLang(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YMediaHasCaption(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YMouseEventsHaveKeyEvents(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YNoAccessKey(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YNoAriaHiddenOnFocusable(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YNoAutofocus(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YNoDistractingElements(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YNoInteractiveElementToNoninteractiveRole(rule) => {
+                rule.is_native_type_aware()
+            }
+            Self::JsxA11YNoNoninteractiveElementInteractions(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YNoNoninteractiveElementToInteractiveRole(rule) => {
+                rule.is_native_type_aware()
+            }
+            Self::JsxA11YNoNoninteractiveTabindex(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YNoRedundantRoles(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YNoStaticElementInteractions(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YPreferTagOverRole(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YRoleHasRequiredAriaProps(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YRoleSupportsAriaProps(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YScope(rule) => rule.is_native_type_aware(),
+            Self::JsxA11YTabindexNoPositive(rule) => rule.is_native_type_aware(),
+            Self::OxcApproxConstant(rule) => rule.is_native_type_aware(),
+            Self::OxcBadArrayMethodOnArguments(rule) => rule.is_native_type_aware(),
+            Self::OxcBadBitwiseOperator(rule) => rule.is_native_type_aware(),
+            Self::OxcBadCharAtComparison(rule) => rule.is_native_type_aware(),
+            Self::OxcBadComparisonSequence(rule) => rule.is_native_type_aware(),
+            Self::OxcBadMinMaxFunc(rule) => rule.is_native_type_aware(),
+            Self::OxcBadObjectLiteralComparison(rule) => rule.is_native_type_aware(),
+            Self::OxcBadReplaceAllArg(rule) => rule.is_native_type_aware(),
+            Self::OxcBranchesSharingCode(rule) => rule.is_native_type_aware(),
+            Self::OxcConstComparisons(rule) => rule.is_native_type_aware(),
+            Self::OxcDoubleComparisons(rule) => rule.is_native_type_aware(),
+            Self::OxcErasingOp(rule) => rule.is_native_type_aware(),
+            Self::OxcMisrefactoredAssignOp(rule) => rule.is_native_type_aware(),
+            Self::OxcMissingThrow(rule) => rule.is_native_type_aware(),
+            Self::OxcNoAccumulatingSpread(rule) => rule.is_native_type_aware(),
+            Self::OxcNoAsyncAwait(rule) => rule.is_native_type_aware(),
+            Self::OxcNoAsyncEndpointHandlers(rule) => rule.is_native_type_aware(),
+            Self::OxcNoBarrelFile(rule) => rule.is_native_type_aware(),
+            Self::OxcNoConstEnum(rule) => rule.is_native_type_aware(),
+            Self::OxcNoMapSpread(rule) => rule.is_native_type_aware(),
+            Self::OxcNoOptionalChaining(rule) => rule.is_native_type_aware(),
+            Self::OxcNoRestSpreadProperties(rule) => rule.is_native_type_aware(),
+            Self::OxcNoThisInExportedFunction(rule) => rule.is_native_type_aware(),
+            Self::OxcNumberArgOutOfRange(rule) => rule.is_native_type_aware(),
+            Self::OxcOnlyUsedInRecursion(rule) => rule.is_native_type_aware(),
+            Self::OxcUninvokedArrayCallback(rule) => rule.is_native_type_aware(),
+            Self::NextjsGoogleFontDisplay(rule) => rule.is_native_type_aware(),
+            Self::NextjsGoogleFontPreconnect(rule) => rule.is_native_type_aware(),
+            Self::NextjsInlineScriptId(rule) => rule.is_native_type_aware(),
+            Self::NextjsNextScriptForGa(rule) => rule.is_native_type_aware(),
+            Self::NextjsNoAssignModuleVariable(rule) => rule.is_native_type_aware(),
+            Self::NextjsNoAsyncClientComponent(rule) => rule.is_native_type_aware(),
+            Self::NextjsNoBeforeInteractiveScriptOutsideDocument(rule) => {
+                rule.is_native_type_aware()
+            }
+            Self::NextjsNoCssTags(rule) => rule.is_native_type_aware(),
+            Self::NextjsNoDocumentImportInPage(rule) => rule.is_native_type_aware(),
+            Self::NextjsNoDuplicateHead(rule) => rule.is_native_type_aware(),
+            Self::NextjsNoHeadElement(rule) => rule.is_native_type_aware(),
+            Self::NextjsNoHeadImportInDocument(rule) => rule.is_native_type_aware(),
+            Self::NextjsNoHtmlLinkForPages(rule) => rule.is_native_type_aware(),
+            Self::NextjsNoImgElement(rule) => rule.is_native_type_aware(),
+            Self::NextjsNoPageCustomFont(rule) => rule.is_native_type_aware(),
+            Self::NextjsNoScriptComponentInHead(rule) => rule.is_native_type_aware(),
+            Self::NextjsNoStyledJsxInDocument(rule) => rule.is_native_type_aware(),
+            Self::NextjsNoSyncScripts(rule) => rule.is_native_type_aware(),
+            Self::NextjsNoTitleInDocumentHead(rule) => rule.is_native_type_aware(),
+            Self::NextjsNoTypos(rule) => rule.is_native_type_aware(),
+            Self::NextjsNoUnwantedPolyfillio(rule) => rule.is_native_type_aware(),
+            Self::JsdocCheckAccess(rule) => rule.is_native_type_aware(),
+            Self::JsdocCheckPropertyNames(rule) => rule.is_native_type_aware(),
+            Self::JsdocCheckTagNames(rule) => rule.is_native_type_aware(),
+            Self::JsdocEmptyTags(rule) => rule.is_native_type_aware(),
+            Self::JsdocImplementsOnClasses(rule) => rule.is_native_type_aware(),
+            Self::JsdocNoDefaults(rule) => rule.is_native_type_aware(),
+            Self::JsdocRequireParam(rule) => rule.is_native_type_aware(),
+            Self::JsdocRequireParamDescription(rule) => rule.is_native_type_aware(),
+            Self::JsdocRequireParamName(rule) => rule.is_native_type_aware(),
+            Self::JsdocRequireParamType(rule) => rule.is_native_type_aware(),
+            Self::JsdocRequireProperty(rule) => rule.is_native_type_aware(),
+            Self::JsdocRequirePropertyDescription(rule) => rule.is_native_type_aware(),
+            Self::JsdocRequirePropertyName(rule) => rule.is_native_type_aware(),
+            Self::JsdocRequirePropertyType(rule) => rule.is_native_type_aware(),
+            Self::JsdocRequireReturns(rule) => rule.is_native_type_aware(),
+            Self::JsdocRequireReturnsDescription(rule) => rule.is_native_type_aware(),
+            Self::JsdocRequireReturnsType(rule) => rule.is_native_type_aware(),
+            Self::JsdocRequireThrowsDescription(rule) => rule.is_native_type_aware(),
+            Self::JsdocRequireThrowsType(rule) => rule.is_native_type_aware(),
+            Self::JsdocRequireYields(rule) => rule.is_native_type_aware(),
+            Self::JsdocRequireYieldsDescription(rule) => rule.is_native_type_aware(),
+            Self::JsdocRequireYieldsType(rule) => rule.is_native_type_aware(),
+            Self::PromiseAlwaysReturn(rule) => rule.is_native_type_aware(),
+            Self::PromiseAvoidNew(rule) => rule.is_native_type_aware(),
+            Self::PromiseCatchOrReturn(rule) => rule.is_native_type_aware(),
+            Self::PromiseNoCallbackInPromise(rule) => rule.is_native_type_aware(),
+            Self::PromiseNoMultipleResolved(rule) => rule.is_native_type_aware(),
+            Self::PromiseNoNesting(rule) => rule.is_native_type_aware(),
+            Self::PromiseNoNewStatics(rule) => rule.is_native_type_aware(),
+            Self::PromiseNoPromiseInCallback(rule) => rule.is_native_type_aware(),
+            Self::PromiseNoReturnInFinally(rule) => rule.is_native_type_aware(),
+            Self::PromiseNoReturnWrap(rule) => rule.is_native_type_aware(),
+            Self::PromiseParamNames(rule) => rule.is_native_type_aware(),
+            Self::PromisePreferAwaitToCallbacks(rule) => rule.is_native_type_aware(),
+            Self::PromisePreferAwaitToThen(rule) => rule.is_native_type_aware(),
+            Self::PromisePreferCatch(rule) => rule.is_native_type_aware(),
+            Self::PromiseSpecOnly(rule) => rule.is_native_type_aware(),
+            Self::PromiseValidParams(rule) => rule.is_native_type_aware(),
+            Self::VitestConsistentEachFor(rule) => rule.is_native_type_aware(),
+            Self::VitestConsistentTestFilename(rule) => rule.is_native_type_aware(),
+            Self::VitestConsistentTestIt(rule) => rule.is_native_type_aware(),
+            Self::VitestConsistentVitestVi(rule) => rule.is_native_type_aware(),
+            Self::VitestExpectExpect(rule) => rule.is_native_type_aware(),
+            Self::VitestHoistedApisOnTop(rule) => rule.is_native_type_aware(),
+            Self::VitestMaxExpects(rule) => rule.is_native_type_aware(),
+            Self::VitestMaxNestedDescribe(rule) => rule.is_native_type_aware(),
+            Self::VitestNoAliasMethods(rule) => rule.is_native_type_aware(),
+            Self::VitestNoCommentedOutTests(rule) => rule.is_native_type_aware(),
+            Self::VitestNoConditionalExpect(rule) => rule.is_native_type_aware(),
+            Self::VitestNoConditionalInTest(rule) => rule.is_native_type_aware(),
+            Self::VitestNoConditionalTests(rule) => rule.is_native_type_aware(),
+            Self::VitestNoDisabledTests(rule) => rule.is_native_type_aware(),
+            Self::VitestNoDuplicateHooks(rule) => rule.is_native_type_aware(),
+            Self::VitestNoFocusedTests(rule) => rule.is_native_type_aware(),
+            Self::VitestNoHooks(rule) => rule.is_native_type_aware(),
+            Self::VitestNoIdenticalTitle(rule) => rule.is_native_type_aware(),
+            Self::VitestNoImportNodeTest(rule) => rule.is_native_type_aware(),
+            Self::VitestNoImportingVitestGlobals(rule) => rule.is_native_type_aware(),
+            Self::VitestNoInterpolationInSnapshots(rule) => rule.is_native_type_aware(),
+            Self::VitestNoLargeSnapshots(rule) => rule.is_native_type_aware(),
+            Self::VitestNoMocksImport(rule) => rule.is_native_type_aware(),
+            Self::VitestNoRestrictedMatchers(rule) => rule.is_native_type_aware(),
+            Self::VitestNoRestrictedViMethods(rule) => rule.is_native_type_aware(),
+            Self::VitestNoStandaloneExpect(rule) => rule.is_native_type_aware(),
+            Self::VitestNoTestPrefixes(rule) => rule.is_native_type_aware(),
+            Self::VitestNoTestReturnStatement(rule) => rule.is_native_type_aware(),
+            Self::VitestNoUnneededAsyncExpectFunction(rule) => rule.is_native_type_aware(),
+            Self::VitestPaddingAroundAfterAllBlocks(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferCalledExactlyOnceWith(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferCalledOnce(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferCalledTimes(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferCalledWith(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferComparisonMatcher(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferDescribeFunctionTitle(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferEach(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferEqualityMatcher(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferExpectAssertions(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferExpectResolves(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferExpectTypeOf(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferHooksInOrder(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferHooksOnTop(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferImportInMock(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferImportingVitestGlobals(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferLowercaseTitle(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferMockPromiseShorthand(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferMockReturnShorthand(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferSnapshotHint(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferSpyOn(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferStrictBooleanMatchers(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferStrictEqual(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferToBe(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferToBeFalsy(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferToBeObject(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferToBeTruthy(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferToContain(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferToHaveBeenCalledTimes(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferToHaveLength(rule) => rule.is_native_type_aware(),
+            Self::VitestPreferTodo(rule) => rule.is_native_type_aware(),
+            Self::VitestRequireAwaitedExpectPoll(rule) => rule.is_native_type_aware(),
+            Self::VitestRequireHook(rule) => rule.is_native_type_aware(),
+            Self::VitestRequireLocalTestContextForConcurrentSnapshots(rule) => {
+                rule.is_native_type_aware()
+            }
+            Self::VitestRequireMockTypeParameters(rule) => rule.is_native_type_aware(),
+            Self::VitestRequireTestTimeout(rule) => rule.is_native_type_aware(),
+            Self::VitestRequireToThrowMessage(rule) => rule.is_native_type_aware(),
+            Self::VitestRequireTopLevelDescribe(rule) => rule.is_native_type_aware(),
+            Self::VitestValidDescribeCallback(rule) => rule.is_native_type_aware(),
+            Self::VitestValidExpect(rule) => rule.is_native_type_aware(),
+            Self::VitestValidExpectInPromise(rule) => rule.is_native_type_aware(),
+            Self::VitestValidTitle(rule) => rule.is_native_type_aware(),
+            Self::VitestWarnTodo(rule) => rule.is_native_type_aware(),
+            Self::NodeCallbackReturn(rule) => rule.is_native_type_aware(),
+            Self::NodeGlobalRequire(rule) => rule.is_native_type_aware(),
+            Self::NodeHandleCallbackErr(rule) => rule.is_native_type_aware(),
+            Self::NodeNoExportsAssign(rule) => rule.is_native_type_aware(),
+            Self::NodeNoMixedRequires(rule) => rule.is_native_type_aware(),
+            Self::NodeNoNewRequire(rule) => rule.is_native_type_aware(),
+            Self::NodeNoPathConcat(rule) => rule.is_native_type_aware(),
+            Self::NodeNoProcessEnv(rule) => rule.is_native_type_aware(),
+            Self::NodeNoSync(rule) => rule.is_native_type_aware(),
+            Self::VueComponentDefinitionNameCasing(rule) => rule.is_native_type_aware(),
+            Self::VueDefineEmitsDeclaration(rule) => rule.is_native_type_aware(),
+            Self::VueDefinePropsDeclaration(rule) => rule.is_native_type_aware(),
+            Self::VueDefinePropsDestructuring(rule) => rule.is_native_type_aware(),
+            Self::VueMaxProps(rule) => rule.is_native_type_aware(),
+            Self::VueNextTickStyle(rule) => rule.is_native_type_aware(),
+            Self::VueNoArrowFunctionsInWatch(rule) => rule.is_native_type_aware(),
+            Self::VueNoAsyncInComputedProperties(rule) => rule.is_native_type_aware(),
+            Self::VueNoComputedPropertiesInData(rule) => rule.is_native_type_aware(),
+            Self::VueNoDeprecatedDataObjectDeclaration(rule) => rule.is_native_type_aware(),
+            Self::VueNoDeprecatedDeleteSet(rule) => rule.is_native_type_aware(),
+            Self::VueNoDeprecatedDestroyedLifecycle(rule) => rule.is_native_type_aware(),
+            Self::VueNoDeprecatedEventsApi(rule) => rule.is_native_type_aware(),
+            Self::VueNoDeprecatedModelDefinition(rule) => rule.is_native_type_aware(),
+            Self::VueNoDeprecatedPropsDefaultThis(rule) => rule.is_native_type_aware(),
+            Self::VueNoDeprecatedVueConfigKeycodes(rule) => rule.is_native_type_aware(),
+            Self::VueNoDupeKeys(rule) => rule.is_native_type_aware(),
+            Self::VueNoExportInScriptSetup(rule) => rule.is_native_type_aware(),
+            Self::VueNoExposeAfterAwait(rule) => rule.is_native_type_aware(),
+            Self::VueNoImportCompilerMacros(rule) => rule.is_native_type_aware(),
+            Self::VueNoLifecycleAfterAwait(rule) => rule.is_native_type_aware(),
+            Self::VueNoMultipleSlotArgs(rule) => rule.is_native_type_aware(),
+            Self::VueNoRequiredPropWithDefault(rule) => rule.is_native_type_aware(),
+            Self::VueNoReservedComponentNames(rule) => rule.is_native_type_aware(),
+            Self::VueNoReservedKeys(rule) => rule.is_native_type_aware(),
+            Self::VueNoReservedProps(rule) => rule.is_native_type_aware(),
+            Self::VueNoSharedComponentData(rule) => rule.is_native_type_aware(),
+            Self::VueNoSideEffectsInComputedProperties(rule) => rule.is_native_type_aware(),
+            Self::VueNoThisInBeforeRouteEnter(rule) => rule.is_native_type_aware(),
+            Self::VueNoWatchAfterAwait(rule) => rule.is_native_type_aware(),
+            Self::VuePreferImportFromVue(rule) => rule.is_native_type_aware(),
+            Self::VuePropNameCasing(rule) => rule.is_native_type_aware(),
+            Self::VueRequireDefaultExport(rule) => rule.is_native_type_aware(),
+            Self::VueRequireDefaultProp(rule) => rule.is_native_type_aware(),
+            Self::VueRequireDirectExport(rule) => rule.is_native_type_aware(),
+            Self::VueRequirePropTypeConstructor(rule) => rule.is_native_type_aware(),
+            Self::VueRequirePropTypes(rule) => rule.is_native_type_aware(),
+            Self::VueRequireRenderReturn(rule) => rule.is_native_type_aware(),
+            Self::VueRequireSlotsAsFunctions(rule) => rule.is_native_type_aware(),
+            Self::VueRequireTypedRef(rule) => rule.is_native_type_aware(),
+            Self::VueReturnInComputedProperty(rule) => rule.is_native_type_aware(),
+            Self::VueReturnInEmitsValidator(rule) => rule.is_native_type_aware(),
+            Self::VueValidDefineEmits(rule) => rule.is_native_type_aware(),
+            Self::VueValidDefineOptions(rule) => rule.is_native_type_aware(),
+            Self::VueValidDefineProps(rule) => rule.is_native_type_aware(),
+            Self::VueValidNextTick(rule) => rule.is_native_type_aware(),
+        }
+    }
     #[doc = r" The version of oxlint in which this rule was first available."]
     #[cfg(feature = "ruledocs")]
     pub fn version(&self) -> &'static str {
@@ -9,12 +9,15 @@
 use std::{
     borrow::Cow,
     iter, mem,
-    path::Path,
+    path::{Path, PathBuf},
     ptr::{self, NonNull},
     rc::Rc,
     string::ToString,
+    sync::{Arc, Mutex},
 };
 
+use rustc_hash::FxHashSet;
+
 use oxc_allocator::{Allocator, AllocatorPool, ArenaVec, CloneIn, TakeIn};
 use oxc_ast::{
     ast::{Comment, CommentContent, CommentKind, Program},
@@ -44,6 +47,7 @@ mod options;
 mod rule;
 mod service;
 mod suppression;
+mod symbol_index;
 pub(crate) mod timing;
 mod tsgolint;
 mod utils;
@@ -78,7 +82,7 @@ pub use crate::{
     external_linter::{
         ExternalLinter, ExternalLinterCreateWorkspaceCb, ExternalLinterDestroyWorkspaceCb,
         ExternalLinterLintFileCb, ExternalLinterLoadPluginCb, ExternalLinterSetupRuleConfigsCb,
-        JsFix, LintFileResult, LoadPluginResult, convert_and_merge_js_fixes,
+        JsFix, LintFileResult, LoadPluginResult, TypeInfo, convert_and_merge_js_fixes,
     },
     external_plugin_store::{ExternalOptionsId, ExternalPluginStore, ExternalRuleId},
     fixer::{Fix, FixKind, Fixer, Message, MessageRule, PossibleFixes},
@@ -91,6 +95,7 @@ pub use crate::{
     rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleRunFunctionsImplemented, RuleRunner},
     service::{LintService, LintServiceOptions, OsFileSystem, RuntimeFileSystem},
     suppression::{OxlintSuppressionFileAction, SuppressionManager},
+    symbol_index::{ModuleSymbol, SymbolIndex, SymbolReference, SymbolReferenceKind},
     timing::{RuleTimingRecord, RuleTimingSource, RuleTimingStore},
     tsgolint::TsGoLintState,
     utils::{read_to_arena_str, read_to_string},
@@ -286,6 +291,8 @@ pub struct Linter {
     config: ConfigStore,
     external_linter: Option<ExternalLinter>,
     workspace_uri: Option<Box<str>>,
+    /// Files whose type-aware rules ran in-process, see [`Linter::native_type_aware_files`].
+    native_type_aware_files: Arc<Mutex<FxHashSet<PathBuf>>>,
 }
 
 impl Linter {
@@ -294,7 +301,13 @@ impl Linter {
         config: ConfigStore,
         external_linter: Option<ExternalLinter>,
     ) -> Self {
-        Self { options, config, external_linter, workspace_uri: None }
+        Self {
+            options,
+            config,
+            external_linter,
+            workspace_uri: None,
+            native_type_aware_files: Arc::default(),
+        }
     }
 
     #[must_use]
@@ -310,6 +323,22 @@ impl Linter {
         self
     }
 
+    /// Enable type-aware linting, so that type-aware rules which support it run in-process.
+    /// See `Rule::is_native_type_aware`.
+    #[must_use]
+    pub fn with_type_aware(mut self, enabled: bool) -> Self {
+        self.options.type_aware = enabled;
+        self
+    }
+
+    /// The linted files whose type-aware rules ran in-process, because the native type checker
+    /// knows all their types. Type-aware rules still run in tsgolint for other files, which e.g.
+    /// use unresolved imports or undeclared globals. See
+    /// `TsGoLintState::with_native_type_aware_files`.
+    pub fn native_type_aware_files(&self) -> Arc<Mutex<FxHashSet<PathBuf>>> {
+        Arc::clone(&self.native_type_aware_files)
+    }
+
     #[must_use]
     pub fn with_report_unused_directives(mut self, report_config: Option<AllowWarnDeny>) -> Self {
         self.options.report_unused_directive = report_config;
@@ -376,12 +405,24 @@ impl Linter {
             .file_extension()
             .is_some_and(|ext| LINT_PARTIAL_LOADER_EXTENSIONS.iter().any(|e| e == &ext));
 
+        let has_native_type_aware_rules = self.options.type_aware
+            && rules.iter().any(|(rule, _)| rule.is_tsgolint_rule() && rule.is_native_type_aware());
+        let mut is_native_type_aware_file = has_native_type_aware_rules;
+
         loop {
             let semantic = ctx_host.semantic();
+            // Type-aware rules run in-process only if the types they use are known, otherwise
+            // they are left to tsgolint
+            let run_native_type_aware_rules = has_native_type_aware_rules
+                && !ctx_host.current_sub_host().expression_types().has_unknown_types();
+            is_native_type_aware_file &= run_native_type_aware_rules;
             let rules = rules
                 .iter()
                 .filter(|(rule, _)| {
-                    if rule.is_tsgolint_rule() {
+                    // Type-aware rules run in tsgolint, unless they can run in-process
+                    if rule.is_tsgolint_rule()
+                        && !(run_native_type_aware_rules && rule.is_native_type_aware())
+                    {
                         return false;
                     }
 
@@ -486,6 +527,18 @@ impl Linter {
             }
         }
 
+        if self.options.type_aware {
+            let mut native_type_aware_files = self
+                .native_type_aware_files
+                .lock()
+                .expect("native_type_aware_files mutex poisoned");
+            if is_native_type_aware_file {
+                native_type_aware_files.insert(path.to_path_buf());
+            } else {
+                native_type_aware_files.remove(path);
+            }
+        }
+
         let diagnostics = ctx_host.take_diagnostics();
         let disable_directives = if is_partial_loader_file {
             None
@@ -545,6 +598,10 @@ impl Linter {
         // TODO: It would be better to avoid the need for a `&mut Program` here, and so avoid this
         // sketchy behavior.
         let ctx_host = Rc::get_mut(ctx_host).unwrap();
+
+        // Collect type information while `Semantic` still exists
+        let type_info = self.options.type_aware.then(|| TypeInfo::new(ctx_host));
+
         let semantic = mem::take(ctx_host.semantic_mut());
         let program_addr = NonNull::from(semantic.nodes().program()).addr();
         // Check `Program` is in `Allocator`'s current chunk
@@ -563,6 +620,7 @@ impl Linter {
                 path,
                 ctx_host,
                 program,
+                type_info,
                 js_allocator_pool,
             );
             return;
@@ -585,6 +643,7 @@ impl Linter {
             ctx_host,
             program,
             tokens,
+            type_info,
             allocator,
         );
     }
@@ -613,6 +672,7 @@ impl Linter {
         path: &Path,
         ctx_host: &ContextHost<'_>,
         original_program: &mut Program<'_>,
+        type_info: Option<TypeInfo>,
         js_allocator_pool: &AllocatorPool,
     ) {
         let js_allocator_guard = js_allocator_pool.get();
@@ -671,6 +731,7 @@ impl Linter {
             ctx_host,
             program,
             tokens,
+            type_info,
             js_allocator,
         );
 
@@ -690,6 +751,7 @@ impl Linter {
         ctx_host: &ContextHost<'_>,
         program: &mut Program<'_>,
         tokens: &mut [Token],
+        type_info: Option<TypeInfo>,
         allocator: &Allocator,
     ) {
         // If has BOM, remove it
@@ -806,6 +868,11 @@ impl Linter {
             "{}".to_string()
         });
 
+        let type_info = type_info.map(|mut type_info| {
+            type_info.convert_spans(&span_converter);
+            type_info
+        });
+
         // `external_linter` always exists when `external_rules` is not empty
         let external_linter = self.external_linter.as_ref().unwrap();
 
@@ -816,6 +883,7 @@ impl Linter {
             external_rules.iter().map(|(_, options_id, _)| options_id.raw()).collect(),
             settings_json,
             globals_json,
+            type_info,
             self.workspace_uri.as_ref().map(ToString::to_string),
             allocator,
         );
@@ -203,25 +203,30 @@ impl LintRunnerBuilder {
         let directives_coordinator = DirectivesStore::new();
 
         let type_aware_linter = if self.type_aware_enabled {
-            match TsGoLintState::try_new(
-                self.lint_service_options.cwd(),
-                self.regular_linter.config.clone(),
-                self.fix_kind,
-            ) {
-                Ok(state) => Some(
-                    state
-                        .with_silent(self.silent)
-                        .with_type_check(self.type_check)
-                        .with_timings(self.timings),
-                ),
-                Err(e) => return Err(e),
-            }
+            let cwd = self.lint_service_options.cwd();
+            let config = self.regular_linter.config.clone();
+            // If all type-aware rules can run in-process, tsgolint is only needed for the files
+            // whose types the native type checker does not know
+            let state = if self.type_check || self.regular_linter.config.needs_tsgolint() {
+                TsGoLintState::try_new(cwd, config, self.fix_kind)?
+            } else {
+                TsGoLintState::new_deferred(cwd, config, self.fix_kind)
+            };
+            Some(
+                state
+                    .with_silent(self.silent)
+                    .with_type_check(self.type_check)
+                    .with_timings(self.timings)
+                    // Type-aware rules which ran in-process are not run again in tsgolint
+                    .with_native_type_aware_files(self.regular_linter.native_type_aware_files()),
+            )
         } else {
             None
         };
 
         let cwd = self.lint_service_options.cwd().to_path_buf();
-        let mut lint_service = LintService::new(self.regular_linter, self.lint_service_options);
+        let linter = self.regular_linter.with_type_aware(self.type_aware_enabled);
+        let mut lint_service = LintService::new(linter, self.lint_service_options);
         lint_service.set_disable_directives_map(directives_coordinator.map());
 
         Ok(LintRunner {
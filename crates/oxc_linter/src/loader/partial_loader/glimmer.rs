@@ -0,0 +1,516 @@
+use oxc_allocator::Allocator;
+use oxc_span::SourceType;
+
+use crate::loader::JavaScriptSource;
+
+const TEMPLATE_START: &str = "<template";
+const TEMPLATE_END: &str = "</template>";
+
+/// Names which are built into strict mode templates, rather than bound in JavaScript.
+const TEMPLATE_KEYWORDS: &[&str] = &[
+    "as",
+    "component",
+    "debugger",
+    "each",
+    "else",
+    "false",
+    "helper",
+    "if",
+    "let",
+    "log",
+    "modifier",
+    "mut",
+    "null",
+    "outlet",
+    "this",
+    "true",
+    "unbound",
+    "undefined",
+    "unless",
+    "yield",
+];
+
+/// Loader for Ember's `.gjs` and `.gts` files, which embed Glimmer templates in JavaScript with
+/// `<template>` tags.
+///
+/// Each `<template>…</template>` region is replaced with a placeholder of the same length, so
+/// spans in the rest of the file need no mapping. The placeholder keeps the names of JavaScript
+/// bindings which the template refers to at their original offsets, so that e.g. an import which
+/// is only used in a template is not reported as unused:
+///
+/// ```text
+/// const Greeting = <template><Hello @name={{name}} /></template>;
+/// const Greeting = [          Hello        ,name               ];
+/// ```
+///
+/// The array has no holes. As a statement, the placeholder calls a method of the array,
+/// `;[…].at()`, so that it is not an unused expression, and inside a class body it is a static
+/// block `static{[…].at()}`.
+///
+/// See: <https://rfcs.emberjs.com/id/0779-first-class-component-templates>
+pub struct GlimmerPartialLoader<'a> {
+    source_text: &'a str,
+    source_type: SourceType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TemplatePosition {
+    Expression,
+    ClassMember,
+    Statement,
+}
+
+struct Template {
+    start: usize,
+    end: usize,
+    position: TemplatePosition,
+}
+
+impl<'a> GlimmerPartialLoader<'a> {
+    pub fn new(source_text: &'a str, source_type: SourceType) -> Self {
+        Self { source_text, source_type }
+    }
+
+    pub fn parse(self, allocator: &'a Allocator) -> Vec<JavaScriptSource<'a>> {
+        let templates = self.find_templates();
+        if templates.is_empty() {
+            return vec![JavaScriptSource::partial(self.source_text, self.source_type, 0)];
+        }
+
+        let mut bytes = self.source_text.as_bytes().to_vec();
+        for template in &templates {
+            Self::write_placeholder(
+                template,
+                &self.source_text[template.start..template.end],
+                &mut bytes,
+            );
+        }
+        // Templates start and end on ASCII characters, and only ASCII is written into them.
+        let source_text = String::from_utf8(bytes).unwrap();
+        vec![JavaScriptSource::partial(allocator.alloc_str(&source_text), self.source_type, 0)]
+    }
+
+    /// Find `<template>` tags outside of strings and comments.
+    fn find_templates(&self) -> Vec<Template> {
+        let bytes = self.source_text.as_bytes();
+        let mut templates = vec![];
+        // Open braces, `true` for class bodies
+        let mut braces = vec![];
+        let mut brackets = 0u32;
+        // Bracket depth of the last `class` keyword whose body has not been reached
+        let mut class_keyword = None;
+        // Last byte of the previous token
+        let mut prev = None;
+        // The previous token, if it was a word
+        let mut prev_word = None;
+        // Whether there is a line break after the previous token
+        let mut line_break = false;
+
+        let mut i = 0;
+        while i < bytes.len() {
+            let b = bytes[i];
+            match b {
+                b' ' | b'\t' | b'\n' | b'\r' => {
+                    line_break |= matches!(b, b'\n' | b'\r');
+                    i += 1;
+                    continue;
+                }
+                b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                    i = self.source_text[i..].find('\n').map_or(bytes.len(), |offset| i + offset);
+                    continue;
+                }
+                b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                    i = self.source_text[i + 2..]
+                        .find("*/")
+                        .map_or(bytes.len(), |offset| i + offset + 4);
+                    continue;
+                }
+                b'/' if prev.is_none_or(|prev: u8| b"(,=:[!&|?{};+-*%<>~^".contains(&prev)) => {
+                    i = skip_regex(bytes, i);
+                }
+                b'\'' | b'"' => i = skip_string(bytes, i),
+                b'`' => i = skip_template_literal(bytes, i),
+                b'<' if is_template_start(self.source_text, i) => {
+                    let Some(end) = find_template_end(self.source_text, i) else { break };
+                    // A template on a new line after a complete expression starts a statement
+                    let after_expression = line_break
+                        && prev.is_some_and(|prev| {
+                            is_identifier_byte(prev) || matches!(prev, b')' | b']')
+                        })
+                        && !prev_word.is_some_and(|word| {
+                            matches!(word, "await" | "default" | "return" | "throw" | "yield")
+                        });
+                    let position = match (braces.last(), prev) {
+                        (Some(true), Some(b'{' | b'}' | b';')) => TemplatePosition::ClassMember,
+                        (Some(true), _) => TemplatePosition::Expression,
+                        (_, None | Some(b'{' | b'}' | b';')) => TemplatePosition::Statement,
+                        _ if after_expression => TemplatePosition::Statement,
+                        _ => TemplatePosition::Expression,
+                    };
+                    templates.push(Template { start: i, end, position });
+                    i = end;
+                    prev = Some(b'>');
+                    prev_word = None;
+                    line_break = false;
+                    continue;
+                }
+                b'{' => {
+                    braces.push(class_keyword == Some(brackets));
+                    if class_keyword == Some(brackets) {
+                        class_keyword = None;
+                    }
+                    i += 1;
+                }
+                b'}' => {
+                    braces.pop();
+                    i += 1;
+                }
+                b'(' | b'[' => {
+                    brackets += 1;
+                    i += 1;
+                }
+                b')' | b']' => {
+                    brackets = brackets.saturating_sub(1);
+                    i += 1;
+                }
+                _ if is_identifier_byte(b) => {
+                    let len = bytes[i..].iter().take_while(|b| is_identifier_byte(**b)).count();
+                    let word = &self.source_text[i..i + len];
+                    if word == "class" && prev != Some(b'.') {
+                        class_keyword = Some(brackets);
+                    }
+                    i += len;
+                    prev = Some(bytes[i - 1]);
+                    prev_word = Some(word);
+                    line_break = false;
+                    continue;
+                }
+                _ => i += 1,
+            }
+            prev = Some(bytes[i - 1]);
+            prev_word = None;
+            line_break = false;
+        }
+
+        templates
+    }
+
+    fn write_placeholder(template: &Template, template_text: &str, bytes: &mut [u8]) {
+        let region = &mut bytes[template.start..template.end];
+        for b in region.iter_mut() {
+            if !matches!(*b, b'\n' | b'\r') {
+                *b = b' ';
+            }
+        }
+        let (open, close): (&[u8], &[u8]) = match template.position {
+            TemplatePosition::Expression => (b"[", b"]"),
+            TemplatePosition::ClassMember => (b"static{[", b"].at()}"),
+            // Leading `;` stops the array from being parsed as a member access of the previous line
+            TemplatePosition::Statement => (b";[", b"].at()"),
+        };
+        region[..open.len()].copy_from_slice(open);
+        let close_start = region.len() - close.len();
+        region[close_start..].copy_from_slice(close);
+
+        // References start after `<template>`, so they never overlap the brackets
+        let content_start =
+            template_text.find('>').map_or(template_text.len(), |offset| offset + 1);
+        let content = &template_text[content_start..template_text.len() - TEMPLATE_END.len()];
+        for (index, (offset, name)) in template_references(content).into_iter().enumerate() {
+            let offset = content_start + offset;
+            if index > 0 {
+                region[offset - 1] = b',';
+            }
+            region[offset..offset + name.len()].copy_from_slice(name.as_bytes());
+        }
+    }
+}
+
+fn is_identifier_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'_' | b'$')
+}
+
+fn is_template_start(source_text: &str, i: usize) -> bool {
+    source_text[i..].starts_with(TEMPLATE_START)
+        && source_text
+            .as_bytes()
+            .get(i + TEMPLATE_START.len())
+            .is_some_and(|b| b == &b'>' || b.is_ascii_whitespace())
+}
+
+/// Find the end of the `<template>` tag starting at `start`, including nested `<template>` elements.
+fn find_template_end(source_text: &str, start: usize) -> Option<usize> {
+    let mut depth = 0u32;
+    let mut i = start;
+    loop {
+        let offset = source_text[i..].find('<')?;
+        i += offset;
+        if is_template_start(source_text, i) {
+            depth += 1;
+            i += TEMPLATE_START.len();
+        } else if source_text[i..].starts_with(TEMPLATE_END) {
+            depth -= 1;
+            i += TEMPLATE_END.len();
+            if depth == 0 {
+                return Some(i);
+            }
+        } else {
+            i += 1;
+        }
+    }
+}
+
+/// Skip a string literal starting at `start`. Returns the offset after it.
+fn skip_string(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'\n' => return i,
+            b if b == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Skip a template literal starting at `start`, including nested template literals in
+/// substitutions. Returns the offset after it.
+fn skip_template_literal(bytes: &[u8], start: usize) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'`' => return i + 1,
+            b'$' if bytes.get(i + 1) == Some(&b'{') => {
+                i += 2;
+                let mut depth = 0u32;
+                while i < bytes.len() {
+                    match bytes[i] {
+                        b'{' => {
+                            depth += 1;
+                            i += 1;
+                        }
+                        b'}' if depth == 0 => {
+                            i += 1;
+                            break;
+                        }
+                        b'}' => {
+                            depth -= 1;
+                            i += 1;
+                        }
+                        b'\'' | b'"' => i = skip_string(bytes, i),
+                        b'`' => i = skip_template_literal(bytes, i),
+                        _ => i += 1,
+                    }
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Skip a regular expression literal starting at `start`. Returns the offset after it.
+fn skip_regex(bytes: &[u8], start: usize) -> usize {
+    let mut in_class = false;
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'\n' => return i,
+            b'[' => in_class = true,
+            b']' => in_class = false,
+            b'/' if !in_class => return i + 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// Find JavaScript bindings which a template refers to, with their offsets in `content`.
+///
+/// These are the heads of paths in mustaches (`{{format date}}`, `{{(helper)}}`, `{{#Block}}`)
+/// and component tag names (`<Greeting />`, `<ui.Button />`), except block params declared
+/// with `as |name|` and names built into templates.
+fn template_references(content: &str) -> Vec<(usize, &str)> {
+    let bytes = content.as_bytes();
+    let mut locals = vec![];
+    let mut references = vec![];
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i..].starts_with(b"<!--") {
+            i = content[i..].find("-->").map_or(bytes.len(), |offset| i + offset + 3);
+        } else if bytes[i..].starts_with(b"{{!") {
+            let end = if bytes[i..].starts_with(b"{{!--") { "--}}" } else { "}}" };
+            i = content[i..].find(end).map_or(bytes.len(), |offset| i + offset + end.len());
+        } else if bytes[i..].starts_with(b"{{") {
+            i = mustache_references(content, i + 2, &mut locals, &mut references);
+        } else if bytes[i] == b'<' && bytes.get(i + 1).is_some_and(u8::is_ascii_alphabetic) {
+            let start = i + 1;
+            let len = bytes[start..]
+                .iter()
+                .take_while(|b| is_identifier_byte(**b) || matches!(b, b'.' | b'-' | b':'))
+                .count();
+            let name = &content[start..start + len];
+            let head = name.split('.').next().unwrap_or(name);
+            if (name.contains('.') || bytes[start].is_ascii_uppercase())
+                && head.bytes().all(is_identifier_byte)
+                && head != "this"
+            {
+                references.push((start, head));
+            }
+            i = start + len;
+        } else if bytes[i..].starts_with(b"as |") {
+            i += 4;
+            let end = content[i..].find('|').map_or(bytes.len(), |offset| i + offset);
+            locals.extend(content[i..end].split_ascii_whitespace());
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+
+    references.retain(|(_, name)| !locals.contains(name));
+    references
+}
+
+/// Collect references in a mustache which starts at `start`, after `{{`. Returns the offset after
+/// the closing `}}`.
+fn mustache_references<'c>(
+    content: &'c str,
+    start: usize,
+    locals: &mut Vec<&'c str>,
+    references: &mut Vec<(usize, &'c str)>,
+) -> usize {
+    let bytes = content.as_bytes();
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'}' if bytes.get(i + 1) == Some(&b'}') => return i + 2,
+            b'\'' | b'"' => i = skip_string(bytes, i),
+            b'|' => {
+                i += 1;
+                let end = content[i..].find('|').map_or(bytes.len(), |offset| i + offset);
+                locals.extend(content[i..end].split_ascii_whitespace());
+                i = end + 1;
+            }
+            b'@' => {
+                i += 1 + bytes[i + 1..]
+                    .iter()
+                    .take_while(|b| is_identifier_byte(**b) || matches!(b, b'.' | b'-'))
+                    .count();
+            }
+            b if b.is_ascii_alphabetic() || matches!(b, b'_' | b'$') => {
+                let len = bytes[i..]
+                    .iter()
+                    .take_while(|b| is_identifier_byte(**b) || matches!(b, b'.' | b'-'))
+                    .count();
+                let path = &content[i..i + len];
+                let head = path.split('.').next().unwrap_or(path);
+                let is_named_argument = bytes.get(i + len) == Some(&b'=');
+                let is_block_end = i > 0 && bytes[i - 1] == b'/';
+                if !is_named_argument
+                    && !is_block_end
+                    && !head.contains('-')
+                    && !TEMPLATE_KEYWORDS.contains(&head)
+                {
+                    references.push((i, head));
+                }
+                i += len;
+            }
+            _ if bytes[i].is_ascii_digit() => {
+                i += bytes[i..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_alphanumeric() || **b == b'.')
+                    .count();
+            }
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::GlimmerPartialLoader;
+
+    fn parse<'a>(allocator: &'a Allocator, source_text: &'a str) -> &'a str {
+        let sources = GlimmerPartialLoader::new(source_text, SourceType::mjs()).parse(allocator);
+        assert_eq!(sources.len(), 1);
+        let source = sources[0];
+        assert_eq!(source.start, 0);
+        assert_eq!(source.source_text.len(), source_text.len());
+        let ret = Parser::new(allocator, source.source_text, SourceType::mjs()).parse();
+        assert!(ret.diagnostics.is_empty(), "{:?}\n{}", ret.diagnostics, source.source_text);
+        source.source_text
+    }
+
+    #[test]
+    fn test_expression() {
+        let allocator = Allocator::default();
+        let source_text = "import Hello from './hello';\nconst Greeting = <template><Hello @name={{name}} /></template>;\n";
+        assert_eq!(
+            parse(&allocator, source_text),
+            "import Hello from './hello';\nconst Greeting = [          Hello        ,name               ];\n"
+        );
+    }
+
+    #[test]
+    fn test_class_member() {
+        let allocator = Allocator::default();
+        let source_text = "
+class Counter extends Component {
+  count = 0;
+  <template>
+    {{#each this.items as |item|}}
+      {{format item this.count style='short'}}
+    {{/each}}
+  </template>
+}
+";
+        let text = parse(&allocator, source_text);
+        assert!(text.contains("  static{[  \n"));
+        assert_eq!(text.matches("format").count(), 1);
+        assert_eq!(text.find("format"), source_text.find("format"));
+        assert!(!text.contains("item"));
+        assert!(!text.contains("short"));
+        assert!(text.ends_with("].at()}\n}\n"));
+    }
+
+    #[test]
+    fn test_statement() {
+        let allocator = Allocator::default();
+        let source_text = "import { on } from '@ember/modifier';\nfoo()\n<template><button {{on 'click' (fn go 1)}}>日本</button></template>\n";
+        let text = parse(&allocator, source_text);
+        assert!(text.contains("foo()\n;["));
+        assert!(text.ends_with("].at()\n"));
+        assert_eq!(text.rfind(" on"), source_text.rfind("{on"));
+        assert!(text.contains(",fn,go"));
+    }
+
+    #[test]
+    fn test_not_templates() {
+        let allocator = Allocator::default();
+        let source_text = "const a = '<template>';\n// <template></template>\nconst b = `${'<template>'}`;\nconst c = /<template>/;\n";
+        assert_eq!(parse(&allocator, source_text), source_text);
+    }
+
+    #[test]
+    fn test_nested_template_element() {
+        let allocator = Allocator::default();
+        let source_text =
+            "export default <template><template><Inner /></template></template>;\nlet x;";
+        let text = parse(&allocator, source_text);
+        assert!(text.starts_with("export default ["));
+        assert!(text.contains(" Inner"));
+        assert!(text.ends_with("];\nlet x;"));
+    }
+}
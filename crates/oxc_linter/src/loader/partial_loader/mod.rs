@@ -1,12 +1,15 @@
 use memchr::{memmem::Finder, memmem::FinderRev};
-use oxc_span::VALID_EXTENSIONS;
+use oxc_allocator::Allocator;
+use oxc_span::{SourceType, VALID_EXTENSIONS};
 
 use crate::loader::JavaScriptSource;
 
 mod astro;
+mod glimmer;
 mod svelte;
 mod vue;
 pub use astro::AstroPartialLoader;
+pub use glimmer::GlimmerPartialLoader;
 pub use svelte::SveltePartialLoader;
 pub use vue::VuePartialLoader;
 
@@ -17,7 +20,7 @@ const COMMENT_END: &str = "-->";
 
 /// File extensions that can contain JS/TS code in certain parts, such as in `<script>` tags, and can
 /// be loaded using the [`PartialLoader`].
-pub const LINT_PARTIAL_LOADER_EXTENSIONS: &[&str] = &["vue", "astro", "svelte"];
+pub const LINT_PARTIAL_LOADER_EXTENSIONS: &[&str] = &["vue", "astro", "svelte", "gjs", "gts"];
 
 /// All valid JavaScript/TypeScript extensions, plus additional framework files that
 /// contain JavaScript/TypeScript code in them (e.g., Vue, Astro, Svelte, etc.).
@@ -29,11 +32,21 @@ pub struct PartialLoader;
 impl PartialLoader {
     /// Extract js section of special files.
     /// Returns `None` if the special file does not have a js section.
-    pub fn parse<'a>(ext: &str, source_text: &'a str) -> Option<Vec<JavaScriptSource<'a>>> {
+    pub fn parse<'a>(
+        ext: &str,
+        source_text: &'a str,
+        allocator: &'a Allocator,
+    ) -> Option<Vec<JavaScriptSource<'a>>> {
         match ext {
             "vue" => Some(VuePartialLoader::new(source_text).parse()),
             "astro" => Some(AstroPartialLoader::new(source_text).parse()),
             "svelte" => Some(SveltePartialLoader::new(source_text).parse()),
+            "gjs" => {
+                Some(GlimmerPartialLoader::new(source_text, SourceType::mjs()).parse(allocator))
+            }
+            "gts" => {
+                Some(GlimmerPartialLoader::new(source_text, SourceType::ts()).parse(allocator))
+            }
             _ => None,
         }
     }
@@ -8,8 +8,9 @@ use std::{
 
 use rustc_hash::FxHashMap;
 
-use oxc_semantic::Semantic;
-use oxc_span::Span;
+use oxc_ast::AstKind;
+use oxc_semantic::{Semantic, SymbolId};
+use oxc_span::{GetSpan, Span};
 use oxc_str::CompactStr;
 pub use oxc_syntax::module_record::RequestedModule;
 
@@ -86,6 +87,20 @@ pub struct ModuleRecord {
     /// `export default name`
     ///         ^^^^^^^ span
     pub export_default: Option<Span>,
+
+    /// Symbols declared in this module for `local_export_entries`, keyed by export name.
+    /// This and the following fields are empty unless [`ModuleRecord::collect_symbols`] is called.
+    pub exported_symbols: FxHashMap<CompactStr, SymbolId>,
+
+    /// Symbols of import bindings, valued by their index in `import_entries`
+    pub imported_symbols: FxHashMap<SymbolId, usize>,
+
+    /// Spans of references to the symbols in `exported_symbols` and `imported_symbols`
+    pub symbol_references: FxHashMap<SymbolId, Vec<Span>>,
+
+    /// `ns.name` and `ns["name"]` member accesses of namespace imports, keyed by the namespace
+    /// binding
+    pub namespace_member_references: FxHashMap<SymbolId, Vec<NameSpan>>,
 }
 
 impl fmt::Debug for ModuleRecord {
@@ -112,6 +127,10 @@ impl fmt::Debug for ModuleRecord {
             .field("exported_bindings", &self.exported_bindings)
             .field("exported_bindings_from_star_export", &self.exported_bindings_from_star_export)
             .field("export_default", &self.export_default)
+            .field("exported_symbols", &self.exported_symbols)
+            .field("imported_symbols", &self.imported_symbols)
+            .field("symbol_references", &self.symbol_references)
+            .field("namespace_member_references", &self.namespace_member_references)
             .finish()
     }
 }
@@ -460,11 +479,7 @@ impl<'a> From<&oxc_syntax::module_record::ExportLocalName<'a>> for ExportLocalNa
 }
 
 impl ModuleRecord {
-    pub fn new(
-        path: &Path,
-        other: &oxc_syntax::module_record::ModuleRecord,
-        _semantic: &Semantic,
-    ) -> Self {
+    pub fn new(path: &Path, other: &oxc_syntax::module_record::ModuleRecord) -> Self {
         Self {
             has_module_syntax: other.has_module_syntax,
             resolved_absolute_path: path.to_path_buf(),
@@ -511,6 +526,65 @@ impl ModuleRecord {
         }
     }
 
+    /// Links import and local export entries to their symbols, and records the references to
+    /// those symbols, for cross-module lookups with [`crate::SymbolIndex`].
+    ///
+    /// This walks the references of every import and export, so the lint service only does it
+    /// when modules are linked, i.e. when the `import` plugin is enabled.
+    pub fn collect_symbols(&mut self, semantic: &Semantic) {
+        let scoping = semantic.scoping();
+        for export_entry in &self.local_export_entries {
+            let local_name = match &export_entry.local_name {
+                ExportLocalName::Name(name) | ExportLocalName::Default(name) => name.name(),
+                ExportLocalName::Null => continue,
+            };
+            let export_name = match &export_entry.export_name {
+                ExportExportName::Name(name) => name.name.clone(),
+                ExportExportName::Default(_) => CompactStr::new("default"),
+                ExportExportName::Null => continue,
+            };
+            if let Some(symbol_id) = scoping.get_root_binding(local_name.into()) {
+                self.exported_symbols.insert(export_name, symbol_id);
+            }
+        }
+        for (index, import_entry) in self.import_entries.iter().enumerate() {
+            if let Some(symbol_id) = scoping.get_root_binding(import_entry.local_name.name().into())
+            {
+                self.imported_symbols.insert(symbol_id, index);
+            }
+        }
+
+        let nodes = semantic.nodes();
+        let symbols = self.exported_symbols.values().chain(self.imported_symbols.keys());
+        for &symbol_id in symbols {
+            let references = scoping.get_resolved_references(symbol_id);
+            let spans = references.map(|reference| nodes.get_node(reference.node_id()).span());
+            self.symbol_references.entry(symbol_id).or_default().extend(spans);
+        }
+
+        for (&symbol_id, &index) in &self.imported_symbols {
+            if !self.import_entries[index].import_name.is_namespace_object() {
+                continue;
+            }
+            let members = scoping.get_resolved_references(symbol_id).filter_map(|reference| {
+                match nodes.parent_kind(reference.node_id()) {
+                    AstKind::StaticMemberExpression(member) => Some(NameSpan::new(
+                        CompactStr::from(member.property.name.as_str()),
+                        member.property.span,
+                    )),
+                    AstKind::ComputedMemberExpression(member) => member
+                        .static_property_info()
+                        .map(|(span, name)| NameSpan::new(CompactStr::from(name), span)),
+                    _ => None,
+                }
+            });
+            let members = members.collect::<Vec<_>>();
+            if !members.is_empty() {
+                self.namespace_member_references.insert(symbol_id, members);
+            }
+        }
+    }
+
     /// # Panics
     ///
     /// * If the RwLock is poisoned (which only happens if a thread panicked while holding the lock).
@@ -13,4 +13,7 @@ pub struct LintOptions {
     pub fix: FixKind,
     pub framework_hints: FrameworkFlags,
     pub report_unused_directive: Option<AllowWarnDeny>,
+    /// Whether type-aware linting is enabled, in which case type-aware rules which support it run
+    /// in-process rather than in tsgolint.
+    pub type_aware: bool,
 }
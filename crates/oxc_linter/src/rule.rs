@@ -73,6 +73,17 @@ pub trait Rule: Sized + Default + fmt::Debug {
     fn should_run(&self, ctx: &ContextHost) -> bool {
         true
     }
+
+    /// Whether this type-aware rule runs in-process, using the types of
+    /// [`LintContext::expression_type`], rather than in tsgolint.
+    ///
+    /// Only rules declared with `(tsgolint)` are asked. Implement this for rules whose checks,
+    /// with their current configuration, do not need more than the native type checker infers:
+    /// its `any` types are unknown types, which a rule must not report on.
+    #[inline]
+    fn is_native_type_aware(&self) -> bool {
+        false
+    }
 }
 
 /// Pretty-print a JSON value and collapse all whitespace runs to single spaces,
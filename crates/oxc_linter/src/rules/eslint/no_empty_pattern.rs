@@ -102,7 +102,10 @@ impl Rule for NoEmptyPattern {
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
-            AstKind::ArrayPattern(array) if array.is_empty() => {
+            // Unnamed Flow function type parameters are represented by empty array patterns
+            AstKind::ArrayPattern(array)
+                if array.is_empty() && !array.is_unnamed_flow_parameter() =>
+            {
                 ctx.diagnostic(no_empty_array_pattern_diagnostic(array.span));
             }
             AstKind::ObjectPattern(object)
@@ -125,6 +125,7 @@ impl NoUnusedExpressions {
             | Expression::BinaryExpression(_)
             | Expression::PrivateInExpression(_)
             | Expression::ThisExpression(_)
+            | Expression::TopicReference(_)
             | Expression::Identifier(_) => true,
             Expression::ChainExpression(chain_expression) => match &chain_expression.expression {
                 ChainElement::CallExpression(_) => false,
@@ -169,6 +170,12 @@ impl NoUnusedExpressions {
             Expression::TSAsExpression(ts_as_expression) => {
                 self.is_disallowed(&ts_as_expression.expression)
             }
+            Expression::FlowTypeCastExpression(type_cast_expression) => {
+                self.is_disallowed(&type_cast_expression.expression)
+            }
+            Expression::PipelineExpression(pipeline_expression) => {
+                self.is_disallowed(&pipeline_expression.body)
+            }
             Expression::TSTypeAssertion(ts_type_assertion) => {
                 self.is_disallowed(&ts_type_assertion.expression)
             }
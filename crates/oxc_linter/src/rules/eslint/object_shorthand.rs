@@ -433,7 +433,7 @@ fn check_consistency<'a>(
         ObjectPropertyKind::ObjectProperty(property) => {
             can_property_have_shorthand(property).then_some(property)
         }
-        ObjectPropertyKind::SpreadProperty(_) => None,
+        ObjectPropertyKind::SpreadProperty(_) | ObjectPropertyKind::ErrorNode(_) => None,
     });
 
     let properties_count = properties.clone().count();
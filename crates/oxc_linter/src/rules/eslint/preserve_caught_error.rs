@@ -145,7 +145,8 @@ impl<'a> Visit<'a> for ThrowFinder<'a, '_> {
                                 };
                                 ident.name == "cause"
                             }
-                            ObjectPropertyKind::SpreadProperty(_) => true,
+                            ObjectPropertyKind::SpreadProperty(_)
+                            | ObjectPropertyKind::ErrorNode(_) => true,
                         });
 
                         if let Some(cause_prop) = cause_prop {
@@ -223,7 +224,9 @@ fn has_cause_property(
                     return is_catch_parameter(&prop.value, catch_param, ctx);
                 }
             }
-            ObjectPropertyKind::SpreadProperty(_) => return true,
+            ObjectPropertyKind::SpreadProperty(_) | ObjectPropertyKind::ErrorNode(_) => {
+                return true;
+            }
         }
     }
     false
@@ -152,7 +152,7 @@ fn is_object_sorted(
 
     for (i, prop) in object.properties.iter().enumerate() {
         match prop {
-            ObjectPropertyKind::SpreadProperty(_) => {
+            ObjectPropertyKind::SpreadProperty(_) | ObjectPropertyKind::ErrorNode(_) => {
                 prev_key = None;
             }
             ObjectPropertyKind::ObjectProperty(obj) => {
@@ -214,7 +214,7 @@ fn count_static_groups(
 
     for (i, prop) in object.properties.iter().enumerate() {
         match prop {
-            ObjectPropertyKind::SpreadProperty(_) => {
+            ObjectPropertyKind::SpreadProperty(_) | ObjectPropertyKind::ErrorNode(_) => {
                 in_static_group = false;
             }
             ObjectPropertyKind::ObjectProperty(obj) => {
@@ -345,6 +345,7 @@ fn collect_fixable_properties<'a>(
                     SpreadPos::CanEnd => spread_pos = SpreadPos::End,
                 }
             }
+            ObjectPropertyKind::ErrorNode(_) => return None,
             ObjectPropertyKind::ObjectProperty(obj) => {
                 match spread_pos {
                     SpreadPos::Start => spread_pos = SpreadPos::CanEnd,
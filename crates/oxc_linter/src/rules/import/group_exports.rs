@@ -86,7 +86,7 @@ impl Rule for GroupExports {
                             modules_nodes.push(export_decl.span);
                         }
                     }
-                    ImportOrExportKind::Type => {
+                    ImportOrExportKind::Type | ImportOrExportKind::Typeof => {
                         if let Some(source) = &export_decl.source {
                             type_source_record
                                 .entry(source.value.to_string())
@@ -487,7 +487,7 @@ fn fix_spread_to_object_assign<'a>(
 
     for prop in &obj.properties {
         match prop {
-            ObjectPropertyKind::ObjectProperty(_) => {
+            ObjectPropertyKind::ObjectProperty(_) | ObjectPropertyKind::ErrorNode(_) => {
                 curr_obj_properties.push(prop.clone_in(&alloc));
             }
             ObjectPropertyKind::SpreadProperty(spread) => {
@@ -544,14 +544,16 @@ impl<'a, 'b> Spread<'a, 'b> {
 
     fn spread_spans(&self) -> Vec<Span> {
         match self {
-            Spread::Object(obj) => obj
-                .properties
-                .iter()
-                .filter_map(|prop| match prop {
-                    ObjectPropertyKind::SpreadProperty(spread) => Some(spread.span()),
-                    ObjectPropertyKind::ObjectProperty(_) => None,
-                })
-                .collect(),
+            Spread::Object(obj) => {
+                obj.properties
+                    .iter()
+                    .filter_map(|prop| match prop {
+                        ObjectPropertyKind::SpreadProperty(spread) => Some(spread.span()),
+                        ObjectPropertyKind::ObjectProperty(_)
+                        | ObjectPropertyKind::ErrorNode(_) => None,
+                    })
+                    .collect()
+            }
             Spread::Array(arr) => arr
                 .elements
                 .iter()
@@ -321,6 +321,7 @@ fn does_object_var_have_prop_name(
 
             does_object_var_have_prop_name(ctx, symbol, ident.name, prop_name)
         }
+        ObjectPropertyKind::ErrorNode(_) => false,
     })
 }
 
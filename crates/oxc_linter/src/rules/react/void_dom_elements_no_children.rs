@@ -138,7 +138,8 @@ impl Rule for VoidDomElementsNoChildren {
                             }
                             _ => false,
                         },
-                        ObjectPropertyKind::SpreadProperty(_) => false,
+                        ObjectPropertyKind::SpreadProperty(_)
+                        | ObjectPropertyKind::ErrorNode(_) => false,
                     });
 
                 if call_expr.arguments.get(2).is_some() || has_children_prop_or_danger {
@@ -1,6 +1,16 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_type_checker::{Type, TypeId, Types};
 
-use crate::rule::Rule;
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn await_thenable_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected `await` of a non-Promise (non-\"Thenable\") value.")
+        .with_help("Remove unnecessary `await`.")
+        .with_label(span)
+}
 
 #[derive(Debug, Default, Clone)]
 pub struct AwaitThenable;
@@ -54,4 +64,64 @@ declare_oxc_lint!(
     short_description = "This rule disallows awaiting a value that is not a Thenable.",
 );
 
-impl Rule for AwaitThenable {}
+impl Rule for AwaitThenable {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AwaitExpression(await_expr) = node.kind() else {
+            return;
+        };
+        let type_id = ctx.expression_type(&await_expr.argument);
+        if may_be_thenable(ctx.types(), type_id) {
+            return;
+        }
+        ctx.diagnostic_with_suggestion(await_thenable_diagnostic(await_expr.span), |fixer| {
+            fixer.delete_range(Span::new(await_expr.span.start, await_expr.argument.span().start))
+        });
+    }
+
+    fn is_native_type_aware(&self) -> bool {
+        true
+    }
+}
+
+/// Whether values of the type may be thenable, which includes types that are not known.
+fn may_be_thenable(types: &Types, type_id: TypeId) -> bool {
+    match types.get(type_id) {
+        Type::Any | Type::Unknown | Type::Never | Type::NonPrimitive => true,
+        Type::Union(members) => members.iter().any(|&member| may_be_thenable(types, member)),
+        _ => types.is_promise_like(type_id),
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async function f() { await Promise.resolve(1); }",
+        "async function f(p: Promise<number>) { await p; }",
+        "async function f() { await g(); } async function g() {}",
+        "async function f(value: number | Promise<number>) { await value; }",
+        "async function f(value: unknown) { await value; }",
+        "async function f(value: object) { await value; }",
+        "async function f(value: { then(): void }) { await value; }",
+        "async function f(value: Foo) { await value; }",
+        "async function f<T>(value: T) { await value; }",
+        "async function f<T extends Promise<number>>(value: T) { await value; }",
+    ];
+
+    let fail = vec![
+        "async function f() { await 1; }",
+        "async function f(value: string) { await value; }",
+        "async function f(value: number | undefined) { await value; }",
+        "async function f() { await { a: 1 }; }",
+        "async function f(value: { then: number }) { await value; }",
+        "async function f() { await g; } async function g() {}",
+        "async function f<T extends number>(value: T) { await value; }",
+    ];
+
+    let fix = vec![("async function f() { await 1; }", "async function f() { 1; }")];
+
+    Tester::new(AwaitThenable::NAME, AwaitThenable::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
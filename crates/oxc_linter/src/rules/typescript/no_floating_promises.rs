@@ -1,12 +1,35 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_type_checker::{Type, TypeId, Types};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    AstNode,
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
     utils::TypeOrValueSpecifier,
 };
 
+fn floating_promise_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Promises must be awaited, end with a call to .catch, end with a call to .then with a rejection handler or be explicitly marked as ignored with the `void` operator.")
+        .with_help("Add void operator to ignore.")
+        .with_label(span)
+}
+
+fn floating_promise_array_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("An array of Promises may be unintentional. Consider handling the promises' fulfillment or rejection with Promise.all or similar, or explicitly marking the expression as ignored with the `void` operator.")
+        .with_label(span)
+}
+
+/// How a floating expression is unhandled.
+enum Floating {
+    Promise,
+    PromiseArray,
+}
+
 #[derive(Debug, Default, Clone, Deserialize)]
 pub struct NoFloatingPromises(Box<NoFloatingPromisesConfig>);
 
@@ -113,6 +136,44 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoFloatingPromises {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(statement) = node.kind() else {
+            return;
+        };
+        let expr = statement.expression.get_inner_expression();
+        if self.0.ignore_iife && is_iife(expr) {
+            return;
+        }
+        match self.floating(ctx, expr) {
+            Some(Floating::Promise) => {
+                ctx.diagnostic_with_suggestion(
+                    floating_promise_diagnostic(statement.span),
+                    |fixer| {
+                        let text = fixer.source_range(statement.expression.span());
+                        if needs_parentheses(&statement.expression) {
+                            fixer.replace(statement.expression.span(), format!("void ({text})"))
+                        } else {
+                            fixer.replace(statement.expression.span(), format!("void {text}"))
+                        }
+                    },
+                );
+            }
+            Some(Floating::PromiseArray) => {
+                ctx.diagnostic(floating_promise_array_diagnostic(statement.span));
+            }
+            None => {}
+        }
+    }
+
+    /// Known safe calls and promises are specified by names which the checker does not record,
+    /// and `void` expressions are only ignored by default, so only the default configuration
+    /// (with any `checkThenables` and `ignoreIIFE`) can be checked in-process.
+    fn is_native_type_aware(&self) -> bool {
+        self.0.allow_for_known_safe_calls.is_empty()
+            && self.0.allow_for_known_safe_promises.is_empty()
+            && self.0.ignore_void
+    }
+
     fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
         serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
     }
@@ -122,6 +183,144 @@ impl Rule for NoFloatingPromises {
     }
 }
 
+impl NoFloatingPromises {
+    /// Whether `expr`, or one of the expressions it may evaluate to, is an unhandled promise.
+    fn floating(&self, ctx: &LintContext<'_>, expr: &Expression<'_>) -> Option<Floating> {
+        match expr.get_inner_expression() {
+            Expression::SequenceExpression(sequence) => {
+                sequence.expressions.iter().find_map(|expr| self.floating(ctx, expr))
+            }
+            Expression::ConditionalExpression(conditional) => self
+                .floating(ctx, &conditional.consequent)
+                .or_else(|| self.floating(ctx, &conditional.alternate)),
+            Expression::LogicalExpression(logical) => {
+                self.floating(ctx, &logical.left).or_else(|| self.floating(ctx, &logical.right))
+            }
+            // The promise is stored, and may be handled later
+            Expression::AssignmentExpression(_) => None,
+            expr => {
+                // The checker does not infer what the methods of a promise return
+                if let Expression::CallExpression(call) = expr
+                    && let Some(member) = call.callee.get_inner_expression().as_member_expression()
+                    && let Some(method @ ("then" | "catch" | "finally")) =
+                        member.static_property_name()
+                    && self.is_promise(ctx.types(), ctx.expression_type(member.object()))
+                {
+                    return match method {
+                        "then" if call.arguments.len() >= 2 => None,
+                        "catch" if !call.arguments.is_empty() => None,
+                        "finally" => self.floating(ctx, member.object()),
+                        _ => Some(Floating::Promise),
+                    };
+                }
+                let types = ctx.types();
+                let type_id = ctx.expression_type(expr);
+                if self.is_promise(types, type_id) {
+                    return Some(Floating::Promise);
+                }
+                let is_promise_array = match types.get(type_id) {
+                    Type::Array(element) => self.is_promise(types, *element),
+                    Type::Tuple(elements) => {
+                        elements.iter().any(|&element| self.is_promise(types, element))
+                    }
+                    _ => false,
+                };
+                is_promise_array.then_some(Floating::PromiseArray)
+            }
+        }
+    }
+
+    fn is_promise(&self, types: &Types, type_id: TypeId) -> bool {
+        if self.0.check_thenables {
+            return types.is_promise_like(type_id);
+        }
+        match types.get(type_id) {
+            Type::Promise(_) => true,
+            Type::Union(members) => {
+                members.iter().any(|&member| matches!(types.get(member), Type::Promise(_)))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Whether `expr` calls a function expression immediately, such as `(async () => {})()`.
+fn is_iife(expr: &Expression<'_>) -> bool {
+    let Expression::CallExpression(call) = expr else { return false };
+    matches!(
+        call.callee.get_inner_expression(),
+        Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_)
+    )
+}
+
+/// Whether `expr` binds less tightly than the operand of `void`.
+fn needs_parentheses(expr: &Expression<'_>) -> bool {
+    !matches!(
+        expr,
+        Expression::Identifier(_)
+            | Expression::CallExpression(_)
+            | Expression::NewExpression(_)
+            | Expression::AwaitExpression(_)
+            | Expression::UnaryExpression(_)
+            | Expression::ParenthesizedExpression(_)
+    ) && !expr.is_member_expression()
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("async function f() { await g(); } async function g() {}", None),
+        ("async function g() {} void g();", None),
+        ("async function g() {} g().catch(() => {});", None),
+        ("async function g() {} g().then(() => {}, () => {});", None),
+        ("async function g() {} g().catch(() => {}).finally(() => {});", None),
+        ("async function g() {} const p = g();", None),
+        ("async function g() {} let p; p = g();", None),
+        ("function f(p: Foo) { p; }", None),
+        ("function f(p: { then(): void }) { p; }", None),
+        ("(async () => {})();", Some(serde_json::json!([{ "ignoreIIFE": true }]))),
+        (
+            "async function g() {} g();",
+            Some(serde_json::json!([{ "allowForKnownSafeCalls": ["g"] }])),
+        ),
+        // The types of unresolved imports are not known, so the file is left to tsgolint
+        ("import { g } from './g'; g().then(() => {});", None),
+        ("import { h } from './h'; async function g() {} h(); g();", None),
+    ];
+
+    let fail = vec![
+        ("async function g() {} g();", None),
+        ("function f(p: Promise<number>) { p; }", None),
+        ("async function g() {} function f(a: boolean) { a ? g() : null; }", None),
+        ("async function g() {} function f(a: boolean) { a && g(); }", None),
+        ("async function g() {} 1, g();", None),
+        ("async function g() {} g().then(() => {});", None),
+        ("async function g() {} g().catch();", None),
+        ("async function g() {} g().finally(() => {});", None),
+        ("(async () => {})();", None),
+        ("async function g() {} [g(), g()];", None),
+        ("function f(p: Promise<number>[]) { p; }", None),
+        (
+            "function f(p: { then(): void }) { p; }",
+            Some(serde_json::json!([{ "checkThenables": true }])),
+        ),
+    ];
+
+    let fix = vec![
+        ("async function g() {} g();", "async function g() {} void g();"),
+        (
+            "async function g() {} function f(a: boolean) { a && g(); }",
+            "async function g() {} function f(a: boolean) { void (a && g()); }",
+        ),
+    ];
+
+    Tester::new(NoFloatingPromises::NAME, NoFloatingPromises::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,8 +1,28 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, LogicalOperator, UnaryOperator},
+};
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_type_checker::{Type, TypeId, Types};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::rule::{DefaultRuleConfig, Rule};
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn conditional_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected non-Promise value in a boolean conditional.").with_label(span)
+}
+
+fn spread_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected a non-Promise value to be spreaded in an object.")
+        .with_label(span)
+}
 
 fn default_checks_void_return() -> ChecksVoidReturn {
     ChecksVoidReturn::Boolean(true)
@@ -131,6 +151,70 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoMisusedPromises {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let config = &self.0;
+        match node.kind() {
+            AstKind::IfStatement(statement) if config.checks_conditionals => {
+                check_conditional(ctx, &statement.test, true);
+            }
+            AstKind::WhileStatement(statement) if config.checks_conditionals => {
+                check_conditional(ctx, &statement.test, true);
+            }
+            AstKind::DoWhileStatement(statement) if config.checks_conditionals => {
+                check_conditional(ctx, &statement.test, true);
+            }
+            AstKind::ForStatement(statement) if config.checks_conditionals => {
+                if let Some(test) = &statement.test {
+                    check_conditional(ctx, test, true);
+                }
+            }
+            AstKind::ConditionalExpression(conditional) if config.checks_conditionals => {
+                check_conditional(ctx, &conditional.test, true);
+            }
+            AstKind::LogicalExpression(logical)
+                if config.checks_conditionals && logical.operator != LogicalOperator::Coalesce =>
+            {
+                check_conditional(ctx, &logical.left, false);
+            }
+            AstKind::UnaryExpression(unary)
+                if config.checks_conditionals && unary.operator == UnaryOperator::LogicalNot =>
+            {
+                check_conditional(ctx, &unary.argument, true);
+            }
+            AstKind::SpreadElement(spread)
+                if config.checks_spreads
+                    && matches!(
+                        ctx.nodes().parent_kind(node.id()),
+                        AstKind::ObjectExpression(_)
+                    ) =>
+            {
+                let types = ctx.types();
+                let type_id = ctx.expression_type(&spread.argument);
+                if members(types, type_id).any(|member| types.is_promise_like(member)) {
+                    ctx.diagnostic(spread_diagnostic(spread.argument.span()));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Whether promise-returning functions are passed where void-returning ones are expected
+    /// depends on contextual and inherited types which the checker does not represent, so only
+    /// configurations without `checksVoidReturn` can be checked in-process.
+    fn is_native_type_aware(&self) -> bool {
+        match &self.0.checks_void_return {
+            ChecksVoidReturn::Boolean(checks) => !checks,
+            ChecksVoidReturn::Options(options) => {
+                !(options.arguments
+                    || options.attributes
+                    || options.inherited_methods
+                    || options.properties
+                    || options.returns
+                    || options.variables)
+            }
+        }
+    }
+
     fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
         serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
     }
@@ -139,3 +223,78 @@ impl Rule for NoMisusedPromises {
         Some(serde_json::to_value(&*self.0))
     }
 }
+
+/// Report `expr` if it is always a promise. Only the last operand of a chain of logical
+/// expressions decides a test, while the others are checked as operands of their own.
+fn check_conditional(ctx: &LintContext<'_>, expr: &Expression<'_>, is_test: bool) {
+    let expr = expr.get_inner_expression();
+    if let Expression::LogicalExpression(logical) = expr
+        && logical.operator != LogicalOperator::Coalesce
+    {
+        if is_test {
+            check_conditional(ctx, &logical.right, true);
+        }
+        return;
+    }
+    let types = ctx.types();
+    let type_id = ctx.expression_type(expr);
+    if members(types, type_id).all(|member| types.is_promise_like(member)) {
+        ctx.diagnostic(conditional_diagnostic(expr.span()));
+    }
+}
+
+/// The members of a union type, or the type itself.
+fn members(types: &Types, type_id: TypeId) -> impl Iterator<Item = TypeId> {
+    let members = match types.get(type_id) {
+        Type::Union(members) => members.to_vec(),
+        _ => vec![type_id],
+    };
+    members.into_iter()
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let config = || Some(serde_json::json!([{ "checksVoidReturn": false }]));
+
+    let pass = vec![
+        ("async function f() { if (await f()) {} }", config()),
+        ("function f(p: Promise<number> | undefined) { if (p) {} }", config()),
+        ("function f(p: Promise<number>) { p ?? 1; }", config()),
+        ("function f(p: Foo) { if (p) {} }", config()),
+        ("function f(p: { a: number }) { return { ...p }; }", config()),
+        ("function f(p: Promise<number>) { return [...p]; }", config()),
+        (
+            "function f(p: Promise<number>) { if (p) {} }",
+            Some(serde_json::json!([{ "checksVoidReturn": false, "checksConditionals": false }])),
+        ),
+        (
+            "function f(p: Promise<number>) { return { ...p }; }",
+            Some(serde_json::json!([{ "checksVoidReturn": false, "checksSpreads": false }])),
+        ),
+        // Checking void returns requires tsgolint
+        ("function f(p: Promise<number>) { if (p) {} }", None),
+    ];
+
+    let fail = vec![
+        ("function f(p: Promise<number>) { if (p) {} }", config()),
+        ("async function g() {} while (g()) {}", config()),
+        ("async function g() {} do {} while (g());", config()),
+        ("async function g() {} for (; g(); ) {}", config()),
+        ("async function g() {} const x = g() ? 1 : 2;", config()),
+        ("async function g() {} const x = !g();", config()),
+        ("async function g() {} function f(a: boolean) { if (a && g()) {} }", config()),
+        ("async function g() {} g() || 1;", config()),
+        ("function f(p: { then(): void }) { if (p) {} }", config()),
+        ("async function g() {} const x = { ...g() };", config()),
+        (
+            "function f(p: Promise<number> | undefined) { return { ...p }; }",
+            Some(
+                serde_json::json!([{ "checksVoidReturn": { "arguments": false, "attributes": false, "inheritedMethods": false, "properties": false, "returns": false, "variables": false } }]),
+            ),
+        ),
+    ];
+
+    Tester::new(NoMisusedPromises::NAME, NoMisusedPromises::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -1,8 +1,38 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator};
+use oxc_type_checker::{Type, TypeId, Types};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::rule::{DefaultRuleConfig, Rule};
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn bigint_and_number_diagnostic(span: Span, left: &str, right: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Numeric '+' operations must either be both bigints or both numbers. Got `{left}` + `{right}`."
+    ))
+    .with_label(span)
+}
+
+fn invalid_diagnostic(span: Span, string_like: &str, type_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Invalid operand for a '+' operation. Operands must each be a number or {string_like}. Got `{type_name}`."
+    ))
+    .with_label(span)
+}
+
+fn mismatched_diagnostic(span: Span, string_like: &str, left: &str, right: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Operands of '+' operations must be a number or {string_like}. Got `{left}` + `{right}`."
+    ))
+    .with_label(span)
+}
 
 #[derive(Debug, Default, Clone, Deserialize)]
 pub struct RestrictPlusOperands(Box<RestrictPlusOperandsConfig>);
@@ -98,6 +128,34 @@ declare_oxc_lint!(
 );
 
 impl Rule for RestrictPlusOperands {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (span, left, right) = match node.kind() {
+            AstKind::BinaryExpression(binary) if binary.operator == BinaryOperator::Addition => (
+                binary.span,
+                (binary.left.span(), ctx.expression_type(&binary.left)),
+                (binary.right.span(), ctx.expression_type(&binary.right)),
+            ),
+            AstKind::AssignmentExpression(assignment)
+                if assignment.operator == AssignmentOperator::Addition
+                    && !self.0.skip_compound_assignments =>
+            {
+                (
+                    assignment.span,
+                    (assignment.left.span(), ctx.assignment_target_type(&assignment.left)),
+                    (assignment.right.span(), ctx.expression_type(&assignment.right)),
+                )
+            }
+            _ => return,
+        };
+        self.check_operands(ctx, span, left, right);
+    }
+
+    /// `any` cannot be told apart from types the checker does not represent, which include
+    /// `RegExp`, so only the configurations which allow both can be checked in-process.
+    fn is_native_type_aware(&self) -> bool {
+        self.0.allow_any && self.0.allow_reg_exp
+    }
+
     fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
         serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
     }
@@ -106,3 +164,165 @@ impl Rule for RestrictPlusOperands {
         Some(serde_json::to_value(&*self.0))
     }
 }
+
+impl RestrictPlusOperands {
+    fn check_operands(
+        &self,
+        ctx: &LintContext<'_>,
+        span: Span,
+        (left_span, left): (Span, TypeId),
+        (right_span, right): (Span, TypeId),
+    ) {
+        let types = ctx.types();
+        let config = &self.0;
+        if left == right && some_member(types, left, |ty| is_number_like(ty) || is_string_like(ty))
+        {
+            return;
+        }
+
+        let string_like = self.string_like();
+        let mut had_individual_complaint = false;
+        for (operand_span, operand) in [(left_span, left), (right_span, right)] {
+            let is_invalid = some_member(types, operand, |ty| {
+                matches!(ty, Type::Symbol | Type::Never | Type::Unknown)
+                    || (!config.allow_boolean
+                        && matches!(ty, Type::Boolean | Type::BooleanLiteral(_)))
+            }) || (!config.allow_nullish
+                && matches!(types.get(operand), Type::Null | Type::Undefined));
+            if is_invalid {
+                ctx.diagnostic(invalid_diagnostic(
+                    operand_span,
+                    &string_like,
+                    &types.display(operand),
+                ));
+                had_individual_complaint = true;
+                continue;
+            }
+            for member in members(types, operand) {
+                if is_object(types.get(member)) {
+                    let type_name = types.display(member);
+                    ctx.diagnostic(invalid_diagnostic(operand_span, &string_like, &type_name));
+                    had_individual_complaint = true;
+                }
+            }
+        }
+        if had_individual_complaint {
+            return;
+        }
+
+        for (base, other) in [(left, right), (right, left)] {
+            if !config.allow_number_and_string
+                && some_member(types, base, is_string_like)
+                && some_member(types, other, |ty| is_number_like(ty) || is_bigint_like(ty))
+            {
+                let (left, right) = (types.display(left), types.display(right));
+                ctx.diagnostic(mismatched_diagnostic(span, &string_like, &left, &right));
+                return;
+            }
+            if some_member(types, base, is_number_like) && some_member(types, other, is_bigint_like)
+            {
+                let (left, right) = (types.display(left), types.display(right));
+                ctx.diagnostic(bigint_and_number_diagnostic(span, &left, &right));
+                return;
+            }
+        }
+    }
+
+    /// The types which may be added to a string, as listed by diagnostics.
+    fn string_like(&self) -> String {
+        let config = &self.0;
+        let allowed = [
+            (config.allow_any, "`any`"),
+            (config.allow_boolean, "`boolean`"),
+            (config.allow_nullish, "`null`"),
+            (config.allow_reg_exp, "`RegExp`"),
+            (config.allow_nullish, "`undefined`"),
+        ]
+        .into_iter()
+        .filter_map(|(allowed, name)| allowed.then_some(name))
+        .collect::<Vec<_>>();
+        match allowed.as_slice() {
+            [] => "string".to_string(),
+            [name] => format!("string, allowing a string + {name}"),
+            names => format!("string, allowing a string + any of: {}", names.join(", ")),
+        }
+    }
+}
+
+/// The members of a union type, or the type itself.
+fn members(types: &Types, type_id: TypeId) -> Vec<TypeId> {
+    match types.get(type_id) {
+        Type::Union(members) => members.to_vec(),
+        _ => vec![type_id],
+    }
+}
+
+fn some_member(types: &Types, type_id: TypeId, predicate: impl Fn(&Type) -> bool) -> bool {
+    members(types, type_id).into_iter().any(|member| predicate(types.get(member)))
+}
+
+fn is_number_like(ty: &Type) -> bool {
+    matches!(ty, Type::Number | Type::NumberLiteral(_))
+}
+
+fn is_bigint_like(ty: &Type) -> bool {
+    matches!(ty, Type::BigInt | Type::BigIntLiteral(_))
+}
+
+fn is_string_like(ty: &Type) -> bool {
+    matches!(ty, Type::String | Type::StringLiteral(_))
+}
+
+/// Whether values of the type are objects which are not strings when converted. Values of the
+/// `object` type may be anything, such as a `RegExp`.
+fn is_object(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Object(_) | Type::Array(_) | Type::Tuple(_) | Type::Function(_) | Type::Promise(_)
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("const x = 1 + 2;", None),
+        ("const x = 'a' + 'b';", None),
+        ("function f(a: number, b: string) { return a + b; }", None),
+        ("function f(a: string, b: boolean | null) { return a + b; }", None),
+        ("function f(a: bigint, b: bigint) { return a + b; }", None),
+        ("function f(a: Foo, b: number) { return a + b; }", None),
+        ("function f(a: object) { return a + ''; }", None),
+        ("let s = ''; s += 1;", None),
+        (
+            "function f(a: number, b: string) { return a + b; }",
+            Some(serde_json::json!([{ "allowAny": false }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("function f(a: number, b: bigint) { return a + b; }", None),
+        ("function f(a: symbol) { return a + ''; }", None),
+        ("function f(a: unknown) { return a + 1; }", None),
+        ("const x = [] + 1;", None),
+        ("const x = { a: 1 } + '';", None),
+        ("function f(a: string | (() => void)) { return a + ''; }", None),
+        ("let n = 1; n += 1n;", None),
+        (
+            "function f(a: number, b: string) { return a + b; }",
+            Some(serde_json::json!([{ "allowNumberAndString": false }])),
+        ),
+        (
+            "function f(a: string, b: boolean) { return a + b; }",
+            Some(serde_json::json!([{ "allowBoolean": false }])),
+        ),
+        (
+            "function f(a: string) { return a + null; }",
+            Some(serde_json::json!([{ "allowNullish": false }])),
+        ),
+    ];
+
+    Tester::new(RestrictPlusOperands::NAME, RestrictPlusOperands::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -509,6 +509,7 @@ fn expression_references_variable(expr: &Expression<'_>, var_name: &str) -> bool
             ObjectPropertyKind::SpreadProperty(s) => {
                 expression_references_variable(&s.argument, var_name)
             }
+            ObjectPropertyKind::ErrorNode(_) => false,
         }),
         Expression::ArrowFunctionExpression(arrow) => {
             // Check if any parameter shadows the variable name
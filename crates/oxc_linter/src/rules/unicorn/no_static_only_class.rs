@@ -92,6 +92,7 @@ impl Rule for NoStaticOnlyClass {
                 ClassElement::AccessorProperty(_)
                 | ClassElement::StaticBlock(_)
                 | ClassElement::TSIndexSignature(_)
+                | ClassElement::ErrorNode(_)
                  => {
                     return true;
                 }
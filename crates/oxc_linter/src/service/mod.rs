@@ -21,6 +21,10 @@ pub struct LintServiceOptions {
     tsconfig: Option<PathBuf>,
 
     cross_module: bool,
+
+    /// Parse with [`oxc_parser::ParseOptions::error_recovery`], so that files with syntax errors
+    /// are still linted, e.g. in the language server while a file is being edited.
+    error_recovery: bool,
 }
 
 impl LintServiceOptions {
@@ -29,7 +33,7 @@ impl LintServiceOptions {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), tsconfig: None, cross_module: false }
+        Self { cwd: cwd.into(), tsconfig: None, cross_module: false, error_recovery: false }
     }
 
     #[inline]
@@ -54,6 +58,17 @@ impl LintServiceOptions {
         self
     }
 
+    /// Lint files with syntax errors which the parser can recover from, reporting the syntax
+    /// errors along with the lint diagnostics.
+    ///
+    /// Default is `false`.
+    #[inline]
+    #[must_use]
+    pub fn with_error_recovery(mut self, error_recovery: bool) -> Self {
+        self.error_recovery = error_recovery;
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
@@ -46,6 +46,8 @@ pub struct Runtime {
     cwd: Box<Path>,
     pub(super) linter: Linter,
     resolver: Option<Resolver>,
+    /// Whether files are parsed with `ParseOptions::error_recovery`.
+    error_recovery: bool,
 
     /// Pool of allocators for parsing and linting.
     allocator_pool: AllocatorPool,
@@ -132,6 +134,9 @@ struct SectionContent<'a> {
     /// Parser tokens for the section.
     /// Empty if section parsing failed, or if token collection was not requested (no JS plugins).
     parser_tokens: ArenaBox<'a, [Token]>,
+    /// Syntax errors which the parser recovered from, in error recovery mode. They are reported
+    /// along with the lint diagnostics of the section.
+    parse_diagnostics: Vec<OxcDiagnostic>,
 }
 
 /// A module with its source text and semantic, ready to be linted.
@@ -258,6 +263,7 @@ impl Runtime {
             cwd: options.cwd,
             linter,
             resolver,
+            error_recovery: options.error_recovery,
             modules_by_path: papaya::HashMap::builder()
                 .hasher(BuildHasherDefault::default())
                 .resize_mode(papaya::ResizeMode::Blocking)
@@ -633,17 +639,28 @@ impl Runtime {
                             .into_iter()
                             .zip(dep.section_contents.drain(..))
                             .filter_map(|(record_result, section)| match record_result {
-                                Ok(module_record) => Some(ContextSubHost::new(
-                                    section.semantic.unwrap(),
-                                    Arc::clone(&module_record),
-                                    section.source.start,
-                                    ContextSubHostOptions {
-                                        framework_options: section.source.framework_options,
-                                        parser_tokens: section.parser_tokens,
-                                        respect_eslint_disable_directives,
-                                        ..Default::default()
-                                    },
-                                )),
+                                Ok(module_record) => {
+                                    if !section.parse_diagnostics.is_empty() {
+                                        let diagnostics = DiagnosticService::wrap_diagnostics(
+                                            &me.cwd,
+                                            path,
+                                            dep.source_text,
+                                            section.parse_diagnostics,
+                                        );
+                                        tx_error.send(diagnostics).unwrap();
+                                    }
+                                    Some(ContextSubHost::new(
+                                        section.semantic.unwrap(),
+                                        Arc::clone(&module_record),
+                                        section.source.start,
+                                        ContextSubHostOptions {
+                                            framework_options: section.source.framework_options,
+                                            parser_tokens: section.parser_tokens,
+                                            respect_eslint_disable_directives,
+                                            ..Default::default()
+                                        },
+                                    ))
+                                }
                                 Err(messages) => {
                                     if !messages.is_empty() {
                                         let diagnostics = DiagnosticService::wrap_diagnostics(
@@ -771,17 +788,28 @@ impl Runtime {
                                 .into_iter()
                                 .zip(section_contents.drain(..))
                                 .filter_map(|(record_result, section)| match record_result {
-                                    Ok(module_record) => Some(ContextSubHost::new(
-                                        section.semantic.unwrap(),
-                                        Arc::clone(&module_record),
-                                        section.source.start,
-                                        ContextSubHostOptions {
-                                            framework_options: section.source.framework_options,
-                                            parser_tokens: section.parser_tokens,
-                                            respect_eslint_disable_directives,
-                                            ..Default::default()
-                                        },
-                                    )),
+                                    Ok(module_record) => {
+                                        messages.lock().unwrap().extend(
+                                            section.parse_diagnostics.into_iter().map(
+                                                |diagnostic| {
+                                                    Message::new(diagnostic, PossibleFixes::None)
+                                                },
+                                            ),
+                                        );
+                                        Some(ContextSubHost::new(
+                                            section.semantic.unwrap(),
+                                            Arc::clone(&module_record),
+                                            section.source.start,
+                                            ContextSubHostOptions {
+                                                framework_options: section
+                                                    .source
+                                                    .framework_options,
+                                                parser_tokens: section.parser_tokens,
+                                                respect_eslint_disable_directives,
+                                                ..Default::default()
+                                            },
+                                        ))
+                                    }
                                     Err(diagnostics) => {
                                         if !diagnostics.is_empty() {
                                             messages.lock().unwrap().extend(
@@ -852,24 +880,23 @@ impl Runtime {
                                 section_contents.len()
                             );
 
-                            for (record_result, _section) in module_to_lint
+                            for (record_result, section) in module_to_lint
                                 .section_module_records
                                 .into_iter()
                                 .zip(section_contents.drain(..))
                             {
-                                match record_result {
-                                    Ok(_) => {}
-                                    Err(diagnostics) => {
-                                        if !diagnostics.is_empty() {
-                                            let wrapped = DiagnosticService::wrap_diagnostics(
-                                                &me.cwd,
-                                                Path::new(&module_to_lint.path),
-                                                source_text,
-                                                diagnostics,
-                                            );
-                                            tx_error.send(wrapped).unwrap();
-                                        }
-                                    }
+                                let diagnostics = match record_result {
+                                    Ok(_) => section.parse_diagnostics,
+                                    Err(diagnostics) => diagnostics,
+                                };
+                                if !diagnostics.is_empty() {
+                                    let wrapped = DiagnosticService::wrap_diagnostics(
+                                        &me.cwd,
+                                        Path::new(&module_to_lint.path),
+                                        source_text,
+                                        diagnostics,
+                                    );
+                                    tx_error.send(wrapped).unwrap();
                                 }
                             }
                         },
@@ -914,17 +941,24 @@ impl Runtime {
                             .into_iter()
                             .zip(section_contents.drain(..))
                             .filter_map(|(record_result, section)| match record_result {
-                                Ok(module_record) => Some(ContextSubHost::new(
-                                    section.semantic.unwrap(),
-                                    Arc::clone(&module_record),
-                                    section.source.start,
-                                    ContextSubHostOptions {
-                                        framework_options: section.source.framework_options,
-                                        parser_tokens: section.parser_tokens,
-                                        respect_eslint_disable_directives,
-                                        ..Default::default()
-                                    },
-                                )),
+                                Ok(module_record) => {
+                                    messages.lock().unwrap().extend(
+                                        section.parse_diagnostics.into_iter().map(|err| {
+                                            Message::new(err, PossibleFixes::None)
+                                        }),
+                                    );
+                                    Some(ContextSubHost::new(
+                                        section.semantic.unwrap(),
+                                        Arc::clone(&module_record),
+                                        section.source.start,
+                                        ContextSubHostOptions {
+                                            framework_options: section.source.framework_options,
+                                            parser_tokens: section.parser_tokens,
+                                            respect_eslint_disable_directives,
+                                            ..Default::default()
+                                        },
+                                    ))
+                                }
                                 Err(errors) => {
                                     if !errors.is_empty() {
                                         messages.lock().unwrap().extend(
@@ -1064,13 +1098,24 @@ impl Runtime {
         allocator: &'a Allocator,
         mut out_sections: Option<&mut SectionContents<'a>>,
     ) -> SmallVec<[Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1]> {
-        let section_sources = PartialLoader::parse(ext, source_text)
+        let section_sources = PartialLoader::parse(ext, source_text, allocator)
             .unwrap_or_else(|| vec![JavaScriptSource::partial(source_text, source_type, 0)]);
 
         let mut section_module_records = SmallVec::<
             [Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1],
         >::with_capacity(section_sources.len());
         for section_source in section_sources {
+            let with_section_offset = |diagnostics: Vec<OxcDiagnostic>| {
+                diagnostics
+                    .into_iter()
+                    .map(|mut diagnostic| {
+                        for label in &mut diagnostic.labels {
+                            label.set_span_offset(label.offset() + section_source.start);
+                        }
+                        diagnostic
+                    })
+                    .collect::<Vec<_>>()
+            };
             match self.process_source_section(
                 path,
                 allocator,
@@ -1078,33 +1123,25 @@ impl Runtime {
                 section_source.source_type,
                 check_syntax_errors,
             ) {
-                Ok((record, semantic, parser_tokens)) => {
+                Ok((record, semantic, parser_tokens, parse_diagnostics)) => {
                     section_module_records.push(Ok(record));
                     if let Some(sections) = &mut out_sections {
                         sections.push(SectionContent {
                             source: section_source,
                             semantic: Some(semantic),
                             parser_tokens,
+                            parse_diagnostics: with_section_offset(parse_diagnostics),
                         });
                     }
                 }
                 Err(err) => {
-                    let err: Vec<OxcDiagnostic> = err
-                        .into_iter()
-                        .map(|mut diagnostic| {
-                            for label in &mut diagnostic.labels {
-                                label.set_span_offset(label.offset() + section_source.start);
-                            }
-                            diagnostic
-                        })
-                        .collect();
-
-                    section_module_records.push(Err(err));
+                    section_module_records.push(Err(with_section_offset(err)));
                     if let Some(sections) = &mut out_sections {
                         sections.push(SectionContent {
                             source: section_source,
                             semantic: None,
                             parser_tokens: ArenaBox::new_empty_boxed_slice(),
+                            parse_diagnostics: vec![],
                         });
                     }
                 }
@@ -1121,34 +1158,59 @@ impl Runtime {
         source_text: &'a str,
         source_type: SourceType,
         check_syntax_errors: bool,
-    ) -> Result<(ResolvedModuleRecord, Semantic<'a>, ArenaBox<'a, [Token]>), Vec<OxcDiagnostic>>
-    {
+    ) -> Result<
+        (ResolvedModuleRecord, Semantic<'a>, ArenaBox<'a, [Token]>, Vec<OxcDiagnostic>),
+        Vec<OxcDiagnostic>,
+    > {
         let collect_tokens = self.linter.has_external_linter();
-        let ret = Parser::new(allocator, source_text, source_type)
-            .with_options(ParseOptions {
-                parse_regular_expression: true,
-                allow_return_outside_function: true,
-                ..ParseOptions::default()
-            })
-            .with_config(RuntimeParserConfig::new(collect_tokens))
-            .parse();
-
-        if !ret.diagnostics.is_empty() {
-            return Err(if ret.is_flow_language { vec![] } else { ret.diagnostics.into() });
+        let parse = |source_type: SourceType| {
+            Parser::new(allocator, source_text, source_type)
+                .with_options(ParseOptions {
+                    parse_regular_expression: true,
+                    allow_return_outside_function: true,
+                    error_recovery: self.error_recovery,
+                    ..ParseOptions::default()
+                })
+                .with_config(RuntimeParserConfig::new(collect_tokens))
+                .parse()
+        };
+        let mut ret = parse(source_type);
+
+        // A file with a `@flow` pragma which failed to parse as JavaScript is parsed again as Flow,
+        // and syntax errors of the Flow parse are reported
+        if ret.is_flow_language {
+            ret = parse(source_type.with_flow(true));
         }
 
+        // In error recovery mode, a program which the parser recovered is linted despite its
+        // syntax errors
+        let parse_diagnostics = if ret.diagnostics.is_empty() {
+            vec![]
+        } else if self.error_recovery && !ret.panicked {
+            ret.diagnostics.into()
+        } else {
+            return Err(ret.diagnostics.into());
+        };
+
         let semantic_ret = SemanticBuilder::new_linter()
             .with_check_syntax_error(check_syntax_errors)
             .build(allocator.alloc(ret.program));
 
         if !semantic_ret.diagnostics.is_empty() {
-            return Err(semantic_ret.diagnostics.into());
+            let mut diagnostics = parse_diagnostics;
+            diagnostics.extend(semantic_ret.diagnostics);
+            return Err(diagnostics);
         }
 
         let mut semantic = semantic_ret.semantic;
         semantic.set_irregular_whitespaces(ret.irregular_whitespaces);
 
-        let module_record = Arc::new(ModuleRecord::new(path, &ret.module_record, &semantic));
+        let mut module_record = ModuleRecord::new(path, &ret.module_record);
+        // Symbols are only looked up across modules, which are only linked for the import plugin
+        if self.resolver.is_some() {
+            module_record.collect_symbols(&semantic);
+        }
+        let module_record = Arc::new(module_record);
 
         let tokens = ret.tokens.into_boxed_slice();
 
@@ -1169,6 +1231,11 @@ impl Runtime {
                 })
                 .collect();
         }
-        Ok((ResolvedModuleRecord { module_record, resolved_module_requests }, semantic, tokens))
+        Ok((
+            ResolvedModuleRecord { module_record, resolved_module_requests },
+            semantic,
+            tokens,
+            parse_diagnostics,
+        ))
     }
 }
@@ -0,0 +1,460 @@
+//! Project-wide index of exported symbols.
+//!
+//! [`SymbolIndex`] links the import bindings of each module to the symbol that is ultimately
+//! exported, following re-exports and `export *`, and finds every reference to an exported
+//! symbol across the module graph. The module records must be linked
+//! ([`ModuleRecord::write_loaded_modules`]) and have their symbols collected
+//! ([`ModuleRecord::collect_symbols`]), which the lint service does when the `import` plugin is
+//! enabled.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_semantic::SymbolId;
+use oxc_span::Span;
+use oxc_str::CompactStr;
+
+use crate::module_record::{
+    ExportEntry, ExportExportName, ExportImportName, ImportImportName, ModuleRecord, NameSpan,
+};
+
+/// A symbol declared at the top level of a module.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ModuleSymbol {
+    pub path: PathBuf,
+    pub symbol_id: SymbolId,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolReferenceKind {
+    /// A reference within the declaring module, or to an import binding in another module.
+    Reference,
+    /// The name in an import specifier, e.g. `a` in `import { a as b } from "./a"`.
+    Import,
+    /// The name in a re-export, e.g. `a` in `export { a } from "./a"`.
+    Export,
+    /// A member access of a namespace import, e.g. `a` in `ns.a`.
+    NamespaceMember,
+}
+
+/// A location in the module graph that refers to a [`ModuleSymbol`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolReference {
+    pub path: PathBuf,
+    pub span: Span,
+    pub kind: SymbolReferenceKind,
+}
+
+/// Cross-module symbol lookups over a set of linked [`ModuleRecord`]s.
+#[derive(Debug, Default)]
+pub struct SymbolIndex {
+    modules: FxHashMap<PathBuf, Arc<ModuleRecord>>,
+}
+
+impl SymbolIndex {
+    /// Indexes `modules` and every module reachable from them.
+    pub fn new(modules: impl IntoIterator<Item = Arc<ModuleRecord>>) -> Self {
+        let mut index = Self::default();
+        let mut stack = modules.into_iter().collect::<Vec<_>>();
+        while let Some(module) = stack.pop() {
+            if index.modules.contains_key(&module.resolved_absolute_path) {
+                continue;
+            }
+            stack.extend(module.loaded_modules().values().filter_map(std::sync::Weak::upgrade));
+            index.modules.insert(module.resolved_absolute_path.clone(), module);
+        }
+        index
+    }
+
+    pub fn module(&self, path: &Path) -> Option<&Arc<ModuleRecord>> {
+        self.modules.get(path)
+    }
+
+    /// Resolves `export_name` of the module at `path` to the symbol that declares it.
+    ///
+    /// Returns `None` if the export does not exist, or is not backed by a symbol, such as
+    /// `export default 1` or `export * as ns from "./a"`.
+    pub fn resolve_export(&self, path: &Path, export_name: &str) -> Option<ModuleSymbol> {
+        self.resolve_export_impl(path, export_name, &mut FxHashSet::default())
+    }
+
+    /// Resolves the import binding `symbol_id` of the module at `path` to the symbol that is
+    /// exported for it.
+    ///
+    /// Returns `None` for namespace imports, and imports that cannot be resolved.
+    pub fn resolve_import(&self, path: &Path, symbol_id: SymbolId) -> Option<ModuleSymbol> {
+        self.resolve_import_impl(path, symbol_id, &mut FxHashSet::default())
+    }
+
+    /// Resolves a symbol declared in or imported into the module at `path` to the symbol that
+    /// declares it.
+    pub fn resolve(&self, path: &Path, symbol_id: SymbolId) -> Option<ModuleSymbol> {
+        let module = self.modules.get(path)?;
+        if module.imported_symbols.contains_key(&symbol_id) {
+            self.resolve_import(path, symbol_id)
+        } else {
+            Some(ModuleSymbol { path: path.to_path_buf(), symbol_id })
+        }
+    }
+
+    /// Finds every reference to `symbol` across the indexed modules, sorted by path and
+    /// position. The declaration itself is not included.
+    pub fn references(&self, symbol: &ModuleSymbol) -> Vec<SymbolReference> {
+        let mut references = vec![];
+        let mut push = |path: &Path, span: Span, kind: SymbolReferenceKind| {
+            references.push(SymbolReference { path: path.to_path_buf(), span, kind });
+        };
+
+        if let Some(module) = self.modules.get(&symbol.path) {
+            for &span in module.symbol_references.get(&symbol.symbol_id).into_iter().flatten() {
+                push(&symbol.path, span, SymbolReferenceKind::Reference);
+            }
+        }
+
+        for (path, module) in &self.modules {
+            for (&symbol_id, &index) in &module.imported_symbols {
+                let import_entry = &module.import_entries[index];
+                if import_entry.import_name.is_namespace_object() {
+                    // `ns.a`
+                    let members = module.namespace_member_references.get(&symbol_id);
+                    for member in members.into_iter().flatten() {
+                        let resolved = self
+                            .get_loaded_module(module, import_entry.module_request.name())
+                            .and_then(|remote| {
+                                self.resolve_export(&remote.resolved_absolute_path, member.name())
+                            });
+                        if resolved.as_ref() == Some(symbol) {
+                            push(path, member.span, SymbolReferenceKind::NamespaceMember);
+                        }
+                    }
+                    continue;
+                }
+                if self.resolve_import(path, symbol_id).as_ref() != Some(symbol) {
+                    continue;
+                }
+                let import_span = match &import_entry.import_name {
+                    ImportImportName::Name(name) => name.span,
+                    ImportImportName::Default(span) => *span,
+                    ImportImportName::NamespaceObject => unreachable!(),
+                };
+                push(path, import_span, SymbolReferenceKind::Import);
+                for &span in module.symbol_references.get(&symbol_id).into_iter().flatten() {
+                    push(path, span, SymbolReferenceKind::Reference);
+                }
+            }
+
+            // `export { a } from "./a"`
+            for export_entry in &module.indirect_export_entries {
+                let ExportImportName::Name(name) = &export_entry.import_name else { continue };
+                let Some(import_name) = indirect_import_name(module, export_entry) else {
+                    continue;
+                };
+                let Some(module_request) = &export_entry.module_request else { continue };
+                let resolved =
+                    self.get_loaded_module(module, module_request.name()).and_then(|remote| {
+                        self.resolve_export(&remote.resolved_absolute_path, import_name)
+                    });
+                if resolved.as_ref() == Some(symbol) {
+                    push(path, name.span, SymbolReferenceKind::Export);
+                }
+            }
+        }
+
+        references.sort_by(|a, b| a.path.cmp(&b.path).then(a.span.start.cmp(&b.span.start)));
+        references.dedup();
+        references
+    }
+
+    /// Exports of the module at `path` that are not imported or re-exported by any indexed
+    /// module, by name and span. Namespace imports and `export *` of the module count as using
+    /// all of its exports.
+    pub fn unused_exports(&self, path: &Path) -> Vec<NameSpan> {
+        let Some(module) = self.modules.get(path) else { return vec![] };
+
+        let mut used: FxHashSet<CompactStr> = FxHashSet::default();
+        for importer in self.modules.values() {
+            let requests_module = |request: &str| {
+                self.get_loaded_module(importer, request)
+                    .is_some_and(|remote| remote.resolved_absolute_path == path)
+            };
+            for import_entry in &importer.import_entries {
+                if !requests_module(import_entry.module_request.name()) {
+                    continue;
+                }
+                match &import_entry.import_name {
+                    ImportImportName::Name(name) => {
+                        used.insert(name.name.clone());
+                    }
+                    ImportImportName::Default(_) => {
+                        used.insert(CompactStr::new("default"));
+                    }
+                    ImportImportName::NamespaceObject => return vec![],
+                }
+            }
+            let export_entries =
+                importer.indirect_export_entries.iter().chain(&importer.star_export_entries);
+            for export_entry in export_entries {
+                let Some(module_request) = &export_entry.module_request else { continue };
+                if !requests_module(module_request.name()) {
+                    continue;
+                }
+                match &export_entry.import_name {
+                    ExportImportName::Name(name) => {
+                        used.insert(name.name.clone());
+                    }
+                    ExportImportName::All | ExportImportName::AllButDefault => return vec![],
+                    ExportImportName::Null => {}
+                }
+            }
+        }
+
+        module
+            .local_export_entries
+            .iter()
+            .chain(&module.indirect_export_entries)
+            .filter_map(|export_entry| match &export_entry.export_name {
+                ExportExportName::Name(name) => Some(name.clone()),
+                ExportExportName::Default(span) => {
+                    Some(NameSpan::new(CompactStr::new("default"), *span))
+                }
+                ExportExportName::Null => None,
+            })
+            .filter(|name| !used.contains(&name.name))
+            .collect()
+    }
+
+    fn resolve_export_impl(
+        &self,
+        path: &Path,
+        export_name: &str,
+        visited: &mut FxHashSet<(PathBuf, CompactStr)>,
+    ) -> Option<ModuleSymbol> {
+        if !visited.insert((path.to_path_buf(), CompactStr::from(export_name))) {
+            return None;
+        }
+        let module = self.modules.get(path)?;
+
+        if let Some(&symbol_id) = module.exported_symbols.get(export_name) {
+            // `import a from "./a"; export default a`
+            if module.imported_symbols.contains_key(&symbol_id) {
+                return self.resolve_import_impl(path, symbol_id, visited);
+            }
+            return Some(ModuleSymbol { path: path.to_path_buf(), symbol_id });
+        }
+
+        for export_entry in &module.indirect_export_entries {
+            let ExportExportName::Name(name) = &export_entry.export_name else { continue };
+            if name.name() != export_name {
+                continue;
+            }
+            let import_name = indirect_import_name(module, export_entry)?;
+            let remote =
+                self.get_loaded_module(module, export_entry.module_request.as_ref()?.name())?;
+            return self.resolve_export_impl(&remote.resolved_absolute_path, import_name, visited);
+        }
+
+        if export_name == "default" {
+            return None;
+        }
+        module.star_export_entries.iter().find_map(|export_entry| {
+            let remote =
+                self.get_loaded_module(module, export_entry.module_request.as_ref()?.name())?;
+            self.resolve_export_impl(&remote.resolved_absolute_path, export_name, visited)
+        })
+    }
+
+    fn resolve_import_impl(
+        &self,
+        path: &Path,
+        symbol_id: SymbolId,
+        visited: &mut FxHashSet<(PathBuf, CompactStr)>,
+    ) -> Option<ModuleSymbol> {
+        let module = self.modules.get(path)?;
+        let import_entry = &module.import_entries[*module.imported_symbols.get(&symbol_id)?];
+        let import_name = match &import_entry.import_name {
+            ImportImportName::Name(name) => name.name(),
+            ImportImportName::Default(_) => "default",
+            ImportImportName::NamespaceObject => return None,
+        };
+        let remote = self.get_loaded_module(module, import_entry.module_request.name())?;
+        self.resolve_export_impl(&remote.resolved_absolute_path, import_name, visited)
+    }
+
+    fn get_loaded_module(
+        &self,
+        module: &ModuleRecord,
+        specifier: &str,
+    ) -> Option<&Arc<ModuleRecord>> {
+        let remote = module.get_loaded_module(specifier)?;
+        self.modules.get(&remote.resolved_absolute_path)
+    }
+}
+
+/// The name imported from the requested module by an indirect export entry.
+///
+/// `import d from "./a"; export { d }` is recorded with the local name `d` as its import name,
+/// so that is mapped back to `default`.
+fn indirect_import_name<'m>(
+    module: &'m ModuleRecord,
+    export_entry: &'m ExportEntry,
+) -> Option<&'m str> {
+    let ExportImportName::Name(name) = &export_entry.import_name else { return None };
+    let is_default_import = module.import_entries.iter().any(|import_entry| {
+        import_entry.import_name.is_default() && import_entry.local_name.span == name.span
+    });
+    Some(if is_default_import { "default" } else { name.name() })
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::{SemanticBuilder, SymbolId};
+    use oxc_span::SourceType;
+
+    use super::{ModuleSymbol, SymbolIndex, SymbolReferenceKind};
+    use crate::module_record::ModuleRecord;
+
+    /// Builds an index of `files`, where `./name` resolves to `/name.js`.
+    fn build(files: &[(&str, &str)]) -> SymbolIndex {
+        let records = files
+            .iter()
+            .map(|(path, source_text)| {
+                let allocator = Allocator::default();
+                let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+                let semantic =
+                    SemanticBuilder::new().with_build_nodes(true).build(&ret.program).semantic;
+                let mut record = ModuleRecord::new(Path::new(path), &ret.module_record);
+                record.collect_symbols(&semantic);
+                Arc::new(record)
+            })
+            .collect::<Vec<_>>();
+        for record in &records {
+            let mut loaded_modules = record.write_loaded_modules();
+            for specifier in record.requested_modules.keys() {
+                let path = PathBuf::from(format!("/{}.js", specifier.trim_start_matches("./")));
+                if let Some(remote) = records.iter().find(|r| r.resolved_absolute_path == path) {
+                    loaded_modules.insert(specifier.clone(), Arc::downgrade(remote));
+                }
+            }
+        }
+        SymbolIndex::new(records)
+    }
+
+    fn imported(index: &SymbolIndex, path: &str, local_name: &str) -> SymbolId {
+        let module = index.module(Path::new(path)).unwrap();
+        let (&symbol_id, _) = module
+            .imported_symbols
+            .iter()
+            .find(|&(_, &i)| module.import_entries[i].local_name.name() == local_name)
+            .unwrap();
+        symbol_id
+    }
+
+    fn exported(index: &SymbolIndex, path: &str, export_name: &str) -> ModuleSymbol {
+        let module = index.module(Path::new(path)).unwrap();
+        ModuleSymbol { path: PathBuf::from(path), symbol_id: module.exported_symbols[export_name] }
+    }
+
+    #[test]
+    fn resolve_through_reexports() {
+        let index = build(&[
+            ("/a.js", "export const foo = 1; export default function bar() {}"),
+            ("/b.js", "export { foo as renamed } from './a'; export * from './a';"),
+            ("/c.js", "import { default as baz } from './b'; import * as ns from './a';"),
+            ("/d.js", "import { renamed, foo } from './b'; import bar from './a'; export { bar };"),
+            ("/e.js", "import { bar } from './d';"),
+        ]);
+        let foo = exported(&index, "/a.js", "foo");
+        let bar = exported(&index, "/a.js", "default");
+
+        let renamed = imported(&index, "/d.js", "renamed");
+        assert_eq!(index.resolve_import(Path::new("/d.js"), renamed), Some(foo.clone()));
+        let foo_import = imported(&index, "/d.js", "foo");
+        assert_eq!(index.resolve(Path::new("/d.js"), foo_import), Some(foo));
+        let bar_import = imported(&index, "/e.js", "bar");
+        assert_eq!(index.resolve_import(Path::new("/e.js"), bar_import), Some(bar));
+
+        // `export *` does not re-export `default`, and namespaces have no symbol.
+        let baz = imported(&index, "/c.js", "baz");
+        assert_eq!(index.resolve_import(Path::new("/c.js"), baz), None);
+        let ns = imported(&index, "/c.js", "ns");
+        assert_eq!(index.resolve_import(Path::new("/c.js"), ns), None);
+    }
+
+    #[test]
+    fn cyclic_star_exports() {
+        let index = build(&[
+            ("/a.js", "export * from './b'; export const foo = 1;"),
+            ("/b.js", "export * from './a';"),
+        ]);
+        assert_eq!(index.resolve_export(Path::new("/b.js"), "missing"), None);
+        assert_eq!(
+            index.resolve_export(Path::new("/b.js"), "foo"),
+            Some(exported(&index, "/a.js", "foo"))
+        );
+    }
+
+    #[test]
+    fn references_across_modules() {
+        let index = build(&[
+            ("/a.js", "export const foo = 1; foo;"),
+            ("/b.js", "export { foo as renamed } from './a'; export * from './a';"),
+            (
+                "/c.js",
+                "import { renamed, foo } from './b'; import * as ns from './b';
+                 renamed; foo + foo; ns.foo; ns['foo']; ns.renamed; ns.other;",
+            ),
+        ]);
+        let foo = exported(&index, "/a.js", "foo");
+        let references = index.references(&foo);
+        let summary = references
+            .iter()
+            .map(|reference| (reference.path.to_str().unwrap(), reference.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                ("/a.js", SymbolReferenceKind::Reference),
+                ("/b.js", SymbolReferenceKind::Export),
+                ("/c.js", SymbolReferenceKind::Import),
+                ("/c.js", SymbolReferenceKind::Import),
+                ("/c.js", SymbolReferenceKind::Reference),
+                ("/c.js", SymbolReferenceKind::Reference),
+                ("/c.js", SymbolReferenceKind::Reference),
+                ("/c.js", SymbolReferenceKind::NamespaceMember),
+                ("/c.js", SymbolReferenceKind::NamespaceMember),
+                ("/c.js", SymbolReferenceKind::NamespaceMember),
+            ]
+        );
+    }
+
+    #[test]
+    fn unused_exports() {
+        let index = build(&[
+            ("/a.js", "export const used = 1; export const unused = 2; export default 3;"),
+            ("/b.js", "import { used } from './a'; export { x } from './c'; export const y = 1;"),
+            ("/c.js", "export const x = 1;"),
+            ("/d.js", "import * as ns from './b';"),
+        ]);
+        let names = |path: &str| {
+            index
+                .unused_exports(Path::new(path))
+                .into_iter()
+                .map(|name| name.name.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names("/a.js"), ["unused", "default"]);
+        assert!(names("/b.js").is_empty());
+        assert!(names("/c.js").is_empty());
+    }
+}
@@ -663,7 +663,8 @@ impl Tester {
             ),
             None,
         )
-        .with_fix(fix_kind.into());
+        .with_fix(fix_kind.into())
+        .with_type_aware(true);
 
         let path_to_lint = if self.plugins.has_import() {
             assert!(path.is_none(), "import plugin does not support path");
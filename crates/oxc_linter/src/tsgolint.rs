@@ -10,7 +10,7 @@ use std::{
 };
 
 use oxc_allocator::Allocator;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
 
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic, Severity};
@@ -26,8 +26,9 @@ use crate::{
 /// State required to initialize the `tsgolint` linter.
 #[derive(Debug, Clone)]
 pub struct TsGoLintState {
-    /// The path to the `tsgolint` executable (at least our best guess at it).
-    executable_path: PathBuf,
+    /// The path to the `tsgolint` executable (at least our best guess at it), or why it could not
+    /// be found, which is only an error once a file is sent to `tsgolint`.
+    executable_path: Result<PathBuf, String>,
     /// Current working directory, used for rendering paths in diagnostics.
     cwd: PathBuf,
     /// The configuration store for `tsgolint` (used to resolve configurations outside of `oxc_linter`)
@@ -43,23 +44,15 @@ pub struct TsGoLintState {
     type_check: bool,
     /// If `true`, request that per-rule debug timings be returned from `tsgolint`.
     timings: bool,
+    /// Files whose type-aware rules already ran in-process, which are not sent to `tsgolint`.
+    native_type_aware_files: Arc<Mutex<FxHashSet<PathBuf>>>,
 }
 
 impl TsGoLintState {
     pub fn new(cwd: &Path, config_store: ConfigStore, fix_kind: FixKind) -> Self {
         let executable_path =
             try_find_tsgolint_executable(cwd).unwrap_or(PathBuf::from("tsgolint"));
-
-        TsGoLintState {
-            config_store,
-            executable_path,
-            cwd: cwd.to_path_buf(),
-            silent: false,
-            fix: fix_kind.contains(FixKind::Fix),
-            fix_suggestions: fix_kind.contains(FixKind::Suggestion),
-            type_check: false,
-            timings: false,
-        }
+        Self::with_executable_path(cwd, config_store, fix_kind, Ok(executable_path))
     }
 
     /// Try to create a new TsGoLintState, returning an error if the executable cannot be found.
@@ -72,8 +65,24 @@ impl TsGoLintState {
         fix_kind: FixKind,
     ) -> Result<Self, String> {
         let executable_path = try_find_tsgolint_executable(cwd)?;
+        Ok(Self::with_executable_path(cwd, config_store, fix_kind, Ok(executable_path)))
+    }
+
+    /// Create a new TsGoLintState which only needs the executable once a file is sent to
+    /// `tsgolint`, for when the type-aware rules may all run in-process. Linting returns an error
+    /// if a file falls back to `tsgolint` and the executable cannot be found.
+    pub fn new_deferred(cwd: &Path, config_store: ConfigStore, fix_kind: FixKind) -> Self {
+        let executable_path = try_find_tsgolint_executable(cwd);
+        Self::with_executable_path(cwd, config_store, fix_kind, executable_path)
+    }
 
-        Ok(TsGoLintState {
+    fn with_executable_path(
+        cwd: &Path,
+        config_store: ConfigStore,
+        fix_kind: FixKind,
+        executable_path: Result<PathBuf, String>,
+    ) -> Self {
+        TsGoLintState {
             config_store,
             executable_path,
             cwd: cwd.to_path_buf(),
@@ -82,7 +91,8 @@ impl TsGoLintState {
             fix_suggestions: fix_kind.contains(FixKind::Suggestion),
             type_check: false,
             timings: false,
-        })
+            native_type_aware_files: Arc::default(),
+        }
     }
 
     /// Set to `true` to skip file system reads.
@@ -113,6 +123,19 @@ impl TsGoLintState {
         self
     }
 
+    /// Set the files whose type-aware rules ran in-process, see `Linter::native_type_aware_files`.
+    /// Only the type-aware rules which cannot run in-process are sent to `tsgolint` for them.
+    ///
+    /// Default is no files.
+    #[must_use]
+    pub fn with_native_type_aware_files(
+        mut self,
+        native_type_aware_files: Arc<Mutex<FxHashSet<PathBuf>>>,
+    ) -> Self {
+        self.native_type_aware_files = native_type_aware_files;
+        self
+    }
+
     /// # Panics
     /// - when `stdin` of subprocess cannot be opened
     /// - when `stdout` of subprocess cannot be opened
@@ -332,7 +355,8 @@ impl TsGoLintState {
 
     /// Spawn the tsgolint process with the given input.
     fn spawn_tsgolint(&self, json_input: &Payload) -> Result<std::process::Child, String> {
-        let mut cmd = std::process::Command::new(&self.executable_path);
+        let executable_path = self.executable_path.as_ref().map_err(Clone::clone)?;
+        let mut cmd = std::process::Command::new(executable_path);
         cmd.arg("headless")
             .stdin(std::process::Stdio::piped())
             .stdout(std::process::Stdio::piped())
@@ -368,7 +392,7 @@ impl TsGoLintState {
             Err(e) => {
                 return Err(format!(
                     "Failed to spawn tsgolint from path `{}`, with error: {e}",
-                    self.executable_path.display()
+                    executable_path.display()
                 ));
             }
         };
@@ -569,11 +593,14 @@ impl TsGoLintState {
         resolved_configs: &mut FxHashMap<PathBuf, ResolvedLinterState>,
     ) -> Payload {
         let mut config_groups: FxHashMap<BTreeSet<Rule>, Vec<String>> = FxHashMap::default();
+        let native_type_aware_files =
+            self.native_type_aware_files.lock().expect("native_type_aware_files mutex poisoned");
 
         for path in paths {
             if SourceType::from_path(Path::new(path)).is_ok() {
                 let path_buf = PathBuf::from(path);
                 let file_path = path.to_string_lossy().to_string();
+                let is_native_type_aware_file = native_type_aware_files.contains(&path_buf);
 
                 let resolved_config = resolved_configs
                     .entry(path_buf.clone())
@@ -583,7 +610,11 @@ impl TsGoLintState {
                     .rules
                     .iter()
                     .filter_map(|(rule, status)| {
-                        if status.is_warn_deny() && rule.is_tsgolint_rule() {
+                        // Rules which already ran in-process for this file are not sent to tsgolint
+                        if status.is_warn_deny()
+                            && rule.is_tsgolint_rule()
+                            && !(is_native_type_aware_file && rule.is_native_type_aware())
+                        {
                             let rule_name = rule.name().to_string();
                             let options = match rule.to_configuration() {
                                 Some(Ok(config)) => Some(config),
@@ -595,6 +626,10 @@ impl TsGoLintState {
                         }
                     })
                     .collect();
+                // Files without rules for tsgolint are only sent to it for type checking
+                if rules.is_empty() && !self.type_check {
+                    continue;
+                }
 
                 config_groups.entry(rules).or_default().push(file_path);
             }
@@ -1792,4 +1827,92 @@ mod test {
         // Identical rules should be deduplicated
         assert_eq!(rules.len(), 1, "BTreeSet should deduplicate identical rules");
     }
+
+    #[test]
+    fn test_native_type_aware_rules_fall_back_to_tsgolint() {
+        use std::{
+            ffi::OsStr,
+            path::{Path, PathBuf},
+            sync::Arc,
+        };
+
+        use rustc_hash::FxHashMap;
+
+        use oxc_allocator::Allocator;
+
+        use crate::{
+            AllowWarnDeny, ConfigStore, ConfigStoreBuilder, LintPlugins, LintService,
+            LintServiceOptions, Linter, RuntimeFileSystem,
+            external_plugin_store::ExternalPluginStore, options::LintOptions, rules::RULES,
+            tsgolint::TsGoLintState,
+        };
+
+        struct FileSystem(FxHashMap<PathBuf, &'static str>);
+
+        impl RuntimeFileSystem for FileSystem {
+            fn read_to_arena_str<'a>(
+                &self,
+                path: &Path,
+                allocator: &'a Allocator,
+            ) -> Result<&'a str, std::io::Error> {
+                Ok(allocator.alloc_str(self.0[path]))
+            }
+
+            fn write_file(&self, _path: &Path, _content: &str) -> Result<(), std::io::Error> {
+                unreachable!()
+            }
+        }
+
+        let cwd = std::env::current_dir().unwrap();
+        let native = cwd.join("native.ts");
+        let unresolved = cwd.join("unresolved.ts");
+        let global = cwd.join("global.ts");
+        let file_system = FileSystem(FxHashMap::from_iter([
+            (native.clone(), "async function g() {} g().then(() => {});"),
+            (unresolved.clone(), "import { g } from './missing'; g().then(() => {});"),
+            // Globals are not known without lib files
+            (global.clone(), "Promise.resolve();"),
+        ]));
+
+        let rule = RULES.iter().find(|rule| rule.name() == "no-floating-promises").unwrap();
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let config = ConfigStoreBuilder::empty()
+            .with_builtin_plugins(LintPlugins::TYPESCRIPT)
+            .with_rule(rule.clone(), AllowWarnDeny::Deny)
+            .build(&mut external_plugin_store)
+            .unwrap();
+        let config_store = ConfigStore::new(config, FxHashMap::default(), external_plugin_store);
+        let linter =
+            Linter::new(LintOptions::default(), config_store.clone(), None).with_type_aware(true);
+        let native_type_aware_files = linter.native_type_aware_files();
+
+        let paths =
+            [&native, &unresolved, &global].map(|path| Arc::<OsStr>::from(path.as_os_str()));
+        let lint_service = LintService::new(linter, LintServiceOptions::new(cwd.clone()));
+        let messages = lint_service.run_source(&file_system, paths.to_vec());
+
+        // Only the file whose types are known is linted in-process
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].span.start, 22);
+        assert!(native_type_aware_files.lock().unwrap().contains(&native));
+        assert!(!native_type_aware_files.lock().unwrap().contains(&unresolved));
+        assert!(!native_type_aware_files.lock().unwrap().contains(&global));
+
+        // and the others are still linted by tsgolint
+        let state = TsGoLintState::new(&cwd, config_store, FixKind::None)
+            .with_native_type_aware_files(native_type_aware_files);
+        let payload = state.json_input(&paths, None, &mut FxHashMap::default());
+        let rules_of = |path: &Path| {
+            let path = path.to_string_lossy();
+            let config = payload
+                .configs
+                .iter()
+                .find(|config| config.file_paths.iter().any(|file_path| *file_path == path))?;
+            Some(config.rules.iter().map(|rule| rule.name.as_str()).collect::<Vec<_>>())
+        };
+        // Files without rules for tsgolint are not sent to it
+        assert_eq!(rules_of(&native), None);
+        assert_eq!(rules_of(&unresolved), Some(vec!["no-floating-promises"]));
+        assert_eq!(rules_of(&global), Some(vec!["no-floating-promises"]));
+    }
 }
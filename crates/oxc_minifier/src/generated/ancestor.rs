@@ -195,134 +195,142 @@ pub(crate) enum AncestorType {
     ExportSpecifierExported = 171,
     V8IntrinsicExpressionName = 172,
     V8IntrinsicExpressionArguments = 173,
-    JSXElementOpeningElement = 174,
-    JSXElementChildren = 175,
-    JSXElementClosingElement = 176,
-    JSXOpeningElementName = 177,
-    JSXOpeningElementTypeArguments = 178,
-    JSXOpeningElementAttributes = 179,
-    JSXClosingElementName = 180,
-    JSXFragmentOpeningFragment = 181,
-    JSXFragmentChildren = 182,
-    JSXFragmentClosingFragment = 183,
-    JSXNamespacedNameNamespace = 184,
-    JSXNamespacedNameName = 185,
-    JSXMemberExpressionObject = 186,
-    JSXMemberExpressionProperty = 187,
-    JSXExpressionContainerExpression = 188,
-    JSXAttributeName = 189,
-    JSXAttributeValue = 190,
-    JSXSpreadAttributeArgument = 191,
-    JSXSpreadChildExpression = 192,
-    TSThisParameterTypeAnnotation = 193,
-    TSEnumDeclarationId = 194,
-    TSEnumDeclarationBody = 195,
-    TSEnumBodyMembers = 196,
-    TSEnumMemberId = 197,
-    TSEnumMemberInitializer = 198,
-    TSTypeAnnotationTypeAnnotation = 199,
-    TSLiteralTypeLiteral = 200,
-    TSConditionalTypeCheckType = 201,
-    TSConditionalTypeExtendsType = 202,
-    TSConditionalTypeTrueType = 203,
-    TSConditionalTypeFalseType = 204,
-    TSUnionTypeTypes = 205,
-    TSIntersectionTypeTypes = 206,
-    TSParenthesizedTypeTypeAnnotation = 207,
-    TSTypeOperatorTypeAnnotation = 208,
-    TSArrayTypeElementType = 209,
-    TSIndexedAccessTypeObjectType = 210,
-    TSIndexedAccessTypeIndexType = 211,
-    TSTupleTypeElementTypes = 212,
-    TSNamedTupleMemberLabel = 213,
-    TSNamedTupleMemberElementType = 214,
-    TSOptionalTypeTypeAnnotation = 215,
-    TSRestTypeTypeAnnotation = 216,
-    TSTypeReferenceTypeName = 217,
-    TSTypeReferenceTypeArguments = 218,
-    TSQualifiedNameLeft = 219,
-    TSQualifiedNameRight = 220,
-    TSTypeParameterInstantiationParams = 221,
-    TSTypeParameterName = 222,
-    TSTypeParameterConstraint = 223,
-    TSTypeParameterDefault = 224,
-    TSTypeParameterDeclarationParams = 225,
-    TSTypeAliasDeclarationId = 226,
-    TSTypeAliasDeclarationTypeParameters = 227,
-    TSTypeAliasDeclarationTypeAnnotation = 228,
-    TSClassImplementsExpression = 229,
-    TSClassImplementsTypeArguments = 230,
-    TSInterfaceDeclarationId = 231,
-    TSInterfaceDeclarationTypeParameters = 232,
-    TSInterfaceDeclarationExtends = 233,
-    TSInterfaceDeclarationBody = 234,
-    TSInterfaceBodyBody = 235,
-    TSPropertySignatureKey = 236,
-    TSPropertySignatureTypeAnnotation = 237,
-    TSIndexSignatureParameters = 238,
-    TSIndexSignatureTypeAnnotation = 239,
-    TSCallSignatureDeclarationTypeParameters = 240,
-    TSCallSignatureDeclarationThisParam = 241,
-    TSCallSignatureDeclarationParams = 242,
-    TSCallSignatureDeclarationReturnType = 243,
-    TSMethodSignatureKey = 244,
-    TSMethodSignatureTypeParameters = 245,
-    TSMethodSignatureThisParam = 246,
-    TSMethodSignatureParams = 247,
-    TSMethodSignatureReturnType = 248,
-    TSConstructSignatureDeclarationTypeParameters = 249,
-    TSConstructSignatureDeclarationParams = 250,
-    TSConstructSignatureDeclarationReturnType = 251,
-    TSIndexSignatureNameTypeAnnotation = 252,
-    TSInterfaceHeritageExpression = 253,
-    TSInterfaceHeritageTypeArguments = 254,
-    TSTypePredicateParameterName = 255,
-    TSTypePredicateTypeAnnotation = 256,
-    TSModuleDeclarationId = 257,
-    TSModuleDeclarationBody = 258,
-    TSGlobalDeclarationBody = 259,
-    TSModuleBlockDirectives = 260,
-    TSModuleBlockBody = 261,
-    TSTypeLiteralMembers = 262,
-    TSInferTypeTypeParameter = 263,
-    TSTypeQueryExprName = 264,
-    TSTypeQueryTypeArguments = 265,
-    TSImportTypeSource = 266,
-    TSImportTypeOptions = 267,
-    TSImportTypeQualifier = 268,
-    TSImportTypeTypeArguments = 269,
-    TSImportTypeQualifiedNameLeft = 270,
-    TSImportTypeQualifiedNameRight = 271,
-    TSFunctionTypeTypeParameters = 272,
-    TSFunctionTypeThisParam = 273,
-    TSFunctionTypeParams = 274,
-    TSFunctionTypeReturnType = 275,
-    TSConstructorTypeTypeParameters = 276,
-    TSConstructorTypeParams = 277,
-    TSConstructorTypeReturnType = 278,
-    TSMappedTypeKey = 279,
-    TSMappedTypeConstraint = 280,
-    TSMappedTypeNameType = 281,
-    TSMappedTypeTypeAnnotation = 282,
-    TSTemplateLiteralTypeQuasis = 283,
-    TSTemplateLiteralTypeTypes = 284,
-    TSAsExpressionExpression = 285,
-    TSAsExpressionTypeAnnotation = 286,
-    TSSatisfiesExpressionExpression = 287,
-    TSSatisfiesExpressionTypeAnnotation = 288,
-    TSTypeAssertionTypeAnnotation = 289,
-    TSTypeAssertionExpression = 290,
-    TSImportEqualsDeclarationId = 291,
-    TSImportEqualsDeclarationModuleReference = 292,
-    TSExternalModuleReferenceExpression = 293,
-    TSNonNullExpressionExpression = 294,
-    DecoratorExpression = 295,
-    TSExportAssignmentExpression = 296,
-    TSNamespaceExportDeclarationId = 297,
-    TSInstantiationExpressionExpression = 298,
-    TSInstantiationExpressionTypeArguments = 299,
-    JSDocNullableTypeTypeAnnotation = 300,
-    JSDocNonNullableTypeTypeAnnotation = 301,
+    PipelineExpressionHead = 174,
+    PipelineExpressionBody = 175,
+    JSXElementOpeningElement = 176,
+    JSXElementChildren = 177,
+    JSXElementClosingElement = 178,
+    JSXOpeningElementName = 179,
+    JSXOpeningElementTypeArguments = 180,
+    JSXOpeningElementAttributes = 181,
+    JSXClosingElementName = 182,
+    JSXFragmentOpeningFragment = 183,
+    JSXFragmentChildren = 184,
+    JSXFragmentClosingFragment = 185,
+    JSXNamespacedNameNamespace = 186,
+    JSXNamespacedNameName = 187,
+    JSXMemberExpressionObject = 188,
+    JSXMemberExpressionProperty = 189,
+    JSXExpressionContainerExpression = 190,
+    JSXAttributeName = 191,
+    JSXAttributeValue = 192,
+    JSXSpreadAttributeArgument = 193,
+    JSXSpreadChildExpression = 194,
+    TSThisParameterTypeAnnotation = 195,
+    TSEnumDeclarationId = 196,
+    TSEnumDeclarationBody = 197,
+    TSEnumBodyMembers = 198,
+    TSEnumMemberId = 199,
+    TSEnumMemberInitializer = 200,
+    TSTypeAnnotationTypeAnnotation = 201,
+    TSLiteralTypeLiteral = 202,
+    TSConditionalTypeCheckType = 203,
+    TSConditionalTypeExtendsType = 204,
+    TSConditionalTypeTrueType = 205,
+    TSConditionalTypeFalseType = 206,
+    TSUnionTypeTypes = 207,
+    TSIntersectionTypeTypes = 208,
+    TSParenthesizedTypeTypeAnnotation = 209,
+    TSTypeOperatorTypeAnnotation = 210,
+    TSArrayTypeElementType = 211,
+    TSIndexedAccessTypeObjectType = 212,
+    TSIndexedAccessTypeIndexType = 213,
+    TSTupleTypeElementTypes = 214,
+    TSNamedTupleMemberLabel = 215,
+    TSNamedTupleMemberElementType = 216,
+    TSOptionalTypeTypeAnnotation = 217,
+    TSRestTypeTypeAnnotation = 218,
+    TSTypeReferenceTypeName = 219,
+    TSTypeReferenceTypeArguments = 220,
+    TSQualifiedNameLeft = 221,
+    TSQualifiedNameRight = 222,
+    TSTypeParameterInstantiationParams = 223,
+    TSTypeParameterName = 224,
+    TSTypeParameterConstraint = 225,
+    TSTypeParameterDefault = 226,
+    TSTypeParameterDeclarationParams = 227,
+    TSTypeAliasDeclarationId = 228,
+    TSTypeAliasDeclarationTypeParameters = 229,
+    TSTypeAliasDeclarationTypeAnnotation = 230,
+    TSClassImplementsExpression = 231,
+    TSClassImplementsTypeArguments = 232,
+    TSInterfaceDeclarationId = 233,
+    TSInterfaceDeclarationTypeParameters = 234,
+    TSInterfaceDeclarationExtends = 235,
+    TSInterfaceDeclarationBody = 236,
+    TSInterfaceBodyBody = 237,
+    TSPropertySignatureKey = 238,
+    TSPropertySignatureTypeAnnotation = 239,
+    TSIndexSignatureParameters = 240,
+    TSIndexSignatureTypeAnnotation = 241,
+    TSCallSignatureDeclarationTypeParameters = 242,
+    TSCallSignatureDeclarationThisParam = 243,
+    TSCallSignatureDeclarationParams = 244,
+    TSCallSignatureDeclarationReturnType = 245,
+    TSMethodSignatureKey = 246,
+    TSMethodSignatureTypeParameters = 247,
+    TSMethodSignatureThisParam = 248,
+    TSMethodSignatureParams = 249,
+    TSMethodSignatureReturnType = 250,
+    TSConstructSignatureDeclarationTypeParameters = 251,
+    TSConstructSignatureDeclarationParams = 252,
+    TSConstructSignatureDeclarationReturnType = 253,
+    TSIndexSignatureNameTypeAnnotation = 254,
+    TSInterfaceHeritageExpression = 255,
+    TSInterfaceHeritageTypeArguments = 256,
+    TSTypePredicateParameterName = 257,
+    TSTypePredicateTypeAnnotation = 258,
+    TSModuleDeclarationId = 259,
+    TSModuleDeclarationBody = 260,
+    TSGlobalDeclarationBody = 261,
+    TSModuleBlockDirectives = 262,
+    TSModuleBlockBody = 263,
+    TSTypeLiteralMembers = 264,
+    TSInferTypeTypeParameter = 265,
+    TSTypeQueryExprName = 266,
+    TSTypeQueryTypeArguments = 267,
+    TSImportTypeSource = 268,
+    TSImportTypeOptions = 269,
+    TSImportTypeQualifier = 270,
+    TSImportTypeTypeArguments = 271,
+    TSImportTypeQualifiedNameLeft = 272,
+    TSImportTypeQualifiedNameRight = 273,
+    TSFunctionTypeTypeParameters = 274,
+    TSFunctionTypeThisParam = 275,
+    TSFunctionTypeParams = 276,
+    TSFunctionTypeReturnType = 277,
+    TSConstructorTypeTypeParameters = 278,
+    TSConstructorTypeParams = 279,
+    TSConstructorTypeReturnType = 280,
+    TSMappedTypeKey = 281,
+    TSMappedTypeConstraint = 282,
+    TSMappedTypeNameType = 283,
+    TSMappedTypeTypeAnnotation = 284,
+    TSTemplateLiteralTypeQuasis = 285,
+    TSTemplateLiteralTypeTypes = 286,
+    TSAsExpressionExpression = 287,
+    TSAsExpressionTypeAnnotation = 288,
+    TSSatisfiesExpressionExpression = 289,
+    TSSatisfiesExpressionTypeAnnotation = 290,
+    TSTypeAssertionTypeAnnotation = 291,
+    TSTypeAssertionExpression = 292,
+    TSImportEqualsDeclarationId = 293,
+    TSImportEqualsDeclarationModuleReference = 294,
+    TSExternalModuleReferenceExpression = 295,
+    TSNonNullExpressionExpression = 296,
+    DecoratorExpression = 297,
+    TSExportAssignmentExpression = 298,
+    TSNamespaceExportDeclarationId = 299,
+    TSInstantiationExpressionExpression = 300,
+    TSInstantiationExpressionTypeArguments = 301,
+    JSDocNullableTypeTypeAnnotation = 302,
+    JSDocNonNullableTypeTypeAnnotation = 303,
+    FlowTypeCastExpressionExpression = 304,
+    FlowTypeCastExpressionTypeAnnotation = 305,
+    FlowOpaqueTypeDeclarationId = 306,
+    FlowOpaqueTypeDeclarationTypeParameters = 307,
+    FlowOpaqueTypeDeclarationSupertype = 308,
+    FlowOpaqueTypeDeclarationImpltype = 309,
 }
 
 /// Ancestor type used in AST traversal.
@@ -656,6 +664,10 @@ pub enum Ancestor<'a, 't> {
         AncestorType::V8IntrinsicExpressionName as u16,
     V8IntrinsicExpressionArguments(V8IntrinsicExpressionWithoutArguments<'a, 't>) =
         AncestorType::V8IntrinsicExpressionArguments as u16,
+    PipelineExpressionHead(PipelineExpressionWithoutHead<'a, 't>) =
+        AncestorType::PipelineExpressionHead as u16,
+    PipelineExpressionBody(PipelineExpressionWithoutBody<'a, 't>) =
+        AncestorType::PipelineExpressionBody as u16,
     JSXElementOpeningElement(JSXElementWithoutOpeningElement<'a, 't>) =
         AncestorType::JSXElementOpeningElement as u16,
     JSXElementChildren(JSXElementWithoutChildren<'a, 't>) = AncestorType::JSXElementChildren as u16,
@@ -907,6 +919,18 @@ pub enum Ancestor<'a, 't> {
         AncestorType::JSDocNullableTypeTypeAnnotation as u16,
     JSDocNonNullableTypeTypeAnnotation(JSDocNonNullableTypeWithoutTypeAnnotation<'a, 't>) =
         AncestorType::JSDocNonNullableTypeTypeAnnotation as u16,
+    FlowTypeCastExpressionExpression(FlowTypeCastExpressionWithoutExpression<'a, 't>) =
+        AncestorType::FlowTypeCastExpressionExpression as u16,
+    FlowTypeCastExpressionTypeAnnotation(FlowTypeCastExpressionWithoutTypeAnnotation<'a, 't>) =
+        AncestorType::FlowTypeCastExpressionTypeAnnotation as u16,
+    FlowOpaqueTypeDeclarationId(FlowOpaqueTypeDeclarationWithoutId<'a, 't>) =
+        AncestorType::FlowOpaqueTypeDeclarationId as u16,
+    FlowOpaqueTypeDeclarationTypeParameters(FlowOpaqueTypeDeclarationWithoutTypeParameters<'a, 't>) =
+        AncestorType::FlowOpaqueTypeDeclarationTypeParameters as u16,
+    FlowOpaqueTypeDeclarationSupertype(FlowOpaqueTypeDeclarationWithoutSupertype<'a, 't>) =
+        AncestorType::FlowOpaqueTypeDeclarationSupertype as u16,
+    FlowOpaqueTypeDeclarationImpltype(FlowOpaqueTypeDeclarationWithoutImpltype<'a, 't>) =
+        AncestorType::FlowOpaqueTypeDeclarationImpltype as u16,
 }
 
 impl<'a, 't> Ancestor<'a, 't> {
@@ -1458,6 +1482,11 @@ impl<'a, 't> Ancestor<'a, 't> {
         matches!(self, Self::V8IntrinsicExpressionName(_) | Self::V8IntrinsicExpressionArguments(_))
     }
 
+    #[inline]
+    pub fn is_pipeline_expression(self) -> bool {
+        matches!(self, Self::PipelineExpressionHead(_) | Self::PipelineExpressionBody(_))
+    }
+
     #[inline]
     pub fn is_jsx_element(self) -> bool {
         matches!(
@@ -1905,6 +1934,26 @@ impl<'a, 't> Ancestor<'a, 't> {
         matches!(self, Self::JSDocNonNullableTypeTypeAnnotation(_))
     }
 
+    #[inline]
+    pub fn is_flow_type_cast_expression(self) -> bool {
+        matches!(
+            self,
+            Self::FlowTypeCastExpressionExpression(_)
+                | Self::FlowTypeCastExpressionTypeAnnotation(_)
+        )
+    }
+
+    #[inline]
+    pub fn is_flow_opaque_type_declaration(self) -> bool {
+        matches!(
+            self,
+            Self::FlowOpaqueTypeDeclarationId(_)
+                | Self::FlowOpaqueTypeDeclarationTypeParameters(_)
+                | Self::FlowOpaqueTypeDeclarationSupertype(_)
+                | Self::FlowOpaqueTypeDeclarationImpltype(_)
+        )
+    }
+
     #[inline]
     pub fn is_parent_of_statement(self) -> bool {
         matches!(
@@ -2003,6 +2052,8 @@ impl<'a, 't> Ancestor<'a, 't> {
                 | Self::AccessorPropertyValue(_)
                 | Self::ImportExpressionSource(_)
                 | Self::ImportExpressionOptions(_)
+                | Self::PipelineExpressionHead(_)
+                | Self::PipelineExpressionBody(_)
                 | Self::JSXSpreadAttributeArgument(_)
                 | Self::JSXSpreadChildExpression(_)
                 | Self::TSEnumMemberInitializer(_)
@@ -2014,6 +2065,7 @@ impl<'a, 't> Ancestor<'a, 't> {
                 | Self::DecoratorExpression(_)
                 | Self::TSExportAssignmentExpression(_)
                 | Self::TSInstantiationExpressionExpression(_)
+                | Self::FlowTypeCastExpressionExpression(_)
         )
     }
 
@@ -2192,6 +2244,8 @@ impl<'a, 't> Ancestor<'a, 't> {
                 | Self::TSTypeAssertionTypeAnnotation(_)
                 | Self::JSDocNullableTypeTypeAnnotation(_)
                 | Self::JSDocNonNullableTypeTypeAnnotation(_)
+                | Self::FlowOpaqueTypeDeclarationSupertype(_)
+                | Self::FlowOpaqueTypeDeclarationImpltype(_)
         )
     }
 
@@ -2431,6 +2485,8 @@ impl<'a, 't> GetAddress for Ancestor<'a, 't> {
             Self::ExportSpecifierExported(a) => a.address(),
             Self::V8IntrinsicExpressionName(a) => a.address(),
             Self::V8IntrinsicExpressionArguments(a) => a.address(),
+            Self::PipelineExpressionHead(a) => a.address(),
+            Self::PipelineExpressionBody(a) => a.address(),
             Self::JSXElementOpeningElement(a) => a.address(),
             Self::JSXElementChildren(a) => a.address(),
             Self::JSXElementClosingElement(a) => a.address(),
@@ -2559,6 +2615,12 @@ impl<'a, 't> GetAddress for Ancestor<'a, 't> {
             Self::TSInstantiationExpressionTypeArguments(a) => a.address(),
             Self::JSDocNullableTypeTypeAnnotation(a) => a.address(),
             Self::JSDocNonNullableTypeTypeAnnotation(a) => a.address(),
+            Self::FlowTypeCastExpressionExpression(a) => a.address(),
+            Self::FlowTypeCastExpressionTypeAnnotation(a) => a.address(),
+            Self::FlowOpaqueTypeDeclarationId(a) => a.address(),
+            Self::FlowOpaqueTypeDeclarationTypeParameters(a) => a.address(),
+            Self::FlowOpaqueTypeDeclarationSupertype(a) => a.address(),
+            Self::FlowOpaqueTypeDeclarationImpltype(a) => a.address(),
         }
     }
 }
@@ -12203,6 +12265,82 @@ impl<'a, 't> GetAddress for V8IntrinsicExpressionWithoutArguments<'a, 't> {
     }
 }
 
+pub(crate) const OFFSET_PIPELINE_EXPRESSION_NODE_ID: usize =
+    offset_of!(PipelineExpression, node_id);
+pub(crate) const OFFSET_PIPELINE_EXPRESSION_SPAN: usize = offset_of!(PipelineExpression, span);
+pub(crate) const OFFSET_PIPELINE_EXPRESSION_HEAD: usize = offset_of!(PipelineExpression, head);
+pub(crate) const OFFSET_PIPELINE_EXPRESSION_BODY: usize = offset_of!(PipelineExpression, body);
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct PipelineExpressionWithoutHead<'a, 't>(
+    pub(crate) *const PipelineExpression<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> PipelineExpressionWithoutHead<'a, 't> {
+    #[inline]
+    pub fn node_id(self) -> &'t Cell<NodeId> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_NODE_ID) as *const Cell<NodeId>)
+        }
+    }
+
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe { &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_SPAN) as *const Span) }
+    }
+
+    #[inline]
+    pub fn body(self) -> &'t Expression<'a> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_BODY) as *const Expression<'a>)
+        }
+    }
+}
+
+impl<'a, 't> GetAddress for PipelineExpressionWithoutHead<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        unsafe { Address::from_ptr(self.0) }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct PipelineExpressionWithoutBody<'a, 't>(
+    pub(crate) *const PipelineExpression<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> PipelineExpressionWithoutBody<'a, 't> {
+    #[inline]
+    pub fn node_id(self) -> &'t Cell<NodeId> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_NODE_ID) as *const Cell<NodeId>)
+        }
+    }
+
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe { &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_SPAN) as *const Span) }
+    }
+
+    #[inline]
+    pub fn head(self) -> &'t Expression<'a> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_HEAD) as *const Expression<'a>)
+        }
+    }
+}
+
+impl<'a, 't> GetAddress for PipelineExpressionWithoutBody<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        unsafe { Address::from_ptr(self.0) }
+    }
+}
+
 pub(crate) const OFFSET_JSX_ELEMENT_NODE_ID: usize = offset_of!(JSXElement, node_id);
 pub(crate) const OFFSET_JSX_ELEMENT_SPAN: usize = offset_of!(JSXElement, span);
 pub(crate) const OFFSET_JSX_ELEMENT_OPENING_ELEMENT: usize =
@@ -18605,3 +18743,391 @@ impl<'a, 't> GetAddress for JSDocNonNullableTypeWithoutTypeAnnotation<'a, 't> {
         unsafe { Address::from_ptr(self.0) }
     }
 }
+
+pub(crate) const OFFSET_FLOW_TYPE_CAST_EXPRESSION_NODE_ID: usize =
+    offset_of!(FlowTypeCastExpression, node_id);
+pub(crate) const OFFSET_FLOW_TYPE_CAST_EXPRESSION_SPAN: usize =
+    offset_of!(FlowTypeCastExpression, span);
+pub(crate) const OFFSET_FLOW_TYPE_CAST_EXPRESSION_EXPRESSION: usize =
+    offset_of!(FlowTypeCastExpression, expression);
+pub(crate) const OFFSET_FLOW_TYPE_CAST_EXPRESSION_TYPE_ANNOTATION: usize =
+    offset_of!(FlowTypeCastExpression, type_annotation);
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct FlowTypeCastExpressionWithoutExpression<'a, 't>(
+    pub(crate) *const FlowTypeCastExpression<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> FlowTypeCastExpressionWithoutExpression<'a, 't> {
+    #[inline]
+    pub fn node_id(self) -> &'t Cell<NodeId> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_TYPE_CAST_EXPRESSION_NODE_ID)
+                as *const Cell<NodeId>)
+        }
+    }
+
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_TYPE_CAST_EXPRESSION_SPAN) as *const Span)
+        }
+    }
+
+    #[inline]
+    pub fn type_annotation(self) -> &'t ArenaBox<'a, TSTypeAnnotation<'a>> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_TYPE_CAST_EXPRESSION_TYPE_ANNOTATION)
+                as *const ArenaBox<'a, TSTypeAnnotation<'a>>)
+        }
+    }
+}
+
+impl<'a, 't> GetAddress for FlowTypeCastExpressionWithoutExpression<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        unsafe { Address::from_ptr(self.0) }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct FlowTypeCastExpressionWithoutTypeAnnotation<'a, 't>(
+    pub(crate) *const FlowTypeCastExpression<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> FlowTypeCastExpressionWithoutTypeAnnotation<'a, 't> {
+    #[inline]
+    pub fn node_id(self) -> &'t Cell<NodeId> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_TYPE_CAST_EXPRESSION_NODE_ID)
+                as *const Cell<NodeId>)
+        }
+    }
+
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_TYPE_CAST_EXPRESSION_SPAN) as *const Span)
+        }
+    }
+
+    #[inline]
+    pub fn expression(self) -> &'t Expression<'a> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_TYPE_CAST_EXPRESSION_EXPRESSION)
+                as *const Expression<'a>)
+        }
+    }
+}
+
+impl<'a, 't> GetAddress for FlowTypeCastExpressionWithoutTypeAnnotation<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        unsafe { Address::from_ptr(self.0) }
+    }
+}
+
+pub(crate) const OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_NODE_ID: usize =
+    offset_of!(FlowOpaqueTypeDeclaration, node_id);
+pub(crate) const OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_SPAN: usize =
+    offset_of!(FlowOpaqueTypeDeclaration, span);
+pub(crate) const OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_ID: usize =
+    offset_of!(FlowOpaqueTypeDeclaration, id);
+pub(crate) const OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_TYPE_PARAMETERS: usize =
+    offset_of!(FlowOpaqueTypeDeclaration, type_parameters);
+pub(crate) const OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_SUPERTYPE: usize =
+    offset_of!(FlowOpaqueTypeDeclaration, supertype);
+pub(crate) const OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_IMPLTYPE: usize =
+    offset_of!(FlowOpaqueTypeDeclaration, impltype);
+pub(crate) const OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_DECLARE: usize =
+    offset_of!(FlowOpaqueTypeDeclaration, declare);
+pub(crate) const OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_SCOPE_ID: usize =
+    offset_of!(FlowOpaqueTypeDeclaration, scope_id);
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct FlowOpaqueTypeDeclarationWithoutId<'a, 't>(
+    pub(crate) *const FlowOpaqueTypeDeclaration<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> FlowOpaqueTypeDeclarationWithoutId<'a, 't> {
+    #[inline]
+    pub fn node_id(self) -> &'t Cell<NodeId> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_NODE_ID)
+                as *const Cell<NodeId>)
+        }
+    }
+
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_SPAN) as *const Span)
+        }
+    }
+
+    #[inline]
+    pub fn type_parameters(self) -> &'t Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_TYPE_PARAMETERS)
+                as *const Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>)
+        }
+    }
+
+    #[inline]
+    pub fn supertype(self) -> &'t Option<TSType<'a>> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_SUPERTYPE)
+                as *const Option<TSType<'a>>)
+        }
+    }
+
+    #[inline]
+    pub fn impltype(self) -> &'t Option<TSType<'a>> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_IMPLTYPE)
+                as *const Option<TSType<'a>>)
+        }
+    }
+
+    #[inline]
+    pub fn declare(self) -> &'t bool {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_DECLARE)
+                as *const bool)
+        }
+    }
+
+    #[inline]
+    pub fn scope_id(self) -> &'t Cell<Option<ScopeId>> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_SCOPE_ID)
+                as *const Cell<Option<ScopeId>>)
+        }
+    }
+}
+
+impl<'a, 't> GetAddress for FlowOpaqueTypeDeclarationWithoutId<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        unsafe { Address::from_ptr(self.0) }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct FlowOpaqueTypeDeclarationWithoutTypeParameters<'a, 't>(
+    pub(crate) *const FlowOpaqueTypeDeclaration<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> FlowOpaqueTypeDeclarationWithoutTypeParameters<'a, 't> {
+    #[inline]
+    pub fn node_id(self) -> &'t Cell<NodeId> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_NODE_ID)
+                as *const Cell<NodeId>)
+        }
+    }
+
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_SPAN) as *const Span)
+        }
+    }
+
+    #[inline]
+    pub fn id(self) -> &'t BindingIdentifier<'a> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_ID)
+                as *const BindingIdentifier<'a>)
+        }
+    }
+
+    #[inline]
+    pub fn supertype(self) -> &'t Option<TSType<'a>> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_SUPERTYPE)
+                as *const Option<TSType<'a>>)
+        }
+    }
+
+    #[inline]
+    pub fn impltype(self) -> &'t Option<TSType<'a>> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_IMPLTYPE)
+                as *const Option<TSType<'a>>)
+        }
+    }
+
+    #[inline]
+    pub fn declare(self) -> &'t bool {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_DECLARE)
+                as *const bool)
+        }
+    }
+
+    #[inline]
+    pub fn scope_id(self) -> &'t Cell<Option<ScopeId>> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_SCOPE_ID)
+                as *const Cell<Option<ScopeId>>)
+        }
+    }
+}
+
+impl<'a, 't> GetAddress for FlowOpaqueTypeDeclarationWithoutTypeParameters<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        unsafe { Address::from_ptr(self.0) }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct FlowOpaqueTypeDeclarationWithoutSupertype<'a, 't>(
+    pub(crate) *const FlowOpaqueTypeDeclaration<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> FlowOpaqueTypeDeclarationWithoutSupertype<'a, 't> {
+    #[inline]
+    pub fn node_id(self) -> &'t Cell<NodeId> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_NODE_ID)
+                as *const Cell<NodeId>)
+        }
+    }
+
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_SPAN) as *const Span)
+        }
+    }
+
+    #[inline]
+    pub fn id(self) -> &'t BindingIdentifier<'a> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_ID)
+                as *const BindingIdentifier<'a>)
+        }
+    }
+
+    #[inline]
+    pub fn type_parameters(self) -> &'t Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_TYPE_PARAMETERS)
+                as *const Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>)
+        }
+    }
+
+    #[inline]
+    pub fn impltype(self) -> &'t Option<TSType<'a>> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_IMPLTYPE)
+                as *const Option<TSType<'a>>)
+        }
+    }
+
+    #[inline]
+    pub fn declare(self) -> &'t bool {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_DECLARE)
+                as *const bool)
+        }
+    }
+
+    #[inline]
+    pub fn scope_id(self) -> &'t Cell<Option<ScopeId>> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_SCOPE_ID)
+                as *const Cell<Option<ScopeId>>)
+        }
+    }
+}
+
+impl<'a, 't> GetAddress for FlowOpaqueTypeDeclarationWithoutSupertype<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        unsafe { Address::from_ptr(self.0) }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct FlowOpaqueTypeDeclarationWithoutImpltype<'a, 't>(
+    pub(crate) *const FlowOpaqueTypeDeclaration<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> FlowOpaqueTypeDeclarationWithoutImpltype<'a, 't> {
+    #[inline]
+    pub fn node_id(self) -> &'t Cell<NodeId> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_NODE_ID)
+                as *const Cell<NodeId>)
+        }
+    }
+
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_SPAN) as *const Span)
+        }
+    }
+
+    #[inline]
+    pub fn id(self) -> &'t BindingIdentifier<'a> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_ID)
+                as *const BindingIdentifier<'a>)
+        }
+    }
+
+    #[inline]
+    pub fn type_parameters(self) -> &'t Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_TYPE_PARAMETERS)
+                as *const Option<ArenaBox<'a, TSTypeParameterDeclaration<'a>>>)
+        }
+    }
+
+    #[inline]
+    pub fn supertype(self) -> &'t Option<TSType<'a>> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_SUPERTYPE)
+                as *const Option<TSType<'a>>)
+        }
+    }
+
+    #[inline]
+    pub fn declare(self) -> &'t bool {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_DECLARE)
+                as *const bool)
+        }
+    }
+
+    #[inline]
+    pub fn scope_id(self) -> &'t Cell<Option<ScopeId>> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_SCOPE_ID)
+                as *const Cell<Option<ScopeId>>)
+        }
+    }
+}
+
+impl<'a, 't> GetAddress for FlowOpaqueTypeDeclarationWithoutImpltype<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        unsafe { Address::from_ptr(self.0) }
+    }
+}
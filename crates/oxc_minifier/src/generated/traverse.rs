@@ -1281,6 +1281,31 @@ pub trait Traverse<'a> {
     ) {
     }
 
+    #[inline]
+    fn enter_pipeline_expression(
+        &mut self,
+        node: &mut PipelineExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    #[inline]
+    fn exit_pipeline_expression(
+        &mut self,
+        node: &mut PipelineExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    #[inline]
+    fn enter_topic_reference(&mut self, node: &mut TopicReference, ctx: &mut TraverseCtx<'a>) {}
+    #[inline]
+    fn exit_topic_reference(&mut self, node: &mut TopicReference, ctx: &mut TraverseCtx<'a>) {}
+
+    #[inline]
+    fn enter_error_node(&mut self, node: &mut ErrorNode, ctx: &mut TraverseCtx<'a>) {}
+    #[inline]
+    fn exit_error_node(&mut self, node: &mut ErrorNode, ctx: &mut TraverseCtx<'a>) {}
+
     #[inline]
     fn enter_boolean_literal(&mut self, node: &mut BooleanLiteral, ctx: &mut TraverseCtx<'a>) {}
     #[inline]
@@ -2481,6 +2506,36 @@ pub trait Traverse<'a> {
     fn exit_js_doc_unknown_type(&mut self, node: &mut JSDocUnknownType, ctx: &mut TraverseCtx<'a>) {
     }
 
+    #[inline]
+    fn enter_flow_type_cast_expression(
+        &mut self,
+        node: &mut FlowTypeCastExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    #[inline]
+    fn exit_flow_type_cast_expression(
+        &mut self,
+        node: &mut FlowTypeCastExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    #[inline]
+    fn enter_flow_opaque_type_declaration(
+        &mut self,
+        node: &mut FlowOpaqueTypeDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    #[inline]
+    fn exit_flow_opaque_type_declaration(
+        &mut self,
+        node: &mut FlowOpaqueTypeDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
     #[inline]
     fn enter_statements(
         &mut self,
@@ -725,7 +725,8 @@ impl<'a> PeepholeOptimizations {
                     {
                         for prop in o.properties.drain(..) {
                             match &prop {
-                                ObjectPropertyKind::SpreadProperty(_) => {
+                                ObjectPropertyKind::SpreadProperty(_)
+                                | ObjectPropertyKind::ErrorNode(_) => {
                                     new_properties.push(prop);
                                 }
                                 ObjectPropertyKind::ObjectProperty(p)
@@ -771,6 +772,7 @@ impl<'a> PeepholeOptimizations {
     ) -> bool {
         e.properties.iter().all(|p| match p {
             ObjectPropertyKind::SpreadProperty(_) => true,
+            ObjectPropertyKind::ErrorNode(_) => false,
             ObjectPropertyKind::ObjectProperty(p) => {
                 // getters are evaluated when spreading
                 matches!(p.kind, PropertyKind::Init)
@@ -1928,6 +1928,7 @@ impl<'a> PeepholeOptimizations {
                             // Stop now because spread properties have side effects
                             return Some(false);
                         }
+                        ObjectPropertyKind::ErrorNode(_) => return Some(false),
                     }
                 }
             }
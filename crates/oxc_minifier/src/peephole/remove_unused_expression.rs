@@ -432,7 +432,7 @@ impl<'a> PeepholeOptimizations {
 
         for prop in object_expr.properties.drain(..) {
             match prop {
-                ObjectPropertyKind::SpreadProperty(_) => {
+                ObjectPropertyKind::SpreadProperty(_) | ObjectPropertyKind::ErrorNode(_) => {
                     pending_spread_elements.push(prop);
                 }
                 ObjectPropertyKind::ObjectProperty(prop) => {
@@ -946,7 +946,9 @@ impl<'a> PeepholeOptimizations {
             // Save computed key.
             if e.computed()
                 && let Some(key) = match e {
-                    ClassElement::TSIndexSignature(_) | ClassElement::StaticBlock(_) => None,
+                    ClassElement::TSIndexSignature(_)
+                    | ClassElement::StaticBlock(_)
+                    | ClassElement::ErrorNode(_) => None,
                     ClassElement::MethodDefinition(def) => Some(&mut def.key),
                     ClassElement::PropertyDefinition(def) => Some(&mut def.key),
                     ClassElement::AccessorProperty(def) => Some(&mut def.key),
@@ -961,7 +963,8 @@ impl<'a> PeepholeOptimizations {
                 && let Some(init) = match e {
                     ClassElement::TSIndexSignature(_)
                     | ClassElement::StaticBlock(_)
-                    | ClassElement::MethodDefinition(_) => None,
+                    | ClassElement::MethodDefinition(_)
+                    | ClassElement::ErrorNode(_) => None,
                     ClassElement::PropertyDefinition(def) => def.value.take(),
                     ClassElement::AccessorProperty(def) => def.value.take(),
                 }
@@ -56,6 +56,13 @@ bitflags! {
         /// or a class field initializer. Arrow functions inherit this from their
         /// surrounding context, and class bodies are transparent to it.
         const NewTarget = 1 << 8;
+
+        /// Flow function types without parentheses around their parameter are not allowed.
+        /// Used in the return type of arrow functions
+        ///
+        /// const f = (x): T => U;
+        /// The "T" is the return type, and "U" is the body of the arrow function.
+        const DisallowFlowAnonFunctionType = 1 << 9;
     }
 }
 
@@ -96,6 +103,11 @@ impl Context {
         self.contains(Self::DisallowConditionalTypes)
     }
 
+    #[inline]
+    pub(crate) fn has_disallow_flow_anon_function_type(self) -> bool {
+        self.contains(Self::DisallowFlowAnonFunctionType)
+    }
+
     #[inline]
     pub(crate) fn has_ambient(self) -> bool {
         self.contains(Self::Ambient)
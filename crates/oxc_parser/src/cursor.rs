@@ -17,7 +17,7 @@ pub struct ParserCheckpoint<'a> {
     cur_token: Token,
     prev_span_end: u32,
     errors_pos: usize,
-    fatal_error: Option<FatalError>,
+    fatal_error: Option<FatalError<'a>>,
 }
 
 impl<'a, C: Config> ParserImpl<'a, C> {
@@ -24,6 +24,26 @@ impl DiagnosticExt for OxcDiagnostic {
     }
 }
 
+/// Creates a diagnostic tagged with its TypeScript error code.
+///
+/// The remaining `OxcDiagnostic::error` diagnostics in this file intentionally have no code:
+/// * Syntax TypeScript does not parse: Flow (`flow`, `unexpected_flow_variance`, `flow_*`),
+///   pipelines (`pipeline_*`), V8 intrinsics (`v8_intrinsic_spread_elem`) and source phase imports
+///   (`only_default_import_allowed_in_source_phase`).
+/// * Early errors TypeScript does not report: `html_comment_in_module`, `for_loop_let_reserved_word`,
+///   `export_lone_surrogate`, `export_named_string`, `export_reserved_word`, `super_private`,
+///   `private_in_private`, `declaration_single_statement`, `async_function_declaration`,
+///   `generator_function_declaration`, `class_declaration` and `identifier_async` / `identifier_generator`.
+/// * Errors TypeScript reports with a different code per context, so a single code would be
+///   wrong for some of the call sites: `jsx_in_non_jsx`, `unexpected_trailing_comma`,
+///   `invalid_escape_sequence`, `unicode_escape_sequence`, `invalid_number`, `template_literal`,
+///   `illegal_newline`, `for_await`, `new_dynamic_import`, `duplicate_export`, `import_meta`,
+///   `unexpected_private_identifier`, `unexpected_export`, `invalid_binding_rest_element`,
+///   `invalid_rest_assignment_target`, `invalid_assignment_target_default_value_operator`,
+///   `expect_function_body`, `unexpected_optional_declaration`,
+///   `identifier_expected_jsx_no_hyphen`, `using_declaration_not_allowed_in_switch_bare_case`
+///   and the other `ts_import_type_options_*` errors.
+/// * `overlong_source`, which is a limit of oxc rather than a syntax error.
 #[inline]
 fn ts_error<C, M>(code: C, message: M) -> OxcDiagnostic
 where
@@ -35,7 +55,7 @@ where
 
 #[cold]
 pub fn redeclaration(x0: &str, declare_span: Span, redeclare_span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Identifier `{x0}` has already been declared")).with_labels([
+    ts_error("2451", format!("Identifier `{x0}` has already been declared")).with_labels([
         declare_span.label(format!("`{x0}` has already been declared here")),
         redeclare_span.label("It can not be redeclared here"),
     ])
@@ -56,14 +76,42 @@ pub fn flow(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Flow is not supported").with_label(span)
 }
 
+#[cold]
+pub fn unexpected_flow_variance(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Variance is not allowed here").with_label(span)
+}
+
+#[cold]
+pub fn flow_declare_module_exports(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("`declare module.exports` is not supported").with_label(span)
+}
+
+#[cold]
+pub fn flow_declare_export_default_type(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("`declare export default` is only supported for functions and classes")
+        .with_label(span)
+}
+
+#[cold]
+pub fn module_declaration_not_at_top_level(is_import: bool, span: Span) -> OxcDiagnostic {
+    let (code, keyword) = if is_import { ("1232", "import") } else { ("1233", "export") };
+    ts_error(
+        code,
+        format!(
+            "An {keyword} declaration can only be used at the top level of a namespace or module."
+        ),
+    )
+    .with_label(span)
+}
+
 #[cold]
 pub fn unexpected_token(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected token").with_label(span)
+    ts_error("1012", "Unexpected token").with_label(span)
 }
 
 #[cold]
 pub fn private_identifier_in_property_name(name: &str, span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Private identifier '#{name}' is not allowed in property names"))
+    ts_error("18016", format!("Private identifier '#{name}' is not allowed in property names"))
         .with_label(span)
 }
 
@@ -78,7 +126,7 @@ pub fn merge_conflict_marker(
     middle_span: Option<Span>,
     end_span: Option<Span>,
 ) -> OxcDiagnostic {
-    let mut diagnostic = OxcDiagnostic::error("Encountered diff marker")
+    let mut diagnostic = ts_error("1185", "Encountered diff marker")
         .and_label(
             start_span.primary_label(
                 "between this marker and `=======` is the code that we're merging into",
@@ -123,7 +171,7 @@ pub fn jsx_in_non_jsx(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn expect_token(x0: &str, x1: &str, span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Expected `{x0}` but found `{x1}`"))
+    ts_error("1005", format!("Expected `{x0}` but found `{x1}`"))
         .with_label(span.label(format!("`{x0}` expected")))
 }
 
@@ -134,12 +182,10 @@ pub fn expect_closing(
     span: Span,
     opening_span: Span,
 ) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Expected `{expected_closing}` but found `{actual}`")).with_labels(
-        [
-            span.primary_label(format!("`{expected_closing}` expected")),
-            opening_span.label("Opened here"),
-        ],
-    )
+    ts_error("1005", format!("Expected `{expected_closing}` but found `{actual}`")).with_labels([
+        span.primary_label(format!("`{expected_closing}` expected")),
+        opening_span.label("Opened here"),
+    ])
 }
 
 #[cold]
@@ -150,9 +196,10 @@ pub fn expect_closing_or_separator(
     span: Span,
     opening_span: Span,
 ) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!(
-        "Expected `{expected_separator}` or `{expected_closing}` but found `{actual}`"
-    ))
+    ts_error(
+        "1005",
+        format!("Expected `{expected_separator}` or `{expected_closing}` but found `{actual}`"),
+    )
     .with_labels([
         span.primary_label(format!("`{expected_separator}` or `{expected_closing}` expected")),
         opening_span.label("Opened here"),
@@ -161,7 +208,7 @@ pub fn expect_closing_or_separator(
 
 #[cold]
 pub fn expect_conditional_alternative(x: &str, span: Span, question_span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Expected `:` but found `{x}`")).with_labels([
+    ts_error("1005", format!("Expected `:` but found `{x}`")).with_labels([
         span.primary_label("`:` expected"),
         question_span.label("Conditional starts here"),
     ])
@@ -186,52 +233,53 @@ pub fn unicode_escape_sequence(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn invalid_character(x0: char, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Invalid Character `{x0}`")).with_label(span1)
+    ts_error("1127", format!("Invalid Character `{x0}`")).with_label(span1)
 }
 
 #[cold]
 pub fn invalid_number_end(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid characters after number").with_label(span)
+    ts_error("1351", "Invalid characters after number").with_label(span)
 }
 
 #[cold]
 pub fn unterminated_multi_line_comment(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unterminated multiline comment").with_label(span)
+    ts_error("1010", "Unterminated multiline comment").with_label(span)
 }
 
 #[cold]
 pub fn unterminated_string(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unterminated string").with_label(span)
+    ts_error("1002", "Unterminated string").with_label(span)
 }
 
 #[cold]
 pub fn reg_exp_flag(x0: char, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Unexpected flag {x0} in regular expression literal"))
+    ts_error("1499", format!("Unexpected flag {x0} in regular expression literal"))
         .with_label(span1)
         .with_help(format!("The allowed flags are `{REGEXP_FLAGS_LIST}`"))
 }
 
 #[cold]
 pub fn reg_exp_flag_twice(x0: char, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Flag {x0} is mentioned twice in regular expression literal"))
+    ts_error("1500", format!("Flag {x0} is mentioned twice in regular expression literal"))
         .with_label(span1)
         .with_help("Remove the duplicated flag here")
 }
 
 #[cold]
 pub fn unexpected_end(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected end of file").with_label(span)
+    ts_error("1126", "Unexpected end of file").with_label(span)
 }
 
 #[cold]
 pub fn unexpected_jsx_end(span: Span, a: char, b: &str) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Unexpected token. Did you mean `{{'{a}'}}` or `&{b};`?"))
+    let code = if a == '}' { "1381" } else { "1382" };
+    ts_error(code, format!("Unexpected token. Did you mean `{{'{a}'}}` or `&{b};`?"))
         .with_label(span)
 }
 
 #[cold]
 pub fn unterminated_reg_exp(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unterminated regular expression").with_label(span)
+    ts_error("1161", "Unterminated regular expression").with_label(span)
 }
 
 #[cold]
@@ -241,12 +289,13 @@ pub fn invalid_number(x0: &str, span1: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn escaped_keyword(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Keywords cannot contain escape characters").with_label(span)
+    ts_error("1260", "Keywords cannot contain escape characters").with_label(span)
 }
 
 #[cold]
 pub fn auto_semicolon_insertion(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
+    ts_error(
+        "1005",
         "Expected a semicolon or an implicit semicolon after a statement, but found none",
     )
     .with_help("Try inserting a semicolon here")
@@ -255,28 +304,28 @@ pub fn auto_semicolon_insertion(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn lineterminator_before_arrow(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Line terminator not permitted before arrow")
+    ts_error("1200", "Line terminator not permitted before arrow")
         .with_label(span)
         .with_help("Remove the line break before here")
 }
 
 #[cold]
 pub fn invalid_destructuring_declaration(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Missing initializer in destructuring declaration")
+    ts_error("1182", "Missing initializer in destructuring declaration")
         .with_label(span)
         .with_help("Add an initializer (e.g. ` = undefined`) here")
 }
 
 #[cold]
 pub fn missing_initializer_in_const(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Missing initializer in const declaration")
+    ts_error("1155", "Missing initializer in const declaration")
         .with_label(span)
         .with_help("Add an initializer (e.g. ` = undefined`) here")
 }
 
 #[cold]
 pub fn lexical_declaration_single_statement(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Lexical declaration cannot appear in a single-statement context")
+    ts_error("1156", "Lexical declaration cannot appear in a single-statement context")
         .with_help("Wrap this declaration in a block statement")
         .with_label(span)
 }
@@ -349,7 +398,8 @@ pub fn generator_function_declaration(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn await_expression(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
+    ts_error(
+        "1308",
         "`await` is only allowed within async functions and at the top levels of modules",
     )
     .with_label(span)
@@ -358,7 +408,7 @@ pub fn await_expression(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn yield_expression(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A 'yield' expression is only allowed in a generator body.")
+    ts_error("1163", "A 'yield' expression is only allowed in a generator body.")
         .with_label(span)
         .with_help("Either remove this `yield` or change the enclosing function to a generator function (`function*`)")
 }
@@ -454,22 +504,23 @@ pub fn type_only_import_default_and_named(specifier_span: Span) -> OxcDiagnostic
 
 #[cold]
 pub fn binding_rest_element_last(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A rest element must be last in a destructuring pattern").with_label(span)
+    ts_error("2462", "A rest element must be last in a destructuring pattern").with_label(span)
 }
 
 #[cold]
 pub fn rest_parameter_last(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A rest parameter must be last in a parameter list").with_label(span)
+    ts_error("1014", "A rest parameter must be last in a parameter list").with_label(span)
 }
 
 #[cold]
 pub fn spread_last_element(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Spread must be last element").with_label(span)
+    ts_error("2462", "Spread must be last element").with_label(span)
 }
 
 #[cold]
 pub fn rest_element_trailing_comma(span: Span) -> OxcDiagnostic {
     unexpected_trailing_comma("A rest parameter or binding pattern", span)
+        .with_error_code("TS", "1013")
 }
 
 #[cold]
@@ -488,17 +539,18 @@ pub fn a_rest_parameter_cannot_be_optional(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn invalid_assignment(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Cannot assign to this expression").with_label(span)
+    ts_error("2364", "Cannot assign to this expression").with_label(span)
 }
 
 #[cold]
 pub fn assignment_is_not_simple(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid left-hand side in assignment").with_label(span)
+    ts_error("2364", "Invalid left-hand side in assignment").with_label(span)
 }
 
 #[cold]
 pub fn invalid_lhs_assignment(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
+    ts_error(
+        "2364",
         "The left-hand side of an assignment expression must be a variable or a property access.",
     )
     .with_label(span)
@@ -506,24 +558,24 @@ pub fn invalid_lhs_assignment(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn new_optional_chain(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Optional chaining cannot appear in the callee of new expressions")
+    ts_error("1209", "Optional chaining cannot appear in the callee of new expressions")
         .with_label(span)
 }
 
 #[cold]
 pub fn invalid_new_optional_chain(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid optional chain from new expression.").with_label(span)
+    ts_error("1209", "Invalid optional chain from new expression.").with_label(span)
 }
 
 #[cold]
 pub fn decorator_optional(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Expression must be enclosed in parentheses to be used as a decorator.")
+    ts_error("1497", "Expression must be enclosed in parentheses to be used as a decorator.")
         .with_label(span)
 }
 
 #[cold]
 pub fn for_loop_async_of(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("The left-hand side of a `for...of` statement may not be `async`")
+    ts_error("1106", "The left-hand side of a `for...of` statement may not be `async`")
         .with_label(span)
         .with_help("Did you mean to use a for await...of statement?")
 }
@@ -549,28 +601,27 @@ pub fn new_dynamic_import(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn new_super(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("'new super()' is not allowed").with_label(span)
+    ts_error("1034", "'new super()' is not allowed").with_label(span)
 }
 
 #[cold]
 pub fn private_name_constructor(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Classes can't have an element named '#constructor'").with_label(span)
+    ts_error("18012", "Classes can't have an element named '#constructor'").with_label(span)
 }
 
 #[cold]
 pub fn static_prototype(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Classes may not have a static property named 'prototype'")
-        .with_label(span)
+    ts_error("2699", "Classes may not have a static property named 'prototype'").with_label(span)
 }
 
 #[cold]
 pub fn constructor_getter_setter(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Constructor can't have get/set modifier").with_label(span)
+    ts_error("1341", "Constructor can't have get/set modifier").with_label(span)
 }
 
 #[cold]
 pub fn constructor_async(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Constructor can't be an async method").with_label(span)
+    ts_error("1089", "Constructor can't be an async method").with_label(span)
 }
 
 #[cold]
@@ -582,13 +633,13 @@ pub fn optional_accessor_property(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn constructor_accessor(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Classes may not have a field named 'constructor'").with_label(span)
+    ts_error("18006", "Classes may not have a field named 'constructor'").with_label(span)
 }
 
 #[cold]
 pub fn optional_definite_property(span: Span) -> OxcDiagnostic {
     // NOTE: could not find an error code when tsc parses this; its parser panics.
-    OxcDiagnostic::error("A property cannot be both optional and definite.")
+    ts_error("1255", "A property cannot be both optional and definite.")
         .with_label(span)
         .with_help("Remove either the `?` or the `!`")
 }
@@ -621,20 +672,21 @@ pub fn identifier_generator(x0: &str, span1: Span, looks_like_expression: bool)
 
 #[cold]
 pub fn identifier_expected(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Identifier expected.").with_label(span)
+    ts_error("1003", "Identifier expected.").with_label(span)
 }
 
 #[cold]
 pub fn identifier_reserved_word(span: Span, reserved: &str) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!(
-        "Identifier expected. '{reserved}' is a reserved word that cannot be used here."
-    ))
+    ts_error(
+        "1359",
+        format!("Identifier expected. '{reserved}' is a reserved word that cannot be used here."),
+    )
     .with_label(span)
 }
 
 #[cold]
 pub fn constructor_generator(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Constructor can't be a generator").with_label(span)
+    ts_error("1368", "Constructor can't be a generator").with_label(span)
 }
 
 #[cold]
@@ -650,7 +702,7 @@ pub fn constructor_return_type(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn field_constructor(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Classes can't have a field named 'constructor'").with_label(span)
+    ts_error("18006", "Classes can't have a field named 'constructor'").with_label(span)
 }
 
 #[cold]
@@ -679,7 +731,7 @@ pub fn template_literal(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn empty_parenthesized_expression(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Empty parenthesized expression").with_label(span)
+    ts_error("1109", "Empty parenthesized expression").with_label(span)
 }
 
 #[cold]
@@ -692,7 +744,7 @@ pub fn illegal_newline(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn optional_chain_tagged_template(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Tagged template expressions are not permitted in an optional chain")
+    ts_error("1358", "Tagged template expressions are not permitted in an optional chain")
         .with_label(span)
 }
 
@@ -740,7 +792,7 @@ pub fn ts_string_literal_expected(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn unexpected_super(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("'super' can only be used with function calls or in property accesses")
+    ts_error("1034", "'super' can only be used with function calls or in property accesses")
         .with_help("Replace with `super()` or `super.prop` or `super[prop]`")
         .with_label(span)
 }
@@ -752,18 +804,36 @@ pub fn super_private(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn expect_function_name(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Expected function name")
+    ts_error("1003", "Expected function name")
         .with_help("Function name is required in function declaration or named export")
         .with_label(span)
 }
 
 #[cold]
 pub fn expect_catch_finally(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Missing catch or finally clause")
+    ts_error("1472", "Missing catch or finally clause")
         .with_label(span)
         .with_help("Either unwrap this try block or add catch / finally clause")
 }
 
+#[cold]
+pub fn pipeline_topic_unused(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Pipe body must contain the topic reference `%`").with_label(span)
+}
+
+#[cold]
+pub fn pipeline_topic_outside_body(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Topic reference `%` is only allowed in the body of a pipe expression")
+        .with_label(span)
+}
+
+#[cold]
+pub fn pipeline_unparenthesized_body(kind: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("{kind} is not allowed as a pipe body without parentheses"))
+        .with_label(span)
+        .with_help("Wrap the pipe body in parentheses")
+}
+
 #[cold]
 pub fn v8_intrinsic_spread_elem(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("V8 runtime calls cannot have spread elements as arguments")
@@ -788,12 +858,13 @@ pub fn return_statement_only_in_function_body(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn invalid_identifier_in_using_declaration(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Using declarations may not have binding patterns.").with_label(span)
+    ts_error("1492", "Using declarations may not have binding patterns.").with_label(span)
 }
 
 #[cold]
 pub fn await_using_declaration_not_allowed_in_for_in_statement(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
+    ts_error(
+        "1494",
         "The left-hand side of a for...in statement cannot be an await using declaration.",
     )
     .with_label(span)
@@ -802,23 +873,21 @@ pub fn await_using_declaration_not_allowed_in_for_in_statement(span: Span) -> Ox
 
 #[cold]
 pub fn using_declaration_not_allowed_in_for_in_statement(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
-        "The left-hand side of a for...in statement cannot be an using declaration.",
-    )
-    .with_label(span)
-    .with_help("Did you mean to use a for...of statement?")
+    ts_error("1493", "The left-hand side of a for...in statement cannot be an using declaration.")
+        .with_label(span)
+        .with_help("Did you mean to use a for...of statement?")
 }
 
 #[cold]
 pub fn using_declarations_must_be_initialized(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Using declarations must have an initializer.")
+    ts_error("1155", "Using declarations must have an initializer.")
         .with_label(span)
         .with_help("Add an initializer (e.g. ` = undefined`) here")
 }
 
 #[cold]
 pub fn using_declaration_cannot_be_exported(identifier: &str, span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Using declarations cannot be exported directly.")
+    ts_error("1491", "Using declarations cannot be exported directly.")
         .with_label(span)
         .with_help(format!("Remove the `export` here and add `export {{ {identifier} }}` as a separate statement to export the declaration"))
 }
@@ -843,16 +912,14 @@ pub fn await_using_declarations_not_allowed_in_ambient_contexts(span: Span) -> O
 
 #[cold]
 pub fn jsx_element_no_match(span: Span, span1: Span, name: &str) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Expected corresponding JSX closing tag for '{name}'."))
-        .with_labels([
-            span1.primary_label(format!("Expected `</{name}>`")),
-            span.label("Opened here"),
-        ])
+    ts_error("17002", format!("Expected corresponding JSX closing tag for '{name}'.")).with_labels(
+        [span1.primary_label(format!("Expected `</{name}>`")), span.label("Opened here")],
+    )
 }
 
 #[cold]
 pub fn jsx_fragment_no_match(opening_span: Span, closing_span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Expected corresponding closing tag for JSX fragment.").with_labels([
+    ts_error("17015", "Expected corresponding closing tag for JSX fragment.").with_labels([
         closing_span.primary_label("Expected `</>`"),
         opening_span.label("Opened here"),
     ])
@@ -860,14 +927,14 @@ pub fn jsx_fragment_no_match(opening_span: Span, closing_span: Span) -> OxcDiagn
 
 #[cold]
 pub fn adjacent_jsx_elements(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Adjacent JSX elements must be wrapped in an enclosing tag.")
+    ts_error("2657", "Adjacent JSX elements must be wrapped in an enclosing tag.")
         .with_help("Did you want a JSX fragment `<>...</>`?")
         .with_label(span)
 }
 
 #[cold]
 pub fn cover_initialized_name(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid assignment in object literal")
+    ts_error("1312", "Invalid assignment in object literal")
 .with_help("Did you mean to use a ':'? An '=' can only follow a property name when the containing object literal is part of a destructuring pattern.")
 .with_label(span)
 }
@@ -887,7 +954,7 @@ pub fn duplicate_default_export(spans: impl IntoIterator<Item = Span>) -> OxcDia
 
 #[cold]
 pub fn invalid_import_property(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
+    ts_error("17012", 
         "The only valid property accesses on import are `import.meta`, `import.source()`, and `import.defer()`",
     )
     .with_label(span)
@@ -895,12 +962,12 @@ pub fn invalid_import_property(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn new_target(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("The only valid meta property for new is new.target").with_label(span)
+    ts_error("17012", "The only valid meta property for new is new.target").with_label(span)
 }
 
 #[cold]
 pub fn new_target_outside_function(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected new.target expression")
+    ts_error("17013", "Unexpected new.target expression")
         .with_help(
             "new.target is only allowed in constructors, functions, and class field initializers",
         )
@@ -935,7 +1002,7 @@ pub fn unexpected_private_identifier(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn import_arguments(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Dynamic imports can only accept a module specifier and an optional set of attributes as arguments").with_label(span)
+    ts_error("1450", "Dynamic imports can only accept a module specifier and an optional set of attributes as arguments").with_label(span)
 }
 
 #[cold]
@@ -945,17 +1012,17 @@ pub fn rest_element_property_name(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn a_rest_element_cannot_have_an_initializer(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A rest element cannot have an initializer.").with_label(span)
+    ts_error("1186", "A rest element cannot have an initializer.").with_label(span)
 }
 
 #[cold]
 pub fn a_rest_parameter_cannot_have_an_initializer(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A rest parameter cannot have an initializer.").with_label(span)
+    ts_error("1048", "A rest parameter cannot have an initializer.").with_label(span)
 }
 
 #[cold]
 pub fn import_requires_a_specifier(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("import() requires a specifier.").with_label(span)
+    ts_error("1450", "import() requires a specifier.").with_label(span)
 }
 
 #[cold]
@@ -963,7 +1030,7 @@ pub fn modifier_cannot_be_used_here(
     modifier: &Modifier,
     allowed: Option<ModifierKinds>,
 ) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("'{}' modifier cannot be used here.", modifier.kind))
+    ts_error("1042", format!("'{}' modifier cannot be used here.", modifier.kind))
         .with_label(modifier.span())
         .with_allowed_modifier_help(allowed)
 }
@@ -1182,14 +1249,14 @@ pub fn index_signature_parameter_literal_type(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn mixed_coalesce(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Logical expressions and coalesce expressions cannot be mixed")
+    ts_error("5076", "Logical expressions and coalesce expressions cannot be mixed")
         .with_help("Wrap either expression by parentheses")
         .with_label(span)
 }
 
 #[cold]
 pub fn unexpected_exponential(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected exponentiation expression")
+    ts_error("17006", "Unexpected exponentiation expression")
         .with_help(format!("Wrap {x0} expression in parentheses to enforce operator precedence"))
         .with_label(span1)
 }
@@ -1276,12 +1343,12 @@ pub fn unexpected_export(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn decorators_in_export_and_class(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Decorators may not appear after 'export' or 'export default' if they also appear before 'export'.").with_label(span)
+    ts_error("8038", "Decorators may not appear after 'export' or 'export default' if they also appear before 'export'.").with_label(span)
 }
 
 #[cold]
 pub fn decorators_are_not_valid_here(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Decorators are not valid here.").with_label(span)
+    ts_error("1206", "Decorators are not valid here.").with_label(span)
 }
 
 #[cold]
@@ -1328,6 +1395,15 @@ pub fn parameter_modifiers_in_ts(
         .with_allowed_modifier_help(allowed)
 }
 
+#[cold]
+pub fn modifier_can_only_be_used_in_typescript_files(modifier: &Modifier) -> OxcDiagnostic {
+    ts_error(
+        "8009",
+        format!("The '{}' modifier can only be used in TypeScript files.", modifier.kind),
+    )
+    .with_label(modifier.span())
+}
+
 #[cold]
 pub fn implements_clause_in_ts(span: Span) -> OxcDiagnostic {
     ts_error("8005", "'implements' clauses can only be used in TypeScript files.").with_label(span)
@@ -1387,16 +1463,16 @@ pub fn setter_with_parameters(span: Span, parameters_count: usize) -> OxcDiagnos
 
 #[cold]
 pub fn setter_with_rest_parameter(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A 'set' accessor cannot have rest parameter.").with_label(span)
+    ts_error("1053", "A 'set' accessor cannot have rest parameter.").with_label(span)
 }
 #[cold]
 pub fn setter_with_initializer(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A 'set' accessor cannot have an initializer.").with_label(span)
+    ts_error("1052", "A 'set' accessor cannot have an initializer.").with_label(span)
 }
 
 #[cold]
 pub fn getter_parameters(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A 'get' accessor must not have any formal parameters.")
+    ts_error("1054", "A 'get' accessor must not have any formal parameters.")
         .with_label(span)
         .with_help("Remove these parameters here")
 }
@@ -1460,7 +1536,7 @@ pub fn expect_function_body(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn expect_switch_clause(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Expected switch clause")
+    ts_error("1130", "Expected switch clause")
         .with_label(span.label("`case` or `default` clause expected here"))
         .with_help("If this is intended to be the condition for the switch statement, add `case` before it.")
 }
@@ -1474,7 +1550,7 @@ pub fn unexpected_optional_declaration(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn identifier_expected_after_question_dot(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Identifier expected after '?.'")
+    ts_error("1003", "Identifier expected after '?.'")
         .with_label(span)
         .with_help("Add an identifier after '?.'")
 }
@@ -1494,7 +1570,7 @@ pub fn jsx_attribute_value_empty_expression(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn import_attribute_value_must_be_string_literal(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Only string literals are allowed as module attribute values.")
+    ts_error("2858", "Only string literals are allowed as module attribute values.")
         .with_label(span)
         .with_help("Wrap this with quotes")
 }
@@ -1519,7 +1595,7 @@ pub fn only_default_import_allowed_in_source_phase(span: Span) -> OxcDiagnostic
 
 #[cold]
 pub fn ts_import_type_options_expected_with(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Expected 'with' in import type options").with_label(span)
+    ts_error("1005", "Expected 'with' in import type options").with_label(span)
 }
 
 #[cold]
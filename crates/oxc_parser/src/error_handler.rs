@@ -1,18 +1,44 @@
 //! Code related to error handling.
 
-use oxc_allocator::{Dummy, GetAllocator};
+use oxc_allocator::{ArenaBox, Dummy, GetAllocator};
+use oxc_ast::ast::ErrorNode;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
 
-use crate::{ParserConfig as Config, ParserImpl, diagnostics, lexer::Kind};
+use crate::{
+    ParserConfig as Config, ParserImpl, diagnostics,
+    lexer::{Kind, LexerCheckpoint, Token},
+};
 
 /// Fatal parsing error.
 #[derive(Debug, Clone)]
-pub struct FatalError {
+pub struct FatalError<'a> {
     /// The fatal error
     pub error: OxcDiagnostic,
     /// Length of `errors` at time fatal error is recorded
     pub errors_len: usize,
+    /// Position of the token which caused the fatal error.
+    /// Only recorded when [`ParseOptions::error_recovery`](crate::ParseOptions::error_recovery) is enabled.
+    pub recovery_point: Option<RecoveryPoint<'a>>,
+}
+
+/// Parser position to resume from when recovering from a [`FatalError`].
+#[derive(Debug, Clone)]
+pub struct RecoveryPoint<'a> {
+    lexer: LexerCheckpoint<'a>,
+    token: Token,
+    prev_token_end: u32,
+}
+
+/// Kind of list element to resynchronize at when recovering from a [`FatalError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryBoundary {
+    /// Skip to the end of the statement, or to the start of the next statement.
+    Statement,
+    /// Skip to the end of the class member, or to the start of the next class member.
+    ClassElement,
+    /// Skip to the `,` or `}` which ends the object property.
+    ObjectProperty,
 }
 
 impl<'a, C: Config> ParserImpl<'a, C> {
@@ -80,8 +106,14 @@ impl<'a, C: Config> ParserImpl<'a, C> {
     #[cold]
     pub(crate) fn set_fatal_error(&mut self, error: OxcDiagnostic) {
         if self.fatal_error.is_none() {
+            let recovery_point = self.options.error_recovery.then(|| RecoveryPoint {
+                lexer: self.lexer.checkpoint(),
+                token: self.token,
+                prev_token_end: self.prev_token_end,
+            });
             self.lexer.advance_to_end();
-            self.fatal_error = Some(FatalError { error, errors_len: self.errors.len() });
+            self.fatal_error =
+                Some(FatalError { error, errors_len: self.errors.len(), recovery_point });
         }
     }
 
@@ -96,6 +128,145 @@ impl<'a, C: Config> ParserImpl<'a, C> {
     }
 }
 
+// ==================== Error Recovery ====================
+//
+// When `ParseOptions::error_recovery` is enabled, a fatal error raised while parsing a statement,
+// class member or object property does not abort parsing. The list parsing that element rewinds
+// the lexer to the token which caused the error, skips tokens until the next element boundary,
+// and records the skipped source text as an `ErrorNode`.
+impl<'a, C: Config> ParserImpl<'a, C> {
+    /// Recover from a fatal error raised while parsing a list element which starts at `start`.
+    ///
+    /// Returns `None` if there is no fatal error, or if error recovery is disabled.
+    #[inline]
+    pub(crate) fn recover_from_fatal_error(
+        &mut self,
+        start: u32,
+        boundary: RecoveryBoundary,
+    ) -> Option<ArenaBox<'a, ErrorNode>> {
+        if self.fatal_error.is_none() || !self.options.error_recovery {
+            return None;
+        }
+        self.recover_from_fatal_error_impl(start, boundary)
+    }
+
+    #[cold]
+    fn recover_from_fatal_error_impl(
+        &mut self,
+        start: u32,
+        boundary: RecoveryBoundary,
+    ) -> Option<ArenaBox<'a, ErrorNode>> {
+        let FatalError { error, errors_len, recovery_point } = self.fatal_error.take()?;
+        let Some(RecoveryPoint { lexer, token, prev_token_end }) = recovery_point else {
+            self.fatal_error = Some(FatalError { error, errors_len, recovery_point: None });
+            return None;
+        };
+
+        // Errors recorded after the fatal error are caused by unwinding from it.
+        self.errors.truncate(errors_len);
+        self.error(error);
+
+        self.lexer.rewind(lexer);
+        // Parsing may resume at this token, so the failed parse must not claim its `@__PURE__` comment
+        self.lexer.trivia_builder.reset_current_pure_comment();
+        self.token = token;
+        self.prev_token_end = prev_token_end;
+
+        self.skip_to_recovery_boundary(boundary);
+
+        // Always make progress, so the enclosing list cannot loop forever.
+        // Object property lists consume their own `,` and `}`.
+        if self.start_span() == start
+            && !matches!(self.cur_kind(), Kind::Eof)
+            && !(boundary == RecoveryBoundary::ObjectProperty
+                && matches!(self.cur_kind(), Kind::Comma | Kind::RCurly))
+        {
+            self.bump_any();
+        }
+
+        let span = Span::new(start, self.prev_token_end.max(start));
+        Some(ErrorNode::boxed(span, self))
+    }
+
+    /// Skip tokens until the end of the current list element, tracking nested brackets so that
+    /// boundaries inside them are ignored.
+    fn skip_to_recovery_boundary(&mut self, boundary: RecoveryBoundary) {
+        let mut depth = 0u32;
+        // Bracket depths at which template literal substitutions were opened
+        let mut template_depths = vec![];
+        loop {
+            let kind = self.cur_kind();
+            match kind {
+                Kind::Eof => return,
+                Kind::LParen | Kind::LBrack | Kind::LCurly => depth += 1,
+                Kind::TemplateHead => template_depths.push(depth),
+                Kind::RParen | Kind::RBrack => depth = depth.saturating_sub(1),
+                Kind::RCurly if template_depths.last() == Some(&depth) => {
+                    self.re_lex_template_substitution_tail();
+                    if self.at(Kind::TemplateTail) {
+                        template_depths.pop();
+                    }
+                }
+                Kind::RCurly if depth == 0 => return,
+                Kind::RCurly => depth -= 1,
+                Kind::Semicolon if depth == 0 && boundary != RecoveryBoundary::ObjectProperty => {
+                    self.bump_any();
+                    return;
+                }
+                Kind::Comma if depth == 0 && boundary == RecoveryBoundary::ObjectProperty => {
+                    return;
+                }
+                _ if depth == 0
+                    && self.cur_token().is_on_new_line()
+                    && Self::starts_element_on_new_line(kind, boundary) =>
+                {
+                    return;
+                }
+                _ => {}
+            }
+            self.bump_any();
+        }
+    }
+
+    /// Whether a token at the start of a line is likely to start a new list element.
+    fn starts_element_on_new_line(kind: Kind, boundary: RecoveryBoundary) -> bool {
+        match boundary {
+            RecoveryBoundary::Statement => matches!(
+                kind,
+                Kind::Var
+                    | Kind::Let
+                    | Kind::Const
+                    | Kind::Function
+                    | Kind::Class
+                    | Kind::If
+                    | Kind::For
+                    | Kind::While
+                    | Kind::Do
+                    | Kind::Return
+                    | Kind::Switch
+                    | Kind::Try
+                    | Kind::Throw
+                    | Kind::Break
+                    | Kind::Continue
+                    | Kind::Debugger
+                    | Kind::Import
+                    | Kind::Export
+                    | Kind::Interface
+                    | Kind::Enum
+                    | Kind::Declare
+                    | Kind::Namespace
+                    | Kind::Abstract
+                    | Kind::At
+            ),
+            RecoveryBoundary::ClassElement => {
+                kind.is_identifier_or_keyword()
+                    || matches!(kind, Kind::LBrack | Kind::Star | Kind::At)
+            }
+            RecoveryBoundary::ObjectProperty => false,
+        }
+    }
+}
+
 // ==================== Merge Conflict Marker Detection ====================
 //
 // Git merge conflict markers detection and error recovery.
@@ -0,0 +1,501 @@
+//! [Flow](https://flow.org) Parsing Functions
+//!
+//! Flow syntax which is shared with TypeScript is parsed by the TypeScript parsing functions,
+//! which are enabled for Flow files. This module only contains the syntax unique to Flow.
+//!
+//! TypeScript-only syntax, e.g. `enum`, `namespace` or the `private` modifier, is an error in Flow
+//! files.
+//!
+//! The existential type `*` is parsed as `any`, and parameters and indexers without names, e.g.
+//! `string` in `(string) => void`, are represented by an empty array pattern with an empty span
+//! and an index signature parameter with an empty name.
+//!
+//! Not supported:
+//! * `declare module.exports: T` and `declare export default T` of a type.
+//! * Object type spreads and explicit inexact object types, e.g. `{ ...T, ... }`.
+//! * Enums, `%checks` predicates and `[[internal]]` slots.
+
+use oxc_allocator::{ArenaBox, ArenaVec};
+use oxc_ast::{ast::*, builder::NONE};
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    Context, ParserConfig as Config, ParserImpl, diagnostics,
+    js::FunctionKind,
+    lexer::Kind,
+    modifiers::{ModifierKind, ModifierKinds, Modifiers},
+};
+
+impl<'a, C: Config> ParserImpl<'a, C> {
+    /// Check if the parser is at the start of `opaque type`.
+    pub(crate) fn at_flow_opaque_type(&mut self) -> bool {
+        if !self.at(Kind::Ident) || self.cur_src() != "opaque" {
+            return false;
+        }
+        let next = self.lexer.peek_token();
+        next.kind() == Kind::Type && !next.is_on_new_line()
+    }
+
+    /// `opaque type ID: Supertype = Impltype;`
+    ///
+    /// [Flow - Opaque Type Aliases](https://flow.org/en/docs/types/opaque-types/)
+    pub(crate) fn parse_flow_opaque_type_declaration(
+        &mut self,
+        span: u32,
+        modifiers: &Modifiers,
+    ) -> Declaration<'a> {
+        self.bump_any(); // bump `opaque`
+        self.expect(Kind::Type);
+        let id = self.parse_binding_identifier();
+        let type_parameters = self.parse_ts_type_parameters_with_variance();
+        let supertype = if self.eat(Kind::Colon) { Some(self.parse_ts_type()) } else { None };
+        let declare = modifiers.contains_declare();
+        // `declare opaque type` has no underlying type
+        let impltype = if declare {
+            None
+        } else {
+            self.expect(Kind::Eq);
+            Some(self.parse_ts_type())
+        };
+        self.asi();
+        let span = self.end_span(span);
+        self.verify_modifiers(
+            modifiers,
+            ModifierKinds::new([ModifierKind::Declare]),
+            true,
+            diagnostics::modifier_cannot_be_used_here,
+        );
+        Declaration::new_flow_opaque_type_declaration(
+            span,
+            id,
+            type_parameters,
+            supertype,
+            impltype,
+            declare,
+            self,
+        )
+    }
+
+    /// Parse an element of a parenthesized expression, which may be a type cast `(expr: Type)`.
+    ///
+    /// [Flow - Type Casting Expressions](https://flow.org/en/docs/types/casting/)
+    pub(crate) fn parse_assignment_expression_or_flow_type_cast(&mut self) -> Expression<'a> {
+        let span = self.start_span();
+        let expression = self.parse_assignment_expression_or_higher();
+        if !self.at(Kind::Colon) {
+            return expression;
+        }
+        let type_annotation = self.parse_flow_type_annotation();
+        Expression::new_flow_type_cast_expression(
+            self.end_span(span),
+            expression,
+            type_annotation,
+            self,
+        )
+    }
+
+    fn parse_flow_type_annotation(&mut self) -> ArenaBox<'a, TSTypeAnnotation<'a>> {
+        let span = self.start_span();
+        self.bump_any(); // bump `:`
+        let type_annotation = self.parse_ts_type();
+        TSTypeAnnotation::boxed(self.end_span(span), type_annotation, self)
+    }
+
+    /// Exact object type `{| foo: string |}`, which is represented as a type literal.
+    ///
+    /// [Flow - Exact object types](https://flow.org/en/docs/types/objects/#exact-and-inexact-object-types)
+    pub(crate) fn parse_flow_exact_object_type(&mut self) -> TSType<'a> {
+        let span = self.start_span();
+        self.bump_any(); // bump `{`
+        let members = if self.eat(Kind::Pipe2) {
+            // `{||}`
+            ArenaVec::new_in(self)
+        } else {
+            self.parse_normal_list(Kind::Pipe, Kind::Pipe, Self::parse_ts_type_signature)
+        };
+        self.expect(Kind::RCurly);
+        TSType::new_ts_type_literal(self.end_span(span), members, self)
+    }
+
+    /// Check if the parser is at the `|}` closing an exact object type,
+    /// which must not be parsed as a union.
+    pub(crate) fn at_flow_exact_object_type_end(&mut self) -> bool {
+        self.is_flow && self.at(Kind::Pipe) && self.lexer.peek_token().kind() == Kind::RCurly
+    }
+
+    /// Eat a variance sigil before a property, e.g. `+` in `{ +foo: string }`.
+    ///
+    /// Returns `true` for `+` (read-only), which is represented as a `readonly` property.
+    /// A `-` (write-only) sigil has no TypeScript equivalent and is dropped.
+    pub(crate) fn eat_flow_variance(&mut self) -> bool {
+        if !self.is_flow {
+            return false;
+        }
+        match self.cur_kind() {
+            Kind::Plus => {
+                self.bump_any();
+                true
+            }
+            Kind::Minus => {
+                self.bump_any();
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Parse a function type, or a type with a higher precedence.
+    ///
+    /// A function type with a single unnamed parameter may omit the parentheses, e.g.
+    /// `string => void`, except in the return type of an arrow function.
+    ///
+    /// [Flow - Function Types](https://flow.org/en/docs/types/functions/)
+    pub(crate) fn parse_flow_function_type_or_higher(&mut self) -> TSType<'a> {
+        let span = self.start_span();
+        let ty = self.parse_flow_prefix_type();
+        if !self.at(Kind::Arrow) || self.ctx.has_disallow_flow_anon_function_type() {
+            return ty;
+        }
+        // `string => void`
+        let param_span = ty.span();
+        let type_annotation = TSTypeAnnotation::boxed(param_span, ty, self);
+        let param = FormalParameter::new(
+            param_span,
+            ArenaVec::new_in(self),
+            self.new_flow_unnamed_pattern(param_span),
+            Some(type_annotation),
+            NONE,
+            false,
+            None,
+            false,
+            false,
+            self,
+        );
+        let params = FormalParameters::boxed(
+            param_span,
+            FormalParameterKind::Signature,
+            ArenaVec::from_value_in(param, self),
+            NONE,
+            self,
+        );
+        let return_type = self.parse_flow_function_type_return_type();
+        TSType::new_ts_function_type(self.end_span(span), NONE, NONE, params, return_type, self)
+    }
+
+    /// Parse a type which may be a function type with parenthesized parameters, but not one
+    /// without, e.g. `T` in the nullable type `?T`.
+    pub(crate) fn parse_flow_prefix_type(&mut self) -> TSType<'a> {
+        let span = self.start_span();
+        if self.at(Kind::LAngle)
+            || (self.at(Kind::LParen) && self.lookahead(Self::is_flow_function_type_params))
+        {
+            return self.parse_flow_function_type(span);
+        }
+        self.context_remove(
+            Context::DisallowConditionalTypes | Context::DisallowFlowAnonFunctionType,
+            Self::parse_postfix_type_or_higher,
+        )
+    }
+
+    /// Check if the `(` the parser is at starts the parameters of a function type.
+    fn is_flow_function_type_params(&mut self) -> bool {
+        self.bump_any(); // bump `(`
+        // `()`, `(...`, `(name:` and `(name?:` are always parameters
+        if matches!(self.cur_kind(), Kind::RParen | Kind::Dot3) {
+            return true;
+        }
+        if (self.cur_kind().is_binding_identifier() || self.at(Kind::This))
+            && matches!(self.lexer.peek_token().kind(), Kind::Colon | Kind::Question)
+        {
+            return true;
+        }
+        // `(x): (string) => void => {}` returns `string`, which is followed by the arrow
+        if self.ctx.has_disallow_flow_anon_function_type() {
+            return false;
+        }
+        // `(string, number) => void`, unlike the parenthesized type `(string)`, is followed by `=>`
+        let mut depth = 1u32;
+        loop {
+            match self.cur_kind() {
+                Kind::LParen => depth += 1,
+                Kind::RParen => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.bump_any();
+                        return self.at(Kind::Arrow);
+                    }
+                }
+                Kind::Eof | Kind::Undetermined => return false,
+                _ => {}
+            }
+            self.bump_any();
+        }
+    }
+
+    /// `<T>(x: T, string) => T`
+    fn parse_flow_function_type(&mut self, span: u32) -> TSType<'a> {
+        let type_parameters = self.parse_ts_type_parameters();
+        let (this_param, params) =
+            self.parse_flow_function_type_params(FormalParameterKind::Signature);
+        let return_type = self.parse_flow_function_type_return_type();
+        TSType::new_ts_function_type(
+            self.end_span(span),
+            type_parameters,
+            this_param,
+            params,
+            return_type,
+            self,
+        )
+    }
+
+    fn parse_flow_function_type_return_type(&mut self) -> TSTypeAnnotation<'a> {
+        let span = self.start_span();
+        self.expect(Kind::Arrow);
+        let return_type = self
+            .context_remove(Context::DisallowConditionalTypes, Self::parse_type_or_type_predicate);
+        TSTypeAnnotation::new(self.end_span(span), return_type, self)
+    }
+
+    /// Parse the parameters of a function type, or of a function or method without a body,
+    /// which may be unnamed, e.g. `(string, y?: number, ...Array<string>)`.
+    pub(crate) fn parse_flow_function_type_params(
+        &mut self,
+        params_kind: FormalParameterKind,
+    ) -> (Option<TSThisParameter<'a>>, ArenaBox<'a, FormalParameters<'a>>) {
+        let span = self.start_span();
+        let opening_span = self.cur_token().span();
+        self.expect(Kind::LParen);
+        let (this_param, items, rest) = self.context_remove(
+            Context::DisallowConditionalTypes | Context::DisallowFlowAnonFunctionType,
+            |p| {
+                let this_param = if p.at(Kind::This) && p.lexer.peek_token().kind() == Kind::Colon {
+                    let param = p.parse_ts_this_parameter();
+                    p.bump(Kind::Comma);
+                    Some(param)
+                } else {
+                    None
+                };
+                let mut items = ArenaVec::new_in(p);
+                let mut rest = None;
+                while !matches!(p.cur_kind(), Kind::RParen | Kind::Eof) && p.fatal_error.is_none() {
+                    if p.at(Kind::Dot3) {
+                        rest = Some(p.parse_flow_function_type_rest_param());
+                        p.bump(Kind::Comma);
+                        break;
+                    }
+                    items.push(p.parse_flow_function_type_param());
+                    if !p.eat(Kind::Comma) {
+                        break;
+                    }
+                }
+                (this_param, items, rest)
+            },
+        );
+        self.expect_closing(Kind::RParen, opening_span);
+        let params = FormalParameters::boxed(self.end_span(span), params_kind, items, rest, self);
+        (this_param, params)
+    }
+
+    fn parse_flow_function_type_param(&mut self) -> FormalParameter<'a> {
+        let span = self.start_span();
+        let (pattern, optional, type_annotation) = self.parse_flow_function_type_param_parts();
+        let pattern =
+            pattern.unwrap_or_else(|| self.new_flow_unnamed_pattern(type_annotation.span));
+        FormalParameter::new(
+            self.end_span(span),
+            ArenaVec::new_in(self),
+            pattern,
+            Some(type_annotation),
+            NONE,
+            optional,
+            None,
+            false,
+            false,
+            self,
+        )
+    }
+
+    fn parse_flow_function_type_rest_param(&mut self) -> ArenaBox<'a, FormalParameterRest<'a>> {
+        let span = self.start_span();
+        self.bump_any(); // bump `...`
+        let dot3_end = self.prev_token_end;
+        let (argument, _, type_annotation) = self.parse_flow_function_type_param_parts();
+        // The rest element is `...name`, or only `...` if the parameter is unnamed
+        let (rest_span, argument) = match argument {
+            Some(argument) => (Span::new(span, argument.span().end), argument),
+            None => {
+                (Span::new(span, dot3_end), self.new_flow_unnamed_pattern(type_annotation.span))
+            }
+        };
+        let rest = BindingRestElement::new(rest_span, argument, self);
+        FormalParameterRest::boxed(
+            self.end_span(span),
+            ArenaVec::new_in(self),
+            rest,
+            Some(type_annotation),
+            self,
+        )
+    }
+
+    /// Parse `name: T`, `name?: T` or `T`, and return the name (`None` for `T`), whether the
+    /// parameter is optional, and the type annotation.
+    fn parse_flow_function_type_param_parts(
+        &mut self,
+    ) -> (Option<BindingPattern<'a>>, bool, ArenaBox<'a, TSTypeAnnotation<'a>>) {
+        let named = self.cur_kind().is_binding_identifier()
+            && matches!(self.lexer.peek_token().kind(), Kind::Colon | Kind::Question);
+        let pattern = named.then(|| self.parse_binding_pattern_kind());
+        let optional = named && self.eat(Kind::Question);
+        let span = self.start_span();
+        if named {
+            self.expect(Kind::Colon);
+        }
+        let ty = self.parse_ts_type();
+        let type_annotation = TSTypeAnnotation::boxed(self.end_span(span), ty, self);
+        (pattern, optional, type_annotation)
+    }
+
+    /// Pattern of an unnamed parameter, which is an empty array pattern with an empty span at the
+    /// start of the parameter's type, e.g. `string` in `(string) => void`.
+    fn new_flow_unnamed_pattern(&self, type_span: Span) -> BindingPattern<'a> {
+        BindingPattern::new_array_pattern(
+            Span::empty(type_span.start),
+            ArenaVec::new_in(self),
+            NONE,
+            self,
+        )
+    }
+
+    /// Object type indexer `[K]: V` or `[name: K]: V`, which is represented as an index signature.
+    ///
+    /// [Flow - Objects as maps](https://flow.org/en/docs/types/objects/#toc-objects-as-maps)
+    pub(crate) fn parse_flow_indexer(&mut self, span: u32, readonly: bool) -> TSSignature<'a> {
+        let opening_span = self.cur_token().span();
+        self.bump_any(); // bump `[`
+        let param_span = self.start_span();
+        let name = if self.cur_kind().is_identifier_name()
+            && self.lexer.peek_token().kind() == Kind::Colon
+        {
+            let name = self.parse_identifier_name().name;
+            self.bump_any(); // bump `:`
+            Str::from(name)
+        } else {
+            Str::empty()
+        };
+        let key_span = self.start_span();
+        let key = self.parse_ts_type();
+        let key = TSTypeAnnotation::boxed(self.end_span(key_span), key, self);
+        let param = TSIndexSignatureName::new(self.end_span(param_span), name, key, self);
+        self.expect_closing(Kind::RBrack, opening_span);
+        let value_span = self.start_span();
+        self.expect(Kind::Colon);
+        let value = self.parse_ts_type();
+        let value = TSTypeAnnotation::boxed(self.end_span(value_span), value, self);
+        self.parse_type_member_semicolon();
+        TSSignature::new_ts_index_signature(
+            self.end_span(span),
+            ArenaVec::from_value_in(param, self),
+            value,
+            readonly,
+            false,
+            self,
+        )
+    }
+
+    /// `declare export function f(): void;`, `declare export default class C {}`, etc.
+    ///
+    /// [Flow - Declaration Files](https://flow.org/en/docs/libdefs/creation/)
+    pub(crate) fn parse_flow_declare_export_declaration(&mut self, span: u32) -> Statement<'a> {
+        let modifiers = Modifiers::new_single(ModifierKind::Declare, span);
+        self.bump_any(); // bump `declare`
+        self.bump_any(); // bump `export`
+        let reserved_ctx = self.ctx;
+        self.ctx = self.ctx.union_ambient_if(true);
+        let decl = match self.cur_kind() {
+            // `declare export * from 'mod'` and `declare export { a }` are the same without `declare`
+            Kind::Star => {
+                ModuleDeclaration::ExportAllDeclaration(self.parse_export_all_declaration(span))
+            }
+            Kind::LCurly => {
+                ModuleDeclaration::ExportNamedDeclaration(self.parse_export_named_specifiers(span))
+            }
+            Kind::Default => {
+                let default_keyword_span = self.cur_token().span();
+                self.bump_any();
+                let decl_span = self.start_span();
+                let declaration = match self.cur_kind() {
+                    Kind::Function => {
+                        self.bump_any();
+                        let id = self.parse_function_id(FunctionKind::DefaultExport, false, false);
+                        ExportDefaultDeclarationKind::FunctionDeclaration(self.parse_function(
+                            decl_span,
+                            id,
+                            /* async */ false,
+                            /* generator */ false,
+                            FunctionKind::DefaultExport,
+                            FormalParameterKind::FormalParameter,
+                            &modifiers,
+                        ))
+                    }
+                    Kind::Class => ExportDefaultDeclarationKind::ClassDeclaration(
+                        self.parse_class_declaration(decl_span, &modifiers, ArenaVec::new_in(self)),
+                    ),
+                    _ => {
+                        self.ctx = reserved_ctx;
+                        let error =
+                            diagnostics::flow_declare_export_default_type(self.end_span(span));
+                        return self.fatal_error(error);
+                    }
+                };
+                let decl = ExportDefaultDeclaration::boxed(self.end_span(span), declaration, self);
+                if reserved_ctx.has_top_level() {
+                    self.module_record_builder
+                        .visit_export_default_declaration(&decl, default_keyword_span);
+                }
+                ModuleDeclaration::ExportDefaultDeclaration(decl)
+            }
+            _ => {
+                let decl_span = self.start_span();
+                let declaration =
+                    self.parse_declaration(decl_span, &modifiers, ArenaVec::new_in(self));
+                let decl = ExportNamedDeclaration::boxed(
+                    self.end_span(span),
+                    Some(declaration),
+                    ArenaVec::new_in(self),
+                    None,
+                    ImportOrExportKind::Type,
+                    NONE,
+                    self,
+                );
+                if reserved_ctx.has_top_level() {
+                    self.module_record_builder.visit_export_named_declaration(&decl);
+                }
+                ModuleDeclaration::ExportNamedDeclaration(decl)
+            }
+        };
+        self.ctx = reserved_ctx;
+        Statement::from(decl)
+    }
+
+    /// Check if the parser is at the start of `declare module.exports: T`.
+    pub(crate) fn at_flow_declare_module_exports(&mut self) -> bool {
+        self.lookahead(|p| {
+            p.bump_any(); // bump `declare`
+            p.at(Kind::Module) && !p.cur_token().is_on_new_line() && {
+                p.bump_any();
+                p.at(Kind::Dot)
+            }
+        })
+    }
+
+    /// `declare module.exports: T`, which has no equivalent in TypeScript.
+    pub(crate) fn parse_flow_declare_module_exports(&mut self) -> Statement<'a> {
+        let span = self.start_span();
+        self.bump_any(); // bump `declare`
+        self.bump_any(); // bump `module`
+        self.bump_any(); // bump `.`
+        self.expect(Kind::Ident);
+        let error = diagnostics::flow_declare_module_exports(self.end_span(span));
+        self.fatal_error(error)
+    }
+}
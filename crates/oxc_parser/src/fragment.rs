@@ -0,0 +1,151 @@
+//! Parsing of source text which is a fragment of a program, rather than a whole program.
+//!
+//! See [`Parser::parse_statement`](crate::Parser::parse_statement) and its siblings.
+
+use oxc_allocator::{ArenaVec, Dummy, GetAllocator};
+use oxc_ast::ast::{BindingPattern, ClassElement, Statement, TSType};
+use oxc_diagnostics::Diagnostics;
+use oxc_span::GetSpan;
+
+use crate::{
+    Context, FatalError, ParserConfig as Config, ParserImpl, StatementContext, diagnostics,
+    lexer::Kind,
+};
+
+/// Return value of the fragment parsers, e.g. [`Parser::parse_statement`], consisting of the
+/// parsed node and errors.
+///
+/// As with [`ParserReturn`], the parser recovers from some syntax errors. Then [`diagnostics`]
+/// is non-empty, but [`node`] is still a full AST and [`panicked`] is `false`.
+///
+/// [`Parser::parse_statement`]: crate::Parser::parse_statement
+/// [`ParserReturn`]: crate::ParserReturn
+/// [`node`]: FragmentReturn::node
+/// [`diagnostics`]: FragmentReturn::diagnostics
+/// [`panicked`]: FragmentReturn::panicked
+#[non_exhaustive]
+pub struct FragmentReturn<T> {
+    /// The parsed node.
+    ///
+    /// Will be a dummy node if the parser panicked.
+    pub node: T,
+
+    /// Syntax errors encountered while parsing, including source text remaining after the
+    /// fragment.
+    pub diagnostics: Diagnostics,
+
+    /// Whether the parser panicked and terminated early.
+    pub panicked: bool,
+}
+
+impl<T> FragmentReturn<T> {
+    pub(crate) fn map<U>(self, f: impl FnOnce(T) -> U) -> FragmentReturn<U> {
+        FragmentReturn {
+            node: f(self.node),
+            diagnostics: self.diagnostics,
+            panicked: self.panicked,
+        }
+    }
+}
+
+/// The grammar production which a fragment is parsed as.
+#[derive(Debug, Clone, Copy)]
+pub enum FragmentKind {
+    Statement,
+    Statements,
+    ModuleItem,
+    TSType,
+    BindingPattern,
+    ClassElement,
+}
+
+/// A parsed fragment. The variant matches the [`FragmentKind`] it was parsed as.
+pub enum Fragment<'a> {
+    Statement(Statement<'a>),
+    Statements(ArenaVec<'a, Statement<'a>>),
+    TSType(TSType<'a>),
+    BindingPattern(BindingPattern<'a>),
+    ClassElement(ClassElement<'a>),
+}
+
+impl<'a, C: Config> ParserImpl<'a, C> {
+    /// Parse the whole source text as a fragment of `kind`.
+    ///
+    /// Unlike [`ParserImpl::parse_expression`], it is an error if source text remains after the
+    /// fragment.
+    pub(crate) fn parse_fragment(mut self, kind: FragmentKind) -> FragmentReturn<Fragment<'a>> {
+        // initialize cur_token and prev_token by moving onto the first token
+        self.bump_any();
+        let mut fragment = match kind {
+            FragmentKind::Statement => {
+                let statement = self.parse_statement_list_item(StatementContext::StatementList);
+                self.check_module_declaration(&statement);
+                Fragment::Statement(statement)
+            }
+            FragmentKind::Statements => {
+                let mut statements = ArenaVec::new_in(&self);
+                while !self.at(Kind::Eof) && !self.has_fatal_error() {
+                    let statement = self.parse_statement_list_item(StatementContext::StatementList);
+                    self.check_module_declaration(&statement);
+                    statements.push(statement);
+                }
+                Fragment::Statements(statements)
+            }
+            FragmentKind::ModuleItem => {
+                self.ctx |= Context::TopLevel;
+                Fragment::Statement(self.parse_statement_list_item(StatementContext::StatementList))
+            }
+            FragmentKind::TSType => Fragment::TSType(self.parse_ts_type()),
+            FragmentKind::BindingPattern => {
+                Fragment::BindingPattern(self.parse_binding_pattern_kind())
+            }
+            FragmentKind::ClassElement => Fragment::ClassElement(self.parse_class_element()),
+        };
+        // Source text after the fragment is an error, but leaves the fragment intact
+        if !self.has_fatal_error() && !self.at(Kind::Eof) {
+            let token = self.cur_token();
+            let error =
+                diagnostics::expect_token(Kind::Eof.to_str(), token.kind().to_str(), token.span());
+            self.error(error);
+        }
+        let mut panicked = false;
+        if let Some(FatalError { error, errors_len, .. }) = self.fatal_error.take() {
+            panicked = true;
+            self.errors.truncate(errors_len);
+            // A lexer error which ended parsing is already reported
+            if self.lexer.errors.is_empty() || !self.cur_kind().is_eof() {
+                self.error(error);
+            }
+            fragment = Fragment::dummy(kind, &self);
+        }
+        self.check_unfinished_errors();
+        let diagnostics = self.lexer.errors.into_iter().chain(self.errors).collect::<Diagnostics>();
+        FragmentReturn { node: fragment, diagnostics, panicked }
+    }
+
+    /// `import` and `export` declarations are only permitted by
+    /// [`Parser::parse_module_item`](crate::Parser::parse_module_item).
+    fn check_module_declaration(&mut self, statement: &Statement<'a>) {
+        if let Some(declaration) = statement.as_module_declaration() {
+            self.error(diagnostics::module_declaration_not_at_top_level(
+                declaration.is_import(),
+                declaration.span(),
+            ));
+        }
+    }
+}
+
+impl<'a> Fragment<'a> {
+    fn dummy<C: Config>(kind: FragmentKind, p: &ParserImpl<'a, C>) -> Self {
+        let allocator = p.allocator();
+        match kind {
+            FragmentKind::Statement | FragmentKind::ModuleItem => {
+                Self::Statement(Dummy::dummy(allocator))
+            }
+            FragmentKind::Statements => Self::Statements(ArenaVec::new_in(p)),
+            FragmentKind::TSType => Self::TSType(Dummy::dummy(allocator)),
+            FragmentKind::BindingPattern => Self::BindingPattern(Dummy::dummy(allocator)),
+            FragmentKind::ClassElement => Self::ClassElement(Dummy::dummy(allocator)),
+        }
+    }
+}
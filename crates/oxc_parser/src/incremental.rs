@@ -0,0 +1,794 @@
+//! Incremental reparsing.
+//!
+//! [`Parser::reparse`](crate::Parser::reparse) parses edited source text by reusing the top-level
+//! statements of a previous parse which were not affected by the edits.
+//!
+//! Parsing starts from the statement before the first statement touched by the edits, because an
+//! edit can change where that statement ends (e.g. inserting `(` into `a \n b` turns `a` into a
+//! call expression). It continues statement by statement until it reaches a statement starting
+//! after the edited region which also started a statement in the previous parse, at the same
+//! position relative to the end of the file. From that point on the token stream is unchanged,
+//! so the remaining statements of the previous parse are reused, with their spans moved by the
+//! change in length.
+//!
+//! Comments, tokens, irregular whitespaces and diagnostics outside of the reparsed region are
+//! reused in the same way, and the module record is rebuilt from the reused statements.
+
+use oxc_allocator::{Allocator, ArenaVec, GetAllocator};
+use oxc_ast::ast::{Comment, Program, RegExpLiteral, Statement};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_diagnostics::{Diagnostics, OxcDiagnostic};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::module_record::{DynamicImport, ModuleRecord};
+
+use crate::{
+    Kind, ParserConfig as Config, ParserImpl, ParserReturn,
+    config::LexerConfig,
+    context::{Context, StatementContext},
+    error_handler::RecoveryBoundary,
+};
+
+/// A change to source text, replacing the text at `span` with `text`.
+///
+/// `span` is relative to the source text after all previous edits in the same list are applied,
+/// which matches the content changes of an LSP `textDocument/didChange` notification.
+#[derive(Debug, Clone, Copy)]
+pub struct TextEdit<'e> {
+    /// Range of the replaced text.
+    pub span: Span,
+    /// Replacement text.
+    pub text: &'e str,
+}
+
+impl<'e> TextEdit<'e> {
+    /// Create a [`TextEdit`] replacing `span` with `text`.
+    pub fn new(span: Span, text: &'e str) -> Self {
+        Self { span, text }
+    }
+}
+
+/// The region of source text changed by a list of [`TextEdit`]s.
+///
+/// Text before `start` is unchanged. Text from `old_end` in the old source text is the same as
+/// text from `new_end` in the new source text.
+#[derive(Debug, Clone, Copy)]
+struct Damage {
+    start: u32,
+    old_end: u32,
+    new_end: u32,
+}
+
+impl Damage {
+    /// Combine `edits` of source text which was `len` bytes long.
+    ///
+    /// Returns the damaged region and length of the edited source text,
+    /// or `None` if there are no edits or an edit is out of bounds.
+    fn new(edits: &[TextEdit<'_>], mut len: u32) -> Option<(Self, u32)> {
+        let mut damage: Option<Self> = None;
+        for edit in edits {
+            let Span { start, end, .. } = edit.span;
+            if start > end || end > len {
+                return None;
+            }
+            let removed_len = end - start;
+            let inserted_len = u32::try_from(edit.text.len()).ok()?;
+            len = (len - removed_len).checked_add(inserted_len)?;
+            damage = Some(match damage {
+                None => Self { start, old_end: end, new_end: start + inserted_len },
+                Some(damage) => Self {
+                    start: damage.start.min(start),
+                    old_end: if end > damage.new_end { damage.to_old(end) } else { damage.old_end },
+                    new_end: damage.new_end.max(end) - removed_len + inserted_len,
+                },
+            });
+        }
+        damage.map(|damage| (damage, len))
+    }
+
+    /// Convert an offset at or after `old_end` in the old source text to the new source text.
+    fn to_new(self, offset: u32) -> u32 {
+        offset - self.old_end + self.new_end
+    }
+
+    /// Convert an offset at or after `new_end` in the new source text to the old source text.
+    fn to_old(self, offset: u32) -> u32 {
+        offset - self.new_end + self.old_end
+    }
+
+    fn span_to_new(self, span: Span) -> Span {
+        Span::new(self.to_new(span.start), self.to_new(span.end))
+    }
+}
+
+/// Moves the spans of reused statements after the damaged region to the new source text.
+struct SpanMover<'a> {
+    damage: Damage,
+    allocator: &'a Allocator,
+    /// The new source text.
+    source_text: &'a str,
+}
+
+impl<'a> VisitMut<'a> for SpanMover<'a> {
+    fn visit_span(&mut self, span: &mut Span) {
+        *span = self.damage.span_to_new(*span);
+    }
+
+    fn visit_reg_exp_literal(&mut self, it: &mut RegExpLiteral<'a>) {
+        walk_mut::walk_reg_exp_literal(self, it);
+        // The parsed pattern has its own spans, so parse it again at its new position.
+        // It parsed without errors before, so it parses without errors again.
+        #[cfg(feature = "regular_expression")]
+        if it.regex.pattern.pattern.is_some() {
+            let literal = it.span.source_text(self.source_text);
+            #[expect(clippy::cast_possible_truncation)]
+            let pattern_end = it.span.start + literal.rfind('/').unwrap() as u32;
+            let pattern_start = it.span.start + 1;
+            let flags_start = pattern_end + 1;
+            let pattern = oxc_regular_expression::LiteralParser::new(
+                self.allocator,
+                &self.source_text[pattern_start as usize..pattern_end as usize],
+                Some(&self.source_text[flags_start as usize..it.span.end as usize]),
+                oxc_regular_expression::Options {
+                    pattern_span_offset: pattern_start,
+                    flags_span_offset: flags_start,
+                },
+            )
+            .parse()
+            .ok();
+            it.regex.pattern.pattern =
+                pattern.map(|pattern| oxc_allocator::ArenaBox::new_in(pattern, &self.allocator));
+        }
+        #[cfg(not(feature = "regular_expression"))]
+        let _ = (self.allocator, self.source_text);
+    }
+}
+
+/// The parts of the previous parse which are reused.
+///
+/// All offsets are in the old source text.
+#[derive(Debug, Clone, Copy)]
+struct Reuse {
+    damage: Damage,
+    /// Everything before this offset is reused.
+    reparse_start: u32,
+    /// Everything from this offset is reused, if parsing resynchronized before end of file.
+    resume_start: Option<u32>,
+}
+
+impl Reuse {
+    /// Get the offset of `span` in the new source text, if it is outside the reparsed region.
+    fn span_to_new(self, span: Span) -> Option<Span> {
+        if span.end <= self.reparse_start {
+            Some(span)
+        } else if self.resume_start.is_some_and(|resume_start| span.start >= resume_start) {
+            Some(self.damage.span_to_new(span))
+        } else {
+            None
+        }
+    }
+
+    fn diagnostic_to_new(self, mut diagnostic: OxcDiagnostic) -> Option<OxcDiagnostic> {
+        for label in &mut diagnostic.labels {
+            let span = Span::sized(label.offset(), label.len());
+            let span = self.span_to_new(span)?;
+            label.set_span_offset(span.start);
+        }
+        Some(diagnostic)
+    }
+}
+
+impl<'a, C: Config> ParserImpl<'a, C> {
+    /// Parse the source text, reusing the parts of `previous` which are not affected by `edits`.
+    ///
+    /// Returns `None` if `previous` cannot be reused, and the source text must be parsed in full.
+    pub(crate) fn reparse(
+        mut self,
+        previous: ParserReturn<'a>,
+        edits: &[TextEdit<'_>],
+    ) -> Option<ParserReturn<'a>> {
+        let ParserReturn {
+            program: previous_program,
+            module_record: previous_module_record,
+            diagnostics: previous_diagnostics,
+            irregular_whitespaces: previous_irregular_whitespaces,
+            tokens: mut previous_tokens,
+            panicked,
+            is_flow_language,
+        } = previous;
+
+        if panicked
+            || is_flow_language
+            || self.overlong_error().is_some()
+            // Only the first non-declaration statement in a `.d.ts` file is reported
+            || self.source_type.is_typescript_definition()
+            // Tokens were not collected by the previous parse
+            || (self.lexer.config.tokens() && previous_tokens.is_empty())
+            || previous_diagnostics.iter().any(|diagnostic| diagnostic.labels.is_empty())
+        {
+            return None;
+        }
+        // The goal of an unambiguous file is resolved from the whole file. Statements are reparsed
+        // with the goal of the previous parse, which must still be the goal after the edits.
+        let resolved_module = if self.source_type.is_unambiguous() {
+            let source_type = previous_program.source_type;
+            if source_type == self.source_type.with_module(true) {
+                Some(true)
+            } else if source_type == self.source_type.with_script(true) {
+                Some(false)
+            } else {
+                return None;
+            }
+        } else if previous_program.source_type == self.source_type {
+            None
+        } else {
+            return None;
+        };
+
+        let old_len = u32::try_from(previous_program.source_text.len()).ok()?;
+        let (damage, new_len) = Damage::new(edits, old_len)?;
+        if new_len as usize != self.source_text.len() {
+            return None;
+        }
+
+        let mut statements = previous_program.body;
+        let first_damaged = statements.partition_point(|stmt| stmt.span().end < damage.start);
+        // Whether the last directive is a directive depends on the statement after it,
+        // so the first statement is not reparsed on its own.
+        let reparse_index = first_damaged.checked_sub(1)?;
+        let reparse_start = statements[reparse_index].span().start;
+
+        for stmt in &statements[..reparse_index] {
+            let default_keyword_span = default_keyword_span(&previous_module_record, stmt)?;
+            self.module_record_builder.visit_reused_statement(stmt, default_keyword_span);
+        }
+        self.reuse_nested_module_syntax(&previous_module_record, |span| {
+            (span.end <= reparse_start).then_some(span)
+        });
+
+        // Parse statements until reaching a statement of the previous parse after the damaged region
+        self.ctx |= Context::TopLevel;
+        if resolved_module == Some(true) {
+            self.ctx = self.ctx.and_await(true);
+        }
+        self.token = self.lexer.first_token_at(reparse_start);
+        self.prev_token_end = reparse_start;
+        let mut reparsed = ArenaVec::new_in(&self);
+        let resume_index = loop {
+            if self.has_fatal_error() {
+                break statements.len();
+            }
+            let stmt_start = self.start_span();
+            if stmt_start >= damage.new_end
+                && let Ok(index) = statements[first_damaged..]
+                    .binary_search_by_key(&damage.to_old(stmt_start), |stmt| stmt.span().start)
+            {
+                break first_damaged + index;
+            }
+            let stmt = self.parse_statement_list_item(StatementContext::StatementList);
+            if let Some(error_node) =
+                self.recover_from_fatal_error(stmt_start, RecoveryBoundary::Statement)
+            {
+                reparsed.push(Statement::ErrorNode(error_node));
+                continue;
+            }
+            reparsed.push(stmt);
+        };
+        // A full parse stops at the same fatal error, discarding the whole program
+        if self.fatal_error.is_some() {
+            return None;
+        }
+        self.check_unfinished_errors();
+
+        let resume_start = statements.get(resume_index).map(|stmt| stmt.span().start);
+        let reuse = Reuse { damage, reparse_start, resume_start };
+        // Offset in the new source text from which everything collected by the lexer is discarded
+        let reparse_end = resume_start.map_or(u32::MAX, |start| damage.to_new(start));
+
+        let mut span_mover =
+            SpanMover { damage, allocator: self.allocator(), source_text: self.source_text };
+        for stmt in &mut statements[resume_index..] {
+            let default_keyword_span = default_keyword_span(&previous_module_record, stmt)?;
+            span_mover.visit_statement(stmt);
+            self.module_record_builder
+                .visit_reused_statement(stmt, damage.span_to_new(default_keyword_span));
+        }
+        if let Some(resume_start) = resume_start {
+            self.reuse_nested_module_syntax(&previous_module_record, |span| {
+                (span.start >= resume_start).then(|| damage.span_to_new(span))
+            });
+        }
+        statements.splice(reparse_index..resume_index, reparsed);
+
+        let mut comments = previous_program.comments;
+        let new_comments = self
+            .lexer
+            .trivia_builder
+            .comments
+            .iter()
+            .filter(|comment| comment.span.end <= reparse_end)
+            .copied();
+        let reparsed_comments = comments
+            .partition_point(|comment| comment.span.end <= reparse_start)
+            ..comments.partition_point(|comment| {
+                resume_start.is_none_or(|resume_start| comment.span.start < resume_start)
+            });
+        for comment in &mut comments[reparsed_comments.end..] {
+            move_comment(comment, damage);
+        }
+        comments.splice(reparsed_comments, new_comments);
+
+        let irregular_whitespaces = previous_irregular_whitespaces
+            .iter()
+            .filter_map(|&span| reuse.span_to_new(span))
+            .chain(
+                self.lexer
+                    .trivia_builder
+                    .irregular_whitespaces
+                    .iter()
+                    .copied()
+                    .filter(|span| span.end <= reparse_end),
+            )
+            .collect::<Vec<_>>();
+        let mut irregular_whitespaces = irregular_whitespaces.into_boxed_slice();
+        irregular_whitespaces.sort_unstable_by_key(|span| span.start);
+
+        let tokens = if self.lexer.config.tokens() {
+            let new_tokens = self.lexer.take_tokens();
+            let new_tokens = new_tokens
+                .iter()
+                .filter(|token| token.kind() != Kind::Eof && token.start() < reparse_end)
+                .copied();
+            let reparsed_tokens = previous_tokens
+                .partition_point(|token| token.start() < reparse_start)
+                ..previous_tokens.partition_point(|token| {
+                    resume_start.is_none_or(|resume_start| token.start() < resume_start)
+                });
+            for token in &mut previous_tokens[reparsed_tokens.end..] {
+                token.set_span(damage.span_to_new(token.span()));
+            }
+            previous_tokens.splice(reparsed_tokens, new_tokens);
+            previous_tokens
+        } else {
+            ArenaVec::new_in(&self)
+        };
+
+        let reused_errors = previous_diagnostics
+            .into_vec()
+            .into_iter()
+            .filter_map(|diagnostic| reuse.diagnostic_to_new(diagnostic))
+            .collect::<Vec<_>>();
+
+        // With a `@flow` pragma, syntax errors are replaced by a single error for the whole file
+        self.lexer.trivia_builder.comments = comments;
+        if (!reused_errors.is_empty() || !self.lexer.errors.is_empty() || !self.errors.is_empty())
+            && self.flow_error().is_some()
+        {
+            return None;
+        }
+
+        let mut deferred_errors = vec![];
+        if let Some(is_module) = resolved_module {
+            if self.module_record_builder.has_module_syntax() != is_module {
+                return None;
+            }
+            deferred_errors = if is_module {
+                std::mem::take(&mut self.lexer.deferred_module_errors)
+            } else {
+                std::mem::take(&mut self.deferred_script_errors)
+            };
+        }
+
+        let mut program = Program::new(
+            Span::sized(0, new_len),
+            previous_program.source_type,
+            self.source_text,
+            ArenaVec::new_in(&self),
+            previous_program.hashbang,
+            previous_program.directives,
+            statements,
+            &self,
+        );
+        program.comments = self.lexer.trivia_builder.comments;
+
+        let (module_record, module_record_errors) = self.module_record_builder.build();
+        let new_errors = self
+            .lexer
+            .errors
+            .into_iter()
+            .chain(self.errors)
+            .chain(module_record_errors)
+            .chain(deferred_errors)
+            .collect::<Vec<_>>();
+        let mut diagnostics = Diagnostics::new();
+        // Skip errors which are reported again, e.g. duplicate exports found by the module record
+        diagnostics.extend(reused_errors.into_iter().filter(|error| !new_errors.contains(error)));
+        diagnostics.extend(new_errors);
+
+        Some(ParserReturn {
+            program,
+            module_record,
+            diagnostics,
+            irregular_whitespaces,
+            tokens,
+            panicked: false,
+            is_flow_language: false,
+        })
+    }
+
+    /// Record dynamic imports and `import.meta`s of the previous parse which are reused.
+    ///
+    /// `to_new` converts a span to the new source text, or returns `None` if it is not reused.
+    fn reuse_nested_module_syntax(
+        &mut self,
+        previous: &ModuleRecord<'a>,
+        to_new: impl Fn(Span) -> Option<Span>,
+    ) {
+        for dynamic_import in &previous.dynamic_imports {
+            if let Some(span) = to_new(dynamic_import.span) {
+                let module_request =
+                    span.start + dynamic_import.module_request.start - dynamic_import.span.start;
+                let module_request =
+                    Span::sized(module_request, dynamic_import.module_request.size());
+                self.module_record_builder
+                    .visit_reused_dynamic_import(DynamicImport { span, module_request });
+            }
+        }
+        for &span in &previous.import_metas {
+            if let Some(span) = to_new(span) {
+                self.module_record_builder.visit_import_meta(span);
+            }
+        }
+    }
+}
+
+/// Get the span of `default` in `stmt` if it is an `export default` declaration,
+/// from the module record of the parse which `stmt` came from.
+///
+/// Returns an empty span for other statements, or `None` if the export entry was not found.
+fn default_keyword_span(module_record: &ModuleRecord<'_>, stmt: &Statement<'_>) -> Option<Span> {
+    let Statement::ExportDefaultDeclaration(decl) = stmt else {
+        return Some(Span::empty(stmt.span().start));
+    };
+    module_record
+        .local_export_entries
+        .iter()
+        .chain(&module_record.indirect_export_entries)
+        .filter(|entry| entry.statement_span == decl.span)
+        .find_map(|entry| entry.export_name.default_export_span())
+}
+
+fn move_comment(comment: &mut Comment, damage: Damage) {
+    comment.span = damage.span_to_new(comment.span);
+    if comment.is_leading() {
+        comment.attached_to = damage.to_new(comment.attached_to);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::{Allocator, GetAddress};
+    use oxc_ast::ast::RegExpLiteral;
+    use oxc_ast_visit::Visit;
+    use oxc_span::{SourceType, Span};
+
+    use crate::{ParseOptions, Parser, ParserReturn, TextEdit, config::TokensParserConfig};
+
+    /// Apply `edits` to `source_text`.
+    fn apply(source_text: &str, edits: &[TextEdit<'_>]) -> String {
+        let mut text = source_text.to_string();
+        for edit in edits {
+            text.replace_range(edit.span.start as usize..edit.span.end as usize, edit.text);
+        }
+        text
+    }
+
+    /// Reparse `source_text` after `edits`, and check the result is the same as a full parse.
+    ///
+    /// Returns the number of top-level statements which were reused.
+    fn reparse(source_type: SourceType, source_text: &str, edits: &[TextEdit<'_>]) -> usize {
+        let options = ParseOptions { error_recovery: true, ..ParseOptions::default() };
+        reparse_with_options(source_type, options, source_text, edits)
+    }
+
+    fn reparse_with_options(
+        source_type: SourceType,
+        options: ParseOptions,
+        source_text: &str,
+        edits: &[TextEdit<'_>],
+    ) -> usize {
+        let allocator = Allocator::default();
+        let new_text = apply(source_text, edits);
+        let new_text = allocator.alloc_str(&new_text);
+
+        let previous = Parser::new(&allocator, source_text, source_type)
+            .with_options(options)
+            .with_config(TokensParserConfig)
+            .parse();
+        let previous_addresses =
+            previous.program.body.iter().map(GetAddress::address).collect::<Vec<_>>();
+        let reparsed = Parser::new(&allocator, new_text, source_type)
+            .with_options(options)
+            .with_config(TokensParserConfig)
+            .reparse(previous, edits);
+        let parsed = Parser::new(&allocator, new_text, source_type)
+            .with_options(options)
+            .with_config(TokensParserConfig)
+            .parse();
+
+        assert_same(&reparsed, &parsed, source_type);
+        reparsed
+            .program
+            .body
+            .iter()
+            .filter(|stmt| previous_addresses.contains(&stmt.address()))
+            .count()
+    }
+
+    fn assert_same(
+        reparsed: &ParserReturn<'_>,
+        parsed: &ParserReturn<'_>,
+        source_type: SourceType,
+    ) {
+        let ts = !source_type.is_javascript();
+        assert_eq!(
+            reparsed.program.to_pretty_estree_json(ts, false),
+            parsed.program.to_pretty_estree_json(ts, false)
+        );
+        assert_eq!(reparsed.program.source_type, parsed.program.source_type);
+        assert_eq!(reparsed.program.comments, parsed.program.comments);
+        let regexes = |ret: &ParserReturn<'_>| {
+            let mut collector = RegExpCollector::default();
+            collector.visit_program(&ret.program);
+            collector.0
+        };
+        assert_eq!(regexes(reparsed), regexes(parsed));
+        assert_eq!(reparsed.panicked, parsed.panicked);
+
+        let sorted_diagnostics = |ret: &ParserReturn<'_>| {
+            let mut diagnostics =
+                ret.diagnostics.iter().map(|error| format!("{error:?}")).collect::<Vec<_>>();
+            diagnostics.sort_unstable();
+            diagnostics
+        };
+        assert_eq!(sorted_diagnostics(reparsed), sorted_diagnostics(parsed));
+        assert_eq!(reparsed.irregular_whitespaces, parsed.irregular_whitespaces);
+        let tokens = |ret: &ParserReturn<'_>| {
+            ret.tokens
+                .iter()
+                .map(|token| (token.kind(), token.span(), token.is_on_new_line()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(tokens(reparsed), tokens(parsed));
+
+        let (reparsed, parsed) = (&reparsed.module_record, &parsed.module_record);
+        assert_eq!(reparsed.has_module_syntax, parsed.has_module_syntax);
+        assert_eq!(reparsed.import_entries, parsed.import_entries);
+        assert_eq!(reparsed.local_export_entries, parsed.local_export_entries);
+        assert_eq!(reparsed.indirect_export_entries, parsed.indirect_export_entries);
+        assert_eq!(reparsed.star_export_entries, parsed.star_export_entries);
+        assert_eq!(
+            format!("{:?}", reparsed.dynamic_imports),
+            format!("{:?}", parsed.dynamic_imports)
+        );
+        assert_eq!(reparsed.import_metas, parsed.import_metas);
+        let requested_modules = |record: &oxc_syntax::module_record::ModuleRecord<'_>| {
+            let mut modules = record
+                .requested_modules
+                .iter()
+                .map(|(name, requests)| format!("{name} {requests:?}"))
+                .collect::<Vec<_>>();
+            modules.sort_unstable();
+            modules
+        };
+        assert_eq!(requested_modules(reparsed), requested_modules(parsed));
+        let exported_bindings = |record: &oxc_syntax::module_record::ModuleRecord<'_>| {
+            let mut bindings = record
+                .exported_bindings
+                .iter()
+                .map(|(name, span)| (name.to_string(), *span))
+                .collect::<Vec<_>>();
+            bindings.sort_unstable();
+            bindings
+        };
+        assert_eq!(exported_bindings(reparsed), exported_bindings(parsed));
+    }
+
+    /// Collects the parsed patterns of regular expressions, which are not in the ESTree output.
+    #[derive(Default)]
+    struct RegExpCollector(Vec<String>);
+
+    impl<'a> Visit<'a> for RegExpCollector {
+        fn visit_reg_exp_literal(&mut self, it: &RegExpLiteral<'a>) {
+            self.0.push(format!("{:?}", it.regex.pattern.pattern));
+        }
+    }
+
+    /// Edit which replaces the first occurrence of `from` in `source_text` with `to`.
+    fn replace<'e>(source_text: &str, from: &str, to: &'e str) -> TextEdit<'e> {
+        #[expect(clippy::cast_possible_truncation)]
+        let start = source_text.find(from).unwrap() as u32;
+        #[expect(clippy::cast_possible_truncation)]
+        TextEdit::new(Span::sized(start, from.len() as u32), to)
+    }
+
+    const SOURCE: &str = "\
+import a from 'a';
+import { b } from 'b';
+
+// leading comment
+function foo(x) {
+  return x + 1;
+}
+
+/** @type {number} */
+const bar = foo(2); // trailing comment
+
+class Baz {
+  method() { return import.meta.url; }
+}
+
+export default Baz;
+export { bar as qux };
+export * from 'c';
+const lazy = () => import('d');
+";
+
+    #[test]
+    fn reuse_unchanged_statements() {
+        let source_type = SourceType::mjs();
+        let edit = replace(SOURCE, "x + 1", "x + 10");
+        // `import { b }` before the damaged statement is reparsed
+        assert_eq!(reparse(source_type, SOURCE, &[edit]), 7);
+
+        // Change the length of a statement in the middle
+        let edit = replace(SOURCE, "foo(2)", "foo(2000000)");
+        assert_eq!(reparse(source_type, SOURCE, &[edit]), 7);
+
+        // Delete text
+        let edit = replace(SOURCE, "  method() { return import.meta.url; }\n", "");
+        assert_eq!(reparse(source_type, SOURCE, &[edit]), 7);
+
+        // Append to end of file
+        #[expect(clippy::cast_possible_truncation)]
+        let edit = TextEdit::new(Span::empty(SOURCE.len() as u32), "export const last = 1;\n");
+        assert_eq!(reparse(source_type, SOURCE, &[edit]), 8);
+    }
+
+    #[test]
+    fn multiple_edits() {
+        let source_type = SourceType::mjs();
+        let first = replace(SOURCE, "x + 1", "x + 100");
+        let text = apply(SOURCE, &[first]);
+        let second = replace(&text, "foo(2)", "foo(3, 4)");
+        let text = apply(&text, &[second]);
+        let third = replace(&text, "// leading comment\n", "");
+        assert_eq!(reparse(source_type, SOURCE, &[first, second, third]), 6);
+    }
+
+    #[test]
+    fn edit_changes_previous_statement() {
+        let source_type = SourceType::mjs();
+        // `bar` becomes a call expression
+        let source = "let a = 1;\nbar\nbaz\nlet b = 2;\n";
+        let edit = replace(source, "baz", "(baz)");
+        assert_eq!(reparse(source_type, source, &[edit]), 2);
+
+        // `;` is removed, which joins two statements
+        let source = "let a = 1;\nx = y;\n(z);\nlet b = 2;\n";
+        let edit = replace(source, "y;", "y");
+        assert_eq!(reparse(source_type, source, &[edit]), 1);
+    }
+
+    #[test]
+    fn edit_changes_rest_of_file() {
+        let source_type = SourceType::mjs();
+        // Unterminated comment and template literal swallow the rest of the file
+        let edit = replace(SOURCE, "// trailing comment", "/* trailing comment");
+        assert_eq!(reparse(source_type, SOURCE, &[edit]), 3);
+        let edit = replace(SOURCE, "x + 1", "`x + 1");
+        assert_eq!(reparse(source_type, SOURCE, &[edit]), 1);
+
+        // A comment which is closed again does not affect the statements after it
+        let source = "let a = 1;\nlet b = 2;\nlet c = 3;\nlet d = 4;\n";
+        let edit = replace(source, "let b = 2;", "/* let b = 2; */");
+        assert_eq!(reparse(source_type, source, &[edit]), 2);
+    }
+
+    #[test]
+    fn syntax_errors() {
+        let source_type = SourceType::mjs();
+        // Errors before and after the edit are reused
+        let source = "let a = ;\nlet b = 1;\nlet c = 2;\nlet d = 3;\nlet e = ;\n";
+        let edit = replace(source, "2", "2 +");
+        assert_eq!(reparse(source_type, source, &[edit]), 3);
+
+        // Fixing an error
+        let edit = replace(source, "let e = ;", "let e = 4;");
+        assert_eq!(reparse(source_type, source, &[edit]), 3);
+
+        // Duplicate exports are reported once
+        let source =
+            "export const a = 1;\nlet b;\nexport { a };\nexport default 1;\nexport default 2;\n";
+        let edit = replace(source, "let b;", "let b = 1;");
+        assert_eq!(reparse(source_type, source, &[edit]), 3);
+    }
+
+    #[test]
+    fn typescript_and_jsx() {
+        let source = "\
+type A = { a: string };
+interface B { b: number }
+const c = <div>{/* comment */ 1}</div>;
+function d<T>(x: T): T { return x }
+enum E { F }
+";
+        let source_type = SourceType::tsx().with_module(true);
+        let edit = replace(source, "{ return x }", "{ return x as T }");
+        assert_eq!(reparse(source_type, source, &[edit]), 3);
+        let edit = replace(source, "1}</div>", "2}</div>");
+        assert_eq!(reparse(source_type, source, &[edit]), 3);
+    }
+
+    #[test]
+    fn fall_back_to_full_parse() {
+        let source_type = SourceType::mjs();
+        // First statement is edited
+        let edit = replace(SOURCE, "import a", "import aa");
+        assert_eq!(reparse(source_type, SOURCE, &[edit]), 0);
+        // The goal of an unambiguous file changes
+        let source_type = SourceType::unambiguous();
+        let source = "let a = 1;\nlet b = 2;\nlet c = 3;\nlet d = 4;\n";
+        let edit = replace(source, "let c = 3;", "export let c = 3;");
+        assert_eq!(reparse(source_type, source, &[edit]), 0);
+        let source = "let a = 1;\nlet b = 2;\nexport let c = 3;\nlet d = 4;\n";
+        let edit = replace(source, "export let c", "let c");
+        assert_eq!(reparse(source_type, source, &[edit]), 0);
+        let source = "let a = 1;\nlet b = 2;\nlet c = 3;\nlet d = 4;\n";
+        let edit = replace(source, "3", "await foo");
+        assert_eq!(reparse(source_type, source, &[edit]), 0);
+        let source_type = SourceType::mjs();
+        // Edit out of bounds
+        let edit = TextEdit::new(Span::new(1000, 1001), "");
+        let allocator = Allocator::default();
+        let previous = Parser::new(&allocator, SOURCE, source_type).parse();
+        let ret = Parser::new(&allocator, SOURCE, source_type).reparse(previous, &[edit]);
+        assert!(ret.diagnostics.is_empty());
+        assert_eq!(ret.program.body.len(), 9);
+    }
+
+    #[test]
+    fn unambiguous() {
+        let source_type = SourceType::unambiguous();
+        // Resolved to module
+        let edit = replace(SOURCE, "x + 1", "x + 10");
+        assert_eq!(reparse(source_type, SOURCE, &[edit]), 7);
+        // Top-level `await` and HTML comments in a module
+        let source = "import a from 'a';\nlet b = 1;\nawait (b);\n<!-- c\nlet d = 2;\nlet e = 3;\n";
+        let edit = replace(source, "1", "await /x/");
+        assert_eq!(reparse(source_type, source, &[edit]), 3);
+        let edit = replace(source, "let d = 2;", "<!-- d\nlet d = 2;");
+        assert_eq!(reparse(source_type, source, &[edit]), 3);
+
+        // Resolved to script
+        let source = "let a = 1;\nlet b = 2;\nlet c = 3;\nlet d = 4;\n";
+        let edit = replace(source, "2", "await (2)");
+        assert_eq!(reparse(source_type, source, &[edit]), 2);
+        let edit = replace(source, "let c = 3;", "<!-- c\nlet c = 3;");
+        assert_eq!(reparse(source_type, source, &[edit]), 2);
+    }
+
+    #[test]
+    fn regular_expressions() {
+        let source_type = SourceType::mjs();
+        let options = ParseOptions {
+            parse_regular_expression: true,
+            error_recovery: true,
+            ..ParseOptions::default()
+        };
+        let source = "let a = /a/g;\nlet b = /b+/;\nlet c = /(?<c>c)/u;\nlet d = /[d/;\n";
+        let edit = replace(source, "/b+/", "/bb+/");
+        assert_eq!(reparse_with_options(source_type, options, source, &[edit]), 2);
+        let edit = replace(source, "let b = /b+/;", "let b = 1;");
+        assert_eq!(reparse_with_options(source_type, options, source, &[edit]), 2);
+    }
+}
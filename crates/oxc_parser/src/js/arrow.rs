@@ -152,6 +152,8 @@ impl<'a, C: Config> ParserImpl<'a, C> {
                         match third {
                             // If we have something like "(a:", then we must have a
                             // type-annotated parameter in an arrow function expression.
+                            // In Flow, it may also be a type cast "(a: T)".
+                            Kind::Colon if self.is_flow => Tristate::Maybe,
                             Kind::Colon => Tristate::True,
                             // If we have "(a?:" or "(a?," or "(a?=" or "(a?)" then it is definitely a lambda.
                             Kind::Question => {
@@ -283,7 +285,16 @@ impl<'a, C: Config> ParserImpl<'a, C> {
             self.error(diagnostics::ts_arrow_function_this_parameter(this_param.span));
         }
 
-        let return_type = if self.is_ts { self.parse_ts_return_type_annotation() } else { None };
+        let return_type = if self.is_flow {
+            self.context_add(
+                Context::DisallowFlowAnonFunctionType,
+                Self::parse_ts_return_type_annotation,
+            )
+        } else if self.is_ts {
+            self.parse_ts_return_type_annotation()
+        } else {
+            None
+        };
 
         self.ctx = self.ctx.and_await(has_await);
 
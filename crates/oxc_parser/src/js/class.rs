@@ -5,6 +5,7 @@ use oxc_span::{GetSpan, Span};
 
 use crate::{
     Context, ParserConfig as Config, ParserImpl, StatementContext, diagnostics,
+    error_handler::RecoveryBoundary,
     lexer::Kind,
     modifiers::{ModifierKind, ModifierKinds, Modifiers},
 };
@@ -221,12 +222,17 @@ impl<'a, C: Config> ParserImpl<'a, C> {
                     return None;
                 }
             }
-            Some(Self::parse_class_element(p))
+            let element_start = p.start_span();
+            let element = Self::parse_class_element(p);
+            Some(
+                p.recover_from_fatal_error(element_start, RecoveryBoundary::ClassElement)
+                    .map_or(element, ClassElement::ErrorNode),
+            )
         });
         ClassBody::boxed(self.end_span(span), class_elements, self)
     }
 
-    fn parse_class_element(&mut self) -> ClassElement<'a> {
+    pub(crate) fn parse_class_element(&mut self) -> ClassElement<'a> {
         let elem = self.parse_class_element_impl();
         if let ClassElement::MethodDefinition(def) = &elem
             && def.value.body.is_none()
@@ -247,6 +253,8 @@ impl<'a, C: Config> ParserImpl<'a, C> {
             /* permit_const_as_modifier */ true,
             /* stop_on_start_of_class_static_block */ true,
         );
+        // Flow `+foo: T`
+        let flow_readonly = self.eat_flow_variance();
 
         // static { block }
         if self.at(Kind::Static) && self.lexer.peek_token().kind() == Kind::LCurly {
@@ -325,13 +333,17 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         let kind = self.cur_kind();
         if kind.is_identifier_or_keyword() || kind == Kind::Star || kind == Kind::LBrack {
             let is_ambient = modifiers.contains(ModifierKind::Declare);
-            return if is_ambient {
+            let mut element = if is_ambient {
                 self.context_add(Context::Ambient, |p| {
                     p.parse_property_or_method_declaration(span, r#type, &modifiers, decorators)
                 })
             } else {
                 self.parse_property_or_method_declaration(span, r#type, &modifiers, decorators)
             };
+            if flow_readonly && let ClassElement::PropertyDefinition(property) = &mut element {
+                property.readonly = true;
+            }
+            return element;
         }
 
         self.unexpected()
@@ -592,7 +604,8 @@ impl<'a, C: Config> ParserImpl<'a, C> {
             );
         }
 
-        let is_definite = self.eat(Kind::Bang);
+        // Flow has no definite assignment assertions
+        let is_definite = !self.is_flow && self.eat(Kind::Bang);
         let definite = is_definite.then_some(self.prev_token_end - 1);
 
         if is_definite && let Some(optional_span) = optional_span {
@@ -110,7 +110,8 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         let (type_annotation, definite) = if self.is_ts {
             // const x!: number = 1
             //        ^ definite
-            let definite = if id.is_binding_identifier()
+            let definite = if self.source_type.is_typescript()
+                && id.is_binding_identifier()
                 && !self.cur_token().is_on_new_line()
                 && self.at(Kind::Bang)
             {
@@ -228,6 +228,12 @@ impl<'a, C: Config> ParserImpl<'a, C> {
             Kind::NoSubstitutionTemplate | Kind::TemplateHead => {
                 self.parse_template_literal_expression(false)
             }
+            Kind::Percent
+                if self.options.allow_pipeline_operator
+                    && (self.state.in_pipeline_body || !self.options.allow_v8_intrinsics) =>
+            {
+                self.parse_topic_reference()
+            }
             Kind::Percent if self.options.allow_v8_intrinsics => {
                 self.parse_v8_intrinsic_expression()
             }
@@ -251,13 +257,13 @@ impl<'a, C: Config> ParserImpl<'a, C> {
             self.lexer.trivia_builder.previous_token_has_no_side_effects_comment();
         self.bump_any(); // `bump` `(`
         let expr_span = self.start_span();
+        let parse_element = if self.is_flow {
+            Self::parse_assignment_expression_or_flow_type_cast
+        } else {
+            Self::parse_assignment_expression_or_higher
+        };
         let (mut expressions, comma_span) = self.context(Context::In, Context::Decorator, |p| {
-            p.parse_delimited_list(
-                Kind::RParen,
-                Kind::Comma,
-                opening_span,
-                Self::parse_assignment_expression_or_higher,
-            )
+            p.parse_delimited_list(Kind::RParen, Kind::Comma, opening_span, parse_element)
         });
 
         if let Some(comma_span) = comma_span {
@@ -685,6 +691,18 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         }
     }
 
+    /// Topic reference `%` in the body of a Hack-style pipeline.
+    fn parse_topic_reference(&mut self) -> Expression<'a> {
+        let span = self.cur_token().span();
+        self.bump_any(); // bump `%`
+        if self.state.in_pipeline_body {
+            self.state.topic_reference_used = true;
+        } else {
+            self.error(diagnostics::pipeline_topic_outside_body(span));
+        }
+        Expression::new_topic_reference(span, self)
+    }
+
     /// V8 Runtime calls.
     /// See: [runtime.h](https://github.com/v8/v8/blob/5fe0aa3bc79c0a9d3ad546b79211f07105f09585/src/runtime/runtime.h#L43)
     pub(crate) fn parse_v8_intrinsic_expression(&mut self) -> Expression<'a> {
@@ -866,7 +884,9 @@ impl<'a, C: Config> ParserImpl<'a, C> {
                 kind if kind.is_template_start_of_tagged_template() => {
                     lhs = self.parse_tagged_template_rest(lhs_span, lhs, *in_optional_chain);
                 }
-                Kind::Bang if self.is_ts && !self.cur_token().is_on_new_line() => {
+                Kind::Bang
+                    if self.source_type.is_typescript() && !self.cur_token().is_on_new_line() =>
+                {
                     self.bump_any();
                     lhs =
                         Expression::new_ts_non_null_expression(self.end_span(lhs_span), lhs, self);
@@ -1207,7 +1227,7 @@ impl<'a, C: Config> ParserImpl<'a, C> {
             // (`<` in a JSX file is not matched here; it falls through to the `UpdateExpression` arm,
             // which parses the JSX element.)
             Kind::LAngle if !self.source_type.is_jsx() => {
-                if self.is_ts {
+                if self.source_type.is_typescript() {
                     self.parse_ts_type_assertion()
                 } else {
                     self.parse_jsx_in_non_jsx_error()
@@ -1229,7 +1249,7 @@ impl<'a, C: Config> ParserImpl<'a, C> {
                 if self.source_type.is_jsx() {
                     return self.parse_jsx_expression();
                 }
-                if self.is_ts {
+                if self.source_type.is_typescript() {
                     return self.parse_ts_type_assertion();
                 }
                 self.parse_jsx_in_non_jsx_error()
@@ -1331,12 +1351,13 @@ impl<'a, C: Config> ParserImpl<'a, C> {
                 let type_annotation = self.parse_ts_type();
                 let span = self.end_span(lhs_span);
                 lhs = if kind == Kind::As {
+                    // Flow also has `as` casts
                     if !self.is_ts {
                         self.error(diagnostics::as_in_ts(span));
                     }
                     Expression::new_ts_as_expression(span, lhs, type_annotation, self)
                 } else {
-                    if !self.is_ts {
+                    if !self.source_type.is_typescript() {
                         self.error(diagnostics::satisfies_in_ts(span));
                     }
                     Expression::new_ts_satisfies_expression(span, lhs, type_annotation, self)
@@ -1435,6 +1456,47 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         )
     }
 
+    /// [Hack-style pipeline](https://github.com/tc39/proposal-pipeline-operator)
+    /// `PipeExpression`[In, Yield, Await] :
+    ///     `ShortCircuitExpression`[?In, ?Yield, ?Await] |> `PipeBody`[?In, ?Yield, ?Await]
+    ///
+    /// The body is parsed as an `AssignmentExpression`, so `a |> f(%) |> g(%)` nests to the right.
+    fn parse_pipeline_expression_rest(
+        &mut self,
+        lhs_span: u32,
+        lhs: Expression<'a>,
+        allow_return_type_in_arrow_function: bool,
+    ) -> Expression<'a> {
+        self.bump_any(); // bump `|>`
+        let body_start = self.cur_token().start();
+        let in_pipeline_body = std::mem::replace(&mut self.state.in_pipeline_body, true);
+        let topic_reference_used = std::mem::replace(&mut self.state.topic_reference_used, false);
+        let body =
+            self.parse_assignment_expression_or_higher_impl(allow_return_type_in_arrow_function);
+        self.state.in_pipeline_body = in_pipeline_body;
+        let body_uses_topic =
+            std::mem::replace(&mut self.state.topic_reference_used, topic_reference_used);
+
+        let body_span = body.span();
+        // Parentheses are not part of the span of the expression inside them
+        if body_span.start == body_start {
+            let kind = match &body {
+                Expression::ArrowFunctionExpression(_) => Some("Arrow function"),
+                Expression::AssignmentExpression(_) => Some("Assignment expression"),
+                Expression::ConditionalExpression(_) => Some("Conditional expression"),
+                Expression::YieldExpression(_) => Some("Yield expression"),
+                _ => None,
+            };
+            if let Some(kind) = kind {
+                self.error(diagnostics::pipeline_unparenthesized_body(kind, body_span));
+            }
+        }
+        if !body_uses_topic {
+            self.error(diagnostics::pipeline_topic_unused(body_span));
+        }
+        Expression::new_pipeline_expression(self.end_span(lhs_span), lhs, body, self)
+    }
+
     /// `AssignmentExpression`[In, Yield, Await] :
     pub(crate) fn parse_assignment_expression_or_higher(&mut self) -> Expression<'a> {
         self.parse_assignment_expression_or_higher_impl(
@@ -1509,6 +1571,14 @@ impl<'a, C: Config> ParserImpl<'a, C> {
             );
         }
 
+        if kind == Kind::PipeGt && self.options.allow_pipeline_operator {
+            return self.parse_pipeline_expression_rest(
+                span,
+                lhs,
+                allow_return_type_in_arrow_function,
+            );
+        }
+
         let mut expr =
             self.parse_conditional_expression_rest(span, lhs, allow_return_type_in_arrow_function);
 
@@ -47,6 +47,11 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         func_kind: FunctionKind,
         params_kind: FormalParameterKind,
     ) -> (Option<TSThisParameter<'a>>, ArenaBox<'a, FormalParameters<'a>>) {
+        // Parameters of Flow method signatures and declared functions may be unnamed
+        if self.is_flow && (params_kind == FormalParameterKind::Signature || self.ctx.has_ambient())
+        {
+            return self.parse_flow_function_type_params(params_kind);
+        }
         let span = self.start_span();
         let opening_span = self.cur_token().span();
         self.expect(Kind::LParen);
@@ -157,7 +162,7 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         decorators: ArenaVec<'a, Decorator<'a>>,
     ) -> FormalParameter<'a> {
         let modifiers = self.parse_modifiers(false, false);
-        if self.is_ts {
+        if self.source_type.is_typescript() {
             let allowed_modifiers = if func_kind == FunctionKind::Constructor {
                 ModifierKinds::new([
                     ModifierKind::Public,
@@ -206,6 +206,8 @@ impl<'a, C: Config> CoverGrammar<'a, ObjectExpression<'a>, C> for ObjectAssignme
                         return p.fatal_error(diagnostics::spread_last_element(spread.span));
                     }
                 }
+                // Syntax error was already reported when the object literal was parsed
+                ObjectPropertyKind::ErrorNode(_) => {}
             }
         }
 
@@ -131,6 +131,15 @@ impl<'a, C: Config> ParserImpl<'a, C> {
                     }
                 }
             }
+        } else if self.is_flow && self.at(Kind::Typeof) {
+            // `import typeof ...`
+            self.bump_any();
+            import_kind = ImportOrExportKind::Typeof;
+            if self.cur_kind().is_binding_identifier() {
+                // `import typeof something from ...`
+                identifier_after_import = Some(self.parse_binding_identifier());
+                has_default_specifier = true;
+            }
         } else if token_after_import.kind() == Kind::Defer {
             if self.at(Kind::Star) {
                 // `import defer * ...`
@@ -492,10 +501,13 @@ impl<'a, C: Config> ParserImpl<'a, C> {
                 }
                 ModuleDeclaration::ExportNamedDeclaration(export_named_decl)
             }
-            Kind::Eq if self.is_ts => ModuleDeclaration::TSExportAssignment(
+            Kind::Eq if self.source_type.is_typescript() => ModuleDeclaration::TSExportAssignment(
                 self.parse_ts_export_assignment_declaration(span),
             ),
-            Kind::As if self.is_ts && self.lexer.peek_token().kind() == Kind::Namespace => {
+            Kind::As
+                if self.source_type.is_typescript()
+                    && self.lexer.peek_token().kind() == Kind::Namespace =>
+            {
                 // `export as namespace ...`
                 ModuleDeclaration::TSNamespaceExportDeclaration(
                     self.parse_ts_export_namespace(span),
@@ -554,7 +566,7 @@ impl<'a, C: Config> ParserImpl<'a, C> {
     // ExportSpecifier :
     //   ModuleExportName
     //   ModuleExportName as ModuleExportName
-    fn parse_export_named_specifiers(
+    pub(crate) fn parse_export_named_specifiers(
         &mut self,
         span: u32,
     ) -> ArenaBox<'a, ExportNamedDeclaration<'a>> {
@@ -801,7 +813,7 @@ impl<'a, C: Config> ParserImpl<'a, C> {
     //   *
     //   * as ModuleExportName
     //   NamedExports
-    fn parse_export_all_declaration(
+    pub(crate) fn parse_export_all_declaration(
         &mut self,
         span: u32,
     ) -> ArenaBox<'a, ExportAllDeclaration<'a>> {
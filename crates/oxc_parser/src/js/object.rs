@@ -1,9 +1,11 @@
-use oxc_allocator::ArenaBox;
+use oxc_allocator::{ArenaBox, ArenaVec};
 use oxc_ast::ast::*;
+use oxc_span::Span;
 use oxc_syntax::operator::AssignmentOperator;
 
 use crate::{
     Context, ParserConfig as Config, ParserImpl, diagnostics,
+    error_handler::RecoveryBoundary,
     lexer::Kind,
     modifiers::{ModifierKind, ModifierKinds, Modifiers},
 };
@@ -21,6 +23,9 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         let opening_span = self.cur_token().span();
         self.expect(Kind::LCurly);
         let (object_expression_properties, comma_span) = self.context_add(Context::In, |p| {
+            if p.options.error_recovery {
+                return p.parse_object_expression_properties_with_recovery(opening_span);
+            }
             p.parse_delimited_list(
                 Kind::RCurly,
                 Kind::Comma,
@@ -35,6 +40,48 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         ObjectExpression::boxed(self.end_span(span), object_expression_properties, self)
     }
 
+    /// Same as `parse_delimited_list` with `parse_object_expression_property`, except that a property
+    /// or separator which fails to parse is replaced with an [`ErrorNode`], and parsing continues
+    /// from the next property.
+    fn parse_object_expression_properties_with_recovery(
+        &mut self,
+        opening_span: Span,
+    ) -> (ArenaVec<'a, ObjectPropertyKind<'a>>, Option<u32>) {
+        let mut properties = ArenaVec::new_in(self);
+        loop {
+            if matches!(self.cur_kind(), Kind::RCurly | Kind::Eof | Kind::Undetermined)
+                || self.fatal_error.is_some()
+            {
+                return (properties, None);
+            }
+            let start = self.start_span();
+            let property = self.parse_object_expression_property();
+            properties.push(
+                self.recover_from_fatal_error(start, RecoveryBoundary::ObjectProperty)
+                    .map_or(property, ObjectPropertyKind::ErrorNode),
+            );
+            let kind = self.cur_kind();
+            if !matches!(kind, Kind::Comma | Kind::RCurly | Kind::Eof | Kind::Undetermined) {
+                let start = self.start_span();
+                self.set_fatal_error(diagnostics::expect_closing_or_separator(
+                    Kind::RCurly.to_str(),
+                    Kind::Comma.to_str(),
+                    kind.to_str(),
+                    self.cur_token().span(),
+                    opening_span,
+                ));
+                if let Some(error_node) =
+                    self.recover_from_fatal_error(start, RecoveryBoundary::ObjectProperty)
+                {
+                    properties.push(ObjectPropertyKind::ErrorNode(error_node));
+                }
+            }
+            if self.eat(Kind::Comma) && self.at(Kind::RCurly) {
+                return (properties, Some(self.prev_token_end - 1));
+            }
+        }
+    }
+
     fn parse_object_expression_property(&mut self) -> ObjectPropertyKind<'a> {
         match self.cur_kind() {
             Kind::Dot3 => ObjectPropertyKind::SpreadProperty(self.parse_spread_element()),
@@ -6,6 +6,7 @@ use oxc_str::Str;
 use super::{VariableDeclarationParent, grammar::CoverGrammar};
 use crate::{
     Context, ParserConfig as Config, ParserImpl, StatementContext, diagnostics,
+    error_handler::RecoveryBoundary,
     lexer::Kind,
     modifiers::{ModifierKind, Modifiers},
 };
@@ -68,8 +69,17 @@ impl<'a, C: Config> ParserImpl<'a, C> {
                 None
             };
 
+            let stmt_start = self.start_span();
             let stmt = self.parse_statement_list_item(stmt_ctx);
 
+            if let Some(error_node) =
+                self.recover_from_fatal_error(stmt_start, RecoveryBoundary::Statement)
+            {
+                expecting_directives = false;
+                statements.push(Statement::ErrorNode(error_node));
+                continue;
+            }
+
             // Don't reparse a module declaration: `export` already committed to the
             // Module goal while parsing, so reparsing would record the export twice.
             // e.g. `@foo export default class C { x = await + 1 }`
@@ -170,6 +180,16 @@ impl<'a, C: Config> ParserImpl<'a, C> {
             Kind::Const => self.parse_const_statement(stmt_ctx),
             Kind::Using if self.is_using_declaration() => self.parse_using_statement(stmt_ctx),
             Kind::Await if self.is_using_statement() => self.parse_using_statement(stmt_ctx),
+            Kind::Declare
+                if self.is_flow
+                    && self.lexer.peek_token().kind() == Kind::Export
+                    && !self.lexer.peek_token().is_on_new_line() =>
+            {
+                self.parse_flow_declare_export_declaration(self.start_span())
+            }
+            Kind::Declare if self.is_flow && self.at_flow_declare_module_exports() => {
+                self.parse_flow_declare_module_exports()
+            }
             Kind::Interface
             | Kind::Type
             | Kind::Module
@@ -188,6 +208,9 @@ impl<'a, C: Config> ParserImpl<'a, C> {
             {
                 self.parse_ts_declaration_statement(self.start_span(), stmt_ctx)
             }
+            Kind::Ident if self.is_flow && self.at_flow_opaque_type() => {
+                self.parse_ts_declaration_statement(self.start_span(), stmt_ctx)
+            }
             _ => self.parse_expression_or_labeled_statement(),
         };
 
@@ -828,7 +851,7 @@ impl<'a, C: Config> ParserImpl<'a, C> {
     fn parse_const_statement(&mut self, stmt_ctx: StatementContext) -> Statement<'a> {
         let span = self.start_span();
         self.bump_any();
-        if self.is_ts && self.at(Kind::Enum) {
+        if self.source_type.is_typescript() && self.at(Kind::Enum) {
             let modifiers = Modifiers::new_single(ModifierKind::Const, span);
             Statement::from(self.parse_ts_enum_declaration(span, &modifiers))
         } else {
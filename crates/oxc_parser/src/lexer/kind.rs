@@ -147,6 +147,7 @@ fieldless_enum! {
         Pipe2,
         Pipe2Eq,
         PipeEq,
+        PipeGt, // |>
         Plus,
         Plus2,
         PlusEq,
@@ -652,6 +653,7 @@ impl Kind {
             Pipe2 => "||",
             Pipe2Eq => "||=",
             PipeEq => "|=",
+            PipeGt => "|>",
             Plus => "+",
             Plus2 => "++",
             PlusEq => "+=",
@@ -254,6 +254,13 @@ impl<'a, C: Config> Lexer<'a, C> {
         }
     }
 
+    /// Read the token at `offset`, which must be the start of a statement.
+    /// Used instead of `first_token` to resume lexing part way through the file.
+    pub(crate) fn first_token_at(&mut self, offset: u32) -> Token {
+        self.source.set_offset(offset);
+        self.next_token()
+    }
+
     /// Read next token in file.
     /// Use `first_token` for first token, and this method for all further tokens.
     pub fn next_token(&mut self) -> Token {
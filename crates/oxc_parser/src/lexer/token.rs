@@ -113,7 +113,7 @@ impl Token {
     }
 
     // `set_span` is only exposed as public API when `mutate_tokens` feature is enabled.
-    // Otherwise, it is only accessible within `lexer` module.
+    // Otherwise, it is only accessible within this crate.
     #[cfg(feature = "mutate_tokens")]
     #[inline]
     pub fn set_span(&mut self, span: Span) {
@@ -122,8 +122,7 @@ impl Token {
 
     #[cfg(not(feature = "mutate_tokens"))]
     #[inline]
-    #[allow(dead_code, clippy::allow_attributes)]
-    pub(super) fn set_span(&mut self, span: Span) {
+    pub(crate) fn set_span(&mut self, span: Span) {
         self.set_span_impl(span);
     }
 
@@ -71,6 +71,16 @@ impl<'a> TriviaBuilder<'a> {
         }
     }
 
+    /// Restore the current token's pure comment (if any) after it was marked as not applied.
+    pub fn reset_current_pure_comment(&mut self) {
+        if let Some(index) = self.pure_comment
+            && let Some(comment) = self.comments.get_mut(index)
+            && comment.content == CommentContent::PureNotApplied
+        {
+            comment.content = CommentContent::Pure;
+        }
+    }
+
     pub fn add_irregular_whitespace(&mut self, start: u32, end: u32) {
         // The irregular whitespaces array is ordered; only add if not added before, to avoid
         // duplicates when the parser looks ahead (e.g. `peek_token`) and rewinds, then re-lexes the
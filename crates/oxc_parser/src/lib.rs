@@ -70,10 +70,13 @@ pub mod config;
 mod context;
 mod cursor;
 mod error_handler;
+mod fragment;
+mod incremental;
 mod modifiers;
 mod module_record;
 mod state;
 
+mod flow;
 mod js;
 mod jsx;
 mod ts;
@@ -89,24 +92,29 @@ pub mod lexer;
 
 use oxc_allocator::{Allocator, ArenaBox, ArenaVec, Dummy, GetAllocator};
 use oxc_ast::{
-    ast::{Expression, Program, Statement},
+    ast::{BindingPattern, ClassElement, Expression, Program, Statement, TSType},
     builder::{AstBuilder, GetAstBuilder},
 };
 use oxc_diagnostics::{Diagnostics, OxcDiagnostic};
 use oxc_span::{SourceType, Span};
 use oxc_syntax::module_record::ModuleRecord;
 
-pub use crate::lexer::{Kind, Token};
 use crate::{
     config::{
         LexerConfig, NoTokensParserConfig, ParserConfig, RuntimeParserConfig, TokensParserConfig,
     },
     context::{Context, StatementContext},
-    error_handler::FatalError,
+    error_handler::{FatalError, RecoveryBoundary},
+    fragment::{Fragment, FragmentKind},
     lexer::Lexer,
     module_record::ModuleRecordBuilder,
     state::ParserState,
 };
+pub use crate::{
+    fragment::FragmentReturn,
+    incremental::TextEdit,
+    lexer::{Kind, Token},
+};
 
 /// Maximum length of source which can be parsed (in bytes).
 /// ~4 GiB on 64-bit systems, ~2 GiB on 32-bit systems.
@@ -140,7 +148,9 @@ pub(crate) const MAX_LEN: usize = if size_of::<usize>() >= 8 {
 /// 3. [`panicked`] will be false
 ///
 /// When the parser cannot recover, it will abort and terminate parsing early. [`program`] will
-/// be empty and [`panicked`] will be `true`.
+/// be empty and [`panicked`] will be `true`. With [`ParseOptions::error_recovery`] enabled, the
+/// parser instead replaces the statement, class member or object property containing the error
+/// with an [`ErrorNode`](oxc_ast::ast::ErrorNode) and continues parsing.
 ///
 /// [`program`]: ParserReturn::program
 /// [`diagnostics`]: ParserReturn::diagnostics
@@ -231,6 +241,29 @@ pub struct ParseOptions {
     ///
     /// [`V8IntrinsicExpression`]: oxc_ast::ast::V8IntrinsicExpression
     pub allow_v8_intrinsics: bool,
+
+    /// Parse the [Hack-style pipeline operator](https://github.com/tc39/proposal-pipeline-operator)
+    /// `a |> f(%)` into [`PipelineExpression`]s, with `%` as the topic reference.
+    ///
+    /// If [`ParseOptions::allow_v8_intrinsics`] is also enabled, `%` is parsed as a V8 runtime
+    /// call outside of pipe bodies.
+    ///
+    /// Default: `false`
+    ///
+    /// [`PipelineExpression`]: oxc_ast::ast::PipelineExpression
+    pub allow_pipeline_operator: bool,
+
+    /// Recover from syntax errors which would otherwise abort parsing.
+    ///
+    /// By default, the parser stops at the first unrecoverable syntax error and returns an empty
+    /// [`Program`]. When this option is `true`, the parser instead skips to the next statement,
+    /// class member or object property, records the skipped source text as an [`ErrorNode`], and
+    /// keeps parsing. Every syntax error is still reported in [`ParserReturn::diagnostics`].
+    ///
+    /// Default: `false`
+    ///
+    /// [`ErrorNode`]: oxc_ast::ast::ErrorNode
+    pub error_recovery: bool,
 }
 
 impl Default for ParseOptions {
@@ -241,6 +274,8 @@ impl Default for ParseOptions {
             allow_return_outside_function: false,
             preserve_parens: true,
             allow_v8_intrinsics: false,
+            allow_pipeline_operator: false,
+            error_recovery: false,
         }
     }
 }
@@ -440,6 +475,169 @@ mod parser_parse {
                 .parse_expression()
             }
         }
+
+        /// Parse a single statement or declaration, e.g. `let x = 1;` or `if (x) {}`.
+        ///
+        /// `import` and `export` declarations are only permitted by [`Parser::parse_module_item`],
+        /// and are reported as errors here.
+        ///
+        /// Syntax errors, including source text which is not part of the statement, are returned in
+        /// [`FragmentReturn::diagnostics`] alongside the parsed statement.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_ast::ast::Statement;
+        /// use oxc_parser::Parser;
+        /// use oxc_span::SourceType;
+        ///
+        /// let allocator = Allocator::new();
+        /// let ret = Parser::new(&allocator, "let x = 1;", SourceType::mjs()).parse_statement();
+        /// assert!(ret.diagnostics.is_empty());
+        /// assert!(matches!(ret.node, Statement::VariableDeclaration(_)));
+        /// ```
+        pub fn parse_statement(self) -> FragmentReturn<Statement<'a>> {
+            self.parse_fragment(FragmentKind::Statement).map(|fragment| match fragment {
+                Fragment::Statement(stmt) => stmt,
+                _ => unreachable!(),
+            })
+        }
+
+        /// Parse a list of statements and declarations, as found in a block or function body.
+        ///
+        /// Unlike [`Parser::parse`], string literals at the start are not parsed as directives.
+        /// As in [`Parser::parse_statement`], `import` and `export` declarations are errors.
+        pub fn parse_statements(self) -> FragmentReturn<ArenaVec<'a, Statement<'a>>> {
+            self.parse_fragment(FragmentKind::Statements).map(|fragment| match fragment {
+                Fragment::Statements(stmts) => stmts,
+                _ => unreachable!(),
+            })
+        }
+
+        /// Parse a single top-level item of a module, which can be an `import` or `export`
+        /// declaration as well as any statement.
+        pub fn parse_module_item(self) -> FragmentReturn<Statement<'a>> {
+            self.parse_fragment(FragmentKind::ModuleItem).map(|fragment| match fragment {
+                Fragment::Statement(stmt) => stmt,
+                _ => unreachable!(),
+            })
+        }
+
+        /// Parse a single TypeScript type, e.g. `Record<string, number[]>`.
+        pub fn parse_ts_type(self) -> FragmentReturn<TSType<'a>> {
+            self.parse_fragment(FragmentKind::TSType).map(|fragment| match fragment {
+                Fragment::TSType(ty) => ty,
+                _ => unreachable!(),
+            })
+        }
+
+        /// Parse a single binding pattern, e.g. `{ a, b: [c, ...d] }`.
+        ///
+        /// Type annotations and initializers are not part of a [`BindingPattern`],
+        /// so are not accepted.
+        pub fn parse_binding_pattern(self) -> FragmentReturn<BindingPattern<'a>> {
+            self.parse_fragment(FragmentKind::BindingPattern).map(|fragment| match fragment {
+                Fragment::BindingPattern(pattern) => pattern,
+                _ => unreachable!(),
+            })
+        }
+
+        /// Parse a single member of a class body, e.g. `static #x = 1;` or `get y() {}`.
+        pub fn parse_class_member(self) -> FragmentReturn<ClassElement<'a>> {
+            self.parse_fragment(FragmentKind::ClassElement).map(|fragment| match fragment {
+                Fragment::ClassElement(element) => element,
+                _ => unreachable!(),
+            })
+        }
+
+        // Dispatches via `Any`, same as `parse` does.
+        fn parse_fragment(self, kind: FragmentKind) -> FragmentReturn<Fragment<'a>> {
+            let config: &dyn Any = &self.config;
+            if config.is::<NoTokensParserConfig>() {
+                parse_fragment_with_no_tokens_config(
+                    self.allocator,
+                    self.source_text,
+                    self.source_type,
+                    self.options,
+                    kind,
+                )
+            } else if config.is::<TokensParserConfig>() {
+                parse_fragment_with_tokens_config(
+                    self.allocator,
+                    self.source_text,
+                    self.source_type,
+                    self.options,
+                    kind,
+                )
+            } else if let Some(&config) = config.downcast_ref::<RuntimeParserConfig>() {
+                parse_fragment_with_runtime_config(
+                    self.allocator,
+                    self.source_text,
+                    self.source_type,
+                    self.options,
+                    config,
+                    kind,
+                )
+            } else {
+                ParserImpl::<C>::new(
+                    self.allocator,
+                    self.source_text,
+                    self.source_type,
+                    self.options,
+                    self.config,
+                    UniquePromise::new(),
+                )
+                .parse_fragment(kind)
+            }
+        }
+
+        /// Parse source text after it was edited, reusing the unchanged parts of a previous parse.
+        ///
+        /// `previous` must be the unmodified result of parsing the source text before `edits`
+        /// were applied, with the same [`SourceType`], [`ParseOptions`] and config.
+        /// Top-level statements which are not affected by `edits` are moved from `previous`
+        /// into the returned [`Program`] instead of being parsed again. The statements which
+        /// are replaced still occupy memory in the arena until the [`Allocator`] is reset.
+        ///
+        /// For an unambiguous [`SourceType`], pass the unambiguous source type again, not the
+        /// resolved [`Program::source_type`] of `previous`. Statements are reused while the
+        /// edits do not change whether the file is a script or a module.
+        ///
+        /// Falls back to parsing the whole source text when `previous` cannot be reused,
+        /// so the result is always the same as [`Parser::parse`] would return.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::{Parser, TextEdit};
+        /// use oxc_span::{SourceType, Span};
+        ///
+        /// let allocator = Allocator::new();
+        /// let source_type = SourceType::mjs();
+        /// let previous = Parser::new(&allocator, "let a = 1;\nlet b = 2;\nlet c = 3;", source_type).parse();
+        ///
+        /// let edits = [TextEdit::new(Span::new(19, 20), "20")];
+        /// let ret = Parser::new(&allocator, "let a = 1;\nlet b = 20;\nlet c = 3;", source_type)
+        ///     .reparse(previous, &edits);
+        /// assert_eq!(ret.program.body.len(), 3);
+        /// ```
+        pub fn reparse(self, previous: ParserReturn<'a>, edits: &[TextEdit<'_>]) -> ParserReturn<'a>
+        where
+            C: Clone,
+        {
+            let ret = ParserImpl::<C>::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                self.config.clone(),
+                UniquePromise::new(),
+            )
+            .reparse(previous, edits);
+            ret.unwrap_or_else(|| self.parse())
+        }
     }
 
     // ===========================================================================
@@ -453,8 +651,9 @@ mod parser_parse {
     // final cdylib — none of which can be deduped by COMDAT (different names)
     // or fat LTO (slightly different inlining contexts).
     //
-    // To avoid that, `Parser<C>::parse` and `Parser<C>::parse_expression` dispatch
-    // via `Any` to one of the helpers below for the three known configs.
+    // To avoid that, `Parser<C>::parse`, `Parser<C>::parse_expression` and the fragment
+    // parsers (`Parser<C>::parse_statement` etc) dispatch via `Any` to one of the helpers
+    // below for the three known configs.
     // Each helper is non-generic, so it's emitted exactly once in `oxc_parser`'s
     // rlib and shared by all consumers. The `Any::is` / `downcast_ref` checks fold
     // at compile time when `C` is concrete, so each monomorphization of the dispatch
@@ -578,6 +777,64 @@ mod parser_parse {
         )
         .parse_expression()
     }
+
+    #[inline(never)]
+    fn parse_fragment_with_no_tokens_config<'a>(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+        options: ParseOptions,
+        kind: FragmentKind,
+    ) -> FragmentReturn<Fragment<'a>> {
+        ParserImpl::<NoTokensParserConfig>::new(
+            allocator,
+            source_text,
+            source_type,
+            options,
+            NoTokensParserConfig,
+            UniquePromise::new(),
+        )
+        .parse_fragment(kind)
+    }
+
+    #[inline(never)]
+    fn parse_fragment_with_tokens_config<'a>(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+        options: ParseOptions,
+        kind: FragmentKind,
+    ) -> FragmentReturn<Fragment<'a>> {
+        ParserImpl::<TokensParserConfig>::new(
+            allocator,
+            source_text,
+            source_type,
+            options,
+            TokensParserConfig,
+            UniquePromise::new(),
+        )
+        .parse_fragment(kind)
+    }
+
+    #[inline(never)]
+    fn parse_fragment_with_runtime_config<'a>(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+        options: ParseOptions,
+        config: RuntimeParserConfig,
+        kind: FragmentKind,
+    ) -> FragmentReturn<Fragment<'a>> {
+        ParserImpl::<RuntimeParserConfig>::new(
+            allocator,
+            source_text,
+            source_type,
+            options,
+            config,
+            UniquePromise::new(),
+        )
+        .parse_fragment(kind)
+    }
 }
 use parser_parse::UniquePromise;
 
@@ -606,7 +863,7 @@ struct ParserImpl<'a, C: ParserConfig> {
     /// If resolved to Script → emit these errors.
     deferred_script_errors: Vec<OxcDiagnostic>,
 
-    fatal_error: Option<FatalError>,
+    fatal_error: Option<FatalError<'a>>,
 
     /// The current parsing token
     token: Token,
@@ -626,8 +883,14 @@ struct ParserImpl<'a, C: ParserConfig> {
     /// Module Record Builder
     module_record_builder: ModuleRecordBuilder<'a>,
 
-    /// Precomputed typescript detection
+    /// Precomputed typescript detection.
+    ///
+    /// Also `true` for Flow, which shares TypeScript's syntax for type annotations,
+    /// generics, type aliases, interfaces and `declare` statements.
     is_ts: bool,
+
+    /// Precomputed Flow detection, for the syntax which differs from TypeScript.
+    is_flow: bool,
 }
 
 impl<'a, C: ParserConfig> ParserImpl<'a, C> {
@@ -659,7 +922,8 @@ impl<'a, C: ParserConfig> ParserImpl<'a, C> {
             ctx: Self::default_context(source_type, options),
             ast: AstBuilder::new(allocator),
             module_record_builder: ModuleRecordBuilder::new(allocator, source_type),
-            is_ts: source_type.is_typescript(),
+            is_ts: source_type.is_typescript() || source_type.is_flow(),
+            is_flow: source_type.is_flow(),
         }
     }
 
@@ -815,9 +1079,15 @@ impl<'a, C: ParserConfig> ParserImpl<'a, C> {
             self.rewind(checkpoint);
 
             // Parse the statement with await context enabled (TopLevel context is already set)
-            let stmt = self.context_add(Context::Await, |p| {
+            let stmt_start = self.start_span();
+            let mut stmt = self.context_add(Context::Await, |p| {
                 p.parse_statement_list_item(StatementContext::StatementList)
             });
+            if let Some(error_node) =
+                self.recover_from_fatal_error(stmt_start, RecoveryBoundary::Statement)
+            {
+                stmt = Statement::ErrorNode(error_node);
+            }
 
             // Replace the statement if the index is valid
             if stmt_index < statements.len() {
@@ -907,7 +1177,7 @@ impl<'a, C: ParserConfig> GetAstBuilder<'a> for ParserImpl<'a, C> {
 mod test {
     use std::path::Path;
 
-    use oxc_ast::ast::{CommentKind, Expression, Statement};
+    use oxc_ast::ast::{ClassElement, CommentKind, Expression, ObjectPropertyKind, Statement};
     use oxc_span::GetSpan;
 
     use super::*;
@@ -932,6 +1202,172 @@ mod test {
         assert!(matches!(expr, Expression::Identifier(_)));
     }
 
+    #[test]
+    fn parse_fragments_smoke_test() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::ts();
+        let parser = |source| Parser::new(&allocator, source, source_type);
+
+        let ret = parser("let x = 1;").parse_statement();
+        assert!(ret.diagnostics.is_empty());
+        assert!(matches!(ret.node, Statement::VariableDeclaration(_)));
+        assert_eq!(ret.node.span(), Span::new(0, 10));
+        let stmts = parser("a;\nif (b) c;\nfunction d() {}").parse_statements().node;
+        assert_eq!(stmts.len(), 3);
+        let ret = parser("export const y = 2;").parse_module_item();
+        assert!(ret.diagnostics.is_empty());
+        assert!(matches!(ret.node, Statement::ExportNamedDeclaration(_)));
+        let ty = parser("Record<string, number[]>").parse_ts_type().node;
+        assert!(matches!(ty, TSType::TSTypeReference(_)));
+        let pattern = parser("{ a, b: [c, ...d] }").parse_binding_pattern().node;
+        assert!(matches!(pattern, BindingPattern::ObjectPattern(_)));
+        let member = parser("static #x: number = 1;").parse_class_member().node;
+        assert!(matches!(member, ClassElement::PropertyDefinition(_)));
+
+        // Syntax errors
+        assert!(!parser("let x = ;").parse_statement().diagnostics.is_empty());
+        assert!(!parser("a: string").parse_ts_type().diagnostics.is_empty());
+        // Source text after the fragment
+        assert!(!parser("a; b;").parse_statement().diagnostics.is_empty());
+        assert!(!parser("{ a } = b").parse_binding_pattern().diagnostics.is_empty());
+        assert!(!parser("x = 1; y = 2;").parse_class_member().diagnostics.is_empty());
+        assert!(!parser("").parse_statement().diagnostics.is_empty());
+    }
+
+    #[test]
+    fn parse_fragment_with_recoverable_error() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let parser = |source| Parser::new(&allocator, source, source_type);
+
+        // Shorthand properties with initializers are only valid in patterns
+        let ret = parser("x = { a = 1 };").parse_statement();
+        assert!(!ret.panicked);
+        assert_eq!(ret.diagnostics.len(), 1);
+        assert!(matches!(ret.node, Statement::ExpressionStatement(_)));
+
+        // Source text after the statement is reported, and the statement kept
+        let ret = parser("a; b;").parse_statement();
+        assert!(!ret.panicked);
+        assert_eq!(ret.diagnostics.len(), 1);
+        assert!(matches!(ret.node, Statement::ExpressionStatement(_)));
+
+        let ret = parser("let x = ;").parse_statement();
+        assert!(ret.panicked);
+        assert!(!ret.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn parse_fragment_module_declarations() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let parser = |source| Parser::new(&allocator, source, source_type);
+
+        for source in ["import a from \"a\";", "export const b = 1;", "export default 1;"] {
+            let ret = parser(source).parse_statement();
+            assert_eq!(ret.diagnostics.len(), 1, "{source}");
+            assert!(ret.node.is_module_declaration(), "{source}");
+
+            let ret = parser(source).parse_statements();
+            assert_eq!(ret.diagnostics.len(), 1, "{source}");
+
+            let ret = parser(source).parse_module_item();
+            assert!(ret.diagnostics.is_empty(), "{source}");
+            assert!(ret.node.is_module_declaration(), "{source}");
+        }
+
+        // `import()` and `import.meta` are expressions
+        assert!(parser("import(\"a\");").parse_statement().diagnostics.is_empty());
+        assert!(parser("import.meta.url;").parse_statement().diagnostics.is_empty());
+    }
+
+    #[test]
+    fn error_recovery() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let opts = ParseOptions { error_recovery: true, ..ParseOptions::default() };
+        let parse =
+            |source| Parser::new(&allocator, source, source_type).with_options(opts).parse();
+
+        // Statements
+        let source = "let a = ;\nfoo bar baz\nlet b = 1;\n}\nlet c = [1, 2";
+        let ret = parse(source);
+        assert!(!ret.panicked);
+        assert_eq!(ret.diagnostics.len(), 4);
+        let body = ret
+            .program
+            .body
+            .iter()
+            .map(|stmt| match stmt {
+                Statement::ErrorNode(node) => format!("error: {}", node.span.source_text(source)),
+                stmt => stmt.span().source_text(source).to_string(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            body,
+            [
+                "error: let a = ;",
+                "error: foo bar baz",
+                "let b = 1;",
+                "error: }",
+                "error: let c = [1, 2"
+            ]
+        );
+
+        // Nested statement lists resynchronize at the closing `}`
+        let source = "function f() { a b c }\nf();";
+        let ret = parse(source);
+        assert_eq!(ret.diagnostics.len(), 1);
+        let Statement::FunctionDeclaration(func) = &ret.program.body[0] else { unreachable!() };
+        let body = &func.body.as_ref().unwrap().statements;
+        let Statement::ErrorNode(node) = &body[0] else { unreachable!() };
+        assert_eq!(node.span.source_text(source), "a b c");
+        assert!(matches!(ret.program.body[1], Statement::ExpressionStatement(_)));
+
+        // Class members
+        let source = "class C {\n  a = ;\n  b() {}\n  c d e\n  f = 1\n}";
+        let ret = parse(source);
+        assert_eq!(ret.diagnostics.len(), 2);
+        let Statement::ClassDeclaration(class) = &ret.program.body[0] else { unreachable!() };
+        let elements = &class.body.body;
+        assert_eq!(elements.len(), 4);
+        assert!(
+            matches!(&elements[0], ClassElement::ErrorNode(node) if node.span.source_text(source) == "a = ;")
+        );
+        assert!(matches!(elements[1], ClassElement::MethodDefinition(_)));
+        assert!(
+            matches!(&elements[2], ClassElement::ErrorNode(node) if node.span.source_text(source) == "c d e")
+        );
+        assert!(matches!(elements[3], ClassElement::PropertyDefinition(_)));
+
+        // Object properties
+        let source = "x = { a: , b: 1 c: (2, 3), d, e: `${ { } }` f, }";
+        let ret = parse(source);
+        assert_eq!(ret.diagnostics.len(), 3);
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else { unreachable!() };
+        let Expression::AssignmentExpression(assign) = &stmt.expression else { unreachable!() };
+        let Expression::ObjectExpression(object) = &assign.right else { unreachable!() };
+        let properties = object
+            .properties
+            .iter()
+            .map(|prop| match prop {
+                ObjectPropertyKind::ErrorNode(node) => {
+                    format!("error: {}", node.span.source_text(source))
+                }
+                prop => prop.span().source_text(source).to_string(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            properties,
+            ["error: a:", "b: 1", "error: c: (2, 3)", "d", "e: `${ { } }`", "error: f"]
+        );
+
+        // Disabled by default
+        let ret = Parser::new(&allocator, "let a = ;\nlet b = 1;", source_type).parse();
+        assert!(ret.panicked);
+        assert!(ret.program.is_empty());
+    }
+
     #[test]
     fn flow_error() {
         let allocator = Allocator::default();
@@ -954,6 +1390,77 @@ mod test {
         }
     }
 
+    #[test]
+    fn flow() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::jsx().with_flow(true);
+        let sources = [
+            "// @flow\nfunction f(x: ?number, ...rest: Array<string>): void {}",
+            "import type { Node } from './node'; import typeof T from './t'; import typeof * as ns from './ns';",
+            "type Maybe<+T: Object = {}, -U> = ?T | U;",
+            "type Exact = {| +a: A | B, -b: number, c?: string |}; type Empty = {||};",
+            "opaque type ID: string = string; export opaque type Token = string; declare opaque type Secret: string;",
+            "declare function f(x: number): string; declare function f(x: string): number; declare var x: number;",
+            "interface Stack<T> { push(item: T): void }",
+            "class C<T> extends B<T> implements I { +p: T; static s: number = 1; m<U>(a: T, b?: U): T { return (a: any); } }",
+            "const x = ((y: any): string); const z = (a, b); const f = async (x: number): Promise<void> => {};",
+            "export default (f: Function);",
+            "type F = (string, number) => void; type G = string => void; type H = ?() => void; type I = (...Array<string>) => void;",
+            "type J = (x: string) => ?string | number; type K = Array<(string) => void>;",
+            "type M = { [string]: number, +[k: string]: T }; type E = *;",
+            "const f = (x): string => x; const g = (x: (string) => void) => {};",
+            "declare export function f(string): void; declare export class C { m(number): void } declare export var x: number;",
+            "declare export type T = string; declare export default class D {} declare export { x }; declare export * from 'm';",
+            "declare module 'm' { declare export function f(): void; declare var y: string; }",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.diagnostics.is_empty(), "{source}: {:?}", ret.diagnostics);
+            assert!(!ret.is_flow_language, "{source}");
+        }
+
+        let ret = Parser::new(&allocator, "(x: number);", source_type).parse();
+        let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else {
+            panic!("expected an expression statement");
+        };
+        assert!(matches!(
+            stmt.expression.without_parentheses(),
+            Expression::FlowTypeCastExpression(_)
+        ));
+
+        let ret = Parser::new(&allocator, "import typeof T from './t';", source_type).parse();
+        let Some(Statement::ImportDeclaration(decl)) = ret.program.body.first() else {
+            panic!("expected an import declaration");
+        };
+        assert!(decl.import_kind.is_typeof());
+
+        // Variance is only allowed on type parameters of classes, interfaces and type aliases
+        let ret = Parser::new(&allocator, "function f<+T>() {}", source_type).parse();
+        assert_eq!(ret.diagnostics.len(), 1);
+
+        // TypeScript-only syntax and unsupported Flow syntax are errors in Flow files
+        let sources = [
+            "declare module.exports: T;",
+            "declare export default string;",
+            "enum E {}",
+            "namespace N {}",
+            "x!;",
+            "class C { private x: T }",
+            "export = x;",
+            "x satisfies T;",
+            "let x!: T;",
+            "type T = unique symbol;",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(!ret.diagnostics.is_empty(), "{source}");
+        }
+
+        // Flow syntax is not parsed without `Language::Flow`
+        let ret = Parser::new(&allocator, "(x: number);", SourceType::jsx()).parse();
+        assert!(!ret.diagnostics.is_empty());
+    }
+
     #[test]
     fn ts_module_declaration() {
         let allocator = Allocator::default();
@@ -1028,6 +1535,90 @@ mod test {
         }
     }
 
+    #[test]
+    fn pipeline_operator() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let opts = ParseOptions { allow_pipeline_operator: true, ..ParseOptions::default() };
+        {
+            let source = "a |> f(%) |> % + 1";
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.diagnostics.is_empty(), "{:?}", ret.diagnostics);
+            let Some(Statement::ExpressionStatement(expr_stmt)) = ret.program.body.first() else {
+                panic!("Expected ExpressionStatement");
+            };
+            let Expression::PipelineExpression(pipeline) = &expr_stmt.expression else {
+                panic!("Expected PipelineExpression");
+            };
+            assert_eq!(pipeline.head.span().source_text(source), "a");
+            let Expression::PipelineExpression(body) = &pipeline.body else {
+                panic!("Expected nested PipelineExpression");
+            };
+            assert_eq!(body.head.span().source_text(source), "f(%)");
+            assert_eq!(body.body.span().source_text(source), "% + 1");
+        }
+        {
+            let sources = [
+                "a |> (% ? b : c)",
+                "a |> (() => %)",
+                "a |> (x = %)",
+                "x = a |> %, y = b |> %",
+                "c ? d : a |> %",
+                "a |> % % 2",
+                "a |> %?.b",
+            ];
+            for source in sources {
+                let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+                assert!(ret.diagnostics.is_empty(), "{source}: {:?}", ret.diagnostics);
+            }
+        }
+        {
+            let sources = [
+                ("a |> f(b)", "Pipe body must contain the topic reference `%`"),
+                ("a |> f(%) |> g(b)", "Pipe body must contain the topic reference `%`"),
+                ("f(%)", "Topic reference `%` is only allowed in the body of a pipe expression"),
+                (
+                    "a |> % ? b : c",
+                    "Conditional expression is not allowed as a pipe body without parentheses",
+                ),
+                (
+                    "a |> () => %",
+                    "Arrow function is not allowed as a pipe body without parentheses",
+                ),
+                (
+                    "a |> x = %",
+                    "Assignment expression is not allowed as a pipe body without parentheses",
+                ),
+            ];
+            for (source, message) in sources {
+                let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+                assert_eq!(ret.diagnostics.len(), 1, "{source}: {:?}", ret.diagnostics);
+                assert_eq!(ret.diagnostics[0].to_string(), message);
+            }
+        }
+        {
+            // Unparenthesized bodies are also detected without `ParenthesizedExpression` nodes
+            let opts = ParseOptions { preserve_parens: false, ..opts };
+            let ret =
+                Parser::new(&allocator, "a |> (() => %)", source_type).with_options(opts).parse();
+            assert!(ret.diagnostics.is_empty(), "{:?}", ret.diagnostics);
+            let ret =
+                Parser::new(&allocator, "a |> () => %", source_type).with_options(opts).parse();
+            assert_eq!(ret.diagnostics.len(), 1);
+        }
+        {
+            // `%` is a V8 runtime call outside of pipe bodies
+            let opts = ParseOptions { allow_v8_intrinsics: true, ..opts };
+            let source = "%DebugPrint(a |> %)";
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.diagnostics.is_empty(), "{:?}", ret.diagnostics);
+        }
+        {
+            let ret = Parser::new(&allocator, "a |> f(%)", source_type).parse();
+            assert_eq!(ret.diagnostics.len(), 1);
+        }
+    }
+
     #[test]
     fn comments() {
         let allocator = Allocator::default();
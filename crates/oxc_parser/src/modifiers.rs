@@ -646,6 +646,19 @@ impl<C: Config> ParserImpl<'_, C> {
     }
 }
 
+/// Modifiers which Flow does not have, e.g. `private` or `readonly`.
+const TYPESCRIPT_ONLY_MODIFIERS: ModifierKinds = ModifierKinds::new([
+    ModifierKind::Public,
+    ModifierKind::Private,
+    ModifierKind::Protected,
+    ModifierKind::Readonly,
+    ModifierKind::Abstract,
+    ModifierKind::Override,
+    ModifierKind::In,
+    ModifierKind::Out,
+    ModifierKind::Const,
+]);
+
 /// Static lookup table for which modifiers are illegal preceding another modifier.
 /// Table is indexed by [`ModifierKind`] discriminant of the later modifier.
 /// This is all calculated at compile time, and produces a 30-byte lookup table.
@@ -734,6 +747,10 @@ impl<C: Config> ParserImpl<'_, C> {
         if existing_kinds.intersects(illegal_preceding_modifier_kinds) {
             self.illegal_modifier_error(existing_kinds, modifier);
         }
+
+        if self.is_flow && TYPESCRIPT_ONLY_MODIFIERS.contains(modifier.kind) {
+            self.error(diagnostics::modifier_can_only_be_used_in_typescript_files(modifier));
+        }
     }
 
     /// Create an error for an illegal modifier.
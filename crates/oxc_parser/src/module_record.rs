@@ -44,8 +44,8 @@ impl<'a> ModuleRecordBuilder<'a> {
 
         let module_record = &self.module_record;
 
-        // Skip checking for exports in TypeScript
-        if !self.source_type.is_typescript() {
+        // Skip checking for exports in TypeScript and Flow
+        if !self.source_type.is_typescript() && !self.source_type.is_flow() {
             // It is a Syntax Error if the ExportedNames of ModuleItemList contains any duplicate entries.
             for name_span in &self.exported_bindings_duplicated {
                 let old_span = module_record.exported_bindings[&name_span.name];
@@ -387,6 +387,30 @@ impl<'a> ModuleRecordBuilder<'a> {
     pub fn set_module_syntax(&mut self) {
         self.module_record.has_module_syntax = true;
     }
+
+    /// Record a top-level statement which was reused from a previous parse, instead of parsed.
+    ///
+    /// `default_keyword_span` is the span of `default` in `export default`.
+    /// Dynamic imports and `import.meta` nested in the statement are not recorded.
+    pub fn visit_reused_statement(&mut self, stmt: &Statement<'a>, default_keyword_span: Span) {
+        match stmt {
+            Statement::ImportDeclaration(decl) => self.visit_import_declaration(decl),
+            Statement::ExportAllDeclaration(decl) => self.visit_export_all_declaration(decl),
+            Statement::ExportDefaultDeclaration(decl) => {
+                self.visit_export_default_declaration(decl, default_keyword_span);
+            }
+            Statement::ExportNamedDeclaration(decl) => self.visit_export_named_declaration(decl),
+            Statement::TSExportAssignment(_) | Statement::TSNamespaceExportDeclaration(_) => {
+                self.set_module_syntax();
+            }
+            _ => {}
+        }
+    }
+
+    /// Record a dynamic import which was reused from a previous parse.
+    pub fn visit_reused_dynamic_import(&mut self, dynamic_import: DynamicImport) {
+        self.module_record.dynamic_imports.push(dynamic_import);
+    }
 }
 
 fn iter_binding_identifiers_of_declaration<'a, F>(decl: &Declaration<'a>, f: &mut F)
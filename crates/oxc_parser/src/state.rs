@@ -31,6 +31,13 @@ pub struct ParserState<'a> {
     /// Used to determine if a statement needs to be stored for potential reparsing
     /// in unambiguous mode.
     pub encountered_await_identifier: bool,
+
+    /// Whether the parser is inside the body of a Hack-style pipeline `a |> body`,
+    /// where `%` is a topic reference.
+    pub in_pipeline_body: bool,
+
+    /// Whether a topic reference was found in the innermost pipeline body.
+    pub topic_reference_used: bool,
 }
 
 impl ParserState<'_> {
@@ -41,6 +48,8 @@ impl ParserState<'_> {
             trailing_commas: FxHashMap::default(),
             potential_await_reparse: Vec::new(),
             encountered_await_identifier: false,
+            in_pipeline_body: false,
+            topic_reference_used: false,
         }
     }
 }
@@ -288,11 +288,19 @@ impl<'a, C: Config> ParserImpl<'a, C> {
             return self.parse_signature_member(CallOrConstructorSignature::Constructor);
         }
 
+        // Flow `+foo: T`
+        let flow_readonly = self.eat_flow_variance();
+
         let modifiers = self.parse_modifiers(
             /* permit_const_as_modifier */ true,
             /* stop_on_start_of_class_static_block */ false,
         );
 
+        // Flow indexer `[K]: V`, whose key may be unnamed
+        if self.is_flow && self.at(Kind::LBrack) {
+            return self.parse_flow_indexer(span, flow_readonly);
+        }
+
         if self.is_index_signature() {
             self.verify_modifiers(
                 &modifiers,
@@ -320,7 +328,11 @@ impl<'a, C: Config> ParserImpl<'a, C> {
             return self.parse_getter_setter_signature_member(span, TSMethodSignatureKind::Set);
         }
 
-        self.parse_property_or_method_signature(span, &modifiers)
+        let mut signature = self.parse_property_or_method_signature(span, &modifiers);
+        if flow_readonly && let TSSignature::TSPropertySignature(property) = &mut signature {
+            property.readonly = true;
+        }
+        signature
     }
 
     pub(crate) fn is_index_signature(&mut self) -> bool {
@@ -525,7 +537,9 @@ impl<'a, C: Config> ParserImpl<'a, C> {
     ) -> Statement<'a> {
         let reserved_ctx = self.ctx;
         let modifiers = self.eat_modifiers_before_declaration();
+        // Flow requires `declare` inside `declare module`
         if let Some(modifier) = modifiers.get(ModifierKind::Declare)
+            && !self.is_flow
             && reserved_ctx.has_ambient()
             && !reserved_ctx.has_top_level()
         {
@@ -612,16 +626,26 @@ impl<'a, C: Config> ParserImpl<'a, C> {
                 }
                 self.parse_ts_import_equals_declaration(import_kind, identifier, start_span)
             }
-            Kind::Module | Kind::Namespace if self.is_ts => {
+            Kind::Module | Kind::Namespace if self.source_type.is_typescript() => {
                 let decl = self.parse_ts_module_declaration(start_span, modifiers);
                 Declaration::TSModuleDeclaration(decl)
             }
-            Kind::Global if self.is_ts => {
+            // Flow only has `declare module`
+            Kind::Module if self.is_flow && modifiers.contains_declare() => {
+                let decl = self.parse_ts_module_declaration(start_span, modifiers);
+                Declaration::TSModuleDeclaration(decl)
+            }
+            Kind::Global if self.source_type.is_typescript() => {
                 let decl = self.parse_ts_global_declaration(start_span, modifiers);
                 Declaration::TSGlobalDeclaration(decl)
             }
             Kind::Type if self.is_ts => self.parse_ts_type_alias_declaration(start_span, modifiers),
-            Kind::Enum if self.is_ts => self.parse_ts_enum_declaration(start_span, modifiers),
+            Kind::Ident if self.is_flow && self.at_flow_opaque_type() => {
+                self.parse_flow_opaque_type_declaration(start_span, modifiers)
+            }
+            Kind::Enum if self.source_type.is_typescript() => {
+                self.parse_ts_enum_declaration(start_span, modifiers)
+            }
             Kind::Interface if self.is_ts => {
                 self.bump_any();
                 self.parse_ts_interface_declaration(start_span, modifiers)
@@ -712,7 +736,7 @@ impl<'a, C: Config> ParserImpl<'a, C> {
 
         let span = self.end_span(span);
 
-        if !self.is_ts {
+        if !self.source_type.is_typescript() {
             self.error(diagnostics::import_equals_can_only_be_used_in_typescript_files(span));
         }
         // `import type Foo = Bar.Baz` is not allowed; `import type Foo = require('./foo')` is.
@@ -866,6 +890,10 @@ impl<'a, C: Config> ParserImpl<'a, C> {
                 Kind::Static => {
                     self.bump_any();
                 }
+                // `declare opaque type`
+                Kind::Ident if self.is_flow => {
+                    return self.at_flow_opaque_type();
+                }
                 _ => {
                     return false;
                 }
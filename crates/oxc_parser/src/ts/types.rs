@@ -13,7 +13,8 @@ use super::{super::js::FunctionKind, statement::CallOrConstructorSignature};
 
 impl<'a, C: Config> ParserImpl<'a, C> {
     pub(crate) fn parse_ts_type(&mut self) -> TSType<'a> {
-        if self.is_start_of_function_type_or_constructor_type() {
+        // Flow function types are parsed by `parse_type_operator_or_higher`
+        if !self.is_flow && self.is_start_of_function_type_or_constructor_type() {
             return self.parse_function_or_constructor_type();
         }
         let span = self.start_span();
@@ -215,6 +216,10 @@ impl<'a, C: Config> ParserImpl<'a, C> {
     fn parse_ts_type_parameter(&mut self, allow_variance: bool) -> TSTypeParameter<'a> {
         let span = self.start_span();
 
+        if self.is_flow && matches!(self.cur_kind(), Kind::Plus | Kind::Minus) {
+            return self.parse_flow_type_parameter(span, allow_variance);
+        }
+
         let modifiers = self.parse_modifiers(true, false);
         let allowed_modifiers = if allow_variance {
             ModifierKinds::new([ModifierKind::In, ModifierKind::Out, ModifierKind::Const])
@@ -253,6 +258,34 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         )
     }
 
+    /// Flow type parameter with a variance sigil, e.g. `+T` (covariant) or `-T: Bound`
+    /// (contravariant). The sigils are represented by TypeScript's `out` and `in` modifiers.
+    fn parse_flow_type_parameter(
+        &mut self,
+        span: u32,
+        allow_variance: bool,
+    ) -> TSTypeParameter<'a> {
+        let variance_span = self.cur_token().span();
+        let covariant = self.eat(Kind::Plus);
+        let contravariant = !covariant && self.eat(Kind::Minus);
+        if !allow_variance {
+            self.error(diagnostics::unexpected_flow_variance(variance_span));
+        }
+        let name = self.parse_binding_identifier();
+        let constraint = self.parse_ts_type_constraint();
+        let default = self.parse_ts_default_type();
+        TSTypeParameter::new(
+            self.end_span(span),
+            name,
+            constraint,
+            default,
+            contravariant,
+            covariant,
+            false,
+            self,
+        )
+    }
+
     fn parse_intersection_type_or_higher(&mut self) -> TSType<'a> {
         self.parse_union_type_or_intersection_type(Kind::Amp, Self::parse_type_operator_or_higher)
     }
@@ -276,9 +309,10 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         let has_leading_operator = self.eat(kind);
         /* hasLeadingOperator && parseFunctionOrConstructorTypeToError(isUnionType) ||*/
         let mut ty = parse_constituent_type(self);
-        if self.at(kind) || has_leading_operator {
+        if (self.at(kind) && !self.at_flow_exact_object_type_end()) || has_leading_operator {
             let mut types = ArenaVec::from_value_in(ty, self);
-            while self.eat(kind) {
+            while self.at(kind) && !self.at_flow_exact_object_type_end() {
+                self.bump_any();
                 types.push(
                     /*parseFunctionOrConstructorTypeToError(isUnionType) || */
                     parse_constituent_type(self),
@@ -297,9 +331,14 @@ impl<'a, C: Config> ParserImpl<'a, C> {
     fn parse_type_operator_or_higher(&mut self) -> TSType<'a> {
         match self.cur_kind() {
             Kind::KeyOf => self.parse_type_operator(TSTypeOperatorOperator::Keyof),
-            Kind::Unique => self.parse_type_operator(TSTypeOperatorOperator::Unique),
-            Kind::Readonly => self.parse_type_operator(TSTypeOperatorOperator::Readonly),
+            Kind::Unique if self.source_type.is_typescript() => {
+                self.parse_type_operator(TSTypeOperatorOperator::Unique)
+            }
+            Kind::Readonly if self.source_type.is_typescript() => {
+                self.parse_type_operator(TSTypeOperatorOperator::Readonly)
+            }
             Kind::Infer => self.parse_infer_type(),
+            _ if self.is_flow => self.parse_flow_function_type_or_higher(),
             _ => self.context_remove(
                 Context::DisallowConditionalTypes,
                 Self::parse_postfix_type_or_higher,
@@ -370,7 +409,7 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         }
     }
 
-    fn parse_postfix_type_or_higher(&mut self) -> TSType<'a> {
+    pub(crate) fn parse_postfix_type_or_higher(&mut self) -> TSType<'a> {
         let span = self.start_span();
         let mut ty = self.parse_non_array_type();
 
@@ -461,7 +500,7 @@ impl<'a, C: Config> ParserImpl<'a, C> {
             Kind::Bang => self.parse_js_doc_non_nullable_type(),
             Kind::Str | Kind::True | Kind::False => self.parse_literal_type(),
             kind if kind.is_number() => self.parse_literal_type(),
-            Kind::NoSubstitutionTemplate => {
+            Kind::NoSubstitutionTemplate if self.source_type.is_typescript() => {
                 let span = self.start_span();
                 let literal = self.parse_template_literal(false);
                 let span = self.end_span(span);
@@ -504,7 +543,7 @@ impl<'a, C: Config> ParserImpl<'a, C> {
             Kind::LBrack => self.parse_tuple_type(),
             Kind::LParen => self.parse_parenthesized_type(),
             Kind::Import => TSType::TSImportType(self.parse_ts_import_type()),
-            Kind::Asserts => {
+            Kind::Asserts if self.source_type.is_typescript() => {
                 // Peek the token after `asserts` to check if this is an asserts type predicate.
                 let next = self.lexer.peek_token();
                 if next.kind().is_identifier_name() && !next.is_on_new_line() {
@@ -515,7 +554,15 @@ impl<'a, C: Config> ParserImpl<'a, C> {
                     self.parse_type_reference()
                 }
             }
-            Kind::TemplateHead => self.parse_template_type(false),
+            Kind::TemplateHead if self.source_type.is_typescript() => {
+                self.parse_template_type(false)
+            }
+            // Flow existential type `*`, which is deprecated and equivalent to `any`
+            Kind::Star if self.is_flow => {
+                let span = self.start_span();
+                self.bump_any();
+                TSType::new_ts_any_keyword(self.end_span(span), self)
+            }
             _ => self.parse_type_reference(),
         }
     }
@@ -710,6 +757,9 @@ impl<'a, C: Config> ParserImpl<'a, C> {
     }
 
     fn parse_type_literal(&mut self) -> TSType<'a> {
+        if self.is_flow && matches!(self.lexer.peek_token().kind(), Kind::Pipe | Kind::Pipe2) {
+            return self.parse_flow_exact_object_type();
+        }
         let span = self.start_span();
         let member_list =
             self.parse_normal_list(Kind::LCurly, Kind::RCurly, Self::parse_ts_type_signature);
@@ -759,7 +809,8 @@ impl<'a, C: Config> ParserImpl<'a, C> {
     }
 
     fn parse_ts_type_constraint(&mut self) -> Option<TSType<'a>> {
-        if !self.at(Kind::Extends) {
+        // Flow bounds are written `T: Bound` instead of `T extends Bound`
+        if !(self.at(Kind::Extends) || (self.is_flow && self.at(Kind::Colon))) {
             return None;
         }
         self.bump_any();
@@ -1341,7 +1392,7 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         self.context_remove(Context::DisallowConditionalTypes, Self::parse_type_or_type_predicate)
     }
 
-    fn parse_type_or_type_predicate(&mut self) -> TSType<'a> {
+    pub(crate) fn parse_type_or_type_predicate(&mut self) -> TSType<'a> {
         let span = self.start_span();
         let type_predicate_variable = self.parse_type_predicate_prefix();
 
@@ -1578,7 +1629,7 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         )
     }
 
-    fn parse_type_member_semicolon(&mut self) {
+    pub(crate) fn parse_type_member_semicolon(&mut self) {
         // We allow type members to be separated by commas or (possibly ASI) semicolons.
         // First check if it was a comma.  If so, we're done with the member.
         if self.eat(Kind::Comma) {
@@ -1612,7 +1663,9 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         ) {
             return TSType::new_js_doc_unknown_type(self.end_span(span), self);
         }
-        let type_annotation = self.parse_ts_type();
+        // Flow `?T` binds tighter than unions, e.g. `?string | number` is `(?string) | number`
+        let type_annotation =
+            if self.is_flow { self.parse_flow_prefix_type() } else { self.parse_ts_type() };
         TSType::new_js_doc_nullable_type(
             self.end_span(span),
             type_annotation,
@@ -757,6 +757,7 @@ fn calls_hooks_or_creates_jsx_in_expr(expr: &oxc::Expression) -> bool {
             oxc::ObjectPropertyKind::SpreadProperty(s) => {
                 calls_hooks_or_creates_jsx_in_expr(&s.argument)
             }
+            oxc::ObjectPropertyKind::ErrorNode(_) => false,
             // Object methods (`{ foo() {} }`, getters/setters): Babel modeled these
             // as `ObjectMethod` and traversed their body statements. Regular
             // properties traverse their value (nested functions are skipped).
@@ -2213,6 +2214,7 @@ impl<'a, 'ast> DiscoveryWalker<'a, 'ast> {
     fn walk_object_property(&mut self, prop: &'ast oxc::ObjectPropertyKind<'ast>) {
         match prop {
             oxc::ObjectPropertyKind::SpreadProperty(s) => self.walk_expression(&s.argument),
+            oxc::ObjectPropertyKind::ErrorNode(_) => {}
             oxc::ObjectPropertyKind::ObjectProperty(p) => {
                 if p.computed {
                     self.walk_property_key(&p.key);
@@ -4022,6 +4022,7 @@ fn collect_identifier_node_ids_from_expr(expr: &oxc::Expression, positions: &mut
                     oxc::ObjectPropertyKind::SpreadProperty(s) => {
                         collect_identifier_node_ids_from_expr(&s.argument, positions);
                     }
+                    oxc::ObjectPropertyKind::ErrorNode(_) => {}
                 }
             }
         }
@@ -4795,6 +4796,14 @@ fn lower_expression<'a>(
         oxc::Expression::TSTypeAssertion(ts) => {
             lower_type_cast_expression(builder, ts.span, &ts.expression, &ts.type_annotation, "as")
         }
+        // Flow `(x: T)` is Babel's `TypeCastExpression` with the `cast` annotation kind.
+        oxc::Expression::FlowTypeCastExpression(cast) => lower_type_cast_expression(
+            builder,
+            cast.span,
+            &cast.expression,
+            &cast.type_annotation.type_annotation,
+            "cast",
+        ),
         // `x!` and `x<T>` unwrap to their inner expression (the original also just
         // unwraps these).
         oxc::Expression::TSNonNullExpression(ts) => lower_expression(builder, &ts.expression),
@@ -4845,6 +4854,11 @@ fn lower_expression<'a>(
                         let place = lower_expression_to_temporary(builder, &spread.argument)?;
                         properties.push(ObjectPropertyOrSpread::Spread(SpreadPattern { place }));
                     }
+                    oxc::ObjectPropertyKind::ErrorNode(_) => {
+                        unreachable!(
+                            "ErrorNode: oxc does not emit this without ParseOptions::error_recovery"
+                        )
+                    }
                 }
             }
             Ok(InstructionValue::ObjectExpression { properties, loc })
@@ -6073,6 +6087,10 @@ fn expression_type_name(expr: &oxc::Expression) -> &'static str {
         oxc::Expression::TSTypeAssertion(_) => "TSTypeAssertion",
         oxc::Expression::TSInstantiationExpression(_) => "TSInstantiationExpression",
         oxc::Expression::V8IntrinsicExpression(_) => "V8IntrinsicExpression",
+        oxc::Expression::FlowTypeCastExpression(_) => "TypeCastExpression",
+        // Babel represents a Hack-style pipe as a `BinaryExpression` with the `|>` operator.
+        oxc::Expression::PipelineExpression(_) => "BinaryExpression",
+        oxc::Expression::TopicReference(_) => "TopicReference",
     }
 }
 
@@ -6344,6 +6362,9 @@ fn is_reorderable_expression(
         oxc::Expression::TSTypeAssertion(ts) => {
             is_reorderable_expression(builder, &ts.expression, allow_local_identifiers)
         }
+        oxc::Expression::FlowTypeCastExpression(cast) => {
+            is_reorderable_expression(builder, &cast.expression, allow_local_identifiers)
+        }
         oxc::Expression::ParenthesizedExpression(p) => {
             is_reorderable_expression(builder, &p.expression, allow_local_identifiers)
         }
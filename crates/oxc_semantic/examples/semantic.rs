@@ -8,13 +8,14 @@
 //!
 //! Create a `test.js` file and run:
 //! ```bash
-//! cargo run -p oxc_semantic --example semantic [filename] [--symbols] [--symbol-references]
+//! cargo run -p oxc_semantic --example semantic [filename] [--symbols] [--symbol-references] [--call-graph]
 //! ```
 //!
 //! ## Options
 //!
 //! - `--symbols`: Display symbol table and reference information
 //! - `--symbol-references`: Display detailed reference information for each symbol
+//! - `--call-graph`: Display the call graph in the DOT format
 
 use std::{env, path::Path, sync::Arc};
 
@@ -36,6 +37,7 @@ fn main() -> std::io::Result<()> {
     let name = env::args().nth(1).unwrap_or_else(|| "test.js".to_string());
     let show_symbols = env::args().skip(1).any(|arg| arg == "--symbols");
     let show_symbol_references = env::args().skip(1).any(|arg| arg == "--symbol-references");
+    let show_call_graph = env::args().skip(1).any(|arg| arg == "--call-graph");
     let path = Path::new(&name);
     let source_text = Arc::new(std::fs::read_to_string(path)?);
     let source_type = SourceType::from_path(path).unwrap();
@@ -119,5 +121,9 @@ fn main() -> std::io::Result<()> {
         }
     }
 
+    if show_call_graph {
+        println!("{}", semantic.semantic.call_graph().display_dot(&semantic.semantic));
+    }
+
     Ok(())
 }
@@ -383,6 +383,23 @@ impl<'a> Binder<'a> for TSImportEqualsDeclaration<'a> {
     }
 }
 
+impl<'a> Binder<'a> for FlowOpaqueTypeDeclaration<'a> {
+    fn bind(&self, builder: &mut SemanticBuilder<'a>) {
+        let includes = if self.declare {
+            SymbolFlags::TypeAlias | SymbolFlags::Ambient
+        } else {
+            SymbolFlags::TypeAlias
+        };
+        let symbol_id = builder.declare_symbol(
+            self.id.span,
+            self.id.name,
+            includes,
+            SymbolFlags::TypeAliasExcludes,
+        );
+        self.id.symbol_id.set(Some(symbol_id));
+    }
+}
+
 impl<'a> Binder<'a> for TSTypeAliasDeclaration<'a> {
     fn bind(&self, builder: &mut SemanticBuilder<'a>) {
         let includes = if self.declare {
@@ -553,6 +570,7 @@ fn get_module_instance_state_for_statement<'a, 'b>(
             // 1. interface declarations, type alias declarations
             Statement::TSInterfaceDeclaration(_)
             | Statement::TSTypeAliasDeclaration(_)
+            | Statement::FlowOpaqueTypeDeclaration(_)
             // 3. non-exported import declarations
             | Statement::TSImportEqualsDeclaration(_) => {
                 ModuleInstanceState::NonInstantiated
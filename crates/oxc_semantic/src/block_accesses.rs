@@ -0,0 +1,125 @@
+use oxc_ast::AstKind;
+use oxc_cfg::{
+    BlockNodeId,
+    dataflow::{Access, AccessKind, BlockAccesses},
+};
+use oxc_span::GetSpan;
+use oxc_syntax::{
+    node::NodeId,
+    symbol::{SymbolFlags, SymbolId},
+};
+
+use crate::Semantic;
+
+impl Semantic<'_> {
+    /// Reads and writes of value symbols in each basic block of the control flow graph, for use
+    /// with the analyses in [`oxc_cfg::dataflow`].
+    ///
+    /// Besides resolved references, writes are recorded for:
+    /// * variable declarators with an initializer, and the left side of `for...in`/`for...of`
+    /// * parameters and catch parameters, on entry to their function or `catch` clause
+    /// * class declarations, after the class body
+    /// * function declarations and imports, on entry to the scope they are hoisted to
+    ///
+    /// Returns `None` if the control flow graph was not built.
+    pub fn block_accesses(&self) -> Option<BlockAccesses> {
+        self.cfg.as_ref()?;
+
+        // Accesses are ordered by the position at which they take effect, so that the write in
+        // `x = x + 1` comes after the read.
+        let mut accesses: Vec<(BlockNodeId, u32, Access)> = vec![];
+        for symbol_id in self.scoping.symbol_ids() {
+            let flags = self.scoping.symbol_flags(symbol_id);
+            if flags.contains(SymbolFlags::Ambient)
+                || !flags.intersects(
+                    SymbolFlags::Variable
+                        | SymbolFlags::Class
+                        | SymbolFlags::Function
+                        | SymbolFlags::CatchVariable
+                        | SymbolFlags::Import,
+                )
+            {
+                continue;
+            }
+
+            for node_id in self.scoping.symbol_declarations(symbol_id) {
+                if let Some((block, position)) = self.declaration_write(symbol_id, node_id) {
+                    let access = Access { symbol_id, node_id, kind: AccessKind::Write };
+                    accesses.push((block, position, access));
+                }
+            }
+
+            for &reference_id in self.scoping.get_resolved_reference_ids(symbol_id) {
+                let reference = self.scoping.get_reference(reference_id);
+                let node_id = reference.node_id();
+                let block = self.nodes.cfg_id(node_id);
+                if reference.is_read() {
+                    let position = self.nodes.kind(node_id).span().start;
+                    let access = Access { symbol_id, node_id, kind: AccessKind::Read };
+                    accesses.push((block, position, access));
+                }
+                if reference.is_write() {
+                    let position = self.reference_write_position(node_id);
+                    let access = Access { symbol_id, node_id, kind: AccessKind::Write };
+                    accesses.push((block, position, access));
+                }
+            }
+        }
+
+        accesses.sort_by_key(|(block, position, access)| (*block, *position, access.is_write()));
+        let mut block_accesses = BlockAccesses::default();
+        for (block, _, access) in accesses {
+            block_accesses.push(block, access);
+        }
+        Some(block_accesses)
+    }
+
+    /// The block and position at which the declaration `node_id` of `symbol_id` writes it, if it
+    /// does.
+    fn declaration_write(
+        &self,
+        symbol_id: SymbolId,
+        node_id: NodeId,
+    ) -> Option<(BlockNodeId, u32)> {
+        let block = self.nodes.cfg_id(node_id);
+        match self.nodes.kind(node_id) {
+            AstKind::VariableDeclarator(declarator) => {
+                let is_for_in_or_of_left = matches!(
+                    self.nodes.ancestor_kinds(node_id).nth(1),
+                    Some(AstKind::ForInStatement(_) | AstKind::ForOfStatement(_))
+                );
+                (declarator.init.is_some() || is_for_in_or_of_left)
+                    .then_some((block, declarator.span.end))
+            }
+            AstKind::FormalParameter(param) => Some((block, param.span.start)),
+            AstKind::CatchParameter(param) => Some((block, param.span.start)),
+            AstKind::Class(class) => Some((block, class.span.end)),
+            AstKind::Function(_)
+            | AstKind::ImportSpecifier(_)
+            | AstKind::ImportDefaultSpecifier(_)
+            | AstKind::ImportNamespaceSpecifier(_)
+            | AstKind::TSImportEqualsDeclaration(_) => {
+                let scope_node_id =
+                    self.scoping.get_node_id(self.scoping.symbol_scope_id(symbol_id));
+                let position = self.nodes.kind(scope_node_id).span().start;
+                Some((self.nodes.cfg_id(scope_node_id), position))
+            }
+            _ => None,
+        }
+    }
+
+    /// Writes through a reference take effect once the value being assigned has been evaluated.
+    fn reference_write_position(&self, node_id: NodeId) -> u32 {
+        for kind in self.nodes.ancestor_kinds(node_id) {
+            match kind {
+                AstKind::AssignmentExpression(expr) => return expr.span.end,
+                AstKind::UpdateExpression(expr) => return expr.span.end,
+                AstKind::ForInStatement(stmt) => return stmt.left.span().end,
+                AstKind::ForOfStatement(stmt) => return stmt.left.span().end,
+                _ if kind.is_statement() || kind.is_function_like() => break,
+                _ => {}
+            }
+        }
+        self.nodes.kind(node_id).span().end
+    }
+}
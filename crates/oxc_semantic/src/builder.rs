@@ -2577,6 +2577,26 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         self.leave_node(kind);
     }
 
+    fn visit_flow_opaque_type_declaration(&mut self, decl: &FlowOpaqueTypeDeclaration<'a>) {
+        let kind = AstKind::FlowOpaqueTypeDeclaration(self.alloc(decl));
+        self.enter_node(kind);
+        decl.bind(self);
+        self.visit_span(&decl.span);
+        self.visit_binding_identifier(&decl.id);
+        self.enter_scope(ScopeFlags::empty(), &decl.scope_id);
+        if let Some(type_parameters) = &decl.type_parameters {
+            self.visit_ts_type_parameter_declaration(type_parameters);
+        }
+        if let Some(supertype) = &decl.supertype {
+            self.visit_ts_type(supertype);
+        }
+        if let Some(impltype) = &decl.impltype {
+            self.visit_ts_type(impltype);
+        }
+        self.leave_scope();
+        self.leave_node(kind);
+    }
+
     fn visit_ts_interface_declaration(&mut self, decl: &TSInterfaceDeclaration<'a>) {
         let kind = AstKind::TSInterfaceDeclaration(self.alloc(decl));
         self.enter_node(kind);
@@ -0,0 +1,209 @@
+use std::{borrow::Cow, fmt::Write};
+
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+
+use oxc_ast::{
+    AstKind,
+    ast::{BindingPattern, Expression},
+};
+use oxc_syntax::{
+    node::NodeId,
+    symbol::{SymbolFlags, SymbolId},
+};
+
+use crate::{AstNode, Semantic};
+
+/// Calls between the functions of a program, built by [`Semantic::call_graph`].
+///
+/// Functions are [`Function`] and [`ArrowFunctionExpression`] nodes. Calls at the top level of
+/// the program are made from [`NodeId::ROOT`].
+///
+/// [`Function`]: oxc_ast::ast::Function
+/// [`ArrowFunctionExpression`]: oxc_ast::ast::ArrowFunctionExpression
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct CallGraph {
+    functions: Vec<NodeId>,
+    calls: Vec<Call>,
+}
+
+/// A call, `new` or tagged template expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct Call {
+    /// The function containing the call, or [`NodeId::ROOT`] at the top level.
+    pub caller: NodeId,
+    /// The call expression.
+    pub node_id: NodeId,
+    pub callee: Callee,
+}
+
+/// The function a [`Call`] invokes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize), serde(tag = "kind", content = "id"))]
+pub enum Callee {
+    /// A function in this program.
+    Function(NodeId),
+    /// An import binding, which may be resolved to a function in another module.
+    Import(SymbolId),
+    /// A callee which cannot be resolved statically, e.g. `obj.method()`, `fns[0]()` or a global.
+    Unknown,
+}
+
+impl CallGraph {
+    /// All functions, in source order.
+    pub fn functions(&self) -> &[NodeId] {
+        &self.functions
+    }
+
+    /// All calls, in source order.
+    pub fn calls(&self) -> &[Call] {
+        &self.calls
+    }
+
+    /// Calls made by `caller`, excluding calls in nested functions.
+    pub fn calls_from(&self, caller: NodeId) -> impl Iterator<Item = &Call> + '_ {
+        self.calls.iter().filter(move |call| call.caller == caller)
+    }
+
+    /// Calls which invoke `function`.
+    pub fn calls_to(&self, function: NodeId) -> impl Iterator<Item = &Call> + '_ {
+        self.calls.iter().filter(move |call| call.callee == Callee::Function(function))
+    }
+
+    /// Render the call graph in the DOT format, labelling functions with their names.
+    pub fn display_dot(&self, semantic: &Semantic) -> String {
+        let mut dot = String::from("digraph {\n");
+        let _ = writeln!(dot, "    {} [ label = \"<program>\" ]", NodeId::ROOT.index());
+        for &function in &self.functions {
+            let name = semantic.function_name(function).unwrap_or(Cow::Borrowed("<anonymous>"));
+            let _ = writeln!(dot, "    {} [ label = \"{name}\" ]", function.index());
+        }
+        for call in &self.calls {
+            let caller = call.caller.index();
+            let _ = match call.callee {
+                Callee::Function(function) => {
+                    writeln!(dot, "    {caller} -> {}", function.index())
+                }
+                Callee::Import(symbol_id) => {
+                    let name = semantic.scoping.symbol_name(symbol_id);
+                    writeln!(dot, "    {caller} -> \"import {name}\"")
+                }
+                Callee::Unknown => {
+                    writeln!(dot, "    {caller} -> \"<unknown>\" [ style = dotted ]")
+                }
+            };
+        }
+        dot.push('}');
+        dot
+    }
+}
+
+impl<'a> Semantic<'a> {
+    /// Calls between the functions of this program.
+    ///
+    /// A callee is resolved when it is a function expression, or an identifier bound to a function
+    /// declaration, an import, or a variable initialized with a function and never reassigned.
+    /// All other calls have an [`Callee::Unknown`] callee.
+    ///
+    /// Requires the AST nodes to be built (see [`SemanticBuilder::with_build_nodes`]).
+    ///
+    /// [`SemanticBuilder::with_build_nodes`]: crate::SemanticBuilder::with_build_nodes
+    pub fn call_graph(&self) -> CallGraph {
+        let mut graph = CallGraph::default();
+        for node in self.nodes.iter() {
+            let callee = match node.kind() {
+                AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+                    graph.functions.push(node.id());
+                    continue;
+                }
+                AstKind::CallExpression(call) => &call.callee,
+                AstKind::NewExpression(new) => &new.callee,
+                AstKind::TaggedTemplateExpression(tagged) => &tagged.tag,
+                _ => continue,
+            };
+            let caller = self
+                .nodes
+                .ancestors(node.id())
+                .find(|ancestor| {
+                    matches!(
+                        ancestor.kind(),
+                        AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                    )
+                })
+                .map_or(NodeId::ROOT, AstNode::id);
+            graph.calls.push(Call {
+                caller,
+                node_id: node.id(),
+                callee: self.resolve_callee(callee),
+            });
+        }
+        graph
+    }
+
+    fn resolve_callee(&self, callee: &Expression<'a>) -> Callee {
+        match callee.get_inner_expression() {
+            Expression::FunctionExpression(function) => Callee::Function(function.node_id()),
+            Expression::ArrowFunctionExpression(arrow) => Callee::Function(arrow.node_id()),
+            Expression::Identifier(ident) => self
+                .scoping
+                .get_reference(ident.reference_id())
+                .symbol_id()
+                .map_or(Callee::Unknown, |symbol_id| self.resolve_callee_symbol(symbol_id)),
+            _ => Callee::Unknown,
+        }
+    }
+
+    fn resolve_callee_symbol(&self, symbol_id: SymbolId) -> Callee {
+        if self.scoping.symbol_flags(symbol_id).contains(SymbolFlags::Import) {
+            return Callee::Import(symbol_id);
+        }
+        for declaration in self.scoping.symbol_declarations(symbol_id) {
+            match self.nodes.kind(declaration) {
+                // Skips overload signatures
+                AstKind::Function(function) if function.body.is_some() => {
+                    return Callee::Function(function.node_id());
+                }
+                AstKind::VariableDeclarator(declarator)
+                    if declarator
+                        .id
+                        .get_binding_identifier()
+                        .is_some_and(|id| id.symbol_id.get() == Some(symbol_id))
+                        && !self.scoping.symbol_is_mutated(symbol_id) =>
+                {
+                    return match declarator.init.as_ref().map(Expression::get_inner_expression) {
+                        Some(Expression::FunctionExpression(function)) => {
+                            Callee::Function(function.node_id())
+                        }
+                        Some(Expression::ArrowFunctionExpression(arrow)) => {
+                            Callee::Function(arrow.node_id())
+                        }
+                        _ => Callee::Unknown,
+                    };
+                }
+                _ => {}
+            }
+        }
+        Callee::Unknown
+    }
+
+    /// The name of a function, or of the variable, property or method it is assigned to.
+    fn function_name(&self, function: NodeId) -> Option<Cow<'a, str>> {
+        if let AstKind::Function(function) = self.nodes.kind(function)
+            && let Some(id) = &function.id
+        {
+            return Some(Cow::Borrowed(id.name.as_str()));
+        }
+        match self.nodes.parent_kind(function) {
+            AstKind::VariableDeclarator(declarator) => match &declarator.id {
+                BindingPattern::BindingIdentifier(id) => Some(Cow::Borrowed(id.name.as_str())),
+                _ => None,
+            },
+            AstKind::MethodDefinition(method) => method.key.static_name(),
+            AstKind::ObjectProperty(property) => property.key.static_name(),
+            AstKind::PropertyDefinition(property) => property.key.static_name(),
+            _ => None,
+        }
+    }
+}
@@ -454,11 +454,11 @@ pub fn check_string_literal(lit: &StringLiteral, ctx: &SemanticBuilder<'_>) {
                     b'0' => {
                         let following_byte = raw_bytes.get(backslash_index + 2);
                         if following_byte.is_some_and(u8::is_ascii_digit) {
-                            return ctx.error(diagnostics::legacy_octal(lit.span));
+                            return ctx.error(diagnostics::legacy_octal_escape_sequence(lit.span));
                         }
                     }
                     b'1'..=b'7' => {
-                        return ctx.error(diagnostics::legacy_octal(lit.span));
+                        return ctx.error(diagnostics::legacy_octal_escape_sequence(lit.span));
                     }
                     b'8'..=b'9' => {
                         return ctx.error(diagnostics::non_octal_decimal_escape_sequence(lit.span));
@@ -472,10 +472,10 @@ pub fn check_string_literal(lit: &StringLiteral, ctx: &SemanticBuilder<'_>) {
                 if b == b'\\' {
                     match bytes.next() {
                         Some(b'0') if bytes.peek().is_some_and(u8::is_ascii_digit) => {
-                            return ctx.error(diagnostics::legacy_octal(lit.span));
+                            return ctx.error(diagnostics::legacy_octal_escape_sequence(lit.span));
                         }
                         Some(b'1'..=b'7') => {
-                            return ctx.error(diagnostics::legacy_octal(lit.span));
+                            return ctx.error(diagnostics::legacy_octal_escape_sequence(lit.span));
                         }
                         Some(b'8'..=b'9') => {
                             return ctx
@@ -22,6 +22,8 @@ pub fn check<'a>(kind: AstKind<'a>, ctx: &SemanticBuilder<'a>) {
             js::check_unresolved_exports(program, ctx);
             js::check_import_value_redeclarations(ctx);
             ts::check_ts_export_assignment_in_program(program, ctx);
+            ts::check_dts_top_level_declarations(program, ctx);
+            ts::check_function_overloads(&program.body, ctx);
         }
         AstKind::BindingIdentifier(ident) => {
             js::check_identifier(&ident.name, ident.span, ident.symbol_id.get(), ctx);
@@ -88,13 +90,19 @@ pub fn check<'a>(kind: AstKind<'a>, ctx: &SemanticBuilder<'a>) {
             ts::check_method_definition(method, ctx);
         }
         AstKind::Super(sup) => js::check_super(sup, ctx),
+        AstKind::BlockStatement(block) => ts::check_function_overloads(&block.body, ctx),
+        AstKind::FunctionBody(body) => ts::check_function_overloads(&body.statements, ctx),
+        AstKind::StaticBlock(block) => ts::check_function_overloads(&block.body, ctx),
 
         AstKind::FormalParameters(params) => {
             ts::check_formal_parameters(params, ctx);
         }
 
         AstKind::AwaitExpression(expr) => js::check_await_expression(expr, ctx),
-        AstKind::ObjectExpression(expr) => js::check_object_expression(expr, ctx),
+        AstKind::ObjectExpression(expr) => {
+            js::check_object_expression(expr, ctx);
+            ts::check_object_expression(expr, ctx);
+        }
         AstKind::UnaryExpression(expr) => js::check_unary_expression(expr, ctx),
         AstKind::YieldExpression(expr) => js::check_yield_expression(expr, ctx),
         AstKind::VariableDeclaration(decl) => {
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use itertools::Itertools;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use oxc_ast::{AstKind, ast::*};
 use oxc_ecmascript::BoundNames;
@@ -88,6 +88,15 @@ pub fn check_formal_parameters(params: &FormalParameters, ctx: &SemanticBuilder<
         check_duplicate_bound_names(params, ctx);
     }
 
+    // `function f(a = 1): void;`
+    if params.items.iter().any(|param| param.initializer.is_some()) && !is_implementation(ctx) {
+        for param in &params.items {
+            if param.initializer.is_some() {
+                ctx.error(diagnostics::parameter_initializer_in_signature(param.span));
+            }
+        }
+    }
+
     let mut has_optional = false;
 
     for param in &params.items {
@@ -100,6 +109,15 @@ pub fn check_formal_parameters(params: &FormalParameters, ctx: &SemanticBuilder<
     }
 }
 
+/// Whether the formal parameters being checked belong to a function with a body.
+fn is_implementation(ctx: &SemanticBuilder<'_>) -> bool {
+    match ctx.ancestry().ancestor_kinds().next() {
+        Some(AstKind::Function(func)) => func.body.is_some(),
+        Some(AstKind::ArrowFunctionExpression(_)) => true,
+        _ => false,
+    }
+}
+
 fn check_duplicate_bound_names<'a, T: BoundNames<'a>>(bound_names: &T, ctx: &SemanticBuilder<'_>) {
     let mut idents: FxHashMap<Str<'a>, Span> = FxHashMap::default();
     bound_names.bound_names(&mut |ident| {
@@ -147,6 +165,17 @@ fn check_ts_module_or_global_declaration(span: Span, ctx: &SemanticBuilder<'_>)
 pub fn check_ts_enum_declaration<'a>(decl: &TSEnumDeclaration<'a>, ctx: &SemanticBuilder<'a>) {
     let mut need_initializer = false;
 
+    let mut members = FxHashMap::default();
+    for (index, member) in decl.body.members.iter().enumerate() {
+        members.entry(member.id.static_name().as_str()).or_insert(index);
+    }
+    for (index, member) in decl.body.members.iter().enumerate() {
+        if let Some(initializer) = &member.initializer {
+            // `enum E { A = B, B = 1 }`
+            check_enum_member_forward_reference(initializer, &decl.id.name, &members, index, ctx);
+        }
+    }
+
     decl.body.members.iter().for_each(|member| {
         #[expect(clippy::unnested_or_patterns)]
         if let Some(initializer) = &member.initializer {
@@ -169,6 +198,64 @@ pub fn check_ts_enum_declaration<'a>(decl: &TSEnumDeclaration<'a>, ctx: &Semanti
     });
 }
 
+/// A member initializer may only reference members declared before it.
+fn check_enum_member_forward_reference(
+    expr: &Expression<'_>,
+    enum_name: &str,
+    members: &FxHashMap<&str, usize>,
+    index: usize,
+    ctx: &SemanticBuilder<'_>,
+) {
+    let is_enum = |object: &Expression| matches!(object, Expression::Identifier(ident) if ident.name == enum_name);
+    let referenced = match expr {
+        Expression::Identifier(ident) => Some(ident.name.as_str()),
+        Expression::StaticMemberExpression(member) if is_enum(&member.object) => {
+            Some(member.property.name.as_str())
+        }
+        Expression::ComputedMemberExpression(member) if is_enum(&member.object) => {
+            match &member.expression {
+                Expression::StringLiteral(lit) => Some(lit.value.as_str()),
+                _ => None,
+            }
+        }
+        Expression::ParenthesizedExpression(paren) => {
+            return check_enum_member_forward_reference(
+                &paren.expression,
+                enum_name,
+                members,
+                index,
+                ctx,
+            );
+        }
+        Expression::UnaryExpression(unary) => {
+            return check_enum_member_forward_reference(
+                &unary.argument,
+                enum_name,
+                members,
+                index,
+                ctx,
+            );
+        }
+        Expression::BinaryExpression(binary) => {
+            check_enum_member_forward_reference(&binary.left, enum_name, members, index, ctx);
+            check_enum_member_forward_reference(&binary.right, enum_name, members, index, ctx);
+            return;
+        }
+        Expression::TemplateLiteral(template) => {
+            for expr in &template.expressions {
+                check_enum_member_forward_reference(expr, enum_name, members, index, ctx);
+            }
+            return;
+        }
+        _ => None,
+    };
+    if let Some(name) = referenced
+        && members.get(name).is_some_and(|&declared| declared > index)
+    {
+        ctx.error(diagnostics::enum_member_forward_reference(expr.span()));
+    }
+}
+
 pub fn check_class<'a>(class: &Class<'a>, ctx: &SemanticBuilder<'a>) {
     if !class.r#abstract {
         for elem in &class.body.body {
@@ -187,13 +274,23 @@ pub fn check_class<'a>(class: &Class<'a>, ctx: &SemanticBuilder<'a>) {
                 && !a.optional
                 && a.value.r#type == FunctionType::TSEmptyBodyFunctionExpression
             {
-                let next_is_same = b.is_some_and(|b| {
-                    matches!(b,
-                        ClassElement::MethodDefinition(b)
-                            if b.key.static_name() == a.key.static_name()
-                    )
-                });
+                let next = match b {
+                    Some(ClassElement::MethodDefinition(b)) => Some(b),
+                    _ => None,
+                };
+                let next_is_same = next.is_some_and(|b| b.key.static_name() == a.key.static_name());
                 if next_is_same {
+                    // `static foo(): void; foo() {}`
+                    if let Some(b) = next
+                        && a.kind.is_method()
+                        && b.kind.is_method()
+                        && a.r#static != b.r#static
+                    {
+                        ctx.error(diagnostics::function_overload_static_mismatch(
+                            a.r#static,
+                            b.key.span(),
+                        ));
+                    }
                     is_in_overload_group = true;
                 } else if a.key.static_name().is_some() || is_in_overload_group {
                     // Report error for:
@@ -201,6 +298,16 @@ pub fn check_class<'a>(class: &Class<'a>, ctx: &SemanticBuilder<'a>) {
                     // 2. The last overload in a computed-name overload group (e.g. [Symbol.iterator])
                     if a.kind.is_constructor() {
                         ctx.error(diagnostics::constructor_implementation_missing(a.key.span()));
+                    } else if let Some(b) = next
+                        && a.kind.is_method()
+                        && b.kind.is_method()
+                        && b.value.body.is_some()
+                    {
+                        // `foo(): void; bar() {}`
+                        ctx.error(diagnostics::function_implementation_name_mismatch(
+                            &ctx.source_text[a.key.span()],
+                            b.key.span(),
+                        ));
                     } else {
                         ctx.error(diagnostics::function_implementation_missing(a.key.span()));
                     }
@@ -213,6 +320,184 @@ pub fn check_class<'a>(class: &Class<'a>, ctx: &SemanticBuilder<'a>) {
             }
         }
     }
+
+    check_class_method_overload_modifiers(class, ctx);
+    check_class_accessor_pairs(class, ctx);
+}
+
+/// Overloads of a method must agree with the implementation (or the first overload, when there
+/// is no implementation) on accessibility, `abstract` and `?`.
+fn check_class_method_overload_modifiers<'a>(class: &Class<'a>, ctx: &SemanticBuilder<'a>) {
+    let mut overloads: FxHashMap<(bool, Cow<'a, str>), Vec<&MethodDefinition<'a>>> =
+        FxHashMap::default();
+    for elem in &class.body.body {
+        if let ClassElement::MethodDefinition(method) = elem
+            && !method.kind.is_accessor()
+            && let Some(name) = method.key.name()
+        {
+            overloads.entry((method.r#static, name)).or_default().push(method);
+        }
+    }
+
+    let mut overloads = overloads.into_values().collect::<Vec<_>>();
+    overloads.sort_unstable_by_key(|methods| methods[0].span.start);
+    for methods in overloads {
+        if methods.len() < 2 || methods.iter().all(|method| method.value.body.is_some()) {
+            continue;
+        }
+        let canonical =
+            methods.iter().find(|method| method.value.body.is_some()).unwrap_or(&methods[0]);
+        let accessibility = |method: &MethodDefinition| {
+            method.accessibility.filter(|accessibility| *accessibility != TSAccessibility::Public)
+        };
+        for method in &methods {
+            let span = method.key.span();
+            if accessibility(method) != accessibility(canonical) {
+                ctx.error(diagnostics::overload_accessibility_mismatch(span));
+            } else if method.r#type.is_abstract() != canonical.r#type.is_abstract() {
+                ctx.error(diagnostics::overload_abstract_mismatch(span));
+            }
+            if method.optional != canonical.optional {
+                ctx.error(diagnostics::overload_optionality_mismatch(span));
+            }
+        }
+    }
+}
+
+/// A getter and setter pair must agree on `abstract`, and the getter must be at least as
+/// accessible as the setter.
+fn check_class_accessor_pairs<'a>(class: &Class<'a>, ctx: &SemanticBuilder<'a>) {
+    let mut getters: FxHashMap<(bool, bool, Cow<'a, str>), &MethodDefinition<'a>> =
+        FxHashMap::default();
+    let mut setters = vec![];
+    for elem in &class.body.body {
+        let ClassElement::MethodDefinition(method) = elem else { continue };
+        let Some(name) = method.key.name() else { continue };
+        let key = (method.r#static, method.key.is_private_identifier(), name);
+        match method.kind {
+            MethodDefinitionKind::Get => {
+                getters.entry(key).or_insert(method);
+            }
+            MethodDefinitionKind::Set => setters.push((key, method)),
+            _ => {}
+        }
+    }
+
+    let mut seen = FxHashSet::default();
+    for (key, setter) in setters {
+        let Some(getter) = getters.get(&key) else { continue };
+        if !seen.insert(key) {
+            continue;
+        }
+        let (getter_span, setter_span) = (getter.key.span(), setter.key.span());
+        if getter.r#type.is_abstract() != setter.r#type.is_abstract() {
+            ctx.error(diagnostics::accessor_abstract_mismatch(getter_span, setter_span));
+        }
+        let is_less_accessible = match getter.accessibility {
+            Some(TSAccessibility::Private) => {
+                setter.accessibility != Some(TSAccessibility::Private)
+            }
+            Some(TSAccessibility::Protected) => !matches!(
+                setter.accessibility,
+                Some(TSAccessibility::Private | TSAccessibility::Protected)
+            ),
+            _ => false,
+        };
+        if is_less_accessible {
+            ctx.error(diagnostics::getter_less_accessible_than_setter(getter_span, setter_span));
+        }
+    }
+}
+
+/// Overloads of a function declaration must be immediately followed by the implementation, and
+/// must agree with it on `export` and `declare`.
+pub fn check_function_overloads<'a>(statements: &[Statement<'a>], ctx: &SemanticBuilder<'a>) {
+    if !ctx.source_type.is_typescript() || ctx.in_declare_scope() {
+        return;
+    }
+
+    let mut overloads: FxHashMap<&str, Vec<(usize, &Function<'a>, bool)>> = FxHashMap::default();
+    for (index, stmt) in statements.iter().enumerate() {
+        if let Some((func, is_exported)) = as_function_declaration(stmt)
+            && let Some(id) = &func.id
+        {
+            overloads.entry(id.name.as_str()).or_default().push((index, func, is_exported));
+        }
+    }
+    let mut overloads = overloads.into_values().collect::<Vec<_>>();
+    overloads.sort_unstable_by_key(|decls| decls[0].0);
+
+    for decls in overloads {
+        if decls.iter().all(|(_, func, _)| func.body.is_some()) {
+            continue;
+        }
+
+        let mut has_implementation = false;
+        let mut previous_overload = None;
+        let mut last_reported = None;
+        for &(index, func, _) in &decls {
+            if let Some(previous) = previous_overload
+                && previous + 1 != index
+                && !(has_implementation && func.body.is_some())
+            {
+                // `function f(): void; foo(); function f() {}`
+                report_missing_function_implementation(statements, previous, ctx);
+                last_reported = Some(previous);
+            }
+            has_implementation |= func.body.is_some();
+            previous_overload = (func.body.is_none() && !func.declare).then_some(index);
+        }
+        if let Some(&(index, func, _)) = decls.iter().rev().find(|(_, func, _)| !func.declare)
+            && func.body.is_none()
+            && last_reported != Some(index)
+        {
+            report_missing_function_implementation(statements, index, ctx);
+        }
+
+        let canonical = decls.iter().find(|(_, func, _)| func.body.is_some()).unwrap_or(&decls[0]);
+        for (_, func, is_exported) in &decls {
+            let span = func.id.as_ref().map_or(func.span, |id| id.span);
+            if *is_exported != canonical.2 {
+                ctx.error(diagnostics::overload_export_mismatch(span));
+            } else if func.declare != canonical.1.declare {
+                ctx.error(diagnostics::overload_ambient_mismatch(span));
+            }
+        }
+    }
+}
+
+fn report_missing_function_implementation(
+    statements: &[Statement<'_>],
+    index: usize,
+    ctx: &SemanticBuilder<'_>,
+) {
+    let Some((func, _)) = as_function_declaration(&statements[index]) else { return };
+    let Some(id) = &func.id else { return };
+    match statements.get(index + 1).and_then(as_function_declaration) {
+        Some((next, _)) if next.id.as_ref().is_some_and(|next_id| next_id.name == id.name) => {}
+        // `function f(): void; function g() {}`
+        Some((next, _)) if next.body.is_some() => {
+            let span = next.id.as_ref().map_or(next.span, |id| id.span);
+            ctx.error(diagnostics::function_implementation_name_mismatch(&id.name, span));
+        }
+        _ => ctx.error(diagnostics::function_implementation_missing(id.span)),
+    }
+}
+
+/// Returns the function declared by `stmt`, and whether it is exported.
+fn as_function_declaration<'b, 'a>(stmt: &'b Statement<'a>) -> Option<(&'b Function<'a>, bool)> {
+    match stmt {
+        Statement::FunctionDeclaration(func) => Some((func, false)),
+        Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+            Some(Declaration::FunctionDeclaration(func)) => Some((func, true)),
+            _ => None,
+        },
+        Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(func) => Some((func, true)),
+            _ => None,
+        },
+        _ => None,
+    }
 }
 
 pub fn check_method_definition<'a>(method: &MethodDefinition<'a>, ctx: &SemanticBuilder<'a>) {
@@ -275,6 +560,77 @@ pub fn check_for_statement_left(left: &ForStatementLeft, is_for_in: bool, ctx: &
     }
 }
 
+/// An object literal may not declare an accessor with the same name as a property, or more than
+/// one getter or setter with the same name. Only the first conflict is reported.
+pub fn check_object_expression<'a>(obj_expr: &ObjectExpression<'a>, ctx: &SemanticBuilder<'a>) {
+    const PROPERTY: u8 = 1;
+    const GET: u8 = 1 << 1;
+    const SET: u8 = 1 << 2;
+
+    if !ctx.source_type.is_typescript() || obj_expr.properties.len() < 2 {
+        return;
+    }
+
+    let mut seen: FxHashMap<Cow<'a, str>, u8> = FxHashMap::default();
+    for prop in &obj_expr.properties {
+        let ObjectPropertyKind::ObjectProperty(prop) = prop else { continue };
+        let Some(name) = prop.key.static_name() else { continue };
+        let kind = match prop.kind {
+            PropertyKind::Init => PROPERTY,
+            PropertyKind::Get => GET,
+            PropertyKind::Set => SET,
+        };
+        let Some(existing) = seen.get_mut(&name) else {
+            seen.insert(name, kind);
+            continue;
+        };
+        if kind == PROPERTY && *existing == PROPERTY {
+            // Duplicate properties are allowed
+        } else if kind != PROPERTY && *existing & PROPERTY == 0 {
+            if *existing == GET | SET || *existing == kind {
+                // `{ get a() {}, get a() {} }`
+                ctx.error(diagnostics::object_literal_duplicate_accessor(prop.key.span()));
+                return;
+            }
+            *existing |= kind;
+        } else {
+            // `{ a: 1, get a() {} }`
+            ctx.error(diagnostics::object_literal_property_and_accessor(prop.key.span()));
+            return;
+        }
+    }
+}
+
+/// Every top-level declaration in a `.d.ts` file must be `declare`d or exported. Only the first
+/// offending declaration is reported.
+pub fn check_dts_top_level_declarations(program: &Program<'_>, ctx: &SemanticBuilder<'_>) {
+    if !ctx.source_type.is_typescript_definition() {
+        return;
+    }
+
+    for stmt in &program.body {
+        let declare = match stmt {
+            Statement::VariableDeclaration(decl) => decl.declare,
+            Statement::FunctionDeclaration(func) => func.declare,
+            Statement::ClassDeclaration(class) => class.declare,
+            Statement::TSEnumDeclaration(decl) => decl.declare,
+            Statement::TSModuleDeclaration(decl) => decl.declare,
+            Statement::TSGlobalDeclaration(decl) => decl.declare,
+            _ => continue,
+        };
+        if !declare {
+            let start = stmt.span().start;
+            let first_token_len = ctx.source_text[start as usize..]
+                .find(|c: char| !c.is_ascii_alphanumeric() && c != '_' && c != '$')
+                .unwrap_or(0);
+            #[expect(clippy::cast_possible_truncation)]
+            let span = Span::sized(start, first_token_len as u32);
+            ctx.error(diagnostics::dts_top_level_declaration_missing_declare(span));
+            return;
+        }
+    }
+}
+
 pub fn check_ts_export_assignment_in_program<'a>(program: &Program<'a>, ctx: &SemanticBuilder<'a>) {
     if !ctx.source_type.is_typescript() {
         return;
@@ -3,6 +3,13 @@ use std::borrow::Cow;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
 
+/// Creates a diagnostic tagged with its TypeScript error code.
+///
+/// The remaining `OxcDiagnostic::error` diagnostics in this file intentionally have no code,
+/// because TypeScript either does not report them (`module_code`,
+/// `using_declaration_not_allowed_in_script`, `function_declaration_strict`,
+/// `function_declaration_non_strict`) or reports them from the type checker with a code that
+/// depends on context (`private_field_undeclared`, `invalid_label_target`, `accessor_without_body`).
 #[cold]
 fn ts_error<M: Into<Cow<'static, str>>>(code: &'static str, message: M) -> OxcDiagnostic {
     OxcDiagnostic::error(message).with_error_code("TS", code)
@@ -10,7 +17,7 @@ fn ts_error<M: Into<Cow<'static, str>>>(code: &'static str, message: M) -> OxcDi
 
 #[cold]
 pub fn redeclaration(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Identifier `{x0}` has already been declared")).with_labels([
+    ts_error("2451", format!("Identifier `{x0}` has already been declared")).with_labels([
         span1.label(format!("`{x0}` has already been declared here")),
         span2.label("It can not be redeclared here"),
     ])
@@ -29,7 +36,7 @@ pub fn static_and_instance_private_identifier(x0: &str, span1: Span, span2: Span
 #[cold]
 pub fn undefined_export(x0: &str, suggestion: Option<&str>, span1: Span) -> OxcDiagnostic {
     let mut diagnostic =
-        OxcDiagnostic::error(format!("Export '{x0}' is not defined")).with_label(span1);
+        ts_error("2661", format!("Export '{x0}' is not defined")).with_label(span1);
     if let Some(suggestion) = suggestion {
         diagnostic = diagnostic.with_help(format!("Did you mean '{suggestion}'?"));
     }
@@ -38,49 +45,50 @@ pub fn undefined_export(x0: &str, suggestion: Option<&str>, span1: Span) -> OxcD
 
 #[cold]
 pub fn class_static_block_await(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Cannot use await in class static initialization block").with_label(span)
+    ts_error("18037", "Cannot use await in class static initialization block").with_label(span)
 }
 
 #[cold]
 pub fn class_static_block_for_await(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Cannot use 'for await' in class static initialization block")
+    ts_error("18038", "Cannot use 'for await' in class static initialization block")
         .with_label(span)
 }
 
 #[cold]
 pub fn class_static_block_await_using(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Cannot use 'await using' in class static initialization block")
+    ts_error("18054", "Cannot use 'await using' in class static initialization block")
         .with_label(span)
 }
 
 #[cold]
 pub fn reserved_keyword(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("The keyword '{x0}' is reserved")).with_label(span1)
+    ts_error("1212", format!("The keyword '{x0}' is reserved")).with_label(span1)
 }
 
 #[cold]
 pub fn unexpected_identifier_assign(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Cannot assign to '{x0}' in strict mode")).with_label(span1)
+    ts_error("1100", format!("Cannot assign to '{x0}' in strict mode")).with_label(span1)
 }
 
 #[cold]
 pub fn invalid_let_declaration(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!(
-        "`let` cannot be declared as a variable name inside of a `{x0}` declaration"
-    ))
+    ts_error(
+        "2480",
+        format!("`let` cannot be declared as a variable name inside of a `{x0}` declaration"),
+    )
     .with_label(span1)
 }
 
 #[cold]
 pub fn unexpected_arguments(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("'arguments' is not allowed in {x0}"))
+    ts_error("2815", format!("'arguments' is not allowed in {x0}"))
         .with_label(span1)
         .with_help("Assign the 'arguments' variable to a temporary variable outside")
 }
 
 #[cold]
 pub fn private_not_in_class(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Private identifier '#{x0}' is not allowed outside class bodies"))
+    ts_error("18016", format!("Private identifier '#{x0}' is not allowed outside class bodies"))
         .with_label(span1)
 }
 
@@ -96,30 +104,39 @@ pub fn private_field_undeclared(x0: &str, suggestion: Option<&str>, span1: Span)
     diagnostic
 }
 
-#[cold]
-pub fn legacy_octal(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("'0'-prefixed octal literals and octal escape sequences are deprecated")
+fn legacy_octal_diagnostic(code: &'static str, span: Span) -> OxcDiagnostic {
+    ts_error(code, "'0'-prefixed octal literals and octal escape sequences are deprecated")
         .with_help("for octal literals use the '0o' prefix instead")
         .with_label(span)
 }
 
+#[cold]
+pub fn legacy_octal(span: Span) -> OxcDiagnostic {
+    legacy_octal_diagnostic("1121", span)
+}
+
+#[cold]
+pub fn legacy_octal_escape_sequence(span: Span) -> OxcDiagnostic {
+    legacy_octal_diagnostic("1487", span)
+}
+
 #[cold]
 pub fn leading_zero_decimal(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Decimals with leading zeros are not allowed in strict mode")
+    ts_error("1489", "Decimals with leading zeros are not allowed in strict mode")
         .with_help("remove the leading zero")
         .with_label(span)
 }
 
 #[cold]
 pub fn non_octal_decimal_escape_sequence(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid escape sequence")
+    ts_error("1488", "Invalid escape sequence")
         .with_help("\\8 and \\9 are not allowed in strict mode")
         .with_label(span)
 }
 
 #[cold]
 pub fn illegal_use_strict(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
+    ts_error("1347", 
         "Illegal 'use strict' directive in function with non-simple parameter list",
     )
     .with_label(span)
@@ -130,10 +147,9 @@ pub fn illegal_use_strict(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn top_level(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!(
-        "'{x0}' declaration can only be used at the top level of a module"
-    ))
-    .with_label(span1)
+    let code = if x0.starts_with("import") { "1232" } else { "1233" };
+    ts_error(code, format!("'{x0}' declaration can only be used at the top level of a module"))
+        .with_label(span1)
 }
 
 #[cold]
@@ -166,12 +182,12 @@ pub fn function_declaration_non_strict(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn with_statement(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("'with' statements are not allowed").with_label(span)
+    ts_error("1101", "'with' statements are not allowed").with_label(span)
 }
 
 #[cold]
 pub fn invalid_label_jump_target(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Jump target cannot cross function boundary.").with_label(span)
+    ts_error("1107", "Jump target cannot cross function boundary.").with_label(span)
 }
 
 #[cold]
@@ -185,7 +201,7 @@ pub fn invalid_label_target(suggestion: Option<&str>, span: Span) -> OxcDiagnost
 
 #[cold]
 pub fn invalid_label_non_iteration(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("A `{x0}` statement can only jump to a label of an enclosing `for`, `while` or `do while` statement."))
+    ts_error("1115", format!("A `{x0}` statement can only jump to a label of an enclosing `for`, `while` or `do while` statement."))
         .with_labels([
             span1.label("This is an non-iteration statement"),
             span2.label("for this label")
@@ -194,21 +210,21 @@ pub fn invalid_label_non_iteration(x0: &str, span1: Span, span2: Span) -> OxcDia
 
 #[cold]
 pub fn invalid_break(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Illegal break statement")
+    ts_error("1105", "Illegal break statement")
         .with_help("A `break` statement can only be used within an enclosing iteration or switch statement.")
         .with_label(span)
 }
 
 #[cold]
 pub fn invalid_continue(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Illegal continue statement: no surrounding iteration statement")
+    ts_error("1104", "Illegal continue statement: no surrounding iteration statement")
         .with_help("A `continue` statement can only be used within an enclosing `for`, `while` or `do while` ")
         .with_label(span)
 }
 
 #[cold]
 pub fn label_redeclaration(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Label `{x0}` has already been declared")).with_labels([
+    ts_error("1114", format!("Label `{x0}` has already been declared")).with_labels([
         span1.label(format!("`{x0}` has already been declared here")),
         span2.label("It can not be redeclared here"),
     ])
@@ -216,21 +232,21 @@ pub fn label_redeclaration(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic
 
 #[cold]
 pub fn multiple_declaration_in_for_loop_head(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!(
-        "Only a single declaration is allowed in a `for...{x0}` statement"
-    ))
-    .with_label(span1)
+    let code = if x0 == "in" { "1091" } else { "1188" };
+    ts_error(code, format!("Only a single declaration is allowed in a `for...{x0}` statement"))
+        .with_label(span1)
 }
 
 #[cold]
 pub fn unexpected_initializer_in_for_loop_head(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("{x0} loop variable declaration may not have an initializer"))
+    let code = if x0 == "for-in" { "1189" } else { "1190" };
+    ts_error(code, format!("{x0} loop variable declaration may not have an initializer"))
         .with_label(span1)
 }
 
 #[cold]
 pub fn duplicate_constructor(span: Span, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Multiple constructor implementations are not allowed.").with_labels([
+    ts_error("2392", "Multiple constructor implementations are not allowed.").with_labels([
         span.label("constructor has already been declared here"),
         span1.label("it cannot be redeclared here"),
     ])
@@ -238,7 +254,7 @@ pub fn duplicate_constructor(span: Span, span1: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn require_class_name(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A class name is required.").with_label(span)
+    ts_error("1211", "A class name is required.").with_label(span)
 }
 
 #[cold]
@@ -252,7 +268,7 @@ pub fn type_predicate_only_in_return_type(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn super_without_derived_class(span: Span, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("'super' can only be referenced in a derived class.")
+    ts_error("2335", "'super' can only be referenced in a derived class.")
         .with_help("either remove this super, or extend the class")
         .with_labels([span.into(), span1.label("class does not have `extends`")])
 }
@@ -265,24 +281,25 @@ pub fn unexpected_super_call(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn unexpected_super_reference(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("'super' can only be referenced in members of derived classes or object literal expressions.")
+    ts_error("2660", "'super' can only be referenced in members of derived classes or object literal expressions.")
         .with_label(span)
 }
 
 #[cold]
 pub fn delete_of_unqualified(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Delete of an unqualified identifier in strict mode.").with_label(span)
+    ts_error("1102", "Delete of an unqualified identifier in strict mode.").with_label(span)
 }
 
 #[cold]
 pub fn delete_private_field(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("The operand of a 'delete' operator cannot be a private identifier.")
+    ts_error("18011", "The operand of a 'delete' operator cannot be a private identifier.")
         .with_label(span)
 }
 
 #[cold]
 pub fn await_or_yield_in_parameter(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("{x0} expression not allowed in formal parameter"))
+    let code = if x0 == "await" { "2524" } else { "2523" };
+    ts_error(code, format!("{x0} expression not allowed in formal parameter"))
         .with_label(span1.label(format!("{x0} expression not allowed in formal parameter")))
 }
 
@@ -331,7 +348,7 @@ pub fn global_scope_augmentation_should_have_declare_modifier(span: Span) -> Oxc
 
 #[cold]
 pub fn enum_member_must_have_initializer(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Enum member must have initializer.").with_label(span)
+    ts_error("1061", "Enum member must have initializer.").with_label(span)
 }
 
 /// 'infer' declarations are only permitted in the 'extends' clause of a conditional type. (1338)
@@ -417,3 +434,109 @@ pub fn ts_export_assignment_cannot_be_used_with_other_exports(span: Span) -> Oxc
         .with_label(span)
         .with_help("If you want to use `export =`, remove other `export`s and put all of them to the right hand value of `export =`. If you want to use `export`s, remove `export =` statement.")
 }
+
+/// Function implementation name must be '{0}'. (2389)
+#[cold]
+pub fn function_implementation_name_mismatch(expected: &str, span: Span) -> OxcDiagnostic {
+    ts_error("2389", format!("Function implementation name must be '{expected}'.")).with_label(span)
+}
+
+/// - Function overload must be static. (2387)
+/// - Function overload must not be static. (2388)
+#[cold]
+pub fn function_overload_static_mismatch(overload_is_static: bool, span: Span) -> OxcDiagnostic {
+    if overload_is_static {
+        ts_error("2387", "Function overload must be static.").with_label(span)
+    } else {
+        ts_error("2388", "Function overload must not be static.").with_label(span)
+    }
+}
+
+/// Overload signatures must all be exported or non-exported. (2383)
+#[cold]
+pub fn overload_export_mismatch(span: Span) -> OxcDiagnostic {
+    ts_error("2383", "Overload signatures must all be exported or non-exported.").with_label(span)
+}
+
+/// Overload signatures must all be ambient or non-ambient. (2384)
+#[cold]
+pub fn overload_ambient_mismatch(span: Span) -> OxcDiagnostic {
+    ts_error("2384", "Overload signatures must all be ambient or non-ambient.").with_label(span)
+}
+
+/// Overload signatures must all be public, private or protected. (2385)
+#[cold]
+pub fn overload_accessibility_mismatch(span: Span) -> OxcDiagnostic {
+    ts_error("2385", "Overload signatures must all be public, private or protected.")
+        .with_label(span)
+}
+
+/// Overload signatures must all be optional or required. (2386)
+#[cold]
+pub fn overload_optionality_mismatch(span: Span) -> OxcDiagnostic {
+    ts_error("2386", "Overload signatures must all be optional or required.").with_label(span)
+}
+
+/// Overload signatures must all be abstract or non-abstract. (2512)
+#[cold]
+pub fn overload_abstract_mismatch(span: Span) -> OxcDiagnostic {
+    ts_error("2512", "Overload signatures must all be abstract or non-abstract.").with_label(span)
+}
+
+/// Accessors must both be abstract or non-abstract. (2676)
+#[cold]
+pub fn accessor_abstract_mismatch(getter: Span, setter: Span) -> OxcDiagnostic {
+    ts_error("2676", "Accessors must both be abstract or non-abstract.")
+        .with_labels([getter, setter])
+}
+
+/// A get accessor must be at least as accessible as the setter. (2808)
+#[cold]
+pub fn getter_less_accessible_than_setter(getter: Span, setter: Span) -> OxcDiagnostic {
+    ts_error("2808", "A get accessor must be at least as accessible as the setter.")
+        .with_labels([getter, setter])
+}
+
+/// A parameter initializer is only allowed in a function or constructor implementation. (2371)
+#[cold]
+pub fn parameter_initializer_in_signature(span: Span) -> OxcDiagnostic {
+    ts_error(
+        "2371",
+        "A parameter initializer is only allowed in a function or constructor implementation.",
+    )
+    .with_label(span)
+}
+
+/// An object literal cannot have multiple get/set accessors with the same name. (1118)
+#[cold]
+pub fn object_literal_duplicate_accessor(span: Span) -> OxcDiagnostic {
+    ts_error("1118", "An object literal cannot have multiple get/set accessors with the same name.")
+        .with_label(span)
+}
+
+/// An object literal cannot have property and accessor with the same name. (1119)
+#[cold]
+pub fn object_literal_property_and_accessor(span: Span) -> OxcDiagnostic {
+    ts_error("1119", "An object literal cannot have property and accessor with the same name.")
+        .with_label(span)
+}
+
+/// A member initializer in a enum declaration cannot reference members declared after it, including members defined in other enums. (2651)
+#[cold]
+pub fn enum_member_forward_reference(span: Span) -> OxcDiagnostic {
+    ts_error(
+        "2651",
+        "A member initializer in a enum declaration cannot reference members declared after it, including members defined in other enums.",
+    )
+    .with_label(span)
+}
+
+/// Top-level declarations in .d.ts files must start with either a 'declare' or 'export' modifier. (1046)
+#[cold]
+pub fn dts_top_level_declaration_missing_declare(span: Span) -> OxcDiagnostic {
+    ts_error(
+        "1046",
+        "Top-level declarations in .d.ts files must start with either a 'declare' or 'export' modifier.",
+    )
+    .with_label(span)
+}
@@ -28,7 +28,10 @@ pub mod dot;
 #[cfg(feature = "linter")]
 mod ast_types_bitset;
 mod binder;
+#[cfg(feature = "cfg")]
+mod block_accesses;
 mod builder;
+mod call_graph;
 mod checker;
 mod class;
 mod diagnostics;
@@ -38,6 +41,7 @@ mod jsdoc;
 mod label;
 mod multi_index_vec;
 mod node;
+mod rename;
 mod scoping;
 mod stats;
 pub mod ts_enum;
@@ -46,12 +50,14 @@ mod unresolved_stack;
 #[cfg(feature = "linter")]
 pub use ast_types_bitset::AstTypesBitset;
 pub use builder::{SemanticBuilder, SemanticBuilderReturn};
+pub use call_graph::{Call, CallGraph, Callee};
 pub use is_global_reference::IsGlobalReference;
 #[cfg(feature = "jsdoc")]
 pub use jsdoc::JSDocFinder;
 pub use node::{Ancestry, AncestryStack, AstNode, AstNodes};
 #[cfg(feature = "jsdoc")]
 pub use oxc_jsdoc::{JSDoc, JSDocTag};
+pub use rename::{RenameError, TextEdit};
 pub use scoping::Scoping;
 pub use stats::Stats;
 
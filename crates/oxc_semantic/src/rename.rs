@@ -0,0 +1,202 @@
+use std::fmt;
+
+use oxc_ast::AstKind;
+use oxc_span::{GetSpan, Span};
+use oxc_str::Ident;
+use oxc_syntax::{
+    identifier::is_identifier_name, keyword::is_reserved_keyword_or_global_object, node::NodeId,
+    reference::Reference, scope::ScopeId, symbol::SymbolId,
+};
+
+use crate::Semantic;
+
+/// Replacement of the source text in `span` with `new_text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub new_text: String,
+}
+
+/// Reason a symbol cannot be renamed, returned by [`Semantic::rename`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameError {
+    /// The new name is not an identifier, or is a reserved word.
+    InvalidName,
+    /// The new name would clash with another binding, or capture a reference to one.
+    /// Holds the span of the conflicting declaration or reference.
+    Conflict(Span),
+    /// A JSX tag would name an intrinsic element, e.g. `<Foo />` renamed to `<foo />`.
+    IntrinsicJsxElement(Span),
+}
+
+impl fmt::Display for RenameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName => f.write_str("The new name is not a valid identifier"),
+            Self::Conflict(_) => f.write_str("The new name conflicts with an existing binding"),
+            Self::IntrinsicJsxElement(_) => {
+                f.write_str("The new name would turn a JSX component into an intrinsic element")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RenameError {}
+
+impl Semantic<'_> {
+    /// Edits renaming `symbol_id`, its declarations and all references to it, to `new_name`.
+    ///
+    /// Shorthand properties are expanded (`{ a }` → `{ a: b }`), and import and export
+    /// specifiers keep the name they import or export (`import { a }` → `import { a as b }`,
+    /// `export { a }` → `export { b as a }`). Declarations exported inline (`export const a`)
+    /// change the exported name.
+    ///
+    /// Edits are sorted by position and do not overlap. Requires the AST nodes to be built
+    /// (see [`SemanticBuilder::with_build_nodes`]).
+    ///
+    /// # Errors
+    ///
+    /// * [`RenameError::InvalidName`] if `new_name` is not an identifier, or is a reserved word
+    /// * [`RenameError::Conflict`] if `new_name` is declared in the symbol's scope, a declaration
+    ///   of `new_name` would shadow a reference to the symbol, or a reference to another
+    ///   `new_name` would resolve to the renamed symbol
+    /// * [`RenameError::IntrinsicJsxElement`] if the symbol is used as a JSX tag and `new_name`
+    ///   starts with a lowercase letter
+    ///
+    /// [`SemanticBuilder::with_build_nodes`]: crate::SemanticBuilder::with_build_nodes
+    pub fn rename(
+        &self,
+        symbol_id: SymbolId,
+        new_name: &str,
+    ) -> Result<Vec<TextEdit>, RenameError> {
+        if !is_identifier_name(new_name) || is_reserved_keyword_or_global_object(new_name) {
+            return Err(RenameError::InvalidName);
+        }
+        let old_name = self.scoping.symbol_name(symbol_id);
+        if old_name == new_name {
+            return Ok(vec![]);
+        }
+
+        let bindings = self
+            .nodes
+            .iter()
+            .filter_map(|node| match node.kind() {
+                AstKind::BindingIdentifier(ident) if ident.symbol_id.get() == Some(symbol_id) => {
+                    Some(node.id())
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        self.check_rename_conflicts(symbol_id, &bindings, new_name)?;
+
+        let mut edits = Vec::with_capacity(
+            bindings.len() + self.scoping.get_resolved_reference_ids(symbol_id).len(),
+        );
+        for node_id in bindings {
+            edits.push(self.rename_binding(node_id, old_name, new_name));
+        }
+        for reference in self.scoping.get_resolved_references(symbol_id) {
+            edits.push(self.rename_reference(reference.node_id(), old_name, new_name)?);
+        }
+        edits.sort_unstable_by_key(|edit| edit.span.start);
+        Ok(edits)
+    }
+
+    fn check_rename_conflicts(
+        &self,
+        symbol_id: SymbolId,
+        bindings: &[NodeId],
+        new_name: &str,
+    ) -> Result<(), RenameError> {
+        let scoping = &self.scoping;
+        let name = Ident::from(new_name);
+        let symbol_scope_id = scoping.symbol_scope_id(symbol_id);
+        let is_within_symbol_scope = |scope_id: ScopeId| {
+            scope_id == symbol_scope_id || scoping.scope_is_descendant_of(scope_id, symbol_scope_id)
+        };
+
+        // `let a; let b;`
+        if let Some(existing) = scoping.get_binding(symbol_scope_id, name) {
+            return Err(RenameError::Conflict(scoping.symbol_span(existing)));
+        }
+
+        // `let a; { let b; a; }`, or `{ let b; var a; }` where `a` is hoisted out of the block.
+        let use_scopes = bindings.iter().map(|&node_id| self.nodes.get_node(node_id).scope_id());
+        let use_scopes =
+            use_scopes.chain(scoping.get_resolved_references(symbol_id).map(Reference::scope_id));
+        for scope_id in use_scopes {
+            for scope_id in scoping.scope_ancestors(scope_id).take_while(|&s| s != symbol_scope_id)
+            {
+                if let Some(shadowing) = scoping.get_binding(scope_id, name) {
+                    return Err(RenameError::Conflict(scoping.symbol_span(shadowing)));
+                }
+            }
+        }
+
+        // `let b; { let a; b; }`, or `{ let a; console.log(a); }` renamed to `console`.
+        let outer_references = scoping
+            .symbol_ids()
+            .filter(|&other| {
+                scoping.symbol_name(other) == new_name
+                    && !is_within_symbol_scope(scoping.symbol_scope_id(other))
+            })
+            .flat_map(|other| scoping.get_resolved_reference_ids(other).iter().copied())
+            .chain(scoping.root_unresolved_references().get(&name).into_iter().flatten().copied());
+        for reference_id in outer_references {
+            let reference = scoping.get_reference(reference_id);
+            if is_within_symbol_scope(reference.scope_id()) {
+                return Err(RenameError::Conflict(self.nodes.kind(reference.node_id()).span()));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn rename_binding(&self, node_id: NodeId, old_name: &str, new_name: &str) -> TextEdit {
+        let span = self.nodes.kind(node_id).span();
+        let mut parent_id = self.nodes.parent_id(node_id);
+        // `{ a = 1 }`
+        if matches!(self.nodes.kind(parent_id), AstKind::AssignmentPattern(_)) {
+            parent_id = self.nodes.parent_id(parent_id);
+        }
+        let new_text = match self.nodes.kind(parent_id) {
+            AstKind::BindingProperty(property) if property.shorthand => {
+                format!("{old_name}: {new_name}")
+            }
+            AstKind::ImportSpecifier(specifier)
+                if specifier.imported.span() == specifier.local.span =>
+            {
+                format!("{old_name} as {new_name}")
+            }
+            _ => new_name.to_string(),
+        };
+        TextEdit { span, new_text }
+    }
+
+    fn rename_reference(
+        &self,
+        node_id: NodeId,
+        old_name: &str,
+        new_name: &str,
+    ) -> Result<TextEdit, RenameError> {
+        let span = self.nodes.kind(node_id).span();
+        let new_text = match self.nodes.parent_kind(node_id) {
+            AstKind::ObjectProperty(property) if property.shorthand => {
+                format!("{old_name}: {new_name}")
+            }
+            AstKind::AssignmentTargetPropertyIdentifier(_) => format!("{old_name}: {new_name}"),
+            AstKind::ExportSpecifier(specifier)
+                if specifier.exported.span() == specifier.local.span() =>
+            {
+                format!("{new_name} as {old_name}")
+            }
+            AstKind::JSXOpeningElement(_) | AstKind::JSXClosingElement(_)
+                if new_name.starts_with(|c: char| c.is_ascii_lowercase()) =>
+            {
+                return Err(RenameError::IntrinsicJsxElement(span));
+            }
+            _ => new_name.to_string(),
+        };
+        Ok(TextEdit { span, new_text })
+    }
+}
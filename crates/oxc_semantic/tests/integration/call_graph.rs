@@ -0,0 +1,103 @@
+use oxc_semantic::{Callee, NodeId, Semantic};
+use oxc_span::GetSpan;
+
+use crate::util::SemanticTester;
+
+/// Each call as `caller -> callee`, naming functions by their source text up to the body.
+fn edges(semantic: &Semantic) -> Vec<String> {
+    let graph = semantic.call_graph();
+    let name = |node_id: NodeId| {
+        if node_id == NodeId::ROOT {
+            return "<program>".to_string();
+        }
+        let span = semantic.nodes().kind(node_id).span();
+        let text = span.source_text(semantic.source_text());
+        text[..text.find('{').unwrap()].trim().to_string()
+    };
+    graph
+        .calls()
+        .iter()
+        .map(|call| {
+            let callee = match call.callee {
+                Callee::Function(function) => name(function),
+                Callee::Import(symbol_id) => {
+                    format!("import {}", semantic.scoping().symbol_name(symbol_id))
+                }
+                Callee::Unknown => "?".to_string(),
+            };
+            format!("{} -> {callee}", name(call.caller))
+        })
+        .collect()
+}
+
+#[test]
+fn test_direct_calls() {
+    let tester =
+        SemanticTester::js("function a() { b(); } function b() { a(); b(); } a(); new b(); b``;");
+    assert_eq!(
+        edges(&tester.build()),
+        [
+            "function a() -> function b()",
+            "function b() -> function a()",
+            "function b() -> function b()",
+            "<program> -> function a()",
+            "<program> -> function b()",
+            "<program> -> function b()",
+        ]
+    );
+}
+
+#[test]
+fn test_function_variables() {
+    let tester = SemanticTester::js(
+        "const a = () => {}; let b = function () {}; let c = () => {}; c = a; a(); b(); c();",
+    );
+    assert_eq!(
+        edges(&tester.build()),
+        ["<program> -> () =>", "<program> -> function ()", "<program> -> ?"]
+    );
+
+    let tester = SemanticTester::js("(function f() {})(); (() => { g(); })();");
+    assert_eq!(
+        edges(&tester.build()),
+        ["<program> -> function f()", "<program> -> () =>", "() => -> ?"]
+    );
+}
+
+#[test]
+fn test_unknown_and_imported_callees() {
+    let tester = SemanticTester::js(
+        "import { a } from 'x'; function f() { a(); obj.method(); fns[0](); console.log(); }",
+    );
+    assert_eq!(
+        edges(&tester.build()),
+        ["function f() -> import a", "function f() -> ?", "function f() -> ?", "function f() -> ?",]
+    );
+}
+
+#[test]
+fn test_overloads() {
+    let tester = SemanticTester::ts("function f(a: string): void; function f(a: any) {} f('');");
+    let semantic = tester.build();
+    let graph = semantic.call_graph();
+    let Callee::Function(function) = graph.calls()[0].callee else { panic!() };
+    assert!(
+        semantic.nodes().kind(function).span().source_text(semantic.source_text()).ends_with("{}")
+    );
+    assert_eq!(graph.calls_to(function).count(), 1);
+}
+
+#[test]
+fn test_display_dot() {
+    let tester = SemanticTester::js("function f() { f(); g(); } f();");
+    let semantic = tester.build();
+    insta::assert_snapshot!(semantic.call_graph().display_dot(&semantic), @r#"
+    digraph {
+        0 [ label = "<program>" ]
+        1 [ label = "f" ]
+        1 -> 1
+        1 -> "<unknown>" [ style = dotted ]
+        0 -> 1
+    }
+    "#);
+}
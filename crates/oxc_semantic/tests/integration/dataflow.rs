@@ -0,0 +1,155 @@
+#![cfg(feature = "cfg")]
+
+use oxc_cfg::{
+    BlockNodeId,
+    dataflow::{
+        AccessKind, BlockAccesses, DefiniteAssignment, Liveness, ReachingDefinitions, solve,
+    },
+};
+use oxc_semantic::{NodeId, Semantic, SymbolId};
+
+use crate::util::SemanticTester;
+
+struct Fixture<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+    accesses: BlockAccesses,
+}
+
+impl<'s, 'a> Fixture<'s, 'a> {
+    fn new(semantic: &'s Semantic<'a>) -> Self {
+        Self { semantic, accesses: semantic.block_accesses().unwrap() }
+    }
+
+    fn entry(&self) -> BlockNodeId {
+        self.semantic.nodes().cfg_id(NodeId::ROOT)
+    }
+
+    fn symbol(&self, name: &str) -> SymbolId {
+        let scoping = self.semantic.scoping();
+        scoping.symbol_ids().find(|&symbol_id| scoping.symbol_name(symbol_id) == name).unwrap()
+    }
+
+    /// Accesses of `name` of the given kind, in source order.
+    fn accesses_of(&self, name: &str, kind: AccessKind) -> Vec<(BlockNodeId, usize)> {
+        let symbol_id = self.symbol(name);
+        let mut found = self
+            .accesses
+            .iter()
+            .flat_map(|(block, accesses)| {
+                accesses.iter().enumerate().filter_map(move |(index, access)| {
+                    (access.symbol_id == symbol_id && access.kind == kind).then_some((
+                        block,
+                        index,
+                        access.node_id,
+                    ))
+                })
+            })
+            .collect::<Vec<_>>();
+        found.sort_by_key(|(_, _, node_id)| *node_id);
+        found.into_iter().map(|(block, index, _)| (block, index)).collect()
+    }
+
+    /// Whether each write of `name` is read before it is overwritten, in source order.
+    fn live_writes(&self, name: &str) -> Vec<bool> {
+        let symbol_id = self.symbol(name);
+        let liveness = Liveness::new(&self.accesses);
+        let results = solve(self.semantic.cfg().unwrap(), &liveness, self.entry());
+        self.accesses_of(name, AccessKind::Write)
+            .into_iter()
+            .map(|(block, index)| liveness.live_after(&results, block, index).contains(&symbol_id))
+            .collect()
+    }
+
+    /// Whether `name` is definitely assigned at each of its reads, in source order.
+    fn assigned_reads(&self, name: &str) -> Vec<bool> {
+        let symbol_id = self.symbol(name);
+        let analysis = DefiniteAssignment::new(&self.accesses);
+        let results = solve(self.semantic.cfg().unwrap(), &analysis, self.entry());
+        self.accesses_of(name, AccessKind::Read)
+            .into_iter()
+            .map(|(block, index)| analysis.is_assigned_before(&results, block, index, symbol_id))
+            .collect()
+    }
+
+    /// The number of writes of `name` reaching each of its reads, in source order.
+    fn reaching_writes(&self, name: &str) -> Vec<usize> {
+        let symbol_id = self.symbol(name);
+        let analysis = ReachingDefinitions::new(&self.accesses);
+        let results = solve(self.semantic.cfg().unwrap(), &analysis, self.entry());
+        self.accesses_of(name, AccessKind::Read)
+            .into_iter()
+            .map(|(block, index)| {
+                analysis
+                    .definitions_before(&results, block, index)
+                    .iter()
+                    .filter(|definition| definition.symbol_id == symbol_id)
+                    .count()
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_liveness() {
+    let tester = SemanticTester::js("let x = 1; x = 2; foo(x); x = 3;").with_cfg(true);
+    let semantic = tester.build();
+    assert_eq!(Fixture::new(&semantic).live_writes("x"), [false, true, false]);
+
+    let tester = SemanticTester::js("let x = 0; x = x + 1; foo(x);").with_cfg(true);
+    let semantic = tester.build();
+    assert_eq!(Fixture::new(&semantic).live_writes("x"), [true, true]);
+
+    let tester = SemanticTester::js("let x = 0; if (a) { x = 1; } else { foo(x); }").with_cfg(true);
+    let semantic = tester.build();
+    assert_eq!(Fixture::new(&semantic).live_writes("x"), [true, false]);
+
+    // The loop condition reads the write at the end of the body through the backedge.
+    let tester = SemanticTester::js("let i = 0; while (i < 10) { foo(i); i += 1; }").with_cfg(true);
+    let semantic = tester.build();
+    assert_eq!(Fixture::new(&semantic).live_writes("i"), [true, true]);
+}
+
+#[test]
+fn test_definite_assignment() {
+    let tester =
+        SemanticTester::js("let x; if (a) { x = 1; } else { x = 2; } foo(x);").with_cfg(true);
+    let semantic = tester.build();
+    assert_eq!(Fixture::new(&semantic).assigned_reads("x"), [true]);
+
+    let tester = SemanticTester::js("let x; if (a) { x = 1; } foo(x);").with_cfg(true);
+    let semantic = tester.build();
+    assert_eq!(Fixture::new(&semantic).assigned_reads("x"), [false]);
+
+    let tester = SemanticTester::js("let x; foo(x); x = 1; foo(x);").with_cfg(true);
+    let semantic = tester.build();
+    assert_eq!(Fixture::new(&semantic).assigned_reads("x"), [false, true]);
+
+    // Function declarations are hoisted, and captured variables are not tracked into closures.
+    let tester = SemanticTester::js("let x; f(); function f() { return x; } x = 1;").with_cfg(true);
+    let semantic = tester.build();
+    let fixture = Fixture::new(&semantic);
+    assert_eq!(fixture.assigned_reads("f"), [true]);
+    assert_eq!(fixture.assigned_reads("x"), [true]);
+
+    let tester = SemanticTester::js("function f(a) { return a; }").with_cfg(true);
+    let semantic = tester.build();
+    assert_eq!(Fixture::new(&semantic).assigned_reads("a"), [true]);
+}
+
+#[test]
+fn test_reaching_definitions() {
+    let tester = SemanticTester::js("let x = 1; if (a) x = 2; foo(x);").with_cfg(true);
+    let semantic = tester.build();
+    assert_eq!(Fixture::new(&semantic).reaching_writes("x"), [2]);
+
+    let tester = SemanticTester::js("let x = 1; x = 2; foo(x);").with_cfg(true);
+    let semantic = tester.build();
+    assert_eq!(Fixture::new(&semantic).reaching_writes("x"), [1]);
+
+    let tester =
+        SemanticTester::js("let x = 0; for (let i of a) { foo(x); x = i; }").with_cfg(true);
+    let semantic = tester.build();
+    let fixture = Fixture::new(&semantic);
+    assert_eq!(fixture.reaching_writes("x"), [2]);
+    assert_eq!(fixture.reaching_writes("i"), [1]);
+}
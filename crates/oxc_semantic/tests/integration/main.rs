@@ -1,9 +1,12 @@
 #![expect(clippy::missing_panics_doc)]
 
+pub mod call_graph;
 pub mod cfg;
 pub mod classes;
+pub mod dataflow;
 pub mod enum_values;
 pub mod modules;
+pub mod rename;
 pub mod scopes;
 pub mod symbols;
 pub mod util;
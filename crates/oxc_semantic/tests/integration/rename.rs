@@ -0,0 +1,116 @@
+use oxc_semantic::{RenameError, Semantic, SymbolId};
+
+use crate::util::SemanticTester;
+
+/// The first symbol named `name`, in declaration order.
+fn symbol(semantic: &Semantic, name: &str) -> SymbolId {
+    let scoping = semantic.scoping();
+    scoping.symbol_ids().find(|&symbol_id| scoping.symbol_name(symbol_id) == name).unwrap()
+}
+
+fn rename(tester: &SemanticTester, name: &str, new_name: &str) -> Result<String, RenameError> {
+    let semantic = tester.build();
+    let edits = semantic.rename(symbol(&semantic, name), new_name)?;
+    let mut output = semantic.source_text().to_string();
+    for edit in edits.iter().rev() {
+        output.replace_range(edit.span.start as usize..edit.span.end as usize, &edit.new_text);
+    }
+    Ok(output)
+}
+
+fn renamed(tester: &SemanticTester, name: &str, new_name: &str) -> String {
+    rename(tester, name, new_name).unwrap()
+}
+
+#[test]
+fn test_rename_references() {
+    let tester = SemanticTester::js("let a = 1; a++; function f(a) { return a; } f(a);");
+    assert_eq!(renamed(&tester, "a", "b"), "let b = 1; b++; function f(a) { return a; } f(b);");
+
+    let tester = SemanticTester::js("var a; var a = 1; a;");
+    assert_eq!(renamed(&tester, "a", "b"), "var b; var b = 1; b;");
+
+    let tester = SemanticTester::ts("type A = string; let x: A; function f(): A {}");
+    assert_eq!(renamed(&tester, "A", "B"), "type B = string; let x: B; function f(): B {}");
+
+    let tester = SemanticTester::js("let a = 1; a;");
+    assert_eq!(renamed(&tester, "a", "a"), "let a = 1; a;");
+}
+
+#[test]
+fn test_rename_shorthand() {
+    let tester = SemanticTester::js("let a = 1; let o = { a, b: a };");
+    assert_eq!(renamed(&tester, "a", "c"), "let c = 1; let o = { a: c, b: c };");
+
+    let tester = SemanticTester::js("let { a, b = 1 } = o;");
+    assert_eq!(renamed(&tester, "a", "c"), "let { a: c, b = 1 } = o;");
+    assert_eq!(renamed(&tester, "b", "c"), "let { a, b: c = 1 } = o;");
+
+    let tester = SemanticTester::js("let a; ({ a } = o); ({ a = 1 } = o);");
+    assert_eq!(renamed(&tester, "a", "c"), "let c; ({ a: c } = o); ({ a: c = 1 } = o);");
+}
+
+#[test]
+fn test_rename_module_specifiers() {
+    let tester = SemanticTester::js("import { a } from 'x'; import d from 'y'; a(d);");
+    assert_eq!(renamed(&tester, "a", "b"), "import { a as b } from 'x'; import d from 'y'; b(d);");
+    assert_eq!(renamed(&tester, "d", "e"), "import { a } from 'x'; import e from 'y'; a(e);");
+
+    let tester = SemanticTester::js("import { a as b } from 'x'; b;");
+    assert_eq!(renamed(&tester, "b", "c"), "import { a as c } from 'x'; c;");
+
+    let tester = SemanticTester::js("let a; export { a }; export { a as c }; export default a;");
+    assert_eq!(
+        renamed(&tester, "a", "b"),
+        "let b; export { b as a }; export { b as c }; export default b;"
+    );
+
+    let tester = SemanticTester::js("export const a = 1;");
+    assert_eq!(renamed(&tester, "a", "b"), "export const b = 1;");
+}
+
+#[test]
+fn test_rename_jsx() {
+    let tester = SemanticTester::tsx("const Foo = 1; <Foo><Foo.Bar /></Foo>;");
+    assert_eq!(renamed(&tester, "Foo", "Baz"), "const Baz = 1; <Baz><Baz.Bar /></Baz>;");
+    assert_eq!(
+        rename(&tester, "Foo", "baz"),
+        Err(RenameError::IntrinsicJsxElement(oxc_span::Span::new(16, 19)))
+    );
+}
+
+#[test]
+fn test_rename_invalid_name() {
+    let tester = SemanticTester::js("let a;");
+    assert_eq!(rename(&tester, "a", "1a"), Err(RenameError::InvalidName));
+    assert_eq!(rename(&tester, "a", "class"), Err(RenameError::InvalidName));
+    assert_eq!(rename(&tester, "a", "undefined"), Err(RenameError::InvalidName));
+}
+
+#[test]
+fn test_rename_conflicts() {
+    fn is_conflict(source_text: &'static str, name: &str, new_name: &str) -> bool {
+        matches!(
+            rename(&SemanticTester::js(source_text), name, new_name),
+            Err(RenameError::Conflict(_))
+        )
+    }
+
+    // Redeclaration in the same scope
+    assert!(is_conflict("let a; let b;", "a", "b"));
+    assert!(is_conflict("function f(a, b) {}", "a", "b"));
+    // A nested declaration would shadow a reference
+    assert!(is_conflict("let a; function f() { let b; a; }", "a", "b"));
+    // A block-scoped declaration would shadow the hoisted `var`
+    assert!(is_conflict("function f() { { let b; var a; } }", "a", "b"));
+    // A reference to an outer binding would be captured
+    assert!(is_conflict("let b; function f() { let a; b; }", "a", "b"));
+    // A reference to a global would be captured
+    assert!(is_conflict("function f() { let a; console.log(a); }", "a", "console"));
+
+    // Shadowing in scopes that do not see the symbol is fine
+    assert!(!is_conflict("let a; function f() { let b; b; } a;", "a", "b"));
+    assert!(!is_conflict("function f() { let a; } function g() { let b; b; }", "a", "b"));
+    // An inner declaration of the new name keeps its own references
+    assert!(!is_conflict("let a; function f() { let b; b; }", "a", "b"));
+}
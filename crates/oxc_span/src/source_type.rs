@@ -47,6 +47,8 @@ pub enum Language {
     TypeScript = 1,
     /// Indicates a TypeScript definition file (`*.d.ts`)
     TypeScriptDefinition = 2,
+    /// Indicates a JavaScript or JSX file with [Flow](https://flow.org) type annotations
+    Flow = 3,
 }
 
 /// Script or Module
@@ -436,6 +438,16 @@ impl SourceType {
         self.language == Language::TypeScriptDefinition
     }
 
+    /// Returns `true` if this is a JavaScript file with [Flow] type annotations.
+    ///
+    /// Flow files are not detected from the file extension. Use [`SourceType::with_flow`] for
+    /// files containing an `@flow` pragma.
+    ///
+    /// [Flow]: Language::Flow
+    pub fn is_flow(self) -> bool {
+        self.language == Language::Flow
+    }
+
     /// Returns `true` if this source type is using JSX.
     ///
     /// Note that TSX is considered JSX in this context.
@@ -536,6 +548,18 @@ impl SourceType {
         self
     }
 
+    /// Mark this [`SourceType`] as using [Flow] if `yes` is `true`. No change
+    /// will occur if `yes` is `false`.
+    ///
+    /// [Flow]: Language::Flow
+    #[must_use]
+    pub const fn with_flow(mut self, yes: bool) -> Self {
+        if yes {
+            self.language = Language::Flow;
+        }
+        self
+    }
+
     /// Mark this [`SourceType`] as using [JSX] if `yes` is `true`. No change
     /// will occur if `yes` is `false`.
     ///
@@ -790,6 +814,21 @@ mod tests {
         assert!(cjs.is_javascript());
         assert!(jsx.is_jsx());
     }
+
+    #[test]
+    fn test_flow() {
+        let flow = SourceType::from_path("foo.js").unwrap().with_flow(true);
+        assert!(flow.is_flow());
+        assert!(!flow.is_javascript());
+        assert!(!flow.is_typescript());
+        assert!(flow.is_unambiguous());
+
+        let flow_jsx = SourceType::jsx().with_flow(true);
+        assert!(flow_jsx.is_flow());
+        assert!(flow_jsx.is_jsx());
+
+        assert!(!SourceType::mjs().with_flow(false).is_flow());
+    }
 }
 
 #[cfg(test)]
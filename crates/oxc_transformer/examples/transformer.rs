@@ -19,6 +19,7 @@
 //! - `--target <target>`: Single target environment
 //! - `--inline-sourcemap`: Append an inline sourcemap comment to the transformed output
 //! - `--sourcemap`: Alias for `--inline-sourcemap`
+//! - `--flow`: Parse the file as Flow
 
 use std::path::Path;
 
@@ -44,13 +45,14 @@ fn main() {
     let targets: Option<String> = args.opt_value_from_str("--targets").unwrap_or(None);
     let target: Option<String> = args.opt_value_from_str("--target").unwrap_or(None);
     let inline_sourcemap = args.contains("--inline-sourcemap");
+    let flow = args.contains("--flow");
     let name = args.free_from_str().unwrap_or_else(|_| "test.js".to_string());
 
     let path = Path::new(&name);
     let source_text =
         std::fs::read_to_string(path).unwrap_or_else(|err| panic!("{name} not found.\n{err}"));
     let allocator = Allocator::default();
-    let source_type = SourceType::from_path(path).unwrap();
+    let source_type = SourceType::from_path(path).unwrap().with_flow(flow);
 
     let ret = Parser::new(&allocator, &source_text, source_type).parse();
 
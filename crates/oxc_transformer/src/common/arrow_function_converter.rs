@@ -269,10 +269,20 @@ impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a> {
         arrow: &mut ArrowFunctionExpression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
-        if self.is_async_only() {
-            let previous = *self.arguments_needs_transform_stack.last();
-            self.arguments_needs_transform_stack.push(previous || arrow.r#async);
+        if self.is_disabled() {
+            return;
+        }
 
+        // Arrow functions are converted to functions, and async arrow functions to generator
+        // functions, which have their own `arguments`, so `arguments` has to be captured from
+        // the enclosing function. Async arrows need this in both modes, because the async
+        // transforms run before arrow functions are converted, and may compile the generator
+        // further into a state machine.
+        let previous = *self.arguments_needs_transform_stack.last();
+        self.arguments_needs_transform_stack
+            .push(previous || arrow.r#async || !self.is_async_only());
+
+        if self.is_async_only() {
             if Self::in_class_property_definition_value(ctx) {
                 self.this_var_stack.push(None);
                 self.super_methods_stack.push(FxIndexMap::default());
@@ -306,7 +316,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a> {
     }
 
     fn enter_function_body(&mut self, _body: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
-        if self.is_async_only() {
+        if !self.is_disabled() {
             // Ignore arrow functions
             if let Ancestor::FunctionBody(func) = ctx.parent() {
                 let is_async_method =
@@ -318,7 +328,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a> {
 
     fn exit_function_body(&mut self, _body: &mut FunctionBody<'a>, _ctx: &mut TraverseCtx<'a>) {
         // This covers exiting either a `Function` or an `ArrowFunctionExpression`
-        if self.is_async_only() {
+        if !self.is_disabled() {
             self.arguments_needs_transform_stack.pop();
         }
     }
@@ -1051,7 +1061,9 @@ impl<'a> ArrowFunctionConverter<'a> {
         ident: &mut IdentifierReference<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
-        if &ident.name != "arguments" {
+        // `arguments` created by other transforms (e.g. to initialize parameters) refers to
+        // the converted function's own `arguments`
+        if &ident.name != "arguments" || ident.span.is_unspanned() {
             return;
         }
 
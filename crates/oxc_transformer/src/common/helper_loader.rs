@@ -200,6 +200,47 @@ pub enum Helper {
     UsingCtx,
     /// Runtime helper `taggedTemplateLiteral`.
     TaggedTemplateLiteral,
+    /// Runtime helper `tdz`.
+    Tdz,
+    /// Runtime helper `temporalRef`.
+    TemporalRef,
+    /// Runtime helper `temporalUndefined`.
+    TemporalUndefined,
+    /// Runtime helper `classCallCheck`.
+    ClassCallCheck,
+    /// Runtime helper `createClass`.
+    CreateClass,
+    /// Runtime helper `inherits`.
+    Inherits,
+    /// Runtime helper `callSuper`.
+    CallSuper,
+    /// Runtime helper `assertThisInitialized`.
+    AssertThisInitialized,
+    /// Runtime helper `possibleConstructorReturn`.
+    PossibleConstructorReturn,
+    /// Runtime helper `wrapNativeSuper`.
+    WrapNativeSuper,
+    /// Runtime helper `toConsumableArray`.
+    ToConsumableArray,
+    /// Runtime helper `slicedToArray`.
+    SlicedToArray,
+    /// Runtime helper `toArray`.
+    ToArray,
+    /// Runtime helper `maybeArrayLike`.
+    MaybeArrayLike,
+    /// Runtime helper `construct`.
+    Construct,
+    /// Runtime helper `taggedTemplateLiteralLoose`.
+    TaggedTemplateLiteralLoose,
+    /// Runtime helper `defineAccessor`.
+    DefineAccessor,
+    /// Runtime helper `createForOfIteratorHelper`.
+    #[expect(clippy::enum_variant_names)]
+    CreateForOfIteratorHelper,
+    /// Runtime helper `createForOfIteratorHelperLoose`.
+    CreateForOfIteratorHelperLoose,
+    /// Runtime helper `regeneratorRuntime`.
+    RegeneratorRuntime,
 }
 
 impl Helper {
@@ -235,6 +276,26 @@ impl Helper {
             Self::DecorateMetadata => "decorateMetadata",
             Self::UsingCtx => "usingCtx",
             Self::TaggedTemplateLiteral => "taggedTemplateLiteral",
+            Self::Tdz => "tdz",
+            Self::TemporalRef => "temporalRef",
+            Self::TemporalUndefined => "temporalUndefined",
+            Self::ClassCallCheck => "classCallCheck",
+            Self::CreateClass => "createClass",
+            Self::Inherits => "inherits",
+            Self::CallSuper => "callSuper",
+            Self::AssertThisInitialized => "assertThisInitialized",
+            Self::PossibleConstructorReturn => "possibleConstructorReturn",
+            Self::WrapNativeSuper => "wrapNativeSuper",
+            Self::ToConsumableArray => "toConsumableArray",
+            Self::SlicedToArray => "slicedToArray",
+            Self::ToArray => "toArray",
+            Self::MaybeArrayLike => "maybeArrayLike",
+            Self::Construct => "construct",
+            Self::TaggedTemplateLiteralLoose => "taggedTemplateLiteralLoose",
+            Self::DefineAccessor => "defineAccessor",
+            Self::CreateForOfIteratorHelper => "createForOfIteratorHelper",
+            Self::CreateForOfIteratorHelperLoose => "createForOfIteratorHelperLoose",
+            Self::RegeneratorRuntime => "regeneratorRuntime",
         }
     }
 
@@ -10,7 +10,6 @@ use serde::Deserialize;
 pub struct CompilerAssumptions {
     /// Assume array-like values are iterable.
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub array_like_is_iterable: bool,
 
     /// Assume re-exported bindings are constant.
@@ -34,17 +33,14 @@ pub struct CompilerAssumptions {
 
     /// Ignore the preferred hint passed to `Symbol.toPrimitive`.
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub ignore_to_primitive_hint: bool,
 
     /// Assume iterable operations only receive arrays.
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub iterable_is_array: bool,
 
     /// Emit mutable template objects.
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub mutable_template_object: bool,
 
     /// Assume class constructors are never called without `new`.
@@ -95,7 +91,6 @@ pub struct CompilerAssumptions {
 
     /// Assume computed properties can be assigned directly.
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub set_computed_properties: bool,
 
     /// When using public class fields, assume that they don't shadow any getter in the current class,
@@ -147,7 +142,6 @@ pub struct CompilerAssumptions {
 
     /// Skip `for..of` iterator closing logic.
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub skip_for_of_iterator_closing: bool,
 
     /// Assume `super` can be invoked as a normal callable constructor.
@@ -0,0 +1,184 @@
+//! Assignments to bindings declared with `const`.
+//!
+//! Once converted to `var`, assigning to the binding would succeed, so the assignment is replaced
+//! with a call to the `readOnlyError` helper, after evaluating anything the assignment would have
+//! evaluated before throwing:
+//!
+//! * `x = value` -> `value, babelHelpers.readOnlyError("x")`
+//! * `x += value` -> `x + value, babelHelpers.readOnlyError("x")`
+//! * `x ||= value` -> `x || (value, babelHelpers.readOnlyError("x"))`
+//! * `x++` -> `+x, babelHelpers.readOnlyError("x")`
+//! * `for (x of xs) {}` -> `for (var _x of xs) { babelHelpers.readOnlyError("x"); }`
+
+use oxc_allocator::{ArenaVec, TakeIn};
+use oxc_ast::{ast::*, builder::NONE};
+use oxc_semantic::{ReferenceFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_str::Ident;
+
+use crate::{
+    common::helper_loader::{Helper, helper_call_expr},
+    context::TraverseCtx,
+};
+
+use super::BlockScoping;
+
+impl<'a> BlockScoping<'a> {
+    /// Transform an assignment to a `const` binding.
+    pub(super) fn transform_const_violation(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match expr {
+            Expression::AssignmentExpression(assign) => {
+                let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left else {
+                    return;
+                };
+                if !self.is_const_reference(ident, ctx) {
+                    return;
+                }
+                let name = ident.name;
+                let error = Self::create_read_only_error(name, ctx);
+                let value = assign.right.take_in(ctx);
+                *expr = match assign.operator {
+                    AssignmentOperator::Assign => {
+                        if let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left {
+                            ctx.delete_reference_for_identifier(ident);
+                        }
+                        Self::create_sequence(assign.span, value, error, ctx)
+                    }
+                    operator => {
+                        let target = Self::take_target_as_read(&mut assign.left, ctx);
+                        if let Some(operator) = operator.to_logical_operator() {
+                            let right = Self::create_sequence(SPAN, value, error, ctx);
+                            Expression::new_logical_expression(
+                                assign.span,
+                                target,
+                                operator,
+                                right,
+                                ctx,
+                            )
+                        } else {
+                            let operator = operator.to_binary_operator().unwrap();
+                            let value = Expression::new_binary_expression(
+                                SPAN, target, operator, value, ctx,
+                            );
+                            Self::create_sequence(assign.span, value, error, ctx)
+                        }
+                    }
+                };
+            }
+            Expression::UpdateExpression(update) => {
+                let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) =
+                    &mut update.argument
+                else {
+                    return;
+                };
+                if !self.is_const_reference(ident, ctx) {
+                    return;
+                }
+                let name = ident.name;
+                *ctx.scoping_mut().get_reference_mut(ident.reference_id()).flags_mut() =
+                    ReferenceFlags::Read;
+                let ident = ident.take_in_box(ctx);
+                let value = Expression::new_unary_expression(
+                    SPAN,
+                    UnaryOperator::UnaryPlus,
+                    Expression::Identifier(ident),
+                    ctx,
+                );
+                let error = Self::create_read_only_error(name, ctx);
+                *expr = Self::create_sequence(update.span, value, error, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    /// Transform `for (x of xs)` where `x` is a `const` binding.
+    ///
+    /// `scope_id` is the scope of the loop head.
+    pub(super) fn transform_for_in_of_left(
+        &self,
+        scope_id: ScopeId,
+        left: &mut ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let ForStatementLeft::AssignmentTargetIdentifier(ident) = left else { return };
+        if !self.is_const_reference(ident, ctx) {
+            return;
+        }
+        let name = ident.name;
+        ctx.delete_reference_for_identifier(ident);
+
+        // `var _x`
+        let var_scope_id = Self::var_scope_id(scope_id, ctx);
+        let binding = ctx.generate_uid(&name, var_scope_id, SymbolFlags::FunctionScopedVariable);
+        let declarator = VariableDeclarator::new(
+            SPAN,
+            VariableDeclarationKind::Var,
+            binding.create_binding_pattern(ctx),
+            NONE,
+            None,
+            false,
+            ctx,
+        );
+        *left = ForStatementLeft::VariableDeclaration(VariableDeclaration::boxed(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ArenaVec::from_value_in(declarator, ctx),
+            false,
+            ctx,
+        ));
+
+        // `babelHelpers.readOnlyError("x");` at the start of the body
+        let error = Self::create_read_only_error(name, ctx);
+        let error = Statement::new_expression_statement(SPAN, error, ctx);
+        Self::ensure_block(body, scope_id, ctx);
+        let Statement::BlockStatement(block) = body else { unreachable!() };
+        block.body.insert(0, error);
+    }
+
+    fn is_const_reference(&self, ident: &IdentifierReference<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        ctx.scoping()
+            .get_reference(ident.reference_id())
+            .symbol_id()
+            .is_some_and(|symbol_id| self.const_symbols.contains(&symbol_id))
+    }
+
+    /// Convert an `AssignmentTarget::AssignmentTargetIdentifier` to a read of the identifier.
+    fn take_target_as_read(
+        target: &mut AssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let AssignmentTarget::AssignmentTargetIdentifier(ident) = target.take_in(ctx) else {
+            unreachable!()
+        };
+        *ctx.scoping_mut().get_reference_mut(ident.reference_id()).flags_mut() =
+            ReferenceFlags::Read;
+        Expression::Identifier(ident)
+    }
+
+    /// `babelHelpers.readOnlyError("x")`
+    fn create_read_only_error(name: Ident<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let arguments = ArenaVec::from_value_in(
+            Argument::from(Expression::new_string_literal(SPAN, name, None, ctx)),
+            ctx,
+        );
+        helper_call_expr(Helper::ReadOnlyError, arguments, ctx)
+    }
+
+    fn create_sequence(
+        span: Span,
+        first: Expression<'a>,
+        second: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        Expression::new_sequence_expression(
+            span,
+            ArenaVec::from_array_in([first, second], ctx),
+            ctx,
+        )
+    }
+}
@@ -0,0 +1,1089 @@
+//! Loops whose bindings are captured by a closure.
+//!
+//! Each iteration of a loop gets a fresh copy of the bindings declared in its head and body.
+//! Once they are converted to `var`, a closure created in the loop would see the value from the
+//! last iteration instead, so the loop body is moved into a function, called on each iteration
+//! with the current values of the loop head's bindings:
+//!
+//! ```js
+//! outer: for (let i = 0; i < 3; i++) {
+//!   if (i === 1) continue;
+//!   if (i === 2) break outer;
+//!   if (done) return i;
+//!   fns.push(() => i);
+//! }
+//! // ->
+//! var _loop = function (i) {
+//!   if (i === 1) return;
+//!   if (i === 2) return 1;
+//!   if (done) return { v: i };
+//!   fns.push(() => i);
+//! }, _ret;
+//! outer: for (var i = 0; i < 3; i++) {
+//!   _ret = _loop(i);
+//!   if (_ret === 1) break;
+//!   if (_ret) return _ret.v;
+//! }
+//! ```
+//!
+//! * `break` and `continue` targeting an enclosing loop return `"break|label"` or
+//!   `"continue|label"`, which is checked after the call.
+//! * A binding of the loop head assigned to in the body is passed as a renamed parameter, and its
+//!   value copied back to the loop head at the end of each iteration, so the loop's update sees it.
+//! * `this` and `arguments` refer to copies made before the loop.
+//! * `var` declarations in the body are hoisted out of the function.
+//! * The function is a generator if the body contains `yield`, and async if it contains `await`.
+
+use std::cell::Cell;
+
+use oxc_allocator::{Address, ArenaBox, ArenaVec, GetAddress, TakeIn};
+use oxc_ast::{ast::*, builder::NONE};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ReferenceId, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN, Span};
+use oxc_str::{Ident, Str, static_ident};
+use oxc_traverse::{Ancestor, BoundIdentifier};
+
+use crate::context::TraverseCtx;
+
+use super::BlockScoping;
+
+/// A loop whose body is being moved into a function.
+pub(super) struct WrappedLoop<'a> {
+    body_scope_id: ScopeId,
+    params: Vec<LoopParam<'a>>,
+}
+
+/// A binding of the loop head, passed to the function as a parameter.
+struct LoopParam<'a> {
+    /// The binding in the loop head.
+    outer_symbol_id: SymbolId,
+    /// The parameter.
+    inner: BoundIdentifier<'a>,
+    /// Whether the body assigns to the binding, so the parameter must be copied back to it.
+    write_back: bool,
+}
+
+impl<'a> BlockScoping<'a> {
+    /// Decide whether the body of a loop needs to be moved into a function, before it is visited.
+    ///
+    /// `head_scope_id` is the scope of the loop head, for `for` loops.
+    pub(super) fn enter_loop(
+        &mut self,
+        head_scope_id: Option<ScopeId>,
+        body: &mut Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut head_symbol_ids = head_scope_id.map_or_else(Vec::new, |scope_id| {
+            ctx.scoping()
+                .iter_bindings_in(scope_id)
+                .filter(|&symbol_id| Self::is_block_scoped_variable(symbol_id, ctx))
+                .collect::<Vec<_>>()
+        });
+        head_symbol_ids.sort_unstable();
+
+        let mut collector = LoopBodyCollector::default();
+        collector.visit_statement(body);
+        let is_captured = head_symbol_ids
+            .iter()
+            .chain(&collector.declared_symbol_ids)
+            .any(|&symbol_id| Self::is_captured(symbol_id, ctx));
+        if !is_captured {
+            return;
+        }
+        if self.options.throw_if_closure_required {
+            ctx.state.error(
+                OxcDiagnostic::error(
+                    "Compiling let/const in this block would add a closure (throwIfClosureRequired).",
+                )
+                .with_label(body.span()),
+            );
+            return;
+        }
+        if collector.has_super_or_new_target {
+            ctx.state.error(
+                OxcDiagnostic::error(
+                    "Cannot move a loop body containing `super` or `new.target` into a function.",
+                )
+                .with_label(body.span()),
+            );
+            return;
+        }
+
+        let parent_scope_id = head_scope_id.unwrap_or_else(|| ctx.current_scope_id());
+        let body_scope_id = Self::ensure_block(body, parent_scope_id, ctx);
+        // The function is inserted before the loop, in the statement list containing it
+        let function_parent_scope_id = Self::containing_scope_id(ctx);
+        let scoping = ctx.scoping_mut();
+        let flags = scoping.scope_flags_mut(body_scope_id);
+        *flags = (*flags & ScopeFlags::StrictMode) | ScopeFlags::Function;
+        scoping.change_scope_parent_id(body_scope_id, Some(function_parent_scope_id));
+        self.wrapped_body_scopes.insert(body_scope_id);
+
+        let params = head_symbol_ids
+            .into_iter()
+            .filter_map(|symbol_id| {
+                self.create_loop_param(symbol_id, body_scope_id, &collector.reference_ids, ctx)
+            })
+            .collect();
+        self.wrapped_loops.push(WrappedLoop { body_scope_id, params });
+    }
+
+    /// Move the body of a loop into a function, after it has been visited.
+    ///
+    /// `body_parent_scope_id` is the scope the loop's body is in.
+    pub(super) fn exit_loop(
+        &mut self,
+        body_parent_scope_id: ScopeId,
+        body: &mut Statement<'a>,
+        address: Address,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Statement::BlockStatement(block) = body else { return };
+        if self.wrapped_loops.last().is_none_or(|wrapped| wrapped.body_scope_id != block.scope_id())
+        {
+            return;
+        }
+        let WrappedLoop { body_scope_id, params } = self.wrapped_loops.pop().unwrap();
+        self.wrapped_body_scopes.remove(&body_scope_id);
+
+        let function_parent_scope_id = ctx.scoping().scope_parent_id(body_scope_id).unwrap();
+        let outer_var_scope_id = Self::var_scope_id(function_parent_scope_id, ctx);
+        let mut own_labels = vec![];
+        for ancestor in ctx.ancestors() {
+            let Ancestor::LabeledStatementBody(labeled) = ancestor else { break };
+            own_labels.push(labeled.label().name);
+        }
+        let write_backs = params
+            .iter()
+            .filter(|param| param.write_back)
+            .map(|param| {
+                let name = Self::symbol_name(param.outer_symbol_id, ctx);
+                (BoundIdentifier::new(name, param.outer_symbol_id), param.inner.clone())
+            })
+            .collect::<Vec<_>>();
+
+        let mut transformer = LoopBodyTransformer {
+            body_scope_id,
+            outer_var_scope_id,
+            own_labels,
+            inner_labels: vec![],
+            write_backs: &write_backs,
+            loop_depth: 0,
+            switch_depth: 0,
+            arrow_depth: 0,
+            scope_ids: vec![body_scope_id],
+            has_break: false,
+            has_return: false,
+            has_yield: false,
+            has_await: false,
+            outer_jumps: vec![],
+            this_binding: None,
+            arguments_binding: None,
+            hoisted_vars: vec![],
+            ctx,
+        };
+        transformer.visit_statements(&mut block.body);
+        let LoopBodyTransformer {
+            has_break,
+            has_return,
+            has_yield,
+            has_await,
+            outer_jumps,
+            this_binding,
+            arguments_binding,
+            hoisted_vars,
+            ..
+        } = transformer;
+
+        // Copy back assigned bindings at the end of the iteration
+        for (outer, inner) in &write_backs {
+            block.body.push(Self::create_write_back(outer, inner, ctx));
+        }
+
+        // `var _this = this, x, _loop = function (i) { ... }, _ret;`
+        let mut declarations = ArenaVec::new_in(ctx);
+        if let Some(this_binding) = &this_binding {
+            let this = Expression::new_this_expression(SPAN, ctx);
+            declarations.push(Self::create_var_declarator(this_binding, Some(this), ctx));
+        }
+        if let Some(arguments_binding) = &arguments_binding {
+            let arguments = ctx.create_unbound_ident_expr(
+                SPAN,
+                static_ident!("arguments"),
+                ReferenceFlags::Read,
+            );
+            declarations.push(Self::create_var_declarator(arguments_binding, Some(arguments), ctx));
+        }
+        for (span, binding) in &hoisted_vars {
+            let id = BindingPattern::BindingIdentifier(ctx.alloc(
+                BindingIdentifier::new_with_symbol_id(*span, binding.name, binding.symbol_id, ctx),
+            ));
+            declarations.push(VariableDeclarator::new(
+                SPAN,
+                VariableDeclarationKind::Var,
+                id,
+                NONE,
+                None,
+                false,
+                ctx,
+            ));
+        }
+
+        let mut formal_params = ArenaVec::with_capacity_in(params.len(), ctx);
+        let mut arguments = ArenaVec::with_capacity_in(params.len(), ctx);
+        for param in &params {
+            let pattern = param.inner.create_binding_pattern(ctx);
+            formal_params.push(FormalParameter::new_plain(SPAN, pattern, ctx));
+            let name = Self::symbol_name(param.outer_symbol_id, ctx);
+            let value =
+                BoundIdentifier::new(name, param.outer_symbol_id).create_read_expression(ctx);
+            arguments.push(Argument::from(value));
+        }
+        let formal_params = FormalParameters::boxed(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            formal_params,
+            NONE,
+            ctx,
+        );
+        let function_body =
+            FunctionBody::boxed(SPAN, ArenaVec::new_in(ctx), block.body.take_in(ctx), ctx);
+        let function = Function::boxed_with_scope_id(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            has_yield,
+            has_await,
+            false,
+            NONE,
+            NONE,
+            formal_params,
+            NONE,
+            Some(function_body),
+            body_scope_id,
+            ctx,
+        );
+        let loop_binding =
+            ctx.generate_uid("loop", outer_var_scope_id, SymbolFlags::FunctionScopedVariable);
+        declarations.push(Self::create_var_declarator(
+            &loop_binding,
+            Some(Expression::FunctionExpression(function)),
+            ctx,
+        ));
+        let ret_binding = (has_break || has_return || !outer_jumps.is_empty()).then(|| {
+            ctx.generate_uid("ret", outer_var_scope_id, SymbolFlags::FunctionScopedVariable)
+        });
+        if let Some(ret_binding) = &ret_binding {
+            declarations.push(Self::create_var_declarator(ret_binding, None, ctx));
+        }
+        let declaration = Statement::new_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+            ctx,
+        );
+        let address = Self::containing_statement_address(address, ctx);
+        ctx.state.statement_injector.insert_before(&address, declaration);
+
+        // `_ret = _loop(i); if (_ret === 1) break; if (_ret) return _ret.v;`
+        let mut call = Expression::new_call_expression(
+            SPAN,
+            loop_binding.create_read_expression(ctx),
+            NONE,
+            arguments,
+            false,
+            ctx,
+        );
+        if has_yield {
+            call = Expression::new_yield_expression(SPAN, true, Some(call), ctx);
+        } else if has_await {
+            call = Expression::new_await_expression(SPAN, call, ctx);
+        }
+        let mut stmts = ArenaVec::new_in(ctx);
+        if let Some(ret_binding) = ret_binding {
+            let assignment = Expression::new_assignment_expression(
+                SPAN,
+                AssignmentOperator::Assign,
+                ret_binding.create_write_target(ctx),
+                call,
+                ctx,
+            );
+            stmts.push(Statement::new_expression_statement(SPAN, assignment, ctx));
+            if has_break {
+                let value =
+                    Expression::new_numeric_literal(SPAN, 1.0, None, NumberBase::Decimal, ctx);
+                let jump = Statement::new_break_statement(SPAN, None, ctx);
+                stmts.push(Self::create_ret_check(&ret_binding, value, jump, ctx));
+            }
+            for (is_continue, label) in outer_jumps {
+                let value = Expression::new_string_literal(
+                    SPAN,
+                    Self::jump_value(is_continue, label, ctx),
+                    None,
+                    ctx,
+                );
+                let label = Some(LabelIdentifier::new(SPAN, label, ctx));
+                let jump = if is_continue {
+                    Statement::new_continue_statement(SPAN, label, ctx)
+                } else {
+                    Statement::new_break_statement(SPAN, label, ctx)
+                };
+                stmts.push(Self::create_ret_check(&ret_binding, value, jump, ctx));
+            }
+            if has_return {
+                let value = Expression::new_static_member_expression(
+                    SPAN,
+                    ret_binding.create_read_expression(ctx),
+                    IdentifierName::new(SPAN, "v", ctx),
+                    false,
+                    ctx,
+                );
+                let ret = Statement::new_return_statement(SPAN, Some(value), ctx);
+                let test = ret_binding.create_read_expression(ctx);
+                stmts.push(Statement::new_if_statement(SPAN, test, ret, None, ctx));
+            }
+        } else {
+            stmts.push(Statement::new_expression_statement(SPAN, call, ctx));
+        }
+        let scope_id = ctx.create_child_scope(body_parent_scope_id, ScopeFlags::empty());
+        *body = Statement::new_block_statement_with_scope_id(SPAN, stmts, scope_id, ctx);
+    }
+
+    /// Wrap a statement in a block, if it is not one already. Returns the scope of the block.
+    pub(super) fn ensure_block(
+        body: &mut Statement<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ScopeId {
+        if let Statement::BlockStatement(block) = body {
+            return block.scope_id();
+        }
+        let scope_id = ctx.insert_scope_below_statement_from_scope_id(
+            body,
+            parent_scope_id,
+            ScopeFlags::empty(),
+        );
+        let stmt = body.take_in(ctx);
+        let span = stmt.span();
+        *body = Statement::new_block_statement_with_scope_id(
+            span,
+            ArenaVec::from_value_in(stmt, ctx),
+            scope_id,
+            ctx,
+        );
+        scope_id
+    }
+
+    /// Whether a binding is referenced in a closure, without the closure declaring it.
+    fn is_captured(symbol_id: SymbolId, ctx: &TraverseCtx<'a>) -> bool {
+        let scoping = ctx.scoping();
+        let symbol_scope_id = scoping.symbol_scope_id(symbol_id);
+        scoping.get_resolved_reference_ids(symbol_id).iter().any(|&reference_id| {
+            let reference_scope_id = scoping.get_reference(reference_id).scope_id();
+            scoping
+                .scope_ancestors(reference_scope_id)
+                .take_while(|&scope_id| scope_id != symbol_scope_id)
+                .any(|scope_id| scoping.scope_flags(scope_id).is_function())
+        })
+    }
+
+    /// Create the parameter for a binding of the loop head, if the body references it, and
+    /// point the body's references to it.
+    fn create_loop_param(
+        &mut self,
+        symbol_id: SymbolId,
+        body_scope_id: ScopeId,
+        reference_ids: &[ReferenceId],
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<LoopParam<'a>> {
+        let scoping = ctx.scoping();
+        let body_reference_ids = reference_ids
+            .iter()
+            .copied()
+            .filter(|&reference_id| {
+                scoping.get_reference(reference_id).symbol_id() == Some(symbol_id)
+            })
+            .collect::<Vec<_>>();
+        if body_reference_ids.is_empty() {
+            return None;
+        }
+        let write_back = body_reference_ids
+            .iter()
+            .any(|&reference_id| scoping.get_reference(reference_id).is_write());
+        let is_const = scoping.symbol_flags(symbol_id).is_const_variable();
+        let name = Self::symbol_name(symbol_id, ctx);
+        let inner = if write_back || ctx.scoping().scope_has_binding(body_scope_id, name) {
+            ctx.generate_uid(&name, body_scope_id, SymbolFlags::FunctionScopedVariable)
+        } else {
+            ctx.generate_binding(name, body_scope_id, SymbolFlags::FunctionScopedVariable)
+        };
+        if inner.name != name {
+            self.renamed_symbols.insert(inner.symbol_id, inner.name);
+        }
+        if is_const {
+            self.const_symbols.insert(inner.symbol_id);
+        }
+
+        let scoping = ctx.scoping_mut();
+        for reference_id in body_reference_ids {
+            scoping.get_reference_mut(reference_id).set_symbol_id(inner.symbol_id);
+            scoping.delete_resolved_reference(symbol_id, reference_id);
+            scoping.add_resolved_reference(inner.symbol_id, reference_id);
+        }
+        Some(LoopParam { outer_symbol_id: symbol_id, inner, write_back })
+    }
+
+    /// The scope of the statement list the current loop is in, through any statements which
+    /// contain it without a block, such as labels or the body of another loop.
+    fn containing_scope_id(ctx: &TraverseCtx<'a>) -> ScopeId {
+        let mut scope_id = ctx.current_scope_id();
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::ForStatementBody(_)
+                | Ancestor::ForInStatementBody(_)
+                | Ancestor::ForOfStatementBody(_)
+                | Ancestor::WithStatementBody(_) => {
+                    scope_id = ctx.scoping().scope_parent_id(scope_id).unwrap();
+                }
+                ancestor if Self::is_nested_statement(ancestor) => {}
+                _ => break,
+            }
+        }
+        scope_id
+    }
+
+    /// The address of the statement in a statement list which contains the current loop.
+    fn containing_statement_address(address: Address, ctx: &TraverseCtx<'a>) -> Address {
+        let mut address = address;
+        for ancestor in ctx.ancestors() {
+            if !Self::is_nested_statement(ancestor) {
+                break;
+            }
+            address = ancestor.address();
+        }
+        address
+    }
+
+    /// Whether the ancestor is a statement containing another statement outside a statement list.
+    fn is_nested_statement(ancestor: Ancestor<'a, '_>) -> bool {
+        matches!(
+            ancestor,
+            Ancestor::LabeledStatementBody(_)
+                | Ancestor::IfStatementConsequent(_)
+                | Ancestor::IfStatementAlternate(_)
+                | Ancestor::ForStatementBody(_)
+                | Ancestor::ForInStatementBody(_)
+                | Ancestor::ForOfStatementBody(_)
+                | Ancestor::WhileStatementBody(_)
+                | Ancestor::DoWhileStatementBody(_)
+                | Ancestor::WithStatementBody(_)
+        )
+    }
+
+    /// `outer = inner;`
+    fn create_write_back(
+        outer: &BoundIdentifier<'a>,
+        inner: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let assignment = Expression::new_assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            outer.create_write_target(ctx),
+            inner.create_read_expression(ctx),
+            ctx,
+        );
+        Statement::new_expression_statement(SPAN, assignment, ctx)
+    }
+
+    fn create_var_declarator(
+        binding: &BoundIdentifier<'a>,
+        init: Option<Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> VariableDeclarator<'a> {
+        VariableDeclarator::new(
+            SPAN,
+            VariableDeclarationKind::Var,
+            binding.create_binding_pattern(ctx),
+            NONE,
+            init,
+            false,
+            ctx,
+        )
+    }
+
+    /// `if (_ret === value) jump;`
+    fn create_ret_check(
+        ret_binding: &BoundIdentifier<'a>,
+        value: Expression<'a>,
+        jump: Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let test = Expression::new_binary_expression(
+            SPAN,
+            ret_binding.create_read_expression(ctx),
+            BinaryOperator::StrictEquality,
+            value,
+            ctx,
+        );
+        Statement::new_if_statement(SPAN, test, jump, None, ctx)
+    }
+
+    /// `"break|label"` or `"continue|label"`
+    fn jump_value(is_continue: bool, label: Ident<'a>, ctx: &TraverseCtx<'a>) -> Str<'a> {
+        let kind = if is_continue { "continue|" } else { "break|" };
+        Str::from_strs_array_in([kind, label.as_str()], ctx)
+    }
+}
+
+/// Collects what [`BlockScoping::enter_loop`] needs to know about a loop body.
+#[derive(Default)]
+struct LoopBodyCollector {
+    /// `let` / `const` bindings declared for each iteration of the loop.
+    declared_symbol_ids: Vec<SymbolId>,
+    /// All references in the body.
+    reference_ids: Vec<ReferenceId>,
+    has_super_or_new_target: bool,
+    /// Depth of non-arrow functions and classes.
+    function_depth: u32,
+    /// Depth of nested loops, whose bindings belong to their own iterations.
+    loop_depth: u32,
+}
+
+impl<'a> Visit<'a> for LoopBodyCollector {
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if decl.kind.is_lexical() && self.function_depth == 0 && self.loop_depth == 0 {
+            decl.bound_names(&mut |ident| self.declared_symbol_ids.push(ident.symbol_id()));
+        }
+        walk::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.reference_ids.push(ident.reference_id());
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        if self.function_depth == 0 {
+            self.has_super_or_new_target = true;
+        }
+    }
+
+    fn visit_meta_property(&mut self, meta: &MetaProperty<'a>) {
+        if self.function_depth == 0 && meta.meta.name == "new" {
+            self.has_super_or_new_target = true;
+        }
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        walk::walk_function(self, func, flags);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        self.function_depth += 1;
+        walk::walk_class(self, class);
+        self.function_depth -= 1;
+    }
+
+    fn visit_for_statement(&mut self, stmt: &ForStatement<'a>) {
+        self.loop_depth += 1;
+        walk::walk_for_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &ForInStatement<'a>) {
+        self.loop_depth += 1;
+        walk::walk_for_in_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        self.loop_depth += 1;
+        walk::walk_for_of_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_while_statement(&mut self, stmt: &WhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk::walk_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &DoWhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk::walk_do_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+}
+
+/// Where a `break` or `continue` jumps to.
+enum JumpTarget<'a> {
+    /// A statement inside the loop body.
+    Inner,
+    /// The loop itself.
+    Own,
+    /// A statement enclosing the loop, by label.
+    Outer(Ident<'a>),
+}
+
+/// Rewrites a loop body to be the body of a function.
+///
+/// Non-arrow functions and classes are not visited, as `this`, `arguments`, `return` and `var`
+/// inside them are their own.
+struct LoopBodyTransformer<'a, 'ctx> {
+    ctx: &'ctx mut TraverseCtx<'a>,
+    body_scope_id: ScopeId,
+    /// Scope to declare `_this`, `_arguments` and the hoisted `var`s in.
+    outer_var_scope_id: ScopeId,
+    /// Labels of the loop.
+    own_labels: Vec<Ident<'a>>,
+    /// Labels of statements in the body enclosing the current node.
+    inner_labels: Vec<Ident<'a>>,
+    /// Bindings of the loop head to copy back before `continue`.
+    write_backs: &'ctx [(BoundIdentifier<'a>, BoundIdentifier<'a>)],
+    /// Depth of loops in the body enclosing the current node.
+    loop_depth: u32,
+    /// Depth of `switch` statements in the body enclosing the current node.
+    switch_depth: u32,
+    /// Depth of arrow functions in the body enclosing the current node.
+    arrow_depth: u32,
+    /// Scopes enclosing the current node, innermost last.
+    scope_ids: Vec<ScopeId>,
+    has_break: bool,
+    has_return: bool,
+    has_yield: bool,
+    has_await: bool,
+    /// `break` / `continue` statements targeting an enclosing statement, as `(is_continue, label)`.
+    outer_jumps: Vec<(bool, Ident<'a>)>,
+    this_binding: Option<BoundIdentifier<'a>>,
+    arguments_binding: Option<BoundIdentifier<'a>>,
+    /// `var` bindings declared in the body, with the span of their first declaration.
+    hoisted_vars: Vec<(Span, BoundIdentifier<'a>)>,
+}
+
+impl<'a> VisitMut<'a> for LoopBodyTransformer<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.scope_ids.push(scope_id.get().unwrap());
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_ids.pop();
+    }
+
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        if self.arrow_depth == 0 {
+            // `var x;` -> removed, `var x = 1;` -> `x = 1;`
+            stmts.retain_mut(|stmt| {
+                let Statement::VariableDeclaration(decl) = stmt else { return true };
+                if !self.is_hoisted(decl) {
+                    return true;
+                }
+                let Some(expr) = self.hoist_declaration(decl) else { return false };
+                *stmt = Statement::new_expression_statement(decl.span, expr, self.ctx);
+                true
+            });
+        }
+        walk_mut::walk_statements(self, stmts);
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        match stmt {
+            Statement::BreakStatement(brk) => {
+                let span = brk.span;
+                match self.jump_target(brk.label.as_ref(), false) {
+                    JumpTarget::Inner => {}
+                    JumpTarget::Own => {
+                        // `break` -> `return 1`
+                        self.has_break = true;
+                        let value = Expression::new_numeric_literal(
+                            SPAN,
+                            1.0,
+                            None,
+                            NumberBase::Decimal,
+                            self.ctx,
+                        );
+                        *stmt = Statement::new_return_statement(span, Some(value), self.ctx);
+                    }
+                    JumpTarget::Outer(label) => *stmt = self.create_outer_jump(span, false, label),
+                }
+            }
+            Statement::ContinueStatement(cont) => {
+                let span = cont.span;
+                match self.jump_target(cont.label.as_ref(), true) {
+                    JumpTarget::Inner => {}
+                    JumpTarget::Own => *stmt = self.create_continue(span),
+                    JumpTarget::Outer(label) => *stmt = self.create_outer_jump(span, true, label),
+                }
+            }
+            Statement::ReturnStatement(ret) if self.arrow_depth == 0 => {
+                // `return x` -> `return { v: x }`
+                if let Some(argument) = &mut ret.argument {
+                    self.visit_expression(argument);
+                }
+                self.has_return = true;
+                let value =
+                    ret.argument.take().unwrap_or_else(|| Expression::new_void_0(SPAN, self.ctx));
+                let property = ObjectPropertyKind::new_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    PropertyKey::new_static_identifier(SPAN, "v", self.ctx),
+                    value,
+                    false,
+                    false,
+                    false,
+                    self.ctx,
+                );
+                ret.argument = Some(Expression::new_object_expression(
+                    SPAN,
+                    ArenaVec::from_value_in(property, self.ctx),
+                    self.ctx,
+                ));
+            }
+            // `var` which is not directly in a statement list, e.g. `if (x) var y = 1;`
+            Statement::VariableDeclaration(decl)
+                if self.arrow_depth == 0 && self.is_hoisted(decl) =>
+            {
+                let span = decl.span;
+                *stmt = match self.hoist_declaration(decl) {
+                    Some(expr) => Statement::new_expression_statement(span, expr, self.ctx),
+                    None => Statement::new_empty_statement(span, self.ctx),
+                };
+                walk_mut::walk_statement(self, stmt);
+            }
+            _ => walk_mut::walk_statement(self, stmt),
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ThisExpression(this) => {
+                let span = this.span;
+                let binding = self.this_binding.get_or_insert_with(|| {
+                    self.ctx.generate_uid(
+                        "this",
+                        self.outer_var_scope_id,
+                        SymbolFlags::FunctionScopedVariable,
+                    )
+                });
+                *expr = binding.create_spanned_read_expression(span, self.ctx);
+                return;
+            }
+            Expression::Identifier(ident)
+                if ident.name == "arguments"
+                    && self
+                        .ctx
+                        .scoping()
+                        .get_reference(ident.reference_id())
+                        .symbol_id()
+                        .is_none() =>
+            {
+                let span = ident.span;
+                self.ctx.delete_reference_for_identifier(ident);
+                let binding = self.arguments_binding.get_or_insert_with(|| {
+                    self.ctx.generate_uid(
+                        "arguments",
+                        self.outer_var_scope_id,
+                        SymbolFlags::FunctionScopedVariable,
+                    )
+                });
+                *expr = binding.create_spanned_read_expression(span, self.ctx);
+                return;
+            }
+            Expression::YieldExpression(_) if self.arrow_depth == 0 => self.has_yield = true,
+            Expression::AwaitExpression(_) if self.arrow_depth == 0 => self.has_await = true,
+            _ => {}
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        // The heritage is evaluated outside the class
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_expression(super_class);
+        }
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.arrow_depth -= 1;
+    }
+
+    fn visit_labeled_statement(&mut self, stmt: &mut LabeledStatement<'a>) {
+        self.inner_labels.push(stmt.label.name);
+        walk_mut::walk_labeled_statement(self, stmt);
+        self.inner_labels.pop();
+    }
+
+    fn visit_switch_statement(&mut self, stmt: &mut SwitchStatement<'a>) {
+        self.switch_depth += 1;
+        walk_mut::walk_switch_statement(self, stmt);
+        self.switch_depth -= 1;
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        if self.arrow_depth == 0
+            && let Some(ForStatementInit::VariableDeclaration(decl)) = &mut stmt.init
+            && self.is_hoisted(decl)
+        {
+            let init = self.hoist_declaration(decl);
+            stmt.init = init.map(ForStatementInit::from);
+        }
+        self.loop_depth += 1;
+        walk_mut::walk_for_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        self.hoist_for_in_of_left(&mut stmt.left);
+        self.loop_depth += 1;
+        walk_mut::walk_for_in_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        if stmt.r#await && self.arrow_depth == 0 {
+            self.has_await = true;
+        }
+        self.hoist_for_in_of_left(&mut stmt.left);
+        self.loop_depth += 1;
+        walk_mut::walk_for_of_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_while_statement(&mut self, stmt: &mut WhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk_mut::walk_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &mut DoWhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk_mut::walk_do_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+}
+
+impl<'a> LoopBodyTransformer<'a, '_> {
+    fn jump_target(
+        &self,
+        label: Option<&LabelIdentifier<'a>>,
+        is_continue: bool,
+    ) -> JumpTarget<'a> {
+        match label {
+            None => {
+                // `continue` skips `switch` statements
+                if self.loop_depth > 0 || (!is_continue && self.switch_depth > 0) {
+                    JumpTarget::Inner
+                } else {
+                    JumpTarget::Own
+                }
+            }
+            Some(label) if self.inner_labels.contains(&label.name) => JumpTarget::Inner,
+            Some(label) if self.own_labels.contains(&label.name) => JumpTarget::Own,
+            Some(label) => JumpTarget::Outer(label.name),
+        }
+    }
+
+    /// `continue` -> `return`, after copying back assigned bindings of the loop head.
+    fn create_continue(&mut self, span: Span) -> Statement<'a> {
+        let ret = Statement::new_return_statement(span, None, self.ctx);
+        if self.write_backs.is_empty() {
+            return ret;
+        }
+        let mut stmts = ArenaVec::with_capacity_in(self.write_backs.len() + 1, self.ctx);
+        for (outer, inner) in self.write_backs {
+            stmts.push(BlockScoping::create_write_back(outer, inner, self.ctx));
+        }
+        stmts.push(ret);
+        let parent_scope_id = *self.scope_ids.last().unwrap();
+        let scope_id = self.ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        Statement::new_block_statement_with_scope_id(SPAN, stmts, scope_id, self.ctx)
+    }
+
+    /// `break label` -> `return "break|label"`
+    fn create_outer_jump(
+        &mut self,
+        span: Span,
+        is_continue: bool,
+        label: Ident<'a>,
+    ) -> Statement<'a> {
+        if !self.outer_jumps.contains(&(is_continue, label)) {
+            self.outer_jumps.push((is_continue, label));
+        }
+        let value = Expression::new_string_literal(
+            SPAN,
+            BlockScoping::jump_value(is_continue, label, self.ctx),
+            None,
+            self.ctx,
+        );
+        Statement::new_return_statement(span, Some(value), self.ctx)
+    }
+
+    /// Whether a `var` declaration declares bindings of the enclosing function, rather than
+    /// bindings converted from `let` / `const` which belong to the loop body.
+    fn is_hoisted(&self, decl: &VariableDeclaration<'a>) -> bool {
+        let mut is_hoisted = false;
+        if decl.kind.is_var() {
+            decl.bound_names(&mut |ident| {
+                is_hoisted |=
+                    self.ctx.scoping().symbol_scope_id(ident.symbol_id()) != self.body_scope_id;
+            });
+        }
+        is_hoisted
+    }
+
+    /// Record the bindings of a `var` declaration to be declared outside the function, and convert
+    /// its initializers to assignments.
+    fn hoist_declaration(&mut self, decl: &mut VariableDeclaration<'a>) -> Option<Expression<'a>> {
+        let mut assignments = ArenaVec::new_in(self.ctx);
+        for declarator in &mut decl.declarations {
+            let Some(init) = declarator.init.take() else {
+                declarator.id.bound_names(&mut |ident| self.hoist_binding(ident));
+                continue;
+            };
+            let target = self.binding_to_assignment_target(declarator.id.take_in(self.ctx));
+            assignments.push(Expression::new_assignment_expression(
+                declarator.span,
+                AssignmentOperator::Assign,
+                target,
+                init,
+                self.ctx,
+            ));
+        }
+        match assignments.len() {
+            0 => None,
+            1 => assignments.pop(),
+            _ => Some(Expression::new_sequence_expression(decl.span, assignments, self.ctx)),
+        }
+    }
+
+    /// `for (var x of xs)` -> `for (x of xs)`
+    fn hoist_for_in_of_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        if self.arrow_depth > 0 {
+            return;
+        }
+        let ForStatementLeft::VariableDeclaration(decl) = left else { return };
+        if !self.is_hoisted(decl) {
+            return;
+        }
+        let [declarator] = decl.declarations.as_mut_slice() else { return };
+        if declarator.init.is_some() {
+            return;
+        }
+        let target = self.binding_to_assignment_target(declarator.id.take_in(self.ctx));
+        *left = ForStatementLeft::from(target);
+    }
+
+    fn hoist_binding(&mut self, ident: &BindingIdentifier<'a>) {
+        let symbol_id = ident.symbol_id();
+        if !self.hoisted_vars.iter().any(|(_, binding)| binding.symbol_id == symbol_id) {
+            self.hoisted_vars.push((ident.span, BoundIdentifier::new(ident.name, symbol_id)));
+        }
+    }
+
+    fn binding_to_assignment_target(
+        &mut self,
+        pattern: BindingPattern<'a>,
+    ) -> AssignmentTarget<'a> {
+        match pattern {
+            BindingPattern::BindingIdentifier(ident) => {
+                let reference = self.create_write_reference(&ident);
+                AssignmentTarget::AssignmentTargetIdentifier(self.ctx.alloc(reference))
+            }
+            BindingPattern::ArrayPattern(array) => {
+                let ArrayPattern { span, elements, rest, .. } = array.unbox();
+                let mut targets = ArenaVec::with_capacity_in(elements.len(), self.ctx);
+                for element in elements {
+                    targets.push(element.map(|element| self.binding_to_maybe_default(element)));
+                }
+                let rest = rest.map(|rest| self.binding_rest_to_target(rest.unbox()));
+                AssignmentTarget::new_array_assignment_target(span, targets, rest, self.ctx)
+            }
+            BindingPattern::ObjectPattern(object) => {
+                let ObjectPattern { span, properties, rest, .. } = object.unbox();
+                let mut targets = ArenaVec::with_capacity_in(properties.len(), self.ctx);
+                for property in properties {
+                    let BindingProperty { span, key, value, shorthand, computed, .. } = property;
+                    let target = match value {
+                        BindingPattern::BindingIdentifier(ident) if shorthand => {
+                            let binding = self.create_write_reference(&ident);
+                            AssignmentTargetProperty::new_assignment_target_property_identifier(
+                                span, binding, None, self.ctx,
+                            )
+                        }
+                        BindingPattern::AssignmentPattern(pattern)
+                            if shorthand
+                                && matches!(pattern.left, BindingPattern::BindingIdentifier(_)) =>
+                        {
+                            let AssignmentPattern { left, right, .. } = pattern.unbox();
+                            let BindingPattern::BindingIdentifier(ident) = left else {
+                                unreachable!()
+                            };
+                            let binding = self.create_write_reference(&ident);
+                            AssignmentTargetProperty::new_assignment_target_property_identifier(
+                                span,
+                                binding,
+                                Some(right),
+                                self.ctx,
+                            )
+                        }
+                        value => {
+                            let binding = self.binding_to_maybe_default(value);
+                            AssignmentTargetProperty::new_assignment_target_property_property(
+                                span, key, binding, computed, self.ctx,
+                            )
+                        }
+                    };
+                    targets.push(target);
+                }
+                let rest = rest.map(|rest| self.binding_rest_to_target(rest.unbox()));
+                AssignmentTarget::new_object_assignment_target(span, targets, rest, self.ctx)
+            }
+            BindingPattern::AssignmentPattern(pattern) => {
+                // Only valid as an element or property, handled by `binding_to_maybe_default`
+                self.binding_to_assignment_target(pattern.unbox().left)
+            }
+        }
+    }
+
+    fn binding_to_maybe_default(
+        &mut self,
+        pattern: BindingPattern<'a>,
+    ) -> AssignmentTargetMaybeDefault<'a> {
+        if let BindingPattern::AssignmentPattern(pattern) = pattern {
+            let AssignmentPattern { span, left, right, .. } = pattern.unbox();
+            let binding = self.binding_to_assignment_target(left);
+            AssignmentTargetMaybeDefault::new_assignment_target_with_default(
+                span, binding, right, self.ctx,
+            )
+        } else {
+            AssignmentTargetMaybeDefault::from(self.binding_to_assignment_target(pattern))
+        }
+    }
+
+    fn binding_rest_to_target(
+        &mut self,
+        rest: BindingRestElement<'a>,
+    ) -> ArenaBox<'a, AssignmentTargetRest<'a>> {
+        let target = self.binding_to_assignment_target(rest.argument);
+        AssignmentTargetRest::boxed(rest.span, target, self.ctx)
+    }
+
+    fn create_write_reference(&mut self, ident: &BindingIdentifier<'a>) -> IdentifierReference<'a> {
+        self.hoist_binding(ident);
+        self.ctx.create_bound_ident_reference(
+            ident.span,
+            ident.name,
+            ident.symbol_id(),
+            ReferenceFlags::Write,
+        )
+    }
+}
@@ -0,0 +1,438 @@
+//! ES2015: Block Scoping
+//!
+//! This plugin transforms `let` and `const` declarations to `var`.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! let a = 1;
+//! {
+//!   let a = 2;
+//!   const b = a;
+//!   b = 3;
+//! }
+//! for (let i = 0; i < 3; i++) {
+//!   fns.push(() => i);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = 1;
+//! {
+//!   var _a = 2;
+//!   var b = _a;
+//!   3, babelHelpers.readOnlyError("b");
+//! }
+//! var _loop = function (i) {
+//!   fns.push(() => i);
+//! };
+//! for (var i = 0; i < 3; i++) {
+//!   _loop(i);
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! When a scope is entered, its `let` / `const` bindings are moved to the enclosing function
+//! scope. A binding is renamed if that would make it clash with another binding of the same name
+//! in any scope between them or above, or shadow a global.
+//!
+//! Assignments to a binding declared with `const` are replaced with a call to the `readOnlyError`
+//! helper, which throws the `TypeError` the assignment would have thrown.
+//!
+//! A loop whose bindings are captured by a closure needs a fresh binding for each iteration, so its
+//! body is moved into a function called on each iteration. See [`loops`] for details.
+//!
+//! With the `tdz` option, reading or writing a binding before its declaration throws, as it would
+//! natively. See [`tdz`] for details.
+//!
+//! ## Options
+//!
+//! ### `tdz`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Insert checks for accesses to a binding in its temporal dead zone.
+//!
+//! ### `throwIfClosureRequired`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Report an error instead of moving a loop body into a function.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-block-scoping>
+//! * Let and const declarations: <https://tc39.es/ecma262/#sec-let-and-const-declarations>
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+
+use oxc_allocator::{CloneIn, GetAllocator, UnstableAddress};
+use oxc_ast::ast::*;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeId, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_str::Ident;
+use oxc_traverse::{Ancestor, Traverse};
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+mod const_violations;
+mod loops;
+mod tdz;
+
+use loops::WrappedLoop;
+use tdz::TdzState;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+/// Options for the ES2015 block-scoping transform.
+pub struct BlockScopingOptions {
+    /// Throw when a binding is accessed before its declaration.
+    pub tdz: bool,
+    /// Report an error instead of moving a loop body into a function.
+    pub throw_if_closure_required: bool,
+}
+
+/// Block scoping transform.
+///
+/// See [module docs] for details.
+///
+/// [module docs]: self
+pub struct BlockScoping<'a> {
+    options: BlockScopingOptions,
+    /// Bindings given a new name, either to avoid a clash once hoisted, or as a parameter of a
+    /// wrapped loop body.
+    renamed_symbols: FxHashMap<SymbolId, Ident<'a>>,
+    /// Bindings declared with `const`.
+    const_symbols: FxHashSet<SymbolId>,
+    /// Loops whose body is being moved into a function, innermost last.
+    wrapped_loops: Vec<WrappedLoop<'a>>,
+    /// Scopes of loop bodies which are being moved into a function.
+    wrapped_body_scopes: FxHashSet<ScopeId>,
+    /// State of the `tdz` option.
+    tdz: TdzState,
+    /// `true` if the classes transform is enabled, in which case class declarations become `var`
+    /// declarations and are hoisted along with `let` and `const`.
+    hoist_classes: bool,
+}
+
+impl BlockScoping<'_> {
+    pub fn new(options: BlockScopingOptions, hoist_classes: bool) -> Self {
+        Self {
+            options,
+            hoist_classes,
+            renamed_symbols: FxHashMap::default(),
+            const_symbols: FxHashSet::default(),
+            wrapped_loops: vec![],
+            wrapped_body_scopes: FxHashSet::default(),
+            tdz: TdzState::default(),
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for BlockScoping<'a> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_scope_bindings(program.scope_id(), ctx);
+        self.record_tdz_declarations(Some(program.scope_id()), &program.body);
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.insert_temporal_undefined(program.scope_id(), &mut program.body, ctx);
+    }
+
+    fn enter_function_body(&mut self, body: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = ctx.current_scope_id();
+        self.hoist_scope_bindings(scope_id, ctx);
+        self.record_tdz_declarations(Some(scope_id), &body.statements);
+    }
+
+    fn exit_function_body(&mut self, body: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.insert_temporal_undefined(ctx.current_scope_id(), &mut body.statements, ctx);
+    }
+
+    fn enter_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_scope_bindings(block.scope_id(), ctx);
+        self.record_tdz_declarations(Some(block.scope_id()), &block.body);
+    }
+
+    fn exit_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.insert_temporal_undefined(block.scope_id(), &mut block.body, ctx);
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_scope_bindings(block.scope_id(), ctx);
+        self.record_tdz_declarations(Some(block.scope_id()), &block.body);
+    }
+
+    fn exit_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.insert_temporal_undefined(block.scope_id(), &mut block.body, ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.hoist_scope_bindings(stmt.scope_id(), ctx);
+        // Bindings in a `switch` are not initialized to `temporalUndefined`, as a `case` can be
+        // jumped over
+        for case in &stmt.cases {
+            self.record_tdz_declarations(None, &case.consequent);
+        }
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.enter_loop(Some(stmt.scope_id()), &mut stmt.body, ctx);
+        self.hoist_scope_bindings(stmt.scope_id(), ctx);
+        if self.options.tdz
+            && let Some(ForStatementInit::VariableDeclaration(decl)) = &stmt.init
+        {
+            self.tdz.record_declaration(decl, None);
+        }
+    }
+
+    fn exit_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let address = stmt.unstable_address();
+        self.exit_loop(stmt.scope_id(), &mut stmt.body, address, ctx);
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.enter_loop(Some(stmt.scope_id()), &mut stmt.body, ctx);
+        self.hoist_scope_bindings(stmt.scope_id(), ctx);
+        self.transform_for_in_of_left(stmt.scope_id(), &mut stmt.left, &mut stmt.body, ctx);
+    }
+
+    fn exit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let address = stmt.unstable_address();
+        self.exit_loop(stmt.scope_id(), &mut stmt.body, address, ctx);
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.enter_loop(Some(stmt.scope_id()), &mut stmt.body, ctx);
+        self.hoist_scope_bindings(stmt.scope_id(), ctx);
+        self.transform_for_in_of_left(stmt.scope_id(), &mut stmt.left, &mut stmt.body, ctx);
+    }
+
+    fn exit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let address = stmt.unstable_address();
+        self.exit_loop(stmt.scope_id(), &mut stmt.body, address, ctx);
+    }
+
+    fn enter_while_statement(&mut self, stmt: &mut WhileStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.enter_loop(None, &mut stmt.body, ctx);
+    }
+
+    fn exit_while_statement(&mut self, stmt: &mut WhileStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let address = stmt.unstable_address();
+        self.exit_loop(ctx.current_scope_id(), &mut stmt.body, address, ctx);
+    }
+
+    fn enter_do_while_statement(
+        &mut self,
+        stmt: &mut DoWhileStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.enter_loop(None, &mut stmt.body, ctx);
+    }
+
+    fn exit_do_while_statement(
+        &mut self,
+        stmt: &mut DoWhileStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let address = stmt.unstable_address();
+        self.exit_loop(ctx.current_scope_id(), &mut stmt.body, address, ctx);
+    }
+
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.transform_variable_declaration(decl, ctx);
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(symbol_id) = ident.symbol_id.get()
+            && let Some(&name) = self.renamed_symbols.get(&symbol_id)
+        {
+            ident.name = name;
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        if let Some(symbol_id) = ctx.scoping().get_reference(reference_id).symbol_id()
+            && let Some(&name) = self.renamed_symbols.get(&symbol_id)
+        {
+            ident.name = name;
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.transform_tdz_access(expr, ctx);
+        self.transform_const_violation(expr, ctx);
+    }
+}
+
+impl<'a> BlockScoping<'a> {
+    /// Move the `let` / `const` bindings of a scope to the enclosing function scope.
+    fn hoist_scope_bindings(&mut self, scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        let mut symbol_ids = ctx
+            .scoping()
+            .iter_bindings_in(scope_id)
+            .filter(|&symbol_id| self.is_hoisted_binding(symbol_id, ctx))
+            .collect::<Vec<_>>();
+        if symbol_ids.is_empty() {
+            return;
+        }
+        // Bindings are stored in a hash map. Sort them so new names are assigned in source order.
+        symbol_ids.sort_unstable();
+        let var_scope_id = Self::var_scope_id(scope_id, ctx);
+        for symbol_id in symbol_ids {
+            self.hoist_binding(symbol_id, scope_id, var_scope_id, ctx);
+        }
+    }
+
+    fn hoist_binding(
+        &mut self,
+        symbol_id: SymbolId,
+        scope_id: ScopeId,
+        var_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let flags = ctx.scoping_mut().symbol_flags_mut(symbol_id);
+        if flags.is_const_variable() {
+            self.const_symbols.insert(symbol_id);
+        }
+        flags.remove(
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable | SymbolFlags::Class,
+        );
+        flags.insert(SymbolFlags::FunctionScopedVariable);
+
+        if scope_id == var_scope_id {
+            return;
+        }
+
+        let name = Self::symbol_name(symbol_id, ctx);
+        let scoping = ctx.scoping();
+        let parent_scope_id = scoping.scope_parent_id(scope_id).unwrap_or(var_scope_id);
+        let clashes = scoping
+            .scope_ancestors(parent_scope_id)
+            .any(|scope_id| scoping.scope_has_binding(scope_id, name))
+            || scoping.root_unresolved_references().contains_key(name.as_str());
+        if clashes {
+            let new_name = ctx.generate_uid_name(&name);
+            ctx.scoping_mut().rename_symbol(symbol_id, scope_id, new_name);
+            self.renamed_symbols.insert(symbol_id, new_name);
+        }
+        ctx.scoping_mut().move_binding_by_symbol_id(scope_id, var_scope_id, symbol_id);
+    }
+
+    /// Convert a `let` / `const` declaration to `var`.
+    ///
+    /// A `let` without an initializer inside a loop body is initialized to `undefined`, as otherwise
+    /// it would keep its value from the previous iteration.
+    fn transform_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !matches!(decl.kind, VariableDeclarationKind::Let | VariableDeclarationKind::Const) {
+            return;
+        }
+        decl.kind = VariableDeclarationKind::Var;
+
+        // Declarations created by other transforms after their scope was entered
+        decl.bound_names(&mut |ident| {
+            let symbol_id = ident.symbol_id();
+            if Self::is_block_scoped_variable(symbol_id, ctx) {
+                let scope_id = ctx.scoping().symbol_scope_id(symbol_id);
+                let var_scope_id = Self::var_scope_id(scope_id, ctx);
+                self.hoist_binding(symbol_id, scope_id, var_scope_id, ctx);
+            }
+        });
+
+        let is_loop_head = matches!(
+            ctx.parent(),
+            Ancestor::ForStatementInit(_)
+                | Ancestor::ForInStatementLeft(_)
+                | Ancestor::ForOfStatementLeft(_)
+        );
+        let needs_init = !is_loop_head && (self.options.tdz || self.is_in_loop_body(ctx));
+        for declarator in &mut decl.declarations {
+            declarator.kind = VariableDeclarationKind::Var;
+            if needs_init && declarator.init.is_none() {
+                declarator.init = Some(Expression::new_void_0(SPAN, ctx));
+            }
+        }
+    }
+
+    /// Whether the current node is inside the body of a loop which is not being moved into a
+    /// function, without a function boundary in between.
+    fn is_in_loop_body(&self, ctx: &TraverseCtx<'a>) -> bool {
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::ForStatementBody(_)
+                | Ancestor::ForInStatementBody(_)
+                | Ancestor::ForOfStatementBody(_)
+                | Ancestor::WhileStatementBody(_)
+                | Ancestor::DoWhileStatementBody(_) => return true,
+                Ancestor::BlockStatementBody(block)
+                    if block
+                        .scope_id()
+                        .get()
+                        .is_some_and(|scope_id| self.wrapped_body_scopes.contains(&scope_id)) =>
+                {
+                    return false;
+                }
+                Ancestor::FunctionBody(_)
+                | Ancestor::ArrowFunctionExpressionBody(_)
+                | Ancestor::StaticBlockBody(_) => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+
+    /// The current name of a symbol.
+    fn symbol_name(symbol_id: SymbolId, ctx: &TraverseCtx<'a>) -> Ident<'a> {
+        ctx.scoping().symbol_ident(symbol_id).clone_in(ctx.allocator())
+    }
+
+    fn is_block_scoped_variable(symbol_id: SymbolId, ctx: &TraverseCtx<'a>) -> bool {
+        ctx.scoping().symbol_flags(symbol_id).contains(SymbolFlags::BlockScopedVariable)
+    }
+
+    /// Whether a binding is moved to the enclosing function scope.
+    fn is_hoisted_binding(&self, symbol_id: SymbolId, ctx: &TraverseCtx<'a>) -> bool {
+        let flags = ctx.scoping().symbol_flags(symbol_id);
+        flags.contains(SymbolFlags::BlockScopedVariable)
+            || (self.hoist_classes && flags.contains(SymbolFlags::Class))
+    }
+
+    /// The closest scope a `var` declared in `scope_id` belongs to.
+    ///
+    /// This includes the bodies of loops being moved into a function.
+    fn var_scope_id(scope_id: ScopeId, ctx: &TraverseCtx<'a>) -> ScopeId {
+        let scoping = ctx.scoping();
+        scoping
+            .scope_ancestors(scope_id)
+            .find(|&scope_id| scoping.scope_flags(scope_id).is_var())
+            .unwrap_or_else(|| scoping.root_scope_id())
+    }
+}
@@ -0,0 +1,280 @@
+//! Temporal dead zone checks, with the `tdz` option.
+//!
+//! An access which is certain to happen before the binding's declaration, because it precedes the
+//! declaration in the same function, is replaced with a call to the `tdz` helper, which throws:
+//!
+//! ```js
+//! x;
+//! let x = 1;
+//! // ->
+//! babelHelpers.tdz("x");
+//! var x = 1;
+//! ```
+//!
+//! A read inside a closure may happen either before or after the declaration, depending on when
+//! the closure is called. The binding starts out as `temporalUndefined`, and the read is checked
+//! with the `temporalRef` helper, which throws if the binding still holds that value:
+//!
+//! ```js
+//! function f() { return x; }
+//! f();
+//! let x = 1;
+//! // ->
+//! x = babelHelpers.temporalUndefined;
+//! function f() { return babelHelpers.temporalRef(x, "x"); }
+//! f();
+//! var x = 1;
+//! ```
+//!
+//! Function declarations are hoisted, so reads inside them are checked even after the declaration.
+//! Bindings declared in a `switch` or a loop head are only checked when the access is certain to
+//! be in the temporal dead zone.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{ArenaVec, TakeIn};
+use oxc_ast::ast::*;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ReferenceId, ScopeId, SymbolId};
+use oxc_span::SPAN;
+use oxc_str::Ident;
+use oxc_traverse::Ancestor;
+
+use crate::{
+    common::helper_loader::{Helper, helper_call_expr, helper_load},
+    context::TraverseCtx,
+};
+
+use super::BlockScoping;
+
+#[derive(Default)]
+pub(super) struct TdzState {
+    /// `let` / `const` declarations, with the end of the declaration, and the scope to initialize
+    /// the binding to `temporalUndefined` in.
+    declarations: FxHashMap<SymbolId, TdzDeclaration>,
+    /// Bindings to initialize to `temporalUndefined`, by the scope of the block declaring them.
+    temporal_undefined: FxHashMap<ScopeId, Vec<SymbolId>>,
+    /// References already wrapped in `temporalRef`.
+    checked_references: FxHashSet<ReferenceId>,
+}
+
+#[derive(Clone, Copy)]
+struct TdzDeclaration {
+    end: u32,
+    scope_id: Option<ScopeId>,
+}
+
+enum TdzStatus {
+    /// The access is after the declaration.
+    Outside,
+    /// The access is before the declaration.
+    Inside,
+    /// The access may be before the declaration.
+    Maybe,
+}
+
+impl TdzState {
+    pub(super) fn record_declaration(
+        &mut self,
+        decl: &VariableDeclaration<'_>,
+        scope_id: Option<ScopeId>,
+    ) {
+        if !decl.kind.is_lexical() {
+            return;
+        }
+        for declarator in &decl.declarations {
+            let declaration = TdzDeclaration { end: declarator.span.end, scope_id };
+            declarator.id.bound_names(&mut |ident| {
+                self.declarations.insert(ident.symbol_id(), declaration);
+            });
+        }
+    }
+}
+
+impl<'a> BlockScoping<'a> {
+    /// Record the `let` / `const` declarations of a block.
+    ///
+    /// `scope_id` is the scope of the block, if its bindings can be initialized to
+    /// `temporalUndefined` at its start.
+    pub(super) fn record_tdz_declarations(
+        &mut self,
+        scope_id: Option<ScopeId>,
+        stmts: &[Statement<'a>],
+    ) {
+        if !self.options.tdz {
+            return;
+        }
+        for stmt in stmts {
+            let decl = match stmt {
+                Statement::VariableDeclaration(decl) => decl,
+                Statement::ExportNamedDeclaration(export) => {
+                    let Some(Declaration::VariableDeclaration(decl)) = &export.declaration else {
+                        continue;
+                    };
+                    decl
+                }
+                _ => continue,
+            };
+            self.tdz.record_declaration(decl, scope_id);
+        }
+    }
+
+    /// Check an access to a binding in its temporal dead zone.
+    pub(super) fn transform_tdz_access(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !self.options.tdz {
+            return;
+        }
+        match expr {
+            Expression::Identifier(ident) => {
+                let Some((symbol_id, status)) = self.tdz_status(ident, ctx) else { return };
+                match status {
+                    TdzStatus::Outside => {}
+                    TdzStatus::Inside => {
+                        ctx.delete_reference_for_identifier(ident);
+                        *expr = Self::create_tdz_error(ident.name, ctx);
+                    }
+                    TdzStatus::Maybe => {
+                        let Some(scope_id) = self.tdz.declarations[&symbol_id].scope_id else {
+                            return;
+                        };
+                        if !self.tdz.checked_references.insert(ident.reference_id()) {
+                            return;
+                        }
+                        let symbols = self.tdz.temporal_undefined.entry(scope_id).or_default();
+                        if !symbols.contains(&symbol_id) {
+                            symbols.push(symbol_id);
+                        }
+                        // `babelHelpers.temporalRef(x, "x")`
+                        let name = ident.name;
+                        let arguments = ArenaVec::from_array_in(
+                            [
+                                Argument::from(expr.take_in(ctx)),
+                                Argument::from(Expression::new_string_literal(
+                                    SPAN, name, None, ctx,
+                                )),
+                            ],
+                            ctx,
+                        );
+                        *expr = helper_call_expr(Helper::TemporalRef, arguments, ctx);
+                    }
+                }
+            }
+            Expression::AssignmentExpression(assign) => {
+                let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left else {
+                    return;
+                };
+                let Some((_, TdzStatus::Inside)) = self.tdz_status(ident, ctx) else { return };
+                let name = ident.name;
+                ctx.delete_reference_for_identifier(ident);
+                let error = Self::create_tdz_error(name, ctx);
+                *expr = if assign.operator == AssignmentOperator::Assign {
+                    // `x = value` -> `value, babelHelpers.tdz("x")`
+                    let value = assign.right.take_in(ctx);
+                    Expression::new_sequence_expression(
+                        assign.span,
+                        ArenaVec::from_array_in([value, error], ctx),
+                        ctx,
+                    )
+                } else {
+                    // `x += value` -> `babelHelpers.tdz("x")`, as `x` is read first
+                    error
+                };
+            }
+            Expression::UpdateExpression(update) => {
+                let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &update.argument
+                else {
+                    return;
+                };
+                let Some((_, TdzStatus::Inside)) = self.tdz_status(ident, ctx) else { return };
+                let name = ident.name;
+                ctx.delete_reference_for_identifier(ident);
+                *expr = Self::create_tdz_error(name, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    fn tdz_status(
+        &self,
+        ident: &IdentifierReference<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<(SymbolId, TdzStatus)> {
+        // Generated references are never in the temporal dead zone
+        if ident.span.is_unspanned() {
+            return None;
+        }
+        let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        let declaration = self.tdz.declarations.get(&symbol_id)?;
+
+        let declaration_scope_id = ctx.scoping().symbol_scope_id(symbol_id);
+        let scoping = ctx.scoping();
+        let in_closure = scoping
+            .scope_ancestors(ctx.current_scope_id())
+            .take_while(|&scope_id| scope_id != declaration_scope_id)
+            .any(|scope_id| scoping.scope_flags(scope_id).is_function());
+        let before_declaration = ident.span.start < declaration.end;
+
+        let status = if !in_closure {
+            if before_declaration { TdzStatus::Inside } else { TdzStatus::Outside }
+        } else if before_declaration || Self::is_in_function_declaration(declaration_scope_id, ctx)
+        {
+            TdzStatus::Maybe
+        } else {
+            TdzStatus::Outside
+        };
+        Some((symbol_id, status))
+    }
+
+    /// Whether the current node is inside a function declaration nested in `scope_id`, which may
+    /// be called before the code preceding it has run.
+    fn is_in_function_declaration(scope_id: ScopeId, ctx: &TraverseCtx<'a>) -> bool {
+        ctx.ancestors().any(|ancestor| {
+            let Ancestor::FunctionBody(func) = ancestor else { return false };
+            *func.r#type() == FunctionType::FunctionDeclaration
+                && func.scope_id().get().is_some_and(|function_scope_id| {
+                    ctx.scoping().scope_is_descendant_of(function_scope_id, scope_id)
+                })
+        })
+    }
+
+    /// `babelHelpers.tdz("x")`
+    fn create_tdz_error(name: Ident<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let arguments = ArenaVec::from_value_in(
+            Argument::from(Expression::new_string_literal(SPAN, name, None, ctx)),
+            ctx,
+        );
+        helper_call_expr(Helper::Tdz, arguments, ctx)
+    }
+
+    /// Insert `x = babelHelpers.temporalUndefined;` at the start of a block, for its bindings
+    /// which are read in a closure.
+    pub(super) fn insert_temporal_undefined(
+        &mut self,
+        scope_id: ScopeId,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(symbol_ids) = self.tdz.temporal_undefined.remove(&scope_id) else { return };
+        let mut new_stmts = ArenaVec::with_capacity_in(stmts.len() + symbol_ids.len(), ctx);
+        for symbol_id in symbol_ids {
+            let name = Self::symbol_name(symbol_id, ctx);
+            let target =
+                ctx.create_bound_ident_reference(SPAN, name, symbol_id, ReferenceFlags::Write);
+            let value = helper_load(Helper::TemporalUndefined, ctx);
+            let assignment = Expression::new_assignment_expression(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::AssignmentTargetIdentifier(ctx.alloc(target)),
+                value,
+                ctx,
+            );
+            new_stmts.push(Statement::new_expression_statement(SPAN, assignment, ctx));
+        }
+        new_stmts.append(stmts);
+        *stmts = new_stmts;
+    }
+}
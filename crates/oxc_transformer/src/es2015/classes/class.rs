@@ -0,0 +1,567 @@
+//! Transform of a class into a constructor function.
+
+use std::borrow::Cow;
+
+use oxc_allocator::{ArenaBox, ArenaVec};
+use oxc_ast::{ast::*, builder::NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{GetSpan, SPAN};
+use oxc_str::Ident;
+use oxc_traverse::BoundIdentifier;
+
+use crate::{
+    common::helper_loader::{Helper, helper_call_expr},
+    context::TraverseCtx,
+    es2015::function_name::name_function,
+    utils::sync_function_symbol_flags,
+};
+
+use super::{ClassDetails, Classes};
+
+/// Property descriptor of a method, getter or setter, passed to the `createClass` helper.
+struct Descriptor<'a> {
+    /// `key` property.
+    key: Expression<'a>,
+    /// Name of a non-computed key, used to merge a getter and setter with the same key.
+    name: Option<Cow<'a, str>>,
+    /// `value` property.
+    value: Option<Expression<'a>>,
+    /// `get` property.
+    get: Option<Expression<'a>>,
+    /// `set` property.
+    set: Option<Expression<'a>>,
+}
+
+impl<'a> Classes<'a> {
+    /// Replace a class declaration, or a statement containing a class which has just been exited.
+    ///
+    /// * `class A {}` -> `var A = ...;`
+    /// * `export class A {}` -> `export var A = ...;`
+    /// * `export default class A {}` -> `var A = ...; export { A as default };`
+    /// * `export default class {}` -> `export default ...;`
+    ///
+    /// `let` is used instead of `var` if the block scoping transform is not enabled.
+    pub(super) fn transform_class_statement(
+        &self,
+        stmt: &mut Statement<'a>,
+        details: ClassDetails<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match stmt {
+            Statement::ClassDeclaration(class) => {
+                let Some(declaration) = self.transform_class_declaration(class, details, ctx)
+                else {
+                    return;
+                };
+                let new_stmt = Statement::VariableDeclaration(declaration);
+                ctx.state.statement_injector.move_insertions(stmt, &new_stmt);
+                *stmt = new_stmt;
+            }
+            Statement::ExportNamedDeclaration(export) => {
+                let Some(Declaration::ClassDeclaration(class)) = &mut export.declaration else {
+                    return;
+                };
+                if let Some(declaration) = self.transform_class_declaration(class, details, ctx) {
+                    export.declaration = Some(Declaration::VariableDeclaration(declaration));
+                }
+            }
+            Statement::ExportDefaultDeclaration(export) => {
+                let ExportDefaultDeclarationKind::ClassDeclaration(class) = &mut export.declaration
+                else {
+                    return;
+                };
+                let Some(id) = &class.id else {
+                    if let Some(expr) = self.transform_class(class, details, ctx) {
+                        export.declaration = ExportDefaultDeclarationKind::from(expr);
+                    }
+                    return;
+                };
+                let binding = BoundIdentifier::from_binding_ident(id);
+                let Some(declaration) = self.transform_class_declaration(class, details, ctx)
+                else {
+                    return;
+                };
+                // `export { A as default };`
+                let kind = ImportOrExportKind::Value;
+                let local =
+                    ModuleExportName::IdentifierReference(binding.create_read_reference(ctx));
+                let exported = ModuleExportName::new_identifier_name(SPAN, "default", ctx);
+                let specifiers = ArenaVec::from_value_in(
+                    ExportSpecifier::new(SPAN, local, exported, kind, ctx),
+                    ctx,
+                );
+                let export = Statement::from(ModuleDeclaration::new_export_named_declaration(
+                    SPAN, None, specifiers, None, kind, NONE, ctx,
+                ));
+
+                let new_stmt = Statement::VariableDeclaration(declaration);
+                ctx.state.statement_injector.move_insertions(stmt, &new_stmt);
+                ctx.state.statement_injector.insert_after(&new_stmt, export);
+                *stmt = new_stmt;
+            }
+            // Class declaration converted to `let A = class A {}` by legacy decorators transform
+            Statement::VariableDeclaration(decl) => {
+                let init = decl.declarations.iter_mut().find_map(|declarator| {
+                    declarator
+                        .init
+                        .as_mut()
+                        .filter(|init| matches!(init, Expression::ClassExpression(_)))
+                });
+                if let Some(init) = init {
+                    let Expression::ClassExpression(class) = init else { unreachable!() };
+                    if let Some(expr) = self.transform_class(class, details, ctx) {
+                        *init = expr;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// `class A {}` -> `var A = ...`
+    fn transform_class_declaration(
+        &self,
+        class: &mut Class<'a>,
+        details: ClassDetails<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<ArenaBox<'a, VariableDeclaration<'a>>> {
+        let span = class.span;
+        let init = self.transform_class(class, details, ctx)?;
+        let id = class.id.take().unwrap();
+
+        let (kind, flags) = if self.block_scoping {
+            (VariableDeclarationKind::Var, SymbolFlags::FunctionScopedVariable)
+        } else {
+            (VariableDeclarationKind::Let, SymbolFlags::BlockScopedVariable)
+        };
+        *ctx.scoping_mut().symbol_flags_mut(id.symbol_id()) = flags;
+
+        let pattern = BindingPattern::BindingIdentifier(ArenaBox::new_in(id, ctx));
+        let declarator = VariableDeclarator::new(SPAN, kind, pattern, NONE, Some(init), false, ctx);
+        Some(VariableDeclaration::boxed(
+            span,
+            kind,
+            ArenaVec::from_value_in(declarator, ctx),
+            false,
+            ctx,
+        ))
+    }
+
+    /// Transform a class into a constructor function.
+    ///
+    /// Without a super class or methods:
+    /// ```js
+    /// /*#__PURE__*/ babelHelpers.createClass(function A() { ... })
+    /// ```
+    ///
+    /// Otherwise:
+    /// ```js
+    /// /*#__PURE__*/ function (_B) {
+    ///   function A() { ... }
+    ///   babelHelpers.inherits(A, _B);
+    ///   return babelHelpers.createClass(A, [ ...methods ], [ ...static methods ]);
+    /// }(B)
+    /// ```
+    ///
+    /// Returns `None` if the class contains elements which should have been removed by the class
+    /// properties transform.
+    pub(super) fn transform_class(
+        &self,
+        class: &mut Class<'a>,
+        details: ClassDetails<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if !Self::check_class_elements(class, ctx) {
+            return None;
+        }
+
+        let ClassDetails { name, is_derived, .. } = details;
+        let span = class.span;
+        // A class expression's name is reused as the constructor function's name
+        let class_id = if class.is_expression() { class.id.take() } else { None };
+        let class_scope_id = class.scope_id();
+        let parent_scope_id = ctx.scoping().scope_parent_id(class_scope_id).unwrap();
+        let is_strict = ctx.scoping().scope_flags(parent_scope_id).is_strict_mode();
+
+        // `extends` clause is evaluated outside the function
+        let super_class = class.super_class.take();
+        if let Some(super_class) = &super_class {
+            ctx.remove_scope_for_expression(class_scope_id, super_class);
+        }
+
+        let mut constructor = None;
+        let mut methods = vec![];
+        let mut static_methods = vec![];
+        for element in class.body.body.drain(..) {
+            let ClassElement::MethodDefinition(method) = element else { continue };
+            let method = method.unbox();
+            if method.value.body.is_none() {
+                continue;
+            }
+            if method.kind == MethodDefinitionKind::Constructor {
+                constructor = Some(method.value);
+            } else if method.r#static {
+                Self::add_descriptor(&mut static_methods, method, ctx);
+            } else {
+                Self::add_descriptor(&mut methods, method, ctx);
+            }
+        }
+
+        if !is_derived && methods.is_empty() && static_methods.is_empty() {
+            return Some(Self::create_simple_class(
+                &name,
+                class_id,
+                constructor,
+                class_scope_id,
+                parent_scope_id,
+                is_strict,
+                span,
+                ctx,
+            ));
+        }
+
+        // `function A() { ... }`
+        *ctx.scoping_mut().symbol_flags_mut(name.symbol_id) = SymbolFlags::Function;
+        let constructor_scope_id = match &constructor {
+            Some(func) => func.scope_id(),
+            None => ctx.create_child_scope(class_scope_id, ScopeFlags::Function),
+        };
+        let mut constructor = Self::create_constructor(
+            &name,
+            constructor,
+            FunctionType::FunctionDeclaration,
+            is_derived,
+            constructor_scope_id,
+            self.spread,
+            ctx,
+        );
+        if class_id.is_some() {
+            constructor.id = class_id;
+        }
+        let mut stmts = ArenaVec::from_value_in(Statement::FunctionDeclaration(constructor), ctx);
+
+        // `babelHelpers.inherits(A, _B);`
+        let mut params = ArenaVec::new_in(ctx);
+        let mut arguments = ArenaVec::new_in(ctx);
+        if let Some(super_class) = super_class {
+            let super_binding = ctx.generate_uid_based_on_node(
+                &super_class,
+                class_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            params.push(FormalParameter::new_plain(
+                SPAN,
+                super_binding.create_binding_pattern(ctx),
+                ctx,
+            ));
+            arguments.push(Argument::from(Self::wrap_native_super(super_class, ctx)));
+
+            let inherits_arguments = ArenaVec::from_array_in(
+                [
+                    Argument::from(name.create_read_expression(ctx)),
+                    Argument::from(super_binding.create_read_expression(ctx)),
+                ],
+                ctx,
+            );
+            let inherits = helper_call_expr(Helper::Inherits, inherits_arguments, ctx);
+            stmts.push(Statement::new_expression_statement(SPAN, inherits, ctx));
+        }
+
+        // `return babelHelpers.createClass(A, [ ...methods ], [ ...static methods ]);`
+        let mut create_class_arguments =
+            ArenaVec::from_value_in(Argument::from(name.create_read_expression(ctx)), ctx);
+        if !methods.is_empty() || !static_methods.is_empty() {
+            let methods = if methods.is_empty() {
+                Expression::new_null_literal(SPAN, ctx)
+            } else {
+                Self::create_descriptors_array(methods, ctx)
+            };
+            create_class_arguments.push(Argument::from(methods));
+        }
+        if !static_methods.is_empty() {
+            let static_methods = Self::create_descriptors_array(static_methods, ctx);
+            create_class_arguments.push(Argument::from(static_methods));
+        }
+        let create_class = helper_call_expr(Helper::CreateClass, create_class_arguments, ctx);
+        stmts.push(Statement::new_return_statement(SPAN, Some(create_class), ctx));
+
+        // `function (_B) { ... }(B)`
+        ctx.scoping_mut().scope_flags_mut(class_scope_id).insert(ScopeFlags::Function);
+        let directives = Self::create_strict_directives(is_strict, ctx);
+        let body = FunctionBody::boxed(SPAN, directives, stmts, ctx);
+        let params =
+            FormalParameters::boxed(SPAN, FormalParameterKind::FormalParameter, params, NONE, ctx);
+        let function = Expression::new_function_expression_with_scope_id_and_pure_and_pife(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            class_scope_id,
+            false,
+            false,
+            ctx,
+        );
+        Some(Expression::new_call_expression_with_pure(
+            span, function, NONE, arguments, false, true, ctx,
+        ))
+    }
+
+    /// `/*#__PURE__*/ babelHelpers.createClass(function A() { ... })`
+    fn create_simple_class(
+        name: &BoundIdentifier<'a>,
+        class_id: Option<BindingIdentifier<'a>>,
+        constructor: Option<ArenaBox<'a, Function<'a>>>,
+        class_scope_id: ScopeId,
+        parent_scope_id: ScopeId,
+        is_strict: bool,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scoping = ctx.scoping_mut();
+        *scoping.symbol_flags_mut(name.symbol_id) = SymbolFlags::Function;
+        let constructor_scope_id = if let Some(constructor) = &constructor {
+            // Constructor's scope becomes the function's scope. Class scope is no longer used.
+            let scope_id = constructor.scope_id();
+            scoping.move_binding_by_symbol_id(class_scope_id, scope_id, name.symbol_id);
+            scoping.change_scope_parent_id(scope_id, Some(parent_scope_id));
+            scope_id
+        } else {
+            // Class scope becomes the function's scope
+            scoping.scope_flags_mut(class_scope_id).insert(ScopeFlags::Function);
+            class_scope_id
+        };
+
+        let mut constructor = Self::create_constructor(
+            name,
+            constructor,
+            FunctionType::FunctionExpression,
+            false,
+            constructor_scope_id,
+            None,
+            ctx,
+        );
+        if class_id.is_some() {
+            constructor.id = class_id;
+        }
+        sync_function_symbol_flags(&constructor, ctx);
+        if !is_strict {
+            let body = constructor.body.as_mut().unwrap();
+            body.directives = Self::create_strict_directives(false, ctx);
+        }
+
+        let arguments = ArenaVec::from_value_in(Argument::FunctionExpression(constructor), ctx);
+        let mut create_class = helper_call_expr(Helper::CreateClass, arguments, ctx);
+        if let Expression::CallExpression(call) = &mut create_class {
+            call.span = span;
+            call.pure = true;
+        }
+        create_class
+    }
+
+    /// `"use strict";`, unless already in strict mode.
+    fn create_strict_directives(
+        is_strict: bool,
+        ctx: &TraverseCtx<'a>,
+    ) -> ArenaVec<'a, Directive<'a>> {
+        if is_strict {
+            ArenaVec::new_in(ctx)
+        } else {
+            ArenaVec::from_value_in(Directive::new_use_strict(ctx), ctx)
+        }
+    }
+
+    /// Check that class contains no elements which should have been removed by the class properties
+    /// transform. Report an error if it does.
+    fn check_class_elements(class: &Class<'a>, ctx: &mut TraverseCtx<'a>) -> bool {
+        let unsupported = class.body.body.iter().find(|element| match element {
+            ClassElement::MethodDefinition(method) => method.key.is_private_identifier(),
+            ClassElement::PropertyDefinition(prop) => !prop.declare,
+            ClassElement::StaticBlock(_) | ClassElement::AccessorProperty(_) => true,
+            ClassElement::TSIndexSignature(_) | ClassElement::ErrorNode(_) => false,
+        });
+        let Some(element) = unsupported else { return true };
+        ctx.state.error(
+            OxcDiagnostic::error("Missing class properties transform.").with_label(element.span()),
+        );
+        false
+    }
+
+    /// Add a method, getter or setter to a list of descriptors.
+    ///
+    /// A getter and setter with the same key share a descriptor.
+    /// A later method with the same key replaces an earlier one.
+    fn add_descriptor(
+        descriptors: &mut Vec<Descriptor<'a>>,
+        method: MethodDefinition<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let MethodDefinition { key, value: mut func, kind, computed, .. } = method;
+        let name = if computed { None } else { key.static_name() };
+
+        // Name function after the key, or `get` / `set`
+        let function_name = match kind {
+            MethodDefinitionKind::Get => Some(Cow::Borrowed("get")),
+            MethodDefinitionKind::Set => Some(Cow::Borrowed("set")),
+            _ => name.clone(),
+        };
+        Self::transform_method_function(&mut func, function_name, ctx);
+        let func = Expression::FunctionExpression(func);
+
+        let descriptor = name.as_ref().and_then(|name| {
+            descriptors.iter_mut().find(|descriptor| descriptor.name.as_ref() == Some(name))
+        });
+        let descriptor = if let Some(descriptor) = descriptor {
+            descriptor
+        } else {
+            let key = match key {
+                PropertyKey::StaticIdentifier(ident) => {
+                    Expression::new_string_literal(ident.span, ident.name, None, ctx)
+                }
+                key => key.into_expression(),
+            };
+            descriptors.push(Descriptor { key, name, value: None, get: None, set: None });
+            descriptors.last_mut().unwrap()
+        };
+
+        match kind {
+            MethodDefinitionKind::Get => {
+                descriptor.value = None;
+                descriptor.get = Some(func);
+            }
+            MethodDefinitionKind::Set => {
+                descriptor.value = None;
+                descriptor.set = Some(func);
+            }
+            _ => {
+                descriptor.get = None;
+                descriptor.set = None;
+                descriptor.value = Some(func);
+            }
+        }
+    }
+
+    /// Convert a method's function to a function expression, named `name` if that would not
+    /// shadow a reference inside the function.
+    fn transform_method_function(
+        func: &mut Function<'a>,
+        name: Option<Cow<'a, str>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        func.r#type = FunctionType::FunctionExpression;
+        let scope_id = func.scope_id();
+        ctx.scoping_mut()
+            .scope_flags_mut(scope_id)
+            .remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+
+        if let Some(name) = name {
+            let name = Ident::from_cow_in(&name, ctx);
+            name_function(func, name, ctx);
+        }
+    }
+
+    /// `[{ key: "a", value: function a() {} }, { key: "b", get: function get() {} }]`
+    fn create_descriptors_array(
+        descriptors: Vec<Descriptor<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let elements = descriptors.into_iter().map(|descriptor| {
+            let mut properties = ArenaVec::with_capacity_in(3, ctx);
+            let mut push = |name: &'static str, value: Expression<'a>| {
+                let key = PropertyKey::new_static_identifier(SPAN, name, ctx);
+                properties.push(ObjectPropertyKind::new_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    key,
+                    value,
+                    false,
+                    false,
+                    false,
+                    ctx,
+                ));
+            };
+            push("key", descriptor.key);
+            if let Some(value) = descriptor.value {
+                push("value", value);
+            }
+            if let Some(get) = descriptor.get {
+                push("get", get);
+            }
+            if let Some(set) = descriptor.set {
+                push("set", set);
+            }
+            ArrayExpressionElement::from(Expression::new_object_expression(SPAN, properties, ctx))
+        });
+        let elements = ArenaVec::from_iter_in(elements, ctx);
+        Expression::new_array_expression(SPAN, elements, ctx)
+    }
+
+    /// `Array` -> `babelHelpers.wrapNativeSuper(Array)`, if `Array` is the built-in class.
+    fn wrap_native_super(super_class: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let is_native = matches!(
+            &super_class,
+            Expression::Identifier(ident)
+                if is_builtin_class(&ident.name)
+                    && ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_none()
+        );
+        if !is_native {
+            return super_class;
+        }
+        let arguments = ArenaVec::from_value_in(Argument::from(super_class), ctx);
+        helper_call_expr(Helper::WrapNativeSuper, arguments, ctx)
+    }
+}
+
+/// Whether `name` is a built-in class which must be constructed natively.
+///
+/// Includes the ECMAScript built-ins, and `HTMLElement` for custom elements.
+fn is_builtin_class(name: &str) -> bool {
+    matches!(
+        name,
+        "AggregateError"
+            | "Array"
+            | "ArrayBuffer"
+            | "BigInt64Array"
+            | "BigUint64Array"
+            | "Boolean"
+            | "DataView"
+            | "Date"
+            | "Error"
+            | "EvalError"
+            | "FinalizationRegistry"
+            | "Float32Array"
+            | "Float64Array"
+            | "Function"
+            | "HTMLElement"
+            | "Int8Array"
+            | "Int16Array"
+            | "Int32Array"
+            | "Map"
+            | "Number"
+            | "Object"
+            | "Promise"
+            | "RangeError"
+            | "ReferenceError"
+            | "RegExp"
+            | "Set"
+            | "SharedArrayBuffer"
+            | "String"
+            | "SyntaxError"
+            | "TypeError"
+            | "Uint8Array"
+            | "Uint8ClampedArray"
+            | "Uint16Array"
+            | "Uint32Array"
+            | "URIError"
+            | "WeakMap"
+            | "WeakRef"
+            | "WeakSet"
+    )
+}
@@ -0,0 +1,473 @@
+//! Transform of class constructors.
+//!
+//! The constructor becomes the constructor function, with a `classCallCheck` call at the start,
+//! which throws if the class is called without `new`.
+//!
+//! In a derived class, `this` only exists once the super class constructor has returned, so the
+//! constructor is rewritten to construct the instance with the `callSuper` helper:
+//!
+//! ```js
+//! class B extends A {
+//!   constructor(x) {
+//!     if (x) super(x);
+//!     else super();
+//!     this.x = x;
+//!     foo(this);
+//!   }
+//! }
+//! ```
+//! ->
+//! ```js
+//! function B(x) {
+//!   var _this;
+//!   babelHelpers.classCallCheck(this, B);
+//!   if (x) _this = babelHelpers.callSuper(this, B, [x]);
+//!   else _this = babelHelpers.callSuper(this, B);
+//!   _this.x = x;
+//!   foo(babelHelpers.assertThisInitialized(_this));
+//!   return babelHelpers.assertThisInitialized(_this);
+//! }
+//! ```
+//!
+//! * `this.prop` -> `_this.prop`.
+//! * Any other `this` -> `babelHelpers.assertThisInitialized(_this)`, which throws if `super()`
+//!   has not been called yet.
+//! * `return value` -> `return babelHelpers.possibleConstructorReturn(_this, value)`.
+//! * `new.target` -> `this.constructor`.
+//!
+//! If the constructor's last statement is `super()`, it is converted to
+//! `return babelHelpers.callSuper(this, B)`.
+//!
+//! A derived class without a constructor gets
+//! `function B() { return babelHelpers.callSuper(this, B, arguments); }`.
+
+use oxc_allocator::{ArenaBox, ArenaVec, TakeIn};
+use oxc_ast::{ast::*, builder::NONE};
+use oxc_ast_visit::{
+    VisitMut,
+    walk_mut::{
+        walk_arrow_function_expression, walk_computed_member_expression,
+        walk_conditional_expression, walk_do_while_statement, walk_expression,
+        walk_for_in_statement, walk_for_of_statement, walk_for_statement, walk_if_statement,
+        walk_logical_expression, walk_private_field_expression, walk_return_statement,
+        walk_static_member_expression, walk_switch_statement, walk_try_statement,
+        walk_while_statement,
+    },
+};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::BoundIdentifier;
+
+use crate::{
+    common::helper_loader::{Helper, helper_call_expr},
+    context::TraverseCtx,
+    es2015::Spread,
+    utils::ast_builder::create_assignment,
+};
+
+use super::Classes;
+
+impl<'a> Classes<'a> {
+    /// Create the constructor function, from the class constructor if there is one.
+    ///
+    /// `scope_id` is the scope to give the constructor function if the class has no constructor.
+    pub(super) fn create_constructor(
+        class_name: &BoundIdentifier<'a>,
+        constructor: Option<ArenaBox<'a, Function<'a>>>,
+        r#type: FunctionType,
+        is_derived: bool,
+        scope_id: ScopeId,
+        spread: Option<Spread>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, Function<'a>> {
+        let mut this_declaration = None;
+        let mut func = if let Some(mut func) = constructor {
+            func.r#type = r#type;
+            func.id = Some(class_name.create_binding_identifier(ctx));
+            ctx.scoping_mut().scope_flags_mut(func.scope_id()).remove(ScopeFlags::Constructor);
+            if is_derived {
+                this_declaration =
+                    Self::transform_derived_constructor(&mut func, class_name, spread, ctx);
+            }
+            func
+        } else {
+            let params = FormalParameters::boxed(
+                SPAN,
+                FormalParameterKind::FormalParameter,
+                ArenaVec::new_in(ctx),
+                NONE,
+                ctx,
+            );
+            let mut stmts = ArenaVec::new_in(ctx);
+            if is_derived {
+                // `return babelHelpers.callSuper(this, B, arguments);`
+                let arguments =
+                    ctx.create_unbound_ident_expr(SPAN, "arguments".into(), ReferenceFlags::Read);
+                let call = Self::create_call_super(class_name, Some(arguments), ctx);
+                stmts.push(Statement::new_return_statement(SPAN, Some(call), ctx));
+            }
+            let body = FunctionBody::boxed(SPAN, ArenaVec::new_in(ctx), stmts, ctx);
+            Function::boxed_with_scope_id(
+                SPAN,
+                r#type,
+                Some(class_name.create_binding_identifier(ctx)),
+                false,
+                false,
+                false,
+                NONE,
+                NONE,
+                params,
+                NONE,
+                Some(body),
+                scope_id,
+                ctx,
+            )
+        };
+
+        // `babelHelpers.classCallCheck(this, A);`
+        let arguments = ArenaVec::from_array_in(
+            [
+                Argument::new_this_expression(SPAN, ctx),
+                Argument::from(class_name.create_read_expression(ctx)),
+            ],
+            ctx,
+        );
+        let check = helper_call_expr(Helper::ClassCallCheck, arguments, ctx);
+        let body = func.body.as_mut().unwrap();
+        body.statements.insert(0, Statement::new_expression_statement(SPAN, check, ctx));
+        if let Some(this_declaration) = this_declaration {
+            body.statements.insert(0, this_declaration);
+        }
+
+        func
+    }
+
+    /// Transform `super()`, `this` and `return` in the constructor of a derived class.
+    ///
+    /// Returns the `var _this;` declaration to insert at the start of the constructor, if required.
+    fn transform_derived_constructor(
+        func: &mut Function<'a>,
+        class_name: &BoundIdentifier<'a>,
+        spread: Option<Spread>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        let scope_id = func.scope_id();
+        let body = func.body.as_mut().unwrap();
+
+        // `super()` as last statement becomes `return babelHelpers.callSuper(this, B)`
+        let last_super_call = match body.statements.last() {
+            Some(Statement::ExpressionStatement(stmt)) if matches!(&stmt.expression, Expression::CallExpression(call) if call.callee.is_super()) =>
+            {
+                let Some(Statement::ExpressionStatement(stmt)) = body.statements.pop() else {
+                    unreachable!()
+                };
+                let Expression::CallExpression(call) = stmt.unbox().expression else {
+                    unreachable!()
+                };
+                Some(call)
+            }
+            _ => None,
+        };
+
+        let mut transformer = DerivedConstructorTransformer::new(class_name, scope_id, spread, ctx);
+        transformer.visit_statements(&mut body.statements);
+
+        let return_value = if let Some(mut call) = last_super_call {
+            for argument in &mut call.arguments {
+                transformer.visit_argument(argument);
+            }
+            Some(transformer.create_call_super_for(call.unbox()))
+        } else if matches!(body.statements.last(), Some(Statement::ReturnStatement(_))) {
+            None
+        } else if transformer.has_unconditional_super {
+            // `return _this` if `super()` is always called, otherwise check it was called
+            Some(transformer.this_binding().create_read_expression(transformer.ctx))
+        } else {
+            Some(transformer.create_assert_this_initialized())
+        };
+        let this_binding = transformer.this_binding;
+
+        if let Some(value) = return_value {
+            body.statements.push(Statement::new_return_statement(SPAN, Some(value), ctx));
+        }
+
+        // `var _this;`
+        this_binding.map(|this_binding| {
+            let declarator = VariableDeclarator::new(
+                SPAN,
+                VariableDeclarationKind::Var,
+                this_binding.create_binding_pattern(ctx),
+                NONE,
+                None,
+                false,
+                ctx,
+            );
+            Statement::new_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ArenaVec::from_value_in(declarator, ctx),
+                false,
+                ctx,
+            )
+        })
+    }
+
+    /// `babelHelpers.callSuper(this, B)` or `babelHelpers.callSuper(this, B, args)`
+    fn create_call_super(
+        class_name: &BoundIdentifier<'a>,
+        args: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ArenaVec::from_array_in(
+            [
+                Argument::new_this_expression(SPAN, ctx),
+                Argument::from(class_name.create_read_expression(ctx)),
+            ],
+            ctx,
+        );
+        if let Some(args) = args {
+            arguments.push(Argument::from(args));
+        }
+        helper_call_expr(Helper::CallSuper, arguments, ctx)
+    }
+}
+
+/// Visitor which transforms the body of a derived class constructor.
+///
+/// Nested functions are not entered, as they have their own `this`.
+struct DerivedConstructorTransformer<'a, 'c> {
+    class_name: &'c BoundIdentifier<'a>,
+    /// Scope of the constructor.
+    scope_id: ScopeId,
+    /// Spread transform, if enabled.
+    spread: Option<Spread>,
+    /// `_this` binding, created when first needed.
+    this_binding: Option<BoundIdentifier<'a>>,
+    /// Depth of arrow functions.
+    arrow_depth: u32,
+    /// Depth of statements and expressions which may not execute their contents.
+    conditional_depth: u32,
+    /// `true` if a `super()` call is always executed.
+    has_unconditional_super: bool,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a, 'c> DerivedConstructorTransformer<'a, 'c> {
+    fn new(
+        class_name: &'c BoundIdentifier<'a>,
+        scope_id: ScopeId,
+        spread: Option<Spread>,
+        ctx: &'c mut TraverseCtx<'a>,
+    ) -> Self {
+        Self {
+            class_name,
+            scope_id,
+            spread,
+            this_binding: None,
+            arrow_depth: 0,
+            conditional_depth: 0,
+            has_unconditional_super: false,
+            ctx,
+        }
+    }
+
+    fn this_binding(&mut self) -> BoundIdentifier<'a> {
+        self.this_binding
+            .get_or_insert_with(|| {
+                self.ctx.generate_uid("this", self.scope_id, SymbolFlags::FunctionScopedVariable)
+            })
+            .clone()
+    }
+
+    /// `babelHelpers.assertThisInitialized(_this)`
+    fn create_assert_this_initialized(&mut self) -> Expression<'a> {
+        let this = self.this_binding().create_read_expression(self.ctx);
+        let arguments = ArenaVec::from_value_in(Argument::from(this), self.ctx);
+        helper_call_expr(Helper::AssertThisInitialized, arguments, self.ctx)
+    }
+
+    /// `super(a, b)` -> `babelHelpers.callSuper(this, B, [a, b])`, assigned to `_this` if it is
+    /// used elsewhere.
+    fn create_call_super_for(&mut self, mut call: CallExpression<'a>) -> Expression<'a> {
+        let args = match call.arguments.as_slice() {
+            [] => None,
+            // `super(...arguments)` -> `babelHelpers.callSuper(this, B, arguments)`
+            [Argument::SpreadElement(spread)]
+                if matches!(&spread.argument, Expression::Identifier(ident)
+                    if ident.name == "arguments" && self.ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_none()) =>
+            {
+                let Some(Argument::SpreadElement(spread)) = call.arguments.pop() else {
+                    unreachable!()
+                };
+                Some(spread.unbox().argument)
+            }
+            _ => {
+                let elements = call.arguments.drain(..).map(ArrayExpressionElement::from);
+                let elements = ArenaVec::from_iter_in(elements, self.ctx);
+                // `super(a, ...b)` -> `babelHelpers.callSuper(this, B, [a].concat(babelHelpers.toConsumableArray(b)))`
+                match self.spread {
+                    Some(spread) if elements.iter().any(ArrayExpressionElement::is_spread) => {
+                        Some(spread.transform_elements(elements, SPAN, self.ctx))
+                    }
+                    _ => Some(Expression::new_array_expression(SPAN, elements, self.ctx)),
+                }
+            }
+        };
+        let call_super = Classes::create_call_super(self.class_name, args, self.ctx);
+        match &self.this_binding {
+            Some(this_binding) => {
+                let this_binding = this_binding.clone();
+                create_assignment(&this_binding, call_super, call.span, self.ctx)
+            }
+            None => call_super,
+        }
+    }
+
+    fn visit_conditional<T>(&mut self, it: &mut T, walk: fn(&mut Self, &mut T)) {
+        self.conditional_depth += 1;
+        walk(self, it);
+        self.conditional_depth -= 1;
+    }
+}
+
+impl<'a> VisitMut<'a> for DerivedConstructorTransformer<'a, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ThisExpression(_) => {
+                *expr = self.create_assert_this_initialized();
+            }
+            Expression::CallExpression(call) if call.callee.is_super() => {
+                for argument in &mut call.arguments {
+                    self.visit_argument(argument);
+                }
+                if self.arrow_depth == 0 && self.conditional_depth == 0 {
+                    self.has_unconditional_super = true;
+                }
+                let Expression::CallExpression(call) = expr.take_in(self.ctx) else {
+                    unreachable!()
+                };
+                // `_this` is always assigned, as it is read after the call
+                self.this_binding();
+                *expr = self.create_call_super_for(call.unbox());
+            }
+            _ if Classes::is_new_target(expr) => {
+                *expr = Classes::create_this_constructor(self.ctx);
+            }
+            _ => walk_expression(self, expr),
+        }
+    }
+
+    fn visit_static_member_expression(&mut self, member: &mut StaticMemberExpression<'a>) {
+        if matches!(member.object, Expression::ThisExpression(_)) {
+            member.object = self.this_binding().create_read_expression(self.ctx);
+        } else {
+            walk_static_member_expression(self, member);
+        }
+    }
+
+    fn visit_computed_member_expression(&mut self, member: &mut ComputedMemberExpression<'a>) {
+        if matches!(member.object, Expression::ThisExpression(_)) {
+            member.object = self.this_binding().create_read_expression(self.ctx);
+            self.visit_expression(&mut member.expression);
+        } else {
+            walk_computed_member_expression(self, member);
+        }
+    }
+
+    fn visit_private_field_expression(&mut self, member: &mut PrivateFieldExpression<'a>) {
+        if matches!(member.object, Expression::ThisExpression(_)) {
+            member.object = self.this_binding().create_read_expression(self.ctx);
+        } else {
+            walk_private_field_expression(self, member);
+        }
+    }
+
+    fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
+        walk_return_statement(self, stmt);
+        if self.arrow_depth > 0 {
+            return;
+        }
+        // `return value` -> `return babelHelpers.possibleConstructorReturn(_this, value)`
+        // `return` -> `return babelHelpers.assertThisInitialized(_this)`
+        stmt.argument = Some(match stmt.argument.take() {
+            Some(argument) => {
+                let this = self.this_binding().create_read_expression(self.ctx);
+                let arguments = ArenaVec::from_array_in(
+                    [Argument::from(this), Argument::from(argument)],
+                    self.ctx,
+                );
+                helper_call_expr(Helper::PossibleConstructorReturn, arguments, self.ctx)
+            }
+            None => self.create_assert_this_initialized(),
+        });
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        // Only the `extends` clause and computed keys are evaluated in the constructor's scope
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_expression(super_class);
+        }
+        for element in &mut class.body.body {
+            match element {
+                ClassElement::MethodDefinition(method) if method.computed => {
+                    self.visit_property_key(&mut method.key);
+                }
+                ClassElement::PropertyDefinition(prop) if prop.computed => {
+                    self.visit_property_key(&mut prop.key);
+                }
+                ClassElement::AccessorProperty(prop) if prop.computed => {
+                    self.visit_property_key(&mut prop.key);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk_arrow_function_expression(self, arrow);
+        self.arrow_depth -= 1;
+    }
+
+    fn visit_if_statement(&mut self, stmt: &mut IfStatement<'a>) {
+        self.visit_conditional(stmt, walk_if_statement);
+    }
+
+    fn visit_switch_statement(&mut self, stmt: &mut SwitchStatement<'a>) {
+        self.visit_conditional(stmt, walk_switch_statement);
+    }
+
+    fn visit_try_statement(&mut self, stmt: &mut TryStatement<'a>) {
+        self.visit_conditional(stmt, walk_try_statement);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        self.visit_conditional(stmt, walk_for_statement);
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        self.visit_conditional(stmt, walk_for_in_statement);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        self.visit_conditional(stmt, walk_for_of_statement);
+    }
+
+    fn visit_while_statement(&mut self, stmt: &mut WhileStatement<'a>) {
+        self.visit_conditional(stmt, walk_while_statement);
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &mut DoWhileStatement<'a>) {
+        self.visit_conditional(stmt, walk_do_while_statement);
+    }
+
+    fn visit_conditional_expression(&mut self, expr: &mut ConditionalExpression<'a>) {
+        self.visit_conditional(expr, walk_conditional_expression);
+    }
+
+    fn visit_logical_expression(&mut self, expr: &mut LogicalExpression<'a>) {
+        self.visit_conditional(expr, walk_logical_expression);
+    }
+}
@@ -0,0 +1,436 @@
+//! ES2015: Classes
+//!
+//! This plugin transforms classes into constructor functions.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! class A extends B {
+//!   constructor(x) {
+//!     super(x);
+//!     this.x = x;
+//!   }
+//!   get x() {
+//!     return super.x;
+//!   }
+//!   static create() {
+//!     return new A(1);
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var A = /*#__PURE__*/ function (_B) {
+//!   "use strict";
+//!   function A(x) {
+//!     var _this;
+//!     babelHelpers.classCallCheck(this, A);
+//!     _this = babelHelpers.callSuper(this, A, [x]);
+//!     _this.x = x;
+//!     return _this;
+//!   }
+//!   babelHelpers.inherits(A, _B);
+//!   return babelHelpers.createClass(A, [{
+//!     key: "x",
+//!     get: function get() {
+//!       return babelHelpers.superPropGet(A, "x", this, 1);
+//!     }
+//!   }], [{
+//!     key: "create",
+//!     value: function create() {
+//!       return new A(1);
+//!     }
+//!   }]);
+//! }(B);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Class fields, static blocks and private methods must already have been removed from the class
+//! by the class properties transform, which runs earlier in the exit phase. A class which still
+//! contains any of them is left as is, and an error is reported.
+//!
+//! The transform is split across the traversal of the class:
+//!
+//! 1. On entering a class, the name which the constructor function will have is decided.
+//!    A class declaration `class A {}` becomes `var A = function () { function A() {} ... }()`,
+//!    so inside the class body `A` refers to a new inner binding, not the outer one.
+//!    References to the class name in the class body are re-pointed to the inner binding as they
+//!    are visited.
+//!
+//! 2. While traversing the class body, `super.prop` and `new.target` in methods and the constructor
+//!    are replaced with helper calls (see [`super_converter`]).
+//!
+//! 3. On exiting the class, the class is replaced with a function expression.
+//!    Methods become property descriptors passed to the `createClass` helper, and the constructor of
+//!    a derived class is rewritten to call the super class via the `callSuper` helper
+//!    (see [`constructor`]).
+//!
+//! Replacing the class happens in `exit_expression` / `exit_statement`, rather than `exit_class`,
+//! as a class declaration has to become a variable declaration.
+//!
+//! Extending a built-in class (`Array`, `Error`, `HTMLElement` etc.) wraps the super class in the
+//! `wrapNativeSuper` helper, so that instances are created by the native constructor.
+//!
+//! ## Options
+//!
+//! Babel's `loose` option, and the `constantSuper`, `noClassCalls`, `setClassMethods` and
+//! `superIsCallableConstructor` assumptions, are not supported.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-classes>
+//! * Class definitions: <https://tc39.es/ecma262/#sec-class-definitions>
+
+use oxc_allocator::{CloneIn, GetAllocator};
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_semantic::{SymbolFlags, SymbolId};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    context::TraverseCtx,
+    es2015::{Spread, function_name::is_valid_function_name},
+    state::TransformState,
+};
+
+mod class;
+mod constructor;
+mod super_converter;
+
+/// Classes transform.
+///
+/// See [module docs] for details.
+///
+/// [module docs]: self
+pub struct Classes<'a> {
+    /// `true` if the block scoping transform is enabled, in which case class declarations are
+    /// converted to `var` rather than `let`.
+    block_scoping: bool,
+    /// Spread transform, if enabled, to transform `super(...args)`.
+    spread: Option<Spread>,
+    /// Classes being traversed, innermost last.
+    class_stack: Vec<ClassDetails<'a>>,
+    /// Kind of the functions being traversed, innermost last.
+    functions: Vec<FunctionKind>,
+    /// Class which has just been exited, and is waiting to be replaced in `exit_expression` or
+    /// `exit_statement`.
+    exited_class: Option<ClassDetails<'a>>,
+}
+
+/// Details of a class.
+struct ClassDetails<'a> {
+    /// Name of the constructor function.
+    name: BoundIdentifier<'a>,
+    /// Symbol of a class declaration's outer binding.
+    outer_symbol_id: Option<SymbolId>,
+    /// `true` if the class has a super class.
+    is_derived: bool,
+    /// `true` once the class body has been entered.
+    in_body: bool,
+    /// Depth of elements which the class properties transform moves outside the class
+    /// (static properties, static blocks, private methods).
+    moved_element_depth: u32,
+}
+
+/// Kind of a function being traversed.
+#[derive(Clone, Copy)]
+enum FunctionKind {
+    /// Class constructor. `class_index` is the index of the class in `Classes::class_stack`.
+    Constructor { class_index: usize, is_derived: bool },
+    /// Class method, getter or setter.
+    Method { class_index: usize, is_static: bool },
+    /// Any other function.
+    Other,
+}
+
+impl Classes<'_> {
+    pub fn new(block_scoping: bool, spread: Option<Spread>) -> Self {
+        Self { block_scoping, spread, class_stack: vec![], functions: vec![], exited_class: None }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Classes<'a> {
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if class.declare {
+            return;
+        }
+        let name = Self::create_class_name(class, ctx);
+        let outer_symbol_id =
+            class.id.as_ref().filter(|_| class.is_declaration()).map(BindingIdentifier::symbol_id);
+        self.class_stack.push(ClassDetails {
+            name,
+            outer_symbol_id,
+            is_derived: class.super_class.is_some(),
+            in_body: false,
+            moved_element_depth: 0,
+        });
+    }
+
+    fn exit_class(&mut self, class: &mut Class<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if class.declare {
+            return;
+        }
+        self.exited_class = self.class_stack.pop();
+    }
+
+    fn enter_class_body(&mut self, _body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Ancestor::ClassBody(class) = ctx.parent()
+            && !*class.declare()
+            && let Some(class) = self.class_stack.last_mut()
+        {
+            class.in_body = true;
+        }
+    }
+
+    fn enter_method_definition(
+        &mut self,
+        method: &mut MethodDefinition<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if method.key.is_private_identifier() {
+            self.enter_moved_element();
+        }
+    }
+
+    fn exit_method_definition(
+        &mut self,
+        method: &mut MethodDefinition<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if method.key.is_private_identifier() {
+            self.exit_moved_element();
+        }
+    }
+
+    fn enter_property_definition(
+        &mut self,
+        _prop: &mut PropertyDefinition<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.enter_moved_element();
+        self.functions.push(FunctionKind::Other);
+    }
+
+    fn exit_property_definition(
+        &mut self,
+        _prop: &mut PropertyDefinition<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.functions.pop();
+        self.exit_moved_element();
+    }
+
+    fn enter_accessor_property(
+        &mut self,
+        _prop: &mut AccessorProperty<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.enter_moved_element();
+        self.functions.push(FunctionKind::Other);
+    }
+
+    fn exit_accessor_property(
+        &mut self,
+        _prop: &mut AccessorProperty<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.functions.pop();
+        self.exit_moved_element();
+    }
+
+    fn enter_static_block(&mut self, _block: &mut StaticBlock<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.enter_moved_element();
+        self.functions.push(FunctionKind::Other);
+    }
+
+    fn exit_static_block(&mut self, _block: &mut StaticBlock<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.functions.pop();
+        self.exit_moved_element();
+    }
+
+    fn enter_function(&mut self, _func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let kind = match ctx.parent() {
+            Ancestor::MethodDefinitionValue(method) if !method.key().is_private_identifier() => {
+                match self.class_stack.last() {
+                    Some(class) => {
+                        let class_index = self.class_stack.len() - 1;
+                        if *method.kind() == MethodDefinitionKind::Constructor {
+                            FunctionKind::Constructor { class_index, is_derived: class.is_derived }
+                        } else {
+                            FunctionKind::Method { class_index, is_static: *method.r#static() }
+                        }
+                    }
+                    None => FunctionKind::Other,
+                }
+            }
+            _ => FunctionKind::Other,
+        };
+        self.functions.push(kind);
+    }
+
+    fn exit_function(&mut self, _func: &mut Function<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.functions.pop();
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.class_stack.is_empty() {
+            return;
+        }
+        let reference_id = ident.reference_id();
+        let Some(symbol_id) = ctx.scoping().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        let Some(class) =
+            self.class_stack.iter().rev().find(|class| class.outer_symbol_id == Some(symbol_id))
+        else {
+            return;
+        };
+        if !class.in_body || class.moved_element_depth > 0 {
+            return;
+        }
+        // Re-point reference to the constructor function's binding
+        let inner_symbol_id = class.name.symbol_id;
+        let scoping = ctx.scoping_mut();
+        scoping.get_reference_mut(reference_id).set_symbol_id(inner_symbol_id);
+        scoping.delete_resolved_reference(symbol_id, reference_id);
+        scoping.add_resolved_reference(inner_symbol_id, reference_id);
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.class_stack.is_empty() {
+            return;
+        }
+        self.transform_super_expression(expr, ctx);
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(class) = self.exited_class.take() else { return };
+        // The class properties transform may have wrapped the class expression into an assignment
+        // to a temp var, or a sequence expression
+        let expr = match expr {
+            Expression::SequenceExpression(sequence) => {
+                let Some(expr) =
+                    sequence.expressions.iter_mut().find(|expr| Self::is_class_or_assignment(expr))
+                else {
+                    return;
+                };
+                expr
+            }
+            expr => expr,
+        };
+        let expr = match expr {
+            Expression::AssignmentExpression(assign) => &mut assign.right,
+            expr => expr,
+        };
+        let Expression::ClassExpression(class_expr) = expr else { return };
+        if let Some(new_expr) = self.transform_class(class_expr, class, ctx) {
+            *expr = new_expr;
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(class) = self.exited_class.take() else { return };
+        self.transform_class_statement(stmt, class, ctx);
+    }
+}
+
+impl<'a> Classes<'a> {
+    fn enter_moved_element(&mut self) {
+        if let Some(class) = self.class_stack.last_mut() {
+            class.moved_element_depth += 1;
+        }
+    }
+
+    fn exit_moved_element(&mut self) {
+        if let Some(class) = self.class_stack.last_mut() {
+            class.moved_element_depth -= 1;
+        }
+    }
+
+    fn is_class_or_assignment(expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::ClassExpression(_) => true,
+            Expression::AssignmentExpression(assign) => {
+                matches!(assign.right, Expression::ClassExpression(_))
+            }
+            _ => false,
+        }
+    }
+
+    /// Create the binding for the name of the constructor function.
+    ///
+    /// * `class A {}` -> new binding `A`, shadowing the outer one.
+    /// * `x = class A {}` -> existing binding `A`.
+    /// * `var x = class {}` -> new binding `x`, unless the class body references `x`.
+    /// * Anything else -> new binding `_class`.
+    fn create_class_name(class: &Class<'a>, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        let scope_id = class.scope_id();
+        if let Some(id) = &class.id {
+            if class.is_expression() {
+                return BoundIdentifier::from_binding_ident(id);
+            }
+            let name = ctx.scoping().symbol_ident(id.symbol_id()).clone_in(ctx.allocator());
+            return ctx.generate_binding(name, scope_id, SymbolFlags::Function);
+        }
+
+        if class.is_expression()
+            && let Some(name) = Self::infer_class_name(ctx)
+            && !ClassNameReferenceFinder::find(name.as_str(), &class.body)
+        {
+            return ctx.generate_binding(name, scope_id, SymbolFlags::Function);
+        }
+
+        let name = if class.is_declaration() { "default" } else { "class" };
+        ctx.generate_uid(name, scope_id, SymbolFlags::Function)
+    }
+
+    /// Name for an anonymous class expression, from the variable or identifier it is assigned to.
+    fn infer_class_name(ctx: &TraverseCtx<'a>) -> Option<oxc_str::Ident<'a>> {
+        let name = match ctx.parent() {
+            Ancestor::VariableDeclaratorInit(decl) => {
+                decl.id().get_binding_identifier().map(|id| id.name)
+            }
+            Ancestor::AssignmentExpressionRight(assign)
+                if *assign.operator() == AssignmentOperator::Assign =>
+            {
+                match assign.left() {
+                    AssignmentTarget::AssignmentTargetIdentifier(ident) => Some(ident.name),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }?;
+        is_valid_function_name(name.as_str()).then_some(name)
+    }
+}
+
+/// Visitor which finds whether a class body contains a reference to a name.
+struct ClassNameReferenceFinder<'n> {
+    name: &'n str,
+    found: bool,
+}
+
+impl ClassNameReferenceFinder<'_> {
+    fn find(name: &str, body: &ClassBody<'_>) -> bool {
+        let mut finder = ClassNameReferenceFinder { name, found: false };
+        finder.visit_class_body(body);
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for ClassNameReferenceFinder<'_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name == self.name {
+            self.found = true;
+        }
+    }
+}
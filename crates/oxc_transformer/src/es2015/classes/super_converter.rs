@@ -0,0 +1,313 @@
+//! Transform of `super` property access and `new.target` in class methods and constructors.
+//!
+//! * `super.prop` -> `babelHelpers.superPropGet(A, "prop", this, 1)`
+//! * `super.method(a)` -> `babelHelpers.superPropGet(A, "method", this, 3)([a])`
+//! * `super.method?.(a)` -> `babelHelpers.superPropGet(A, "method", this, 1)?.call(this, a)`
+//! * `super.prop = value` -> `babelHelpers.superPropSet(A, "prop", value, this, 1, 1)`
+//! * `new.target` -> `this.constructor` in a constructor, `void 0` in a method
+//!
+//! In static methods, the flag selecting the prototype is omitted:
+//!
+//! * `super.prop` -> `babelHelpers.superPropGet(A, "prop", this)`
+//! * `super.prop = value` -> `babelHelpers.superPropSet(A, "prop", value, this, 1)`
+//!
+//! `super()` calls and `new.target` in the constructor of a derived class are transformed along
+//! with the rest of the constructor, once the class is exited. See [`super::constructor`].
+
+use oxc_allocator::{ArenaVec, TakeIn};
+use oxc_ast::ast::*;
+use oxc_span::SPAN;
+use oxc_str::static_ident;
+use oxc_traverse::{BoundIdentifier, ast_operations::get_var_name_from_node};
+
+use crate::{
+    common::{
+        duplicate::duplicate_expression,
+        helper_loader::{Helper, helper_call_expr},
+        var_declarations::VarDeclarationsStore,
+    },
+    context::TraverseCtx,
+    utils::ast_builder::{create_assignment, create_bind_call, create_member_callee},
+};
+
+use super::{Classes, FunctionKind};
+
+/// Home object of the method which `super` expressions are transformed in.
+struct SuperHome<'a> {
+    /// Binding of the constructor function.
+    class_name: BoundIdentifier<'a>,
+    /// `true` in a static method.
+    is_static: bool,
+}
+
+impl<'a> Classes<'a> {
+    /// Transform `super` property access or `new.target`, if inside a class method or constructor.
+    pub(super) fn transform_super_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let (class_index, is_static) = match self.functions.last() {
+            Some(&FunctionKind::Method { class_index, is_static }) => {
+                if Self::is_new_target(expr) {
+                    *expr = Expression::new_void_0(SPAN, ctx);
+                    return;
+                }
+                (class_index, is_static)
+            }
+            Some(&FunctionKind::Constructor { class_index, is_derived }) => {
+                // `new.target` in a derived constructor is transformed with the rest of the constructor
+                if !is_derived && Self::is_new_target(expr) {
+                    *expr = Self::create_this_constructor(ctx);
+                    return;
+                }
+                (class_index, false)
+            }
+            _ => return,
+        };
+        let home = SuperHome { class_name: self.class_stack[class_index].name.clone(), is_static };
+
+        match expr {
+            Expression::StaticMemberExpression(_) | Expression::ComputedMemberExpression(_) => {
+                if let Some(property) = Self::take_super_property(expr, ctx) {
+                    *expr = home.create_super_prop_get(property, false, ctx);
+                }
+            }
+            Expression::CallExpression(call) => home.transform_call_expression(call, ctx),
+            Expression::ChainExpression(chain) => {
+                if let ChainElement::CallExpression(call) = &mut chain.expression {
+                    if call.optional {
+                        home.transform_optional_call_expression(call, ctx);
+                    } else {
+                        home.transform_call_expression(call, ctx);
+                    }
+                }
+            }
+            Expression::TaggedTemplateExpression(tagged) => {
+                if let Some(property) = Self::take_super_property(&mut tagged.tag, ctx) {
+                    // `super.tag`x`` -> `superPropGet(A, "tag", this, 1).bind(this)`x``
+                    let get_call = home.create_super_prop_get(property, false, ctx);
+                    let this = Expression::new_this_expression(SPAN, ctx);
+                    tagged.tag = create_bind_call(get_call, this, SPAN, ctx);
+                }
+            }
+            Expression::AssignmentExpression(_) => {
+                home.transform_assignment_expression(expr, ctx);
+            }
+            Expression::UpdateExpression(_) => home.transform_update_expression(expr, ctx),
+            _ => {}
+        }
+    }
+
+    /// `new.target`
+    pub(super) fn is_new_target(expr: &Expression<'a>) -> bool {
+        matches!(expr, Expression::MetaProperty(meta) if meta.meta.name == "new" && meta.property.name == "target")
+    }
+
+    /// `this.constructor`
+    pub(super) fn create_this_constructor(ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        let this = Expression::new_this_expression(SPAN, ctx);
+        create_member_callee(this, static_ident!("constructor"), SPAN, ctx)
+    }
+
+    /// If `expr` is `super.prop` or `super[prop]`, take the property key.
+    fn take_super_property(
+        expr: &mut Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        match expr {
+            Expression::StaticMemberExpression(member) if member.object.is_super() => {
+                let property = &member.property;
+                Some(Expression::new_string_literal(property.span, property.name, None, ctx))
+            }
+            Expression::ComputedMemberExpression(member) if member.object.is_super() => {
+                Some(member.expression.take_in(ctx))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a> SuperHome<'a> {
+    /// `super.method(a)` -> `superPropGet(A, "method", this, 3)([a])`
+    fn transform_call_expression(&self, call: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(property) = Classes::take_super_property(&mut call.callee, ctx) else { return };
+        call.callee = self.create_super_prop_get(property, true, ctx);
+        let elements = call.arguments.drain(..).map(ArrayExpressionElement::from);
+        let elements = ArenaVec::from_iter_in(elements, ctx);
+        let array = Expression::new_array_expression(SPAN, elements, ctx);
+        call.arguments.push(Argument::from(array));
+    }
+
+    /// `super.method?.(a)` -> `superPropGet(A, "method", this, 1)?.call(this, a)`
+    fn transform_optional_call_expression(
+        &self,
+        call: &mut CallExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(property) = Classes::take_super_property(&mut call.callee, ctx) else { return };
+        let get_call = self.create_super_prop_get(property, false, ctx);
+        let property = IdentifierName::new(SPAN, static_ident!("call"), ctx);
+        call.callee = Expression::new_static_member_expression(SPAN, get_call, property, true, ctx);
+        call.optional = false;
+        call.arguments.insert(0, Argument::new_this_expression(SPAN, ctx));
+    }
+
+    /// * `super.prop = value` -> `superPropSet(A, "prop", value, this, 1, 1)`
+    /// * `super.prop += value` -> `superPropSet(A, "prop", superPropGet(A, "prop", this, 1) + value, this, 1, 1)`
+    /// * `super.prop &&= value` -> `superPropGet(A, "prop", this, 1) && superPropSet(A, "prop", value, this, 1, 1)`
+    fn transform_assignment_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::AssignmentExpression(assign) = expr else { unreachable!() };
+        let property = match &mut assign.left {
+            AssignmentTarget::StaticMemberExpression(member) if member.object.is_super() => {
+                let property = &member.property;
+                Expression::new_string_literal(property.span, property.name, None, ctx)
+            }
+            AssignmentTarget::ComputedMemberExpression(member) if member.object.is_super() => {
+                member.expression.take_in(ctx)
+            }
+            _ => return,
+        };
+        let span = assign.span;
+        let operator = assign.operator;
+        let value = assign.right.take_in(ctx);
+
+        *expr = if operator == AssignmentOperator::Assign {
+            self.create_super_prop_set(property, value, ctx)
+        } else {
+            let (property1, property2) = duplicate_expression(property, false, ctx);
+            if let Some(operator) = operator.to_binary_operator() {
+                let get_call = self.create_super_prop_get(property2, false, ctx);
+                let value = Expression::new_binary_expression(SPAN, get_call, operator, value, ctx);
+                self.create_super_prop_set(property1, value, ctx)
+            } else {
+                let operator = operator.to_logical_operator().unwrap();
+                let get_call = self.create_super_prop_get(property1, false, ctx);
+                let set_call = self.create_super_prop_set(property2, value, ctx);
+                Expression::new_logical_expression(span, get_call, operator, set_call, ctx)
+            }
+        };
+    }
+
+    /// * `++super.prop` -> `superPropSet(A, "prop", (_super$prop = superPropGet(A, "prop", this, 1), ++_super$prop), this, 1, 1)`
+    /// * `super.prop++` ->
+    ///   `(superPropSet(A, "prop", (_super$prop = superPropGet(A, "prop", this, 1), _super$prop2 = _super$prop++, _super$prop), this, 1, 1), _super$prop2)`
+    fn transform_update_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::UpdateExpression(update) = expr else { unreachable!() };
+        let (temp_var_name_base, property) = match &mut update.argument {
+            SimpleAssignmentTarget::StaticMemberExpression(member) if member.object.is_super() => {
+                let property = &member.property;
+                let key = Expression::new_string_literal(property.span, property.name, None, ctx);
+                (get_var_name_from_node(member.as_ref()), key)
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(member)
+                if member.object.is_super() =>
+            {
+                let name = get_var_name_from_node(member.as_ref());
+                (name, member.expression.get_inner_expression_mut().take_in(ctx))
+            }
+            _ => return,
+        };
+        let Expression::UpdateExpression(mut update) = expr.take_in(ctx) else { unreachable!() };
+
+        let (property1, property2) = duplicate_expression(property, false, ctx);
+        let get_call = self.create_super_prop_get(property2, false, ctx);
+        let temp_binding = VarDeclarationsStore::create_uid_var(&temp_var_name_base, ctx);
+        let assignment = create_assignment(&temp_binding, get_call, SPAN, ctx);
+
+        let span = update.span;
+        update.span = SPAN;
+        update.argument = temp_binding.create_read_write_simple_target(ctx);
+        let prefix = update.prefix;
+        let update = Expression::UpdateExpression(update);
+
+        *expr = if prefix {
+            let value = Expression::new_sequence_expression(
+                SPAN,
+                ArenaVec::from_array_in([assignment, update], ctx),
+                ctx,
+            );
+            self.create_super_prop_set(property1, value, ctx)
+        } else {
+            let temp_binding2 = VarDeclarationsStore::create_uid_var(&temp_var_name_base, ctx);
+            let assignment2 = create_assignment(&temp_binding2, update, SPAN, ctx);
+            let value = Expression::new_sequence_expression(
+                SPAN,
+                ArenaVec::from_array_in(
+                    [assignment, assignment2, temp_binding.create_read_expression(ctx)],
+                    ctx,
+                ),
+                ctx,
+            );
+            let set_call = self.create_super_prop_set(property1, value, ctx);
+            Expression::new_sequence_expression(
+                span,
+                ArenaVec::from_array_in([set_call, temp_binding2.create_read_expression(ctx)], ctx),
+                ctx,
+            )
+        };
+    }
+
+    /// * Instance: `superPropGet(A, prop, this, 1)`, or `superPropGet(A, prop, this, 3)` for a callee
+    /// * Static: `superPropGet(A, prop, this)`, or `superPropGet(A, prop, this, 2)` for a callee
+    fn create_super_prop_get(
+        &self,
+        property: Expression<'a>,
+        is_callee: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ArenaVec::from_array_in(
+            [
+                Argument::from(self.class_name.create_read_expression(ctx)),
+                Argument::from(property),
+                Argument::new_this_expression(SPAN, ctx),
+            ],
+            ctx,
+        );
+        let flags = u8::from(!self.is_static) | (u8::from(is_callee) << 1);
+        if flags != 0 {
+            arguments.push(Argument::new_numeric_literal(
+                SPAN,
+                f64::from(flags),
+                None,
+                NumberBase::Decimal,
+                ctx,
+            ));
+        }
+        helper_call_expr(Helper::SuperPropGet, arguments, ctx)
+    }
+
+    /// * Instance: `superPropSet(A, prop, value, this, 1, 1)`
+    /// * Static: `superPropSet(A, prop, value, this, 1)`
+    fn create_super_prop_set(
+        &self,
+        property: Expression<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ArenaVec::from_array_in(
+            [
+                Argument::from(self.class_name.create_read_expression(ctx)),
+                Argument::from(property),
+                Argument::from(value),
+                Argument::new_this_expression(SPAN, ctx),
+                Argument::new_numeric_literal(SPAN, 1.0, None, NumberBase::Decimal, ctx),
+            ],
+            ctx,
+        );
+        if !self.is_static {
+            arguments.push(Argument::new_numeric_literal(
+                SPAN,
+                1.0,
+                None,
+                NumberBase::Decimal,
+                ctx,
+            ));
+        }
+        helper_call_expr(Helper::SuperPropSet, arguments, ctx)
+    }
+}
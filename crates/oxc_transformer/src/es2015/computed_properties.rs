@@ -0,0 +1,254 @@
+//! ES2015: Computed Properties
+//!
+//! This plugin transforms object literals with computed keys into plain object literals,
+//! with the computed properties defined afterwards.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var obj = {
+//!   a: 1,
+//!   ["x" + foo]: "heh",
+//!   b: 2,
+//!   get [bar]() {},
+//! };
+//! var single = { [foo]: "bar" };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _obj;
+//! var obj = (
+//!   _obj = { a: 1 },
+//!   babelHelpers.defineProperty(_obj, "x" + foo, "heh"),
+//!   babelHelpers.defineProperty(_obj, "b", 2),
+//!   babelHelpers.defineAccessor("get", _obj, bar, function () {}),
+//!   _obj
+//! );
+//! var single = babelHelpers.defineProperty({}, foo, "bar");
+//! ```
+//!
+//! Every property from the first computed one onwards is defined separately, to preserve
+//! evaluation order and which of duplicate keys wins.
+//!
+//! With `setComputedProperties` assumption (or `loose` option), the properties are assigned
+//! instead of defined:
+//! ```js
+//! var obj = (_obj = { a: 1 }, _obj["x" + foo] = "heh", _obj.b = 2, babelHelpers.defineAccessor("get", _obj, bar, function () {}), _obj);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-computed-properties](https://babeljs.io/docs/babel-plugin-transform-computed-properties).
+//!
+//! Objects are left unchanged if a spread property follows a computed property (the
+//! object-rest-spread transform handles those), or if a method after a computed property
+//! contains `super`.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-computed-properties/src/index.ts>
+//! * Object initializer: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use serde::Deserialize;
+
+use oxc_allocator::{ArenaVec, TakeIn};
+use oxc_ast::ast::*;
+use oxc_semantic::ScopeFlags;
+use oxc_span::SPAN;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, ast_operations::get_var_name_from_node};
+
+use crate::{
+    common::{
+        helper_loader::{Helper, helper_call_expr},
+        var_declarations::VarDeclarationsStore,
+    },
+    context::TraverseCtx,
+    state::TransformState,
+    utils::ast_builder::create_assignment,
+};
+
+use super::shorthand_properties::SuperFinder;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ComputedPropertiesOptions {
+    /// Assign properties instead of defining them. Same as the `setComputedProperties` assumption.
+    pub loose: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ComputedProperties {
+    set_computed_properties: bool,
+}
+
+impl ComputedProperties {
+    pub fn new(options: ComputedPropertiesOptions, state: &TransformState<'_>) -> Self {
+        Self { set_computed_properties: options.loose || state.assumptions.set_computed_properties }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ComputedProperties {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::ObjectExpression(obj) = expr
+            && let Some(index) = Self::first_computed_property(obj)
+        {
+            self.transform_object_expression(expr, index, ctx);
+        }
+    }
+}
+
+impl<'a> ComputedProperties {
+    /// Index of the first computed property, if the object can be transformed.
+    fn first_computed_property(obj: &ObjectExpression<'a>) -> Option<usize> {
+        let index = obj.properties.iter().position(
+            |prop| matches!(prop, ObjectPropertyKind::ObjectProperty(prop) if prop.computed),
+        )?;
+        let can_transform = obj.properties[index..].iter().all(|prop| match prop {
+            ObjectPropertyKind::ObjectProperty(prop) => !matches!(
+                &prop.value,
+                Expression::FunctionExpression(func)
+                    if (prop.method || prop.kind != PropertyKind::Init) && SuperFinder::find(func)
+            ),
+            ObjectPropertyKind::SpreadProperty(_) | ObjectPropertyKind::ErrorNode(_) => false,
+        });
+        can_transform.then_some(index)
+    }
+
+    /// `{ a: 1, [b]: 2, c: 3 }` ->
+    /// `(_obj = { a: 1 }, babelHelpers.defineProperty(_obj, b, 2), babelHelpers.defineProperty(_obj, "c", 3), _obj)`
+    fn transform_object_expression(
+        self,
+        expr: &mut Expression<'a>,
+        index: usize,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::ObjectExpression(obj) = expr else { unreachable!() };
+        let span = obj.span;
+        let computed_props = obj.properties.split_off(index);
+        let init_obj = expr.take_in(ctx);
+
+        // `{ [a]: b }` -> `babelHelpers.defineProperty({}, a, b)`.
+        // Assigning needs the object twice, so it is only done with a temp var.
+        let is_single = matches!(computed_props.as_slice(), [ObjectPropertyKind::ObjectProperty(prop)]
+            if prop.kind != PropertyKind::Init || !self.set_computed_properties);
+        if is_single {
+            let Some(ObjectPropertyKind::ObjectProperty(prop)) = computed_props.into_iter().next()
+            else {
+                unreachable!()
+            };
+            let mut result = self.define_property(init_obj, prop.unbox(), ctx);
+            if let Expression::CallExpression(call) = &mut result {
+                call.span = span;
+            }
+            *expr = result;
+            return;
+        }
+
+        let binding = Self::create_temp_var(ctx);
+        let mut expressions = ArenaVec::with_capacity_in(computed_props.len() + 2, ctx);
+        expressions.push(create_assignment(&binding, init_obj, SPAN, ctx));
+        for prop in computed_props {
+            let ObjectPropertyKind::ObjectProperty(prop) = prop else { unreachable!() };
+            let object = binding.create_read_expression(ctx);
+            expressions.push(self.define_property(object, prop.unbox(), ctx));
+        }
+        expressions.push(binding.create_read_expression(ctx));
+        *expr = Expression::new_sequence_expression(span, expressions, ctx);
+    }
+
+    /// Create the temp var for the object, named after the variable or assignment target
+    /// the object is assigned to.
+    fn create_temp_var(ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        let name = match ctx.parent() {
+            Ancestor::VariableDeclaratorInit(decl) => Some(get_var_name_from_node(decl.id())),
+            Ancestor::AssignmentExpressionRight(assign) => {
+                Some(get_var_name_from_node(assign.left()))
+            }
+            _ => None,
+        };
+        VarDeclarationsStore::create_uid_var(name.as_deref().unwrap_or("obj"), ctx)
+    }
+
+    /// Define `prop` on `object`.
+    ///
+    /// * `get [a]() {}` -> `babelHelpers.defineAccessor("get", object, a, function () {})`
+    /// * `[a]: b` -> `babelHelpers.defineProperty(object, a, b)`
+    /// * `[a]: b` -> `object[a] = b` (with `setComputedProperties`)
+    /// * `a: b` -> `object.a = b` (with `setComputedProperties`)
+    /// * `__proto__: b` -> `object.__proto__ = b`, which sets the prototype as the literal would
+    fn define_property(
+        self,
+        object: Expression<'a>,
+        prop: ObjectProperty<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let ObjectProperty { span, kind, key, value, method, shorthand, computed, .. } = prop;
+
+        if let Expression::FunctionExpression(func) = &value {
+            ctx.scoping_mut()
+                .scope_flags_mut(func.scope_id())
+                .remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+        }
+
+        let kind = match kind {
+            PropertyKind::Init => None,
+            PropertyKind::Get => Some("get"),
+            PropertyKind::Set => Some("set"),
+        };
+
+        // `{ __proto__: b }` sets the prototype, unlike `{ ["__proto__"]: b }`, `{ __proto__ }`
+        // or `{ __proto__() {} }`, which define an own property
+        let is_proto_setter = kind.is_none()
+            && !computed
+            && !method
+            && !shorthand
+            && key.is_specific_static_name("__proto__");
+
+        if (self.set_computed_properties && kind.is_none()) || is_proto_setter {
+            let target = match key {
+                PropertyKey::StaticIdentifier(ident) if !computed => {
+                    let property = ident.unbox();
+                    MemberExpression::new_static_member_expression(
+                        SPAN, object, property, false, ctx,
+                    )
+                }
+                key => {
+                    let property = key.into_expression();
+                    MemberExpression::new_computed_member_expression(
+                        SPAN, object, property, false, ctx,
+                    )
+                }
+            };
+            let target = AssignmentTarget::from(target);
+            return Expression::new_assignment_expression(
+                span,
+                AssignmentOperator::Assign,
+                target,
+                value,
+                ctx,
+            );
+        }
+
+        let key = match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                Expression::new_string_literal(ident.span, ident.name, None, ctx)
+            }
+            key => key.into_expression(),
+        };
+        let mut arguments = ArenaVec::with_capacity_in(4, ctx);
+        let helper = if let Some(kind) = kind {
+            arguments.push(Argument::new_string_literal(SPAN, kind, None, ctx));
+            Helper::DefineAccessor
+        } else {
+            Helper::DefineProperty
+        };
+        arguments.push(Argument::from(object));
+        arguments.push(Argument::from(key));
+        arguments.push(Argument::from(value));
+        helper_call_expr(helper, arguments, ctx)
+    }
+}
@@ -0,0 +1,998 @@
+//! ES2015: Destructuring
+//!
+//! This plugin transforms array and object destructuring in variable declarations, assignments,
+//! `for-in` / `for-of` statements and `catch` clauses.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var { a, b: { c } } = obj;
+//! var [d, e = 1, ...f] = arr;
+//! ({ a, c } = obj);
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _obj = obj, a = _obj.a, c = _obj.b.c;
+//! var _arr = babelHelpers.toArray(arr), d = _arr[0], _arr$ = _arr[1], e = _arr$ === void 0 ? 1 : _arr$, f = _arr.slice(2);
+//! var _obj2;
+//! _obj2 = obj, a = _obj2.a, c = _obj2.c;
+//! ```
+//!
+//! With `iterableIsArray` assumption (or `loose` option), destructured values are assumed to be
+//! arrays, and are indexed directly:
+//! ```js
+//! var _arr = arr, d = _arr[0], _arr$ = _arr[1], e = _arr$ === void 0 ? 1 : _arr$, f = _arr.slice(2);
+//! ```
+//!
+//! With `arrayLikeIsIterable` assumption, array-like objects are also accepted:
+//! ```js
+//! var _arr = babelHelpers.maybeArrayLike(babelHelpers.toArray, arr);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-destructuring](https://babeljs.io/docs/babel-plugin-transform-destructuring).
+//!
+//! Differences from Babel:
+//!
+//! * Assignments are transformed to a sequence expression, rather than multiple statements.
+//! * The destructured value is only stored in a temp var by patterns which read it more than once,
+//!   so `var [a] = arr` does not produce `var _arr = arr, _arr2 = babelHelpers.slicedToArray(_arr, 1)`.
+//! * A default value for a nested pattern in a `var` declaration is stored in a new temp var,
+//!   rather than reassigning the existing one, so the declaration is not split.
+//! * Object rest (`{ ...a }`) must be transformed by the object rest spread transform first.
+//! * Destructured function parameters are moved into the function body by the parameters
+//!   transform, and are then transformed as variable declarations.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-destructuring>
+//! * Destructuring assignment: <https://tc39.es/ecma262/#sec-destructuring-assignment>
+
+use std::mem;
+
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+
+use oxc_allocator::{ArenaBox, ArenaVec, TakeIn};
+use oxc_ast::{ast::*, builder::NONE};
+use oxc_ast_visit::Visit;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN};
+use oxc_str::{Ident, static_ident};
+use oxc_traverse::{
+    Ancestor, BoundIdentifier, MaybeBoundIdentifier, Traverse,
+    ast_operations::get_var_name_from_node,
+};
+
+use crate::{
+    common::{
+        helper_loader::{Helper, helper_call_expr},
+        var_declarations::VarDeclarationsStore,
+    },
+    context::TraverseCtx,
+    state::TransformState,
+    utils::{ast_builder::create_member_callee, kind_to_symbol_flags},
+};
+
+use super::{
+    parameters::create_number,
+    spread::{create_arguments_slice, create_to_array, is_arguments},
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DestructuringOptions {
+    /// Assume all destructured values are arrays. Same as the `iterableIsArray` assumption.
+    pub loose: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Destructuring {
+    iterable_is_array: bool,
+    array_like_is_iterable: bool,
+}
+
+impl Destructuring {
+    pub fn new(options: DestructuringOptions, state: &TransformState<'_>) -> Self {
+        Self {
+            iterable_is_array: options.loose || state.assumptions.iterable_is_array,
+            array_like_is_iterable: state.assumptions.array_like_is_iterable,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Destructuring {
+    // `export var { a } = b;` -> `var { a } = b; export { a };`
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Statement::ExportNamedDeclaration(export) = stmt
+            && let Some(Declaration::VariableDeclaration(decl)) = &export.declaration
+            && decl.declarations.iter().any(|declarator| !declarator.id.is_binding_identifier())
+        {
+            Self::transform_export_named_declaration(stmt, ctx);
+        }
+    }
+
+    // `var { a } = b;`
+    // Includes `for (var [a] = b;;);`
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if decl
+            .declarations
+            .iter()
+            .any(|declarator| declarator.init.is_some() && !declarator.id.is_binding_identifier())
+        {
+            self.transform_variable_declaration(decl, ctx);
+        }
+    }
+
+    // `({ a } = b)`
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::AssignmentExpression(assign) = expr
+            && assign.left.is_assignment_target_pattern()
+        {
+            self.transform_assignment_expression(expr, ctx);
+        }
+    }
+
+    // `for (var [a] in b);` `for ([a] in b);`
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id();
+        Self::transform_for_statement_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+
+    // `for (var [a] of b);` `for ([a] of b);`
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id();
+        Self::transform_for_statement_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+
+    // `try {} catch ({ a }) {}`
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        if clause.param.as_ref().is_some_and(|param| !param.pattern.is_binding_identifier()) {
+            Self::transform_catch_clause(clause, ctx);
+        }
+    }
+}
+
+impl<'a> Destructuring {
+    /// `var { a, b: [c] } = d, e = 1;`
+    /// -> `var a = d.a, _d$b = babelHelpers.slicedToArray(d.b, 1), c = _d$b[0], e = 1;`
+    fn transform_variable_declaration(
+        self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let kind = decl.kind;
+        let scope_id =
+            if kind.is_var() { ctx.current_hoist_scope_id() } else { ctx.current_scope_id() };
+        let symbol_flags = kind_to_symbol_flags(kind);
+        let mode = Mode::Declaration { kind, scope_id, symbol_flags };
+        let mut transformer = DestructuringTransformer::new(self, mode);
+        for declarator in decl.declarations.take_in(ctx) {
+            match declarator.init {
+                Some(init) if !declarator.id.is_binding_identifier() => {
+                    let pattern = Pattern::from_binding_pattern(declarator.id, ctx);
+                    transformer.push(pattern, init, ctx);
+                }
+                _ => transformer.declarators.push(declarator),
+            }
+        }
+        decl.declarations = ArenaVec::from_iter_in(transformer.declarators, ctx);
+    }
+
+    /// * `[a, b] = c;` -> `var _c; _c = c, a = _c[0], b = _c[1];`
+    /// * `x = [a, b] = c;` -> `var _c; x = (_c = c, a = _c[0], b = _c[1], _c);`
+    fn transform_assignment_expression(self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let is_value_used = Self::is_value_used(ctx);
+        let Expression::AssignmentExpression(assign) = expr else { unreachable!() };
+        let span = assign.span;
+        let pattern = Pattern::from_assignment_target(assign.left.take_in(ctx), ctx);
+        let value = assign.right.take_in(ctx);
+
+        let mut transformer = DestructuringTransformer::new(self, Mode::Assignment);
+        if is_value_used {
+            let is_array = matches!(value, Expression::ArrayExpression(_));
+            let binding = transformer.generate_temp(&get_var_name_from_node(&value), ctx);
+            if is_array {
+                transformer.array_ref = Some(binding.symbol_id);
+            }
+            transformer.push_temp(&binding, value, ctx);
+            transformer.push(pattern, binding.create_read_expression(ctx), ctx);
+            transformer.expressions.push(binding.create_read_expression(ctx));
+        } else {
+            transformer.push(pattern, value, ctx);
+        }
+
+        let mut expressions = transformer.expressions;
+        *expr = match expressions.len() {
+            0 => Expression::new_void_0(span, ctx),
+            1 => expressions.pop().unwrap(),
+            _ => Expression::new_sequence_expression(
+                span,
+                ArenaVec::from_iter_in(expressions, ctx),
+                ctx,
+            ),
+        };
+    }
+
+    /// Returns `false` if the value of the current expression is discarded.
+    fn is_value_used(ctx: &TraverseCtx<'a>) -> bool {
+        let mut ancestors = ctx.ancestors().skip_while(|ancestor| {
+            matches!(ancestor, Ancestor::ParenthesizedExpressionExpression(_))
+        });
+        match ancestors.next() {
+            // `() => [a] = b` returns the value
+            Some(Ancestor::ExpressionStatementExpression(_)) => matches!(
+                (ancestors.next(), ancestors.next()),
+                (
+                    Some(Ancestor::FunctionBodyStatements(_)),
+                    Some(Ancestor::ArrowFunctionExpressionBody(arrow))
+                ) if *arrow.expression()
+            ),
+            Some(Ancestor::ForStatementInit(_) | Ancestor::ForStatementUpdate(_)) => false,
+            _ => true,
+        }
+    }
+
+    /// * `for (let [a] of b) {}` -> `for (let _ref of b) { let [a] = _ref; }`
+    /// * `for ([a] of b) {}` -> `for (var _ref of b) { [a] = _ref; }`
+    ///
+    /// The inserted declaration or assignment is transformed when the body is visited.
+    fn transform_for_statement_left(
+        left: &mut ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let stmt = if let ForStatementLeft::VariableDeclaration(decl) = left {
+            let kind = decl.kind;
+            let declarator = &mut decl.declarations[0];
+            if declarator.id.is_binding_identifier() {
+                return;
+            }
+            let binding_scope_id =
+                if kind.is_var() { ctx.current_hoist_scope_id() } else { scope_id };
+            let binding = ctx.generate_uid("ref", binding_scope_id, kind_to_symbol_flags(kind));
+            let pattern = mem::replace(&mut declarator.id, binding.create_binding_pattern(ctx));
+            let block_scope_id = Self::ensure_block(body, scope_id, ctx);
+            // Move the bindings from the loop head to the loop body.
+            if !kind.is_var() {
+                pattern.bound_names(&mut |ident| {
+                    ctx.scoping_mut().move_binding_by_symbol_id(
+                        scope_id,
+                        block_scope_id,
+                        ident.symbol_id(),
+                    );
+                });
+            }
+            let init = binding.create_read_expression(ctx);
+            let declarator =
+                VariableDeclarator::new(SPAN, kind, pattern, NONE, Some(init), false, ctx);
+            let declarations = ArenaVec::from_value_in(declarator, ctx);
+            Statement::VariableDeclaration(VariableDeclaration::boxed(
+                SPAN,
+                kind,
+                declarations,
+                false,
+                ctx,
+            ))
+        } else {
+            if !left.to_assignment_target().is_assignment_target_pattern() {
+                return;
+            }
+            let target = left.to_assignment_target_mut().take_in(ctx);
+            let flags = SymbolFlags::FunctionScopedVariable;
+            let binding = ctx.generate_uid("ref", ctx.current_hoist_scope_id(), flags);
+            let kind = VariableDeclarationKind::Var;
+            let id = binding.create_binding_pattern(ctx);
+            let declarations = ArenaVec::from_value_in(
+                VariableDeclarator::new(SPAN, kind, id, NONE, None, false, ctx),
+                ctx,
+            );
+            *left = ForStatementLeft::VariableDeclaration(VariableDeclaration::boxed(
+                SPAN,
+                kind,
+                declarations,
+                false,
+                ctx,
+            ));
+            Self::ensure_block(body, scope_id, ctx);
+            let value = binding.create_read_expression(ctx);
+            let expr = Expression::new_assignment_expression(
+                SPAN,
+                AssignmentOperator::Assign,
+                target,
+                value,
+                ctx,
+            );
+            Statement::new_expression_statement(SPAN, expr, ctx)
+        };
+
+        let Statement::BlockStatement(block) = body else { unreachable!() };
+        block.body.insert(0, stmt);
+    }
+
+    /// Wrap the body of a loop in a block, if it is not one already. Returns the scope of the block.
+    ///
+    /// Also used by the for-of transform.
+    pub(super) fn ensure_block(
+        body: &mut Statement<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ScopeId {
+        match body {
+            Statement::BlockStatement(block) => block.scope_id(),
+            Statement::EmptyStatement(empty) => {
+                let span = empty.span;
+                let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+                *body = Statement::new_block_statement_with_scope_id(
+                    span,
+                    ArenaVec::new_in(ctx),
+                    scope_id,
+                    ctx,
+                );
+                scope_id
+            }
+            _ => Self::wrap_in_block(body, parent_scope_id, ctx),
+        }
+    }
+
+    /// Wrap a statement in a new block. Returns the scope of the block.
+    pub(super) fn wrap_in_block(
+        body: &mut Statement<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ScopeId {
+        let scope_id = ctx.insert_scope_below_statement_from_scope_id(
+            body,
+            parent_scope_id,
+            ScopeFlags::empty(),
+        );
+        let stmt = body.take_in(ctx);
+        let span = stmt.span();
+        *body = Statement::new_block_statement_with_scope_id(
+            span,
+            ArenaVec::from_value_in(stmt, ctx),
+            scope_id,
+            ctx,
+        );
+        scope_id
+    }
+
+    /// `try {} catch ({ a }) {}` -> `try {} catch (_ref) { let { a } = _ref; }`
+    ///
+    /// The inserted declaration is transformed when the body is visited.
+    fn transform_catch_clause(clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Bindings of the catch parameter are in the scope of the body
+        let body_scope_id = clause.body.scope_id();
+        let Some(param) = &mut clause.param else { unreachable!() };
+
+        let flags = SymbolFlags::FunctionScopedVariable | SymbolFlags::CatchVariable;
+        let binding = ctx.generate_uid("ref", body_scope_id, flags);
+        let pattern = mem::replace(&mut param.pattern, binding.create_binding_pattern(ctx));
+        // The bindings of the pattern are now declared by `let`
+        pattern.bound_names(&mut |ident| {
+            *ctx.scoping_mut().symbol_flags_mut(ident.symbol_id()) =
+                SymbolFlags::BlockScopedVariable;
+        });
+
+        let kind = VariableDeclarationKind::Let;
+        let init = binding.create_read_expression(ctx);
+        let declarator = VariableDeclarator::new(SPAN, kind, pattern, NONE, Some(init), false, ctx);
+        let declarations = ArenaVec::from_value_in(declarator, ctx);
+        let decl = VariableDeclaration::boxed(SPAN, kind, declarations, false, ctx);
+        clause.body.body.insert(0, Statement::VariableDeclaration(decl));
+    }
+
+    /// `export var { a, b: [c] } = d;` -> `var { a, b: [c] } = d; export { a, c };`
+    ///
+    /// Otherwise temp vars would be exported too.
+    fn transform_export_named_declaration(stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Statement::ExportNamedDeclaration(export) = stmt else { unreachable!() };
+        let Some(Declaration::VariableDeclaration(decl)) = export.declaration.take() else {
+            unreachable!()
+        };
+
+        let kind = ImportOrExportKind::Value;
+        let mut specifiers = ArenaVec::new_in(ctx);
+        decl.bound_names(&mut |ident| {
+            let binding = BoundIdentifier::from_binding_ident(ident);
+            let local = ModuleExportName::IdentifierReference(binding.create_read_reference(ctx));
+            let exported = ModuleExportName::new_identifier_name(SPAN, ident.name, ctx);
+            specifiers.push(ExportSpecifier::new(SPAN, local, exported, kind, ctx));
+        });
+        let export_decl = ModuleDeclaration::new_export_named_declaration(
+            SPAN, None, specifiers, None, kind, NONE, ctx,
+        );
+
+        let new_stmt = Statement::VariableDeclaration(decl);
+        ctx.state.statement_injector.move_insertions(stmt, &new_stmt);
+        ctx.state.statement_injector.insert_after(&new_stmt, Statement::from(export_decl));
+        *stmt = new_stmt;
+    }
+}
+
+/// Where the output of [`DestructuringTransformer`] goes.
+#[derive(Debug, Clone, Copy)]
+enum Mode {
+    /// `var { a } = b`. Produces declarators, and temp vars are declared in the same declaration.
+    Declaration { kind: VariableDeclarationKind, scope_id: ScopeId, symbol_flags: SymbolFlags },
+    /// `({ a } = b)`. Produces assignment expressions, and temp vars are declared with `var`.
+    Assignment,
+}
+
+/// Lowers a [`Pattern`] to a list of declarators or assignments.
+///
+/// Based on Babel's `DestructuringTransformer`.
+struct DestructuringTransformer<'a> {
+    mode: Mode,
+    iterable_is_array: bool,
+    array_like_is_iterable: bool,
+    /// Temp vars. They are never reassigned after being read, so can be read multiple times.
+    temps: FxHashSet<SymbolId>,
+    /// Temp var holding an array literal, which does not need to be converted to an array.
+    array_ref: Option<SymbolId>,
+    /// Output in [`Mode::Declaration`].
+    declarators: Vec<VariableDeclarator<'a>>,
+    /// Output in [`Mode::Assignment`].
+    expressions: Vec<Expression<'a>>,
+}
+
+impl<'a> DestructuringTransformer<'a> {
+    fn new(destructuring: Destructuring, mode: Mode) -> Self {
+        Self {
+            mode,
+            iterable_is_array: destructuring.iterable_is_array,
+            array_like_is_iterable: destructuring.array_like_is_iterable,
+            temps: FxHashSet::default(),
+            array_ref: None,
+            declarators: vec![],
+            expressions: vec![],
+        }
+    }
+
+    /// Destructure `value` into `pattern`.
+    ///
+    /// `value` is only stored in a temp var by patterns which read it more than once.
+    fn push(&mut self, pattern: Pattern<'a>, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match pattern {
+            Pattern::Leaf(leaf) => self.push_leaf(leaf, value, ctx),
+            Pattern::Object(properties) => self.push_object_pattern(properties, value, ctx),
+            Pattern::Array { elements, rest } => {
+                self.push_array_pattern(elements, rest, value, ctx);
+            }
+            Pattern::Default { pattern, init } => self.push_default(*pattern, init, value, ctx),
+        }
+    }
+
+    /// * Declaration: `a = value`
+    /// * Assignment: `a = value`, `a.b = value`
+    fn push_leaf(&mut self, leaf: Leaf<'a>, value: Expression<'a>, ctx: &TraverseCtx<'a>) {
+        match leaf {
+            Leaf::Binding(ident) => {
+                let Mode::Declaration { kind, .. } = self.mode else { unreachable!() };
+                let id = BindingPattern::BindingIdentifier(ident);
+                let declarator =
+                    VariableDeclarator::new(SPAN, kind, id, NONE, Some(value), false, ctx);
+                self.declarators.push(declarator);
+            }
+            Leaf::Target(target) => {
+                let target = AssignmentTarget::from(target);
+                let op = AssignmentOperator::Assign;
+                let expr = Expression::new_assignment_expression(SPAN, op, target, value, ctx);
+                self.expressions.push(expr);
+            }
+        }
+    }
+
+    /// Evaluate `value` only for its side effects.
+    fn push_unused(&mut self, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match self.mode {
+            Mode::Declaration { .. } => {
+                let binding = self.generate_temp("ref", ctx);
+                self.push_temp(&binding, value, ctx);
+            }
+            Mode::Assignment => self.expressions.push(value),
+        }
+    }
+
+    fn generate_temp(&mut self, name: &str, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        let binding = match self.mode {
+            Mode::Declaration { scope_id, symbol_flags, .. } => {
+                ctx.generate_uid(name, scope_id, symbol_flags)
+            }
+            Mode::Assignment => VarDeclarationsStore::create_uid_var(name, ctx),
+        };
+        self.temps.insert(binding.symbol_id);
+        binding
+    }
+
+    /// `_temp = value`
+    fn push_temp(
+        &mut self,
+        binding: &BoundIdentifier<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let leaf = match self.mode {
+            Mode::Declaration { .. } => {
+                Leaf::Binding(ArenaBox::new_in(binding.create_binding_identifier(ctx), ctx))
+            }
+            Mode::Assignment => Leaf::Target(binding.create_write_simple_target(ctx)),
+        };
+        self.push_leaf(leaf, value, ctx);
+    }
+
+    /// Get a reference to `value` which can be read multiple times.
+    ///
+    /// Stores `value` in a temp var, unless it is `this`, or an identifier which is not reassigned.
+    fn memoize(&mut self, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> ValueRef<'a> {
+        match &value {
+            Expression::ThisExpression(_) => return ValueRef::This,
+            Expression::Identifier(ident) => {
+                let scoping = ctx.scoping();
+                if let Some(symbol_id) = scoping.get_reference(ident.reference_id()).symbol_id()
+                    && (self.temps.contains(&symbol_id) || !scoping.symbol_is_mutated(symbol_id))
+                {
+                    let identifier = MaybeBoundIdentifier::from_identifier_reference(ident, ctx);
+                    return ValueRef::Identifier(Some(value), identifier);
+                }
+            }
+            _ => {}
+        }
+        let binding = self.generate_temp(&get_var_name_from_node(&value), ctx);
+        self.push_temp(&binding, value, ctx);
+        ValueRef::Identifier(None, binding.to_maybe_bound_identifier())
+    }
+
+    /// * `{ a, b: { c } } = value` -> `a = value.a, c = value.b.c`
+    /// * `{} = value` -> `babelHelpers.objectDestructuringEmpty(value)`
+    fn push_object_pattern(
+        &mut self,
+        properties: Vec<PatternProperty<'a>>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if properties.is_empty() {
+            let arguments = ArenaVec::from_value_in(Argument::from(value), ctx);
+            let call = helper_call_expr(Helper::ObjectDestructuringEmpty, arguments, ctx);
+            self.push_unused(call, ctx);
+            return;
+        }
+
+        let mut object = if properties.len() == 1 {
+            ValueRef::Once(Some(value))
+        } else {
+            self.memoize(value, ctx)
+        };
+        for property in properties {
+            let object = object.read(ctx);
+            let value = match property.key {
+                PropertyKey::StaticIdentifier(ident) if !property.computed => {
+                    Expression::new_static_member_expression(
+                        SPAN,
+                        object,
+                        ident.unbox(),
+                        false,
+                        ctx,
+                    )
+                }
+                // `{ "a-b": c }` and `{ [a]: b }` -> `value["a-b"]`, `value[a]`
+                key => Expression::new_computed_member_expression(
+                    SPAN,
+                    object,
+                    key.into_expression(),
+                    false,
+                    ctx,
+                ),
+            };
+            self.push(property.value, value, ctx);
+        }
+    }
+
+    /// * `[a, , b] = value` -> `_value = babelHelpers.slicedToArray(value, 3), a = _value[0], b = _value[2]`
+    /// * `[a, ...b] = value` -> `_value = babelHelpers.toArray(value), a = _value[0], b = _value.slice(1)`
+    /// * `[a, b] = [1, 2]` -> `a = 1, b = 2`
+    fn push_array_pattern(
+        &mut self,
+        elements: Vec<Option<Pattern<'a>>>,
+        rest: Option<Box<Pattern<'a>>>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if Self::can_unpack_array_pattern(&elements, rest.as_deref(), &value) {
+            self.push_unpacked_array_pattern(elements, rest, value, ctx);
+            return;
+        }
+
+        let count = elements.len();
+        let is_array = match &value {
+            Expression::Identifier(ident) => {
+                self.iterable_is_array || self.is_array_ref(ident, ctx)
+            }
+            _ => false,
+        };
+        let mut array = if is_array {
+            if rest.is_none() && elements.iter().all(Option::is_none) {
+                self.push_unused(value, ctx);
+                return;
+            }
+            self.memoize(value, ctx)
+        } else {
+            let binding = self.generate_temp(&get_var_name_from_node(&value), ctx);
+            let length = rest.is_none().then_some(count);
+            let array = self.to_array(value, length, ctx);
+            self.push_temp(&binding, array, ctx);
+            ValueRef::Identifier(None, binding.to_maybe_bound_identifier())
+        };
+
+        for (index, element) in elements.into_iter().enumerate() {
+            let Some(element) = element else { continue };
+            let index = create_number(index, ctx);
+            let value = Expression::new_computed_member_expression(
+                SPAN,
+                array.read(ctx),
+                index,
+                false,
+                ctx,
+            );
+            self.push(element, value, ctx);
+        }
+        if let Some(rest) = rest {
+            let callee = create_member_callee(array.read(ctx), static_ident!("slice"), SPAN, ctx);
+            let arguments = ArenaVec::from_value_in(Argument::from(create_number(count, ctx)), ctx);
+            let value = Expression::new_call_expression(SPAN, callee, NONE, arguments, false, ctx);
+            self.push(*rest, value, ctx);
+        }
+    }
+
+    /// Whether `[a, b] = [x, y]` can be transformed to `a = x, b = y`.
+    fn can_unpack_array_pattern(
+        elements: &[Option<Pattern<'a>>],
+        rest: Option<&Pattern<'a>>,
+        value: &Expression<'a>,
+    ) -> bool {
+        let Expression::ArrayExpression(array) = value else { return false };
+        let count = elements.len() + usize::from(rest.is_some());
+        if count > array.elements.len() || (count < array.elements.len() && rest.is_none()) {
+            return false;
+        }
+        // Holes and member expressions in the pattern
+        if !elements.iter().all(|element| element.as_ref().is_some_and(|e| !e.is_member_target())) {
+            return false;
+        }
+        // Elements which could observe the assignments
+        if array.elements.iter().any(|element| match element {
+            ArrayExpressionElement::SpreadElement(_) => true,
+            ArrayExpressionElement::Elision(_) => false,
+            element => {
+                let expr = element.to_expression();
+                expr.is_call_expression() || expr.is_member_expression()
+            }
+        }) {
+            return false;
+        }
+        // References to the bindings of the pattern, e.g. `[a, b] = [b, a]`
+        let mut names = vec![];
+        for element in elements.iter().flatten().chain(rest) {
+            element.binding_names(&mut names);
+        }
+        let mut finder = ReferenceFinder { names: &names, found: false };
+        finder.visit_array_expression(array);
+        !finder.found
+    }
+
+    fn push_unpacked_array_pattern(
+        &mut self,
+        elements: Vec<Option<Pattern<'a>>>,
+        rest: Option<Box<Pattern<'a>>>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::ArrayExpression(array) = value else { unreachable!() };
+        let mut values = array
+            .unbox()
+            .elements
+            .into_iter()
+            .map(|element| match element {
+                ArrayExpressionElement::Elision(_) => Expression::new_void_0(SPAN, ctx),
+                element => element.into_expression(),
+            })
+            .collect::<Vec<_>>()
+            .into_iter();
+        for element in elements.into_iter().flatten() {
+            let value = values.next().unwrap();
+            self.push(element, value, ctx);
+        }
+        if let Some(rest) = rest {
+            let elements = ArenaVec::from_iter_in(values.map(ArrayExpressionElement::from), ctx);
+            let value = Expression::new_array_expression(SPAN, elements, ctx);
+            self.push(*rest, value, ctx);
+        }
+    }
+
+    /// * `babelHelpers.slicedToArray(value, length)`
+    /// * `babelHelpers.toArray(value)` if the pattern has a rest element
+    /// * `Array.prototype.slice.call(arguments)`
+    fn to_array(
+        &self,
+        value: Expression<'a>,
+        length: Option<usize>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if self.iterable_is_array || matches!(value, Expression::ArrayExpression(_)) {
+            return value;
+        }
+        if is_arguments(&value, ctx) {
+            return create_arguments_slice(value, ctx);
+        }
+        let (helper, length) = match length {
+            Some(length) => (Helper::SlicedToArray, Some(create_number(length, ctx))),
+            None => (Helper::ToArray, None),
+        };
+        create_to_array(helper, value, length, self.array_like_is_iterable, ctx)
+    }
+
+    fn is_array_ref(&self, ident: &IdentifierReference<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        self.array_ref.is_some_and(|symbol_id| {
+            ctx.scoping().get_reference(ident.reference_id()).symbol_id() == Some(symbol_id)
+        })
+    }
+
+    /// * `a = 1` with `value` -> `_value = value, a = _value === void 0 ? 1 : _value`
+    /// * `[a] = []` with `value` -> `_value = value, _value2 = _value === void 0 ? [] : _value, ...`
+    fn push_default(
+        &mut self,
+        pattern: Pattern<'a>,
+        init: Expression<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if value.is_void_0() {
+            self.push(pattern, init, ctx);
+            return;
+        }
+
+        let binding = self.generate_temp(&get_var_name_from_node(&value), ctx);
+        self.push_temp(&binding, value, ctx);
+        let test = Expression::new_binary_expression(
+            SPAN,
+            binding.create_read_expression(ctx),
+            BinaryOperator::StrictEquality,
+            Expression::new_void_0(SPAN, ctx),
+            ctx,
+        );
+        let consequent = binding.create_read_expression(ctx);
+        let value = Expression::new_conditional_expression(SPAN, test, init, consequent, ctx);
+
+        if let Pattern::Leaf(leaf) = pattern {
+            self.push_leaf(leaf, value, ctx);
+        } else {
+            // A declared temp var cannot be reassigned in the same declaration
+            let binding = match self.mode {
+                Mode::Declaration { .. } => self.generate_temp(&binding.name, ctx),
+                Mode::Assignment => binding,
+            };
+            self.push_temp(&binding, value, ctx);
+            self.push(pattern, binding.create_read_expression(ctx), ctx);
+        }
+    }
+}
+
+/// A value being destructured, which may be read more than once.
+enum ValueRef<'a> {
+    /// Expression which is only read once.
+    Once(Option<Expression<'a>>),
+    /// `this`.
+    This,
+    /// Identifier or temp var. The original expression (if any) is used for the first read.
+    Identifier(Option<Expression<'a>>, MaybeBoundIdentifier<'a>),
+}
+
+impl<'a> ValueRef<'a> {
+    fn read(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match self {
+            Self::Once(expr) => expr.take().expect("value should only be read once"),
+            Self::This => Expression::new_this_expression(SPAN, ctx),
+            Self::Identifier(expr, identifier) => {
+                expr.take().unwrap_or_else(|| identifier.create_read_expression(ctx))
+            }
+        }
+    }
+}
+
+/// A destructuring pattern, from either a [`BindingPattern`] or an [`AssignmentTarget`].
+enum Pattern<'a> {
+    Leaf(Leaf<'a>),
+    Object(Vec<PatternProperty<'a>>),
+    Array { elements: Vec<Option<Pattern<'a>>>, rest: Option<Box<Pattern<'a>>> },
+    Default { pattern: Box<Pattern<'a>>, init: Expression<'a> },
+}
+
+enum Leaf<'a> {
+    /// `a` in `var { a } = b`
+    Binding(ArenaBox<'a, BindingIdentifier<'a>>),
+    /// `a` or `a.b` in `({ x: a, y: a.b } = c)`
+    Target(SimpleAssignmentTarget<'a>),
+}
+
+struct PatternProperty<'a> {
+    key: PropertyKey<'a>,
+    computed: bool,
+    value: Pattern<'a>,
+}
+
+impl<'a> Pattern<'a> {
+    fn from_binding_pattern(pattern: BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) -> Self {
+        match pattern {
+            BindingPattern::BindingIdentifier(ident) => Self::Leaf(Leaf::Binding(ident)),
+            BindingPattern::ObjectPattern(pattern) => {
+                let pattern = pattern.unbox();
+                if let Some(rest) = pattern.rest {
+                    Self::report_object_rest(rest.span, ctx);
+                }
+                let properties = pattern
+                    .properties
+                    .into_iter()
+                    .map(|property| PatternProperty {
+                        key: property.key,
+                        computed: property.computed,
+                        value: Self::from_binding_pattern(property.value, ctx),
+                    })
+                    .collect();
+                Self::Object(properties)
+            }
+            BindingPattern::ArrayPattern(pattern) => {
+                let pattern = pattern.unbox();
+                let elements = pattern
+                    .elements
+                    .into_iter()
+                    .map(|element| element.map(|element| Self::from_binding_pattern(element, ctx)))
+                    .collect();
+                let rest = pattern
+                    .rest
+                    .map(|rest| Box::new(Self::from_binding_pattern(rest.unbox().argument, ctx)));
+                Self::Array { elements, rest }
+            }
+            BindingPattern::AssignmentPattern(pattern) => {
+                let pattern = pattern.unbox();
+                let init = pattern.right;
+                Self::Default {
+                    pattern: Box::new(Self::from_binding_pattern(pattern.left, ctx)),
+                    init,
+                }
+            }
+        }
+    }
+
+    fn from_assignment_target(target: AssignmentTarget<'a>, ctx: &mut TraverseCtx<'a>) -> Self {
+        match target {
+            AssignmentTarget::ObjectAssignmentTarget(target) => {
+                let target = target.unbox();
+                if let Some(rest) = target.rest {
+                    Self::report_object_rest(rest.span, ctx);
+                }
+                let properties = target
+                    .properties
+                    .into_iter()
+                    .map(|property| match property {
+                        // `{ a = 1 }`
+                        AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                            let property = property.unbox();
+                            let ident = property.binding;
+                            let key =
+                                PropertyKey::new_static_identifier(ident.span, ident.name, ctx);
+                            let target = SimpleAssignmentTarget::AssignmentTargetIdentifier(
+                                ArenaBox::new_in(ident, ctx),
+                            );
+                            let mut value = Self::Leaf(Leaf::Target(target));
+                            if let Some(init) = property.init {
+                                value = Self::Default { pattern: Box::new(value), init };
+                            }
+                            PatternProperty { key, computed: false, value }
+                        }
+                        // `{ a: b = 1 }`
+                        AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                            let property = property.unbox();
+                            PatternProperty {
+                                key: property.name,
+                                computed: property.computed,
+                                value: Self::from_maybe_default(property.binding, ctx),
+                            }
+                        }
+                    })
+                    .collect();
+                Self::Object(properties)
+            }
+            AssignmentTarget::ArrayAssignmentTarget(target) => {
+                let target = target.unbox();
+                let elements = target
+                    .elements
+                    .into_iter()
+                    .map(|element| element.map(|element| Self::from_maybe_default(element, ctx)))
+                    .collect();
+                let rest = target
+                    .rest
+                    .map(|rest| Box::new(Self::from_assignment_target(rest.unbox().target, ctx)));
+                Self::Array { elements, rest }
+            }
+            target => Self::Leaf(Leaf::Target(target.into_simple_assignment_target())),
+        }
+    }
+
+    fn from_maybe_default(
+        target: AssignmentTargetMaybeDefault<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Self {
+        match target {
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                let target = target.unbox();
+                let pattern = Box::new(Self::from_assignment_target(target.binding, ctx));
+                Self::Default { pattern, init: target.init }
+            }
+            target => Self::from_assignment_target(target.into_assignment_target(), ctx),
+        }
+    }
+
+    fn report_object_rest(span: Span, ctx: &mut TraverseCtx<'a>) {
+        ctx.state.error(
+            OxcDiagnostic::error("Object rest in destructuring cannot be transformed on its own.")
+                .with_label(span)
+                .with_help("Enable the object-rest-spread transform."),
+        );
+    }
+
+    /// `true` for `a.b` in `[a.b] = c`.
+    fn is_member_target(&self) -> bool {
+        matches!(self, Self::Leaf(Leaf::Target(target))
+            if !matches!(target, SimpleAssignmentTarget::AssignmentTargetIdentifier(_)))
+    }
+
+    fn binding_names(&self, names: &mut Vec<Ident<'a>>) {
+        match self {
+            Self::Leaf(Leaf::Binding(ident)) => names.push(ident.name),
+            Self::Leaf(Leaf::Target(SimpleAssignmentTarget::AssignmentTargetIdentifier(ident))) => {
+                names.push(ident.name);
+            }
+            Self::Leaf(Leaf::Target(_)) => {}
+            Self::Object(properties) => {
+                for property in properties {
+                    property.value.binding_names(names);
+                }
+            }
+            Self::Array { elements, rest } => {
+                for element in elements.iter().flatten() {
+                    element.binding_names(names);
+                }
+                if let Some(rest) = rest {
+                    rest.binding_names(names);
+                }
+            }
+            Self::Default { pattern, .. } => pattern.binding_names(names),
+        }
+    }
+}
+
+/// Visitor to find references to any of `names`.
+struct ReferenceFinder<'a, 'n> {
+    names: &'n [Ident<'a>],
+    found: bool,
+}
+
+impl<'a> Visit<'a> for ReferenceFinder<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if self.names.contains(&ident.name) {
+            self.found = true;
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! ES2015: Duplicate Keys
+//!
+//! This plugin makes duplicate keys in object literals computed, because duplicate keys are a
+//! syntax error in strict mode in ES5.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var x = { a: 5, a: 6 };
+//! var y = {
+//!   get a() {},
+//!   set a(x) {},
+//!   a: 3,
+//! };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var x = { a: 5, ["a"]: 6 };
+//! var y = {
+//!   get a() {},
+//!   set a(x) {},
+//!   ["a"]: 3,
+//! };
+//! ```
+//!
+//! A getter and a setter for the same key are not duplicates of each other.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-duplicate-keys](https://babeljs.io/docs/babel-plugin-transform-duplicate-keys).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-duplicate-keys/src/index.ts>
+
+use std::borrow::Cow;
+
+use rustc_hash::FxHashSet;
+
+use oxc_ast::ast::*;
+use oxc_span::GetSpan;
+use oxc_str::Str;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+pub struct DuplicateKeys;
+
+impl DuplicateKeys {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for DuplicateKeys {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::ObjectExpression(obj) = expr
+            && obj.properties.len() > 1
+        {
+            Self::transform_object_expression(obj, ctx);
+        }
+    }
+}
+
+impl<'a> DuplicateKeys {
+    fn transform_object_expression(obj: &mut ObjectExpression<'a>, ctx: &TraverseCtx<'a>) {
+        let mut seen_data = FxHashSet::<Cow<'a, str>>::default();
+        let mut seen_getters = FxHashSet::<Cow<'a, str>>::default();
+        let mut seen_setters = FxHashSet::<Cow<'a, str>>::default();
+
+        for prop in &mut obj.properties {
+            let ObjectPropertyKind::ObjectProperty(prop) = prop else { continue };
+            if prop.computed {
+                continue;
+            }
+            let Some(name) = prop.key.static_name() else { continue };
+
+            let is_duplicate = match prop.kind {
+                PropertyKind::Get => {
+                    seen_data.contains(&name) || !seen_getters.insert(name.clone())
+                }
+                PropertyKind::Set => {
+                    seen_data.contains(&name) || !seen_setters.insert(name.clone())
+                }
+                PropertyKind::Init => {
+                    seen_getters.contains(&name)
+                        || seen_setters.contains(&name)
+                        || !seen_data.insert(name.clone())
+                }
+            };
+
+            if is_duplicate {
+                let span = prop.key.span();
+                let name = Str::from_str_in(&name, ctx);
+                prop.key = PropertyKey::new_string_literal(span, name, None, ctx);
+                prop.computed = true;
+                prop.shorthand = false;
+            }
+        }
+    }
+}
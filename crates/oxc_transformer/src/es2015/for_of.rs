@@ -0,0 +1,499 @@
+//! ES2015: For Of
+//!
+//! This plugin transforms `for...of` loops into `for` loops over an iterator helper, or over the
+//! indexes of an array literal.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! for (const x of y) {
+//!   foo(x);
+//! }
+//! for (const x of [1, 2, 3]) {
+//!   foo(x);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _iterator = babelHelpers.createForOfIteratorHelper(y), _step;
+//! try {
+//!   for (_iterator.s(); !(_step = _iterator.n()).done;) {
+//!     const x = _step.value;
+//!     foo(x);
+//!   }
+//! } catch (err) {
+//!   _iterator.e(err);
+//! } finally {
+//!   _iterator.f();
+//! }
+//! for (var _i = 0, _arr = [1, 2, 3]; _i < _arr.length; _i++) {
+//!   const x = _arr[_i];
+//!   foo(x);
+//! }
+//! ```
+//!
+//! With `skipForOfIteratorClosing` assumption (or `loose` option), the iterator is not closed
+//! when the loop exits early, or throws:
+//! ```js
+//! for (var _iterator = babelHelpers.createForOfIteratorHelperLoose(y), _step; !(_step = _iterator()).done;) {
+//!   const x = _step.value;
+//!   foo(x);
+//! }
+//! ```
+//!
+//! With `iterableIsArray` assumption (or `assumeArray` option), every loop is compiled as if it
+//! iterated over an array literal.
+//!
+//! With `arrayLikeIsIterable` assumption (or `allowArrayLike` option), array-like objects are
+//! also accepted by the iterator helper:
+//! ```js
+//! var _iterator = babelHelpers.createForOfIteratorHelper(y, true), _step;
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-for-of](https://babeljs.io/docs/babel-plugin-transform-for-of).
+//!
+//! `for await` loops are left for the async generator functions transform.
+//!
+//! If the loop body declares a binding with the same name as a binding declared by the loop's
+//! left side, the body is nested in another block. Babel renames the body's binding.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-for-of/src/index.ts>
+//! * `for...of` statement: <https://tc39.es/ecma262/#sec-for-in-and-for-of-statements>
+
+use serde::Deserialize;
+
+use oxc_allocator::{ArenaBox, ArenaVec, TakeIn};
+use oxc_ast::{ast::*, builder::NONE};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_str::{Ident, static_ident};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, ast_operations::GatherNodeParts};
+
+use crate::{
+    common::helper_loader::{Helper, helper_call_expr},
+    context::TraverseCtx,
+    state::TransformState,
+    utils::ast_builder::{create_member_callee, create_property_access},
+};
+
+use super::Destructuring;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ForOfOptions {
+    /// Do not close iterators. Same as the `skipForOfIteratorClosing` assumption.
+    pub loose: bool,
+    /// Assume all iterables are arrays. Same as the `iterableIsArray` assumption.
+    pub assume_array: bool,
+    /// Accept array-like objects. Same as the `arrayLikeIsIterable` assumption.
+    pub allow_array_like: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ForOf {
+    skip_for_of_iterator_closing: bool,
+    iterable_is_array: bool,
+    array_like_is_iterable: bool,
+}
+
+impl ForOf {
+    pub fn new(options: ForOfOptions, state: &TransformState<'_>) -> Self {
+        Self {
+            skip_for_of_iterator_closing: options.loose
+                || state.assumptions.skip_for_of_iterator_closing,
+            iterable_is_array: options.assume_array || state.assumptions.iterable_is_array,
+            array_like_is_iterable: options.allow_array_like
+                || state.assumptions.array_like_is_iterable,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ForOf {
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            // The label of a loop wrapped in `try` must be moved inside it
+            Statement::LabeledStatement(labeled) => {
+                if let Statement::ForOfStatement(for_of) = &labeled.body
+                    && !for_of.r#await
+                    && !self.is_array_loop(for_of)
+                    && !self.skip_for_of_iterator_closing
+                {
+                    self.transform_iterator_loop(stmt, ctx);
+                }
+            }
+            Statement::ForOfStatement(for_of) if !for_of.r#await => {
+                if self.is_array_loop(for_of) {
+                    Self::transform_array_loop(stmt, ctx);
+                } else {
+                    self.transform_iterator_loop(stmt, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> ForOf {
+    fn is_array_loop(self, for_of: &ForOfStatement<'a>) -> bool {
+        self.iterable_is_array || matches!(for_of.right, Expression::ArrayExpression(_))
+    }
+
+    /// `for (const x of [a, b]) {}` -> `for (var _i = 0, _arr = [a, b]; _i < _arr.length; _i++) { const x = _arr[_i]; }`
+    fn transform_array_loop(stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Statement::ForOfStatement(for_of) = stmt else { unreachable!() };
+        let span = for_of.span;
+        let scope_id = for_of.scope_id();
+        let right = for_of.right.take_in(ctx);
+
+        let hoist_scope_id = ctx.current_hoist_scope_id();
+        let flags = SymbolFlags::FunctionScopedVariable;
+        let mut has_name = false;
+        right.gather(&mut |_| has_name = true);
+        let array = if has_name {
+            ctx.generate_uid_based_on_node(&right, hoist_scope_id, flags)
+        } else {
+            ctx.generate_uid("arr", hoist_scope_id, flags)
+        };
+        let index = ctx.generate_uid("i", hoist_scope_id, flags);
+
+        // `_arr[_i]`
+        let value = Expression::from(MemberExpression::new_computed_member_expression(
+            SPAN,
+            array.create_read_expression(ctx),
+            index.create_read_expression(ctx),
+            false,
+            ctx,
+        ));
+        let body = Self::create_body(for_of, value, ctx);
+
+        // `var _i = 0, _arr = [a, b]`
+        let zero = Expression::new_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal, ctx);
+        let init = Self::create_var_declaration([(&index, Some(zero)), (&array, Some(right))], ctx);
+        // `_i < _arr.length`
+        let length = create_property_access(SPAN, array.create_read_expression(ctx), "length", ctx);
+        let test = Expression::new_binary_expression(
+            SPAN,
+            index.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            length,
+            ctx,
+        );
+        // `_i++`
+        let update = Expression::new_update_expression(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            index.create_read_write_simple_target(ctx),
+            ctx,
+        );
+
+        let new_stmt = Statement::new_for_statement_with_scope_id(
+            span,
+            Some(ForStatementInit::VariableDeclaration(init)),
+            Some(test),
+            Some(update),
+            body,
+            scope_id,
+            ctx,
+        );
+        ctx.state.statement_injector.move_insertions(stmt, &new_stmt);
+        *stmt = new_stmt;
+    }
+
+    /// Transform a loop over an iterator. `stmt` is either a `ForOfStatement`, or a `LabeledStatement`
+    /// with a `ForOfStatement` body.
+    ///
+    /// ```js
+    /// var _iterator = babelHelpers.createForOfIteratorHelper(y), _step;
+    /// try {
+    ///   for (_iterator.s(); !(_step = _iterator.n()).done;) {
+    ///     const x = _step.value;
+    ///   }
+    /// } catch (err) {
+    ///   _iterator.e(err);
+    /// } finally {
+    ///   _iterator.f();
+    /// }
+    /// ```
+    fn transform_iterator_loop(self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let (for_of, label) = match stmt {
+            Statement::ForOfStatement(for_of) => (for_of, None),
+            Statement::LabeledStatement(labeled) => {
+                let LabeledStatement { label, body, .. } = labeled.as_mut();
+                let Statement::ForOfStatement(for_of) = body else { unreachable!() };
+                (for_of, Some(label.clone()))
+            }
+            _ => unreachable!(),
+        };
+        let span = for_of.span;
+        let scope_id = for_of.scope_id();
+        let right = for_of.right.take_in(ctx);
+
+        let hoist_scope_id = ctx.current_hoist_scope_id();
+        let flags = SymbolFlags::FunctionScopedVariable;
+        let iterator = ctx.generate_uid("iterator", hoist_scope_id, flags);
+        let step = ctx.generate_uid("step", hoist_scope_id, flags);
+
+        // `_step.value`
+        let value = create_property_access(SPAN, step.create_read_expression(ctx), "value", ctx);
+        let body = Self::create_body(for_of, value, ctx);
+
+        // `babelHelpers.createForOfIteratorHelper(y, true)`
+        let mut arguments = ArenaVec::from_value_in(Argument::from(right), ctx);
+        if self.array_like_is_iterable {
+            arguments.push(Argument::new_boolean_literal(SPAN, true, ctx));
+        }
+        let helper = if self.skip_for_of_iterator_closing {
+            Helper::CreateForOfIteratorHelperLoose
+        } else {
+            Helper::CreateForOfIteratorHelper
+        };
+        let helper_call = helper_call_expr(helper, arguments, ctx);
+        let declaration =
+            Self::create_var_declaration([(&iterator, Some(helper_call)), (&step, None)], ctx);
+
+        // `!(_step = _iterator.n()).done`, or `!(_step = _iterator()).done`
+        let next = if self.skip_for_of_iterator_closing {
+            iterator.create_read_expression(ctx)
+        } else {
+            create_member_callee(
+                iterator.create_read_expression(ctx),
+                static_ident!("n"),
+                SPAN,
+                ctx,
+            )
+        };
+        let next =
+            Expression::new_call_expression(SPAN, next, NONE, ArenaVec::new_in(ctx), false, ctx);
+        let assignment = Expression::new_assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            step.create_write_target(ctx),
+            next,
+            ctx,
+        );
+        let assignment = Expression::new_parenthesized_expression(SPAN, assignment, ctx);
+        let done = create_property_access(SPAN, assignment, "done", ctx);
+        let test = Expression::new_unary_expression(SPAN, UnaryOperator::LogicalNot, done, ctx);
+
+        if self.skip_for_of_iterator_closing {
+            let new_stmt = Statement::new_for_statement_with_scope_id(
+                span,
+                Some(ForStatementInit::VariableDeclaration(declaration)),
+                Some(test),
+                None,
+                body,
+                scope_id,
+                ctx,
+            );
+            ctx.state.statement_injector.move_insertions(stmt, &new_stmt);
+            *stmt = new_stmt;
+            return;
+        }
+
+        let allow_multiple_statements = matches!(
+            ctx.parent(),
+            Ancestor::ProgramBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::TSModuleBlockBody(_)
+        );
+        let parent_scope_id = if allow_multiple_statements {
+            ctx.current_scope_id()
+        } else {
+            ctx.create_child_scope_of_current(ScopeFlags::empty())
+        };
+
+        // `try { for (_iterator.s(); ...) {} }`
+        let try_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        ctx.scoping_mut().change_scope_parent_id(scope_id, Some(try_scope_id));
+        let start = create_member_callee(
+            iterator.create_read_expression(ctx),
+            static_ident!("s"),
+            SPAN,
+            ctx,
+        );
+        let start =
+            Expression::new_call_expression(SPAN, start, NONE, ArenaVec::new_in(ctx), false, ctx);
+        let mut for_stmt = Statement::new_for_statement_with_scope_id(
+            span,
+            Some(ForStatementInit::from(start)),
+            Some(test),
+            None,
+            body,
+            scope_id,
+            ctx,
+        );
+        if let Some(label) = label {
+            for_stmt = Statement::new_labeled_statement(SPAN, label, for_stmt, ctx);
+        }
+        let block = BlockStatement::new_with_scope_id(
+            SPAN,
+            ArenaVec::from_value_in(for_stmt, ctx),
+            try_scope_id,
+            ctx,
+        );
+
+        // `catch (err) { _iterator.e(err); }`
+        let catch_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::CatchClause);
+        let catch_body_scope_id = ctx.create_child_scope(catch_scope_id, ScopeFlags::empty());
+        let err = ctx.generate_binding(
+            static_ident!("err"),
+            catch_body_scope_id,
+            SymbolFlags::CatchVariable | SymbolFlags::FunctionScopedVariable,
+        );
+        let param = CatchParameter::new(SPAN, err.create_binding_pattern(ctx), NONE, ctx);
+        let error = Self::create_iterator_call(
+            &iterator,
+            static_ident!("e"),
+            Some(err.create_read_expression(ctx)),
+            ctx,
+        );
+        let catch_body = BlockStatement::new_with_scope_id(
+            SPAN,
+            ArenaVec::from_value_in(error, ctx),
+            catch_body_scope_id,
+            ctx,
+        );
+        let handler =
+            CatchClause::new_with_scope_id(SPAN, Some(param), catch_body, catch_scope_id, ctx);
+
+        // `finally { _iterator.f(); }`
+        let finally_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let finish = Self::create_iterator_call(&iterator, static_ident!("f"), None, ctx);
+        let finalizer = BlockStatement::new_with_scope_id(
+            SPAN,
+            ArenaVec::from_value_in(finish, ctx),
+            finally_scope_id,
+            ctx,
+        );
+
+        let try_stmt =
+            Statement::new_try_statement(SPAN, block, Some(handler), Some(finalizer), ctx);
+        let declaration = Statement::VariableDeclaration(declaration);
+        let new_stmt = if allow_multiple_statements {
+            ctx.state.statement_injector.move_insertions(stmt, &try_stmt);
+            ctx.state.statement_injector.insert_before(&try_stmt, declaration);
+            try_stmt
+        } else {
+            Statement::new_block_statement_with_scope_id(
+                SPAN,
+                ArenaVec::from_array_in([declaration, try_stmt], ctx),
+                parent_scope_id,
+                ctx,
+            )
+        };
+        *stmt = new_stmt;
+    }
+
+    /// Create the body of the new loop, starting with the declaration or assignment of the left side.
+    ///
+    /// `for (const x of y) foo(x);` -> `{ const x = value; foo(x); }`
+    fn create_body(
+        for_of: &mut ForOfStatement<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id = for_of.scope_id();
+        let block_scope_id = Self::ensure_body_block(for_of, ctx);
+        let declaration = match &mut for_of.left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                let kind = decl.kind;
+                let mut declarator = decl.declarations.pop().unwrap();
+                declarator.init = Some(value);
+                // Move the bindings from the loop head to the loop body
+                if !kind.is_var() {
+                    declarator.id.bound_names(&mut |ident| {
+                        ctx.scoping_mut().move_binding_by_symbol_id(
+                            scope_id,
+                            block_scope_id,
+                            ident.symbol_id(),
+                        );
+                    });
+                }
+                let declarations = ArenaVec::from_value_in(declarator, ctx);
+                Statement::new_variable_declaration(SPAN, kind, declarations, false, ctx)
+            }
+            left @ match_assignment_target!(ForStatementLeft) => {
+                let target = left.to_assignment_target_mut().take_in(ctx);
+                let expr = Expression::new_assignment_expression(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target,
+                    value,
+                    ctx,
+                );
+                Statement::new_expression_statement(SPAN, expr, ctx)
+            }
+        };
+
+        let mut body = for_of.body.take_in(ctx);
+        let Statement::BlockStatement(block) = &mut body else { unreachable!() };
+        block.body.insert(0, declaration);
+        body
+    }
+
+    /// Make sure the loop body is a block which can also hold the bindings of the loop head.
+    /// Returns the scope of the block.
+    fn ensure_body_block(for_of: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) -> ScopeId {
+        let scope_id = for_of.scope_id();
+        if let (ForStatementLeft::VariableDeclaration(decl), Statement::BlockStatement(block)) =
+            (&for_of.left, &for_of.body)
+        {
+            let block_scope_id = block.scope_id();
+            let mut has_conflict = false;
+            decl.bound_names(&mut |ident| {
+                has_conflict |= ctx.scoping().scope_has_binding(block_scope_id, ident.name);
+            });
+            if has_conflict {
+                return Destructuring::wrap_in_block(&mut for_of.body, scope_id, ctx);
+            }
+        }
+        Destructuring::ensure_block(&mut for_of.body, scope_id, ctx)
+    }
+
+    /// `var a = init, b`
+    fn create_var_declaration<const N: usize>(
+        declarators: [(&BoundIdentifier<'a>, Option<Expression<'a>>); N],
+        ctx: &TraverseCtx<'a>,
+    ) -> ArenaBox<'a, VariableDeclaration<'a>> {
+        let kind = VariableDeclarationKind::Var;
+        let declarators = declarators.into_iter().map(|(binding, init)| {
+            VariableDeclarator::new(
+                SPAN,
+                kind,
+                binding.create_binding_pattern(ctx),
+                NONE,
+                init,
+                false,
+                ctx,
+            )
+        });
+        let declarators = ArenaVec::from_iter_in(declarators, ctx);
+        VariableDeclaration::boxed(SPAN, kind, declarators, false, ctx)
+    }
+
+    /// `_iterator.method(argument);`
+    fn create_iterator_call(
+        iterator: &BoundIdentifier<'a>,
+        method: Ident<'a>,
+        argument: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let callee = create_member_callee(iterator.create_read_expression(ctx), method, SPAN, ctx);
+        let arguments = ArenaVec::from_iter_in(argument.map(Argument::from), ctx);
+        let call = Expression::new_call_expression(SPAN, callee, NONE, arguments, false, ctx);
+        Statement::new_expression_statement(SPAN, call, ctx)
+    }
+}
@@ -0,0 +1,177 @@
+//! ES2015: Function Name
+//!
+//! This plugin names anonymous function expressions after the variable, assignment target or
+//! property key they are assigned to, as ES2015 engines do implicitly.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var foo = function () {};
+//! bar = function () {};
+//! var obj = { baz: function () {} };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var foo = function foo() {};
+//! bar = function bar() {};
+//! var obj = { baz: function baz() {} };
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-function-name](https://babeljs.io/docs/babel-plugin-transform-function-name).
+//!
+//! Differences from Babel:
+//!
+//! * A function is left anonymous if naming it would change what a reference inside it refers to
+//!   (e.g. `var f = function () { return f; }`). Babel renames the inner binding, or wraps the
+//!   function so the reference still reaches the outer binding.
+//! * Names which are not valid identifiers are not converted (Babel names `{ "a-b": function () {} }`
+//!   `aB`), and reserved words are skipped rather than prefixed with `_`.
+//! * Only function expressions are named. Anonymous class expressions are named by the classes
+//!   transform.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-function-name/src/index.ts>
+//! * Babel helper implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-helper-function-name/src/index.ts>
+//! * `NamedEvaluation`: <https://tc39.es/ecma262/#sec-runtime-semantics-namedevaluation>
+
+use std::borrow::Cow;
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ScopeFlags, ScopeId, Scoping, SymbolFlags};
+use oxc_str::Ident;
+use oxc_syntax::{identifier::is_identifier_name, keyword::is_reserved_keyword_or_global_object};
+use oxc_traverse::{Ancestor, Traverse};
+
+use crate::{context::TraverseCtx, state::TransformState, utils::sync_function_symbol_flags};
+
+pub struct FunctionName;
+
+impl FunctionName {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for FunctionName {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::FunctionExpression(func) = expr else { return };
+        if func.id.is_some() {
+            return;
+        }
+        if let Some(name) = Self::infer_name(ctx) {
+            let name = Ident::from_cow_in(&name, ctx);
+            name_function(func, name, ctx);
+        }
+    }
+}
+
+impl<'a> FunctionName {
+    /// Name for an anonymous function expression, from its parent.
+    ///
+    /// * `var foo = function () {}` -> `foo`
+    /// * `foo = function () {}` -> `foo`
+    /// * `{ foo: function () {} }`, `{ "foo": function () {} }` -> `foo`
+    fn infer_name(ctx: &TraverseCtx<'a>) -> Option<Cow<'a, str>> {
+        match ctx.parent() {
+            Ancestor::VariableDeclaratorInit(decl) => {
+                decl.id().get_binding_identifier().map(|id| Cow::Borrowed(id.name.as_str()))
+            }
+            Ancestor::AssignmentExpressionRight(assign)
+                if *assign.operator() == AssignmentOperator::Assign =>
+            {
+                match assign.left() {
+                    AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                        Some(Cow::Borrowed(ident.name.as_str()))
+                    }
+                    _ => None,
+                }
+            }
+            Ancestor::ObjectPropertyValue(prop)
+                if *prop.kind() == PropertyKind::Init && !*prop.method() =>
+            {
+                let key = prop.key();
+                if *prop.computed() && !matches!(key, PropertyKey::StringLiteral(_)) {
+                    return None;
+                }
+                key.static_name()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Name a function expression `name`, if that is a valid function name, and would not shadow
+/// a binding that is referenced inside the function.
+///
+/// Returns `true` if the function was named.
+///
+/// Also used by the classes transform to name methods after their keys.
+pub(super) fn name_function<'a>(
+    func: &mut Function<'a>,
+    name: Ident<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> bool {
+    if !is_valid_function_name(name.as_str()) {
+        return false;
+    }
+    let scope_id = func.scope_id();
+    if ctx.scoping().scope_has_binding(scope_id, name)
+        || FunctionNameConflictFinder::find(name, scope_id, func, ctx.scoping())
+    {
+        return false;
+    }
+    let binding = ctx.generate_binding(name, scope_id, SymbolFlags::Function);
+    func.id = Some(binding.create_binding_identifier(ctx));
+    sync_function_symbol_flags(func, ctx);
+    true
+}
+
+/// Whether `name` can be used as the name of a function expression.
+pub(super) fn is_valid_function_name(name: &str) -> bool {
+    is_identifier_name(name)
+        && !is_reserved_keyword_or_global_object(name)
+        && !matches!(name, "arguments" | "eval")
+}
+
+/// Visitor which finds whether naming a function `name` would change what a reference inside it
+/// refers to.
+struct FunctionNameConflictFinder<'a, 's> {
+    name: Ident<'a>,
+    scope_id: ScopeId,
+    scoping: &'s Scoping,
+    found: bool,
+}
+
+impl<'a, 's> FunctionNameConflictFinder<'a, 's> {
+    fn find(name: Ident<'a>, scope_id: ScopeId, func: &Function<'a>, scoping: &'s Scoping) -> bool {
+        let mut finder = Self { name, scope_id, scoping, found: false };
+        finder.visit_function(func, ScopeFlags::Function);
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for FunctionNameConflictFinder<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name != self.name {
+            return;
+        }
+        // Conflict if reference is unresolved, or resolves to a binding outside the function
+        let symbol_id = self.scoping.get_reference(ident.reference_id()).symbol_id();
+        let is_inside = symbol_id.is_some_and(|symbol_id| {
+            let scope_id = self.scoping.symbol_scope_id(symbol_id);
+            scope_id == self.scope_id
+                || self.scoping.scope_is_descendant_of(scope_id, self.scope_id)
+        });
+        if !is_inside {
+            self.found = true;
+        }
+    }
+}
@@ -1,32 +1,453 @@
+use oxc_ast::ast::*;
 use oxc_traverse::Traverse;
 
-use crate::state::TransformState;
+use crate::{context::TraverseCtx, state::TransformState};
 
 mod arrow_functions;
+mod block_scoping;
+mod classes;
+mod computed_properties;
+mod destructuring;
+mod duplicate_keys;
+mod for_of;
+mod function_name;
+mod new_target;
 mod options;
+mod parameters;
+mod regenerator;
+mod shorthand_properties;
+mod spread;
+mod template_literals;
 
 pub use arrow_functions::{ArrowFunctions, ArrowFunctionsOptions};
+pub use block_scoping::{BlockScoping, BlockScopingOptions};
+pub use classes::Classes;
+pub use computed_properties::{ComputedProperties, ComputedPropertiesOptions};
+pub use destructuring::{Destructuring, DestructuringOptions};
+pub use duplicate_keys::DuplicateKeys;
+pub use for_of::{ForOf, ForOfOptions};
+pub use function_name::FunctionName;
+pub use new_target::NewTarget;
 pub use options::ES2015Options;
+pub use parameters::{Parameters, ParametersOptions};
+pub use regenerator::{Regenerator, RegeneratorOptions};
+pub use shorthand_properties::ShorthandProperties;
+pub use spread::{Spread, SpreadOptions};
+pub use template_literals::{TemplateLiterals, TemplateLiteralsOptions};
 
 pub struct ES2015<'a> {
-    #[expect(unused)]
     options: ES2015Options,
 
     // Plugins
     #[expect(unused)]
     arrow_functions: ArrowFunctions,
-
-    _marker: std::marker::PhantomData<&'a ()>,
+    block_scoping: BlockScoping<'a>,
+    classes: Classes<'a>,
+    parameters: Parameters,
+    destructuring: Destructuring,
+    spread: Spread,
+    template_literals: TemplateLiterals,
+    shorthand_properties: ShorthandProperties,
+    computed_properties: ComputedProperties,
+    for_of: ForOf,
+    function_name: FunctionName,
+    duplicate_keys: DuplicateKeys,
+    new_target: NewTarget<'a>,
+    regenerator: Regenerator,
 }
 
 impl ES2015<'_> {
-    pub fn new(options: ES2015Options) -> Self {
+    pub fn new(options: ES2015Options, state: &TransformState<'_>) -> Self {
+        let spread = Spread::new(options.spread.unwrap_or_default(), options.classes, state);
         Self {
             arrow_functions: ArrowFunctions::new(options.arrow_function.unwrap_or_default()),
+            block_scoping: BlockScoping::new(
+                options.block_scoping.unwrap_or_default(),
+                options.classes,
+            ),
+            classes: Classes::new(
+                options.block_scoping.is_some(),
+                options.spread.is_some().then_some(spread),
+            ),
+            parameters: Parameters::new(
+                options.parameters.unwrap_or_default(),
+                options.arrow_function.is_some(),
+                state,
+            ),
+            destructuring: Destructuring::new(options.destructuring.unwrap_or_default(), state),
+            spread,
+            template_literals: TemplateLiterals::new(
+                options.template_literals.unwrap_or_default(),
+                state,
+            ),
+            shorthand_properties: ShorthandProperties::new(),
+            computed_properties: ComputedProperties::new(
+                options.computed_properties.unwrap_or_default(),
+                state,
+            ),
+            for_of: ForOf::new(options.for_of.unwrap_or_default(), state),
+            function_name: FunctionName::new(),
+            duplicate_keys: DuplicateKeys::new(),
+            new_target: NewTarget::new(),
+            regenerator: Regenerator::new(options.regenerator.unwrap_or_default()),
             options,
-            _marker: std::marker::PhantomData,
         }
     }
 }
 
-impl<'a> Traverse<'a, TransformState<'a>> for ES2015<'a> {}
+impl<'a> Traverse<'a, TransformState<'a>> for ES2015<'a> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_program(program, ctx);
+        }
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_program(program, ctx);
+        }
+        if self.options.regenerator.is_some() {
+            self.regenerator.exit_program(program, ctx);
+        }
+    }
+
+    fn enter_function_body(&mut self, body: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_function_body(body, ctx);
+        }
+    }
+
+    fn exit_function_body(&mut self, body: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_function_body(body, ctx);
+        }
+    }
+
+    fn enter_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_static_block(block, ctx);
+        }
+        if self.options.classes {
+            self.classes.enter_static_block(block, ctx);
+        }
+    }
+
+    fn exit_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_static_block(block, ctx);
+        }
+        if self.options.classes {
+            self.classes.exit_static_block(block, ctx);
+        }
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_block_statement(block, ctx);
+        }
+    }
+
+    fn exit_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_block_statement(block, ctx);
+        }
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_switch_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_for_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_for_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_for_in_statement(stmt, ctx);
+        }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_for_in_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_for_in_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_for_of_statement(stmt, ctx);
+        }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_for_of_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_for_of_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_while_statement(&mut self, stmt: &mut WhileStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_while_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_while_statement(&mut self, stmt: &mut WhileStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_while_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_do_while_statement(
+        &mut self,
+        stmt: &mut DoWhileStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_do_while_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_do_while_statement(
+        &mut self,
+        stmt: &mut DoWhileStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_do_while_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_variable_declaration(decl, ctx);
+        }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_variable_declaration(decl, ctx);
+        }
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_binding_identifier(ident, ctx);
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_identifier_reference(ident, ctx);
+        }
+        if self.options.classes {
+            self.classes.enter_identifier_reference(ident, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.duplicate_keys {
+            self.duplicate_keys.enter_expression(expr, ctx);
+        }
+        if self.options.function_name {
+            self.function_name.enter_expression(expr, ctx);
+        }
+        if self.options.new_target {
+            self.new_target.enter_expression(expr, ctx);
+        }
+        if self.options.template_literals.is_some() {
+            self.template_literals.enter_expression(expr, ctx);
+        }
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_expression(expr, ctx);
+        }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_expression(expr, ctx);
+        }
+        if self.options.classes {
+            self.classes.enter_expression(expr, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.computed_properties.is_some() {
+            self.computed_properties.exit_expression(expr, ctx);
+        }
+        if self.options.spread.is_some() {
+            self.spread.exit_expression(expr, ctx);
+        }
+        if self.options.regenerator.is_some() {
+            self.regenerator.exit_expression(expr, ctx);
+        }
+        if self.options.classes {
+            self.classes.exit_expression(expr, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.regenerator.is_some() {
+            self.regenerator.exit_statement(stmt, ctx);
+        }
+        if self.options.classes {
+            self.classes.exit_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.classes {
+            self.classes.enter_class(class, ctx);
+        }
+    }
+
+    fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.classes {
+            self.classes.exit_class(class, ctx);
+        }
+    }
+
+    fn enter_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.classes {
+            self.classes.enter_class_body(body, ctx);
+        }
+    }
+
+    fn enter_method_definition(
+        &mut self,
+        method: &mut MethodDefinition<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.classes {
+            self.classes.enter_method_definition(method, ctx);
+        }
+    }
+
+    fn exit_method_definition(
+        &mut self,
+        method: &mut MethodDefinition<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.classes {
+            self.classes.exit_method_definition(method, ctx);
+        }
+    }
+
+    fn enter_property_definition(
+        &mut self,
+        prop: &mut PropertyDefinition<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.classes {
+            self.classes.enter_property_definition(prop, ctx);
+        }
+    }
+
+    fn exit_property_definition(
+        &mut self,
+        prop: &mut PropertyDefinition<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.classes {
+            self.classes.exit_property_definition(prop, ctx);
+        }
+    }
+
+    fn enter_accessor_property(
+        &mut self,
+        prop: &mut AccessorProperty<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.classes {
+            self.classes.enter_accessor_property(prop, ctx);
+        }
+    }
+
+    fn exit_accessor_property(
+        &mut self,
+        prop: &mut AccessorProperty<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.classes {
+            self.classes.exit_accessor_property(prop, ctx);
+        }
+    }
+
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.parameters.is_some() {
+            self.parameters.enter_function(func, ctx);
+        }
+        if self.options.classes {
+            self.classes.enter_function(func, ctx);
+        }
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.new_target {
+            self.new_target.exit_function(func, ctx);
+        }
+        if self.options.regenerator.is_some() {
+            self.regenerator.exit_function(func, ctx);
+        }
+        if self.options.classes {
+            self.classes.exit_function(func, ctx);
+        }
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.parameters.is_some() {
+            self.parameters.enter_arrow_function_expression(arrow, ctx);
+        }
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.for_of.is_some() {
+            self.for_of.enter_statement(stmt, ctx);
+        }
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.shorthand_properties {
+            self.shorthand_properties.enter_object_property(prop, ctx);
+        }
+    }
+
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_catch_clause(clause, ctx);
+        }
+    }
+}
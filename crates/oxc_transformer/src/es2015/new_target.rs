@@ -0,0 +1,214 @@
+//! ES2015: New Target
+//!
+//! This plugin transforms `new.target` meta property.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function Foo() {
+//!   console.log(new.target);
+//! }
+//!
+//! class Bar {
+//!   constructor() {
+//!     console.log(new.target);
+//!   }
+//!   method() {
+//!     console.log(new.target);
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! function Foo() {
+//!   console.log(this instanceof Foo ? this.constructor : void 0);
+//! }
+//!
+//! class Bar {
+//!   constructor() {
+//!     console.log(this.constructor);
+//!   }
+//!   method() {
+//!     console.log(void 0);
+//!   }
+//! }
+//! ```
+//!
+//! Anonymous functions are given a name (`_target`), so they can be referred to.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-new-target](https://babeljs.io/docs/babel-plugin-transform-new-target).
+//!
+//! Differences from Babel:
+//!
+//! * `new.target` in class field initializers and static blocks is `void 0`, as the spec says.
+//!   Babel uses `this.constructor`.
+//! * If the function's name is shadowed where `new.target` appears, an error is reported.
+//!   Babel renames the shadowing binding.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-new-target/src/index.ts>
+//! * `new.target`: <https://tc39.es/ecma262/#sec-meta-properties>
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::ast::*;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeId, SymbolFlags};
+use oxc_span::{SPAN, Span};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    context::TraverseCtx,
+    state::TransformState,
+    utils::{ast_builder::create_property_access, sync_function_symbol_flags},
+};
+
+/// What `new.target` refers to.
+enum Target<'a> {
+    /// Methods, accessors, class field initializers and static blocks: `void 0`.
+    Undefined,
+    /// Class constructor: `this.constructor`.
+    Constructor,
+    /// Plain function: `this instanceof Foo ? this.constructor : void 0`.
+    Function { scope_id: ScopeId, is_declaration: bool, id: Option<BoundIdentifier<'a>> },
+}
+
+pub struct NewTarget<'a> {
+    /// Names generated for anonymous functions, keyed by the function's scope.
+    /// The names are added to the functions in `exit_function`.
+    function_names: FxHashMap<ScopeId, BoundIdentifier<'a>>,
+}
+
+impl NewTarget<'_> {
+    pub fn new() -> Self {
+        Self { function_names: FxHashMap::default() }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for NewTarget<'a> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::MetaProperty(meta) = expr
+            && meta.meta.name == "new"
+            && meta.property.name == "target"
+            && let Some(replacement) = self.transform_new_target(meta.span, ctx)
+        {
+            *expr = replacement;
+        }
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(binding) = self.function_names.remove(&func.scope_id()) {
+            func.id = Some(binding.create_binding_identifier(ctx));
+            sync_function_symbol_flags(func, ctx);
+        }
+    }
+}
+
+impl<'a> NewTarget<'a> {
+    fn transform_new_target(
+        &mut self,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let this_constructor = |ctx: &mut TraverseCtx<'a>| {
+            let this = Expression::new_this_expression(SPAN, ctx);
+            create_property_access(span, this, "constructor", ctx)
+        };
+
+        let (scope_id, is_declaration, id) = match Self::find_target(ctx)? {
+            Target::Undefined => return Some(Expression::new_void_0(span, ctx)),
+            Target::Constructor => return Some(this_constructor(ctx)),
+            Target::Function { scope_id, is_declaration, id } => (scope_id, is_declaration, id),
+        };
+
+        let binding = if let Some(id) = id {
+            // `function Foo() { let Foo; new.target }` - `Foo` does not refer to the function
+            let symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), id.name);
+            if symbol_id != Some(id.symbol_id) {
+                ctx.state.error(
+                    OxcDiagnostic::error(format!(
+                        "`new.target` cannot be transformed, as `{}` is shadowed.",
+                        id.name
+                    ))
+                    .with_label(span),
+                );
+                return None;
+            }
+            id
+        } else {
+            self.function_names
+                .entry(scope_id)
+                .or_insert_with(|| {
+                    // A function declaration's name is bound in the parent scope
+                    let binding_scope_id = if is_declaration {
+                        ctx.scoping().scope_parent_id(scope_id).unwrap()
+                    } else {
+                        scope_id
+                    };
+                    ctx.generate_uid("target", binding_scope_id, SymbolFlags::Function)
+                })
+                .clone()
+        };
+
+        // `this instanceof Foo ? this.constructor : void 0`
+        let test = Expression::new_binary_expression(
+            SPAN,
+            Expression::new_this_expression(SPAN, ctx),
+            BinaryOperator::Instanceof,
+            binding.create_read_expression(ctx),
+            ctx,
+        );
+        let consequent = this_constructor(ctx);
+        let alternate = Expression::new_void_0(SPAN, ctx);
+        Some(Expression::new_conditional_expression(span, test, consequent, alternate, ctx))
+    }
+
+    /// Find what `new.target` refers to, from the closest non-arrow function or class element.
+    ///
+    /// Returns `None` if `new.target` is not inside a function (a syntax error).
+    fn find_target(ctx: &TraverseCtx<'a>) -> Option<Target<'a>> {
+        let mut ancestors = ctx.ancestors();
+        let (scope_id, is_declaration, id) = loop {
+            match ancestors.next()? {
+                Ancestor::FunctionBody(func) => {
+                    break (func.scope_id(), *func.r#type(), func.id());
+                }
+                Ancestor::FunctionParams(func) => {
+                    break (func.scope_id(), *func.r#type(), func.id());
+                }
+                Ancestor::PropertyDefinitionValue(_)
+                | Ancestor::AccessorPropertyValue(_)
+                | Ancestor::StaticBlockBody(_) => return Some(Target::Undefined),
+                _ => {}
+            }
+        };
+
+        let target = match ancestors.next() {
+            Some(Ancestor::MethodDefinitionValue(method)) => {
+                if *method.kind() == MethodDefinitionKind::Constructor {
+                    Target::Constructor
+                } else {
+                    Target::Undefined
+                }
+            }
+            Some(Ancestor::ObjectPropertyValue(prop))
+                if *prop.method() || *prop.kind() != PropertyKind::Init =>
+            {
+                Target::Undefined
+            }
+            _ => Target::Function {
+                scope_id: scope_id.get().unwrap(),
+                is_declaration: is_declaration == FunctionType::FunctionDeclaration,
+                id: id.as_ref().map(BoundIdentifier::from_binding_ident),
+            },
+        };
+        Some(target)
+    }
+}
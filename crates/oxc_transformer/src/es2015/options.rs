@@ -1,6 +1,9 @@
 use serde::Deserialize;
 
-use super::ArrowFunctionsOptions;
+use super::{
+    ArrowFunctionsOptions, BlockScopingOptions, ComputedPropertiesOptions, DestructuringOptions,
+    ForOfOptions, ParametersOptions, RegeneratorOptions, SpreadOptions, TemplateLiteralsOptions,
+};
 
 #[derive(Debug, Default, Clone, Copy, Deserialize)]
 #[serde(default, rename_all = "camelCase", deny_unknown_fields)]
@@ -9,4 +12,56 @@ pub struct ES2015Options {
     /// Arrow-function transform options.
     #[serde(skip)]
     pub arrow_function: Option<ArrowFunctionsOptions>,
+
+    /// Block-scoping transform options.
+    #[serde(skip)]
+    pub block_scoping: Option<BlockScopingOptions>,
+
+    /// Enable classes transform.
+    #[serde(skip)]
+    pub classes: bool,
+
+    /// Parameters transform options.
+    #[serde(skip)]
+    pub parameters: Option<ParametersOptions>,
+
+    /// Destructuring transform options.
+    #[serde(skip)]
+    pub destructuring: Option<DestructuringOptions>,
+
+    /// Spread transform options.
+    #[serde(skip)]
+    pub spread: Option<SpreadOptions>,
+
+    /// Template-literals transform options.
+    #[serde(skip)]
+    pub template_literals: Option<TemplateLiteralsOptions>,
+
+    /// Enable shorthand-properties transform.
+    #[serde(skip)]
+    pub shorthand_properties: bool,
+
+    /// Computed-properties transform options.
+    #[serde(skip)]
+    pub computed_properties: Option<ComputedPropertiesOptions>,
+
+    /// For-of transform options.
+    #[serde(skip)]
+    pub for_of: Option<ForOfOptions>,
+
+    /// Enable function-name transform.
+    #[serde(skip)]
+    pub function_name: bool,
+
+    /// Enable duplicate-keys transform.
+    #[serde(skip)]
+    pub duplicate_keys: bool,
+
+    /// Enable new-target transform.
+    #[serde(skip)]
+    pub new_target: bool,
+
+    /// Regenerator transform options.
+    #[serde(skip)]
+    pub regenerator: Option<RegeneratorOptions>,
 }
@@ -0,0 +1,518 @@
+//! ES2015: Parameters
+//!
+//! This plugin transforms default parameters, destructured parameters and rest parameters.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function foo(a, b = 1, { c }, ...d) {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! function foo(a) {
+//!   var b = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : 1;
+//!   var { c } = arguments.length > 2 ? arguments[2] : undefined;
+//!   for (var _len = arguments.length, d = new Array(_len > 3 ? _len - 3 : 0), _key = 3; _key < _len; _key++) {
+//!     d[_key - 3] = arguments[_key];
+//!   }
+//! }
+//! ```
+//!
+//! Parameters after the first one with a default value are removed, so that `Function#length`
+//! stays the same. With the `ignoreFunctionLength` assumption (or `loose` option), the parameters
+//! are kept and defaults are assigned instead:
+//! ```js
+//! function foo(a, b, _ref) {
+//!   if (b === void 0) {
+//!     b = 1;
+//!   }
+//!   var { c } = _ref;
+//! }
+//! ```
+//! Setters always use this form, as they must have exactly one parameter.
+//!
+//! Destructured parameters are moved into the function body, and are then lowered by
+//! the destructuring transform, if it is enabled.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-parameters](https://babeljs.io/docs/babel-plugin-transform-parameters).
+//!
+//! Differences from Babel:
+//!
+//! * Declarations are `var`, not `let`, so they do not conflict with `var` redeclarations
+//!   of the parameters in the function body.
+//! * Babel wraps the function body in an IIFE when a closure in the parameters could observe
+//!   a binding shadowed in the body. This is not implemented.
+//! * Babel replaces `rest[i]` with `arguments[i + n]` when the rest array is only read by index.
+//!   This is not implemented, the rest array is always created.
+//! * Arrow functions are only converted to use `arguments` if the arrow functions transform is
+//!   enabled. Otherwise default parameters use the `ignoreFunctionLength` form, and rest
+//!   parameters are an error.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-parameters>
+//! * Function definitions: <https://tc39.es/ecma262/#sec-function-definitions>
+
+use serde::Deserialize;
+
+use oxc_allocator::{ArenaVec, TakeIn};
+use oxc_ast::{ast::*, builder::NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_str::static_ident;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ParametersOptions {
+    /// Do not preserve `Function#length`. Same as the `ignoreFunctionLength` assumption.
+    pub loose: bool,
+}
+
+pub struct Parameters {
+    ignore_function_length: bool,
+    /// `true` if the arrow functions transform is enabled.
+    /// Arrow functions can only use `arguments` if they are converted to functions.
+    arrow_functions: bool,
+}
+
+impl Parameters {
+    pub fn new(
+        options: ParametersOptions,
+        arrow_functions: bool,
+        state: &TransformState<'_>,
+    ) -> Self {
+        Self {
+            ignore_function_length: options.loose || state.assumptions.ignore_function_length,
+            arrow_functions,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Parameters {
+    // Transform before the body is traversed, so other transforms visit the moved parameters.
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !has_non_simple_params(&func.params) {
+            return;
+        }
+        let scope_id = func.scope_id();
+        let Some(body) = func.body.as_mut() else { return };
+        let is_setter = match ctx.parent() {
+            Ancestor::MethodDefinitionValue(method) => *method.kind() == MethodDefinitionKind::Set,
+            Ancestor::ObjectPropertyValue(prop) => *prop.kind() == PropertyKind::Set,
+            _ => false,
+        };
+        let loose = self.ignore_function_length || is_setter;
+        Self::transform_params(&mut func.params, &mut body.statements, scope_id, loose, true, ctx);
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !has_non_simple_params(&arrow.params) {
+            return;
+        }
+        if arrow.expression {
+            // `(a = 1) => a` -> `(a = 1) => { return a; }`
+            arrow.expression = false;
+            let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.pop() else {
+                unreachable!("`arrow.expression` is true, so body is a single ExpressionStatement")
+            };
+            let stmt = stmt.unbox();
+            let return_stmt =
+                Statement::new_return_statement(stmt.span, Some(stmt.expression), ctx);
+            arrow.body.statements.push(return_stmt);
+        }
+        let loose = self.ignore_function_length || !self.arrow_functions;
+        let scope_id = arrow.scope_id();
+        Self::transform_params(
+            &mut arrow.params,
+            &mut arrow.body.statements,
+            scope_id,
+            loose,
+            self.arrow_functions,
+            ctx,
+        );
+    }
+}
+
+impl<'a> Parameters {
+    /// Move all non-simple parameters into the function body.
+    ///
+    /// If `loose` is `true`, parameters with default values are kept, and assigned in the body.
+    /// Otherwise, they are removed, and initialized from `arguments`.
+    ///
+    /// Rest parameters require `arguments`, so `can_use_arguments` must be `true` to transform them.
+    fn transform_params(
+        params: &mut FormalParameters<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        loose: bool,
+        can_use_arguments: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut stmts = vec![];
+        let mut rest_stmts = vec![];
+
+        let mut first_optional_index = None;
+        for (index, param) in params.items.iter_mut().enumerate() {
+            if let Some(init) = param.initializer.take() {
+                let init = init.unbox();
+                if loose {
+                    stmts.push(Self::create_loose_default(&mut param.pattern, init, scope_id, ctx));
+                } else {
+                    first_optional_index.get_or_insert(index);
+                    // `var a = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : init`
+                    let pattern = param.pattern.take_in(ctx);
+                    let init = Self::create_default_from_arguments(index, init, ctx);
+                    stmts.push(Self::create_var_declaration(pattern, init, ctx));
+                }
+            } else if first_optional_index.is_some() {
+                // `var a = arguments.length > 1 ? arguments[1] : undefined`
+                let pattern = param.pattern.take_in(ctx);
+                let init = Self::create_safe_arguments_access(index, ctx);
+                stmts.push(Self::create_var_declaration(pattern, init, ctx));
+            } else if !param.pattern.is_binding_identifier() {
+                // `function f({ a }) {}` -> `function f(_ref) { var { a } = _ref; }`
+                let binding =
+                    ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+                let pattern =
+                    std::mem::replace(&mut param.pattern, binding.create_binding_pattern(ctx));
+                let init = binding.create_read_expression(ctx);
+                stmts.push(Self::create_var_declaration(pattern, init, ctx));
+            }
+        }
+
+        if let Some(rest) = params.rest.take() {
+            if can_use_arguments {
+                let start = params.items.len();
+                let argument = rest.unbox().rest.argument;
+                Self::transform_rest(argument, start, scope_id, &mut rest_stmts, ctx);
+            } else {
+                ctx.state.error(
+                    OxcDiagnostic::error(
+                        "Rest parameters in arrow functions can only be transformed together with arrow functions.",
+                    )
+                    .with_label(rest.span)
+                    .with_help("Enable the arrow functions transform."),
+                );
+                params.rest = Some(rest);
+            }
+        }
+
+        if let Some(index) = first_optional_index {
+            params.items.truncate(index);
+        }
+
+        body.splice(0..0, stmts.into_iter().chain(rest_stmts));
+    }
+
+    /// * `a = init` -> `if (a === void 0) { a = init; }`
+    /// * `{ a } = init` -> `_ref`, `var { a } = _ref === void 0 ? init : _ref;`
+    fn create_loose_default(
+        pattern: &mut BindingPattern<'a>,
+        init: Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        if let BindingPattern::BindingIdentifier(ident) = pattern {
+            let binding = BoundIdentifier::from_binding_ident(ident);
+            let assignment = Expression::new_assignment_expression(
+                SPAN,
+                AssignmentOperator::Assign,
+                binding.create_write_target(ctx),
+                init,
+                ctx,
+            );
+            let assignment = Statement::new_expression_statement(SPAN, assignment, ctx);
+            let block_scope_id = ctx.insert_scope_below_statement_from_scope_id(
+                &assignment,
+                scope_id,
+                ScopeFlags::empty(),
+            );
+            let block = Statement::new_block_statement_with_scope_id(
+                SPAN,
+                ArenaVec::from_value_in(assignment, ctx),
+                block_scope_id,
+                ctx,
+            );
+            let test = Self::create_is_undefined(binding.create_read_expression(ctx), ctx);
+            return Statement::new_if_statement(SPAN, test, block, None, ctx);
+        }
+
+        let binding = ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+        let pattern = std::mem::replace(pattern, binding.create_binding_pattern(ctx));
+        let test = Self::create_is_undefined(binding.create_read_expression(ctx), ctx);
+        let value = binding.create_read_expression(ctx);
+        let init = Expression::new_conditional_expression(SPAN, test, init, value, ctx);
+        Self::create_var_declaration(pattern, init, ctx)
+    }
+
+    /// `arguments.length > index && arguments[index] !== undefined ? arguments[index] : init`
+    fn create_default_from_arguments(
+        index: usize,
+        init: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let has_argument = Self::create_arguments_length_greater_than(index, ctx);
+        let argument = Self::create_arguments_access(index, ctx);
+        let undefined =
+            ctx.create_unbound_ident_expr(SPAN, static_ident!("undefined"), ReferenceFlags::Read);
+        let is_defined = Expression::new_binary_expression(
+            SPAN,
+            argument,
+            BinaryOperator::StrictInequality,
+            undefined,
+            ctx,
+        );
+        let test = Expression::new_logical_expression(
+            SPAN,
+            has_argument,
+            LogicalOperator::And,
+            is_defined,
+            ctx,
+        );
+        let argument = Self::create_arguments_access(index, ctx);
+        Expression::new_conditional_expression(SPAN, test, argument, init, ctx)
+    }
+
+    /// `arguments.length > index ? arguments[index] : undefined`
+    fn create_safe_arguments_access(index: usize, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let test = Self::create_arguments_length_greater_than(index, ctx);
+        let argument = Self::create_arguments_access(index, ctx);
+        let undefined =
+            ctx.create_unbound_ident_expr(SPAN, static_ident!("undefined"), ReferenceFlags::Read);
+        Expression::new_conditional_expression(SPAN, test, argument, undefined, ctx)
+    }
+
+    /// `arguments.length > index`
+    fn create_arguments_length_greater_than(
+        index: usize,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let length = Self::create_arguments_length(ctx);
+        let index = create_number(index, ctx);
+        Expression::new_binary_expression(SPAN, length, BinaryOperator::GreaterThan, index, ctx)
+    }
+
+    /// `arguments[index]`
+    fn create_arguments_access(index: usize, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let arguments = Self::create_arguments(ctx);
+        let index = create_number(index, ctx);
+        Expression::new_computed_member_expression(SPAN, arguments, index, false, ctx)
+    }
+
+    /// `arguments.length`
+    fn create_arguments_length(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let arguments = Self::create_arguments(ctx);
+        let property = IdentifierName::new(SPAN, static_ident!("length"), ctx);
+        Expression::new_static_member_expression(SPAN, arguments, property, false, ctx)
+    }
+
+    fn create_arguments(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        ctx.create_unbound_ident_expr(SPAN, static_ident!("arguments"), ReferenceFlags::Read)
+    }
+
+    /// `expr === void 0`
+    fn create_is_undefined(expr: Expression<'a>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        let void_0 = Expression::new_void_0(SPAN, ctx);
+        Expression::new_binary_expression(SPAN, expr, BinaryOperator::StrictEquality, void_0, ctx)
+    }
+
+    /// `var pattern = init;`
+    fn create_var_declaration(
+        pattern: BindingPattern<'a>,
+        init: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Var;
+        let declarator = VariableDeclarator::new(SPAN, kind, pattern, NONE, Some(init), false, ctx);
+        let declarations = ArenaVec::from_value_in(declarator, ctx);
+        Statement::new_variable_declaration(SPAN, kind, declarations, false, ctx)
+    }
+
+    /// Create a loop which collects the rest arguments into an array.
+    ///
+    /// ```js
+    /// for (var _len = arguments.length, rest = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++) {
+    ///   rest[_key - 1] = arguments[_key];
+    /// }
+    /// ```
+    ///
+    /// If the rest element is a pattern, the array is assigned to a temporary variable,
+    /// and then destructured: `var [a, b] = _ref;`.
+    fn transform_rest(
+        mut pattern: BindingPattern<'a>,
+        start: usize,
+        scope_id: ScopeId,
+        stmts: &mut Vec<Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let flags = SymbolFlags::FunctionScopedVariable;
+        let destructure = if pattern.is_binding_identifier() {
+            None
+        } else {
+            let binding = ctx.generate_uid("ref", scope_id, flags);
+            let pattern = std::mem::replace(&mut pattern, binding.create_binding_pattern(ctx));
+            Some(Self::create_var_declaration(pattern, binding.create_read_expression(ctx), ctx))
+        };
+        let BindingPattern::BindingIdentifier(rest) = &pattern else { unreachable!() };
+        let rest_binding = BoundIdentifier::from_binding_ident(rest);
+
+        let len = ctx.generate_uid("len", scope_id, flags);
+        let key = ctx.generate_uid("key", scope_id, flags);
+
+        // `_len > 1 ? _len - 1 : 0` or `_len`
+        let array_len = if start == 0 {
+            len.create_read_expression(ctx)
+        } else {
+            let test = Expression::new_binary_expression(
+                SPAN,
+                len.create_read_expression(ctx),
+                BinaryOperator::GreaterThan,
+                create_number(start, ctx),
+                ctx,
+            );
+            let consequent = Expression::new_binary_expression(
+                SPAN,
+                len.create_read_expression(ctx),
+                BinaryOperator::Subtraction,
+                create_number(start, ctx),
+                ctx,
+            );
+            let alternate = create_number(0, ctx);
+            Expression::new_conditional_expression(SPAN, test, consequent, alternate, ctx)
+        };
+        // `new Array(...)`
+        let array =
+            ctx.create_unbound_ident_expr(SPAN, static_ident!("Array"), ReferenceFlags::Read);
+        let arguments = ArenaVec::from_value_in(Argument::from(array_len), ctx);
+        let new_array = Expression::new_new_expression(SPAN, array, NONE, arguments, ctx);
+
+        // `var _len = arguments.length, rest = new Array(...), _key = 1`
+        let kind = VariableDeclarationKind::Var;
+        let declarations = ArenaVec::from_array_in(
+            [
+                VariableDeclarator::new(
+                    SPAN,
+                    kind,
+                    len.create_binding_pattern(ctx),
+                    NONE,
+                    Some(Self::create_arguments_length(ctx)),
+                    false,
+                    ctx,
+                ),
+                VariableDeclarator::new(SPAN, kind, pattern, NONE, Some(new_array), false, ctx),
+                VariableDeclarator::new(
+                    SPAN,
+                    kind,
+                    key.create_binding_pattern(ctx),
+                    NONE,
+                    Some(create_number(start, ctx)),
+                    false,
+                    ctx,
+                ),
+            ],
+            ctx,
+        );
+        let init = ForStatementInit::new_variable_declaration(SPAN, kind, declarations, false, ctx);
+
+        // `_key < _len`
+        let test = Expression::new_binary_expression(
+            SPAN,
+            key.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            len.create_read_expression(ctx),
+            ctx,
+        );
+        // `_key++`
+        let update = Expression::new_update_expression(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            key.create_read_write_simple_target(ctx),
+            ctx,
+        );
+
+        // `rest[_key - 1] = arguments[_key];`
+        let array_key = if start == 0 {
+            key.create_read_expression(ctx)
+        } else {
+            Expression::new_binary_expression(
+                SPAN,
+                key.create_read_expression(ctx),
+                BinaryOperator::Subtraction,
+                create_number(start, ctx),
+                ctx,
+            )
+        };
+        let target = AssignmentTarget::new_computed_member_expression(
+            SPAN,
+            rest_binding.create_read_expression(ctx),
+            array_key,
+            false,
+            ctx,
+        );
+        let value = Expression::new_computed_member_expression(
+            SPAN,
+            Self::create_arguments(ctx),
+            key.create_read_expression(ctx),
+            false,
+            ctx,
+        );
+        let assignment = Expression::new_assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            target,
+            value,
+            ctx,
+        );
+        let assignment = Statement::new_expression_statement(SPAN, assignment, ctx);
+
+        let for_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+        let block_scope_id = ctx.create_child_scope(for_scope_id, ScopeFlags::empty());
+        let body = Statement::new_block_statement_with_scope_id(
+            SPAN,
+            ArenaVec::from_value_in(assignment, ctx),
+            block_scope_id,
+            ctx,
+        );
+        stmts.push(Statement::new_for_statement_with_scope_id(
+            SPAN,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            for_scope_id,
+            ctx,
+        ));
+        stmts.extend(destructure);
+    }
+}
+
+/// Returns `true` if any parameter is not a plain identifier.
+fn has_non_simple_params(params: &FormalParameters<'_>) -> bool {
+    params.rest.is_some()
+        || params
+            .items
+            .iter()
+            .any(|param| param.initializer.is_some() || !param.pattern.is_binding_identifier())
+}
+
+/// Create a numeric literal for an index or length.
+pub(super) fn create_number<'a>(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    #[expect(clippy::cast_precision_loss)]
+    let value = value as f64;
+    Expression::new_numeric_literal(SPAN, value, None, NumberBase::Decimal, ctx)
+}
@@ -0,0 +1,1346 @@
+//! Emitter of a generator's state machine.
+//!
+//! The statements of the generator's body are "exploded" into a flat listing. A statement
+//! containing a `yield`, or a jump out of it, is broken up so that every point execution can
+//! resume from, or jump to, starts a statement in the listing. Those points are locations, which
+//! are marked when the listing reaches them. The listing is then split at the marked locations into
+//! the cases of the dispatch loop:
+//!
+//! ```js
+//! while (1) switch (_context.prev = _context.next) {
+//!   case 0:
+//!     _context.next = 2;
+//!     return x;
+//!   case 2:
+//!     _context.t0 = _context.sent;
+//!     ...
+//!   case "end":
+//!     return _context.stop();
+//! }
+//! ```
+//!
+//! Intermediate values which must survive a `yield` are stored in properties of the context object
+//! (`_context.t0`, `_context.t1`, ...), as the locals of the inner function do not survive between
+//! calls.
+//!
+//! Port of regenerator's [`emit.js`](https://github.com/facebook/regenerator/blob/v0.14.1/packages/regenerator-transform/src/emit.js).
+
+use std::{cell::Cell, mem};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{
+    Address, ArenaVec, CloneIn, GetAddress, GetAllocator, TakeIn, UnstableAddress,
+};
+use oxc_ast::{ast::*, builder::NONE};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolId};
+use oxc_span::{GetSpan, SPAN, Span};
+use oxc_str::{Ident, Str};
+use oxc_traverse::BoundIdentifier;
+
+use crate::{context::TraverseCtx, utils::ast_builder::create_property_access};
+
+use super::{
+    Regenerator,
+    leap::LeapManager,
+    meta::{
+        argument_contains_leap, assignment_target_contains_leap, expression_contains_leap,
+        statement_contains_leap,
+    },
+};
+
+/// A location in the listing.
+///
+/// Resolves to the index of a statement in the listing once marked.
+#[derive(Debug, Clone, Copy)]
+pub struct Loc(usize);
+
+/// A property of the context object holding an intermediate value: `_context.tN`.
+#[derive(Debug, Clone, Copy)]
+struct TempVar(usize);
+
+/// Locations of a `try` statement, passed to the runtime so it can route exceptions.
+struct TryEntry {
+    first_loc: Loc,
+    catch_loc: Option<Loc>,
+    finally: Option<(Loc, Loc)>,
+}
+
+pub struct Emitter<'a, 'ctx> {
+    ctx: &'ctx mut TraverseCtx<'a>,
+    regenerator: Regenerator,
+    /// Parameter of the inner function: `_context`.
+    context: BoundIdentifier<'a>,
+    /// Scope of the dispatch loop's `switch`, the parent of blocks in the listing.
+    switch_scope_id: ScopeId,
+    listing: Vec<Statement<'a>>,
+    /// Indexes of statements in the listing which start a case.
+    marked: FxHashSet<usize>,
+    /// Index each location resolves to, `None` until it's marked.
+    locs: Vec<Option<usize>>,
+    /// Numeric literals standing for a location, filled in once all locations are marked.
+    loc_literals: FxHashMap<Address, Loc>,
+    /// Location at the end of the listing, where a completed generator goes.
+    final_loc: Loc,
+    next_temp_var: usize,
+    try_entries: Vec<TryEntry>,
+    leap_manager: LeapManager<'a>,
+}
+
+impl<'a, 'ctx> Emitter<'a, 'ctx> {
+    pub fn new(
+        regenerator: Regenerator,
+        context: BoundIdentifier<'a>,
+        switch_scope_id: ScopeId,
+        ctx: &'ctx mut TraverseCtx<'a>,
+    ) -> Self {
+        let mut emitter = Self {
+            ctx,
+            regenerator,
+            context,
+            switch_scope_id,
+            listing: vec![],
+            marked: std::iter::once(0).collect(),
+            locs: vec![],
+            loc_literals: FxHashMap::default(),
+            final_loc: Loc(0),
+            next_temp_var: 0,
+            try_entries: vec![],
+            leap_manager: LeapManager::default(),
+        };
+        emitter.final_loc = emitter.loc();
+        emitter
+    }
+
+    /// Split the listing into the cases of the dispatch loop.
+    ///
+    /// Returns the dispatch loop, and the locations of `try` statements if there are any.
+    pub fn finish(mut self) -> (Statement<'a>, Option<Expression<'a>>) {
+        let final_index = self.listing.len();
+        self.locs[self.final_loc.0] = Some(final_index);
+
+        let mut listing = mem::take(&mut self.listing);
+        let mut patcher = LocPatcher { loc_literals: &self.loc_literals, locs: &self.locs };
+        for stmt in &mut listing {
+            patcher.visit_statement(stmt);
+        }
+
+        // Statements after a completion statement in the same case are unreachable
+        let mut cases: Vec<(usize, ArenaVec<'a, Statement<'a>>)> = vec![];
+        let mut already_ended = false;
+        for (index, stmt) in listing.into_iter().enumerate() {
+            if self.marked.contains(&index) {
+                cases.push((index, ArenaVec::new_in(self.ctx)));
+                already_ended = false;
+            }
+            if already_ended {
+                ReferenceDeleter { ctx: self.ctx }.visit_statement(&stmt);
+            } else {
+                already_ended = matches!(
+                    stmt,
+                    Statement::BreakStatement(_)
+                        | Statement::ContinueStatement(_)
+                        | Statement::ReturnStatement(_)
+                        | Statement::ThrowStatement(_)
+                );
+                cases.last_mut().unwrap().1.push(stmt);
+            }
+        }
+        cases.push((final_index, ArenaVec::new_in(self.ctx)));
+
+        let mut reparenter =
+            ScopeReparenter { ctx: self.ctx, switch_scope_id: self.switch_scope_id, depth: 0 };
+        for (_, consequent) in &cases {
+            for stmt in consequent {
+                reparenter.visit_statement(stmt);
+            }
+        }
+
+        let mut switch_cases = ArenaVec::with_capacity_in(cases.len() + 1, self.ctx);
+        for (index, consequent) in cases {
+            let test = self.index_expr(index);
+            switch_cases.push(SwitchCase::new(SPAN, Some(test), consequent, self.ctx));
+        }
+        // `case "end": return _context.stop();`
+        let stop = self.context_call("stop", ArenaVec::new_in(self.ctx));
+        let test = Expression::new_string_literal(SPAN, "end", None, self.ctx);
+        switch_cases.push(SwitchCase::new(
+            SPAN,
+            Some(test),
+            ArenaVec::from_value_in(
+                Statement::new_return_statement(SPAN, Some(stop), self.ctx),
+                self.ctx,
+            ),
+            self.ctx,
+        ));
+
+        // `while (1) switch (_context.prev = _context.next) { ... }`
+        let discriminant = Expression::new_assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            self.context_target("prev"),
+            self.context_property("next"),
+            self.ctx,
+        );
+        let switch = Statement::new_switch_statement_with_scope_id(
+            SPAN,
+            discriminant,
+            switch_cases,
+            self.switch_scope_id,
+            self.ctx,
+        );
+        let test = Expression::new_numeric_literal(SPAN, 1.0, None, NumberBase::Decimal, self.ctx);
+        let dispatch_loop = Statement::new_while_statement(SPAN, test, switch, self.ctx);
+
+        (dispatch_loop, self.try_locs_list())
+    }
+
+    /// `[[first, catch, finally, after], ...]`
+    fn try_locs_list(&self) -> Option<Expression<'a>> {
+        if self.try_entries.is_empty() {
+            return None;
+        }
+        let mut list = ArenaVec::with_capacity_in(self.try_entries.len(), self.ctx);
+        for entry in &self.try_entries {
+            let mut locs = ArenaVec::with_capacity_in(4, self.ctx);
+            locs.push(ArrayExpressionElement::from(self.resolved_loc_expr(entry.first_loc)));
+            // A hole, so the runtime sees no catch location
+            locs.push(match entry.catch_loc {
+                Some(loc) => ArrayExpressionElement::from(self.resolved_loc_expr(loc)),
+                None => ArrayExpressionElement::new_elision(SPAN, self.ctx),
+            });
+            if let Some((finally_loc, after_loc)) = entry.finally {
+                locs.push(ArrayExpressionElement::from(self.resolved_loc_expr(finally_loc)));
+                locs.push(ArrayExpressionElement::from(self.resolved_loc_expr(after_loc)));
+            }
+            list.push(ArrayExpressionElement::from(Expression::new_array_expression(
+                SPAN, locs, self.ctx,
+            )));
+        }
+        Some(Expression::new_array_expression(SPAN, list, self.ctx))
+    }
+}
+
+// Locations and jumps
+impl<'a> Emitter<'a, '_> {
+    /// Create a new location, to be marked later.
+    fn loc(&mut self) -> Loc {
+        self.locs.push(None);
+        Loc(self.locs.len() - 1)
+    }
+
+    /// Resolve a location to the current end of the listing, and start a new case there.
+    fn mark(&mut self, loc: Loc) -> Loc {
+        let index = self.listing.len();
+        debug_assert!(self.locs[loc.0].is_none_or(|value| value == index));
+        self.locs[loc.0] = Some(index);
+        self.marked.insert(index);
+        loc
+    }
+
+    /// A location at the current end of the listing, without starting a new case there.
+    fn unmarked_current_loc(&mut self) -> Loc {
+        self.locs.push(Some(self.listing.len()));
+        Loc(self.locs.len() - 1)
+    }
+
+    /// A numeric literal standing for a location, which may not be marked yet.
+    fn loc_expr(&mut self, loc: Loc) -> Expression<'a> {
+        let literal = NumericLiteral::boxed(SPAN, 0.0, None, NumberBase::Decimal, self.ctx);
+        self.loc_literals.insert(literal.address(), loc);
+        Expression::NumericLiteral(literal)
+    }
+
+    /// A numeric literal for a location which has been marked.
+    fn resolved_loc_expr(&self, loc: Loc) -> Expression<'a> {
+        self.index_expr(self.locs[loc.0].unwrap())
+    }
+
+    fn index_expr(&self, index: usize) -> Expression<'a> {
+        #[expect(clippy::cast_precision_loss)]
+        let value = index as f64;
+        Expression::new_numeric_literal(SPAN, value, None, NumberBase::Decimal, self.ctx)
+    }
+
+    /// `_context.next = loc; break;`
+    fn jump(&mut self, loc: Loc) {
+        let loc = self.loc_expr(loc);
+        self.jump_to_expr(loc);
+    }
+
+    fn jump_to_expr(&mut self, loc: Expression<'a>) {
+        self.emit_assign_context("next", loc);
+        self.emit(Statement::new_break_statement(SPAN, None, self.ctx));
+    }
+
+    /// `if (test) { _context.next = loc; break; }`
+    fn jump_if(&mut self, test: Expression<'a>, loc: Loc) {
+        let consequent = self.create_jump_block(loc);
+        self.emit(Statement::new_if_statement(SPAN, test, consequent, None, self.ctx));
+    }
+
+    /// `if (!test) { _context.next = loc; break; }`
+    fn jump_if_not(&mut self, test: Expression<'a>, loc: Loc) {
+        let test = match test {
+            Expression::UnaryExpression(mut unary)
+                if unary.operator == UnaryOperator::LogicalNot =>
+            {
+                unary.argument.take_in(self.ctx)
+            }
+            test => {
+                Expression::new_unary_expression(SPAN, UnaryOperator::LogicalNot, test, self.ctx)
+            }
+        };
+        self.jump_if(test, loc);
+    }
+
+    fn create_jump_block(&mut self, loc: Loc) -> Statement<'a> {
+        let loc = self.loc_expr(loc);
+        let assignment = Expression::new_assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            self.context_target("next"),
+            loc,
+            self.ctx,
+        );
+        let mut body = ArenaVec::with_capacity_in(2, self.ctx);
+        body.push(Statement::new_expression_statement(SPAN, assignment, self.ctx));
+        body.push(Statement::new_break_statement(SPAN, None, self.ctx));
+        let scope_id = self.ctx.create_child_scope(self.switch_scope_id, ScopeFlags::empty());
+        Statement::new_block_statement_with_scope_id(SPAN, body, scope_id, self.ctx)
+    }
+
+    /// `_context.prev = loc;`
+    ///
+    /// `loc` must resolve to the current end of the listing.
+    /// If it is `None`, an unmarked location at the current end of the listing is used.
+    fn update_context_prev(&mut self, loc: Option<Loc>) -> Loc {
+        let loc = match loc {
+            Some(loc) => {
+                let index = self.listing.len();
+                let value = &mut self.locs[loc.0];
+                debug_assert!(value.is_none_or(|value| value == index));
+                *value = Some(index);
+                loc
+            }
+            None => self.unmarked_current_loc(),
+        };
+        let loc_expr = self.loc_expr(loc);
+        self.emit_assign_context("prev", loc_expr);
+        loc
+    }
+
+    /// `return _context.abrupt(type, value);`
+    fn emit_abrupt_completion(
+        &mut self,
+        span: Span,
+        r#type: &'static str,
+        value: Option<Expression<'a>>,
+    ) {
+        let mut arguments = ArenaVec::with_capacity_in(2, self.ctx);
+        arguments
+            .push(Argument::from(Expression::new_string_literal(SPAN, r#type, None, self.ctx)));
+        if let Some(value) = value {
+            arguments.push(Argument::from(value));
+        }
+        let abrupt = self.context_call("abrupt", arguments);
+        self.emit(Statement::new_return_statement(span, Some(abrupt), self.ctx));
+    }
+}
+
+// Context object and temporary variables
+impl<'a> Emitter<'a, '_> {
+    fn emit(&mut self, stmt: Statement<'a>) {
+        self.listing.push(stmt);
+    }
+
+    fn emit_expression(&mut self, expr: Expression<'a>) {
+        let span = expr.span();
+        self.emit(Statement::new_expression_statement(span, expr, self.ctx));
+    }
+
+    /// `_context.name`
+    fn context_property(&mut self, name: &str) -> Expression<'a> {
+        let context = self.context.create_read_expression(self.ctx);
+        create_property_access(SPAN, context, name, self.ctx)
+    }
+
+    /// `_context.name` as an assignment target.
+    fn context_target(&mut self, name: &str) -> AssignmentTarget<'a> {
+        AssignmentTarget::from(self.context_property(name).into_member_expression())
+    }
+
+    /// `_context.name(...arguments)`
+    fn context_call(
+        &mut self,
+        name: &str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+    ) -> Expression<'a> {
+        let callee = self.context_property(name);
+        Expression::new_call_expression(SPAN, callee, NONE, arguments, false, self.ctx)
+    }
+
+    /// `_context.name = value;`
+    fn emit_assign_context(&mut self, name: &str, value: Expression<'a>) {
+        let assignment = Expression::new_assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            self.context_target(name),
+            value,
+            self.ctx,
+        );
+        self.emit_expression(assignment);
+    }
+
+    fn make_temp_var(&mut self) -> TempVar {
+        let temp_var = TempVar(self.next_temp_var);
+        self.next_temp_var += 1;
+        temp_var
+    }
+
+    fn temp_var_name(temp_var: TempVar) -> String {
+        format!("t{}", temp_var.0)
+    }
+
+    /// `_context.tN`
+    fn temp_var_expr(&mut self, temp_var: TempVar) -> Expression<'a> {
+        self.context_property(&Self::temp_var_name(temp_var))
+    }
+
+    /// `_context.tN = value;`
+    fn emit_assign_temp_var(&mut self, temp_var: TempVar, value: Expression<'a>) {
+        self.emit_assign_context(&Self::temp_var_name(temp_var), value);
+    }
+
+    /// `_context.tN = value;`, returning `_context.tN`.
+    fn emit_assign_temp_var_expr(
+        &mut self,
+        temp_var: TempVar,
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        self.emit_assign_temp_var(temp_var, value);
+        self.temp_var_expr(temp_var)
+    }
+
+    fn unsupported(&mut self, span: Span, message: &'static str) {
+        self.ctx.state.error(OxcDiagnostic::error(message).with_label(span));
+    }
+}
+
+// Statements
+impl<'a> Emitter<'a, '_> {
+    /// Explode a statement of the generator's body into the listing.
+    ///
+    /// `label` is the label of the enclosing labeled statement, if `stmt` is its body.
+    pub fn explode_statement(&mut self, stmt: Statement<'a>, label: Option<Ident<'a>>) {
+        if let Statement::BlockStatement(block) = stmt {
+            for stmt in block.unbox().body {
+                self.explode_statement(stmt, None);
+            }
+            return;
+        }
+        if !statement_contains_leap(&stmt) {
+            self.emit(stmt);
+            return;
+        }
+
+        match stmt {
+            Statement::ExpressionStatement(stmt) => {
+                self.explode_expression(stmt.unbox().expression, true);
+            }
+            Statement::LabeledStatement(stmt) => {
+                let LabeledStatement { label, body, .. } = stmt.unbox();
+                let after_loc = self.loc();
+                self.leap_manager.push_labeled(after_loc, label.name);
+                self.explode_statement(body, Some(label.name));
+                self.leap_manager.pop();
+                self.mark(after_loc);
+            }
+            Statement::WhileStatement(stmt) => {
+                let WhileStatement { test, body, .. } = stmt.unbox();
+                let before_loc = self.loc();
+                let after_loc = self.loc();
+                self.mark(before_loc);
+                let test = self.explode_value(test);
+                self.jump_if_not(test, after_loc);
+                self.leap_manager.push_loop(after_loc, before_loc, label);
+                self.explode_statement(body, None);
+                self.leap_manager.pop();
+                self.jump(before_loc);
+                self.mark(after_loc);
+            }
+            Statement::DoWhileStatement(stmt) => {
+                let DoWhileStatement { body, test, .. } = stmt.unbox();
+                let first_loc = self.loc();
+                let test_loc = self.loc();
+                let after_loc = self.loc();
+                self.mark(first_loc);
+                self.leap_manager.push_loop(after_loc, test_loc, label);
+                self.explode_statement(body, None);
+                self.leap_manager.pop();
+                self.mark(test_loc);
+                let test = self.explode_value(test);
+                self.jump_if(test, first_loc);
+                self.mark(after_loc);
+            }
+            Statement::ForStatement(stmt) => {
+                let ForStatement { init, test, update, body, .. } = stmt.unbox();
+                let head_loc = self.loc();
+                let update_loc = self.loc();
+                let after_loc = self.loc();
+                match init {
+                    Some(ForStatementInit::VariableDeclaration(decl)) => {
+                        self.emit(Statement::VariableDeclaration(decl));
+                    }
+                    Some(init) => {
+                        self.explode_expression(init.into_expression(), true);
+                    }
+                    None => {}
+                }
+                self.mark(head_loc);
+                if let Some(test) = test {
+                    let test = self.explode_value(test);
+                    self.jump_if_not(test, after_loc);
+                }
+                self.leap_manager.push_loop(after_loc, update_loc, label);
+                self.explode_statement(body, None);
+                self.leap_manager.pop();
+                self.mark(update_loc);
+                if let Some(update) = update {
+                    self.explode_expression(update, true);
+                }
+                self.jump(head_loc);
+                self.mark(after_loc);
+            }
+            Statement::ForInStatement(stmt) => {
+                let ForInStatement { span, left, right, body, .. } = stmt.unbox();
+                let left = match left {
+                    ForStatementLeft::VariableDeclaration(decl) => {
+                        self.unsupported(
+                            decl.span,
+                            "This declaration cannot be compiled inside a generator function.",
+                        );
+                        return;
+                    }
+                    left => left.into_assignment_target(),
+                };
+                let head_loc = self.loc();
+                let after_loc = self.loc();
+
+                // `_context.t0 = regeneratorRuntime().keys(right);`
+                let key_iter_next_fn = self.make_temp_var();
+                let right = self.explode_value(right);
+                let keys = self.regenerator.runtime_call("keys", right, self.ctx);
+                self.emit_assign_temp_var(key_iter_next_fn, keys);
+
+                // `if ((_context.t1 = _context.t0()).done) { ... }`
+                self.mark(head_loc);
+                let key_info = self.make_temp_var();
+                let next = Expression::new_call_expression(
+                    SPAN,
+                    self.temp_var_expr(key_iter_next_fn),
+                    NONE,
+                    ArenaVec::new_in(self.ctx),
+                    false,
+                    self.ctx,
+                );
+                let assignment = Expression::new_assignment_expression(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    self.context_target(&Self::temp_var_name(key_info)),
+                    next,
+                    self.ctx,
+                );
+                let assignment =
+                    Expression::new_parenthesized_expression(SPAN, assignment, self.ctx);
+                let done = create_property_access(SPAN, assignment, "done", self.ctx);
+                self.jump_if(done, after_loc);
+
+                // `left = _context.t1.value;`
+                let left = self.explode_assignment_target(left);
+                let value =
+                    create_property_access(SPAN, self.temp_var_expr(key_info), "value", self.ctx);
+                let assignment = Expression::new_assignment_expression(
+                    span,
+                    AssignmentOperator::Assign,
+                    left,
+                    value,
+                    self.ctx,
+                );
+                self.emit_expression(assignment);
+
+                self.leap_manager.push_loop(after_loc, head_loc, label);
+                self.explode_statement(body, None);
+                self.leap_manager.pop();
+                self.jump(head_loc);
+                self.mark(after_loc);
+            }
+            Statement::BreakStatement(stmt) => {
+                let label = stmt.label.as_ref().map(|label| label.name);
+                match self.leap_manager.break_loc(label) {
+                    Some(loc) => {
+                        let loc = self.loc_expr(loc);
+                        self.emit_abrupt_completion(stmt.span, "break", Some(loc));
+                    }
+                    None => self.emit(Statement::BreakStatement(stmt)),
+                }
+            }
+            Statement::ContinueStatement(stmt) => {
+                let label = stmt.label.as_ref().map(|label| label.name);
+                match self.leap_manager.continue_loc(label) {
+                    Some(loc) => {
+                        let loc = self.loc_expr(loc);
+                        self.emit_abrupt_completion(stmt.span, "continue", Some(loc));
+                    }
+                    None => self.emit(Statement::ContinueStatement(stmt)),
+                }
+            }
+            Statement::SwitchStatement(stmt) => self.explode_switch_statement(stmt.unbox()),
+            Statement::IfStatement(stmt) => {
+                let IfStatement { test, consequent, alternate, .. } = stmt.unbox();
+                let else_loc = alternate.is_some().then(|| self.loc());
+                let after_loc = self.loc();
+                let test = self.explode_value(test);
+                self.jump_if_not(test, else_loc.unwrap_or(after_loc));
+                self.explode_statement(consequent, None);
+                if let (Some(else_loc), Some(alternate)) = (else_loc, alternate) {
+                    self.jump(after_loc);
+                    self.mark(else_loc);
+                    self.explode_statement(alternate, None);
+                }
+                self.mark(after_loc);
+            }
+            Statement::ReturnStatement(stmt) => {
+                let ReturnStatement { span, argument, .. } = stmt.unbox();
+                let argument = argument.map(|argument| self.explode_value(argument));
+                self.emit_abrupt_completion(span, "return", argument);
+            }
+            Statement::TryStatement(stmt) => self.explode_try_statement(stmt.unbox()),
+            Statement::ThrowStatement(stmt) => {
+                let ThrowStatement { span, argument, .. } = stmt.unbox();
+                let argument = self.explode_value(argument);
+                self.emit(Statement::new_throw_statement(span, argument, self.ctx));
+            }
+            stmt => {
+                self.unsupported(
+                    stmt.span(),
+                    "This statement cannot be compiled inside a generator function.",
+                );
+                self.emit(stmt);
+            }
+        }
+    }
+
+    fn explode_switch_statement(&mut self, stmt: SwitchStatement<'a>) {
+        let SwitchStatement { discriminant, mut cases, .. } = stmt;
+        let discriminant_temp_var = self.make_temp_var();
+        let discriminant = self.explode_value(discriminant);
+        self.emit_assign_temp_var(discriminant_temp_var, discriminant);
+
+        let after_loc = self.loc();
+        let default_loc = self.loc();
+
+        // `_context.t0 === a ? 1 : _context.t0 === b ? 2 : default`
+        let mut case_locs = vec![default_loc; cases.len()];
+        let mut condition = self.loc_expr(default_loc);
+        for (case, case_loc) in cases.iter_mut().zip(&mut case_locs).rev() {
+            if let Some(test) = case.test.take() {
+                let loc = self.loc();
+                *case_loc = loc;
+                let test = Expression::new_binary_expression(
+                    SPAN,
+                    self.temp_var_expr(discriminant_temp_var),
+                    BinaryOperator::StrictEquality,
+                    test,
+                    self.ctx,
+                );
+                let loc = self.loc_expr(loc);
+                condition =
+                    Expression::new_conditional_expression(SPAN, test, loc, condition, self.ctx);
+            }
+        }
+        let condition = self.explode_value(condition);
+        self.jump_to_expr(condition);
+
+        self.leap_manager.push_switch(after_loc);
+        for (case, case_loc) in cases.into_iter().zip(case_locs) {
+            self.mark(case_loc);
+            for stmt in case.consequent {
+                self.explode_statement(stmt, None);
+            }
+        }
+        self.leap_manager.pop();
+
+        self.mark(after_loc);
+        if self.locs[default_loc.0].is_none() {
+            self.mark(default_loc);
+        }
+    }
+
+    fn explode_try_statement(&mut self, stmt: TryStatement<'a>) {
+        let TryStatement { block, handler, finalizer, .. } = stmt;
+        let after_loc = self.loc();
+        let catch_loc = handler.is_some().then(|| self.loc());
+        let finally_loc = finalizer.is_some().then(|| self.loc());
+
+        let first_loc = self.update_context_prev(None);
+        self.try_entries.push(TryEntry {
+            first_loc,
+            catch_loc,
+            finally: finally_loc.map(|finally_loc| (finally_loc, after_loc)),
+        });
+
+        self.explode_statement(Statement::BlockStatement(block), None);
+
+        if let (Some(catch_loc), Some(handler)) = (catch_loc, handler) {
+            self.jump(finally_loc.unwrap_or(after_loc));
+            let catch_loc = self.mark(catch_loc);
+            self.update_context_prev(Some(catch_loc));
+
+            // `_context.t0 = _context["catch"](first);`
+            let safe_param = self.make_temp_var();
+            let first_loc = self.loc_expr(first_loc);
+            let context = self.context.create_read_expression(self.ctx);
+            let property = Expression::new_string_literal(SPAN, "catch", None, self.ctx);
+            let callee = Expression::new_computed_member_expression(
+                SPAN, context, property, false, self.ctx,
+            );
+            let catch_call = Expression::new_call_expression(
+                SPAN,
+                callee,
+                NONE,
+                ArenaVec::from_value_in(Argument::from(first_loc), self.ctx),
+                false,
+                self.ctx,
+            );
+            self.emit_assign_temp_var(safe_param, catch_call);
+
+            let CatchClause { param, body, .. } = handler.unbox();
+            let mut body = Statement::BlockStatement(body);
+            if let Some(param) = param {
+                match param.pattern {
+                    BindingPattern::BindingIdentifier(ident) => {
+                        let mut replacer = CatchParamReplacer {
+                            ctx: self.ctx,
+                            context: &self.context,
+                            symbol_id: ident.symbol_id(),
+                            safe_param: Self::temp_var_name(safe_param),
+                        };
+                        replacer.visit_statement(&mut body);
+                    }
+                    pattern => self.unsupported(
+                        pattern.span(),
+                        "Destructuring a caught exception cannot be compiled inside a generator function.",
+                    ),
+                }
+            }
+            self.explode_statement(body, None);
+        }
+
+        if let (Some(finally_loc), Some(finalizer)) = (finally_loc, finalizer) {
+            let finally_loc = self.mark(finally_loc);
+            self.update_context_prev(Some(finally_loc));
+            self.explode_statement(Statement::BlockStatement(finalizer), None);
+            // `return _context.finish(finally);`
+            let finally_loc = self.loc_expr(finally_loc);
+            let finish = self.context_call(
+                "finish",
+                ArenaVec::from_value_in(Argument::from(finally_loc), self.ctx),
+            );
+            self.emit(Statement::new_return_statement(SPAN, Some(finish), self.ctx));
+        }
+
+        self.mark(after_loc);
+    }
+}
+
+// Expressions
+impl<'a> Emitter<'a, '_> {
+    /// Explode an expression whose value is used.
+    fn explode_value(&mut self, expr: Expression<'a>) -> Expression<'a> {
+        self.explode_expression(expr, false).unwrap()
+    }
+
+    /// Explode an expression into the listing.
+    ///
+    /// Returns an expression for its value, or `None` if `ignore_result` is `true`, in which case
+    /// the expression is emitted as a statement.
+    fn explode_expression(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        if !expression_contains_leap(&expr) {
+            return self.finish_expression(expr, ignore_result);
+        }
+
+        match expr {
+            Expression::ParenthesizedExpression(paren) => {
+                self.explode_expression(paren.unbox().expression, ignore_result)
+            }
+            Expression::StaticMemberExpression(mut member) => {
+                member.object = self.explode_value(member.object.take_in(self.ctx));
+                self.finish_expression(Expression::StaticMemberExpression(member), ignore_result)
+            }
+            Expression::ComputedMemberExpression(mut member) => {
+                member.object = self.explode_value(member.object.take_in(self.ctx));
+                member.expression =
+                    self.explode_via_temp_var(None, member.expression.take_in(self.ctx));
+                self.finish_expression(Expression::ComputedMemberExpression(member), ignore_result)
+            }
+            Expression::PrivateFieldExpression(mut member) => {
+                member.object = self.explode_value(member.object.take_in(self.ctx));
+                self.finish_expression(Expression::PrivateFieldExpression(member), ignore_result)
+            }
+            Expression::CallExpression(call) => {
+                let call = self.explode_call_expression(call.unbox());
+                self.finish_expression(call, ignore_result)
+            }
+            Expression::NewExpression(mut new) => {
+                new.callee = self.explode_via_temp_var(None, new.callee.take_in(self.ctx));
+                for argument in &mut new.arguments {
+                    self.explode_argument_via_temp_var(argument);
+                }
+                self.finish_expression(Expression::NewExpression(new), ignore_result)
+            }
+            Expression::ObjectExpression(mut object) => {
+                for property in &mut object.properties {
+                    match property {
+                        ObjectPropertyKind::ObjectProperty(property) => {
+                            if property.computed
+                                && let Some(key) = property.key.as_expression_mut()
+                            {
+                                *key = self.explode_via_temp_var(None, key.take_in(self.ctx));
+                            }
+                            if property.kind == PropertyKind::Init && !property.method {
+                                property.value = self
+                                    .explode_via_temp_var(None, property.value.take_in(self.ctx));
+                                property.shorthand = false;
+                            }
+                        }
+                        ObjectPropertyKind::SpreadProperty(spread) => {
+                            spread.argument =
+                                self.explode_via_temp_var(None, spread.argument.take_in(self.ctx));
+                        }
+                        ObjectPropertyKind::ErrorNode(_) => {}
+                    }
+                }
+                self.finish_expression(Expression::ObjectExpression(object), ignore_result)
+            }
+            Expression::ArrayExpression(mut array) => {
+                for element in &mut array.elements {
+                    match element {
+                        ArrayExpressionElement::SpreadElement(spread) => {
+                            spread.argument =
+                                self.explode_via_temp_var(None, spread.argument.take_in(self.ctx));
+                        }
+                        ArrayExpressionElement::Elision(_) => {}
+                        element => {
+                            let expr = element.to_expression_mut();
+                            *expr = self.explode_via_temp_var(None, expr.take_in(self.ctx));
+                        }
+                    }
+                }
+                self.finish_expression(Expression::ArrayExpression(array), ignore_result)
+            }
+            Expression::SequenceExpression(sequence) => {
+                let mut expressions = sequence.unbox().expressions;
+                let last = expressions.pop().unwrap();
+                for expr in expressions {
+                    self.explode_expression(expr, true);
+                }
+                self.explode_expression(last, ignore_result)
+            }
+            Expression::LogicalExpression(logical) => {
+                let LogicalExpression { left, operator, right, .. } = logical.unbox();
+                let after_loc = self.loc();
+                let result = (!ignore_result).then(|| self.make_temp_var());
+                let left = self.explode_via_temp_var(result, left);
+                match operator {
+                    LogicalOperator::And => self.jump_if_not(left, after_loc),
+                    LogicalOperator::Or => self.jump_if(left, after_loc),
+                    LogicalOperator::Coalesce => {
+                        // `left != null`
+                        let null = Expression::new_null_literal(SPAN, self.ctx);
+                        let test = Expression::new_binary_expression(
+                            SPAN,
+                            left,
+                            BinaryOperator::Inequality,
+                            null,
+                            self.ctx,
+                        );
+                        self.jump_if(test, after_loc);
+                    }
+                }
+                self.explode_into_or_ignore(result, right);
+                self.mark(after_loc);
+                result.map(|result| self.temp_var_expr(result))
+            }
+            Expression::ConditionalExpression(conditional) => {
+                let ConditionalExpression { test, consequent, alternate, .. } = conditional.unbox();
+                let else_loc = self.loc();
+                let after_loc = self.loc();
+                let test = self.explode_value(test);
+                self.jump_if_not(test, else_loc);
+                let result = (!ignore_result).then(|| self.make_temp_var());
+                self.explode_into_or_ignore(result, consequent);
+                self.jump(after_loc);
+                self.mark(else_loc);
+                self.explode_into_or_ignore(result, alternate);
+                self.mark(after_loc);
+                result.map(|result| self.temp_var_expr(result))
+            }
+            Expression::UnaryExpression(mut unary) => {
+                unary.argument = self.explode_value(unary.argument.take_in(self.ctx));
+                self.finish_expression(Expression::UnaryExpression(unary), ignore_result)
+            }
+            Expression::BinaryExpression(mut binary) => {
+                binary.left = self.explode_via_temp_var(None, binary.left.take_in(self.ctx));
+                binary.right = self.explode_via_temp_var(None, binary.right.take_in(self.ctx));
+                self.finish_expression(Expression::BinaryExpression(binary), ignore_result)
+            }
+            Expression::AssignmentExpression(assignment) => {
+                let assignment = self.explode_assignment_expression(assignment.unbox());
+                self.finish_expression(assignment, ignore_result)
+            }
+            Expression::UpdateExpression(mut update) => {
+                let target = AssignmentTarget::from(update.argument.take_in(self.ctx));
+                update.argument =
+                    self.explode_assignment_target(target).into_simple_assignment_target();
+                self.finish_expression(Expression::UpdateExpression(update), ignore_result)
+            }
+            Expression::YieldExpression(yield_expr) => {
+                let YieldExpression { span, delegate, argument, .. } = yield_expr.unbox();
+                let after_loc = self.loc();
+                let argument = argument.map(|argument| self.explode_value(argument));
+                match argument {
+                    // `return _context.delegateYield(argument, "tN", after);`
+                    Some(argument) if delegate => {
+                        let result = self.make_temp_var();
+                        let mut arguments = ArenaVec::with_capacity_in(3, self.ctx);
+                        arguments.push(Argument::from(argument));
+                        arguments.push(Argument::from(Expression::new_string_literal(
+                            SPAN,
+                            Str::from_str_in(&Self::temp_var_name(result), self.ctx),
+                            None,
+                            self.ctx,
+                        )));
+                        arguments.push(Argument::from(self.loc_expr(after_loc)));
+                        let delegate_yield = self.context_call("delegateYield", arguments);
+                        self.emit(Statement::new_return_statement(
+                            span,
+                            Some(delegate_yield),
+                            self.ctx,
+                        ));
+                        self.mark(after_loc);
+                        (!ignore_result).then(|| self.temp_var_expr(result))
+                    }
+                    // `_context.next = after; return argument;`
+                    argument => {
+                        let loc = self.loc_expr(after_loc);
+                        self.emit_assign_context("next", loc);
+                        self.emit(Statement::new_return_statement(span, argument, self.ctx));
+                        self.mark(after_loc);
+                        (!ignore_result).then(|| self.context_property("sent"))
+                    }
+                }
+            }
+            expr => {
+                self.unsupported(expr.span(), "`yield` cannot be compiled inside this expression.");
+                self.finish_expression(expr, ignore_result)
+            }
+        }
+    }
+
+    fn finish_expression(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        if ignore_result {
+            self.emit_expression(expr);
+            None
+        } else {
+            Some(expr)
+        }
+    }
+
+    /// Explode an expression, and store its value in a temporary variable unless it's a literal.
+    ///
+    /// Evaluating a sibling later may yield, so the value must be captured before that.
+    fn explode_via_temp_var(
+        &mut self,
+        temp_var: Option<TempVar>,
+        expr: Expression<'a>,
+    ) -> Expression<'a> {
+        let result = self.explode_value(expr);
+        if temp_var.is_none() && result.is_literal() {
+            return result;
+        }
+        let temp_var = temp_var.unwrap_or_else(|| self.make_temp_var());
+        self.emit_assign_temp_var_expr(temp_var, result)
+    }
+
+    /// Explode an expression, storing its value in `temp_var` if given, or else ignoring it.
+    fn explode_into_or_ignore(&mut self, temp_var: Option<TempVar>, expr: Expression<'a>) {
+        if let Some(temp_var) = temp_var {
+            let value = self.explode_value(expr);
+            self.emit_assign_temp_var(temp_var, value);
+        } else {
+            self.explode_expression(expr, true);
+        }
+    }
+
+    fn explode_argument_via_temp_var(&mut self, argument: &mut Argument<'a>) {
+        match argument {
+            Argument::SpreadElement(spread) => {
+                spread.argument =
+                    self.explode_via_temp_var(None, spread.argument.take_in(self.ctx));
+            }
+            argument => {
+                let expr = argument.to_expression_mut();
+                *expr = self.explode_via_temp_var(None, expr.take_in(self.ctx));
+            }
+        }
+    }
+
+    fn explode_call_expression(&mut self, call: CallExpression<'a>) -> Expression<'a> {
+        let CallExpression { span, callee, mut arguments, .. } = call;
+        let has_leaping_args = arguments.iter().any(argument_contains_leap);
+
+        let mut this_arg = None;
+        let callee = if callee.is_member_expression() {
+            if has_leaping_args {
+                // `_context.t0 = obj; ... _context.t0.method.call(_context.t0, ...)`,
+                // so the method is called on the object evaluated before the arguments
+                let object_temp_var = self.make_temp_var();
+                let mut member = callee.into_member_expression();
+                let object = member.object_mut().take_in(self.ctx);
+                *member.object_mut() = self.explode_via_temp_var(Some(object_temp_var), object);
+                if let MemberExpression::ComputedMemberExpression(member) = &mut member {
+                    member.expression =
+                        self.explode_via_temp_var(None, member.expression.take_in(self.ctx));
+                }
+                this_arg = Some(object_temp_var);
+                create_property_access(SPAN, Expression::from(member), "call", self.ctx)
+            } else {
+                self.explode_value(callee)
+            }
+        } else {
+            let callee = self.explode_via_temp_var(None, callee);
+            if callee.is_member_expression() {
+                // `(0, _context.t0)(...)`, so the callee is not called as a method
+                let zero =
+                    Expression::new_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal, self.ctx);
+                let mut expressions = ArenaVec::with_capacity_in(2, self.ctx);
+                expressions.push(zero);
+                expressions.push(callee);
+                Expression::new_sequence_expression(SPAN, expressions, self.ctx)
+            } else {
+                callee
+            }
+        };
+
+        if has_leaping_args {
+            for argument in &mut arguments {
+                self.explode_argument_via_temp_var(argument);
+            }
+            if let Some(this_arg) = this_arg {
+                arguments.insert(0, Argument::from(self.temp_var_expr(this_arg)));
+            }
+        }
+        Expression::new_call_expression(span, callee, NONE, arguments, false, self.ctx)
+    }
+
+    fn explode_assignment_expression(
+        &mut self,
+        assignment: AssignmentExpression<'a>,
+    ) -> Expression<'a> {
+        let AssignmentExpression { span, operator, left, right, .. } = assignment;
+        let left = self.explode_assignment_target(left);
+        if operator == AssignmentOperator::Assign {
+            let right = self.explode_value(right);
+            return Expression::new_assignment_expression(span, operator, left, right, self.ctx);
+        }
+
+        // `a += yield b` -> `_context.t0 = a; ...; a = _context.t0 += _context.sent`
+        let (read, left) = self.duplicate_target(left);
+        let temp_var = self.make_temp_var();
+        let temp = self.emit_assign_temp_var_expr(temp_var, read);
+        let right = self.explode_value(right);
+        let temp = AssignmentTarget::from(temp.into_member_expression());
+        let value = Expression::new_assignment_expression(SPAN, operator, temp, right, self.ctx);
+        Expression::new_assignment_expression(
+            span,
+            AssignmentOperator::Assign,
+            left,
+            value,
+            self.ctx,
+        )
+    }
+
+    fn explode_assignment_target(&mut self, target: AssignmentTarget<'a>) -> AssignmentTarget<'a> {
+        if !assignment_target_contains_leap(&target) {
+            return target;
+        }
+        match target {
+            AssignmentTarget::StaticMemberExpression(mut member) => {
+                member.object = self.explode_value(member.object.take_in(self.ctx));
+                AssignmentTarget::StaticMemberExpression(member)
+            }
+            AssignmentTarget::ComputedMemberExpression(mut member) => {
+                member.object = self.explode_value(member.object.take_in(self.ctx));
+                member.expression =
+                    self.explode_via_temp_var(None, member.expression.take_in(self.ctx));
+                AssignmentTarget::ComputedMemberExpression(member)
+            }
+            AssignmentTarget::PrivateFieldExpression(mut member) => {
+                member.object = self.explode_value(member.object.take_in(self.ctx));
+                AssignmentTarget::PrivateFieldExpression(member)
+            }
+            target => {
+                self.unsupported(
+                    target.span(),
+                    "`yield` cannot be compiled inside this assignment target.",
+                );
+                target
+            }
+        }
+    }
+
+    /// Split the target of a compound assignment into an expression reading it, and a target
+    /// writing it. Objects and properties which are not simple are evaluated once, into temporary
+    /// variables.
+    fn duplicate_target(
+        &mut self,
+        target: AssignmentTarget<'a>,
+    ) -> (Expression<'a>, AssignmentTarget<'a>) {
+        match target {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                let reference_id = ident.reference_id();
+                let symbol_id = self.ctx.scoping().get_reference(reference_id).symbol_id();
+                let read = self.ctx.create_ident_expr(
+                    ident.span,
+                    ident.name,
+                    symbol_id,
+                    ReferenceFlags::Read,
+                );
+                // The original reference now only writes
+                *self.ctx.scoping_mut().get_reference_mut(reference_id).flags_mut() =
+                    ReferenceFlags::Write;
+                (read, AssignmentTarget::AssignmentTargetIdentifier(ident))
+            }
+            AssignmentTarget::StaticMemberExpression(mut member) => {
+                let (read_object, write_object) =
+                    self.duplicate_operand(member.object.take_in(self.ctx));
+                member.object = write_object;
+                let property = member.property.clone_in(self.ctx.allocator());
+                let read = Expression::new_static_member_expression(
+                    member.span,
+                    read_object,
+                    property,
+                    false,
+                    self.ctx,
+                );
+                (read, AssignmentTarget::StaticMemberExpression(member))
+            }
+            AssignmentTarget::ComputedMemberExpression(mut member) => {
+                let (read_object, write_object) =
+                    self.duplicate_operand(member.object.take_in(self.ctx));
+                let (read_expression, write_expression) =
+                    self.duplicate_operand(member.expression.take_in(self.ctx));
+                member.object = write_object;
+                member.expression = write_expression;
+                let read = Expression::new_computed_member_expression(
+                    member.span,
+                    read_object,
+                    read_expression,
+                    false,
+                    self.ctx,
+                );
+                (read, AssignmentTarget::ComputedMemberExpression(member))
+            }
+            AssignmentTarget::PrivateFieldExpression(mut member) => {
+                let (read_object, write_object) =
+                    self.duplicate_operand(member.object.take_in(self.ctx));
+                member.object = write_object;
+                let field = member.field.clone_in(self.ctx.allocator());
+                let read = Expression::new_private_field_expression(
+                    member.span,
+                    read_object,
+                    field,
+                    false,
+                    self.ctx,
+                );
+                (read, AssignmentTarget::PrivateFieldExpression(member))
+            }
+            target => {
+                self.unsupported(
+                    target.span(),
+                    "`yield` cannot be compiled inside this assignment.",
+                );
+                (Expression::new_void_0(SPAN, self.ctx), target)
+            }
+        }
+    }
+
+    /// An expression and a copy of it, via a temporary variable if it's not simple.
+    fn duplicate_operand(&mut self, expr: Expression<'a>) -> (Expression<'a>, Expression<'a>) {
+        if let Some(copy) = self.copy_simple_expression(&expr) {
+            return (copy, expr);
+        }
+        let temp_var = self.make_temp_var();
+        let read = self.emit_assign_temp_var_expr(temp_var, expr);
+        (read, self.temp_var_expr(temp_var))
+    }
+
+    /// Copy an expression which has no side effects and can be evaluated twice.
+    fn copy_simple_expression(&mut self, expr: &Expression<'a>) -> Option<Expression<'a>> {
+        match expr {
+            Expression::Identifier(ident) => {
+                let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                Some(self.ctx.create_ident_expr(
+                    ident.span,
+                    ident.name,
+                    symbol_id,
+                    ReferenceFlags::Read,
+                ))
+            }
+            Expression::ThisExpression(this) => {
+                Some(Expression::new_this_expression(this.span, self.ctx))
+            }
+            Expression::StaticMemberExpression(member) => {
+                let object = self.copy_simple_expression(&member.object)?;
+                let property = member.property.clone_in(self.ctx.allocator());
+                Some(Expression::new_static_member_expression(
+                    member.span,
+                    object,
+                    property,
+                    false,
+                    self.ctx,
+                ))
+            }
+            expr if expr.is_literal() && !matches!(expr, Expression::RegExpLiteral(_)) => {
+                Some(expr.clone_in(self.ctx.allocator()))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Fills in the numeric literals standing for locations.
+struct LocPatcher<'e> {
+    loc_literals: &'e FxHashMap<Address, Loc>,
+    locs: &'e [Option<usize>],
+}
+
+impl<'a> VisitMut<'a> for LocPatcher<'_> {
+    fn visit_numeric_literal(&mut self, literal: &mut NumericLiteral<'a>) {
+        if let Some(loc) = self.loc_literals.get(&literal.unstable_address()) {
+            #[expect(clippy::cast_precision_loss)]
+            let value = self.locs[loc.0].unwrap() as f64;
+            literal.value = value;
+        }
+    }
+}
+
+/// Replaces references to a catch clause's parameter with the temporary variable holding the
+/// exception, as the parameter does not survive across cases.
+struct CatchParamReplacer<'a, 'e> {
+    ctx: &'e mut TraverseCtx<'a>,
+    context: &'e BoundIdentifier<'a>,
+    symbol_id: SymbolId,
+    safe_param: String,
+}
+
+impl<'a> CatchParamReplacer<'a, '_> {
+    fn is_param(&self, ident: &IdentifierReference<'a>) -> bool {
+        self.ctx.scoping().get_reference(ident.reference_id()).symbol_id() == Some(self.symbol_id)
+    }
+
+    /// `_context.tN`
+    fn safe_param_member(&mut self, span: Span) -> MemberExpression<'a> {
+        let context = self.context.create_read_expression(self.ctx);
+        create_property_access(span, context, &self.safe_param, self.ctx).into_member_expression()
+    }
+}
+
+impl<'a> VisitMut<'a> for CatchParamReplacer<'a, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr
+            && self.is_param(ident)
+        {
+            let span = ident.span;
+            self.ctx.delete_reference_for_identifier(ident);
+            *expr = Expression::from(self.safe_param_member(span));
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target
+            && self.is_param(ident)
+        {
+            let span = ident.span;
+            self.ctx.delete_reference_for_identifier(ident);
+            *target = SimpleAssignmentTarget::from(self.safe_param_member(span));
+            return;
+        }
+        walk_mut::walk_simple_assignment_target(self, target);
+    }
+}
+
+/// Deletes the references in an unreachable statement, which is dropped from the listing.
+struct ReferenceDeleter<'a, 'e> {
+    ctx: &'e mut TraverseCtx<'a>,
+}
+
+impl<'a> Visit<'a> for ReferenceDeleter<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.ctx.delete_reference_for_identifier(ident);
+    }
+}
+
+/// Moves scopes of statements in the listing to the dispatch loop's `switch` statement, as the
+/// blocks which contained them may have been exploded.
+///
+/// Class declarations in the listing are also bound in the `switch` statement's scope.
+struct ScopeReparenter<'a, 'e> {
+    ctx: &'e mut TraverseCtx<'a>,
+    switch_scope_id: ScopeId,
+    depth: u32,
+}
+
+impl<'a> Visit<'a> for ScopeReparenter<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            self.ctx
+                .scoping_mut()
+                .change_scope_parent_id(scope_id.get().unwrap(), Some(self.switch_scope_id));
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        if self.depth == 0
+            && class.is_declaration()
+            && let Some(id) = &class.id
+        {
+            let symbol_id = id.symbol_id();
+            let scope_id = self.ctx.scoping().symbol_scope_id(symbol_id);
+            self.ctx.scoping_mut().move_binding_by_symbol_id(
+                scope_id,
+                self.switch_scope_id,
+                symbol_id,
+            );
+        }
+        walk::walk_class(self, class);
+    }
+}
@@ -0,0 +1,368 @@
+//! Hoisting of a generator's declarations out of its body.
+//!
+//! The body of a generator is split between the cases of a `switch` statement, and each call to
+//! `next()` enters the `switch` afresh, so no binding declared in the body can survive between
+//! calls. [`Hoister`] moves every binding declared by `var`, `let`, `const` and function
+//! declarations to the generator's scope, to be declared by a single `var` outside the state
+//! machine:
+//!
+//! ```js
+//! var x = 1;         // -> x = 1;
+//! { let y; }         // -> {}
+//! function f() {}    // -> f = function _f() {}; (moved to the start of the statement list)
+//! ```
+//!
+//! A `let` or `const` binding which would clash with another binding of the same name once
+//! hoisted is renamed.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{ArenaBox, ArenaVec, CloneIn, GetAllocator, TakeIn};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_str::Ident;
+use oxc_traverse::BoundIdentifier;
+
+use crate::{context::TraverseCtx, utils::sync_function_symbol_flags};
+
+/// Hoists the declarations in a generator's body.
+///
+/// Nested functions and class static blocks are not visited, as declarations inside them are
+/// their own.
+pub struct Hoister<'a, 'ctx> {
+    ctx: &'ctx mut TraverseCtx<'a>,
+    /// Scope of the generator function.
+    function_scope_id: ScopeId,
+    /// Bindings of the generator's parameters, which are already declared.
+    param_symbols: FxHashSet<SymbolId>,
+    /// Bindings to declare with `var`, in order of first declaration.
+    vars: Vec<BoundIdentifier<'a>>,
+    /// Bindings which were renamed to avoid a clash.
+    renamed_symbols: FxHashMap<SymbolId, Ident<'a>>,
+}
+
+impl<'a, 'ctx> Hoister<'a, 'ctx> {
+    /// Hoist declarations in `stmts`, the body of the generator function with scope
+    /// `function_scope_id` and parameters `params`.
+    ///
+    /// Returns the bindings to declare with `var`.
+    pub fn hoist(
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        params: &FormalParameters<'a>,
+        function_scope_id: ScopeId,
+        ctx: &'ctx mut TraverseCtx<'a>,
+    ) -> Vec<BoundIdentifier<'a>> {
+        let mut param_symbols = FxHashSet::default();
+        params.bound_names(&mut |ident| {
+            param_symbols.insert(ident.symbol_id());
+        });
+        let mut hoister = Self {
+            ctx,
+            function_scope_id,
+            param_symbols,
+            vars: vec![],
+            renamed_symbols: FxHashMap::default(),
+        };
+        hoister.visit_statements(stmts);
+
+        if !hoister.renamed_symbols.is_empty() {
+            let mut renamer =
+                SymbolRenamer { ctx: hoister.ctx, renamed_symbols: &hoister.renamed_symbols };
+            renamer.visit_statements(stmts);
+        }
+        hoister.vars
+    }
+}
+
+impl<'a> VisitMut<'a> for Hoister<'a, '_> {
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        // `var x;` -> removed, `var x = 1;` -> `x = 1;`.
+        // Function declarations are moved to the start, as they are initialized before any other
+        // statement runs.
+        let mut functions = vec![];
+        stmts.retain_mut(|stmt| match stmt {
+            Statement::VariableDeclaration(decl) if Self::is_hoisted(decl) => {
+                let Some(expr) = self.hoist_declaration(decl) else { return false };
+                *stmt = Statement::new_expression_statement(decl.span, expr, self.ctx);
+                true
+            }
+            Statement::FunctionDeclaration(_) => {
+                let Statement::FunctionDeclaration(func) = stmt.take_in(self.ctx) else {
+                    unreachable!()
+                };
+                functions.push(self.hoist_function_declaration(func));
+                false
+            }
+            _ => true,
+        });
+        if !functions.is_empty() {
+            stmts.splice(0..0, functions);
+        }
+        walk_mut::walk_statements(self, stmts);
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        match stmt {
+            // Declarations which are not directly in a statement list, e.g. `if (x) var y = 1;`
+            Statement::VariableDeclaration(decl) if Self::is_hoisted(decl) => {
+                let span = decl.span;
+                *stmt = match self.hoist_declaration(decl) {
+                    Some(expr) => Statement::new_expression_statement(span, expr, self.ctx),
+                    None => Statement::new_empty_statement(span, self.ctx),
+                };
+            }
+            Statement::FunctionDeclaration(_) => {
+                let Statement::FunctionDeclaration(func) = stmt.take_in(self.ctx) else {
+                    unreachable!()
+                };
+                *stmt = self.hoist_function_declaration(func);
+            }
+            _ => {}
+        }
+        walk_mut::walk_statement(self, stmt);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        if let Some(ForStatementInit::VariableDeclaration(decl)) = &mut stmt.init
+            && Self::is_hoisted(decl)
+        {
+            let init = self.hoist_declaration(decl);
+            stmt.init = init.map(ForStatementInit::from);
+        }
+        walk_mut::walk_for_statement(self, stmt);
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        self.hoist_for_in_of_left(&mut stmt.left);
+        walk_mut::walk_for_in_statement(self, stmt);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        self.hoist_for_in_of_left(&mut stmt.left);
+        walk_mut::walk_for_of_statement(self, stmt);
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &mut ArrowFunctionExpression<'a>) {}
+
+    fn visit_static_block(&mut self, _block: &mut StaticBlock<'a>) {}
+}
+
+impl<'a> Hoister<'a, '_> {
+    fn is_hoisted(decl: &VariableDeclaration<'a>) -> bool {
+        matches!(
+            decl.kind,
+            VariableDeclarationKind::Var
+                | VariableDeclarationKind::Let
+                | VariableDeclarationKind::Const
+        )
+    }
+
+    /// Record the bindings of a declaration to be declared outside the state machine, and convert
+    /// its initializers to assignments.
+    fn hoist_declaration(&mut self, decl: &mut VariableDeclaration<'a>) -> Option<Expression<'a>> {
+        let mut assignments = ArenaVec::new_in(self.ctx);
+        for declarator in &mut decl.declarations {
+            let Some(init) = declarator.init.take() else {
+                declarator.id.bound_names(&mut |ident| self.hoist_binding(ident));
+                continue;
+            };
+            let target = self.binding_to_assignment_target(declarator.id.take_in(self.ctx));
+            assignments.push(Expression::new_assignment_expression(
+                declarator.span,
+                AssignmentOperator::Assign,
+                target,
+                init,
+                self.ctx,
+            ));
+        }
+        match assignments.len() {
+            0 => None,
+            1 => assignments.pop(),
+            _ => Some(Expression::new_sequence_expression(decl.span, assignments, self.ctx)),
+        }
+    }
+
+    /// `for (var x of xs)` -> `for (x of xs)`
+    fn hoist_for_in_of_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        let ForStatementLeft::VariableDeclaration(decl) = left else { return };
+        if !Self::is_hoisted(decl) {
+            return;
+        }
+        let [declarator] = decl.declarations.as_mut_slice() else { return };
+        if declarator.init.is_some() {
+            return;
+        }
+        let target = self.binding_to_assignment_target(declarator.id.take_in(self.ctx));
+        *left = ForStatementLeft::from(target);
+    }
+
+    /// `function f() {}` -> `f = function _f() {};`
+    fn hoist_function_declaration(
+        &mut self,
+        mut func: ArenaBox<'a, Function<'a>>,
+    ) -> Statement<'a> {
+        let span = func.span;
+        let id = func.id.take().unwrap();
+        let reference = self.create_write_reference(&id);
+        let target = AssignmentTarget::AssignmentTargetIdentifier(self.ctx.alloc(reference));
+
+        let binding = self.ctx.generate_uid(&id.name, func.scope_id(), SymbolFlags::Function);
+        func.id = Some(binding.create_binding_identifier(self.ctx));
+        func.r#type = FunctionType::FunctionExpression;
+        sync_function_symbol_flags(&func, self.ctx);
+
+        let expr = Expression::new_assignment_expression(
+            span,
+            AssignmentOperator::Assign,
+            target,
+            Expression::FunctionExpression(func),
+            self.ctx,
+        );
+        Statement::new_expression_statement(span, expr, self.ctx)
+    }
+
+    /// Move a binding to the generator's scope, and record it to be declared with `var`.
+    fn hoist_binding(&mut self, ident: &BindingIdentifier<'a>) {
+        let symbol_id = ident.symbol_id();
+        if self.param_symbols.contains(&symbol_id)
+            || self.vars.iter().any(|binding| binding.symbol_id == symbol_id)
+        {
+            return;
+        }
+
+        let scope_id = self.ctx.scoping().symbol_scope_id(symbol_id);
+        if scope_id != self.function_scope_id {
+            let scoping = self.ctx.scoping();
+            let parent_scope_id = scoping.scope_parent_id(scope_id).unwrap();
+            let clashes = scoping
+                .scope_ancestors(parent_scope_id)
+                .any(|scope_id| scoping.scope_has_binding(scope_id, ident.name))
+                || scoping.root_unresolved_references().contains_key(ident.name.as_str());
+            if clashes {
+                let new_name = self.ctx.generate_uid_name(&ident.name);
+                self.ctx.scoping_mut().rename_symbol(symbol_id, scope_id, new_name);
+                self.renamed_symbols.insert(symbol_id, new_name);
+            }
+            self.ctx.scoping_mut().move_binding_by_symbol_id(
+                scope_id,
+                self.function_scope_id,
+                symbol_id,
+            );
+        }
+
+        let flags = self.ctx.scoping_mut().symbol_flags_mut(symbol_id);
+        flags.remove(
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable | SymbolFlags::Function,
+        );
+        flags.insert(SymbolFlags::FunctionScopedVariable);
+
+        let name = self.symbol_name(symbol_id);
+        self.vars.push(BoundIdentifier::new(name, symbol_id));
+    }
+
+    fn binding_to_assignment_target(
+        &mut self,
+        pattern: BindingPattern<'a>,
+    ) -> AssignmentTarget<'a> {
+        match pattern {
+            BindingPattern::BindingIdentifier(ident) => {
+                let reference = self.create_write_reference(&ident);
+                AssignmentTarget::AssignmentTargetIdentifier(self.ctx.alloc(reference))
+            }
+            BindingPattern::ArrayPattern(array) => {
+                let ArrayPattern { span, elements, rest, .. } = array.unbox();
+                let mut targets = ArenaVec::with_capacity_in(elements.len(), self.ctx);
+                for element in elements {
+                    targets.push(element.map(|element| self.binding_to_maybe_default(element)));
+                }
+                let rest = rest.map(|rest| self.binding_rest_to_target(rest.unbox()));
+                AssignmentTarget::new_array_assignment_target(span, targets, rest, self.ctx)
+            }
+            BindingPattern::ObjectPattern(object) => {
+                let ObjectPattern { span, properties, rest, .. } = object.unbox();
+                let mut targets = ArenaVec::with_capacity_in(properties.len(), self.ctx);
+                for property in properties {
+                    let BindingProperty { span, key, value, computed, .. } = property;
+                    // Always use the long form, as the binding may be renamed
+                    let binding = self.binding_to_maybe_default(value);
+                    targets.push(
+                        AssignmentTargetProperty::new_assignment_target_property_property(
+                            span, key, binding, computed, self.ctx,
+                        ),
+                    );
+                }
+                let rest = rest.map(|rest| self.binding_rest_to_target(rest.unbox()));
+                AssignmentTarget::new_object_assignment_target(span, targets, rest, self.ctx)
+            }
+            BindingPattern::AssignmentPattern(pattern) => {
+                // Only valid as an element or property, handled by `binding_to_maybe_default`
+                self.binding_to_assignment_target(pattern.unbox().left)
+            }
+        }
+    }
+
+    fn binding_to_maybe_default(
+        &mut self,
+        pattern: BindingPattern<'a>,
+    ) -> AssignmentTargetMaybeDefault<'a> {
+        if let BindingPattern::AssignmentPattern(pattern) = pattern {
+            let AssignmentPattern { span, left, right, .. } = pattern.unbox();
+            let binding = self.binding_to_assignment_target(left);
+            AssignmentTargetMaybeDefault::new_assignment_target_with_default(
+                span, binding, right, self.ctx,
+            )
+        } else {
+            AssignmentTargetMaybeDefault::from(self.binding_to_assignment_target(pattern))
+        }
+    }
+
+    fn binding_rest_to_target(
+        &mut self,
+        rest: BindingRestElement<'a>,
+    ) -> ArenaBox<'a, AssignmentTargetRest<'a>> {
+        let target = self.binding_to_assignment_target(rest.argument);
+        AssignmentTargetRest::boxed(rest.span, target, self.ctx)
+    }
+
+    fn create_write_reference(&mut self, ident: &BindingIdentifier<'a>) -> IdentifierReference<'a> {
+        self.hoist_binding(ident);
+        let symbol_id = ident.symbol_id();
+        let name = self.symbol_name(symbol_id);
+        self.ctx.create_bound_ident_reference(ident.span, name, symbol_id, ReferenceFlags::Write)
+    }
+
+    /// The current name of a symbol.
+    fn symbol_name(&self, symbol_id: SymbolId) -> Ident<'a> {
+        self.renamed_symbols.get(&symbol_id).copied().unwrap_or_else(|| {
+            self.ctx.scoping().symbol_ident(symbol_id).clone_in(self.ctx.allocator())
+        })
+    }
+}
+
+/// Updates references to bindings renamed by [`Hoister`], including in nested functions.
+struct SymbolRenamer<'a, 'ctx> {
+    ctx: &'ctx TraverseCtx<'a>,
+    renamed_symbols: &'ctx FxHashMap<SymbolId, Ident<'a>>,
+}
+
+impl<'a> VisitMut<'a> for SymbolRenamer<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if let Some(symbol_id) = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()
+            && let Some(&name) = self.renamed_symbols.get(&symbol_id)
+        {
+            ident.name = name;
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(symbol_id) = ident.symbol_id.get()
+            && let Some(&name) = self.renamed_symbols.get(&symbol_id)
+        {
+            ident.name = name;
+        }
+    }
+}
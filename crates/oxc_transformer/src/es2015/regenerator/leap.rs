@@ -0,0 +1,67 @@
+//! Targets of `break` and `continue` statements.
+//!
+//! While a loop, `switch` or labeled statement is being exploded, an entry for it is pushed onto
+//! the [`LeapManager`], recording the locations that `break` and `continue` jump to.
+
+use oxc_str::Ident;
+
+use super::emit::Loc;
+
+enum Entry<'a> {
+    Loop { break_loc: Loc, continue_loc: Loc, label: Option<Ident<'a>> },
+    Switch { break_loc: Loc },
+    Labeled { break_loc: Loc, label: Ident<'a> },
+}
+
+#[derive(Default)]
+pub struct LeapManager<'a> {
+    /// Enclosing jump targets, innermost last.
+    entries: Vec<Entry<'a>>,
+}
+
+impl<'a> LeapManager<'a> {
+    pub fn push_loop(&mut self, break_loc: Loc, continue_loc: Loc, label: Option<Ident<'a>>) {
+        self.entries.push(Entry::Loop { break_loc, continue_loc, label });
+    }
+
+    pub fn push_switch(&mut self, break_loc: Loc) {
+        self.entries.push(Entry::Switch { break_loc });
+    }
+
+    pub fn push_labeled(&mut self, break_loc: Loc, label: Ident<'a>) {
+        self.entries.push(Entry::Labeled { break_loc, label });
+    }
+
+    pub fn pop(&mut self) {
+        self.entries.pop();
+    }
+
+    /// Location a `break` statement jumps to.
+    ///
+    /// An unlabeled `break` exits the innermost loop or `switch`.
+    pub fn break_loc(&self, label: Option<Ident<'a>>) -> Option<Loc> {
+        self.entries.iter().rev().find_map(|entry| match (entry, label) {
+            (Entry::Loop { break_loc, .. } | Entry::Switch { break_loc }, None) => Some(*break_loc),
+            (
+                Entry::Loop { break_loc, label: Some(entry_label), .. }
+                | Entry::Labeled { break_loc, label: entry_label },
+                Some(label),
+            ) if *entry_label == label => Some(*break_loc),
+            _ => None,
+        })
+    }
+
+    /// Location a `continue` statement jumps to.
+    ///
+    /// Only loops can be continued.
+    pub fn continue_loc(&self, label: Option<Ident<'a>>) -> Option<Loc> {
+        self.entries.iter().rev().find_map(|entry| match entry {
+            Entry::Loop { continue_loc, label: entry_label, .. }
+                if label.is_none() || *entry_label == label =>
+            {
+                Some(*continue_loc)
+            }
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,84 @@
+//! Detection of the parts of a generator's body which must be exploded.
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_semantic::ScopeFlags;
+
+/// `true` if the statement contains a `yield` expression, or a `break`, `continue`, `return` or
+/// `throw` statement. Each of those has to be compiled into a jump between cases of the
+/// state machine.
+///
+/// Nested functions and class static blocks are not searched.
+pub fn statement_contains_leap(stmt: &Statement<'_>) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_statement(stmt);
+    finder.found
+}
+
+/// `true` if the expression contains a `yield` expression.
+///
+/// Nested functions and class static blocks are not searched.
+pub fn expression_contains_leap(expr: &Expression<'_>) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_expression(expr);
+    finder.found
+}
+
+/// `true` if the assignment target contains a `yield` expression.
+pub fn assignment_target_contains_leap(target: &AssignmentTarget<'_>) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_assignment_target(target);
+    finder.found
+}
+
+/// `true` if the call argument contains a `yield` expression.
+pub fn argument_contains_leap(arg: &Argument<'_>) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_argument(arg);
+    finder.found
+}
+
+#[derive(Default)]
+struct LeapFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for LeapFinder {
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        if !self.found {
+            oxc_ast_visit::walk::walk_statement(self, stmt);
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if !self.found {
+            oxc_ast_visit::walk::walk_expression(self, expr);
+        }
+    }
+
+    fn visit_yield_expression(&mut self, _it: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_break_statement(&mut self, _it: &BreakStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_continue_statement(&mut self, _it: &ContinueStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_return_statement(&mut self, _it: &ReturnStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_throw_statement(&mut self, _it: &ThrowStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_static_block(&mut self, _it: &StaticBlock<'a>) {}
+}
@@ -0,0 +1,584 @@
+//! ES2015: Regenerator
+//!
+//! This plugin compiles generator functions into state machines driven by the
+//! `regeneratorRuntime`.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function* foo(x) {
+//!   try {
+//!     yield x;
+//!   } finally {
+//!     bar();
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _marked = /*#__PURE__*/babelHelpers.regeneratorRuntime().mark(foo);
+//! function foo(x) {
+//!   return babelHelpers.regeneratorRuntime().wrap(function foo$(_context) {
+//!     while (1) switch (_context.prev = _context.next) {
+//!       case 0:
+//!         _context.prev = 0;
+//!         _context.next = 3;
+//!         return x;
+//!       case 3:
+//!         _context.prev = 3;
+//!         bar();
+//!         return _context.finish(3);
+//!       case 6:
+//!       case "end":
+//!         return _context.stop();
+//!     }
+//!   }, _marked, null, [[0,, 3, 6]]);
+//! }
+//! ```
+//!
+//! Async functions and async generator functions are compiled too, once the async-to-generator
+//! and async generator functions transforms have turned them into generators.
+//!
+//! ## Options
+//!
+//! ### `inlineRuntime`
+//!
+//! By default, the runtime is loaded with the `regeneratorRuntime` helper. With `inlineRuntime`,
+//! the runtime is inserted at the top of the file instead, so the output has no dependencies:
+//! ```js
+//! function _regeneratorRuntime() {
+//!   /* ... */
+//! }
+//! var _marked = /*#__PURE__*/_regeneratorRuntime().mark(foo);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-regenerator](https://babeljs.io/docs/babel-plugin-transform-regenerator),
+//! which is a port of [regenerator-transform](https://github.com/facebook/regenerator/tree/main/packages/regenerator-transform).
+//!
+//! The body of the generator is compiled in three steps:
+//! 1. [`hoist`] moves its declarations out of the body, as locals do not survive a `yield`.
+//! 2. [`emit`] explodes the body into a listing of statements, split into the cases of a
+//!    `switch` at every location which a `yield` resumes at, or which is jumped to.
+//! 3. The `switch` is wrapped in the inner function, which the runtime calls to resume the
+//!    generator.
+//!
+//! Differences from Babel:
+//!
+//! * Generator methods stay methods. Babel converts object methods into properties.
+//!   The runtime is passed `null` as the outer function of a method, so `mark` is not called.
+//! * A generator method containing `super` is an error, as `super` cannot be moved into the
+//!   inner function. Babel outputs invalid code.
+//! * Async functions are only compiled when the async-to-generator transform is also enabled.
+//!   Babel compiles them with `regeneratorRuntime().async` otherwise.
+//! * `for...of` loops containing `yield` are an error, unless the for-of transform is also
+//!   enabled. Babel's preset always enables it alongside this transform.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-regenerator/src/index.ts>
+//! * regenerator-transform: <https://github.com/facebook/regenerator/tree/v0.14.1/packages/regenerator-transform/src>
+//! * Generator function definitions: <https://tc39.es/ecma262/#sec-generator-function-definitions>
+
+mod emit;
+mod hoist;
+mod leap;
+mod meta;
+
+use serde::Deserialize;
+
+use oxc_allocator::{ArenaBox, ArenaVec, GetAddress, GetAllocator, TakeIn};
+use oxc_ast::{ast::*, builder::NONE};
+use oxc_ast_visit::{Visit, VisitMut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{SPAN, SourceType, Span};
+use oxc_str::{Ident, static_ident};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::{Helper, helper_call_expr},
+    context::TraverseCtx,
+    state::TransformState,
+    utils::{ast_builder::create_property_access, sync_function_symbol_flags},
+};
+
+use emit::Emitter;
+use hoist::Hoister;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RegeneratorOptions {
+    /// Insert the runtime into the file, instead of loading the `regeneratorRuntime` helper.
+    pub inline_runtime: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Regenerator {
+    inline_runtime: bool,
+}
+
+impl Regenerator {
+    pub fn new(options: RegeneratorOptions) -> Self {
+        Self { inline_runtime: options.inline_runtime }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Regenerator {
+    fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(runtime) = ctx.state.regenerator_runtime.clone() {
+            Self::insert_runtime(&runtime, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::FunctionExpression(func) = expr else { return };
+        if !Self::is_generator(func) {
+            return;
+        }
+        // Object methods are transformed in `exit_function`
+        if matches!(ctx.parent(), Ancestor::ObjectPropertyValue(prop) if *prop.method()) {
+            return;
+        }
+        let Expression::FunctionExpression(func) = expr.take_in(ctx) else { unreachable!() };
+        *expr = self.transform_generator_expression(func, ctx);
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let address = stmt.address();
+        let is_export = !matches!(stmt, Statement::FunctionDeclaration(_));
+        let Some(func) = Self::generator_declaration_mut(stmt) else { return };
+
+        let id = if let Some(id) = &func.id {
+            BoundIdentifier::from_binding_ident(id)
+        } else {
+            // `export default function* () {}`
+            let id = ctx.generate_uid_in_current_scope("callee", SymbolFlags::Function);
+            func.id = Some(id.create_binding_identifier(ctx));
+            sync_function_symbol_flags(func, ctx);
+            id
+        };
+
+        // `var _marked = regeneratorRuntime().mark(foo);`
+        let marked = ctx.generate_uid_in_current_hoist_scope("marked");
+        self.transform_function(func, Some(marked.create_read_expression(ctx)), ctx);
+        let init = self.create_mark_call(id.create_read_expression(ctx), ctx);
+        if matches!(ctx.parent(), Ancestor::ProgramBody(_) | Ancestor::FunctionBodyStatements(_)) {
+            ctx.state.var_declarations.insert_var_with_init(&marked, init, &ctx.ast);
+        } else {
+            let declarator = VariableDeclarator::new(
+                SPAN,
+                VariableDeclarationKind::Var,
+                marked.create_binding_pattern(ctx),
+                NONE,
+                Some(init),
+                false,
+                ctx,
+            );
+            let declaration = Statement::new_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ArenaVec::from_value_in(declarator, ctx),
+                false,
+                ctx,
+            );
+            ctx.state.statement_injector.insert_before(&address, declaration);
+        }
+        if !is_export {
+            Self::hoist_block_function_binding(&id, ctx);
+        }
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !Self::is_generator(func) {
+            return;
+        }
+        // Function expressions and declarations are transformed in `exit_expression`
+        // and `exit_statement`, where they can be wrapped in a `mark` call
+        let is_method = match ctx.parent() {
+            Ancestor::MethodDefinitionValue(_) => true,
+            Ancestor::ObjectPropertyValue(prop) => *prop.method(),
+            _ => false,
+        };
+        if !is_method {
+            return;
+        }
+        if let Some(span) = find_super(func) {
+            ctx.state.error(
+                OxcDiagnostic::error("`super` cannot be compiled inside a generator method.")
+                    .with_label(span),
+            );
+            return;
+        }
+        self.transform_function(func, None, ctx);
+    }
+}
+
+impl<'a> Regenerator {
+    fn is_generator(func: &Function<'a>) -> bool {
+        func.generator && !func.r#async && func.body.is_some()
+    }
+
+    fn generator_declaration_mut<'s>(stmt: &'s mut Statement<'a>) -> Option<&'s mut Function<'a>> {
+        let func = match stmt {
+            Statement::FunctionDeclaration(func) => func,
+            Statement::ExportNamedDeclaration(decl) => match &mut decl.declaration {
+                Some(Declaration::FunctionDeclaration(func)) => func,
+                _ => return None,
+            },
+            Statement::ExportDefaultDeclaration(decl) => match &mut decl.declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => func,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Self::is_generator(func).then_some(func)
+    }
+
+    /// Move the binding of a function declaration which is no longer a generator to the var scope,
+    /// if it's in a block of sloppy mode code.
+    ///
+    /// Plain function declarations in blocks are also bound in the enclosing function's scope
+    /// (Annex B.3.2.1), but generator declarations are not.
+    fn hoist_block_function_binding(id: &BoundIdentifier<'a>, ctx: &mut TraverseCtx<'a>) {
+        let flags = ctx.current_scope_flags();
+        if flags.is_var() || flags.is_strict_mode() || ctx.state.source_type.is_typescript() {
+            return;
+        }
+        let scope_id = ctx.current_scope_id();
+        let var_scope_id = ctx.current_hoist_scope_id();
+        if !ctx.scoping().scope_has_binding(var_scope_id, id.name) {
+            ctx.scoping_mut().move_binding_by_symbol_id(scope_id, var_scope_id, id.symbol_id);
+        }
+    }
+
+    /// Transform a generator function expression.
+    ///
+    /// `function* () { yield 1 }` ->
+    /// `regeneratorRuntime().mark(function _callee() { return regeneratorRuntime().wrap(...) })`
+    pub fn transform_generator_expression(
+        self,
+        mut func: ArenaBox<'a, Function<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let id = if let Some(id) = &func.id {
+            BoundIdentifier::from_binding_ident(id)
+        } else {
+            let id = ctx.generate_uid("callee", func.scope_id(), SymbolFlags::Function);
+            func.id = Some(id.create_binding_identifier(ctx));
+            sync_function_symbol_flags(&func, ctx);
+            id
+        };
+        self.transform_function(&mut func, Some(id.create_read_expression(ctx)), ctx);
+        self.create_mark_call(Expression::FunctionExpression(func), ctx)
+    }
+
+    /// Replace the body of a generator function with a call to `regeneratorRuntime().wrap`,
+    /// and make it an ordinary function.
+    ///
+    /// `outer_fn` is the marked function, or `None` for methods, which are not marked.
+    ///
+    /// ```js
+    /// var x, _args = arguments;
+    /// return regeneratorRuntime().wrap(function foo$(_context) { ... }, outer_fn, this, tryLocs);
+    /// ```
+    fn transform_function(
+        self,
+        func: &mut Function<'a>,
+        outer_fn: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let function_scope_id = func.scope_id();
+        let name = func.id.as_ref().map_or_else(|| ctx.generate_uid_name("callee"), |id| id.name);
+        let body = func.body.as_mut().unwrap();
+        let mut stmts = body.statements.take_in(ctx);
+
+        let vars = Hoister::hoist(&mut stmts, &func.params, function_scope_id, ctx);
+
+        let mut function_context =
+            FunctionContext { ctx, function_scope_id, arguments: None, uses_this: false };
+        function_context.visit_statements(&mut stmts);
+        let FunctionContext { arguments: args, uses_this, .. } = function_context;
+
+        // Explode the body into the dispatch loop of the inner function
+        let inner_scope_id = ctx.create_child_scope(function_scope_id, ScopeFlags::Function);
+        let context =
+            ctx.generate_uid("context", inner_scope_id, SymbolFlags::FunctionScopedVariable);
+        let switch_scope_id = ctx.create_child_scope(inner_scope_id, ScopeFlags::empty());
+        let mut emitter = Emitter::new(self, context.clone(), switch_scope_id, ctx);
+        for stmt in stmts {
+            emitter.explode_statement(stmt, None);
+        }
+        let (dispatch_loop, try_locs) = emitter.finish();
+
+        let inner_fn =
+            Self::create_inner_function(name, &context, dispatch_loop, inner_scope_id, ctx);
+
+        // `regeneratorRuntime().wrap(foo$, _marked, this, tryLocs)`
+        let mut arguments = ArenaVec::with_capacity_in(4, ctx);
+        arguments.push(Argument::FunctionExpression(inner_fn));
+        arguments.push(Argument::from(
+            outer_fn.unwrap_or_else(|| Expression::new_null_literal(SPAN, ctx)),
+        ));
+        if uses_this {
+            arguments.push(Argument::new_this_expression(SPAN, ctx));
+        } else if try_locs.is_some() {
+            arguments.push(Argument::new_null_literal(SPAN, ctx));
+        }
+        if let Some(try_locs) = try_locs {
+            arguments.push(Argument::from(try_locs));
+        }
+        let wrap = self.runtime_property("wrap", ctx);
+        let wrap_call = Expression::new_call_expression(SPAN, wrap, NONE, arguments, false, ctx);
+
+        let body = func.body.as_mut().unwrap();
+        let mut declarations = ArenaVec::with_capacity_in(vars.len() + 1, ctx);
+        for var in &vars {
+            // Keep the span of the original declaration, which the symbol still refers to
+            let span = ctx.scoping().symbol_span(var.symbol_id);
+            declarations.push(Self::create_var_declarator(span, var, None, ctx));
+        }
+        if let Some(args) = &args {
+            let init = ctx.create_unbound_ident_expr(
+                SPAN,
+                static_ident!("arguments"),
+                ReferenceFlags::Read,
+            );
+            declarations.push(Self::create_var_declarator(SPAN, args, Some(init), ctx));
+        }
+        if !declarations.is_empty() {
+            body.statements.push(Statement::new_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                declarations,
+                false,
+                ctx,
+            ));
+        }
+        body.statements.push(Statement::new_return_statement(SPAN, Some(wrap_call), ctx));
+
+        func.generator = false;
+        sync_function_symbol_flags(func, ctx);
+    }
+
+    /// `function foo$(_context) { while (1) switch (...) { ... } }`
+    fn create_inner_function(
+        name: Ident<'a>,
+        context: &BoundIdentifier<'a>,
+        dispatch_loop: Statement<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, Function<'a>> {
+        let id_name = Ident::from_strs_array_in([name.as_str(), "$"], ctx);
+        let id = ctx.generate_binding(id_name, scope_id, SymbolFlags::Function);
+        let param = FormalParameter::new_plain(SPAN, context.create_binding_pattern(ctx), ctx);
+        let params = FormalParameters::boxed(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ArenaVec::from_value_in(param, ctx),
+            NONE,
+            ctx,
+        );
+        let body = FunctionBody::boxed(
+            SPAN,
+            ArenaVec::new_in(ctx),
+            ArenaVec::from_value_in(dispatch_loop, ctx),
+            ctx,
+        );
+        let func = Function::boxed_with_scope_id(
+            SPAN,
+            FunctionType::FunctionExpression,
+            Some(id.create_binding_identifier(ctx)),
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            scope_id,
+            ctx,
+        );
+        sync_function_symbol_flags(&func, ctx);
+        func
+    }
+
+    fn create_var_declarator(
+        span: Span,
+        binding: &BoundIdentifier<'a>,
+        init: Option<Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> VariableDeclarator<'a> {
+        VariableDeclarator::new(
+            SPAN,
+            VariableDeclarationKind::Var,
+            binding.create_spanned_binding_pattern(span, ctx),
+            NONE,
+            init,
+            false,
+            ctx,
+        )
+    }
+
+    /// `/*#__PURE__*/ regeneratorRuntime().mark(func)`
+    fn create_mark_call(self, func: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let callee = self.runtime_property("mark", ctx);
+        let arguments = ArenaVec::from_value_in(Argument::from(func), ctx);
+        Expression::new_call_expression_with_pure(SPAN, callee, NONE, arguments, false, true, ctx)
+    }
+
+    /// `regeneratorRuntime().name(argument)`
+    pub fn runtime_call(
+        self,
+        name: &'static str,
+        argument: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = self.runtime_property(name, ctx);
+        let arguments = ArenaVec::from_value_in(Argument::from(argument), ctx);
+        Expression::new_call_expression(SPAN, callee, NONE, arguments, false, ctx)
+    }
+
+    /// `regeneratorRuntime().name`
+    ///
+    /// With `inlineRuntime`, `regeneratorRuntime` is the function inserted by `exit_program`.
+    fn runtime_property(self, name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let runtime = if self.inline_runtime {
+            let binding = if let Some(binding) = &ctx.state.regenerator_runtime {
+                binding.clone()
+            } else {
+                let binding =
+                    ctx.generate_uid_in_root_scope("regeneratorRuntime", SymbolFlags::Function);
+                ctx.state.regenerator_runtime = Some(binding.clone());
+                binding
+            };
+            let callee = binding.create_read_expression(ctx);
+            Expression::new_call_expression(SPAN, callee, NONE, ArenaVec::new_in(ctx), false, ctx)
+        } else {
+            helper_call_expr(Helper::RegeneratorRuntime, ArenaVec::new_in(ctx), ctx)
+        };
+        create_property_access(SPAN, runtime, name, ctx)
+    }
+
+    /// Insert the runtime at the top of the program, as a function declaration of `runtime`.
+    ///
+    /// The parsed runtime has no scopes or symbols. The transformer rebuilds the semantic data
+    /// of the program after the traversal when [`TransformState::regenerator_runtime`] is set.
+    fn insert_runtime(runtime: &BoundIdentifier<'a>, ctx: &mut TraverseCtx<'a>) {
+        let source = include_str!("runtime.js");
+        let mut program = Parser::new(ctx.allocator(), source, SourceType::cjs()).parse().program;
+        RuntimePatcher { name: runtime.name }.visit_program(&mut program);
+        ctx.state.top_level_statements.insert_statements(program.body);
+    }
+}
+
+/// Rewrites the parts of a generator's body which refer to the generator function itself,
+/// as they will end up in the inner function.
+///
+/// * `arguments` is replaced with `_args`, declared in the generator function.
+/// * Whether `this` is used is recorded, so it can be passed to the inner function.
+struct FunctionContext<'a, 'ctx> {
+    ctx: &'ctx mut TraverseCtx<'a>,
+    function_scope_id: ScopeId,
+    arguments: Option<BoundIdentifier<'a>>,
+    uses_this: bool,
+}
+
+impl<'a> VisitMut<'a> for FunctionContext<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if ident.name != "arguments" {
+            return;
+        }
+        let reference = self.ctx.scoping().get_reference(ident.reference_id());
+        if reference.symbol_id().is_some() {
+            return;
+        }
+        let flags = reference.flags();
+        self.ctx.delete_reference_for_identifier(ident);
+        let arguments = self.arguments.get_or_insert_with(|| {
+            self.ctx.generate_uid(
+                "args",
+                self.function_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            )
+        });
+        *ident = arguments.create_spanned_reference(ident.span, flags, self.ctx);
+    }
+
+    fn visit_this_expression(&mut self, _it: &mut ThisExpression) {
+        self.uses_this = true;
+    }
+
+    fn visit_function(&mut self, _it: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_static_block(&mut self, _it: &mut StaticBlock<'a>) {}
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        // `this` and `arguments` in class elements refer to the class, except in `extends`
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_expression(super_class);
+        }
+    }
+}
+
+/// Find a `super` expression which refers to the function's home object.
+fn find_super(func: &Function<'_>) -> Option<Span> {
+    let mut finder = SuperFinder { span: None };
+    if let Some(body) = &func.body {
+        finder.visit_function_body(body);
+    }
+    finder.span
+}
+
+struct SuperFinder {
+    span: Option<Span>,
+}
+
+impl<'a> Visit<'a> for SuperFinder {
+    fn visit_super(&mut self, it: &Super) {
+        self.span.get_or_insert(it.span);
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        if let Some(super_class) = &class.super_class {
+            self.visit_expression(super_class);
+        }
+    }
+}
+
+/// Adapts the parsed runtime to the program it's inserted into.
+///
+/// * `_regeneratorRuntime` is renamed to the name generated for the runtime.
+/// * Spans are reset, as they refer to `runtime.js`, not the source file.
+struct RuntimePatcher<'a> {
+    name: Ident<'a>,
+}
+
+impl<'a> VisitMut<'a> for RuntimePatcher<'a> {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if ident.name == "_regeneratorRuntime" {
+            ident.name = self.name;
+        }
+        self.visit_span(&mut ident.span);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if ident.name == "_regeneratorRuntime" {
+            ident.name = self.name;
+        }
+        self.visit_span(&mut ident.span);
+    }
+
+    fn visit_span(&mut self, span: &mut Span) {
+        *span = SPAN;
+    }
+}
@@ -0,0 +1,111 @@
+//! ES2015: Shorthand Properties
+//!
+//! This plugin transforms shorthand properties and methods in object literals into plain properties.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var obj = {
+//!   a,
+//!   b() {},
+//!   __proto__,
+//! };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var obj = {
+//!   a: a,
+//!   b: function () {},
+//!   ["__proto__"]: __proto__,
+//! };
+//! ```
+//!
+//! `__proto__` keys are made computed, because a non-computed `__proto__: value` property would
+//! set the object's prototype instead of defining a property.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-shorthand-properties](https://babeljs.io/docs/babel-plugin-transform-shorthand-properties).
+//!
+//! Methods which contain `super` are left as they are, because `super` is only valid inside
+//! methods. Babel relies on the object-super transform having removed `super` first.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-shorthand-properties/src/index.ts>
+//! * Object initializer: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_semantic::ScopeFlags;
+use oxc_span::SPAN;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+pub struct ShorthandProperties;
+
+impl ShorthandProperties {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ShorthandProperties {
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if prop.shorthand {
+            prop.shorthand = false;
+        } else if prop.method {
+            let Expression::FunctionExpression(func) = &prop.value else { return };
+            if SuperFinder::find(func) {
+                return;
+            }
+            prop.method = false;
+        } else {
+            return;
+        }
+
+        // `__proto__` -> `["__proto__"]`
+        if !prop.computed && prop.key.is_specific_static_name("__proto__") {
+            prop.key = PropertyKey::new_string_literal(SPAN, "__proto__", None, ctx);
+            prop.computed = true;
+        }
+    }
+}
+
+/// Visitor which finds whether a method uses `super`.
+///
+/// Nested functions and class bodies are skipped, as `super` inside them does not refer to
+/// the method's home object.
+pub(super) struct SuperFinder {
+    found: bool,
+}
+
+impl SuperFinder {
+    pub(super) fn find(func: &Function<'_>) -> bool {
+        let mut finder = Self { found: false };
+        if let Some(body) = &func.body {
+            finder.visit_function_body(body);
+        }
+        finder.visit_formal_parameters(&func.params);
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for SuperFinder {
+    fn visit_super(&mut self, _it: &Super) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        if let Some(super_class) = &class.super_class {
+            self.visit_expression(super_class);
+        }
+    }
+}
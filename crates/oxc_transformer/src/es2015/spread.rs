@@ -0,0 +1,353 @@
+//! ES2015: Spread
+//!
+//! This plugin transforms spread elements in array literals, call arguments and `new` arguments.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var a = [b, ...c];
+//! foo(...a);
+//! obj.method(x, ...a);
+//! new Foo(...a);
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = [b].concat(babelHelpers.toConsumableArray(c));
+//! foo.apply(void 0, babelHelpers.toConsumableArray(a));
+//! obj.method.apply(obj, [x].concat(babelHelpers.toConsumableArray(a)));
+//! babelHelpers.construct(Foo, babelHelpers.toConsumableArray(a));
+//! ```
+//!
+//! With `iterableIsArray` assumption (or `loose` option), the spread arguments are assumed to be
+//! arrays, and used directly:
+//! ```js
+//! var a = [b].concat(c);
+//! foo.apply(void 0, a);
+//! ```
+//!
+//! With `arrayLikeIsIterable` assumption, array-like objects are also accepted:
+//! ```js
+//! var a = [b].concat(babelHelpers.maybeArrayLike(babelHelpers.toConsumableArray, c));
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-spread](https://babeljs.io/docs/babel-plugin-transform-spread).
+//!
+//! `super(...args)` is left for the classes transform, which calls [`Spread::transform_elements`].
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-spread/src/index.ts>
+//! * Spread syntax: <https://tc39.es/ecma262/#sec-array-initializer>
+
+use serde::Deserialize;
+
+use oxc_allocator::{ArenaVec, TakeIn};
+use oxc_ast::{ast::*, builder::NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::ReferenceFlags;
+use oxc_span::SPAN;
+use oxc_str::static_ident;
+use oxc_traverse::Traverse;
+
+use crate::{
+    common::{
+        duplicate::duplicate_expression,
+        helper_loader::{Helper, helper_call_expr, helper_load},
+    },
+    context::TraverseCtx,
+    state::TransformState,
+    utils::ast_builder::{create_member_callee, create_prototype_member},
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SpreadOptions {
+    /// Assume all spread arguments are arrays. Same as the `iterableIsArray` assumption.
+    pub loose: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Spread {
+    iterable_is_array: bool,
+    array_like_is_iterable: bool,
+    /// `true` if the classes transform is enabled, which transforms `super(...args)` itself.
+    classes: bool,
+}
+
+impl Spread {
+    pub fn new(options: SpreadOptions, classes: bool, state: &TransformState<'_>) -> Self {
+        Self {
+            iterable_is_array: options.loose || state.assumptions.iterable_is_array,
+            array_like_is_iterable: state.assumptions.array_like_is_iterable,
+            classes,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Spread {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::ArrayExpression(array)
+                if array.elements.iter().any(ArrayExpressionElement::is_spread) =>
+            {
+                self.transform_array_expression(expr, ctx);
+            }
+            Expression::CallExpression(call) if call.arguments.iter().any(Argument::is_spread) => {
+                self.transform_call_expression(call, ctx);
+            }
+            Expression::NewExpression(new_expr)
+                if new_expr.arguments.iter().any(Argument::is_spread) =>
+            {
+                self.transform_new_expression(expr, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Spread {
+    /// `[a, ...b]` -> `[a].concat(babelHelpers.toConsumableArray(b))`
+    fn transform_array_expression(self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ArrayExpression(array) = expr else { unreachable!() };
+        let span = array.span;
+        let elements = array.elements.take_in(ctx);
+        *expr = self.transform_elements(elements, span, ctx);
+    }
+
+    /// Convert array elements containing spread elements into an array expression without spread.
+    ///
+    /// * `[...a]` -> `babelHelpers.toConsumableArray(a)`
+    /// * `[a, ...b, c]` -> `[a].concat(babelHelpers.toConsumableArray(b), [c])`
+    /// * `[...a, b]` -> `[].concat(babelHelpers.toConsumableArray(a), [b])`
+    pub fn transform_elements(
+        self,
+        elements: ArenaVec<'a, ArrayExpressionElement<'a>>,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut parts = self.build(elements, ctx);
+
+        // If there is a single spread element, and it was converted, it's already a new array
+        if parts.len() == 1 && parts[0].converted {
+            let mut expr = parts.pop().unwrap().expr;
+            if let Expression::CallExpression(call) = &mut expr {
+                call.span = span;
+            }
+            return expr;
+        }
+
+        // `[a].concat(...)`, otherwise `[].concat(...)`.
+        // `arr.concat(...)` is not used, because `arr` may not be a plain array.
+        let first = if matches!(parts.first(), Some(part) if matches!(part.expr, Expression::ArrayExpression(_)))
+        {
+            parts.remove(0).expr
+        } else {
+            Expression::new_array_expression(SPAN, ArenaVec::new_in(ctx), ctx)
+        };
+        let callee = create_member_callee(first, static_ident!("concat"), SPAN, ctx);
+        let arguments =
+            ArenaVec::from_iter_in(parts.into_iter().map(|p| Argument::from(p.expr)), ctx);
+        Expression::new_call_expression(span, callee, NONE, arguments, false, ctx)
+    }
+
+    /// Convert call arguments into a single array expression, to pass to `apply`.
+    ///
+    /// * `(...a)` -> `babelHelpers.toConsumableArray(a)`
+    /// * `(...arguments)` -> `arguments`
+    /// * `(a, ...b)` -> `[a].concat(babelHelpers.toConsumableArray(b))`
+    /// * `(...a, b)` -> `babelHelpers.toConsumableArray(a).concat([b])`
+    fn transform_arguments(
+        self,
+        arguments: &mut ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if let [Argument::SpreadElement(spread)] = arguments.as_mut_slice()
+            && is_arguments(&spread.argument, ctx)
+        {
+            return spread.argument.take_in(ctx);
+        }
+
+        let elements = arguments.drain(..).map(ArrayExpressionElement::from);
+        let elements = ArenaVec::from_iter_in(elements, ctx);
+        let mut parts = self.build(elements, ctx).into_iter().map(|part| part.expr);
+        let first = parts.next().unwrap();
+        let rest = ArenaVec::from_iter_in(parts.map(Argument::from), ctx);
+        if rest.is_empty() {
+            return first;
+        }
+        let callee = create_member_callee(first, static_ident!("concat"), SPAN, ctx);
+        Expression::new_call_expression(SPAN, callee, NONE, rest, false, ctx)
+    }
+
+    /// Split elements into groups of non-spread elements (wrapped in an array literal),
+    /// and converted spread arguments.
+    fn build(
+        self,
+        elements: ArenaVec<'a, ArrayExpressionElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<SpreadPart<'a>> {
+        let mut parts = vec![];
+        let mut group = ArenaVec::new_in(ctx);
+        for element in elements {
+            if let ArrayExpressionElement::SpreadElement(spread) = element {
+                if !group.is_empty() {
+                    let group = group.take_in(ctx);
+                    let expr = Expression::new_array_expression(SPAN, group, ctx);
+                    parts.push(SpreadPart { expr, converted: false });
+                }
+                parts.push(self.create_spread_literal(spread.unbox().argument, ctx));
+            } else {
+                group.push(element);
+            }
+        }
+        if !group.is_empty() {
+            let expr = Expression::new_array_expression(SPAN, group, ctx);
+            parts.push(SpreadPart { expr, converted: false });
+        }
+        parts
+    }
+
+    /// Convert the argument of a spread element to an array.
+    ///
+    /// * `...a` -> `babelHelpers.toConsumableArray(a)`
+    /// * `...arguments` -> `Array.prototype.slice.call(arguments)`
+    /// * `...[a, b]` -> `[a, b]`
+    fn create_spread_literal(
+        self,
+        argument: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> SpreadPart<'a> {
+        if matches!(argument, Expression::ArrayExpression(_)) {
+            return SpreadPart { expr: argument, converted: false };
+        }
+        if is_arguments(&argument, ctx) {
+            let expr = create_arguments_slice(argument, ctx);
+            return SpreadPart { expr, converted: true };
+        }
+        if self.iterable_is_array {
+            return SpreadPart { expr: argument, converted: false };
+        }
+        let expr = create_to_array(
+            Helper::ToConsumableArray,
+            argument,
+            None,
+            self.array_like_is_iterable,
+            ctx,
+        );
+        SpreadPart { expr, converted: true }
+    }
+
+    /// * `foo(...a)` -> `foo.apply(void 0, babelHelpers.toConsumableArray(a))`
+    /// * `obj.foo(...a)` -> `obj.foo.apply(obj, babelHelpers.toConsumableArray(a))`
+    /// * `obj.foo?.(...a)` -> `obj.foo?.apply(obj, babelHelpers.toConsumableArray(a))`
+    fn transform_call_expression(self, call: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if call.callee.is_super() {
+            if !self.classes {
+                ctx.state.error(
+                    OxcDiagnostic::error(
+                        "It's not possible to compile spread arguments in `super()` without compiling classes.",
+                    )
+                    .with_label(call.span)
+                    .with_help("Enable the classes transform."),
+                );
+            }
+            return;
+        }
+
+        let arguments = self.transform_arguments(&mut call.arguments, ctx);
+
+        let callee = call.callee.get_inner_expression_mut();
+        let this = match callee.as_member_expression_mut() {
+            Some(member) => {
+                let object = member.object_mut();
+                if object.is_super() {
+                    Expression::new_this_expression(SPAN, ctx)
+                } else {
+                    let (assignment, reference) =
+                        duplicate_expression(object.take_in(ctx), true, ctx);
+                    *object = assignment;
+                    reference
+                }
+            }
+            None => Expression::new_void_0(SPAN, ctx),
+        };
+
+        let callee = call.callee.take_in(ctx);
+        let property = IdentifierName::new(SPAN, static_ident!("apply"), ctx);
+        call.callee =
+            Expression::new_static_member_expression(SPAN, callee, property, call.optional, ctx);
+        call.optional = false;
+        call.arguments =
+            ArenaVec::from_array_in([Argument::from(this), Argument::from(arguments)], ctx);
+    }
+
+    /// `new Foo(...a)` -> `babelHelpers.construct(Foo, babelHelpers.toConsumableArray(a))`
+    fn transform_new_expression(self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::NewExpression(new_expr) = expr else { unreachable!() };
+        let arguments = self.transform_arguments(&mut new_expr.arguments, ctx);
+        let callee = new_expr.callee.take_in(ctx);
+        let arguments =
+            ArenaVec::from_array_in([Argument::from(callee), Argument::from(arguments)], ctx);
+        let mut construct = helper_call_expr(Helper::Construct, arguments, ctx);
+        if let Expression::CallExpression(call) = &mut construct {
+            call.span = new_expr.span;
+        }
+        *expr = construct;
+    }
+}
+
+/// Part of an array produced from array elements.
+struct SpreadPart<'a> {
+    expr: Expression<'a>,
+    /// `true` if the spread argument was converted into a new array.
+    converted: bool,
+}
+
+/// `babelHelpers.toConsumableArray(a)`, or
+/// `babelHelpers.maybeArrayLike(babelHelpers.toConsumableArray, a)` with `arrayLikeIsIterable`.
+///
+/// Also used by the destructuring transform, with `slicedToArray` and `toArray`.
+pub(super) fn create_to_array<'a>(
+    helper: Helper,
+    argument: Expression<'a>,
+    length: Option<Expression<'a>>,
+    array_like_is_iterable: bool,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let mut arguments = ArenaVec::from_value_in(Argument::from(argument), ctx);
+    if let Some(length) = length {
+        arguments.push(Argument::from(length));
+    }
+    if array_like_is_iterable {
+        arguments.insert(0, Argument::from(helper_load(helper, ctx)));
+        helper_call_expr(Helper::MaybeArrayLike, arguments, ctx)
+    } else {
+        helper_call_expr(helper, arguments, ctx)
+    }
+}
+
+/// `arguments` -> `Array.prototype.slice.call(arguments)`
+pub(super) fn create_arguments_slice<'a>(
+    argument: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let array = ctx.create_unbound_ident_expr(SPAN, static_ident!("Array"), ReferenceFlags::Read);
+    let prototype = create_prototype_member(array, SPAN, ctx);
+    let slice = create_member_callee(prototype, static_ident!("slice"), SPAN, ctx);
+    let callee = create_member_callee(slice, static_ident!("call"), SPAN, ctx);
+    let arguments = ArenaVec::from_value_in(Argument::from(argument), ctx);
+    Expression::new_call_expression(SPAN, callee, NONE, arguments, false, ctx)
+}
+
+/// Returns `true` if `expr` is a reference to the function's `arguments` object.
+pub(super) fn is_arguments(expr: &Expression<'_>, ctx: &TraverseCtx<'_>) -> bool {
+    matches!(expr, Expression::Identifier(ident)
+        if ident.name == "arguments"
+            && ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_none())
+}
@@ -0,0 +1,196 @@
+//! ES2015: Template Literals
+//!
+//! This plugin transforms template literals and tagged templates into ES5 string operations.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! `foo${bar}baz`;
+//! `${a}${b}`;
+//! tag`foo${bar}\n`;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _templateObject;
+//! "foo".concat(bar, "baz");
+//! "".concat(a).concat(b);
+//! tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo", "\n"], ["foo", "\\n"])), bar);
+//! ```
+//!
+//! With `ignoreToPrimitiveHint` assumption (or `loose` option), `+` is used instead of `concat`,
+//! which calls `valueOf` rather than `toString` on objects:
+//! ```js
+//! "foo" + bar + "baz";
+//! "" + a + b;
+//! ```
+//!
+//! With `mutableTemplateObject` assumption (or `loose` option), the cached template object is not frozen:
+//! ```js
+//! tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteralLoose(["foo", "\n"], ["foo", "\\n"])), bar);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-template-literals](https://babeljs.io/docs/babel-plugin-transform-template-literals).
+//!
+//! Tagged templates are lowered with the same code as the `</script` escaping plugin
+//! ([`TaggedTemplateTransform`]), only with a different helper in loose mode.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-template-literals/src/index.ts>
+//! * Template literals: <https://tc39.es/ecma262/#sec-template-literals>
+
+use serde::Deserialize;
+
+use oxc_allocator::{ArenaVec, TakeIn};
+use oxc_ast::{ast::*, builder::NONE};
+use oxc_span::SPAN;
+use oxc_str::static_ident;
+use oxc_traverse::Traverse;
+
+use crate::{
+    common::helper_loader::Helper, context::TraverseCtx, plugins::TaggedTemplateTransform,
+    state::TransformState, utils::ast_builder::create_member_callee,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TemplateLiteralsOptions {
+    /// Same as enabling both the `ignoreToPrimitiveHint` and `mutableTemplateObject` assumptions.
+    pub loose: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TemplateLiterals {
+    ignore_to_primitive_hint: bool,
+    mutable_template_object: bool,
+}
+
+impl TemplateLiterals {
+    pub fn new(options: TemplateLiteralsOptions, state: &TransformState<'_>) -> Self {
+        Self {
+            ignore_to_primitive_hint: options.loose || state.assumptions.ignore_to_primitive_hint,
+            mutable_template_object: options.loose || state.assumptions.mutable_template_object,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for TemplateLiterals {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::TemplateLiteral(_) => self.transform_template_literal(expr, ctx),
+            Expression::TaggedTemplateExpression(_) => {
+                self.transform_tagged_template_expression(expr, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> TemplateLiterals {
+    /// * `` `foo${bar}baz` `` -> `"foo".concat(bar, "baz")`
+    /// * `` `foo${bar}baz` `` -> `"foo" + bar + "baz"` (with `ignoreToPrimitiveHint`)
+    fn transform_template_literal(self, expr: &mut Expression<'a>, ctx: &TraverseCtx<'a>) {
+        let Expression::TemplateLiteral(lit) = expr.take_in(ctx) else { unreachable!() };
+        let TemplateLiteral { span, quasis, expressions, .. } = lit.unbox();
+
+        let mut nodes = Vec::with_capacity(quasis.len() + expressions.len() + 1);
+        let mut expressions = expressions.into_iter();
+        for quasi in quasis {
+            // Untagged templates with invalid escapes are a syntax error, so `cooked` is always `Some`
+            if let Some(cooked) = quasi.value.cooked
+                && !cooked.is_empty()
+            {
+                nodes.push(Expression::new_string_literal(SPAN, cooked, None, ctx));
+            }
+            if let Some(expression) = expressions.next()
+                && !expression.is_specific_string_literal("")
+            {
+                nodes.push(expression);
+            }
+        }
+
+        // `+` is left-associative, so make sure the first or second operand is a string
+        let first_is_string = matches!(nodes.first(), Some(Expression::StringLiteral(_)));
+        let second_is_string = matches!(nodes.get(1), Some(Expression::StringLiteral(_)));
+        if !(first_is_string || self.ignore_to_primitive_hint && second_is_string) {
+            nodes.insert(0, Expression::new_string_literal(SPAN, "", None, ctx));
+        }
+
+        let mut nodes = nodes.into_iter();
+        let first = nodes.next().unwrap();
+        let mut result = if self.ignore_to_primitive_hint {
+            nodes.fold(first, |left, right| {
+                Expression::new_binary_expression(SPAN, left, BinaryOperator::Addition, right, ctx)
+            })
+        } else {
+            Self::build_concat_call_expressions(first, nodes, ctx)
+        };
+
+        match &mut result {
+            Expression::StringLiteral(lit) => lit.span = span,
+            Expression::BinaryExpression(binary) => binary.span = span,
+            Expression::CallExpression(call) => call.span = span,
+            _ => {}
+        }
+        *expr = result;
+    }
+
+    /// Chain `.concat()` calls, starting from `first`.
+    ///
+    /// Literals are appended to the arguments of the previous `concat` call, as is the first
+    /// non-literal expression. Every other expression starts a new `concat` call, so `toString`
+    /// is called on each of them in order, after evaluating all of the expressions before it.
+    ///
+    /// `["", a, b, "c"]` -> `"".concat(a).concat(b, "c")`
+    fn build_concat_call_expressions(
+        first: Expression<'a>,
+        nodes: impl Iterator<Item = Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut avail = true;
+        nodes.fold(first, |mut left, right| {
+            let mut can_be_inserted =
+                right.is_literal() || matches!(right, Expression::TemplateLiteral(_));
+            if !can_be_inserted && avail {
+                can_be_inserted = true;
+                avail = false;
+            }
+            if can_be_inserted && let Expression::CallExpression(call) = &mut left {
+                call.arguments.push(Argument::from(right));
+                return left;
+            }
+            let callee = create_member_callee(left, static_ident!("concat"), SPAN, ctx);
+            let arguments = ArenaVec::from_value_in(Argument::from(right), ctx);
+            Expression::new_call_expression(SPAN, callee, NONE, arguments, false, ctx)
+        })
+    }
+
+    /// `` tag`foo${bar}` `` ->
+    /// `tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo", ""])), bar)`
+    fn transform_tagged_template_expression(
+        self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::TaggedTemplateExpression(tagged) = expr.take_in(ctx) else {
+            unreachable!()
+        };
+        let TaggedTemplateExpression { span, tag, quasi, type_arguments, .. } = tagged.unbox();
+
+        let helper = if self.mutable_template_object {
+            Helper::TaggedTemplateLiteralLoose
+        } else {
+            Helper::TaggedTemplateLiteral
+        };
+        let binding = TaggedTemplateTransform::create_top_level_binding(ctx);
+        let arguments =
+            TaggedTemplateTransform::transform_template_literal(&binding, quasi, helper, ctx);
+        *expr = Expression::new_call_expression(span, tag, type_arguments, arguments, false, ctx);
+    }
+}
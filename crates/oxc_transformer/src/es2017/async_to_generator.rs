@@ -68,6 +68,7 @@ use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
 use crate::{
     common::helper_loader::{Helper, helper_call_expr},
     context::TraverseCtx,
+    es2015::{Regenerator, RegeneratorOptions},
     state::TransformState,
     utils::sync_function_symbol_flags,
 };
@@ -77,8 +78,9 @@ pub struct AsyncToGenerator<'a> {
 }
 
 impl AsyncToGenerator<'_> {
-    pub fn new() -> Self {
-        Self { executor: AsyncGeneratorExecutor::new(Helper::AsyncToGenerator) }
+    pub fn new(regenerator: Option<RegeneratorOptions>) -> Self {
+        let regenerator = regenerator.map(Regenerator::new);
+        Self { executor: AsyncGeneratorExecutor::new(Helper::AsyncToGenerator, regenerator) }
     }
 }
 
@@ -194,12 +196,14 @@ impl<'a> AsyncToGenerator<'a> {
 
 pub struct AsyncGeneratorExecutor<'a> {
     helper: Helper,
+    /// Compiles the generator passed to the helper, if the regenerator transform is enabled.
+    regenerator: Option<Regenerator>,
     _marker: std::marker::PhantomData<&'a ()>,
 }
 
 impl<'a> AsyncGeneratorExecutor<'a> {
-    pub fn new(helper: Helper) -> Self {
-        Self { helper, _marker: std::marker::PhantomData }
+    pub fn new(helper: Helper, regenerator: Option<Regenerator>) -> Self {
+        Self { helper, regenerator, _marker: std::marker::PhantomData }
     }
 
     /// Transforms async method definitions to generator functions wrapped in asyncToGenerator.
@@ -770,7 +774,12 @@ impl<'a> AsyncGeneratorExecutor<'a> {
             ctx,
         );
         function.generator = true;
-        let arguments = ArenaVec::from_value_in(Argument::FunctionExpression(function), ctx);
+        let argument = if let Some(regenerator) = self.regenerator {
+            Argument::from(regenerator.transform_generator_expression(function, ctx))
+        } else {
+            Argument::FunctionExpression(function)
+        };
+        let arguments = ArenaVec::from_value_in(argument, ctx);
         helper_call_expr(self.helper, arguments, ctx)
     }
 
@@ -1,7 +1,7 @@
 use oxc_ast::ast::{Expression, Function, Statement};
 use oxc_traverse::Traverse;
 
-use crate::{context::TraverseCtx, state::TransformState};
+use crate::{context::TraverseCtx, es2015::RegeneratorOptions, state::TransformState};
 
 mod async_to_generator;
 mod options;
@@ -16,8 +16,8 @@ pub struct ES2017<'a> {
 }
 
 impl<'a> ES2017<'a> {
-    pub fn new(options: ES2017Options) -> ES2017<'a> {
-        ES2017 { async_to_generator: AsyncToGenerator::new(), options }
+    pub fn new(options: ES2017Options, regenerator: Option<RegeneratorOptions>) -> ES2017<'a> {
+        ES2017 { async_to_generator: AsyncToGenerator::new(regenerator), options }
     }
 }
 
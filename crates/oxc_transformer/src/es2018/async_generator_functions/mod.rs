@@ -74,6 +74,7 @@ use oxc_traverse::{Ancestor, Traverse};
 use crate::{
     common::helper_loader::{Helper, helper_call_expr},
     context::TraverseCtx,
+    es2015::{Regenerator, RegeneratorOptions},
     es2017::AsyncGeneratorExecutor,
     state::TransformState,
 };
@@ -83,8 +84,9 @@ pub struct AsyncGeneratorFunctions<'a> {
 }
 
 impl AsyncGeneratorFunctions<'_> {
-    pub fn new() -> Self {
-        Self { executor: AsyncGeneratorExecutor::new(Helper::WrapAsyncGenerator) }
+    pub fn new(regenerator: Option<RegeneratorOptions>) -> Self {
+        let regenerator = regenerator.map(Regenerator::new);
+        Self { executor: AsyncGeneratorExecutor::new(Helper::WrapAsyncGenerator, regenerator) }
     }
 }
 
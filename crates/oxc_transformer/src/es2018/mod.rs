@@ -1,7 +1,7 @@
 use oxc_ast::ast::*;
 use oxc_traverse::Traverse;
 
-use crate::{context::TraverseCtx, state::TransformState};
+use crate::{context::TraverseCtx, es2015::RegeneratorOptions, state::TransformState};
 
 mod async_generator_functions;
 mod object_rest_spread;
@@ -20,13 +20,14 @@ pub struct ES2018<'a> {
 }
 
 impl<'a> ES2018<'a> {
-    pub fn new(options: ES2018Options, state: &mut TransformState<'a>) -> Self {
+    pub fn new(
+        options: ES2018Options,
+        regenerator: Option<RegeneratorOptions>,
+        state: &mut TransformState<'a>,
+    ) -> Self {
         Self {
-            object_rest_spread: ObjectRestSpread::new(
-                options.object_rest_spread.unwrap_or_default(),
-                state,
-            ),
-            async_generator_functions: AsyncGeneratorFunctions::new(),
+            object_rest_spread: ObjectRestSpread::new(options.object_rest_spread, state),
+            async_generator_functions: AsyncGeneratorFunctions::new(regenerator),
             options,
         }
     }
@@ -43,6 +43,7 @@ use crate::{
     common::helper_loader::{Helper, helper_call, helper_call_expr, helper_load},
     context::TraverseCtx,
     state::TransformState,
+    utils::kind_to_symbol_flags,
 };
 
 #[derive(Debug, Default, Clone, Copy, Deserialize)]
@@ -60,28 +61,30 @@ pub struct ObjectRestSpread<'a> {
 }
 
 impl<'a> ObjectRestSpread<'a> {
-    pub fn new(options: ObjectRestSpreadOptions, state: &mut TransformState<'a>) -> Self {
-        if options.loose {
-            state.error(OxcDiagnostic::error(
-                "Option `loose` is not implemented for object-rest-spread.",
-            ));
-        }
-        if options.use_built_ins {
-            state.error(OxcDiagnostic::error(
-                "Option `useBuiltIns` is not implemented for object-rest-spread.",
-            ));
-        }
-        if state.assumptions.object_rest_no_symbols {
-            state.error(OxcDiagnostic::error(
-                "Compiler assumption `objectRestNoSymbols` is not implemented for object-rest-spread.",
-            ));
-        }
-        if state.assumptions.ignore_function_length {
-            state.error(OxcDiagnostic::error(
-                "Compiler assumption `ignoreFunctionLength` is not implemented for object-rest-spread.",
-            ));
+    pub fn new(options: Option<ObjectRestSpreadOptions>, state: &mut TransformState<'a>) -> Self {
+        if let Some(options) = options {
+            if options.loose {
+                state.error(OxcDiagnostic::error(
+                    "Option `loose` is not implemented for object-rest-spread.",
+                ));
+            }
+            if options.use_built_ins {
+                state.error(OxcDiagnostic::error(
+                    "Option `useBuiltIns` is not implemented for object-rest-spread.",
+                ));
+            }
+            if state.assumptions.object_rest_no_symbols {
+                state.error(OxcDiagnostic::error(
+                    "Compiler assumption `objectRestNoSymbols` is not implemented for object-rest-spread.",
+                ));
+            }
+            if state.assumptions.ignore_function_length {
+                state.error(OxcDiagnostic::error(
+                    "Compiler assumption `ignoreFunctionLength` is not implemented for object-rest-spread.",
+                ));
+            }
         }
-        Self { options, excluded_variable_declarators: vec![] }
+        Self { options: options.unwrap_or_default(), excluded_variable_declarators: vec![] }
     }
 }
 
@@ -1102,18 +1105,6 @@ impl State {
     }
 }
 
-fn kind_to_symbol_flags(kind: VariableDeclarationKind) -> SymbolFlags {
-    match kind {
-        VariableDeclarationKind::Var => SymbolFlags::FunctionScopedVariable,
-        VariableDeclarationKind::Let
-        | VariableDeclarationKind::Using
-        | VariableDeclarationKind::AwaitUsing => SymbolFlags::BlockScopedVariable,
-        VariableDeclarationKind::Const => {
-            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
-        }
-    }
-}
-
 #[derive(Debug)]
 enum BindingPatternOrAssignmentTarget<'a> {
     BindingPattern(BindingPattern<'a>),
@@ -162,7 +162,7 @@ impl<'a> ClassProperties<'a> {
                         );
                     }
                 }
-                ClassElement::TSIndexSignature(_) => {
+                ClassElement::TSIndexSignature(_) | ClassElement::ErrorNode(_) => {
                     // TODO: Need to handle this?
                 }
             }
@@ -280,7 +280,9 @@ impl<'a> ClassProperties<'a> {
                         constructor = Some(method.value.as_mut());
                     }
                 }
-                ClassElement::AccessorProperty(_) | ClassElement::TSIndexSignature(_) => {
+                ClassElement::AccessorProperty(_)
+                | ClassElement::TSIndexSignature(_)
+                | ClassElement::ErrorNode(_) => {
                     // TODO: Need to handle these?
                 }
                 ClassElement::StaticBlock(_) => {}
@@ -799,7 +801,9 @@ impl<'a> ClassProperties<'a> {
                         return false;
                     }
                 }
-                ClassElement::AccessorProperty(_) | ClassElement::TSIndexSignature(_) => {
+                ClassElement::AccessorProperty(_)
+                | ClassElement::TSIndexSignature(_)
+                | ClassElement::ErrorNode(_) => {
                     // TODO: Need to handle these?
                 }
             }
@@ -13,7 +13,6 @@ use oxc_diagnostics::Diagnostics;
 #[cfg(feature = "react_compiler")]
 use oxc_react_compiler::{PluginOptions, transform as react_compiler_transform};
 use oxc_semantic::Scoping;
-#[cfg(feature = "react_compiler")]
 use oxc_semantic::SemanticBuilder;
 use oxc_span::{GetSpan, SPAN};
 use oxc_traverse::{ReusableTraverseCtx, Traverse, traverse_mut_with_ctx};
@@ -57,6 +56,7 @@ use es2021::ES2021;
 use es2022::ES2022;
 use es2026::ES2026;
 use jsx::Jsx;
+use proposals::Proposals;
 use regexp::RegExp;
 use rustc_hash::FxHashMap;
 use state::TransformState;
@@ -68,7 +68,11 @@ pub use crate::{
     compiler_assumptions::CompilerAssumptions,
     context::TransformCtx,
     decorator::DecoratorOptions,
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ComputedPropertiesOptions,
+        DestructuringOptions, ES2015Options, ForOfOptions, ParametersOptions, RegeneratorOptions,
+        SpreadOptions, TemplateLiteralsOptions,
+    },
     es2016::ES2016Options,
     es2017::ES2017Options,
     es2018::ES2018Options,
@@ -114,7 +118,6 @@ pub struct Transformer<'a> {
     plugins: PluginsOptions,
     jsx: JsxOptions,
     env: EnvOptions,
-    #[expect(dead_code)]
     proposals: ProposalOptions,
 }
 
@@ -183,9 +186,7 @@ impl<'a> Transformer<'a> {
             common: Common::new(&self.env),
             decorator: Decorator::new(self.decorator),
             plugins: Plugins::new(self.plugins),
-            x0_typescript: program
-                .source_type
-                .is_typescript()
+            x0_typescript: (program.source_type.is_typescript() || program.source_type.is_flow())
                 .then(|| TypeScript::new(&self.typescript, &self.state)),
             x1_jsx: Jsx::new(
                 self.jsx,
@@ -202,16 +203,21 @@ impl<'a> Transformer<'a> {
             x2_es2021: ES2021::new(self.env.es2021),
             x2_es2020: ES2020::new(self.env.es2020),
             x2_es2019: ES2019::new(self.env.es2019),
-            x2_es2018: ES2018::new(self.env.es2018, &mut self.state),
+            x2_es2018: ES2018::new(self.env.es2018, self.env.es2015.regenerator, &mut self.state),
             x2_es2016: ES2016::new(self.env.es2016),
-            x2_es2017: ES2017::new(self.env.es2017),
-            x3_es2015: ES2015::new(self.env.es2015),
+            x2_es2017: ES2017::new(self.env.es2017, self.env.es2015.regenerator),
+            x3_es2015: ES2015::new(self.env.es2015, &self.state),
             x4_regexp: RegExp::new(self.env.regexp),
+            x5_proposals: Proposals::new(self.proposals),
         };
 
         let mut reusable_ctx = ReusableTraverseCtx::new(self.state, scoping, allocator);
         traverse_mut_with_ctx(&mut transformer, program, &mut reusable_ctx);
-        let (mut state, scoping) = reusable_ctx.into_state_and_scoping();
+        let (mut state, mut scoping) = reusable_ctx.into_state_and_scoping();
+        // The inlined regenerator runtime was parsed without semantic analysis
+        if state.regenerator_runtime.is_some() {
+            scoping = SemanticBuilder::new().build(program).semantic.into_scoping();
+        }
         let helpers_used = state.helper_loader.used_helpers.drain().collect();
         let mut diagnostics = react_compiler_diagnostics;
         diagnostics.extend(state.take_errors());
@@ -253,9 +259,9 @@ struct TransformerImpl<'a> {
     x2_es2018: ES2018<'a>,
     x2_es2017: ES2017<'a>,
     x2_es2016: ES2016<'a>,
-    #[expect(unused)]
     x3_es2015: ES2015<'a>,
     x4_regexp: RegExp,
+    x5_proposals: Proposals,
     common: Common<'a>,
 }
 
@@ -267,6 +273,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         self.plugins.enter_program(program, ctx);
         self.x1_jsx.enter_program(program, ctx);
         self.x2_es2026.enter_program(program, ctx);
+        self.x3_es2015.enter_program(program, ctx);
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -278,6 +285,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         self.x2_es2022.exit_program(program, ctx);
         self.x2_es2020.exit_program(program, ctx);
         self.x2_es2018.exit_program(program, ctx);
+        self.x3_es2015.exit_program(program, ctx);
         self.common.exit_program(program, ctx);
     }
 
@@ -292,6 +300,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
             typescript.enter_arrow_function_expression(arrow, ctx);
         }
         self.x2_es2018.enter_arrow_function_expression(arrow, ctx);
+        self.x3_es2015.enter_arrow_function_expression(arrow, ctx);
     }
 
     fn enter_variable_declaration(
@@ -300,6 +309,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.x2_es2018.enter_variable_declaration(decl, ctx);
+        self.x3_es2015.enter_variable_declaration(decl, ctx);
     }
 
     fn enter_variable_declarator(
@@ -347,6 +357,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_binding_identifier(node, ctx);
+        self.x3_es2015.enter_binding_identifier(node, ctx);
     }
 
     fn enter_identifier_reference(
@@ -355,6 +366,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_identifier_reference(node, ctx);
+        self.x3_es2015.enter_identifier_reference(node, ctx);
     }
 
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -382,6 +394,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_class(class, ctx);
         }
+        self.x3_es2015.enter_class(class, ctx);
     }
 
     fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -392,21 +405,25 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         self.x2_es2022.exit_class(class, ctx);
         // `decorator` has some statements should be inserted after `class-properties` plugin.
         self.decorator.exit_class_at_end(class, ctx);
+        self.x3_es2015.exit_class(class, ctx);
     }
 
     fn enter_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x2_es2022.enter_class_body(body, ctx);
+        self.x3_es2015.enter_class_body(body, ctx);
     }
 
     fn enter_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
         self.common.enter_static_block(block, ctx);
         self.x2_es2022.enter_static_block(block, ctx);
+        self.x3_es2015.enter_static_block(block, ctx);
     }
 
     fn exit_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
         self.common.exit_static_block(block, ctx);
         self.x2_es2026.exit_static_block(block, ctx);
         self.x2_es2022.exit_static_block(block, ctx);
+        self.x3_es2015.exit_static_block(block, ctx);
     }
 
     #[inline]
@@ -421,7 +438,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         self.x2_es2020.enter_expression(expr, ctx);
         self.x2_es2018.enter_expression(expr, ctx);
         self.x2_es2016.enter_expression(expr, ctx);
+        self.x3_es2015.enter_expression(expr, ctx);
         self.x4_regexp.enter_expression(expr, ctx);
+        self.x5_proposals.enter_expression(expr, ctx);
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -430,6 +449,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         self.x2_es2022.exit_expression(expr, ctx);
         self.x2_es2018.exit_expression(expr, ctx);
         self.x2_es2017.exit_expression(expr, ctx);
+        self.x3_es2015.exit_expression(expr, ctx);
     }
 
     fn enter_simple_assignment_target(
@@ -482,6 +502,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
         self.common.enter_function(func, ctx);
         self.x2_es2018.enter_function(func, ctx);
+        self.x3_es2015.enter_function(func, ctx);
     }
 
     fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -491,15 +512,18 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         self.x1_jsx.exit_function(func, ctx);
         self.x2_es2018.exit_function(func, ctx);
         self.x2_es2017.exit_function(func, ctx);
+        self.x3_es2015.exit_function(func, ctx);
         self.common.exit_function(func, ctx);
     }
 
     fn enter_function_body(&mut self, body: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
         self.common.enter_function_body(body, ctx);
         self.x2_es2026.enter_function_body(body, ctx);
+        self.x3_es2015.enter_function_body(body, ctx);
     }
 
     fn exit_function_body(&mut self, body: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_function_body(body, ctx);
         self.common.exit_function_body(body, ctx);
     }
 
@@ -547,6 +571,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_method_definition(def, ctx);
         }
+        self.x3_es2015.enter_method_definition(def, ctx);
     }
 
     fn exit_method_definition(
@@ -555,6 +580,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.decorator.exit_method_definition(def, ctx);
+        self.x3_es2015.exit_method_definition(def, ctx);
     }
 
     fn enter_new_expression(&mut self, expr: &mut NewExpression<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -573,6 +599,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
             typescript.enter_property_definition(def, ctx);
         }
         self.x2_es2022.enter_property_definition(def, ctx);
+        self.x3_es2015.enter_property_definition(def, ctx);
     }
 
     fn exit_property_definition(
@@ -582,6 +609,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
     ) {
         self.decorator.exit_property_definition(def, ctx);
         self.x2_es2022.exit_property_definition(def, ctx);
+        self.x3_es2015.exit_property_definition(def, ctx);
     }
 
     fn enter_accessor_property(
@@ -593,6 +621,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_accessor_property(node, ctx);
         }
+        self.x3_es2015.enter_accessor_property(node, ctx);
     }
 
     fn exit_accessor_property(
@@ -601,6 +630,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.decorator.exit_accessor_property(node, ctx);
+        self.x3_es2015.exit_accessor_property(node, ctx);
     }
 
     fn enter_statements(
@@ -670,6 +700,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         self.decorator.exit_statement(stmt, ctx);
         self.x2_es2018.exit_statement(stmt, ctx);
         self.x2_es2017.exit_statement(stmt, ctx);
+        self.x3_es2015.exit_statement(stmt, ctx);
     }
 
     fn enter_tagged_template_expression(
@@ -689,6 +720,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         }
         self.x2_es2018.enter_statement(stmt, ctx);
         self.x2_es2026.enter_statement(stmt, ctx);
+        self.x3_es2015.enter_statement(stmt, ctx);
     }
 
     fn enter_declaration(&mut self, decl: &mut Declaration<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -707,6 +739,11 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_while_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_while_statement(stmt, ctx);
+    }
+
+    fn exit_while_statement(&mut self, stmt: &mut WhileStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_while_statement(stmt, ctx);
     }
 
     fn enter_do_while_statement(
@@ -717,12 +754,26 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_do_while_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_do_while_statement(stmt, ctx);
+    }
+
+    fn exit_do_while_statement(
+        &mut self,
+        stmt: &mut DoWhileStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.exit_do_while_statement(stmt, ctx);
     }
 
     fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_for_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_for_statement(stmt, ctx);
+    }
+
+    fn exit_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_for_statement(stmt, ctx);
     }
 
     fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -731,6 +782,11 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         }
         self.x2_es2026.enter_for_of_statement(stmt, ctx);
         self.x2_es2018.enter_for_of_statement(stmt, ctx);
+        self.x3_es2015.enter_for_of_statement(stmt, ctx);
+    }
+
+    fn exit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_for_of_statement(stmt, ctx);
     }
 
     fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -738,15 +794,41 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
             typescript.enter_for_in_statement(stmt, ctx);
         }
         self.x2_es2018.enter_for_in_statement(stmt, ctx);
+        self.x3_es2015.enter_for_in_statement(stmt, ctx);
+    }
+
+    fn exit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_for_in_statement(stmt, ctx);
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.enter_block_statement(block, ctx);
+    }
+
+    fn exit_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_block_statement(block, ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_switch_statement(stmt, ctx);
     }
 
     fn enter_try_statement(&mut self, stmt: &mut TryStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x2_es2026.enter_try_statement(stmt, ctx);
     }
 
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.enter_object_property(prop, ctx);
+    }
+
     fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x2_es2019.enter_catch_clause(clause, ctx);
         self.x2_es2018.enter_catch_clause(clause, ctx);
+        self.x3_es2015.enter_catch_clause(clause, ctx);
     }
 
     fn enter_import_declaration(
@@ -1,8 +1,15 @@
 use serde::Deserialize;
 
 use crate::{
-    DecoratorOptions, TypeScriptOptions, es2015::ArrowFunctionsOptions,
-    es2018::ObjectRestSpreadOptions, es2022::ClassPropertiesOptions, jsx::JsxOptions,
+    DecoratorOptions, TypeScriptOptions,
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ComputedPropertiesOptions,
+        DestructuringOptions, ForOfOptions, ParametersOptions, RegeneratorOptions, SpreadOptions,
+        TemplateLiteralsOptions,
+    },
+    es2018::ObjectRestSpreadOptions,
+    es2022::ClassPropertiesOptions,
+    jsx::JsxOptions,
     plugins::StyledComponentsOptions,
 };
 
@@ -22,6 +29,15 @@ pub struct SyntaxDecoratorOptions {
     pub version: String,
 }
 
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PipelineOperatorOptions {
+    #[serde(default)]
+    pub proposal: String,
+    #[serde(default)]
+    pub topic_token: String,
+}
+
 #[derive(Debug, Default, Clone, Deserialize)]
 #[serde(try_from = "PluginPresetEntries")]
 pub struct BabelPlugins {
@@ -55,6 +71,19 @@ pub struct BabelPlugins {
     pub set_notation: bool,
     // ES2015
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    pub block_scoping: Option<BlockScopingOptions>,
+    pub classes: bool,
+    pub parameters: Option<ParametersOptions>,
+    pub destructuring: Option<DestructuringOptions>,
+    pub spread: Option<SpreadOptions>,
+    pub template_literals: Option<TemplateLiteralsOptions>,
+    pub shorthand_properties: bool,
+    pub computed_properties: Option<ComputedPropertiesOptions>,
+    pub for_of: Option<ForOfOptions>,
+    pub function_name: bool,
+    pub duplicate_keys: bool,
+    pub new_target: bool,
+    pub regenerator: Option<RegeneratorOptions>,
     // ES2016
     pub exponentiation_operator: bool,
     // ES2017
@@ -75,6 +104,8 @@ pub struct BabelPlugins {
     pub class_properties: Option<ClassPropertiesOptions>,
     // ES2026
     pub explicit_resource_management: bool,
+    // Proposals
+    pub pipeline_operator: bool,
     // Decorator
     pub legacy_decorator: Option<DecoratorOptions>,
     // Built-in plugins
@@ -142,6 +173,53 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         .map_err(|err| p.errors.push(err))
                         .ok();
                 }
+                "transform-block-scoping" => {
+                    p.block_scoping = entry
+                        .value::<BlockScopingOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-classes" => p.classes = true,
+                "transform-parameters" => {
+                    p.parameters = entry
+                        .value::<ParametersOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-destructuring" => {
+                    p.destructuring = entry
+                        .value::<DestructuringOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-spread" => {
+                    p.spread =
+                        entry.value::<SpreadOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-template-literals" => {
+                    p.template_literals = entry
+                        .value::<TemplateLiteralsOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-shorthand-properties" => p.shorthand_properties = true,
+                "transform-computed-properties" => {
+                    p.computed_properties = entry
+                        .value::<ComputedPropertiesOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-for-of" => {
+                    p.for_of =
+                        entry.value::<ForOfOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-function-name" => p.function_name = true,
+                "transform-duplicate-keys" => p.duplicate_keys = true,
+                "transform-new-target" => p.new_target = true,
+                "transform-regenerator" => {
+                    p.regenerator =
+                        entry.value::<RegeneratorOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
                 "transform-exponentiation-operator" => p.exponentiation_operator = true,
                 "transform-async-to-generator" => p.async_to_generator = true,
                 "transform-object-rest-spread" => {
@@ -163,6 +241,17 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         .map_err(|err| p.errors.push(err))
                         .ok();
                 }
+                "proposal-pipeline-operator" => {
+                    match entry.value::<PipelineOperatorOptions>() {
+                        Ok(options) if options.proposal == "hack" && options.topic_token == "%" => {
+                            p.pipeline_operator = true;
+                        }
+                        Ok(_) => p.errors.push(
+                            "Only `{ proposal: \"hack\", topicToken: \"%\" }` is supported for `proposal-pipeline-operator`".to_string(),
+                        ),
+                        Err(err) => p.errors.push(err),
+                    }
+                }
                 // This is not a Babel plugin, we pretend it exists for running legacy decorator by Babel options
                 "transform-legacy-decorator" => {
                     p.legacy_decorator =
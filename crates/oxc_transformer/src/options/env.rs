@@ -1,7 +1,11 @@
 use serde::Deserialize;
 
 use crate::{
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ComputedPropertiesOptions,
+        DestructuringOptions, ES2015Options, ForOfOptions, ParametersOptions, RegeneratorOptions,
+        SpreadOptions, TemplateLiteralsOptions,
+    },
     es2016::ES2016Options,
     es2017::ES2017Options,
     es2018::{ES2018Options, ObjectRestSpreadOptions},
@@ -99,6 +103,51 @@ impl EnvOptions {
                 } else {
                     None
                 },
+                block_scoping: if include_unfinished_plugins {
+                    Some(BlockScopingOptions::default())
+                } else {
+                    None
+                },
+                classes: include_unfinished_plugins,
+                parameters: if include_unfinished_plugins {
+                    Some(ParametersOptions::default())
+                } else {
+                    None
+                },
+                destructuring: if include_unfinished_plugins {
+                    Some(DestructuringOptions::default())
+                } else {
+                    None
+                },
+                spread: if include_unfinished_plugins {
+                    Some(SpreadOptions::default())
+                } else {
+                    None
+                },
+                template_literals: if include_unfinished_plugins {
+                    Some(TemplateLiteralsOptions::default())
+                } else {
+                    None
+                },
+                shorthand_properties: include_unfinished_plugins,
+                computed_properties: if include_unfinished_plugins {
+                    Some(ComputedPropertiesOptions::default())
+                } else {
+                    None
+                },
+                for_of: if include_unfinished_plugins {
+                    Some(ForOfOptions::default())
+                } else {
+                    None
+                },
+                function_name: include_unfinished_plugins,
+                duplicate_keys: include_unfinished_plugins,
+                new_target: include_unfinished_plugins,
+                regenerator: if include_unfinished_plugins {
+                    Some(RegeneratorOptions::default())
+                } else {
+                    None
+                },
             },
             regexp: RegExpOptions {
                 sticky_flag: true,
@@ -183,6 +232,19 @@ impl From<EngineTargets> for EnvOptions {
             },
             es2015: ES2015Options {
                 arrow_function: o.has_feature(ES2015ArrowFunctions).then(Default::default),
+                block_scoping: o.has_feature(ES2015BlockScoping).then(Default::default),
+                classes: o.has_feature(ES2015Classes),
+                parameters: o.has_feature(ES2015Parameters).then(Default::default),
+                destructuring: o.has_feature(ES2015Destructuring).then(Default::default),
+                spread: o.has_feature(ES2015Spread).then(Default::default),
+                template_literals: o.has_feature(ES2015TemplateLiterals).then(Default::default),
+                shorthand_properties: o.has_feature(ES2015ShorthandProperties),
+                computed_properties: o.has_feature(ES2015ComputedProperties).then(Default::default),
+                for_of: o.has_feature(ES2015ForOf).then(Default::default),
+                function_name: o.has_feature(ES2015FunctionName),
+                duplicate_keys: o.has_feature(ES2015DuplicateKeys),
+                new_target: o.has_feature(ES2015NewTarget),
+                regenerator: o.has_feature(ES2015Regenerator).then(Default::default),
             },
             regexp: RegExpOptions {
                 sticky_flag: o.has_feature(ES2015StickyRegex),
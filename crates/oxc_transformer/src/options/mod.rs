@@ -38,7 +38,7 @@ pub use oxc_syntax::es_target::ESTarget;
 ///
 /// Transform options are listed in evaluation order: `react_compiler` runs in its own
 /// pass before the main traversal, which then applies `typescript`, `decorator`, `plugins`,
-/// `jsx`, and `env` (newest edition to oldest, then RegExp) in order.
+/// `jsx`, `env` (newest edition to oldest, then RegExp) and `proposals` in order.
 #[derive(Debug, Default, Clone)]
 pub struct TransformOptions {
     //
@@ -85,7 +85,7 @@ pub struct TransformOptions {
 
     /// TC39 Proposals
     ///
-    /// Currently none are implemented.
+    /// Only the Hack-style pipeline operator is implemented.
     pub proposals: ProposalOptions,
 
     /// Helper loading configuration for generated runtime helpers.
@@ -121,7 +121,7 @@ impl TransformOptions {
                 ..JsxOptions::default()
             },
             env: EnvOptions::enable_all(/* include_unfinished_plugins */ false),
-            proposals: ProposalOptions::default(),
+            proposals: ProposalOptions { pipeline_operator: true },
             helper_loader: HelperLoaderOptions {
                 mode: HelperLoaderMode::Runtime,
                 ..Default::default()
@@ -239,6 +239,23 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let es2015 = ES2015Options {
             arrow_function: options.plugins.arrow_function.or(env.es2015.arrow_function),
+            block_scoping: options.plugins.block_scoping.or(env.es2015.block_scoping),
+            classes: options.plugins.classes || env.es2015.classes,
+            parameters: options.plugins.parameters.or(env.es2015.parameters),
+            destructuring: options.plugins.destructuring.or(env.es2015.destructuring),
+            spread: options.plugins.spread.or(env.es2015.spread),
+            template_literals: options.plugins.template_literals.or(env.es2015.template_literals),
+            shorthand_properties: options.plugins.shorthand_properties
+                || env.es2015.shorthand_properties,
+            computed_properties: options
+                .plugins
+                .computed_properties
+                .or(env.es2015.computed_properties),
+            for_of: options.plugins.for_of.or(env.es2015.for_of),
+            function_name: options.plugins.function_name || env.es2015.function_name,
+            duplicate_keys: options.plugins.duplicate_keys || env.es2015.duplicate_keys,
+            new_target: options.plugins.new_target || env.es2015.new_target,
+            regenerator: options.plugins.regenerator.or(env.es2015.regenerator),
         };
 
         let es2016 = ES2016Options {
@@ -328,7 +345,7 @@ impl TryFrom<&BabelOptions> for TransformOptions {
                 es2015,
                 regexp,
             },
-            proposals: ProposalOptions::default(),
+            proposals: ProposalOptions { pipeline_operator: options.plugins.pipeline_operator },
             helper_loader,
         })
     }
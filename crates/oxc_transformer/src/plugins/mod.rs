@@ -6,13 +6,10 @@ pub use options::PluginsOptions;
 use oxc_ast::ast::*;
 use oxc_traverse::Traverse;
 pub use styled_components::StyledComponentsOptions;
+pub use tagged_template_transform::TaggedTemplateTransform;
 
 use crate::{
-    context::TraverseCtx,
-    plugins::{
-        styled_components::StyledComponents, tagged_template_transform::TaggedTemplateTransform,
-    },
-    state::TransformState,
+    context::TraverseCtx, plugins::styled_components::StyledComponents, state::TransformState,
 };
 
 pub struct Plugins<'a> {
@@ -63,7 +63,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TaggedTemplateTransform {
     #[inline]
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         if matches!(expr, Expression::TaggedTemplateExpression(_)) {
-            self.transform_tagged_template(expr, ctx);
+            Self::transform_tagged_template(expr, ctx);
         }
     }
 }
@@ -75,7 +75,7 @@ impl<'a> TaggedTemplateTransform {
 
     /// Transform a tagged template expression to use the [`Helper::TaggedTemplateLiteral`] helper function.
     #[cold] // Tagged template expressions are rare
-    fn transform_tagged_template(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+    fn transform_tagged_template(expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         debug_assert!(matches!(expr, Expression::TaggedTemplateExpression(_)));
 
         if !matches!(expr, Expression::TaggedTemplateExpression(tagged) if Self::contains_closing_script_tag(&tagged.quasi))
@@ -90,8 +90,13 @@ impl<'a> TaggedTemplateTransform {
         let TaggedTemplateExpression { span, tag, quasi: template_lit, type_arguments, .. } =
             tagged.unbox();
 
-        let binding = self.create_top_level_binding(ctx);
-        let arguments = self.transform_template_literal(&binding, template_lit, ctx);
+        let binding = Self::create_top_level_binding(ctx);
+        let arguments = Self::transform_template_literal(
+            &binding,
+            template_lit,
+            Helper::TaggedTemplateLiteral,
+            ctx,
+        );
         *expr = Expression::new_call_expression(span, tag, type_arguments, arguments, false, ctx);
     }
 
@@ -132,7 +137,7 @@ impl<'a> TaggedTemplateTransform {
     ///   quasis:
     ///     - Create an array expression containing the cooked string literals
     ///     - If cooked differs from raw, create a second array with raw strings
-    ///     - Call the helper function with the array expression(s)
+    ///     - Call `helper` with the array expression(s)
     ///     - Create a logical OR expression to cache the result in the binding
     ///     - Wrap the logical OR expression as the first argument
     ///   expressions:
@@ -141,11 +146,13 @@ impl<'a> TaggedTemplateTransform {
     /// Final arguments:
     /// - `(binding || (binding = babelHelpers.taggedTemplateLiteral([<...cooked>])), <...expressions>)` when cooked == raw
     /// - `(binding || (binding = babelHelpers.taggedTemplateLiteral([<...cooked>], [<...raw>])), <...expressions>)` when cooked != raw
-    #[expect(clippy::unused_self)]
-    fn transform_template_literal(
-        &self,
+    ///
+    /// Also used by the ES2015 template literals transform, which passes
+    /// [`Helper::TaggedTemplateLiteralLoose`] under the `mutableTemplateObject` assumption.
+    pub(crate) fn transform_template_literal(
         binding: &BoundIdentifier<'a>,
         template_lit: TemplateLiteral<'a>,
+        helper: Helper,
         ctx: &mut TraverseCtx<'a>,
     ) -> ArenaVec<'a, Argument<'a>> {
         // Create cooked array: `[cooked0, cooked1, ...]`.
@@ -186,8 +193,7 @@ impl<'a> TaggedTemplateTransform {
         };
 
         // `babelHelpers.taggedTemplateLiteral([<...cooked>], [<...raw>]?)`
-        let template_call =
-            helper_call_expr(Helper::TaggedTemplateLiteral, template_arguments, ctx);
+        let template_call = helper_call_expr(helper, template_arguments, ctx);
         // `binding || (binding = babelHelpers.taggedTemplateLiteral([<...cooked>], [<...raw>]?))`
         let template_call =
             Argument::from(Self::create_logical_or_expression(binding, template_call, ctx));
@@ -219,8 +225,7 @@ impl<'a> TaggedTemplateTransform {
     }
 
     /// Creates a `var binding;` variable declaration at the top level and returns the binding
-    #[expect(clippy::unused_self)]
-    fn create_top_level_binding(&self, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+    pub(crate) fn create_top_level_binding(ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
         let binding = ctx.generate_uid(
             "templateObject",
             ctx.scoping().root_scope_id(),
@@ -1,3 +1,31 @@
+use oxc_ast::ast::*;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
 mod options;
+mod pipeline_operator;
 
 pub use options::ProposalOptions;
+use pipeline_operator::PipelineOperator;
+
+pub struct Proposals {
+    options: ProposalOptions,
+
+    // Plugins
+    pipeline_operator: PipelineOperator,
+}
+
+impl Proposals {
+    pub fn new(options: ProposalOptions) -> Self {
+        Self { options, pipeline_operator: PipelineOperator::new() }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Proposals {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.pipeline_operator {
+            self.pipeline_operator.enter_expression(expr, ctx);
+        }
+    }
+}
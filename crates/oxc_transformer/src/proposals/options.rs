@@ -1,6 +1,8 @@
-#[expect(clippy::empty_structs_with_brackets)]
 #[derive(Debug, Clone, Copy, Default)]
 /// Options for proposal transforms.
 pub struct ProposalOptions {
-    // Currently no proposals are configured here.
+    /// Enable the Hack-style pipeline operator (`a |> f(%)`) transform.
+    ///
+    /// The source must be parsed with `ParseOptions::allow_pipeline_operator`.
+    pub pipeline_operator: bool,
 }
@@ -0,0 +1,98 @@
+//! Proposal: Pipeline Operator (Hack-style)
+//!
+//! This plugin transforms the pipeline operator (`|>`) into a sequence expression which assigns
+//! the left-hand side to a temporary variable, and replaces topic references (`%`) in the body
+//! with that variable.
+//!
+//! Pipeline expressions are only parsed with `ParseOptions::allow_pipeline_operator` enabled.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! value |> double(%) |> % + 1;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _ref, _ref2;
+//! _ref = value, (_ref2 = double(_ref), _ref2 + 1);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-pipeline-operator](https://babeljs.io/docs/babel-plugin-proposal-pipeline-operator)
+//! with `{ proposal: "hack", topicToken: "%" }`.
+//!
+//! The parser nests a chain of pipes to the right (`a |> (f(%) |> g(%))`). The head of a nested
+//! pipe belongs to the body of the enclosing pipe, so its topic references are replaced with the
+//! enclosing pipe's variable, and the nested pipe is then transformed when it is entered.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-proposal-pipeline-operator>
+//! * Pipeline operator TC39 proposal: <https://github.com/tc39/proposal-pipeline-operator>
+
+use oxc_allocator::{ArenaVec, TakeIn};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_span::SPAN;
+use oxc_syntax::operator::AssignmentOperator;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+pub struct PipelineOperator;
+
+impl PipelineOperator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for PipelineOperator {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !matches!(expr, Expression::PipelineExpression(_)) {
+            return;
+        }
+
+        let Expression::PipelineExpression(pipeline) = expr.take_in(ctx) else { unreachable!() };
+        let PipelineExpression { span, head, mut body, .. } = pipeline.unbox();
+
+        // `var _ref;`
+        let binding = ctx.generate_uid_in_current_hoist_scope("ref");
+        ctx.state.var_declarations.insert_var(&binding, &ctx.ast);
+
+        // `f(%)` -> `f(_ref)`
+        TopicReplacer { binding: &binding, ctx }.visit_expression(&mut body);
+
+        // `_ref = a, f(_ref)`
+        let assignment = Expression::new_assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            head,
+            ctx,
+        );
+        let expressions = ArenaVec::from_array_in([assignment, body], ctx);
+        *expr = Expression::new_sequence_expression(span, expressions, ctx);
+    }
+}
+
+/// Replaces topic references in the body of a pipe with reads of the pipe's variable.
+struct TopicReplacer<'a, 'b> {
+    binding: &'b BoundIdentifier<'a>,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for TopicReplacer<'a, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::TopicReference(topic) => {
+                *expr = self.binding.create_spanned_read_expression(topic.span, self.ctx);
+            }
+            // Topic references in the body of a nested pipe refer to that pipe's head
+            Expression::PipelineExpression(pipeline) => self.visit_expression(&mut pipeline.head),
+            _ => walk_mut::walk_expression(self, expr),
+        }
+    }
+}
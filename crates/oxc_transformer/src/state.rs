@@ -5,6 +5,7 @@ use std::{
 
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::SourceType;
+use oxc_traverse::BoundIdentifier;
 
 use crate::{
     CompilerAssumptions, HelperLoaderOptions, Module, TransformOptions,
@@ -47,6 +48,11 @@ pub struct TransformState<'a> {
     // State for multiple plugins interacting
     /// `true` if class properties plugin is enabled
     pub is_class_properties_plugin_enabled: bool,
+    /// Binding of the `regeneratorRuntime` function inserted by the regenerator plugin,
+    /// when its `inlineRuntime` option is enabled. `None` if it's not used.
+    ///
+    /// The inserted runtime is parsed from source, so the scoping is rebuilt once this is set.
+    pub regenerator_runtime: Option<BoundIdentifier<'a>>,
 }
 
 impl Default for TransformState<'_> {
@@ -65,6 +71,7 @@ impl Default for TransformState<'_> {
             statement_injector: StatementInjectorStore::new(),
             top_level_statements: TopLevelStatementsStore::new(),
             is_class_properties_plugin_enabled: false,
+            regenerator_runtime: None,
         }
     }
 }
@@ -89,6 +96,7 @@ impl TransformState<'_> {
             statement_injector: StatementInjectorStore::new(),
             top_level_statements: TopLevelStatementsStore::new(),
             is_class_properties_plugin_enabled: options.env.es2022.class_properties.is_some(),
+            regenerator_runtime: None,
         }
     }
 
@@ -29,7 +29,7 @@ pub struct TypeScriptAnnotations<'a> {
 }
 
 impl TypeScriptAnnotations<'_> {
-    pub fn new(options: &TypeScriptOptions) -> Self {
+    pub fn new(options: &TypeScriptOptions, only_remove_type_imports: bool) -> Self {
         let jsx_element_import_name = if options.jsx_pragma.contains('.') {
             options.jsx_pragma.split('.').next().map(String::from).unwrap()
         } else {
@@ -43,7 +43,7 @@ impl TypeScriptAnnotations<'_> {
         };
 
         Self {
-            only_remove_type_imports: options.only_remove_type_imports,
+            only_remove_type_imports,
             has_super_call: false,
             assignments: vec![],
             has_jsx_element: false,
@@ -232,7 +232,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptAnnotations<'a> {
                 matches!(prop.r#type, AccessorPropertyType::AccessorProperty)
             }
             ClassElement::TSIndexSignature(_) => false,
-            ClassElement::StaticBlock(_) => true,
+            ClassElement::StaticBlock(_) | ClassElement::ErrorNode(_) => true,
         });
     }
 
@@ -247,7 +247,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptAnnotations<'a> {
     }
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        if expr.is_typescript_syntax() {
+        if expr.is_typescript_syntax() || matches!(expr, Expression::FlowTypeCastExpression(_)) {
             let inner_expr = expr.get_inner_expression_mut();
             *expr = inner_expr.take_in(ctx);
         }
@@ -501,10 +501,11 @@ impl<'a> TypeScriptAnnotations<'a> {
     #[inline]
     fn should_keep_declaration(&self, decl: &Declaration<'a>, ctx: &mut TraverseCtx<'a>) -> bool {
         match decl {
-            // Remove type aliases, interfaces, and `declare global {}`
+            // Remove type aliases, interfaces, `declare global {}`, and Flow opaque types
             Declaration::TSTypeAliasDeclaration(_)
             | Declaration::TSInterfaceDeclaration(_)
-            | Declaration::TSGlobalDeclaration(_) => false,
+            | Declaration::TSGlobalDeclaration(_)
+            | Declaration::FlowOpaqueTypeDeclaration(_) => false,
             // Remove `declare var/let/const`
             Declaration::VariableDeclaration(var_decl) => !var_decl.declare,
             // Remove `declare function` and function overload signatures (no body)
@@ -37,6 +37,10 @@ use rewrite_extensions::TypeScriptRewriteExtensions;
 ///
 /// This plugin is included in `preset-typescript`.
 ///
+/// Flow files are parsed into the same TypeScript AST nodes, so this preset also strips Flow types,
+/// like [transform-flow-strip-types](https://babeljs.io/docs/babel-plugin-transform-flow-strip-types).
+/// For Flow, only type imports are removed, regardless of `only_remove_type_imports`.
+///
 /// ## Example
 ///
 /// In:  `const x: number = 0;`
@@ -56,11 +60,14 @@ pub struct TypeScript<'a> {
 
 impl<'a> TypeScript<'a> {
     pub fn new(options: &TypeScriptOptions, state: &TransformState<'a>) -> Self {
+        // Unlike TypeScript, Flow never elides unused value imports
+        let only_remove_type_imports =
+            options.only_remove_type_imports || state.source_type.is_flow();
         Self {
-            annotations: TypeScriptAnnotations::new(options),
+            annotations: TypeScriptAnnotations::new(options, only_remove_type_imports),
             r#enum: TypeScriptEnum::new(options.optimize_const_enums, options.optimize_enums),
             namespace: TypeScriptNamespace::new(options),
-            module: TypeScriptModule::new(options.only_remove_type_imports, state.module),
+            module: TypeScriptModule::new(only_remove_type_imports, state.module),
             rewrite_extensions: TypeScriptRewriteExtensions::new(options),
             source_type_is_typescript_definition: state.source_type.is_typescript_definition(),
             is_class_properties_plugin_enabled: state.is_class_properties_plugin_enabled,
@@ -273,7 +273,8 @@ impl<'a> TypeScriptNamespace {
                             }
                             Declaration::TSTypeAliasDeclaration(_)
                             | Declaration::TSInterfaceDeclaration(_)
-                            | Declaration::TSGlobalDeclaration(_) => {}
+                            | Declaration::TSGlobalDeclaration(_)
+                            | Declaration::FlowOpaqueTypeDeclaration(_) => {}
                         }
                     }
                 }
@@ -1,6 +1,6 @@
 pub mod ast_builder;
 
-use oxc_ast::ast::Function;
+use oxc_ast::ast::{Function, VariableDeclarationKind};
 use oxc_syntax::symbol::SymbolFlags;
 
 use crate::context::TraverseCtx;
@@ -23,3 +23,16 @@ pub fn sync_function_symbol_flags<'a>(func: &Function<'a>, ctx: &mut TraverseCtx
     flags.set(SymbolFlags::AsyncOrGeneratorFunction, func.r#async || func.generator);
     flags.set(SymbolFlags::FunctionExpression, func.is_expression());
 }
+
+/// Get the `SymbolFlags` of a binding declared by a variable declaration of `kind`.
+pub fn kind_to_symbol_flags(kind: VariableDeclarationKind) -> SymbolFlags {
+    match kind {
+        VariableDeclarationKind::Var => SymbolFlags::FunctionScopedVariable,
+        VariableDeclarationKind::Let
+        | VariableDeclarationKind::Using
+        | VariableDeclarationKind::AwaitUsing => SymbolFlags::BlockScopedVariable,
+        VariableDeclarationKind::Const => {
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
+        }
+    }
+}
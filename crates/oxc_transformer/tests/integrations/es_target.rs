@@ -10,6 +10,7 @@ fn es_target() {
     use std::fmt::Write;
 
     let cases = [
+        ("es5", "const f = () => {}"),
         ("es6", "a ** b"),
         ("es2015", "a ** b"),
         ("es2016", "async function foo() {}"),
@@ -69,6 +70,17 @@ fn target_list_pass() {
     assert!(result.env.es2022.class_static_block);
 }
 
+#[test]
+fn target_es5_pass() {
+    let result = TransformOptions::from_target("es5").unwrap();
+    assert!(result.env.es2015.arrow_function.is_some());
+    assert!(result.env.es2015.block_scoping.is_some());
+    assert!(result.env.es2015.classes);
+    assert!(result.env.es2015.destructuring.is_some());
+    assert!(result.env.es2015.regenerator.is_some());
+    assert!(result.env.es2016.exponentiation_operator);
+}
+
 #[test]
 fn target_list_fail() {
     let targets = [
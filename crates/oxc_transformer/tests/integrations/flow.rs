@@ -0,0 +1,57 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{TransformOptions, Transformer};
+
+use crate::codegen;
+
+fn transform(source_text: &str) -> String {
+    let source_type = SourceType::jsx().with_flow(true);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.diagnostics.is_empty(), "{:?}", ret.diagnostics);
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, Path::new(""), &TransformOptions::default())
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.diagnostics.is_empty(), "{:?}", ret.diagnostics);
+    Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+#[test]
+fn strip_types() {
+    let source = "
+        import type { A } from 'a';
+        import typeof B from 'b';
+        import { type C, d } from 'c';
+        import Unused from 'unused';
+        type T<+U: A = {}> = {| +x: ?U |};
+        opaque type ID: string = string;
+        export opaque type Token = string;
+        declare opaque type Secret: string;
+        declare function f(x: number): string;
+        declare var v: number;
+        interface I { m(): void }
+        class K<T> implements I { +p: T = (d: any); m(): void {} }
+        function g(x: ?number, ...rest: Array<C>): number { return ((x: any): number); }
+        type F = { [string]: (string, ...Array<number>) => void };
+        declare export function h(string): void;
+        declare export default class D {}
+        const k = (x): string => x;
+    ";
+    let expected = "
+        import { d } from 'c';
+        import Unused from 'unused';
+        class K { p = d; m() {} }
+        function g(x, ...rest) { return x; }
+        const k = (x) => x;
+    ";
+    assert_eq!(transform(source), codegen(expected, SourceType::mjs()));
+}
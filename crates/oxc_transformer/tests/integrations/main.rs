@@ -1,6 +1,8 @@
 mod enum_eval;
 mod es_target;
+mod flow;
 mod helper_call;
+mod pipeline_operator;
 #[cfg(feature = "react_compiler")]
 mod react_compiler;
 mod targets;
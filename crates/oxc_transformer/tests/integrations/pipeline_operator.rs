@@ -0,0 +1,52 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{ProposalOptions, TransformOptions, Transformer};
+
+use crate::codegen;
+
+fn transform(source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let options = ParseOptions { allow_pipeline_operator: true, ..ParseOptions::default() };
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).with_options(options).parse();
+    assert!(ret.diagnostics.is_empty(), "{:?}", ret.diagnostics);
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let options = TransformOptions {
+        proposals: ProposalOptions { pipeline_operator: true },
+        ..TransformOptions::default()
+    };
+    let ret = Transformer::new(&allocator, Path::new(""), &options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.diagnostics.is_empty(), "{:?}", ret.diagnostics);
+    Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+#[test]
+fn lower() {
+    let cases = [
+        ("x = a |> f(%, %);", "var _ref; x = (_ref = a, f(_ref, _ref));"),
+        (
+            "x = a |> f(%) |> % + 1;",
+            "var _ref, _ref2; x = (_ref = a, (_ref2 = f(_ref), _ref2 + 1));",
+        ),
+        (
+            "x = (a |> f(%)) |> [%, (b |> % * 2)];",
+            "var _ref, _ref2, _ref3; x = (_ref = (_ref2 = a, f(_ref2)), [_ref, (_ref3 = b, _ref3 * 2)]);",
+        ),
+        (
+            "function g() { return a |> (() => %); }",
+            "function g() { var _ref; return _ref = a, (() => _ref); }",
+        ),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(transform(source), codegen(expected, SourceType::mjs()), "{source}");
+    }
+}
@@ -35,3 +35,27 @@ fn targets() {
         assert_eq!(test(case, &options), test(case, &options_node));
     }
 }
+
+#[test]
+fn ie_targets() {
+    let cases = [
+        ("let a = 1"),
+        ("() => {}"),
+        ("class A {}"),
+        ("const { a, ...b } = c"),
+        ("function* foo() { yield 1 }"),
+        ("async function foo() {}"),
+        ("`a${b}`"),
+    ];
+
+    // Internet Explorer supports none of the features after ES5.
+    let options = TransformOptions::from(ESTarget::ES5);
+    let options_ie = TransformOptions {
+        env: EnvOptions::from_browserslist_query("ie 11").unwrap(),
+        ..TransformOptions::default()
+    };
+    for case in cases {
+        assert_ne!(Ok(codegen(case, SourceType::mjs())), test(case, &options_ie));
+        assert_eq!(test(case, &options), test(case, &options_ie));
+    }
+}
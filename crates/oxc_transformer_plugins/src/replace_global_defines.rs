@@ -990,7 +990,7 @@ impl<'a> ReplaceGlobalDefines<'a> {
                     }
                 }
                 // not static key
-                ObjectPropertyKind::SpreadProperty(_) => true,
+                ObjectPropertyKind::SpreadProperty(_) | ObjectPropertyKind::ErrorNode(_) => true,
             };
             should_preserved_keys.push(v);
         }
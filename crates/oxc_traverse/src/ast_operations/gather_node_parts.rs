@@ -430,6 +430,7 @@ impl<'a> GatherNodeParts<'a> for ObjectPropertyKind<'a> {
         match self {
             ObjectPropertyKind::ObjectProperty(prop) => prop.gather(f),
             ObjectPropertyKind::SpreadProperty(prop) => prop.gather(f),
+            ObjectPropertyKind::ErrorNode(_) => {}
         }
     }
 }
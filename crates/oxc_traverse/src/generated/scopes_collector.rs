@@ -74,6 +74,8 @@ impl<'a> Visit<'a> for ChildScopeCollector {
             Expression::TSNonNullExpression(it) => self.visit_ts_non_null_expression(it),
             Expression::TSInstantiationExpression(it) => self.visit_ts_instantiation_expression(it),
             Expression::V8IntrinsicExpression(it) => self.visit_v8_intrinsic_expression(it),
+            Expression::FlowTypeCastExpression(it) => self.visit_flow_type_cast_expression(it),
+            Expression::PipelineExpression(it) => self.visit_pipeline_expression(it),
             Expression::ComputedMemberExpression(it) => self.visit_computed_member_expression(it),
             Expression::StaticMemberExpression(it) => self.visit_static_member_expression(it),
             Expression::PrivateFieldExpression(it) => self.visit_private_field_expression(it),
@@ -89,6 +91,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -177,6 +180,10 @@ impl<'a> Visit<'a> for ChildScopeCollector {
             ArrayExpressionElement::V8IntrinsicExpression(it) => {
                 self.visit_v8_intrinsic_expression(it)
             }
+            ArrayExpressionElement::FlowTypeCastExpression(it) => {
+                self.visit_flow_type_cast_expression(it)
+            }
+            ArrayExpressionElement::PipelineExpression(it) => self.visit_pipeline_expression(it),
             ArrayExpressionElement::ComputedMemberExpression(it) => {
                 self.visit_computed_member_expression(it)
             }
@@ -199,6 +206,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -213,6 +221,18 @@ impl<'a> Visit<'a> for ChildScopeCollector {
         self.visit_object_property_kinds(&it.properties);
     }
 
+    #[inline]
+    fn visit_object_property_kind(&mut self, it: &ObjectPropertyKind<'a>) {
+        match it {
+            ObjectPropertyKind::ObjectProperty(it) => self.visit_object_property(it),
+            ObjectPropertyKind::SpreadProperty(it) => self.visit_spread_element(it),
+            _ => {
+                // Remaining variants do not contain scopes:
+                // `ErrorNode`
+            }
+        }
+    }
+
     #[inline]
     fn visit_object_property(&mut self, it: &ObjectProperty<'a>) {
         self.visit_property_key(&it.key);
@@ -256,6 +276,8 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 self.visit_ts_instantiation_expression(it)
             }
             PropertyKey::V8IntrinsicExpression(it) => self.visit_v8_intrinsic_expression(it),
+            PropertyKey::FlowTypeCastExpression(it) => self.visit_flow_type_cast_expression(it),
+            PropertyKey::PipelineExpression(it) => self.visit_pipeline_expression(it),
             PropertyKey::ComputedMemberExpression(it) => self.visit_computed_member_expression(it),
             PropertyKey::StaticMemberExpression(it) => self.visit_static_member_expression(it),
             PropertyKey::PrivateFieldExpression(it) => self.visit_private_field_expression(it),
@@ -273,6 +295,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -376,6 +399,8 @@ impl<'a> Visit<'a> for ChildScopeCollector {
             Argument::TSNonNullExpression(it) => self.visit_ts_non_null_expression(it),
             Argument::TSInstantiationExpression(it) => self.visit_ts_instantiation_expression(it),
             Argument::V8IntrinsicExpression(it) => self.visit_v8_intrinsic_expression(it),
+            Argument::FlowTypeCastExpression(it) => self.visit_flow_type_cast_expression(it),
+            Argument::PipelineExpression(it) => self.visit_pipeline_expression(it),
             Argument::ComputedMemberExpression(it) => self.visit_computed_member_expression(it),
             Argument::StaticMemberExpression(it) => self.visit_static_member_expression(it),
             Argument::PrivateFieldExpression(it) => self.visit_private_field_expression(it),
@@ -391,6 +416,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -616,6 +642,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
             Statement::TSEnumDeclaration(it) => self.visit_ts_enum_declaration(it),
             Statement::TSModuleDeclaration(it) => self.visit_ts_module_declaration(it),
             Statement::TSGlobalDeclaration(it) => self.visit_ts_global_declaration(it),
+            Statement::FlowOpaqueTypeDeclaration(it) => self.visit_flow_opaque_type_declaration(it),
             Statement::ExportDefaultDeclaration(it) => self.visit_export_default_declaration(it),
             Statement::ExportNamedDeclaration(it) => self.visit_export_named_declaration(it),
             Statement::TSExportAssignment(it) => self.visit_ts_export_assignment(it),
@@ -625,6 +652,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `ContinueStatement`
                 // `DebuggerStatement`
                 // `EmptyStatement`
+                // `ErrorNode`
                 // `TSImportEqualsDeclaration`
                 // `ImportDeclaration`
                 // `ExportAllDeclaration`
@@ -661,6 +689,9 @@ impl<'a> Visit<'a> for ChildScopeCollector {
             Declaration::TSEnumDeclaration(it) => self.visit_ts_enum_declaration(it),
             Declaration::TSModuleDeclaration(it) => self.visit_ts_module_declaration(it),
             Declaration::TSGlobalDeclaration(it) => self.visit_ts_global_declaration(it),
+            Declaration::FlowOpaqueTypeDeclaration(it) => {
+                self.visit_flow_opaque_type_declaration(it)
+            }
             _ => {
                 // Remaining variants do not contain scopes:
                 // `TSImportEqualsDeclaration`
@@ -764,6 +795,10 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 self.visit_ts_instantiation_expression(it)
             }
             ForStatementInit::V8IntrinsicExpression(it) => self.visit_v8_intrinsic_expression(it),
+            ForStatementInit::FlowTypeCastExpression(it) => {
+                self.visit_flow_type_cast_expression(it)
+            }
+            ForStatementInit::PipelineExpression(it) => self.visit_pipeline_expression(it),
             ForStatementInit::ComputedMemberExpression(it) => {
                 self.visit_computed_member_expression(it)
             }
@@ -781,6 +816,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -1002,6 +1038,21 @@ impl<'a> Visit<'a> for ChildScopeCollector {
         self.visit_class_elements(&it.body);
     }
 
+    #[inline]
+    fn visit_class_element(&mut self, it: &ClassElement<'a>) {
+        match it {
+            ClassElement::StaticBlock(it) => self.visit_static_block(it),
+            ClassElement::MethodDefinition(it) => self.visit_method_definition(it),
+            ClassElement::PropertyDefinition(it) => self.visit_property_definition(it),
+            ClassElement::AccessorProperty(it) => self.visit_accessor_property(it),
+            ClassElement::TSIndexSignature(it) => self.visit_ts_index_signature(it),
+            _ => {
+                // Remaining variants do not contain scopes:
+                // `ErrorNode`
+            }
+        }
+    }
+
     #[inline]
     fn visit_method_definition(&mut self, it: &MethodDefinition<'a>) {
         self.visit_decorators(&it.decorators);
@@ -1207,6 +1258,12 @@ impl<'a> Visit<'a> for ChildScopeCollector {
             ExportDefaultDeclarationKind::V8IntrinsicExpression(it) => {
                 self.visit_v8_intrinsic_expression(it)
             }
+            ExportDefaultDeclarationKind::FlowTypeCastExpression(it) => {
+                self.visit_flow_type_cast_expression(it)
+            }
+            ExportDefaultDeclarationKind::PipelineExpression(it) => {
+                self.visit_pipeline_expression(it)
+            }
             ExportDefaultDeclarationKind::ComputedMemberExpression(it) => {
                 self.visit_computed_member_expression(it)
             }
@@ -1228,6 +1285,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -1242,6 +1300,22 @@ impl<'a> Visit<'a> for ChildScopeCollector {
         self.visit_arguments(&it.arguments);
     }
 
+    #[inline]
+    fn visit_pipeline_expression(&mut self, it: &PipelineExpression<'a>) {
+        self.visit_expression(&it.head);
+        self.visit_expression(&it.body);
+    }
+
+    #[inline(always)]
+    fn visit_topic_reference(&mut self, it: &TopicReference) {
+        // Struct does not contain a scope. Halt traversal.
+    }
+
+    #[inline(always)]
+    fn visit_error_node(&mut self, it: &ErrorNode) {
+        // Struct does not contain a scope. Halt traversal.
+    }
+
     #[inline(always)]
     fn visit_boolean_literal(&mut self, it: &BooleanLiteral) {
         // Struct does not contain a scope. Halt traversal.
@@ -1370,6 +1444,8 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 self.visit_ts_instantiation_expression(it)
             }
             JSXExpression::V8IntrinsicExpression(it) => self.visit_v8_intrinsic_expression(it),
+            JSXExpression::FlowTypeCastExpression(it) => self.visit_flow_type_cast_expression(it),
+            JSXExpression::PipelineExpression(it) => self.visit_pipeline_expression(it),
             JSXExpression::ComputedMemberExpression(it) => {
                 self.visit_computed_member_expression(it)
             }
@@ -1388,6 +1464,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -2020,6 +2097,17 @@ impl<'a> Visit<'a> for ChildScopeCollector {
         // Struct does not contain a scope. Halt traversal.
     }
 
+    #[inline]
+    fn visit_flow_type_cast_expression(&mut self, it: &FlowTypeCastExpression<'a>) {
+        self.visit_expression(&it.expression);
+        self.visit_ts_type_annotation(&it.type_annotation);
+    }
+
+    #[inline]
+    fn visit_flow_opaque_type_declaration(&mut self, it: &FlowOpaqueTypeDeclaration<'a>) {
+        self.add_scope(&it.scope_id);
+    }
+
     #[inline(always)]
     fn visit_span(&mut self, it: &Span) {
         // Struct does not contain a scope. Halt traversal.
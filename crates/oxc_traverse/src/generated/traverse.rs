@@ -1587,6 +1587,41 @@ pub trait Traverse<'a, State> {
     ) {
     }
 
+    #[inline]
+    fn enter_pipeline_expression(
+        &mut self,
+        node: &mut PipelineExpression<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+    #[inline]
+    fn exit_pipeline_expression(
+        &mut self,
+        node: &mut PipelineExpression<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+
+    #[inline]
+    fn enter_topic_reference(
+        &mut self,
+        node: &mut TopicReference,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+    #[inline]
+    fn exit_topic_reference(
+        &mut self,
+        node: &mut TopicReference,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+
+    #[inline]
+    fn enter_error_node(&mut self, node: &mut ErrorNode, ctx: &mut TraverseCtx<'a, State>) {}
+    #[inline]
+    fn exit_error_node(&mut self, node: &mut ErrorNode, ctx: &mut TraverseCtx<'a, State>) {}
+
     #[inline]
     fn enter_boolean_literal(
         &mut self,
@@ -3116,6 +3151,36 @@ pub trait Traverse<'a, State> {
     ) {
     }
 
+    #[inline]
+    fn enter_flow_type_cast_expression(
+        &mut self,
+        node: &mut FlowTypeCastExpression<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+    #[inline]
+    fn exit_flow_type_cast_expression(
+        &mut self,
+        node: &mut FlowTypeCastExpression<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+
+    #[inline]
+    fn enter_flow_opaque_type_declaration(
+        &mut self,
+        node: &mut FlowOpaqueTypeDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+    #[inline]
+    fn exit_flow_opaque_type_declaration(
+        &mut self,
+        node: &mut FlowOpaqueTypeDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+
     #[inline]
     fn enter_statements(
         &mut self,
@@ -196,6 +196,15 @@ unsafe fn walk_expression<'a, State, Tr: Traverse<'a, State>>(
         Expression::V8IntrinsicExpression(node) => {
             walk_v8_intrinsic_expression(traverser, (&mut **node) as *mut _, ctx)
         }
+        Expression::FlowTypeCastExpression(node) => {
+            walk_flow_type_cast_expression(traverser, (&mut **node) as *mut _, ctx)
+        }
+        Expression::PipelineExpression(node) => {
+            walk_pipeline_expression(traverser, (&mut **node) as *mut _, ctx)
+        }
+        Expression::TopicReference(node) => {
+            walk_topic_reference(traverser, (&mut **node) as *mut _, ctx)
+        }
         Expression::ComputedMemberExpression(_)
         | Expression::StaticMemberExpression(_)
         | Expression::PrivateFieldExpression(_) => {
@@ -321,6 +330,9 @@ unsafe fn walk_array_expression_element<'a, State, Tr: Traverse<'a, State>>(
         | ArrayExpressionElement::TSNonNullExpression(_)
         | ArrayExpressionElement::TSInstantiationExpression(_)
         | ArrayExpressionElement::V8IntrinsicExpression(_)
+        | ArrayExpressionElement::FlowTypeCastExpression(_)
+        | ArrayExpressionElement::PipelineExpression(_)
+        | ArrayExpressionElement::TopicReference(_)
         | ArrayExpressionElement::ComputedMemberExpression(_)
         | ArrayExpressionElement::StaticMemberExpression(_)
         | ArrayExpressionElement::PrivateFieldExpression(_) => {
@@ -370,6 +382,9 @@ unsafe fn walk_object_property_kind<'a, State, Tr: Traverse<'a, State>>(
         ObjectPropertyKind::SpreadProperty(node) => {
             walk_spread_element(traverser, (&mut **node) as *mut _, ctx)
         }
+        ObjectPropertyKind::ErrorNode(node) => {
+            walk_error_node(traverser, (&mut **node) as *mut _, ctx)
+        }
     }
     traverser.exit_object_property_kind(&mut *node, ctx);
 }
@@ -451,6 +466,9 @@ unsafe fn walk_property_key<'a, State, Tr: Traverse<'a, State>>(
         | PropertyKey::TSNonNullExpression(_)
         | PropertyKey::TSInstantiationExpression(_)
         | PropertyKey::V8IntrinsicExpression(_)
+        | PropertyKey::FlowTypeCastExpression(_)
+        | PropertyKey::PipelineExpression(_)
+        | PropertyKey::TopicReference(_)
         | PropertyKey::ComputedMemberExpression(_)
         | PropertyKey::StaticMemberExpression(_)
         | PropertyKey::PrivateFieldExpression(_) => walk_expression(traverser, node as *mut _, ctx),
@@ -774,6 +792,9 @@ unsafe fn walk_argument<'a, State, Tr: Traverse<'a, State>>(
         | Argument::TSNonNullExpression(_)
         | Argument::TSInstantiationExpression(_)
         | Argument::V8IntrinsicExpression(_)
+        | Argument::FlowTypeCastExpression(_)
+        | Argument::PipelineExpression(_)
+        | Argument::TopicReference(_)
         | Argument::ComputedMemberExpression(_)
         | Argument::StaticMemberExpression(_)
         | Argument::PrivateFieldExpression(_) => walk_expression(traverser, node as *mut _, ctx),
@@ -1373,6 +1394,7 @@ unsafe fn walk_statement<'a, State, Tr: Traverse<'a, State>>(
         Statement::WithStatement(node) => {
             walk_with_statement(traverser, (&mut **node) as *mut _, ctx)
         }
+        Statement::ErrorNode(node) => walk_error_node(traverser, (&mut **node) as *mut _, ctx),
         Statement::VariableDeclaration(_)
         | Statement::FunctionDeclaration(_)
         | Statement::ClassDeclaration(_)
@@ -1381,7 +1403,8 @@ unsafe fn walk_statement<'a, State, Tr: Traverse<'a, State>>(
         | Statement::TSEnumDeclaration(_)
         | Statement::TSModuleDeclaration(_)
         | Statement::TSGlobalDeclaration(_)
-        | Statement::TSImportEqualsDeclaration(_) => {
+        | Statement::TSImportEqualsDeclaration(_)
+        | Statement::FlowOpaqueTypeDeclaration(_) => {
             walk_declaration(traverser, node as *mut _, ctx)
         }
         Statement::ImportDeclaration(_)
@@ -1483,6 +1506,9 @@ unsafe fn walk_declaration<'a, State, Tr: Traverse<'a, State>>(
         Declaration::TSImportEqualsDeclaration(node) => {
             walk_ts_import_equals_declaration(traverser, (&mut **node) as *mut _, ctx)
         }
+        Declaration::FlowOpaqueTypeDeclaration(node) => {
+            walk_flow_opaque_type_declaration(traverser, (&mut **node) as *mut _, ctx)
+        }
     }
     traverser.exit_declaration(&mut *node, ctx);
 }
@@ -1734,6 +1760,9 @@ unsafe fn walk_for_statement_init<'a, State, Tr: Traverse<'a, State>>(
         | ForStatementInit::TSNonNullExpression(_)
         | ForStatementInit::TSInstantiationExpression(_)
         | ForStatementInit::V8IntrinsicExpression(_)
+        | ForStatementInit::FlowTypeCastExpression(_)
+        | ForStatementInit::PipelineExpression(_)
+        | ForStatementInit::TopicReference(_)
         | ForStatementInit::ComputedMemberExpression(_)
         | ForStatementInit::StaticMemberExpression(_)
         | ForStatementInit::PrivateFieldExpression(_) => {
@@ -2613,6 +2642,7 @@ unsafe fn walk_class_element<'a, State, Tr: Traverse<'a, State>>(
         ClassElement::TSIndexSignature(node) => {
             walk_ts_index_signature(traverser, (&mut **node) as *mut _, ctx)
         }
+        ClassElement::ErrorNode(node) => walk_error_node(traverser, (&mut **node) as *mut _, ctx),
     }
     traverser.exit_class_element(&mut *node, ctx);
 }
@@ -3159,6 +3189,9 @@ unsafe fn walk_export_default_declaration_kind<'a, State, Tr: Traverse<'a, State
         | ExportDefaultDeclarationKind::TSNonNullExpression(_)
         | ExportDefaultDeclarationKind::TSInstantiationExpression(_)
         | ExportDefaultDeclarationKind::V8IntrinsicExpression(_)
+        | ExportDefaultDeclarationKind::FlowTypeCastExpression(_)
+        | ExportDefaultDeclarationKind::PipelineExpression(_)
+        | ExportDefaultDeclarationKind::TopicReference(_)
         | ExportDefaultDeclarationKind::ComputedMemberExpression(_)
         | ExportDefaultDeclarationKind::StaticMemberExpression(_)
         | ExportDefaultDeclarationKind::PrivateFieldExpression(_) => {
@@ -3212,6 +3245,48 @@ unsafe fn walk_v8_intrinsic_expression<'a, State, Tr: Traverse<'a, State>>(
     traverser.exit_v8_intrinsic_expression(&mut *node, ctx);
 }
 
+unsafe fn walk_pipeline_expression<'a, State, Tr: Traverse<'a, State>>(
+    traverser: &mut Tr,
+    node: *mut PipelineExpression<'a>,
+    ctx: &mut TraverseCtx<'a, State>,
+) {
+    traverser.enter_pipeline_expression(&mut *node, ctx);
+    let pop_token = ctx.push_stack(Ancestor::PipelineExpressionHead(
+        ancestor::PipelineExpressionWithoutHead(node, PhantomData),
+    ));
+    walk_expression(
+        traverser,
+        (node as *mut u8).add(ancestor::OFFSET_PIPELINE_EXPRESSION_HEAD) as *mut Expression,
+        ctx,
+    );
+    ctx.retag_stack(AncestorType::PipelineExpressionBody);
+    walk_expression(
+        traverser,
+        (node as *mut u8).add(ancestor::OFFSET_PIPELINE_EXPRESSION_BODY) as *mut Expression,
+        ctx,
+    );
+    ctx.pop_stack(pop_token);
+    traverser.exit_pipeline_expression(&mut *node, ctx);
+}
+
+unsafe fn walk_topic_reference<'a, State, Tr: Traverse<'a, State>>(
+    traverser: &mut Tr,
+    node: *mut TopicReference,
+    ctx: &mut TraverseCtx<'a, State>,
+) {
+    traverser.enter_topic_reference(&mut *node, ctx);
+    traverser.exit_topic_reference(&mut *node, ctx);
+}
+
+unsafe fn walk_error_node<'a, State, Tr: Traverse<'a, State>>(
+    traverser: &mut Tr,
+    node: *mut ErrorNode,
+    ctx: &mut TraverseCtx<'a, State>,
+) {
+    traverser.enter_error_node(&mut *node, ctx);
+    traverser.exit_error_node(&mut *node, ctx);
+}
+
 unsafe fn walk_boolean_literal<'a, State, Tr: Traverse<'a, State>>(
     traverser: &mut Tr,
     node: *mut BooleanLiteral,
@@ -3561,6 +3636,9 @@ unsafe fn walk_jsx_expression<'a, State, Tr: Traverse<'a, State>>(
         | JSXExpression::TSNonNullExpression(_)
         | JSXExpression::TSInstantiationExpression(_)
         | JSXExpression::V8IntrinsicExpression(_)
+        | JSXExpression::FlowTypeCastExpression(_)
+        | JSXExpression::PipelineExpression(_)
+        | JSXExpression::TopicReference(_)
         | JSXExpression::ComputedMemberExpression(_)
         | JSXExpression::StaticMemberExpression(_)
         | JSXExpression::PrivateFieldExpression(_) => {
@@ -5733,6 +5811,80 @@ unsafe fn walk_js_doc_unknown_type<'a, State, Tr: Traverse<'a, State>>(
     traverser.exit_js_doc_unknown_type(&mut *node, ctx);
 }
 
+unsafe fn walk_flow_type_cast_expression<'a, State, Tr: Traverse<'a, State>>(
+    traverser: &mut Tr,
+    node: *mut FlowTypeCastExpression<'a>,
+    ctx: &mut TraverseCtx<'a, State>,
+) {
+    traverser.enter_flow_type_cast_expression(&mut *node, ctx);
+    let pop_token = ctx.push_stack(Ancestor::FlowTypeCastExpressionExpression(
+        ancestor::FlowTypeCastExpressionWithoutExpression(node, PhantomData),
+    ));
+    walk_expression(
+        traverser,
+        (node as *mut u8).add(ancestor::OFFSET_FLOW_TYPE_CAST_EXPRESSION_EXPRESSION)
+            as *mut Expression,
+        ctx,
+    );
+    ctx.retag_stack(AncestorType::FlowTypeCastExpressionTypeAnnotation);
+    walk_ts_type_annotation(
+        traverser,
+        (&mut **((node as *mut u8).add(ancestor::OFFSET_FLOW_TYPE_CAST_EXPRESSION_TYPE_ANNOTATION)
+            as *mut ArenaBox<TSTypeAnnotation>)) as *mut _,
+        ctx,
+    );
+    ctx.pop_stack(pop_token);
+    traverser.exit_flow_type_cast_expression(&mut *node, ctx);
+}
+
+unsafe fn walk_flow_opaque_type_declaration<'a, State, Tr: Traverse<'a, State>>(
+    traverser: &mut Tr,
+    node: *mut FlowOpaqueTypeDeclaration<'a>,
+    ctx: &mut TraverseCtx<'a, State>,
+) {
+    traverser.enter_flow_opaque_type_declaration(&mut *node, ctx);
+    let pop_token = ctx.push_stack(Ancestor::FlowOpaqueTypeDeclarationId(
+        ancestor::FlowOpaqueTypeDeclarationWithoutId(node, PhantomData),
+    ));
+    walk_binding_identifier(
+        traverser,
+        (node as *mut u8).add(ancestor::OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_ID)
+            as *mut BindingIdentifier,
+        ctx,
+    );
+    let previous_scope_id = ctx.current_scope_id();
+    let current_scope_id = (*((node as *mut u8)
+        .add(ancestor::OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_SCOPE_ID)
+        as *mut Cell<Option<ScopeId>>))
+        .get()
+        .unwrap();
+    ctx.set_current_scope_id(current_scope_id);
+    if let Some(field) = &mut *((node as *mut u8)
+        .add(ancestor::OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_TYPE_PARAMETERS)
+        as *mut Option<ArenaBox<TSTypeParameterDeclaration>>)
+    {
+        ctx.retag_stack(AncestorType::FlowOpaqueTypeDeclarationTypeParameters);
+        walk_ts_type_parameter_declaration(traverser, (&mut **field) as *mut _, ctx);
+    }
+    if let Some(field) = &mut *((node as *mut u8)
+        .add(ancestor::OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_SUPERTYPE)
+        as *mut Option<TSType>)
+    {
+        ctx.retag_stack(AncestorType::FlowOpaqueTypeDeclarationSupertype);
+        walk_ts_type(traverser, field as *mut _, ctx);
+    }
+    if let Some(field) = &mut *((node as *mut u8)
+        .add(ancestor::OFFSET_FLOW_OPAQUE_TYPE_DECLARATION_IMPLTYPE)
+        as *mut Option<TSType>)
+    {
+        ctx.retag_stack(AncestorType::FlowOpaqueTypeDeclarationImpltype);
+        walk_ts_type(traverser, field as *mut _, ctx);
+    }
+    ctx.pop_stack(pop_token);
+    ctx.set_current_scope_id(previous_scope_id);
+    traverser.exit_flow_opaque_type_declaration(&mut *node, ctx);
+}
+
 unsafe fn walk_statements<'a, State, Tr: Traverse<'a, State>>(
     traverser: &mut Tr,
     stmts: *mut ArenaVec<'a, Statement<'a>>,
@@ -9,23 +9,30 @@
 //! Create a `test.ts` file and run:
 //!
 //! ```bash
-//! cargo run -p oxc_type_checker --example checker [filename]
+//! cargo run -p oxc_type_checker --example checker [filename] [lib_path]
 //! ```
 //!
-//! Since the checker is a scaffold that performs no checks yet, a well-formed file simply
-//! reports "No type errors found". Add checks in `crates/oxc_type_checker/src/lib.rs` and watch
-//! them show up here.
+//! `lib_path` is a directory containing TypeScript's `lib.*.d.ts` files, such as
+//! `node_modules/typescript/lib`, which declare globals like `Math`.
+//!
+//! Type errors such as `TS2322: Type 'string' is not assignable to type 'number'.` are printed,
+//! or "No type errors found" if there are none.
 
-use std::{env, path::Path, sync::Arc};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use oxc_allocator::Allocator;
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
-use oxc_type_checker::TypeChecker;
+use oxc_type_checker::{TypeChecker, TypeCheckerOptions};
 
 fn main() -> std::io::Result<()> {
     let name = env::args().nth(1).unwrap_or_else(|| "test.ts".to_string());
+    let lib_path = env::args().nth(2).map(PathBuf::from);
     let path = Path::new(&name);
     let source_text: Arc<str> = Arc::from(std::fs::read_to_string(path)?);
     let source_type = SourceType::from_path(path).unwrap_or_else(|_| SourceType::ts());
@@ -42,15 +49,17 @@ fn main() -> std::io::Result<()> {
     }
     let program = parser_ret.program;
 
-    // 2. Run semantic analysis to build the symbol table and scope tree.
-    let semantic_ret = SemanticBuilder::new().build(&program);
+    // 2. Run semantic analysis to build the symbol table, scope tree and AST nodes.
+    let semantic_ret = SemanticBuilder::new().with_build_nodes(true).with_cfg(true).build(&program);
     if !semantic_ret.diagnostics.is_empty() {
         println!("Semantic analysis reported problems:\n");
         print_diagnostics(semantic_ret.diagnostics, &source_text);
     }
 
     // 3. Type check.
-    let checker_ret = TypeChecker::new().check(&program, &semantic_ret.semantic);
+    let options = TypeCheckerOptions { lib_path, ..TypeCheckerOptions::default() };
+    let checker_ret =
+        TypeChecker::new().with_options(options).check(&program, &semantic_ret.semantic);
     if checker_ret.diagnostics.is_empty() {
         println!("No type errors found in {name}.");
     } else {
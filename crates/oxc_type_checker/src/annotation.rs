@@ -0,0 +1,453 @@
+//! Types written in type annotations.
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        PropertyKey, TSConditionalType, TSLiteral, TSMappedType, TSMappedTypeModifierOperator,
+        TSMethodSignatureKind, TSSignature, TSTupleElement, TSType, TSTypeName,
+        TSTypeOperatorOperator, TSTypeParameterDeclaration, TSTypeQuery, TSTypeQueryExprName,
+        TSTypeReference, UnaryOperator,
+    },
+};
+use oxc_str::{CompactStr, Ident};
+use oxc_syntax::symbol::SymbolId;
+
+use crate::{
+    TypeCheckerVisitor,
+    instantiation::Substitution,
+    types::{
+        ConditionalType, GenericType, MappedModifier, MappedType, NumberLiteral, ObjectType,
+        Property, Type, TypeId, TypeParameter, Types,
+    },
+};
+
+impl<'a> TypeCheckerVisitor<'a, '_> {
+    /// The type denoted by a type annotation.
+    ///
+    /// Types which cannot be represented yet, such as intersections and template literal types,
+    /// are `any`, so that they are never reported.
+    pub(crate) fn annotation_type(&mut self, ty: &TSType<'a>) -> TypeId {
+        match ty {
+            TSType::TSUnknownKeyword(_) => TypeId::UNKNOWN,
+            TSType::TSNeverKeyword(_) => TypeId::NEVER,
+            TSType::TSVoidKeyword(_) => TypeId::VOID,
+            TSType::TSUndefinedKeyword(_) => TypeId::UNDEFINED,
+            TSType::TSNullKeyword(_) => TypeId::NULL,
+            TSType::TSBooleanKeyword(_) => TypeId::BOOLEAN,
+            TSType::TSNumberKeyword(_) => TypeId::NUMBER,
+            TSType::TSStringKeyword(_) => TypeId::STRING,
+            TSType::TSBigIntKeyword(_) => TypeId::BIGINT,
+            TSType::TSSymbolKeyword(_) => TypeId::SYMBOL,
+            TSType::TSObjectKeyword(_) => TypeId::NON_PRIMITIVE,
+            TSType::TSLiteralType(literal) => self.literal_type(&literal.literal),
+            TSType::TSParenthesizedType(ty) => self.annotation_type(&ty.type_annotation),
+            TSType::TSUnionType(union) => {
+                let members =
+                    union.types.iter().map(|ty| self.annotation_type(ty)).collect::<Vec<_>>();
+                self.types.union(members)
+            }
+            TSType::TSArrayType(array) => {
+                let element = self.annotation_type(&array.element_type);
+                self.types.intern(Type::Array(element))
+            }
+            TSType::TSTypeOperatorType(operator) => match operator.operator {
+                TSTypeOperatorOperator::Readonly => self.annotation_type(&operator.type_annotation),
+                TSTypeOperatorOperator::Keyof => {
+                    let target = self.annotation_type(&operator.type_annotation);
+                    self.types.key_of(target)
+                }
+                TSTypeOperatorOperator::Unique => self.unknown_type(),
+            },
+            TSType::TSIndexedAccessType(access) => {
+                let object = self.annotation_type(&access.object_type);
+                let index = self.annotation_type(&access.index_type);
+                self.types.indexed_access(object, index)
+            }
+            TSType::TSConditionalType(conditional) => self.conditional_type(conditional),
+            TSType::TSMappedType(mapped) => self.mapped_type(mapped),
+            TSType::TSInferType(infer) => infer
+                .type_parameter
+                .name
+                .symbol_id
+                .get()
+                .map_or(TypeId::ANY, |symbol_id| self.declared_type(symbol_id)),
+            TSType::TSTypeQuery(query) => self.type_query_type(query),
+            TSType::TSTupleType(tuple) => {
+                let mut elements = Vec::with_capacity(tuple.element_types.len());
+                for element in &tuple.element_types {
+                    let element = match element {
+                        TSTupleElement::TSNamedTupleMember(member) if !member.optional => {
+                            member.element_type.as_ts_type()
+                        }
+                        TSTupleElement::TSOptionalType(_)
+                        | TSTupleElement::TSRestType(_)
+                        | TSTupleElement::TSNamedTupleMember(_) => None,
+                        _ => element.as_ts_type(),
+                    };
+                    // Optional and rest elements are not represented yet
+                    let Some(element) = element else { return self.unknown_type() };
+                    elements.push(self.annotation_type(element));
+                }
+                self.types.intern(Type::Tuple(elements.into_boxed_slice()))
+            }
+            TSType::TSTypeLiteral(literal) => self.object_type(&literal.members),
+            TSType::TSFunctionType(function) => {
+                let type_params = self.type_parameters(function.type_parameters.as_deref());
+                let return_type = self.annotation_type(&function.return_type.type_annotation);
+                self.parameters_type(type_params, &function.params, return_type)
+            }
+            TSType::TSTypeReference(reference) => self.type_reference_type(reference),
+            TSType::TSAnyKeyword(_) => TypeId::ANY,
+            _ => self.unknown_type(),
+        }
+    }
+
+    fn literal_type(&mut self, literal: &TSLiteral<'a>) -> TypeId {
+        let ty = match literal {
+            TSLiteral::BooleanLiteral(literal) => return Types::boolean_literal(literal.value),
+            TSLiteral::NumericLiteral(literal) => {
+                Type::NumberLiteral(NumberLiteral::new(literal.value))
+            }
+            TSLiteral::StringLiteral(literal) => Type::StringLiteral(literal.value.into()),
+            TSLiteral::BigIntLiteral(literal) => Type::BigIntLiteral(
+                literal.raw.as_ref().map_or("", |raw| raw.trim_end_matches('n')).into(),
+            ),
+            TSLiteral::TemplateLiteral(literal) => match literal.single_quasi() {
+                Some(value) => Type::StringLiteral(value.into()),
+                None => return TypeId::STRING,
+            },
+            TSLiteral::UnaryExpression(unary) => {
+                match (unary.operator, unary.argument.get_inner_expression()) {
+                    (
+                        UnaryOperator::UnaryNegation,
+                        oxc_ast::ast::Expression::NumericLiteral(literal),
+                    ) => Type::NumberLiteral(NumberLiteral::new(-literal.value)),
+                    _ => return TypeId::NUMBER,
+                }
+            }
+        };
+        self.types.intern(ty)
+    }
+
+    /// The object type described by the members of type literals or merged interfaces.
+    pub(crate) fn object_type<'m>(
+        &mut self,
+        members: impl IntoIterator<Item = &'m TSSignature<'a>>,
+    ) -> TypeId
+    where
+        'a: 'm,
+    {
+        let mut properties: Vec<Property> = vec![];
+        for member in members {
+            let (key, optional, type_id) = match member {
+                TSSignature::TSPropertySignature(property) if !property.computed => {
+                    let type_id = property
+                        .type_annotation
+                        .as_ref()
+                        .map_or(TypeId::ANY, |ty| self.annotation_type(&ty.type_annotation));
+                    (&property.key, property.optional, type_id)
+                }
+                TSSignature::TSMethodSignature(method) if !method.computed => {
+                    let return_type = method
+                        .return_type
+                        .as_ref()
+                        .map_or(TypeId::ANY, |ty| self.annotation_type(&ty.type_annotation));
+                    let type_id = match method.kind {
+                        TSMethodSignatureKind::Get => return_type,
+                        TSMethodSignatureKind::Method => {
+                            let type_params =
+                                self.type_parameters(method.type_parameters.as_deref());
+                            self.parameters_type(type_params, &method.params, return_type)
+                        }
+                        // Setters are not represented yet
+                        TSMethodSignatureKind::Set => self.unknown_type(),
+                    };
+                    (&method.key, method.optional, type_id)
+                }
+                // Index, call and construct signatures are not represented yet
+                _ => return self.unknown_type(),
+            };
+            let Some(name) = property_key_name(key) else { return self.unknown_type() };
+            // Overloaded methods, and accessor pairs
+            if let Some(existing) = properties.iter_mut().find(|property| property.name == name) {
+                existing.type_id = TypeId::ANY;
+                self.unknown_types = true;
+                continue;
+            }
+            properties.push(Property { name, type_id, optional });
+        }
+        self.types.intern(Type::Object(ObjectType { properties: properties.into_boxed_slice() }))
+    }
+
+    fn type_reference_type(&mut self, reference: &TSTypeReference<'a>) -> TypeId {
+        let TSTypeName::IdentifierReference(ident) = &reference.type_name else {
+            return self.unknown_type();
+        };
+        let symbol_id = self.semantic.scoping().get_reference(ident.reference_id()).symbol_id();
+        match (symbol_id, &reference.type_arguments) {
+            // The global `Promise<T>` interface, as declared by the lib files
+            (Some(symbol_id), Some(arguments))
+                if ident.name == "Promise"
+                    && arguments.params.len() == 1
+                    && self.is_global_interface(symbol_id) =>
+            {
+                let value = self.annotation_type(&arguments.params[0]);
+                self.types.intern(Type::Promise(value))
+            }
+            (Some(symbol_id), None) => {
+                let declared = self.declared_type(symbol_id);
+                self.types.instantiate_generic(declared, &[])
+            }
+            (Some(symbol_id), Some(arguments)) => {
+                let declared = self.declared_type(symbol_id);
+                let arguments =
+                    arguments.params.iter().map(|ty| self.annotation_type(ty)).collect::<Vec<_>>();
+                self.types.instantiate_generic(declared, &arguments)
+            }
+            // `Array<T>` and `ReadonlyArray<T>`, until interfaces with index signatures are
+            // represented
+            (None, Some(arguments))
+                if matches!(ident.name.as_str(), "Array" | "ReadonlyArray")
+                    && arguments.params.len() == 1 =>
+            {
+                let element = self.annotation_type(&arguments.params[0]);
+                self.types.intern(Type::Array(element))
+            }
+            (None, Some(arguments)) if ident.name == "Promise" && arguments.params.len() == 1 => {
+                let value = self.annotation_type(&arguments.params[0]);
+                self.types.intern(Type::Promise(value))
+            }
+            // `Awaited<T>`, whose declaration unwraps objects with a `then` method, which
+            // `Types::awaited` does for the promises that are represented
+            (None, Some(arguments)) if ident.name == "Awaited" && arguments.params.len() == 1 => {
+                let value = self.annotation_type(&arguments.params[0]);
+                if self.types.has_free_type_parameters(value, &[]) {
+                    self.unknown_type()
+                } else {
+                    self.types.awaited(value)
+                }
+            }
+            (None, arguments) => {
+                let Some(declared) =
+                    self.globals.and_then(|globals| globals.type_declaration(&ident.name))
+                else {
+                    return self.unknown_type();
+                };
+                let arguments = arguments.as_ref().map_or_else(Vec::new, |arguments| {
+                    arguments.params.iter().map(|ty| self.annotation_type(ty)).collect()
+                });
+                self.types.instantiate_generic(declared, &arguments)
+            }
+        }
+    }
+
+    /// Whether `symbol_id` is an interface declared at the top level, like those of the lib files.
+    fn is_global_interface(&self, symbol_id: SymbolId) -> bool {
+        let scoping = self.semantic.scoping();
+        scoping.symbol_scope_id(symbol_id) == scoping.root_scope_id()
+            && scoping.symbol_flags(symbol_id).is_interface()
+    }
+
+    /// The type of a variable or function in `typeof x`.
+    fn type_query_type(&mut self, query: &TSTypeQuery<'a>) -> TypeId {
+        let (TSTypeQueryExprName::IdentifierReference(ident), None) =
+            (&query.expr_name, &query.type_arguments)
+        else {
+            return self.unknown_type();
+        };
+        match self.semantic.scoping().get_reference(ident.reference_id()).symbol_id() {
+            Some(symbol_id) => self.symbol_type(symbol_id),
+            None => self.global_value_type(&ident.name),
+        }
+    }
+
+    /// The types of the type parameters of a generic function or signature.
+    pub(crate) fn type_parameters(
+        &mut self,
+        declaration: Option<&TSTypeParameterDeclaration<'a>>,
+    ) -> Box<[TypeId]> {
+        declaration.map_or_else(Box::default, |declaration| {
+            declaration
+                .params
+                .iter()
+                .map(|param| {
+                    param
+                        .name
+                        .symbol_id
+                        .get()
+                        .map_or(TypeId::ANY, |symbol_id| self.declared_type(symbol_id))
+                })
+                .collect()
+        })
+    }
+
+    fn conditional_type(&mut self, conditional: &TSConditionalType<'a>) -> TypeId {
+        let check = self.annotation_type(&conditional.check_type);
+        // The type parameters declared by `infer`, which are bound in the scope of the extends
+        // and true types
+        let scoping = self.semantic.scoping();
+        let infer_params = conditional.scope_id.get().map_or_else(Box::default, |scope_id| {
+            scoping
+                .iter_bindings_in(scope_id)
+                .map(|symbol_id| self.declared_type(symbol_id))
+                .collect()
+        });
+        let conditional = ConditionalType {
+            check,
+            extends: self.annotation_type(&conditional.extends_type),
+            true_type: self.annotation_type(&conditional.true_type),
+            false_type: self.annotation_type(&conditional.false_type),
+            infer_params,
+            distributive: matches!(self.types.get(check), Type::TypeParameter(_)),
+        };
+        self.types.conditional(conditional)
+    }
+
+    fn mapped_type(&mut self, mapped: &TSMappedType<'a>) -> TypeId {
+        let Some(param_symbol) = mapped.key.symbol_id.get() else { return TypeId::ANY };
+        let constraint = self.annotation_type(&mapped.constraint);
+        // A homomorphic mapped type, `{ [P in keyof T]: ... }` or `{ [P in K]: ... }` where `K`
+        // extends `keyof T`, keeps the modifiers of the properties of `T`
+        let modifiers_source = match &mapped.constraint {
+            TSType::TSTypeOperatorType(operator)
+                if operator.operator == TSTypeOperatorOperator::Keyof =>
+            {
+                Some(self.annotation_type(&operator.type_annotation))
+            }
+            _ => match self.types.get(constraint) {
+                Type::TypeParameter(param) => {
+                    param.constraint.and_then(|constraint| match self.types.get(constraint) {
+                        Type::KeyOf(source) => Some(*source),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            },
+        };
+        let mapped = MappedType {
+            param: self.declared_type(param_symbol),
+            constraint,
+            name_type: mapped.name_type.as_ref().map(|name_type| self.annotation_type(name_type)),
+            template: mapped
+                .type_annotation
+                .as_ref()
+                .map_or(TypeId::ANY, |template| self.annotation_type(template)),
+            optional: match mapped.optional {
+                None => MappedModifier::Preserve,
+                Some(TSMappedTypeModifierOperator::True | TSMappedTypeModifierOperator::Plus) => {
+                    MappedModifier::Add
+                }
+                Some(TSMappedTypeModifierOperator::Minus) => MappedModifier::Remove,
+            },
+            modifiers_source,
+        };
+        self.types.mapped(mapped)
+    }
+
+    /// The type a type parameter stands for, declared by a generic function or type, by `infer`,
+    /// or by a mapped type.
+    fn type_parameter_type(&mut self, symbol_id: SymbolId) -> TypeId {
+        let semantic = self.semantic;
+        let name = CompactStr::from(semantic.scoping().symbol_name(symbol_id));
+        let declaration = semantic.nodes().kind(semantic.scoping().symbol_declaration(symbol_id));
+        let param = match declaration {
+            AstKind::TSTypeParameter(param) => TypeParameter::new(
+                name,
+                param.constraint.as_ref().map(|constraint| self.annotation_type(constraint)),
+                param.default.as_ref().map(|default| self.annotation_type(default)),
+            ),
+            _ => TypeParameter::new(name, None, None),
+        };
+        self.types.intern(Type::TypeParameter(param))
+    }
+
+    /// The type declared by a type alias or interface, which is a [`Type::Generic`] if it has
+    /// type parameters, or the type a type parameter stands for.
+    ///
+    /// References to a type alias within its own declaration are `any`.
+    pub(crate) fn declared_type(&mut self, symbol_id: SymbolId) -> TypeId {
+        if let Some(&type_id) = self.declared_types.get(&symbol_id) {
+            return type_id;
+        }
+        if !self.resolving_types.insert(symbol_id) {
+            return TypeId::ANY;
+        }
+        let semantic = self.semantic;
+        if semantic.scoping().symbol_flags(symbol_id).is_import() {
+            let type_id = self.import_type(semantic.scoping().symbol_declaration(symbol_id), true);
+            self.resolving_types.remove(&symbol_id);
+            self.declared_types.insert(symbol_id, type_id);
+            return type_id;
+        }
+        if semantic.scoping().symbol_flags(symbol_id).is_type_parameter() {
+            let type_id = self.type_parameter_type(symbol_id);
+            self.resolving_types.remove(&symbol_id);
+            self.declared_types.insert(symbol_id, type_id);
+            return type_id;
+        }
+        let mut alias = None;
+        let mut interfaces = vec![];
+        for declaration in semantic.scoping().symbol_declarations(symbol_id) {
+            match semantic.nodes().kind(declaration) {
+                AstKind::TSTypeAliasDeclaration(declaration) => alias = Some(declaration),
+                AstKind::TSInterfaceDeclaration(declaration) => interfaces.push(declaration),
+                // Values which share the name, e.g. `declare var Math: Math;`
+                _ => {}
+            }
+        }
+        let type_id = match (alias, interfaces.as_slice()) {
+            (Some(alias), []) => {
+                let params = self.type_parameters(alias.type_parameters.as_deref());
+                let body = self.annotation_type(&alias.type_annotation);
+                self.generic_type(params, body)
+            }
+            // Interfaces with the same name are merged
+            (None, [first, rest @ ..])
+                if interfaces.iter().all(|interface| interface.extends.is_empty()) =>
+            {
+                let params = self.type_parameters(first.type_parameters.as_deref());
+                let mut body =
+                    self.object_type(interfaces.iter().flat_map(|interface| &interface.body.body));
+                // Each declaration declares its own type parameters, which are those of the first
+                let mut substitution = Substitution::default();
+                for interface in rest {
+                    let other_params = self.type_parameters(interface.type_parameters.as_deref());
+                    if other_params.len() != params.len() {
+                        body = self.unknown_type();
+                    }
+                    substitution.extend(other_params.iter().copied().zip(params.iter().copied()));
+                }
+                body = self.types.instantiate(body, &substitution);
+                self.generic_type(params, body)
+            }
+            // Classes, enums, and interfaces which extend others
+            _ => self.unknown_type(),
+        };
+        self.resolving_types.remove(&symbol_id);
+        self.declared_types.insert(symbol_id, type_id);
+        type_id
+    }
+}
+
+impl TypeCheckerVisitor<'_, '_> {
+    /// The type declared by a type alias or interface with type parameters `params`.
+    fn generic_type(&mut self, params: Box<[TypeId]>, body: TypeId) -> TypeId {
+        if params.is_empty() || body == TypeId::ANY {
+            return body;
+        }
+        self.types.intern(Type::Generic(GenericType { params, body }))
+    }
+}
+
+/// The name of a property, if it is known statically.
+pub fn property_key_name(key: &PropertyKey) -> Option<CompactStr> {
+    key.static_name().map(|name| CompactStr::from(name.as_ref()))
+}
+
+/// The name of a parameter, or `__0` style names for destructured parameters as TypeScript does.
+pub fn binding_name(name: Option<Ident>, index: usize) -> CompactStr {
+    name.map_or_else(
+        || CompactStr::from(format!("__{index}")),
+        |name| CompactStr::from(name.as_str()),
+    )
+}
@@ -0,0 +1,133 @@
+//! The assignability relation, which decides whether a value of one type can be used where another
+//! type is expected.
+//!
+//! This follows TypeScript with `strict` enabled: `null` and `undefined` are only assignable to
+//! themselves (and `undefined` to `void`), and function parameters are compared contravariantly.
+
+use crate::types::{FunctionType, ObjectType, Type, TypeId, Types};
+
+impl Types {
+    /// Whether a value of type `source` can be assigned to a location of type `target`.
+    pub fn is_assignable(&self, source: TypeId, target: TypeId) -> bool {
+        // Deferred types are only related by their instantiations
+        if source == target || self.is_deferred(source) || self.is_deferred(target) {
+            return true;
+        }
+        match (self.get(source), self.get(target)) {
+            (_, Type::Any | Type::Unknown)
+            | (Type::Any | Type::Never, _)
+            | (Type::Undefined, Type::Void)
+            | (Type::BooleanLiteral(_), Type::Boolean)
+            | (Type::NumberLiteral(_), Type::Number)
+            | (Type::StringLiteral(_), Type::String)
+            | (Type::BigIntLiteral(_), Type::BigInt)
+            | (
+                Type::NonPrimitive
+                | Type::Object(_)
+                | Type::Array(_)
+                | Type::Tuple(_)
+                | Type::Function(_)
+                | Type::Promise(_),
+                Type::NonPrimitive,
+            )
+            // Neither the members of promises nor whether other objects are promises are
+            // represented yet, so only primitives are known not to be promises.
+            | (Type::Promise(_), Type::Object(_))
+            | (
+                Type::NonPrimitive | Type::Object(_) | Type::Array(_) | Type::Tuple(_) | Type::Function(_),
+                Type::Promise(_),
+            ) => true,
+            (Type::Union(members), _) => {
+                members.iter().all(|&member| self.is_assignable(member, target))
+            }
+            (_, Type::Union(members)) => {
+                members.iter().any(|&member| self.is_assignable(source, member))
+            }
+            // A value of a type parameter is any value of its constraint
+            (Type::TypeParameter(param), _) => {
+                param.constraint.is_some_and(|constraint| self.is_assignable(constraint, target))
+            }
+            // Generic functions are not compared yet
+            (Type::Function(source), Type::Function(target))
+                if !source.type_params.is_empty() || !target.type_params.is_empty() =>
+            {
+                true
+            }
+            (Type::Object(source), Type::Object(target)) => {
+                self.is_object_assignable(source, target)
+            }
+            // Arrays and functions have no properties that type literals describe yet.
+            (Type::Array(_) | Type::Tuple(_) | Type::Function(_), Type::Object(target)) => {
+                target.properties.iter().all(|property| property.optional)
+            }
+            (Type::Array(source), Type::Array(target))
+            | (Type::Promise(source), Type::Promise(target)) => self.is_assignable(*source, *target),
+            (Type::Tuple(elements), Type::Array(target)) => {
+                elements.iter().all(|&element| self.is_assignable(element, *target))
+            }
+            (Type::Tuple(source), Type::Tuple(target)) => {
+                source.len() == target.len()
+                    && source.iter().zip(target).all(|(&s, &t)| self.is_assignable(s, t))
+            }
+            (Type::Function(source), Type::Function(target)) => {
+                self.is_function_assignable(source, target)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether a value of type `source` can be assigned to an optional property or parameter of
+    /// type `target`, which also accepts `undefined`.
+    pub fn is_assignable_to_optional(&self, source: TypeId, target: TypeId) -> bool {
+        match self.get(source) {
+            Type::Undefined => true,
+            Type::Union(members) => members
+                .iter()
+                .all(|&member| member == TypeId::UNDEFINED || self.is_assignable(member, target)),
+            _ => self.is_assignable(source, target),
+        }
+    }
+
+    fn is_deferred(&self, type_id: TypeId) -> bool {
+        matches!(
+            self.get(type_id),
+            Type::KeyOf(_)
+                | Type::IndexedAccess(..)
+                | Type::Conditional(_)
+                | Type::Mapped(_)
+                | Type::Generic(_)
+        )
+    }
+
+    fn is_object_assignable(&self, source: &ObjectType, target: &ObjectType) -> bool {
+        target.properties.iter().all(|target_property| {
+            match source.property(&target_property.name) {
+                Some(source_property) if target_property.optional => {
+                    self.is_assignable_to_optional(source_property.type_id, target_property.type_id)
+                }
+                Some(source_property) => {
+                    !source_property.optional
+                        && self.is_assignable(source_property.type_id, target_property.type_id)
+                }
+                None => target_property.optional,
+            }
+        })
+    }
+
+    fn is_function_assignable(&self, source: &FunctionType, target: &FunctionType) -> bool {
+        // A function may ignore arguments, but must not require more than it is given.
+        if source.required_params() > target.params.len() && target.rest.is_none() {
+            return false;
+        }
+
+        let target_params = target.params.iter().map(|param| param.type_id);
+        let source_params = source.params.iter().map(|param| param.type_id);
+        let params_assignable = source_params.zip(target_params).all(|(source, target)| {
+            // Parameters are contravariant: the source must accept whatever the target is given.
+            self.is_assignable(target, source)
+        });
+        let returns_assignable = target.return_type == TypeId::VOID
+            || self.is_assignable(source.return_type, target.return_type);
+        params_assignable && returns_assignable
+    }
+}
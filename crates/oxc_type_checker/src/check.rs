@@ -0,0 +1,183 @@
+//! Assignability checks, reported in the form TypeScript reports them.
+
+use oxc_ast::ast::{Argument, CallExpression, Expression, ObjectPropertyKind, PropertyKind};
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    TypeCheckerVisitor,
+    annotation::property_key_name,
+    diagnostics,
+    types::{Type, TypeId},
+};
+
+/// What a value is assigned to, which decides how an error is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    /// A variable, property or return type.
+    Assignment,
+    /// A parameter.
+    Argument,
+}
+
+impl<'a> TypeCheckerVisitor<'a, '_> {
+    /// Report `expr` at `span` if it is not assignable to `target`.
+    ///
+    /// Errors in object and array literals are reported at the offending property or element
+    /// instead. Returns whether an error was reported.
+    pub(crate) fn check_assignable(
+        &mut self,
+        expr: &Expression<'a>,
+        target: TypeId,
+        span: Span,
+        relation: Relation,
+    ) -> bool {
+        if self.elaborate(expr, target) {
+            return true;
+        }
+        let source = self.expression_type(expr, Some(target));
+        if self.types.is_assignable(source, target) {
+            return false;
+        }
+
+        // Literal types are only shown when they could matter, e.g. `"a"` for a `"b"` target.
+        let shown_source = if self.types.could_have_singleton_types(target) {
+            source
+        } else {
+            self.types.widen_literals(source)
+        };
+        let source_name = self.types.display(shown_source);
+        let target_name = self.types.display(target);
+        let diagnostic = match (relation, self.types.get(shown_source), self.types.get(target)) {
+            (Relation::Argument, ..) => {
+                diagnostics::argument_not_assignable(&source_name, &target_name, span)
+            }
+            (Relation::Assignment, Type::Object(source_object), Type::Object(target_object)) => {
+                match target_object.properties.iter().find(|property| {
+                    !property.optional && source_object.property(&property.name).is_none()
+                }) {
+                    Some(missing) => diagnostics::property_missing(
+                        &missing.name,
+                        &source_name,
+                        &target_name,
+                        span,
+                    ),
+                    None => diagnostics::type_not_assignable(&source_name, &target_name, span),
+                }
+            }
+            (Relation::Assignment, ..) => {
+                diagnostics::type_not_assignable(&source_name, &target_name, span)
+            }
+        };
+        self.diagnostics.push(diagnostic);
+        true
+    }
+
+    /// Check the properties of an object literal and the elements of an array literal
+    /// individually. Returns whether an error was reported.
+    fn elaborate(&mut self, expr: &Expression<'a>, target: TypeId) -> bool {
+        let mut reported = false;
+        match (expr.get_inner_expression(), self.types.get(target).clone()) {
+            (Expression::ObjectExpression(object), Type::Object(target)) => {
+                for property in &object.properties {
+                    let ObjectPropertyKind::ObjectProperty(property) = property else { continue };
+                    if property.kind != PropertyKind::Init {
+                        continue;
+                    }
+                    let Some(target_property) =
+                        property_key_name(&property.key).and_then(|name| target.property(&name))
+                    else {
+                        continue;
+                    };
+                    let mut property_type = target_property.type_id;
+                    if target_property.optional {
+                        property_type = self.types.union([property_type, TypeId::UNDEFINED]);
+                    }
+                    reported |= self.check_assignable(
+                        &property.value,
+                        property_type,
+                        property.key.span(),
+                        Relation::Assignment,
+                    );
+                }
+            }
+            (Expression::ArrayExpression(array), Type::Array(element_type)) => {
+                for element in &array.elements {
+                    if let Some(element) = element.as_expression() {
+                        reported |= self.check_assignable(
+                            element,
+                            element_type,
+                            element.span(),
+                            Relation::Assignment,
+                        );
+                    }
+                }
+            }
+            (Expression::ArrayExpression(array), Type::Tuple(element_types))
+                if array.elements.len() == element_types.len() =>
+            {
+                for (element, element_type) in array.elements.iter().zip(element_types) {
+                    if let Some(element) = element.as_expression() {
+                        reported |= self.check_assignable(
+                            element,
+                            element_type,
+                            element.span(),
+                            Relation::Assignment,
+                        );
+                    }
+                }
+            }
+            _ => {}
+        }
+        reported
+    }
+
+    /// Check the number and types of the arguments of a call to a function of known type, whose
+    /// type parameters are instantiated with the type arguments of the call.
+    pub(crate) fn check_call(&mut self, call: &CallExpression<'a>) {
+        let Some(function) = self.call_signature(call) else { return };
+        // The number of arguments a spread passes is not known
+        if call.arguments.iter().any(Argument::is_spread) {
+            return;
+        }
+
+        let count = call.arguments.len();
+        let required = function.required_params();
+        let max = function.params.len();
+        let expected =
+            || if required == max { max.to_string() } else { format!("{required}-{max}") };
+        if count < required {
+            self.diagnostics.push(if function.rest.is_some() {
+                diagnostics::expected_at_least_arguments(required, count, call.span)
+            } else {
+                diagnostics::expected_arguments(&expected(), count, call.span)
+            });
+            return;
+        }
+        if count > max && function.rest.is_none() {
+            // The excess arguments
+            let span =
+                Span::new(call.arguments[max].span().start, call.arguments[count - 1].span().end);
+            self.diagnostics.push(diagnostics::expected_arguments(&expected(), count, span));
+            return;
+        }
+
+        for (index, argument) in call.arguments.iter().enumerate() {
+            let target = match function.params.get(index) {
+                Some(param) if param.optional => {
+                    self.types.union([param.type_id, TypeId::UNDEFINED])
+                }
+                Some(param) => param.type_id,
+                None => match function.rest.as_ref().map(|rest| self.types.get(rest.type_id)) {
+                    Some(Type::Array(element)) => *element,
+                    _ => continue,
+                },
+            };
+            self.check_assignable(
+                argument.to_expression(),
+                target,
+                argument.span(),
+                Relation::Argument,
+            );
+        }
+    }
+}
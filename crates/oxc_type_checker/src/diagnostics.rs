@@ -5,15 +5,49 @@ use oxc_span::Span;
 
 /// Build a type-error diagnostic labelled at `span`.
 ///
-/// This is a convenience template for reporting problems from the checker. As real checks
-/// land, prefer dedicated constructors that carry precise, TypeScript-style error codes
-/// (for example `TS2322: Type 'string' is not assignable to type 'number'.`), following
-/// the pattern used by `oxc_isolated_declarations`.
-///
-/// ```ignore
-/// self.diagnostics.push(type_error("Type 'string' is not assignable to type 'number'.", span));
-/// ```
+/// Checks report through the dedicated constructors below, which carry TypeScript's error codes.
 #[cold]
 pub fn type_error<M: Into<Cow<'static, str>>>(message: M, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(message).with_label(span)
 }
+
+#[cold]
+pub fn type_not_assignable(source: &str, target: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("TS2322: Type '{source}' is not assignable to type '{target}'."))
+        .with_label(span)
+}
+
+#[cold]
+pub fn argument_not_assignable(source: &str, target: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "TS2345: Argument of type '{source}' is not assignable to parameter of type '{target}'."
+    ))
+    .with_label(span)
+}
+
+#[cold]
+pub fn property_missing(name: &str, source: &str, target: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "TS2741: Property '{name}' is missing in type '{source}' but required in type '{target}'."
+    ))
+    .with_label(span)
+}
+
+#[cold]
+pub fn expected_arguments(expected: &str, count: usize, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("TS2554: Expected {expected} arguments, but got {count}."))
+        .with_label(span)
+}
+
+#[cold]
+pub fn expected_at_least_arguments(expected: usize, count: usize, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "TS2555: Expected at least {expected} arguments, but got {count}."
+    ))
+    .with_label(span)
+}
+
+#[cold]
+pub fn file_not_found(path: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("TS6053: File '{path}' not found."))
+}
@@ -0,0 +1,115 @@
+//! The types of all expressions of a program, for consumers such as lint rules.
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::ast::{AssignmentTarget, Expression, Program, SimpleAssignmentTarget};
+use oxc_ast_visit::{
+    Visit,
+    walk::{walk_expression, walk_simple_assignment_target},
+};
+use oxc_syntax::node::NodeId;
+
+use crate::{
+    TypeCheckerVisitor,
+    types::{TypeId, Types},
+};
+
+/// The types of the expressions of a program, computed by [`TypeChecker::expression_types`].
+///
+/// Expressions whose type cannot be represented yet are `any`, so `any` means that the type is not
+/// known rather than that the program uses `any`. Values of a type parameter have the type of its
+/// constraint, or `unknown`, as lint rules check what values may be.
+///
+/// [`TypeChecker::expression_types`]: crate::TypeChecker::expression_types
+#[derive(Debug, Default)]
+pub struct ExpressionTypes {
+    types: Types,
+    /// Keyed by the node ids of expressions and of identifiers which are assigned to.
+    expressions: FxHashMap<NodeId, TypeId>,
+    unresolved_imports: bool,
+    unknown_types: bool,
+}
+
+impl ExpressionTypes {
+    /// The arena which the type ids returned by [`ExpressionTypes::get`] are valid in.
+    pub fn types(&self) -> &Types {
+        &self.types
+    }
+
+    /// Take the arena of the types, for consumers which outlive the checked program.
+    pub fn into_types(self) -> Types {
+        self.types
+    }
+
+    /// The type of `expr`, or `None` if it is not part of the checked program.
+    pub fn get(&self, expr: &Expression) -> Option<TypeId> {
+        self.expressions.get(&expr.node_id()).copied()
+    }
+
+    /// The type of the variable `target` assigns to, or `None` if it is not an identifier of the
+    /// checked program.
+    pub fn assignment_target(&self, target: &AssignmentTarget) -> Option<TypeId> {
+        let AssignmentTarget::AssignmentTargetIdentifier(ident) = target else { return None };
+        self.expressions.get(&ident.node_id()).copied()
+    }
+
+    /// The node ids of all expressions and assigned identifiers, with their types.
+    pub fn iter(&self) -> impl Iterator<Item = (NodeId, TypeId)> + '_ {
+        self.expressions.iter().map(|(&node_id, &type_id)| (node_id, type_id))
+    }
+
+    /// Whether the program refers to an import which could not be resolved, so that the types
+    /// which depend on it are `any`. Imports are never resolved, as only a single file is checked.
+    pub fn has_unresolved_imports(&self) -> bool {
+        self.unresolved_imports
+    }
+
+    /// Whether some type is `any` because it is not known, e.g. that of a global which the loaded
+    /// lib files do not declare, of a class, or of an overloaded function or a method of a
+    /// primitive, or because the program refers to an unresolved import.
+    ///
+    /// Other `any` types are those of the program, or of errors, or of expressions such as
+    /// spreads whose type is not represented yet but which lint rules inspect themselves.
+    pub fn has_unknown_types(&self) -> bool {
+        self.unresolved_imports || self.unknown_types
+    }
+}
+
+impl<'a> TypeCheckerVisitor<'a, '_> {
+    /// Infer the types of all expressions of `program`, which consumes the checker.
+    pub(crate) fn expression_types(&mut self, program: &Program<'a>) -> ExpressionTypes {
+        let mut collector = Collector { checker: self, expressions: FxHashMap::default() };
+        collector.visit_program(program);
+        let expressions = collector.expressions;
+        let types = std::mem::take(&mut self.types);
+        ExpressionTypes {
+            types,
+            expressions,
+            unresolved_imports: self.unresolved_imports,
+            unknown_types: self.unknown_types,
+        }
+    }
+}
+
+struct Collector<'v, 'a, 'c> {
+    checker: &'v mut TypeCheckerVisitor<'a, 'c>,
+    expressions: FxHashMap<NodeId, TypeId>,
+}
+
+impl<'a> Visit<'a> for Collector<'_, 'a, '_> {
+    fn visit_expression(&mut self, it: &Expression<'a>) {
+        let type_id = self.checker.expression_type(it, None);
+        let type_id = self.checker.types.base_constraint(type_id);
+        self.expressions.insert(it.node_id(), type_id);
+        walk_expression(self, it);
+    }
+
+    fn visit_simple_assignment_target(&mut self, it: &SimpleAssignmentTarget<'a>) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = it {
+            let type_id = self.checker.reference_type(ident);
+            let type_id = self.checker.types.base_constraint(type_id);
+            self.expressions.insert(ident.node_id(), type_id);
+        }
+        walk_simple_assignment_target(self, it);
+    }
+}
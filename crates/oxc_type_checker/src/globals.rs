@@ -0,0 +1,170 @@
+//! The global environment declared by TypeScript's `lib.*.d.ts` files.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, LazyLock, Mutex},
+};
+
+use cow_utils::CowUtils;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::Allocator;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_str::CompactStr;
+use oxc_syntax::es_target::ESTarget;
+
+use crate::{
+    TypeCheckerOptions, TypeCheckerVisitor, diagnostics,
+    types::{TypeId, Types},
+};
+
+/// Loaded globals, keyed by the root lib files and type declaration files.
+type Cache = FxHashMap<(Vec<PathBuf>, Vec<PathBuf>), Arc<Globals>>;
+
+static CACHE: LazyLock<Mutex<Cache>> = LazyLock::new(Mutex::default);
+
+/// The types of the global variables, functions, interfaces and type aliases declared by a set of
+/// `lib.*.d.ts` files, such as `Math`, `parseInt` and `console`.
+///
+/// The global declarations of the files in [`TypeCheckerOptions::types`] are included too.
+#[derive(Debug)]
+pub struct Globals {
+    types: Types,
+    values: FxHashMap<CompactStr, TypeId>,
+    type_declarations: FxHashMap<CompactStr, TypeId>,
+}
+
+impl Globals {
+    /// Load the lib files selected by `options` from [`TypeCheckerOptions::lib_path`] and the
+    /// declaration files in [`TypeCheckerOptions::types`], following their
+    /// `/// <reference lib="..." />` and `/// <reference path="..." />` directives.
+    ///
+    /// The files are parsed once per process for each set of files.
+    /// Returns `None` if there are no files to load.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file cannot be read or parsed.
+    pub fn load(options: &TypeCheckerOptions) -> Result<Option<Arc<Self>>, OxcDiagnostic> {
+        let lib_files = options.lib_path.as_ref().map_or_else(Vec::new, |lib_path| {
+            let file_names = match &options.lib {
+                Some(libs) => libs.iter().map(|lib| lib_file_name(lib)).collect(),
+                None => vec![default_lib_file_name(options.target)],
+            };
+            file_names.into_iter().map(|file_name| lib_path.join(file_name)).collect()
+        });
+        if lib_files.is_empty() && options.types.is_empty() {
+            return Ok(None);
+        }
+        let key = (lib_files, options.types.clone());
+
+        let mut cache = CACHE.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        if let Some(globals) = cache.get(&key) {
+            return Ok(Some(Arc::clone(globals)));
+        }
+        let files = key.0.iter().chain(&key.1).cloned().collect();
+        let source_text = read_files(options.lib_path.as_deref(), files)?;
+        let globals = Arc::new(Self::from_source_text(&source_text)?);
+        cache.insert(key, Arc::clone(&globals));
+        Ok(Some(globals))
+    }
+
+    fn from_source_text(source_text: &str) -> Result<Self, OxcDiagnostic> {
+        let allocator = Allocator::default();
+        let parser_ret = Parser::new(&allocator, source_text, SourceType::d_ts()).parse();
+        if let Some(error) = parser_ret.diagnostics.into_iter().next() {
+            return Err(error);
+        }
+        let semantic =
+            SemanticBuilder::new().with_build_nodes(true).build(&parser_ret.program).semantic;
+
+        let options = TypeCheckerOptions::default();
+        let mut visitor = TypeCheckerVisitor::new(&semantic, &options, None, None);
+        let mut values = FxHashMap::default();
+        let mut type_declarations = FxHashMap::default();
+        let scoping = semantic.scoping();
+        for symbol_id in scoping.iter_bindings_in(scoping.root_scope_id()) {
+            let name = CompactStr::from(scoping.symbol_name(symbol_id));
+            let flags = scoping.symbol_flags(symbol_id);
+            if flags.is_variable() || flags.is_function() {
+                values.insert(name.clone(), visitor.symbol_type(symbol_id));
+            }
+            if flags.is_interface() || flags.is_type_alias() {
+                type_declarations.insert(name, visitor.declared_type(symbol_id));
+            }
+        }
+        Ok(Self { types: visitor.types, values, type_declarations })
+    }
+
+    /// The types of the globals, whose ids are valid in a copy of them.
+    pub(crate) fn types(&self) -> &Types {
+        &self.types
+    }
+
+    /// The type of a global variable or function.
+    pub fn value(&self, name: &str) -> Option<TypeId> {
+        self.values.get(name).copied()
+    }
+
+    /// The type declared by a global interface or type alias.
+    pub fn type_declaration(&self, name: &str) -> Option<TypeId> {
+        self.type_declarations.get(name).copied()
+    }
+}
+
+/// The file TypeScript loads for a `lib` name, e.g. `lib.es2015.core.d.ts` for `es2015.core`.
+fn lib_file_name(lib: &str) -> String {
+    let lib = lib.cow_to_ascii_lowercase();
+    let lib = match lib.as_ref() {
+        "es6" => "es2015",
+        "es7" => "es2016",
+        lib => lib,
+    };
+    format!("lib.{lib}.d.ts")
+}
+
+/// The file TypeScript loads for a `target` when `lib` is not set.
+fn default_lib_file_name(target: ESTarget) -> String {
+    match target {
+        ESTarget::ES5 => "lib.d.ts".to_string(),
+        ESTarget::ES2015 => "lib.es6.d.ts".to_string(),
+        target => format!("lib.{target}.full.d.ts"),
+    }
+}
+
+/// Concatenate `files` and the files they reference: libs in `lib_path`, and other files relative
+/// to the referencing file. Lib files are global scripts, so they can be checked as a single
+/// program.
+fn read_files(lib_path: Option<&Path>, mut pending: Vec<PathBuf>) -> Result<String, OxcDiagnostic> {
+    let mut source_text = String::new();
+    let mut seen = FxHashSet::default();
+    while let Some(path) = pending.pop() {
+        if !seen.insert(path.clone()) {
+            continue;
+        }
+        let text = fs::read_to_string(&path)
+            .map_err(|_| diagnostics::file_not_found(&path.to_string_lossy()))?;
+        if let Some(lib_path) = lib_path {
+            pending.extend(references(&text, "lib").map(|lib| lib_path.join(lib_file_name(lib))));
+        }
+        if let Some(dir) = path.parent() {
+            pending.extend(references(&text, "path").map(|reference| dir.join(reference)));
+        }
+        source_text.push_str(&text);
+        source_text.push('\n');
+    }
+    Ok(source_text)
+}
+
+/// The values of `/// <reference {attribute}="..." />` directives.
+fn references<'s>(source_text: &'s str, attribute: &str) -> impl Iterator<Item = &'s str> {
+    let prefix = format!("/// <reference {attribute}=\"");
+    source_text.lines().filter_map(move |line| {
+        let reference = line.trim_start().strip_prefix(prefix.as_str())?;
+        reference.split_once('"').map(|(reference, _)| reference)
+    })
+}
@@ -0,0 +1,620 @@
+//! Types inferred for expressions and declarations.
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrayExpression, ArrayExpressionElement, ArrowFunctionExpression, AssignmentOperator,
+        BinaryOperator, BindingPattern, CallExpression, ComputedMemberExpression, Expression,
+        FormalParameters, Function, IdentifierReference, LogicalOperator, ObjectExpression,
+        ObjectPropertyKind, PropertyKind, ReturnStatement, StaticMemberExpression, TSType,
+        UnaryOperator,
+    },
+};
+use oxc_ast_visit::Visit;
+use oxc_syntax::{
+    scope::ScopeFlags,
+    symbol::{SymbolFlags, SymbolId},
+};
+
+use crate::{
+    TypeCheckerVisitor,
+    annotation::{binding_name, property_key_name},
+    inference::Inferences,
+    instantiation::Substitution,
+    types::{FunctionType, NumberLiteral, ObjectType, Parameter, Property, Type, TypeId, Types},
+};
+
+impl<'a> TypeCheckerVisitor<'a, '_> {
+    /// The type of `expr`.
+    ///
+    /// `contextual` is the type `expr` is assigned to, if any. It only decides whether an array
+    /// literal is a tuple.
+    pub(crate) fn expression_type(
+        &mut self,
+        expr: &Expression<'a>,
+        contextual: Option<TypeId>,
+    ) -> TypeId {
+        match expr {
+            Expression::BooleanLiteral(literal) => Types::boolean_literal(literal.value),
+            Expression::NullLiteral(_) => TypeId::NULL,
+            Expression::NumericLiteral(literal) => {
+                self.types.intern(Type::NumberLiteral(NumberLiteral::new(literal.value)))
+            }
+            Expression::StringLiteral(literal) => {
+                self.types.intern(Type::StringLiteral(literal.value.into()))
+            }
+            Expression::BigIntLiteral(literal) => {
+                self.types.intern(Type::BigIntLiteral(literal.value.into()))
+            }
+            Expression::TemplateLiteral(literal) => match literal.single_quasi() {
+                Some(value) => self.types.intern(Type::StringLiteral(value.into())),
+                None => TypeId::STRING,
+            },
+            Expression::Identifier(ident) => self.reference_type(ident),
+            Expression::ParenthesizedExpression(expr) => {
+                self.expression_type(&expr.expression, contextual)
+            }
+            Expression::TSAsExpression(expr) => {
+                self.asserted_type(&expr.expression, &expr.type_annotation)
+            }
+            Expression::TSTypeAssertion(expr) => {
+                self.asserted_type(&expr.expression, &expr.type_annotation)
+            }
+            Expression::TSSatisfiesExpression(expr) => {
+                self.expression_type(&expr.expression, contextual)
+            }
+            Expression::TSNonNullExpression(expr) => {
+                let type_id = self.expression_type(&expr.expression, None);
+                self.types.non_nullable(type_id)
+            }
+            Expression::ArrayExpression(array) => self.array_literal_type(array, contextual),
+            Expression::ObjectExpression(object) => self.object_literal_type(object),
+            Expression::FunctionExpression(function) => self.function_type(function),
+            Expression::ArrowFunctionExpression(arrow) => self.arrow_function_type(arrow),
+            Expression::UnaryExpression(expr) => match expr.operator {
+                UnaryOperator::LogicalNot | UnaryOperator::Delete => TypeId::BOOLEAN,
+                UnaryOperator::Typeof => TypeId::STRING,
+                UnaryOperator::Void => TypeId::UNDEFINED,
+                UnaryOperator::UnaryNegation => match expr.argument.get_inner_expression() {
+                    Expression::NumericLiteral(literal) => {
+                        self.types.intern(Type::NumberLiteral(NumberLiteral::new(-literal.value)))
+                    }
+                    argument => {
+                        let argument = self.expression_type(argument, None);
+                        self.numeric_type(argument, argument)
+                    }
+                },
+                UnaryOperator::BitwiseNot => {
+                    let argument = self.expression_type(&expr.argument, None);
+                    self.numeric_type(argument, argument)
+                }
+                UnaryOperator::UnaryPlus => TypeId::NUMBER,
+            },
+            Expression::UpdateExpression(expr) => {
+                let argument = expr
+                    .argument
+                    .get_expression()
+                    .map_or(TypeId::ANY, |argument| self.expression_type(argument, None));
+                self.numeric_type(argument, argument)
+            }
+            Expression::BinaryExpression(expr) => {
+                if expr.operator.is_equality()
+                    || expr.operator.is_compare()
+                    || expr.operator.is_relational()
+                {
+                    return TypeId::BOOLEAN;
+                }
+                let left = self.expression_type(&expr.left, None);
+                let right = self.expression_type(&expr.right, None);
+                if expr.operator == BinaryOperator::Addition {
+                    self.addition_type(left, right)
+                } else if expr.operator == BinaryOperator::ShiftRightZeroFill {
+                    TypeId::NUMBER
+                } else {
+                    self.numeric_type(left, right)
+                }
+            }
+            Expression::LogicalExpression(expr) => {
+                let left = self.expression_type(&expr.left, None);
+                let right = self.expression_type(&expr.right, contextual);
+                match expr.operator {
+                    // The falsy values of the left operand are not represented yet
+                    LogicalOperator::And => TypeId::ANY,
+                    LogicalOperator::Or | LogicalOperator::Coalesce => {
+                        let left = self.types.non_nullable(left);
+                        self.types.union([left, right])
+                    }
+                }
+            }
+            Expression::ConditionalExpression(expr) => {
+                let consequent = self.expression_type(&expr.consequent, contextual);
+                let alternate = self.expression_type(&expr.alternate, contextual);
+                self.types.union([consequent, alternate])
+            }
+            Expression::AssignmentExpression(expr)
+                if expr.operator == AssignmentOperator::Assign =>
+            {
+                self.expression_type(&expr.right, None)
+            }
+            Expression::AwaitExpression(expr) => {
+                let argument = self.expression_type(&expr.argument, None);
+                self.types.awaited(argument)
+            }
+            Expression::SequenceExpression(expr) => {
+                expr.expressions.last().map_or(TypeId::ANY, |expr| self.expression_type(expr, None))
+            }
+            Expression::CallExpression(call) if !call.optional => {
+                self.call_signature(call).map_or(TypeId::ANY, |function| function.return_type)
+            }
+            Expression::StaticMemberExpression(member) if !member.optional => {
+                self.static_member_type(member)
+            }
+            Expression::ComputedMemberExpression(member) if !member.optional => {
+                self.computed_member_type(member)
+            }
+            // The operands of a compound assignment are checked themselves
+            Expression::AssignmentExpression(_) => TypeId::ANY,
+            _ => self.unknown_type(),
+        }
+    }
+
+    /// `any` for a type which is not known, as opposed to the `any` of the program or of an
+    /// error. See [`ExpressionTypes::has_unknown_types`].
+    ///
+    /// [`ExpressionTypes::has_unknown_types`]: crate::ExpressionTypes::has_unknown_types
+    pub(crate) fn unknown_type(&mut self) -> TypeId {
+        self.unknown_types = true;
+        TypeId::ANY
+    }
+
+    pub(crate) fn reference_type(&mut self, ident: &IdentifierReference<'a>) -> TypeId {
+        let reference = self.semantic.scoping().get_reference(ident.reference_id());
+        let Some(symbol_id) = reference.symbol_id() else {
+            return match ident.name.as_str() {
+                "undefined" => TypeId::UNDEFINED,
+                name => self.global_value_type(name),
+            };
+        };
+        let type_id = self.symbol_type(symbol_id);
+        if !self.is_narrowable(type_id) {
+            return type_id;
+        }
+        // Without control flow narrowing, a reference to e.g. a `string | undefined` variable may
+        // well be a `string`, so such references are not checked. The same goes for references
+        // in unreachable code.
+        self.narrowed
+            .as_ref()
+            .and_then(|narrowed| narrowed.get(&ident.node_id()).copied())
+            .unwrap_or(TypeId::ANY)
+    }
+
+    /// The type of a global variable or function, which is not known if the loaded lib files do
+    /// not declare it, or declare it with a type which is not represented yet.
+    pub(crate) fn global_value_type(&mut self, name: &str) -> TypeId {
+        match self.globals.and_then(|globals| globals.value(name)) {
+            Some(type_id) if type_id != TypeId::ANY => type_id,
+            _ => self.unknown_type(),
+        }
+    }
+
+    /// The type of a variable, parameter or function.
+    ///
+    /// Variables without an annotation have the widened type of their initializer, or `any`.
+    pub(crate) fn symbol_type(&mut self, symbol_id: SymbolId) -> TypeId {
+        if let Some(&type_id) = self.symbol_types.get(&symbol_id) {
+            return type_id;
+        }
+        let semantic = self.semantic;
+        if semantic.scoping().symbol_flags(symbol_id).is_import() {
+            let type_id = self.import_type(semantic.scoping().symbol_declaration(symbol_id), false);
+            self.symbol_types.insert(symbol_id, type_id);
+            return type_id;
+        }
+        // Interfaces, type aliases and type parameters are not values
+        if !semantic.scoping().symbol_flags(symbol_id).intersects(SymbolFlags::Value) {
+            return TypeId::ANY;
+        }
+        // Interfaces and type aliases which share the name declare types, not values
+        let mut declarations = semantic
+            .scoping()
+            .symbol_declarations(symbol_id)
+            .map(|id| semantic.nodes().kind(id))
+            .filter(|kind| {
+                !matches!(
+                    kind,
+                    AstKind::TSInterfaceDeclaration(_) | AstKind::TSTypeAliasDeclaration(_)
+                )
+            });
+        // Overloads and merged declarations are not represented yet, and recursive inference is
+        // `any`
+        let (Some(declaration), None) = (declarations.next(), declarations.next()) else {
+            return self.unknown_type();
+        };
+        if !self.resolving.insert(symbol_id) {
+            return TypeId::ANY;
+        }
+        let type_id = match declaration {
+            AstKind::VariableDeclarator(declarator) if binds_symbol(&declarator.id, symbol_id) => {
+                match (&declarator.type_annotation, &declarator.init) {
+                    (Some(annotation), _) => self.annotation_type(&annotation.type_annotation),
+                    (None, Some(init)) => {
+                        let type_id = self.expression_type(init, None);
+                        if declarator.kind.is_const() {
+                            self.widen_nested(type_id)
+                        } else if matches!(type_id, TypeId::NULL | TypeId::UNDEFINED) {
+                            // TypeScript infers such variables from their later assignments
+                            TypeId::ANY
+                        } else {
+                            self.types.widen(type_id)
+                        }
+                    }
+                    (None, None) => TypeId::ANY,
+                }
+            }
+            AstKind::FormalParameter(param) if binds_symbol(&param.pattern, symbol_id) => {
+                match (&param.type_annotation, &param.initializer) {
+                    (Some(annotation), _) => self.annotation_type(&annotation.type_annotation),
+                    (None, Some(initializer)) => {
+                        let type_id = self.expression_type(initializer, None);
+                        self.types.widen(type_id)
+                    }
+                    (None, None) => TypeId::ANY,
+                }
+            }
+            AstKind::FormalParameterRest(rest) if binds_symbol(&rest.rest.argument, symbol_id) => {
+                rest.type_annotation.as_ref().map_or(TypeId::ANY, |annotation| {
+                    self.annotation_type(&annotation.type_annotation)
+                })
+            }
+            AstKind::Function(function) => self.function_type(function),
+            AstKind::CatchParameter(_) => TypeId::ANY,
+            // Classes, enums, namespaces and destructured variables
+            _ => self.unknown_type(),
+        };
+        self.resolving.remove(&symbol_id);
+        self.symbol_types.insert(symbol_id, type_id);
+        type_id
+    }
+
+    /// Whether references to variables of the type are narrowed by control flow: unions,
+    /// `unknown`, and type parameters, which e.g. `typeof` narrows to their intersection with a
+    /// primitive.
+    pub(crate) fn is_narrowable(&self, type_id: TypeId) -> bool {
+        matches!(self.types.get(type_id), Type::Union(_) | Type::Unknown)
+            || self.types.is_instantiable(type_id)
+    }
+
+    /// The signature of the function `call` calls, with the type parameters of a generic
+    /// function replaced by the explicit type arguments or by those inferred from the arguments.
+    pub(crate) fn call_signature(&mut self, call: &CallExpression<'a>) -> Option<FunctionType> {
+        let callee = self.expression_type(&call.callee, None);
+        let callee = self.types.base_constraint(callee);
+        let Type::Function(function) = self.types.get(callee).clone() else { return None };
+        if function.type_params.is_empty() {
+            return Some(function);
+        }
+        let substitution = if let Some(arguments) = &call.type_arguments {
+            let arguments =
+                arguments.params.iter().map(|ty| self.annotation_type(ty)).collect::<Vec<_>>();
+            self.explicit_type_arguments(&function.type_params, &arguments)
+        } else {
+            let mut inferences = Inferences::new(&function.type_params);
+            for (index, argument) in call.arguments.iter().enumerate() {
+                let Some(argument) = argument.as_expression() else { break };
+                let target = match function.params.get(index) {
+                    Some(param) => param.type_id,
+                    None => match function.rest.as_ref().map(|rest| self.types.get(rest.type_id)) {
+                        Some(Type::Array(element)) => *element,
+                        _ => break,
+                    },
+                };
+                let source = self.expression_type(argument, Some(target));
+                self.types.infer_from(source, target, &mut inferences);
+            }
+            self.types.inferred_type_arguments(&inferences, Some(function.return_type))
+        };
+        let function = FunctionType { type_params: Box::default(), ..function };
+        let instantiated = self.types.intern(Type::Function(function));
+        let instantiated = self.types.instantiate(instantiated, &substitution);
+        match self.types.get(instantiated) {
+            Type::Function(function) => Some(function.clone()),
+            _ => None,
+        }
+    }
+
+    /// The substitution for explicit type arguments, completed by the defaults of the type
+    /// parameters. The wrong number of arguments is an error, for which all are `any`.
+    fn explicit_type_arguments(&mut self, params: &[TypeId], arguments: &[TypeId]) -> Substitution {
+        let mut substitution = Substitution::default();
+        for (index, &param) in params.iter().enumerate() {
+            let default = match self.types.get(param) {
+                Type::TypeParameter(param) => param.default,
+                _ => None,
+            };
+            let argument = match (arguments.get(index), default) {
+                (Some(&argument), _) if arguments.len() <= params.len() => argument,
+                (None, Some(default)) => self.types.instantiate(default, &substitution),
+                _ => TypeId::ANY,
+            };
+            substitution.insert(param, argument);
+        }
+        substitution
+    }
+
+    /// The type of a `const` variable, which keeps a literal type but not literal types nested in
+    /// an object or array.
+    fn widen_nested(&mut self, type_id: TypeId) -> TypeId {
+        match self.types.get(type_id) {
+            Type::Object(_) | Type::Array(_) | Type::Tuple(_) => self.types.widen(type_id),
+            _ => type_id,
+        }
+    }
+
+    /// The type of `expr as T`, or of `expr as const`.
+    fn asserted_type(&mut self, expr: &Expression<'a>, annotation: &TSType<'a>) -> TypeId {
+        if annotation.is_const_type_reference() {
+            // Readonly tuples and properties are not represented yet
+            return match expr.get_inner_expression() {
+                Expression::ArrayExpression(_) | Expression::ObjectExpression(_) => {
+                    self.unknown_type()
+                }
+                expr => self.expression_type(expr, None),
+            };
+        }
+        self.annotation_type(annotation)
+    }
+
+    fn array_literal_type(
+        &mut self,
+        array: &ArrayExpression<'a>,
+        contextual: Option<TypeId>,
+    ) -> TypeId {
+        let tuple = contextual.and_then(|contextual| match self.types.get(contextual) {
+            Type::Tuple(elements) => Some(elements.clone()),
+            _ => None,
+        });
+        let mut elements = Vec::with_capacity(array.elements.len());
+        for (index, element) in array.elements.iter().enumerate() {
+            let element = match element {
+                // The element types of an iterable are not represented yet
+                ArrayExpressionElement::SpreadElement(_) => return TypeId::ANY,
+                ArrayExpressionElement::Elision(_) => TypeId::UNDEFINED,
+                _ => {
+                    let contextual = tuple.as_ref().and_then(|tuple| tuple.get(index).copied());
+                    self.expression_type(element.to_expression(), contextual)
+                }
+            };
+            elements.push(element);
+        }
+        if tuple.is_some() {
+            return self.types.intern(Type::Tuple(elements.into_boxed_slice()));
+        }
+        let element = if elements.is_empty() {
+            // `never[]`, which is assignable to any array
+            TypeId::NEVER
+        } else {
+            let elements =
+                elements.into_iter().map(|element| self.types.widen(element)).collect::<Vec<_>>();
+            self.types.union(elements)
+        };
+        self.types.intern(Type::Array(element))
+    }
+
+    fn object_literal_type(&mut self, object: &ObjectExpression<'a>) -> TypeId {
+        let mut properties: Vec<Property> = Vec::with_capacity(object.properties.len());
+        for property in &object.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else {
+                // The properties of a spread object are not represented yet
+                return TypeId::ANY;
+            };
+            let (PropertyKind::Init, Some(name)) =
+                (property.kind, property_key_name(&property.key))
+            else {
+                return TypeId::ANY;
+            };
+            let type_id = self.expression_type(&property.value, None);
+            let type_id = self.types.widen(type_id);
+            // A later property overrides an earlier one with the same name
+            properties.retain(|property| property.name != name);
+            properties.push(Property { name, type_id, optional: false });
+        }
+        self.types.intern(Type::Object(ObjectType { properties: properties.into_boxed_slice() }))
+    }
+
+    pub(crate) fn function_type(&mut self, function: &Function<'a>) -> TypeId {
+        if function.generator {
+            return self.unknown_type();
+        }
+        let type_params = self.type_parameters(function.type_parameters.as_deref());
+        let return_type = match (&function.return_type, &function.body) {
+            (Some(annotation), _) => self.annotation_type(&annotation.type_annotation),
+            (None, Some(body)) => {
+                let mut collector = ReturnTypes::new(self);
+                collector.visit_function_body(body);
+                let return_type = collector.return_type();
+                self.inferred_return_type(function.r#async, return_type)
+            }
+            (None, None) => TypeId::ANY,
+        };
+        self.parameters_type(type_params, &function.params, return_type)
+    }
+
+    fn arrow_function_type(&mut self, arrow: &ArrowFunctionExpression<'a>) -> TypeId {
+        let type_params = self.type_parameters(arrow.type_parameters.as_deref());
+        let return_type = match (&arrow.return_type, arrow.get_expression()) {
+            (Some(annotation), _) => self.annotation_type(&annotation.type_annotation),
+            (None, Some(expr)) => {
+                let type_id = self.expression_type(expr, None);
+                let type_id = self.types.widen(type_id);
+                self.inferred_return_type(arrow.r#async, type_id)
+            }
+            (None, None) => {
+                let mut collector = ReturnTypes::new(self);
+                collector.visit_function_body(&arrow.body);
+                let return_type = collector.return_type();
+                self.inferred_return_type(arrow.r#async, return_type)
+            }
+        };
+        self.parameters_type(type_params, &arrow.params, return_type)
+    }
+
+    /// The return type of a function without a return type annotation, whose `return` statements
+    /// return `return_type`. Async functions return a promise of the awaited type.
+    fn inferred_return_type(&mut self, is_async: bool, return_type: TypeId) -> TypeId {
+        if !is_async {
+            return return_type;
+        }
+        let value = self.types.awaited(return_type);
+        self.types.intern(Type::Promise(value))
+    }
+
+    /// The function type of a signature or function, whose parameters may be unannotated.
+    pub(crate) fn parameters_type(
+        &mut self,
+        type_params: Box<[TypeId]>,
+        params: &FormalParameters<'a>,
+        return_type: TypeId,
+    ) -> TypeId {
+        let mut items = Vec::with_capacity(params.items.len());
+        for (index, param) in params.items.iter().enumerate() {
+            let type_id = match (&param.type_annotation, &param.initializer) {
+                (Some(annotation), _) => self.annotation_type(&annotation.type_annotation),
+                (None, Some(initializer)) => {
+                    let type_id = self.expression_type(initializer, None);
+                    self.types.widen(type_id)
+                }
+                (None, None) => TypeId::ANY,
+            };
+            items.push(Parameter {
+                name: binding_name(param.pattern.get_identifier_name(), index),
+                type_id,
+                optional: param.optional || param.initializer.is_some(),
+            });
+        }
+        let rest = params.rest.as_ref().map(|rest| Parameter {
+            name: binding_name(rest.rest.argument.get_identifier_name(), params.items.len()),
+            type_id: rest.type_annotation.as_ref().map_or(TypeId::ANY, |annotation| {
+                self.annotation_type(&annotation.type_annotation)
+            }),
+            optional: true,
+        });
+        let function =
+            FunctionType { type_params, params: items.into_boxed_slice(), rest, return_type };
+        self.types.intern(Type::Function(function))
+    }
+
+    fn static_member_type(&mut self, member: &StaticMemberExpression<'a>) -> TypeId {
+        let object = self.expression_type(&member.object, None);
+        let object = self.types.base_constraint(object);
+        let name = member.property.name.as_str();
+        match self.types.get(object) {
+            Type::Object(object) => match object.property(name) {
+                Some(property) if property.optional => {
+                    let type_id = property.type_id;
+                    self.types.union([type_id, TypeId::UNDEFINED])
+                }
+                Some(property) => property.type_id,
+                None => TypeId::ANY,
+            },
+            Type::Array(_) | Type::Tuple(_) | Type::String | Type::StringLiteral(_)
+                if name == "length" =>
+            {
+                TypeId::NUMBER
+            }
+            // The lint rules which use the methods of a promise check their calls themselves
+            Type::Promise(_) if matches!(name, "then" | "catch" | "finally") => TypeId::ANY,
+            Type::Any | Type::Unknown | Type::Never => TypeId::ANY,
+            // The methods of primitives, arrays and functions, and the members of unions
+            _ => self.unknown_type(),
+        }
+    }
+
+    fn computed_member_type(&mut self, member: &ComputedMemberExpression<'a>) -> TypeId {
+        let object = self.expression_type(&member.object, None);
+        let object = self.types.base_constraint(object);
+        match (self.types.get(object), member.expression.get_inner_expression()) {
+            (Type::Array(element), _) => *element,
+            (Type::Tuple(elements), Expression::NumericLiteral(index)) => {
+                #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let index = index.value as usize;
+                elements.get(index).copied().unwrap_or(TypeId::ANY)
+            }
+            (Type::Object(object), Expression::StringLiteral(name)) => {
+                object.property(&name.value).map_or(TypeId::ANY, |property| property.type_id)
+            }
+            (Type::Any | Type::Unknown | Type::Never, _) => TypeId::ANY,
+            _ => self.unknown_type(),
+        }
+    }
+
+    /// The type of `left + right`.
+    fn addition_type(&mut self, left: TypeId, right: TypeId) -> TypeId {
+        let left = self.types.widen(left);
+        let right = self.types.widen(right);
+        match (left, right) {
+            (TypeId::ANY, _) | (_, TypeId::ANY) => TypeId::ANY,
+            (TypeId::STRING, _) | (_, TypeId::STRING) => TypeId::STRING,
+            (TypeId::NUMBER, TypeId::NUMBER) => TypeId::NUMBER,
+            (TypeId::BIGINT, TypeId::BIGINT) => TypeId::BIGINT,
+            _ => TypeId::ANY,
+        }
+    }
+
+    /// The type of an arithmetic or bitwise operation, which is `bigint` for `bigint` operands.
+    fn numeric_type(&mut self, left: TypeId, right: TypeId) -> TypeId {
+        let left = self.types.widen(left);
+        let right = self.types.widen(right);
+        match (left, right) {
+            (TypeId::BIGINT, TypeId::BIGINT) => TypeId::BIGINT,
+            (TypeId::BIGINT | TypeId::ANY, _) | (_, TypeId::BIGINT | TypeId::ANY) => TypeId::ANY,
+            _ => TypeId::NUMBER,
+        }
+    }
+}
+
+/// Whether `pattern` is the identifier which declares `symbol_id`, rather than a destructuring
+/// pattern containing it.
+fn binds_symbol(pattern: &BindingPattern, symbol_id: SymbolId) -> bool {
+    matches!(pattern, BindingPattern::BindingIdentifier(id) if id.symbol_id.get() == Some(symbol_id))
+}
+
+/// Collects the types of the values returned from a function body, excluding nested functions.
+struct ReturnTypes<'v, 'a, 'c> {
+    checker: &'v mut TypeCheckerVisitor<'a, 'c>,
+    types: Vec<TypeId>,
+    has_empty_return: bool,
+}
+
+impl<'v, 'a, 'c> ReturnTypes<'v, 'a, 'c> {
+    fn new(checker: &'v mut TypeCheckerVisitor<'a, 'c>) -> Self {
+        Self { checker, types: vec![], has_empty_return: false }
+    }
+
+    /// The inferred return type: `void` if no value is returned.
+    fn return_type(mut self) -> TypeId {
+        if self.types.is_empty() {
+            return TypeId::VOID;
+        }
+        if self.has_empty_return {
+            self.types.push(TypeId::UNDEFINED);
+        }
+        self.checker.types.union(self.types)
+    }
+}
+
+impl<'a> Visit<'a> for ReturnTypes<'_, 'a, '_> {
+    fn visit_return_statement(&mut self, it: &ReturnStatement<'a>) {
+        match &it.argument {
+            Some(argument) => {
+                let type_id = self.checker.expression_type(argument, None);
+                let type_id = self.checker.types.widen(type_id);
+                self.types.push(type_id);
+            }
+            None => self.has_empty_return = true,
+        }
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+}
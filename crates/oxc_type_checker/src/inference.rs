@@ -0,0 +1,344 @@
+//! Inference of type arguments, for calls to generic functions and for the `infer` declarations
+//! of conditional types.
+//!
+//! As in TypeScript, the types of the arguments are matched structurally against the types of the
+//! parameters, collecting candidates for each type parameter, which are then combined: literals
+//! are widened unless the type parameter is constrained to a primitive or is the return type, and
+//! several candidates resolve to their common supertype.
+
+use crate::{
+    instantiation::Substitution,
+    types::{ConditionalType, Type, TypeId, Types},
+};
+
+/// How deeply inference may recurse into the structure of types.
+const MAX_INFERENCE_DEPTH: u32 = 100;
+
+/// The candidates collected for each type parameter being inferred.
+pub struct Inferences {
+    params: Box<[TypeId]>,
+    /// Candidates from covariant positions, such as the type of a parameter.
+    covariant: Vec<Vec<TypeId>>,
+    /// Candidates from contravariant positions, such as the parameters of a callback.
+    contravariant: Vec<Vec<TypeId>>,
+}
+
+impl Inferences {
+    pub fn new(params: &[TypeId]) -> Self {
+        Self {
+            params: params.into(),
+            covariant: vec![vec![]; params.len()],
+            contravariant: vec![vec![]; params.len()],
+        }
+    }
+
+    fn add(&mut self, index: usize, candidate: TypeId, contravariant: bool) {
+        if !contravariant {
+            self.covariant[index].push(candidate);
+        } else if candidate != TypeId::ANY {
+            // Unannotated callback parameters are `any`, which says nothing about what they are
+            // passed
+            self.contravariant[index].push(candidate);
+        }
+    }
+}
+
+impl Types {
+    /// Collect candidates for the type parameters of `inferences` from a value of type `source`
+    /// being assigned to `target`.
+    pub(crate) fn infer_from(
+        &mut self,
+        source: TypeId,
+        target: TypeId,
+        inferences: &mut Inferences,
+    ) {
+        self.infer_at_depth(source, target, inferences, false, 0);
+    }
+
+    fn infer_at_depth(
+        &mut self,
+        source: TypeId,
+        target: TypeId,
+        inferences: &mut Inferences,
+        contravariant: bool,
+        depth: u32,
+    ) {
+        if depth > MAX_INFERENCE_DEPTH {
+            return;
+        }
+        let depth = depth + 1;
+        if let Some(index) = inferences.params.iter().position(|&param| param == target) {
+            inferences.add(index, source, contravariant);
+            return;
+        }
+        if source == TypeId::ANY {
+            let mut params = vec![];
+            self.collect_type_parameters(target, &mut params);
+            for param in params {
+                if let Some(index) = inferences.params.iter().position(|&p| p == param) {
+                    inferences.add(index, TypeId::ANY, false);
+                }
+            }
+            return;
+        }
+        match (self.get(source).clone(), self.get(target).clone()) {
+            (_, Type::Union(targets)) => {
+                let (naked, others): (Vec<TypeId>, Vec<TypeId>) =
+                    targets.iter().partition(|target| inferences.params.contains(target));
+                for &other in &others {
+                    self.infer_at_depth(source, other, inferences, contravariant, depth);
+                }
+                // `T` in `T | undefined` is inferred from the members the other members do not
+                // match
+                if let [naked] = naked.as_slice() {
+                    let remaining = self
+                        .union_members(source)
+                        .into_iter()
+                        .filter(|&member| {
+                            !others.iter().any(|&other| {
+                                !self.has_free_type_parameters(other, &[])
+                                    && self.is_assignable(member, other)
+                            })
+                        })
+                        .collect::<Vec<_>>();
+                    if !remaining.is_empty() {
+                        let remaining = self.union(remaining);
+                        self.infer_at_depth(remaining, *naked, inferences, contravariant, depth);
+                    }
+                }
+            }
+            (Type::Union(sources), _) => {
+                for source in sources {
+                    self.infer_at_depth(source, target, inferences, contravariant, depth);
+                }
+            }
+            (Type::Object(source), Type::Object(target)) => {
+                for property in &target.properties {
+                    if let Some(source_property) = source.property(&property.name) {
+                        self.infer_at_depth(
+                            source_property.type_id,
+                            property.type_id,
+                            inferences,
+                            contravariant,
+                            depth,
+                        );
+                    }
+                }
+            }
+            (Type::Array(source), Type::Array(target))
+            | (Type::Promise(source), Type::Promise(target)) => {
+                self.infer_at_depth(source, target, inferences, contravariant, depth);
+            }
+            (Type::Tuple(elements), Type::Array(target)) => {
+                for element in elements {
+                    self.infer_at_depth(element, target, inferences, contravariant, depth);
+                }
+            }
+            (Type::Tuple(sources), Type::Tuple(targets)) if sources.len() == targets.len() => {
+                for (source, target) in sources.into_iter().zip(targets) {
+                    self.infer_at_depth(source, target, inferences, contravariant, depth);
+                }
+            }
+            (Type::Function(source), Type::Function(target)) => {
+                for (source_param, target_param) in source.params.iter().zip(&target.params) {
+                    self.infer_at_depth(
+                        source_param.type_id,
+                        target_param.type_id,
+                        inferences,
+                        !contravariant,
+                        depth,
+                    );
+                }
+                if let Some(rest) = &target.rest {
+                    let remaining = source.params.get(target.params.len()..).unwrap_or_default();
+                    if let Some(index) = inferences.params.iter().position(|&p| p == rest.type_id) {
+                        // `...args: infer P` is the tuple of the remaining parameters
+                        if source.rest.is_some() || remaining.iter().any(|param| param.optional) {
+                            // Optional and rest elements of tuples are not represented yet
+                            inferences.add(index, TypeId::ANY, false);
+                        } else {
+                            let elements = remaining.iter().map(|param| param.type_id).collect();
+                            let tuple = self.intern(Type::Tuple(elements));
+                            inferences.add(index, tuple, !contravariant);
+                        }
+                    } else if let Type::Array(element) = self.get(rest.type_id).clone() {
+                        for param in remaining {
+                            self.infer_at_depth(
+                                param.type_id,
+                                element,
+                                inferences,
+                                !contravariant,
+                                depth,
+                            );
+                        }
+                    }
+                }
+                self.infer_at_depth(
+                    source.return_type,
+                    target.return_type,
+                    inferences,
+                    contravariant,
+                    depth,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn union_members(&self, type_id: TypeId) -> Vec<TypeId> {
+        match self.get(type_id) {
+            Type::Union(members) => members.to_vec(),
+            _ => vec![type_id],
+        }
+    }
+
+    /// The type parameters `type_id` refers to.
+    fn collect_type_parameters(&self, type_id: TypeId, params: &mut Vec<TypeId>) {
+        let mut collect = |type_id| self.collect_type_parameters(type_id, params);
+        match self.get(type_id) {
+            Type::TypeParameter(_) => params.push(type_id),
+            Type::Union(members) | Type::Tuple(members) => {
+                members.iter().copied().for_each(collect);
+            }
+            Type::Object(object) => {
+                object.properties.iter().for_each(|property| collect(property.type_id));
+            }
+            Type::Array(element) | Type::Promise(element) | Type::KeyOf(element) => {
+                collect(*element);
+            }
+            Type::IndexedAccess(object, index) => {
+                collect(*object);
+                collect(*index);
+            }
+            Type::Function(function) => {
+                function
+                    .params
+                    .iter()
+                    .chain(&function.rest)
+                    .for_each(|param| collect(param.type_id));
+                collect(function.return_type);
+            }
+            Type::Conditional(conditional) => {
+                for type_id in [
+                    conditional.check,
+                    conditional.extends,
+                    conditional.true_type,
+                    conditional.false_type,
+                ] {
+                    collect(type_id);
+                }
+            }
+            Type::Mapped(mapped) => {
+                collect(mapped.constraint);
+                collect(mapped.template);
+            }
+            _ => {}
+        }
+    }
+
+    /// The type arguments inferred from the collected candidates.
+    ///
+    /// `return_type` is the return type of the generic function being called, whose type
+    /// parameters' literal candidates are widened unless the type parameter is returned as is.
+    /// The `infer` declarations of conditional types are not widened.
+    pub(crate) fn inferred_type_arguments(
+        &mut self,
+        inferences: &Inferences,
+        return_type: Option<TypeId>,
+    ) -> Substitution {
+        let mut substitution = Substitution::default();
+        for (index, &param) in inferences.params.iter().enumerate() {
+            let Type::TypeParameter(type_param) = self.get(param).clone() else { continue };
+            let constraint =
+                type_param.constraint.map(|constraint| self.instantiate(constraint, &substitution));
+            let covariant = &inferences.covariant[index];
+            let contravariant = &inferences.contravariant[index];
+            let mut inferred = if covariant.contains(&TypeId::ANY) {
+                TypeId::ANY
+            } else if !covariant.is_empty() {
+                let widen = return_type.is_some_and(|return_type| {
+                    !self.is_at_top_level(param, return_type)
+                        && !constraint.is_some_and(|constraint| self.is_primitive_like(constraint))
+                });
+                let candidates = if widen {
+                    covariant.iter().map(|&candidate| self.widen(candidate)).collect()
+                } else {
+                    covariant.clone()
+                };
+                self.common_supertype(&candidates)
+            } else if let Some((&first, rest)) = contravariant.split_first() {
+                // The common subtype
+                rest.iter().fold(first, |subtype, &candidate| {
+                    if self.is_assignable(candidate, subtype) { candidate } else { subtype }
+                })
+            } else {
+                type_param
+                    .default
+                    .map_or(TypeId::UNKNOWN, |default| self.instantiate(default, &substitution))
+            };
+            if let Some(constraint) = constraint
+                && !self.is_assignable(inferred, constraint)
+            {
+                inferred = constraint;
+            }
+            substitution.insert(param, inferred);
+        }
+        substitution
+    }
+
+    /// The union of literals of the same primitive, e.g. `1 | 2`, or else the first candidate the
+    /// others are assignable to.
+    fn common_supertype(&mut self, candidates: &[TypeId]) -> TypeId {
+        let literal_base = |types: &Self, candidate: TypeId| match types.get(candidate) {
+            Type::BooleanLiteral(_) | Type::Boolean => Some(TypeId::BOOLEAN),
+            Type::NumberLiteral(_) => Some(TypeId::NUMBER),
+            Type::StringLiteral(_) => Some(TypeId::STRING),
+            Type::BigIntLiteral(_) => Some(TypeId::BIGINT),
+            _ => None,
+        };
+        let bases = candidates.iter().map(|&candidate| literal_base(self, candidate));
+        if let Some(Some(base)) = bases.clone().next()
+            && bases.clone().all(|other| other == Some(base))
+        {
+            return self.union(candidates.iter().copied());
+        }
+        let (&first, rest) = candidates.split_first().expect("at least one candidate");
+        rest.iter().fold(first, |supertype, &candidate| {
+            if self.is_assignable(supertype, candidate) { candidate } else { supertype }
+        })
+    }
+
+    /// Whether `type_id` is `param` or a union including it.
+    fn is_at_top_level(&self, param: TypeId, type_id: TypeId) -> bool {
+        type_id == param
+            || matches!(self.get(type_id), Type::Union(members) if members.contains(&param))
+    }
+
+    /// Whether a constraint includes primitive types, such as `string` in `T extends string`.
+    fn is_primitive_like(&self, type_id: TypeId) -> bool {
+        match self.get(type_id) {
+            Type::Boolean
+            | Type::Number
+            | Type::String
+            | Type::BigInt
+            | Type::Symbol
+            | Type::BooleanLiteral(_)
+            | Type::NumberLiteral(_)
+            | Type::StringLiteral(_)
+            | Type::BigIntLiteral(_)
+            | Type::KeyOf(_) => true,
+            Type::Union(members) => members.iter().any(|&member| self.is_primitive_like(member)),
+            _ => false,
+        }
+    }
+
+    /// The types inferred for the `infer` declarations of a conditional type from its check type.
+    pub(crate) fn infer_conditional(&mut self, conditional: &ConditionalType) -> Substitution {
+        if conditional.infer_params.is_empty() {
+            return Substitution::default();
+        }
+        let mut inferences = Inferences::new(&conditional.infer_params);
+        self.infer_from(conditional.check, conditional.extends, &mut inferences);
+        self.inferred_type_arguments(&inferences, None)
+    }
+}
@@ -0,0 +1,564 @@
+//! Instantiation of generic types, and evaluation of the type operators whose operands may be
+//! generic: `keyof`, indexed access, conditional types and mapped types.
+//!
+//! An operator whose operands still refer to type parameters is kept as a deferred type, e.g.
+//! [`Type::KeyOf`], and evaluated once [`Types::instantiate`] substitutes the type parameters.
+
+use rustc_hash::FxHashMap;
+
+use oxc_str::CompactStr;
+use oxc_syntax::number::ToJsString;
+
+use crate::types::{
+    ConditionalType, FunctionType, MappedModifier, MappedType, NumberLiteral, ObjectType,
+    Parameter, Property, Type, TypeId, Types,
+};
+
+/// Type arguments, keyed by the type parameters they replace.
+pub type Substitution = FxHashMap<TypeId, TypeId>;
+
+/// How deeply instantiations may nest, like TypeScript's limit which reports "Type instantiation
+/// is excessively deep and possibly infinite". Deeper instantiations are `any`.
+const MAX_INSTANTIATION_DEPTH: u32 = 100;
+
+impl Types {
+    /// `type_id` with the type parameters in `substitution` replaced by their type arguments, and
+    /// the deferred types which no longer refer to type parameters evaluated.
+    pub fn instantiate(&mut self, type_id: TypeId, substitution: &Substitution) -> TypeId {
+        self.instantiate_at_depth(type_id, substitution, 0)
+    }
+
+    /// A generic type alias or interface instantiated with `arguments`, which the defaults of
+    /// the type parameters complete. A type which is not generic is returned as is, if it is not
+    /// given arguments.
+    ///
+    /// Too many or too few arguments are errors, and `any`.
+    pub fn instantiate_generic(&mut self, type_id: TypeId, arguments: &[TypeId]) -> TypeId {
+        let Type::Generic(generic) = self.get(type_id).clone() else {
+            return if arguments.is_empty() { type_id } else { TypeId::ANY };
+        };
+        if arguments.len() > generic.params.len() {
+            return TypeId::ANY;
+        }
+        let mut substitution = Substitution::default();
+        for (index, &param) in generic.params.iter().enumerate() {
+            let default = match self.get(param) {
+                Type::TypeParameter(param) => param.default,
+                _ => None,
+            };
+            let argument = match (arguments.get(index), default) {
+                (Some(&argument), _) => argument,
+                // Defaults may refer to the preceding type parameters
+                (None, Some(default)) => self.instantiate(default, &substitution),
+                (None, None) => return TypeId::ANY,
+            };
+            substitution.insert(param, argument);
+        }
+        self.instantiate(generic.body, &substitution)
+    }
+
+    fn instantiate_at_depth(
+        &mut self,
+        type_id: TypeId,
+        substitution: &Substitution,
+        depth: u32,
+    ) -> TypeId {
+        if substitution.is_empty() {
+            return type_id;
+        }
+        if depth > MAX_INSTANTIATION_DEPTH {
+            return TypeId::ANY;
+        }
+        let depth = depth + 1;
+        let ty = match self.get(type_id).clone() {
+            Type::TypeParameter(_) => {
+                return substitution.get(&type_id).copied().unwrap_or(type_id);
+            }
+            Type::Union(members) => {
+                let members = self.instantiate_all(&members, substitution, depth);
+                return self.union(members);
+            }
+            Type::Object(object) => Type::Object(ObjectType {
+                properties: object
+                    .properties
+                    .iter()
+                    .map(|property| Property {
+                        type_id: self.instantiate_at_depth(property.type_id, substitution, depth),
+                        ..property.clone()
+                    })
+                    .collect(),
+            }),
+            Type::Array(element) => {
+                Type::Array(self.instantiate_at_depth(element, substitution, depth))
+            }
+            Type::Promise(value) => {
+                Type::Promise(self.instantiate_at_depth(value, substitution, depth))
+            }
+            Type::Tuple(elements) => {
+                Type::Tuple(self.instantiate_all(&elements, substitution, depth).into())
+            }
+            Type::Function(function) => {
+                let mut instantiate_param = |param: &Parameter| Parameter {
+                    type_id: self.instantiate_at_depth(param.type_id, substitution, depth),
+                    ..param.clone()
+                };
+                let params = function.params.iter().map(&mut instantiate_param).collect();
+                let rest = function.rest.as_ref().map(&mut instantiate_param);
+                Type::Function(FunctionType {
+                    type_params: function.type_params,
+                    params,
+                    rest,
+                    return_type: self.instantiate_at_depth(
+                        function.return_type,
+                        substitution,
+                        depth,
+                    ),
+                })
+            }
+            Type::KeyOf(target) => {
+                let target = self.instantiate_at_depth(target, substitution, depth);
+                return self.key_of(target);
+            }
+            Type::IndexedAccess(object, index) => {
+                let object = self.instantiate_at_depth(object, substitution, depth);
+                let index = self.instantiate_at_depth(index, substitution, depth);
+                return self.indexed_access(object, index);
+            }
+            Type::Conditional(conditional) => {
+                return self.instantiate_conditional(&conditional, substitution, depth);
+            }
+            Type::Mapped(mapped) => {
+                return self.instantiate_mapped(&mapped, substitution, depth);
+            }
+            _ => return type_id,
+        };
+        self.intern(ty)
+    }
+
+    fn instantiate_all(
+        &mut self,
+        type_ids: &[TypeId],
+        substitution: &Substitution,
+        depth: u32,
+    ) -> Vec<TypeId> {
+        type_ids
+            .iter()
+            .map(|&type_id| self.instantiate_at_depth(type_id, substitution, depth))
+            .collect()
+    }
+
+    /// Whether `type_id` refers to type parameters other than those in `bound`, such as those of
+    /// the generic function or type it is part of.
+    pub fn has_free_type_parameters(&self, type_id: TypeId, bound: &[TypeId]) -> bool {
+        let is_free = |type_id: TypeId| self.has_free_type_parameters(type_id, bound);
+        match self.get(type_id) {
+            Type::TypeParameter(_) => !bound.contains(&type_id),
+            Type::Union(members) | Type::Tuple(members) => members.iter().copied().any(is_free),
+            Type::Object(object) => {
+                object.properties.iter().any(|property| is_free(property.type_id))
+            }
+            Type::Array(element) | Type::Promise(element) | Type::KeyOf(element) => {
+                is_free(*element)
+            }
+            Type::IndexedAccess(object, index) => is_free(*object) || is_free(*index),
+            Type::Function(function) => {
+                let bound = [bound, &function.type_params].concat();
+                function
+                    .params
+                    .iter()
+                    .chain(&function.rest)
+                    .map(|param| param.type_id)
+                    .chain([function.return_type])
+                    .any(|type_id| self.has_free_type_parameters(type_id, &bound))
+            }
+            Type::Conditional(conditional) => {
+                let bound_in_extends = [bound, &conditional.infer_params].concat();
+                is_free(conditional.check)
+                    || is_free(conditional.false_type)
+                    || [conditional.extends, conditional.true_type]
+                        .into_iter()
+                        .any(|type_id| self.has_free_type_parameters(type_id, &bound_in_extends))
+            }
+            Type::Mapped(mapped) => {
+                let bound_in_template = [bound, &[mapped.param]].concat();
+                is_free(mapped.constraint)
+                    || mapped
+                        .name_type
+                        .into_iter()
+                        .chain([mapped.template])
+                        .any(|type_id| self.has_free_type_parameters(type_id, &bound_in_template))
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether a type is a type parameter, a deferred type operator, or a union with such a
+    /// member, whose values are not known until it is instantiated.
+    pub fn is_instantiable(&self, type_id: TypeId) -> bool {
+        match self.get(type_id) {
+            Type::TypeParameter(_)
+            | Type::KeyOf(_)
+            | Type::IndexedAccess(..)
+            | Type::Conditional(_)
+            | Type::Mapped(_) => true,
+            Type::Union(members) => members.iter().any(|&member| self.is_instantiable(member)),
+            _ => false,
+        }
+    }
+
+    /// The type with type parameters replaced by their constraints, or `unknown` if they have
+    /// none, which is what values of a generic type are known to be. Deferred type operators are
+    /// `any`.
+    pub fn base_constraint(&mut self, type_id: TypeId) -> TypeId {
+        self.base_constraint_at_depth(type_id, 0)
+    }
+
+    fn base_constraint_at_depth(&mut self, type_id: TypeId, depth: u32) -> TypeId {
+        if depth > MAX_INSTANTIATION_DEPTH {
+            return TypeId::ANY;
+        }
+        match self.get(type_id).clone() {
+            Type::TypeParameter(param) => param.constraint.map_or(TypeId::UNKNOWN, |constraint| {
+                self.base_constraint_at_depth(constraint, depth + 1)
+            }),
+            Type::KeyOf(_) => self.union([TypeId::STRING, TypeId::NUMBER, TypeId::SYMBOL]),
+            Type::IndexedAccess(..) | Type::Conditional(_) | Type::Mapped(_) => TypeId::ANY,
+            Type::Union(members) if self.is_instantiable(type_id) => {
+                let members = members
+                    .iter()
+                    .map(|&member| self.base_constraint_at_depth(member, depth + 1))
+                    .collect::<Vec<_>>();
+                self.union(members)
+            }
+            _ => type_id,
+        }
+    }
+
+    /// `keyof target`: the union of the names of its properties.
+    ///
+    /// Keys of types other than object types, such as the methods of arrays and primitives, are
+    /// not represented yet and are `any`.
+    pub fn key_of(&mut self, target: TypeId) -> TypeId {
+        if self.has_free_type_parameters(target, &[]) {
+            return self.intern(Type::KeyOf(target));
+        }
+        match self.get(target).clone() {
+            Type::Any | Type::Never => self.union([TypeId::STRING, TypeId::NUMBER, TypeId::SYMBOL]),
+            Type::Object(object) => {
+                let keys = object
+                    .properties
+                    .iter()
+                    .map(|property| self.intern(Type::StringLiteral(property.name.clone())))
+                    .collect::<Vec<_>>();
+                self.union(keys)
+            }
+            // The keys every member has
+            Type::Union(members) => {
+                let keys = members.iter().map(|&member| self.key_of(member)).collect::<Vec<_>>();
+                let Some((&first, rest)) = keys.split_first() else { return TypeId::NEVER };
+                if keys.contains(&TypeId::ANY) {
+                    return TypeId::ANY;
+                }
+                let common = self
+                    .unit_members(first)
+                    .into_iter()
+                    .filter(|&key| rest.iter().all(|&keys| self.unit_members(keys).contains(&key)))
+                    .collect::<Vec<_>>();
+                self.union(common)
+            }
+            _ => TypeId::ANY,
+        }
+    }
+
+    fn unit_members(&self, type_id: TypeId) -> Vec<TypeId> {
+        match self.get(type_id) {
+            Type::Union(members) => members.to_vec(),
+            Type::Never => vec![],
+            _ => vec![type_id],
+        }
+    }
+
+    /// `object[index]`, the type of the property `index` names.
+    pub fn indexed_access(&mut self, object: TypeId, index: TypeId) -> TypeId {
+        if self.has_free_type_parameters(object, &[]) || self.has_free_type_parameters(index, &[]) {
+            return self.intern(Type::IndexedAccess(object, index));
+        }
+        if let Type::Union(members) = self.get(index).clone() {
+            let types = members
+                .iter()
+                .map(|&member| self.indexed_access(object, member))
+                .collect::<Vec<_>>();
+            return self.union(types);
+        }
+        match (self.get(object).clone(), self.get(index)) {
+            (Type::Union(members), _) => {
+                let types = members
+                    .iter()
+                    .map(|&member| self.indexed_access(member, index))
+                    .collect::<Vec<_>>();
+                self.union(types)
+            }
+            (Type::Object(object), Type::StringLiteral(name)) => match object.property(name) {
+                Some(property) if property.optional => {
+                    let type_id = property.type_id;
+                    self.union([type_id, TypeId::UNDEFINED])
+                }
+                Some(property) => property.type_id,
+                None => TypeId::ANY,
+            },
+            (Type::Array(element), Type::Number | Type::NumberLiteral(_)) => element,
+            (Type::Tuple(elements), Type::Number) => self.union(elements.iter().copied()),
+            (Type::Tuple(elements), Type::NumberLiteral(number)) => {
+                #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let index = number.value() as usize;
+                elements.get(index).copied().unwrap_or(TypeId::ANY)
+            }
+            _ => TypeId::ANY,
+        }
+    }
+
+    /// Evaluate a conditional type, unless its check or extends type are generic.
+    pub fn conditional(&mut self, conditional: ConditionalType) -> TypeId {
+        self.evaluate_conditional(conditional, 0)
+    }
+
+    fn instantiate_conditional(
+        &mut self,
+        conditional: &ConditionalType,
+        substitution: &Substitution,
+        depth: u32,
+    ) -> TypeId {
+        let check = self.instantiate_at_depth(conditional.check, substitution, depth);
+        // `T extends U ? X : Y` is evaluated for each member of a union `T` is instantiated with
+        if conditional.distributive {
+            match self.get(check).clone() {
+                Type::Never => return TypeId::NEVER,
+                Type::Union(members) => {
+                    let mut results = Vec::with_capacity(members.len());
+                    for member in members {
+                        let mut substitution = substitution.clone();
+                        substitution.insert(conditional.check, member);
+                        results.push(self.instantiate_conditional_branches(
+                            conditional,
+                            member,
+                            &substitution,
+                            depth,
+                        ));
+                    }
+                    return self.union(results);
+                }
+                _ => {}
+            }
+        }
+        self.instantiate_conditional_branches(conditional, check, substitution, depth)
+    }
+
+    fn instantiate_conditional_branches(
+        &mut self,
+        conditional: &ConditionalType,
+        check: TypeId,
+        substitution: &Substitution,
+        depth: u32,
+    ) -> TypeId {
+        let extends = self.instantiate_at_depth(conditional.extends, substitution, depth);
+        let true_type = self.instantiate_at_depth(conditional.true_type, substitution, depth);
+        let false_type = self.instantiate_at_depth(conditional.false_type, substitution, depth);
+        let conditional = ConditionalType {
+            check,
+            extends,
+            true_type,
+            false_type,
+            infer_params: conditional.infer_params.clone(),
+            distributive: conditional.distributive
+                && matches!(self.get(check), Type::TypeParameter(_)),
+        };
+        self.evaluate_conditional(conditional, depth)
+    }
+
+    fn evaluate_conditional(&mut self, conditional: ConditionalType, depth: u32) -> TypeId {
+        let ConditionalType { check, extends, true_type, false_type, .. } = conditional;
+        if self.has_free_type_parameters(check, &[])
+            || self.has_free_type_parameters(extends, &conditional.infer_params)
+        {
+            return self.intern(Type::Conditional(Box::new(conditional)));
+        }
+        let substitution = self.infer_conditional(&conditional);
+        let extends = self.instantiate_at_depth(extends, &substitution, depth);
+        let true_type = self.instantiate_at_depth(true_type, &substitution, depth);
+        if check == TypeId::ANY {
+            return self.union([true_type, false_type]);
+        }
+        if self.is_assignable(check, extends) { true_type } else { false_type }
+    }
+
+    /// Evaluate a mapped type, unless the keys it maps are generic.
+    pub fn mapped(&mut self, mapped: MappedType) -> TypeId {
+        self.evaluate_mapped(mapped, 0)
+    }
+
+    fn instantiate_mapped(
+        &mut self,
+        mapped: &MappedType,
+        substitution: &Substitution,
+        depth: u32,
+    ) -> TypeId {
+        let modifiers_source = mapped
+            .modifiers_source
+            .map(|source| (source, self.instantiate_at_depth(source, substitution, depth)));
+        // Homomorphic mapped types are mapped over each member of a union, and over the
+        // elements of arrays and tuples
+        if let Some((source, instantiated)) = modifiers_source
+            && matches!(self.get(source), Type::TypeParameter(_))
+            && *self.get(mapped.constraint) == Type::KeyOf(source)
+        {
+            match self.get(instantiated).clone() {
+                Type::Union(members) => {
+                    let mut results = Vec::with_capacity(members.len());
+                    for member in members {
+                        let mut substitution = substitution.clone();
+                        substitution.insert(source, member);
+                        results.push(self.instantiate_mapped(mapped, &substitution, depth));
+                    }
+                    return self.union(results);
+                }
+                Type::Array(_) | Type::Tuple(_) => {
+                    return self.map_elements(mapped, instantiated, substitution, depth);
+                }
+                _ => {}
+            }
+        }
+        let mapped = MappedType {
+            param: mapped.param,
+            constraint: self.instantiate_at_depth(mapped.constraint, substitution, depth),
+            name_type: mapped
+                .name_type
+                .map(|name_type| self.instantiate_at_depth(name_type, substitution, depth)),
+            template: self.instantiate_at_depth(mapped.template, substitution, depth),
+            optional: mapped.optional,
+            modifiers_source: modifiers_source.map(|(_, instantiated)| instantiated),
+        };
+        self.evaluate_mapped(mapped, depth)
+    }
+
+    /// A homomorphic mapped type applied to the elements of an array or tuple, e.g.
+    /// `(number | undefined)[]` for `Partial<number[]>`.
+    fn map_elements(
+        &mut self,
+        mapped: &MappedType,
+        source: TypeId,
+        substitution: &Substitution,
+        depth: u32,
+    ) -> TypeId {
+        let map_element = |types: &mut Self, key: TypeId| {
+            let mut substitution = substitution.clone();
+            substitution.insert(mapped.param, key);
+            let element = types.instantiate_at_depth(mapped.template, &substitution, depth);
+            match mapped.optional {
+                MappedModifier::Add => types.union([element, TypeId::UNDEFINED]),
+                MappedModifier::Remove => types.without_undefined(element),
+                MappedModifier::Preserve => element,
+            }
+        };
+        let ty = match self.get(source).clone() {
+            Type::Tuple(elements) => {
+                let elements = (0..elements.len())
+                    .map(|index| {
+                        #[expect(clippy::cast_precision_loss)]
+                        let key =
+                            self.intern(Type::NumberLiteral(NumberLiteral::new(index as f64)));
+                        map_element(self, key)
+                    })
+                    .collect();
+                Type::Tuple(elements)
+            }
+            _ => Type::Array(map_element(self, TypeId::NUMBER)),
+        };
+        self.intern(ty)
+    }
+
+    fn evaluate_mapped(&mut self, mapped: MappedType, depth: u32) -> TypeId {
+        if self.has_free_type_parameters(mapped.constraint, &[])
+            || mapped
+                .modifiers_source
+                .is_some_and(|source| self.has_free_type_parameters(source, &[]))
+        {
+            return self.intern(Type::Mapped(Box::new(mapped)));
+        }
+        let source = mapped.modifiers_source.and_then(|source| match self.get(source) {
+            Type::Object(object) => Some(object.clone()),
+            _ => None,
+        });
+        let mut properties: Vec<Property> = vec![];
+        for key in self.unit_members(mapped.constraint) {
+            let Some(name) = self.property_name(key) else {
+                // Index signatures are not represented yet
+                return TypeId::ANY;
+            };
+            let mut substitution = Substitution::default();
+            substitution.insert(mapped.param, key);
+            let names = match mapped.name_type {
+                Some(name_type) => {
+                    let name_type = self.instantiate_at_depth(name_type, &substitution, depth);
+                    let mut names = vec![];
+                    for name in self.unit_members(name_type) {
+                        let Some(name) = self.property_name(name) else { return TypeId::ANY };
+                        names.push(name);
+                    }
+                    names
+                }
+                None => vec![name.clone()],
+            };
+            let source_property = source.as_ref().and_then(|source| source.property(&name));
+            let optional = match mapped.optional {
+                MappedModifier::Add => true,
+                MappedModifier::Remove => false,
+                MappedModifier::Preserve => {
+                    source_property.is_some_and(|property| property.optional)
+                }
+            };
+            let mut type_id = self.instantiate_at_depth(mapped.template, &substitution, depth);
+            // Optional properties hold the type without the `undefined` they may be, as do
+            // the properties `-?` makes required
+            if optional
+                || (mapped.optional == MappedModifier::Remove
+                    && source_property.is_some_and(|property| property.optional))
+            {
+                type_id = self.without_undefined(type_id);
+            }
+            for name in names {
+                match properties.iter_mut().find(|property| property.name == name) {
+                    Some(existing) => {
+                        existing.type_id = self.union([existing.type_id, type_id]);
+                    }
+                    None => properties.push(Property { name, type_id, optional }),
+                }
+            }
+        }
+        self.intern(Type::Object(ObjectType { properties: properties.into_boxed_slice() }))
+    }
+
+    /// The name of the property a key type names, e.g. `a` for `"a"` and `1` for `1`.
+    fn property_name(&self, key: TypeId) -> Option<CompactStr> {
+        match self.get(key) {
+            Type::StringLiteral(name) => Some(name.clone()),
+            Type::NumberLiteral(number) => Some(CompactStr::from(number.value().to_js_string())),
+            _ => None,
+        }
+    }
+
+    /// The type without `undefined`.
+    fn without_undefined(&mut self, type_id: TypeId) -> TypeId {
+        match self.get(type_id) {
+            Type::Undefined => TypeId::NEVER,
+            Type::Union(members) => {
+                let members = members
+                    .iter()
+                    .copied()
+                    .filter(|&member| member != TypeId::UNDEFINED)
+                    .collect::<Vec<_>>();
+                self.union(members)
+            }
+            _ => type_id,
+        }
+    }
+}
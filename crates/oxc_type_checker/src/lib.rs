@@ -2,14 +2,45 @@
 //!
 //! An **experimental**, work-in-progress type checker for JavaScript and TypeScript.
 //!
-//! This crate is intentionally a thin scaffold. It does *not* type check anything yet.
-//! Instead it provides the plumbing you need to start experimenting with a type checker
-//! built on top of oxc:
+//! Within a single file, the checker infers the types of variables, parameters and expressions
+//! from their annotations and initializers, and reports TypeScript's assignability errors
+//! (`TS2322`, `TS2345`, ...) for variable declarations, assignments, `return` statements and
+//! call arguments. Types are interned in a [`Types`] arena, and can be primitives, literals,
+//! unions, object types, arrays, tuples, functions and promises, which `async` functions return
+//! and `await` unwraps. Anything else, such as intersections or classes, is `any`, so that only
+//! errors which TypeScript would also report are reported.
+//!
+//! Generic functions, type aliases and interfaces are instantiated with explicit type arguments
+//! or with those inferred from the arguments of a call. `keyof`, indexed access types,
+//! conditional types with `infer` and mapped types are evaluated once their operands are known,
+//! so that utility types such as `Partial`, `Pick` and `ReturnType` work as in TypeScript.
+//! Recursive type aliases and instantiations nested too deeply are `any`.
+//!
+//! References to variables of a union or `unknown` type are narrowed by control flow, as in
+//! `if (typeof x === "string") { ... }`, when the control flow graph is built with
+//! [`SemanticBuilder::with_cfg`]. Without it, such references are not checked.
+//!
+//! Globals such as `Math` and `parseInt` get their types from TypeScript's `lib.*.d.ts` files,
+//! when [`TypeCheckerOptions::lib_path`] points to them.
+//!
+//! [`TypeChecker::check`] checks a single file, in which imports are `any`. A [`ProgramChecker`]
+//! checks a set of files in parallel, and resolves their imports with `oxc_resolver` as TypeScript
+//! does, honouring `tsconfig.json`, so that imported values and types from other files and from
+//! declaration files in `node_modules` are checked.
+//!
+//! [`TypeChecker::expression_types`] records the type of every expression instead of checking
+//! them, for type-aware lint rules.
+//!
+//! The checker is built on:
 //!
 //! - a [`TypeChecker`] entry point with room for [`TypeCheckerOptions`],
 //! - an AST walk over the program via [`oxc_ast_visit::Visit`],
-//! - access to symbol and scope information through [`oxc_semantic::Semantic`], and
-//! - a [`Diagnostics`] collector plus a [`type_error`] helper for reporting problems.
+//! - access to symbol and scope information through [`oxc_semantic::Semantic`], which must be
+//!   built with [`SemanticBuilder::with_build_nodes`], and
+//! - a [`Diagnostics`] collector for reporting problems.
+//!
+//! [`SemanticBuilder::with_build_nodes`]: oxc_semantic::SemanticBuilder::with_build_nodes
+//! [`SemanticBuilder::with_cfg`]: oxc_semantic::SemanticBuilder::with_cfg
 //!
 //! ## Usage
 //!
@@ -21,22 +52,27 @@
 //! use oxc_span::SourceType;
 //!
 //! let allocator = Allocator::default();
-//! let source_text = "const x: number = 1;";
+//! let source_text = "const x: number = 1; const y: string = x;";
 //! let source_type = SourceType::ts();
 //!
 //! let parser_ret = Parser::new(&allocator, source_text, source_type).parse();
-//! let semantic_ret = SemanticBuilder::new().build(&parser_ret.program);
+//! let semantic_ret =
+//!     SemanticBuilder::new().with_build_nodes(true).with_cfg(true).build(&parser_ret.program);
 //!
 //! let checker_ret = TypeChecker::new().check(&parser_ret.program, &semantic_ret.semantic);
-//! assert!(checker_ret.diagnostics.is_empty());
+//! assert_eq!(
+//!     checker_ret.diagnostics[0].message,
+//!     "TS2322: Type 'number' is not assignable to type 'string'.",
+//! );
 //! ```
 //!
 //! ## Adding a check
 //!
 //! Type checks live in the private `TypeCheckerVisitor`. Override the relevant `visit_*`
-//! method, inspect the node (using `self.semantic` to resolve identifiers and look up
-//! symbols), push a [`type_error`] onto `self.diagnostics` when something is wrong, and
-//! remember to keep walking the subtree with the matching `walk_*` function:
+//! method, inspect the node (using `self.expression_type` and `self.annotation_type` to get
+//! types, and `self.semantic` to resolve identifiers and look up symbols), push a diagnostic
+//! onto `self.diagnostics` when something is wrong, and remember to keep walking the subtree
+//! with the matching `walk_*` function:
 //!
 //! ```ignore
 //! use oxc_ast::ast::TSTypeAliasDeclaration;
@@ -53,21 +89,69 @@
 //!
 //! See `examples/checker.rs` for a runnable end-to-end example.
 
-use oxc_ast::ast::Program;
-use oxc_ast_visit::Visit;
+use std::path::PathBuf;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_ast::ast::{
+    ArrowFunctionExpression, AssignmentExpression, AssignmentOperator, AssignmentTarget,
+    CallExpression, Function, Program, ReturnStatement, VariableDeclarator,
+};
+use oxc_ast_visit::{
+    Visit,
+    walk::{
+        walk_arrow_function_expression, walk_assignment_expression, walk_call_expression,
+        walk_function, walk_return_statement, walk_variable_declarator,
+    },
+};
 use oxc_diagnostics::Diagnostics;
 use oxc_semantic::Semantic;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{es_target::ESTarget, node::NodeId, scope::ScopeFlags, symbol::SymbolId};
 
+mod annotation;
+mod assignability;
+mod check;
 mod diagnostics;
+mod expression_types;
+mod globals;
+mod infer;
+mod inference;
+mod instantiation;
+mod modules;
+mod narrowing;
+mod program;
+mod types;
 
-pub use crate::diagnostics::type_error;
+use crate::{check::Relation, modules::ModuleContext};
+pub use crate::{
+    diagnostics::type_error,
+    expression_types::ExpressionTypes,
+    globals::Globals,
+    instantiation::Substitution,
+    program::{CheckedFile, ProgramChecker, ProgramCheckerReturn},
+    types::{
+        ConditionalType, FunctionType, GenericType, MappedModifier, MappedType, NumberLiteral,
+        ObjectType, Parameter, Property, Type, TypeId, TypeParameter, Types,
+    },
+};
 
 /// Options controlling how the [`TypeChecker`] behaves.
-///
-/// Empty for now. Add knobs here — strictness flags, the target `lib`, and so on — as
-/// the checker grows.
 #[derive(Debug, Default, Clone)]
-pub struct TypeCheckerOptions;
+pub struct TypeCheckerOptions {
+    /// Directory containing TypeScript's `lib.*.d.ts` files, such as
+    /// `node_modules/typescript/lib`.
+    ///
+    /// Without it, globals such as `Math` and `console` are `any`. See [`Globals`].
+    pub lib_path: Option<PathBuf>,
+    /// Like TypeScript's `target`, which selects the default [`lib`](Self::lib).
+    pub target: ESTarget,
+    /// Like TypeScript's `lib`, e.g. `["es2020", "dom"]`.
+    pub lib: Option<Vec<String>>,
+    /// Declaration files whose global declarations are included, such as those of the packages
+    /// listed in TypeScript's `types`, e.g. `node_modules/@types/node/index.d.ts`.
+    pub types: Vec<PathBuf>,
+}
 
 /// The result of running [`TypeChecker::check`].
 #[non_exhaustive]
@@ -101,41 +185,213 @@ impl TypeChecker {
 
     /// Type check `program`.
     ///
-    /// `semantic` supplies the symbol table and scope tree for `program` (build it with
-    /// [`oxc_semantic::SemanticBuilder`]). The returned [`TypeCheckerReturn`] carries any
-    /// diagnostics that were produced — an empty list means no problems were found.
+    /// `semantic` supplies the symbol table, scope tree and AST nodes for `program` (build it
+    /// with [`oxc_semantic::SemanticBuilder`], with nodes enabled, and with the control flow graph
+    /// for narrowing). The returned [`TypeCheckerReturn`] carries any diagnostics that were
+    /// produced — an empty list means no problems were found.
+    ///
+    /// Globals are loaded as configured by [`TypeCheckerOptions::lib_path`], and a lib file which
+    /// cannot be loaded is reported.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the AST nodes of `semantic` were not built.
     pub fn check<'a>(&self, program: &Program<'a>, semantic: &Semantic<'a>) -> TypeCheckerReturn {
-        let mut visitor = TypeCheckerVisitor {
-            semantic,
-            options: &self.options,
-            diagnostics: Diagnostics::new(),
+        assert!(
+            !semantic.nodes().is_empty(),
+            "The type checker requires `SemanticBuilder::with_build_nodes(true)`"
+        );
+        let (globals, error) = match Globals::load(&self.options) {
+            Ok(globals) => (globals, None),
+            Err(error) => (None, Some(error)),
         };
+        let mut visitor =
+            TypeCheckerVisitor::new(semantic, &self.options, globals.as_deref(), None);
+        visitor.diagnostics.extend(error);
+        visitor.compute_narrowing();
         visitor.visit_program(program);
         TypeCheckerReturn { diagnostics: visitor.diagnostics }
     }
+
+    /// Infer the types of all expressions of `program`, without checking it.
+    ///
+    /// `semantic` must be built as for [`TypeChecker::check`]. Globals which cannot be loaded are
+    /// `any`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the AST nodes of `semantic` were not built.
+    pub fn expression_types<'a>(
+        &self,
+        program: &Program<'a>,
+        semantic: &Semantic<'a>,
+    ) -> ExpressionTypes {
+        assert!(
+            !semantic.nodes().is_empty(),
+            "The type checker requires `SemanticBuilder::with_build_nodes(true)`"
+        );
+        let globals = Globals::load(&self.options).ok().flatten();
+        let mut visitor =
+            TypeCheckerVisitor::new(semantic, &self.options, globals.as_deref(), None);
+        visitor.compute_narrowing();
+        visitor.expression_types(program)
+    }
 }
 
 /// Walks the AST and accumulates diagnostics.
 ///
-/// This is where type checking happens. Right now it inherits the default (no-op) walk
-/// from [`Visit`], so it reports nothing; override `visit_*` methods to add checks. See
-/// the crate-level docs for the pattern.
+/// This is where type checking happens. Types are inferred on demand and cached per symbol.
 struct TypeCheckerVisitor<'a, 'c> {
     /// Symbol and scope information for the program being checked. Use it to resolve
     /// identifiers to symbols, inspect symbol flags, walk references, and so on.
-    #[expect(dead_code, reason = "scaffold: available to the checks you add")]
     semantic: &'c Semantic<'a>,
-    /// Configuration for the checks you add.
-    #[expect(dead_code, reason = "scaffold: available to the checks you add")]
+    /// Configuration for the checks.
+    #[expect(dead_code, reason = "no options are used by checks yet")]
     options: &'c TypeCheckerOptions,
     /// Type errors and warnings, surfaced via [`TypeCheckerReturn::diagnostics`].
     diagnostics: Diagnostics,
+    /// The types of unresolved references, whose type ids are valid in [`Self::types`].
+    globals: Option<&'c Globals>,
+    types: Types,
+    /// The types of variables, parameters and functions.
+    symbol_types: FxHashMap<SymbolId, TypeId>,
+    /// The types declared by type aliases and interfaces.
+    declared_types: FxHashMap<SymbolId, TypeId>,
+    /// Symbols whose type is being inferred, to break cycles.
+    resolving: FxHashSet<SymbolId>,
+    /// Type aliases and interfaces whose type is being resolved, to break cycles.
+    resolving_types: FxHashSet<SymbolId>,
+    /// The annotated return type of each function being walked, innermost last.
+    return_types: Vec<Option<TypeId>>,
+    /// The narrowed types of references to variables of a union, `unknown` or generic type, if
+    /// the control flow graph was built.
+    narrowed: Option<FxHashMap<NodeId, TypeId>>,
+    /// The module being checked, when checking a program, which imports are resolved from.
+    /// Without it, imports are `any`.
+    module: Option<ModuleContext<'c>>,
+    /// Whether an import whose module or export could not be resolved was referenced.
+    unresolved_imports: bool,
+    /// Whether a type was `any` because it is not known rather than because the program uses
+    /// `any`, e.g. the type of an undeclared global, a class, or a method of a primitive.
+    unknown_types: bool,
+}
+
+impl<'a, 'c> TypeCheckerVisitor<'a, 'c> {
+    fn new(
+        semantic: &'c Semantic<'a>,
+        options: &'c TypeCheckerOptions,
+        globals: Option<&'c Globals>,
+        module: Option<ModuleContext<'c>>,
+    ) -> Self {
+        Self {
+            semantic,
+            options,
+            diagnostics: Diagnostics::new(),
+            globals,
+            types: globals.map_or_else(Types::new, |globals| globals.types().clone()),
+            symbol_types: FxHashMap::default(),
+            declared_types: FxHashMap::default(),
+            resolving: FxHashSet::default(),
+            resolving_types: FxHashSet::default(),
+            return_types: vec![],
+            narrowed: None,
+            module,
+            unresolved_imports: false,
+            unknown_types: false,
+        }
+    }
 }
 
 impl<'a> Visit<'a> for TypeCheckerVisitor<'a, '_> {
-    // Override `visit_*` methods here to implement type checks.
-    //
-    // Reach for `self.semantic` when a check needs symbol or scope information, push onto
-    // `self.diagnostics` (e.g. with `type_error(..)`) to report a problem, and call the
-    // matching `walk_*` free function so traversal continues into the node's children.
+    fn visit_variable_declarator(&mut self, it: &VariableDeclarator<'a>) {
+        if let (Some(annotation), Some(init)) = (&it.type_annotation, &it.init) {
+            let target = self.annotation_type(&annotation.type_annotation);
+            self.check_assignable(init, target, it.id.span(), Relation::Assignment);
+        }
+        walk_variable_declarator(self, it);
+    }
+
+    fn visit_assignment_expression(&mut self, it: &AssignmentExpression<'a>) {
+        if it.operator == AssignmentOperator::Assign
+            && let Some(target) = self.assignment_target_type(&it.left)
+        {
+            self.check_assignable(&it.right, target, it.left.span(), Relation::Assignment);
+        }
+        walk_assignment_expression(self, it);
+    }
+
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        let return_type = self.annotated_return_type(
+            it.r#async || it.generator,
+            it.return_type.as_deref().map(|annotation| &annotation.type_annotation),
+        );
+        self.return_types.push(return_type);
+        walk_function(self, it, flags);
+        self.return_types.pop();
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        let return_type = self.annotated_return_type(
+            it.r#async,
+            it.return_type.as_deref().map(|annotation| &annotation.type_annotation),
+        );
+        if let (Some(return_type), Some(expr)) = (return_type, it.get_expression()) {
+            self.check_assignable(expr, return_type, expr.span(), Relation::Assignment);
+        }
+        self.return_types.push(return_type);
+        walk_arrow_function_expression(self, it);
+        self.return_types.pop();
+    }
+
+    fn visit_return_statement(&mut self, it: &ReturnStatement<'a>) {
+        if let (Some(Some(return_type)), Some(argument)) = (self.return_types.last(), &it.argument)
+        {
+            // Reported at the `return` keyword
+            let span = Span::sized(it.span.start, 6);
+            self.check_assignable(argument, *return_type, span, Relation::Assignment);
+        }
+        walk_return_statement(self, it);
+    }
+
+    fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+        self.check_call(it);
+        walk_call_expression(self, it);
+    }
+}
+
+impl<'a> TypeCheckerVisitor<'a, '_> {
+    /// The type of an assignment target, if it is a variable or property of known type.
+    fn assignment_target_type(&mut self, target: &AssignmentTarget<'a>) -> Option<TypeId> {
+        match target {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                let reference = self.semantic.scoping().get_reference(ident.reference_id());
+                reference.symbol_id().map(|symbol_id| self.symbol_type(symbol_id))
+            }
+            AssignmentTarget::StaticMemberExpression(member) => {
+                let object = self.expression_type(&member.object, None);
+                let Type::Object(object) = self.types.get(object) else { return None };
+                let property = object.property(&member.property.name)?;
+                let type_id = property.type_id;
+                Some(if property.optional {
+                    self.types.union([type_id, TypeId::UNDEFINED])
+                } else {
+                    type_id
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// The return type which `return` statements are checked against. Async functions and
+    /// generators return promises and iterators, which are not represented yet.
+    fn annotated_return_type(
+        &mut self,
+        is_async_or_generator: bool,
+        annotation: Option<&oxc_ast::ast::TSType<'a>>,
+    ) -> Option<TypeId> {
+        if is_async_or_generator {
+            return None;
+        }
+        annotation.map(|annotation| self.annotation_type(annotation))
+    }
 }
@@ -0,0 +1,283 @@
+//! The types exported by modules, which imports refer to.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    AstKind,
+    ast::{ExportDefaultDeclarationKind, Program},
+};
+use oxc_parser::Parser;
+use oxc_resolver::Resolver;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_str::CompactStr;
+use oxc_syntax::{
+    module_record::{ExportExportName, ExportImportName, ExportLocalName, ModuleRecord},
+    node::NodeId,
+};
+
+use crate::{
+    Globals, TypeCheckerOptions, TypeCheckerVisitor,
+    types::{ObjectType, Property, Type, TypeId, Types},
+};
+
+/// The values and types a module exports.
+#[derive(Debug, Default)]
+pub struct ModuleExports {
+    types: Types,
+    values: FxHashMap<CompactStr, TypeId>,
+    type_declarations: FxHashMap<CompactStr, TypeId>,
+}
+
+impl ModuleExports {
+    fn value(&self, name: &str) -> Option<TypeId> {
+        self.values.get(name).copied()
+    }
+
+    fn type_declaration(&self, name: &str) -> Option<TypeId> {
+        self.type_declarations.get(name).copied()
+    }
+}
+
+/// The modules of a program, shared by the files being checked.
+///
+/// Imports are resolved as TypeScript resolves them to declaration files, and the exports of each
+/// module are computed once.
+pub struct Modules {
+    options: TypeCheckerOptions,
+    globals: Option<Arc<Globals>>,
+    resolver: Resolver,
+    exports: Mutex<FxHashMap<PathBuf, Arc<ModuleExports>>>,
+}
+
+impl Modules {
+    pub fn new(
+        options: TypeCheckerOptions,
+        globals: Option<Arc<Globals>>,
+        resolver: Resolver,
+    ) -> Self {
+        Self { options, globals, resolver, exports: Mutex::default() }
+    }
+
+    pub fn options(&self) -> &TypeCheckerOptions {
+        &self.options
+    }
+
+    pub fn globals(&self) -> Option<&Globals> {
+        self.globals.as_deref()
+    }
+
+    /// The file `specifier` refers to when imported from `importer`, if it is a TypeScript file.
+    fn resolve(&self, importer: &Path, specifier: &str) -> Option<PathBuf> {
+        let path = self.resolver.resolve_dts(importer, specifier).ok()?.into_path_buf();
+        let source_type = SourceType::from_path(&path).ok()?;
+        source_type.is_typescript().then_some(path)
+    }
+
+    /// The exports of the module at `path`, which is imported through `importers`.
+    ///
+    /// Returns `None` if the module cannot be read or parsed, or imports itself through
+    /// `importers`. Modules are not locked while their exports are computed, so the exports of a
+    /// module imported from several threads at once may be computed more than once.
+    fn exports(&self, path: &Path, importers: &[PathBuf]) -> Option<Arc<ModuleExports>> {
+        let cached = self.lock().get(path).cloned();
+        if let Some(exports) = cached {
+            return Some(exports);
+        }
+        if importers.iter().any(|importer| importer == path) {
+            return None;
+        }
+
+        let source_text = fs::read_to_string(path).ok()?;
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(path).ok()?;
+        let parser_ret = Parser::new(&allocator, &source_text, source_type).parse();
+        if !parser_ret.diagnostics.is_empty() {
+            return None;
+        }
+        let semantic =
+            SemanticBuilder::new().with_build_nodes(true).build(&parser_ret.program).semantic;
+        let module = ModuleContext { modules: self, path, importers };
+        let mut visitor =
+            TypeCheckerVisitor::new(&semantic, &self.options, self.globals(), Some(module));
+        let exports = Arc::new(visitor.exports(&parser_ret.program, &parser_ret.module_record));
+
+        Some(Arc::clone(self.lock().entry(path.to_path_buf()).or_insert(exports)))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, FxHashMap<PathBuf, Arc<ModuleExports>>> {
+        self.exports.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// The module being checked, which imports are resolved from.
+#[derive(Clone, Copy)]
+pub struct ModuleContext<'c> {
+    pub modules: &'c Modules,
+    pub path: &'c Path,
+    /// The modules whose exports are being computed and which import this one, to break cycles.
+    pub importers: &'c [PathBuf],
+}
+
+impl ModuleContext<'_> {
+    fn imported_module(&self, specifier: &str) -> Option<Arc<ModuleExports>> {
+        let path = self.modules.resolve(self.path, specifier)?;
+        let mut importers = self.importers.to_vec();
+        importers.push(self.path.to_path_buf());
+        self.modules.exports(&path, &importers)
+    }
+}
+
+impl<'a> TypeCheckerVisitor<'a, '_> {
+    /// The type of an imported value, or the type declared by an imported type, for the import
+    /// specifier `declaration`.
+    pub(crate) fn import_type(&mut self, declaration: NodeId, is_type: bool) -> TypeId {
+        let type_id = self.resolve_import_type(declaration, is_type);
+        if type_id.is_none() {
+            self.unresolved_imports = true;
+        }
+        type_id.unwrap_or(TypeId::ANY)
+    }
+
+    fn resolve_import_type(&mut self, declaration: NodeId, is_type: bool) -> Option<TypeId> {
+        let nodes = self.semantic.nodes();
+        let AstKind::ImportDeclaration(import) = nodes.parent_kind(declaration) else {
+            return None;
+        };
+        let module = self.module.and_then(|module| module.imported_module(&import.source.value))?;
+        let name = match nodes.kind(declaration) {
+            AstKind::ImportSpecifier(specifier) => specifier.imported.name(),
+            AstKind::ImportDefaultSpecifier(_) => "default".into(),
+            AstKind::ImportNamespaceSpecifier(_) if !is_type => {
+                return Some(self.namespace_type(&module));
+            }
+            _ => return None,
+        };
+        let type_id = if is_type { module.type_declaration(&name) } else { module.value(&name) };
+        type_id.map(|type_id| self.types.import(&module.types, type_id))
+    }
+
+    /// The type of a namespace import such as `import * as ns from "..."`.
+    fn namespace_type(&mut self, module: &ModuleExports) -> TypeId {
+        let mut values = module.values.iter().collect::<Vec<_>>();
+        values.sort_unstable_by_key(|(name, _)| *name);
+        let properties = values
+            .into_iter()
+            .map(|(name, &type_id)| Property {
+                name: name.clone(),
+                type_id: self.types.import(&module.types, type_id),
+                optional: false,
+            })
+            .collect();
+        self.types.intern(Type::Object(ObjectType { properties }))
+    }
+
+    /// The values and types exported by `program`, which consumes the checker.
+    fn exports(
+        &mut self,
+        program: &Program<'a>,
+        module_record: &ModuleRecord<'a>,
+    ) -> ModuleExports {
+        let mut exports = ModuleExports::default();
+        let scoping = self.semantic.scoping();
+        for entry in &module_record.local_export_entries {
+            let export_name = match &entry.export_name {
+                ExportExportName::Name(name) => CompactStr::from(name.name.as_str()),
+                ExportExportName::Default(_) => CompactStr::from("default"),
+                ExportExportName::Null => continue,
+            };
+            match &entry.local_name {
+                ExportLocalName::Name(local) => {
+                    let Some(symbol_id) = scoping.get_root_binding(local.name.into()) else {
+                        continue;
+                    };
+                    let flags = scoping.symbol_flags(symbol_id);
+                    if flags.is_value() || flags.is_import() {
+                        exports.values.insert(export_name.clone(), self.symbol_type(symbol_id));
+                    }
+                    if flags.is_type() || flags.is_import() {
+                        exports
+                            .type_declarations
+                            .insert(export_name, self.declared_type(symbol_id));
+                    }
+                }
+                // `export default <expression>`
+                _ => {
+                    exports.values.insert(export_name, self.default_export_type(program));
+                }
+            }
+        }
+
+        let Some(module) = self.module else { return exports };
+        for entry in &module_record.indirect_export_entries {
+            let (Some(request), ExportExportName::Name(export_name)) =
+                (&entry.module_request, &entry.export_name)
+            else {
+                continue;
+            };
+            let Some(imported) = module.imported_module(&request.name) else { continue };
+            let export_name = CompactStr::from(export_name.name.as_str());
+            match &entry.import_name {
+                // `export { a as b } from "..."`
+                ExportImportName::Name(import_name) => {
+                    if let Some(type_id) = imported.value(&import_name.name) {
+                        let type_id = self.types.import(&imported.types, type_id);
+                        exports.values.insert(export_name.clone(), type_id);
+                    }
+                    if let Some(type_id) = imported.type_declaration(&import_name.name) {
+                        let type_id = self.types.import(&imported.types, type_id);
+                        exports.type_declarations.insert(export_name, type_id);
+                    }
+                }
+                // `export * as ns from "..."`
+                ExportImportName::All => {
+                    let type_id = self.namespace_type(&imported);
+                    exports.values.insert(export_name, type_id);
+                }
+                _ => {}
+            }
+        }
+        // `export * from "..."`, which does not override local exports or export defaults
+        for entry in &module_record.star_export_entries {
+            let Some(request) = &entry.module_request else { continue };
+            let Some(imported) = module.imported_module(&request.name) else { continue };
+            for (name, &type_id) in &imported.values {
+                if name != "default" && !exports.values.contains_key(name) {
+                    let type_id = self.types.import(&imported.types, type_id);
+                    exports.values.insert(name.clone(), type_id);
+                }
+            }
+            for (name, &type_id) in &imported.type_declarations {
+                if !exports.type_declarations.contains_key(name) {
+                    let type_id = self.types.import(&imported.types, type_id);
+                    exports.type_declarations.insert(name.clone(), type_id);
+                }
+            }
+        }
+        exports.types = std::mem::take(&mut self.types);
+        exports
+    }
+
+    fn default_export_type(&mut self, program: &Program<'a>) -> TypeId {
+        let declaration = program.body.iter().find_map(|statement| match statement {
+            oxc_ast::ast::Statement::ExportDefaultDeclaration(declaration) => Some(declaration),
+            _ => None,
+        });
+        match declaration.map(|declaration| &declaration.declaration) {
+            Some(ExportDefaultDeclarationKind::FunctionDeclaration(function)) => {
+                self.function_type(function)
+            }
+            Some(declaration) => declaration
+                .as_expression()
+                .map_or(TypeId::ANY, |expr| self.expression_type(expr, None)),
+            None => TypeId::ANY,
+        }
+    }
+}
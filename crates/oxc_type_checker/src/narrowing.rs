@@ -0,0 +1,934 @@
+//! Control flow narrowing, which refines the declared type of a variable at each of its
+//! references, e.g. to `string` inside `if (typeof x === "string") { ... }`.
+//!
+//! Narrowing is a forward dataflow analysis over the control flow graph built by `oxc_semantic`.
+//! The state maps each variable of a union, `unknown` or generic type to its narrowed type.
+//! Assignments replace it, logical assignments such as `x ||= "a"` add the assigned value to what
+//! is kept when they short-circuit, and the edges leaving a condition narrow it by the outcome of
+//! the condition: truthiness, `typeof`, equality with literals, `null` and `undefined`, discriminant
+//! properties, `in`, `instanceof` and type predicates. Assertion functions narrow the code
+//! following them.
+//!
+//! Narrowing TypeScript would do but which is not understood here must not leave a variable with a
+//! type that is too wide, so variables tested by other conditions are `any` in both branches. This
+//! includes the cases of `switch` statements, and captured variables are `any` in nested functions.
+
+use std::cell::RefCell;
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        BinaryExpression, BinaryOperator, BindingPattern, CallExpression, Expression,
+        FormalParameters, IdentifierReference, LogicalOperator, TSTypePredicate,
+        TSTypePredicateName, UnaryOperator,
+    },
+};
+use oxc_ast_visit::Visit;
+use oxc_cfg::{
+    BlockNodeId, ControlFlowGraph, EdgeType,
+    dataflow::{Access, AccessKind, Analysis, DataflowResults, Direction, solve},
+    graph::{Direction as EdgeDirection, visit::EdgeRef},
+};
+use oxc_span::GetSpan;
+use oxc_str::CompactStr;
+use oxc_syntax::{node::NodeId, symbol::SymbolId};
+
+use crate::{
+    TypeCheckerVisitor,
+    types::{Type, TypeId, Types},
+};
+
+/// The narrowed types of variables at a point of the program, or `None` where control never
+/// reaches.
+type State = Option<FxHashMap<SymbolId, TypeId>>;
+
+/// What a condition tells about the variables it tests, when it is true or false.
+#[derive(Debug)]
+enum Condition {
+    /// A test of a variable.
+    Test(SymbolId, Test),
+    Not(Box<Condition>),
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+    /// A condition which may narrow the variables it mentions in ways that are not understood.
+    Opaque(Vec<SymbolId>),
+}
+
+#[derive(Debug)]
+enum Test {
+    /// `x`
+    Truthy,
+    /// `typeof x === "string"`
+    TypeOf(CompactStr),
+    /// `x === "a"`, or `x == null` if not strict. The value has a single value type.
+    Equals { value: TypeId, strict: bool },
+    /// `x.kind === "a"`. The value has a single value type.
+    Property { name: CompactStr, value: TypeId },
+    /// `"a" in x`
+    In(CompactStr),
+    /// `x instanceof C`
+    InstanceOf,
+    /// A call to a function returning `x is T`.
+    Is(TypeId),
+}
+
+/// Something which happens in a basic block.
+#[derive(Debug)]
+enum Event {
+    Read(SymbolId, NodeId),
+    /// A write, and the type it narrows the variable to.
+    Write(SymbolId, TypeId),
+    /// A logical assignment e.g. `x ||= "a"`, and the type the assigned value narrows the
+    /// variable to. The variable keeps its value when the assignment short-circuits.
+    LogicalWrite(SymbolId, LogicalOperator, TypeId),
+    /// A call to an assertion function, which asserts the condition.
+    Assert(usize),
+}
+
+impl<'a> TypeCheckerVisitor<'a, '_> {
+    /// Compute the narrowed types of the references to variables of a union, `unknown` or generic
+    /// type.
+    ///
+    /// Does nothing if the control flow graph was not built.
+    pub(crate) fn compute_narrowing(&mut self) {
+        let semantic = self.semantic;
+        let (Some(cfg), Some(accesses)) = (semantic.cfg(), semantic.block_accesses()) else {
+            return;
+        };
+
+        let mut declared = FxHashMap::default();
+        for symbol_id in semantic.scoping().symbol_ids() {
+            let type_id = self.symbol_type(symbol_id);
+            if self.is_narrowable(type_id) {
+                declared.insert(symbol_id, type_id);
+            }
+        }
+
+        let mut conditions = vec![];
+        let mut events: FxHashMap<BlockNodeId, Vec<(u32, Event)>> = FxHashMap::default();
+        for (block, block_accesses) in accesses.iter() {
+            for access in block_accesses {
+                let Some(&declared_type) = declared.get(&access.symbol_id) else { continue };
+                let position = semantic.nodes().kind(access.node_id).span().start;
+                let event = match access.kind {
+                    AccessKind::Read => Event::Read(access.symbol_id, access.node_id),
+                    AccessKind::Write => {
+                        let type_id = self.assigned_type(access, declared_type);
+                        match self.logical_assignment_operator(access) {
+                            Some(operator) => {
+                                Event::LogicalWrite(access.symbol_id, operator, type_id)
+                            }
+                            None => Event::Write(access.symbol_id, type_id),
+                        }
+                    }
+                };
+                events.entry(block).or_default().push((position, event));
+            }
+        }
+        for node in semantic.nodes().iter() {
+            let AstKind::CallExpression(call) = node.kind() else { continue };
+            if !matches!(semantic.nodes().parent_kind(node.id()), AstKind::ExpressionStatement(_)) {
+                continue;
+            }
+            let Some(condition) = self.assertion(call) else { continue };
+            conditions.push(condition);
+            let block = events.entry(semantic.nodes().cfg_id(node.id())).or_default();
+            let index = block.partition_point(|(position, _)| *position < call.span.end);
+            block.insert(index, (call.span.end, Event::Assert(conditions.len() - 1)));
+        }
+        let events = events
+            .into_iter()
+            .map(|(block, events)| (block, events.into_iter().map(|(_, event)| event).collect()))
+            .collect();
+
+        let branches = self.branches(cfg, &mut conditions);
+
+        let analysis = Narrowing {
+            types: RefCell::new(&mut self.types),
+            declared,
+            conditions,
+            events,
+            branches,
+        };
+        let results = solve(cfg, &analysis, semantic.nodes().cfg_id(NodeId::ROOT));
+        self.narrowed = Some(analysis.narrowed_references(&results));
+    }
+
+    /// The type a write narrows a variable to: the members of its declared type which the
+    /// assigned value is assignable to.
+    fn assigned_type(&mut self, access: &Access, declared: TypeId) -> TypeId {
+        let nodes = self.semantic.nodes();
+        let value = match nodes.kind(access.node_id) {
+            AstKind::VariableDeclarator(declarator)
+                if matches!(declarator.id, BindingPattern::BindingIdentifier(_)) =>
+            {
+                declarator.init.as_ref()
+            }
+            AstKind::IdentifierReference(_) => match nodes.parent_kind(access.node_id) {
+                AstKind::AssignmentExpression(assignment)
+                    if (assignment.operator.is_assign() || assignment.operator.is_logical())
+                        && assignment.left.span() == nodes.kind(access.node_id).span() =>
+                {
+                    Some(&assignment.right)
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+        let Some(value) = value else { return declared };
+        let value = self.expression_type(value, Some(declared));
+        if value == TypeId::ANY {
+            return declared;
+        }
+        match self.types.get(declared).clone() {
+            Type::Union(members) => {
+                let values = self.types.members(value);
+                let members = members
+                    .iter()
+                    .copied()
+                    .filter(|&member| {
+                        values.iter().any(|&value| self.types.is_assignable(value, member))
+                    })
+                    .collect::<Vec<_>>();
+                if members.is_empty() { declared } else { self.types.union(members) }
+            }
+            _ if self.types.is_instantiable(declared) => declared,
+            _ => self.types.widen(value),
+        }
+    }
+
+    /// The operator of a logical assignment to the variable written by `access`, e.g. `||` for
+    /// `x ||= "a"`.
+    fn logical_assignment_operator(&self, access: &Access) -> Option<LogicalOperator> {
+        let nodes = self.semantic.nodes();
+        let AstKind::IdentifierReference(ident) = nodes.kind(access.node_id) else { return None };
+        match nodes.parent_kind(access.node_id) {
+            AstKind::AssignmentExpression(assignment) if assignment.left.span() == ident.span => {
+                assignment.operator.to_logical_operator()
+            }
+            _ => None,
+        }
+    }
+
+    /// The condition asserted by a call to an assertion function, e.g. `assertIsString(x)`.
+    fn assertion(&mut self, call: &CallExpression<'a>) -> Option<Condition> {
+        let (predicate, params) = self.type_predicate(&call.callee)?;
+        if !predicate.asserts {
+            return None;
+        }
+        let argument = predicate_argument(call, predicate, params)?;
+        Some(match &predicate.type_annotation {
+            // `asserts x is T`
+            Some(annotation) => {
+                let type_id = self.annotation_type(&annotation.type_annotation);
+                self.predicate_condition(argument, type_id)
+            }
+            // `asserts x`
+            None => self.condition(argument),
+        })
+    }
+
+    /// The type predicate returned by the function `callee` refers to, and the parameters it
+    /// refers to.
+    fn type_predicate(
+        &self,
+        callee: &Expression<'a>,
+    ) -> Option<(&'a TSTypePredicate<'a>, &'a FormalParameters<'a>)> {
+        let Expression::Identifier(ident) = callee.get_inner_expression() else { return None };
+        let semantic = self.semantic;
+        let symbol_id = semantic.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        let mut declarations = semantic.scoping().symbol_declarations(symbol_id);
+        let (Some(declaration), None) = (declarations.next(), declarations.next()) else {
+            return None;
+        };
+        let (return_type, params) = match semantic.nodes().kind(declaration) {
+            AstKind::Function(function) => (function.return_type.as_deref(), &*function.params),
+            AstKind::VariableDeclarator(declarator) => match &declarator.init {
+                Some(Expression::ArrowFunctionExpression(arrow)) => {
+                    (arrow.return_type.as_deref(), &*arrow.params)
+                }
+                Some(Expression::FunctionExpression(function)) => {
+                    (function.return_type.as_deref(), &*function.params)
+                }
+                _ => return None,
+            },
+            _ => return None,
+        };
+        match &return_type?.type_annotation {
+            oxc_ast::ast::TSType::TSTypePredicate(predicate) => Some((predicate, params)),
+            _ => None,
+        }
+    }
+
+    /// What `expr` tells about the variables it tests, when used as a condition.
+    fn condition(&mut self, expr: &Expression<'a>) -> Condition {
+        match expr.get_inner_expression() {
+            Expression::Identifier(ident) => match self.reference_symbol(ident) {
+                Some(symbol_id) => Condition::Test(symbol_id, Test::Truthy),
+                None => Condition::Opaque(vec![]),
+            },
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+                Condition::Not(Box::new(self.condition(&unary.argument)))
+            }
+            Expression::LogicalExpression(logical) => {
+                let left = Box::new(self.condition(&logical.left));
+                let right = Box::new(self.condition(&logical.right));
+                match logical.operator {
+                    LogicalOperator::And => Condition::And(left, right),
+                    LogicalOperator::Or => Condition::Or(left, right),
+                    LogicalOperator::Coalesce => Condition::Opaque(self.mentions(expr)),
+                }
+            }
+            Expression::BinaryExpression(binary) => self.binary_condition(binary),
+            Expression::CallExpression(call) => match self.type_predicate(&call.callee) {
+                Some((predicate, params)) if !predicate.asserts => {
+                    let argument = predicate_argument(call, predicate, params);
+                    match (argument, &predicate.type_annotation) {
+                        (Some(argument), Some(annotation)) => {
+                            let type_id = self.annotation_type(&annotation.type_annotation);
+                            self.predicate_condition(argument, type_id)
+                        }
+                        _ => Condition::Opaque(self.mentions(expr)),
+                    }
+                }
+                _ => Condition::Opaque(self.mentions(expr)),
+            },
+            _ => Condition::Opaque(self.mentions(expr)),
+        }
+    }
+
+    fn binary_condition(&mut self, binary: &BinaryExpression<'a>) -> Condition {
+        let condition = match binary.operator {
+            BinaryOperator::StrictEquality | BinaryOperator::Equality => {
+                return self.equality_condition(&binary.left, &binary.right, binary.operator);
+            }
+            BinaryOperator::StrictInequality => {
+                self.equality_condition(&binary.left, &binary.right, BinaryOperator::StrictEquality)
+            }
+            BinaryOperator::Inequality => {
+                self.equality_condition(&binary.left, &binary.right, BinaryOperator::Equality)
+            }
+            BinaryOperator::In => {
+                let (Expression::StringLiteral(name), Expression::Identifier(ident)) =
+                    (binary.left.get_inner_expression(), binary.right.get_inner_expression())
+                else {
+                    return Condition::Opaque(self.mentions(&binary.right));
+                };
+                return match self.reference_symbol(ident) {
+                    Some(symbol_id) => {
+                        Condition::Test(symbol_id, Test::In(name.value.as_str().into()))
+                    }
+                    None => Condition::Opaque(vec![]),
+                };
+            }
+            BinaryOperator::Instanceof => {
+                return match binary.left.get_inner_expression() {
+                    Expression::Identifier(ident) => match self.reference_symbol(ident) {
+                        Some(symbol_id) => Condition::Test(symbol_id, Test::InstanceOf),
+                        None => Condition::Opaque(vec![]),
+                    },
+                    left => Condition::Opaque(self.mentions(left)),
+                };
+            }
+            // Relational and arithmetic operators do not narrow their operands
+            _ => return Condition::Opaque(vec![]),
+        };
+        Condition::Not(Box::new(condition))
+    }
+
+    /// The condition `left == right` or `left === right`.
+    fn equality_condition(
+        &mut self,
+        left: &Expression<'a>,
+        right: &Expression<'a>,
+        operator: BinaryOperator,
+    ) -> Condition {
+        let strict = operator == BinaryOperator::StrictEquality;
+        let operands = [(left, right), (right, left)];
+        for (tested, other) in operands {
+            let tested = tested.get_inner_expression();
+            // `typeof x === "string"`
+            if let (Expression::UnaryExpression(unary), Expression::StringLiteral(name)) =
+                (tested, other.get_inner_expression())
+                && unary.operator == UnaryOperator::Typeof
+                && let Expression::Identifier(ident) = unary.argument.get_inner_expression()
+                && let Some(symbol_id) = self.reference_symbol(ident)
+            {
+                return Condition::Test(symbol_id, Test::TypeOf(name.value.as_str().into()));
+            }
+
+            let value = match other.get_inner_expression() {
+                // Other identifiers may be narrowed themselves
+                Expression::Identifier(ident) if ident.name != "undefined" => continue,
+                other => self.expression_type(other, None),
+            };
+            if !self.types.is_unit(value) {
+                continue;
+            }
+            let is_nullish = matches!(value, TypeId::NULL | TypeId::UNDEFINED);
+            match tested {
+                Expression::Identifier(ident) if strict || is_nullish => {
+                    if let Some(symbol_id) = self.reference_symbol(ident) {
+                        return Condition::Test(symbol_id, Test::Equals { value, strict });
+                    }
+                }
+                // `x.kind === "a"`
+                Expression::StaticMemberExpression(member) if strict && !member.optional => {
+                    if let Expression::Identifier(ident) = member.object.get_inner_expression()
+                        && let Some(symbol_id) = self.reference_symbol(ident)
+                    {
+                        let name = member.property.name.as_str().into();
+                        return Condition::Test(symbol_id, Test::Property { name, value });
+                    }
+                }
+                _ => {}
+            }
+        }
+        let mut mentions = self.mentions(left);
+        mentions.extend(self.mentions(right));
+        Condition::Opaque(mentions)
+    }
+
+    /// The condition that `argument` is of type `type_id`, for a type predicate.
+    fn predicate_condition(&self, argument: &Expression<'a>, type_id: TypeId) -> Condition {
+        match argument.get_inner_expression() {
+            Expression::Identifier(ident) => match self.reference_symbol(ident) {
+                Some(symbol_id) => Condition::Test(symbol_id, Test::Is(type_id)),
+                None => Condition::Opaque(vec![]),
+            },
+            argument => Condition::Opaque(self.mentions(argument)),
+        }
+    }
+
+    fn reference_symbol(&self, ident: &IdentifierReference<'a>) -> Option<SymbolId> {
+        self.semantic.scoping().get_reference(ident.reference_id()).symbol_id()
+    }
+
+    /// The variables referenced in `expr`.
+    fn mentions(&self, expr: &Expression<'a>) -> Vec<SymbolId> {
+        let mut mentions = Mentions { checker: self, symbols: vec![] };
+        mentions.visit_expression(expr);
+        mentions.symbols
+    }
+
+    /// The conditions and their outcomes applied to the edges of `cfg`, by source and target.
+    fn branches(
+        &mut self,
+        cfg: &ControlFlowGraph,
+        conditions: &mut Vec<Condition>,
+    ) -> FxHashMap<(BlockNodeId, BlockNodeId), Vec<(usize, bool)>> {
+        let semantic = self.semantic;
+        let nodes = semantic.nodes();
+        let mut branches: FxHashMap<_, Vec<_>> = FxHashMap::default();
+        for node in nodes.iter() {
+            // The condition, and the start of the code which runs when it is true
+            let (condition, consequent) = match node.kind() {
+                AstKind::IfStatement(stmt) => {
+                    (self.condition(&stmt.test), stmt.consequent.node_id())
+                }
+                AstKind::WhileStatement(stmt) => (self.condition(&stmt.test), stmt.body.node_id()),
+                AstKind::ForStatement(stmt) => {
+                    let Some(test) = &stmt.test else { continue };
+                    (self.condition(test), stmt.body.node_id())
+                }
+                AstKind::ConditionalExpression(expr) => {
+                    (self.condition(&expr.test), expr.consequent.node_id())
+                }
+                AstKind::LogicalExpression(expr) => {
+                    let left = self.condition(&expr.left);
+                    let condition = match expr.operator {
+                        LogicalOperator::And => left,
+                        LogicalOperator::Or => Condition::Not(Box::new(left)),
+                        LogicalOperator::Coalesce => match expr.left.get_inner_expression() {
+                            Expression::Identifier(ident) => match self.reference_symbol(ident) {
+                                Some(symbol_id) => Condition::Test(
+                                    symbol_id,
+                                    Test::Equals { value: TypeId::NULL, strict: false },
+                                ),
+                                None => continue,
+                            },
+                            left => Condition::Opaque(self.mentions(left)),
+                        },
+                    };
+                    (condition, expr.right.node_id())
+                }
+                AstKind::SwitchStatement(stmt) => {
+                    let mentions = self.mentions(&stmt.discriminant);
+                    if mentions.is_empty() {
+                        continue;
+                    }
+                    conditions.push(Condition::Opaque(mentions));
+                    for case in &stmt.cases {
+                        let Some(first) = case.consequent.first() else { continue };
+                        let start = nodes.cfg_id(first.node_id());
+                        for edge in cfg.graph().edges_directed(start, EdgeDirection::Incoming) {
+                            if matches!(edge.weight(), EdgeType::Jump) {
+                                let branch = (edge.source(), start);
+                                branches
+                                    .entry(branch)
+                                    .or_default()
+                                    .push((conditions.len() - 1, true));
+                            }
+                        }
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            let start = nodes.cfg_id(consequent);
+            let mut predecessors = cfg
+                .graph()
+                .edges_directed(start, EdgeDirection::Incoming)
+                .filter(|edge| is_branch(edge.weight()));
+            let (Some(predecessor), None) = (predecessors.next(), predecessors.next()) else {
+                continue;
+            };
+            let source = predecessor.source();
+            if source == start {
+                continue;
+            }
+            conditions.push(condition);
+            let index = conditions.len() - 1;
+            for edge in cfg.graph().edges_directed(source, EdgeDirection::Outgoing) {
+                if is_branch(edge.weight()) {
+                    let outcome = edge.target() == start;
+                    branches.entry((source, edge.target())).or_default().push((index, outcome));
+                }
+            }
+        }
+        branches
+    }
+}
+
+/// Whether an edge can be taken depending on the outcome of a condition.
+fn is_branch(edge: &EdgeType) -> bool {
+    matches!(edge, EdgeType::Jump | EdgeType::Normal)
+}
+
+/// The argument passed to the parameter a type predicate refers to.
+fn predicate_argument<'c, 'a>(
+    call: &'c CallExpression<'a>,
+    predicate: &TSTypePredicate<'a>,
+    params: &FormalParameters<'a>,
+) -> Option<&'c Expression<'a>> {
+    let TSTypePredicateName::Identifier(name) = &predicate.parameter_name else { return None };
+    let index = params.items.iter().position(|param| {
+        matches!(&param.pattern, BindingPattern::BindingIdentifier(id) if id.name == name.name)
+    })?;
+    call.arguments.get(index).and_then(|argument| argument.as_expression())
+}
+
+/// Collects the variables referenced in an expression.
+struct Mentions<'v, 'a, 'c> {
+    checker: &'v TypeCheckerVisitor<'a, 'c>,
+    symbols: Vec<SymbolId>,
+}
+
+impl<'a> Visit<'a> for Mentions<'_, 'a, '_> {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        self.symbols.extend(self.checker.reference_symbol(it));
+    }
+}
+
+/// The narrowing analysis, solved with [`solve`].
+struct Narrowing<'t> {
+    types: RefCell<&'t mut Types>,
+    /// The declared types of the variables which can be narrowed.
+    declared: FxHashMap<SymbolId, TypeId>,
+    conditions: Vec<Condition>,
+    events: FxHashMap<BlockNodeId, Vec<Event>>,
+    branches: FxHashMap<(BlockNodeId, BlockNodeId), Vec<(usize, bool)>>,
+}
+
+impl Narrowing<'_> {
+    /// The narrowed type of each read of a variable in reachable code.
+    fn narrowed_references(&self, results: &DataflowResults<State>) -> FxHashMap<NodeId, TypeId> {
+        let mut narrowed = FxHashMap::default();
+        for (&block, events) in &self.events {
+            let Some(mut state) = results.entry(block).clone() else { continue };
+            for event in events {
+                if let Event::Read(symbol_id, node_id) = event
+                    && let Some(&type_id) = state.get(symbol_id)
+                {
+                    narrowed.insert(*node_id, type_id);
+                }
+                self.apply_event(event, &mut state);
+            }
+        }
+        narrowed
+    }
+
+    fn apply_event(&self, event: &Event, state: &mut FxHashMap<SymbolId, TypeId>) {
+        match event {
+            Event::Read(..) => {}
+            Event::Write(symbol_id, type_id) => {
+                state.insert(*symbol_id, *type_id);
+            }
+            Event::LogicalWrite(symbol_id, operator, type_id) => {
+                let Some(&current) = state.get(symbol_id) else { return };
+                // The value kept when the assignment short-circuits
+                let (test, outcome) = match operator {
+                    LogicalOperator::Or => (Test::Truthy, true),
+                    LogicalOperator::And => (Test::Truthy, false),
+                    LogicalOperator::Coalesce => {
+                        (Test::Equals { value: TypeId::NULL, strict: false }, false)
+                    }
+                };
+                let kept = self.types.borrow_mut().narrow(current, &test, outcome);
+                state.insert(*symbol_id, self.join_types(*symbol_id, kept, *type_id));
+            }
+            Event::Assert(condition) => {
+                self.narrow(&self.conditions[*condition], true, state);
+            }
+        }
+    }
+
+    /// Narrow `state` by the outcome of `condition`.
+    fn narrow(
+        &self,
+        condition: &Condition,
+        outcome: bool,
+        state: &mut FxHashMap<SymbolId, TypeId>,
+    ) {
+        match condition {
+            Condition::Test(symbol_id, test) => {
+                if let Some(type_id) = state.get_mut(symbol_id) {
+                    *type_id = self.types.borrow_mut().narrow(*type_id, test, outcome);
+                }
+            }
+            Condition::Not(condition) => self.narrow(condition, !outcome, state),
+            // `a && b` is true if both are, and `a || b` is false if both are
+            Condition::And(left, right) | Condition::Or(left, right)
+                if outcome == matches!(condition, Condition::And(..)) =>
+            {
+                self.narrow(left, outcome, state);
+                self.narrow(right, outcome, state);
+            }
+            // Otherwise, either the left side decides, or the right side does
+            Condition::And(left, right) | Condition::Or(left, right) => {
+                let mut right_decides = state.clone();
+                self.narrow(left, outcome, state);
+                self.narrow(left, !outcome, &mut right_decides);
+                self.narrow(right, outcome, &mut right_decides);
+                self.join_maps(state, &right_decides);
+            }
+            Condition::Opaque(symbols) => {
+                for symbol_id in symbols {
+                    if let Some(type_id) = state.get_mut(symbol_id) {
+                        *type_id = TypeId::ANY;
+                    }
+                }
+            }
+        }
+    }
+
+    fn join_maps(
+        &self,
+        state: &mut FxHashMap<SymbolId, TypeId>,
+        other: &FxHashMap<SymbolId, TypeId>,
+    ) {
+        for (symbol_id, type_id) in state {
+            let Some(&other) = other.get(symbol_id) else { continue };
+            *type_id = self.join_types(*symbol_id, *type_id, other);
+        }
+    }
+
+    /// The union of two types of a variable.
+    fn join_types(&self, symbol_id: SymbolId, type_id: TypeId, other: TypeId) -> TypeId {
+        let mut types = self.types.borrow_mut();
+        let joined = types.union([type_id, other]);
+        // Keep the order of the declared type, e.g. `string | number` rather than
+        // `number | string` after `if (typeof x === "number") { ... }`
+        let declared = types.members(self.declared[&symbol_id]);
+        let joined_members = types.members(joined);
+        if joined_members.iter().all(|member| declared.contains(member)) {
+            types.union(declared.into_iter().filter(|member| joined_members.contains(member)))
+        } else {
+            joined
+        }
+    }
+}
+
+impl Analysis for Narrowing<'_> {
+    type Domain = State;
+
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn bottom(&self) -> Self::Domain {
+        None
+    }
+
+    fn boundary(&self) -> Self::Domain {
+        Some(self.declared.clone())
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) {
+        match (state.as_mut(), other) {
+            (_, None) => {}
+            (None, Some(other)) => *state = Some(other.clone()),
+            (Some(state), Some(other)) => self.join_maps(state, other),
+        }
+    }
+
+    fn transfer(&self, block: BlockNodeId, state: &mut Self::Domain) {
+        if let (Some(state), Some(events)) = (state, self.events.get(&block)) {
+            for event in events {
+                self.apply_event(event, state);
+            }
+        }
+    }
+
+    fn transfer_edge(
+        &self,
+        source: BlockNodeId,
+        target: BlockNodeId,
+        edge: &EdgeType,
+        state: &Self::Domain,
+    ) -> Option<Self::Domain> {
+        let state = state.as_ref()?;
+        if matches!(edge, EdgeType::NewFunction) {
+            // Captured variables may be assigned before the function is called
+            return Some(Some(state.keys().map(|&symbol_id| (symbol_id, TypeId::ANY)).collect()));
+        }
+        let branches = self.branches.get(&(source, target))?;
+        let mut state = state.clone();
+        for &(condition, outcome) in branches {
+            self.narrow(&self.conditions[condition], outcome, &mut state);
+        }
+        Some(Some(state))
+    }
+
+    fn follows_edge(&self, edge: &EdgeType) -> bool {
+        !matches!(edge, EdgeType::Unreachable)
+    }
+}
+
+impl Types {
+    /// The members of a union, with `boolean` split into `true | false`, or the type itself.
+    fn members(&self, type_id: TypeId) -> Vec<TypeId> {
+        let members = match self.get(type_id) {
+            Type::Union(members) => members.to_vec(),
+            _ => vec![type_id],
+        };
+        members
+            .into_iter()
+            .flat_map(|member| {
+                if member == TypeId::BOOLEAN {
+                    vec![TypeId::TRUE, TypeId::FALSE]
+                } else {
+                    vec![member]
+                }
+            })
+            .collect()
+    }
+
+    /// The union of the members of `type_id` for which `keep` is true.
+    fn filter(&mut self, type_id: TypeId, keep: impl Fn(&Self, TypeId) -> bool) -> TypeId {
+        let members = self
+            .members(type_id)
+            .into_iter()
+            .filter(|&member| keep(self, member))
+            .collect::<Vec<_>>();
+        self.union(members)
+    }
+
+    /// Whether a type has a single value, such as a literal, `null` or `undefined`.
+    fn is_unit(&self, type_id: TypeId) -> bool {
+        matches!(
+            self.get(type_id),
+            Type::Null
+                | Type::Undefined
+                | Type::BooleanLiteral(_)
+                | Type::NumberLiteral(_)
+                | Type::StringLiteral(_)
+                | Type::BigIntLiteral(_)
+        )
+    }
+
+    fn is_non_primitive(&self, type_id: TypeId) -> bool {
+        matches!(
+            self.get(type_id),
+            Type::NonPrimitive
+                | Type::Object(_)
+                | Type::Array(_)
+                | Type::Tuple(_)
+                | Type::Function(_)
+                | Type::Promise(_)
+        )
+    }
+
+    /// `type_id` narrowed by the outcome of `test`.
+    fn narrow(&mut self, type_id: TypeId, test: &Test, outcome: bool) -> TypeId {
+        if type_id == TypeId::ANY {
+            return type_id;
+        }
+        // Type parameters are narrowed to their intersection with the tested type, which is not
+        // represented yet, except when the test only removes members or keeps them as they are
+        if self.members(type_id).iter().any(|&member| self.is_instantiable(member))
+            && !matches!(
+                (test, outcome),
+                (Test::Truthy | Test::Equals { strict: false, .. }, _)
+                    | (Test::Equals { strict: true, .. }, false)
+            )
+        {
+            return TypeId::ANY;
+        }
+        let is_unknown = type_id == TypeId::UNKNOWN;
+        match test {
+            Test::Truthy if is_unknown => type_id,
+            Test::Truthy => self.filter(type_id, |types, member| {
+                let (truthy, falsy) = types.truthiness(member);
+                if outcome { truthy } else { falsy }
+            }),
+            Test::TypeOf(name) if is_unknown => {
+                if !outcome {
+                    return type_id;
+                }
+                match name.as_str() {
+                    "string" => TypeId::STRING,
+                    "number" => TypeId::NUMBER,
+                    "bigint" => TypeId::BIGINT,
+                    "boolean" => TypeId::BOOLEAN,
+                    "symbol" => TypeId::SYMBOL,
+                    "undefined" => TypeId::UNDEFINED,
+                    "object" => self.union([TypeId::NON_PRIMITIVE, TypeId::NULL]),
+                    // `Function` is not represented yet
+                    _ => TypeId::ANY,
+                }
+            }
+            Test::TypeOf(name) => self.filter(type_id, |types, member| {
+                let (is, is_not) = types.type_of_is(member, name);
+                if outcome { is } else { is_not }
+            }),
+            Test::Equals { value, strict: true } => {
+                if !outcome {
+                    return self.filter(type_id, |_, member| member != *value);
+                }
+                if is_unknown
+                    || self
+                        .members(type_id)
+                        .iter()
+                        .any(|&member| self.is_assignable(*value, member))
+                {
+                    *value
+                } else {
+                    TypeId::NEVER
+                }
+            }
+            Test::Equals { strict: false, .. } => {
+                if is_unknown {
+                    return if outcome {
+                        self.union([TypeId::NULL, TypeId::UNDEFINED])
+                    } else {
+                        type_id
+                    };
+                }
+                self.filter(type_id, |_, member| {
+                    matches!(member, TypeId::NULL | TypeId::UNDEFINED | TypeId::VOID) == outcome
+                })
+            }
+            Test::Property { name, value } => {
+                // The `length` of tuples, and properties of other objects, are not represented yet
+                if self.members(type_id).iter().any(|&member| {
+                    self.is_non_primitive(member) && !matches!(self.get(member), Type::Object(_))
+                }) {
+                    return TypeId::ANY;
+                }
+                self.filter(type_id, |types, member| {
+                    let Type::Object(object) = types.get(member) else { return true };
+                    let Some(property) = object.property(name) else { return !outcome };
+                    if outcome {
+                        types.is_assignable(*value, property.type_id)
+                            || (property.optional && *value == TypeId::UNDEFINED)
+                    } else {
+                        property.optional || property.type_id != *value
+                    }
+                })
+            }
+            Test::In(name) => self.filter(type_id, |types, member| {
+                let Type::Object(object) = types.get(member) else { return true };
+                match object.property(name) {
+                    Some(property) => outcome || property.optional,
+                    None => !outcome,
+                }
+            }),
+            // The class is not known, so the narrowed type only is if it can be just one object
+            Test::InstanceOf => {
+                let objects = self
+                    .members(type_id)
+                    .into_iter()
+                    .filter(|&member| self.is_non_primitive(member))
+                    .collect::<Vec<_>>();
+                match (outcome, objects.as_slice()) {
+                    (true, [object]) => *object,
+                    (false, []) => type_id,
+                    _ => TypeId::ANY,
+                }
+            }
+            Test::Is(TypeId::ANY) => TypeId::ANY,
+            Test::Is(predicate) if outcome => {
+                if is_unknown {
+                    return *predicate;
+                }
+                let predicate = *predicate;
+                match self.filter(type_id, |types, member| types.is_assignable(member, predicate)) {
+                    TypeId::NEVER => predicate,
+                    narrowed => narrowed,
+                }
+            }
+            Test::Is(predicate) => {
+                let predicate = *predicate;
+                self.filter(type_id, |types, member| !types.is_assignable(member, predicate))
+            }
+        }
+    }
+
+    /// Whether values of a union member may be truthy, and whether they may be falsy.
+    fn truthiness(&self, type_id: TypeId) -> (bool, bool) {
+        match self.get(type_id) {
+            Type::Undefined | Type::Null | Type::Void => (false, true),
+            Type::BooleanLiteral(value) => (*value, !*value),
+            Type::NumberLiteral(number) => {
+                let truthy = number.value() != 0.0 && !number.value().is_nan();
+                (truthy, !truthy)
+            }
+            Type::StringLiteral(value) => (!value.is_empty(), value.is_empty()),
+            Type::BigIntLiteral(digits) => {
+                let truthy = !digits.trim_start_matches('0').is_empty();
+                (truthy, !truthy)
+            }
+            Type::Never => (false, false),
+            Type::Symbol
+            | Type::NonPrimitive
+            | Type::Object(_)
+            | Type::Array(_)
+            | Type::Tuple(_)
+            | Type::Function(_)
+            | Type::Promise(_) => (true, false),
+            _ => (true, true),
+        }
+    }
+
+    /// Whether `typeof` of values of a union member may be `name`, and whether it may be
+    /// something else.
+    fn type_of_is(&self, type_id: TypeId, name: &str) -> (bool, bool) {
+        let type_of = match self.get(type_id) {
+            Type::String | Type::StringLiteral(_) => "string",
+            Type::Number | Type::NumberLiteral(_) => "number",
+            Type::BigInt | Type::BigIntLiteral(_) => "bigint",
+            Type::Boolean | Type::BooleanLiteral(_) => "boolean",
+            Type::Symbol => "symbol",
+            Type::Undefined | Type::Void => "undefined",
+            Type::Null | Type::Object(_) | Type::Array(_) | Type::Tuple(_) | Type::Promise(_) => {
+                "object"
+            }
+            Type::Function(_) => "function",
+            // `object` may be a function
+            Type::NonPrimitive => return (matches!(name, "object" | "function"), true),
+            _ => return (true, true),
+        };
+        (type_of == name, type_of != name)
+    }
+}
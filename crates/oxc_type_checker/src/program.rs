@@ -0,0 +1,172 @@
+//! Checking a set of files which import each other.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use rayon::prelude::*;
+
+use oxc_allocator::Allocator;
+use oxc_ast_visit::Visit;
+use oxc_diagnostics::Diagnostics;
+use oxc_parser::Parser;
+use oxc_resolver::{
+    ResolveOptions, Resolver, TsconfigDiscovery, TsconfigOptions, TsconfigReferences,
+};
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+use crate::{
+    Globals, TypeCheckerOptions, TypeCheckerVisitor, diagnostics,
+    modules::{ModuleContext, Modules},
+};
+
+/// Type checks a set of files, resolving their imports.
+///
+/// Imports are resolved to TypeScript files and declaration files as TypeScript's
+/// `moduleResolution: "bundler"` does, honouring the `paths` and `baseUrl` of the `tsconfig.json`,
+/// and the types of imported values and types are computed once and shared by all files. The
+/// files are checked in parallel.
+///
+/// ```ignore
+/// let ret = ProgramChecker::new(options).with_tsconfig("tsconfig.json").check_files(&paths);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ProgramChecker {
+    options: TypeCheckerOptions,
+    tsconfig: Option<PathBuf>,
+}
+
+/// The result of running [`ProgramChecker::check_files`].
+#[non_exhaustive]
+pub struct ProgramCheckerReturn {
+    /// The checked files, in the order they were given.
+    pub files: Vec<CheckedFile>,
+    /// Errors which are not about one of the files, such as a lib file which cannot be loaded.
+    pub diagnostics: Diagnostics,
+}
+
+/// A file checked by a [`ProgramChecker`].
+#[non_exhaustive]
+pub struct CheckedFile {
+    pub path: PathBuf,
+    /// Empty if the file cannot be read.
+    pub source_text: String,
+    /// Syntax and type errors in the file.
+    pub diagnostics: Diagnostics,
+}
+
+impl ProgramChecker {
+    pub fn new(options: TypeCheckerOptions) -> Self {
+        Self { options, tsconfig: None }
+    }
+
+    /// Use the `tsconfig.json` at `path`. Without it, the nearest `tsconfig.json` of each file is
+    /// used to resolve its imports.
+    ///
+    /// Unless [`TypeCheckerOptions::types`] is set, the global declarations of the packages listed
+    /// in its `compilerOptions.types` are included, or, if it does not list any, those of every
+    /// package in the `node_modules/@types` directories next to it and its ancestors.
+    #[must_use]
+    pub fn with_tsconfig<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.tsconfig = Some(path.into());
+        self
+    }
+
+    /// Type check the files at `paths`, which should be absolute.
+    pub fn check_files(&self, paths: &[PathBuf]) -> ProgramCheckerReturn {
+        let resolver = self.resolver();
+        let mut options = self.options.clone();
+        if options.types.is_empty()
+            && let Some(tsconfig) = &self.tsconfig
+        {
+            options.types = types(&resolver, tsconfig);
+        }
+        let mut diagnostics = Diagnostics::new();
+        let globals = Globals::load(&options).unwrap_or_else(|error| {
+            diagnostics.push(error);
+            None
+        });
+        let modules = Modules::new(options, globals, resolver);
+        let files = paths.par_iter().map(|path| check_file(&modules, path)).collect();
+        ProgramCheckerReturn { files, diagnostics }
+    }
+
+    fn resolver(&self) -> Resolver {
+        let tsconfig = match &self.tsconfig {
+            Some(path) => TsconfigDiscovery::Manual(TsconfigOptions {
+                config_file: path.clone(),
+                references: TsconfigReferences::Auto,
+            }),
+            None => TsconfigDiscovery::Auto,
+        };
+        Resolver::new(ResolveOptions {
+            condition_names: vec!["import".into()],
+            tsconfig: Some(tsconfig),
+            ..ResolveOptions::default()
+        })
+    }
+}
+
+fn check_file(modules: &Modules, path: &Path) -> CheckedFile {
+    let mut diagnostics = Diagnostics::new();
+    let Ok(source_text) = fs::read_to_string(path) else {
+        diagnostics.push(diagnostics::file_not_found(&path.to_string_lossy()));
+        return CheckedFile { path: path.to_path_buf(), source_text: String::new(), diagnostics };
+    };
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path(path).unwrap_or_default();
+    let parser_ret = Parser::new(&allocator, &source_text, source_type).parse();
+    if parser_ret.diagnostics.is_empty() {
+        let semantic = SemanticBuilder::new()
+            .with_build_nodes(true)
+            .with_cfg(true)
+            .build(&parser_ret.program)
+            .semantic;
+        let module = ModuleContext { modules, path, importers: &[] };
+        let mut visitor =
+            TypeCheckerVisitor::new(&semantic, modules.options(), modules.globals(), Some(module));
+        visitor.compute_narrowing();
+        visitor.visit_program(&parser_ret.program);
+        diagnostics = visitor.diagnostics;
+    } else {
+        diagnostics.extend(parser_ret.diagnostics);
+    }
+    let path = path.to_path_buf();
+    CheckedFile { path, source_text, diagnostics }
+}
+
+/// The declaration files of the packages whose global declarations are included, as selected by
+/// the `compilerOptions.types` of `tsconfig`.
+fn types(resolver: &Resolver, tsconfig: &Path) -> Vec<PathBuf> {
+    let names = types_option(tsconfig).unwrap_or_else(|| {
+        let directories = tsconfig.ancestors().skip(1).map(|dir| dir.join("node_modules/@types"));
+        let mut names = directories
+            .filter_map(|dir| fs::read_dir(dir).ok())
+            .flat_map(|entries| entries.filter_map(Result::ok))
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter(|name| !name.starts_with('.'))
+            .map(|name| format!("@types/{name}"))
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names.dedup();
+        names
+    });
+    names
+        .iter()
+        .filter_map(|name| {
+            let resolution = resolver.resolve_dts(tsconfig, name).ok()?;
+            Some(resolution.into_path_buf())
+        })
+        .collect()
+}
+
+/// The `compilerOptions.types` of `tsconfig`, if set.
+fn types_option(tsconfig: &Path) -> Option<Vec<String>> {
+    let mut json = fs::read_to_string(tsconfig).ok()?;
+    json_strip_comments::strip(&mut json).ok()?;
+    let value = serde_json::from_str::<serde_json::Value>(&json).ok()?;
+    let types = value.get("compilerOptions")?.get("types")?.as_array()?;
+    Some(types.iter().filter_map(|name| name.as_str().map(ToString::to_string)).collect())
+}
@@ -0,0 +1,719 @@
+//! Type representation.
+//!
+//! Types are interned in [`Types`] and referred to by [`TypeId`], so structurally identical types
+//! share an id and comparing ids is comparing types.
+
+use std::{
+    fmt::{self, Write},
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+use rustc_hash::FxHashMap;
+
+use oxc_index::{IndexVec, define_index_type};
+use oxc_str::CompactStr;
+use oxc_syntax::number::ToJsString;
+
+define_index_type! {
+    /// Identifies a [`Type`] interned in [`Types`].
+    pub struct TypeId = u32;
+}
+
+impl TypeId {
+    pub const ANY: Self = Self::from_usize(0);
+    pub const UNKNOWN: Self = Self::from_usize(1);
+    pub const NEVER: Self = Self::from_usize(2);
+    pub const VOID: Self = Self::from_usize(3);
+    pub const UNDEFINED: Self = Self::from_usize(4);
+    pub const NULL: Self = Self::from_usize(5);
+    pub const BOOLEAN: Self = Self::from_usize(6);
+    pub const NUMBER: Self = Self::from_usize(7);
+    pub const STRING: Self = Self::from_usize(8);
+    pub const BIGINT: Self = Self::from_usize(9);
+    pub const SYMBOL: Self = Self::from_usize(10);
+    /// The `object` type.
+    pub const NON_PRIMITIVE: Self = Self::from_usize(11);
+    pub const TRUE: Self = Self::from_usize(12);
+    pub const FALSE: Self = Self::from_usize(13);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Type {
+    Any,
+    Unknown,
+    Never,
+    Void,
+    Undefined,
+    Null,
+    Boolean,
+    Number,
+    String,
+    BigInt,
+    Symbol,
+    /// The `object` type.
+    NonPrimitive,
+    BooleanLiteral(bool),
+    NumberLiteral(NumberLiteral),
+    StringLiteral(CompactStr),
+    /// Holds the digits of the literal, without the `n` suffix.
+    BigIntLiteral(CompactStr),
+    /// Members are normalized by [`Types::union`]: there are at least two, none of which is a
+    /// union, and literals of a primitive which is also a member are removed.
+    Union(Box<[TypeId]>),
+    Object(ObjectType),
+    Array(TypeId),
+    Tuple(Box<[TypeId]>),
+    Function(FunctionType),
+    /// `Promise<T>`, until generic interfaces are represented.
+    Promise(TypeId),
+    /// A type parameter of a generic function or type, e.g. `T` in `<T>(value: T) => T`.
+    TypeParameter(TypeParameter),
+    /// `keyof T`, for a generic `T`. Evaluated when `T` is instantiated.
+    KeyOf(TypeId),
+    /// `T[K]`, for a generic `T` or `K`. Evaluated when they are instantiated.
+    IndexedAccess(TypeId, TypeId),
+    /// `T extends U ? X : Y`, for a generic `T` or `U`. Evaluated when they are instantiated.
+    Conditional(Box<ConditionalType>),
+    /// `{ [P in K]: X }`, for a generic `K`. Evaluated when it is instantiated.
+    Mapped(Box<MappedType>),
+    /// A generic type alias or interface, which is not a type itself but is instantiated with
+    /// type arguments, as in `Box<number>`.
+    Generic(GenericType),
+}
+
+/// A number literal type, stored as the bits of its value so that [`Type`] can be hashed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumberLiteral(u64);
+
+impl NumberLiteral {
+    pub fn new(value: f64) -> Self {
+        // `-0` and `0` are the same type
+        Self(if value == 0.0 { 0.0f64 } else { value }.to_bits())
+    }
+
+    pub fn value(self) -> f64 {
+        f64::from_bits(self.0)
+    }
+}
+
+/// A type literal or interface, e.g. `{ a: number; b?: string }`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ObjectType {
+    pub properties: Box<[Property]>,
+}
+
+impl ObjectType {
+    pub fn property(&self, name: &str) -> Option<&Property> {
+        self.properties.iter().find(|property| property.name == name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Property {
+    pub name: CompactStr,
+    pub type_id: TypeId,
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FunctionType {
+    /// The type parameters of a generic function, which are inferred from the arguments of each
+    /// call.
+    pub type_params: Box<[TypeId]>,
+    pub params: Box<[Parameter]>,
+    /// The rest parameter, whose type is e.g. `number[]` in `(...args: number[]) => void`.
+    pub rest: Option<Parameter>,
+    pub return_type: TypeId,
+}
+
+impl FunctionType {
+    /// The number of arguments a call must pass.
+    pub fn required_params(&self) -> usize {
+        self.params.iter().rposition(|param| !param.optional).map_or(0, |index| index + 1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Parameter {
+    pub name: CompactStr,
+    pub type_id: TypeId,
+    pub optional: bool,
+}
+
+/// Source of [`TypeParameter::id`]s, which are unique across all [`Types`] arenas so that type
+/// parameters keep their identity when imported.
+static NEXT_TYPE_PARAMETER_ID: AtomicU32 = AtomicU32::new(0);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypeParameter {
+    /// Distinguishes type parameters with the same name.
+    pub id: u32,
+    pub name: CompactStr,
+    /// The type in `T extends C`, which a type argument is assignable to.
+    pub constraint: Option<TypeId>,
+    /// The type in `T = D`, which is used when a type argument is neither given nor inferred.
+    pub default: Option<TypeId>,
+}
+
+impl TypeParameter {
+    pub fn new(name: CompactStr, constraint: Option<TypeId>, default: Option<TypeId>) -> Self {
+        let id = NEXT_TYPE_PARAMETER_ID.fetch_add(1, Ordering::Relaxed);
+        Self { id, name, constraint, default }
+    }
+}
+
+/// `check extends extends_type ? true_type : false_type`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConditionalType {
+    pub check: TypeId,
+    pub extends: TypeId,
+    pub true_type: TypeId,
+    pub false_type: TypeId,
+    /// The type parameters declared by `infer` in `extends`.
+    pub infer_params: Box<[TypeId]>,
+    /// Whether `check` is a type parameter, in which case the conditional type is evaluated for
+    /// each member of a union it is instantiated with.
+    pub distributive: bool,
+}
+
+/// `{ [param in constraint as name_type]?: template }`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MappedType {
+    /// The type parameter of the keys.
+    pub param: TypeId,
+    pub constraint: TypeId,
+    /// The type of the remapped keys, in `as name_type`.
+    pub name_type: Option<TypeId>,
+    pub template: TypeId,
+    pub optional: MappedModifier,
+    /// The `T` of a homomorphic mapped type such as `{ [P in keyof T]: T[P] }`, or of
+    /// `{ [P in K]: T[P] }` where `K extends keyof T`, whose properties keep their optionality.
+    pub modifiers_source: Option<TypeId>,
+}
+
+/// How a mapped type changes the optionality of properties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MappedModifier {
+    /// Properties are optional if they are in the source of a homomorphic mapped type.
+    Preserve,
+    /// `?` or `+?`
+    Add,
+    /// `-?`
+    Remove,
+}
+
+/// A generic type alias or interface.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GenericType {
+    pub params: Box<[TypeId]>,
+    /// The aliased type or interface, which refers to the type parameters.
+    pub body: TypeId,
+}
+
+/// Interner for [`Type`]s.
+#[derive(Debug, Clone)]
+pub struct Types {
+    types: IndexVec<TypeId, Type>,
+    ids: FxHashMap<Type, TypeId>,
+}
+
+impl Default for Types {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Types {
+    pub fn new() -> Self {
+        let mut types = Self { types: IndexVec::new(), ids: FxHashMap::default() };
+        for (type_id, ty) in [
+            (TypeId::ANY, Type::Any),
+            (TypeId::UNKNOWN, Type::Unknown),
+            (TypeId::NEVER, Type::Never),
+            (TypeId::VOID, Type::Void),
+            (TypeId::UNDEFINED, Type::Undefined),
+            (TypeId::NULL, Type::Null),
+            (TypeId::BOOLEAN, Type::Boolean),
+            (TypeId::NUMBER, Type::Number),
+            (TypeId::STRING, Type::String),
+            (TypeId::BIGINT, Type::BigInt),
+            (TypeId::SYMBOL, Type::Symbol),
+            (TypeId::NON_PRIMITIVE, Type::NonPrimitive),
+            (TypeId::TRUE, Type::BooleanLiteral(true)),
+            (TypeId::FALSE, Type::BooleanLiteral(false)),
+        ] {
+            let interned = types.intern(ty);
+            debug_assert_eq!(interned, type_id);
+        }
+        types
+    }
+
+    pub fn get(&self, type_id: TypeId) -> &Type {
+        &self.types[type_id]
+    }
+
+    pub fn intern(&mut self, ty: Type) -> TypeId {
+        if let Some(&type_id) = self.ids.get(&ty) {
+            return type_id;
+        }
+        let type_id = self.types.push(ty.clone());
+        self.ids.insert(ty, type_id);
+        type_id
+    }
+
+    pub fn boolean_literal(value: bool) -> TypeId {
+        if value { TypeId::TRUE } else { TypeId::FALSE }
+    }
+
+    /// The union of `members`, flattening nested unions and removing redundant members.
+    pub fn union(&mut self, members: impl IntoIterator<Item = TypeId>) -> TypeId {
+        let mut flattened: Vec<TypeId> = vec![];
+        for member in members {
+            match self.get(member) {
+                Type::Any => return TypeId::ANY,
+                Type::Unknown => return TypeId::UNKNOWN,
+                Type::Never => {}
+                Type::Union(members) => flattened.extend(members.iter().copied()),
+                _ => flattened.push(member),
+            }
+        }
+
+        let mut members: Vec<TypeId> = Vec::with_capacity(flattened.len());
+        for member in flattened {
+            if !members.contains(&member) {
+                members.push(member);
+            }
+        }
+        if members.contains(&TypeId::TRUE)
+            && members.contains(&TypeId::FALSE)
+            && let Some(first) =
+                members.iter_mut().find(|member| matches!(**member, TypeId::TRUE | TypeId::FALSE))
+        {
+            // The other literal is removed below, as `boolean` subsumes it
+            *first = TypeId::BOOLEAN;
+        }
+        let has = |members: &[TypeId], type_id| members.contains(&type_id);
+        let without_subsumed = members
+            .iter()
+            .copied()
+            .filter(|&member| match self.get(member) {
+                Type::BooleanLiteral(_) => !has(&members, TypeId::BOOLEAN),
+                Type::NumberLiteral(_) => !has(&members, TypeId::NUMBER),
+                Type::StringLiteral(_) => !has(&members, TypeId::STRING),
+                Type::BigIntLiteral(_) => !has(&members, TypeId::BIGINT),
+                _ => true,
+            })
+            .collect::<Vec<_>>();
+
+        match without_subsumed.as_slice() {
+            [] => TypeId::NEVER,
+            [member] => *member,
+            _ => self.intern(Type::Union(without_subsumed.into_boxed_slice())),
+        }
+    }
+
+    /// Intern a type of another [`Types`] arena, such as one of the types exported by a module.
+    pub fn import(&mut self, from: &Self, type_id: TypeId) -> TypeId {
+        let ty = match from.get(type_id) {
+            Type::Union(members) => {
+                Type::Union(members.iter().map(|&member| self.import(from, member)).collect())
+            }
+            Type::Object(object) => Type::Object(ObjectType {
+                properties: object
+                    .properties
+                    .iter()
+                    .map(|property| Property {
+                        type_id: self.import(from, property.type_id),
+                        ..property.clone()
+                    })
+                    .collect(),
+            }),
+            Type::Array(element) => Type::Array(self.import(from, *element)),
+            Type::Promise(value) => Type::Promise(self.import(from, *value)),
+            Type::Tuple(elements) => {
+                Type::Tuple(elements.iter().map(|&element| self.import(from, element)).collect())
+            }
+            Type::Function(function) => {
+                let mut import_param = |param: &Parameter| Parameter {
+                    type_id: self.import(from, param.type_id),
+                    ..param.clone()
+                };
+                let params = function.params.iter().map(&mut import_param).collect();
+                let rest = function.rest.as_ref().map(&mut import_param);
+                Type::Function(FunctionType {
+                    type_params: self.import_all(from, &function.type_params),
+                    params,
+                    rest,
+                    return_type: self.import(from, function.return_type),
+                })
+            }
+            Type::TypeParameter(param) => Type::TypeParameter(TypeParameter {
+                constraint: param.constraint.map(|constraint| self.import(from, constraint)),
+                default: param.default.map(|default| self.import(from, default)),
+                ..param.clone()
+            }),
+            Type::KeyOf(target) => Type::KeyOf(self.import(from, *target)),
+            Type::IndexedAccess(object, index) => {
+                Type::IndexedAccess(self.import(from, *object), self.import(from, *index))
+            }
+            Type::Conditional(conditional) => Type::Conditional(Box::new(ConditionalType {
+                check: self.import(from, conditional.check),
+                extends: self.import(from, conditional.extends),
+                true_type: self.import(from, conditional.true_type),
+                false_type: self.import(from, conditional.false_type),
+                infer_params: self.import_all(from, &conditional.infer_params),
+                distributive: conditional.distributive,
+            })),
+            Type::Mapped(mapped) => Type::Mapped(Box::new(MappedType {
+                param: self.import(from, mapped.param),
+                constraint: self.import(from, mapped.constraint),
+                name_type: mapped.name_type.map(|name_type| self.import(from, name_type)),
+                template: self.import(from, mapped.template),
+                optional: mapped.optional,
+                modifiers_source: mapped.modifiers_source.map(|source| self.import(from, source)),
+            })),
+            Type::Generic(generic) => Type::Generic(GenericType {
+                params: self.import_all(from, &generic.params),
+                body: self.import(from, generic.body),
+            }),
+            ty => ty.clone(),
+        };
+        self.intern(ty)
+    }
+
+    fn import_all(&mut self, from: &Self, type_ids: &[TypeId]) -> Box<[TypeId]> {
+        type_ids.iter().map(|&type_id| self.import(from, type_id)).collect()
+    }
+
+    /// The type with its literal types replaced by their primitive, e.g. `number` for `1`, and
+    /// `{ a: string }` for `{ a: "a" }`.
+    pub fn widen(&mut self, type_id: TypeId) -> TypeId {
+        match self.get(type_id).clone() {
+            Type::BooleanLiteral(_) => TypeId::BOOLEAN,
+            Type::NumberLiteral(_) => TypeId::NUMBER,
+            Type::StringLiteral(_) => TypeId::STRING,
+            Type::BigIntLiteral(_) => TypeId::BIGINT,
+            Type::Union(members) => {
+                let members = members.iter().map(|&member| self.widen(member)).collect::<Vec<_>>();
+                self.union(members)
+            }
+            Type::Object(object) => {
+                let properties = object
+                    .properties
+                    .iter()
+                    .map(|property| Property {
+                        type_id: self.widen(property.type_id),
+                        ..property.clone()
+                    })
+                    .collect();
+                self.intern(Type::Object(ObjectType { properties }))
+            }
+            Type::Array(element) => {
+                let element = self.widen(element);
+                self.intern(Type::Array(element))
+            }
+            Type::Tuple(elements) => {
+                let elements = elements.iter().map(|&element| self.widen(element)).collect();
+                self.intern(Type::Tuple(elements))
+            }
+            _ => type_id,
+        }
+    }
+
+    /// The type with its literal types replaced by their primitive, unlike [`Types::widen`] not
+    /// those of properties, as TypeScript shows the source type of an error.
+    pub fn widen_literals(&mut self, type_id: TypeId) -> TypeId {
+        match self.get(type_id) {
+            Type::BooleanLiteral(_) => TypeId::BOOLEAN,
+            Type::NumberLiteral(_) => TypeId::NUMBER,
+            Type::StringLiteral(_) => TypeId::STRING,
+            Type::BigIntLiteral(_) => TypeId::BIGINT,
+            Type::Union(members) => {
+                let members = members.clone();
+                let members =
+                    members.iter().map(|&member| self.widen_literals(member)).collect::<Vec<_>>();
+                self.union(members)
+            }
+            Type::Tuple(elements) => {
+                let elements = elements.clone();
+                let elements =
+                    elements.iter().map(|&element| self.widen_literals(element)).collect();
+                self.intern(Type::Tuple(elements))
+            }
+            _ => type_id,
+        }
+    }
+
+    /// The type of `await` on a value of type `type_id`, e.g. `number` for `Promise<number>`.
+    pub fn awaited(&mut self, type_id: TypeId) -> TypeId {
+        match self.get(type_id).clone() {
+            Type::Promise(value) => value,
+            Type::Union(members) => {
+                let members =
+                    members.iter().map(|&member| self.awaited(member)).collect::<Vec<_>>();
+                self.union(members)
+            }
+            _ => type_id,
+        }
+    }
+
+    /// Whether values of the type may be promises or other objects with a `then` method, which
+    /// `await` unwraps.
+    pub fn is_promise_like(&self, type_id: TypeId) -> bool {
+        match self.get(type_id) {
+            Type::Promise(_) => true,
+            Type::Union(members) => members.iter().any(|&member| self.is_promise_like(member)),
+            Type::Object(object) => object
+                .property("then")
+                .is_some_and(|then| matches!(self.get(then.type_id), Type::Function(_))),
+            _ => false,
+        }
+    }
+
+    /// TypeScript's `ts.TypeFlags` for the type, for consumers written against TypeScript's API.
+    ///
+    /// `boolean` is only `Boolean`, although TypeScript represents it as the union `true | false`.
+    pub fn typescript_flags(&self, type_id: TypeId) -> u32 {
+        match self.get(type_id) {
+            Type::Any => 1,
+            Type::Unknown => 1 << 1,
+            Type::String => 1 << 2,
+            Type::Number => 1 << 3,
+            Type::Boolean => 1 << 4,
+            Type::BigInt => 1 << 6,
+            Type::StringLiteral(_) => 1 << 7,
+            Type::NumberLiteral(_) => 1 << 8,
+            Type::BooleanLiteral(_) => 1 << 9,
+            Type::BigIntLiteral(_) => 1 << 11,
+            Type::Symbol => 1 << 12,
+            Type::Void => 1 << 14,
+            Type::Undefined => 1 << 15,
+            Type::Null => 1 << 16,
+            Type::Never => 1 << 17,
+            Type::TypeParameter(_) => 1 << 18,
+            Type::Object(_)
+            | Type::Array(_)
+            | Type::Tuple(_)
+            | Type::Function(_)
+            | Type::Promise(_)
+            | Type::Mapped(_)
+            | Type::Generic(_) => 1 << 19,
+            Type::Union(_) => 1 << 20,
+            Type::KeyOf(_) => 1 << 22,
+            Type::IndexedAccess(..) => 1 << 23,
+            Type::Conditional(_) => 1 << 24,
+            Type::NonPrimitive => 1 << 26,
+        }
+    }
+
+    /// The type without `null` and `undefined`.
+    pub fn non_nullable(&mut self, type_id: TypeId) -> TypeId {
+        match self.get(type_id) {
+            Type::Null | Type::Undefined => TypeId::NEVER,
+            Type::Union(members) => {
+                let members = members
+                    .iter()
+                    .copied()
+                    .filter(|&member| member != TypeId::NULL && member != TypeId::UNDEFINED)
+                    .collect::<Vec<_>>();
+                self.union(members)
+            }
+            _ => type_id,
+        }
+    }
+
+    /// Whether a type includes types with a single value, such as literals, `null` or `undefined`.
+    ///
+    /// Error messages show the primitive of a literal source type unless the target is such a type.
+    pub fn could_have_singleton_types(&self, type_id: TypeId) -> bool {
+        match self.get(type_id) {
+            Type::Null
+            | Type::Undefined
+            | Type::BooleanLiteral(_)
+            | Type::NumberLiteral(_)
+            | Type::StringLiteral(_)
+            | Type::BigIntLiteral(_) => true,
+            // `boolean` is `true | false`, which only matters inside a union
+            Type::Union(members) => members.iter().any(|&member| {
+                member == TypeId::BOOLEAN || self.could_have_singleton_types(member)
+            }),
+            _ => false,
+        }
+    }
+
+    /// Format a type the way TypeScript prints it in diagnostics.
+    pub fn display(&self, type_id: TypeId) -> String {
+        let mut output = String::new();
+        // Writing to a `String` cannot fail
+        let _ = self.write_type(&mut output, type_id);
+        output
+    }
+
+    fn write_type(&self, f: &mut String, type_id: TypeId) -> fmt::Result {
+        match self.get(type_id) {
+            Type::Any => f.write_str("any"),
+            Type::Unknown => f.write_str("unknown"),
+            Type::Never => f.write_str("never"),
+            Type::Void => f.write_str("void"),
+            Type::Undefined => f.write_str("undefined"),
+            Type::Null => f.write_str("null"),
+            Type::Boolean => f.write_str("boolean"),
+            Type::Number => f.write_str("number"),
+            Type::String => f.write_str("string"),
+            Type::BigInt => f.write_str("bigint"),
+            Type::Symbol => f.write_str("symbol"),
+            Type::NonPrimitive => f.write_str("object"),
+            Type::BooleanLiteral(value) => write!(f, "{value}"),
+            Type::NumberLiteral(value) => f.write_str(&value.value().to_js_string()),
+            Type::StringLiteral(value) => {
+                f.write_char('"')?;
+                for c in value.chars() {
+                    if matches!(c, '"' | '\\') {
+                        f.write_char('\\')?;
+                    }
+                    f.write_char(c)?;
+                }
+                f.write_char('"')
+            }
+            Type::BigIntLiteral(value) => write!(f, "{value}n"),
+            Type::Union(members) => {
+                for (index, &member) in members.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(" | ")?;
+                    }
+                    self.write_type_parenthesized(f, member)?;
+                }
+                Ok(())
+            }
+            Type::Object(object) => {
+                if object.properties.is_empty() {
+                    return f.write_str("{}");
+                }
+                f.write_str("{ ")?;
+                for property in &object.properties {
+                    let optional = if property.optional { "?" } else { "" };
+                    write!(f, "{}{optional}: ", property.name)?;
+                    self.write_type(f, property.type_id)?;
+                    f.write_str("; ")?;
+                }
+                f.write_char('}')
+            }
+            Type::Array(element) => {
+                self.write_type_parenthesized(f, *element)?;
+                f.write_str("[]")
+            }
+            Type::Promise(value) => {
+                f.write_str("Promise<")?;
+                self.write_type(f, *value)?;
+                f.write_char('>')
+            }
+            Type::Tuple(elements) => {
+                f.write_char('[')?;
+                for (index, &element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    self.write_type(f, element)?;
+                }
+                f.write_char(']')
+            }
+            Type::Function(function) => {
+                if !function.type_params.is_empty() {
+                    f.write_char('<')?;
+                    for (index, &param) in function.type_params.iter().enumerate() {
+                        if index > 0 {
+                            f.write_str(", ")?;
+                        }
+                        self.write_type_parameter_declaration(f, param)?;
+                    }
+                    f.write_char('>')?;
+                }
+                f.write_char('(')?;
+                for (index, param) in function.params.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    let optional = if param.optional { "?" } else { "" };
+                    write!(f, "{}{optional}: ", param.name)?;
+                    self.write_type(f, param.type_id)?;
+                }
+                if let Some(rest) = &function.rest {
+                    if !function.params.is_empty() {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "...{}: ", rest.name)?;
+                    self.write_type(f, rest.type_id)?;
+                }
+                f.write_str(") => ")?;
+                self.write_type(f, function.return_type)
+            }
+            Type::TypeParameter(param) => f.write_str(&param.name),
+            Type::KeyOf(target) => {
+                f.write_str("keyof ")?;
+                self.write_type_parenthesized(f, *target)
+            }
+            Type::IndexedAccess(object, index) => {
+                self.write_type_parenthesized(f, *object)?;
+                f.write_char('[')?;
+                self.write_type(f, *index)?;
+                f.write_char(']')
+            }
+            Type::Conditional(conditional) => {
+                self.write_type_parenthesized(f, conditional.check)?;
+                f.write_str(" extends ")?;
+                self.write_type_parenthesized(f, conditional.extends)?;
+                f.write_str(" ? ")?;
+                self.write_type(f, conditional.true_type)?;
+                f.write_str(" : ")?;
+                self.write_type(f, conditional.false_type)
+            }
+            Type::Mapped(mapped) => {
+                f.write_str("{ [")?;
+                self.write_type(f, mapped.param)?;
+                f.write_str(" in ")?;
+                self.write_type(f, mapped.constraint)?;
+                if let Some(name_type) = mapped.name_type {
+                    f.write_str(" as ")?;
+                    self.write_type(f, name_type)?;
+                }
+                f.write_char(']')?;
+                f.write_str(match mapped.optional {
+                    MappedModifier::Preserve => "",
+                    MappedModifier::Add => "?",
+                    MappedModifier::Remove => "-?",
+                })?;
+                f.write_str(": ")?;
+                self.write_type(f, mapped.template)?;
+                f.write_str("; }")
+            }
+            Type::Generic(generic) => self.write_type(f, generic.body),
+        }
+    }
+
+    /// Writes `T extends C = D` for a type parameter of a generic function.
+    fn write_type_parameter_declaration(&self, f: &mut String, type_id: TypeId) -> fmt::Result {
+        self.write_type(f, type_id)?;
+        let Type::TypeParameter(param) = self.get(type_id) else { return Ok(()) };
+        if let Some(constraint) = param.constraint {
+            f.write_str(" extends ")?;
+            self.write_type(f, constraint)?;
+        }
+        if let Some(default) = param.default {
+            f.write_str(" = ")?;
+            self.write_type(f, default)?;
+        }
+        Ok(())
+    }
+
+    /// Writes unions, function types and conditional types in parentheses, for use as array
+    /// elements and union members.
+    fn write_type_parenthesized(&self, f: &mut String, type_id: TypeId) -> fmt::Result {
+        if matches!(self.get(type_id), Type::Union(_) | Type::Function(_) | Type::Conditional(_)) {
+            f.write_char('(')?;
+            self.write_type(f, type_id)?;
+            f.write_char(')')
+        } else {
+            self.write_type(f, type_id)
+        }
+    }
+}
@@ -0,0 +1,22 @@
+let a: number = 1;
+a = "a";
+a = 2;
+
+let b = "b";
+b = 1;
+
+let c: string | undefined;
+c = undefined;
+c = null;
+
+const point: { x: number; y?: number } = { x: 1 };
+point.x = "1";
+point.y = undefined;
+point.y = "2";
+
+// `let` without an initializer or with `null` is not checked
+let d;
+d = 1;
+d = "d";
+let e = null;
+e = 1;
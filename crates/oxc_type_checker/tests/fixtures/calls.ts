@@ -0,0 +1,37 @@
+function f(a: number, b: string): void {}
+
+f(1, "b");
+f("a", "b");
+f(1, 2);
+f(1);
+f(1, "b", 3, 4);
+
+function g(a: number, b?: string, c = 1) {}
+
+g(1);
+g(1, undefined);
+g(1, "b", "c");
+g();
+
+function h(a: string, ...rest: number[]) {}
+
+h("a", 1, 2);
+h("a", 1, "2");
+h();
+
+const i = (point: { x: number }) => point.x;
+i({ x: "1" });
+i({});
+
+// Spread arguments and unknown callees are not checked
+const args = [1, "b"];
+f(...args);
+unknown(1, 2, 3);
+
+// Callbacks are checked against function types
+function map(callback: (value: number) => string) {}
+map((value: number) => "a");
+map((value: string) => "a");
+map((value: number) => 1);
+map(() => "a");
+map((value: number, index: number) => "a");
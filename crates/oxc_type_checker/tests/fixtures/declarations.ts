@@ -0,0 +1,50 @@
+// Annotated declarations
+const a: number = "a";
+let b: string = 1;
+const c: "x" | "y" = "z";
+const d: boolean = null;
+let e: number | undefined = undefined;
+let f: string | null = null;
+const g: unknown = 1;
+const h: any = 1;
+const i: 1n = 1n;
+const j: object = "j";
+
+// Inferred from initializers
+let k = 1;
+const l: string = k;
+const m = "m";
+const n: "m" = m;
+const o: "n" = m;
+let p = "p";
+const q: "p" = p;
+
+// Type aliases and interfaces
+type Id = string | number;
+interface Point {
+  x: number;
+  y: number;
+  label?: string;
+}
+const id: Id = true;
+const point: Point = { x: 1, y: "2" };
+const missing: Point = { x: 1 };
+const labelled: Point = { x: 1, y: 2, label: 3 };
+const nested: { point: Point } = { point: { x: 1, y: 2 } };
+const fromVariable = { x: 1 };
+const notAPoint: Point = fromVariable;
+
+// Arrays and tuples
+const numbers: number[] = [1, 2, "3"];
+const strings: Array<string> = ["a", 1];
+const tuple: [number, string] = [1, 2];
+const tupleLength: [number, string] = [1];
+const empty: number[] = [];
+const mixed = [1, "a"];
+const onlyNumbers: number[] = mixed;
+const first: number = tuple[0];
+const second: number = tuple[1];
+
+// Unsupported types are not checked
+const generic: Map<string, number> = 1;
+const intersection: { a: number } & { b: string } = 3;
@@ -0,0 +1,79 @@
+// Type arguments are inferred from the arguments of calls
+function identity<T>(value: T): T {
+  return value;
+}
+const a: number = identity(1);
+const b: string = identity(1);
+const c: number = identity<string>("c");
+identity<number>("d");
+
+function first<T>(items: T[]): T | undefined {
+  return items[0];
+}
+const e: number | undefined = first([1, 2]);
+const f: string | undefined = first([1, 2]);
+
+function pair<K extends string, V>(key: K, value: V): { key: K; value: V } {
+  return { key, value };
+}
+const g: { key: "g"; value: number } = pair("g", 1);
+const h: { key: "h"; value: string } = pair("h", 1);
+
+function same<T>(a: T, b: T): T {
+  return a;
+}
+same(1, "b");
+
+function withDefault<T = string>(): T {
+  return null as any;
+}
+const i: number = withDefault();
+
+// Values of a type parameter are only assignable to it and to what its constraint is
+function generic<T, U extends string>(t: T, u: U) {
+  const j: T = t;
+  const k: string = t;
+  const l: string = u;
+  const m: U = "m";
+  if (typeof t === "string") {
+    const n: string = t;
+  }
+}
+
+// `keyof`, indexed access types, conditional types and mapped types
+interface Point {
+  x: number;
+  y: number;
+  label?: string;
+}
+const o: keyof Point = "z";
+const p: Point["label"] = 1;
+
+function get<T, K extends keyof T>(object: T, key: K): T[K] {
+  return object[key];
+}
+const q: string = get({ q: 1 }, "q");
+
+type IsString<T> = T extends string ? "yes" : "no";
+const r: IsString<"r"> = "no";
+const s: IsString<"s" | 1> = "maybe";
+
+type Unpacked<T> = T extends (infer U)[] ? U : T;
+const t: Unpacked<string[]> = 1;
+
+type Optional<T> = { [K in keyof T]?: T[K] };
+const u: Optional<Point> = { x: "u" };
+const v: Optional<Point> = {};
+
+type Prefixed<T> = { [K in keyof T as K extends "x" ? "px" : never]: T[K] };
+const w: Prefixed<Point> = { px: "w" };
+
+type Box<T> = { value: T };
+const x: Box<number> = { value: "x" };
+
+// References to a type alias within its own declaration are not checked
+type List<T> = { head: T; tail: List<T> | null };
+const y: List<number> = { head: 1, tail: { head: "y", tail: null } };
+type Ping<T> = Pong<T>;
+type Pong<T> = Ping<T>;
+const z: Ping<number> = "z";
@@ -0,0 +1,38 @@
+// None of these are errors
+const a: number = 1 + 2;
+const b: string = "b" + 1;
+const c: boolean = 1 < 2;
+const d: string = typeof a;
+const e: number = -1;
+const f: -1 = -1;
+const g: bigint = 1n * 2n;
+const h: string = `h${a}`;
+const i: "i" = `i`;
+const j: number = [1, 2].length;
+const k: string = a > 0 ? "k" : "";
+const l: "x" | "y" = Math.random() ? "x" : "y";
+const m: number = (a as any) ?? 1;
+const n: string = (1 as unknown) as string;
+
+// References to union typed variables are narrowed
+function narrow(x: string | undefined): string {
+  if (x) {
+    return x;
+  }
+  return "";
+}
+
+// Recursive functions
+function fib(n: number): number {
+  return n < 2 ? n : fib(n - 1) + fib(n - 2);
+}
+function loop() {
+  return loop();
+}
+
+// These are errors
+const o: string = 1 + 2;
+const p: number = "p" + 1;
+const q: string = !a;
+const r: 1 = -1;
+const s: string = a > 0 ? "s" : 0;
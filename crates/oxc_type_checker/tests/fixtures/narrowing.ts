@@ -0,0 +1,162 @@
+// None of these are errors
+function truthiness(x: string | undefined): string {
+  if (x) {
+    return x;
+  }
+  return "";
+}
+
+function early_return(x: string | null): string {
+  if (!x) return "";
+  return x;
+}
+
+function type_of(x: string | number, y: unknown): string {
+  if (typeof x === "number") {
+    const n: number = x;
+    return "" + n;
+  }
+  if (typeof y !== "string") {
+    return x;
+  }
+  return y;
+}
+
+function equality(x: "a" | "b" | null, y: string | undefined) {
+  if (x === "a") {
+    const a: "a" = x;
+  } else if (x !== null) {
+    const b: "b" = x;
+  }
+  if (y != null) {
+    const s: string = y;
+  }
+  if (y === undefined) {
+    const u: undefined = y;
+  }
+}
+
+type Shape = { kind: "circle"; radius: number } | { kind: "square"; size: number };
+function discriminant(shape: Shape): number {
+  if (shape.kind === "circle") {
+    const circle: { kind: "circle"; radius: number } = shape;
+    return circle.radius;
+  }
+  const square: { kind: "square"; size: number } = shape;
+  return square.size;
+}
+
+type Fish = { swim: () => void };
+type Bird = { fly: () => void };
+function in_operator(animal: Fish | Bird) {
+  if ("swim" in animal) {
+    const fish: Fish = animal;
+  } else {
+    const bird: Bird = animal;
+  }
+}
+
+function instance_of(x: string | Date) {
+  if (x instanceof Date) {
+    const date: Date = x;
+  } else {
+    const s: string = x;
+  }
+}
+
+function isString(value: unknown): value is string {
+  return typeof value === "string";
+}
+function predicate(x: string | number): string {
+  if (isString(x)) {
+    return x;
+  }
+  const n: number = x;
+  return "";
+}
+
+function assertIsString(value: unknown): asserts value is string {}
+function assertion(x: string | number): string {
+  assertIsString(x);
+  return x;
+}
+
+function logical(x: string | undefined, y: number | undefined): string {
+  const a: string = x && x.length ? x : "";
+  const b: string = x || "b";
+  if (x && y) {
+    const n: number = y;
+    return x;
+  }
+  if (typeof x === "string" || typeof y === "number") {
+    return "";
+  }
+  const u: undefined = x;
+  return "";
+}
+
+function assignments(x: string | number) {
+  x = "a";
+  const s: string = x;
+  let y: string | undefined;
+  y = "b";
+  const t: string = y;
+}
+
+function logical_assignments(x: string | undefined, y: number | null) {
+  x ||= "a";
+  const s: string = x;
+  y ??= 0;
+  const n: number = y;
+}
+
+function loops(x: string | undefined) {
+  while (x) {
+    const s: string = x;
+  }
+  for (; typeof x === "string"; ) {
+    const s: string = x;
+  }
+}
+
+// Captured variables may be reassigned before a callback is called, so they are not checked
+function captured(x: string | undefined) {
+  if (x) {
+    [1].map(() => {
+      const n: number = x;
+    });
+  }
+}
+
+// Narrowing which is not understood leaves the variable unchecked
+function unknown_narrowing(x: string | string[]) {
+  if (Array.isArray(x)) {
+    const a: string[] = x;
+  }
+  switch (typeof x) {
+    case "string":
+      const s: string = x;
+  }
+}
+
+// These are errors
+function errors(x: string | undefined, y: string | number, z: unknown) {
+  const a: string = x;
+  if (x) {
+    const n: number = x;
+  } else {
+    const s: string = x;
+  }
+  if (typeof y === "string") {
+    const n: number = y;
+  }
+  const b: string = y;
+  if (typeof z === "number") {
+    const s: string = z;
+  }
+  const c: string = z;
+  x = undefined;
+  const d: string = x;
+  x &&= "a";
+  const e: string = x;
+}
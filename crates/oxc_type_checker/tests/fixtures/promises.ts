@@ -0,0 +1,16 @@
+async function fetchCount() {
+  return 1;
+}
+
+const fetchName = async (): Promise<string> => "name";
+const fetchNothing = async () => {};
+
+async function main() {
+  const count: number = await fetchCount();
+  const name: number = await fetchName();
+  const nothing: number = await fetchNothing();
+  const promise: Promise<number> = fetchCount();
+  const other: Promise<string> = fetchCount();
+  const notAPromise: Promise<number> = 1;
+  const value: string = await 1;
+}
@@ -0,0 +1,52 @@
+function a(): number {
+  return "a";
+}
+
+function b(): string {
+  if (Math.random()) {
+    return "b";
+  }
+  return 1;
+}
+
+const c = (): number => "c";
+
+const d = (): boolean => {
+  return 1;
+};
+
+function e(): void {
+  return;
+}
+
+function f(): number {
+  // Nested functions are checked against their own return type
+  const inner = () => "inner";
+  function g(): string {
+    return 1;
+  }
+  return inner.length;
+}
+
+// Async functions and generators are not checked yet
+async function h(): Promise<number> {
+  return "h";
+}
+function* i(): Generator<number> {
+  return "i";
+}
+
+// Inferred return types
+function j() {
+  return 1;
+}
+const k: string = j();
+const l = () => ({ x: "l" });
+const m: { x: number } = l();
+function n(x: boolean) {
+  if (x) {
+    return 1;
+  }
+  return;
+}
+const o: number = n(true);
@@ -0,0 +1,23 @@
+/// <reference no-default-lib="true"/>
+
+interface Console {
+    log(...data: any[]): void;
+}
+declare var console: Console;
+
+type TimerHandler = string | Function;
+declare function setTimeout(handler: TimerHandler, timeout?: number): number;
+
+interface Body {
+    readonly bodyUsed: boolean;
+    json(): Promise<any>;
+    text(): Promise<string>;
+}
+
+interface Response extends Body {
+    readonly ok: boolean;
+    readonly status: number;
+}
+
+type RequestInfo = Request | string;
+declare function fetch(input: RequestInfo | URL, init?: RequestInit): Promise<Response>;
@@ -0,0 +1,11 @@
+/// <reference no-default-lib="true"/>
+
+interface Math {
+    sign(x: number): number;
+}
+
+interface ArrayConstructor {
+    from<T>(arrayLike: ArrayLike<T>): T[];
+    from<T, U>(arrayLike: ArrayLike<T>, mapfn: (v: T, k: number) => U, thisArg?: any): U[];
+    of<T>(...items: T[]): T[];
+}
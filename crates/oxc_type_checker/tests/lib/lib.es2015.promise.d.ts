@@ -0,0 +1,12 @@
+/// <reference no-default-lib="true"/>
+
+interface PromiseConstructor {
+    readonly prototype: Promise<any>;
+    new <T>(executor: (resolve: (value: T | PromiseLike<T>) => void, reject: (reason?: any) => void) => void): Promise<T>;
+    reject<T = never>(reason?: any): Promise<T>;
+    resolve(): Promise<void>;
+    resolve<T>(value: T): Promise<Awaited<T>>;
+    resolve<T>(value: T | PromiseLike<T>): Promise<Awaited<T>>;
+}
+
+declare var Promise: PromiseConstructor;
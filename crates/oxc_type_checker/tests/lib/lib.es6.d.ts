@@ -0,0 +1,3 @@
+/// <reference no-default-lib="true"/>
+/// <reference lib="es2015" />
+/// <reference lib="dom" />
@@ -0,0 +1,350 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Expression, Statement};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_syntax::es_target::ESTarget;
+use oxc_type_checker::{Globals, ProgramChecker, TypeChecker, TypeCheckerOptions};
+
+fn check(path: &Path, source_text: &str) -> String {
+    check_with_options(path, source_text, TypeCheckerOptions::default())
+}
+
+fn check_with_options(path: &Path, source_text: &str, options: TypeCheckerOptions) -> String {
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path(path).unwrap();
+    let parser_ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(
+        parser_ret.diagnostics.is_empty(),
+        "Parser errors for {}: {:?}",
+        path.display(),
+        parser_ret.diagnostics
+    );
+    let semantic_ret =
+        SemanticBuilder::new().with_build_nodes(true).with_cfg(true).build(&parser_ret.program);
+
+    let checker_ret =
+        TypeChecker::new().with_options(options).check(&parser_ret.program, &semantic_ret.semantic);
+    let source = Arc::new(source_text.to_string());
+    checker_ret
+        .diagnostics
+        .into_iter()
+        .map(|d| d.with_source_code(Arc::clone(&source)))
+        .fold(String::new(), |s, error| s + &format!("{error:?}"))
+}
+
+#[test]
+fn snapshots() {
+    insta::glob!("fixtures/*.{ts,tsx}", |path| {
+        let source_text = fs::read_to_string(path).unwrap();
+        let snapshot = check(path, &source_text);
+        let name = path.file_stem().unwrap().to_str().unwrap();
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!(name, snapshot);
+        });
+    });
+}
+
+fn lib_options(target: ESTarget, lib: Option<&[&str]>) -> TypeCheckerOptions {
+    TypeCheckerOptions {
+        lib_path: Some(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/lib")),
+        target,
+        lib: lib.map(|lib| lib.iter().map(ToString::to_string).collect()),
+        ..TypeCheckerOptions::default()
+    }
+}
+
+/// Messages of the diagnostics for `source_text`.
+fn messages(source_text: &str, options: TypeCheckerOptions) -> Vec<String> {
+    let allocator = Allocator::default();
+    let parser_ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+    let semantic_ret =
+        SemanticBuilder::new().with_build_nodes(true).with_cfg(true).build(&parser_ret.program);
+    let checker_ret =
+        TypeChecker::new().with_options(options).check(&parser_ret.program, &semantic_ret.semantic);
+    checker_ret.diagnostics.iter().map(|diagnostic| diagnostic.message.to_string()).collect()
+}
+
+#[test]
+fn globals() {
+    let source_text = r#"
+        const a: string = parseInt("1");
+        parseInt(1);
+        const b: number = Math.sign(-1) + Math.max(1, 2) + Math.PI;
+        const c: string = Math.random();
+        console.log(1, "a");
+        setTimeout(() => {}, 1);
+        setTimeout(1);
+        const d: number = unknownGlobal;
+        const e: TimerHandler = 1;
+    "#;
+    let expected = [
+        "TS2322: Type 'number' is not assignable to type 'string'.",
+        "TS2345: Argument of type 'number' is not assignable to parameter of type 'string'.",
+        "TS2322: Type 'number' is not assignable to type 'string'.",
+    ];
+    // `TimerHandler` is `string | Function`, and `Function` is not declared
+    assert_eq!(messages(source_text, lib_options(ESTarget::ES2015, None)), expected);
+
+    // Without lib files, globals are `any`
+    assert!(messages(source_text, TypeCheckerOptions::default()).is_empty());
+
+    // `lib` replaces the default lib of the target
+    let source_text = "const a: string = Math.sign(1); const b: string = Math.random();";
+    assert_eq!(
+        messages(source_text, lib_options(ESTarget::ES2015, Some(&["ES5"]))),
+        ["TS2322: Type 'number' is not assignable to type 'string'."]
+    );
+}
+
+#[test]
+fn globals_are_cached() {
+    let options = lib_options(ESTarget::ES2015, Some(&["es2015", "dom"]));
+    let first = Globals::load(&options).unwrap().unwrap();
+    let second = Globals::load(&options).unwrap().unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+    assert!(first.value("console").is_some());
+    assert!(first.type_declaration("Math").is_some());
+}
+
+#[test]
+fn missing_lib_file() {
+    let messages = messages("const a: number = 1;", lib_options(ESTarget::ESNext, None));
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].starts_with("TS6053: File '"));
+    assert!(messages[0].ends_with("lib.esnext.full.d.ts' not found."));
+}
+
+#[test]
+fn utility_types() {
+    let source_text = r#"
+        interface User { id: number; name: string; email?: string }
+        const a: Partial<User> = {};
+        const b: Required<User> = { id: 1, name: "b" };
+        const c: Pick<User, "id" | "name"> = { id: 1 };
+        const d: Omit<User, "email"> = { id: 1, name: 2 };
+        const e: Record<"a" | "b", number> = { a: 1, b: "e" };
+        const f: Exclude<"a" | "b" | "c", "a"> = "a";
+        const g: Extract<string | number, number> = "g";
+        function fn(x: number, y: string): boolean { return true; }
+        const h: ReturnType<typeof fn> = 1;
+        const i: Parameters<typeof fn> = [1, 2];
+        const j: Awaited<Promise<number>> = "j";
+        const k: ReturnType<any> = 1;
+        const l: Pick<User, "email"> = {};
+    "#;
+    let expected = [
+        "TS2741: Property 'email' is missing in type '{ id: number; name: string; }' but required in type '{ id: number; name: string; email: string; }'.",
+        "TS2741: Property 'name' is missing in type '{ id: number; }' but required in type '{ id: number; name: string; }'.",
+        "TS2322: Type 'number' is not assignable to type 'string'.",
+        "TS2322: Type 'string' is not assignable to type 'number'.",
+        "TS2322: Type '\"a\"' is not assignable to type '\"b\" | \"c\"'.",
+        "TS2322: Type 'string' is not assignable to type 'number'.",
+        "TS2322: Type 'number' is not assignable to type 'boolean'.",
+        "TS2322: Type 'number' is not assignable to type 'string'.",
+        "TS2322: Type 'string' is not assignable to type 'number'.",
+    ];
+    assert_eq!(messages(source_text, lib_options(ESTarget::ES2015, Some(&["es5"]))), expected);
+}
+
+/// The types of the top-level expression statements of `source_text`.
+fn displayed_types(source_text: &str, options: TypeCheckerOptions) -> Vec<String> {
+    let allocator = Allocator::default();
+    let parser_ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+    let semantic_ret =
+        SemanticBuilder::new().with_build_nodes(true).with_cfg(true).build(&parser_ret.program);
+    let expression_types = TypeChecker::new()
+        .with_options(options)
+        .expression_types(&parser_ret.program, &semantic_ret.semantic);
+    let types = expression_types.types();
+    parser_ret
+        .program
+        .body
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::ExpressionStatement(statement) => {
+                Some(types.display(expression_types.get(&statement.expression).unwrap()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Declarations written as in TypeScript's lib files: interfaces merged across files, values
+/// declared with a constructor interface (`declare var Promise: PromiseConstructor`), and
+/// overloaded methods.
+#[test]
+fn lib_declarations() {
+    let source_text = r#"
+        Math.sign(1);
+        JSON.parse("1");
+        JSON.stringify(1);
+        Array.isArray([]);
+        Array.from([1]);
+        Promise.resolve(1);
+        new Promise(() => {});
+        fetch("a");
+    "#;
+    assert_eq!(
+        displayed_types(source_text, lib_options(ESTarget::ES2015, None)),
+        // Overloads, and interfaces with call or construct signatures, are not represented
+        ["number", "any", "any", "any", "any", "any", "any", "Promise<any>"]
+    );
+}
+
+/// TypeScript's own lib files, when the `typescript` package is installed.
+#[test]
+fn typescript_lib_files() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let Some(lib_path) = ["node_modules/typescript/lib", "napi/parser/node_modules/typescript/lib"]
+        .into_iter()
+        .map(|path| root.join(path))
+        .find(|path| path.join("lib.es5.d.ts").exists())
+    else {
+        return;
+    };
+    let options = TypeCheckerOptions {
+        lib_path: Some(lib_path),
+        target: ESTarget::ES2020,
+        lib: Some(vec!["es2020".to_string(), "dom".to_string()]),
+        ..TypeCheckerOptions::default()
+    };
+    let globals = Globals::load(&options).unwrap().unwrap();
+    for name in ["Math", "JSON", "Array", "Promise", "console", "document", "fetch"] {
+        assert!(globals.value(name).is_some(), "{name}");
+    }
+    for name in ["Array", "ArrayConstructor", "Promise", "PromiseConstructor", "Partial"] {
+        assert!(globals.type_declaration(name).is_some(), "{name}");
+    }
+    assert_eq!(displayed_types(r#"fetch("a");"#, options), ["Promise<any>"]);
+}
+
+#[test]
+fn expression_types() {
+    let source_text = r#"
+        async function f() { return 1; }
+        function g(x: number | string) {
+            if (typeof x === "number") x;
+        }
+        let n = 1;
+        f();
+        await f();
+        [1, "a"];
+        n += 2;
+        unknownGlobal;
+    "#;
+    let allocator = Allocator::default();
+    let parser_ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+    let semantic_ret =
+        SemanticBuilder::new().with_build_nodes(true).with_cfg(true).build(&parser_ret.program);
+    let expression_types =
+        TypeChecker::new().expression_types(&parser_ret.program, &semantic_ret.semantic);
+    let types = expression_types.types();
+
+    // The expressions of the top-level expression statements, and of the one in `g`
+    let mut expressions = vec![];
+    for statement in &parser_ret.program.body {
+        match statement {
+            Statement::ExpressionStatement(statement) => expressions.push(&statement.expression),
+            Statement::FunctionDeclaration(function) => {
+                let body = function.body.as_ref().unwrap();
+                if let Some(Statement::IfStatement(statement)) = body.statements.first()
+                    && let Statement::ExpressionStatement(statement) = &statement.consequent
+                {
+                    expressions.push(&statement.expression);
+                }
+            }
+            _ => {}
+        }
+    }
+    let displayed = expressions
+        .iter()
+        .map(|expr| types.display(expression_types.get(expr).unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        displayed,
+        ["number", "Promise<number>", "number", "(number | string)[]", "any", "any"]
+    );
+    let flags = expressions
+        .iter()
+        .map(|expr| types.typescript_flags(expression_types.get(expr).unwrap()))
+        .collect::<Vec<_>>();
+    // `ts.TypeFlags.Number`, `Object` and `Any`
+    assert_eq!(flags, [8, 1 << 19, 8, 1 << 19, 1, 1]);
+
+    // The type of the variable assigned to
+    let Expression::AssignmentExpression(assignment) = expressions[4] else { unreachable!() };
+    let target = expression_types.assignment_target(&assignment.left).unwrap();
+    assert_eq!(types.display(target), "number");
+    assert!(!expression_types.has_unresolved_imports());
+}
+
+#[test]
+fn expression_types_unresolved_imports() {
+    let has_unresolved_imports = |source_text: &str| {
+        let allocator = Allocator::default();
+        let parser_ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+        let semantic_ret = SemanticBuilder::new().with_build_nodes(true).build(&parser_ret.program);
+        TypeChecker::new()
+            .expression_types(&parser_ret.program, &semantic_ret.semantic)
+            .has_unresolved_imports()
+    };
+    assert!(has_unresolved_imports(r#"import { g } from "./g"; g().then(() => {});"#));
+    // Imports which are not referenced do not affect the types
+    assert!(!has_unresolved_imports(r#"import { g } from "./g"; 1 + 1;"#));
+}
+
+#[test]
+fn expression_types_unknown_types() {
+    let has_unknown_types = |source_text: &str| {
+        let allocator = Allocator::default();
+        let parser_ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+        let semantic_ret =
+            SemanticBuilder::new().with_build_nodes(true).with_cfg(true).build(&parser_ret.program);
+        TypeChecker::new()
+            .expression_types(&parser_ret.program, &semantic_ret.semantic)
+            .has_unknown_types()
+    };
+    // Globals are not declared without lib files
+    assert!(has_unknown_types("Promise.resolve(1);"));
+    assert!(has_unknown_types("class A {} new A();"));
+    assert!(has_unknown_types("function f(p: Foo) { p; }"));
+    assert!(has_unknown_types(r#""a".trim();"#));
+    assert!(has_unknown_types("function f(): void; function f(a?: number) {} f();"));
+    assert!(has_unknown_types(r#"import { g } from "./g"; g();"#));
+    // The `any` of the program, and of errors
+    assert!(!has_unknown_types("declare const a: any; a.b(); undefined;"));
+    assert!(!has_unknown_types("async function f() {} f().then(() => {}); [] + 1;"));
+    assert!(!has_unknown_types("interface A {} function f<T extends A>(value: T) { value; }"));
+}
+
+#[test]
+fn program() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/program");
+    let paths =
+        ["src/main.ts", "src/a.ts", "src/b.ts", "src/unknown.ts"].map(|path| root.join(path));
+    let ret = ProgramChecker::new(TypeCheckerOptions::default())
+        .with_tsconfig(root.join("tsconfig.json"))
+        .check_files(&paths);
+    assert!(ret.diagnostics.is_empty());
+    let mut messages = ret
+        .files
+        .iter()
+        .map(|file| {
+            assert!(paths.contains(&file.path));
+            file.diagnostics.iter().map(|diagnostic| diagnostic.message.to_string()).collect()
+        })
+        .collect::<Vec<Vec<_>>>();
+    let unknown = messages.pop().unwrap();
+    assert_eq!(unknown.len(), 1);
+    assert!(unknown[0].ends_with("unknown.ts' not found."));
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!("program", format!("{messages:#?}"));
+    });
+}
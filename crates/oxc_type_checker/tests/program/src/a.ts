@@ -0,0 +1,4 @@
+import { b } from "./b";
+
+export const a: number = 1;
+export const fromB: string = b;
@@ -0,0 +1,4 @@
+import { a } from "./a";
+
+export const b: string = "b";
+export const fromA: number = a;
@@ -0,0 +1,3 @@
+export * from "./math";
+export { add as plus } from "@lib/math";
+export * as math from "./math";
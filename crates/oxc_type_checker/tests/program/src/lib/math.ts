@@ -0,0 +1,12 @@
+export interface Point {
+  x: number;
+  y: number;
+}
+
+export const origin: Point = { x: 0, y: 0 };
+
+export function add(a: number, b: number): number {
+  return a + b;
+}
+
+export default "math";
@@ -0,0 +1,18 @@
+import name, { add, origin, type Point } from "@lib/math";
+import { plus, math } from "./lib";
+import * as lib from "src/lib/index";
+import { greet } from "greeter";
+import { a } from "./a";
+import { missing } from "./missing";
+
+const n: string = add(1, 2);
+const p: Point = { x: 1 };
+const x: string = origin.x;
+const s: number = name;
+plus("1", 2);
+math.add(1, "2");
+const y: string = lib.origin.y;
+greet(1);
+const z: string = a;
+const m: number = missing;
+setup(1);
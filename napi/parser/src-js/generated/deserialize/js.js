@@ -152,6 +152,12 @@ function deserializeExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -300,6 +306,12 @@ function deserializeArrayExpressionElement(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -336,6 +348,8 @@ function deserializeObjectPropertyKind(pos) {
       return deserializeBoxObjectProperty(pos + 8);
     case 1:
       return deserializeBoxSpreadElement(pos + 8);
+    case 2:
+      return deserializeBoxErrorNode(pos + 8);
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for ObjectPropertyKind`);
   }
@@ -440,6 +454,12 @@ function deserializePropertyKey(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -698,6 +718,12 @@ function deserializeArgument(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -1110,6 +1136,8 @@ function deserializeStatement(pos) {
       return deserializeBoxWhileStatement(pos + 8);
     case 17:
       return deserializeBoxWithStatement(pos + 8);
+    case 18:
+      return deserializeBoxErrorNode(pos + 8);
     case 32:
       return deserializeBoxVariableDeclaration(pos + 8);
     case 33:
@@ -1128,6 +1156,8 @@ function deserializeStatement(pos) {
       return deserializeBoxTSGlobalDeclaration(pos + 8);
     case 40:
       return deserializeBoxTSImportEqualsDeclaration(pos + 8);
+    case 41:
+      return deserializeBoxFlowOpaqueTypeDeclaration(pos + 8);
     case 64:
       return deserializeBoxImportDeclaration(pos + 8);
     case 65:
@@ -1197,6 +1227,8 @@ function deserializeDeclaration(pos) {
       return deserializeBoxTSGlobalDeclaration(pos + 8);
     case 40:
       return deserializeBoxTSImportEqualsDeclaration(pos + 8);
+    case 41:
+      return deserializeBoxFlowOpaqueTypeDeclaration(pos + 8);
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for Declaration`);
   }
@@ -1403,6 +1435,12 @@ function deserializeForStatementInit(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -1860,6 +1898,8 @@ function deserializeClassElement(pos) {
       return deserializeBoxAccessorProperty(pos + 8);
     case 4:
       return deserializeBoxTSIndexSignature(pos + 8);
+    case 5:
+      return deserializeBoxErrorNode(pos + 8);
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for ClassElement`);
   }
@@ -2245,6 +2285,12 @@ function deserializeExportDefaultDeclarationKind(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2288,6 +2334,35 @@ function deserializeV8IntrinsicExpression(pos) {
   return node;
 }
 
+function deserializePipelineExpression(pos) {
+  let node = {
+    type: "PipelineExpression",
+    head: null,
+    body: null,
+    start: deserializeI32(pos),
+    end: deserializeI32(pos + 4),
+  };
+  node.head = deserializeExpression(pos + 16);
+  node.body = deserializeExpression(pos + 32);
+  return node;
+}
+
+function deserializeTopicReference(pos) {
+  return {
+    type: "TopicReference",
+    start: deserializeI32(pos),
+    end: deserializeI32(pos + 4),
+  };
+}
+
+function deserializeErrorNode(pos) {
+  return {
+    type: "ErrorNode",
+    start: deserializeI32(pos),
+    end: deserializeI32(pos + 4),
+  };
+}
+
 function deserializeBooleanLiteral(pos) {
   let value = deserializeBool(pos + 12),
     start = deserializeI32(pos),
@@ -2662,6 +2737,12 @@ function deserializeJSXExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -4080,6 +4161,8 @@ function deserializeImportOrExportKind(pos) {
       return "value";
     case 1:
       return "type";
+    case 2:
+      return "typeof";
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for ImportOrExportKind`);
   }
@@ -4117,6 +4200,37 @@ function deserializeJSDocUnknownType(pos) {
   };
 }
 
+function deserializeFlowTypeCastExpression(pos) {
+  let node = {
+    type: "FlowTypeCastExpression",
+    expression: null,
+    typeAnnotation: null,
+    start: deserializeI32(pos),
+    end: deserializeI32(pos + 4),
+  };
+  node.expression = deserializeExpression(pos + 16);
+  node.typeAnnotation = deserializeBoxTSTypeAnnotation(pos + 32);
+  return node;
+}
+
+function deserializeFlowOpaqueTypeDeclaration(pos) {
+  let node = {
+    type: "FlowOpaqueTypeDeclaration",
+    id: null,
+    typeParameters: null,
+    supertype: null,
+    impltype: null,
+    declare: deserializeBool(pos + 88),
+    start: deserializeI32(pos),
+    end: deserializeI32(pos + 4),
+  };
+  node.id = deserializeBindingIdentifier(pos + 16);
+  node.typeParameters = deserializeOptionBoxTSTypeParameterDeclaration(pos + 48);
+  node.supertype = deserializeOptionTSType(pos + 56);
+  node.impltype = deserializeOptionTSType(pos + 72);
+  return node;
+}
+
 function deserializeCommentKind(pos) {
   switch (uint8[pos]) {
     case 0:
@@ -4759,6 +4873,18 @@ function deserializeBoxV8IntrinsicExpression(pos) {
   return deserializeV8IntrinsicExpression(int32[pos >> 2]);
 }
 
+function deserializeBoxFlowTypeCastExpression(pos) {
+  return deserializeFlowTypeCastExpression(int32[pos >> 2]);
+}
+
+function deserializeBoxPipelineExpression(pos) {
+  return deserializePipelineExpression(int32[pos >> 2]);
+}
+
+function deserializeBoxTopicReference(pos) {
+  return deserializeTopicReference(int32[pos >> 2]);
+}
+
 function deserializeVecArrayExpressionElement(pos) {
   let arr = [],
     pos32 = pos >> 2;
@@ -4795,6 +4921,10 @@ function deserializeBoxObjectProperty(pos) {
   return deserializeObjectProperty(int32[pos >> 2]);
 }
 
+function deserializeBoxErrorNode(pos) {
+  return deserializeErrorNode(int32[pos >> 2]);
+}
+
 function deserializeBool(pos) {
   return uint8[pos] === 1;
 }
@@ -5031,6 +5161,10 @@ function deserializeBoxTSImportEqualsDeclaration(pos) {
   return deserializeTSImportEqualsDeclaration(int32[pos >> 2]);
 }
 
+function deserializeBoxFlowOpaqueTypeDeclaration(pos) {
+  return deserializeFlowOpaqueTypeDeclaration(int32[pos >> 2]);
+}
+
 function deserializeVecVariableDeclarator(pos) {
   let arr = [],
     pos32 = pos >> 2;
@@ -155,6 +155,12 @@ function deserializeExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -311,6 +317,12 @@ function deserializeArrayExpressionElement(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -350,6 +362,8 @@ function deserializeObjectPropertyKind(pos) {
       return deserializeBoxObjectProperty(pos + 8);
     case 1:
       return deserializeBoxSpreadElement(pos + 8);
+    case 2:
+      return deserializeBoxErrorNode(pos + 8);
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for ObjectPropertyKind`);
   }
@@ -457,6 +471,12 @@ function deserializePropertyKey(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -743,6 +763,12 @@ function deserializeArgument(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -1215,6 +1241,8 @@ function deserializeStatement(pos) {
       return deserializeBoxWhileStatement(pos + 8);
     case 17:
       return deserializeBoxWithStatement(pos + 8);
+    case 18:
+      return deserializeBoxErrorNode(pos + 8);
     case 32:
       return deserializeBoxVariableDeclaration(pos + 8);
     case 33:
@@ -1233,6 +1261,8 @@ function deserializeStatement(pos) {
       return deserializeBoxTSGlobalDeclaration(pos + 8);
     case 40:
       return deserializeBoxTSImportEqualsDeclaration(pos + 8);
+    case 41:
+      return deserializeBoxFlowOpaqueTypeDeclaration(pos + 8);
     case 64:
       return deserializeBoxImportDeclaration(pos + 8);
     case 65:
@@ -1309,6 +1339,8 @@ function deserializeDeclaration(pos) {
       return deserializeBoxTSGlobalDeclaration(pos + 8);
     case 40:
       return deserializeBoxTSImportEqualsDeclaration(pos + 8);
+    case 41:
+      return deserializeBoxFlowOpaqueTypeDeclaration(pos + 8);
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for Declaration`);
   }
@@ -1537,6 +1569,12 @@ function deserializeForStatementInit(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2073,6 +2111,8 @@ function deserializeClassElement(pos) {
       return deserializeBoxAccessorProperty(pos + 8);
     case 4:
       return deserializeBoxTSIndexSignature(pos + 8);
+    case 5:
+      return deserializeBoxErrorNode(pos + 8);
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for ClassElement`);
   }
@@ -2501,6 +2541,12 @@ function deserializeExportDefaultDeclarationKind(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2547,6 +2593,40 @@ function deserializeV8IntrinsicExpression(pos) {
   return node;
 }
 
+function deserializePipelineExpression(pos) {
+  let previousParent = parent,
+    node = (parent = {
+      type: "PipelineExpression",
+      head: null,
+      body: null,
+      start: deserializeI32(pos),
+      end: deserializeI32(pos + 4),
+      parent,
+    });
+  node.head = deserializeExpression(pos + 16);
+  node.body = deserializeExpression(pos + 32);
+  parent = previousParent;
+  return node;
+}
+
+function deserializeTopicReference(pos) {
+  return {
+    type: "TopicReference",
+    start: deserializeI32(pos),
+    end: deserializeI32(pos + 4),
+    parent,
+  };
+}
+
+function deserializeErrorNode(pos) {
+  return {
+    type: "ErrorNode",
+    start: deserializeI32(pos),
+    end: deserializeI32(pos + 4),
+    parent,
+  };
+}
+
 function deserializeBooleanLiteral(pos) {
   let value = deserializeBool(pos + 12),
     start = deserializeI32(pos),
@@ -2960,6 +3040,12 @@ function deserializeJSXExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -4586,6 +4672,8 @@ function deserializeImportOrExportKind(pos) {
       return "value";
     case 1:
       return "type";
+    case 2:
+      return "typeof";
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for ImportOrExportKind`);
   }
@@ -4630,6 +4718,43 @@ function deserializeJSDocUnknownType(pos) {
   };
 }
 
+function deserializeFlowTypeCastExpression(pos) {
+  let previousParent = parent,
+    node = (parent = {
+      type: "FlowTypeCastExpression",
+      expression: null,
+      typeAnnotation: null,
+      start: deserializeI32(pos),
+      end: deserializeI32(pos + 4),
+      parent,
+    });
+  node.expression = deserializeExpression(pos + 16);
+  node.typeAnnotation = deserializeBoxTSTypeAnnotation(pos + 32);
+  parent = previousParent;
+  return node;
+}
+
+function deserializeFlowOpaqueTypeDeclaration(pos) {
+  let previousParent = parent,
+    node = (parent = {
+      type: "FlowOpaqueTypeDeclaration",
+      id: null,
+      typeParameters: null,
+      supertype: null,
+      impltype: null,
+      declare: deserializeBool(pos + 88),
+      start: deserializeI32(pos),
+      end: deserializeI32(pos + 4),
+      parent,
+    });
+  node.id = deserializeBindingIdentifier(pos + 16);
+  node.typeParameters = deserializeOptionBoxTSTypeParameterDeclaration(pos + 48);
+  node.supertype = deserializeOptionTSType(pos + 56);
+  node.impltype = deserializeOptionTSType(pos + 72);
+  parent = previousParent;
+  return node;
+}
+
 function deserializeCommentKind(pos) {
   switch (uint8[pos]) {
     case 0:
@@ -5272,6 +5397,18 @@ function deserializeBoxV8IntrinsicExpression(pos) {
   return deserializeV8IntrinsicExpression(int32[pos >> 2]);
 }
 
+function deserializeBoxFlowTypeCastExpression(pos) {
+  return deserializeFlowTypeCastExpression(int32[pos >> 2]);
+}
+
+function deserializeBoxPipelineExpression(pos) {
+  return deserializePipelineExpression(int32[pos >> 2]);
+}
+
+function deserializeBoxTopicReference(pos) {
+  return deserializeTopicReference(int32[pos >> 2]);
+}
+
 function deserializeVecArrayExpressionElement(pos) {
   let arr = [],
     pos32 = pos >> 2;
@@ -5308,6 +5445,10 @@ function deserializeBoxObjectProperty(pos) {
   return deserializeObjectProperty(int32[pos >> 2]);
 }
 
+function deserializeBoxErrorNode(pos) {
+  return deserializeErrorNode(int32[pos >> 2]);
+}
+
 function deserializeBool(pos) {
   return uint8[pos] === 1;
 }
@@ -5544,6 +5685,10 @@ function deserializeBoxTSImportEqualsDeclaration(pos) {
   return deserializeTSImportEqualsDeclaration(int32[pos >> 2]);
 }
 
+function deserializeBoxFlowOpaqueTypeDeclaration(pos) {
+  return deserializeFlowOpaqueTypeDeclaration(int32[pos >> 2]);
+}
+
 function deserializeVecVariableDeclarator(pos) {
   let arr = [],
     pos32 = pos >> 2;
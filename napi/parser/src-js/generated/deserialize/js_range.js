@@ -153,6 +153,12 @@ function deserializeExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -314,6 +320,12 @@ function deserializeArrayExpressionElement(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -353,6 +365,8 @@ function deserializeObjectPropertyKind(pos) {
       return deserializeBoxObjectProperty(pos + 8);
     case 1:
       return deserializeBoxSpreadElement(pos + 8);
+    case 2:
+      return deserializeBoxErrorNode(pos + 8);
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for ObjectPropertyKind`);
   }
@@ -460,6 +474,12 @@ function deserializePropertyKey(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -746,6 +766,12 @@ function deserializeArgument(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -1214,6 +1240,8 @@ function deserializeStatement(pos) {
       return deserializeBoxWhileStatement(pos + 8);
     case 17:
       return deserializeBoxWithStatement(pos + 8);
+    case 18:
+      return deserializeBoxErrorNode(pos + 8);
     case 32:
       return deserializeBoxVariableDeclaration(pos + 8);
     case 33:
@@ -1232,6 +1260,8 @@ function deserializeStatement(pos) {
       return deserializeBoxTSGlobalDeclaration(pos + 8);
     case 40:
       return deserializeBoxTSImportEqualsDeclaration(pos + 8);
+    case 41:
+      return deserializeBoxFlowOpaqueTypeDeclaration(pos + 8);
     case 64:
       return deserializeBoxImportDeclaration(pos + 8);
     case 65:
@@ -1309,6 +1339,8 @@ function deserializeDeclaration(pos) {
       return deserializeBoxTSGlobalDeclaration(pos + 8);
     case 40:
       return deserializeBoxTSImportEqualsDeclaration(pos + 8);
+    case 41:
+      return deserializeBoxFlowOpaqueTypeDeclaration(pos + 8);
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for Declaration`);
   }
@@ -1538,6 +1570,12 @@ function deserializeForStatementInit(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2071,6 +2109,8 @@ function deserializeClassElement(pos) {
       return deserializeBoxAccessorProperty(pos + 8);
     case 4:
       return deserializeBoxTSIndexSignature(pos + 8);
+    case 5:
+      return deserializeBoxErrorNode(pos + 8);
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for ClassElement`);
   }
@@ -2500,6 +2540,12 @@ function deserializeExportDefaultDeclarationKind(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2546,6 +2592,42 @@ function deserializeV8IntrinsicExpression(pos) {
   return node;
 }
 
+function deserializePipelineExpression(pos) {
+  let start,
+    end,
+    node = {
+      type: "PipelineExpression",
+      head: null,
+      body: null,
+      start: (start = deserializeI32(pos)),
+      end: (end = deserializeI32(pos + 4)),
+      range: [start, end],
+    };
+  node.head = deserializeExpression(pos + 16);
+  node.body = deserializeExpression(pos + 32);
+  return node;
+}
+
+function deserializeTopicReference(pos) {
+  let start, end;
+  return {
+    type: "TopicReference",
+    start: (start = deserializeI32(pos)),
+    end: (end = deserializeI32(pos + 4)),
+    range: [start, end],
+  };
+}
+
+function deserializeErrorNode(pos) {
+  let start, end;
+  return {
+    type: "ErrorNode",
+    start: (start = deserializeI32(pos)),
+    end: (end = deserializeI32(pos + 4)),
+    range: [start, end],
+  };
+}
+
 function deserializeBooleanLiteral(pos) {
   let value = deserializeBool(pos + 12),
     start = deserializeI32(pos),
@@ -2955,6 +3037,12 @@ function deserializeJSXExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -4587,6 +4675,8 @@ function deserializeImportOrExportKind(pos) {
       return "value";
     case 1:
       return "type";
+    case 2:
+      return "typeof";
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for ImportOrExportKind`);
   }
@@ -4632,6 +4722,43 @@ function deserializeJSDocUnknownType(pos) {
   };
 }
 
+function deserializeFlowTypeCastExpression(pos) {
+  let start,
+    end,
+    node = {
+      type: "FlowTypeCastExpression",
+      expression: null,
+      typeAnnotation: null,
+      start: (start = deserializeI32(pos)),
+      end: (end = deserializeI32(pos + 4)),
+      range: [start, end],
+    };
+  node.expression = deserializeExpression(pos + 16);
+  node.typeAnnotation = deserializeBoxTSTypeAnnotation(pos + 32);
+  return node;
+}
+
+function deserializeFlowOpaqueTypeDeclaration(pos) {
+  let start,
+    end,
+    node = {
+      type: "FlowOpaqueTypeDeclaration",
+      id: null,
+      typeParameters: null,
+      supertype: null,
+      impltype: null,
+      declare: deserializeBool(pos + 88),
+      start: (start = deserializeI32(pos)),
+      end: (end = deserializeI32(pos + 4)),
+      range: [start, end],
+    };
+  node.id = deserializeBindingIdentifier(pos + 16);
+  node.typeParameters = deserializeOptionBoxTSTypeParameterDeclaration(pos + 48);
+  node.supertype = deserializeOptionTSType(pos + 56);
+  node.impltype = deserializeOptionTSType(pos + 72);
+  return node;
+}
+
 function deserializeCommentKind(pos) {
   switch (uint8[pos]) {
     case 0:
@@ -5300,6 +5427,18 @@ function deserializeBoxV8IntrinsicExpression(pos) {
   return deserializeV8IntrinsicExpression(int32[pos >> 2]);
 }
 
+function deserializeBoxFlowTypeCastExpression(pos) {
+  return deserializeFlowTypeCastExpression(int32[pos >> 2]);
+}
+
+function deserializeBoxPipelineExpression(pos) {
+  return deserializePipelineExpression(int32[pos >> 2]);
+}
+
+function deserializeBoxTopicReference(pos) {
+  return deserializeTopicReference(int32[pos >> 2]);
+}
+
 function deserializeVecArrayExpressionElement(pos) {
   let arr = [],
     pos32 = pos >> 2;
@@ -5336,6 +5475,10 @@ function deserializeBoxObjectProperty(pos) {
   return deserializeObjectProperty(int32[pos >> 2]);
 }
 
+function deserializeBoxErrorNode(pos) {
+  return deserializeErrorNode(int32[pos >> 2]);
+}
+
 function deserializeBool(pos) {
   return uint8[pos] === 1;
 }
@@ -5572,6 +5715,10 @@ function deserializeBoxTSImportEqualsDeclaration(pos) {
   return deserializeTSImportEqualsDeclaration(int32[pos >> 2]);
 }
 
+function deserializeBoxFlowOpaqueTypeDeclaration(pos) {
+  return deserializeFlowOpaqueTypeDeclaration(int32[pos >> 2]);
+}
+
 function deserializeVecVariableDeclarator(pos) {
   let arr = [],
     pos32 = pos >> 2;
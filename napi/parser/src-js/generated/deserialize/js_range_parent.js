@@ -156,6 +156,12 @@ function deserializeExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -325,6 +331,12 @@ function deserializeArrayExpressionElement(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -367,6 +379,8 @@ function deserializeObjectPropertyKind(pos) {
       return deserializeBoxObjectProperty(pos + 8);
     case 1:
       return deserializeBoxSpreadElement(pos + 8);
+    case 2:
+      return deserializeBoxErrorNode(pos + 8);
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for ObjectPropertyKind`);
   }
@@ -477,6 +491,12 @@ function deserializePropertyKey(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -791,6 +811,12 @@ function deserializeArgument(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -1318,6 +1344,8 @@ function deserializeStatement(pos) {
       return deserializeBoxWhileStatement(pos + 8);
     case 17:
       return deserializeBoxWithStatement(pos + 8);
+    case 18:
+      return deserializeBoxErrorNode(pos + 8);
     case 32:
       return deserializeBoxVariableDeclaration(pos + 8);
     case 33:
@@ -1336,6 +1364,8 @@ function deserializeStatement(pos) {
       return deserializeBoxTSGlobalDeclaration(pos + 8);
     case 40:
       return deserializeBoxTSImportEqualsDeclaration(pos + 8);
+    case 41:
+      return deserializeBoxFlowOpaqueTypeDeclaration(pos + 8);
     case 64:
       return deserializeBoxImportDeclaration(pos + 8);
     case 65:
@@ -1420,6 +1450,8 @@ function deserializeDeclaration(pos) {
       return deserializeBoxTSGlobalDeclaration(pos + 8);
     case 40:
       return deserializeBoxTSImportEqualsDeclaration(pos + 8);
+    case 41:
+      return deserializeBoxFlowOpaqueTypeDeclaration(pos + 8);
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for Declaration`);
   }
@@ -1671,6 +1703,12 @@ function deserializeForStatementInit(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2283,6 +2321,8 @@ function deserializeClassElement(pos) {
       return deserializeBoxAccessorProperty(pos + 8);
     case 4:
       return deserializeBoxTSIndexSignature(pos + 8);
+    case 5:
+      return deserializeBoxErrorNode(pos + 8);
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for ClassElement`);
   }
@@ -2755,6 +2795,12 @@ function deserializeExportDefaultDeclarationKind(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2804,6 +2850,47 @@ function deserializeV8IntrinsicExpression(pos) {
   return node;
 }
 
+function deserializePipelineExpression(pos) {
+  let start,
+    end,
+    previousParent = parent,
+    node = (parent = {
+      type: "PipelineExpression",
+      head: null,
+      body: null,
+      start: (start = deserializeI32(pos)),
+      end: (end = deserializeI32(pos + 4)),
+      range: [start, end],
+      parent,
+    });
+  node.head = deserializeExpression(pos + 16);
+  node.body = deserializeExpression(pos + 32);
+  parent = previousParent;
+  return node;
+}
+
+function deserializeTopicReference(pos) {
+  let start, end;
+  return {
+    type: "TopicReference",
+    start: (start = deserializeI32(pos)),
+    end: (end = deserializeI32(pos + 4)),
+    range: [start, end],
+    parent,
+  };
+}
+
+function deserializeErrorNode(pos) {
+  let start, end;
+  return {
+    type: "ErrorNode",
+    start: (start = deserializeI32(pos)),
+    end: (end = deserializeI32(pos + 4)),
+    range: [start, end],
+    parent,
+  };
+}
+
 function deserializeBooleanLiteral(pos) {
   let value = deserializeBool(pos + 12),
     start = deserializeI32(pos),
@@ -3252,6 +3339,12 @@ function deserializeJSXExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxFlowTypeCastExpression(pos + 8);
+    case 41:
+      return deserializeBoxPipelineExpression(pos + 8);
+    case 42:
+      return deserializeBoxTopicReference(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -5092,6 +5185,8 @@ function deserializeImportOrExportKind(pos) {
       return "value";
     case 1:
       return "type";
+    case 2:
+      return "typeof";
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for ImportOrExportKind`);
   }
@@ -5144,6 +5239,49 @@ function deserializeJSDocUnknownType(pos) {
   };
 }
 
+function deserializeFlowTypeCastExpression(pos) {
+  let start,
+    end,
+    previousParent = parent,
+    node = (parent = {
+      type: "FlowTypeCastExpression",
+      expression: null,
+      typeAnnotation: null,
+      start: (start = deserializeI32(pos)),
+      end: (end = deserializeI32(pos + 4)),
+      range: [start, end],
+      parent,
+    });
+  node.expression = deserializeExpression(pos + 16);
+  node.typeAnnotation = deserializeBoxTSTypeAnnotation(pos + 32);
+  parent = previousParent;
+  return node;
+}
+
+function deserializeFlowOpaqueTypeDeclaration(pos) {
+  let start,
+    end,
+    previousParent = parent,
+    node = (parent = {
+      type: "FlowOpaqueTypeDeclaration",
+      id: null,
+      typeParameters: null,
+      supertype: null,
+      impltype: null,
+      declare: deserializeBool(pos + 88),
+      start: (start = deserializeI32(pos)),
+      end: (end = deserializeI32(pos + 4)),
+      range: [start, end],
+      parent,
+    });
+  node.id = deserializeBindingIdentifier(pos + 16);
+  node.typeParameters = deserializeOptionBoxTSTypeParameterDeclaration(pos + 48);
+  node.supertype = deserializeOptionTSType(pos + 56);
+  node.impltype = deserializeOptionTSType(pos + 72);
+  parent = previousParent;
+  return node;
+}
+
 function deserializeCommentKind(pos) {
   switch (uint8[pos]) {
     case 0:
@@ -5812,6 +5950,18 @@ function deserializeBoxV8IntrinsicExpression(pos) {
   return deserializeV8IntrinsicExpression(int32[pos >> 2]);
 }
 
+function deserializeBoxFlowTypeCastExpression(pos) {
+  return deserializeFlowTypeCastExpression(int32[pos >> 2]);
+}
+
+function deserializeBoxPipelineExpression(pos) {
+  return deserializePipelineExpression(int32[pos >> 2]);
+}
+
+function deserializeBoxTopicReference(pos) {
+  return deserializeTopicReference(int32[pos >> 2]);
+}
+
 function deserializeVecArrayExpressionElement(pos) {
   let arr = [],
     pos32 = pos >> 2;
@@ -5848,6 +5998,10 @@ function deserializeBoxObjectProperty(pos) {
   return deserializeObjectProperty(int32[pos >> 2]);
 }
 
+function deserializeBoxErrorNode(pos) {
+  return deserializeErrorNode(int32[pos >> 2]);
+}
+
 function deserializeBool(pos) {
   return uint8[pos] === 1;
 }
@@ -6084,6 +6238,10 @@ function deserializeBoxTSImportEqualsDeclaration(pos) {
   return deserializeTSImportEqualsDeclaration(int32[pos >> 2]);
 }
 
+function deserializeBoxFlowOpaqueTypeDeclaration(pos) {
+  return deserializeFlowOpaqueTypeDeclaration(int32[pos >> 2]);
+}
+
 function deserializeVecVariableDeclarator(pos) {
   let arr = [],
     pos32 = pos >> 2;
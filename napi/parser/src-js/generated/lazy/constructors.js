@@ -151,6 +151,12 @@ function constructExpression(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxFlowTypeCastExpression(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 42:
+      return constructBoxTopicReference(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -527,6 +533,12 @@ function constructArrayExpressionElement(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxFlowTypeCastExpression(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 42:
+      return constructBoxTopicReference(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -636,6 +648,8 @@ function constructObjectPropertyKind(pos, ast) {
       return constructBoxObjectProperty(pos + 8, ast);
     case 1:
       return constructBoxSpreadElement(pos + 8, ast);
+    case 2:
+      return constructBoxErrorNode(pos + 8, ast);
     default:
       throw new Error(`Unexpected discriminant ${ast.buffer[pos]} for ObjectPropertyKind`);
   }
@@ -799,6 +813,12 @@ function constructPropertyKey(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxFlowTypeCastExpression(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 42:
+      return constructBoxTopicReference(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -1528,6 +1548,12 @@ function constructArgument(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxFlowTypeCastExpression(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 42:
+      return constructBoxTopicReference(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -2629,6 +2655,8 @@ function constructStatement(pos, ast) {
       return constructBoxWhileStatement(pos + 8, ast);
     case 17:
       return constructBoxWithStatement(pos + 8, ast);
+    case 18:
+      return constructBoxErrorNode(pos + 8, ast);
     case 32:
       return constructBoxVariableDeclaration(pos + 8, ast);
     case 33:
@@ -2647,6 +2675,8 @@ function constructStatement(pos, ast) {
       return constructBoxTSGlobalDeclaration(pos + 8, ast);
     case 40:
       return constructBoxTSImportEqualsDeclaration(pos + 8, ast);
+    case 41:
+      return constructBoxFlowOpaqueTypeDeclaration(pos + 8, ast);
     case 64:
       return constructBoxImportDeclaration(pos + 8, ast);
     case 65:
@@ -2834,6 +2864,8 @@ function constructDeclaration(pos, ast) {
       return constructBoxTSGlobalDeclaration(pos + 8, ast);
     case 40:
       return constructBoxTSImportEqualsDeclaration(pos + 8, ast);
+    case 41:
+      return constructBoxFlowOpaqueTypeDeclaration(pos + 8, ast);
     default:
       throw new Error(`Unexpected discriminant ${ast.buffer[pos]} for Declaration`);
   }
@@ -3371,6 +3403,12 @@ function constructForStatementInit(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxFlowTypeCastExpression(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 42:
+      return constructBoxTopicReference(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -4993,6 +5031,8 @@ function constructClassElement(pos, ast) {
       return constructBoxAccessorProperty(pos + 8, ast);
     case 4:
       return constructBoxTSIndexSignature(pos + 8, ast);
+    case 5:
+      return constructBoxErrorNode(pos + 8, ast);
     default:
       throw new Error(`Unexpected discriminant ${ast.buffer[pos]} for ClassElement`);
   }
@@ -6205,6 +6245,12 @@ function constructExportDefaultDeclarationKind(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxFlowTypeCastExpression(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 42:
+      return constructBoxTopicReference(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -6291,6 +6337,138 @@ export class V8IntrinsicExpression {
 
 const DebugV8IntrinsicExpression = class V8IntrinsicExpression {};
 
+export class PipelineExpression {
+  type = "PipelineExpression";
+  #internal;
+
+  constructor(pos, ast) {
+    if (ast?.token !== TOKEN) constructorError();
+
+    const { nodes } = ast;
+    const cached = nodes.get(pos);
+    if (cached !== void 0) return cached;
+
+    this.#internal = { pos, ast };
+    nodes.set(pos, this);
+  }
+
+  get start() {
+    const internal = this.#internal;
+    return constructI32(internal.pos, internal.ast);
+  }
+
+  get end() {
+    const internal = this.#internal;
+    return constructI32(internal.pos + 4, internal.ast);
+  }
+
+  get head() {
+    const internal = this.#internal;
+    return constructExpression(internal.pos + 16, internal.ast);
+  }
+
+  get body() {
+    const internal = this.#internal;
+    return constructExpression(internal.pos + 32, internal.ast);
+  }
+
+  toJSON() {
+    return {
+      type: "PipelineExpression",
+      start: this.start,
+      end: this.end,
+      head: this.head,
+      body: this.body,
+    };
+  }
+
+  [inspectSymbol]() {
+    return Object.setPrototypeOf(this.toJSON(), DebugPipelineExpression.prototype);
+  }
+}
+
+const DebugPipelineExpression = class PipelineExpression {};
+
+export class TopicReference {
+  type = "TopicReference";
+  #internal;
+
+  constructor(pos, ast) {
+    if (ast?.token !== TOKEN) constructorError();
+
+    const { nodes } = ast;
+    const cached = nodes.get(pos);
+    if (cached !== void 0) return cached;
+
+    this.#internal = { pos, ast };
+    nodes.set(pos, this);
+  }
+
+  get start() {
+    const internal = this.#internal;
+    return constructI32(internal.pos, internal.ast);
+  }
+
+  get end() {
+    const internal = this.#internal;
+    return constructI32(internal.pos + 4, internal.ast);
+  }
+
+  toJSON() {
+    return {
+      type: "TopicReference",
+      start: this.start,
+      end: this.end,
+    };
+  }
+
+  [inspectSymbol]() {
+    return Object.setPrototypeOf(this.toJSON(), DebugTopicReference.prototype);
+  }
+}
+
+const DebugTopicReference = class TopicReference {};
+
+export class ErrorNode {
+  type = "ErrorNode";
+  #internal;
+
+  constructor(pos, ast) {
+    if (ast?.token !== TOKEN) constructorError();
+
+    const { nodes } = ast;
+    const cached = nodes.get(pos);
+    if (cached !== void 0) return cached;
+
+    this.#internal = { pos, ast };
+    nodes.set(pos, this);
+  }
+
+  get start() {
+    const internal = this.#internal;
+    return constructI32(internal.pos, internal.ast);
+  }
+
+  get end() {
+    const internal = this.#internal;
+    return constructI32(internal.pos + 4, internal.ast);
+  }
+
+  toJSON() {
+    return {
+      type: "ErrorNode",
+      start: this.start,
+      end: this.end,
+    };
+  }
+
+  [inspectSymbol]() {
+    return Object.setPrototypeOf(this.toJSON(), DebugErrorNode.prototype);
+  }
+}
+
+const DebugErrorNode = class ErrorNode {};
+
 export class BooleanLiteral {
   type = "BooleanLiteral";
   #internal;
@@ -7263,6 +7441,12 @@ function constructJSXExpression(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxFlowTypeCastExpression(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 42:
+      return constructBoxTopicReference(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -11596,6 +11780,8 @@ function constructImportOrExportKind(pos, ast) {
       return "value";
     case 1:
       return "type";
+    case 2:
+      return "typeof";
     default:
       throw new Error(`Unexpected discriminant ${ast.buffer[pos]} for ImportOrExportKind`);
   }
@@ -11745,6 +11931,128 @@ export class JSDocUnknownType {
 
 const DebugJSDocUnknownType = class JSDocUnknownType {};
 
+export class FlowTypeCastExpression {
+  type = "FlowTypeCastExpression";
+  #internal;
+
+  constructor(pos, ast) {
+    if (ast?.token !== TOKEN) constructorError();
+
+    const { nodes } = ast;
+    const cached = nodes.get(pos);
+    if (cached !== void 0) return cached;
+
+    this.#internal = { pos, ast };
+    nodes.set(pos, this);
+  }
+
+  get start() {
+    const internal = this.#internal;
+    return constructI32(internal.pos, internal.ast);
+  }
+
+  get end() {
+    const internal = this.#internal;
+    return constructI32(internal.pos + 4, internal.ast);
+  }
+
+  get expression() {
+    const internal = this.#internal;
+    return constructExpression(internal.pos + 16, internal.ast);
+  }
+
+  get typeAnnotation() {
+    const internal = this.#internal;
+    return constructBoxTSTypeAnnotation(internal.pos + 32, internal.ast);
+  }
+
+  toJSON() {
+    return {
+      type: "FlowTypeCastExpression",
+      start: this.start,
+      end: this.end,
+      expression: this.expression,
+      typeAnnotation: this.typeAnnotation,
+    };
+  }
+
+  [inspectSymbol]() {
+    return Object.setPrototypeOf(this.toJSON(), DebugFlowTypeCastExpression.prototype);
+  }
+}
+
+const DebugFlowTypeCastExpression = class FlowTypeCastExpression {};
+
+export class FlowOpaqueTypeDeclaration {
+  type = "FlowOpaqueTypeDeclaration";
+  #internal;
+
+  constructor(pos, ast) {
+    if (ast?.token !== TOKEN) constructorError();
+
+    const { nodes } = ast;
+    const cached = nodes.get(pos);
+    if (cached !== void 0) return cached;
+
+    this.#internal = { pos, ast };
+    nodes.set(pos, this);
+  }
+
+  get start() {
+    const internal = this.#internal;
+    return constructI32(internal.pos, internal.ast);
+  }
+
+  get end() {
+    const internal = this.#internal;
+    return constructI32(internal.pos + 4, internal.ast);
+  }
+
+  get id() {
+    const internal = this.#internal;
+    return new BindingIdentifier(internal.pos + 16, internal.ast);
+  }
+
+  get typeParameters() {
+    const internal = this.#internal;
+    return constructOptionBoxTSTypeParameterDeclaration(internal.pos + 48, internal.ast);
+  }
+
+  get supertype() {
+    const internal = this.#internal;
+    return constructOptionTSType(internal.pos + 56, internal.ast);
+  }
+
+  get impltype() {
+    const internal = this.#internal;
+    return constructOptionTSType(internal.pos + 72, internal.ast);
+  }
+
+  get declare() {
+    const internal = this.#internal;
+    return constructBool(internal.pos + 88, internal.ast);
+  }
+
+  toJSON() {
+    return {
+      type: "FlowOpaqueTypeDeclaration",
+      start: this.start,
+      end: this.end,
+      id: this.id,
+      typeParameters: this.typeParameters,
+      supertype: this.supertype,
+      impltype: this.impltype,
+      declare: this.declare,
+    };
+  }
+
+  [inspectSymbol]() {
+    return Object.setPrototypeOf(this.toJSON(), DebugFlowOpaqueTypeDeclaration.prototype);
+  }
+}
+
+const DebugFlowOpaqueTypeDeclaration = class FlowOpaqueTypeDeclaration {};
+
 function constructCommentKind(pos, ast) {
   switch (ast.buffer[pos]) {
     case 0:
@@ -12855,6 +13163,18 @@ function constructBoxV8IntrinsicExpression(pos, ast) {
   return new V8IntrinsicExpression(ast.buffer.int32[pos >> 2], ast);
 }
 
+function constructBoxFlowTypeCastExpression(pos, ast) {
+  return new FlowTypeCastExpression(ast.buffer.int32[pos >> 2], ast);
+}
+
+function constructBoxPipelineExpression(pos, ast) {
+  return new PipelineExpression(ast.buffer.int32[pos >> 2], ast);
+}
+
+function constructBoxTopicReference(pos, ast) {
+  return new TopicReference(ast.buffer.int32[pos >> 2], ast);
+}
+
 function constructVecArrayExpressionElement(pos, ast) {
   const { int32 } = ast.buffer,
     pos32 = pos >> 2;
@@ -12879,6 +13199,10 @@ function constructBoxObjectProperty(pos, ast) {
   return new ObjectProperty(ast.buffer.int32[pos >> 2], ast);
 }
 
+function constructBoxErrorNode(pos, ast) {
+  return new ErrorNode(ast.buffer.int32[pos >> 2], ast);
+}
+
 function constructBool(pos, ast) {
   return ast.buffer[pos] === 1;
 }
@@ -13096,6 +13420,10 @@ function constructBoxTSImportEqualsDeclaration(pos, ast) {
   return new TSImportEqualsDeclaration(ast.buffer.int32[pos >> 2], ast);
 }
 
+function constructBoxFlowOpaqueTypeDeclaration(pos, ast) {
+  return new FlowOpaqueTypeDeclaration(ast.buffer.int32[pos >> 2], ast);
+}
+
 function constructVecVariableDeclarator(pos, ast) {
   const { int32 } = ast.buffer,
     pos32 = pos >> 2;
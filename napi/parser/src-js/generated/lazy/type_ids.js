@@ -16,176 +16,181 @@ export const NODE_TYPE_IDS_MAP = new Map([
   ["EmptyStatement", 9],
   ["DebuggerStatement", 10],
   ["PrivateIdentifier", 11],
-  ["BooleanLiteral", 12],
-  ["NullLiteral", 13],
-  ["NumericLiteral", 14],
-  ["StringLiteral", 15],
-  ["BigIntLiteral", 16],
-  ["RegExpLiteral", 17],
-  ["JSXOpeningFragment", 18],
-  ["JSXClosingFragment", 19],
-  ["JSXEmptyExpression", 20],
-  ["JSXIdentifier", 21],
-  ["JSXText", 22],
-  ["TSAnyKeyword", 23],
-  ["TSStringKeyword", 24],
-  ["TSBooleanKeyword", 25],
-  ["TSNumberKeyword", 26],
-  ["TSNeverKeyword", 27],
-  ["TSIntrinsicKeyword", 28],
-  ["TSUnknownKeyword", 29],
-  ["TSNullKeyword", 30],
-  ["TSUndefinedKeyword", 31],
-  ["TSVoidKeyword", 32],
-  ["TSSymbolKeyword", 33],
-  ["TSThisType", 34],
-  ["TSObjectKeyword", 35],
-  ["TSBigIntKeyword", 36],
-  ["JSDocUnknownType", 37],
+  ["TopicReference", 12],
+  ["ErrorNode", 13],
+  ["BooleanLiteral", 14],
+  ["NullLiteral", 15],
+  ["NumericLiteral", 16],
+  ["StringLiteral", 17],
+  ["BigIntLiteral", 18],
+  ["RegExpLiteral", 19],
+  ["JSXOpeningFragment", 20],
+  ["JSXClosingFragment", 21],
+  ["JSXEmptyExpression", 22],
+  ["JSXIdentifier", 23],
+  ["JSXText", 24],
+  ["TSAnyKeyword", 25],
+  ["TSStringKeyword", 26],
+  ["TSBooleanKeyword", 27],
+  ["TSNumberKeyword", 28],
+  ["TSNeverKeyword", 29],
+  ["TSIntrinsicKeyword", 30],
+  ["TSUnknownKeyword", 31],
+  ["TSNullKeyword", 32],
+  ["TSUndefinedKeyword", 33],
+  ["TSVoidKeyword", 34],
+  ["TSSymbolKeyword", 35],
+  ["TSThisType", 36],
+  ["TSObjectKeyword", 37],
+  ["TSBigIntKeyword", 38],
+  ["JSDocUnknownType", 39],
   // Non-leaf nodes
-  ["Program", 38],
-  ["ArrayExpression", 39],
-  ["ObjectExpression", 40],
-  ["ObjectProperty", 41],
-  ["TemplateLiteral", 42],
-  ["TaggedTemplateExpression", 43],
-  ["ComputedMemberExpression", 44],
-  ["StaticMemberExpression", 45],
-  ["PrivateFieldExpression", 46],
-  ["CallExpression", 47],
-  ["NewExpression", 48],
-  ["MetaProperty", 49],
-  ["SpreadElement", 50],
-  ["UpdateExpression", 51],
-  ["UnaryExpression", 52],
-  ["BinaryExpression", 53],
-  ["PrivateInExpression", 54],
-  ["LogicalExpression", 55],
-  ["ConditionalExpression", 56],
-  ["AssignmentExpression", 57],
-  ["ArrayAssignmentTarget", 58],
-  ["ObjectAssignmentTarget", 59],
-  ["AssignmentTargetWithDefault", 60],
-  ["AssignmentTargetPropertyIdentifier", 61],
-  ["AssignmentTargetPropertyProperty", 62],
-  ["SequenceExpression", 63],
-  ["AwaitExpression", 64],
-  ["ChainExpression", 65],
-  ["ParenthesizedExpression", 66],
-  ["BlockStatement", 67],
-  ["VariableDeclaration", 68],
-  ["VariableDeclarator", 69],
-  ["ExpressionStatement", 70],
-  ["IfStatement", 71],
-  ["DoWhileStatement", 72],
-  ["WhileStatement", 73],
-  ["ForStatement", 74],
-  ["ForInStatement", 75],
-  ["ForOfStatement", 76],
-  ["ContinueStatement", 77],
-  ["BreakStatement", 78],
-  ["ReturnStatement", 79],
-  ["WithStatement", 80],
-  ["SwitchStatement", 81],
-  ["SwitchCase", 82],
-  ["LabeledStatement", 83],
-  ["ThrowStatement", 84],
-  ["TryStatement", 85],
-  ["CatchClause", 86],
-  ["AssignmentPattern", 87],
-  ["ObjectPattern", 88],
-  ["BindingProperty", 89],
-  ["ArrayPattern", 90],
-  ["Function", 91],
-  ["FormalParameters", 92],
-  ["FunctionBody", 93],
-  ["ArrowFunctionExpression", 94],
-  ["YieldExpression", 95],
-  ["Class", 96],
-  ["ClassBody", 97],
-  ["MethodDefinition", 98],
-  ["PropertyDefinition", 99],
-  ["StaticBlock", 100],
-  ["AccessorProperty", 101],
-  ["ImportExpression", 102],
-  ["ImportDeclaration", 103],
-  ["ImportSpecifier", 104],
-  ["ImportDefaultSpecifier", 105],
-  ["ImportNamespaceSpecifier", 106],
-  ["ImportAttribute", 107],
-  ["ExportNamedDeclaration", 108],
-  ["ExportDefaultDeclaration", 109],
-  ["ExportAllDeclaration", 110],
-  ["ExportSpecifier", 111],
-  ["V8IntrinsicExpression", 112],
-  ["JSXElement", 113],
-  ["JSXOpeningElement", 114],
-  ["JSXClosingElement", 115],
-  ["JSXFragment", 116],
-  ["JSXNamespacedName", 117],
-  ["JSXMemberExpression", 118],
-  ["JSXExpressionContainer", 119],
-  ["JSXAttribute", 120],
-  ["JSXSpreadAttribute", 121],
-  ["JSXSpreadChild", 122],
-  ["TSEnumDeclaration", 123],
-  ["TSEnumBody", 124],
-  ["TSEnumMember", 125],
-  ["TSTypeAnnotation", 126],
-  ["TSLiteralType", 127],
-  ["TSConditionalType", 128],
-  ["TSUnionType", 129],
-  ["TSIntersectionType", 130],
-  ["TSParenthesizedType", 131],
-  ["TSTypeOperator", 132],
-  ["TSArrayType", 133],
-  ["TSIndexedAccessType", 134],
-  ["TSTupleType", 135],
-  ["TSNamedTupleMember", 136],
-  ["TSOptionalType", 137],
-  ["TSRestType", 138],
-  ["TSTypeReference", 139],
-  ["TSQualifiedName", 140],
-  ["TSTypeParameterInstantiation", 141],
-  ["TSTypeParameter", 142],
-  ["TSTypeParameterDeclaration", 143],
-  ["TSTypeAliasDeclaration", 144],
-  ["TSClassImplements", 145],
-  ["TSInterfaceDeclaration", 146],
-  ["TSInterfaceBody", 147],
-  ["TSPropertySignature", 148],
-  ["TSIndexSignature", 149],
-  ["TSCallSignatureDeclaration", 150],
-  ["TSMethodSignature", 151],
-  ["TSConstructSignatureDeclaration", 152],
-  ["TSIndexSignatureName", 153],
-  ["TSInterfaceHeritage", 154],
-  ["TSTypePredicate", 155],
-  ["TSModuleDeclaration", 156],
-  ["TSGlobalDeclaration", 157],
-  ["TSModuleBlock", 158],
-  ["TSTypeLiteral", 159],
-  ["TSInferType", 160],
-  ["TSTypeQuery", 161],
-  ["TSImportType", 162],
-  ["TSImportTypeQualifiedName", 163],
-  ["TSFunctionType", 164],
-  ["TSConstructorType", 165],
-  ["TSMappedType", 166],
-  ["TSTemplateLiteralType", 167],
-  ["TSAsExpression", 168],
-  ["TSSatisfiesExpression", 169],
-  ["TSTypeAssertion", 170],
-  ["TSImportEqualsDeclaration", 171],
-  ["TSExternalModuleReference", 172],
-  ["TSNonNullExpression", 173],
-  ["Decorator", 174],
-  ["TSExportAssignment", 175],
-  ["TSNamespaceExportDeclaration", 176],
-  ["TSInstantiationExpression", 177],
-  ["JSDocNullableType", 178],
-  ["JSDocNonNullableType", 179],
+  ["Program", 40],
+  ["ArrayExpression", 41],
+  ["ObjectExpression", 42],
+  ["ObjectProperty", 43],
+  ["TemplateLiteral", 44],
+  ["TaggedTemplateExpression", 45],
+  ["ComputedMemberExpression", 46],
+  ["StaticMemberExpression", 47],
+  ["PrivateFieldExpression", 48],
+  ["CallExpression", 49],
+  ["NewExpression", 50],
+  ["MetaProperty", 51],
+  ["SpreadElement", 52],
+  ["UpdateExpression", 53],
+  ["UnaryExpression", 54],
+  ["BinaryExpression", 55],
+  ["PrivateInExpression", 56],
+  ["LogicalExpression", 57],
+  ["ConditionalExpression", 58],
+  ["AssignmentExpression", 59],
+  ["ArrayAssignmentTarget", 60],
+  ["ObjectAssignmentTarget", 61],
+  ["AssignmentTargetWithDefault", 62],
+  ["AssignmentTargetPropertyIdentifier", 63],
+  ["AssignmentTargetPropertyProperty", 64],
+  ["SequenceExpression", 65],
+  ["AwaitExpression", 66],
+  ["ChainExpression", 67],
+  ["ParenthesizedExpression", 68],
+  ["BlockStatement", 69],
+  ["VariableDeclaration", 70],
+  ["VariableDeclarator", 71],
+  ["ExpressionStatement", 72],
+  ["IfStatement", 73],
+  ["DoWhileStatement", 74],
+  ["WhileStatement", 75],
+  ["ForStatement", 76],
+  ["ForInStatement", 77],
+  ["ForOfStatement", 78],
+  ["ContinueStatement", 79],
+  ["BreakStatement", 80],
+  ["ReturnStatement", 81],
+  ["WithStatement", 82],
+  ["SwitchStatement", 83],
+  ["SwitchCase", 84],
+  ["LabeledStatement", 85],
+  ["ThrowStatement", 86],
+  ["TryStatement", 87],
+  ["CatchClause", 88],
+  ["AssignmentPattern", 89],
+  ["ObjectPattern", 90],
+  ["BindingProperty", 91],
+  ["ArrayPattern", 92],
+  ["Function", 93],
+  ["FormalParameters", 94],
+  ["FunctionBody", 95],
+  ["ArrowFunctionExpression", 96],
+  ["YieldExpression", 97],
+  ["Class", 98],
+  ["ClassBody", 99],
+  ["MethodDefinition", 100],
+  ["PropertyDefinition", 101],
+  ["StaticBlock", 102],
+  ["AccessorProperty", 103],
+  ["ImportExpression", 104],
+  ["ImportDeclaration", 105],
+  ["ImportSpecifier", 106],
+  ["ImportDefaultSpecifier", 107],
+  ["ImportNamespaceSpecifier", 108],
+  ["ImportAttribute", 109],
+  ["ExportNamedDeclaration", 110],
+  ["ExportDefaultDeclaration", 111],
+  ["ExportAllDeclaration", 112],
+  ["ExportSpecifier", 113],
+  ["V8IntrinsicExpression", 114],
+  ["PipelineExpression", 115],
+  ["JSXElement", 116],
+  ["JSXOpeningElement", 117],
+  ["JSXClosingElement", 118],
+  ["JSXFragment", 119],
+  ["JSXNamespacedName", 120],
+  ["JSXMemberExpression", 121],
+  ["JSXExpressionContainer", 122],
+  ["JSXAttribute", 123],
+  ["JSXSpreadAttribute", 124],
+  ["JSXSpreadChild", 125],
+  ["TSEnumDeclaration", 126],
+  ["TSEnumBody", 127],
+  ["TSEnumMember", 128],
+  ["TSTypeAnnotation", 129],
+  ["TSLiteralType", 130],
+  ["TSConditionalType", 131],
+  ["TSUnionType", 132],
+  ["TSIntersectionType", 133],
+  ["TSParenthesizedType", 134],
+  ["TSTypeOperator", 135],
+  ["TSArrayType", 136],
+  ["TSIndexedAccessType", 137],
+  ["TSTupleType", 138],
+  ["TSNamedTupleMember", 139],
+  ["TSOptionalType", 140],
+  ["TSRestType", 141],
+  ["TSTypeReference", 142],
+  ["TSQualifiedName", 143],
+  ["TSTypeParameterInstantiation", 144],
+  ["TSTypeParameter", 145],
+  ["TSTypeParameterDeclaration", 146],
+  ["TSTypeAliasDeclaration", 147],
+  ["TSClassImplements", 148],
+  ["TSInterfaceDeclaration", 149],
+  ["TSInterfaceBody", 150],
+  ["TSPropertySignature", 151],
+  ["TSIndexSignature", 152],
+  ["TSCallSignatureDeclaration", 153],
+  ["TSMethodSignature", 154],
+  ["TSConstructSignatureDeclaration", 155],
+  ["TSIndexSignatureName", 156],
+  ["TSInterfaceHeritage", 157],
+  ["TSTypePredicate", 158],
+  ["TSModuleDeclaration", 159],
+  ["TSGlobalDeclaration", 160],
+  ["TSModuleBlock", 161],
+  ["TSTypeLiteral", 162],
+  ["TSInferType", 163],
+  ["TSTypeQuery", 164],
+  ["TSImportType", 165],
+  ["TSImportTypeQualifiedName", 166],
+  ["TSFunctionType", 167],
+  ["TSConstructorType", 168],
+  ["TSMappedType", 169],
+  ["TSTemplateLiteralType", 170],
+  ["TSAsExpression", 171],
+  ["TSSatisfiesExpression", 172],
+  ["TSTypeAssertion", 173],
+  ["TSImportEqualsDeclaration", 174],
+  ["TSExternalModuleReference", 175],
+  ["TSNonNullExpression", 176],
+  ["Decorator", 177],
+  ["TSExportAssignment", 178],
+  ["TSNamespaceExportDeclaration", 179],
+  ["TSInstantiationExpression", 180],
+  ["JSDocNullableType", 181],
+  ["JSDocNonNullableType", 182],
+  ["FlowTypeCastExpression", 183],
+  ["FlowOpaqueTypeDeclaration", 184],
 ]);
 
-export const NODE_TYPES_COUNT = 180;
-export const LEAF_NODE_TYPES_COUNT = 38;
+export const NODE_TYPES_COUNT = 185;
+export const LEAF_NODE_TYPES_COUNT = 40;
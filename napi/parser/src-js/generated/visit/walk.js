@@ -16,6 +16,9 @@ function walkNode(node, visitors) {
       case "EmptyStatement":
         walkEmptyStatement(node, visitors);
         break;
+      case "ErrorNode":
+        walkErrorNode(node, visitors);
+        break;
       case "Literal":
         walkLiteral(node, visitors);
         break;
@@ -518,133 +521,138 @@ function walkEmptyStatement(node, visitors) {
   visit !== null && visit(node);
 }
 
-function walkLiteral(node, visitors) {
+function walkErrorNode(node, visitors) {
   let visit = visitors[2];
   visit !== null && visit(node);
 }
 
-function walkPrivateIdentifier(node, visitors) {
+function walkLiteral(node, visitors) {
   let visit = visitors[3];
   visit !== null && visit(node);
 }
 
-function walkSuper(node, visitors) {
+function walkPrivateIdentifier(node, visitors) {
   let visit = visitors[4];
   visit !== null && visit(node);
 }
 
-function walkTemplateElement(node, visitors) {
+function walkSuper(node, visitors) {
   let visit = visitors[5];
   visit !== null && visit(node);
 }
 
-function walkThisExpression(node, visitors) {
+function walkTemplateElement(node, visitors) {
   let visit = visitors[6];
   visit !== null && visit(node);
 }
 
-function walkJSXClosingFragment(node, visitors) {
+function walkThisExpression(node, visitors) {
   let visit = visitors[7];
   visit !== null && visit(node);
 }
 
-function walkJSXEmptyExpression(node, visitors) {
+function walkJSXClosingFragment(node, visitors) {
   let visit = visitors[8];
   visit !== null && visit(node);
 }
 
-function walkJSXIdentifier(node, visitors) {
+function walkJSXEmptyExpression(node, visitors) {
   let visit = visitors[9];
   visit !== null && visit(node);
 }
 
-function walkJSXOpeningFragment(node, visitors) {
+function walkJSXIdentifier(node, visitors) {
   let visit = visitors[10];
   visit !== null && visit(node);
 }
 
-function walkJSXText(node, visitors) {
+function walkJSXOpeningFragment(node, visitors) {
   let visit = visitors[11];
   visit !== null && visit(node);
 }
 
-function walkTSAnyKeyword(node, visitors) {
+function walkJSXText(node, visitors) {
   let visit = visitors[12];
   visit !== null && visit(node);
 }
 
-function walkTSBigIntKeyword(node, visitors) {
+function walkTSAnyKeyword(node, visitors) {
   let visit = visitors[13];
   visit !== null && visit(node);
 }
 
-function walkTSBooleanKeyword(node, visitors) {
+function walkTSBigIntKeyword(node, visitors) {
   let visit = visitors[14];
   visit !== null && visit(node);
 }
 
-function walkTSIntrinsicKeyword(node, visitors) {
+function walkTSBooleanKeyword(node, visitors) {
   let visit = visitors[15];
   visit !== null && visit(node);
 }
 
-function walkTSJSDocUnknownType(node, visitors) {
+function walkTSIntrinsicKeyword(node, visitors) {
   let visit = visitors[16];
   visit !== null && visit(node);
 }
 
-function walkTSNeverKeyword(node, visitors) {
+function walkTSJSDocUnknownType(node, visitors) {
   let visit = visitors[17];
   visit !== null && visit(node);
 }
 
-function walkTSNullKeyword(node, visitors) {
+function walkTSNeverKeyword(node, visitors) {
   let visit = visitors[18];
   visit !== null && visit(node);
 }
 
-function walkTSNumberKeyword(node, visitors) {
+function walkTSNullKeyword(node, visitors) {
   let visit = visitors[19];
   visit !== null && visit(node);
 }
 
-function walkTSObjectKeyword(node, visitors) {
+function walkTSNumberKeyword(node, visitors) {
   let visit = visitors[20];
   visit !== null && visit(node);
 }
 
-function walkTSStringKeyword(node, visitors) {
+function walkTSObjectKeyword(node, visitors) {
   let visit = visitors[21];
   visit !== null && visit(node);
 }
 
-function walkTSSymbolKeyword(node, visitors) {
+function walkTSStringKeyword(node, visitors) {
   let visit = visitors[22];
   visit !== null && visit(node);
 }
 
-function walkTSThisType(node, visitors) {
+function walkTSSymbolKeyword(node, visitors) {
   let visit = visitors[23];
   visit !== null && visit(node);
 }
 
-function walkTSUndefinedKeyword(node, visitors) {
+function walkTSThisType(node, visitors) {
   let visit = visitors[24];
   visit !== null && visit(node);
 }
 
-function walkTSUnknownKeyword(node, visitors) {
+function walkTSUndefinedKeyword(node, visitors) {
   let visit = visitors[25];
   visit !== null && visit(node);
 }
 
-function walkTSVoidKeyword(node, visitors) {
+function walkTSUnknownKeyword(node, visitors) {
   let visit = visitors[26];
   visit !== null && visit(node);
 }
 
+function walkTSVoidKeyword(node, visitors) {
+  let visit = visitors[27];
+  visit !== null && visit(node);
+}
+
 function walkAccessorProperty(node, visitors) {
-  let enterExit = visitors[27],
+  let enterExit = visitors[28],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -659,7 +667,7 @@ function walkAccessorProperty(node, visitors) {
 }
 
 function walkArrayExpression(node, visitors) {
-  let enterExit = visitors[28],
+  let enterExit = visitors[29],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -671,7 +679,7 @@ function walkArrayExpression(node, visitors) {
 }
 
 function walkArrayPattern(node, visitors) {
-  let enterExit = visitors[29],
+  let enterExit = visitors[30],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -685,7 +693,7 @@ function walkArrayPattern(node, visitors) {
 }
 
 function walkArrowFunctionExpression(node, visitors) {
-  let enterExit = visitors[30],
+  let enterExit = visitors[31],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -700,7 +708,7 @@ function walkArrowFunctionExpression(node, visitors) {
 }
 
 function walkAssignmentExpression(node, visitors) {
-  let enterExit = visitors[31],
+  let enterExit = visitors[32],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -713,7 +721,7 @@ function walkAssignmentExpression(node, visitors) {
 }
 
 function walkAssignmentPattern(node, visitors) {
-  let enterExit = visitors[32],
+  let enterExit = visitors[33],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -728,7 +736,7 @@ function walkAssignmentPattern(node, visitors) {
 }
 
 function walkAwaitExpression(node, visitors) {
-  let enterExit = visitors[33],
+  let enterExit = visitors[34],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -740,7 +748,7 @@ function walkAwaitExpression(node, visitors) {
 }
 
 function walkBinaryExpression(node, visitors) {
-  let enterExit = visitors[34],
+  let enterExit = visitors[35],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -753,7 +761,7 @@ function walkBinaryExpression(node, visitors) {
 }
 
 function walkBlockStatement(node, visitors) {
-  let enterExit = visitors[35],
+  let enterExit = visitors[36],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -765,7 +773,7 @@ function walkBlockStatement(node, visitors) {
 }
 
 function walkBreakStatement(node, visitors) {
-  let enterExit = visitors[36],
+  let enterExit = visitors[37],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -777,7 +785,7 @@ function walkBreakStatement(node, visitors) {
 }
 
 function walkCallExpression(node, visitors) {
-  let enterExit = visitors[37],
+  let enterExit = visitors[38],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -791,7 +799,7 @@ function walkCallExpression(node, visitors) {
 }
 
 function walkCatchClause(node, visitors) {
-  let enterExit = visitors[38],
+  let enterExit = visitors[39],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -804,7 +812,7 @@ function walkCatchClause(node, visitors) {
 }
 
 function walkChainExpression(node, visitors) {
-  let enterExit = visitors[39],
+  let enterExit = visitors[40],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -816,7 +824,7 @@ function walkChainExpression(node, visitors) {
 }
 
 function walkClassBody(node, visitors) {
-  let enterExit = visitors[40],
+  let enterExit = visitors[41],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -828,7 +836,7 @@ function walkClassBody(node, visitors) {
 }
 
 function walkClassDeclaration(node, visitors) {
-  let enterExit = visitors[41],
+  let enterExit = visitors[42],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -846,7 +854,7 @@ function walkClassDeclaration(node, visitors) {
 }
 
 function walkClassExpression(node, visitors) {
-  let enterExit = visitors[42],
+  let enterExit = visitors[43],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -864,7 +872,7 @@ function walkClassExpression(node, visitors) {
 }
 
 function walkConditionalExpression(node, visitors) {
-  let enterExit = visitors[43],
+  let enterExit = visitors[44],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -878,7 +886,7 @@ function walkConditionalExpression(node, visitors) {
 }
 
 function walkContinueStatement(node, visitors) {
-  let enterExit = visitors[44],
+  let enterExit = visitors[45],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -890,7 +898,7 @@ function walkContinueStatement(node, visitors) {
 }
 
 function walkDecorator(node, visitors) {
-  let enterExit = visitors[45],
+  let enterExit = visitors[46],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -902,7 +910,7 @@ function walkDecorator(node, visitors) {
 }
 
 function walkDoWhileStatement(node, visitors) {
-  let enterExit = visitors[46],
+  let enterExit = visitors[47],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -915,7 +923,7 @@ function walkDoWhileStatement(node, visitors) {
 }
 
 function walkExportAllDeclaration(node, visitors) {
-  let enterExit = visitors[47],
+  let enterExit = visitors[48],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -929,7 +937,7 @@ function walkExportAllDeclaration(node, visitors) {
 }
 
 function walkExportDefaultDeclaration(node, visitors) {
-  let enterExit = visitors[48],
+  let enterExit = visitors[49],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -941,7 +949,7 @@ function walkExportDefaultDeclaration(node, visitors) {
 }
 
 function walkExportNamedDeclaration(node, visitors) {
-  let enterExit = visitors[49],
+  let enterExit = visitors[50],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -956,7 +964,7 @@ function walkExportNamedDeclaration(node, visitors) {
 }
 
 function walkExportSpecifier(node, visitors) {
-  let enterExit = visitors[50],
+  let enterExit = visitors[51],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -969,7 +977,7 @@ function walkExportSpecifier(node, visitors) {
 }
 
 function walkExpressionStatement(node, visitors) {
-  let enterExit = visitors[51],
+  let enterExit = visitors[52],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -981,7 +989,7 @@ function walkExpressionStatement(node, visitors) {
 }
 
 function walkForInStatement(node, visitors) {
-  let enterExit = visitors[52],
+  let enterExit = visitors[53],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -995,7 +1003,7 @@ function walkForInStatement(node, visitors) {
 }
 
 function walkForOfStatement(node, visitors) {
-  let enterExit = visitors[53],
+  let enterExit = visitors[54],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1009,7 +1017,7 @@ function walkForOfStatement(node, visitors) {
 }
 
 function walkForStatement(node, visitors) {
-  let enterExit = visitors[54],
+  let enterExit = visitors[55],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1024,7 +1032,7 @@ function walkForStatement(node, visitors) {
 }
 
 function walkFunctionDeclaration(node, visitors) {
-  let enterExit = visitors[55],
+  let enterExit = visitors[56],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1040,7 +1048,7 @@ function walkFunctionDeclaration(node, visitors) {
 }
 
 function walkFunctionExpression(node, visitors) {
-  let enterExit = visitors[56],
+  let enterExit = visitors[57],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1056,7 +1064,7 @@ function walkFunctionExpression(node, visitors) {
 }
 
 function walkIdentifier(node, visitors) {
-  let enterExit = visitors[57],
+  let enterExit = visitors[58],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1069,7 +1077,7 @@ function walkIdentifier(node, visitors) {
 }
 
 function walkIfStatement(node, visitors) {
-  let enterExit = visitors[58],
+  let enterExit = visitors[59],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1083,7 +1091,7 @@ function walkIfStatement(node, visitors) {
 }
 
 function walkImportAttribute(node, visitors) {
-  let enterExit = visitors[59],
+  let enterExit = visitors[60],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1096,7 +1104,7 @@ function walkImportAttribute(node, visitors) {
 }
 
 function walkImportDeclaration(node, visitors) {
-  let enterExit = visitors[60],
+  let enterExit = visitors[61],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1110,7 +1118,7 @@ function walkImportDeclaration(node, visitors) {
 }
 
 function walkImportDefaultSpecifier(node, visitors) {
-  let enterExit = visitors[61],
+  let enterExit = visitors[62],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1122,7 +1130,7 @@ function walkImportDefaultSpecifier(node, visitors) {
 }
 
 function walkImportExpression(node, visitors) {
-  let enterExit = visitors[62],
+  let enterExit = visitors[63],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1135,7 +1143,7 @@ function walkImportExpression(node, visitors) {
 }
 
 function walkImportNamespaceSpecifier(node, visitors) {
-  let enterExit = visitors[63],
+  let enterExit = visitors[64],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1147,7 +1155,7 @@ function walkImportNamespaceSpecifier(node, visitors) {
 }
 
 function walkImportSpecifier(node, visitors) {
-  let enterExit = visitors[64],
+  let enterExit = visitors[65],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1160,7 +1168,7 @@ function walkImportSpecifier(node, visitors) {
 }
 
 function walkLabeledStatement(node, visitors) {
-  let enterExit = visitors[65],
+  let enterExit = visitors[66],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1173,7 +1181,7 @@ function walkLabeledStatement(node, visitors) {
 }
 
 function walkLogicalExpression(node, visitors) {
-  let enterExit = visitors[66],
+  let enterExit = visitors[67],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1186,7 +1194,7 @@ function walkLogicalExpression(node, visitors) {
 }
 
 function walkMemberExpression(node, visitors) {
-  let enterExit = visitors[67],
+  let enterExit = visitors[68],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1199,7 +1207,7 @@ function walkMemberExpression(node, visitors) {
 }
 
 function walkMetaProperty(node, visitors) {
-  let enterExit = visitors[68],
+  let enterExit = visitors[69],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1212,7 +1220,7 @@ function walkMetaProperty(node, visitors) {
 }
 
 function walkMethodDefinition(node, visitors) {
-  let enterExit = visitors[69],
+  let enterExit = visitors[70],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1226,7 +1234,7 @@ function walkMethodDefinition(node, visitors) {
 }
 
 function walkNewExpression(node, visitors) {
-  let enterExit = visitors[70],
+  let enterExit = visitors[71],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1240,7 +1248,7 @@ function walkNewExpression(node, visitors) {
 }
 
 function walkObjectExpression(node, visitors) {
-  let enterExit = visitors[71],
+  let enterExit = visitors[72],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1252,7 +1260,7 @@ function walkObjectExpression(node, visitors) {
 }
 
 function walkObjectPattern(node, visitors) {
-  let enterExit = visitors[72],
+  let enterExit = visitors[73],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1266,7 +1274,7 @@ function walkObjectPattern(node, visitors) {
 }
 
 function walkParenthesizedExpression(node, visitors) {
-  let enterExit = visitors[73],
+  let enterExit = visitors[74],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1278,7 +1286,7 @@ function walkParenthesizedExpression(node, visitors) {
 }
 
 function walkProgram(node, visitors) {
-  let enterExit = visitors[74],
+  let enterExit = visitors[75],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1290,7 +1298,7 @@ function walkProgram(node, visitors) {
 }
 
 function walkProperty(node, visitors) {
-  let enterExit = visitors[75],
+  let enterExit = visitors[76],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1303,7 +1311,7 @@ function walkProperty(node, visitors) {
 }
 
 function walkPropertyDefinition(node, visitors) {
-  let enterExit = visitors[76],
+  let enterExit = visitors[77],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1318,7 +1326,7 @@ function walkPropertyDefinition(node, visitors) {
 }
 
 function walkRestElement(node, visitors) {
-  let enterExit = visitors[77],
+  let enterExit = visitors[78],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1332,7 +1340,7 @@ function walkRestElement(node, visitors) {
 }
 
 function walkReturnStatement(node, visitors) {
-  let enterExit = visitors[78],
+  let enterExit = visitors[79],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1344,7 +1352,7 @@ function walkReturnStatement(node, visitors) {
 }
 
 function walkSequenceExpression(node, visitors) {
-  let enterExit = visitors[79],
+  let enterExit = visitors[80],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1356,7 +1364,7 @@ function walkSequenceExpression(node, visitors) {
 }
 
 function walkSpreadElement(node, visitors) {
-  let enterExit = visitors[80],
+  let enterExit = visitors[81],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1368,7 +1376,7 @@ function walkSpreadElement(node, visitors) {
 }
 
 function walkStaticBlock(node, visitors) {
-  let enterExit = visitors[81],
+  let enterExit = visitors[82],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1380,7 +1388,7 @@ function walkStaticBlock(node, visitors) {
 }
 
 function walkSwitchCase(node, visitors) {
-  let enterExit = visitors[82],
+  let enterExit = visitors[83],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1393,7 +1401,7 @@ function walkSwitchCase(node, visitors) {
 }
 
 function walkSwitchStatement(node, visitors) {
-  let enterExit = visitors[83],
+  let enterExit = visitors[84],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1406,7 +1414,7 @@ function walkSwitchStatement(node, visitors) {
 }
 
 function walkTaggedTemplateExpression(node, visitors) {
-  let enterExit = visitors[84],
+  let enterExit = visitors[85],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1420,7 +1428,7 @@ function walkTaggedTemplateExpression(node, visitors) {
 }
 
 function walkTemplateLiteral(node, visitors) {
-  let enterExit = visitors[85],
+  let enterExit = visitors[86],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1433,7 +1441,7 @@ function walkTemplateLiteral(node, visitors) {
 }
 
 function walkThrowStatement(node, visitors) {
-  let enterExit = visitors[86],
+  let enterExit = visitors[87],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1445,7 +1453,7 @@ function walkThrowStatement(node, visitors) {
 }
 
 function walkTryStatement(node, visitors) {
-  let enterExit = visitors[87],
+  let enterExit = visitors[88],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1459,7 +1467,7 @@ function walkTryStatement(node, visitors) {
 }
 
 function walkUnaryExpression(node, visitors) {
-  let enterExit = visitors[88],
+  let enterExit = visitors[89],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1471,7 +1479,7 @@ function walkUnaryExpression(node, visitors) {
 }
 
 function walkUpdateExpression(node, visitors) {
-  let enterExit = visitors[89],
+  let enterExit = visitors[90],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1483,7 +1491,7 @@ function walkUpdateExpression(node, visitors) {
 }
 
 function walkV8IntrinsicExpression(node, visitors) {
-  let enterExit = visitors[90],
+  let enterExit = visitors[91],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1496,7 +1504,7 @@ function walkV8IntrinsicExpression(node, visitors) {
 }
 
 function walkVariableDeclaration(node, visitors) {
-  let enterExit = visitors[91],
+  let enterExit = visitors[92],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1508,7 +1516,7 @@ function walkVariableDeclaration(node, visitors) {
 }
 
 function walkVariableDeclarator(node, visitors) {
-  let enterExit = visitors[92],
+  let enterExit = visitors[93],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1521,7 +1529,7 @@ function walkVariableDeclarator(node, visitors) {
 }
 
 function walkWhileStatement(node, visitors) {
-  let enterExit = visitors[93],
+  let enterExit = visitors[94],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1534,7 +1542,7 @@ function walkWhileStatement(node, visitors) {
 }
 
 function walkWithStatement(node, visitors) {
-  let enterExit = visitors[94],
+  let enterExit = visitors[95],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1547,7 +1555,7 @@ function walkWithStatement(node, visitors) {
 }
 
 function walkYieldExpression(node, visitors) {
-  let enterExit = visitors[95],
+  let enterExit = visitors[96],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1559,7 +1567,7 @@ function walkYieldExpression(node, visitors) {
 }
 
 function walkJSXAttribute(node, visitors) {
-  let enterExit = visitors[96],
+  let enterExit = visitors[97],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1572,7 +1580,7 @@ function walkJSXAttribute(node, visitors) {
 }
 
 function walkJSXClosingElement(node, visitors) {
-  let enterExit = visitors[97],
+  let enterExit = visitors[98],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1584,7 +1592,7 @@ function walkJSXClosingElement(node, visitors) {
 }
 
 function walkJSXElement(node, visitors) {
-  let enterExit = visitors[98],
+  let enterExit = visitors[99],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1598,7 +1606,7 @@ function walkJSXElement(node, visitors) {
 }
 
 function walkJSXExpressionContainer(node, visitors) {
-  let enterExit = visitors[99],
+  let enterExit = visitors[100],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1610,7 +1618,7 @@ function walkJSXExpressionContainer(node, visitors) {
 }
 
 function walkJSXFragment(node, visitors) {
-  let enterExit = visitors[100],
+  let enterExit = visitors[101],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1624,7 +1632,7 @@ function walkJSXFragment(node, visitors) {
 }
 
 function walkJSXMemberExpression(node, visitors) {
-  let enterExit = visitors[101],
+  let enterExit = visitors[102],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1637,7 +1645,7 @@ function walkJSXMemberExpression(node, visitors) {
 }
 
 function walkJSXNamespacedName(node, visitors) {
-  let enterExit = visitors[102],
+  let enterExit = visitors[103],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1650,7 +1658,7 @@ function walkJSXNamespacedName(node, visitors) {
 }
 
 function walkJSXOpeningElement(node, visitors) {
-  let enterExit = visitors[103],
+  let enterExit = visitors[104],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1664,7 +1672,7 @@ function walkJSXOpeningElement(node, visitors) {
 }
 
 function walkJSXSpreadAttribute(node, visitors) {
-  let enterExit = visitors[104],
+  let enterExit = visitors[105],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1676,7 +1684,7 @@ function walkJSXSpreadAttribute(node, visitors) {
 }
 
 function walkJSXSpreadChild(node, visitors) {
-  let enterExit = visitors[105],
+  let enterExit = visitors[106],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1688,7 +1696,7 @@ function walkJSXSpreadChild(node, visitors) {
 }
 
 function walkTSAbstractAccessorProperty(node, visitors) {
-  let enterExit = visitors[106],
+  let enterExit = visitors[107],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1702,7 +1710,7 @@ function walkTSAbstractAccessorProperty(node, visitors) {
 }
 
 function walkTSAbstractMethodDefinition(node, visitors) {
-  let enterExit = visitors[107],
+  let enterExit = visitors[108],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1715,7 +1723,7 @@ function walkTSAbstractMethodDefinition(node, visitors) {
 }
 
 function walkTSAbstractPropertyDefinition(node, visitors) {
-  let enterExit = visitors[108],
+  let enterExit = visitors[109],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1729,7 +1737,7 @@ function walkTSAbstractPropertyDefinition(node, visitors) {
 }
 
 function walkTSArrayType(node, visitors) {
-  let enterExit = visitors[109],
+  let enterExit = visitors[110],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1741,7 +1749,7 @@ function walkTSArrayType(node, visitors) {
 }
 
 function walkTSAsExpression(node, visitors) {
-  let enterExit = visitors[110],
+  let enterExit = visitors[111],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1754,7 +1762,7 @@ function walkTSAsExpression(node, visitors) {
 }
 
 function walkTSCallSignatureDeclaration(node, visitors) {
-  let enterExit = visitors[111],
+  let enterExit = visitors[112],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1768,7 +1776,7 @@ function walkTSCallSignatureDeclaration(node, visitors) {
 }
 
 function walkTSClassImplements(node, visitors) {
-  let enterExit = visitors[112],
+  let enterExit = visitors[113],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1781,7 +1789,7 @@ function walkTSClassImplements(node, visitors) {
 }
 
 function walkTSConditionalType(node, visitors) {
-  let enterExit = visitors[113],
+  let enterExit = visitors[114],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1796,7 +1804,7 @@ function walkTSConditionalType(node, visitors) {
 }
 
 function walkTSConstructSignatureDeclaration(node, visitors) {
-  let enterExit = visitors[114],
+  let enterExit = visitors[115],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1810,7 +1818,7 @@ function walkTSConstructSignatureDeclaration(node, visitors) {
 }
 
 function walkTSConstructorType(node, visitors) {
-  let enterExit = visitors[115],
+  let enterExit = visitors[116],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1824,7 +1832,7 @@ function walkTSConstructorType(node, visitors) {
 }
 
 function walkTSDeclareFunction(node, visitors) {
-  let enterExit = visitors[116],
+  let enterExit = visitors[117],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1840,7 +1848,7 @@ function walkTSDeclareFunction(node, visitors) {
 }
 
 function walkTSEmptyBodyFunctionExpression(node, visitors) {
-  let enterExit = visitors[117],
+  let enterExit = visitors[118],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1855,7 +1863,7 @@ function walkTSEmptyBodyFunctionExpression(node, visitors) {
 }
 
 function walkTSEnumBody(node, visitors) {
-  let enterExit = visitors[118],
+  let enterExit = visitors[119],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1867,7 +1875,7 @@ function walkTSEnumBody(node, visitors) {
 }
 
 function walkTSEnumDeclaration(node, visitors) {
-  let enterExit = visitors[119],
+  let enterExit = visitors[120],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1880,7 +1888,7 @@ function walkTSEnumDeclaration(node, visitors) {
 }
 
 function walkTSEnumMember(node, visitors) {
-  let enterExit = visitors[120],
+  let enterExit = visitors[121],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1893,7 +1901,7 @@ function walkTSEnumMember(node, visitors) {
 }
 
 function walkTSExportAssignment(node, visitors) {
-  let enterExit = visitors[121],
+  let enterExit = visitors[122],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1905,7 +1913,7 @@ function walkTSExportAssignment(node, visitors) {
 }
 
 function walkTSExternalModuleReference(node, visitors) {
-  let enterExit = visitors[122],
+  let enterExit = visitors[123],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1917,7 +1925,7 @@ function walkTSExternalModuleReference(node, visitors) {
 }
 
 function walkTSFunctionType(node, visitors) {
-  let enterExit = visitors[123],
+  let enterExit = visitors[124],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1931,7 +1939,7 @@ function walkTSFunctionType(node, visitors) {
 }
 
 function walkTSImportEqualsDeclaration(node, visitors) {
-  let enterExit = visitors[124],
+  let enterExit = visitors[125],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1944,7 +1952,7 @@ function walkTSImportEqualsDeclaration(node, visitors) {
 }
 
 function walkTSImportType(node, visitors) {
-  let enterExit = visitors[125],
+  let enterExit = visitors[126],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1959,7 +1967,7 @@ function walkTSImportType(node, visitors) {
 }
 
 function walkTSIndexSignature(node, visitors) {
-  let enterExit = visitors[126],
+  let enterExit = visitors[127],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1972,7 +1980,7 @@ function walkTSIndexSignature(node, visitors) {
 }
 
 function walkTSIndexedAccessType(node, visitors) {
-  let enterExit = visitors[127],
+  let enterExit = visitors[128],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1985,7 +1993,7 @@ function walkTSIndexedAccessType(node, visitors) {
 }
 
 function walkTSInferType(node, visitors) {
-  let enterExit = visitors[128],
+  let enterExit = visitors[129],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -1997,7 +2005,7 @@ function walkTSInferType(node, visitors) {
 }
 
 function walkTSInstantiationExpression(node, visitors) {
-  let enterExit = visitors[129],
+  let enterExit = visitors[130],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2010,7 +2018,7 @@ function walkTSInstantiationExpression(node, visitors) {
 }
 
 function walkTSInterfaceBody(node, visitors) {
-  let enterExit = visitors[130],
+  let enterExit = visitors[131],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2022,7 +2030,7 @@ function walkTSInterfaceBody(node, visitors) {
 }
 
 function walkTSInterfaceDeclaration(node, visitors) {
-  let enterExit = visitors[131],
+  let enterExit = visitors[132],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2037,7 +2045,7 @@ function walkTSInterfaceDeclaration(node, visitors) {
 }
 
 function walkTSInterfaceHeritage(node, visitors) {
-  let enterExit = visitors[132],
+  let enterExit = visitors[133],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2050,7 +2058,7 @@ function walkTSInterfaceHeritage(node, visitors) {
 }
 
 function walkTSIntersectionType(node, visitors) {
-  let enterExit = visitors[133],
+  let enterExit = visitors[134],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2062,7 +2070,7 @@ function walkTSIntersectionType(node, visitors) {
 }
 
 function walkTSJSDocNonNullableType(node, visitors) {
-  let enterExit = visitors[134],
+  let enterExit = visitors[135],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2074,7 +2082,7 @@ function walkTSJSDocNonNullableType(node, visitors) {
 }
 
 function walkTSJSDocNullableType(node, visitors) {
-  let enterExit = visitors[135],
+  let enterExit = visitors[136],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2086,7 +2094,7 @@ function walkTSJSDocNullableType(node, visitors) {
 }
 
 function walkTSLiteralType(node, visitors) {
-  let enterExit = visitors[136],
+  let enterExit = visitors[137],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2098,7 +2106,7 @@ function walkTSLiteralType(node, visitors) {
 }
 
 function walkTSMappedType(node, visitors) {
-  let enterExit = visitors[137],
+  let enterExit = visitors[138],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2113,7 +2121,7 @@ function walkTSMappedType(node, visitors) {
 }
 
 function walkTSMethodSignature(node, visitors) {
-  let enterExit = visitors[138],
+  let enterExit = visitors[139],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2128,7 +2136,7 @@ function walkTSMethodSignature(node, visitors) {
 }
 
 function walkTSModuleBlock(node, visitors) {
-  let enterExit = visitors[139],
+  let enterExit = visitors[140],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2140,7 +2148,7 @@ function walkTSModuleBlock(node, visitors) {
 }
 
 function walkTSModuleDeclaration(node, visitors) {
-  let enterExit = visitors[140],
+  let enterExit = visitors[141],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2153,7 +2161,7 @@ function walkTSModuleDeclaration(node, visitors) {
 }
 
 function walkTSNamedTupleMember(node, visitors) {
-  let enterExit = visitors[141],
+  let enterExit = visitors[142],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2166,7 +2174,7 @@ function walkTSNamedTupleMember(node, visitors) {
 }
 
 function walkTSNamespaceExportDeclaration(node, visitors) {
-  let enterExit = visitors[142],
+  let enterExit = visitors[143],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2178,7 +2186,7 @@ function walkTSNamespaceExportDeclaration(node, visitors) {
 }
 
 function walkTSNonNullExpression(node, visitors) {
-  let enterExit = visitors[143],
+  let enterExit = visitors[144],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2190,7 +2198,7 @@ function walkTSNonNullExpression(node, visitors) {
 }
 
 function walkTSOptionalType(node, visitors) {
-  let enterExit = visitors[144],
+  let enterExit = visitors[145],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2202,7 +2210,7 @@ function walkTSOptionalType(node, visitors) {
 }
 
 function walkTSParameterProperty(node, visitors) {
-  let enterExit = visitors[145],
+  let enterExit = visitors[146],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2215,7 +2223,7 @@ function walkTSParameterProperty(node, visitors) {
 }
 
 function walkTSParenthesizedType(node, visitors) {
-  let enterExit = visitors[146],
+  let enterExit = visitors[147],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2227,7 +2235,7 @@ function walkTSParenthesizedType(node, visitors) {
 }
 
 function walkTSPropertySignature(node, visitors) {
-  let enterExit = visitors[147],
+  let enterExit = visitors[148],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2240,7 +2248,7 @@ function walkTSPropertySignature(node, visitors) {
 }
 
 function walkTSQualifiedName(node, visitors) {
-  let enterExit = visitors[148],
+  let enterExit = visitors[149],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2253,7 +2261,7 @@ function walkTSQualifiedName(node, visitors) {
 }
 
 function walkTSRestType(node, visitors) {
-  let enterExit = visitors[149],
+  let enterExit = visitors[150],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2265,7 +2273,7 @@ function walkTSRestType(node, visitors) {
 }
 
 function walkTSSatisfiesExpression(node, visitors) {
-  let enterExit = visitors[150],
+  let enterExit = visitors[151],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2278,7 +2286,7 @@ function walkTSSatisfiesExpression(node, visitors) {
 }
 
 function walkTSTemplateLiteralType(node, visitors) {
-  let enterExit = visitors[151],
+  let enterExit = visitors[152],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2291,7 +2299,7 @@ function walkTSTemplateLiteralType(node, visitors) {
 }
 
 function walkTSTupleType(node, visitors) {
-  let enterExit = visitors[152],
+  let enterExit = visitors[153],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2303,7 +2311,7 @@ function walkTSTupleType(node, visitors) {
 }
 
 function walkTSTypeAliasDeclaration(node, visitors) {
-  let enterExit = visitors[153],
+  let enterExit = visitors[154],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2317,7 +2325,7 @@ function walkTSTypeAliasDeclaration(node, visitors) {
 }
 
 function walkTSTypeAnnotation(node, visitors) {
-  let enterExit = visitors[154],
+  let enterExit = visitors[155],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2329,7 +2337,7 @@ function walkTSTypeAnnotation(node, visitors) {
 }
 
 function walkTSTypeAssertion(node, visitors) {
-  let enterExit = visitors[155],
+  let enterExit = visitors[156],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2342,7 +2350,7 @@ function walkTSTypeAssertion(node, visitors) {
 }
 
 function walkTSTypeLiteral(node, visitors) {
-  let enterExit = visitors[156],
+  let enterExit = visitors[157],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2354,7 +2362,7 @@ function walkTSTypeLiteral(node, visitors) {
 }
 
 function walkTSTypeOperator(node, visitors) {
-  let enterExit = visitors[157],
+  let enterExit = visitors[158],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2366,7 +2374,7 @@ function walkTSTypeOperator(node, visitors) {
 }
 
 function walkTSTypeParameter(node, visitors) {
-  let enterExit = visitors[158],
+  let enterExit = visitors[159],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2380,7 +2388,7 @@ function walkTSTypeParameter(node, visitors) {
 }
 
 function walkTSTypeParameterDeclaration(node, visitors) {
-  let enterExit = visitors[159],
+  let enterExit = visitors[160],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2392,7 +2400,7 @@ function walkTSTypeParameterDeclaration(node, visitors) {
 }
 
 function walkTSTypeParameterInstantiation(node, visitors) {
-  let enterExit = visitors[160],
+  let enterExit = visitors[161],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2404,7 +2412,7 @@ function walkTSTypeParameterInstantiation(node, visitors) {
 }
 
 function walkTSTypePredicate(node, visitors) {
-  let enterExit = visitors[161],
+  let enterExit = visitors[162],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2417,7 +2425,7 @@ function walkTSTypePredicate(node, visitors) {
 }
 
 function walkTSTypeQuery(node, visitors) {
-  let enterExit = visitors[162],
+  let enterExit = visitors[163],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2430,7 +2438,7 @@ function walkTSTypeQuery(node, visitors) {
 }
 
 function walkTSTypeReference(node, visitors) {
-  let enterExit = visitors[163],
+  let enterExit = visitors[164],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -2443,7 +2451,7 @@ function walkTSTypeReference(node, visitors) {
 }
 
 function walkTSUnionType(node, visitors) {
-  let enterExit = visitors[164],
+  let enterExit = visitors[165],
     exit = null,
     enter;
   if (enterExit !== null) {
@@ -154,6 +154,8 @@ export interface OxcParserOptions {
   allowReturnOutsideFunction: boolean
   preserveParens: boolean
   allowV8Intrinsics: boolean
+  allowPipelineOperator: boolean
+  errorRecovery: boolean
   semanticErrors: boolean
 }
 
@@ -135,7 +135,7 @@ impl Oxc {
             self.build_semantic(&program, run_options, parser_options, &control_flow_options);
 
         // Phase 3: Run linter
-        let linter_module_record = Arc::new(ModuleRecord::new(&path, &module_record, &semantic));
+        let linter_module_record = Arc::new(ModuleRecord::new(&path, &module_record));
         self.run_linter(
             run_options,
             &linter_options,
@@ -222,6 +222,8 @@ impl Oxc {
             allow_return_outside_function: parser_options.allow_return_outside_function,
             preserve_parens: parser_options.preserve_parens,
             allow_v8_intrinsics: parser_options.allow_v8_intrinsics,
+            allow_pipeline_operator: parser_options.allow_pipeline_operator,
+            error_recovery: parser_options.error_recovery,
         };
         let ParserReturn { program, diagnostics, module_record, .. } =
             Parser::new(allocator, source_text, source_type).with_options(parser_options).parse();
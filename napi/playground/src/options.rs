@@ -45,6 +45,8 @@ pub struct OxcParserOptions {
     pub allow_return_outside_function: bool,
     pub preserve_parens: bool,
     pub allow_v8_intrinsics: bool,
+    pub allow_pipeline_operator: bool,
+    pub error_recovery: bool,
     pub semantic_errors: bool,
 }
 
@@ -48,11 +48,32 @@ export interface ArrowFunctionsOptions {
   spec?: boolean
 }
 
+export interface BlockScopingOptions {
+  /**
+   * Throw when a `let` or `const` binding is accessed before its declaration.
+   *
+   * @default false
+   */
+  tdz?: boolean
+  /**
+   * Report an error instead of moving a loop body into a function, when a closure captures
+   * a binding declared in the loop.
+   *
+   * @default false
+   */
+  throwIfClosureRequired?: boolean
+}
+
 export interface CompilerAssumptions {
+  arrayLikeIsIterable?: boolean
   ignoreFunctionLength?: boolean
+  ignoreToPrimitiveHint?: boolean
+  iterableIsArray?: boolean
+  mutableTemplateObject?: boolean
   noDocumentAll?: boolean
   objectRestNoSymbols?: boolean
   pureGetters?: boolean
+  setComputedProperties?: boolean
   /**
    * When using public class fields, assume that they don't shadow any getter in the current class,
    * in its subclasses or in its superclass. Thus, it's safe to assign them rather than using
@@ -95,6 +116,17 @@ export interface CompilerAssumptions {
    * to `true`.
    */
   setPublicClassFields?: boolean
+  skipForOfIteratorClosing?: boolean
+}
+
+export interface ComputedPropertiesOptions {
+  /**
+   * Assign computed properties instead of defining them. Same as the
+   * `setComputedProperties` assumption.
+   *
+   * @default false
+   */
+  loose?: boolean
 }
 
 export interface DecoratorOptions {
@@ -132,9 +164,86 @@ export interface DecoratorOptions {
   strictNullChecks?: boolean
 }
 
+export interface DestructuringOptions {
+  /**
+   * Assume all destructured values are arrays. Same as the `iterableIsArray` assumption.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface Es2015Options {
   /** Transform arrow functions into function expressions. */
   arrowFunction?: ArrowFunctionsOptions
+  /** Transform `let` and `const` declarations into `var`. */
+  blockScoping?: BlockScopingOptions
+  /**
+   * Transform classes into constructor functions.
+   *
+   * @default false
+   */
+  classes?: boolean
+  /** Transform default, destructured and rest parameters. */
+  parameters?: ParametersOptions
+  /** Transform array and object destructuring. */
+  destructuring?: DestructuringOptions
+  /** Transform spread elements in arrays, calls and `new` expressions. */
+  spread?: SpreadOptions
+  /** Transform template literals and tagged templates. */
+  templateLiterals?: TemplateLiteralsOptions
+  /**
+   * Transform shorthand properties and methods in object literals.
+   *
+   * @default false
+   */
+  shorthandProperties?: boolean
+  /** Transform computed properties in object literals. */
+  computedProperties?: ComputedPropertiesOptions
+  /** Transform `for...of` loops. */
+  forOf?: ForOfOptions
+  /**
+   * Name anonymous function expressions after the binding they are assigned to.
+   *
+   * @default false
+   */
+  functionName?: boolean
+  /**
+   * Make duplicate keys in object literals computed.
+   *
+   * @default false
+   */
+  duplicateKeys?: boolean
+  /**
+   * Transform `new.target`.
+   *
+   * @default false
+   */
+  newTarget?: boolean
+  /** Compile generator functions into state machines run by the regenerator runtime. */
+  regenerator?: RegeneratorOptions
+}
+
+export interface ForOfOptions {
+  /**
+   * Do not close iterators when the loop exits early. Same as the
+   * `skipForOfIteratorClosing` assumption.
+   *
+   * @default false
+   */
+  loose?: boolean
+  /**
+   * Assume all iterated values are arrays. Same as the `iterableIsArray` assumption.
+   *
+   * @default false
+   */
+  assumeArray?: boolean
+  /**
+   * Also iterate array-like objects. Same as the `arrayLikeIsIterable` assumption.
+   *
+   * @default false
+   */
+  allowArrayLike?: boolean
 }
 
 export declare const enum HelperMode {
@@ -324,6 +433,15 @@ export interface ModuleRunnerTransformResult {
 /** @deprecated Only works for Vite. */
 export declare function moduleRunnerTransformSync(filename: string, sourceText: string, options?: ModuleRunnerTransformOptions | undefined | null): ModuleRunnerTransformResult
 
+export interface ParametersOptions {
+  /**
+   * Do not preserve `Function#length`. Same as the `ignoreFunctionLength` assumption.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface PluginsOptions {
   styledComponents?: StyledComponentsOptions
   taggedTemplateEscape?: boolean
@@ -345,6 +463,25 @@ export interface ReactRefreshOptions {
   emitFullSignatures?: boolean
 }
 
+export interface RegeneratorOptions {
+  /**
+   * Insert the regenerator runtime into the file, instead of loading the
+   * `regeneratorRuntime` helper.
+   *
+   * @default false
+   */
+  inlineRuntime?: boolean
+}
+
+export interface SpreadOptions {
+  /**
+   * Assume all spread arguments are arrays. Same as the `iterableIsArray` assumption.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 /**
  * Configure how styled-components are transformed.
  *
@@ -427,6 +564,16 @@ export interface StyledComponentsOptions {
   topLevelImportPaths?: Array<string>
 }
 
+export interface TemplateLiteralsOptions {
+  /**
+   * Use `+` instead of `concat`, and do not freeze template objects. Same as enabling both
+   * the `ignoreToPrimitiveHint` and `mutableTemplateObject` assumptions.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 /**
  * Transpile a JavaScript or TypeScript into a target ECMAScript version, asynchronously.
  *
@@ -490,7 +637,7 @@ export interface TransformOptions {
   /**
    * Sets the target environment for the generated JavaScript.
    *
-   * The lowest target is `es2015`.
+   * The lowest target is `es5`.
    *
    * Example:
    *
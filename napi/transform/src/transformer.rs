@@ -128,7 +128,7 @@ pub struct TransformOptions {
 
     /// Sets the target environment for the generated JavaScript.
     ///
-    /// The lowest target is `es2015`.
+    /// The lowest target is `es5`.
     ///
     /// Example:
     ///
@@ -221,10 +221,15 @@ impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
 #[napi(object)]
 #[derive(Default, Debug)]
 pub struct CompilerAssumptions {
+    pub array_like_is_iterable: Option<bool>,
     pub ignore_function_length: Option<bool>,
+    pub ignore_to_primitive_hint: Option<bool>,
+    pub iterable_is_array: Option<bool>,
+    pub mutable_template_object: Option<bool>,
     pub no_document_all: Option<bool>,
     pub object_rest_no_symbols: Option<bool>,
     pub pure_getters: Option<bool>,
+    pub set_computed_properties: Option<bool>,
     /// When using public class fields, assume that they don't shadow any getter in the current class,
     /// in its subclasses or in its superclass. Thus, it's safe to assign them rather than using
     /// `Object.defineProperty`.
@@ -265,23 +270,40 @@ pub struct CompilerAssumptions {
     /// set both `set_public_class_fields` and [`crate::TypeScriptOptions::remove_class_fields_without_initializer`]
     /// to `true`.
     pub set_public_class_fields: Option<bool>,
+    pub skip_for_of_iterator_closing: Option<bool>,
 }
 
 impl From<CompilerAssumptions> for oxc::transformer::CompilerAssumptions {
     fn from(value: CompilerAssumptions) -> Self {
         let ops = oxc::transformer::CompilerAssumptions::default();
         Self {
+            array_like_is_iterable: value
+                .array_like_is_iterable
+                .unwrap_or(ops.array_like_is_iterable),
             ignore_function_length: value
                 .ignore_function_length
                 .unwrap_or(ops.ignore_function_length),
+            ignore_to_primitive_hint: value
+                .ignore_to_primitive_hint
+                .unwrap_or(ops.ignore_to_primitive_hint),
+            iterable_is_array: value.iterable_is_array.unwrap_or(ops.iterable_is_array),
+            mutable_template_object: value
+                .mutable_template_object
+                .unwrap_or(ops.mutable_template_object),
             no_document_all: value.no_document_all.unwrap_or(ops.no_document_all),
             object_rest_no_symbols: value
                 .object_rest_no_symbols
                 .unwrap_or(ops.object_rest_no_symbols),
             pure_getters: value.pure_getters.unwrap_or(ops.pure_getters),
+            set_computed_properties: value
+                .set_computed_properties
+                .unwrap_or(ops.set_computed_properties),
             set_public_class_fields: value
                 .set_public_class_fields
                 .unwrap_or(ops.set_public_class_fields),
+            skip_for_of_iterator_closing: value
+                .skip_for_of_iterator_closing
+                .unwrap_or(ops.skip_for_of_iterator_closing),
             ..ops
         }
     }
@@ -691,15 +713,220 @@ impl From<ArrowFunctionsOptions> for oxc::transformer::ArrowFunctionsOptions {
     }
 }
 
+#[napi(object)]
+pub struct BlockScopingOptions {
+    /// Throw when a `let` or `const` binding is accessed before its declaration.
+    ///
+    /// @default false
+    pub tdz: Option<bool>,
+
+    /// Report an error instead of moving a loop body into a function, when a closure captures
+    /// a binding declared in the loop.
+    ///
+    /// @default false
+    pub throw_if_closure_required: Option<bool>,
+}
+
+impl From<BlockScopingOptions> for oxc::transformer::BlockScopingOptions {
+    fn from(options: BlockScopingOptions) -> Self {
+        oxc::transformer::BlockScopingOptions {
+            tdz: options.tdz.unwrap_or_default(),
+            throw_if_closure_required: options.throw_if_closure_required.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct ParametersOptions {
+    /// Do not preserve `Function#length`. Same as the `ignoreFunctionLength` assumption.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ParametersOptions> for oxc::transformer::ParametersOptions {
+    fn from(options: ParametersOptions) -> Self {
+        oxc::transformer::ParametersOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct DestructuringOptions {
+    /// Assume all destructured values are arrays. Same as the `iterableIsArray` assumption.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<DestructuringOptions> for oxc::transformer::DestructuringOptions {
+    fn from(options: DestructuringOptions) -> Self {
+        oxc::transformer::DestructuringOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct SpreadOptions {
+    /// Assume all spread arguments are arrays. Same as the `iterableIsArray` assumption.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<SpreadOptions> for oxc::transformer::SpreadOptions {
+    fn from(options: SpreadOptions) -> Self {
+        oxc::transformer::SpreadOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct TemplateLiteralsOptions {
+    /// Use `+` instead of `concat`, and do not freeze template objects. Same as enabling both
+    /// the `ignoreToPrimitiveHint` and `mutableTemplateObject` assumptions.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<TemplateLiteralsOptions> for oxc::transformer::TemplateLiteralsOptions {
+    fn from(options: TemplateLiteralsOptions) -> Self {
+        oxc::transformer::TemplateLiteralsOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct ComputedPropertiesOptions {
+    /// Assign computed properties instead of defining them. Same as the
+    /// `setComputedProperties` assumption.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ComputedPropertiesOptions> for oxc::transformer::ComputedPropertiesOptions {
+    fn from(options: ComputedPropertiesOptions) -> Self {
+        oxc::transformer::ComputedPropertiesOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct ForOfOptions {
+    /// Do not close iterators when the loop exits early. Same as the
+    /// `skipForOfIteratorClosing` assumption.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+
+    /// Assume all iterated values are arrays. Same as the `iterableIsArray` assumption.
+    ///
+    /// @default false
+    pub assume_array: Option<bool>,
+
+    /// Also iterate array-like objects. Same as the `arrayLikeIsIterable` assumption.
+    ///
+    /// @default false
+    pub allow_array_like: Option<bool>,
+}
+
+impl From<ForOfOptions> for oxc::transformer::ForOfOptions {
+    fn from(options: ForOfOptions) -> Self {
+        oxc::transformer::ForOfOptions {
+            loose: options.loose.unwrap_or_default(),
+            assume_array: options.assume_array.unwrap_or_default(),
+            allow_array_like: options.allow_array_like.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct RegeneratorOptions {
+    /// Insert the regenerator runtime into the file, instead of loading the
+    /// `regeneratorRuntime` helper.
+    ///
+    /// @default false
+    pub inline_runtime: Option<bool>,
+}
+
+impl From<RegeneratorOptions> for oxc::transformer::RegeneratorOptions {
+    fn from(options: RegeneratorOptions) -> Self {
+        oxc::transformer::RegeneratorOptions {
+            inline_runtime: options.inline_runtime.unwrap_or_default(),
+        }
+    }
+}
+
 #[napi(object)]
 pub struct Es2015Options {
     /// Transform arrow functions into function expressions.
     pub arrow_function: Option<ArrowFunctionsOptions>,
+
+    /// Transform `let` and `const` declarations into `var`.
+    pub block_scoping: Option<BlockScopingOptions>,
+
+    /// Transform classes into constructor functions.
+    ///
+    /// @default false
+    pub classes: Option<bool>,
+
+    /// Transform default, destructured and rest parameters.
+    pub parameters: Option<ParametersOptions>,
+
+    /// Transform array and object destructuring.
+    pub destructuring: Option<DestructuringOptions>,
+
+    /// Transform spread elements in arrays, calls and `new` expressions.
+    pub spread: Option<SpreadOptions>,
+
+    /// Transform template literals and tagged templates.
+    pub template_literals: Option<TemplateLiteralsOptions>,
+
+    /// Transform shorthand properties and methods in object literals.
+    ///
+    /// @default false
+    pub shorthand_properties: Option<bool>,
+
+    /// Transform computed properties in object literals.
+    pub computed_properties: Option<ComputedPropertiesOptions>,
+
+    /// Transform `for...of` loops.
+    pub for_of: Option<ForOfOptions>,
+
+    /// Name anonymous function expressions after the binding they are assigned to.
+    ///
+    /// @default false
+    pub function_name: Option<bool>,
+
+    /// Make duplicate keys in object literals computed.
+    ///
+    /// @default false
+    pub duplicate_keys: Option<bool>,
+
+    /// Transform `new.target`.
+    ///
+    /// @default false
+    pub new_target: Option<bool>,
+
+    /// Compile generator functions into state machines run by the regenerator runtime.
+    pub regenerator: Option<RegeneratorOptions>,
 }
 
 impl From<Es2015Options> for oxc::transformer::ES2015Options {
     fn from(options: Es2015Options) -> Self {
-        oxc::transformer::ES2015Options { arrow_function: options.arrow_function.map(Into::into) }
+        oxc::transformer::ES2015Options {
+            arrow_function: options.arrow_function.map(Into::into),
+            block_scoping: options.block_scoping.map(Into::into),
+            classes: options.classes.unwrap_or_default(),
+            parameters: options.parameters.map(Into::into),
+            destructuring: options.destructuring.map(Into::into),
+            spread: options.spread.map(Into::into),
+            template_literals: options.template_literals.map(Into::into),
+            shorthand_properties: options.shorthand_properties.unwrap_or_default(),
+            computed_properties: options.computed_properties.map(Into::into),
+            for_of: options.for_of.map(Into::into),
+            function_name: options.function_name.unwrap_or_default(),
+            duplicate_keys: options.duplicate_keys.unwrap_or_default(),
+            new_target: options.new_target.unwrap_or_default(),
+            regenerator: options.regenerator.map(Into::into),
+        }
     }
 }
 
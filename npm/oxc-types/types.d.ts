@@ -50,6 +50,9 @@ export type Expression =
   | TSNonNullExpression
   | TSInstantiationExpression
   | V8IntrinsicExpression
+  | FlowTypeCastExpression
+  | PipelineExpression
+  | TopicReference
   | MemberExpression;
 
 export interface IdentifierName extends Span {
@@ -107,7 +110,7 @@ export interface ObjectExpression extends Span {
   parent?: Node;
 }
 
-export type ObjectPropertyKind = ObjectProperty | SpreadElement;
+export type ObjectPropertyKind = ObjectProperty | SpreadElement | ErrorNode;
 
 export interface ObjectProperty extends Span {
   type: "Property";
@@ -402,6 +405,7 @@ export type Statement =
   | TryStatement
   | WhileStatement
   | WithStatement
+  | ErrorNode
   | Declaration
   | ModuleDeclaration;
 
@@ -433,7 +437,8 @@ export type Declaration =
   | TSEnumDeclaration
   | TSModuleDeclaration
   | TSGlobalDeclaration
-  | TSImportEqualsDeclaration;
+  | TSImportEqualsDeclaration
+  | FlowOpaqueTypeDeclaration;
 
 export interface VariableDeclaration extends Span {
   type: "VariableDeclaration";
@@ -741,7 +746,8 @@ export type ClassElement =
   | MethodDefinition
   | PropertyDefinition
   | AccessorProperty
-  | TSIndexSignature;
+  | TSIndexSignature
+  | ErrorNode;
 
 export interface MethodDefinition extends Span {
   type: MethodDefinitionType;
@@ -918,6 +924,23 @@ export interface V8IntrinsicExpression extends Span {
   parent?: Node;
 }
 
+export interface PipelineExpression extends Span {
+  type: "PipelineExpression";
+  head: Expression;
+  body: Expression;
+  parent?: Node;
+}
+
+export interface TopicReference extends Span {
+  type: "TopicReference";
+  parent?: Node;
+}
+
+export interface ErrorNode extends Span {
+  type: "ErrorNode";
+  parent?: Node;
+}
+
 export interface BooleanLiteral extends Span {
   type: "Literal";
   value: boolean;
@@ -1649,7 +1672,7 @@ export interface TSInstantiationExpression extends Span {
   parent?: Node;
 }
 
-export type ImportOrExportKind = "value" | "type";
+export type ImportOrExportKind = "value" | "type" | "typeof";
 
 export interface JSDocNullableType extends Span {
   type: "TSJSDocNullableType";
@@ -1670,6 +1693,23 @@ export interface JSDocUnknownType extends Span {
   parent?: Node;
 }
 
+export interface FlowTypeCastExpression extends Span {
+  type: "FlowTypeCastExpression";
+  expression: Expression;
+  typeAnnotation: TSTypeAnnotation;
+  parent?: Node;
+}
+
+export interface FlowOpaqueTypeDeclaration extends Span {
+  type: "FlowOpaqueTypeDeclaration";
+  id: BindingIdentifier;
+  typeParameters: TSTypeParameterDeclaration | null;
+  supertype: TSType | null;
+  impltype: TSType | null;
+  declare: boolean;
+  parent?: Node;
+}
+
 export type ModuleKind = "script" | "module" | "commonjs";
 
 export interface Span {
@@ -1815,6 +1855,9 @@ export type Node =
   | ExportAllDeclaration
   | ExportSpecifier
   | V8IntrinsicExpression
+  | PipelineExpression
+  | TopicReference
+  | ErrorNode
   | BooleanLiteral
   | NullLiteral
   | NumericLiteral
@@ -1909,4 +1952,6 @@ export type Node =
   | JSDocNullableType
   | JSDocNonNullableType
   | JSDocUnknownType
+  | FlowTypeCastExpression
+  | FlowOpaqueTypeDeclaration
   | ParamPattern;
@@ -21,7 +21,7 @@ const AST_FILES_PREFIX: &str = "crates/oxc_ast/src/";
 ///
 /// Paths are relative to [`AST_FILES_PREFIX`].
 const AST_PRIORITY_PATHS: &[&str] =
-    &["ast/js.rs", "ast/literal.rs", "ast/jsx.rs", "ast/ts.rs", "ast/comment.rs"];
+    &["ast/js.rs", "ast/literal.rs", "ast/jsx.rs", "ast/ts.rs", "ast/flow.rs", "ast/comment.rs"];
 
 /// Find all crates and source files containing AST types.
 ///
@@ -162,6 +162,7 @@ fn generate(codegen: &Codegen) -> Codes {
         ("TSJSDocNonNullableType", &["typeAnnotation"]),
         ("TSJSDocNullableType", &["typeAnnotation"]),
         ("TSJSDocUnknownType", &[]),
+        ("ErrorNode", &[]),
     ];
     nodes.extend(extra.iter().map(|&(name, keys)| NodeKeys {
         name: name.to_string(),
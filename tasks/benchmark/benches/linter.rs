@@ -33,8 +33,7 @@ fn bench_linter(criterion: &mut Criterion) {
                 let path = Path::new("");
                 let semantic_ret = SemanticBuilder::new_linter().build(&parser_ret.program);
                 let semantic = semantic_ret.semantic;
-                let module_record =
-                    Arc::new(ModuleRecord::new(path, &parser_ret.module_record, &semantic));
+                let module_record = Arc::new(ModuleRecord::new(path, &parser_ret.module_record));
                 let mut external_plugin_store = ExternalPluginStore::default();
                 let lint_config =
                     ConfigStoreBuilder::all().build(&mut external_plugin_store).unwrap();
@@ -0,0 +1,10 @@
+abstract class A {
+  abstract get a(): number;
+  set a(v: number) {}
+
+  protected get b() { return 1; }
+  set b(v: number) {}
+
+  private get c() { return 1; }
+  protected set c(v: number) {}
+}
@@ -0,0 +1,6 @@
+declare const a: number;
+export const b: number;
+interface I {}
+type T = 1;
+function f(): void;
+class C {}
@@ -0,0 +1,7 @@
+enum E {
+  A = B,
+  B = 1,
+  C = E.D + 1,
+  D = (E["F"] << 1) | 2,
+  F = 4,
+}
@@ -0,0 +1,15 @@
+const a = {
+  get x() { return 1; },
+  get x() { return 2; },
+};
+
+const b = {
+  x: 1,
+  get x() { return 2; },
+};
+
+const c = {
+  get x() { return 1; },
+  set x(v) {},
+  set x(v) {},
+};
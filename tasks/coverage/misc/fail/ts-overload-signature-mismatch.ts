@@ -0,0 +1,29 @@
+abstract class A {
+  foo(): void;
+  bar() {}
+
+  private baz(): void;
+  baz(x?: any) {}
+
+  static qux(): void;
+  qux() {}
+
+  abstract quux(): void;
+  quux() {}
+
+  corge?(): void;
+  corge() {}
+}
+
+function f(): void;
+function g() {}
+
+export function h(): void;
+function h() {}
+
+declare function i(): void;
+function i() {}
+
+function j(): void;
+const x = 1;
+function j() {}
@@ -0,0 +1,17 @@
+function f(x = 1): void;
+function f(x: number) {}
+
+declare function g(x = 1): void;
+
+interface I {
+  (x = 1): void;
+  new (x = 1): I;
+  m(x = 1): void;
+}
+
+type T = (x = 1) => void;
+
+class C {
+  constructor(x = 1);
+  constructor(x: number) {}
+}
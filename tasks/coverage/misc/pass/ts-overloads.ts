@@ -0,0 +1,43 @@
+class A {
+  foo(): void;
+  foo(x?: number): void;
+  foo() {}
+
+  get p() { return 1; }
+  set p(v) {}
+
+  private get q() { return 1; }
+  private set q(v) {}
+
+  constructor();
+  constructor(a?: number) {}
+}
+
+function f(): void;
+function f(x: number): void;
+function f(x?: number) {}
+
+export function g(): void;
+export function g() {}
+
+export default function h(): void;
+export default function h() {}
+
+declare function i(x: number): void;
+declare function i(): void;
+
+function outer() {
+  function inner(): void;
+  function inner() {}
+  {
+    function block(a: string): void;
+    function block() {}
+  }
+}
+
+const o = { get a() { return 1; }, set a(v) {}, b: 1, b: 2, c() {}, c: 3 };
+
+enum E { A = 1, B = A, C = E.B | E.A, D = `${C}` }
+
+type T = (a?: number) => void;
+const arrow = (a = 1) => a;
@@ -476,6 +476,9 @@ impl AppArgs {
             &data.typescript,
             typescript::error_baseline::run_errors_typescript,
         );
+        if self.filter.is_none() {
+            typescript::error_baseline::save_error_code_mapping(&data.typescript);
+        }
         self.run_tool(
             "types_typescript",
             TYPESCRIPT_PATH,
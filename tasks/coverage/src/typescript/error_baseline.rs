@@ -14,14 +14,20 @@
 //! baseline: no false positives, and every baseline position whose TS code oxc can emit is
 //! found. Type / binder errors (`TS2xxx`, `TS18xxx`) oxc can never produce are the
 //! type-checker surface and are excluded from the pass criterion.
+//!
+//! [`save_error_code_mapping`] also snapshots which baseline codes each oxc code (including the
+//! semantic checker's) lines up with, so a wrong or missing `TSxxxx` shows up in review.
+
+use std::{collections::BTreeMap, fmt::Write};
 
 use lazy_regex::{Lazy, Regex, lazy_regex};
-use oxc::{allocator::Allocator, parser::Parser};
+use oxc::{allocator::Allocator, parser::Parser, semantic::SemanticBuilder};
+use oxc_tasks_common::Snapshot;
 use rayon::prelude::*;
 use rustc_hash::FxHashSet;
 
 use super::{meta::TestCaseContent, scanner};
-use crate::{CoverageResult, TestResult, TypeScriptFile};
+use crate::{CoverageResult, TestResult, TypeScriptFile, snap_root, workspace_root};
 
 /// A 1-based error location (matching TypeScript's `.errors.txt` summary), plus the TS code.
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -144,3 +150,124 @@ pub fn run_errors_typescript(files: &[TypeScriptFile]) -> Vec<CoverageResult> {
         })
         .collect()
 }
+
+/// How the TS codes oxc attaches to one kind of diagnostic line up with the baselines.
+#[derive(Default)]
+struct CodeMapping {
+    /// oxc's code agrees with a baseline error at the same position.
+    agree: usize,
+    /// Codes of baseline errors at the same position when oxc's code disagrees with all of them.
+    conflicts: BTreeMap<String, usize>,
+}
+
+/// Save the table of TS codes which oxc's parser and semantic checker diagnostics carry, checked
+/// against the errors TypeScript reports at the same positions in the conformance baselines.
+///
+/// Each row is an oxc code (or an untagged message) with the number of diagnostics whose code
+/// agrees with the baseline, and the baseline codes it conflicts with. Untagged rows are syntax
+/// errors which should be tagged with one of the listed codes.
+pub fn save_error_code_mapping(files: &[TypeScriptFile]) {
+    let diagnostics = files
+        .par_iter()
+        .filter_map(|f| {
+            let texts = TestCaseContent::get_error_files(&f.path, &f.settings);
+            if texts.is_empty() {
+                return None;
+            }
+            let baseline = parse_baseline_positions(&texts);
+            let ignore_file = f.units.len() <= 1;
+            let diagnostics = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                f.units
+                    .iter()
+                    .flat_map(|u| oxc_checked_positions(&u.name, &u.content, u.source_type))
+                    .collect::<Vec<_>>()
+            }))
+            .ok()?;
+            let matched = diagnostics
+                .into_iter()
+                .filter_map(|(o, message)| {
+                    let at_position: Vec<&Pos> = baseline
+                        .iter()
+                        .filter(|b| pos_matches(&Pos { code: None, ..o.clone() }, b, ignore_file))
+                        .collect();
+                    if at_position.is_empty() {
+                        return None;
+                    }
+                    let agrees = o.code.is_some() && at_position.iter().any(|b| b.code == o.code);
+                    let conflicts = if agrees {
+                        vec![]
+                    } else {
+                        at_position.iter().filter_map(|b| b.code.clone()).collect()
+                    };
+                    let row = o.code.map_or_else(|| format!("- {message}"), |c| format!("TS{c}"));
+                    Some((row, conflicts))
+                })
+                .collect::<Vec<_>>();
+            Some(matched)
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+
+    let mut table: BTreeMap<String, CodeMapping> = BTreeMap::new();
+    for (row, conflicts) in diagnostics {
+        let mapping = table.entry(row).or_default();
+        if conflicts.is_empty() {
+            mapping.agree += 1;
+        }
+        for code in conflicts {
+            *mapping.conflicts.entry(code).or_default() += 1;
+        }
+    }
+
+    let tagged = table.keys().filter(|row| row.starts_with("TS")).count();
+    let mut out = String::new();
+    writeln!(out, "error_codes_typescript Summary:").unwrap();
+    writeln!(out, "Tagged: {tagged}/{}", table.len()).unwrap();
+    writeln!(out).unwrap();
+    for (row, mapping) in &table {
+        write!(out, "{row} | agree: {}", mapping.agree).unwrap();
+        if !mapping.conflicts.is_empty() {
+            let conflicts = mapping
+                .conflicts
+                .iter()
+                .map(|(code, count)| format!("TS{code} ({count})"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(out, " | conflicts: {conflicts}").unwrap();
+        }
+        writeln!(out).unwrap();
+    }
+
+    let snapshot = Snapshot::new(&workspace_root().join("typescript/tests/cases"), true);
+    snapshot.save(&snap_root().join("error_codes_typescript.snap"), &out);
+}
+
+/// oxc's diagnostic positions with their messages, including the semantic checker's syntax errors.
+fn oxc_checked_positions(
+    unit_name: &str,
+    content: &str,
+    source_type: oxc::span::SourceType,
+) -> Vec<(Pos, String)> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, content, source_type).parse();
+    let mut diagnostics = ret.diagnostics;
+    if !ret.panicked {
+        let semantic = SemanticBuilder::new().with_check_syntax_error(true).build(&ret.program);
+        diagnostics.extend(semantic.diagnostics);
+    }
+    let line_starts = scanner::compute_line_starts(content);
+    diagnostics
+        .iter()
+        .filter_map(|d| {
+            let offset = d.labels.first()?.offset();
+            let (line, col) = scanner::line_and_character(content, &line_starts, offset);
+            let code = if d.code.scope.as_deref() == Some("TS") {
+                d.code.number.as_deref().map(str::to_string)
+            } else {
+                None
+            };
+            let pos = Pos { file: unit_name.to_string(), line: line + 1, col: col + 1, code };
+            Some((pos, d.message.to_string()))
+        })
+        .collect()
+}
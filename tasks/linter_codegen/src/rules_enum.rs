@@ -246,6 +246,14 @@ fn generate_rule_enum_impl(rule_entries: &[RuleEntry<'_>]) -> TokenStream {
         })
         .collect();
 
+    let is_native_type_aware_arms: Vec<TokenStream> = rule_entries
+        .iter()
+        .map(|rule| {
+            let enum_name = make_enum_ident(rule);
+            quote! { Self::#enum_name(rule) => rule.is_native_type_aware() }
+        })
+        .collect();
+
     let is_tsgolint_rule_arms: Vec<TokenStream> = rule_entries
         .iter()
         .map(|rule| {
@@ -437,6 +445,12 @@ fn generate_rule_enum_impl(rule_entries: &[RuleEntry<'_>]) -> TokenStream {
                 }
             }
 
+            pub fn is_native_type_aware(&self) -> bool {
+                match self {
+                    #(#is_native_type_aware_arms),*
+                }
+            }
+
             /// The version of oxlint in which this rule was first available.
             #[cfg(feature = "ruledocs")]
             pub fn version(&self) -> &'static str {
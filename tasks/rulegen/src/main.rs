@@ -365,7 +365,7 @@ impl<'a> Visit<'a> for TestCase {
                     }
                     _ => {}
                 },
-                ObjectPropertyKind::SpreadProperty(_) => {}
+                ObjectPropertyKind::SpreadProperty(_) | ObjectPropertyKind::ErrorNode(_) => {}
             }
         }
     }
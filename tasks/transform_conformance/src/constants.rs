@@ -36,14 +36,22 @@ pub const PLUGINS: &[&str] = &[
     "babel-plugin-transform-exponentiation-operator",
     // ES2015
     "babel-plugin-transform-arrow-functions",
-    // "babel-plugin-transform-function-name",
-    // "babel-plugin-transform-shorthand-properties",
+    "babel-plugin-transform-block-scoping",
+    "babel-plugin-transform-classes",
+    "babel-plugin-transform-parameters",
+    "babel-plugin-transform-destructuring",
+    "babel-plugin-transform-spread",
+    "babel-plugin-transform-template-literals",
+    "babel-plugin-transform-shorthand-properties",
+    "babel-plugin-transform-computed-properties",
+    "babel-plugin-transform-for-of",
+    "babel-plugin-transform-function-name",
+    "babel-plugin-transform-duplicate-keys",
+    "babel-plugin-transform-new-target",
+    "babel-plugin-transform-regenerator",
     // "babel-plugin-transform-sticky-regex",
     // "babel-plugin-transform-unicode-regex",
-    // "babel-plugin-transform-template-literals",
-    // "babel-plugin-transform-duplicate-keys",
     // "babel-plugin-transform-instanceof",
-    // "babel-plugin-transform-new-target",
     // // ES3
     // "babel-plugin-transform-property-literals",
     // TypeScript
@@ -69,10 +77,7 @@ pub const PLUGINS: &[&str] = &[
 
 pub const PLUGINS_NOT_SUPPORTED_YET: &[&str] = &[
     "proposal-decorators",
-    "transform-classes",
-    "transform-destructuring",
     "transform-modules-commonjs",
-    "transform-parameters",
     "transform-property-literals",
     "transform-react-constant-elements",
 ];
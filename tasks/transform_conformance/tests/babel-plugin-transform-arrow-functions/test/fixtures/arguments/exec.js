@@ -0,0 +1,9 @@
+function f() {
+  return (() => arguments[0])();
+}
+expect(f(42)).toBe(42);
+
+function g() {
+  return () => () => arguments.length;
+}
+expect(g(1, 2, 3)()()).toBe(3);
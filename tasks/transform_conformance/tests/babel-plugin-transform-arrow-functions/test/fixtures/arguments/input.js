@@ -0,0 +1,7 @@
+function f() {
+  return (() => arguments[0])();
+}
+
+function g() {
+  return () => () => arguments.length;
+}
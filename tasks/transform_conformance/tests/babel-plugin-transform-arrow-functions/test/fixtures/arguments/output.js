@@ -0,0 +1,14 @@
+function f() {
+  var _arguments = arguments;
+  return function() {
+    return _arguments[0];
+  }();
+}
+function g() {
+  var _arguments2 = arguments;
+  return function() {
+    return function() {
+      return _arguments2.length;
+    };
+  };
+}
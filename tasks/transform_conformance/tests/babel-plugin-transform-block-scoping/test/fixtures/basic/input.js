@@ -0,0 +1,18 @@
+let a = 1;
+{
+  let a = 2;
+  const b = a;
+  console.log(a, b);
+}
+function f() {
+  let x;
+  if (x) {
+    let x = 1;
+    return x;
+  }
+  return x;
+}
+while (a) {
+  let c;
+  a = c;
+}
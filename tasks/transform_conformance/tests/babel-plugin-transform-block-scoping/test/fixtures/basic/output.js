@@ -0,0 +1,18 @@
+var a = 1;
+{
+  var _a = 2;
+  var b = _a;
+  console.log(_a, b);
+}
+function f() {
+  var x;
+  if (x) {
+    var _x = 1;
+    return _x;
+  }
+  return x;
+}
+while (a) {
+  var c = void 0;
+  a = c;
+}
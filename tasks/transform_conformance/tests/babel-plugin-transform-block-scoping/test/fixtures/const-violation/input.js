@@ -0,0 +1,6 @@
+const a = 1;
+a = 2;
+a += 3;
+a ||= 4;
+a++;
+for (a of []) {}
@@ -0,0 +1,8 @@
+var a = 1;
+2, babelHelpers.readOnlyError("a");
+a + 3, babelHelpers.readOnlyError("a");
+a || (4, babelHelpers.readOnlyError("a"));
++a, babelHelpers.readOnlyError("a");
+for (var _a of []) {
+  babelHelpers.readOnlyError("a");
+}
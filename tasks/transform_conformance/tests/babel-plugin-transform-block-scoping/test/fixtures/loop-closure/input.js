@@ -0,0 +1,15 @@
+const fns = [];
+for (let i = 0; i < 3; i++) {
+  fns.push(() => i);
+}
+for (const x of [1, 2]) {
+  let y = x * 2;
+  fns.push(function () {
+    return y;
+  });
+}
+while (fns.length < 10) {
+  let z = fns.length;
+  fns.push(() => z);
+}
+for (let i = 0; i < 3; i++) fns.push(() => i);
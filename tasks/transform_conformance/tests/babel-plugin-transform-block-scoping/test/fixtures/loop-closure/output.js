@@ -0,0 +1,29 @@
+var fns = [];
+var _loop = function (i) {
+  fns.push(() => i);
+};
+for (var i = 0; i < 3; i++) {
+  _loop(i);
+}
+var _loop2 = function (x) {
+  var y = x * 2;
+  fns.push(function () {
+    return y;
+  });
+};
+for (var x of [1, 2]) {
+  _loop2(x);
+}
+var _loop3 = function () {
+  var z = fns.length;
+  fns.push(() => z);
+};
+while (fns.length < 10) {
+  _loop3();
+}
+var _loop4 = function (i) {
+  fns.push(() => i);
+};
+for (var _i = 0; _i < 3; _i++) {
+  _loop4(_i);
+}
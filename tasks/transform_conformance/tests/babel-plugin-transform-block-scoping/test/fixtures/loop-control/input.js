@@ -0,0 +1,15 @@
+function f(items) {
+  outer: for (const item of items) {
+    for (let i = 0; i < item.length; i++) {
+      if (i === 0) continue;
+      if (item[i] === null) break;
+      if (item[i] === undefined) continue outer;
+      if (item[i] === false) break outer;
+      if (item[i] === true) return item;
+      setTimeout(() => console.log(this, arguments, i));
+      i += 1;
+      var last = i;
+    }
+  }
+  return last;
+}
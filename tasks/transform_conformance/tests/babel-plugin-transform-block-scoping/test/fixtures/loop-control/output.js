@@ -0,0 +1,26 @@
+function f(items) {
+  outer: for (var item of items) {
+    var _this = this, _arguments = arguments, last, _loop = function (_i) {
+      if (_i === 0) {
+        i = _i;
+        return;
+      }
+      if (item[_i] === null) return 1;
+      if (item[_i] === undefined) return "continue|outer";
+      if (item[_i] === false) return "break|outer";
+      if (item[_i] === true) return { v: item };
+      setTimeout(() => console.log(_this, _arguments, _i));
+      _i += 1;
+      last = _i;
+      i = _i;
+    }, _ret;
+    for (var i = 0; i < item.length; i++) {
+      _ret = _loop(i);
+      if (_ret === 1) break;
+      if (_ret === "continue|outer") continue outer;
+      if (_ret === "break|outer") break outer;
+      if (_ret) return _ret.v;
+    }
+  }
+  return last;
+}
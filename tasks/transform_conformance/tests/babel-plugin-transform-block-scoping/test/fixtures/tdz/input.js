@@ -0,0 +1,9 @@
+x;
+let x = 1;
+function f() {
+  return y;
+}
+f();
+let y = 2;
+z = 3;
+let z;
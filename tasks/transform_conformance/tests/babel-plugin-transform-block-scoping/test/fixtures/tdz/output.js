@@ -0,0 +1,10 @@
+y = babelHelpers.temporalUndefined;
+babelHelpers.tdz("x");
+var x = 1;
+function f() {
+  return babelHelpers.temporalRef(y, "y");
+}
+f();
+var y = 2;
+3, babelHelpers.tdz("z");
+var z = void 0;
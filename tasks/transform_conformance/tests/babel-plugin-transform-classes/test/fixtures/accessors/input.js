@@ -0,0 +1,13 @@
+class A {
+  get x() {
+    return this._x;
+  }
+  set x(value) {
+    this._x = value;
+  }
+  static get y() {
+    return 1;
+  }
+  ["computed"]() {}
+  [key]() {}
+}
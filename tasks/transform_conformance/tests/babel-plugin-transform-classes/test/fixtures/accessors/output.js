@@ -0,0 +1,30 @@
+let A = /* @__PURE__ */ function() {
+  "use strict";
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+  }
+  return babelHelpers.createClass(A, [
+    {
+      key: "x",
+      get: function get() {
+        return this._x;
+      },
+      set: function set(value) {
+        this._x = value;
+      }
+    },
+    {
+      key: "computed",
+      value: function() {}
+    },
+    {
+      key,
+      value: function() {}
+    }
+  ], [{
+    key: "y",
+    get: function get() {
+      return 1;
+    }
+  }]);
+}();
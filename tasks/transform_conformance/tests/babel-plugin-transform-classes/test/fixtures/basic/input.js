@@ -0,0 +1,19 @@
+class A {}
+
+class B {
+  constructor(x) {
+    this.x = x;
+  }
+}
+
+class C {
+  constructor() {
+    this.c = C;
+  }
+  method() {
+    return C;
+  }
+  static create() {
+    return new C();
+  }
+}
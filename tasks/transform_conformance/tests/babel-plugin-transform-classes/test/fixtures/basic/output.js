@@ -0,0 +1,27 @@
+let A = /* @__PURE__ */ babelHelpers.createClass(function A() {
+  "use strict";
+  babelHelpers.classCallCheck(this, A);
+});
+let B = /* @__PURE__ */ babelHelpers.createClass(function B(x) {
+  "use strict";
+  babelHelpers.classCallCheck(this, B);
+  this.x = x;
+});
+let C = /* @__PURE__ */ function() {
+  "use strict";
+  function C() {
+    babelHelpers.classCallCheck(this, C);
+    this.c = C;
+  }
+  return babelHelpers.createClass(C, [{
+    key: "method",
+    value: function method() {
+      return C;
+    }
+  }], [{
+    key: "create",
+    value: function create() {
+      return new C();
+    }
+  }]);
+}();
@@ -0,0 +1,9 @@
+class MyError extends Error {}
+
+class MyArray extends Array {
+  first() {
+    return this[0];
+  }
+}
+
+class MyElement extends HTMLElement {}
@@ -0,0 +1,32 @@
+let MyError = /* @__PURE__ */ function(_Error) {
+  "use strict";
+  function MyError() {
+    babelHelpers.classCallCheck(this, MyError);
+    return babelHelpers.callSuper(this, MyError, arguments);
+  }
+  babelHelpers.inherits(MyError, _Error);
+  return babelHelpers.createClass(MyError);
+}(babelHelpers.wrapNativeSuper(Error));
+let MyArray = /* @__PURE__ */ function(_Array) {
+  "use strict";
+  function MyArray() {
+    babelHelpers.classCallCheck(this, MyArray);
+    return babelHelpers.callSuper(this, MyArray, arguments);
+  }
+  babelHelpers.inherits(MyArray, _Array);
+  return babelHelpers.createClass(MyArray, [{
+    key: "first",
+    value: function first() {
+      return this[0];
+    }
+  }]);
+}(babelHelpers.wrapNativeSuper(Array));
+let MyElement = /* @__PURE__ */ function(_HTMLElement) {
+  "use strict";
+  function MyElement() {
+    babelHelpers.classCallCheck(this, MyElement);
+    return babelHelpers.callSuper(this, MyElement, arguments);
+  }
+  babelHelpers.inherits(MyElement, _HTMLElement);
+  return babelHelpers.createClass(MyElement);
+}(babelHelpers.wrapNativeSuper(HTMLElement));
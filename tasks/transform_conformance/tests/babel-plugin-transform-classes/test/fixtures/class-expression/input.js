@@ -0,0 +1,15 @@
+var A = class {};
+
+var B = class B {
+  method() {
+    return B;
+  }
+};
+
+var C = class {
+  method() {
+    return C;
+  }
+};
+
+foo(class extends D {});
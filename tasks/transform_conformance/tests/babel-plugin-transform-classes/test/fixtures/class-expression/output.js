@@ -0,0 +1,37 @@
+var A = /* @__PURE__ */ babelHelpers.createClass(function A() {
+  "use strict";
+  babelHelpers.classCallCheck(this, A);
+});
+var B = /* @__PURE__ */ function() {
+  "use strict";
+  function B() {
+    babelHelpers.classCallCheck(this, B);
+  }
+  return babelHelpers.createClass(B, [{
+    key: "method",
+    value: function method() {
+      return B;
+    }
+  }]);
+}();
+var C = /* @__PURE__ */ function() {
+  "use strict";
+  function _class() {
+    babelHelpers.classCallCheck(this, _class);
+  }
+  return babelHelpers.createClass(_class, [{
+    key: "method",
+    value: function method() {
+      return C;
+    }
+  }]);
+}();
+foo(/* @__PURE__ */ function(_D) {
+  "use strict";
+  function _class2() {
+    babelHelpers.classCallCheck(this, _class2);
+    return babelHelpers.callSuper(this, _class2, arguments);
+  }
+  babelHelpers.inherits(_class2, _D);
+  return babelHelpers.createClass(_class2);
+}(D));
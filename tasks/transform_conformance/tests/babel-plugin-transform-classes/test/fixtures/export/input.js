@@ -0,0 +1,5 @@
+export class A {}
+
+export default class B {
+  method() {}
+}
@@ -0,0 +1,15 @@
+export let A = /* @__PURE__ */ babelHelpers.createClass(function A() {
+  "use strict";
+  babelHelpers.classCallCheck(this, A);
+});
+let B = /* @__PURE__ */ function() {
+  "use strict";
+  function B() {
+    babelHelpers.classCallCheck(this, B);
+  }
+  return babelHelpers.createClass(B, [{
+    key: "method",
+    value: function method() {}
+  }]);
+}();
+export { B as default };
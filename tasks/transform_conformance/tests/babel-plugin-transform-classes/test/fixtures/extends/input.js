@@ -0,0 +1,25 @@
+class A extends B {}
+
+class C extends D {
+  constructor(x) {
+    super(x);
+    this.x = x;
+  }
+}
+
+class E extends F {
+  constructor() {
+    if (cond) {
+      super(1);
+    } else {
+      super(2);
+    }
+    return {};
+  }
+}
+
+class G extends H {
+  constructor(...args) {
+    super(...args);
+  }
+}
@@ -0,0 +1,45 @@
+let A = /* @__PURE__ */ function(_B) {
+  "use strict";
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+    return babelHelpers.callSuper(this, A, arguments);
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A);
+}(B);
+let C = /* @__PURE__ */ function(_D) {
+  "use strict";
+  function C(x) {
+    var _this;
+    babelHelpers.classCallCheck(this, C);
+    _this = babelHelpers.callSuper(this, C, [x]);
+    _this.x = x;
+    return _this;
+  }
+  babelHelpers.inherits(C, _D);
+  return babelHelpers.createClass(C);
+}(D);
+let E = /* @__PURE__ */ function(_F) {
+  "use strict";
+  function E() {
+    var _this2;
+    babelHelpers.classCallCheck(this, E);
+    if (cond) {
+      _this2 = babelHelpers.callSuper(this, E, [1]);
+    } else {
+      _this2 = babelHelpers.callSuper(this, E, [2]);
+    }
+    return babelHelpers.possibleConstructorReturn(_this2, {});
+  }
+  babelHelpers.inherits(E, _F);
+  return babelHelpers.createClass(E);
+}(F);
+let G = /* @__PURE__ */ function(_H) {
+  "use strict";
+  function G(...args) {
+    babelHelpers.classCallCheck(this, G);
+    return babelHelpers.callSuper(this, G, [...args]);
+  }
+  babelHelpers.inherits(G, _H);
+  return babelHelpers.createClass(G);
+}(H);
@@ -0,0 +1,25 @@
+let A = /* @__PURE__ */ function() {
+  "use strict";
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+    this.target = this.constructor;
+  }
+  return babelHelpers.createClass(A, [{
+    key: "method",
+    value: function method() {
+      return void 0;
+    }
+  }]);
+}();
+let B = /* @__PURE__ */ function(_A) {
+  "use strict";
+  function B() {
+    var _this;
+    babelHelpers.classCallCheck(this, B);
+    _this = babelHelpers.callSuper(this, B);
+    _this.derivedTarget = this.constructor;
+    return _this;
+  }
+  babelHelpers.inherits(B, _A);
+  return babelHelpers.createClass(B);
+}(A);
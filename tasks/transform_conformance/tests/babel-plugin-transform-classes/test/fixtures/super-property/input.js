@@ -0,0 +1,15 @@
+class A extends B {
+  constructor() {
+    super();
+    super.init();
+  }
+  method() {
+    super.method(1, 2);
+    super.x = 1;
+    super.y += 2;
+    return super[key];
+  }
+  static create() {
+    return super.create();
+  }
+}
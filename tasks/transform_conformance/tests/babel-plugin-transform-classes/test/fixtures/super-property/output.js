@@ -0,0 +1,25 @@
+let A = /* @__PURE__ */ function(_B) {
+  "use strict";
+  function A() {
+    var _this;
+    babelHelpers.classCallCheck(this, A);
+    _this = babelHelpers.callSuper(this, A);
+    babelHelpers.superPropGet(A, "init", babelHelpers.assertThisInitialized(_this), 3)([]);
+    return _this;
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A, [{
+    key: "method",
+    value: function method() {
+      babelHelpers.superPropGet(A, "method", this, 3)([1, 2]);
+      babelHelpers.superPropSet(A, "x", 1, this, 1, 1);
+      babelHelpers.superPropSet(A, "y", babelHelpers.superPropGet(A, "y", this, 1) + 2, this, 1, 1);
+      return babelHelpers.superPropGet(A, key, this, 1);
+    }
+  }], [{
+    key: "create",
+    value: function create() {
+      return babelHelpers.superPropGet(A, "create", this, 2)([]);
+    }
+  }]);
+}(B);
@@ -0,0 +1,15 @@
+class A {}
+
+if (cond) {
+  class A {
+    method() {
+      return A;
+    }
+  }
+  use(A);
+}
+
+for (let i = 0; i < 3; i++) {
+  class B {}
+  fns.push(() => new B(i));
+}
@@ -0,0 +1,29 @@
+var A = /* @__PURE__ */ babelHelpers.createClass(function A() {
+  "use strict";
+  babelHelpers.classCallCheck(this, A);
+});
+if (cond) {
+  var _A = /* @__PURE__ */ function() {
+    "use strict";
+    function _A() {
+      babelHelpers.classCallCheck(this, _A);
+    }
+    return babelHelpers.createClass(_A, [{
+      key: "method",
+      value: function method() {
+        return _A;
+      }
+    }]);
+  }();
+  use(_A);
+}
+var _loop = function(i) {
+  var B = /* @__PURE__ */ babelHelpers.createClass(function B() {
+    "use strict";
+    babelHelpers.classCallCheck(this, B);
+  });
+  fns.push(() => new B(i));
+};
+for (var i = 0; i < 3; i++) {
+  _loop(i);
+}
@@ -0,0 +1,16 @@
+class A extends B {
+  x = 1;
+  #y = 2;
+  static z = A;
+  constructor() {
+    super();
+    this.w = this.#y;
+  }
+  #method() {
+    return A;
+  }
+}
+
+var C = class {
+  static self = C;
+};
@@ -0,0 +1,27 @@
+var _A, _Class;
+var _y = /* @__PURE__ */ new WeakMap();
+var _A_brand = /* @__PURE__ */ new WeakSet();
+let A = /* @__PURE__ */ function(_B) {
+  "use strict";
+  function A() {
+    var _this;
+    babelHelpers.classCallCheck(this, A);
+    _this = babelHelpers.callSuper(this, A);
+    babelHelpers.classPrivateMethodInitSpec(babelHelpers.assertThisInitialized(_this), _A_brand);
+    babelHelpers.defineProperty(babelHelpers.assertThisInitialized(_this), "x", 1);
+    babelHelpers.classPrivateFieldInitSpec(babelHelpers.assertThisInitialized(_this), _y, 2);
+    _this.w = babelHelpers.classPrivateFieldGet2(_y, babelHelpers.assertThisInitialized(_this));
+    return _this;
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A);
+}(B);
+_A = A;
+function _method() {
+  return _A;
+}
+babelHelpers.defineProperty(A, "z", _A);
+var C = (_Class = /* @__PURE__ */ babelHelpers.createClass(function _class() {
+  "use strict";
+  babelHelpers.classCallCheck(this, _class);
+}), babelHelpers.defineProperty(_Class, "self", C), _Class);
@@ -0,0 +1,2 @@
+foo.bar = { [a]: 1, b: 2 };
+fn({ [a]: 1, b: 2 });
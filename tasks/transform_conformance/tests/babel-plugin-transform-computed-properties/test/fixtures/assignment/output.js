@@ -0,0 +1,3 @@
+var _foo$bar, _obj;
+foo.bar = (_foo$bar = {}, babelHelpers.defineProperty(_foo$bar, a, 1), babelHelpers.defineProperty(_foo$bar, "b", 2), _foo$bar);
+fn((_obj = {}, babelHelpers.defineProperty(_obj, a, 1), babelHelpers.defineProperty(_obj, "b", 2), _obj));
@@ -0,0 +1,2 @@
+var obj = { a: 1, [b]: 2, c: 3, "d-e": 4, get [f]() {} };
+var single = { [foo]: "bar" };
@@ -0,0 +1,3 @@
+var _obj, _single;
+var obj = (_obj = { a: 1 }, _obj[b] = 2, _obj.c = 3, _obj["d-e"] = 4, babelHelpers.defineAccessor("get", _obj, f, function() {}), _obj);
+var single = (_single = {}, _single[foo] = "bar", _single);
@@ -0,0 +1,10 @@
+var obj = {
+  a: 1,
+  ["x" + foo]: "heh",
+  b: 2,
+  "c": 3,
+  4: 4,
+  get [bar]() {},
+  set [bar](v) {},
+  baz() {},
+};
@@ -0,0 +1,2 @@
+var _obj;
+var obj = (_obj = { a: 1 }, babelHelpers.defineProperty(_obj, "x" + foo, "heh"), babelHelpers.defineProperty(_obj, "b", 2), babelHelpers.defineProperty(_obj, "c", 3), babelHelpers.defineProperty(_obj, 4, 4), babelHelpers.defineAccessor("get", _obj, bar, function() {}), babelHelpers.defineAccessor("set", _obj, bar, function(v) {}), babelHelpers.defineProperty(_obj, "baz", function() {}), _obj);
@@ -0,0 +1,5 @@
+const proto = { inherited: 1 };
+const obj = { ["a"]: 1, __proto__: proto };
+expect(obj.inherited).toBe(1);
+expect(Object.getPrototypeOf(obj)).toBe(proto);
+expect(Object.prototype.hasOwnProperty.call(obj, "__proto__")).toBe(false);
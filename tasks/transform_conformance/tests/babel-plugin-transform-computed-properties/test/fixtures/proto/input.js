@@ -0,0 +1,7 @@
+var obj = {
+  ["a"]: 1,
+  __proto__: proto,
+  ["__proto__"]: 2,
+  __proto__() {},
+};
+var obj2 = { [a]: 1, "__proto__": proto };
@@ -0,0 +1,3 @@
+var _obj, _obj2;
+var obj = (_obj = {}, babelHelpers.defineProperty(_obj, "a", 1), _obj.__proto__ = proto, babelHelpers.defineProperty(_obj, "__proto__", 2), babelHelpers.defineProperty(_obj, "__proto__", function() {}), _obj);
+var obj2 = (_obj2 = {}, babelHelpers.defineProperty(_obj2, a, 1), _obj2["__proto__"] = proto, _obj2);
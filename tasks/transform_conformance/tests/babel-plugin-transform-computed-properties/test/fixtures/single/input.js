@@ -0,0 +1,2 @@
+var a = { [foo]: "bar" };
+var b = { get [foo]() {} };
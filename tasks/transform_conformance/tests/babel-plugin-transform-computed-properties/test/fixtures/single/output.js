@@ -0,0 +1,2 @@
+var a = babelHelpers.defineProperty({}, foo, "bar");
+var b = babelHelpers.defineAccessor("get", {}, foo, function() {});
@@ -0,0 +1,2 @@
+var obj = { [a]: 1, ...b };
+var obj2 = { ...b, [a]: 1 };
@@ -0,0 +1,5 @@
+var obj = {
+  [a]: 1,
+  ...b
+};
+var obj2 = babelHelpers.defineProperty({ ...b }, a, 1);
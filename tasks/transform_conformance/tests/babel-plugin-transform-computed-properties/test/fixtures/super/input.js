@@ -0,0 +1,6 @@
+var obj = {
+  [a]: 1,
+  b() {
+    return super.b();
+  },
+};
@@ -0,0 +1,3 @@
+function f() {
+  var _arguments = Array.prototype.slice.call(arguments), a = _arguments[0], b = _arguments[1];
+}
@@ -0,0 +1,2 @@
+var [a, b] = arr;
+var [c, ...d] = arr;
@@ -0,0 +1,2 @@
+var _arr = babelHelpers.maybeArrayLike(babelHelpers.slicedToArray, arr, 2), a = _arr[0], b = _arr[1];
+var _arr2 = babelHelpers.maybeArrayLike(babelHelpers.toArray, arr), c = _arr2[0], d = _arr2.slice(1);
@@ -0,0 +1,4 @@
+var [a, b] = [1, 2];
+var [c, ...d] = [1, 2, 3];
+var [e, f] = [f, e];
+var [g] = [1, 2];
@@ -0,0 +1,4 @@
+var a = 1, b = 2;
+var c = 1, d = [2, 3];
+var _ref = [f, e], e = _ref[0], f = _ref[1];
+var _ref2 = [1, 2], g = _ref2[0];
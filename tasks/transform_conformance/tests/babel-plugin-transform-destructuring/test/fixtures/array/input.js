@@ -0,0 +1,4 @@
+var [a, b] = arr;
+var [c, , d] = arr;
+var [e, ...f] = arr;
+var [[g, h], i] = getArr();
@@ -0,0 +1,4 @@
+var _arr = babelHelpers.slicedToArray(arr, 2), a = _arr[0], b = _arr[1];
+var _arr2 = babelHelpers.slicedToArray(arr, 3), c = _arr2[0], d = _arr2[2];
+var _arr3 = babelHelpers.toArray(arr), e = _arr3[0], f = _arr3.slice(1);
+var _getArr = babelHelpers.slicedToArray(getArr(), 2), _getArr$ = babelHelpers.slicedToArray(_getArr[0], 2), g = _getArr$[0], h = _getArr$[1], i = _getArr[1];
@@ -0,0 +1,4 @@
+x = [a, b] = arr;
+foo(({ c } = obj));
+var f = () => ({ d } = obj);
+for ([e] = arr; ; [e] = arr) {}
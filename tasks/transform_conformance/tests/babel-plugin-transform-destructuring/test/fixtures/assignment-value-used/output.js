@@ -0,0 +1,8 @@
+var _arr, _arr2, _obj, _arr3, _arr4;
+x = (_arr = arr, _arr2 = babelHelpers.slicedToArray(_arr, 2), a = _arr2[0], b = _arr2[1], _arr);
+foo((_obj = obj, c = _obj.c, _obj));
+var f = () => {
+  var _obj2;
+  return _obj2 = obj, d = _obj2.d, _obj2;
+};
+for (_arr3 = babelHelpers.slicedToArray(arr, 1), e = _arr3[0];; _arr4 = babelHelpers.slicedToArray(arr, 1), e = _arr4[0]) {}
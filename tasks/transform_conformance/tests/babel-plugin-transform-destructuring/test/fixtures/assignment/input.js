@@ -0,0 +1,4 @@
+({ a, b: { c } } = obj);
+[d, e] = [e, d];
+[f.g, h[i]] = arr;
+({ j = 1, k: l = 2 } = obj);
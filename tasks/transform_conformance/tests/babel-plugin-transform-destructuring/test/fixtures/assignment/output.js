@@ -0,0 +1,5 @@
+var _obj, _ref, _arr, _obj2, _obj2$j, _obj2$k;
+_obj = obj, a = _obj.a, c = _obj.b.c;
+_ref = [e, d], d = _ref[0], e = _ref[1];
+_arr = babelHelpers.slicedToArray(arr, 2), f.g = _arr[0], h[i] = _arr[1];
+_obj2 = obj, _obj2$j = _obj2.j, j = _obj2$j === void 0 ? 1 : _obj2$j, _obj2$k = _obj2.k, l = _obj2$k === void 0 ? 2 : _obj2$k;
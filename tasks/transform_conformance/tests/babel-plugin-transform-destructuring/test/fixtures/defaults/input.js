@@ -0,0 +1,2 @@
+var { a = 1, b: { c } = {} } = obj;
+var [d = 2, [e] = []] = arr;
@@ -0,0 +1,2 @@
+var _obj = obj, _obj$a = _obj.a, a = _obj$a === void 0 ? 1 : _obj$a, _obj$b = _obj.b, _obj$b2 = _obj$b === void 0 ? {} : _obj$b, c = _obj$b2.c;
+var _arr = babelHelpers.slicedToArray(arr, 2), _arr$ = _arr[0], d = _arr$ === void 0 ? 2 : _arr$, _arr$2 = _arr[1], _arr$3 = _arr$2 === void 0 ? [] : _arr$2, _arr$4 = babelHelpers.slicedToArray(_arr$3, 1), e = _arr$4[0];
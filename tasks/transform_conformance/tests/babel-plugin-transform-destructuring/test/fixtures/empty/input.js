@@ -0,0 +1,3 @@
+var {} = obj;
+var [] = arr;
+({} = obj);
@@ -0,0 +1,3 @@
+var _ref = babelHelpers.objectDestructuringEmpty(obj);
+var _arr = babelHelpers.slicedToArray(arr, 0);
+babelHelpers.objectDestructuringEmpty(obj);
@@ -0,0 +1 @@
+for (var [a, b] in obj) {}
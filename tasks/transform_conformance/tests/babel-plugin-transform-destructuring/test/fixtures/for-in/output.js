@@ -0,0 +1,3 @@
+for (var _ref in obj) {
+  var _ref2 = babelHelpers.slicedToArray(_ref, 2), a = _ref2[0], b = _ref2[1];
+}
@@ -0,0 +1,3 @@
+for (var [a, b] of entries) {}
+for (let { c } of items) console.log(c);
+for ([d, e] of entries);
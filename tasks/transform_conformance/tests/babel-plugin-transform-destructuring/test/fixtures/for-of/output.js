@@ -0,0 +1,11 @@
+for (var _ref of entries) {
+  var _ref2 = babelHelpers.slicedToArray(_ref, 2), a = _ref2[0], b = _ref2[1];
+}
+for (let _ref3 of items) {
+  let c = _ref3.c;
+  console.log(c);
+}
+for (var _ref4 of entries) {
+  var _ref5;
+  _ref5 = babelHelpers.slicedToArray(_ref4, 2), d = _ref5[0], e = _ref5[1];
+}
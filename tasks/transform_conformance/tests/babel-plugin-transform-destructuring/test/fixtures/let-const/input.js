@@ -0,0 +1,2 @@
+let { a, b } = obj;
+const [c, d] = arr;
@@ -0,0 +1,2 @@
+let _obj = obj, a = _obj.a, b = _obj.b;
+const _arr = babelHelpers.slicedToArray(arr, 2), c = _arr[0], d = _arr[1];
@@ -0,0 +1 @@
+var _arr = arr, a = _arr[0], b = _arr[1], c = _arr.slice(2);
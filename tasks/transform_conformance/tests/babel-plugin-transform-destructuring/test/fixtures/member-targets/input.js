@@ -0,0 +1,2 @@
+[a.b, c] = [1, 2];
+({ x: this.x, y: obj[key] } = point);
@@ -0,0 +1,3 @@
+var _ref, _point;
+_ref = [1, 2], a.b = _ref[0], c = _ref[1];
+_point = point, this.x = _point.x, obj[key] = _point.y;
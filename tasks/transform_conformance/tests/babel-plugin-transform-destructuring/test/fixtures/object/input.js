@@ -0,0 +1,3 @@
+var { a, b: { c }, "d-e": f, [g]: h } = obj;
+var { i } = getObj();
+var { j, k } = this;
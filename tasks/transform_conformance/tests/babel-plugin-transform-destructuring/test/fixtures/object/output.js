@@ -0,0 +1,3 @@
+var _obj = obj, a = _obj.a, c = _obj.b.c, f = _obj["d-e"], h = _obj[g];
+var i = getObj().i;
+var j = this.j, k = this.k;
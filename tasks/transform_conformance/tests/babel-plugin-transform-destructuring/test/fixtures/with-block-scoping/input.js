@@ -0,0 +1,7 @@
+for (const [a, b] of entries) {
+  fns.push(() => a + b);
+}
+let { c, d: [e] } = obj;
+try {} catch ({ message }) {
+  fns.push(() => message);
+}
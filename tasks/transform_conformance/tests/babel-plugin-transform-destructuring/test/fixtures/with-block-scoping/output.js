@@ -0,0 +1,12 @@
+var _loop = function(_ref) {
+  var _ref2 = babelHelpers.slicedToArray(_ref, 2), a = _ref2[0], b = _ref2[1];
+  fns.push(() => a + b);
+};
+for (var _ref of entries) {
+  _loop(_ref);
+}
+var _obj = obj, c = _obj.c, _obj$d = babelHelpers.slicedToArray(_obj.d, 1), e = _obj$d[0];
+try {} catch (_ref3) {
+  var message = _ref3.message;
+  fns.push(() => message);
+}
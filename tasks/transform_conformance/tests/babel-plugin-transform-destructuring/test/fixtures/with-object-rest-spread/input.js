@@ -0,0 +1,3 @@
+var { a, ...b } = obj;
+var [c, { d, ...e }] = arr;
+({ f, ...g } = obj);
@@ -0,0 +1,5 @@
+var _obj2;
+var a = obj.a, b = babelHelpers.objectWithoutProperties(obj, ["a"]);
+var _arr = babelHelpers.slicedToArray(arr, 2), c = _arr[0], _ref = _arr[1], d = _ref.d, e = babelHelpers.objectWithoutProperties(_ref, ["d"]);
+var _obj;
+_obj = obj, _obj2 = _obj, f = _obj2.f, _obj2, g = babelHelpers.objectWithoutProperties(_obj, ["f"]);
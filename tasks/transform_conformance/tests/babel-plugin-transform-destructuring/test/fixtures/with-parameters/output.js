@@ -0,0 +1,8 @@
+function foo(_ref) {
+  var a = _ref.a, b = _ref.b;
+  var _ref3 = babelHelpers.slicedToArray(arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : [], 1), c = _ref3[0];
+  for (var _len = arguments.length, _ref2 = new Array(_len > 2 ? _len - 2 : 0), _key = 2; _key < _len; _key++) {
+    _ref2[_key - 2] = arguments[_key];
+  }
+  var _ref4 = babelHelpers.slicedToArray(_ref2, 1), d = _ref4[0];
+}
@@ -0,0 +1,8 @@
+var x = { a: 5, a: 6 };
+var y = {
+  get a() {},
+  set a(x) {},
+  a: 3,
+};
+var z = { "b": 1, b: 2, 1: 3, "1": 4 };
+var s = { c, c };
@@ -0,0 +1,2 @@
+var x = { a: 1, b: 2, [a]: 3 };
+var y = { get a() {}, set a(v) {} };
@@ -0,0 +1,3 @@
+for (const i of [1, 2, 3]) {
+  console.log(i);
+}
@@ -0,0 +1,8 @@
+for (var _i = 0, _arr = [
+  1,
+  2,
+  3
+]; _i < _arr.length; _i++) {
+  const i = _arr[_i];
+  console.log(i);
+}
@@ -0,0 +1,4 @@
+for (const i of arr) {
+  console.log(i);
+}
+for (elm of array) console.log(elm);
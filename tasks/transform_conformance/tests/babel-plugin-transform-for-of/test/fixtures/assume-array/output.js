@@ -0,0 +1,8 @@
+for (var _i = 0, _arr = arr; _i < _arr.length; _i++) {
+  const i = _arr[_i];
+  console.log(i);
+}
+for (var _i2 = 0, _array = array; _i2 < _array.length; _i2++) {
+  elm = _array[_i2];
+  console.log(elm);
+}
@@ -0,0 +1,7 @@
+for (var i of arr) {
+  console.log(i);
+}
+for (const [a, b] of map) {
+  console.log(a, b);
+}
+for (x of y) fn(x);
@@ -0,0 +1,33 @@
+var _iterator = babelHelpers.createForOfIteratorHelper(arr), _step;
+try {
+  for (_iterator.s(); !(_step = _iterator.n()).done;) {
+    var i = _step.value;
+    console.log(i);
+  }
+} catch (err) {
+  _iterator.e(err);
+} finally {
+  _iterator.f();
+}
+var _iterator2 = babelHelpers.createForOfIteratorHelper(map), _step2;
+try {
+  for (_iterator2.s(); !(_step2 = _iterator2.n()).done;) {
+    const [a, b] = _step2.value;
+    console.log(a, b);
+  }
+} catch (err) {
+  _iterator2.e(err);
+} finally {
+  _iterator2.f();
+}
+var _iterator3 = babelHelpers.createForOfIteratorHelper(y), _step3;
+try {
+  for (_iterator3.s(); !(_step3 = _iterator3.n()).done;) {
+    x = _step3.value;
+    fn(x);
+  }
+} catch (err) {
+  _iterator3.e(err);
+} finally {
+  _iterator3.f();
+}
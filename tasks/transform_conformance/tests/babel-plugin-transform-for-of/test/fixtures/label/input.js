@@ -0,0 +1,6 @@
+outer: for (const i of arr) {
+  for (const j of arr) {
+    if (j) continue outer;
+    break outer;
+  }
+}
@@ -0,0 +1,22 @@
+var _iterator = babelHelpers.createForOfIteratorHelper(arr), _step;
+try {
+  outer: for (_iterator.s(); !(_step = _iterator.n()).done;) {
+    const i = _step.value;
+    var _iterator2 = babelHelpers.createForOfIteratorHelper(arr), _step2;
+    try {
+      for (_iterator2.s(); !(_step2 = _iterator2.n()).done;) {
+        const j = _step2.value;
+        if (j) continue outer;
+        break outer;
+      }
+    } catch (err) {
+      _iterator2.e(err);
+    } finally {
+      _iterator2.f();
+    }
+  }
+} catch (err) {
+  _iterator.e(err);
+} finally {
+  _iterator.f();
+}
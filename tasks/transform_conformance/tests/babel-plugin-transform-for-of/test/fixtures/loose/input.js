@@ -0,0 +1,3 @@
+for (const i of arr) {
+  console.log(i);
+}
@@ -0,0 +1 @@
+if (a) for (const i of arr) console.log(i);
@@ -0,0 +1,13 @@
+if (a) {
+  var _iterator = babelHelpers.createForOfIteratorHelper(arr), _step;
+  try {
+    for (_iterator.s(); !(_step = _iterator.n()).done;) {
+      const i = _step.value;
+      console.log(i);
+    }
+  } catch (err) {
+    _iterator.e(err);
+  } finally {
+    _iterator.f();
+  }
+}
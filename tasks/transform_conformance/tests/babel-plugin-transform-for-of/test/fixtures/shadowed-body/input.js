@@ -0,0 +1,4 @@
+for (const i of arr) {
+  const i = 1;
+  console.log(i);
+}
@@ -0,0 +1,10 @@
+var foo = function () {};
+bar = function () {};
+var obj = {
+  baz: function () {},
+  "qux": function () {},
+  "a-b": function () {},
+  [c]: function () {},
+};
+var named = function other() {};
+var arrow = () => {};
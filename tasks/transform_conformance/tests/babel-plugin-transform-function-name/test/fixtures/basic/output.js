@@ -0,0 +1,10 @@
+var foo = function foo() {};
+bar = function bar() {};
+var obj = {
+  baz: function baz() {},
+  "qux": function qux() {},
+  "a-b": function() {},
+  [c]: function() {}
+};
+var named = function other() {};
+var arrow = () => {};
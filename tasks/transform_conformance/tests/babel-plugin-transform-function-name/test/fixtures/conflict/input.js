@@ -0,0 +1,10 @@
+var foo = function () {
+  return foo;
+};
+var bar = function () {
+  var bar = 1;
+  return bar;
+};
+var obj = {
+  delete: function () {},
+};
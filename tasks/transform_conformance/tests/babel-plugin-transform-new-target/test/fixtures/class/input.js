@@ -0,0 +1,23 @@
+class Foo {
+  constructor() {
+    console.log(new.target);
+  }
+  method() {
+    return new.target;
+  }
+  static method() {
+    return new.target;
+  }
+  field = new.target;
+  static {
+    new.target;
+  }
+}
+var obj = {
+  method() {
+    return new.target;
+  },
+  get getter() {
+    return new.target;
+  },
+};
@@ -0,0 +1,23 @@
+class Foo {
+  constructor() {
+    console.log(this.constructor);
+  }
+  method() {
+    return void 0;
+  }
+  static method() {
+    return void 0;
+  }
+  field = void 0;
+  static {
+    void 0;
+  }
+}
+var obj = {
+  method() {
+    return void 0;
+  },
+  get getter() {
+    return void 0;
+  }
+};
@@ -0,0 +1,10 @@
+function Foo() {
+  console.log(new.target);
+  const arrow = () => new.target;
+}
+var Bar = function () {
+  console.log(new.target, new.target);
+};
+export default function () {
+  return new.target;
+}
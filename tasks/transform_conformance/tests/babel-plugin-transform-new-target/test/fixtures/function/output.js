@@ -0,0 +1,10 @@
+function Foo() {
+  console.log(this instanceof Foo ? this.constructor : void 0);
+  const arrow = () => this instanceof Foo ? this.constructor : void 0;
+}
+var Bar = function _target() {
+  console.log(this instanceof _target ? this.constructor : void 0, this instanceof _target ? this.constructor : void 0);
+};
+export default function _target2() {
+  return this instanceof _target2 ? this.constructor : void 0;
+}
@@ -0,0 +1,6 @@
+function Foo() {
+  {
+    let Foo;
+    new.target;
+  }
+}
@@ -0,0 +1 @@
+var f = (a, b = 1) => a + b;
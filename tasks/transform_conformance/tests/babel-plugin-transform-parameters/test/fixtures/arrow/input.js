@@ -0,0 +1,2 @@
+var f = (a = 1) => a;
+var g = (...args) => args;
@@ -0,0 +1,10 @@
+var f = function() {
+  var a = arguments.length > 0 && arguments[0] !== undefined ? arguments[0] : 1;
+  return a;
+};
+var g = function() {
+  for (var _len = arguments.length, args = new Array(_len), _key = 0; _key < _len; _key++) {
+    args[_key] = arguments[_key];
+  }
+  return args;
+};
@@ -0,0 +1,4 @@
+function foo(a, b = 1, c = a + b) {
+  return a + b + c;
+}
+var bar = function (a = {}) {};
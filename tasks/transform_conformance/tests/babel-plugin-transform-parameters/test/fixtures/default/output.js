@@ -0,0 +1,8 @@
+function foo(a) {
+  var b = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : 1;
+  var c = arguments.length > 2 && arguments[2] !== undefined ? arguments[2] : a + b;
+  return a + b + c;
+}
+var bar = function() {
+  var a = arguments.length > 0 && arguments[0] !== undefined ? arguments[0] : {};
+};
@@ -0,0 +1 @@
+function foo({ a, b }, [c, d] = []) {}
@@ -0,0 +1,4 @@
+function foo(_ref) {
+  var { a, b } = _ref;
+  var [c, d] = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : [];
+}
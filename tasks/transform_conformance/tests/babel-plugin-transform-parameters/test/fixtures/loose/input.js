@@ -0,0 +1 @@
+function foo(a = 1, ...b) {}
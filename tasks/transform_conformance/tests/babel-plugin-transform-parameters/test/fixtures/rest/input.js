@@ -0,0 +1,6 @@
+function foo(...args) {
+  return args;
+}
+function bar(a, b, ...rest) {
+  return rest;
+}
@@ -0,0 +1,6 @@
+var obj = {
+  set a(value = 1) {},
+};
+class A {
+  set b({ c }) {}
+}
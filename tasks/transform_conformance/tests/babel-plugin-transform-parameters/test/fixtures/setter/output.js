@@ -0,0 +1,10 @@
+var obj = { set a(value) {
+  if (value === void 0) {
+    value = 1;
+  }
+} };
+class A {
+  set b(_ref) {
+    var { c } = _ref;
+  }
+}
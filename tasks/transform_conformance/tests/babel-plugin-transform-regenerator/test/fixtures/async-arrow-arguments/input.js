@@ -0,0 +1,6 @@
+const obj = {
+  v: 7,
+  m() {
+    return (async () => this.v + arguments.length)();
+  }
+};
@@ -0,0 +1,15 @@
+const obj = {
+  v: 7,
+  m() {
+    var _arguments = arguments, _this = this;
+    return babelHelpers.asyncToGenerator(babelHelpers.regeneratorRuntime().mark(function _callee() {
+      return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+        while (1) switch (_context.prev = _context.next) {
+          case 0: return _context.abrupt("return", _this.v + _arguments.length);
+          case 1:
+          case "end": return _context.stop();
+        }
+      }, _callee);
+    }))();
+  }
+};
@@ -0,0 +1,20 @@
+function foo() {
+  return _foo.apply(this, arguments);
+}
+function _foo() {
+  _foo = babelHelpers.wrapAsyncGenerator(/* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(function _callee() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+      while (1) switch (_context.prev = _context.next) {
+        case 0:
+          _context.next = 2;
+          return babelHelpers.awaitAsyncGenerator(bar());
+        case 2:
+          _context.next = 4;
+          return _context.sent;
+        case 4:
+        case "end": return _context.stop();
+      }
+    }, _callee);
+  }));
+  return _foo.apply(this, arguments);
+}
@@ -0,0 +1,13 @@
+async function foo() {
+  await bar();
+}
+
+const baz = async () => {
+  while (await more()) {
+    try {
+      await next();
+    } catch (err) {
+      console.error(err);
+    }
+  }
+};
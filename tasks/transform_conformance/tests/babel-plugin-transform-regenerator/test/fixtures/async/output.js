@@ -0,0 +1,51 @@
+function foo() {
+  return _foo.apply(this, arguments);
+}
+function _foo() {
+  _foo = babelHelpers.asyncToGenerator(babelHelpers.regeneratorRuntime().mark(function _callee() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+      while (1) switch (_context.prev = _context.next) {
+        case 0:
+          _context.next = 2;
+          return bar();
+        case 2:
+        case "end": return _context.stop();
+      }
+    }, _callee);
+  }));
+  return _foo.apply(this, arguments);
+}
+const baz = function() {
+  var _ref = babelHelpers.asyncToGenerator(babelHelpers.regeneratorRuntime().mark(function _callee2() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee2$(_context2) {
+      while (1) switch (_context2.prev = _context2.next) {
+        case 0:
+          _context2.next = 2;
+          return more();
+        case 2:
+          if (!_context2.sent) {
+            _context2.next = 13;
+            break;
+          }
+          _context2.prev = 3;
+          _context2.next = 6;
+          return next();
+        case 6:
+          _context2.next = 11;
+          break;
+        case 8:
+          _context2.prev = 8;
+          _context2.t0 = _context2["catch"](3);
+          console.error(_context2.t0);
+        case 11:
+          _context2.next = 0;
+          break;
+        case 13:
+        case "end": return _context2.stop();
+      }
+    }, _callee2, null, [[3, 8]]);
+  }));
+  return function baz() {
+    return _ref.apply(this, arguments);
+  };
+}();
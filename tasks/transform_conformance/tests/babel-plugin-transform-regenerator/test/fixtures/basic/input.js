@@ -0,0 +1,13 @@
+function* gen(a) {
+  const b = yield a;
+  console.log(b);
+  return a + b;
+}
+
+var expr = function* () {
+  yield 1;
+};
+
+var named = function* named() {
+  yield named;
+};
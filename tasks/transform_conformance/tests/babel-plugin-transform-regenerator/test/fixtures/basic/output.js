@@ -0,0 +1,39 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen(a) {
+  var b;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return a;
+      case 2:
+        b = _context.sent;
+        console.log(b);
+        return _context.abrupt("return", a + b);
+      case 5:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
+var expr = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(function _callee() {
+  return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context2) {
+    while (1) switch (_context2.prev = _context2.next) {
+      case 0:
+        _context2.next = 2;
+        return 1;
+      case 2:
+      case "end": return _context2.stop();
+    }
+  }, _callee);
+});
+var named = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(function named() {
+  return babelHelpers.regeneratorRuntime().wrap(function named$(_context3) {
+    while (1) switch (_context3.prev = _context3.next) {
+      case 0:
+        _context3.next = 2;
+        return named;
+      case 2:
+      case "end": return _context3.stop();
+    }
+  }, named);
+});
@@ -0,0 +1,25 @@
+function* gen() {
+  yield inner();
+  {
+    let x = 1;
+    yield x;
+  }
+  {
+    let x = 2;
+    yield x;
+  }
+  const { a, b: [c] } = yield;
+  function inner() {
+    return a + c;
+  }
+}
+
+export function* exported() {
+  yield 1;
+}
+
+if (cond) {
+  function* inBlock() {
+    yield 1;
+  }
+}
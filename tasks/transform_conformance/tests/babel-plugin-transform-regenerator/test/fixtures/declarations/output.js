@@ -0,0 +1,53 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen), _marked2 = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(exported);
+function gen() {
+  var a, c, inner, x, _x;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        inner = function _inner() {
+          return a + c;
+        };
+        _context.next = 3;
+        return inner();
+      case 3:
+        x = 1;
+        _context.next = 6;
+        return x;
+      case 6:
+        _x = 2;
+        _context.next = 9;
+        return _x;
+      case 9:
+        _context.next = 11;
+        return;
+      case 11: ({a: a, b: [c]} = _context.sent);
+      case 12:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
+export function exported() {
+  return babelHelpers.regeneratorRuntime().wrap(function exported$(_context2) {
+    while (1) switch (_context2.prev = _context2.next) {
+      case 0:
+        _context2.next = 2;
+        return 1;
+      case 2:
+      case "end": return _context2.stop();
+    }
+  }, _marked2);
+}
+if (cond) {
+  var _marked3 = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(inBlock);
+  function inBlock() {
+    return babelHelpers.regeneratorRuntime().wrap(function inBlock$(_context3) {
+      while (1) switch (_context3.prev = _context3.next) {
+        case 0:
+          _context3.next = 2;
+          return 1;
+        case 2:
+        case "end": return _context3.stop();
+      }
+    }, _marked3);
+  }
+}
@@ -0,0 +1,5 @@
+function* gen(other) {
+  yield* other;
+  const result = yield* inner();
+  return result;
+}
@@ -0,0 +1,9 @@
+function* gen(obj) {
+  const sum = (yield 1) + (yield 2);
+  obj.count += yield sum;
+  obj[yield "key"] = yield "value";
+  const value = (yield a) ? yield b : c;
+  const both = (yield x) && (yield y);
+  foo(yield 1, bar(), yield 2);
+  return [yield 3, ...(yield 4)];
+}
@@ -0,0 +1,82 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen(obj) {
+  var sum, value, both;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return 1;
+      case 2:
+        _context.t0 = _context.sent;
+        _context.next = 5;
+        return 2;
+      case 5:
+        _context.t1 = _context.sent;
+        sum = _context.t0 + _context.t1;
+        _context.t2 = obj.count;
+        _context.next = 10;
+        return sum;
+      case 10:
+        obj.count = _context.t2 += _context.sent;
+        _context.next = 13;
+        return "key";
+      case 13:
+        _context.t3 = _context.sent;
+        _context.next = 16;
+        return "value";
+      case 16:
+        obj[_context.t3] = _context.sent;
+        _context.next = 19;
+        return a;
+      case 19:
+        if (!_context.sent) {
+          _context.next = 25;
+          break;
+        }
+        _context.next = 22;
+        return b;
+      case 22:
+        _context.t4 = _context.sent;
+        _context.next = 26;
+        break;
+      case 25: _context.t4 = c;
+      case 26:
+        value = _context.t4;
+        _context.next = 29;
+        return x;
+      case 29:
+        _context.t5 = _context.sent;
+        if (!_context.t5) {
+          _context.next = 34;
+          break;
+        }
+        _context.next = 33;
+        return y;
+      case 33: _context.t5 = _context.sent;
+      case 34:
+        both = _context.t5;
+        _context.t6 = foo;
+        _context.next = 38;
+        return 1;
+      case 38:
+        _context.t7 = _context.sent;
+        _context.t8 = bar();
+        _context.next = 42;
+        return 2;
+      case 42:
+        _context.t9 = _context.sent;
+        (0, _context.t6)(_context.t7, _context.t8, _context.t9);
+        _context.next = 46;
+        return 3;
+      case 46:
+        _context.t10 = _context.sent;
+        _context.next = 49;
+        return 4;
+      case 49:
+        _context.t11 = _context.sent;
+        return _context.abrupt("return", [_context.t10, ..._context.t11]);
+      case 51:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
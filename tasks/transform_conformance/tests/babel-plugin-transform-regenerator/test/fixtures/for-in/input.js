@@ -0,0 +1,6 @@
+function* keys(obj) {
+  var key;
+  for (key in obj) {
+    yield key;
+  }
+}
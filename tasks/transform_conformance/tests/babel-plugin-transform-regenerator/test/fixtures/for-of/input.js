@@ -0,0 +1,5 @@
+function* gen(list) {
+  for (const item of list) {
+    yield item;
+  }
+}
@@ -0,0 +1,41 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen(list) {
+  var _iterator, _step, item;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _iterator = babelHelpers.createForOfIteratorHelper(list);
+        _context.prev = 1;
+        _iterator.s();
+      case 3:
+        if ((_step = _iterator.n()).done) {
+          _context.next = 9;
+          break;
+        }
+        item = _step.value;
+        _context.next = 7;
+        return item;
+      case 7:
+        _context.next = 3;
+        break;
+      case 9:
+        _context.next = 14;
+        break;
+      case 11:
+        _context.prev = 11;
+        _context.t0 = _context["catch"](1);
+        _iterator.e(_context.t0);
+      case 14:
+        _context.prev = 14;
+        _iterator.f();
+        return _context.finish(14);
+      case 17:
+      case "end": return _context.stop();
+    }
+  }, _marked, null, [[
+    1,
+    11,
+    14,
+    17
+  ]]);
+}
@@ -0,0 +1,24 @@
+function* gen(n) {
+  for (let i = 0; i < n; i++) {
+    if (i % 2) continue;
+    yield i;
+  }
+  let j = 0;
+  while (true) {
+    if (j++ > n) break;
+    yield j;
+  }
+  do {
+    yield j--;
+  } while (j > 0);
+}
+
+function* labeled(rows) {
+  outer: for (var i = 0; i < rows.length; i++) {
+    for (var j = 0; j < rows[i].length; j++) {
+      if (rows[i][j] === null) continue outer;
+      if (rows[i][j] === undefined) break outer;
+      yield rows[i][j];
+    }
+  }
+}
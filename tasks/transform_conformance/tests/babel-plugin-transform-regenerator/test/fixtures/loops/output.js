@@ -0,0 +1,95 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen), _marked2 = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(labeled);
+function gen(n) {
+  var j, i;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0: i = 0;
+      case 1:
+        if (!(i < n)) {
+          _context.next = 9;
+          break;
+        }
+        if (!(i % 2)) {
+          _context.next = 4;
+          break;
+        }
+        return _context.abrupt("continue", 6);
+      case 4:
+        _context.next = 6;
+        return i;
+      case 6:
+        i++;
+        _context.next = 1;
+        break;
+      case 9: j = 0;
+      case 10:
+        if (!true) {
+          _context.next = 17;
+          break;
+        }
+        if (!(j++ > n)) {
+          _context.next = 13;
+          break;
+        }
+        return _context.abrupt("break", 17);
+      case 13:
+        _context.next = 15;
+        return j;
+      case 15:
+        _context.next = 10;
+        break;
+      case 17:
+        _context.next = 19;
+        return j--;
+      case 19: if (j > 0) {
+        _context.next = 17;
+        break;
+      }
+      case 20:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
+function labeled(rows) {
+  var i, j;
+  return babelHelpers.regeneratorRuntime().wrap(function labeled$(_context2) {
+    while (1) switch (_context2.prev = _context2.next) {
+      case 0: i = 0;
+      case 1:
+        if (!(i < rows.length)) {
+          _context2.next = 16;
+          break;
+        }
+        j = 0;
+      case 3:
+        if (!(j < rows[i].length)) {
+          _context2.next = 13;
+          break;
+        }
+        if (!(rows[i][j] === null)) {
+          _context2.next = 6;
+          break;
+        }
+        return _context2.abrupt("continue", 13);
+      case 6:
+        if (!(rows[i][j] === undefined)) {
+          _context2.next = 8;
+          break;
+        }
+        return _context2.abrupt("break", 16);
+      case 8:
+        _context2.next = 10;
+        return rows[i][j];
+      case 10:
+        j++;
+        _context2.next = 3;
+        break;
+      case 13:
+        i++;
+        _context2.next = 1;
+        break;
+      case 16:
+      case "end": return _context2.stop();
+    }
+  }, _marked2);
+}
@@ -0,0 +1,17 @@
+class A {
+  *gen() {
+    yield this.x;
+  }
+  static *[Symbol.iterator]() {
+    yield 1;
+  }
+}
+
+const obj = {
+  *gen() {
+    yield 1;
+  },
+  prop: function* () {
+    yield 2;
+  },
+};
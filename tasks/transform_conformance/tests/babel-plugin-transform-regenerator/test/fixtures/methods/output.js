@@ -0,0 +1,48 @@
+class A {
+  gen() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+      while (1) switch (_context.prev = _context.next) {
+        case 0:
+          _context.next = 2;
+          return this.x;
+        case 2:
+        case "end": return _context.stop();
+      }
+    }, null, this);
+  }
+  static [Symbol.iterator]() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee2$(_context2) {
+      while (1) switch (_context2.prev = _context2.next) {
+        case 0:
+          _context2.next = 2;
+          return 1;
+        case 2:
+        case "end": return _context2.stop();
+      }
+    }, null);
+  }
+}
+const obj = {
+  gen() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee3$(_context3) {
+      while (1) switch (_context3.prev = _context3.next) {
+        case 0:
+          _context3.next = 2;
+          return 1;
+        case 2:
+        case "end": return _context3.stop();
+      }
+    }, null);
+  },
+  prop: /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(function _callee4() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee4$(_context4) {
+      while (1) switch (_context4.prev = _context4.next) {
+        case 0:
+          _context4.next = 2;
+          return 2;
+        case 2:
+        case "end": return _context4.stop();
+      }
+    }, _callee4);
+  })
+};
@@ -0,0 +1,5 @@
+class A extends B {
+  *gen() {
+    yield super.gen();
+  }
+}
@@ -0,0 +1,13 @@
+function* gen(x) {
+  switch (x) {
+    case 1:
+      yield "one";
+    case 2:
+      yield "two";
+      break;
+    case (yield "key"):
+      return;
+    default:
+      yield "other";
+  }
+}
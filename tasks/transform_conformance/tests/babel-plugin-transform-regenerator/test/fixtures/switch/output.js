@@ -0,0 +1,56 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen(x) {
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.t0 = x;
+        if (!(_context.t0 === 1)) {
+          _context.next = 5;
+          break;
+        }
+        _context.t1 = 22;
+        _context.next = 20;
+        break;
+      case 5:
+        if (!(_context.t0 === 2)) {
+          _context.next = 9;
+          break;
+        }
+        _context.t2 = 24;
+        _context.next = 19;
+        break;
+      case 9:
+        _context.t3 = _context.t0;
+        _context.next = 12;
+        return "key";
+      case 12:
+        _context.t4 = _context.sent;
+        if (!(_context.t3 === _context.t4)) {
+          _context.next = 17;
+          break;
+        }
+        _context.t5 = 27;
+        _context.next = 18;
+        break;
+      case 17: _context.t5 = 28;
+      case 18: _context.t2 = _context.t5;
+      case 19: _context.t1 = _context.t2;
+      case 20:
+        _context.next = _context.t1;
+        break;
+      case 22:
+        _context.next = 24;
+        return "one";
+      case 24:
+        _context.next = 26;
+        return "two";
+      case 26: return _context.abrupt("break", 30);
+      case 27: return _context.abrupt("return");
+      case 28:
+        _context.next = 30;
+        return "other";
+      case 30:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
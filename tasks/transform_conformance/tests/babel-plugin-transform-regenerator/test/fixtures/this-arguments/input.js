@@ -0,0 +1,9 @@
+function* gen() {
+  yield this;
+  yield arguments.length;
+  const f = () => arguments[0];
+  yield f();
+  function inner() {
+    return this + arguments.length;
+  }
+}
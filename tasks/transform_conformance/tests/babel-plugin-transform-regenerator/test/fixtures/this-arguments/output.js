@@ -0,0 +1,23 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen() {
+  var f, inner, _args = arguments;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        inner = function _inner() {
+          return this + arguments.length;
+        };
+        _context.next = 3;
+        return this;
+      case 3:
+        _context.next = 5;
+        return _args.length;
+      case 5:
+        f = () => _args[0];
+        _context.next = 8;
+        return f();
+      case 8:
+      case "end": return _context.stop();
+    }
+  }, _marked, this);
+}
@@ -0,0 +1,29 @@
+function* gen() {
+  try {
+    yield 1;
+  } catch (e) {
+    yield e;
+  } finally {
+    yield 2;
+  }
+}
+
+function* onlyFinally() {
+  try {
+    return yield 1;
+  } finally {
+    cleanup();
+  }
+}
+
+function* nested() {
+  try {
+    try {
+      yield 1;
+    } catch {
+      throw new Error("inner");
+    }
+  } catch (err) {
+    console.log(err);
+  }
+}
@@ -0,0 +1,80 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen), _marked2 = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(onlyFinally), _marked3 = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(nested);
+function gen() {
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.prev = 0;
+        _context.next = 3;
+        return 1;
+      case 3:
+        _context.next = 9;
+        break;
+      case 5:
+        _context.prev = 5;
+        _context.t0 = _context["catch"](0);
+        _context.next = 9;
+        return _context.t0;
+      case 9:
+        _context.prev = 9;
+        _context.next = 12;
+        return 2;
+      case 12: return _context.finish(9);
+      case 13:
+      case "end": return _context.stop();
+    }
+  }, _marked, null, [[
+    0,
+    5,
+    9,
+    13
+  ]]);
+}
+function onlyFinally() {
+  return babelHelpers.regeneratorRuntime().wrap(function onlyFinally$(_context2) {
+    while (1) switch (_context2.prev = _context2.next) {
+      case 0:
+        _context2.prev = 0;
+        _context2.next = 3;
+        return 1;
+      case 3: return _context2.abrupt("return", _context2.sent);
+      case 4:
+        _context2.prev = 4;
+        cleanup();
+        return _context2.finish(4);
+      case 7:
+      case "end": return _context2.stop();
+    }
+  }, _marked2, null, [[
+    0,
+    ,
+    4,
+    7
+  ]]);
+}
+function nested() {
+  return babelHelpers.regeneratorRuntime().wrap(function nested$(_context3) {
+    while (1) switch (_context3.prev = _context3.next) {
+      case 0:
+        _context3.prev = 0;
+        _context3.prev = 1;
+        _context3.next = 4;
+        return 1;
+      case 4:
+        _context3.next = 9;
+        break;
+      case 6:
+        _context3.prev = 6;
+        _context3.t0 = _context3["catch"](1);
+        throw new Error("inner");
+      case 9:
+        _context3.next = 14;
+        break;
+      case 11:
+        _context3.prev = 11;
+        _context3.t1 = _context3["catch"](0);
+        console.log(_context3.t1);
+      case 14:
+      case "end": return _context3.stop();
+    }
+  }, _marked3, null, [[0, 11], [1, 6]]);
+}
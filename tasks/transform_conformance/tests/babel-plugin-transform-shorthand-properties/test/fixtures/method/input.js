@@ -0,0 +1,11 @@
+var obj = {
+  a() {},
+  *b() {},
+  async c() {},
+  ["d"]() {},
+  __proto__() {},
+  e() {
+    return super.e();
+  },
+  get f() {},
+};
@@ -0,0 +1,11 @@
+var obj = {
+  a: function() {},
+  b: function* () {},
+  c: async function() {},
+  ["d"]: function() {},
+  ["__proto__"]: function() {},
+  e() {
+    return super.e();
+  },
+  get f() {}
+};
@@ -0,0 +1,2 @@
+var obj = { a, b, c: c };
+var proto = { __proto__ };
@@ -0,0 +1,6 @@
+var obj = {
+  a,
+  b,
+  c
+};
+var proto = { ["__proto__"]: __proto__ };
@@ -0,0 +1,4 @@
+function f() {
+  foo(...arguments);
+  return [...arguments];
+}
@@ -0,0 +1,4 @@
+function f() {
+  foo.apply(void 0, arguments);
+  return Array.prototype.slice.call(arguments);
+}
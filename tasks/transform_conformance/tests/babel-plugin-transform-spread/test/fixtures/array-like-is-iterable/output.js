@@ -0,0 +1,2 @@
+var a = [b].concat(babelHelpers.maybeArrayLike(babelHelpers.toConsumableArray, c));
+foo.apply(void 0, babelHelpers.maybeArrayLike(babelHelpers.toConsumableArray, args));
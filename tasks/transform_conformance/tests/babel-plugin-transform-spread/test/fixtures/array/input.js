@@ -0,0 +1,5 @@
+var a = [...b];
+var c = [d, ...e];
+var f = [...g, h];
+var i = [j, ...k, l, ...m];
+var n = [...[1, 2], ...o];
@@ -0,0 +1,5 @@
+var a = babelHelpers.toConsumableArray(b);
+var c = [d].concat(babelHelpers.toConsumableArray(e));
+var f = [].concat(babelHelpers.toConsumableArray(g), [h]);
+var i = [j].concat(babelHelpers.toConsumableArray(k), [l], babelHelpers.toConsumableArray(m));
+var n = [1, 2].concat(babelHelpers.toConsumableArray(o));
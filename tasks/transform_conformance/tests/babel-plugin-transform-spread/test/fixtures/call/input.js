@@ -0,0 +1,7 @@
+foo(...args);
+foo(a, ...args);
+obj.method(...args);
+obj.a.b.method(a, ...args, b);
+obj[key](...args);
+this.method(...args);
+getObj().method(...args);
@@ -0,0 +1,8 @@
+var _obj, _obj$a$b, _obj2, _getObj;
+foo.apply(void 0, babelHelpers.toConsumableArray(args));
+foo.apply(void 0, [a].concat(babelHelpers.toConsumableArray(args)));
+(_obj = obj).method.apply(_obj, babelHelpers.toConsumableArray(args));
+(_obj$a$b = obj.a.b).method.apply(_obj$a$b, [a].concat(babelHelpers.toConsumableArray(args), [b]));
+(_obj2 = obj)[key].apply(_obj2, babelHelpers.toConsumableArray(args));
+this.method.apply(this, babelHelpers.toConsumableArray(args));
+(_getObj = getObj()).method.apply(_getObj, babelHelpers.toConsumableArray(args));
@@ -0,0 +1,3 @@
+var a = [b, ...c];
+foo(...args);
+obj.method(a, ...args);
@@ -0,0 +1,4 @@
+var _obj;
+var a = [b].concat(c);
+foo.apply(void 0, args);
+(_obj = obj).method.apply(_obj, [a].concat(args));
@@ -0,0 +1,3 @@
+new Foo(...args);
+new Foo(a, ...args);
+new obj.Foo(...args, b);
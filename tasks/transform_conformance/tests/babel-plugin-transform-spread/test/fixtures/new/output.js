@@ -0,0 +1,3 @@
+babelHelpers.construct(Foo, babelHelpers.toConsumableArray(args));
+babelHelpers.construct(Foo, [a].concat(babelHelpers.toConsumableArray(args)));
+babelHelpers.construct(obj.Foo, babelHelpers.toConsumableArray(args).concat([b]));
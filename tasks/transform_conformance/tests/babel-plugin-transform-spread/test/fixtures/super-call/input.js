@@ -0,0 +1,6 @@
+class A extends B {
+  constructor(a, ...args) {
+    super(a, ...args);
+    foo(...args);
+  }
+}
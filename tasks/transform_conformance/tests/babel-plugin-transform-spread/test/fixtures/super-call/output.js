@@ -0,0 +1,12 @@
+let A = /* @__PURE__ */ function(_B) {
+  "use strict";
+  function A(a, ...args) {
+    var _this;
+    babelHelpers.classCallCheck(this, A);
+    _this = babelHelpers.callSuper(this, A, [a].concat(babelHelpers.toConsumableArray(args)));
+    foo.apply(void 0, babelHelpers.toConsumableArray(args));
+    return _this;
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A);
+}(B);
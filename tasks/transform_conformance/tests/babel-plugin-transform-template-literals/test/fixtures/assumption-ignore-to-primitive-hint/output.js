@@ -0,0 +1,4 @@
+var _templateObject;
+var a = "foo" + bar + "baz" + qux;
+var b = "" + foo + bar;
+var c = tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo", ""])), bar);
@@ -0,0 +1,2 @@
+var a = tag`foo${bar}`;
+var b = tag`\unicode`;
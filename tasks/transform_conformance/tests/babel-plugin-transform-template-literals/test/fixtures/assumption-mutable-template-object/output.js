@@ -0,0 +1,4 @@
+var _templateObject;
+var _templateObject2;
+var a = tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteralLoose(["foo", ""])), bar);
+var b = tag(_templateObject2 || (_templateObject2 = babelHelpers.taggedTemplateLiteralLoose([void 0], ["\\unicode"])));
@@ -0,0 +1,7 @@
+var a = `foo`;
+var b = `foo${bar}`;
+var c = `${foo}bar`;
+var d = `foo${bar}baz${qux}`;
+var e = `${a}${b}${c}`;
+var f = `foo${1}${"bar"}${baz}`;
+var g = `\n${a}A`;
@@ -0,0 +1,7 @@
+var a = "foo";
+var b = "foo".concat(bar);
+var c = "".concat(foo, "bar");
+var d = "foo".concat(bar, "baz").concat(qux);
+var e = "".concat(a).concat(b).concat(c);
+var f = "foo".concat(1, "bar", baz);
+var g = "\n".concat(a, "A");
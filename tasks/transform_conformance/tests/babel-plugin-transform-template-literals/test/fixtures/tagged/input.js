@@ -0,0 +1,7 @@
+var a = tag`foo`;
+var b = tag`foo${bar}baz`;
+var c = tag`\unicode and \u{55}`;
+var d = obj.tag`foo`;
+function f() {
+  return tag`inner${x}`;
+}
@@ -0,0 +1,12 @@
+var _templateObject;
+var _templateObject2;
+var _templateObject3;
+var _templateObject4;
+var _templateObject5;
+var a = tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo"])));
+var b = tag(_templateObject2 || (_templateObject2 = babelHelpers.taggedTemplateLiteral(["foo", "baz"])), bar);
+var c = tag(_templateObject3 || (_templateObject3 = babelHelpers.taggedTemplateLiteral([void 0], ["\\unicode and \\u{55}"])));
+var d = obj.tag(_templateObject4 || (_templateObject4 = babelHelpers.taggedTemplateLiteral(["foo"])));
+function f() {
+  return tag(_templateObject5 || (_templateObject5 = babelHelpers.taggedTemplateLiteral(["inner", ""])), x);
+}